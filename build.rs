@@ -0,0 +1,93 @@
+//! 构建脚本
+//!
+//! 在编译时收集构建信息，通过 `cargo:rustc-env` 注入环境变量，由 `VersionInfo` 读取：
+//! - `WORKFLOW_GIT_COMMIT` - 构建时的 Git commit hash（不在 Git 仓库中构建时不设置）
+//! - `WORKFLOW_BUILD_DATE` - 构建日期（UTC，`YYYY-MM-DD`；设置了 `SOURCE_DATE_EPOCH` 时使用该时间，便于可重现构建）
+//! - `WORKFLOW_RUSTC_VERSION` - 编译使用的 rustc 版本
+//! - `WORKFLOW_TARGET` - 目标平台三元组
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=SOURCE_DATE_EPOCH");
+
+    if let Some(commit) = git_commit() {
+        println!("cargo:rustc-env=WORKFLOW_GIT_COMMIT={}", commit);
+    }
+    println!("cargo:rustc-env=WORKFLOW_BUILD_DATE={}", build_date());
+    if let Some(rustc_version) = rustc_version() {
+        println!("cargo:rustc-env=WORKFLOW_RUSTC_VERSION={}", rustc_version);
+    }
+    if let Ok(target) = env::var("TARGET") {
+        println!("cargo:rustc-env=WORKFLOW_TARGET={}", target);
+    }
+}
+
+/// 获取当前 Git commit hash，并在 HEAD 变化时重新运行构建脚本
+fn git_commit() -> Option<String> {
+    let git_dir = command_output("git", &["rev-parse", "--git-dir"])?;
+    let head = Path::new(&git_dir).join("HEAD");
+    println!("cargo:rerun-if-changed={}", head.display());
+    if let Some(reference) = std::fs::read_to_string(&head)
+        .ok()
+        .and_then(|content| content.strip_prefix("ref: ").map(|r| r.trim().to_string()))
+    {
+        println!(
+            "cargo:rerun-if-changed={}",
+            Path::new(&git_dir).join(reference).display()
+        );
+    }
+
+    command_output("git", &["rev-parse", "HEAD"])
+}
+
+/// 获取 rustc 版本（如 `rustc 1.89.0 (29483883e 2025-08-04)`）
+fn rustc_version() -> Option<String> {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    command_output(&rustc, &["--version"])
+}
+
+/// 构建日期（UTC，`YYYY-MM-DD`）
+fn build_date() -> String {
+    let secs = env::var("SOURCE_DATE_EPOCH")
+        .ok()
+        .and_then(|value| value.trim().parse::<i64>().ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs() as i64)
+                .unwrap_or(0)
+        });
+
+    let (year, month, day) = civil_from_days(secs.div_euclid(86_400));
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+/// 将自 1970-01-01 起的天数转换为公历日期（Howard Hinnant 的 `civil_from_days` 算法）
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// 执行命令并返回去掉首尾空白的标准输出（命令失败或输出为空时返回 `None`）
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let stdout = String::from_utf8(output.stdout).ok()?;
+    let stdout = stdout.trim();
+    (!stdout.is_empty()).then(|| stdout.to_string())
+}
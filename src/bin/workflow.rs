@@ -5,42 +5,52 @@
 
 use clap::Parser;
 use color_eyre::Result;
+use std::path::Path;
 
-use workflow::commands::alias::{AliasAddCommand, AliasListCommand, AliasRemoveCommand};
+use workflow::commands::alias::{
+    AliasAddCommand, AliasInstallCommand, AliasListCommand, AliasRemoveCommand,
+};
 use workflow::commands::branch::{
     create as branch_create, delete, ignore, rename, switch, sync as branch_sync,
 };
-use workflow::commands::check::check;
+use workflow::commands::cache::{CacheCleanCommand, CacheListCommand};
+use workflow::commands::check::{api::ApiCheckOptions, check};
 use workflow::commands::commit::{CommitAmendCommand, CommitRewordCommand, CommitSquashCommand};
-use workflow::commands::config::{completion, export, import, log, setup, show, validate};
+use workflow::commands::config::{
+    completion, export, git as config_git, import, log, profile, setup, show, validate,
+};
 use workflow::commands::github::github;
 use workflow::commands::jira::{
-    AttachmentsCommand, ChangelogCommand, CleanCommand, CommentCommand, CommentsCommand,
-    InfoCommand, RelatedCommand,
+    AttachCommand, AttachmentsCommand, ChangelogCommand, CleanCommand, CommentCommand,
+    CommentsCommand, InfoCommand, RelatedCommand, SprintCommand,
 };
 use workflow::commands::lifecycle::{uninstall, update as lifecycle_update, version};
 use workflow::commands::llm::{LLMSetupCommand, LLMShowCommand};
-use workflow::commands::log::{DownloadCommand, FindCommand, SearchCommand};
+use workflow::commands::log::{DownloadCommand, FindCommand, SearchCommand, TailCommand};
+use workflow::commands::mcp::McpServeCommand;
 use workflow::commands::migrate::MigrateCommand;
 use workflow::commands::pr::{
-    approve, close, comment, create as pr_create, list, merge, pick, rebase, reword, status,
-    summarize, sync, update as pr_update,
+    approve, close, comment, create as pr_create, list, merge, pick, rebase, review, reword,
+    status, summarize, sync, update as pr_update,
 };
 use workflow::commands::proxy::proxy;
 use workflow::commands::repo::{clean as repo_clean, setup as repo_setup, show as repo_show};
 use workflow::commands::stash::{apply, drop, list as stash_list, pop, push};
-use workflow::commands::tag::TagDeleteCommand;
+use workflow::commands::tag::{
+    TagCreateCommand, TagDeleteCommand, TagListCommand, TagNextCommand, TagPushCommand,
+};
 
 use workflow::cli::{
-    AliasSubcommand, BranchSubcommand, Cli, Commands, CommitSubcommand, CompletionSubcommand,
-    ConfigSubcommand, GitHubSubcommand, IgnoreSubcommand, JiraSubcommand, LLMSubcommand,
-    LogLevelSubcommand, LogSubcommand, PRCommands, ProxySubcommand, RepoSubcommand,
-    StashSubcommand, TagSubcommand,
+    AliasSubcommand, BranchSubcommand, CacheSubcommand, Cli, Commands, CommitSubcommand,
+    CompletionSubcommand, ConfigSubcommand, GitConfigSubcommand, GitHubSubcommand,
+    IgnoreSubcommand, JiraSubcommand, LLMSubcommand, LogLevelSubcommand, LogSubcommand,
+    McpSubcommand, PRCommands, ProfileSubcommand, ProxySubcommand, RepoSubcommand, StashSubcommand,
+    TagSubcommand,
 };
 use workflow::*;
 
 use workflow::base::alias::AliasManager;
-use workflow::base::settings::Settings;
+use workflow::base::settings::{Paths, Profile, Settings, CONFIG_ENV, PROFILE_ENV};
 
 /// 主函数
 ///
@@ -49,25 +59,78 @@ fn main() -> Result<()> {
     // 安装 color-eyre（最早调用）
     color_eyre::install()?;
 
-    // 初始化日志级别（从配置文件读取，用于 log_*! 宏）
+    // 配置路径：`--config` 优先于 WORKFLOW_CONFIG，需在首次加载配置之前设置并校验
+    let args: Vec<String> = std::env::args().collect();
+    if let Some(path) = Paths::config_from_args(&args) {
+        std::env::set_var(CONFIG_ENV, &path);
+    }
+    if let Some(path) = Paths::config_override() {
+        Paths::validate_config_override(&path)?;
+    }
+
+    // 选择配置 Profile：`--profile` 优先于 WORKFLOW_PROFILE，需在首次加载配置之前设置
+    if let Some(name) = Profile::from_args(&args) {
+        Profile::validate_name(&name)?;
+        std::env::set_var(PROFILE_ENV, &name);
+    }
+
+    // 别名展开：在解析前展开别名
+    let expanded_args = AliasManager::expand_args(args)?;
+
+    // 使用展开后的参数重新解析
+    let cli = Cli::parse_from(expanded_args);
+
+    // 颜色开关：`--no-color`、NO_COLOR 或 stdout 不是终端时禁用颜色输出
+    workflow::base::logger::console::init_colors(cli.no_color);
+
+    // 初始化日志级别（用于 log_*! 宏）
+    // 优先级：全局参数 `-v`/`-q` > 环境变量 WORKFLOW_LOG__LEVEL > 配置文件 log.level
     {
         let config_level = Settings::get()
             .log
             .level
             .as_ref()
             .and_then(|s| s.parse::<workflow::LogLevel>().ok());
-        workflow::LogLevel::init(config_level);
+        workflow::LogLevel::init(cli.verbosity.level_override().or(config_level));
     }
 
-    // 初始化 tracing（从配置文件读取，统一管理）
-    workflow::Tracer::init();
+    // HTTP 跟踪：`--http-trace`、`-vv` 或 `log.http_trace`，需在初始化 tracing 之前设置
+    workflow::base::http::HttpTrace::set_enabled(
+        cli.http_trace
+            || cli.verbosity.is_trace()
+            || Settings::get().log.http_trace.unwrap_or(false),
+    );
 
-    // 别名展开：在解析前展开别名
-    let args: Vec<String> = std::env::args().collect();
-    let expanded_args = AliasManager::expand_args(args)?;
+    // 日志输出格式：`--log-format json` 时日志以 JSON 对象输出到 stderr，需在初始化 tracing 之前设置
+    workflow::LogFormat::set(cli.log_format.unwrap_or_default());
 
-    // 使用展开后的参数重新解析
-    let cli = Cli::parse_from(expanded_args);
+    // 初始化 tracing（从配置文件读取，统一管理；`-vv` 时以 debug 级别输出到控制台）
+    workflow::Tracer::init_with_trace(cli.verbosity.is_trace());
+
+    // 启动时校验配置：只提示错误，不中断执行（完整结果见 `workflow check`）
+    {
+        let errors: Vec<_> = Settings::validate_active()
+            .into_iter()
+            .filter(|issue| issue.is_error())
+            .collect();
+        for issue in &errors {
+            eprintln!(
+                "{}",
+                workflow::base::logger::console::warning(format!(
+                    "Config {}: {}",
+                    issue.field, issue.message
+                ))
+            );
+        }
+        if !errors.is_empty() {
+            eprintln!(
+                "{}",
+                workflow::base::logger::console::info(
+                    "Run 'workflow check' or 'workflow config validate' for details."
+                )
+            );
+        }
+    }
 
     match cli.command {
         // 代理管理命令
@@ -77,15 +140,43 @@ fn main() -> Result<()> {
         }) => match subcommand {
             ProxySubcommand::On => proxy::ProxyCommand::on(temporary)?,
             ProxySubcommand::Off => proxy::ProxyCommand::off()?,
-            ProxySubcommand::Check => proxy::ProxyCommand::check()?,
+            ProxySubcommand::Check { output_format } => proxy::ProxyCommand::check(output_format)?,
         },
         // 环境检查
-        Some(Commands::Check) => {
-            check::CheckCommand::run_all()?;
+        Some(Commands::Check {
+            watch,
+            interval,
+            skip_jira,
+            skip_github,
+            skip_llm,
+            fix,
+        }) => {
+            let api_options = ApiCheckOptions {
+                skip_jira,
+                skip_github,
+                skip_llm,
+            };
+            if fix {
+                check::CheckCommand::fix()?;
+                log_break!();
+            }
+            if watch {
+                check::CheckCommand::watch(interval, &api_options)?;
+            } else {
+                check::CheckCommand::run(&api_options)?;
+            }
         }
         // 配置初始化
-        Some(Commands::Setup) => {
-            setup::SetupCommand::run()?;
+        Some(Commands::Setup {
+            non_interactive,
+            answers,
+            skip_verify,
+        }) => {
+            if non_interactive {
+                setup::SetupCommand::run_non_interactive(answers.as_deref().map(Path::new))?;
+            } else {
+                setup::SetupCommand::run_with_verification(!skip_verify)?;
+            }
         }
         // 配置管理命令
         Some(Commands::Config { subcommand }) => match subcommand {
@@ -100,7 +191,7 @@ fn main() -> Result<()> {
             Some(ConfigSubcommand::Export {
                 output_path,
                 section,
-                no_secrets,
+                include_secrets,
                 toml,
                 json,
                 yaml,
@@ -108,7 +199,7 @@ fn main() -> Result<()> {
                 export::ConfigExportCommand::export(
                     output_path,
                     section,
-                    no_secrets,
+                    include_secrets,
                     toml,
                     json,
                     yaml,
@@ -127,6 +218,19 @@ fn main() -> Result<()> {
                     dry_run.is_dry_run(),
                 )?;
             }
+            Some(ConfigSubcommand::Profile { subcommand }) => match subcommand {
+                ProfileSubcommand::List => profile::ProfileCommand::list()?,
+                ProfileSubcommand::Use { name } => profile::ProfileCommand::use_profile(name)?,
+                ProfileSubcommand::Create { name } => profile::ProfileCommand::create(name)?,
+            },
+            Some(ConfigSubcommand::Git { subcommand }) => match subcommand {
+                GitConfigSubcommand::Get { key, local } => {
+                    config_git::GitConfigCommand::get(key.as_deref(), local)?
+                }
+                GitConfigSubcommand::Set { key, value, local } => {
+                    config_git::GitConfigCommand::set(&key, &value, local)?
+                }
+            },
             None => {
                 // 当没有子命令时，显示帮助信息
                 log_message!("Configuration Management");
@@ -135,20 +239,32 @@ fn main() -> Result<()> {
                 log_message!("  workflow config validate - Validate configuration file");
                 log_message!("  workflow config export   - Export configuration to a file");
                 log_message!("  workflow config import   - Import configuration from a file");
+                log_message!("  workflow config profile  - Manage configuration profiles");
+                log_message!("  workflow config git      - Manage git identity and signing");
                 log_message!("\nUse 'workflow config <subcommand> --help' for more information.");
             }
         },
         // 卸载
-        Some(Commands::Uninstall) => {
-            uninstall::UninstallCommand::run()?;
+        Some(Commands::Uninstall { dry_run, keep }) => {
+            uninstall::UninstallCommand::run(dry_run.is_dry_run(), &keep)?;
         }
         // 版本信息
-        Some(Commands::Version) => {
-            version::VersionCommand::show()?;
+        Some(Commands::Version { json }) => {
+            version::VersionCommand::show(json)?;
         }
         // 更新
-        Some(Commands::Update { version }) => {
-            lifecycle_update::UpdateCommand::update(version)?;
+        Some(Commands::Update {
+            version,
+            check,
+            skip_verify,
+        }) => {
+            if check {
+                if lifecycle_update::UpdateCommand::check()?.update_available() {
+                    std::process::exit(lifecycle_update::UPDATE_AVAILABLE_EXIT_CODE);
+                }
+            } else {
+                lifecycle_update::UpdateCommand::update(version, skip_verify)?;
+            }
         }
         // 日志级别管理命令
         Some(Commands::Log { subcommand }) => match subcommand {
@@ -162,8 +278,9 @@ fn main() -> Result<()> {
             GitHubSubcommand::Current => github::GitHubCommand::current()?,
             GitHubSubcommand::Add => github::GitHubCommand::add()?,
             GitHubSubcommand::Remove => github::GitHubCommand::remove()?,
-            GitHubSubcommand::Switch => github::GitHubCommand::switch()?,
+            GitHubSubcommand::Switch { global } => github::GitHubCommand::switch(global)?,
             GitHubSubcommand::Update => github::GitHubCommand::update()?,
+            GitHubSubcommand::RateLimit => github::GitHubCommand::rate_limit()?,
         },
         // LLM 配置管理命令
         Some(Commands::Llm { subcommand }) => match subcommand {
@@ -172,8 +289,16 @@ fn main() -> Result<()> {
         },
         // Completion 管理命令
         Some(Commands::Completion { subcommand }) => match subcommand {
-            CompletionSubcommand::Generate => completion::CompletionCommand::generate()?,
-            CompletionSubcommand::Check => completion::CompletionCommand::check()?,
+            CompletionSubcommand::Generate { output } => {
+                completion::CompletionCommand::generate(output)?
+            }
+            CompletionSubcommand::Install => completion::CompletionCommand::install()?,
+            CompletionSubcommand::Check { exit_code } => {
+                let check = completion::CompletionCommand::check()?;
+                if exit_code && check.needs_regeneration() {
+                    std::process::exit(completion::COMPLETION_STALE_EXIT_CODE);
+                }
+            }
             CompletionSubcommand::Remove => completion::CompletionCommand::remove()?,
         },
         // 分支管理命令
@@ -211,8 +336,15 @@ fn main() -> Result<()> {
                 rebase,
                 ff_only,
                 squash,
+                dry_run,
             } => {
-                branch_sync::BranchSyncCommand::sync(source_branch, rebase, ff_only, squash)?;
+                branch_sync::BranchSyncCommand::sync(
+                    source_branch,
+                    rebase,
+                    ff_only,
+                    squash,
+                    dry_run.is_dry_run(),
+                )?;
             }
             BranchSubcommand::Delete {
                 branch_name,
@@ -236,8 +368,9 @@ fn main() -> Result<()> {
                 message,
                 no_edit,
                 no_verify,
+                co_authors,
             } => {
-                CommitAmendCommand::execute(message, no_edit, no_verify)?;
+                CommitAmendCommand::execute(message, no_edit, no_verify, co_authors)?;
             }
             CommitSubcommand::Reword { commit_id } => {
                 CommitRewordCommand::execute(commit_id)?;
@@ -252,28 +385,59 @@ fn main() -> Result<()> {
                 jira_id,
                 title,
                 description,
+                from_commits,
                 dry_run,
             } => {
                 pr_create::PullRequestCreateCommand::create(
                     jira_id.into_option(),
                     title,
                     description,
+                    from_commits,
                     dry_run.is_dry_run(),
                 )?;
             }
             PRCommands::Merge {
                 pull_request_id,
                 force,
+                dry_run,
+                auto,
+                strategy,
+                delete_branch,
+                keep_branch,
             } => {
-                merge::PullRequestMergeCommand::merge(pull_request_id, force.is_force())?;
+                if auto {
+                    merge::PullRequestMergeCommand::enable_auto_merge(pull_request_id, strategy)?;
+                } else {
+                    merge::PullRequestMergeCommand::merge(
+                        pull_request_id,
+                        force.is_force(),
+                        dry_run.is_dry_run(),
+                        delete_branch,
+                        keep_branch,
+                        strategy,
+                    )?;
+                }
             }
             PRCommands::Status {
                 pull_request_id_or_branch,
+                output_format,
             } => {
-                status::PullRequestStatusCommand::show(pull_request_id_or_branch)?;
+                status::PullRequestStatusCommand::show(pull_request_id_or_branch, output_format)?;
             }
-            PRCommands::List { state, pagination } => {
-                list::PullRequestListCommand::list(state, pagination.limit)?;
+            PRCommands::List {
+                state,
+                filters,
+                pagination,
+                output,
+                json,
+            } => {
+                list::PullRequestListCommand::list(
+                    state,
+                    filters,
+                    pagination.limit,
+                    output.format,
+                    json,
+                )?;
             }
             PRCommands::Update => {
                 pr_update::PullRequestUpdateCommand::update()?;
@@ -283,8 +447,21 @@ fn main() -> Result<()> {
                 rebase,
                 ff_only,
                 squash,
+                message,
+                no_push,
+                all,
+                dry_run,
             } => {
-                sync::PullRequestSyncCommand::sync(source_branch, rebase, ff_only, squash)?;
+                sync::PullRequestSyncCommand::sync(
+                    source_branch,
+                    rebase,
+                    ff_only,
+                    squash,
+                    dry_run.is_dry_run(),
+                    message,
+                    no_push,
+                    all,
+                )?;
             }
             PRCommands::Rebase {
                 target_branch,
@@ -300,17 +477,44 @@ fn main() -> Result<()> {
             PRCommands::Close { pull_request_id } => {
                 close::PullRequestCloseCommand::close(pull_request_id)?;
             }
-            PRCommands::Summarize { pull_request_id } => {
-                summarize::SummarizeCommand::summarize(pull_request_id)?;
+            PRCommands::Summarize {
+                pull_request_id,
+                no_cache,
+                clear_cache,
+            } => {
+                if clear_cache {
+                    summarize::SummarizeCommand::clear_cache()?;
+                } else {
+                    summarize::SummarizeCommand::summarize(pull_request_id, no_cache)?;
+                }
+            }
+            PRCommands::Approve {
+                pull_request_id,
+                message,
+            } => {
+                approve::PullRequestApproveCommand::approve(pull_request_id, message)?;
             }
-            PRCommands::Approve { pull_request_id } => {
-                approve::PullRequestApproveCommand::approve(pull_request_id)?;
+            PRCommands::Review {
+                pull_request_id,
+                review_type,
+                message,
+            } => {
+                review::PullRequestReviewCommand::review(pull_request_id, review_type, message)?;
             }
             PRCommands::Comment {
                 pull_request_id,
+                file,
+                line,
+                reply_to,
                 message,
             } => {
-                comment::PullRequestCommentCommand::comment(pull_request_id, message)?;
+                comment::PullRequestCommentCommand::comment(
+                    pull_request_id,
+                    message,
+                    file,
+                    line,
+                    reply_to,
+                )?;
             }
             PRCommands::Pick {
                 from_branch,
@@ -338,14 +542,18 @@ fn main() -> Result<()> {
             JiraSubcommand::Info { args } => {
                 InfoCommand::show(args)?;
             }
-            JiraSubcommand::Related { args } => {
-                RelatedCommand::show(args)?;
+            JiraSubcommand::Related { args, open } => {
+                RelatedCommand::show(args, open)?;
             }
-            JiraSubcommand::Changelog { args } => {
-                ChangelogCommand::show(args)?;
+            JiraSubcommand::Changelog {
+                args,
+                field,
+                timestamps,
+            } => {
+                ChangelogCommand::show(args, field, timestamps.is_absolute())?;
             }
-            JiraSubcommand::Comment { jira_id } => {
-                CommentCommand::add(jira_id.into_option())?;
+            JiraSubcommand::Comment { jira_id, message } => {
+                CommentCommand::add(jira_id.into_option(), message)?;
             }
             JiraSubcommand::Comments {
                 jira_id,
@@ -353,6 +561,7 @@ fn main() -> Result<()> {
                 author,
                 since,
                 output_format,
+                timestamps,
             } => {
                 CommentsCommand::show(
                     jira_id.into_option(),
@@ -361,8 +570,19 @@ fn main() -> Result<()> {
                     author,
                     since,
                     output_format,
+                    timestamps.is_absolute(),
                 )?;
             }
+            JiraSubcommand::Sprint {
+                project,
+                board,
+                output_format,
+            } => {
+                SprintCommand::show(project, board, output_format)?;
+            }
+            JiraSubcommand::Attach { jira_id, file } => {
+                AttachCommand::attach(&jira_id, &file)?;
+            }
             JiraSubcommand::Attachments { jira_id } => {
                 AttachmentsCommand::download(jira_id.into_option())?;
             }
@@ -381,14 +601,35 @@ fn main() -> Result<()> {
                 LogSubcommand::Find {
                     jira_id,
                     request_id,
+                    filter,
+                    format,
                 } => {
-                    FindCommand::find_request_id(jira_id.into_option(), request_id)?;
+                    FindCommand::find_request_id(
+                        jira_id.into_option(),
+                        request_id,
+                        filter.to_filter(),
+                        format,
+                    )?;
                 }
                 LogSubcommand::Search {
                     jira_id,
                     search_term,
+                    filter,
+                    format,
+                } => {
+                    SearchCommand::search(
+                        jira_id.into_option(),
+                        search_term,
+                        filter.to_filter(),
+                        format,
+                    )?;
+                }
+                LogSubcommand::Tail {
+                    jira_id,
+                    lines,
+                    follow,
                 } => {
-                    SearchCommand::search(jira_id.into_option(), search_term)?;
+                    TailCommand::tail(jira_id.into_option(), lines, follow)?;
                 }
             },
         },
@@ -401,8 +642,8 @@ fn main() -> Result<()> {
         }
         // Stash 管理命令
         Some(Commands::Stash { subcommand }) => match subcommand {
-            StashSubcommand::List { stat } => {
-                stash_list::StashListCommand::execute(stat)?;
+            StashSubcommand::List { stat, output } => {
+                stash_list::StashListCommand::execute(stat, output.format)?;
             }
             StashSubcommand::Apply => {
                 apply::StashApplyCommand::execute()?;
@@ -431,12 +672,64 @@ fn main() -> Result<()> {
         },
         // 别名管理命令
         Some(Commands::Alias { subcommand }) => match subcommand {
-            AliasSubcommand::List => AliasListCommand::list()?,
-            AliasSubcommand::Add { name, command } => AliasAddCommand::add(name, command)?,
-            AliasSubcommand::Remove { name } => AliasRemoveCommand::remove(name)?,
+            AliasSubcommand::List { output } => AliasListCommand::list(output.format)?,
+            AliasSubcommand::Add {
+                name,
+                command,
+                shell,
+            } => AliasAddCommand::add(name, command, shell)?,
+            AliasSubcommand::Install { names, dry_run } => {
+                AliasInstallCommand::install(names, dry_run.is_dry_run())?
+            }
+            AliasSubcommand::Remove { name, shell, all } => {
+                AliasRemoveCommand::remove(name, shell, all)?
+            }
+        },
+        // 缓存管理命令
+        Some(Commands::Cache { subcommand }) => match subcommand {
+            CacheSubcommand::List { output } => CacheListCommand::list(output.format)?,
+            CacheSubcommand::Clean {
+                cache,
+                older_than,
+                dry_run,
+                force,
+            } => CacheCleanCommand::clean(
+                cache.map(Into::into),
+                older_than,
+                dry_run.is_dry_run(),
+                force.is_force(),
+            )?,
         },
         // Tag 管理命令
         Some(Commands::Tag { subcommand }) => match subcommand {
+            TagSubcommand::List { pattern, local } => {
+                TagListCommand::execute(pattern, local)?;
+            }
+            TagSubcommand::Create {
+                tag_name,
+                message,
+                target,
+                push,
+            } => {
+                TagCreateCommand::execute(tag_name, message, target, push)?;
+            }
+            TagSubcommand::Next {
+                bump,
+                pre,
+                prefix,
+                create,
+                message,
+                push,
+            } => {
+                TagNextCommand::execute(bump, pre, prefix, create, message, push)?;
+            }
+            TagSubcommand::Push {
+                tag_name,
+                all,
+                pattern,
+            } => {
+                TagPushCommand::execute(tag_name, all, pattern)?;
+            }
             TagSubcommand::Delete {
                 tag_name,
                 local,
@@ -455,6 +748,10 @@ fn main() -> Result<()> {
                 )?;
             }
         },
+        // MCP 服务器命令
+        Some(Commands::Mcp { subcommand }) => match subcommand {
+            McpSubcommand::Serve => McpServeCommand::serve()?,
+        },
         // 无命令时显示帮助信息
         None => {
             log_message!("Workflow CLI - Configuration Management");
@@ -465,6 +762,7 @@ fn main() -> Result<()> {
             log_message!("  workflow config     - View current configuration");
             log_message!("  workflow github     - Manage GitHub accounts (list/add/remove/switch/update/current)");
             log_message!("  workflow log        - Manage log level (set/check)");
+            log_message!("  workflow mcp        - Run an MCP server for AI assistants (serve)");
             log_message!("  workflow migrate    - Migrate configuration to new format");
             log_message!("  workflow proxy      - Manage proxy settings (on/off/check)");
             log_message!("  workflow setup      - Initialize or update configuration");
@@ -474,7 +772,9 @@ fn main() -> Result<()> {
                 "  workflow update     - Update Workflow CLI (rebuild and update binaries)"
             );
             log_message!("  workflow pr         - Pull Request operations (create/merge/close/status/list/update/sync)");
-            log_message!("  workflow jira       - Jira operations (info/attachments/clean/log)");
+            log_message!(
+                "  workflow jira       - Jira operations (info/attach/attachments/clean/log)"
+            );
             log_message!("  workflow stash      - Git stash management (list/apply/drop/pop/push)");
             log_message!("\nOther CLI tools:");
             log_message!("  install             - Install Workflow CLI components (binaries and/or completions)");
@@ -1,9 +1,10 @@
 //! 别名添加命令
 //!
-//! 支持直接添加和交互式添加别名。
+//! 支持直接添加和交互式添加别名，以及添加写入 shell 配置文件的 shell 别名。
 
 use crate::base::alias::{AliasManager, CommandsConfig};
 use crate::base::dialog::{ConfirmDialog, FormBuilder, GroupConfig, InputDialog};
+use crate::base::shell::ShellConfigManager;
 use crate::{log_info, log_success, log_warning};
 use color_eyre::{eyre::WrapErr, Result};
 
@@ -16,7 +17,13 @@ impl AliasAddCommand {
     /// 支持两种模式：
     /// - 直接模式：提供 name 和 command 参数
     /// - 交互式模式：不提供参数，通过对话框输入
-    pub fn add(name: Option<String>, command: Option<String>) -> Result<()> {
+    ///
+    /// `shell` 为 `true` 时添加 shell 别名（见 `add_shell_alias`）。
+    pub fn add(name: Option<String>, command: Option<String>, shell: bool) -> Result<()> {
+        if shell {
+            return Self::add_shell_alias(name, command);
+        }
+
         let (alias_name, alias_command, is_direct_mode) = if let (Some(name), Some(cmd)) =
             (name, command)
         {
@@ -29,14 +36,7 @@ impl AliasAddCommand {
             // 收集别名名称
             let name = InputDialog::new("Enter alias name")
                 .with_validator(|input: &str| {
-                    let trimmed = input.trim();
-                    if trimmed.is_empty() {
-                        Err("Alias name cannot be empty".to_string())
-                    } else if trimmed.contains(' ') {
-                        Err("Alias name cannot contain spaces".to_string())
-                    } else {
-                        Ok(())
-                    }
+                    AliasManager::validate_alias_name(input.trim()).map_err(|e| e.to_string())
                 })
                 .prompt()
                 .wrap_err("Failed to get alias name")?
@@ -115,6 +115,9 @@ impl AliasAddCommand {
             (name, cmd, false)
         };
 
+        // 检查别名名称（不能与内置命令冲突）
+        AliasManager::validate_alias_name(&alias_name)?;
+
         // 检查循环别名
         if AliasManager::check_circular(&alias_name, &alias_command)? {
             return Err(color_eyre::eyre::eyre!(
@@ -170,4 +173,93 @@ impl AliasAddCommand {
 
         Ok(())
     }
+
+    /// 添加 shell 别名
+    ///
+    /// 别名写入 shell 配置文件的托管别名配置块（如 `alias prc='workflow pr create'`），
+    /// 重复添加相同的别名不会修改配置文件；重新加载 shell 配置后生效。
+    /// 名称或命令未提供时通过对话框输入。
+    fn add_shell_alias(name: Option<String>, command: Option<String>) -> Result<()> {
+        let name = match name {
+            Some(name) => name,
+            None => InputDialog::new("Enter shell alias name")
+                .with_validator(|input: &str| {
+                    AliasManager::validate_shell_alias_name(input.trim()).map_err(|e| e.to_string())
+                })
+                .prompt()
+                .wrap_err("Failed to get alias name")?
+                .trim()
+                .to_string(),
+        };
+        AliasManager::validate_shell_alias_name(&name)?;
+
+        let command = match command {
+            Some(command) => command,
+            None => InputDialog::new("Enter command")
+                .with_default("workflow")
+                .with_validator(|input: &str| {
+                    if input.trim().is_empty() {
+                        Err("Command cannot be empty".to_string())
+                    } else {
+                        Ok(())
+                    }
+                })
+                .prompt()
+                .wrap_err("Failed to get command")?,
+        };
+        let command = command.trim().to_string();
+        if command.is_empty() {
+            return Err(color_eyre::eyre::eyre!("Command cannot be empty"));
+        }
+
+        // 配置块外已有同名别名时，后定义的别名会覆盖先定义的别名
+        if ShellConfigManager::load_unmanaged_alias_names()?.contains(&name) {
+            log_warning!(
+                "'{}' is already defined outside the workflow alias block in your shell config; \
+                 the definition that comes last wins",
+                name
+            );
+        }
+
+        // 检查别名是否已存在
+        if let Some(existing) = ShellConfigManager::load_aliases()?.get(&name) {
+            if *existing == command {
+                log_info!("Shell alias '{}' = '{}' already exists", name, command);
+                return Ok(());
+            }
+
+            let should_overwrite = ConfirmDialog::new(format!(
+                "Shell alias '{}' = '{}' already exists. Overwrite? (y/N)",
+                name, existing
+            ))
+            .with_default(false)
+            .prompt()
+            .unwrap_or(false);
+
+            if !should_overwrite {
+                log_info!("Operation cancelled");
+                return Ok(());
+            }
+        }
+
+        match ShellConfigManager::set_alias(&name, &command)? {
+            Some(edit) => {
+                log_success!(
+                    "Shell alias '{}' = '{}' added to {}",
+                    name,
+                    command,
+                    edit.config_path.display()
+                );
+                if let Some(ref backup_path) = edit.backup_path {
+                    log_info!("  Backup of previous shell config: {:?}", backup_path);
+                }
+                log_info!("Reload your shell configuration or open a new terminal to use it.");
+            }
+            None => {
+                log_info!("Shell alias '{}' = '{}' already exists", name, command);
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,123 @@
+//! 别名安装命令
+//!
+//! 将默认的 shell 别名（如 `wf`、`wpr`）批量写入 shell 配置文件的托管别名配置块。
+
+use std::collections::BTreeMap;
+
+use color_eyre::{eyre::WrapErr, Result};
+
+use crate::base::alias::{AliasManager, CommandsConfig};
+use crate::base::settings::paths::Paths;
+use crate::base::shell::{Detect, ShellConfigManager};
+use crate::{log_break, log_info, log_message, log_success, log_warning};
+
+/// 别名安装命令
+pub struct AliasInstallCommand;
+
+impl AliasInstallCommand {
+    /// 安装默认的 shell 别名
+    ///
+    /// 别名按当前 shell 的语法写入托管别名配置块（zsh/bash 为 `alias`，fish 为 `abbr`，
+    /// PowerShell 为 `function`），可以通过 `workflow alias remove --shell --all` 整体移除。
+    /// 以下别名会跳过并给出警告：
+    /// - 配置块外已有同名别名（用户自己定义的别名）
+    /// - 与 PATH 中已有命令同名
+    /// - 托管别名配置块中已有同名但命令不同的别名
+    ///
+    /// # 参数
+    ///
+    /// * `names` - 要安装的别名名称（为空时安装所有默认别名）
+    /// * `dry_run` - 只预览要安装的别名，不修改配置文件
+    pub fn install(names: Vec<String>, dry_run: bool) -> Result<()> {
+        let defaults = CommandsConfig::get_shell_aliases();
+
+        let unknown: Vec<&str> = names
+            .iter()
+            .filter(|name| !defaults.contains_key(*name))
+            .map(String::as_str)
+            .collect();
+        if !unknown.is_empty() {
+            return Err(color_eyre::eyre::eyre!(
+                "Unknown shell alias(es): {} (available: {})",
+                unknown.join(", "),
+                defaults.keys().cloned().collect::<Vec<_>>().join(", ")
+            ));
+        }
+
+        let requested: BTreeMap<String, String> = defaults
+            .into_iter()
+            .filter(|(name, _)| names.is_empty() || names.contains(name))
+            .collect();
+
+        let shell = Detect::shell().wrap_err("Failed to detect current shell type")?;
+        let config_path = Paths::config_file(&shell)?;
+        let managed = ShellConfigManager::load_aliases()?;
+        let unmanaged = ShellConfigManager::load_unmanaged_alias_names()?;
+
+        log_message!(
+            "Installing shell aliases for {} ({})",
+            shell,
+            config_path.display()
+        );
+
+        let mut to_install = BTreeMap::new();
+        for (name, command) in requested {
+            if unmanaged.contains(&name) {
+                log_warning!(
+                    "Skipping '{}': already defined outside the workflow alias block in your shell config",
+                    name
+                );
+                continue;
+            }
+            if let Err(e) = AliasManager::validate_shell_alias_name(&name) {
+                log_warning!("Skipping '{}': {}", name, e);
+                continue;
+            }
+            match managed.get(&name) {
+                Some(existing) if *existing == command => {
+                    log_info!("  {} = {} (already installed)", name, command);
+                }
+                Some(existing) => {
+                    log_warning!(
+                        "Skipping '{}': already installed as '{}' (remove it first with: workflow alias remove --shell {})",
+                        name,
+                        existing,
+                        name
+                    );
+                }
+                None => {
+                    log_info!("  {} = {}", name, command);
+                    to_install.insert(name, command);
+                }
+            }
+        }
+
+        if to_install.is_empty() {
+            log_info!("No shell aliases to install");
+            return Ok(());
+        }
+
+        if dry_run {
+            log_break!();
+            log_info!(
+                "Dry-run mode: {} shell alias/aliases will not be actually installed",
+                to_install.len()
+            );
+            return Ok(());
+        }
+
+        if let Some(edit) = ShellConfigManager::set_aliases(&to_install)? {
+            log_success!(
+                "Installed {} shell alias/aliases to {}",
+                to_install.len(),
+                edit.config_path.display()
+            );
+            if let Some(ref backup_path) = edit.backup_path {
+                log_info!("  Backup of previous shell config: {:?}", backup_path);
+            }
+            log_info!("Reload your shell configuration or open a new terminal to use them.");
+        }
+
+        Ok(())
+    }
+}
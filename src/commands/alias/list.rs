@@ -1,10 +1,14 @@
 //! 别名列表命令
 //!
-//! 显示所有已定义的别名，使用表格格式。
+//! 显示所有已定义的别名（workflow 别名和 shell 别名）及其展开后的完整命令，使用表格格式。
+
+use std::collections::{HashMap, HashSet};
 
 use crate::base::alias::AliasManager;
+use crate::base::shell::ShellConfigManager;
 use crate::base::table::{TableBuilder, TableStyle};
-use crate::{log_break, log_info, log_message, log_success};
+use crate::cli::TableFormat;
+use crate::{log_break, log_info, log_message, log_success, log_warning};
 use color_eyre::Result;
 use tabled::Tabled;
 
@@ -13,8 +17,12 @@ use tabled::Tabled;
 struct AliasRow {
     #[tabled(rename = "Alias Name")]
     alias_name: String,
+    #[tabled(rename = "Type")]
+    kind: String,
     #[tabled(rename = "Command")]
     command: String,
+    #[tabled(rename = "Expansion")]
+    expansion: String,
 }
 
 /// 别名列表命令
@@ -23,37 +31,100 @@ pub struct AliasListCommand;
 impl AliasListCommand {
     /// 列出所有别名
     ///
-    /// 使用表格格式显示所有已定义的别名。
-    pub fn list() -> Result<()> {
-        log_break!();
-        log_message!("Alias List");
+    /// 使用表格格式显示所有已定义的别名；`format` 不是 `table` 时只输出数据。
+    /// - workflow 别名：保存在配置文件中，展开为 `workflow <命令>`（支持嵌套别名）
+    /// - shell 别名：保存在 shell 配置文件的托管别名配置块中，命令中的 workflow 别名同样会被展开
+    pub fn list(format: TableFormat) -> Result<()> {
+        if format.is_table() {
+            log_break!();
+            log_message!("Alias List");
+        }
 
         let aliases = AliasManager::list()?;
+        let shell_aliases = match ShellConfigManager::load_aliases() {
+            Ok(shell_aliases) => shell_aliases,
+            Err(e) => {
+                if format.is_table() {
+                    log_warning!("Failed to read shell aliases: {}", e);
+                }
+                Default::default()
+            }
+        };
 
-        if aliases.is_empty() {
+        if aliases.is_empty() && shell_aliases.is_empty() && format.is_table() {
             log_info!("No aliases defined");
             log_message!("Run 'workflow alias add' to add an alias.");
             return Ok(());
         }
 
-        // 构建表格数据
-        let rows: Vec<AliasRow> = aliases
-            .iter()
-            .map(|(alias_name, command)| AliasRow {
+        // 构建表格数据（workflow 别名按名称排序，排在 shell 别名之前）
+        let mut names: Vec<&String> = aliases.keys().collect();
+        names.sort();
+        let mut rows: Vec<AliasRow> = names
+            .into_iter()
+            .map(|alias_name| AliasRow {
                 alias_name: alias_name.clone(),
-                command: command.clone(),
+                kind: "workflow".to_string(),
+                command: aliases[alias_name].clone(),
+                expansion: Self::expand_workflow_alias(alias_name),
             })
             .collect();
+        rows.extend(shell_aliases.iter().map(|(alias_name, command)| AliasRow {
+            alias_name: alias_name.clone(),
+            kind: "shell".to_string(),
+            command: command.clone(),
+            expansion: Self::expand_shell_command(command, &aliases),
+        }));
 
         // 显示表格
-        let table = TableBuilder::new(rows)
-            .with_title("Defined Aliases")
-            .with_style(TableStyle::Modern)
-            .render();
+        let table = format.render(
+            TableBuilder::new(rows)
+                .with_title("Defined Aliases")
+                .with_style(TableStyle::Modern),
+        );
 
         log_message!("{}", table);
-        log_success!("Found {} alias/aliases", aliases.len());
+        if !format.is_table() {
+            return Ok(());
+        }
+        log_success!(
+            "Found {} alias/aliases ({} workflow, {} shell)",
+            aliases.len() + shell_aliases.len(),
+            aliases.len(),
+            shell_aliases.len()
+        );
 
         Ok(())
     }
+
+    /// 展开 workflow 别名为完整命令（展开失败时显示错误原因）
+    fn expand_workflow_alias(alias_name: &str) -> String {
+        match AliasManager::expand(alias_name, &mut HashSet::new(), 0) {
+            Ok(expanded) => format!("workflow {}", expanded),
+            Err(e) => format!("<{}>", e),
+        }
+    }
+
+    /// 展开 shell 别名的命令
+    ///
+    /// 命令以 `workflow <workflow 别名>` 开头时展开其中的 workflow 别名，否则原样返回。
+    fn expand_shell_command(command: &str, aliases: &HashMap<String, String>) -> String {
+        let parts: Vec<&str> = command.split_whitespace().collect();
+        match parts.as_slice() {
+            ["workflow", alias_name, rest @ ..] if aliases.contains_key(*alias_name) => {
+                match AliasManager::expand(alias_name, &mut HashSet::new(), 0) {
+                    Ok(expanded) => {
+                        let mut expanded = format!("workflow {}", expanded);
+                        for part in rest {
+                            expanded.push(' ');
+                            expanded.push_str(part);
+                        }
+                        expanded
+                    }
+                    Err(_) => command.to_string(),
+                }
+            }
+            _ => command.to_string(),
+        }
+    }
 }
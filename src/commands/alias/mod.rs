@@ -1,11 +1,13 @@
 //! 别名管理命令
 //!
-//! 提供别名的列表、添加和删除功能。
+//! 提供别名的列表、添加、安装和删除功能。
 
 pub mod add;
+pub mod install;
 pub mod list;
 pub mod remove;
 
 pub use add::AliasAddCommand;
+pub use install::AliasInstallCommand;
 pub use list::AliasListCommand;
 pub use remove::AliasRemoveCommand;
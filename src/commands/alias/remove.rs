@@ -1,9 +1,10 @@
 //! 别名删除命令
 //!
-//! 支持直接删除和交互式多选删除别名。
+//! 支持直接删除和交互式多选删除别名，以及删除 shell 配置文件中的 shell 别名。
 
 use crate::base::alias::AliasManager;
 use crate::base::dialog::{ConfirmDialog, MultiSelectDialog};
+use crate::base::shell::ShellConfigManager;
 use crate::{log_info, log_message, log_success, log_warning};
 use color_eyre::{eyre::WrapErr, Result};
 
@@ -16,7 +17,17 @@ impl AliasRemoveCommand {
     /// 支持两种模式：
     /// - 直接模式：提供 name 参数
     /// - 交互式模式：不提供参数，通过多选对话框选择
-    pub fn remove(name: Option<String>) -> Result<()> {
+    ///
+    /// `shell` 为 `true` 时删除 shell 别名（见 `remove_shell_alias`），
+    /// 同时 `all` 为 `true` 时删除所有 shell 别名（见 `remove_all_shell_aliases`）。
+    pub fn remove(name: Option<String>, shell: bool, all: bool) -> Result<()> {
+        if shell && all {
+            return Self::remove_all_shell_aliases();
+        }
+        if shell {
+            return Self::remove_shell_alias(name);
+        }
+
         let aliases = AliasManager::list()?;
 
         if aliases.is_empty() {
@@ -118,4 +129,137 @@ impl AliasRemoveCommand {
 
         Ok(())
     }
+
+    /// 删除 shell 别名
+    ///
+    /// 从 shell 配置文件的托管别名配置块中删除别名，配置块外用户自己定义的别名不受影响。
+    fn remove_shell_alias(name: Option<String>) -> Result<()> {
+        let aliases = ShellConfigManager::load_aliases()?;
+
+        if aliases.is_empty() {
+            log_info!("No shell aliases defined");
+            return Ok(());
+        }
+
+        let names_to_remove = if let Some(name) = name {
+            if !aliases.contains_key(&name) {
+                return Err(color_eyre::eyre::eyre!("Shell alias '{}' not found", name));
+            }
+            vec![name]
+        } else {
+            let names: Vec<String> = aliases.keys().cloned().collect();
+            let options: Vec<String> = aliases
+                .iter()
+                .map(|(alias_name, command)| format!("{} = {}", alias_name, command))
+                .collect();
+
+            let selected =
+                MultiSelectDialog::new("Select shell aliases to remove", options.clone())
+                    .prompt()
+                    .wrap_err("Failed to select aliases")?;
+
+            if selected.is_empty() {
+                log_info!("No aliases selected");
+                return Ok(());
+            }
+
+            names
+                .into_iter()
+                .zip(options)
+                .filter(|(_, option)| selected.contains(option))
+                .map(|(alias_name, _)| alias_name)
+                .collect()
+        };
+
+        log_message!("Shell aliases to be removed:");
+        for name in &names_to_remove {
+            if let Some(command) = aliases.get(name) {
+                log_info!("  {} = {}", name, command);
+            }
+        }
+
+        let confirmed = ConfirmDialog::new(format!(
+            "Are you sure you want to remove {} shell alias/aliases?",
+            names_to_remove.len()
+        ))
+        .with_default(false)
+        .prompt()
+        .wrap_err("Failed to get user confirmation")?;
+
+        if !confirmed {
+            log_info!("Operation cancelled");
+            return Ok(());
+        }
+
+        for name in &names_to_remove {
+            match ShellConfigManager::remove_alias(name) {
+                Ok(Some(edit)) => {
+                    log_success!(
+                        "Shell alias '{}' removed from {}",
+                        name,
+                        edit.config_path.display()
+                    );
+                    if let Some(ref backup_path) = edit.backup_path {
+                        log_info!("  Backup of previous shell config: {:?}", backup_path);
+                    }
+                }
+                Ok(None) => {
+                    log_warning!(
+                        "Shell alias '{}' not found (may have been removed already)",
+                        name
+                    );
+                }
+                Err(e) => {
+                    log_warning!("Failed to remove shell alias '{}': {}", name, e);
+                }
+            }
+        }
+        log_info!("Open a new terminal for the change to take effect.");
+
+        Ok(())
+    }
+
+    /// 删除所有 shell 别名
+    ///
+    /// 移除 shell 配置文件中的整个托管别名配置块，配置块外用户自己定义的别名不受影响。
+    fn remove_all_shell_aliases() -> Result<()> {
+        let aliases = ShellConfigManager::load_aliases()?;
+
+        if aliases.is_empty() {
+            log_info!("No shell aliases defined");
+            return Ok(());
+        }
+
+        log_message!("Shell aliases to be removed:");
+        for (name, command) in &aliases {
+            log_info!("  {} = {}", name, command);
+        }
+
+        let confirmed = ConfirmDialog::new(format!(
+            "Are you sure you want to remove all {} shell alias/aliases?",
+            aliases.len()
+        ))
+        .with_default(false)
+        .prompt()
+        .wrap_err("Failed to get user confirmation")?;
+
+        if !confirmed {
+            log_info!("Operation cancelled");
+            return Ok(());
+        }
+
+        if let Some(edit) = ShellConfigManager::remove_all_aliases()? {
+            log_success!(
+                "Removed {} shell alias/aliases from {}",
+                aliases.len(),
+                edit.config_path.display()
+            );
+            if let Some(ref backup_path) = edit.backup_path {
+                log_info!("  Backup of previous shell config: {:?}", backup_path);
+            }
+        }
+        log_info!("Open a new terminal for the change to take effect.");
+
+        Ok(())
+    }
 }
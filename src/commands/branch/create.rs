@@ -12,7 +12,7 @@ use crate::base::dialog::{ConfirmDialog, InputDialog};
 use crate::base::format::MessageFormatter;
 use crate::base::indicator::Spinner;
 use crate::branch::{BranchNaming, BranchType};
-use crate::commands::pr::helpers::handle_stash_pop_result;
+use crate::commands::pr::helpers::{handle_stash_pop_result, jira_ticket_if_configured};
 use crate::git::{GitBranch, GitCommit, GitStash};
 use crate::jira::helpers::validate_jira_ticket_format;
 use crate::jira::Jira;
@@ -114,7 +114,13 @@ impl CreateCommand {
     }
 
     /// Resolve ticket ID (optional, interactive if not provided)
+    ///
+    /// Skipped when Jira is not configured: no prompt, and a given ticket is ignored.
     fn resolve_ticket_id(ticket_id: Option<String>) -> Result<Option<String>> {
+        if !Jira::is_configured() {
+            return Ok(jira_ticket_if_configured(ticket_id));
+        }
+
         let ticket = if let Some(t) = ticket_id {
             let trimmed = t.trim().to_string();
             if trimmed.is_empty() {
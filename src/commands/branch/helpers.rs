@@ -7,6 +7,9 @@ use crate::git::GitBranch;
 use crate::repo::config::RepoConfig;
 use color_eyre::{eyre::WrapErr, Result};
 
+/// Number of branches shown per page in the branch selection dialog
+const BRANCH_SELECT_PAGE_SIZE: usize = 15;
+
 /// Sort branches with priority
 ///
 /// Sorts branches according to the following priority:
@@ -155,6 +158,7 @@ impl BranchSelectionOptions {
 /// - Gets all branches (local + remote, deduplicated)
 /// - Filters branches based on options
 /// - Always uses fuzzy matching (SkimMatcherV2) for search
+/// - Shows `BRANCH_SELECT_PAGE_SIZE` branches per page
 /// - Returns the selected branch name
 ///
 /// # Examples
@@ -223,14 +227,17 @@ pub fn select_branch(options: BranchSelectionOptions) -> Result<String> {
     branch_options =
         sort_branches_with_priority(branch_options).wrap_err("Failed to sort branches")?;
 
+    // Pair each branch with its label
+    let mut items: Vec<(String, String)> =
+        branch_options.into_iter().map(|branch| (branch.clone(), branch)).collect();
+
     // Mark current branch if needed
     if options.mark_current {
         if let Some(ref current) = current_branch {
-            let current_marker = format!("{} [current]", current);
             // Remove current branch from its original position
-            branch_options.retain(|b| b != current);
+            items.retain(|(_, branch)| branch != current);
             // Insert at the top
-            branch_options.insert(0, current_marker);
+            items.insert(0, (format!("{} [current]", current), current.clone()));
         }
     }
 
@@ -242,18 +249,14 @@ pub fn select_branch(options: BranchSelectionOptions) -> Result<String> {
     };
 
     // Create dialog (fuzzy matching is enabled by default)
-    let mut dialog = SelectDialog::new(&prompt, branch_options);
+    let mut dialog =
+        SelectDialog::new_with_items(&prompt, items).with_page_size(BRANCH_SELECT_PAGE_SIZE);
 
     // Set default index if provided
     if let Some(default_idx) = options.default_index {
         dialog = dialog.with_default(default_idx);
     }
 
-    // Prompt user
-    let selected = dialog.prompt().wrap_err("Failed to select branch")?;
-
-    // Extract branch name (remove [current] marker if present)
-    let branch_name = selected.strip_suffix(" [current]").unwrap_or(&selected).to_string();
-
-    Ok(branch_name)
+    // Prompt user (returns the branch name, without the [current] marker)
+    dialog.prompt().wrap_err("Failed to select branch")
 }
@@ -4,7 +4,7 @@
 
 use crate::branch::sync::{BranchSync, BranchSyncCallbacks, BranchSyncOptions, BranchSyncResult};
 use crate::commands::check;
-use crate::{log_info, log_success};
+use crate::{log_break, log_info, log_success};
 use color_eyre::Result;
 
 /// 分支同步命令（无 PR 逻辑）
@@ -32,7 +32,14 @@ impl BranchSyncCommand {
     /// * `rebase` - 是否使用 rebase（默认使用 merge）
     /// * `ff_only` - 是否只允许 fast-forward 合并
     /// * `squash` - 是否使用 squash 合并
-    pub fn sync(source_branch: String, rebase: bool, ff_only: bool, squash: bool) -> Result<()> {
+    /// * `dry_run` - 只预览同步计划，不执行同步
+    pub fn sync(
+        source_branch: String,
+        rebase: bool,
+        ff_only: bool,
+        squash: bool,
+        dry_run: bool,
+    ) -> Result<()> {
         // 运行检查
         log_info!("Running pre-flight checks...");
         check::CheckCommand::run_all()?;
@@ -42,6 +49,10 @@ impl BranchSyncCommand {
             rebase,
             ff_only,
             squash,
+            dry_run,
+            message: None,
+            no_push: false,
+            all: false,
         };
 
         // 使用空回调（不需要 PR 处理）
@@ -49,6 +60,12 @@ impl BranchSyncCommand {
 
         BranchSync::sync(options, Some(callbacks))?;
 
+        if dry_run {
+            log_break!();
+            log_info!("Dry run mode: no changes were made");
+            return Ok(());
+        }
+
         log_success!("Branch sync completed successfully!");
         Ok(())
     }
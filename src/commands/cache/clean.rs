@@ -0,0 +1,153 @@
+//! 缓存清理命令
+//!
+//! 清理所有缓存或指定的缓存，支持：
+//! - 只清理修改时间早于指定时长的文件（`--older-than`）
+//! - 预览操作（dry-run）：列出每个缓存将要删除的条目数和可释放的空间，不修改磁盘
+//! - 跳过确认（`--force`）
+//!
+//! 实际删除前会先显示同样的预览，确认后才删除。
+
+use std::time::Duration;
+
+use color_eyre::{eyre::WrapErr, Result};
+use tabled::Tabled;
+
+use crate::base::dialog::ConfirmDialog;
+use crate::base::format::DisplayFormatter;
+use crate::base::table::{TableBuilder, TableStyle};
+use crate::cache::{CacheKind, CacheManager, CachePlan};
+use crate::{log_break, log_info, log_message, log_success, log_warning};
+
+/// 清理预览表格行
+#[derive(Tabled, Clone)]
+struct CacheCleanRow {
+    #[tabled(rename = "Cache")]
+    name: String,
+    #[tabled(rename = "Entries")]
+    entries: String,
+    #[tabled(rename = "Size")]
+    size: String,
+    #[tabled(rename = "Location")]
+    location: String,
+}
+
+/// 缓存清理命令
+pub struct CacheCleanCommand;
+
+impl CacheCleanCommand {
+    /// 清理缓存
+    ///
+    /// # 参数
+    ///
+    /// * `kind` - 要清理的缓存（为 `None` 时清理所有缓存）
+    /// * `older_than` - 只清理修改时间早于该时长的文件（Jira 用户信息没有修改时间，此时不会被清理）
+    /// * `dry_run` - 如果为 true，只预览操作，不实际删除
+    /// * `force` - 如果为 true，跳过确认
+    pub fn clean(
+        kind: Option<CacheKind>,
+        older_than: Option<Duration>,
+        dry_run: bool,
+        force: bool,
+    ) -> Result<()> {
+        let kinds: Vec<CacheKind> = match kind {
+            Some(kind) => vec![kind],
+            None => CacheKind::all().collect(),
+        };
+
+        if older_than.is_some() && kinds.contains(&CacheKind::JiraUsers) {
+            log_warning!(
+                "'{}' has no modification times and is skipped with --older-than",
+                CacheKind::JiraUsers.name()
+            );
+        }
+
+        // 计算将要删除的内容（只读取缓存，不修改磁盘）
+        let plans = kinds
+            .into_iter()
+            .map(|kind| {
+                CacheManager::plan(kind, older_than)
+                    .wrap_err_with(|| format!("Failed to scan cache '{}'", kind.name()))
+            })
+            .collect::<Result<Vec<CachePlan>>>()?;
+
+        let entries: usize = plans.iter().map(|plan| plan.entries).sum();
+        let size: u64 = plans.iter().map(|plan| plan.size).sum();
+        if entries == 0 {
+            log_info!("Nothing to clean.");
+            return Ok(());
+        }
+
+        Self::show_summary(&plans, entries, size);
+
+        if dry_run {
+            log_info!(
+                "[DRY RUN] Preview completed. {} entries ({}) would be deleted.",
+                entries,
+                DisplayFormatter::size(size)
+            );
+            return Ok(());
+        }
+
+        if !force {
+            let confirmed = ConfirmDialog::new(format!(
+                "Are you sure you want to delete {} entries ({})?",
+                entries,
+                DisplayFormatter::size(size)
+            ))
+            .with_default(false)
+            .prompt()?;
+
+            if !confirmed {
+                log_info!("Clean operation was cancelled.");
+                return Ok(());
+            }
+        }
+
+        for plan in plans.iter().filter(|plan| !plan.is_empty()) {
+            CacheManager::remove(plan)
+                .wrap_err_with(|| format!("Failed to clean cache '{}'", plan.kind.name()))?;
+        }
+
+        log_break!();
+        log_success!(
+            "Clean completed successfully! Reclaimed {} ({} entries).",
+            DisplayFormatter::size(size),
+            entries
+        );
+
+        Ok(())
+    }
+
+    /// 显示每个缓存将要删除的条目数和可释放的空间
+    fn show_summary(plans: &[CachePlan], entries: usize, size: u64) {
+        let mut rows: Vec<CacheCleanRow> = plans
+            .iter()
+            .filter(|plan| !plan.is_empty())
+            .map(|plan| CacheCleanRow {
+                name: plan.kind.name().to_string(),
+                entries: plan.entries.to_string(),
+                size: if plan.kind.is_dir() {
+                    DisplayFormatter::size(plan.size)
+                } else {
+                    "-".to_string()
+                },
+                location: plan.path.display().to_string(),
+            })
+            .collect();
+        rows.push(CacheCleanRow {
+            name: "Total".to_string(),
+            entries: entries.to_string(),
+            size: DisplayFormatter::size(size),
+            location: String::new(),
+        });
+
+        log_message!(
+            "{}",
+            TableBuilder::new(rows)
+                .with_title("Clean Summary")
+                .with_style(TableStyle::Modern)
+                .render()
+        );
+        log_info!("Reclaimable space: {}", DisplayFormatter::size(size));
+    }
+}
@@ -0,0 +1,71 @@
+//! 缓存列表命令
+//!
+//! 显示所有缓存的位置、条目数量和大小，使用表格格式。
+
+use color_eyre::Result;
+use tabled::Tabled;
+
+use crate::base::format::DisplayFormatter;
+use crate::base::table::{TableBuilder, TableStyle};
+use crate::cache::{CacheInfo, CacheManager};
+use crate::cli::TableFormat;
+use crate::{log_break, log_info, log_message};
+
+/// 缓存表格行
+#[derive(Tabled, Clone)]
+struct CacheRow {
+    #[tabled(rename = "Cache")]
+    name: String,
+    #[tabled(rename = "Description")]
+    description: String,
+    #[tabled(rename = "Entries")]
+    entries: String,
+    #[tabled(rename = "Size")]
+    size: String,
+    #[tabled(rename = "Location")]
+    location: String,
+}
+
+impl From<&CacheInfo> for CacheRow {
+    fn from(info: &CacheInfo) -> Self {
+        Self {
+            name: info.kind.name().to_string(),
+            description: info.kind.description().to_string(),
+            entries: info.entries.to_string(),
+            size: info.size.map_or_else(|| "-".to_string(), DisplayFormatter::size),
+            location: info.path.display().to_string(),
+        }
+    }
+}
+
+/// 缓存列表命令
+pub struct CacheListCommand;
+
+impl CacheListCommand {
+    /// 列出所有缓存
+    ///
+    /// 使用表格格式显示每个缓存的位置、条目数量和大小；`format` 不是 `table` 时只输出数据。
+    pub fn list(format: TableFormat) -> Result<()> {
+        let caches = CacheManager::list()?;
+        let rows: Vec<CacheRow> = caches.iter().map(CacheRow::from).collect();
+
+        if format.is_table() {
+            log_break!();
+        }
+        log_message!(
+            "{}",
+            format.render(
+                TableBuilder::new(rows).with_title("Caches").with_style(TableStyle::Modern)
+            )
+        );
+        if !format.is_table() {
+            return Ok(());
+        }
+
+        let total: u64 = caches.iter().filter_map(|info| info.size).sum();
+        log_info!("Total size: {}", DisplayFormatter::size(total));
+        log_message!("Run 'workflow cache clean [CACHE] [--older-than AGE]' to free up space.");
+
+        Ok(())
+    }
+}
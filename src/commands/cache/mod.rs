@@ -0,0 +1,9 @@
+//! 缓存管理命令
+//!
+//! 提供缓存的列表和清理功能。
+
+pub mod clean;
+pub mod list;
+
+pub use clean::CacheCleanCommand;
+pub use list::CacheListCommand;
@@ -0,0 +1,443 @@
+//! API 连通性检查
+//!
+//! 使用当前配置的凭据对 Jira、GitHub 和 LLM 各发起一次轻量的认证请求：
+//! - Jira：`GET /rest/api/2/myself`（通过 `JiraClient`，成功时给出当前用户的显示名称）
+//! - GitHub：`GET /user`
+//! - LLM：`GET /models`
+//!
+//! 诊断输出中出现的 token 都会被隐藏。
+
+use crate::base::http::client::HttpClient;
+use crate::base::http::{
+    Authorization, HttpRequestError, HttpRequestErrorKind, HttpStatusError, RequestConfig,
+};
+use crate::base::settings::{JiraAuthType, Settings};
+use crate::base::util::string::Sensitive;
+use crate::jira::JiraClient;
+use color_eyre::Result;
+use reqwest::header::{HeaderMap, HeaderValue, ACCEPT, USER_AGENT};
+use serde_json::Value;
+use std::time::Duration;
+
+/// API 连通性检查的超时时间
+const API_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 要检查的服务
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiService {
+    Jira,
+    GitHub,
+    Llm,
+}
+
+impl ApiService {
+    /// 服务名称（用于输出）
+    pub fn name(&self) -> &'static str {
+        match self {
+            ApiService::Jira => "Jira",
+            ApiService::GitHub => "GitHub",
+            ApiService::Llm => "LLM",
+        }
+    }
+}
+
+/// 跳过的 API 连通性检查
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ApiCheckOptions {
+    pub skip_jira: bool,
+    pub skip_github: bool,
+    pub skip_llm: bool,
+}
+
+impl ApiCheckOptions {
+    /// 是否跳过指定服务
+    pub fn skips(&self, service: ApiService) -> bool {
+        match service {
+            ApiService::Jira => self.skip_jira,
+            ApiService::GitHub => self.skip_github,
+            ApiService::Llm => self.skip_llm,
+        }
+    }
+
+    /// 需要检查的服务
+    pub fn services(&self) -> Vec<ApiService> {
+        [ApiService::Jira, ApiService::GitHub, ApiService::Llm]
+            .into_iter()
+            .filter(|service| !self.skips(*service))
+            .collect()
+    }
+}
+
+/// API 连通性检查状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiCheckState {
+    /// 请求成功，凭据有效（2xx）
+    Authenticated,
+    /// 服务可达，但凭据被拒绝（401/403）
+    Reachable,
+    /// 请求失败（网络错误或其他状态码）
+    Failed,
+    /// 未配置凭据，跳过检查
+    NotConfigured,
+    /// 可选服务完全未配置（如只使用 GitHub 时的 Jira），跳过检查
+    Optional,
+}
+
+impl ApiCheckState {
+    /// 根据 HTTP 状态码判断检查状态
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            200..=299 => ApiCheckState::Authenticated,
+            401 | 403 => ApiCheckState::Reachable,
+            _ => ApiCheckState::Failed,
+        }
+    }
+
+    /// 状态说明
+    pub fn label(&self) -> &'static str {
+        match self {
+            ApiCheckState::Authenticated => "authenticated",
+            ApiCheckState::Reachable => "reachable, authentication failed",
+            ApiCheckState::Failed => "failed",
+            ApiCheckState::NotConfigured => "not configured, skipped",
+            ApiCheckState::Optional => "not configured (optional)",
+        }
+    }
+
+    /// 是否算作检查失败（未配置和可选服务未配置不算失败）
+    pub fn is_failure(&self) -> bool {
+        matches!(self, ApiCheckState::Reachable | ApiCheckState::Failed)
+    }
+}
+
+/// 单个服务的 API 连通性检查结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiCheckResult {
+    pub service: ApiService,
+    pub state: ApiCheckState,
+    /// HTTP 状态码（请求未完成时为 `None`）
+    pub status: Option<u16>,
+    /// 失败原因（已隐藏 token）
+    pub detail: Option<String>,
+}
+
+impl ApiCheckResult {
+    fn not_configured(service: ApiService, missing: &str) -> Self {
+        Self {
+            service,
+            state: ApiCheckState::NotConfigured,
+            status: None,
+            detail: Some(format!("{} is not configured", missing)),
+        }
+    }
+
+    fn optional(service: ApiService) -> Self {
+        Self {
+            service,
+            state: ApiCheckState::Optional,
+            status: None,
+            detail: None,
+        }
+    }
+
+    /// 格式化为单行输出（如 `GitHub: authenticated (HTTP 200)`）
+    pub fn summary(&self) -> String {
+        let mut line = format!("{}: {}", self.service.name(), self.state.label());
+        if let Some(status) = self.status {
+            line.push_str(&format!(" (HTTP {})", status));
+        }
+        if let Some(ref detail) = self.detail {
+            line.push_str(&format!(" - {}", detail));
+        }
+        line
+    }
+}
+
+/// 认证方式
+#[derive(Clone, PartialEq, Eq)]
+pub enum ProbeAuth {
+    /// Basic 认证（Jira：邮箱 + API token）
+    Basic { username: String, token: String },
+    /// Bearer token（GitHub、LLM，以及使用 PAT/OAuth 的 Jira）
+    Bearer(String),
+}
+
+/// Debug 输出中隐藏 token
+impl std::fmt::Debug for ProbeAuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ProbeAuth::Basic { username, token } => f
+                .debug_struct("Basic")
+                .field("username", username)
+                .field("token", &token.mask())
+                .finish(),
+            ProbeAuth::Bearer(token) => f.debug_tuple("Bearer").field(&token.mask()).finish(),
+        }
+    }
+}
+
+impl ProbeAuth {
+    fn token(&self) -> &str {
+        match self {
+            ProbeAuth::Basic { token, .. } | ProbeAuth::Bearer(token) => token,
+        }
+    }
+}
+
+/// 一次 API 连通性检查请求
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiProbe {
+    pub service: ApiService,
+    pub url: String,
+    pub auth: ProbeAuth,
+}
+
+impl ApiProbe {
+    /// 构建 Jira 检查请求（`GET /rest/api/2/myself`）
+    pub fn jira(service_address: &str, email: &str, api_token: &str) -> Self {
+        Self {
+            service: ApiService::Jira,
+            url: format!(
+                "{}/rest/api/2/myself",
+                service_address.trim().trim_end_matches('/')
+            ),
+            auth: ProbeAuth::Basic {
+                username: email.trim().to_string(),
+                token: api_token.trim().to_string(),
+            },
+        }
+    }
+
+    /// 构建使用 Bearer token 的 Jira 检查请求（`jira.auth_type = "bearer"`）
+    pub fn jira_bearer(service_address: &str, token: &str) -> Self {
+        Self {
+            service: ApiService::Jira,
+            url: format!(
+                "{}/rest/api/2/myself",
+                service_address.trim().trim_end_matches('/')
+            ),
+            auth: ProbeAuth::Bearer(token.trim().to_string()),
+        }
+    }
+
+    /// 根据配置构建检查请求
+    ///
+    /// 缺少必要配置时返回 `Err(缺少的配置项)`。
+    pub fn from_settings(
+        service: ApiService,
+        settings: &Settings,
+    ) -> std::result::Result<Self, &'static str> {
+        match service {
+            ApiService::Jira => {
+                let address = non_empty(settings.jira.service_address.as_deref())
+                    .ok_or("jira.service_address")?;
+                let token =
+                    non_empty(settings.jira.api_token.as_deref()).ok_or("jira.api_token")?;
+                match settings.jira.auth_type() {
+                    JiraAuthType::Basic => {
+                        let username =
+                            non_empty(settings.jira.email.as_deref()).ok_or("jira.email")?;
+                        Ok(Self::jira(address, username, token))
+                    }
+                    JiraAuthType::Bearer => Ok(Self::jira_bearer(address, token)),
+                }
+            }
+            ApiService::GitHub => {
+                let token = non_empty(settings.github.get_current_token())
+                    .ok_or("github.accounts[].api_token")?;
+                Ok(Self {
+                    service,
+                    url: format!("{}/user", settings.github.api_base_url()),
+                    auth: ProbeAuth::Bearer(token.to_string()),
+                })
+            }
+            ApiService::Llm => {
+                let current = settings.llm.current_provider();
+                let token = non_empty(current.key.as_deref()).ok_or("llm.key")?;
+                let url = match settings.llm.provider.as_str() {
+                    "deepseek" => "https://api.deepseek.com/models".to_string(),
+                    "proxy" => format!(
+                        "{}/models",
+                        non_empty(current.url.as_deref()).ok_or("llm.url")?.trim_end_matches('/')
+                    ),
+                    _ => "https://api.openai.com/v1/models".to_string(),
+                };
+                Ok(Self {
+                    service,
+                    url,
+                    auth: ProbeAuth::Bearer(token.to_string()),
+                })
+            }
+        }
+    }
+
+    /// 发送请求并判断检查状态
+    pub fn run(&self) -> ApiCheckResult {
+        match self.send() {
+            Ok(status) => {
+                let state = ApiCheckState::from_status(status);
+                ApiCheckResult {
+                    service: self.service,
+                    state,
+                    status: Some(status),
+                    detail: None,
+                }
+            }
+            Err(e) => ApiCheckResult {
+                service: self.service,
+                state: ApiCheckState::Failed,
+                status: None,
+                detail: Some(redact_secret(&e.to_string(), self.auth.token())),
+            },
+        }
+    }
+
+    /// 发送请求，返回 HTTP 状态码
+    fn send(&self) -> Result<u16> {
+        let client = HttpClient::global()?;
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT, HeaderValue::from_static("application/json"));
+        headers.insert(USER_AGENT, HeaderValue::from_static("workflow-cli"));
+
+        let auth = match self.auth {
+            ProbeAuth::Basic {
+                ref username,
+                ref token,
+            } => Authorization::new(username, token),
+            ProbeAuth::Bearer(ref token) => Authorization::bearer(token),
+        };
+        let config = RequestConfig::<Value, Value>::new()
+            .auth(&auth)
+            .headers(&headers)
+            .timeout(API_TIMEOUT);
+        let response = client.get(&self.url, config)?;
+        Ok(response.status)
+    }
+}
+
+/// 隐藏文本中出现的 token
+///
+/// # 示例
+///
+/// ```
+/// use workflow::commands::check::api::redact_secret;
+///
+/// assert_eq!(
+///     redact_secret("invalid token ghp_1234567890abcdef", "ghp_1234567890abcdef"),
+///     "invalid token ghp_***cdef"
+/// );
+/// ```
+pub fn redact_secret(text: &str, secret: &str) -> String {
+    if secret.is_empty() {
+        return text.to_string();
+    }
+    text.replace(secret, &secret.mask())
+}
+
+/// Jira 检查失败时的提示
+///
+/// 区分 token 无效或过期（401）、地址错误（404、无法连接）和网络问题（超时），
+/// 其他情况返回 `None`。
+///
+/// # 示例
+///
+/// ```
+/// use workflow::base::http::HttpRequestErrorKind;
+/// use workflow::commands::check::api::jira_failure_hint;
+///
+/// assert!(jira_failure_hint(Some(401), None).unwrap().contains("expired"));
+/// assert!(jira_failure_hint(None, Some(HttpRequestErrorKind::Connect))
+///     .unwrap()
+///     .contains("jira.service_address"));
+/// assert_eq!(jira_failure_hint(Some(500), None), None);
+/// ```
+pub fn jira_failure_hint(
+    status: Option<u16>,
+    error_kind: Option<HttpRequestErrorKind>,
+) -> Option<&'static str> {
+    match (status, error_kind) {
+        (Some(401), _) => {
+            Some("API token is invalid or expired, update jira.api_token with 'workflow setup'")
+        }
+        (Some(403), _) => Some(
+            "credentials were rejected, check jira.email, jira.auth_type and the account's access",
+        ),
+        (Some(404), _) => Some("Jira REST API not found, check jira.service_address"),
+        (None, Some(HttpRequestErrorKind::Connect)) => {
+            Some("check jira.service_address (DNS) and the network or proxy settings")
+        }
+        (None, Some(HttpRequestErrorKind::Timeout)) => {
+            Some("Jira did not respond in time, the network might be slow or unstable")
+        }
+        _ => None,
+    }
+}
+
+/// 通过 `JiraClient` 检查 Jira 的服务地址和认证信息
+///
+/// 成功时在结果中给出当前用户的显示名称；失败时通过 `jira_failure_hint` 给出提示。
+/// Jira 是可选的：完全没有配置 Jira 时返回 `Optional`，只配置了部分认证信息时返回 `NotConfigured`。
+pub fn check_jira(settings: &Settings) -> ApiCheckResult {
+    if !settings.jira.has_credentials() {
+        return ApiCheckResult::optional(ApiService::Jira);
+    }
+    if let Err(missing) = ApiProbe::from_settings(ApiService::Jira, settings) {
+        return ApiCheckResult::not_configured(ApiService::Jira, missing);
+    }
+
+    let error = match JiraClient::get_current_user() {
+        Ok(user) => {
+            return ApiCheckResult {
+                service: ApiService::Jira,
+                state: ApiCheckState::Authenticated,
+                status: Some(200),
+                detail: Some(format!("signed in as {}", user.display_name)),
+            }
+        }
+        Err(e) => e,
+    };
+
+    let token = settings.jira.api_token.as_deref().unwrap_or_default();
+    let status = error.downcast_ref::<HttpStatusError>().map(|e| e.status);
+    let request_error = error.downcast_ref::<HttpRequestError>();
+    let hint = jira_failure_hint(status, request_error.map(|e| e.kind));
+    let detail = match (request_error, hint) {
+        (Some(e), Some(hint)) => format!("{}: {}", e, hint),
+        (None, Some(hint)) => hint.to_string(),
+        (_, None) => format!("{:#}", error),
+    };
+
+    ApiCheckResult {
+        service: ApiService::Jira,
+        state: status.map_or(ApiCheckState::Failed, ApiCheckState::from_status),
+        status,
+        detail: Some(redact_secret(&detail, token)),
+    }
+}
+
+/// 检查单个服务的 API 连通性
+///
+/// Jira 通过 `check_jira` 检查，其他服务通过 `ApiProbe` 检查。
+pub fn check_api(service: ApiService, settings: &Settings) -> ApiCheckResult {
+    if service == ApiService::Jira {
+        return check_jira(settings);
+    }
+    match ApiProbe::from_settings(service, settings) {
+        Ok(probe) => probe.run(),
+        Err(missing) => ApiCheckResult::not_configured(service, missing),
+    }
+}
+
+/// 检查所有未跳过的服务的 API 连通性
+pub fn check_apis(options: &ApiCheckOptions, settings: &Settings) -> Vec<ApiCheckResult> {
+    options
+        .services()
+        .into_iter()
+        .map(|service| check_api(service, settings))
+        .collect()
+}
+
+/// 配置值为空或只有空白字符时视为未配置
+pub(super) fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.filter(|v| !v.trim().is_empty())
+}
@@ -1,13 +1,19 @@
-use crate::base::constants::{errors::http_client, git::check_errors, messages::log};
-use crate::base::http::client::HttpClient;
-use crate::base::http::{HttpMethod, RequestConfig};
-use crate::git::{GitCommit, GitRepo};
-use crate::{log_break, log_error, log_info, log_message, log_success};
+use crate::base::constants::{git::check_errors, messages::log};
+use crate::base::settings::Settings;
+use crate::git::{GitCommit, GitConfig, GitRepo, GitSigningFormat};
+use crate::{log_break, log_error, log_info, log_message, log_success, log_warning};
 use color_eyre::{eyre::WrapErr, Result};
 use duct::cmd;
-use serde_json::Value;
 use std::time::Duration;
 
+use super::api::{check_apis, ApiCheckOptions, ApiCheckState};
+use super::fix::{detect_fixes, run_fixes};
+use super::llm::{check_llm_health, LlmHealthState};
+use super::network::{probe_targets, render_network_table, NetworkProbeResult};
+
+/// 网络检查每个目标的超时时间
+const NETWORK_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// 环境检查命令
 #[allow(dead_code)]
 pub struct CheckCommand;
@@ -16,17 +22,58 @@ pub struct CheckCommand;
 impl CheckCommand {
     /// 执行综合环境检查
     ///
-    /// 检查 Git 仓库状态和到 GitHub 的网络连接。
+    /// 检查 Git 仓库状态、网络检查目标的连通性和配置文件。
+    /// 作为其他命令的前置检查使用，不检查 API 连通性。
     pub fn run_all() -> Result<()> {
+        Self::run_checks(None)
+    }
+
+    /// 执行 `workflow check` 命令
+    ///
+    /// 在综合环境检查之外，使用配置的凭据检查 Jira、GitHub 和 LLM 的 API 连通性，
+    /// 并检查 LLM 是否能正常生成内容（可用 `--skip-llm` 跳过）。
+    pub fn run(api_options: &ApiCheckOptions) -> Result<()> {
+        Self::run_checks(Some(api_options))
+    }
+
+    /// 检测并修复常见问题（`workflow check --fix`）
+    ///
+    /// 每个修复都需要单独确认；存在修复失败时返回错误。
+    pub fn fix() -> Result<()> {
+        log_message!("Detecting fixable problems...");
+        log_break!();
+
+        let fixes = detect_fixes();
+        if fixes.is_empty() {
+            log_success!("No fixable problems found");
+            return Ok(());
+        }
+
+        let report = run_fixes(&fixes)?;
+        if report.failed > 0 {
+            color_eyre::eyre::bail!("{} fix(es) failed", report.failed);
+        }
+        Ok(())
+    }
+
+    /// 依次执行各项检查，`api_options` 为 `None` 时不检查 API 连通性和 LLM 健康状态
+    fn run_checks(api_options: Option<&ApiCheckOptions>) -> Result<()> {
+        let check_llm = api_options.is_some_and(|options| !options.skip_llm);
+        let total = match api_options {
+            Some(_) if check_llm => 5,
+            Some(_) => 4,
+            None => 3,
+        };
         log_message!("Running environment checks...");
         log_break!();
 
         // 1. 检查 Git 状态
-        log_message!("[1/2] Checking Git repository status...");
+        log_message!("[1/{}] Checking Git repository status...", total);
         if !GitRepo::is_git_repo() {
             log_error!("Not in a Git repository");
             color_eyre::eyre::bail!("{}", check_errors::NOT_GIT_REPO);
         }
+        Self::check_git_identity()?;
 
         let git_output = GitCommit::status().wrap_err("Failed to check git status")?;
         if git_output.trim().is_empty() {
@@ -38,32 +85,226 @@ impl CheckCommand {
         log_break!();
 
         // 2. 检查网络连接
-        log_message!("[2/2] Checking network connection to GitHub...");
-        let client = HttpClient::global().wrap_err(http_client::CREATE_CLIENT_FAILED)?;
-        let config = RequestConfig::<Value, Value>::new().timeout(Duration::from_secs(10));
-        match client.stream(HttpMethod::Get, crate::git::github::BASE, config) {
-            Ok(response) => {
-                if response.status().is_success() {
-                    log_success!("GitHub network is available");
-                } else {
-                    log_error!(
-                        "GitHub network check failed (status: {})",
-                        response.status()
-                    );
-                    color_eyre::eyre::bail!("Network check failed");
+        log_message!("[2/{}] Checking network connectivity...", total);
+        Self::check_network()?;
+
+        log_break!();
+
+        // 3. 检查配置
+        log_message!("[3/{}] Checking configuration...", total);
+        Self::check_config()?;
+        Self::check_commit_signing()?;
+
+        // 4. 检查 API 连通性
+        if let Some(api_options) = api_options {
+            log_break!();
+            log_message!("[4/{}] Checking API connectivity...", total);
+            Self::check_api_connectivity(api_options)?;
+        }
+
+        // 5. 检查 LLM 健康状态
+        if check_llm {
+            log_break!();
+            log_message!("[5/{}] Checking LLM health...", total);
+            Self::check_llm_health()?;
+        }
+
+        log_break!();
+        log_success!("All checks passed");
+        Ok(())
+    }
+
+    /// 检查网络检查目标的可达性和延迟
+    ///
+    /// 目标来自 `Settings::network_targets`（`check.network_targets`，未配置时根据已配置的服务生成）。
+    /// 任意目标不可达时检查失败，并按失败类型（DNS、TLS、超时、连接失败）给出提示。
+    pub fn check_network() -> Result<()> {
+        let targets = Settings::get().network_targets();
+        let results = probe_targets(&targets, NETWORK_TIMEOUT);
+        log_message!("{}", render_network_table(&results));
+
+        let failed: Vec<&NetworkProbeResult> =
+            results.iter().filter(|result| !result.is_reachable()).collect();
+        if failed.is_empty() {
+            log_success!("All {} network target(s) are reachable", results.len());
+            return Ok(());
+        }
+
+        for result in &failed {
+            log_error!(
+                "{} ({}): {}",
+                result.target.name,
+                result.target.url,
+                result.state.label()
+            );
+            if let Some(hint) = result.state.hint() {
+                log_error!("  {}", hint);
+            }
+        }
+        let names: Vec<&str> = failed.iter().map(|result| result.target.name.as_str()).collect();
+        color_eyre::eyre::bail!("Network check failed: {}", names.join(", "))
+    }
+
+    /// 检查 Git 用户身份（`user.name` 和 `user.email`）是否已配置
+    ///
+    /// 读取当前生效的值（包含仓库和全局配置），缺少时无法创建提交。
+    pub fn check_git_identity() -> Result<()> {
+        let identity = GitConfig::get_effective_user();
+        let missing: Vec<&str> = [
+            ("user.name", &identity.name),
+            ("user.email", &identity.email),
+        ]
+        .into_iter()
+        .filter(|(_, value)| value.is_none())
+        .map(|(key, _)| key)
+        .collect();
+        if !missing.is_empty() {
+            for key in &missing {
+                log_error!("Git {} is not set", key);
+            }
+            log_error!("  Run 'workflow config git set <key> <value>' to set it, e.g.:");
+            log_error!("  workflow config git set user.email you@example.com");
+            color_eyre::eyre::bail!("Git identity is not configured: {}", missing.join(", "));
+        }
+
+        log_success!("Git identity: {}", identity);
+        Ok(())
+    }
+
+    /// 校验当前生效的配置
+    ///
+    /// 警告只输出提示；存在错误时检查失败。
+    pub fn check_config() -> Result<()> {
+        let issues = Settings::validate_active();
+        if issues.is_empty() {
+            log_success!("Configuration is valid");
+            return Ok(());
+        }
+
+        for issue in &issues {
+            if issue.is_error() {
+                log_error!("{}: {}", issue.field, issue.message);
+            } else {
+                log_warning!("{}: {}", issue.field, issue.message);
+            }
+        }
+
+        let error_count = issues.iter().filter(|issue| issue.is_error()).count();
+        if error_count > 0 {
+            color_eyre::eyre::bail!("Configuration check failed with {} error(s)", error_count);
+        }
+        Ok(())
+    }
+
+    /// 启用提交签名（`[commit] sign = true`）时，检查 Git 中是否配置了签名密钥
+    ///
+    /// 未启用签名时跳过；SSH 签名需要 `user.signingkey`，GPG/X.509 签名在未设置
+    /// `user.signingkey` 时需要 gpg/gpgsm 中存在私钥。
+    pub fn check_commit_signing() -> Result<()> {
+        if !Settings::get().commit.is_signing_enabled() {
+            return Ok(());
+        }
+
+        let signing = GitConfig::get_signing_config();
+        let has_default_key = signing.key.is_none() && Self::has_secret_key(signing.format);
+        if let Some(issue) = signing.issue(has_default_key) {
+            log_error!("Commit signing is enabled, but {}", issue);
+            color_eyre::eyre::bail!("Commit signing check failed");
+        }
+
+        match signing.key {
+            Some(ref key) => {
+                log_success!(
+                    "Commit signing is enabled ({}, key: {})",
+                    signing.format.as_str(),
+                    key
+                );
+            }
+            None => {
+                log_success!(
+                    "Commit signing is enabled ({}, default key for the committer email)",
+                    signing.format.as_str()
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// gpg（GPG）或 gpgsm（X.509）中是否存在私钥
+    fn has_secret_key(format: GitSigningFormat) -> bool {
+        let program = match format {
+            GitSigningFormat::Gpg => "gpg",
+            GitSigningFormat::X509 => "gpgsm",
+            GitSigningFormat::Ssh => return false,
+        };
+        cmd(program, &["--list-secret-keys", "--with-colons"])
+            .stderr_null()
+            .read()
+            .map(|output| {
+                output.lines().any(|line| line.starts_with("sec:") || line.starts_with("crs:"))
+            })
+            .unwrap_or(false)
+    }
+
+    /// 检查 Jira、GitHub 和 LLM 的 API 连通性
+    ///
+    /// 未配置凭据的服务只输出提示；凭据被拒绝或请求失败时检查失败。
+    pub fn check_api_connectivity(api_options: &ApiCheckOptions) -> Result<()> {
+        let services = api_options.services();
+        if services.is_empty() {
+            log_info!("All API connectivity checks skipped");
+            return Ok(());
+        }
+
+        let results = check_apis(api_options, Settings::get());
+        for result in &results {
+            match result.state {
+                ApiCheckState::Authenticated => {
+                    log_success!("{}", result.summary());
+                }
+                ApiCheckState::NotConfigured => {
+                    log_warning!("{}", result.summary());
+                }
+                ApiCheckState::Optional => {
+                    log_info!("{}", result.summary());
+                }
+                ApiCheckState::Reachable | ApiCheckState::Failed => {
+                    log_error!("{}", result.summary());
                 }
             }
-            Err(e) => {
-                log_error!("Failed to check network connection: {}", e);
-                log_error!(
-                "  This might be due to network issues, proxy settings, or firewall restrictions"
-            );
-                color_eyre::eyre::bail!("Network check failed: {}", e);
+        }
+
+        let failed: Vec<&str> = results
+            .iter()
+            .filter(|result| result.state.is_failure())
+            .map(|result| result.service.name())
+            .collect();
+        if !failed.is_empty() {
+            color_eyre::eyre::bail!("API connectivity check failed: {}", failed.join(", "));
+        }
+        Ok(())
+    }
+
+    /// 通过 LLMClient 发送一个极短的提示词，检查配置的 LLM 是否可用
+    ///
+    /// 未配置 LLM 时只输出提示；请求失败、响应无法解析或 Ollama 模型未拉取时检查失败。
+    pub fn check_llm_health() -> Result<()> {
+        let result = check_llm_health(Settings::get());
+        match result.state {
+            LlmHealthState::Healthy => {
+                log_success!("{}", result.summary());
+            }
+            LlmHealthState::NotConfigured => {
+                log_warning!("{}", result.summary());
+            }
+            LlmHealthState::ModelMissing | LlmHealthState::Failed => {
+                log_error!("{}", result.summary());
             }
         }
 
-        log_break!();
-        log_success!("All checks passed");
+        if result.state.is_failure() {
+            color_eyre::eyre::bail!("LLM health check failed");
+        }
         Ok(())
     }
 
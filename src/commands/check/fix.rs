@@ -0,0 +1,199 @@
+//! 常见问题的自动修复（`workflow check --fix`）
+//!
+//! 检测可以自动修复的问题，逐个确认后修复：
+//! - 缺失的配置目录：重新创建
+//! - 缺失的 completion 脚本：使用 `CompletionGenerator` 重新生成
+//! - 缺失的必填配置项：重新运行 `setup`
+//! - Shell 配置：重新生成 completion 后重新加载
+
+use crate::base::dialog::ConfirmDialog;
+use crate::base::settings::paths::Paths;
+use crate::base::settings::{ConfigIssue, Settings};
+use crate::base::shell::{Detect, Reload, Shell};
+use crate::commands::config::setup::SetupCommand;
+use crate::completion::{Completion, CompletionGenerator};
+use crate::{log_break, log_error, log_info, log_message, log_success, log_warning};
+use color_eyre::{eyre::WrapErr, Result};
+use std::path::PathBuf;
+
+/// 必填字段缺失时配置校验使用的问题描述前缀
+const MISSING_REQUIRED_FIELD: &str = "Missing required field";
+
+/// 可自动修复的问题
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fix {
+    /// 重新创建缺失的配置目录
+    CreateDirs(Vec<PathBuf>),
+    /// 重新生成缺失的 completion 脚本
+    RegenerateCompletions { shell: Shell, missing: Vec<PathBuf> },
+    /// 重新运行 `setup` 补充缺失的必填配置项
+    RunSetup(Vec<String>),
+    /// 重新加载 shell 配置
+    ReloadShell(Shell),
+}
+
+impl Fix {
+    /// 问题描述
+    pub fn problem(&self) -> String {
+        match self {
+            Fix::CreateDirs(dirs) => format!(
+                "Missing directories: {}",
+                dirs.iter().map(|d| d.display().to_string()).collect::<Vec<_>>().join(", ")
+            ),
+            Fix::RegenerateCompletions { shell, missing } => format!(
+                "Missing {} completion scripts: {}",
+                shell,
+                missing.iter().map(|f| f.display().to_string()).collect::<Vec<_>>().join(", ")
+            ),
+            Fix::RunSetup(fields) => {
+                format!("Missing required config fields: {}", fields.join(", "))
+            }
+            Fix::ReloadShell(shell) => format!("{} configuration needs to be reloaded", shell),
+        }
+    }
+
+    /// 确认修复时的提示
+    pub fn prompt(&self) -> String {
+        match self {
+            Fix::CreateDirs(_) => "Re-create missing directories?".to_string(),
+            Fix::RegenerateCompletions { shell, .. } => {
+                format!("Regenerate {} completion scripts?", shell)
+            }
+            Fix::RunSetup(_) => "Re-run setup to fill in missing fields?".to_string(),
+            Fix::ReloadShell(shell) => format!("Reload {} configuration?", shell),
+        }
+    }
+
+    /// 执行修复
+    fn apply(&self) -> Result<()> {
+        match self {
+            Fix::CreateDirs(_) => {
+                Paths::config_dir()?;
+                Paths::completion_dir()?;
+                Paths::logs_dir()?;
+                Paths::work_history_dir()?;
+                Ok(())
+            }
+            Fix::RegenerateCompletions { shell, .. } => {
+                CompletionGenerator::new(Some(shell.to_string()), None)?
+                    .generate_all()
+                    .wrap_err("Failed to generate completion scripts")?;
+                Ok(())
+            }
+            Fix::RunSetup(_) => SetupCommand::run(),
+            Fix::ReloadShell(shell) => {
+                let result = Reload::shell(shell)?;
+                if !result.reloaded {
+                    color_eyre::eyre::bail!(
+                        "{} (run manually: {})",
+                        result.messages.join("; "),
+                        result.reload_hint
+                    );
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// 自动修复结果统计
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FixReport {
+    /// 修复成功的问题数
+    pub fixed: usize,
+    /// 修复失败的问题数
+    pub failed: usize,
+    /// 用户跳过的问题数
+    pub skipped: usize,
+}
+
+/// 筛选出不存在的目录
+pub fn missing_dirs(dirs: &[PathBuf]) -> Vec<PathBuf> {
+    dirs.iter().filter(|dir| !dir.is_dir()).cloned().collect()
+}
+
+/// 从配置问题中提取缺失的必填字段
+pub fn missing_required_fields(issues: &[ConfigIssue]) -> Vec<String> {
+    issues
+        .iter()
+        .filter(|issue| issue.is_error() && issue.message.starts_with(MISSING_REQUIRED_FIELD))
+        .map(|issue| issue.field.clone())
+        .collect()
+}
+
+/// 检测可自动修复的问题
+///
+/// 只在 shell 配置中已启用 completion 时检查 completion 脚本是否缺失；
+/// 需要重新生成 completion 时，同时提供重新加载 shell 配置的修复。
+pub fn detect_fixes() -> Vec<Fix> {
+    let mut fixes = Vec::new();
+
+    let dirs = missing_dirs(&Paths::workflow_dirs().unwrap_or_default());
+    if !dirs.is_empty() {
+        fixes.push(Fix::CreateDirs(dirs));
+    }
+
+    if let Ok(shell) = Detect::shell() {
+        let configured = Completion::is_shell_configured(&shell)
+            .map(|(configured, _)| configured)
+            .unwrap_or(false);
+        if configured {
+            let missing: Vec<PathBuf> = Completion::get_completion_files(&shell)
+                .into_iter()
+                .filter(|file| !file.exists())
+                .collect();
+            if !missing.is_empty() {
+                fixes.push(Fix::RegenerateCompletions { shell, missing });
+                fixes.push(Fix::ReloadShell(shell));
+            }
+        }
+    }
+
+    let fields = missing_required_fields(&Settings::validate_active());
+    if !fields.is_empty() {
+        fixes.push(Fix::RunSetup(fields));
+    }
+
+    fixes
+}
+
+/// 逐个确认并执行修复
+///
+/// 单个修复失败不会中断后续修复，失败原因会输出到日志。
+pub fn run_fixes(fixes: &[Fix]) -> Result<FixReport> {
+    let mut report = FixReport::default();
+
+    for fix in fixes {
+        log_warning!("{}", fix.problem());
+        let confirmed = ConfirmDialog::new(fix.prompt())
+            .with_default(true)
+            .prompt()
+            .wrap_err("Failed to confirm fix")?;
+        if !confirmed {
+            log_info!("Skipped");
+            report.skipped += 1;
+            log_break!();
+            continue;
+        }
+
+        match fix.apply() {
+            Ok(()) => {
+                log_success!("Fixed");
+                report.fixed += 1;
+            }
+            Err(e) => {
+                log_error!("Fix failed: {}", e);
+                report.failed += 1;
+            }
+        }
+        log_break!();
+    }
+
+    log_message!(
+        "Fixed: {}, failed: {}, skipped: {}",
+        report.fixed,
+        report.failed,
+        report.skipped
+    );
+    Ok(report)
+}
@@ -0,0 +1,247 @@
+//! LLM 健康检查
+//!
+//! API 连通性检查只验证 LLM 凭据（`GET /models`），不能确认配置的模型真的可用。
+//! 健康检查通过 `LLMClient` 发送一个极短的提示词，确认请求返回 200 且响应可以解析，
+//! 并输出 provider、模型和往返耗时。
+//!
+//! `llm.provider = "proxy"` 且地址指向 Ollama（端口 11434 或地址中包含 `ollama`）时，
+//! 先通过 `GET /api/tags` 确认模型已经拉取（`ollama pull <model>`）。
+
+use crate::base::http::client::HttpClient;
+use crate::base::http::RequestConfig;
+use crate::base::llm::{LLMClient, LLMRequestParams};
+use crate::base::settings::Settings;
+use color_eyre::Result;
+use serde::Deserialize;
+use serde_json::Value;
+use std::time::{Duration, Instant};
+
+use super::api::{non_empty, redact_secret};
+use super::watch::format_latency;
+
+/// 健康检查发送的提示词
+pub const HEALTH_CHECK_PROMPT: &str = "Reply with the single word OK.";
+
+/// 健康检查的最大输出 token 数
+const HEALTH_CHECK_MAX_TOKENS: u32 = 5;
+
+/// 查询 Ollama 模型列表的超时时间
+const OLLAMA_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Ollama 的默认端口
+const OLLAMA_PORT: &str = ":11434";
+
+/// LLM 健康检查状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LlmHealthState {
+    /// 请求成功，响应可以解析
+    Healthy,
+    /// Ollama 中没有拉取配置的模型
+    ModelMissing,
+    /// 请求失败或响应无法解析
+    Failed,
+    /// 未配置 LLM，跳过检查
+    NotConfigured,
+}
+
+impl LlmHealthState {
+    /// 状态说明
+    pub fn label(&self) -> &'static str {
+        match self {
+            LlmHealthState::Healthy => "healthy",
+            LlmHealthState::ModelMissing => "model not pulled",
+            LlmHealthState::Failed => "failed",
+            LlmHealthState::NotConfigured => "not configured, skipped",
+        }
+    }
+
+    /// 是否算作检查失败（未配置不算失败）
+    pub fn is_failure(&self) -> bool {
+        matches!(self, LlmHealthState::ModelMissing | LlmHealthState::Failed)
+    }
+}
+
+/// LLM 健康检查结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LlmHealthResult {
+    /// 当前 provider（openai、deepseek、proxy）
+    pub provider: String,
+    /// 使用的模型（未配置时为 `None`）
+    pub model: Option<String>,
+    pub state: LlmHealthState,
+    /// 往返耗时（请求未完成时为 `None`）
+    pub latency: Option<Duration>,
+    /// 失败原因或提示（已隐藏 key）
+    pub detail: Option<String>,
+}
+
+impl LlmHealthResult {
+    fn not_configured(provider: &str, missing: &str) -> Self {
+        Self {
+            provider: provider.to_string(),
+            model: None,
+            state: LlmHealthState::NotConfigured,
+            latency: None,
+            detail: Some(format!("{} is not configured", missing)),
+        }
+    }
+
+    /// 格式化为单行输出（如 `LLM (openai, gpt-4o-mini): healthy in 820 ms`）
+    pub fn summary(&self) -> String {
+        let mut line = match self.model {
+            Some(ref model) => {
+                format!("LLM ({}, {}): {}", self.provider, model, self.state.label())
+            }
+            None => format!("LLM ({}): {}", self.provider, self.state.label()),
+        };
+        if let Some(latency) = self.latency {
+            line.push_str(&format!(" in {}", format_latency(latency)));
+        }
+        if let Some(ref detail) = self.detail {
+            line.push_str(&format!(" - {}", detail));
+        }
+        line
+    }
+}
+
+/// Ollama `GET /api/tags` 的响应
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OllamaTags {
+    #[serde(default)]
+    pub models: Vec<OllamaModel>,
+}
+
+/// Ollama 中已拉取的模型
+#[derive(Debug, Clone, Deserialize)]
+pub struct OllamaModel {
+    /// 模型名称（如 `llama3:latest`）
+    pub name: String,
+}
+
+impl OllamaTags {
+    /// 模型是否已拉取
+    ///
+    /// 没有指定 tag 的模型名等同于 `:latest`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::commands::check::llm::OllamaTags;
+    ///
+    /// let tags: OllamaTags =
+    ///     serde_json::from_str(r#"{"models":[{"name":"llama3:latest"},{"name":"qwen2.5:7b"}]}"#)
+    ///         .unwrap();
+    /// assert!(tags.has_model("llama3"));
+    /// assert!(tags.has_model("qwen2.5:7b"));
+    /// assert!(!tags.has_model("qwen2.5"));
+    /// ```
+    pub fn has_model(&self, model: &str) -> bool {
+        let wanted = with_default_tag(model.trim());
+        self.models.iter().any(|m| with_default_tag(&m.name) == wanted)
+    }
+}
+
+fn with_default_tag(model: &str) -> String {
+    if model.contains(':') {
+        model.to_string()
+    } else {
+        format!("{}:latest", model)
+    }
+}
+
+/// 判断 LLM 地址是否指向 Ollama，并返回 Ollama 原生 API 的根地址
+///
+/// 只有 `proxy` provider 可能指向 Ollama；OpenAI 兼容地址末尾的 `/v1` 会被去掉。
+///
+/// # 示例
+///
+/// ```
+/// use workflow::commands::check::llm::ollama_base_url;
+///
+/// assert_eq!(
+///     ollama_base_url("proxy", Some("http://localhost:11434/v1/")),
+///     Some("http://localhost:11434".to_string())
+/// );
+/// assert_eq!(ollama_base_url("proxy", Some("https://llm.example.com/v1")), None);
+/// assert_eq!(ollama_base_url("openai", Some("http://localhost:11434/v1")), None);
+/// ```
+pub fn ollama_base_url(provider: &str, url: Option<&str>) -> Option<String> {
+    if provider != "proxy" {
+        return None;
+    }
+    let url = url?.trim().trim_end_matches('/');
+    if !url.contains(OLLAMA_PORT) && !url.to_lowercase().contains("ollama") {
+        return None;
+    }
+    Some(url.strip_suffix("/v1").unwrap_or(url).to_string())
+}
+
+/// 检查当前配置的 LLM 是否可用
+///
+/// 缺少 key、proxy 地址或 proxy 模型时返回 `NotConfigured`，不发起请求。
+pub fn check_llm_health(settings: &Settings) -> LlmHealthResult {
+    let provider = settings.llm.provider.as_str();
+    let current = settings.llm.current_provider();
+
+    let Some(key) = non_empty(current.key.as_deref()) else {
+        return LlmHealthResult::not_configured(provider, "llm.key");
+    };
+    if provider == "proxy" && non_empty(current.url.as_deref()).is_none() {
+        return LlmHealthResult::not_configured(provider, "llm.url");
+    }
+    let model = settings.llm.model();
+    if model.trim().is_empty() {
+        return LlmHealthResult::not_configured(provider, "llm.model");
+    }
+
+    let mut result = LlmHealthResult {
+        provider: provider.to_string(),
+        model: Some(model.clone()),
+        state: LlmHealthState::Healthy,
+        latency: None,
+        detail: None,
+    };
+
+    if let Some(base_url) = ollama_base_url(provider, current.url.as_deref()) {
+        match fetch_ollama_tags(&base_url) {
+            Ok(tags) if tags.has_model(&model) => {}
+            Ok(_) => {
+                result.state = LlmHealthState::ModelMissing;
+                result.detail = Some(format!("run 'ollama pull {}'", model));
+                return result;
+            }
+            Err(e) => {
+                result.state = LlmHealthState::Failed;
+                result.detail = Some(format!("failed to list Ollama models: {}", e));
+                return result;
+            }
+        }
+    }
+
+    let params = LLMRequestParams {
+        user_prompt: HEALTH_CHECK_PROMPT.to_string(),
+        max_tokens: Some(HEALTH_CHECK_MAX_TOKENS),
+        temperature: 0.0,
+        model,
+        ..Default::default()
+    };
+    let started = Instant::now();
+    match LLMClient::global().call(&params) {
+        Ok(_) => result.latency = Some(started.elapsed()),
+        Err(e) => {
+            result.state = LlmHealthState::Failed;
+            result.detail = Some(redact_secret(&format!("{:#}", e), key));
+        }
+    }
+    result
+}
+
+/// 查询 Ollama 中已拉取的模型（`GET /api/tags`）
+fn fetch_ollama_tags(base_url: &str) -> Result<OllamaTags> {
+    let client = HttpClient::global()?;
+    let config = RequestConfig::<Value, Value>::new().timeout(OLLAMA_TIMEOUT);
+    client
+        .get(&format!("{}/api/tags", base_url), config)?
+        .ensure_success()?
+        .as_json()
+}
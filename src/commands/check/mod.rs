@@ -1,4 +1,9 @@
+pub mod api;
 #[allow(clippy::module_inception)]
 pub mod check;
+pub mod fix;
+pub mod llm;
+pub mod network;
+pub mod watch;
 
 pub use check::CheckCommand;
@@ -0,0 +1,229 @@
+//! 网络检查
+//!
+//! 依次探测 `Settings::network_targets` 中的地址（默认根据已配置的服务生成：GitHub API、
+//! Jira 地址、LLM 地址），记录每个目标的可达性和延迟。
+//!
+//! 请求通过 `HttpClient` 发送，使用环境变量中的代理（`https_proxy`、`http_proxy`、`all_proxy`，
+//! 与 `workflow proxy on` 设置的一致）。收到任何 HTTP 响应（包括 401、404）都视为可达；
+//! 请求失败时区分 DNS 解析失败、TLS 错误、超时和连接失败。
+
+use crate::base::http::client::HttpClient;
+use crate::base::http::{HttpMethod, HttpRequestError, RequestConfig};
+use crate::base::settings::settings::NetworkTarget;
+use crate::base::table::{TableBuilder, TableStyle};
+use crate::proxy::ProxyType;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tabled::Tabled;
+
+use super::watch::format_latency;
+
+/// 网络检查状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NetworkState {
+    /// 收到 HTTP 响应
+    Reachable,
+    /// DNS 解析失败
+    DnsFailure,
+    /// TLS 握手失败或证书无效
+    TlsError,
+    /// 请求超时
+    Timeout,
+    /// 无法建立连接或请求失败
+    Unreachable,
+}
+
+impl NetworkState {
+    /// 状态说明
+    pub fn label(&self) -> &'static str {
+        match self {
+            NetworkState::Reachable => "reachable",
+            NetworkState::DnsFailure => "DNS failure",
+            NetworkState::TlsError => "TLS error",
+            NetworkState::Timeout => "timeout",
+            NetworkState::Unreachable => "unreachable",
+        }
+    }
+
+    /// 失败时的排查提示
+    pub fn hint(&self) -> Option<&'static str> {
+        match self {
+            NetworkState::Reachable => None,
+            NetworkState::DnsFailure => Some("check the host name and your DNS settings"),
+            NetworkState::TlsError => Some(
+                "check the certificate of the server, or whether a proxy intercepts HTTPS traffic",
+            ),
+            NetworkState::Timeout => Some("the network might be slow or unstable"),
+            NetworkState::Unreachable => Some(
+                "check proxy settings, firewall restrictions, or whether the service is running",
+            ),
+        }
+    }
+
+    /// 根据请求错误判断失败类型
+    pub fn from_error(error: &color_eyre::Report) -> Self {
+        match error.downcast_ref::<HttpRequestError>() {
+            Some(error) if error.is_timeout() => NetworkState::Timeout,
+            Some(error) if error.is_dns_failure() => NetworkState::DnsFailure,
+            Some(error) if error.is_tls_failure() => NetworkState::TlsError,
+            _ => NetworkState::Unreachable,
+        }
+    }
+}
+
+/// 单个网络检查目标的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NetworkProbeResult {
+    pub target: NetworkTarget,
+    pub state: NetworkState,
+    /// HTTP 状态码（没有收到响应时为 `None`）
+    pub status: Option<u16>,
+    /// 往返耗时
+    pub latency: Duration,
+    /// 使用的代理（直连时为 `None`）
+    pub proxy: Option<String>,
+    /// 失败原因
+    pub detail: Option<String>,
+}
+
+impl NetworkProbeResult {
+    /// 是否可达
+    pub fn is_reachable(&self) -> bool {
+        self.state == NetworkState::Reachable
+    }
+}
+
+/// 网络检查表格行
+#[derive(Tabled)]
+pub struct NetworkProbeRow {
+    #[tabled(rename = "Target")]
+    pub name: String,
+    #[tabled(rename = "URL")]
+    pub url: String,
+    #[tabled(rename = "Status")]
+    pub status: String,
+    #[tabled(rename = "Latency")]
+    pub latency: String,
+    #[tabled(rename = "Via")]
+    pub via: String,
+}
+
+impl From<&NetworkProbeResult> for NetworkProbeRow {
+    fn from(result: &NetworkProbeResult) -> Self {
+        let status = match (result.state, result.status) {
+            (NetworkState::Reachable, Some(status)) => format!("✓ reachable (HTTP {})", status),
+            (state, _) => format!("✗ {}", state.label()),
+        };
+        Self {
+            name: result.target.name.clone(),
+            url: result.target.url.clone(),
+            status,
+            latency: format_latency(result.latency),
+            via: result.proxy.clone().unwrap_or_else(|| "direct".to_string()),
+        }
+    }
+}
+
+/// 选择请求使用的代理
+///
+/// https 地址依次使用 `https_proxy`、`all_proxy`；http 地址依次使用 `http_proxy`、`all_proxy`。
+/// 环境变量名同时支持小写和大写。
+///
+/// # 示例
+///
+/// ```
+/// use std::collections::HashMap;
+/// use workflow::commands::check::network::proxy_for_url;
+///
+/// let env = HashMap::from([
+///     ("HTTPS_PROXY".to_string(), "http://127.0.0.1:7890".to_string()),
+///     ("all_proxy".to_string(), "socks5://127.0.0.1:7891".to_string()),
+/// ]);
+/// assert_eq!(
+///     proxy_for_url("https://api.github.com", &env).as_deref(),
+///     Some("http://127.0.0.1:7890")
+/// );
+/// assert_eq!(
+///     proxy_for_url("http://localhost:11434", &env).as_deref(),
+///     Some("socks5://127.0.0.1:7891")
+/// );
+/// assert_eq!(proxy_for_url("https://api.github.com", &HashMap::new()), None);
+/// ```
+pub fn proxy_for_url(url: &str, env: &HashMap<String, String>) -> Option<String> {
+    let scheme_proxy = if url.to_lowercase().starts_with("https://") {
+        ProxyType::Https
+    } else {
+        ProxyType::Http
+    };
+    [scheme_proxy, ProxyType::Socks].into_iter().find_map(|proxy_type| {
+        let key = proxy_type.env_key();
+        [key.to_string(), key.to_uppercase()]
+            .iter()
+            .find_map(|key| env.get(key))
+            .filter(|value| !value.trim().is_empty())
+            .cloned()
+    })
+}
+
+/// 读取环境变量中的代理设置（小写和大写）
+pub fn env_proxies() -> HashMap<String, String> {
+    ProxyType::all()
+        .flat_map(|proxy_type| {
+            let key = proxy_type.env_key();
+            [key.to_string(), key.to_uppercase()]
+        })
+        .filter_map(|key| std::env::var(&key).ok().map(|value| (key, value)))
+        .collect()
+}
+
+/// 探测单个目标
+///
+/// 发送 GET 请求，不校验状态码：收到任何响应都视为可达。
+pub fn probe_target(
+    target: &NetworkTarget,
+    timeout: Duration,
+    proxies: &HashMap<String, String>,
+) -> NetworkProbeResult {
+    let proxy = proxy_for_url(&target.url, proxies);
+    let start = Instant::now();
+    let result = HttpClient::global().and_then(|client| {
+        let config = RequestConfig::<Value, Value>::new().timeout(timeout);
+        client.stream(HttpMethod::Get, &target.url, config)
+    });
+    let latency = start.elapsed();
+
+    match result {
+        Ok(response) => NetworkProbeResult {
+            target: target.clone(),
+            state: NetworkState::Reachable,
+            status: Some(response.status().as_u16()),
+            latency,
+            proxy,
+            detail: None,
+        },
+        Err(e) => NetworkProbeResult {
+            target: target.clone(),
+            state: NetworkState::from_error(&e),
+            status: None,
+            latency,
+            proxy,
+            detail: Some(format!("{:#}", e)),
+        },
+    }
+}
+
+/// 依次探测所有目标
+pub fn probe_targets(targets: &[NetworkTarget], timeout: Duration) -> Vec<NetworkProbeResult> {
+    let proxies = env_proxies();
+    targets.iter().map(|target| probe_target(target, timeout, &proxies)).collect()
+}
+
+/// 渲染网络检查表格
+pub fn render_network_table(results: &[NetworkProbeResult]) -> String {
+    let rows: Vec<NetworkProbeRow> = results.iter().map(NetworkProbeRow::from).collect();
+    TableBuilder::new(rows)
+        .with_title("Network Connectivity")
+        .with_style(TableStyle::Modern)
+        .render()
+}
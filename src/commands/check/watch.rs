@@ -0,0 +1,291 @@
+//! 环境检查的监视模式
+//!
+//! 按固定间隔重复执行环境检查，每次清屏并重绘状态表格，
+//! 所有检查通过或按下 Ctrl+C 时退出。
+
+use crate::base::settings::Settings;
+use crate::base::table::{TableBuilder, TableStyle};
+use crate::{log_break, log_message, log_success, log_warning};
+use color_eyre::{eyre::WrapErr, Result};
+use console::Term;
+use duct::cmd;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tabled::Tabled;
+
+use super::api::{check_api, ApiCheckOptions, ApiCheckResult};
+use super::network::{probe_targets, NetworkProbeResult};
+use super::CheckCommand;
+
+/// 网络检查的超时时间（监视模式下缩短，以便及时响应 Ctrl+C）
+const NETWORK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 等待下一轮检查时检测 Ctrl+C 的间隔
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 单项检查结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckStatus {
+    /// 检查项名称
+    pub name: String,
+    /// 是否通过
+    pub passed: bool,
+    /// 结果说明
+    pub detail: String,
+    /// 检查耗时（仅对涉及外部调用的检查项记录）
+    pub latency: Option<Duration>,
+}
+
+impl CheckStatus {
+    /// 创建通过的检查结果
+    pub fn pass(name: &str, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            passed: true,
+            detail: detail.into(),
+            latency: None,
+        }
+    }
+
+    /// 创建失败的检查结果
+    pub fn fail(name: &str, detail: impl Into<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            passed: false,
+            detail: detail.into(),
+            latency: None,
+        }
+    }
+
+    /// 记录检查耗时
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = Some(latency);
+        self
+    }
+}
+
+/// 检查状态表格行
+#[derive(Tabled)]
+pub struct CheckStatusRow {
+    #[tabled(rename = "Check")]
+    pub name: String,
+    #[tabled(rename = "Status")]
+    pub status: String,
+    #[tabled(rename = "Latency")]
+    pub latency: String,
+    #[tabled(rename = "Detail")]
+    pub detail: String,
+}
+
+impl From<&CheckStatus> for CheckStatusRow {
+    fn from(status: &CheckStatus) -> Self {
+        Self {
+            name: status.name.clone(),
+            status: if status.passed {
+                "✓ pass"
+            } else {
+                "✗ fail"
+            }
+            .to_string(),
+            latency: status.latency.map(format_latency).unwrap_or_else(|| "-".to_string()),
+            detail: status.detail.clone(),
+        }
+    }
+}
+
+/// 格式化检查耗时（如 `120 ms`、`1.25 s`）
+pub fn format_latency(latency: Duration) -> String {
+    let millis = latency.as_millis();
+    if millis < 1000 {
+        format!("{} ms", millis)
+    } else {
+        format!("{:.2} s", latency.as_secs_f64())
+    }
+}
+
+/// 检查 Jira 和 GitHub token 是否已配置
+pub fn check_tokens(settings: &Settings) -> CheckStatus {
+    const NAME: &str = "Tokens present";
+
+    let mut missing = Vec::new();
+    if settings.github.get_current_token().is_none_or(|token| token.trim().is_empty()) {
+        missing.push("github");
+    }
+    if settings.jira.api_token.as_deref().is_none_or(|token| token.trim().is_empty()) {
+        missing.push("jira");
+    }
+
+    if missing.is_empty() {
+        CheckStatus::pass(NAME, "GitHub and Jira tokens configured")
+    } else {
+        CheckStatus::fail(NAME, format!("Missing token: {}", missing.join(", ")))
+    }
+}
+
+/// 将 API 连通性检查结果转换为检查状态
+///
+/// 未配置凭据的服务视为通过（不阻止监视模式退出）。
+pub fn api_status(result: &ApiCheckResult) -> CheckStatus {
+    let name = format!("{} API", result.service.name());
+    let detail = match result.status {
+        Some(status) => format!("{} (HTTP {})", result.state.label(), status),
+        None => match result.detail {
+            Some(ref detail) => format!("{}: {}", result.state.label(), detail),
+            None => result.state.label().to_string(),
+        },
+    };
+
+    if result.state.is_failure() {
+        CheckStatus::fail(&name, detail)
+    } else {
+        CheckStatus::pass(&name, detail)
+    }
+}
+
+/// 将网络检查结果转换为检查状态
+pub fn network_status(result: &NetworkProbeResult) -> CheckStatus {
+    let name = format!("Network: {}", result.target.name);
+    let status = match result.status {
+        Some(status) => CheckStatus::pass(&name, format!("reachable (HTTP {})", status)),
+        None => CheckStatus::fail(&name, result.state.label()),
+    };
+    status.with_latency(result.latency)
+}
+
+impl CheckCommand {
+    /// 监视模式：按间隔重复执行环境检查
+    ///
+    /// 每轮清屏并重绘状态表格，所有检查通过或按下 Ctrl+C 时正常退出。
+    pub fn watch(interval: u64, api_options: &ApiCheckOptions) -> Result<()> {
+        let stopped = Arc::new(AtomicBool::new(false));
+        let handler_flag = Arc::clone(&stopped);
+        ctrlc::set_handler(move || handler_flag.store(true, Ordering::SeqCst))
+            .wrap_err("Failed to install Ctrl+C handler")?;
+
+        let term = Term::stdout();
+        let interval = Duration::from_secs(interval);
+        let mut round = 0;
+
+        loop {
+            round += 1;
+            let statuses = Self::collect_statuses(api_options);
+            let passed = statuses.iter().filter(|status| status.passed).count();
+
+            term.clear_screen().wrap_err("Failed to clear terminal")?;
+            Self::render_statuses(&statuses, round);
+
+            if passed == statuses.len() {
+                log_success!("All checks passed");
+                return Ok(());
+            }
+
+            log_message!(
+                "{}/{} checks passed, re-checking in {}s (press Ctrl+C to stop)...",
+                passed,
+                statuses.len(),
+                interval.as_secs()
+            );
+
+            if Self::wait_or_stop(interval, &stopped) {
+                log_break!();
+                log_warning!(
+                    "Stopped watching ({}/{} checks passed)",
+                    passed,
+                    statuses.len()
+                );
+                return Ok(());
+            }
+        }
+    }
+
+    /// 执行所有检查项
+    fn collect_statuses(api_options: &ApiCheckOptions) -> Vec<CheckStatus> {
+        let settings = Settings::load_with_env_overrides().0;
+        let mut statuses = vec![Self::check_git_installed()];
+        statuses.extend(Self::network_statuses(&settings));
+        statuses.push(Self::check_config_status());
+        statuses.push(check_tokens(&settings));
+
+        for service in api_options.services() {
+            let start = Instant::now();
+            let result = check_api(service, &settings);
+            let status = api_status(&result);
+            statuses.push(match result.status {
+                Some(_) => status.with_latency(start.elapsed()),
+                None => status,
+            });
+        }
+        statuses
+    }
+
+    /// 渲染状态表格
+    fn render_statuses(statuses: &[CheckStatus], round: usize) {
+        let rows: Vec<CheckStatusRow> = statuses.iter().map(CheckStatusRow::from).collect();
+        log_message!(
+            "{}",
+            TableBuilder::new(rows)
+                .with_title(format!(
+                    "Environment Checks (#{} at {})",
+                    round,
+                    chrono::Local::now().format("%H:%M:%S")
+                ))
+                .with_style(TableStyle::Modern)
+                .render()
+        );
+    }
+
+    /// 等待下一轮检查
+    ///
+    /// 等待期间按下 Ctrl+C 时返回 `true`。
+    fn wait_or_stop(interval: Duration, stopped: &AtomicBool) -> bool {
+        let deadline = Instant::now() + interval;
+        while Instant::now() < deadline {
+            if stopped.load(Ordering::SeqCst) {
+                return true;
+            }
+            std::thread::sleep(STOP_POLL_INTERVAL);
+        }
+        stopped.load(Ordering::SeqCst)
+    }
+
+    /// 检查 Git 是否已安装
+    fn check_git_installed() -> CheckStatus {
+        const NAME: &str = "Git installed";
+
+        let start = Instant::now();
+        match cmd("git", &["--version"]).stderr_null().read() {
+            Ok(version) => CheckStatus::pass(NAME, version.trim()).with_latency(start.elapsed()),
+            Err(_) => CheckStatus::fail(NAME, "git command not found"),
+        }
+    }
+
+    /// 检查网络检查目标的可达性（每个目标一项）
+    fn network_statuses(settings: &Settings) -> Vec<CheckStatus> {
+        probe_targets(&settings.network_targets(), NETWORK_TIMEOUT)
+            .iter()
+            .map(network_status)
+            .collect()
+    }
+
+    /// 校验当前生效的配置（不输出日志）
+    fn check_config_status() -> CheckStatus {
+        const NAME: &str = "Config valid";
+
+        let issues = Settings::validate_active();
+        let errors: Vec<_> = issues.iter().filter(|issue| issue.is_error()).collect();
+        match errors.first() {
+            None if issues.is_empty() => CheckStatus::pass(NAME, "No issues"),
+            None => CheckStatus::pass(NAME, format!("{} warning(s)", issues.len())),
+            Some(first) => CheckStatus::fail(
+                NAME,
+                format!(
+                    "{} error(s), first: {}: {}",
+                    errors.len(),
+                    first.field,
+                    first.message
+                ),
+            ),
+        }
+    }
+}
@@ -7,6 +7,7 @@ use crate::base::dialog::{ConfirmDialog, InputDialog, MultiSelectDialog, SelectD
 use crate::commands::check;
 use crate::commands::commit::helpers::{
     check_has_last_commit, check_not_on_default_branch, handle_force_push_warning,
+    prefill_commit_trailers,
 };
 use crate::commit::CommitAmend;
 use crate::git::GitCommit;
@@ -37,7 +38,13 @@ impl CommitAmendCommand {
     /// * `message` - New commit message (optional, will prompt if not provided)
     /// * `no_edit` - Don't edit the commit message
     /// * `no_verify` - Skip pre-commit hooks
-    pub fn execute(message: Option<String>, no_edit: bool, no_verify: bool) -> Result<()> {
+    /// * `co_authors` - Co-authors to add as `Co-authored-by:` trailers
+    pub fn execute(
+        message: Option<String>,
+        no_edit: bool,
+        no_verify: bool,
+        co_authors: Vec<String>,
+    ) -> Result<()> {
         // 0. 检查并确保仓库配置存在
         crate::commands::repo::setup::RepoSetupCommand::ensure()?;
 
@@ -81,6 +88,7 @@ impl CommitAmendCommand {
                 } else {
                     Self::input_new_message(&commit_info.message)?
                 };
+                let msg = prefill_commit_trailers(msg, &current_branch, &co_authors)?;
                 (Some(msg), Vec::new())
             }
             AmendOperation::FilesOnly => {
@@ -89,6 +97,7 @@ impl CommitAmendCommand {
             }
             AmendOperation::MessageAndFiles => {
                 let msg = Self::input_new_message(&commit_info.message)?;
+                let msg = prefill_commit_trailers(msg, &current_branch, &co_authors)?;
                 let files = Self::select_files_to_add()?;
                 (Some(msg), files)
             }
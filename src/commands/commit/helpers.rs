@@ -3,7 +3,11 @@
 //! 提供 Commit 命令之间共享的公共功能，避免代码重复。
 
 use crate::base::dialog::ConfirmDialog;
+use crate::commit::ConventionalCommit;
 use crate::git::{GitBranch, GitCommit};
+use crate::jira::helpers::extract_jira_ticket_from_branch;
+use crate::pr::helpers::generate_commit_message;
+use crate::template::TemplateConfig;
 use crate::{log_break, log_info, log_success};
 use color_eyre::{eyre::WrapErr, Result};
 
@@ -93,3 +97,64 @@ pub fn check_has_last_commit() -> Result<()> {
     }
     Ok(())
 }
+
+/// 询问是否在提交消息中添加 ticket 引用和共同作者 trailer
+///
+/// ticket 从当前分支名提取（`Refs: PROJ-123`），共同作者来自 `--co-author`；
+/// 消息中已有的 trailer 不会重复添加。确认后使用 commit 正文模板重新生成消息
+/// （见 `generate_commit_message`）。启用 `template.commit.validate_conventional` 时校验最终消息。
+///
+/// # 参数
+///
+/// * `message` - 用户输入的提交消息（第一行为标题）
+/// * `current_branch` - 当前分支名称
+/// * `co_authors` - 共同作者（`Name <email>`）
+pub fn prefill_commit_trailers(
+    message: String,
+    current_branch: &str,
+    co_authors: &[String],
+) -> Result<String> {
+    if let Some(invalid) = co_authors
+        .iter()
+        .find(|co_author| !(co_author.contains(" <") && co_author.ends_with('>')))
+    {
+        color_eyre::eyre::bail!("Invalid co-author '{}', expected 'Name <email>'", invalid);
+    }
+
+    let refs = extract_jira_ticket_from_branch(current_branch)
+        .filter(|ticket| !message.contains(&format!("Refs: {}", ticket)));
+    let new_co_authors: Vec<String> = co_authors
+        .iter()
+        .filter(|co_author| !message.contains(co_author.as_str()))
+        .cloned()
+        .collect();
+
+    let mut additions = Vec::new();
+    if let Some(ticket) = &refs {
+        additions.push(format!("'Refs: {}'", ticket));
+    }
+    if !new_co_authors.is_empty() {
+        additions.push(format!(
+            "{} Co-authored-by trailer(s)",
+            new_co_authors.len()
+        ));
+    }
+
+    let should_prefill = !additions.is_empty()
+        && ConfirmDialog::new(format!(
+            "Add {} to the commit message?",
+            additions.join(" and ")
+        ))
+        .with_default(true)
+        .prompt()
+        .wrap_err("Failed to get confirmation")?;
+    if should_prefill {
+        let (title, body) = message.split_once('\n').unwrap_or((message.as_str(), ""));
+        return generate_commit_message(title, Some(body), refs.as_deref(), &new_co_authors);
+    }
+
+    if TemplateConfig::load().unwrap_or_default().commit.validate_conventional {
+        ConventionalCommit::validate(&message)?;
+    }
+    Ok(message)
+}
@@ -11,7 +11,7 @@ use crate::commands::commit::helpers::{
 use crate::commit::{CommitReword, RewordHistoryOptions};
 use crate::git::{CommitInfo, GitCommit};
 use crate::{log_break, log_info, log_message, log_success};
-use color_eyre::{eyre::eyre, eyre::WrapErr, Result};
+use color_eyre::{eyre::WrapErr, Result};
 
 /// Commit reword command
 pub struct CommitRewordCommand;
@@ -140,55 +140,21 @@ impl CommitRewordCommand {
         }
 
         // 格式化选项：显示 SHA、消息等信息
-        let options: Vec<String> = commits
-            .iter()
+        let items: Vec<(String, CommitInfo)> = commits
+            .into_iter()
             .enumerate()
             .map(|(idx, c)| {
                 // 标记 HEAD（第一个）
                 let marker = if idx == 0 { "[HEAD] " } else { "" };
-                format!("{}[{}] {}", marker, &c.sha[..8], c.message)
+                (format!("{}[{}] {}", marker, &c.sha[..8], c.message), c)
             })
             .collect();
 
         // SelectDialog 默认支持 fuzzy-matcher
-        let selected = SelectDialog::new("Select commit to reword", options)
+        SelectDialog::new_with_items("Select commit to reword", items)
             .with_default(0) // 默认选中 HEAD
             .prompt()
-            .wrap_err("Failed to select commit")?;
-
-        // 从选中的选项解析出 commit SHA
-        // 格式: "[HEAD] [abc1234] message" 或 "[def5678] message"
-        let commit_sha = if selected.starts_with("[HEAD] [") {
-            // 格式: "[HEAD] [abc1234] message"
-            let sha_start = selected.find('[').and_then(|pos1| {
-                let after_first = &selected[pos1 + 1..];
-                if after_first.starts_with("HEAD] [") {
-                    let sha_start_pos = after_first.find('[').map(|pos2| pos2 + 1)?;
-                    let sha_part = &after_first[sha_start_pos..];
-                    sha_part.find(']').map(|end_pos| sha_part[..end_pos].to_string())
-                } else {
-                    None
-                }
-            });
-            sha_start.ok_or_else(|| eyre!("Failed to parse selected commit"))?
-        } else if selected.starts_with('[') {
-            // 格式: "[def5678] message"
-            let sha_start =
-                selected.find('[').ok_or_else(|| eyre!("Failed to parse selected commit"))? + 1;
-            let sha_part = &selected[sha_start..];
-            sha_part
-                .find(']')
-                .map(|end_pos| sha_part[..end_pos].to_string())
-                .ok_or_else(|| eyre!("Failed to parse selected commit"))?
-        } else {
-            color_eyre::eyre::bail!("Failed to parse selected commit format");
-        };
-
-        // 从 commits 列表中找到对应的 CommitInfo
-        commits
-            .into_iter()
-            .find(|c| c.sha.starts_with(&commit_sha))
-            .ok_or_else(|| eyre!("Selected commit not found"))
+            .wrap_err("Failed to select commit")
     }
 
     /// 输入新消息并确认（支持重新输入）
@@ -3,12 +3,12 @@
 
 use std::path::PathBuf;
 
-use clap_complete::shells::Shell;
 use color_eyre::{eyre::WrapErr, Result};
 
 use crate::base::dialog::{ConfirmDialog, MultiSelectDialog};
 use crate::base::settings::paths::Paths;
-use crate::base::shell::Detect;
+use crate::base::shell::{Detect, Shell};
+use crate::completion::CompletionScriptStatus;
 use crate::{log_break, log_debug, log_info, log_message, log_success, log_warning, Completion};
 
 /// Shell 配置状态
@@ -18,6 +18,44 @@ struct ShellStatus {
     installed: bool,
     configured: bool,
     config_path: PathBuf,
+    /// completion 脚本状态（只检查已配置 completion 的 shell）
+    scripts: Option<CompletionScriptStatus>,
+}
+
+impl ShellStatus {
+    /// 配置状态描述
+    fn describe(&self) -> String {
+        if !self.configured {
+            return "Completion not configured".to_string();
+        }
+
+        let scripts =
+            self.scripts.map(|status| format!(", scripts {}", status)).unwrap_or_default();
+        format!(
+            "Completion configured ({}){}",
+            self.config_path.display(),
+            scripts
+        )
+    }
+}
+
+/// `completion check --exit-code` 发现当前 shell 的脚本过期或缺失时的退出码
+///
+/// 与一般错误（退出码 1）区分，`workflow update` 用它判断是否需要重新生成 completion。
+pub const COMPLETION_STALE_EXIT_CODE: i32 = 10;
+
+/// Completion 检查结果
+#[derive(Debug, Clone, Copy)]
+pub struct CompletionCheck {
+    /// 当前 shell 的 completion 脚本状态（无法检测 shell 或未配置 completion 时为 `None`）
+    pub current: Option<CompletionScriptStatus>,
+}
+
+impl CompletionCheck {
+    /// 当前 shell 的 completion 脚本是否需要重新生成
+    pub fn needs_regeneration(&self) -> bool {
+        self.current.is_some_and(|status| status.needs_regeneration())
+    }
 }
 
 /// Completion 管理命令
@@ -26,8 +64,14 @@ pub struct CompletionCommand;
 impl CompletionCommand {
     /// 检查 completion 状态
     ///
-    /// 检测系统中已安装的 shell 和已配置 completion 的 shell。
-    pub fn check() -> Result<()> {
+    /// 检测系统中已安装的 shell 和已配置 completion 的 shell，
+    /// 并通过校验和比较已安装的脚本与当前 CLI 定义生成的脚本（up to date / stale / missing）。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `CompletionCheck`，调用方根据 `needs_regeneration()` 决定退出码
+    /// （见 `COMPLETION_STALE_EXIT_CODE`）。
+    pub fn check() -> Result<CompletionCheck> {
         log_info!("Checking shell completion status...");
         log_break!();
 
@@ -53,12 +97,18 @@ impl CompletionCommand {
             let installed = installed_shells.contains(shell);
             let (configured, config_path) = Completion::is_shell_configured(shell)
                 .unwrap_or_else(|_| (false, Paths::config_file(shell).unwrap_or_default()));
+            let scripts = if configured {
+                Completion::script_status(shell).ok()
+            } else {
+                None
+            };
 
             statuses.push(ShellStatus {
                 shell: *shell,
                 installed,
                 configured,
                 config_path,
+                scripts,
             });
         }
 
@@ -66,21 +116,26 @@ impl CompletionCommand {
         if let Some(current) = current_shell {
             if let Some(status) = statuses.iter().find(|s| s.shell == current) {
                 log_message!("Current shell:");
-                let config_status = if status.configured {
-                    format!("Completion configured ({})", status.config_path.display())
-                } else {
-                    "Completion not configured".to_string()
-                };
-                log_message!("  {} - {}", status.shell, config_status);
+                log_message!("  {} - {}", status.shell, status.describe());
                 log_break!();
 
-                // 如果当前 shell 未配置，显示警告
+                // 如果当前 shell 未配置或脚本不是最新，显示警告
                 if !status.configured {
                     log_warning!(
                         "Your current shell ({}) does not have completion configured",
                         current
                     );
-                    log_info!("Hint: Run `workflow completion generate` to generate completion");
+                    log_info!("Hint: Run `workflow completion install` to install completion");
+                    log_break!();
+                } else if let Some(scripts) =
+                    status.scripts.filter(CompletionScriptStatus::needs_regeneration)
+                {
+                    log_warning!(
+                        "Completion scripts for your current shell ({}) are {} (they do not match the current CLI)",
+                        current,
+                        scripts
+                    );
+                    log_info!("Hint: Run `workflow completion install` to update completion");
                     log_break!();
                 }
             }
@@ -95,12 +150,7 @@ impl CompletionCommand {
         if !other_shells.is_empty() {
             log_message!("Other installed shells:");
             for status in &other_shells {
-                let config_status = if status.configured {
-                    format!("Completion configured ({})", status.config_path.display())
-                } else {
-                    "Completion not configured".to_string()
-                };
-                log_message!("  {} - {}", status.shell, config_status);
+                log_message!("  {} - {}", status.shell, status.describe());
             }
             log_break!();
         }
@@ -112,11 +162,7 @@ impl CompletionCommand {
         if !uninstalled_configured.is_empty() {
             log_message!("Uninstalled but configured shells:");
             for status in &uninstalled_configured {
-                log_message!(
-                    "  {} - Completion configured ({})",
-                    status.shell,
-                    status.config_path.display()
-                );
+                log_message!("  {} - {}", status.shell, status.describe());
             }
             log_break!();
         }
@@ -124,13 +170,18 @@ impl CompletionCommand {
         // 显示当前 shell 的最终状态
         if let Some(current) = current_shell {
             if let Some(status) = statuses.iter().find(|s| s.shell == current) {
-                if status.configured {
+                if status.scripts == Some(CompletionScriptStatus::UpToDate) {
+                    log_success!("Current shell ({}) has up-to-date completion", current);
+                } else if status.configured {
                     log_success!("Current shell ({}) has completion configured", current);
                 }
             }
         }
 
-        Ok(())
+        let current = current_shell
+            .and_then(|current| statuses.iter().find(|s| s.shell == current))
+            .and_then(|status| status.scripts);
+        Ok(CompletionCheck { current })
     }
 
     /// 移除 completion 配置
@@ -272,29 +323,50 @@ impl CompletionCommand {
 
     /// 生成 completion 脚本
     ///
-    /// 自动检测当前 shell 类型，生成对应的 completion 脚本并应用到配置文件。
-    /// 行为与安装流程完全一致。
-    pub fn generate() -> Result<()> {
+    /// 自动检测当前 shell 类型，生成对应的 completion 脚本，不修改 shell 配置文件。
+    ///
+    /// # 参数
+    ///
+    /// * `output` - 输出目录（默认为 completion 目录 `~/.workflow/completions`）
+    pub fn generate(output: Option<String>) -> Result<()> {
         log_info!("Generating shell completion scripts...");
 
-        // 1. 自动检测当前 shell 类型（使用 Detect::shell()）
-        let shell = Detect::shell().wrap_err("Failed to detect current shell type")?;
-        log_debug!("Detected shell type: {}", shell);
-
-        let completion_dir = Paths::completion_dir()?;
-        log_debug!("Completion directory: {}", completion_dir.display());
+        let Some(shell) = Self::detect_completion_shell()? else {
+            return Ok(());
+        };
 
-        // 2. 生成 completion 脚本（与安装流程一致）
-        let shell_type_str = shell.to_string();
-        log_debug!("Generating {} completion scripts...", shell_type_str);
+        let output_dir = match output {
+            Some(output) => PathBuf::from(output),
+            None => Paths::completion_dir()?,
+        };
+        log_debug!("Generating {} completion scripts...", shell);
         Completion::generate_all_completions(
-            Some(shell_type_str),
-            Some(completion_dir.to_string_lossy().to_string()),
+            Some(shell.to_string()),
+            Some(output_dir.to_string_lossy().to_string()),
         )?;
 
-        // 3. 应用到对应的 shell 配置文件（使用 ShellConfigManager）
-        log_debug!("Configuring shell configuration file...");
-        let config_result = Completion::configure_shell_config(&shell)?;
+        log_success!(
+            "{} completion scripts generated to: {}",
+            shell,
+            output_dir.display()
+        );
+        log_info!("Hint: Run `workflow completion install` to enable completion in your shell");
+
+        Ok(())
+    }
+
+    /// 安装 completion 脚本
+    ///
+    /// 自动检测当前 shell 类型，将 completion 脚本写入 completion 目录，
+    /// 并在 shell 配置文件中启用（已启用时不重复添加，可重复执行）。
+    pub fn install() -> Result<()> {
+        log_info!("Installing shell completion scripts...");
+
+        let Some(shell) = Self::detect_completion_shell()? else {
+            return Ok(());
+        };
+
+        let config_result = Completion::install(&shell)?;
 
         if config_result.already_exists {
             log_success!(
@@ -309,8 +381,11 @@ impl CompletionCommand {
         } else {
             log_success!("Completion config written to shell config file");
         }
+        if let Some(ref backup_path) = config_result.backup_path {
+            log_info!("  Backup of previous shell config: {:?}", backup_path);
+        }
 
-        log_success!("  shell completion generation complete");
+        log_success!("  shell completion installation complete");
         log_break!();
 
         // 根据检测到的 shell 类型提示相应的重新加载命令
@@ -328,4 +403,20 @@ impl CompletionCommand {
 
         Ok(())
     }
+
+    /// 检测当前 shell 类型
+    ///
+    /// clap 无法为部分 shell（如 nushell）生成 completion 脚本，此时提示手动配置方式并返回 `None`。
+    fn detect_completion_shell() -> Result<Option<Shell>> {
+        let shell = Detect::shell().wrap_err("Failed to detect current shell type")?;
+        log_debug!("Detected shell type: {}", shell);
+
+        if shell.completion_shell().is_none() {
+            log_warning!("Completion scripts cannot be generated for {}", shell);
+            log_info!("See the \"Nushell\" section in README for manual completion setup");
+            return Ok(None);
+        }
+
+        Ok(Some(shell))
+    }
 }
@@ -1,8 +1,11 @@
 //! 配置导出命令
 //! 导出配置文件用于备份和迁移
+//!
+//! 默认省略敏感信息（以 `<omitted>` 标记），使用 `--include-secrets` 导出完整配置。
 
 use crate::base::settings::paths::Paths;
 use crate::base::settings::settings::Settings;
+use crate::base::settings::OMITTED_SECRET;
 use crate::base::util::directory::DirectoryWalker;
 use crate::base::util::file::FileWriter;
 use crate::commands::config::helpers::extract_section;
@@ -19,7 +22,7 @@ impl ConfigExportCommand {
     pub fn export(
         output_path: String,
         section: Option<String>,
-        no_secrets: bool,
+        include_secrets: bool,
         _toml: bool,
         json: bool,
         yaml: bool,
@@ -42,69 +45,41 @@ impl ConfigExportCommand {
             ));
         }
 
-        // 加载配置
+        // 加载配置（当前激活 Profile 的生效配置）
         let settings = Settings::load();
+        let export_config = match section {
+            Some(ref section_name) => extract_section(&settings, section_name)?,
+            None => settings,
+        };
 
-        // 如果指定了 section，验证该 section 的配置
-        if let Some(ref section_name) = section {
-            let export_config = extract_section(&settings, section_name)?;
-            log_info!("Validating configuration before export...");
-            let validation_result =
-                ConfigValidateCommand::validate_config(&export_config, &config_path)?;
-
-            if !validation_result.errors.is_empty() {
-                log_error!("Configuration validation failed");
-                for error in &validation_result.errors {
-                    log_message!("  - {}: {}", error.field, error.message);
-                }
-                return Err(eyre!(
-                    "Export cancelled. Please fix configuration errors before exporting."
-                ));
-            }
-
-            if !validation_result.warnings.is_empty() {
-                log_warning!("Configuration validation warnings:");
-                for warning in &validation_result.warnings {
-                    log_message!("  - {}: {}", warning.field, warning.message);
-                }
-                log_info!("Continuing with export despite warnings...");
-            }
-        } else {
-            // 导出完整配置时验证
-            log_info!("Validating configuration before export...");
-            let validation_result =
-                ConfigValidateCommand::validate_config(&settings, &config_path)?;
+        // 导出前验证配置
+        log_info!("Validating configuration before export...");
+        let validation_result =
+            ConfigValidateCommand::validate_config(&export_config, &config_path)?;
 
-            if !validation_result.errors.is_empty() {
-                log_error!("Configuration validation failed");
-                for error in &validation_result.errors {
-                    log_message!("  - {}: {}", error.field, error.message);
-                }
-                return Err(eyre!(
-                    "Export cancelled. Please fix configuration errors before exporting."
-                ));
+        if !validation_result.errors.is_empty() {
+            log_error!("Configuration validation failed");
+            for error in &validation_result.errors {
+                log_message!("  - {}: {}", error.field, error.message);
             }
+            return Err(eyre!(
+                "Export cancelled. Please fix configuration errors before exporting."
+            ));
+        }
 
-            if !validation_result.warnings.is_empty() {
-                log_warning!("Configuration validation warnings:");
-                for warning in &validation_result.warnings {
-                    log_message!("  - {}: {}", warning.field, warning.message);
-                }
-                log_info!("Continuing with export despite warnings...");
+        if !validation_result.warnings.is_empty() {
+            log_warning!("Configuration validation warnings:");
+            for warning in &validation_result.warnings {
+                log_message!("  - {}: {}", warning.field, warning.message);
             }
+            log_info!("Continuing with export despite warnings...");
         }
 
-        // 过滤敏感信息（只在需要时处理，save_config 会处理 section 提取）
-        let filtered_count = if no_secrets {
-            if let Some(ref _section_name) = section {
-                // 对于 section 导出，在 save_config 中处理过滤
-                0 // 将在 save_config 中计算
-            } else {
-                let (_, count) = Self::filter_secrets(settings.clone());
-                count
-            }
+        // 默认省略敏感信息
+        let (export_config, omitted_count) = if include_secrets {
+            (export_config, 0)
         } else {
-            0
+            export_config.redact_secrets()
         };
 
         // 确定输出路径
@@ -124,13 +99,13 @@ impl ConfigExportCommand {
             output_path = output_path.join(filename);
         }
 
-        // 导出到文件（直接使用原始 settings，save_config 会处理 section 提取和过滤）
+        // 导出到文件
         Self::save_config(
-            &settings,
+            &export_config,
             section.as_deref(),
             &output_path,
             format,
-            no_secrets,
+            include_secrets,
         )?;
 
         // 显示结果
@@ -144,66 +119,19 @@ impl ConfigExportCommand {
             log_success!("Configuration exported to {:?}", output_path);
         }
 
-        // 如果指定了 section，需要重新计算 filtered_count
-        let actual_filtered_count = if no_secrets {
-            if let Some(ref section_name) = section {
-                // 对于 section 导出，计算该 section 中被过滤的字段数
-                let export_config = extract_section(&settings, section_name)?;
-                let (_, count) = Self::filter_secrets(export_config);
-                count
-            } else {
-                filtered_count
-            }
-        } else {
-            0
-        };
-
-        if actual_filtered_count > 0 {
+        if include_secrets {
+            log_warning!("The exported file contains secrets, do not share it publicly");
+        } else if omitted_count > 0 {
             log_warning!(
-                "Sensitive information has been filtered ({} field(s))",
-                actual_filtered_count
+                "{} secret field(s) omitted and marked as \"{}\" (use --include-secrets to export them)",
+                omitted_count,
+                OMITTED_SECRET
             );
         }
 
         Ok(())
     }
 
-    /// 过滤敏感信息
-    fn filter_secrets(settings: Settings) -> (Settings, usize) {
-        let mut filtered = settings;
-        let mut count = 0;
-
-        // 过滤 JIRA API token
-        if filtered.jira.api_token.is_some() {
-            filtered.jira.api_token = Some("***FILTERED***".to_string());
-            count += 1;
-        }
-
-        // 过滤 GitHub API tokens
-        for account in &mut filtered.github.accounts {
-            if !account.api_token.is_empty() {
-                account.api_token = "***FILTERED***".to_string();
-                count += 1;
-            }
-        }
-
-        // 过滤 LLM keys（所有 provider）
-        if filtered.llm.openai.key.is_some() {
-            filtered.llm.openai.key = Some("***FILTERED***".to_string());
-            count += 1;
-        }
-        if filtered.llm.deepseek.key.is_some() {
-            filtered.llm.deepseek.key = Some("***FILTERED***".to_string());
-            count += 1;
-        }
-        if filtered.llm.proxy.key.is_some() {
-            filtered.llm.proxy.key = Some("***FILTERED***".to_string());
-            count += 1;
-        }
-
-        (filtered, count)
-    }
-
     /// 生成文件名
     fn generate_filename(section: Option<&str>, format: &str) -> String {
         let extension = match format {
@@ -220,143 +148,61 @@ impl ConfigExportCommand {
     }
 
     /// 保存配置到文件
+    ///
+    /// 包含敏感信息时，文件权限设置为仅当前用户可读写。
     fn save_config(
         config: &Settings,
         section: Option<&str>,
         path: &PathBuf,
         format: &str,
-        no_secrets: bool,
+        include_secrets: bool,
     ) -> Result<()> {
         // 确保父目录存在
         DirectoryWalker::new(".").ensure_parent_exists(path)?;
 
         let content = match format.to_lowercase().as_str() {
             "toml" => {
-                // 如果指定了 section，只序列化该 section
+                // 如果指定了 section，只序列化该 section（包含 section 标题）
                 if let Some(section_name) = section {
-                    // 直接从原始 config 中提取 section，然后过滤（如果需要），并序列化
-                    let section_str = match section_name.to_lowercase().as_str() {
-                        "jira" => {
-                            let mut jira = config.jira.clone();
-                            if no_secrets && jira.api_token.is_some() {
-                                jira.api_token = Some("***FILTERED***".to_string());
-                            }
-                            toml::to_string_pretty(&jira)
-                                .wrap_err("Failed to serialize jira config to TOML")?
-                        }
-                        "github" => {
-                            let mut github = config.github.clone();
-                            if no_secrets {
-                                for account in &mut github.accounts {
-                                    if !account.api_token.is_empty() {
-                                        account.api_token = "***FILTERED***".to_string();
-                                    }
-                                }
-                            }
-                            toml::to_string_pretty(&github)
-                                .wrap_err("Failed to serialize github config to TOML")?
-                        }
-                        "log" => toml::to_string_pretty(&config.log)
-                            .wrap_err("Failed to serialize log config to TOML")?,
-                        "llm" => {
-                            let mut llm = config.llm.clone();
-                            if no_secrets {
-                                if llm.openai.key.is_some() {
-                                    llm.openai.key = Some("***FILTERED***".to_string());
-                                }
-                                if llm.deepseek.key.is_some() {
-                                    llm.deepseek.key = Some("***FILTERED***".to_string());
-                                }
-                                if llm.proxy.key.is_some() {
-                                    llm.proxy.key = Some("***FILTERED***".to_string());
-                                }
-                            }
-                            toml::to_string_pretty(&llm)
-                                .wrap_err("Failed to serialize llm config to TOML")?
-                        }
+                    let section_value = match section_name.to_lowercase().as_str() {
+                        "jira" => toml::Value::try_from(&config.jira),
+                        "github" => toml::Value::try_from(&config.github),
+                        "log" => toml::Value::try_from(&config.log),
+                        "llm" => toml::Value::try_from(&config.llm),
                         _ => {
                             return Err(eyre!("Unknown section: '{}'", section_name));
                         }
-                    };
-
-                    // 解析为 toml::Value，然后构建包含 section 标题的 TOML
-                    let section_value: toml::Value = toml::from_str(&section_str)
-                        .wrap_err("Failed to parse section config as TOML value")?;
+                    }
+                    .wrap_err_with(|| {
+                        format!("Failed to serialize {} config to TOML", section_name)
+                    })?;
 
-                    // 构建包含 section 标题的 TOML 表
                     let mut table = toml::map::Map::new();
                     table.insert(section_name.to_string(), section_value);
-                    let root_table = toml::Value::Table(table);
-
-                    toml::to_string_pretty(&root_table)
+                    toml::to_string_pretty(&toml::Value::Table(table))
                         .wrap_err("Failed to serialize section config to TOML")?
                 } else {
-                    // 导出完整配置
-                    let config_to_serialize = if no_secrets {
-                        let (filtered, _) = Self::filter_secrets(config.clone());
-                        filtered
-                    } else {
-                        config.clone()
-                    };
-                    toml::to_string_pretty(&config_to_serialize)
-                        .wrap_err("Failed to serialize config to TOML")?
+                    toml::to_string_pretty(config).wrap_err("Failed to serialize config to TOML")?
                 }
             }
             "json" => {
                 // 如果指定了 section，只序列化该 section
                 if let Some(section_name) = section {
                     let json_value = match section_name.to_lowercase().as_str() {
-                        "jira" => {
-                            let mut jira = config.jira.clone();
-                            if no_secrets && jira.api_token.is_some() {
-                                jira.api_token = Some("***FILTERED***".to_string());
-                            }
-                            serde_json::to_value(&jira)
-                                .wrap_err("Failed to serialize jira config to JSON")?
-                        }
-                        "github" => {
-                            let mut github = config.github.clone();
-                            if no_secrets {
-                                for account in &mut github.accounts {
-                                    if !account.api_token.is_empty() {
-                                        account.api_token = "***FILTERED***".to_string();
-                                    }
-                                }
-                            }
-                            serde_json::to_value(&github)
-                                .wrap_err("Failed to serialize github config to JSON")?
-                        }
-                        "log" => serde_json::to_value(&config.log)
-                            .wrap_err("Failed to serialize log config to JSON")?,
-                        "llm" => {
-                            let mut llm = config.llm.clone();
-                            if no_secrets {
-                                if llm.openai.key.is_some() {
-                                    llm.openai.key = Some("***FILTERED***".to_string());
-                                }
-                                if llm.deepseek.key.is_some() {
-                                    llm.deepseek.key = Some("***FILTERED***".to_string());
-                                }
-                                if llm.proxy.key.is_some() {
-                                    llm.proxy.key = Some("***FILTERED***".to_string());
-                                }
-                            }
-                            serde_json::to_value(&llm)
-                                .wrap_err("Failed to serialize llm config to JSON")?
-                        }
+                        "jira" => serde_json::to_value(&config.jira),
+                        "github" => serde_json::to_value(&config.github),
+                        "log" => serde_json::to_value(&config.log),
+                        "llm" => serde_json::to_value(&config.llm),
                         _ => {
                             return Err(eyre!("Unknown section: '{}'", section_name));
                         }
-                    };
+                    }
+                    .wrap_err_with(|| {
+                        format!("Failed to serialize {} config to JSON", section_name)
+                    })?;
                     serde_json::to_string_pretty(&json_value).wrap_err("Failed to format JSON")?
                 } else {
-                    let config_to_serialize = if no_secrets {
-                        let (filtered, _) = Self::filter_secrets(config.clone());
-                        filtered
-                    } else {
-                        config.clone()
-                    };
-                    serde_json::to_string_pretty(&config_to_serialize)
+                    serde_json::to_string_pretty(config)
                         .wrap_err("Failed to serialize config to JSON")?
                 }
             }
@@ -364,56 +210,19 @@ impl ConfigExportCommand {
                 // 如果指定了 section，只序列化该 section
                 if let Some(section_name) = section {
                     match section_name.to_lowercase().as_str() {
-                        "jira" => {
-                            let mut jira = config.jira.clone();
-                            if no_secrets && jira.api_token.is_some() {
-                                jira.api_token = Some("***FILTERED***".to_string());
-                            }
-                            serde_saphyr::to_string(&jira)
-                                .wrap_err("Failed to serialize jira config to YAML")?
-                        }
-                        "github" => {
-                            let mut github = config.github.clone();
-                            if no_secrets {
-                                for account in &mut github.accounts {
-                                    if !account.api_token.is_empty() {
-                                        account.api_token = "***FILTERED***".to_string();
-                                    }
-                                }
-                            }
-                            serde_saphyr::to_string(&github)
-                                .wrap_err("Failed to serialize github config to YAML")?
-                        }
-                        "log" => serde_saphyr::to_string(&config.log)
-                            .wrap_err("Failed to serialize log config to YAML")?,
-                        "llm" => {
-                            let mut llm = config.llm.clone();
-                            if no_secrets {
-                                if llm.openai.key.is_some() {
-                                    llm.openai.key = Some("***FILTERED***".to_string());
-                                }
-                                if llm.deepseek.key.is_some() {
-                                    llm.deepseek.key = Some("***FILTERED***".to_string());
-                                }
-                                if llm.proxy.key.is_some() {
-                                    llm.proxy.key = Some("***FILTERED***".to_string());
-                                }
-                            }
-                            serde_saphyr::to_string(&llm)
-                                .wrap_err("Failed to serialize llm config to YAML")?
-                        }
+                        "jira" => serde_saphyr::to_string(&config.jira),
+                        "github" => serde_saphyr::to_string(&config.github),
+                        "log" => serde_saphyr::to_string(&config.log),
+                        "llm" => serde_saphyr::to_string(&config.llm),
                         _ => {
                             return Err(eyre!("Unknown section: '{}'", section_name));
                         }
                     }
+                    .wrap_err_with(|| {
+                        format!("Failed to serialize {} config to YAML", section_name)
+                    })?
                 } else {
-                    let config_to_serialize = if no_secrets {
-                        let (filtered, _) = Self::filter_secrets(config.clone());
-                        filtered
-                    } else {
-                        config.clone()
-                    };
-                    serde_saphyr::to_string(&config_to_serialize)
+                    serde_saphyr::to_string(config)
                         .wrap_err("Failed to serialize config to YAML")?
                 }
             }
@@ -425,10 +234,16 @@ impl ConfigExportCommand {
             }
         };
 
-        FileWriter::new(path)
+        let writer = FileWriter::new(path);
+        writer
             .write_str(&content)
             .wrap_err_with(|| format!("Failed to write config file: {:?}", path))?;
 
+        if include_secrets {
+            #[cfg(unix)]
+            writer.set_permissions(0o600)?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,83 @@
+//! Git 配置管理命令
+//! 读取和写入 Workflow 使用的 Git 身份和提交签名配置（默认全局配置）
+
+use crate::base::settings::table::GitConfigRow;
+use crate::base::table::{TableBuilder, TableStyle};
+use crate::git::{GitConfig, GitConfigScope, MANAGED_GIT_CONFIG_KEYS};
+use crate::{log_message, log_success};
+use color_eyre::Result;
+
+/// Git 配置管理命令
+pub struct GitConfigCommand;
+
+impl GitConfigCommand {
+    /// 显示 Git 配置
+    ///
+    /// 未指定配置项时显示所有受支持的配置项。
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 配置项（如 `user.email`）
+    /// * `local` - 是否读取仓库配置（否则读取全局配置）
+    pub fn get(key: Option<&str>, local: bool) -> Result<()> {
+        let scope = Self::scope(local);
+        let keys: Vec<&str> = match key {
+            Some(key) => {
+                Self::ensure_managed(key)?;
+                vec![key]
+            }
+            None => MANAGED_GIT_CONFIG_KEYS.to_vec(),
+        };
+
+        let rows: Vec<GitConfigRow> = keys
+            .into_iter()
+            .map(|key| GitConfigRow {
+                key: key.to_string(),
+                value: GitConfig::get(scope, key).unwrap_or_else(|| "-".to_string()),
+                effective: GitConfig::get_effective(key).unwrap_or_else(|| "-".to_string()),
+            })
+            .collect();
+
+        log_message!(
+            "{}",
+            TableBuilder::new(rows)
+                .with_title(format!("Git Configuration ({})", scope.as_str()))
+                .with_style(TableStyle::Modern)
+                .render()
+        );
+        Ok(())
+    }
+
+    /// 校验并写入 Git 配置
+    ///
+    /// # 参数
+    ///
+    /// * `key` - 配置项（如 `user.email`）
+    /// * `value` - 配置值
+    /// * `local` - 是否写入仓库配置（否则写入全局配置）
+    pub fn set(key: &str, value: &str, local: bool) -> Result<()> {
+        let scope = Self::scope(local);
+        let value = GitConfig::set(scope, key, value)?;
+        log_success!("Set {} git config {} = {}", scope.as_str(), key, value);
+        Ok(())
+    }
+
+    fn scope(local: bool) -> GitConfigScope {
+        if local {
+            GitConfigScope::Local
+        } else {
+            GitConfigScope::Global
+        }
+    }
+
+    fn ensure_managed(key: &str) -> Result<()> {
+        if !MANAGED_GIT_CONFIG_KEYS.contains(&key) {
+            color_eyre::eyre::bail!(
+                "Unsupported git config key '{}', expected one of: {}",
+                key,
+                MANAGED_GIT_CONFIG_KEYS.join(", ")
+            );
+        }
+        Ok(())
+    }
+}
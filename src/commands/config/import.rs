@@ -11,86 +11,102 @@ use std::path::{Path, PathBuf};
 use color_eyre::{eyre::eyre, eyre::WrapErr, Result};
 
 // 项目内部导入
+use crate::base::dialog::ConfirmDialog;
 use crate::base::settings::paths::Paths;
+use crate::base::settings::profile::Profile;
 use crate::base::settings::settings::Settings;
+use crate::base::settings::ConfigIssue;
 use crate::base::util::date::get_unix_timestamp;
-use crate::base::util::file::{FileReader, FileWriter};
+use crate::base::util::file::FileReader;
 use crate::commands::config::helpers::{extract_section, parse_config};
-use crate::commands::config::validate::ConfigValidateCommand;
 use crate::{log_error, log_info, log_message, log_success, log_warning};
 
 /// 导入事务结构
 /// 用于管理导入过程中的备份和回滚
 struct ImportTransaction {
-    backup_path: PathBuf,
-    original_config: Settings,
+    /// 备份文件路径（配置文件原本不存在时为 `None`）
+    backup_path: Option<PathBuf>,
     config_path: PathBuf,
 }
 
 impl ImportTransaction {
     /// 创建新的事务
     fn new(config_path: PathBuf) -> Result<Self> {
-        let original_config = if config_path.exists() {
-            Settings::load()
+        // 配置文件存在时创建备份
+        let backup_path = if config_path.exists() {
+            Some(Self::create_backup(&config_path)?)
         } else {
-            Settings::default()
+            None
         };
 
-        // 创建备份
-        let backup_path = Self::create_backup(&config_path)?;
-
         Ok(Self {
             backup_path,
-            original_config,
             config_path,
         })
     }
 
     /// 回滚到原始配置
     fn rollback(&self) -> Result<()> {
-        // 从备份恢复
-        fs::copy(&self.backup_path, &self.config_path).wrap_err("Failed to restore from backup")?;
+        match self.backup_path {
+            Some(ref backup_path) => {
+                // 从备份恢复
+                fs::copy(backup_path, &self.config_path)
+                    .wrap_err("Failed to restore from backup")?;
+
+                // 设置文件权限（Unix 系统）
+                #[cfg(unix)]
+                {
+                    let mut perms = fs::metadata(&self.config_path)?.permissions();
+                    perms.set_mode(0o600);
+                    fs::set_permissions(&self.config_path, perms)?;
+                }
 
-        // 设置文件权限（Unix 系统）
-        #[cfg(unix)]
-        {
-            let mut perms = fs::metadata(&self.config_path)?.permissions();
-            perms.set_mode(0o600);
-            fs::set_permissions(&self.config_path, perms)?;
+                log_success!("Configuration restored from backup: {:?}", backup_path);
+            }
+            None => {
+                // 配置文件原本不存在，删除导入时创建的文件
+                if self.config_path.exists() {
+                    fs::remove_file(&self.config_path)
+                        .wrap_err("Failed to remove imported configuration")?;
+                }
+                log_success!("Imported configuration removed: {:?}", self.config_path);
+            }
         }
 
         // 验证恢复的配置
-        let restored = Settings::load();
-        let validation = ConfigValidateCommand::validate_config(&restored, &self.config_path)?;
-
-        if !validation.errors.is_empty() {
+        let errors: Vec<ConfigIssue> = Settings::validate_active()
+            .into_iter()
+            .filter(|issue| issue.is_error())
+            .collect();
+        if !errors.is_empty() {
             log_warning!("Warning: Restored configuration has validation errors");
-            for error in &validation.errors {
+            for error in &errors {
                 log_message!("  - {}: {}", error.field, error.message);
             }
         }
 
-        log_success!("Configuration restored from backup: {:?}", self.backup_path);
         Ok(())
     }
 
     /// 提交事务（删除备份）
     fn commit(&self) -> Result<()> {
-        if self.backup_path.exists() {
-            fs::remove_file(&self.backup_path).wrap_err("Failed to remove backup file")?;
+        if let Some(ref backup_path) = self.backup_path {
+            if backup_path.exists() {
+                fs::remove_file(backup_path).wrap_err("Failed to remove backup file")?;
+            }
         }
         Ok(())
     }
 
-    /// 创建备份文件
-    fn create_backup(config_path: &Path) -> Result<PathBuf> {
-        if !config_path.exists() {
-            return Err(eyre!(
-                "Configuration file does not exist: {:?}",
-                config_path
-            ));
+    /// 提示备份文件位置（回滚失败时使用）
+    fn log_backup_location(&self) {
+        if let Some(ref backup_path) = self.backup_path {
+            log_error!("Backup file is available at: {:?}", backup_path);
         }
+    }
 
+    /// 创建备份文件
+    fn create_backup(config_path: &Path) -> Result<PathBuf> {
         let timestamp = get_unix_timestamp();
         let backup_filename = format!("config.backup.{}.toml", timestamp);
         let backup_path = config_path
@@ -110,6 +126,9 @@ pub struct ConfigImportCommand;
 
 impl ConfigImportCommand {
     /// 导入配置文件
+    ///
+    /// 导入文件先经过配置校验器检查，被省略的敏感信息（`<omitted>`）保留本地已有的值。
+    /// 写入当前激活 Profile 的配置前需要用户确认。
     pub fn import(
         input_path: String,
         overwrite: bool,
@@ -124,84 +143,87 @@ impl ConfigImportCommand {
 
         // 读取并解析输入文件
         let content = FileReader::new(&input_path).to_string()?;
-
-        let imported_settings = parse_config(&content, &input_path)?;
+        let imported_settings = Self::parse_input(&content, &input_path)?;
 
         // 提取要导入的配置段
-        let imported = if let Some(ref section_name) = section {
+        let mut imported = if let Some(ref section_name) = section {
             extract_section(&imported_settings, section_name)?
         } else {
             imported_settings
         };
 
         // 验证导入的配置
-        let validation_result = ConfigValidateCommand::validate_config(&imported, &input_path)?;
-        if !validation_result.errors.is_empty() {
-            log_error!("Configuration validation failed");
-            for error in &validation_result.errors {
-                log_message!("  - {}: {}", error.field, error.message);
-            }
-            return Err(eyre!("Import cancelled. Configuration validation failed."));
+        Self::check_issues(&imported.validate(), "Configuration validation failed")?;
+
+        // 还原被省略的敏感信息
+        let current_settings = Settings::load();
+        Self::restore_secrets(&mut imported, &current_settings);
+
+        // 执行导入（合并或覆盖）
+        let final_settings = if let Some(ref section_name) = section {
+            Self::merge_section(&current_settings, &imported, section_name)
+        } else if overwrite {
+            imported
+        } else {
+            Self::merge_configs(&current_settings, &imported)
+        };
+
+        // 验证最终配置
+        Self::check_issues(
+            &final_settings.validate(),
+            "Configuration validation failed after import",
+        )?;
+
+        let changes = Self::collect_changes(&current_settings, &final_settings)?;
+        if changes.is_empty() {
+            log_info!("No configuration changes to import");
+            return Ok(());
         }
 
         if dry_run {
             log_info!("Dry run mode - previewing changes:");
-            Self::preview_changes(&imported, section.as_deref())?;
+            for change in &changes {
+                log_message!("{}", change);
+            }
             log_info!("\nNo changes were made. Remove --dry-run to apply changes.");
             return Ok(());
         }
 
-        // 获取配置文件路径
-        let current_config_path =
-            Paths::workflow_config().wrap_err("Failed to get workflow config path")?;
-
-        // 创建事务（包含备份）
-        let transaction = ImportTransaction::new(current_config_path.clone())?;
-        log_success!(
-            "Configuration backup created: {:?}",
-            transaction.backup_path
+        // 确认后再覆盖当前配置
+        let profile = Profile::active();
+        log_info!(
+            "The following changes will be applied to profile '{}':",
+            profile
         );
-
-        // 加载当前配置
-        let current_settings = transaction.original_config.clone();
-
-        // 执行导入（合并或覆盖）
-        let final_settings = if overwrite {
-            if let Some(ref section_name) = section {
-                Self::merge_section(&current_settings, &imported, section_name)
-            } else {
-                imported.clone()
-            }
-        } else if let Some(ref section_name) = section {
-            Self::merge_section(&current_settings, &imported, section_name)
+        for change in &changes {
+            log_message!("{}", change);
+        }
+        let prompt = if overwrite && section.is_none() {
+            format!(
+                "Overwrite the configuration of profile '{}' with {:?}?",
+                profile, input_path
+            )
         } else {
-            Self::merge_configs(&current_settings, &imported)
+            format!("Import configuration from {:?}?", input_path)
         };
+        let confirmed = ConfirmDialog::new(prompt).with_default(false).prompt()?;
+        if !confirmed {
+            log_info!("Import cancelled");
+            return Ok(());
+        }
 
-        // 验证最终配置
-        let final_validation =
-            ConfigValidateCommand::validate_config(&final_settings, &current_config_path)?;
-        if !final_validation.errors.is_empty() {
-            log_error!("Configuration validation failed after import");
-            for error in &final_validation.errors {
-                log_message!("  - {}: {}", error.field, error.message);
-            }
-            // 自动回滚
-            match transaction.rollback() {
-                Ok(_) => {
-                    log_success!("Successfully rolled back to original configuration");
-                }
-                Err(e) => {
-                    log_error!("Failed to rollback configuration: {}", e);
-                    log_error!("Backup file is available at: {:?}", transaction.backup_path);
-                    log_error!("Please manually restore from backup if needed");
-                }
-            }
-            return Err(eyre!("Import cancelled. Configuration validation failed."));
+        // 获取当前激活 Profile 的配置文件路径
+        let current_config_path =
+            Paths::active_profile_config().wrap_err("Failed to get workflow config path")?;
+
+        // 创建事务（包含备份）
+        let transaction = ImportTransaction::new(current_config_path)?;
+        if let Some(ref backup_path) = transaction.backup_path {
+            log_success!("Configuration backup created: {:?}", backup_path);
         }
 
         // 保存配置
-        if let Err(e) = Self::save_config(&final_settings, &current_config_path) {
+        if let Err(e) = Settings::update_active_profile(|settings| *settings = final_settings) {
             log_error!("Failed to save configuration: {}", e);
             // 保存失败，回滚
             match transaction.rollback() {
@@ -210,14 +232,14 @@ impl ConfigImportCommand {
                 }
                 Err(rollback_err) => {
                     log_error!("Failed to rollback: {}", rollback_err);
-                    log_error!("Backup file is available at: {:?}", transaction.backup_path);
+                    transaction.log_backup_location();
                 }
             }
             return Err(e.wrap_err("Failed to save configuration"));
         }
 
         // 验证保存后的配置（确保文件写入正确）
-        if !Self::verify_saved_config(&current_config_path)? {
+        if !Self::verify_saved_config() {
             log_error!("Post-save validation failed");
             // 保存后验证失败，回滚
             match transaction.rollback() {
@@ -226,7 +248,7 @@ impl ConfigImportCommand {
                 }
                 Err(e) => {
                     log_error!("Failed to rollback: {}", e);
-                    log_error!("Backup file is available at: {:?}", transaction.backup_path);
+                    transaction.log_backup_location();
                 }
             }
             return Err(eyre!("Post-save validation failed"));
@@ -240,12 +262,72 @@ impl ConfigImportCommand {
             log_success!("Configuration imported successfully (overwrite mode)");
         } else {
             log_success!("Configuration imported successfully (merge mode)");
-            Self::show_changes(&current_settings, &final_settings, section.as_deref())?;
         }
 
         Ok(())
     }
 
+    /// 解析输入文件
+    ///
+    /// TOML 文件解析失败时，使用配置校验器定位出错的配置键。
+    fn parse_input(content: &str, input_path: &Path) -> Result<Settings> {
+        parse_config(content, input_path).map_err(|e| {
+            let is_toml = input_path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .map(|ext| ext.eq_ignore_ascii_case("toml"))
+                .unwrap_or(false);
+            if is_toml {
+                for issue in Settings::validate_file(input_path) {
+                    log_message!("  - {}: {}", issue.field, issue.message);
+                }
+            }
+            e.wrap_err("Import cancelled. Failed to parse configuration file.")
+        })
+    }
+
+    /// 显示校验问题，存在错误时取消导入
+    fn check_issues(issues: &[ConfigIssue], message: &str) -> Result<()> {
+        let (errors, warnings): (Vec<_>, Vec<_>) = issues.iter().partition(|i| i.is_error());
+
+        if !warnings.is_empty() {
+            log_warning!("Configuration validation warnings:");
+            for warning in &warnings {
+                log_message!("  - {}: {}", warning.field, warning.message);
+            }
+        }
+
+        if !errors.is_empty() {
+            log_error!("{}", message);
+            for error in &errors {
+                log_message!("  - {}: {}", error.field, error.message);
+            }
+            return Err(eyre!("Import cancelled. {}.", message));
+        }
+
+        Ok(())
+    }
+
+    /// 还原被省略的敏感信息
+    ///
+    /// 本地没有对应 token 的 GitHub 账号不会被导入。
+    fn restore_secrets(imported: &mut Settings, current: &Settings) {
+        let skipped = imported.restore_omitted_secrets(current);
+        if skipped.is_empty() {
+            return;
+        }
+
+        imported.github.accounts.retain(|account| !skipped.contains(&account.name));
+        if imported.github.current.as_ref().is_some_and(|name| skipped.contains(name)) {
+            imported.github.current = None;
+        }
+        log_warning!(
+            "Skipped GitHub account(s) whose token was omitted from the import file: {}",
+            skipped.join(", ")
+        );
+        log_message!("  Add them with 'workflow github add'.");
+    }
+
     /// 合并配置段
     fn merge_section(current: &Settings, imported: &Settings, section: &str) -> Settings {
         let mut merged = current.clone();
@@ -283,6 +365,15 @@ impl ConfigImportCommand {
         if imported.jira.service_address.is_some() {
             merged.jira.service_address = imported.jira.service_address.clone();
         }
+        if imported.jira.timezone.is_some() {
+            merged.jira.timezone = imported.jira.timezone.clone();
+        }
+        if imported.jira.auth_type.is_some() {
+            merged.jira.auth_type = imported.jira.auth_type.clone();
+        }
+        if imported.jira.sprint_field.is_some() {
+            merged.jira.sprint_field = imported.jira.sprint_field.clone();
+        }
 
         // 合并 GitHub 配置（完全替换）
         if !imported.github.accounts.is_empty() {
@@ -291,6 +382,12 @@ impl ConfigImportCommand {
         if imported.github.current.is_some() {
             merged.github.current = imported.github.current.clone();
         }
+        if imported.github.api_base_url.is_some() {
+            merged.github.api_base_url = imported.github.api_base_url.clone();
+        }
+        if imported.github.web_base_url.is_some() {
+            merged.github.web_base_url = imported.github.web_base_url.clone();
+        }
 
         // 合并日志配置
         // 只有当导入的值不是默认值时才合并 output_folder_name
@@ -306,6 +403,12 @@ impl ConfigImportCommand {
         if imported.log.enable_trace_console.is_some() {
             merged.log.enable_trace_console = imported.log.enable_trace_console;
         }
+        if imported.log.http_trace.is_some() {
+            merged.log.http_trace = imported.log.http_trace;
+        }
+        if imported.log.redact_patterns.is_some() {
+            merged.log.redact_patterns = imported.log.redact_patterns.clone();
+        }
 
         // 合并 LLM 配置
         merged.llm.provider = imported.llm.provider.clone();
@@ -334,155 +437,103 @@ impl ConfigImportCommand {
         if imported.llm.proxy.model.is_some() {
             merged.llm.proxy.model = imported.llm.proxy.model.clone();
         }
-
-        merged
-    }
-
-    /// 验证保存后的配置
-    fn verify_saved_config(config_path: &Path) -> Result<bool> {
-        // 重新读取配置文件
-        let content = FileReader::new(config_path).to_string()?;
-
-        let saved_settings = parse_config(&content, config_path)?;
-
-        // 验证配置
-        let validation = ConfigValidateCommand::validate_config(&saved_settings, config_path)?;
-
-        if !validation.errors.is_empty() {
-            log_warning!("Post-save validation found errors:");
-            for error in &validation.errors {
-                log_message!("  - {}: {}", error.field, error.message);
+        if imported.llm.max_retries.is_some() {
+            merged.llm.max_retries = imported.llm.max_retries;
+        }
+        for (merged_provider, imported_provider) in [
+            (&mut merged.llm.openai, &imported.llm.openai),
+            (&mut merged.llm.deepseek, &imported.llm.deepseek),
+            (&mut merged.llm.proxy, &imported.llm.proxy),
+        ] {
+            if imported_provider.max_diff_chars.is_some() {
+                merged_provider.max_diff_chars = imported_provider.max_diff_chars;
             }
-            return Ok(false);
         }
 
-        Ok(true)
-    }
-
-    /// 保存配置
-    fn save_config(settings: &Settings, path: &PathBuf) -> Result<()> {
-        FileWriter::new(path).write_toml(settings)?;
-
-        // 设置文件权限（Unix 系统）
-        #[cfg(unix)]
-        {
-            let mut perms = fs::metadata(path)?.permissions();
-            perms.set_mode(0o600);
-            fs::set_permissions(path, perms)?;
+        // 合并自定义 prompt 配置
+        if imported.prompt.generate_branch_path.is_some() {
+            merged.prompt.generate_branch_path = imported.prompt.generate_branch_path.clone();
+        }
+        if imported.prompt.summarize_pr_path.is_some() {
+            merged.prompt.summarize_pr_path = imported.prompt.summarize_pr_path.clone();
         }
 
-        Ok(())
-    }
+        // 合并 HTTP 配置
+        if imported.http.timeout_secs.is_some() {
+            merged.http.timeout_secs = imported.http.timeout_secs;
+        }
+        if imported.http.download_timeout_secs.is_some() {
+            merged.http.download_timeout_secs = imported.http.download_timeout_secs;
+        }
+        if imported.http.max_concurrent_downloads.is_some() {
+            merged.http.max_concurrent_downloads = imported.http.max_concurrent_downloads;
+        }
 
-    /// 预览变更
-    fn preview_changes(imported: &Settings, section: Option<&str>) -> Result<()> {
-        let current = Settings::load();
-
-        if let Some(section_name) = section {
-            log_message!("  Section: {}", section_name);
-            match section_name.to_lowercase().as_str() {
-                "jira" => {
-                    if imported.jira.email.is_some() {
-                        log_message!("    - jira.email: will be updated");
-                    }
-                    if imported.jira.service_address.is_some() {
-                        log_message!("    - jira.service_address: will be updated");
-                    }
-                }
-                "github" => {
-                    if !imported.github.accounts.is_empty() {
-                        log_message!(
-                            "    - github.accounts: {} account(s) will be imported",
-                            imported.github.accounts.len()
-                        );
-                    }
-                }
-                "log" => {
-                    log_message!("    - log.output_folder_name: will be updated");
-                }
-                "llm" => {
-                    if imported.llm.provider != current.llm.provider {
-                        log_message!(
-                            "    - llm.provider: {} -> {}",
-                            current.llm.provider,
-                            imported.llm.provider
-                        );
-                    }
-                }
-                _ => {}
-            }
-        } else {
-            log_message!("  Full configuration will be imported");
+        // 合并环境检查配置（网络检查目标整体替换）
+        if !imported.check.network_targets.is_empty() {
+            merged.check.network_targets = imported.check.network_targets.clone();
         }
 
-        Ok(())
+        merged
     }
 
-    /// 显示变更
-    fn show_changes(
-        current: &Settings,
-        final_settings: &Settings,
-        section: Option<&str>,
-    ) -> Result<()> {
-        let mut changes = Vec::new();
-
-        if section.is_none() || section == Some("jira") {
-            if current.jira.email != final_settings.jira.email {
-                changes.push("  - Updated: jira.email".to_string());
-            }
-            if current.jira.service_address != final_settings.jira.service_address {
-                changes.push("  - Updated: jira.service_address".to_string());
+    /// 验证保存后的配置
+    fn verify_saved_config() -> bool {
+        // 重新读取配置文件（包含基础配置和当前 Profile）并验证
+        let errors: Vec<ConfigIssue> = Settings::validate_active()
+            .into_iter()
+            .filter(|issue| issue.is_error())
+            .collect();
+
+        if !errors.is_empty() {
+            log_warning!("Post-save validation found errors:");
+            for error in &errors {
+                log_message!("  - {}: {}", error.field, error.message);
             }
+            return false;
         }
 
-        if (section.is_none() || section == Some("github"))
-            && current.github.accounts.len() != final_settings.github.accounts.len()
-        {
-            changes.push(format!(
-                "  - Updated: github.accounts ({} account(s))",
-                final_settings.github.accounts.len()
-            ));
-        }
-
-        if (section.is_none() || section == Some("log"))
-            && current.log.get_output_folder_name() != final_settings.log.get_output_folder_name()
-        {
-            changes.push("  - Updated: log.output_folder_name".to_string());
-        }
+        true
+    }
 
-        if section.is_none() || section == Some("llm") {
-            if current.llm.provider != final_settings.llm.provider {
-                changes.push("  - Updated: llm.provider".to_string());
-            }
-            if current.llm.language != final_settings.llm.language {
-                changes.push("  - Updated: llm.language".to_string());
-            }
-            // 检查各 provider 的配置变更
-            if current.llm.openai.key != final_settings.llm.openai.key
-                || current.llm.openai.model != final_settings.llm.openai.model
-            {
-                changes.push("  - Updated: llm.openai".to_string());
-            }
-            if current.llm.deepseek.key != final_settings.llm.deepseek.key
-                || current.llm.deepseek.model != final_settings.llm.deepseek.model
-            {
-                changes.push("  - Updated: llm.deepseek".to_string());
-            }
-            if current.llm.proxy.url != final_settings.llm.proxy.url
-                || current.llm.proxy.key != final_settings.llm.proxy.key
-                || current.llm.proxy.model != final_settings.llm.proxy.model
-            {
-                changes.push("  - Updated: llm.proxy".to_string());
-            }
-        }
+    /// 收集变更的配置键
+    ///
+    /// 只列出配置键，不显示值（避免输出敏感信息）。
+    fn collect_changes(current: &Settings, final_settings: &Settings) -> Result<Vec<String>> {
+        let current = toml::Value::try_from(current).wrap_err("Failed to serialize settings")?;
+        let updated =
+            toml::Value::try_from(final_settings).wrap_err("Failed to serialize settings")?;
+
+        let mut updated_keys = Vec::new();
+        Self::flatten_keys(&Profile::diff(&current, &updated), "", &mut updated_keys);
+        let mut reverse_keys = Vec::new();
+        Self::flatten_keys(&Profile::diff(&updated, &current), "", &mut reverse_keys);
+
+        let removed = reverse_keys
+            .iter()
+            .filter(|key| !updated_keys.contains(key))
+            .map(|key| format!("  - Removed: {}", key));
+        Ok(updated_keys
+            .iter()
+            .map(|key| format!("  - Updated: {}", key))
+            .chain(removed)
+            .collect())
+    }
 
-        if !changes.is_empty() {
-            log_info!("Changes applied:");
-            for change in changes {
-                log_message!("{}", change);
+    /// 展开 TOML 表中所有叶子节点的键路径（数组视为叶子节点）
+    fn flatten_keys(value: &toml::Value, prefix: &str, keys: &mut Vec<String>) {
+        match value.as_table() {
+            Some(table) => {
+                for (key, value) in table {
+                    let path = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}.{}", prefix, key)
+                    };
+                    Self::flatten_keys(value, &path, keys);
+                }
             }
+            None => keys.push(prefix.to_string()),
         }
-
-        Ok(())
     }
 }
@@ -3,10 +3,13 @@
 
 // 应用配置管理
 pub mod export;
+pub mod git;
 pub mod helpers;
 pub mod import;
 pub mod log;
+pub mod profile;
 pub mod setup;
+pub mod setup_answers;
 pub mod show;
 pub mod validate;
 
@@ -0,0 +1,122 @@
+//! 配置 Profile 管理命令
+//! 列出、切换和创建命名配置 Profile
+
+use crate::base::settings::paths::Paths;
+use crate::base::settings::profile::{Profile, PROFILE_ENV};
+use crate::base::settings::settings::Settings;
+use crate::base::settings::table::ProfileRow;
+use crate::base::table::{TableBuilder, TableStyle};
+use crate::git::GitConfig;
+use crate::{log_break, log_info, log_message, log_success, log_warning};
+use color_eyre::Result;
+
+/// 配置 Profile 管理命令
+pub struct ProfileCommand;
+
+impl ProfileCommand {
+    /// 列出所有 Profile，并标记当前激活的 Profile
+    ///
+    /// 同时显示每个 Profile 生效的 Jira 账号和 GitHub 账号（未覆盖的字段从基础配置继承）。
+    pub fn list() -> Result<()> {
+        let active = Profile::active();
+        let profiles = Profile::list()?;
+
+        let rows: Vec<ProfileRow> = profiles
+            .iter()
+            .map(|name| {
+                let settings = Settings::load_profile(name);
+                ProfileRow {
+                    active: if name == &active { "*" } else { "" }.to_string(),
+                    name: name.clone(),
+                    jira: Self::jira_summary(&settings),
+                    github: settings
+                        .github
+                        .get_current_account()
+                        .map(|account| account.name.clone())
+                        .unwrap_or_else(|| "-".to_string()),
+                }
+            })
+            .collect();
+
+        log_message!(
+            "{}",
+            TableBuilder::new(rows)
+                .with_title("Configuration Profiles")
+                .with_style(TableStyle::Modern)
+                .render()
+        );
+
+        if !profiles.contains(&active) {
+            log_break!();
+            log_warning!(
+                "Active profile '{}' does not exist, only the base configuration is used",
+                active
+            );
+        }
+
+        Ok(())
+    }
+
+    /// 切换当前激活的 Profile
+    ///
+    /// 切换后 Jira、GitHub、LLM 等所有配置都使用新 Profile 的值；
+    /// 新 Profile 中有当前 GitHub 账号时，同时将 Git 全局用户切换为该账号（与 `workflow github switch` 相同）。
+    pub fn use_profile(name: String) -> Result<()> {
+        Profile::set_active(&name)?;
+
+        log_success!("Switched to profile: {}", name);
+        if std::env::var(PROFILE_ENV).map(|v| !v.trim().is_empty()).unwrap_or(false) {
+            log_warning!(
+                "{} is set and takes precedence over the saved profile",
+                PROFILE_ENV
+            );
+            return Ok(());
+        }
+
+        let settings = Settings::load_profile(&name);
+        log_message!("  Jira: {}", Self::jira_summary(&settings));
+        match settings.github.get_current_account() {
+            Some(account) => {
+                log_message!("  GitHub account: {}", account.name);
+                let result = GitConfig::set_global_user(&account.email, &account.name)?;
+                log_info!("Git global config updated:");
+                log_message!("  user.email: {}", result.email);
+                log_message!("  user.name: {}", result.name);
+            }
+            None => {
+                log_message!("  GitHub account: -");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Jira 配置摘要（`email @ service_address`，未配置的部分显示为 `-`）
+    fn jira_summary(settings: &Settings) -> String {
+        let jira = &settings.jira;
+        if jira.email.is_none() && jira.service_address.is_none() {
+            return "-".to_string();
+        }
+        format!(
+            "{} @ {}",
+            jira.email.as_deref().unwrap_or("-"),
+            jira.service_address.as_deref().unwrap_or("-")
+        )
+    }
+
+    /// 创建新的 Profile
+    pub fn create(name: String) -> Result<()> {
+        Profile::create(&name)?;
+
+        log_success!("Profile '{}' created", name);
+        if let Ok(config_path) = Paths::profile_config(&name) {
+            log_message!("  Config file: {}", config_path.display());
+        }
+        log_info!(
+            "Fields not set in this profile are inherited from the base configuration.\n   Run 'workflow config profile use {}' and then 'workflow setup' to configure it.",
+            name
+        );
+
+        Ok(())
+    }
+}
@@ -1,25 +1,37 @@
 //! 初始化设置命令
 //! 交互式配置应用，保存到 TOML 配置文件（~/.workflow/config/workflow.toml）
+//!
+//! 使用 `--non-interactive` 时从环境变量或配置值文件读取配置（参见 `setup_answers`），
+//! 与交互式表单共用同一套结果处理逻辑。
 
 use crate::base::constants::messages::log;
-use crate::base::dialog::{FormBuilder, GroupConfig, SelectDialog};
+use crate::base::dialog::{
+    ConfirmDialog, FormBuilder, FormResult, GroupConfig, InputDialog, SelectDialog,
+};
 use crate::base::indicator::Spinner;
-use crate::base::llm::{get_supported_language_display_names, SUPPORTED_LANGUAGES};
-use crate::base::settings::paths::Paths;
+use crate::base::llm::{get_output_language_display_name, get_output_language_display_names};
 use crate::base::settings::settings::{
-    default_download_base_dir, GitHubAccount, GitHubSettings, JiraSettings, LLMSettings,
-    LogSettings, Settings,
+    default_download_base_dir, AzureDevOpsSettings, CommitSettings, GitHubAccount, GitHubSettings,
+    JiraAuthType, JiraSettings, LLMSettings, LogSettings, Settings,
 };
+use crate::commands::check::api::{ApiCheckState, ApiProbe};
 use crate::commands::github::helpers::collect_github_account;
-use crate::git::GitConfig;
-use crate::jira::config::ConfigManager;
+use crate::git::{GitConfig, GitConfigScope, GitSigningFormat};
 use crate::{log_break, log_info, log_message, log_success, log_warning};
 use color_eyre::{eyre::eyre, eyre::WrapErr, Result};
-use std::collections::HashMap;
+use std::path::Path;
+
+use super::setup_answers::SetupAnswers;
 
 /// 初始化设置命令
 pub struct SetupCommand;
 
+/// Tracing 控制台输出选项：同时输出到文件和控制台
+pub(crate) const TRACE_CONSOLE_ENABLE: &str = "Enable (output to both file and console)";
+
+/// Tracing 控制台输出选项：只输出到文件
+pub(crate) const TRACE_CONSOLE_DISABLE: &str = "Disable (output to file only)";
+
 /// 收集的配置数据
 #[derive(Debug, Clone)]
 struct CollectedConfig {
@@ -27,8 +39,10 @@ struct CollectedConfig {
     jira_email: Option<String>,
     jira_api_token: Option<String>,
     jira_service_address: Option<String>,
+    jira_auth_type: Option<String>,
     github_accounts: Vec<GitHubAccount>,
     github_current: Option<String>,
+    azure_devops_api_token: Option<String>,
     log_output_folder_name: Option<String>,
     log_download_base_dir: Option<String>,
     enable_trace_console: Option<bool>,
@@ -43,23 +57,72 @@ struct CollectedConfig {
     llm_proxy_url: Option<String>,
     llm_proxy_key: Option<String>,
     llm_proxy_model: Option<String>,
+    // 提交配置
+    commit_sign: Option<bool>,
 }
 
 impl SetupCommand {
     /// 运行初始化设置流程
     pub fn run() -> Result<()> {
+        Self::run_with_verification(true)
+    }
+
+    /// 运行初始化设置流程，可以跳过凭据的在线验证
+    ///
+    /// # 参数
+    ///
+    /// * `verify_credentials` - 是否在输入后在线验证凭据（如 Jira email/token），离线时传入 `false`
+    pub fn run_with_verification(verify_credentials: bool) -> Result<()> {
         log_success!("Starting Workflow CLI initialization...\n");
 
         // 加载现有配置（从 TOML 文件）
         let existing_config = Self::load_existing_config()?;
 
         // 收集配置信息（智能处理现有配置）
-        let config = Self::collect_config(&existing_config)?;
+        let mut config = Self::collect_config(&existing_config, verify_credentials)?;
+        config.commit_sign = Self::collect_commit_signing(existing_config.commit_sign)?;
 
+        Self::save_and_verify(&config)
+    }
+
+    /// 运行非交互式初始化设置流程
+    ///
+    /// 从环境变量（如 `WORKFLOW_JIRA_EMAIL`）和可选的配置值文件读取配置，环境变量优先。
+    /// 缺少必填配置项或配置值格式错误时不提示输入，直接返回列出所有问题的错误。
+    ///
+    /// # 参数
+    ///
+    /// * `answers_file` - 配置值文件路径（可选，TOML 格式）
+    pub fn run_non_interactive(answers_file: Option<&Path>) -> Result<()> {
+        log_success!("Starting Workflow CLI initialization (non-interactive)...\n");
+
+        let file_answers = match answers_file {
+            Some(path) => SetupAnswers::from_file(path)?,
+            None => SetupAnswers::default(),
+        };
+        let answers = file_answers.merge(SetupAnswers::from_env());
+        answers.validate(&Settings::load())?;
+
+        let existing_config = Self::load_existing_config()?;
+        let (github_accounts, github_current) =
+            Self::apply_github_answers(&existing_config, &answers)?;
+        let form_result = answers.to_form_result(&existing_config.llm_provider);
+        let config = Self::apply_form_result(
+            &existing_config,
+            github_accounts,
+            github_current,
+            &form_result,
+        )?;
+
+        Self::save_and_verify(&config)
+    }
+
+    /// 保存配置并验证
+    fn save_and_verify(config: &CollectedConfig) -> Result<()> {
         // 保存配置到 TOML 文件
         log_message!("Saving configuration...");
-        Self::save_config(&config)?;
-        if let Ok(config_path) = crate::base::Paths::workflow_config() {
+        Self::save_config(config)?;
+        if let Ok(config_path) = crate::base::Paths::active_profile_config() {
             log_success!("{} {}", log::CONFIG_SAVED_PREFIX, config_path.display());
         } else {
             log_success!(
@@ -102,15 +165,18 @@ impl SetupCommand {
 
     /// 加载现有配置（从 TOML 文件）
     fn load_existing_config() -> Result<CollectedConfig> {
-        let settings = Settings::get();
+        // 使用 load() 只读取配置文件，避免将环境变量覆盖的值写回配置文件
+        let settings = Settings::load();
         let llm = &settings.llm;
 
         Ok(CollectedConfig {
             jira_email: settings.jira.email.clone(),
             jira_api_token: settings.jira.api_token.clone(),
             jira_service_address: settings.jira.service_address.clone(),
+            jira_auth_type: settings.jira.auth_type.clone(),
             github_accounts: settings.github.accounts.clone(),
             github_current: settings.github.current.clone(),
+            azure_devops_api_token: settings.azure_devops.api_token.clone(),
             log_output_folder_name: settings.log.output_folder_name.clone(),
             log_download_base_dir: settings.log.download_base_dir.clone(),
             enable_trace_console: settings.log.enable_trace_console,
@@ -127,11 +193,40 @@ impl SetupCommand {
             llm_proxy_url: llm.proxy.url.clone(),
             llm_proxy_key: llm.proxy.key.clone(),
             llm_proxy_model: llm.proxy.model.clone(),
+            commit_sign: settings.commit.sign,
         })
     }
 
+    /// 验证 Jira 凭据是否有效
+    ///
+    /// 使用 `GET /rest/api/2/myself` 发起一次认证请求，认证失败或请求失败时返回错误消息（已隐藏 token）。
+    /// `auth_type` 为 `Bearer` 时不使用邮箱。
+    pub fn verify_jira_credentials(
+        service_address: &str,
+        auth_type: JiraAuthType,
+        email: &str,
+        api_token: &str,
+    ) -> std::result::Result<(), String> {
+        let probe = match auth_type {
+            JiraAuthType::Basic => ApiProbe::jira(service_address, email, api_token),
+            JiraAuthType::Bearer => ApiProbe::jira_bearer(service_address, api_token),
+        };
+        let result = probe.run();
+        if result.state == ApiCheckState::Authenticated {
+            Ok(())
+        } else {
+            Err(format!(
+                "Failed to verify Jira credentials: {} (re-enter the token, or run `workflow setup --skip-verify` to skip verification)",
+                result.summary()
+            ))
+        }
+    }
+
     /// 收集配置信息
-    fn collect_config(existing: &CollectedConfig) -> Result<CollectedConfig> {
+    fn collect_config(
+        existing: &CollectedConfig,
+        verify_credentials: bool,
+    ) -> Result<CollectedConfig> {
         // ==================== 必填项：GitHub 配置 ====================
         log_break!();
         log_message!("  GitHub Configuration (Required)");
@@ -239,6 +334,7 @@ impl SetupCommand {
         let has_jira_email = existing.jira_email.is_some();
         let has_jira_address = existing.jira_service_address.is_some();
         let has_jira_token = existing.jira_api_token.is_some();
+        let has_azure_devops_token = existing.azure_devops_api_token.is_some();
         let default_folder_name = LogSettings::default_log_folder();
         let is_custom_folder_name = existing
             .log_output_folder_name
@@ -253,14 +349,36 @@ impl SetupCommand {
             .unwrap_or(false);
         let current_trace_console = existing.enable_trace_console.unwrap_or(false);
 
+        // Jira 凭据验证时，留空的字段使用现有值
+        let existing_jira_email = existing.jira_email.clone();
+        let existing_jira_address = existing.jira_service_address.clone();
+        let existing_jira_token = existing.jira_api_token.clone();
+        let current_jira_auth_type: JiraAuthType = existing
+            .jira_auth_type
+            .as_deref()
+            .and_then(|t| t.parse().ok())
+            .unwrap_or_default();
+
         // 使用 FormBuilder 收集所有配置
         let form_result = FormBuilder::new()
-            // Group 1: Jira Configuration (必填组)
+            .skip_async_validation(!verify_credentials)
+            // Group 1: Jira Configuration (可选组，跳过时保留现有值)
             .add_group(
                 "jira",
                 |g| {
                     g.step(|f| {
-                        // Jira email
+                        // Jira auth type
+                        let auth_types: Vec<String> =
+                            JiraAuthType::ALL.iter().map(|t| t.to_string()).collect();
+                        f.add_selection(
+                            "jira_auth_type",
+                            "Jira auth type (basic: email + API token, bearer: personal access token / OAuth token)",
+                            auth_types,
+                        )
+                        .default(current_jira_auth_type.to_string())
+                    })
+                    .step_if("jira_auth_type", JiraAuthType::Basic.as_str(), |f| {
+                        // Jira email（只有 Basic 认证需要）
                         let jira_email_prompt = if has_jira_email {
                             "Jira email address (press Enter to keep)"
                         } else {
@@ -320,28 +438,69 @@ impl SetupCommand {
                     .step(|f| {
                         // Jira API token
                         let jira_token_prompt = if has_jira_token {
-                            "Jira API token [current: ***] (press Enter to keep)"
+                            "Jira API token or personal access token [current: ***] (press Enter to keep)"
                         } else {
-                            "Jira API token (required)"
+                            "Jira API token or personal access token (required)"
                         };
-                        let mut field = f.add_text("jira_api_token", jira_token_prompt);
+                        let mut field = f.add_password("jira_api_token", jira_token_prompt);
                         if has_jira_token {
                             field = field.allow_empty(true);
                         } else {
                             field = field.required();
                         }
-                        field.validate(move |input: &str| {
-                            if input.is_empty() && !has_jira_token {
-                                Err("Jira API token is required".to_string())
-                            } else {
-                                Ok(())
-                            }
-                        })
+                        field
+                            .validate(move |input: &str| {
+                                if input.is_empty() && !has_jira_token {
+                                    Err("Jira API token is required".to_string())
+                                } else {
+                                    Ok(())
+                                }
+                            })
+                            .validate_async(
+                                "Verifying Jira credentials...",
+                                move |input: &str, answers: &FormResult| {
+                                    let address = value_or_existing(
+                                        answers.get("jira_service_address").map(|v| v.as_str()),
+                                        &existing_jira_address,
+                                    );
+                                    let email = value_or_existing(
+                                        answers.get("jira_email").map(|v| v.as_str()),
+                                        &existing_jira_email,
+                                    );
+                                    let token =
+                                        value_or_existing(Some(input), &existing_jira_token);
+                                    let auth_type = answers
+                                        .get("jira_auth_type")
+                                        .and_then(|t| t.parse().ok())
+                                        .unwrap_or(current_jira_auth_type);
+                                    Self::verify_jira_credentials(
+                                        &address, auth_type, &email, &token,
+                                    )
+                                },
+                            )
                     })
                 },
-                GroupConfig::required().with_title("Jira Configuration (Required)"),
+                GroupConfig::optional()
+                    .with_title("Jira Configuration (Optional)")
+                    .with_default_enabled(has_jira_address || has_jira_token),
             )
-            // Group 2: Log Configuration (可选组)
+            // Group 2: Azure DevOps Configuration (可选组)
+            .add_group(
+                "azure_devops",
+                |g| {
+                    g.step(|f| {
+                        let azure_devops_token_prompt = if has_azure_devops_token {
+                            "Azure DevOps personal access token [current: ***] (press Enter to keep)"
+                        } else {
+                            "Azure DevOps personal access token (press Enter to skip)"
+                        };
+                        f.add_password("azure_devops_api_token", azure_devops_token_prompt)
+                            .allow_empty(true)
+                    })
+                },
+                GroupConfig::optional().with_title("Azure DevOps Configuration (Optional)"),
+            )
+            // Group 3: Log Configuration (可选组)
             .add_group(
                 "log",
                 |g| {
@@ -403,8 +562,8 @@ impl SetupCommand {
                     .step(|f| {
                         // Tracing Console Output
                         let trace_console_options = vec![
-                            "Enable (output to both file and console)".to_string(),
-                            "Disable (output to file only)".to_string(),
+                            TRACE_CONSOLE_ENABLE.to_string(),
+                            TRACE_CONSOLE_DISABLE.to_string(),
                         ];
                         let default_option = if current_trace_console {
                             trace_console_options[0].clone()
@@ -421,7 +580,7 @@ impl SetupCommand {
                 },
                 GroupConfig::optional().with_title("Log Configuration (Optional)"),
             )
-            // Group 3: LLM Configuration (可选组)
+            // Group 4: LLM Configuration (可选组)
             .add_group(
                 "llm",
                 |g| {
@@ -491,22 +650,18 @@ impl SetupCommand {
                             .default(existing.llm_provider.clone())
                     })
                     .step_if("llm_provider", "openai", |f| {
-                        let mut form =
-                            f.add_text("llm_openai_key", openai_key_prompt).allow_empty(true);
-                        if let Some(ref key) = existing.llm_openai_key {
-                            form = form.default(key.clone());
-                        }
-                        form.add_text("llm_openai_model", openai_model_prompt)
+                        // 不设置默认值，按 Enter 保留现有 key
+                        f.add_password("llm_openai_key", openai_key_prompt)
+                            .allow_empty(true)
+                            .add_text("llm_openai_model", openai_model_prompt)
                             .allow_empty(true)
                             .default(openai_model_default)
                     })
                     .step_if("llm_provider", "deepseek", |f| {
-                        let mut form =
-                            f.add_text("llm_deepseek_key", deepseek_key_prompt).allow_empty(true);
-                        if let Some(ref key) = existing.llm_deepseek_key {
-                            form = form.default(key.clone());
-                        }
-                        form.add_text("llm_deepseek_model", deepseek_model_prompt)
+                        // 不设置默认值，按 Enter 保留现有 key
+                        f.add_password("llm_deepseek_key", deepseek_key_prompt)
+                            .allow_empty(true)
+                            .add_text("llm_deepseek_model", deepseek_model_prompt)
                             .allow_empty(true)
                             .default(deepseek_model_default)
                     })
@@ -528,7 +683,7 @@ impl SetupCommand {
                             }
                         });
 
-                        let mut form = form.add_text("llm_proxy_key", proxy_key_prompt);
+                        let mut form = form.add_password("llm_proxy_key", proxy_key_prompt);
                         if has_existing_proxy_key {
                             form = form.allow_empty(true);
                         } else {
@@ -561,20 +716,16 @@ impl SetupCommand {
                     })
                     .step(|f| {
                         // LLM output language (所有 provider 共享)
-                        let language_display_names = get_supported_language_display_names();
+                        // 支持的语言之后追加自动检测选项（auto）
+                        let language_display_names = get_output_language_display_names();
                         let current_language = if !existing.llm_language.is_empty() {
                             existing.llm_language.as_str()
                         } else {
                             "en" // 默认英文
                         };
-                        let current_idx = SUPPORTED_LANGUAGES
-                            .iter()
-                            .position(|lang| lang.code == current_language)
-                            .unwrap_or(0);
-                        let default_display_name = language_display_names
-                            .get(current_idx)
-                            .cloned()
-                            .unwrap_or_else(|| language_display_names[0].clone());
+                        let default_display_name =
+                            get_output_language_display_name(current_language)
+                                .unwrap_or_else(|| language_display_names[0].clone());
                         let llm_language_prompt =
                             format!("Select LLM output language [current: {}]", current_language);
                         f.add_selection(
@@ -590,41 +741,84 @@ impl SetupCommand {
             .run()
             .wrap_err("Failed to collect configuration")?;
 
-        // 处理结果：Jira 配置
-        let jira_email = if let Some(email) = form_result.get("jira_email") {
-            if !email.is_empty() {
-                Some(email.clone())
-            } else if has_jira_email {
-                existing.jira_email.clone()
-            } else {
-                color_eyre::eyre::bail!("Jira email address is required");
+        Self::apply_form_result(existing, github_accounts, github_current, &form_result)
+    }
+
+    /// 使用非交互式配置值更新 GitHub 账号
+    ///
+    /// 提供了完整的账号信息时，添加该账号（同名账号会被替换）并设为当前账号；
+    /// 否则保留现有账号。与交互式流程一致，同时更新 Git 全局用户配置。
+    fn apply_github_answers(
+        existing: &CollectedConfig,
+        answers: &SetupAnswers,
+    ) -> Result<(Vec<GitHubAccount>, Option<String>)> {
+        let mut github_accounts = existing.github_accounts.clone();
+        let mut github_current = existing.github_current.clone();
+
+        if let Some(account) = answers.github_account() {
+            github_accounts.retain(|a| a.name != account.name);
+            github_current = Some(account.name.clone());
+            github_accounts.push(account);
+        }
+
+        if let Some(ref current_name) = github_current {
+            if let Some(current_account) = github_accounts.iter().find(|a| &a.name == current_name)
+            {
+                let _ = GitConfig::set_global_user(&current_account.email, &current_account.name)?;
             }
-        } else {
-            color_eyre::eyre::bail!("Jira email address is required");
-        };
+        }
 
-        let jira_service_address = if let Some(address) = form_result.get("jira_service_address") {
-            if !address.is_empty() {
-                Some(address.clone())
-            } else if has_jira_address {
-                existing.jira_service_address.clone()
-            } else {
-                color_eyre::eyre::bail!("Jira service address is required");
+        Ok((github_accounts, github_current))
+    }
+
+    /// 处理表单结果，生成最终配置
+    ///
+    /// 交互式表单和非交互式配置值共用此逻辑；表单中留空或未出现的字段保留现有值。
+    fn apply_form_result(
+        existing: &CollectedConfig,
+        github_accounts: Vec<GitHubAccount>,
+        github_current: Option<String>,
+        form_result: &FormResult,
+    ) -> Result<CollectedConfig> {
+        let default_folder_name = LogSettings::default_log_folder();
+        let is_custom_folder_name = existing
+            .log_output_folder_name
+            .as_ref()
+            .map(|name| name != &default_folder_name)
+            .unwrap_or(false);
+        let default_dir = default_download_base_dir();
+        let is_custom_dir = existing
+            .log_download_base_dir
+            .as_ref()
+            .map(|dir| dir != &default_dir)
+            .unwrap_or(false);
+
+        // 处理结果：Jira 配置（Basic 认证不保存 auth_type，使用默认值）
+        // Jira 为可选组：跳过该组或字段留空时保留现有值，没有现有值时保持未配置
+        let jira_auth_type = match form_result.get("jira_auth_type") {
+            Some(auth_type) if !auth_type.is_empty() => {
+                let auth_type: JiraAuthType = auth_type.parse().map_err(|e: String| eyre!(e))?;
+                (auth_type == JiraAuthType::Bearer).then(|| auth_type.to_string())
             }
-        } else {
-            color_eyre::eyre::bail!("Jira service address is required");
+            _ => existing.jira_auth_type.clone(),
+        };
+        let jira_email = match form_result.get("jira_email") {
+            Some(email) if !email.is_empty() => Some(email.clone()),
+            _ => existing.jira_email.clone(),
+        };
+        let jira_service_address = match form_result.get("jira_service_address") {
+            Some(address) if !address.is_empty() => Some(address.clone()),
+            _ => existing.jira_service_address.clone(),
+        };
+        let jira_api_token = match form_result.get("jira_api_token") {
+            Some(token) if !token.is_empty() => Some(token.clone()),
+            _ => existing.jira_api_token.clone(),
         };
 
-        let jira_api_token = if let Some(token) = form_result.get("jira_api_token") {
-            if !token.is_empty() {
-                Some(token.clone())
-            } else if has_jira_token {
-                existing.jira_api_token.clone()
-            } else {
-                color_eyre::eyre::bail!("Jira API token is required");
-            }
-        } else {
-            color_eyre::eyre::bail!("Jira API token is required");
+        // 处理结果：Azure DevOps 配置（留空时保留现有值）
+        let azure_devops_api_token = match form_result.get("azure_devops_api_token") {
+            Some(token) if !token.is_empty() => Some(token.clone()),
+            _ => existing.azure_devops_api_token.clone(),
         };
 
         // 处理结果：Log 配置（包含 Tracing）
@@ -671,7 +865,7 @@ impl SetupCommand {
 
             // Tracing 配置
             let enable_trace_console = if let Some(mode) = form_result.get("trace_console_mode") {
-                if mode == "Enable (output to both file and console)" {
+                if mode == TRACE_CONSOLE_ENABLE {
                     Some(true)
                 } else {
                     None
@@ -833,8 +1027,10 @@ impl SetupCommand {
             jira_email,
             jira_api_token,
             jira_service_address,
+            jira_auth_type,
             github_accounts,
             github_current,
+            azure_devops_api_token,
             log_output_folder_name,
             log_download_base_dir,
             enable_trace_console,
@@ -847,28 +1043,93 @@ impl SetupCommand {
             llm_proxy_url,
             llm_proxy_key,
             llm_proxy_model,
+            commit_sign: existing.commit_sign,
         })
     }
 
+    /// 收集提交签名配置
+    ///
+    /// 启用签名时，通过 `GitConfig::set` 写入全局的 `gpg.format` 和 `user.signingkey`
+    /// （已配置的值作为默认值）。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `commit.sign` 的新值（未启用且之前未配置时保持 `None`）。
+    fn collect_commit_signing(existing: Option<bool>) -> Result<Option<bool>> {
+        log_break!();
+        log_message!("  Commit Signing (Optional)");
+        log_break!('─', 65);
+
+        let sign = ConfirmDialog::new("Sign commits created by Workflow (GPG or SSH)?")
+            .with_default(existing.unwrap_or(false))
+            .prompt()
+            .wrap_err("Failed to get commit signing choice")?;
+        if !sign {
+            return Ok(existing.map(|_| false));
+        }
+
+        let signing = GitConfig::get_signing_config();
+        let options = vec!["gpg".to_string(), "ssh".to_string()];
+        let default_index = usize::from(signing.format == GitSigningFormat::Ssh);
+        let format = SelectDialog::new("Select signing format", options)
+            .with_default(default_index)
+            .prompt()
+            .wrap_err("Failed to select signing format")?;
+        GitConfig::set(GitConfigScope::Global, "gpg.format", &format)?;
+
+        let key_prompt = if format == "ssh" {
+            "Signing key (path to SSH public key, e.g. ~/.ssh/id_ed25519.pub)"
+        } else {
+            "Signing key (GPG key ID)"
+        };
+        let mut key_input = InputDialog::new(key_prompt).with_validator(|input: &str| {
+            GitConfig::validate_value("user.signingkey", input)
+                .map(|_| ())
+                .map_err(|e| e.to_string())
+        });
+        if let Some(ref key) = signing.key {
+            key_input = key_input.with_default(key.clone());
+        }
+        let key = key_input.prompt().wrap_err("Failed to get signing key")?;
+        GitConfig::set(GitConfigScope::Global, "user.signingkey", &key)?;
+
+        log_success!("Git signing configured ({}, {})", format, key.trim());
+        Ok(Some(true))
+    }
+
     /// 保存配置到 TOML 文件
     fn save_config(config: &CollectedConfig) -> Result<()> {
+        // setup 不收集别名、日志级别、diff 预算、重试次数、日志隐藏格式、Jira 时区和 GitHub Enterprise 地址，保留现有配置
+        let existing = Settings::load();
+        let existing_llm = existing.llm;
+
         // 构建 Settings 结构体
         let settings = Settings {
-            aliases: HashMap::new(),
+            aliases: existing.aliases,
             jira: JiraSettings {
                 email: config.jira_email.clone(),
                 api_token: config.jira_api_token.clone(),
                 service_address: config.jira_service_address.clone(),
+                timezone: existing.jira.timezone,
+                sprint_field: existing.jira.sprint_field,
+                auth_type: config.jira_auth_type.clone(),
             },
             github: GitHubSettings {
                 accounts: config.github_accounts.clone(),
                 current: config.github_current.clone(),
+                api_base_url: existing.github.api_base_url,
+                web_base_url: existing.github.web_base_url,
+            },
+            azure_devops: AzureDevOpsSettings {
+                api_token: config.azure_devops_api_token.clone(),
             },
             log: LogSettings {
                 output_folder_name: config.log_output_folder_name.clone(),
                 download_base_dir: config.log_download_base_dir.clone(),
-                level: None, // 日志级别通过 workflow log set 命令设置
+                level: existing.log.level, // 日志级别通过 workflow log set 命令设置
                 enable_trace_console: config.enable_trace_console,
+                http_trace: existing.log.http_trace,
+                redact_patterns: existing.log.redact_patterns,
             },
             llm: crate::base::settings::settings::LLMSettings {
                 provider: config.llm_provider.clone(),
@@ -877,25 +1138,45 @@ impl SetupCommand {
                     url: None,
                     key: config.llm_openai_key.clone(),
                     model: config.llm_openai_model.clone(),
+                    max_diff_chars: existing_llm.openai.max_diff_chars,
                 },
                 deepseek: crate::base::settings::settings::LLMProviderSettings {
                     url: None,
                     key: config.llm_deepseek_key.clone(),
                     model: config.llm_deepseek_model.clone(),
+                    max_diff_chars: existing_llm.deepseek.max_diff_chars,
                 },
                 proxy: crate::base::settings::settings::LLMProviderSettings {
                     url: config.llm_proxy_url.clone(),
                     key: config.llm_proxy_key.clone(),
                     model: config.llm_proxy_model.clone(),
+                    max_diff_chars: existing_llm.proxy.max_diff_chars,
                 },
+                max_retries: existing_llm.max_retries,
             },
+            rollback: Settings::load().rollback,
+            commit: CommitSettings {
+                sign: config.commit_sign,
+            },
+            tag: Settings::load().tag,
+            prompt: Settings::load().prompt,
+            http: Settings::load().http,
+            check: Settings::load().check,
         };
 
-        // 保存 workflow.toml
-        let config_path = Paths::workflow_config()?;
-        let manager = ConfigManager::<Settings>::new(config_path);
-        manager.write(&settings)?;
+        // 保存到当前激活 Profile 的 workflow.toml
+        Settings::update_active_profile(|current| *current = settings)?;
 
         Ok(())
     }
 }
+
+/// 输入值为空时使用现有值（都没有时返回空字符串）
+fn value_or_existing(value: Option<&str>, existing: &Option<String>) -> String {
+    value
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(str::to_string)
+        .or_else(|| existing.clone())
+        .unwrap_or_default()
+}
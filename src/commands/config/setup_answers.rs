@@ -0,0 +1,325 @@
+//! 非交互式初始化设置的配置来源
+//!
+//! `workflow setup --non-interactive` 不提示输入，而是从以下来源读取配置值：
+//! - 环境变量：`WORKFLOW_` 加上大写的配置项名称（如 `WORKFLOW_JIRA_EMAIL`）
+//! - 配置值文件（`--answers <PATH>`）：扁平的 TOML 文件，键为配置项名称（如 `jira_email = "..."`）
+//!
+//! 同一配置项同时出现在两处时，环境变量优先。
+//!
+//! 配置值会转换为与交互式表单相同的 `FormResult`，交给同一套逻辑处理，
+//! 因此两种方式生成的配置完全一致。
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use color_eyre::{eyre::WrapErr, Result};
+use toml::Value;
+
+use super::setup::{TRACE_CONSOLE_DISABLE, TRACE_CONSOLE_ENABLE};
+use crate::base::dialog::FormResult;
+use crate::base::llm::{find_language, get_output_language_display_name, is_auto_language};
+use crate::base::settings::settings::{GitHubAccount, JiraAuthType, Settings};
+use crate::base::settings::validation::SUPPORTED_PROVIDERS;
+use crate::base::util::file::FileReader;
+
+/// 环境变量前缀
+pub const ANSWER_ENV_PREFIX: &str = "WORKFLOW_";
+
+/// 支持的配置项名称
+pub const ANSWER_KEYS: &[&str] = &[
+    "jira_auth_type",
+    "jira_email",
+    "jira_service_address",
+    "jira_api_token",
+    "github_name",
+    "github_email",
+    "github_api_token",
+    "azure_devops_api_token",
+    "log_output_folder_name",
+    "log_download_base_dir",
+    "enable_trace_console",
+    "llm_provider",
+    "llm_language",
+    "llm_openai_key",
+    "llm_openai_model",
+    "llm_deepseek_key",
+    "llm_deepseek_model",
+    "llm_proxy_url",
+    "llm_proxy_key",
+    "llm_proxy_model",
+];
+
+/// Jira 配置项（提供任一 Jira 配置值时必填）
+const JIRA_KEYS: &[&str] = &["jira_email", "jira_service_address", "jira_api_token"];
+
+/// GitHub 账号配置项（提供时必须完整）
+const GITHUB_KEYS: &[&str] = &["github_name", "github_email", "github_api_token"];
+
+/// LLM provider 配置项（只提供这些配置项而未指定 provider 时，使用当前 provider）
+const LLM_PROVIDER_KEYS: &[&str] = &[
+    "llm_openai_key",
+    "llm_openai_model",
+    "llm_deepseek_key",
+    "llm_deepseek_model",
+    "llm_proxy_url",
+    "llm_proxy_key",
+    "llm_proxy_model",
+];
+
+/// 非交互式初始化设置的配置值
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SetupAnswers {
+    values: HashMap<String, String>,
+}
+
+impl SetupAnswers {
+    /// 配置项对应的环境变量名称（如 `jira_email` → `WORKFLOW_JIRA_EMAIL`）
+    pub fn env_var(key: &str) -> String {
+        format!("{}{}", ANSWER_ENV_PREFIX, key.to_uppercase())
+    }
+
+    /// 从环境变量读取配置值
+    pub fn from_env() -> Self {
+        Self::from_env_with(|name| std::env::var(name).ok())
+    }
+
+    /// 使用指定的查找函数读取环境变量中的配置值
+    pub fn from_env_with(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let values = ANSWER_KEYS
+            .iter()
+            .filter_map(|key| lookup(&Self::env_var(key)).map(|value| (key.to_string(), value)))
+            .collect();
+        Self { values }
+    }
+
+    /// 从配置值文件读取
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = FileReader::new(path).to_string()?;
+        Self::from_toml(&content)
+            .wrap_err_with(|| format!("Failed to parse answers file: {}", path.display()))
+    }
+
+    /// 从 TOML 内容读取
+    ///
+    /// 值可以是字符串、布尔值或整数；不支持的配置项名称会报错，避免拼写错误被静默忽略。
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let table: toml::Table = toml::from_str(content)?;
+        let mut values = HashMap::new();
+        for (key, value) in table {
+            if !ANSWER_KEYS.contains(&key.as_str()) {
+                color_eyre::eyre::bail!("Unknown setup answer: '{}'", key);
+            }
+            let value = match value {
+                Value::String(s) => s,
+                Value::Boolean(b) => b.to_string(),
+                Value::Integer(i) => i.to_string(),
+                _ => color_eyre::eyre::bail!("Unsupported value type for '{}'", key),
+            };
+            values.insert(key, value);
+        }
+        Ok(Self { values })
+    }
+
+    /// 合并配置值，`other` 中的值优先
+    pub fn merge(mut self, other: Self) -> Self {
+        self.values.extend(other.values);
+        self
+    }
+
+    /// 获取配置值（空字符串视为未提供）
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.values.get(key).map(|v| v.trim()).filter(|v| !v.is_empty())
+    }
+
+    /// 获取配置值中的 GitHub 账号（未提供时返回 `None`）
+    pub fn github_account(&self) -> Option<GitHubAccount> {
+        Some(GitHubAccount {
+            name: self.get("github_name")?.to_string(),
+            email: self.get("github_email")?.to_string(),
+            api_token: self.get("github_api_token")?.to_string(),
+            login: None,
+        })
+    }
+
+    /// 检查缺失的必填配置项
+    ///
+    /// 现有配置中已有值的配置项不视为缺失：
+    /// - Jira：可选；提供了任一 Jira 配置值时，需要邮箱、服务地址和 API token（认证方式为 `bearer` 时不需要邮箱）
+    /// - GitHub：没有已配置的账号，或提供了部分账号信息时，需要完整的账号信息
+    /// - LLM：provider 为 `proxy` 时需要 URL、key 和模型
+    pub fn missing_required(&self, existing: &Settings) -> Vec<&'static str> {
+        let mut missing = Vec::new();
+
+        let jira_existing = [
+            &existing.jira.email,
+            &existing.jira.service_address,
+            &existing.jira.api_token,
+        ];
+        let is_bearer = self
+            .get("jira_auth_type")
+            .and_then(|t| t.parse().ok())
+            .unwrap_or_else(|| existing.jira.auth_type())
+            == JiraAuthType::Bearer;
+        let jira_partial =
+            JIRA_KEYS.iter().chain(&["jira_auth_type"]).any(|key| self.get(key).is_some());
+        for (key, value) in JIRA_KEYS.iter().zip(jira_existing) {
+            if !jira_partial || (is_bearer && *key == "jira_email") {
+                continue;
+            }
+            if self.get(key).is_none() && value.is_none() {
+                missing.push(*key);
+            }
+        }
+
+        let github_partial = GITHUB_KEYS.iter().any(|key| self.get(key).is_some());
+        if github_partial || existing.github.accounts.is_empty() {
+            missing.extend(GITHUB_KEYS.iter().filter(|key| self.get(key).is_none()));
+        }
+
+        let provider = self.get("llm_provider").unwrap_or(&existing.llm.provider);
+        if provider == "proxy" {
+            let proxy_existing = [
+                &existing.llm.proxy.url,
+                &existing.llm.proxy.key,
+                &existing.llm.proxy.model,
+            ];
+            let proxy_keys = ["llm_proxy_url", "llm_proxy_key", "llm_proxy_model"];
+            for (key, value) in proxy_keys.into_iter().zip(proxy_existing) {
+                if self.get(key).is_none() && value.is_none() {
+                    missing.push(key);
+                }
+            }
+        }
+
+        missing
+    }
+
+    /// 检查配置值的格式（与交互式表单的校验一致）
+    pub fn invalid_values(&self) -> Vec<String> {
+        let mut invalid = Vec::new();
+
+        for key in ["jira_email", "github_email"] {
+            if let Some(email) = self.get(key) {
+                if !email.contains('@') {
+                    invalid.push(format!("{}: invalid email address '{}'", key, email));
+                }
+            }
+        }
+        if let Some(auth_type) = self.get("jira_auth_type") {
+            if let Err(e) = auth_type.parse::<JiraAuthType>() {
+                invalid.push(format!("jira_auth_type: {}", e));
+            }
+        }
+        if let Some(address) = self.get("jira_service_address") {
+            if !address.starts_with("http://") && !address.starts_with("https://") {
+                invalid.push(format!(
+                    "jira_service_address: must start with http:// or https:// ('{}')",
+                    address
+                ));
+            }
+        }
+        if let Some(provider) = self.get("llm_provider") {
+            if !SUPPORTED_PROVIDERS.contains(&provider) {
+                invalid.push(format!(
+                    "llm_provider: unsupported provider '{}' (supported: {})",
+                    provider,
+                    SUPPORTED_PROVIDERS.join(", ")
+                ));
+            }
+        }
+        if let Some(language) = self.get("llm_language") {
+            if find_language(language).is_none() && !is_auto_language(language) {
+                invalid.push(format!("llm_language: unsupported language '{}'", language));
+            }
+        }
+        if let Some(value) = self.get("enable_trace_console") {
+            if value.parse::<bool>().is_err() {
+                invalid.push(format!(
+                    "enable_trace_console: expected true or false, got '{}'",
+                    value
+                ));
+            }
+        }
+
+        invalid
+    }
+
+    /// 校验配置值
+    ///
+    /// 存在缺失的必填配置项或格式错误时返回错误，错误信息中列出所有问题及对应的环境变量。
+    pub fn validate(&self, existing: &Settings) -> Result<()> {
+        let missing = self.missing_required(existing);
+        let invalid = self.invalid_values();
+        if missing.is_empty() && invalid.is_empty() {
+            return Ok(());
+        }
+
+        let mut lines = Vec::new();
+        if !missing.is_empty() {
+            lines.push("Missing required values:".to_string());
+            lines.extend(
+                missing.iter().map(|key| format!("  {} (env: {})", key, Self::env_var(key))),
+            );
+        }
+        if !invalid.is_empty() {
+            lines.push("Invalid values:".to_string());
+            lines.extend(invalid.iter().map(|issue| format!("  {}", issue)));
+        }
+        color_eyre::eyre::bail!("Non-interactive setup failed\n{}", lines.join("\n"))
+    }
+
+    /// 转换为交互式表单的 `FormResult`
+    ///
+    /// 只包含提供的配置值，未提供的配置组保持现有配置不变。
+    ///
+    /// # 参数
+    ///
+    /// * `current_provider` - 当前 LLM provider（只提供了 provider 配置项时使用）
+    pub fn to_form_result(&self, current_provider: &str) -> FormResult {
+        let mut values = HashMap::new();
+        let mut set = |name: &str, value: &str| {
+            values.insert(name.to_string(), value.to_string());
+        };
+
+        for key in JIRA_KEYS
+            .iter()
+            .chain(&["jira_auth_type", "azure_devops_api_token"])
+            .chain(LLM_PROVIDER_KEYS)
+        {
+            if let Some(value) = self.get(key) {
+                set(key, value);
+            }
+        }
+
+        if let Some(folder_name) = self.get("log_output_folder_name") {
+            set("should_configure_log_folder", "yes");
+            set("log_output_folder_name", folder_name);
+        }
+        if let Some(base_dir) = self.get("log_download_base_dir") {
+            set("should_configure_doc_dir", "yes");
+            set("log_download_base_dir", base_dir);
+        }
+        if let Some(enabled) = self.get("enable_trace_console") {
+            let mode = if enabled == "true" {
+                TRACE_CONSOLE_ENABLE
+            } else {
+                TRACE_CONSOLE_DISABLE
+            };
+            set("trace_console_mode", mode);
+        }
+
+        if let Some(provider) = self.get("llm_provider") {
+            set("llm_provider", provider);
+        } else if LLM_PROVIDER_KEYS.iter().any(|key| self.get(key).is_some()) {
+            set("llm_provider", current_provider);
+        }
+
+        if let Some(display_name) =
+            self.get("llm_language").and_then(get_output_language_display_name)
+        {
+            set("llm_language_display", &display_name);
+        }
+
+        FormResult { values }
+    }
+}
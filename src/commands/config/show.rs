@@ -1,8 +1,9 @@
 //! 配置查看命令
-//! 显示当前的 TOML 配置文件
+//! 显示当前生效的配置（TOML 配置文件 + 环境变量覆盖）
 
 use crate::base::indicator::Spinner;
 use crate::base::settings::paths::Paths;
+use crate::base::settings::profile::Profile;
 use crate::base::settings::settings::Settings;
 use crate::base::settings::table::{GitHubAccountRow, JiraConfigRow, LLMConfigRow};
 use crate::base::table::{TableBuilder, TableStyle};
@@ -13,7 +14,7 @@ use color_eyre::{eyre::eyre, Result};
 pub struct ConfigCommand;
 
 impl ConfigCommand {
-    /// 显示当前配置（从 TOML 文件读取）
+    /// 显示当前配置（从 TOML 文件读取，并标注来自环境变量的配置项）
     pub fn show() -> Result<()> {
         log_break!('=', 40, "Current Configuration");
         log_break!();
@@ -22,15 +23,34 @@ impl ConfigCommand {
         let workflow_config_path =
             Paths::workflow_config().map_err(|_| eyre!("Failed to get workflow config path"))?;
 
-        log_info!("Workflow config: {:?}\n", workflow_config_path);
+        log_info!("Workflow config: {:?}", workflow_config_path);
+
+        // 显示当前激活的 Profile
+        let profile = Profile::active();
+        if Profile::is_default(&profile) {
+            log_info!("Active profile: {}\n", profile);
+        } else {
+            let profile_config_path = Paths::profile_config(&profile)
+                .map_err(|_| eyre!("Failed to get profile config path"))?;
+            log_info!("Active profile: {} ({:?})\n", profile, profile_config_path);
+        }
 
         // 检查配置文件权限
         if let Some(warning) = Settings::check_permissions() {
             log_warning!("{}", warning);
         }
 
-        // 从 TOML 文件加载配置（使用 load() 获取最新配置，避免 OnceLock 缓存问题）
-        let settings = Settings::load();
+        // 从 TOML 文件加载配置并应用环境变量覆盖（不使用 get()，避免 OnceLock 缓存问题）
+        let (settings, overrides) = Settings::load_with_env_overrides();
+
+        // 显示来自环境变量的配置项
+        if !overrides.is_empty() {
+            log_info!("Environment overrides (take precedence over the config file):");
+            for item in &overrides {
+                log_message!("  {} <- {}", item.key, item.var);
+            }
+            log_break!();
+        }
 
         // 检查是否有配置
         if Self::is_empty_config(&settings) {
@@ -84,7 +104,12 @@ impl ConfigCommand {
             log_info!("Verifying Jira configuration...");
             if let Some(ref config) = result.jira.config {
                 let config_rows = vec![JiraConfigRow {
-                    email: config.email.clone(),
+                    email: if config.email.is_empty() {
+                        "-".to_string()
+                    } else {
+                        config.email.clone()
+                    },
+                    auth_type: config.auth_type.to_string(),
                     service_address: config.service_address.clone(),
                     api_token: config.api_token.clone(),
                 }];
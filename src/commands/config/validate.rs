@@ -1,5 +1,5 @@
 //! 配置验证命令
-//! 验证配置文件的完整性和有效性
+//! 验证配置文件的完整性和有效性（校验规则由 `Settings::validate()` 提供）
 
 // 标准库导入
 use std::fs;
@@ -13,6 +13,7 @@ use color_eyre::{eyre::eyre, eyre::WrapErr, Result};
 // 项目内部导入
 use crate::base::settings::paths::Paths;
 use crate::base::settings::settings::Settings;
+use crate::base::settings::{ConfigIssue, IssueSeverity};
 use crate::base::util::date::get_unix_timestamp;
 use crate::base::util::file::{FileReader, FileWriter};
 use crate::commands::config::helpers::parse_config;
@@ -24,7 +25,6 @@ pub struct ValidationError {
     pub field: String,
     pub message: String,
     pub fixable: bool,
-    pub fix_suggestion: Option<String>,
 }
 
 /// 配置验证警告
@@ -32,6 +32,7 @@ pub struct ValidationError {
 pub struct ValidationWarning {
     pub field: String,
     pub message: String,
+    pub fixable: bool,
 }
 
 /// 配置验证结果
@@ -41,17 +42,57 @@ pub struct ValidationResult {
     pub warnings: Vec<ValidationWarning>,
 }
 
+impl ValidationResult {
+    /// 是否有可以自动修复的问题
+    fn has_fixable(&self) -> bool {
+        self.errors.iter().any(|e| e.fixable) || self.warnings.iter().any(|w| w.fixable)
+    }
+}
+
+impl From<Vec<ConfigIssue>> for ValidationResult {
+    /// 按严重程度拆分 `Settings::validate()` 返回的问题
+    fn from(issues: Vec<ConfigIssue>) -> Self {
+        let mut result = Self {
+            errors: Vec::new(),
+            warnings: Vec::new(),
+        };
+        for issue in issues {
+            let fixable = ConfigValidateCommand::is_fixable(&issue);
+            match issue.severity {
+                IssueSeverity::Error => result.errors.push(ValidationError {
+                    field: issue.field,
+                    message: issue.message,
+                    fixable,
+                }),
+                IssueSeverity::Warning => result.warnings.push(ValidationWarning {
+                    field: issue.field,
+                    message: issue.message,
+                    fixable,
+                }),
+            }
+        }
+        result
+    }
+}
+
 /// 配置验证命令
+///
+/// 校验规则统一由 `Settings::validate()` 提供（与 `workflow check`、启动时校验和导入使用同一套规则），
+/// 本命令负责选择要校验的配置、输出结果和自动修复。
 pub struct ConfigValidateCommand;
 
 impl ConfigValidateCommand {
     /// 验证配置文件
+    ///
+    /// 未指定配置文件时校验当前生效的配置（基础配置、当前激活的 Profile 和环境变量覆盖），
+    /// 与启动时校验的结果一致；`--fix` 修改基础配置文件。
     pub fn validate(config_path: Option<String>, fix: bool, strict: bool) -> Result<()> {
         // 确定要验证的配置文件路径
         let default_path =
             Paths::workflow_config().wrap_err("Failed to get workflow config path")?;
+        let explicit = config_path.is_some();
         let config_path = if let Some(path) = config_path {
-            std::path::PathBuf::from(path)
+            PathBuf::from(path)
         } else {
             default_path.clone()
         };
@@ -64,22 +105,17 @@ impl ConfigValidateCommand {
             return Ok(());
         }
 
-        // 读取配置文件内容
-        let content = FileReader::new(&config_path).to_string()?;
-
-        // 解析配置文件（支持 TOML、JSON、YAML）
-        let mut settings = parse_config(&content, &config_path)?;
-
         // 执行验证
-        let mut result = Self::validate_config(&settings, &config_path)?;
+        let mut result = Self::validate_path(&config_path, explicit)?;
 
         // 如果有修复选项，尝试自动修复
-        if fix && !result.errors.is_empty() {
-            let fixed_count = Self::attempt_fixes(&mut result, &config_path, &mut settings)?;
+        if fix && result.has_fixable() {
+            let content = FileReader::new(&config_path).to_string()?;
+            let mut settings = parse_config(&content, &config_path)?;
+            let fixed_count = Self::attempt_fixes(&config_path, &mut settings)?;
             if fixed_count > 0 {
-                log_warning!("Found {} issue(s), fixed automatically:", fixed_count);
-                // 重新验证以获取更新后的错误列表
-                result = Self::validate_config(&settings, &config_path)?;
+                // 重新验证以获取更新后的问题列表
+                result = Self::validate_path(&config_path, explicit)?;
             }
         }
 
@@ -88,7 +124,7 @@ impl ConfigValidateCommand {
 
         // 如果有错误（或在严格模式下有警告），返回错误
         if !result.errors.is_empty() || (strict && !result.warnings.is_empty()) {
-            if !fix {
+            if !fix && result.has_fixable() {
                 log_info!("\nRun 'workflow config validate --fix' to attempt automatic fixes.");
             }
             std::process::exit(1);
@@ -103,295 +139,135 @@ impl ConfigValidateCommand {
         settings: &Settings,
         _config_path: &std::path::Path,
     ) -> Result<ValidationResult> {
-        let mut errors = Vec::new();
-        let mut warnings = Vec::new();
-
-        // 验证 JIRA 配置
-        if let (Some(ref email), Some(ref api_token), Some(ref service_address)) = (
-            &settings.jira.email,
-            &settings.jira.api_token,
-            &settings.jira.service_address,
-        ) {
-            // 验证邮箱格式
-            if !email.contains('@') {
-                errors.push(ValidationError {
-                    field: "jira.email".to_string(),
-                    message: format!("Invalid email format: '{}'", email),
-                    fixable: false,
-                    fix_suggestion: None,
-                });
-            }
-
-            // 验证 URL 格式
-            if !service_address.starts_with("http://") && !service_address.starts_with("https://") {
-                errors.push(ValidationError {
-                    field: "jira.service_address".to_string(),
-                    message: format!(
-                        "Invalid URL format: '{}' (must start with http:// or https://)",
-                        service_address
-                    ),
-                    fixable: true,
-                    fix_suggestion: Some(format!(
-                        "Updated 'jira.service_address' from '{}' to 'https://{}'",
-                        service_address,
-                        service_address
-                            .trim_start_matches("http://")
-                            .trim_start_matches("https://")
-                    )),
-                });
-            }
-
-            // 验证 API token 不为空
-            if api_token.is_empty() {
-                errors.push(ValidationError {
-                    field: "jira.api_token".to_string(),
-                    message: "API token cannot be empty".to_string(),
-                    fixable: false,
-                    fix_suggestion: None,
-                });
-            }
-        }
-
-        // 验证 GitHub 配置
-        if !settings.github.accounts.is_empty() {
-            for (idx, account) in settings.github.accounts.iter().enumerate() {
-                // 验证账号名称不为空
-                if account.name.is_empty() {
-                    errors.push(ValidationError {
-                        field: format!("github.accounts[{}].name", idx),
-                        message: "Account name cannot be empty".to_string(),
-                        fixable: false,
-                        fix_suggestion: None,
-                    });
-                }
-
-                // 验证邮箱格式
-                if !account.email.contains('@') {
-                    errors.push(ValidationError {
-                        field: format!("github.accounts[{}].email", idx),
-                        message: format!("Invalid email format: '{}'", account.email),
-                        fixable: false,
-                        fix_suggestion: None,
-                    });
-                }
-
-                // 验证 API token 不为空
-                if account.api_token.is_empty() {
-                    errors.push(ValidationError {
-                        field: format!("github.accounts[{}].api_token", idx),
-                        message: "API token cannot be empty".to_string(),
-                        fixable: false,
-                        fix_suggestion: None,
-                    });
-                }
-            }
+        Ok(settings.validate().into())
+    }
 
-            // 检查 current 账号是否存在
-            if let Some(ref current) = settings.github.current {
-                if !settings.github.accounts.iter().any(|acc| acc.name == *current) {
-                    warnings.push(ValidationWarning {
-                        field: "github.current".to_string(),
-                        message: format!(
-                            "Current account '{}' does not exist in accounts list",
-                            current
-                        ),
-                    });
-                }
-            }
+    /// 校验配置文件
+    ///
+    /// 未显式指定文件时校验当前生效的配置（`Settings::validate_active()`）；
+    /// TOML 文件的语法和类型错误定位到具体配置键，JSON/YAML 文件解析后再校验。
+    fn validate_path(config_path: &Path, explicit: bool) -> Result<ValidationResult> {
+        if !explicit {
+            return Ok(Settings::validate_active().into());
         }
 
-        // 验证 LLM 配置
-        let valid_providers = ["openai", "deepseek", "proxy"];
-        if !valid_providers.contains(&settings.llm.provider.as_str()) {
-            errors.push(ValidationError {
-                field: "llm.provider".to_string(),
-                message: format!(
-                    "Invalid provider: '{}' (expected: {})",
-                    settings.llm.provider,
-                    valid_providers.join(", ")
-                ),
-                fixable: false,
-                fix_suggestion: None,
-            });
+        let extension = config_path
+            .extension()
+            .and_then(|s| s.to_str())
+            .unwrap_or("toml")
+            .to_lowercase();
+        if extension == "toml" {
+            return Ok(Settings::validate_file(config_path).into());
         }
 
-        // 验证当前 provider 的配置
-        match settings.llm.provider.as_str() {
-            "openai" => {
-                // OpenAI 需要 key（可选，但建议配置）
-                // model 可选，有默认值
-            }
-            "deepseek" => {
-                // DeepSeek 需要 key（可选，但建议配置）
-                // model 可选，有默认值
-            }
-            "proxy" => {
-                // Proxy 需要 URL
-                if settings.llm.proxy.url.is_none()
-                    || settings.llm.proxy.url.as_ref().map(|s| s.is_empty()).unwrap_or(true)
-                {
-                    errors.push(ValidationError {
-                        field: "llm.proxy.url".to_string(),
-                        message: "LLM proxy URL is required when provider is 'proxy'".to_string(),
-                        fixable: false,
-                        fix_suggestion: None,
-                    });
-                }
+        let content = FileReader::new(config_path).to_string()?;
+        let settings = parse_config(&content, config_path)?;
+        Self::validate_config(&settings, config_path)
+    }
 
-                // Proxy 需要 model
-                if settings.llm.proxy.model.is_none() {
-                    errors.push(ValidationError {
-                        field: "llm.proxy.model".to_string(),
-                        message: "LLM model is required when provider is 'proxy'".to_string(),
-                        fixable: false,
-                        fix_suggestion: None,
-                    });
-                }
-            }
-            _ => {}
+    /// 尝试自动修复配置问题
+    ///
+    /// 修复前创建备份；保存失败时从备份恢复。
+    fn attempt_fixes(config_path: &Path, settings: &mut Settings) -> Result<usize> {
+        let fixes = Self::apply_fixes(settings);
+        if fixes.is_empty() {
+            return Ok(0);
         }
 
-        // 验证所有 provider 的 key（建议配置，但不强制）
-        // 这里只验证当前 provider 的 key，其他 provider 的 key 是可选的
-        let current = settings.llm.current_provider();
-        if current.key.is_none() || current.key.as_ref().map(|s| s.is_empty()).unwrap_or(true) {
-            warnings.push(ValidationWarning {
-                field: format!("llm.{}.key", settings.llm.provider),
-                message: format!(
-                    "LLM API key is not configured for provider '{}'. Some features may not work.",
-                    settings.llm.provider
-                ),
-            });
+        log_warning!("Found {} issue(s), fixed automatically:", fixes.len());
+        for fix in &fixes {
+            log_info!("  - Fixed: {}", fix);
         }
 
-        Ok(ValidationResult { errors, warnings })
-    }
-
-    /// 尝试自动修复配置错误
-    fn attempt_fixes(
-        result: &mut ValidationResult,
-        config_path: &Path,
-        settings: &mut Settings,
-    ) -> Result<usize> {
-        let mut fixed_count = 0;
-        let mut backup_created = false;
-        let mut backup_path = None;
-
         // 创建备份（如果配置文件存在）
-        if config_path.exists() {
-            match Self::create_backup(config_path) {
-                Ok(backup) => {
-                    backup_path = Some(backup.clone());
-                    backup_created = true;
-                    log_info!("Backup created: {:?}", backup);
-                }
-                Err(e) => {
-                    log_warning!("Failed to create backup: {}. Continuing without backup.", e);
-                }
+        let backup_path = match Self::create_backup(config_path) {
+            Ok(backup) => {
+                log_info!("Backup created: {:?}", backup);
+                Some(backup)
             }
-        }
-
-        // 修复可修复的错误
-        let fixable_errors: Vec<_> = result.errors.iter().filter(|e| e.fixable).cloned().collect();
-
-        for error in fixable_errors {
-            let fixed = Self::apply_fix(settings, &error)?;
-            if fixed {
-                fixed_count += 1;
-                if let Some(ref suggestion) = error.fix_suggestion {
-                    log_info!("  - Fixed: {}", suggestion);
-                } else {
-                    log_info!("  - Fixed: {}", error.field);
-                }
+            Err(e) => {
+                log_warning!("Failed to create backup: {}. Continuing without backup.", e);
+                None
             }
-        }
+        };
 
-        // 如果修复了错误，保存配置文件
-        if fixed_count > 0 {
-            match Self::save_config(settings, config_path) {
-                Ok(_) => {
-                    log_success!("Fixed {} issue(s) and saved configuration", fixed_count);
-                    // 删除备份（修复成功）
-                    if let Some(backup) = backup_path {
-                        fs::remove_file(&backup).ok();
-                    }
+        match Self::save_config(settings, config_path) {
+            Ok(_) => {
+                log_success!("Fixed {} issue(s) and saved configuration", fixes.len());
+                // 删除备份（修复成功）
+                if let Some(backup) = backup_path {
+                    fs::remove_file(&backup).ok();
                 }
-                Err(e) => {
-                    log_error!("Failed to save fixed configuration: {}", e);
-                    // 保存失败，尝试恢复备份
-                    if backup_created {
-                        if let Some(backup) = backup_path {
-                            if let Err(restore_err) =
-                                Self::restore_from_backup(&backup, config_path)
-                            {
-                                log_error!("Failed to restore from backup: {}", restore_err);
-                                log_error!("Backup file is available at: {:?}", backup);
-                            } else {
-                                log_success!("Restored original configuration from backup");
-                            }
-                        }
+                Ok(fixes.len())
+            }
+            Err(e) => {
+                log_error!("Failed to save fixed configuration: {}", e);
+                // 保存失败，尝试恢复备份
+                if let Some(backup) = backup_path {
+                    if let Err(restore_err) = Self::restore_from_backup(&backup, config_path) {
+                        log_error!("Failed to restore from backup: {}", restore_err);
+                        log_error!("Backup file is available at: {:?}", backup);
+                    } else {
+                        log_success!("Restored original configuration from backup");
                     }
-                    return Err(e.wrap_err("Failed to save fixed configuration"));
                 }
-            }
-        } else if backup_created {
-            // 如果没有修复任何错误，删除备份
-            if let Some(backup) = backup_path {
-                fs::remove_file(&backup).ok();
+                Err(e.wrap_err("Failed to save fixed configuration"))
             }
         }
-
-        // 从错误列表中移除已修复的错误
-        result.errors.retain(|e| !e.fixable || !Self::can_fix_error(e));
-
-        Ok(fixed_count)
     }
 
-    /// 应用修复
-    fn apply_fix(settings: &mut Settings, error: &ValidationError) -> Result<bool> {
-        match error.field.as_str() {
-            "jira.service_address" => Self::fix_jira_url(settings, error),
-            "github.current" => Self::fix_github_current(settings, error),
-            _ => Ok(false),
+    /// 检查问题是否可以自动修复
+    fn is_fixable(issue: &ConfigIssue) -> bool {
+        match issue.field.as_str() {
+            // 缺少协议的 Jira 地址可以补全为 https://
+            "jira.service_address" => issue.message.contains("must start with http:// or https://"),
+            "github.current" => true,
+            _ => false,
         }
     }
 
-    /// 检查是否可以修复错误
-    fn can_fix_error(error: &ValidationError) -> bool {
-        matches!(
-            error.field.as_str(),
-            "jira.service_address" | "github.current"
-        )
+    /// 修复配置中可以自动修复的问题
+    ///
+    /// # 返回
+    ///
+    /// 返回每个修复的说明。
+    fn apply_fixes(settings: &mut Settings) -> Vec<String> {
+        let mut fixes = Vec::new();
+        fixes.extend(Self::fix_jira_url(settings));
+        fixes.extend(Self::fix_github_current(settings));
+        fixes
     }
 
     /// 修复 JIRA URL 格式
-    fn fix_jira_url(settings: &mut Settings, _error: &ValidationError) -> Result<bool> {
-        if let Some(ref mut service_address) = settings.jira.service_address {
-            if !service_address.starts_with("http://") && !service_address.starts_with("https://") {
-                *service_address = format!("https://{}", service_address);
-                return Ok(true);
-            }
+    fn fix_jira_url(settings: &mut Settings) -> Option<String> {
+        let service_address = settings.jira.service_address.as_mut()?;
+        if service_address.trim().is_empty() || service_address.contains("://") {
+            return None;
         }
-        Ok(false)
+
+        let fixed = format!("https://{}", service_address);
+        let fix = format!(
+            "Updated 'jira.service_address' from '{}' to '{}'",
+            service_address, fixed
+        );
+        *service_address = fixed;
+        Some(fix)
     }
 
-    /// 修复 GitHub current 账号
-    fn fix_github_current(settings: &mut Settings, _error: &ValidationError) -> Result<bool> {
-        if let Some(ref current) = settings.github.current {
-            // 检查 current 账号是否存在
-            if !settings.github.accounts.iter().any(|acc| acc.name == *current) {
-                // 如果不存在，设置为第一个账号或清除
-                if let Some(first_account) = settings.github.accounts.first() {
-                    settings.github.current = Some(first_account.name.clone());
-                    return Ok(true);
-                } else {
-                    // 没有账号，清除 current
-                    settings.github.current = None;
-                    return Ok(true);
-                }
-            }
+    /// 修复 GitHub current 账号（不存在时设置为第一个账号，没有账号时清除）
+    fn fix_github_current(settings: &mut Settings) -> Option<String> {
+        let current = settings.github.current.as_ref()?;
+        if settings.github.accounts.iter().any(|acc| acc.name == *current) {
+            return None;
         }
-        Ok(false)
+
+        let fixed = settings.github.accounts.first().map(|account| account.name.clone());
+        let fix = match fixed {
+            Some(ref name) => format!("Updated 'github.current' from '{}' to '{}'", current, name),
+            None => format!("Removed 'github.current' ('{}' does not exist)", current),
+        };
+        settings.github.current = fixed;
+        Some(fix)
     }
 
     /// 创建备份
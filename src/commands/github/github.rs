@@ -3,18 +3,20 @@
 
 use crate::base::dialog::{ConfirmDialog, SelectDialog};
 use crate::base::indicator::Spinner;
-use crate::base::settings::paths::Paths;
 use crate::base::settings::settings::Settings;
-use crate::base::settings::table::GitHubAccountListRow;
+use crate::base::settings::table::{GitHubAccountListRow, GitHubRateLimitRow};
 use crate::base::table::{TableBuilder, TableStyle};
 use crate::base::util::mask_sensitive_value;
 use crate::commands::github::helpers::{
-    collect_github_account, collect_github_account_with_defaults,
+    collect_github_account, collect_github_account_with_defaults, verify_github_token,
 };
-use crate::git::GitConfig;
-use crate::jira::config::ConfigManager;
+use crate::git::{GitConfig, GitConfigScope, GitRepo};
+use crate::pr::github::rate_limit::{format_duration, format_reset};
+use crate::pr::GitHub;
 use crate::{log_break, log_info, log_message, log_success, log_warning};
+use chrono::Utc;
 use color_eyre::{eyre::eyre, eyre::WrapErr, Result};
+use std::time::Duration;
 
 /// GitHub 账号管理命令
 pub struct GitHubCommand;
@@ -107,6 +109,9 @@ impl GitHubCommand {
 
         if let Some(account) = github.get_current_account() {
             log_success!("Current account: {}", account.name);
+            if let Some(ref login) = account.login {
+                log_message!("  Login: {}", login);
+            }
             log_message!("  Email: {}", account.email);
             log_message!("  API Token: {}", mask_sensitive_value(&account.api_token));
         } else {
@@ -122,7 +127,7 @@ impl GitHubCommand {
         log_break!('=', 40, "Add GitHub Account");
         log_break!();
 
-        let account = collect_github_account()?;
+        let mut account = collect_github_account()?;
 
         // 先检查账号名称是否已存在
         let settings = Settings::load();
@@ -130,14 +135,15 @@ impl GitHubCommand {
             return Err(eyre!("Account with name '{}' already exists", account.name));
         }
 
+        // 校验 token，并保存 token 对应的登录名
+        verify_github_token(&mut account)?;
+        log_break!();
+
         let is_first_account = settings.github.accounts.is_empty();
         let account_name = account.name.clone();
         let account_email = account.email.clone();
 
-        let config_path = Paths::workflow_config()?;
-        let manager = ConfigManager::<Settings>::new(config_path);
-
-        manager.update(|settings| {
+        Settings::update_active_profile(|settings| {
             settings.github.accounts.push(account.clone());
 
             // 如果这是第一个账号，自动设置为当前账号
@@ -166,9 +172,7 @@ impl GitHubCommand {
             .prompt()?;
 
             if should_set_current {
-                let config_path = Paths::workflow_config()?;
-                let manager = ConfigManager::<Settings>::new(config_path);
-                manager.update(|settings| {
+                Settings::update_active_profile(|settings| {
                     settings.github.current = Some(account_name.clone());
                 })?;
                 let result = GitConfig::set_global_user(&account_email, &account_name)?;
@@ -236,10 +240,7 @@ impl GitHubCommand {
         let was_current =
             settings.github.current.as_ref().map(|c| c == &account_name).unwrap_or(false);
 
-        let config_path = Paths::workflow_config()?;
-        let manager = ConfigManager::<Settings>::new(config_path);
-
-        manager.update(|settings| {
+        Settings::update_active_profile(|settings| {
             settings.github.accounts.remove(selection);
 
             // 如果删除后还有账号
@@ -301,7 +302,14 @@ impl GitHubCommand {
     }
 
     /// 切换当前 GitHub 账号
-    pub fn switch() -> Result<()> {
+    ///
+    /// 同时更新 Git 用户身份（user.name/user.email）：默认更新当前仓库（local），
+    /// `global` 为 true 或不在 Git 仓库中时更新全局配置。切换回之前的账号时恢复切换前的身份。
+    ///
+    /// # 参数
+    ///
+    /// * `global` - 是否更新全局 Git 身份
+    pub fn switch(global: bool) -> Result<()> {
         log_break!('=', 40, "Switch GitHub Account");
         log_break!();
 
@@ -343,22 +351,90 @@ impl GitHubCommand {
         let account_name = account_names[selection].clone();
         let account = &settings.github.accounts[selection];
 
-        let config_path = Paths::workflow_config()?;
-        let manager = ConfigManager::<Settings>::new(config_path);
-
-        manager.update(|settings| {
+        Settings::update_active_profile(|settings| {
             settings.github.current = Some(account_name.clone());
         })?;
 
-        let result = GitConfig::set_global_user(&account.email, &account.name)?;
-        log_info!("Git global config updated:");
-        log_message!("  user.email: {}", result.email);
-        log_message!("  user.name: {}", result.name);
+        let scope = if global {
+            GitConfigScope::Global
+        } else if GitRepo::is_git_repo() {
+            GitConfigScope::Local
+        } else {
+            log_warning!("Not in a Git repository, updating the global git identity instead.");
+            GitConfigScope::Global
+        };
+
+        let result = GitConfig::switch_user(scope, &account.email, &account.name)?;
+        log_info!("Git {} identity updated:", result.scope.as_str());
+        log_message!("  Before: {}", result.before);
+        log_message!("  After:  {}", result.after);
+        if result.restored {
+            log_info!("Restored the identity used before switching accounts.");
+        }
         log_success!("Switched to account '{}'.", account_name);
 
         Ok(())
     }
 
+    /// 显示当前 GitHub 账号的 API 速率限制配额
+    ///
+    /// 列出各资源（core、search、graphql 等）的已用、剩余配额和重置时间，配额耗尽时给出警告。
+    pub fn rate_limit() -> Result<()> {
+        log_break!('=', 40, "GitHub API Rate Limit");
+        log_break!();
+
+        let settings = Settings::load();
+        let Some(account) = settings.github.get_current_account() else {
+            log_warning!("No GitHub account is currently active.");
+            log_message!("Run 'workflow github add' to add an account.");
+            return Ok(());
+        };
+        log_info!("Account: {}", account.name);
+
+        let rate_limit = Spinner::with("Fetching GitHub API rate limit...", GitHub::rate_limit)?;
+
+        let rows: Vec<GitHubRateLimitRow> = rate_limit
+            .resources
+            .iter()
+            .map(|(resource, quota)| GitHubRateLimitRow {
+                resource: resource.clone(),
+                used: quota.used.to_string(),
+                remaining: quota.remaining.to_string(),
+                limit: quota.limit.to_string(),
+                reset: format_reset(quota.reset),
+            })
+            .collect();
+
+        log_message!(
+            "{}",
+            TableBuilder::new(rows)
+                .with_title("GitHub API Rate Limit")
+                .with_style(TableStyle::Modern)
+                .render()
+        );
+
+        let now = Utc::now().timestamp();
+        let exhausted: Vec<_> = rate_limit
+            .resources
+            .iter()
+            .filter(|(_, quota)| quota.remaining == 0 && quota.limit > 0)
+            .collect();
+        if exhausted.is_empty() {
+            log_success!("No rate limit reached.");
+        }
+        for (resource, quota) in exhausted {
+            let reset_in = (quota.reset - now).max(0) as u64;
+            log_warning!(
+                "'{}' quota exhausted (primary rate limit); resets at {} (in {})",
+                resource,
+                format_reset(quota.reset),
+                format_duration(Duration::from_secs(reset_in))
+            );
+        }
+
+        Ok(())
+    }
+
     /// 更新 GitHub 账号信息
     pub fn update() -> Result<()> {
         log_break!('=', 40, "Update GitHub Account");
@@ -405,7 +481,7 @@ impl GitHubCommand {
         log_break!();
 
         // 收集新的账号信息（使用现有值作为默认值）
-        let new_account = collect_github_account_with_defaults(old_account)?;
+        let mut new_account = collect_github_account_with_defaults(old_account)?;
 
         // 如果账号名称改变了，检查新名称是否已存在（排除当前正在更新的账号）
         if new_account.name != old_account.name
@@ -422,6 +498,10 @@ impl GitHubCommand {
             ));
         }
 
+        // 校验 token，并保存 token 对应的登录名
+        verify_github_token(&mut new_account)?;
+        log_break!();
+
         // 检查这个账号是否是当前账号
         let is_current = settings
             .github
@@ -441,10 +521,7 @@ impl GitHubCommand {
         let new_account_name = new_account.name.clone();
         let new_account_email = new_account.email.clone();
 
-        let config_path = Paths::workflow_config()?;
-        let manager = ConfigManager::<Settings>::new(config_path);
-
-        manager.update(|settings| {
+        Settings::update_active_profile(|settings| {
             settings.github.accounts[selection] = new_account.clone();
 
             // 如果账号名称改变了，且这个账号是当前账号，需要更新 current 字段
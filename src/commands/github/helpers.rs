@@ -2,8 +2,14 @@
 //!
 //! 提供 GitHub 账号管理的共享逻辑，减少代码重复。
 
-use crate::base::dialog::InputDialog;
+use crate::base::dialog::{ConfirmDialog, InputDialog};
+use crate::base::http::HttpStatusError;
+use crate::base::indicator::Spinner;
 use crate::base::settings::settings::GitHubAccount;
+use crate::base::util::mask_sensitive_value;
+use crate::pr::github::REQUIRED_TOKEN_SCOPES;
+use crate::pr::GitHub;
+use crate::{log_message, log_success, log_warning};
 use color_eyre::{eyre::WrapErr, Result};
 
 /// 收集 GitHub 账号信息
@@ -46,7 +52,20 @@ pub fn collect_github_account() -> Result<GitHubAccount> {
         .prompt()
         .wrap_err("Failed to get GitHub account email")?;
 
+    let api_token = prompt_github_token()?;
+
+    Ok(GitHubAccount {
+        name: name.trim().to_string(),
+        email: email.trim().to_string(),
+        api_token,
+        login: None,
+    })
+}
+
+/// 输入 GitHub API token（必填，不回显）
+fn prompt_github_token() -> Result<String> {
     let api_token = InputDialog::new("GitHub API token (required)")
+        .secret(true)
         .with_validator(|input: &str| {
             if input.trim().is_empty() {
                 Err("GitHub API token is required and cannot be empty".to_string())
@@ -56,18 +75,84 @@ pub fn collect_github_account() -> Result<GitHubAccount> {
         })
         .prompt()
         .wrap_err("Failed to get GitHub API token")?;
+    Ok(api_token.trim().to_string())
+}
 
-    Ok(GitHubAccount {
-        name: name.trim().to_string(),
-        email: email.trim().to_string(),
-        api_token: api_token.trim().to_string(),
-    })
+/// 校验 GitHub 账号的 API token
+///
+/// 调用 GitHub `/user` 端点校验 token，显示认证的登录名和 token 权限范围，
+/// 缺少必需权限（`repo`、`read:org`）时给出警告。校验通过后将登录名保存到 `account.login`。
+///
+/// token 被拒绝（401）时提示重新输入；其他失败（如网络不可用）时询问是否跳过校验直接保存。
+/// 所有输出中的 token 均已脱敏。
+///
+/// # 参数
+///
+/// * `account` - 待校验的账号，重新输入的 token 和解析到的登录名会写回该账号
+///
+/// # 错误
+///
+/// 用户取消输入或拒绝保存未校验的账号时返回错误
+pub fn verify_github_token(account: &mut GitHubAccount) -> Result<()> {
+    loop {
+        let token = account.api_token.clone();
+        let result = Spinner::with("Verifying GitHub API token...", || {
+            GitHub::validate_token(&token)
+        });
+
+        match result {
+            Ok(info) => {
+                log_success!("Authenticated as '{}'.", info.user.login);
+                log_message!("  API Token: {}", mask_sensitive_value(&account.api_token));
+                match &info.scopes {
+                    Some(scopes) if scopes.is_empty() => {
+                        log_message!("  Scopes: (none)");
+                    }
+                    Some(scopes) => {
+                        log_message!("  Scopes: {}", scopes.join(", "));
+                    }
+                    None => {
+                        log_message!("  Scopes: (not reported, e.g. fine-grained token)");
+                    }
+                }
+
+                let missing = info.missing_scopes();
+                if !missing.is_empty() {
+                    log_warning!(
+                        "Token is missing required scope(s): {} (required: {})",
+                        missing.join(", "),
+                        REQUIRED_TOKEN_SCOPES.join(", ")
+                    );
+                }
+
+                account.login = Some(info.user.login);
+                return Ok(());
+            }
+            Err(e) if e.downcast_ref::<HttpStatusError>().is_some_and(|e| e.status == 401) => {
+                log_warning!("{}", e);
+                log_message!("  API Token: {}", mask_sensitive_value(&account.api_token));
+                log_message!("Please enter a valid token.");
+                account.api_token = prompt_github_token()?;
+            }
+            Err(e) => {
+                log_warning!("Failed to verify the GitHub API token: {}", e);
+                let save_anyway =
+                    ConfirmDialog::new("Save the account without verifying the token?")
+                        .with_default(false)
+                        .prompt()?;
+                if !save_anyway {
+                    return Err(e.wrap_err("GitHub API token verification failed"));
+                }
+                return Ok(());
+            }
+        }
+    }
 }
 
 /// 收集 GitHub 账号信息（使用现有值作为默认值）
 ///
 /// 与 `collect_github_account()` 类似，但使用 `old_account` 的值作为默认值，
-/// 用户可以直接按 Enter 保留现有值。
+/// 用户可以直接按 Enter 保留现有值。API token 不回显，留空时保留现有 token。
 ///
 /// # 参数
 ///
@@ -107,21 +192,29 @@ pub fn collect_github_account_with_defaults(old_account: &GitHubAccount) -> Resu
         .prompt()
         .wrap_err("Failed to get GitHub account email")?;
 
-    let api_token = InputDialog::new("GitHub API token (press Enter to keep)")
-        .with_default(old_account.api_token.clone())
-        .with_validator(|input: &str| {
-            if input.trim().is_empty() {
-                Err("GitHub API token is required and cannot be empty".to_string())
-            } else {
-                Ok(())
-            }
-        })
+    // token 不回显，按 Enter 保留现有 token
+    let api_token = InputDialog::new("GitHub API token [current: ***] (press Enter to keep)")
+        .secret(true)
+        .allow_empty(true)
         .prompt()
         .wrap_err("Failed to get GitHub API token")?;
+    let api_token = if api_token.trim().is_empty() {
+        old_account.api_token.clone()
+    } else {
+        api_token.trim().to_string()
+    };
+
+    // token 未变化时保留已解析的登录名
+    let login = if api_token == old_account.api_token {
+        old_account.login.clone()
+    } else {
+        None
+    };
 
     Ok(GitHubAccount {
         name: name.trim().to_string(),
         email: email.trim().to_string(),
-        api_token: api_token.trim().to_string(),
+        api_token,
+        login,
     })
 }
@@ -0,0 +1,47 @@
+//! JIRA 上传附件命令
+//!
+//! 将本地文件作为附件上传到 JIRA ticket（`workflow jira attach PROJ-123 build.log`）。
+
+use crate::base::format::DisplayFormatter;
+use crate::base::indicator::Spinner;
+use crate::jira::Jira;
+use crate::{log_info, log_success};
+use color_eyre::{
+    eyre::{ContextCompat, WrapErr},
+    Result,
+};
+use std::path::Path;
+
+/// 上传附件命令
+pub struct AttachCommand;
+
+impl AttachCommand {
+    /// 上传文件作为附件
+    ///
+    /// # 参数
+    ///
+    /// * `jira_id` - JIRA ticket ID
+    /// * `file` - 要上传的文件路径
+    pub fn attach(jira_id: &str, file: &Path) -> Result<()> {
+        if !file.is_file() {
+            color_eyre::eyre::bail!("File not found: {}", file.display());
+        }
+        let file_path = file
+            .to_str()
+            .wrap_err_with(|| format!("File path is not valid UTF-8: {}", file.display()))?;
+
+        let attachments = Spinner::with(
+            format!("Uploading {} to {}...", file.display(), jira_id),
+            || Jira::upload_attachment(jira_id, file_path),
+        )
+        .wrap_err_with(|| format!("Failed to attach {} to {}", file.display(), jira_id))?;
+
+        for attachment in &attachments {
+            let size = attachment.size.map(DisplayFormatter::size).unwrap_or_default();
+            log_success!("Attached {} ({}) to {}", attachment.filename, size, jira_id);
+            log_info!("  {}", attachment.content_url);
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,9 @@
-use crate::base::indicator::{Progress, Spinner};
-use crate::jira::logs::{JiraLogs, ProgressCallback};
+use crate::base::indicator::Spinner;
+use crate::jira::attachments::DownloadProgressDisplay;
+use crate::jira::logs::JiraLogs;
 use crate::jira::Jira;
 use crate::{log_break, log_info, log_success};
 use color_eyre::{eyre::WrapErr, Result};
-use std::sync::{Arc, Mutex};
 
 use super::helpers::get_jira_id;
 
@@ -31,28 +31,9 @@ impl AttachmentsCommand {
         log_info!("{} file(s) will be downloaded", total_files);
         log_break!();
 
-        // 创建 Progress Bar
-        let progress = Arc::new(Mutex::new(Progress::new(
-            total_files,
-            "Downloading attachments...",
-        )));
-        let progress_clone = progress.clone();
-
-        // 创建回调函数，更新进度条
-        let callback: ProgressCallback = Box::new(move |msg| {
-            if !msg.is_empty() {
-                if msg.starts_with("Downloaded:") || msg.contains("Downloaded:") {
-                    if let Ok(pb) = progress_clone.lock() {
-                        pb.inc(1);
-                    }
-                } else if msg.starts_with("Failed to download:") {
-                    // 失败的文件也计入进度
-                    if let Ok(pb) = progress_clone.lock() {
-                        pb.inc(1);
-                    }
-                }
-            }
-        });
+        // 创建进度显示（有 Content-Length 时显示字节进度条，否则显示 spinner）
+        let progress = DownloadProgressDisplay::new(total_files, "Downloading attachments...");
+        let callback = progress.callback();
 
         // 创建 JiraLogs 实例
         let logs = JiraLogs::new().wrap_err("Failed to initialize JiraLogs")?;
@@ -70,9 +51,7 @@ impl AttachmentsCommand {
             .wrap_err("Failed to download attachments from Jira")?;
 
         // 完成进度条
-        if let Ok(pb) = progress.lock() {
-            pb.finish_ref();
-        }
+        progress.finish();
 
         // 显示下载结果
         if !result.failed_files.is_empty() {
@@ -1,145 +1,154 @@
-use crate::jira::JiraIssueApi;
+use crate::base::table::{TableBuilder, TableStyle};
+use crate::jira::{ChangelogEvent, ChangelogRow, JiraChangelog, JiraIssueApi};
 use crate::{log_break, log_debug, log_message};
 use color_eyre::{eyre::WrapErr, Result};
 use serde_json;
 use serde_saphyr;
 use std::collections::HashMap;
 
-use super::helpers::{format_date, get_jira_id, OutputFormat};
+use super::helpers::{format_date, format_timestamp, get_jira_id, OutputFormat};
 use crate::cli::JiraQueryArgs;
 
 /// 显示变更历史命令
 pub struct ChangelogCommand;
 
 impl ChangelogCommand {
-    /// 显示 ticket 的变更历史
-    pub fn show(args: JiraQueryArgs) -> Result<()> {
+    /// 显示 ticket 的变更时间线
+    ///
+    /// 只包含 `fields` 中指定的字段（如 `status`、`assignee`）。
+    /// 表格中的时间默认显示为相对时间，`absolute` 为 `true` 时显示绝对时间。
+    pub fn show(args: JiraQueryArgs, fields: Vec<String>, absolute: bool) -> Result<()> {
         // 获取 JIRA ID（从参数或交互式输入）
         let jira_id = get_jira_id(args.jira_id.into_option(), None)?;
 
-        // 根据详细程度控制日志输出
-        if args.query_display.verbosity.is_verbose() {
-            log_debug!("Getting changelog for {}...", jira_id);
-        }
+        // 调试日志只在 `-v` 或 `log.level = debug` 时输出
+        log_debug!("Getting changelog for {}...", jira_id);
+
+        let changelog = JiraIssueApi::get_changelog(&jira_id)
+            .wrap_err_with(|| format!("Failed to get changelog for {}", jira_id))?;
+        let timeline = changelog.timeline(&fields);
 
         // 确定输出格式
         let format = OutputFormat::from(&args.query_display.output_format);
 
         // 根据输出格式选择不同的显示方式
         match format {
-            OutputFormat::Json => Self::output_json(&jira_id)?,
-            OutputFormat::Yaml => Self::output_yaml(&jira_id)?,
-            OutputFormat::Markdown => Self::output_markdown(&jira_id)?,
-            OutputFormat::Table => Self::output_table(&jira_id)?,
+            OutputFormat::Json => Self::output_json(&changelog, &timeline)?,
+            OutputFormat::Yaml => Self::output_yaml(&changelog, &timeline)?,
+            OutputFormat::Markdown => Self::output_markdown(&timeline, &fields)?,
+            OutputFormat::Table => Self::output_table(&jira_id, &timeline, &fields, absolute)?,
         }
 
         Ok(())
     }
 
     /// 表格格式输出
-    fn output_table(jira_id: &str) -> Result<()> {
-        let changelog = JiraIssueApi::get_issue_changelog(jira_id)
-            .wrap_err_with(|| format!("Failed to get changelog for {}", jira_id))?;
-
+    fn output_table(
+        jira_id: &str,
+        timeline: &[ChangelogEvent],
+        fields: &[String],
+        absolute: bool,
+    ) -> Result<()> {
         log_break!();
         log_break!('=', 40, "Changelog");
 
-        if changelog.histories.is_empty() {
-            log_message!("No change history available.");
+        if timeline.is_empty() {
+            log_message!("No changes to {} found.", fields.join(", "));
             return Ok(());
         }
 
-        for history in &changelog.histories {
-            log_break!();
-            log_message!("Change at {}", format_date(&history.created)?);
-            if let Some(author) = &history.author {
-                log_message!(
-                    "  Author: {} ({})",
-                    author.display_name,
-                    author.email_address.as_deref().unwrap_or("N/A")
-                );
-            }
-
-            for item in &history.items {
-                log_message!("  Field: {}", item.field);
-                if let Some(from_str) = &item.from_string {
-                    log_message!("    From: {}", from_str);
-                } else if let Some(from) = &item.from {
-                    log_message!("    From: {}", from);
-                }
-                if let Some(to_str) = &item.to_string {
-                    log_message!("    To: {}", to_str);
-                } else if let Some(to) = &item.to {
-                    log_message!("    To: {}", to);
-                }
-            }
-        }
+        let rows = Self::rows(timeline, |created| format_timestamp(created, absolute))?;
+
+        log_message!(
+            "{}",
+            TableBuilder::new(rows)
+                .with_title(format!("{} Timeline", jira_id))
+                .with_style(TableStyle::Modern)
+                .with_terminal_width()
+                .render()
+        );
 
         Ok(())
     }
 
     /// JSON 格式输出
-    fn output_json(jira_id: &str) -> Result<()> {
-        let changelog = JiraIssueApi::get_issue_changelog(jira_id)
-            .wrap_err_with(|| format!("Failed to get changelog for {}", jira_id))?;
-
-        let mut output: HashMap<String, serde_json::Value> = HashMap::new();
-        output.insert("changelog".to_string(), serde_json::to_value(changelog)?);
-
+    fn output_json(changelog: &JiraChangelog, timeline: &[ChangelogEvent]) -> Result<()> {
+        let output = Self::structured_output(changelog, timeline)?;
         log_message!("{}", serde_json::to_string_pretty(&output)?);
         Ok(())
     }
 
     /// YAML 格式输出
-    fn output_yaml(jira_id: &str) -> Result<()> {
-        let changelog = JiraIssueApi::get_issue_changelog(jira_id)
-            .wrap_err_with(|| format!("Failed to get changelog for {}", jira_id))?;
-
-        let mut output: HashMap<String, serde_json::Value> = HashMap::new();
-        output.insert("changelog".to_string(), serde_json::to_value(changelog)?);
-
+    fn output_yaml(changelog: &JiraChangelog, timeline: &[ChangelogEvent]) -> Result<()> {
+        let output = Self::structured_output(changelog, timeline)?;
         log_message!("{}", serde_saphyr::to_string(&output)?);
         Ok(())
     }
 
     /// Markdown 格式输出
-    fn output_markdown(jira_id: &str) -> Result<()> {
-        let changelog = JiraIssueApi::get_issue_changelog(jira_id)
-            .wrap_err_with(|| format!("Failed to get changelog for {}", jira_id))?;
+    fn output_markdown(timeline: &[ChangelogEvent], fields: &[String]) -> Result<()> {
+        log_message!("{}", Self::render_markdown(timeline, fields)?);
+        Ok(())
+    }
 
-        if changelog.histories.is_empty() {
-            log_message!("# Changelog\n\nNo change history available.\n");
-            return Ok(());
+    /// 渲染 Markdown 格式的变更时间线
+    ///
+    /// 使用 `TableBuilder::to_markdown` 生成表格，单元格中的 `|` 和换行（如标题变更）会被转义。
+    pub fn render_markdown(timeline: &[ChangelogEvent], fields: &[String]) -> Result<String> {
+        if timeline.is_empty() {
+            return Ok(format!(
+                "# Changelog\n\nNo changes to {} found.\n",
+                fields.join(", ")
+            ));
         }
 
-        log_message!("# Changelog\n");
-
-        for history in &changelog.histories {
-            log_message!("## {}", format_date(&history.created)?);
-            if let Some(author) = &history.author {
-                log_message!(
-                    "**Author:** {} ({})\n",
-                    author.display_name,
-                    author.email_address.as_deref().unwrap_or("N/A")
-                );
-            }
-
-            for item in &history.items {
-                log_message!("- **{}**", item.field);
-                if let Some(from_str) = &item.from_string {
-                    log_message!("  - From: {}", from_str);
-                } else if let Some(from) = &item.from {
-                    log_message!("  - From: {}", from);
-                }
-                if let Some(to_str) = &item.to_string {
-                    log_message!("  - To: {}", to_str);
-                } else if let Some(to) = &item.to {
-                    log_message!("  - To: {}", to);
-                }
-            }
-            log_break!();
-        }
+        let rows = Self::rows(timeline, format_date)?;
+        Ok(format!(
+            "# Changelog\n\n{}",
+            TableBuilder::new(rows).to_markdown()
+        ))
+    }
 
-        Ok(())
+    /// 生成时间线表格行（`format_time` 用于格式化变更时间）
+    fn rows(
+        timeline: &[ChangelogEvent],
+        format_time: impl Fn(&str) -> Result<String>,
+    ) -> Result<Vec<ChangelogRow>> {
+        timeline
+            .iter()
+            .map(|event| {
+                Ok(ChangelogRow {
+                    time: format_time(&event.created)?,
+                    actor: Self::actor(event).to_string(),
+                    field: event.field.clone(),
+                    change: Self::change(event),
+                })
+            })
+            .collect()
+    }
+
+    /// 构建 JSON/YAML 输出（原始变更历史 + 过滤后的时间线）
+    fn structured_output(
+        changelog: &JiraChangelog,
+        timeline: &[ChangelogEvent],
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        let mut output: HashMap<String, serde_json::Value> = HashMap::new();
+        output.insert("changelog".to_string(), serde_json::to_value(changelog)?);
+        output.insert("timeline".to_string(), serde_json::to_value(timeline)?);
+        Ok(output)
+    }
+
+    /// 获取操作人显示名称
+    fn actor(event: &ChangelogEvent) -> &str {
+        event.actor.as_deref().unwrap_or("Unknown")
+    }
+
+    /// 格式化变更内容（`from → to`）
+    fn change(event: &ChangelogEvent) -> String {
+        format!(
+            "{} → {}",
+            event.from.as_deref().unwrap_or("(none)"),
+            event.to.as_deref().unwrap_or("(none)")
+        )
     }
 }
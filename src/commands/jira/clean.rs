@@ -3,17 +3,19 @@
 //! 提供清理日志目录的功能，支持：
 //! - 清理指定 JIRA ID 的日志目录（当提供 jira_id 时）
 //! - 清理整个基础目录（当指定 --all 标志或交互式输入时留空）
-//! - 预览操作（dry-run）
+//! - 预览操作（dry-run）：列出将要删除的内容、每个 ticket 的文件数和大小（按大小从大到小排序）以及可释放的空间，不修改磁盘
 //! - 列出将要删除的内容（list-only）
+//!
+//! 实际删除前会先显示同样的预览，确认后才删除。
 
 use color_eyre::{eyre::WrapErr, Result};
 
 use crate::base::constants::errors::input_reading;
-use crate::base::dialog::InputDialog;
+use crate::base::dialog::{ConfirmDialog, InputDialog};
 use crate::base::format::DisplayFormatter;
 use crate::base::table::{TableBuilder, TableStyle};
-use crate::jira::attachments::AttachmentCleaner;
-use crate::jira::table::FileRow;
+use crate::jira::attachments::{AttachmentCleaner, DirEntry, DirInfo};
+use crate::jira::table::{CleanSummaryRow, FileRow};
 use crate::{log_break, log_info, log_message, log_success};
 
 /// 清理日志命令
@@ -69,109 +71,139 @@ impl CleanCommand {
             log_info!("Cleaning logs for {}...", jira_id);
         }
 
-        // 创建清理器并执行清理
+        // 计算将要删除的内容（只读取目录，不修改磁盘）
         let cleaner = AttachmentCleaner::new();
-        let result = cleaner
-            .clean_dir(&jira_id, dry_run, list_only)
-            .wrap_err("Failed to clean logs directory")?;
-
-        // 显示目录信息
-        if let Some(ref dir_info) = result.dir_info {
-            // 根据 dir_name 判断显示格式
-            if let Some(ref jira_id) = dir_info.jira_id {
-                log_info!("JIRA ID: {}", jira_id);
-            } else {
-                log_info!("{}: {:?}", dir_info.dir_name, dir_info.dir);
-            }
-            log_info!("Directory: {:?}", dir_info.dir);
-            log_info!("Total size: {}", DisplayFormatter::size(dir_info.size));
-            log_info!("Total files: {}", dir_info.file_count);
-            log_break!();
-            log_info!("Contents:");
-
-            if dir_info.is_base_dir {
-                // 按 ticket 分组显示
-                let mut current_ticket: Option<String> = None;
-                let mut rows: Vec<FileRow> = Vec::new();
-
-                for entry in &dir_info.contents {
-                    // 从 entry_type 中提取 ticket ID
-                    let ticket_id = if entry.entry_type.contains("(") {
-                        entry
-                            .entry_type
-                            .split('(')
-                            .nth(1)
-                            .and_then(|s| s.strip_suffix(')'))
-                            .map(|s| s.to_string())
-                    } else {
-                        None
-                    };
-
-                    if ticket_id != current_ticket {
-                        // 显示之前的表格
-                        if !rows.is_empty() {
-                            if let Some(ref ticket) = current_ticket {
-                                log_message!(
-                                    "{}",
-                                    TableBuilder::new(rows.clone())
-                                        .with_title(format!("Files: {}", ticket))
-                                        .with_style(TableStyle::Modern)
-                                        .render()
-                                );
-                                log_break!();
-                            }
-                            rows.clear();
-                        }
-                        current_ticket = ticket_id;
-                    }
-
-                    rows.push(FileRow {
-                        file_type: entry.entry_type.clone(),
-                        name: entry.name.clone(),
-                        size: entry.size.clone().unwrap_or_else(|| "-".to_string()),
-                    });
-                }
+        let plan = cleaner.plan(&jira_id).wrap_err("Failed to scan logs directory")?;
 
-                // 显示最后一个表格
-                if !rows.is_empty() {
-                    if let Some(ref ticket) = current_ticket {
-                        log_message!(
-                            "{}",
-                            TableBuilder::new(rows)
-                                .with_title(format!("Files: {}", ticket))
-                                .with_style(TableStyle::Modern)
-                                .render()
-                        );
-                        log_break!();
-                    }
-                }
-            } else {
-                // 单个 ticket 目录，直接列出内容
-                for entry in &dir_info.contents {
-                    if let Some(ref size) = entry.size {
-                        log_info!("  {} {} ({})", entry.entry_type, entry.name, size);
-                    } else {
-                        log_info!("  {} {}", entry.entry_type, entry.name);
-                    }
-                }
-            }
+        let Some(dir_info) = plan.dir_info.as_ref() else {
+            log_info!("Directory does not exist.");
+            return Ok(());
+        };
+
+        Self::show_contents(dir_info);
+        Self::show_summary(dir_info);
+
+        if list_only {
+            // list_only 模式，信息已在上面的 dir_info 显示中输出
+            return Ok(());
         }
 
+        if dry_run {
+            log_info!(
+                "[DRY RUN] Preview completed. {} files ({}) would be deleted.",
+                plan.file_count(),
+                DisplayFormatter::size(plan.reclaimable_bytes())
+            );
+            return Ok(());
+        }
+
+        let confirmed = ConfirmDialog::new(format!(
+            "Are you sure you want to delete {}? This will remove {} files ({}).",
+            dir_info.dir_name,
+            plan.file_count(),
+            DisplayFormatter::size(plan.reclaimable_bytes())
+        ))
+        .with_default(false)
+        .prompt()?;
+
+        if !confirmed {
+            log_info!("Clean operation was cancelled.");
+            return Ok(());
+        }
+
+        let result = cleaner.remove(plan).wrap_err("Failed to clean logs directory")?;
         if result.deleted {
             log_break!();
-            log_success!("Clean completed successfully!");
-        } else if result.cancelled {
-            log_info!("Clean operation was cancelled.");
-        } else if !result.dir_exists {
-            log_info!("Directory does not exist.");
-        } else if result.dry_run {
-            log_info!("[DRY RUN] Preview completed.");
-        } else if result.list_only {
-            // list_only 模式，信息已在上面的 dir_info 显示中输出
-        } else {
-            log_info!("Clean operation was cancelled or directory does not exist.");
+            log_success!(
+                "Clean completed successfully! Reclaimed {} ({} files).",
+                DisplayFormatter::size(result.reclaimed_bytes()),
+                result.file_count()
+            );
         }
 
         Ok(())
     }
+
+    /// 显示目录内容
+    fn show_contents(dir_info: &DirInfo) {
+        // 根据 dir_name 判断显示格式
+        if let Some(ref jira_id) = dir_info.jira_id {
+            log_info!("JIRA ID: {}", jira_id);
+        } else {
+            log_info!("{}: {:?}", dir_info.dir_name, dir_info.dir);
+        }
+        log_info!("Directory: {:?}", dir_info.dir);
+        log_break!();
+        log_info!("Contents:");
+
+        if dir_info.is_base_dir {
+            // 按 ticket 分组显示
+            for ticket in &dir_info.tickets {
+                if ticket.contents.is_empty() {
+                    continue;
+                }
+                let rows: Vec<FileRow> = ticket.contents.iter().map(Self::file_row).collect();
+                log_message!(
+                    "{}",
+                    TableBuilder::new(rows)
+                        .with_title(format!(
+                            "Files: {}",
+                            ticket.jira_id.as_deref().unwrap_or("-")
+                        ))
+                        .with_style(TableStyle::Modern)
+                        .render()
+                );
+                log_break!();
+            }
+        } else {
+            // 单个 ticket 目录，直接列出内容
+            for entry in &dir_info.contents {
+                if let Some(ref size) = entry.size {
+                    log_info!("  {} {} ({})", entry.entry_type, entry.name, size);
+                } else {
+                    log_info!("  {} {}", entry.entry_type, entry.name);
+                }
+            }
+            log_break!();
+        }
+    }
+
+    /// 显示将要删除的文件数和可释放的空间（基础目录按 ticket 分行，按大小从大到小排序）
+    fn show_summary(dir_info: &DirInfo) {
+        let mut rows: Vec<CleanSummaryRow> = dir_info
+            .tickets_by_size()
+            .iter()
+            .map(|ticket| CleanSummaryRow {
+                jira_id: ticket.jira_id.clone().unwrap_or_else(|| "-".to_string()),
+                files: ticket.file_count.to_string(),
+                size: DisplayFormatter::size(ticket.size),
+            })
+            .collect();
+        rows.push(CleanSummaryRow {
+            jira_id: "Total".to_string(),
+            files: dir_info.file_count.to_string(),
+            size: DisplayFormatter::size(dir_info.size),
+        });
+
+        log_message!(
+            "{}",
+            TableBuilder::new(rows)
+                .with_title("Clean Summary")
+                .with_style(TableStyle::Modern)
+                .render()
+        );
+        log_info!(
+            "Reclaimable space: {}",
+            DisplayFormatter::size(dir_info.size)
+        );
+    }
+
+    /// 转换为文件表格行
+    fn file_row(entry: &DirEntry) -> FileRow {
+        FileRow {
+            file_type: entry.entry_type.clone(),
+            name: entry.name.clone(),
+            size: entry.size.clone().unwrap_or_else(|| "-".to_string()),
+        }
+    }
 }
@@ -1,10 +1,10 @@
 //! JIRA 添加评论命令
 //!
-//! 为 JIRA ticket 添加评论，支持交互式输入：
-//! - 输入评论文本
-//! - 可选择附加文件内容
+//! 为 JIRA ticket 添加评论：
+//! - 通过 `-m` 指定评论内容时直接发送
+//! - 未指定时打开 `$EDITOR` 编辑评论内容，并可选择附加文件内容
 
-use crate::base::dialog::{ConfirmDialog, InputDialog};
+use crate::base::dialog::{ConfirmDialog, EditorDialog, InputDialog};
 use crate::base::indicator::Spinner;
 use crate::jira::helpers::validate_jira_ticket_format;
 use crate::jira::Jira;
@@ -29,28 +29,32 @@ impl CommentCommand {
     /// # 参数
     ///
     /// * `jira_id` - JIRA ticket ID（可选，如果不提供会交互式输入）
+    /// * `message` - 评论内容（可选，如果不提供会打开编辑器输入）
     ///
     /// # 返回
     ///
     /// 成功时返回 `Ok(())`。
-    pub fn add(jira_id: Option<String>) -> Result<()> {
+    pub fn add(jira_id: Option<String>, message: Option<String>) -> Result<()> {
         // 步骤 1: 获取或输入 Jira ticket（参考 pr/create.rs 的方式）
         let ticket = Self::resolve_jira_ticket(jira_id)?;
 
-        // 步骤 2: 输入评论内容
-        let message = InputDialog::new("Enter comment message")
-            .prompt()
-            .wrap_err("Failed to get comment message")?;
+        // 步骤 2: 获取评论内容（未通过 -m 指定时打开编辑器）
+        let interactive = message.is_none();
+        let message = match message {
+            Some(message) => message,
+            None => EditorDialog::new().prompt().wrap_err("Failed to get comment message")?,
+        };
 
         if message.trim().is_empty() {
             color_eyre::eyre::bail!("Comment message cannot be empty");
         }
 
-        // 步骤 3: 询问是否需要附加文件
-        let attach_file = ConfirmDialog::new("Do you want to attach a file?")
-            .with_default(false)
-            .prompt()
-            .wrap_err("Failed to get file attachment choice")?;
+        // 步骤 3: 询问是否需要附加文件（只在编辑器输入时询问）
+        let attach_file = interactive
+            && ConfirmDialog::new("Do you want to attach a file?")
+                .with_default(false)
+                .prompt()
+                .wrap_err("Failed to get file attachment choice")?;
 
         // 步骤 4: 处理文件附件和构建评论内容
         let final_comment = if attach_file {
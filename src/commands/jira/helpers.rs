@@ -4,6 +4,9 @@
 
 use crate::base::constants::errors::input_reading;
 use crate::base::dialog::InputDialog;
+use crate::base::format::DisplayFormatter;
+use crate::base::settings::Settings;
+use crate::base::LogFormat;
 use chrono::{DateTime, FixedOffset};
 use color_eyre::{eyre::WrapErr, Result};
 
@@ -31,6 +34,16 @@ impl OutputFormat {
             Self::Table
         }
     }
+
+    /// JSON 输出时将提示信息改为输出到 stderr
+    ///
+    /// stdout 只保留 JSON 文档：成功、警告、信息等提示以 JSON 日志（同 `--log-format json`）输出到 stderr，
+    /// 分隔线不输出。需要在输出任何提示之前调用。
+    pub fn route_diagnostics(self) {
+        if matches!(self, Self::Json) {
+            LogFormat::set(LogFormat::Json);
+        }
+    }
 }
 
 // 为 OutputFormatArgs 添加转换方法
@@ -61,6 +74,19 @@ pub fn get_jira_id(jira_id: Option<String>, prompt_message: Option<&str>) -> Res
     }
 }
 
+/// 解析 Jira 返回的日期时间字符串，并转换到配置的时区（`jira.timezone`，默认为本地时区）
+///
+/// 支持 RFC3339 格式和 Jira 常用的 `2024-01-15T10:30:00.000+0000` 格式。
+pub fn parse_date(date_str: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::<FixedOffset>::parse_from_rfc3339(date_str)
+        .or_else(|_| {
+            // 尝试其他格式
+            DateTime::parse_from_str(date_str, "%Y-%m-%dT%H:%M:%S%.3f%z")
+        })
+        .ok()
+        .map(|dt| Settings::get().jira.timezone().convert(&dt))
+}
+
 /// 格式化日期时间字符串
 ///
 /// 支持 RFC3339 格式和其他常见格式的日期时间解析。
@@ -73,11 +99,18 @@ pub fn get_jira_id(jira_id: Option<String>, prompt_message: Option<&str>) -> Res
 ///
 /// 格式化后的日期时间字符串（格式：YYYY-MM-DD HH:MM:SS）
 pub fn format_date(date_str: &str) -> Result<String> {
-    DateTime::<FixedOffset>::parse_from_rfc3339(date_str)
-        .or_else(|_| {
-            // 尝试其他格式
-            DateTime::parse_from_str(date_str, "%Y-%m-%dT%H:%M:%S%.3f%z")
-        })
+    Ok(parse_date(date_str)
         .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
-        .or_else(|_| Ok(date_str.to_string()))
+        .unwrap_or_else(|| date_str.to_string()))
+}
+
+/// 格式化列表中的时间戳
+///
+/// 默认显示相对时间（如 `3 hours ago`），`absolute` 为 `true`（`--absolute`）时显示
+/// 与 `format_date` 相同的绝对时间。无法解析时原样返回。
+pub fn format_timestamp(date_str: &str, absolute: bool) -> Result<String> {
+    match parse_date(date_str) {
+        Some(dt) if !absolute => Ok(DisplayFormatter::relative_time(&dt)),
+        _ => format_date(date_str),
+    }
 }
@@ -1,8 +1,10 @@
 use crate::base::format::DisplayFormatter;
 use crate::base::indicator::Spinner;
+use crate::base::settings::Settings;
 use crate::base::table::{TableBuilder, TableStyle};
+use crate::base::LogLevel;
 use crate::jira::table::AttachmentRow;
-use crate::jira::Jira;
+use crate::jira::{Jira, JiraIssueOutput};
 use crate::{log_break, log_message};
 use color_eyre::{eyre::WrapErr, Result};
 use serde_json;
@@ -21,8 +23,12 @@ impl InfoCommand {
         // 获取 JIRA ID（从参数或交互式输入）
         let jira_id = get_jira_id(args.jira_id.into_option(), None)?;
 
-        // 根据详细程度控制 Spinner 显示
-        let issue = if args.query_display.verbosity.is_quiet() {
+        // 确定输出格式
+        let format = OutputFormat::from(&args.query_display.output_format);
+        format.route_diagnostics();
+
+        // 根据日志级别控制 Spinner 显示（`-q` 或 `log.level` 为 error/off 时不显示）
+        let issue = if !LogLevel::get_level().should_log(LogLevel::Info) {
             // 静默模式：不显示 Spinner
             Jira::get_ticket_info(&jira_id)
                 .wrap_err_with(|| format!("Failed to get ticket info for {}", jira_id))?
@@ -34,9 +40,6 @@ impl InfoCommand {
             })?
         };
 
-        // 确定输出格式
-        let format = OutputFormat::from(&args.query_display.output_format);
-
         // 根据输出格式选择不同的显示方式
         match format {
             OutputFormat::Json => Self::output_json(&issue)?,
@@ -94,6 +97,17 @@ impl InfoCommand {
             }
         }
 
+        if let Some(sprint) = issue.current_sprint(Settings::get().jira.sprint_field()) {
+            match sprint.state.as_deref() {
+                Some(state) => {
+                    log_message!("Sprint: {} ({})", sprint.name, state);
+                }
+                None => {
+                    log_message!("Sprint: {}", sprint.name);
+                }
+            }
+        }
+
         if let Some(components) = &issue.fields.components {
             if !components.is_empty() {
                 let component_names: Vec<String> =
@@ -249,10 +263,14 @@ impl InfoCommand {
         Ok(())
     }
 
-    /// JSON 格式输出
+    /// JSON 格式输出（`JiraIssueOutput`）
     fn output_json(issue: &crate::jira::JiraIssue) -> Result<()> {
-        let mut output: HashMap<String, serde_json::Value> = HashMap::new();
-        output.insert("issue".to_string(), serde_json::to_value(issue)?);
+        let settings = Settings::get();
+        let output = JiraIssueOutput::new(
+            issue,
+            settings.jira.sprint_field(),
+            settings.jira.service_address.as_deref(),
+        );
 
         log_message!("{}", serde_json::to_string_pretty(&output)?);
         Ok(())
@@ -1,7 +1,8 @@
 //! Jira 操作命令模块
 //!
-//! 提供 Jira ticket 信息查看、附件下载、清理本地数据等功能。
+//! 提供 Jira ticket 信息查看、附件上传和下载、清理本地数据等功能。
 
+pub mod attach;
 pub mod attachments;
 pub mod changelog;
 pub mod clean;
@@ -10,12 +11,15 @@ pub mod comments;
 pub mod helpers;
 pub mod info;
 pub mod related;
+pub mod sprint;
 
+pub use attach::AttachCommand;
 pub use attachments::AttachmentsCommand;
 pub use changelog::ChangelogCommand;
 pub use clean::CleanCommand;
 pub use comment::CommentCommand;
 pub use comments::CommentsCommand;
-pub use helpers::{format_date, get_jira_id, OutputFormat};
+pub use helpers::{format_date, format_timestamp, get_jira_id, parse_date, OutputFormat};
 pub use info::InfoCommand;
 pub use related::RelatedCommand;
+pub use sprint::SprintCommand;
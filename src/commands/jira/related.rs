@@ -1,41 +1,107 @@
+use crate::base::dialog::SelectDialog;
+use crate::base::table::{TableBuilder, TableStyle};
+use crate::commands::log::DownloadCommand;
 use crate::git::GitBranch;
-use crate::jira::history::JiraWorkHistory;
-use crate::{log_break, log_message};
-use color_eyre::Result;
+use crate::jira::history::{JiraWorkHistory, WorkHistoryEntry};
+use crate::jira::{JiraIssueApi, RelatedIssueRow, RelatedIssues};
+use crate::{log_break, log_debug, log_message, log_warning};
+use color_eyre::{eyre::WrapErr, Result};
 use serde_json;
+use serde_saphyr;
 use std::collections::HashMap;
 
+use super::attachments::AttachmentsCommand;
 use super::helpers::{get_jira_id, OutputFormat};
 use crate::cli::JiraQueryArgs;
 
+/// `--open` 可执行的操作：下载附件
+const OPEN_ATTACHMENTS: &str = "Download attachments";
+/// `--open` 可执行的操作：下载日志
+const OPEN_LOGS: &str = "Download logs";
+
 /// 显示关联信息命令
 pub struct RelatedCommand;
 
 impl RelatedCommand {
-    /// 显示 ticket 的关联信息（PR 和分支）
-    pub fn show(args: JiraQueryArgs) -> Result<()> {
+    /// 显示 ticket 的关联信息（关联 ticket、PR 和分支）
+    ///
+    /// `open` 为 `true` 时，显示后选择一个关联 ticket 并下载其附件或日志。
+    pub fn show(args: JiraQueryArgs, open: bool) -> Result<()> {
         // 获取 JIRA ID（从参数或交互式输入）
         let jira_id = get_jira_id(args.jira_id.into_option(), None)?;
 
+        // 调试日志只在 `-v` 或 `log.level = debug` 时输出
+        log_debug!("Getting related tickets for {}...", jira_id);
+
+        let issue = JiraIssueApi::get_issue(&jira_id)
+            .wrap_err_with(|| format!("Failed to get issue {}", jira_id))?;
+        let related = RelatedIssues::from_issue(&issue);
+
         // 确定输出格式
         let format = OutputFormat::from(&args.query_display.output_format);
 
         // 根据输出格式选择不同的显示方式
         match format {
-            OutputFormat::Json => Self::output_json(&jira_id)?,
-            OutputFormat::Yaml => Self::output_yaml(&jira_id)?,
-            OutputFormat::Markdown => Self::output_markdown(&jira_id)?,
-            OutputFormat::Table => Self::output_table(&jira_id)?,
+            OutputFormat::Json => Self::output_json(&jira_id, &related)?,
+            OutputFormat::Yaml => Self::output_yaml(&jira_id, &related)?,
+            OutputFormat::Markdown => Self::output_markdown(&jira_id, &related)?,
+            OutputFormat::Table => Self::output_table(&jira_id, &related)?,
+        }
+
+        if open {
+            Self::open_related(&related)?;
         }
 
         Ok(())
     }
 
+    /// 选择一个关联 ticket，下载其附件或日志
+    fn open_related(related: &RelatedIssues) -> Result<()> {
+        let issues = related.related();
+        if issues.is_empty() {
+            log_warning!("No related tickets to open");
+            return Ok(());
+        }
+
+        let items: Vec<(String, String)> =
+            issues.iter().map(|issue| (issue.label(), issue.key.clone())).collect();
+        let key = SelectDialog::new_with_items("Select a related ticket", items)
+            .prompt()
+            .wrap_err("Failed to select related ticket")?;
+
+        let actions = vec![OPEN_ATTACHMENTS.to_string(), OPEN_LOGS.to_string()];
+        let action = SelectDialog::new(format!("What to open for {}?", key), actions)
+            .prompt()
+            .wrap_err("Failed to select action")?;
+
+        log_break!();
+        if action == OPEN_ATTACHMENTS {
+            AttachmentsCommand::download(Some(key))
+        } else {
+            DownloadCommand::download(Some(key))
+        }
+    }
+
     /// 表格格式输出
-    fn output_table(jira_id: &str) -> Result<()> {
+    fn output_table(jira_id: &str, related: &RelatedIssues) -> Result<()> {
         log_break!();
         log_break!('=', 40, "Related Information");
-        log_message!("Jira Ticket: {}", jira_id);
+        log_message!("{}", related.issue.label());
+        if related.is_empty() {
+            log_message!(
+                "{} has no epic, parent, subtasks or linked tickets.",
+                jira_id
+            );
+        } else {
+            log_message!(
+                "{}",
+                TableBuilder::new(Self::related_rows(related))
+                    .with_title(format!("{} Related Tickets", jira_id))
+                    .with_style(TableStyle::Modern)
+                    .with_terminal_width()
+                    .render()
+            );
+        }
         log_break!();
 
         // 1. 查找关联的 PR
@@ -60,19 +126,8 @@ impl RelatedCommand {
             log_message!("Related Pull Requests: None");
         }
 
-        // 2. 查找关联的分支（从工作历史记录）
-        let branches_from_history = JiraWorkHistory::find_branches_by_jira_ticket(jira_id)?;
-
-        // 3. 从 Git 仓库中查找包含 ticket ID 的分支（补充）
-        let branches_from_git = Self::find_branches_by_ticket_id(jira_id)?;
-
-        // 合并并去重
-        let mut all_branches: Vec<String> = branches_from_history;
-        for branch in branches_from_git {
-            if !all_branches.contains(&branch) {
-                all_branches.push(branch);
-            }
-        }
+        // 2. 查找关联的分支
+        let all_branches = Self::find_all_branches(jira_id)?;
 
         if !all_branches.is_empty() {
             log_break!();
@@ -103,57 +158,91 @@ impl RelatedCommand {
         Ok(())
     }
 
+    /// 关联 ticket 表格行（按关联关系分组：Epic、Parent、Subtasks，然后是各类 Issue 链接）
+    fn related_rows(related: &RelatedIssues) -> Vec<RelatedIssueRow> {
+        related
+            .groups()
+            .into_iter()
+            .flat_map(|group| {
+                group.issues.into_iter().map(move |issue| RelatedIssueRow {
+                    relation: group.name.clone(),
+                    key: issue.key,
+                    status: issue.status.unwrap_or_else(|| "-".to_string()),
+                    summary: issue.summary.unwrap_or_else(|| "-".to_string()),
+                })
+            })
+            .collect()
+    }
+
     /// JSON 格式输出
-    fn output_json(jira_id: &str) -> Result<()> {
+    fn output_json(jira_id: &str, related: &RelatedIssues) -> Result<()> {
+        let output = Self::structured_output(jira_id, related)?;
+        log_message!("{}", serde_json::to_string_pretty(&output)?);
+        Ok(())
+    }
+
+    /// YAML 格式输出
+    fn output_yaml(jira_id: &str, related: &RelatedIssues) -> Result<()> {
+        let output = Self::structured_output(jira_id, related)?;
+        log_message!("{}", serde_saphyr::to_string(&output)?);
+        Ok(())
+    }
+
+    /// 构建 JSON/YAML 输出（关联 ticket、PR 和分支）
+    fn structured_output(
+        jira_id: &str,
+        related: &RelatedIssues,
+    ) -> Result<HashMap<String, serde_json::Value>> {
         let mut output: HashMap<String, serde_json::Value> = HashMap::new();
         output.insert("jira_ticket".to_string(), serde_json::json!(jira_id));
+        output.insert("related".to_string(), serde_json::to_value(related)?);
 
         let pr_entries = JiraWorkHistory::find_prs_by_jira_ticket(jira_id)?;
-        let prs: Vec<serde_json::Value> = pr_entries
-            .iter()
-            .map(|e| {
-                let mut pr = serde_json::Map::new();
-                if let Some(url) = &e.pull_request_url {
-                    pr.insert("url".to_string(), serde_json::json!(url));
-                }
-                if let Some(branch) = &e.branch {
-                    pr.insert("branch".to_string(), serde_json::json!(branch));
-                }
-                if let Some(created) = &e.created_at {
-                    pr.insert("created_at".to_string(), serde_json::json!(created));
-                }
-                if let Some(merged) = &e.merged_at {
-                    pr.insert("merged_at".to_string(), serde_json::json!(merged));
-                }
-                serde_json::Value::Object(pr)
-            })
-            .collect();
+        let prs: Vec<serde_json::Value> = pr_entries.iter().map(Self::pr_to_json).collect();
         output.insert("pull_requests".to_string(), serde_json::json!(prs));
 
-        let branches_from_history = JiraWorkHistory::find_branches_by_jira_ticket(jira_id)?;
-        let branches_from_git = Self::find_branches_by_ticket_id(jira_id)?;
-        let mut all_branches: Vec<String> = branches_from_history;
-        for branch in branches_from_git {
-            if !all_branches.contains(&branch) {
-                all_branches.push(branch);
-            }
-        }
+        let all_branches = Self::find_all_branches(jira_id)?;
         output.insert("branches".to_string(), serde_json::json!(all_branches));
 
-        log_message!("{}", serde_json::to_string_pretty(&output)?);
-        Ok(())
+        Ok(output)
     }
 
-    /// YAML 格式输出
-    fn output_yaml(jira_id: &str) -> Result<()> {
-        // 暂时使用 JSON 格式
-        Self::output_json(jira_id)
+    /// 将 PR 记录转换为 JSON
+    fn pr_to_json(entry: &WorkHistoryEntry) -> serde_json::Value {
+        let mut pr = serde_json::Map::new();
+        if let Some(url) = &entry.pull_request_url {
+            pr.insert("url".to_string(), serde_json::json!(url));
+        }
+        if let Some(branch) = &entry.branch {
+            pr.insert("branch".to_string(), serde_json::json!(branch));
+        }
+        if let Some(created) = &entry.created_at {
+            pr.insert("created_at".to_string(), serde_json::json!(created));
+        }
+        if let Some(merged) = &entry.merged_at {
+            pr.insert("merged_at".to_string(), serde_json::json!(merged));
+        }
+        serde_json::Value::Object(pr)
     }
 
     /// Markdown 格式输出
-    fn output_markdown(jira_id: &str) -> Result<()> {
+    fn output_markdown(jira_id: &str, related: &RelatedIssues) -> Result<()> {
         log_message!("# Related Information for {}\n", jira_id);
 
+        log_message!("## Related Tickets\n");
+        let groups = related.groups();
+        if groups.is_empty() {
+            log_message!("None\n");
+        } else {
+            for group in &groups {
+                log_message!("- **{}**", group.name);
+                for issue in &group.issues {
+                    log_message!("  - {}", issue.label());
+                }
+            }
+            log_message!("");
+        }
+
         let pr_entries = JiraWorkHistory::find_prs_by_jira_ticket(jira_id)?;
         if !pr_entries.is_empty() {
             log_message!("## Related Pull Requests\n");
@@ -175,14 +264,7 @@ impl RelatedCommand {
             log_message!("## Related Pull Requests\n\nNone\n");
         }
 
-        let branches_from_history = JiraWorkHistory::find_branches_by_jira_ticket(jira_id)?;
-        let branches_from_git = Self::find_branches_by_ticket_id(jira_id)?;
-        let mut all_branches: Vec<String> = branches_from_history;
-        for branch in branches_from_git {
-            if !all_branches.contains(&branch) {
-                all_branches.push(branch);
-            }
-        }
+        let all_branches = Self::find_all_branches(jira_id)?;
 
         if !all_branches.is_empty() {
             log_message!("\n## Related Branches\n");
@@ -209,6 +291,17 @@ impl RelatedCommand {
         Ok(())
     }
 
+    /// 查找关联的分支（工作历史记录 + Git 仓库中包含 ticket ID 的分支，去重）
+    fn find_all_branches(jira_ticket: &str) -> Result<Vec<String>> {
+        let mut all_branches = JiraWorkHistory::find_branches_by_jira_ticket(jira_ticket)?;
+        for branch in Self::find_branches_by_ticket_id(jira_ticket)? {
+            if !all_branches.contains(&branch) {
+                all_branches.push(branch);
+            }
+        }
+        Ok(all_branches)
+    }
+
     /// 从 Git 仓库中查找包含 ticket ID 的分支
     fn find_branches_by_ticket_id(jira_ticket: &str) -> Result<Vec<String>> {
         // 使用 GitBranch::get_all_branches 获取所有分支（包括本地和远程）
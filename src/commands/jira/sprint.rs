@@ -0,0 +1,193 @@
+use crate::base::dialog::{InputDialog, SelectDialog};
+use crate::base::table::{TableBuilder, TableStyle};
+use crate::jira::{JiraBoard, JiraIssue, JiraIssueApi, JiraProjectApi, JiraSprint, SprintIssueRow};
+use crate::{log_break, log_debug, log_message};
+use color_eyre::{
+    eyre::{eyre, WrapErr},
+    Result,
+};
+use serde_json;
+use serde_saphyr;
+use std::collections::HashMap;
+
+use super::helpers::{format_date, OutputFormat};
+use crate::cli::OutputFormatArgs;
+
+/// 显示进行中的 Sprint 命令
+pub struct SprintCommand;
+
+impl SprintCommand {
+    /// 显示项目进行中的 Sprint 及其中的 ticket
+    ///
+    /// 未指定 `board` 时查找项目的看板；项目有多个看板时交互式选择。
+    pub fn show(
+        project: Option<String>,
+        board: Option<u64>,
+        output_format: OutputFormatArgs,
+    ) -> Result<()> {
+        let project = match project {
+            Some(project) => project,
+            None => InputDialog::new("Enter Jira project key (e.g., PROJ)")
+                .prompt()
+                .wrap_err("Failed to read Jira project key")?,
+        };
+
+        let board_id = match board {
+            Some(board_id) => board_id,
+            None => Self::select_board(&project)?,
+        };
+
+        log_debug!("Getting active sprint for board {}...", board_id);
+        let sprint = JiraIssueApi::get_active_sprint(board_id)?
+            .ok_or_else(|| eyre!("No active sprint on board {} ({})", board_id, project))?;
+        let issues = JiraIssueApi::get_sprint_issues(sprint.id)
+            .wrap_err_with(|| format!("Failed to get issues for sprint {}", sprint.name))?;
+
+        // 确定输出格式
+        let format = OutputFormat::from(&output_format);
+
+        match format {
+            OutputFormat::Json => {
+                let output = Self::structured_output(&sprint, &issues)?;
+                log_message!("{}", serde_json::to_string_pretty(&output)?);
+            }
+            OutputFormat::Yaml => {
+                let output = Self::structured_output(&sprint, &issues)?;
+                log_message!("{}", serde_saphyr::to_string(&output)?);
+            }
+            OutputFormat::Markdown => Self::output_markdown(&sprint, &issues)?,
+            OutputFormat::Table => Self::output_table(&sprint, &issues)?,
+        }
+
+        Ok(())
+    }
+
+    /// 选择项目的看板（只有一个看板时直接使用）
+    fn select_board(project: &str) -> Result<u64> {
+        log_debug!("Getting boards for project {}...", project);
+        let mut boards = JiraProjectApi::get_boards(project)
+            .wrap_err_with(|| format!("Failed to get boards for project {}", project))?;
+
+        match boards.len() {
+            0 => Err(eyre!("Project {} has no Agile board", project)),
+            1 => Ok(boards.remove(0).id),
+            _ => {
+                let items: Vec<(String, u64)> =
+                    boards.iter().map(|board| (Self::board_label(board), board.id)).collect();
+                SelectDialog::new_with_items(format!("Select a board for {}", project), items)
+                    .prompt()
+                    .wrap_err("Failed to select board")
+            }
+        }
+    }
+
+    /// 看板的显示标签（`名称 (类型, #ID)`）
+    fn board_label(board: &JiraBoard) -> String {
+        match board.board_type.as_deref() {
+            Some(board_type) => format!("{} ({}, #{})", board.name, board_type, board.id),
+            None => format!("{} (#{})", board.name, board.id),
+        }
+    }
+
+    /// Sprint 的时间范围（`开始 → 结束`）
+    fn date_range(sprint: &JiraSprint) -> Result<Option<String>> {
+        if sprint.start_date.is_none() && sprint.end_date.is_none() {
+            return Ok(None);
+        }
+        let format = |date: Option<&str>| match date {
+            Some(date) => format_date(date),
+            None => Ok("-".to_string()),
+        };
+        Ok(Some(format!(
+            "{} → {}",
+            format(sprint.start_date.as_deref())?,
+            format(sprint.end_date.as_deref())?
+        )))
+    }
+
+    /// Sprint ticket 表格行
+    fn issue_rows(issues: &[JiraIssue]) -> Vec<SprintIssueRow> {
+        issues
+            .iter()
+            .map(|issue| SprintIssueRow {
+                key: issue.key.clone(),
+                status: issue.fields.status.name.clone(),
+                assignee: issue
+                    .fields
+                    .assignee
+                    .as_ref()
+                    .map(|user| user.display_name.clone())
+                    .unwrap_or_else(|| "-".to_string()),
+                summary: issue.fields.summary.clone(),
+            })
+            .collect()
+    }
+
+    /// 表格格式输出
+    fn output_table(sprint: &JiraSprint, issues: &[JiraIssue]) -> Result<()> {
+        log_break!();
+        log_break!('=', 40, "Active Sprint");
+        log_message!("{} (#{})", sprint.name, sprint.id);
+        if let Some(range) = Self::date_range(sprint)? {
+            log_message!("Dates: {}", range);
+        }
+        if let Some(goal) = sprint.goal.as_deref().filter(|g| !g.is_empty()) {
+            log_message!("Goal: {}", goal);
+        }
+        log_break!();
+
+        if issues.is_empty() {
+            log_message!("{} has no issues.", sprint.name);
+            return Ok(());
+        }
+
+        log_message!(
+            "{}",
+            TableBuilder::new(Self::issue_rows(issues))
+                .with_title(format!("{} Issues ({})", sprint.name, issues.len()))
+                .with_style(TableStyle::Modern)
+                .with_terminal_width()
+                .render()
+        );
+
+        Ok(())
+    }
+
+    /// Markdown 格式输出
+    fn output_markdown(sprint: &JiraSprint, issues: &[JiraIssue]) -> Result<()> {
+        log_message!("{}", Self::render_markdown(sprint, issues)?);
+        Ok(())
+    }
+
+    /// 渲染 Markdown 格式的 Sprint 信息和 ticket 列表
+    ///
+    /// ticket 表格使用 `TableBuilder::to_markdown` 生成，标题中的 `|` 和换行会被转义。
+    pub fn render_markdown(sprint: &JiraSprint, issues: &[JiraIssue]) -> Result<String> {
+        let mut sections = vec![format!("# {}", sprint.name)];
+        if let Some(range) = Self::date_range(sprint)? {
+            sections.push(format!("**Dates:** {}", range));
+        }
+        if let Some(goal) = sprint.goal.as_deref().filter(|g| !g.is_empty()) {
+            sections.push(format!("**Goal:** {}", goal));
+        }
+
+        if issues.is_empty() {
+            sections.push("No issues.".to_string());
+        } else {
+            sections.push(TableBuilder::new(Self::issue_rows(issues)).to_markdown());
+        }
+
+        Ok(sections.join("\n\n"))
+    }
+
+    /// 构建 JSON/YAML 输出（Sprint 信息和 ticket 列表）
+    fn structured_output(
+        sprint: &JiraSprint,
+        issues: &[JiraIssue],
+    ) -> Result<HashMap<String, serde_json::Value>> {
+        let mut output: HashMap<String, serde_json::Value> = HashMap::new();
+        output.insert("sprint".to_string(), serde_json::to_value(sprint)?);
+        output.insert("issues".to_string(), serde_json::to_value(issues)?);
+        Ok(output)
+    }
+}
@@ -6,14 +6,13 @@ use std::fs;
 use std::path::PathBuf;
 use std::process::Command;
 
-use clap_complete::shells::Shell;
 use color_eyre::{
     eyre::{ContextCompat, WrapErr},
     Result,
 };
 
 use crate::base::settings::paths::Paths;
-use crate::base::shell::Detect;
+use crate::base::shell::{Detect, Shell};
 use crate::base::util::directory::DirectoryWalker;
 use crate::{log_break, log_debug, log_info, log_success, log_warning, Completion};
 
@@ -31,29 +30,19 @@ impl InstallCommand {
         log_info!("Installing shell completion scripts...");
 
         let shell = Detect::shell()?;
-        let completion_dir = Paths::completion_dir()?;
 
         log_debug!("Detecting shell type...");
         log_debug!("Detected: {}", shell);
 
-        // 创建 completion 目录
-        DirectoryWalker::new(&completion_dir).ensure_exists()?;
-        log_debug!("Completion directory: {}", completion_dir.display());
-
-        // 生成 completion 脚本
-        // 只生成当前检测到的 shell 类型的补全脚本
-        log_debug!("Generating completion scripts...");
-
-        let shell_type_str = shell.to_string();
-        log_debug!("Generating {} completion scripts...", shell_type_str);
-        Completion::generate_all_completions(
-            Some(shell_type_str),
-            Some(completion_dir.to_string_lossy().to_string()),
-        )?;
+        // clap 无法为部分 shell（如 nushell）生成 completion 脚本，需要手动配置
+        if shell.completion_shell().is_none() {
+            log_warning!("Completion scripts cannot be generated for {}", shell);
+            log_info!("See the \"Nushell\" section in README for manual completion setup");
+            return Ok(());
+        }
 
-        // 配置 shell 配置文件
-        log_debug!("Configuring shell configuration file...");
-        let config_result = Completion::configure_shell_config(&shell)?;
+        // 生成当前 shell 的 completion 脚本并配置 shell 配置文件
+        let config_result = Completion::install(&shell)?;
 
         if config_result.already_exists {
             log_success!(
@@ -68,6 +57,9 @@ impl InstallCommand {
         } else {
             log_success!("Completion config written to shell config file");
         }
+        if let Some(ref backup_path) = config_result.backup_path {
+            log_info!("  Backup of previous shell config: {:?}", backup_path);
+        }
 
         log_success!("  shell completion installation complete");
         log_break!();
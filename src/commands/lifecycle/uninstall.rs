@@ -1,5 +1,8 @@
 //! 卸载命令
 //! 删除 Workflow CLI 的所有配置
+//!
+//! 支持 `--dry-run` 预览将被删除的文件和对 shell 配置文件的修改（以差异显示），
+//! 以及 `--keep config,completions` 保留指定组件。
 
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -11,45 +14,64 @@ use color_eyre::{eyre::eyre, eyre::WrapErr, Result};
 
 use crate::base::dialog::ConfirmDialog;
 use crate::base::settings::paths::Paths;
-use crate::base::shell::{Detect, Reload};
+use crate::base::shell::{Detect, Reload, ShellConfigManager, ShellConfigPreview};
 use crate::base::util::Clipboard;
+use crate::cli::UninstallComponent;
+use crate::proxy::ProxyType;
 use crate::{
     log_break, log_debug, log_info, log_message, log_success, log_warning, Completion, ProxyManager,
 };
 
+/// 卸载计划
+///
+/// 记录卸载时将被删除的文件和将被修改的 shell 配置文件，用于 `--dry-run` 预览和确认前的展示。
+struct UninstallPlan {
+    /// 将被删除的二进制文件
+    binaries: Vec<String>,
+    /// 将被删除的 completion 文件和目录（保留 completion 时为空）
+    completion_paths: Vec<PathBuf>,
+    /// 将被删除的 TOML 配置文件（保留配置时为空）
+    config_files: Vec<PathBuf>,
+    /// 将对 shell 配置文件进行的修改（修改说明，修改预览）
+    shell_edits: Vec<(&'static str, ShellConfigPreview)>,
+}
+
 /// 卸载命令
 pub struct UninstallCommand;
 
 impl UninstallCommand {
     /// 运行卸载流程（一次性清理全部）
-    pub fn run() -> Result<()> {
+    ///
+    /// # 参数
+    ///
+    /// * `dry_run` - 只显示将被删除的文件和 shell 配置文件的修改，不实际执行
+    /// * `keep` - 保留的组件（配置文件、completion）
+    pub fn run(dry_run: bool, keep: &[UninstallComponent]) -> Result<()> {
+        let keep_config = keep.contains(&UninstallComponent::Config);
+        let keep_completions = keep.contains(&UninstallComponent::Completions);
+
+        let install_binary = Self::install_binary_path();
+        let plan = Self::collect_plan(keep_config, keep_completions)?;
+
+        if dry_run {
+            Self::show_plan(&plan, keep_config, keep_completions);
+            return Ok(());
+        }
+        let existing_binaries = plan.binaries;
+
         log_warning!("  Uninstall Workflow CLI");
         log_break!();
-        log_message!("This will remove all Workflow CLI configuration and binaries.");
+        log_message!("This will remove Workflow CLI configuration and binaries.");
         log_message!("This includes:");
-        log_message!("  - TOML configuration files (workflow.toml)");
-        log_message!("  - Binary files: workflow, install");
-        log_message!("  - Shell completion scripts");
-        log_break!();
-
-        // 显示将要删除的二进制文件
-        let binary_paths = Paths::binary_paths();
-        let mut existing_binaries = Vec::new();
-        for binary_path in &binary_paths {
-            let path = Path::new(binary_path);
-            if path.exists() {
-                existing_binaries.push(binary_path.clone());
-            }
+        if !keep_config {
+            log_message!("  - TOML configuration files (workflow.toml)");
         }
-
-        // 检查 install 二进制
-        let install_dir = Paths::binary_install_dir();
-        let install_path = PathBuf::from(&install_dir);
-        let install_name = Paths::binary_name("install");
-        let install_binary = install_path.join(install_name);
-        if install_binary.exists() {
-            existing_binaries.push(install_binary.to_string_lossy().to_string());
+        log_message!("  - Binary files: workflow, install");
+        if !keep_completions {
+            log_message!("  - Shell completion scripts");
         }
+        log_message!("Run with --dry-run to preview every file and shell config change.");
+        log_break!();
 
         if !existing_binaries.is_empty() {
             log_message!("Binary files to be removed:");
@@ -60,18 +82,21 @@ impl UninstallCommand {
         }
 
         // 第一步确认：是否删除二进制文件和 completion 脚本
-        if !ConfirmDialog::new("Remove binary files and shell completion scripts?")
-            .with_default(false)
-            .prompt()?
-        {
+        let prompt = if keep_completions {
+            "Remove binary files?"
+        } else {
+            "Remove binary files and shell completion scripts?"
+        };
+        if !ConfirmDialog::new(prompt).with_default(false).prompt()? {
             log_message!("Uninstall cancelled.");
             return Ok(());
         }
 
-        // 第二步确认：是否删除 TOML 配置文件
-        let remove_config = ConfirmDialog::new("Remove TOML config file (workflow.toml)?")
-            .with_default(true)
-            .prompt()?;
+        // 第二步确认：是否删除 TOML 配置文件（--keep config 时跳过）
+        let remove_config = !keep_config
+            && ConfirmDialog::new("Remove TOML config file (workflow.toml)?")
+                .with_default(true)
+                .prompt()?;
 
         // 删除二进制文件
         if !existing_binaries.is_empty() {
@@ -206,77 +231,82 @@ impl UninstallCommand {
             }
         }
 
-        // 卸载 shell completion（只要第一步确认就删除）
-        log_break!();
-        log_message!("Removing shell completion scripts...");
-        // 删除所有 shell 类型的 completion 文件（不依赖当前 shell）
-        let removal_result =
-            Completion::remove_completion_files(&clap_complete::shells::Shell::Zsh)?;
-
-        // 显示删除的文件
-        for file in &removal_result.removed_files {
-            log_info!("  Removed: {}", file.display());
-        }
+        // 卸载 shell completion（只要第一步确认就删除，--keep completions 时保留）
+        if keep_completions {
+            log_break!();
+            log_message!("Shell completion scripts will be kept (not removed).");
+        } else {
+            log_break!();
+            log_message!("Removing shell completion scripts...");
+            // 删除所有 shell 类型的 completion 文件（不依赖当前 shell）
+            let removal_result =
+                Completion::remove_completion_files(&crate::base::shell::Shell::Zsh)?;
+
+            // 显示删除的文件
+            for file in &removal_result.removed_files {
+                log_info!("  Removed: {}", file.display());
+            }
 
-        // 显示失败的文件
-        for (file, error) in &removal_result.failed_files {
-            log_info!("Failed to delete: {} ({})", file.display(), error);
-        }
+            // 显示失败的文件
+            for (file, error) in &removal_result.failed_files {
+                log_info!("Failed to delete: {} ({})", file.display(), error);
+            }
 
-        if removal_result.removed_count > 0 {
-            log_info!("  Completion script files removed");
-        }
+            if removal_result.removed_count > 0 {
+                log_info!("  Completion script files removed");
+            }
 
-        // 删除 completions 文件夹
-        let completion_dir = Paths::completion_dir();
-        if let Ok(dir) = completion_dir {
-            if dir.exists() {
-                // 先尝试删除空文件夹，如果失败（非空）则删除整个文件夹及其内容
-                match fs::remove_dir(&dir) {
-                    Ok(_) => {
-                        log_info!("  Removed: {}", dir.display());
-                    }
-                    Err(e) => {
-                        // 如果文件夹非空，使用 remove_dir_all 删除整个文件夹
-                        if e.kind() == std::io::ErrorKind::DirectoryNotEmpty {
-                            match fs::remove_dir_all(&dir) {
-                                Ok(_) => {
-                                    log_info!("  Removed: {}", dir.display());
-                                }
-                                Err(e2) => {
-                                    log_debug!(
-                                        "  Could not remove completions directory: {} ({})",
-                                        dir.display(),
-                                        e2
-                                    );
+            // 删除 completions 文件夹
+            let completion_dir = Paths::completion_dir();
+            if let Ok(dir) = completion_dir {
+                if dir.exists() {
+                    // 先尝试删除空文件夹，如果失败（非空）则删除整个文件夹及其内容
+                    match fs::remove_dir(&dir) {
+                        Ok(_) => {
+                            log_info!("  Removed: {}", dir.display());
+                        }
+                        Err(e) => {
+                            // 如果文件夹非空，使用 remove_dir_all 删除整个文件夹
+                            if e.kind() == std::io::ErrorKind::DirectoryNotEmpty {
+                                match fs::remove_dir_all(&dir) {
+                                    Ok(_) => {
+                                        log_info!("  Removed: {}", dir.display());
+                                    }
+                                    Err(e2) => {
+                                        log_debug!(
+                                            "  Could not remove completions directory: {} ({})",
+                                            dir.display(),
+                                            e2
+                                        );
+                                    }
                                 }
+                            } else {
+                                log_debug!(
+                                    "  Could not remove completions directory: {} ({})",
+                                    dir.display(),
+                                    e
+                                );
                             }
-                        } else {
-                            log_debug!(
-                                "  Could not remove completions directory: {} ({})",
-                                dir.display(),
-                                e
-                            );
                         }
                     }
                 }
             }
-        }
 
-        let config_file_removed = Completion::remove_completion_config_file()?;
-        if config_file_removed {
-            log_info!(
-                "  Removed: {}",
-                Paths::local_base_dir()?.join(".completions").display()
-            );
-        } else {
-            log_info!(
-                "  Completion config file not found: {}",
-                Paths::local_base_dir()?.join(".completions").display()
-            );
+            let config_file_removed = Completion::remove_completion_config_file()?;
+            if config_file_removed {
+                log_info!(
+                    "  Removed: {}",
+                    Paths::local_base_dir()?.join(".completions").display()
+                );
+            } else {
+                log_info!(
+                    "  Completion config file not found: {}",
+                    Paths::local_base_dir()?.join(".completions").display()
+                );
+            }
+            // 移除所有 shell 的 completion 配置
+            Completion::remove_all_completion_configs()?;
         }
-        // 移除所有 shell 的 completion 配置
-        Completion::remove_all_completion_configs()?;
 
         // 删除配置（需要第二步确认）
         if remove_config {
@@ -309,7 +339,9 @@ impl UninstallCommand {
         if !existing_binaries.is_empty() {
             log_message!("All Workflow CLI binary files have been removed.");
         }
-        log_message!("All Workflow CLI shell completion scripts have been removed.");
+        if !keep_completions {
+            log_message!("All Workflow CLI shell completion scripts have been removed.");
+        }
 
         // 尝试重新加载 shell 配置
         log_break!();
@@ -334,6 +366,122 @@ impl UninstallCommand {
         Ok(())
     }
 
+    /// 收集卸载计划
+    ///
+    /// 只读取文件系统和 shell 配置文件，不做任何修改。
+    ///
+    /// # 参数
+    ///
+    /// * `keep_config` - 是否保留 TOML 配置文件
+    /// * `keep_completions` - 是否保留 completion 脚本和 shell 配置文件中的 source 语句
+    fn collect_plan(keep_config: bool, keep_completions: bool) -> Result<UninstallPlan> {
+        let mut binaries: Vec<String> = Paths::binary_paths()
+            .into_iter()
+            .filter(|path| Path::new(path).exists())
+            .collect();
+        let install_binary = Self::install_binary_path();
+        if install_binary.exists() {
+            binaries.push(install_binary.to_string_lossy().to_string());
+        }
+
+        let mut shell_edits = Vec::new();
+        let completion_paths = if keep_completions {
+            Vec::new()
+        } else {
+            shell_edits.extend(
+                Completion::preview_remove_all_completion_configs()
+                    .into_iter()
+                    .map(|preview| ("remove completion source", preview)),
+            );
+            Completion::installed_completion_paths().unwrap_or_default()
+        };
+
+        // 代理设置总是会被移除（与 ProxyManager::disable() 一致）
+        if let Ok(Some(preview)) =
+            ShellConfigManager::preview_remove_env_vars(&ProxyType::all_env_keys())
+        {
+            shell_edits.push(("remove proxy settings", preview));
+        }
+
+        let config_files = if keep_config {
+            Vec::new()
+        } else {
+            Self::config_files()
+        };
+
+        Ok(UninstallPlan {
+            binaries,
+            completion_paths,
+            config_files,
+            shell_edits,
+        })
+    }
+
+    /// 显示卸载计划（`--dry-run`）
+    fn show_plan(plan: &UninstallPlan, keep_config: bool, keep_completions: bool) {
+        log_warning!("  Uninstall Workflow CLI (dry run, nothing will be removed)");
+        log_break!();
+
+        let sections: [(&str, Vec<String>); 3] = [
+            ("Binary files to be removed:", plan.binaries.clone()),
+            (
+                "Completion files and directories to be removed:",
+                plan.completion_paths.iter().map(|p| p.display().to_string()).collect(),
+            ),
+            (
+                "Configuration files to be removed:",
+                plan.config_files.iter().map(|p| p.display().to_string()).collect(),
+            ),
+        ];
+        for (title, paths) in &sections {
+            if paths.is_empty() {
+                continue;
+            }
+            log_message!("{}", title);
+            for path in paths {
+                log_message!("  - {}", path);
+            }
+            log_break!();
+        }
+
+        for (description, preview) in &plan.shell_edits {
+            log_message!(
+                "Shell config changes ({}): {}",
+                description,
+                preview.config_path.display()
+            );
+            for line in preview.diff().lines() {
+                log_message!("  {}", line);
+            }
+            log_break!();
+        }
+
+        if keep_config {
+            log_info!("Configuration files will be kept (--keep config).");
+        }
+        if keep_completions {
+            log_info!("Shell completion scripts will be kept (--keep completions).");
+        }
+        log_message!("Run without --dry-run to uninstall.");
+    }
+
+    /// install 二进制文件的路径
+    fn install_binary_path() -> PathBuf {
+        PathBuf::from(Paths::binary_install_dir()).join(Paths::binary_name("install"))
+    }
+
+    /// 获取已存在的 TOML 配置文件（workflow.toml 和 jira-users.toml）
+    fn config_files() -> Vec<PathBuf> {
+        let workflow_config = Paths::workflow_config().ok();
+        let jira_users_config = Paths::config_dir().ok().map(|dir| dir.join("jira-users.toml"));
+
+        [workflow_config, jira_users_config]
+            .into_iter()
+            .flatten()
+            .filter(|path| path.exists())
+            .collect()
+    }
+
     /// 从 shell 环境变量中移除代理设置
     fn remove_proxy_settings() -> Result<()> {
         let result = ProxyManager::disable().wrap_err("Failed to remove proxy settings")?;
@@ -345,6 +493,9 @@ impl UninstallCommand {
 
         if let Some(ref shell_config_path) = result.shell_config_path {
             log_success!("  Proxy settings removed from {:?}", shell_config_path);
+            if let Some(ref backup) = result.shell_config_backup {
+                log_info!("  Backup of previous config: {:?}", backup);
+            }
         }
 
         if let Some(ref unset_cmd) = result.unset_command {
@@ -414,23 +565,11 @@ impl UninstallCommand {
     fn remove_config_files() -> Result<Vec<String>> {
         let mut removed = Vec::new();
 
-        // 删除 workflow.toml
-        if let Ok(workflow_config_path) = Paths::workflow_config() {
-            if workflow_config_path.exists() {
-                fs::remove_file(&workflow_config_path)
-                    .wrap_err("Failed to remove workflow.toml")?;
-                removed.push(crate::base::settings::paths::WORKFLOW_CONFIG_FILE.to_string());
-            }
-        }
-
-        // 删除 jira-users.toml
-        if let Ok(config_dir) = Paths::config_dir() {
-            let jira_users_config_path = config_dir.join("jira-users.toml");
-            if jira_users_config_path.exists() {
-                fs::remove_file(&jira_users_config_path)
-                    .wrap_err("Failed to remove jira-users.toml")?;
-                removed.push("jira-users.toml".to_string());
-            }
+        for path in Self::config_files() {
+            let file_name =
+                path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+            fs::remove_file(&path).wrap_err_with(|| format!("Failed to remove {}", file_name))?;
+            removed.push(file_name);
         }
 
         Ok(removed)
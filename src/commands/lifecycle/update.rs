@@ -6,7 +6,7 @@ use std::env;
 use std::fs::{self, File};
 use std::io::{Read, Write};
 use std::path::{Path, PathBuf};
-use std::process::Command;
+use std::process::{Command, Stdio};
 use std::time::{Duration, UNIX_EPOCH};
 
 use color_eyre::{eyre::eyre, eyre::WrapErr, Result};
@@ -25,6 +25,8 @@ use crate::base::settings::paths::Paths;
 use crate::base::settings::Settings;
 use crate::base::shell::Detect;
 use crate::base::util::{detect_release_platform, Checksum, Unzip};
+use crate::commands::config::completion::COMPLETION_STALE_EXIT_CODE;
+use crate::proxy::ProxyManager;
 use crate::rollback::RollbackManager;
 use crate::{
     get_completion_files_for_shell, log_break, log_debug, log_error, log_info, log_success,
@@ -39,10 +41,20 @@ use std::os::unix::fs::PermissionsExt;
 #[allow(dead_code)]
 struct GitHubRelease {
     tag_name: String,
+    /// Release 页面地址（包含更新日志）
+    #[serde(default)]
+    html_url: Option<String>,
     #[allow(dead_code)]
     assets: Vec<ReleaseAsset>,
 }
 
+impl GitHubRelease {
+    /// 版本号（去掉 tag 的 `v` 前缀）
+    fn version(&self) -> String {
+        self.tag_name.trim_start_matches('v').to_string()
+    }
+}
+
 /// Release 资源文件
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
@@ -63,6 +75,96 @@ enum VersionComparison {
     Downgrade,
 }
 
+/// `update --check` 发现可用更新时的退出码
+///
+/// 与一般错误（退出码 1）区分，便于脚本和 CI 判断是否需要提醒更新。
+pub const UPDATE_AVAILABLE_EXIT_CODE: i32 = 10;
+
+/// 更新检查结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateCheck {
+    /// 当前版本号（不含 `v` 前缀）
+    pub current_version: String,
+    /// 最新版本号（不含 `v` 前缀）
+    pub latest_version: String,
+    /// 最新版本的 Release 页面（更新日志）地址
+    pub release_url: String,
+}
+
+impl UpdateCheck {
+    /// 最新版本是否高于当前版本
+    pub fn update_available(&self) -> bool {
+        matches!(
+            UpdateCommand::compare_versions(&self.current_version, &self.latest_version),
+            VersionComparison::NeedsUpdate
+        )
+    }
+}
+
+/// Workflow CLI 的安装方式
+///
+/// 根据当前可执行文件的位置判断，决定 `workflow update` 使用的更新方式。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallMethod {
+    /// 通过安装脚本或 `make install` 安装到 `Paths::binary_install_dir()`：
+    /// 下载 Release 包并运行其中的 `./install`（同时更新补全脚本）
+    Installer,
+    /// 从源码构建后直接运行（位于 Cargo 的 `target/debug` 或 `target/release` 目录，如 `make update`）：
+    /// 与 `Installer` 相同，将 Release 包安装到系统目录
+    Source,
+    /// 通过 Homebrew 安装：提示使用 `brew upgrade` 更新
+    Homebrew,
+    /// 独立放置的预编译二进制文件：下载 Release 包，校验后原子替换当前可执行文件
+    Standalone,
+}
+
+impl InstallMethod {
+    /// 根据可执行文件路径判断安装方式
+    ///
+    /// # 参数
+    ///
+    /// * `exe` - 可执行文件路径（应先解析符号链接，Homebrew 的命令是指向 `Cellar` 的符号链接）
+    pub fn detect(exe: &Path) -> Self {
+        let components: Vec<String> =
+            exe.components().map(|c| c.as_os_str().to_string_lossy().to_string()).collect();
+
+        if components.iter().any(|c| c == "Cellar") {
+            return InstallMethod::Homebrew;
+        }
+        if components
+            .windows(2)
+            .any(|w| w[0] == "target" && (w[1] == "debug" || w[1] == "release"))
+        {
+            return InstallMethod::Source;
+        }
+        if exe.parent() == Some(Path::new(&Paths::binary_install_dir())) {
+            return InstallMethod::Installer;
+        }
+        InstallMethod::Standalone
+    }
+
+    /// 判断当前运行的 Workflow CLI 的安装方式
+    ///
+    /// # 返回
+    ///
+    /// 返回安装方式和解析符号链接后的可执行文件路径。
+    pub fn current() -> Result<(Self, PathBuf)> {
+        let exe = env::current_exe().wrap_err("Failed to get current executable path")?;
+        let exe = exe.canonicalize().unwrap_or(exe);
+        Ok((Self::detect(&exe), exe))
+    }
+
+    /// 安装方式名称
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InstallMethod::Installer => "installer",
+            InstallMethod::Source => "source build",
+            InstallMethod::Homebrew => "homebrew",
+            InstallMethod::Standalone => "standalone binary",
+        }
+    }
+}
+
 /// 二进制文件状态
 #[derive(Debug)]
 struct BinaryStatus {
@@ -240,74 +342,139 @@ impl UpdateCommand {
                 Ok(v)
             }
             None => {
-                let url = format!(
-                    "{}/repos/zevwings/workflow.rs/releases/latest",
-                    crate::git::github::API_BASE
-                );
-                let retry_config = HttpRetryConfig::new();
-
-                let retry_result = Spinner::with("Fetching latest version...", || {
-                    HttpRetry::retry(
-                        || {
-                            // GitHub API 要求必须包含 User-Agent 头
-                            let mut headers = HeaderMap::new();
-                            headers.insert(
-                                "User-Agent",
-                                "workflow-cli"
-                                    .parse()
-                                    .wrap_err("Failed to parse User-Agent header")?,
-                            );
+                let version = Self::fetch_latest_release()?.version();
+                log_success!("  Latest version: v{}", version);
+                Ok(version)
+            }
+        }
+    }
 
-                            // 添加 Accept 头（GitHub API 推荐）
-                            headers.insert(
-                                "Accept",
-                                "application/vnd.github+json"
-                                    .parse()
-                                    .wrap_err("Failed to parse Accept header")?,
-                            );
+    /// 从 GitHub API 获取最新 Release 信息
+    ///
+    /// 请求前确保系统代理已应用到当前进程（见 `ProxyManager::ensure_proxy_enabled`），
+    /// 如果用户已配置 GitHub token，会使用 token 提高速率限制。
+    fn fetch_latest_release() -> Result<GitHubRelease> {
+        if let Err(e) = ProxyManager::ensure_proxy_enabled() {
+            log_debug!("Failed to apply system proxy settings: {}", e);
+        }
 
-                            // 可选地使用 GitHub token（如果用户已配置）
-                            // 使用 token 可以提高速率限制（从 60/小时 提升到 5000/小时）
-                            let settings = Settings::load();
-                            if let Some(token) = settings.github.get_current_token() {
-                                headers.insert(
-                                    "Authorization",
-                                    format!("Bearer {}", token)
-                                        .parse()
-                                        .wrap_err("Failed to parse Authorization header")?,
-                                );
-                                log_debug!("Using GitHub token for API request");
-                            }
+        let url = format!(
+            "{}/repos/zevwings/workflow.rs/releases/latest",
+            crate::git::github::API_BASE
+        );
+        let retry_config = HttpRetryConfig::new();
 
-                            let client = HttpClient::global()?;
-                            let config = RequestConfig::<Value, Value>::new().headers(&headers);
-                            client
-                                .get(&url, config)
-                                .wrap_err("Failed to fetch latest release from GitHub")
-                        },
-                        &retry_config,
-                        "Fetching latest version information",
-                    )
-                })?;
+        let retry_result = Spinner::with("Fetching latest version...", || {
+            HttpRetry::retry(
+                || {
+                    // GitHub API 要求必须包含 User-Agent 头
+                    let mut headers = HeaderMap::new();
+                    headers.insert(
+                        "User-Agent",
+                        "workflow-cli".parse().wrap_err("Failed to parse User-Agent header")?,
+                    );
 
-                let response = retry_result.result;
-                if !retry_result.succeeded_on_first_attempt {
-                    log_success!(
-                        "Fetching latest version information succeeded after {} retry attempts",
-                        retry_result.retry_count
+                    // 添加 Accept 头（GitHub API 推荐）
+                    headers.insert(
+                        "Accept",
+                        "application/vnd.github+json"
+                            .parse()
+                            .wrap_err("Failed to parse Accept header")?,
                     );
-                }
 
-                // 检查响应状态码并处理错误
-                Self::handle_github_api_error(&response)?;
+                    // 可选地使用 GitHub token（如果用户已配置）
+                    // 使用 token 可以提高速率限制（从 60/小时 提升到 5000/小时）
+                    let settings = Settings::load();
+                    if let Some(token) = settings.github.get_current_token() {
+                        headers.insert(
+                            "Authorization",
+                            format!("Bearer {}", token)
+                                .parse()
+                                .wrap_err("Failed to parse Authorization header")?,
+                        );
+                        log_debug!("Using GitHub token for API request");
+                    }
 
-                let release: GitHubRelease = response.as_json()?;
-                let version = release.tag_name.trim_start_matches('v').to_string();
+                    let client = HttpClient::global()?;
+                    let config = RequestConfig::<Value, Value>::new().headers(&headers);
+                    client.get(&url, config).wrap_err("Failed to fetch latest release from GitHub")
+                },
+                &retry_config,
+                "Fetching latest version information",
+            )
+        })?;
 
-                log_success!("  Latest version: v{}", version);
-                Ok(version)
-            }
+        let response = retry_result.result;
+        if !retry_result.succeeded_on_first_attempt {
+            log_success!(
+                "Fetching latest version information succeeded after {} retry attempts",
+                retry_result.retry_count
+            );
         }
+
+        // 检查响应状态码并处理错误
+        Self::handle_github_api_error(&response)?;
+
+        response.as_json()
+    }
+
+    /// 构建指定版本的 Release 页面地址
+    ///
+    /// # 参数
+    ///
+    /// * `version` - 版本号（不含 `v` 前缀）
+    ///
+    /// # 返回
+    ///
+    /// 返回 GitHub Release 页面地址，如 `https://github.com/zevwings/workflow.rs/releases/tag/v1.2.3`。
+    pub fn release_url(version: &str) -> String {
+        format!(
+            "{}/zevwings/workflow.rs/releases/tag/v{}",
+            crate::git::github::BASE,
+            version
+        )
+    }
+
+    /// 检查是否有可用更新（不下载、不安装）
+    ///
+    /// 从 GitHub Releases 获取最新版本并与当前版本比较，输出检查结果和更新日志地址。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `UpdateCheck`，调用方根据 `update_available()` 决定退出码
+    /// （见 `UPDATE_AVAILABLE_EXIT_CODE`）。
+    pub fn check() -> Result<UpdateCheck> {
+        let current_version =
+            Self::get_current_version()?.unwrap_or_else(|| env!("CARGO_PKG_VERSION").to_string());
+        log_info!("Current version: v{}", current_version);
+
+        let release = Self::fetch_latest_release()?;
+        let latest_version = release.version();
+        let check = UpdateCheck {
+            release_url: release
+                .html_url
+                .filter(|url| !url.is_empty())
+                .unwrap_or_else(|| Self::release_url(&latest_version)),
+            current_version,
+            latest_version,
+        };
+
+        if check.update_available() {
+            log_warning!(
+                "Update available: v{} -> v{}",
+                check.current_version,
+                check.latest_version
+            );
+            log_info!("Changelog: {}", check.release_url);
+            log_info!("Run 'workflow update' to install the latest version.");
+        } else {
+            log_success!(
+                "Already at latest version (v{}), no update needed",
+                check.current_version
+            );
+        }
+
+        Ok(check)
     }
 
     /// 第三步：构建下载 URL
@@ -351,8 +518,10 @@ impl UpdateCommand {
 
                 // 使用 get_stream 方法流式下载二进制文件
                 let http_client = HttpClient::global()?;
+                let config = RequestConfig::<Value, Value>::new()
+                    .timeout(Settings::get().http.download_timeout());
                 let mut response = http_client
-                    .stream(HttpMethod::Get, url, RequestConfig::<Value, Value>::new())
+                    .stream(HttpMethod::Get, url, config)
                     .wrap_err("Failed to send HTTP request")?;
 
                 if !response.status().is_success() {
@@ -413,6 +582,83 @@ impl UpdateCommand {
         Ok(())
     }
 
+    /// 验证文件完整性
+    ///
+    /// 下载 Release 包对应的 SHA256 校验和文件并验证下载的文件。
+    /// 校验和文件不存在（404）时，`allow_missing` 为 `true` 则跳过验证并给出警告，否则返回错误。
+    fn verify_checksum(download_url: &str, archive_path: &Path, allow_missing: bool) -> Result<()> {
+        let checksum_url = Checksum::build_url(download_url);
+
+        // 下载校验和文件（使用 http 模块）
+        let http_client = HttpClient::global()?;
+        let retry_config = HttpRetryConfig::new();
+
+        // 尝试下载校验和文件，如果不存在（404）则跳过验证
+        match HttpRetry::retry(
+            || {
+                let config = RequestConfig::<Value, Value>::new();
+                let response = http_client.get(&checksum_url, config)?;
+                // 使用 ensure_success_with 统一处理 404 错误
+                let response = response.ensure_success_with(|r| {
+                    if r.status == 404 {
+                        eyre!("Checksum file not found (404)")
+                    } else {
+                        eyre!(
+                            "HTTP request failed with status {}: {}",
+                            r.status,
+                            r.status_text
+                        )
+                    }
+                })?;
+                response.as_text()
+            },
+            &retry_config,
+            "Downloading checksum file",
+        ) {
+            Ok(retry_result) => {
+                let checksum_content = retry_result.result;
+                if !retry_result.succeeded_on_first_attempt {
+                    log_success!(
+                        "Downloading checksum file succeeded after {} retry attempts",
+                        retry_result.retry_count
+                    );
+                }
+                // 解析哈希值（使用 checksum 模块）
+                let expected_hash = Checksum::parse_hash_from_content(&checksum_content)
+                    .wrap_err("Failed to parse checksum file")?;
+
+                // 验证文件（使用 checksum 模块）
+                Checksum::verify(archive_path, &expected_hash)?;
+            }
+            Err(e) => {
+                let missing = e.to_string().contains("404") || e.to_string().contains("not found");
+                if missing && !allow_missing {
+                    return Err(e.wrap_err(format!(
+                        "Checksum file not found ({}), refusing to replace the executable without verification. Re-run with --skip-verify to update anyway",
+                        checksum_url
+                    )));
+                }
+                // 如果是 404 错误，跳过验证但给出警告
+                if missing {
+                    log_warning!("Checksum file not found, skipping integrity verification");
+                    log_warning!("  Checksum URL: {}", checksum_url);
+                    log_warning!("  This may indicate the release does not include checksum files");
+                    log_warning!("  Proceeding with update without verification...");
+
+                    // 仍然计算并显示文件的 SHA256，供用户参考
+                    if let Ok(actual_hash) = Checksum::calculate_file_sha256(archive_path) {
+                        log_info!("Downloaded file SHA256: {}", actual_hash);
+                    }
+                } else {
+                    // 其他错误，仍然返回错误
+                    return Err(e.wrap_err("Failed to download checksum file"));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// 第五步：解压文件
     ///
     /// 解压 tar.gz 或 zip 文件到指定目录。
@@ -486,6 +732,112 @@ impl UpdateCommand {
         Ok(())
     }
 
+    /// 原子替换可执行文件
+    ///
+    /// 先将新文件复制到目标文件所在目录的临时文件（保证与目标在同一文件系统），
+    /// 设置执行权限后通过 `rename` 替换目标文件，替换过程中不会出现不完整的可执行文件。
+    ///
+    /// - Unix：`rename` 只替换目录项，正在运行的进程继续使用旧文件的 inode；
+    ///   macOS 上不能直接覆盖写入正在运行的二进制文件（会导致代码签名校验失败，进程被系统终止），因此必须使用 `rename`
+    /// - Windows：正在运行的可执行文件不能被覆盖，但可以重命名，先将其重命名为 `.old`，再将新文件移动到原位置
+    ///
+    /// # 参数
+    ///
+    /// * `new_binary` - 新的可执行文件
+    /// * `target` - 要替换的可执行文件（通常是当前运行的可执行文件）
+    ///
+    /// # 错误
+    ///
+    /// 如果复制、设置权限或重命名失败（如没有目标目录的写入权限），返回相应的错误信息，目标文件保持不变。
+    pub fn replace_executable(new_binary: &Path, target: &Path) -> Result<()> {
+        let file_name = target
+            .file_name()
+            .ok_or_else(|| eyre!("Invalid executable path: {}", target.display()))?
+            .to_string_lossy()
+            .to_string();
+        let temp_path = target.with_file_name(format!(".{}.new", file_name));
+
+        let result = (|| -> Result<()> {
+            fs::copy(new_binary, &temp_path).wrap_err_with(|| {
+                format!(
+                    "Failed to copy new binary to {} (check write permission)",
+                    temp_path.display()
+                )
+            })?;
+
+            #[cfg(unix)]
+            fs::set_permissions(&temp_path, fs::Permissions::from_mode(0o755))
+                .wrap_err("Failed to set executable permission")?;
+
+            #[cfg(windows)]
+            {
+                let old_path = target.with_file_name(format!("{}.old", file_name));
+                if old_path.exists() {
+                    fs::remove_file(&old_path).wrap_err_with(|| {
+                        format!("Failed to remove old binary: {}", old_path.display())
+                    })?;
+                }
+                fs::rename(target, &old_path).wrap_err("Failed to move running executable")?;
+                if let Err(e) = fs::rename(&temp_path, target) {
+                    // 恢复原文件
+                    let _ = fs::rename(&old_path, target);
+                    return Err(e).wrap_err("Failed to replace executable");
+                }
+            }
+
+            #[cfg(not(windows))]
+            fs::rename(&temp_path, target)
+                .wrap_err_with(|| format!("Failed to replace executable: {}", target.display()))?;
+
+            Ok(())
+        })();
+
+        if result.is_err() && temp_path.exists() {
+            let _ = fs::remove_file(&temp_path);
+        }
+        result
+    }
+
+    /// 更新独立放置的预编译二进制文件
+    ///
+    /// 下载并校验 Release 包、解压后原子替换当前可执行文件（见 `replace_executable`）。
+    /// 不运行 `./install`，因此不会修改系统目录和补全脚本；替换失败时原文件保持不变，无需回滚。
+    /// Release 缺少校验和文件时拒绝替换，除非指定了 `skip_verify`。
+    fn update_standalone(
+        exe: &Path,
+        download_url: &str,
+        temp_manager: &TempDirManager,
+        skip_verify: bool,
+    ) -> Result<()> {
+        Self::download_file(download_url, &temp_manager.archive_path)?;
+        log_break!();
+
+        Self::verify_checksum(download_url, &temp_manager.archive_path, skip_verify)?;
+        log_break!();
+
+        Self::extract_archive(&temp_manager.archive_path, &temp_manager.extract_dir)?;
+        log_break!();
+
+        let new_binary = temp_manager.extract_dir.join(Paths::binary_name("workflow"));
+        if !new_binary.exists() {
+            color_eyre::eyre::bail!(
+                "Binary not found in update package: {}",
+                new_binary.display()
+            );
+        }
+
+        Spinner::with("Replacing executable...", || {
+            Self::replace_executable(&new_binary, exe)
+        })?;
+        log_success!("  Replaced: {}", exe.display());
+
+        if !Self::check_executable(exe)? {
+            color_eyre::eyre::bail!("Updated binary is not executable: {}", exe.display());
+        }
+
+        Ok(())
+    }
+
     // ==================== 验证相关 ====================
 
     // --- 基础验证工具方法 ---
@@ -656,6 +1008,49 @@ impl UpdateCommand {
         Ok(all_valid)
     }
 
+    /// 检查 completion 脚本是否与新版本的命令一致，不一致时询问是否重新生成
+    ///
+    /// 当前进程仍是旧版本，无法得知新版本的命令定义，因此通过新安装的二进制文件运行
+    /// `workflow completion check --exit-code`（按校验和比较脚本）。检查或重新生成失败只输出警告，不影响更新结果。
+    fn prompt_regenerate_completions() {
+        let binary =
+            PathBuf::from(Paths::binary_install_dir()).join(Paths::binary_name("workflow"));
+        let stale = Command::new(&binary)
+            .args(["completion", "check", "--exit-code"])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .map(|status| status.code() == Some(COMPLETION_STALE_EXIT_CODE));
+
+        match stale {
+            Ok(true) => {}
+            Ok(false) => return,
+            Err(e) => {
+                log_debug!("Failed to check completion scripts: {}", e);
+                return;
+            }
+        }
+
+        log_break!();
+        log_warning!("Completion scripts are stale: the command surface changed in this version");
+        let regenerate = ConfirmDialog::new("Regenerate shell completion scripts now?")
+            .with_default(true)
+            .prompt()
+            .unwrap_or(false);
+        if !regenerate {
+            log_info!("You can regenerate them later with: workflow completion install");
+            return;
+        }
+
+        match Command::new(&binary).args(["completion", "install"]).status() {
+            Ok(status) if status.success() => {}
+            _ => {
+                log_warning!("Failed to regenerate completion scripts");
+                log_info!("Run `workflow completion install` manually");
+            }
+        }
+    }
+
     /// 验证安装结果
     ///
     /// 只验证文件是否存在和是否有执行权限，不执行任何命令验证。
@@ -748,10 +1143,27 @@ impl UpdateCommand {
     /// 8. 解压文件
     /// 9. 使用 ./install 安装二进制文件和补全脚本
     /// 10. 验证安装结果
-    pub fn update(version: Option<String>) -> Result<()> {
+    ///
+    /// 更新方式由安装方式决定（见 `InstallMethod`）：独立放置的预编译二进制文件不创建备份、
+    /// 不运行 `./install`，解压后直接原子替换当前可执行文件；Homebrew 安装提示使用 `brew upgrade`。
+    ///
+    /// 独立放置的二进制文件必须通过校验和验证才会被替换，`skip_verify` 为 `true` 时
+    /// 允许在 Release 缺少校验和文件时继续更新。
+    pub fn update(version: Option<String>, skip_verify: bool) -> Result<()> {
         log_info!("Starting Workflow CLI update...");
         log_break!();
 
+        let (install_method, exe) = InstallMethod::current()?;
+        log_info!(
+            "Install method: {} ({})",
+            install_method.as_str(),
+            exe.display()
+        );
+        if install_method == InstallMethod::Homebrew {
+            log_info!("Workflow CLI is managed by Homebrew, please run: brew upgrade workflow");
+            return Ok(());
+        }
+
         // 获取当前版本
         let current_version = Self::get_current_version()?;
         if let Some(ref current) = current_version {
@@ -815,6 +1227,20 @@ impl UpdateCommand {
         }
         log_break!();
 
+        // 独立放置的二进制文件：直接替换当前可执行文件
+        if install_method == InstallMethod::Standalone {
+            let temp_manager = TempDirManager::new(&target_version, &platform)?;
+            let download_url = Self::build_download_url(&target_version, &platform);
+            log_info!("Download URL: {}", download_url);
+            log_break!();
+
+            let result = Self::update_standalone(&exe, &download_url, &temp_manager, skip_verify);
+            Self::cleanup_update_resources(&temp_manager.temp_dir, None);
+            result?;
+            log_success!("Workflow CLI update complete! (v{})", target_version);
+            return Ok(());
+        }
+
         // 第四步：创建备份（在更新前备份当前版本）
         let backup_info = match RollbackManager::create_backup() {
             Ok(backup) => {
@@ -843,71 +1269,7 @@ impl UpdateCommand {
             log_break!();
 
             // 第七步：验证文件完整性
-            let checksum_url = Checksum::build_url(&download_url);
-
-            // 下载校验和文件（使用 http 模块）
-            let http_client = HttpClient::global()?;
-            let retry_config = HttpRetryConfig::new();
-
-            // 尝试下载校验和文件，如果不存在（404）则跳过验证
-            match HttpRetry::retry(
-                || {
-                    let config = RequestConfig::<Value, Value>::new();
-                    let response = http_client.get(&checksum_url, config)?;
-                    // 使用 ensure_success_with 统一处理 404 错误
-                    let response = response.ensure_success_with(|r| {
-                        if r.status == 404 {
-                            eyre!("Checksum file not found (404)")
-                        } else {
-                            eyre!(
-                                "HTTP request failed with status {}: {}",
-                                r.status,
-                                r.status_text
-                            )
-                        }
-                    })?;
-                    response.as_text()
-                },
-                &retry_config,
-                "Downloading checksum file",
-            ) {
-                Ok(retry_result) => {
-                    let checksum_content = retry_result.result;
-                    if !retry_result.succeeded_on_first_attempt {
-                        log_success!(
-                            "Downloading checksum file succeeded after {} retry attempts",
-                            retry_result.retry_count
-                        );
-                    }
-                    // 解析哈希值（使用 checksum 模块）
-                    let expected_hash = Checksum::parse_hash_from_content(&checksum_content)
-                        .wrap_err("Failed to parse checksum file")?;
-
-                    // 验证文件（使用 checksum 模块）
-                    Checksum::verify(&temp_manager.archive_path, &expected_hash)?;
-                }
-                Err(e) => {
-                    // 如果是 404 错误，跳过验证但给出警告
-                    if e.to_string().contains("404") || e.to_string().contains("not found") {
-                        log_warning!("Checksum file not found, skipping integrity verification");
-                        log_warning!("  Checksum URL: {}", checksum_url);
-                        log_warning!(
-                            "  This may indicate the release does not include checksum files"
-                        );
-                        log_warning!("  Proceeding with update without verification...");
-
-                        // 仍然计算并显示文件的 SHA256，供用户参考
-                        if let Ok(actual_hash) =
-                            Checksum::calculate_file_sha256(&temp_manager.archive_path)
-                        {
-                            log_info!("Downloaded file SHA256: {}", actual_hash);
-                        }
-                    } else {
-                        // 其他错误，仍然返回错误
-                        return Err(e.wrap_err("Failed to download checksum file"));
-                    }
-                }
-            }
+            Self::verify_checksum(&download_url, &temp_manager.archive_path, true)?;
             log_break!();
 
             // 第八步：解压文件
@@ -942,6 +1304,7 @@ impl UpdateCommand {
                     backup_info.as_ref().map(|b| &b.backup_info),
                 );
                 log_success!("Workflow CLI update complete! All verifications passed.");
+                Self::prompt_regenerate_completions();
                 Ok(())
             }
             Err(e) => {
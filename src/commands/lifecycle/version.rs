@@ -1,7 +1,8 @@
 //! 版本命令
 //! 显示 Workflow CLI 的版本信息
 
-use crate::log_success;
+use crate::base::util::VersionInfo;
+use crate::{log_info, log_message, log_success};
 use color_eyre::Result;
 
 /// 版本命令
@@ -10,11 +11,26 @@ pub struct VersionCommand;
 impl VersionCommand {
     /// 显示当前版本信息
     ///
-    /// 从编译时嵌入的版本号获取（使用 env! 宏）。
-    /// 注意：env! 宏在编译时展开，所以这个值在运行时总是可用的。
-    pub fn show() -> Result<()> {
-        const VERSION: &str = env!("CARGO_PKG_VERSION");
-        log_success!("workflow v{}", VERSION);
+    /// 版本号和构建信息在编译时嵌入（见 `VersionInfo`），运行时总是可用。
+    ///
+    /// # 参数
+    ///
+    /// * `json` - 以 JSON 格式输出版本号、Git commit、构建日期、rustc 版本和目标平台
+    pub fn show(json: bool) -> Result<()> {
+        let info = VersionInfo::current();
+
+        if json {
+            log_message!("{}", info.to_json()?);
+            return Ok(());
+        }
+
+        log_success!("workflow v{}", info.version);
+        if let Some(commit) = info.git_commit {
+            log_info!("  Commit: {}", commit);
+        }
+        if let Some(build_date) = info.build_date {
+            log_info!("  Built: {}", build_date);
+        }
         Ok(())
     }
 }
@@ -2,7 +2,7 @@
 //! 交互式设置 LLM 相关配置（provider, url, key, model, language）
 
 use crate::base::dialog::{FormBuilder, GroupConfig};
-use crate::base::llm::{get_supported_language_display_names, SUPPORTED_LANGUAGES};
+use crate::base::llm::{get_output_language_display_name, get_output_language_display_names};
 use crate::base::settings::paths::Paths;
 use crate::base::settings::settings::{LLMSettings, Settings};
 use crate::jira::config::ConfigManager;
@@ -95,22 +95,18 @@ impl LLMSetupCommand {
                             .default(existing.provider.clone())
                     })
                     .step_if("llm_provider", "openai", |f| {
-                        let mut form =
-                            f.add_text("openai_key", openai_key_prompt).allow_empty(true);
-                        if let Some(ref key) = existing.openai.key {
-                            form = form.default(key.clone());
-                        }
-                        form.add_text("openai_model", openai_model_prompt)
+                        // 不设置默认值，按 Enter 保留现有 key
+                        f.add_password("openai_key", openai_key_prompt)
+                            .allow_empty(true)
+                            .add_text("openai_model", openai_model_prompt)
                             .allow_empty(true)
                             .default(openai_model_default)
                     })
                     .step_if("llm_provider", "deepseek", |f| {
-                        let mut form =
-                            f.add_text("deepseek_key", deepseek_key_prompt).allow_empty(true);
-                        if let Some(ref key) = existing.deepseek.key {
-                            form = form.default(key.clone());
-                        }
-                        form.add_text("deepseek_model", deepseek_model_prompt)
+                        // 不设置默认值，按 Enter 保留现有 key
+                        f.add_password("deepseek_key", deepseek_key_prompt)
+                            .allow_empty(true)
+                            .add_text("deepseek_model", deepseek_model_prompt)
                             .allow_empty(true)
                             .default(deepseek_model_default)
                     })
@@ -132,10 +128,10 @@ impl LLMSetupCommand {
                             }
                         });
 
-                        let mut form = form.add_text("proxy_key", proxy_key_prompt);
+                        let mut form = form.add_password("proxy_key", proxy_key_prompt);
                         if has_existing_proxy_key {
                             form = form.allow_empty(true);
-                            // 不设置默认值，这样用户按 Enter 时会保留现有值（显示 *** 而不是明文）
+                            // 不设置默认值，用户按 Enter 时保留现有值
                         } else {
                             form = form.required();
                         }
@@ -168,20 +164,16 @@ impl LLMSetupCommand {
                     })
                     .step(|f| {
                         // LLM output language (所有 provider 共享)
-                        let language_display_names = get_supported_language_display_names();
+                        // 支持的语言之后追加自动检测选项（auto）
+                        let language_display_names = get_output_language_display_names();
                         let current_language = if !existing.language.is_empty() {
                             existing.language.as_str()
                         } else {
                             "en" // 默认英文
                         };
-                        let current_idx = SUPPORTED_LANGUAGES
-                            .iter()
-                            .position(|lang| lang.code == current_language)
-                            .unwrap_or(0);
-                        let default_display_name = language_display_names
-                            .get(current_idx)
-                            .cloned()
-                            .unwrap_or_else(|| language_display_names[0].clone());
+                        let default_display_name =
+                            get_output_language_display_name(current_language)
+                                .unwrap_or_else(|| language_display_names[0].clone());
                         let llm_language_prompt =
                             format!("Select LLM output language [current: {}]", current_language);
                         f.add_selection(
@@ -1,12 +1,11 @@
 use crate::base::constants::errors::input_reading;
 use crate::base::dialog::InputDialog;
-use crate::base::indicator::Progress;
-use crate::jira::logs::{JiraLogs, ProgressCallback};
+use crate::jira::attachments::DownloadProgressDisplay;
+use crate::jira::logs::JiraLogs;
 use crate::jira::Jira;
 use crate::{log_break, log_info, log_success};
 use color_eyre::{eyre::WrapErr, Result};
 use regex::Regex;
-use std::sync::{Arc, Mutex};
 
 /// 下载日志命令
 pub struct DownloadCommand;
@@ -46,28 +45,9 @@ impl DownloadCommand {
             color_eyre::eyre::bail!("No log attachments found for {}", jira_id);
         }
 
-        // 创建 Progress Bar
-        let progress = Arc::new(Mutex::new(Progress::new(
-            total_files,
-            "Downloading logs...",
-        )));
-        let progress_clone = progress.clone();
-
-        // 创建回调函数，更新进度条
-        let callback: ProgressCallback = Box::new(move |msg| {
-            if !msg.is_empty() {
-                if msg.starts_with("Downloaded:") || msg.contains("Downloaded:") {
-                    if let Ok(pb) = progress_clone.lock() {
-                        pb.inc(1);
-                    }
-                } else if msg.starts_with("Failed to download:") {
-                    // 失败的文件也计入进度
-                    if let Ok(pb) = progress_clone.lock() {
-                        pb.inc(1);
-                    }
-                }
-            }
-        });
+        // 创建进度显示（有 Content-Length 时显示字节进度条，否则显示 spinner）
+        let progress = DownloadProgressDisplay::new(total_files, "Downloading logs...");
+        let callback = progress.callback();
 
         // 创建 JiraLogs 实例
         let logs = JiraLogs::new().wrap_err("Failed to initialize JiraLogs")?;
@@ -78,9 +58,7 @@ impl DownloadCommand {
             .wrap_err("Failed to download attachments from Jira")?;
 
         // 完成进度条
-        if let Ok(pb) = progress.lock() {
-            pb.finish_ref();
-        }
+        progress.finish();
 
         // 显示下载结果
         if !result.failed_files.is_empty() {
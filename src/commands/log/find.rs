@@ -1,8 +1,9 @@
 use crate::base::constants::errors::input_reading;
 use crate::base::dialog::InputDialog;
 use crate::base::util::Clipboard;
-use crate::jira::logs::JiraLogs;
-use crate::{log_debug, log_error, log_success};
+use crate::cli::LogOutputFormat;
+use crate::jira::logs::{JiraLogs, LogFilter};
+use crate::{log_debug, log_error, log_message, log_success, log_warning};
 use color_eyre::{eyre::WrapErr, Result};
 
 /// 查找请求 ID 命令
@@ -10,7 +11,16 @@ pub struct FindCommand;
 
 impl FindCommand {
     /// 查找请求 ID
-    pub fn find_request_id(jira_id: Option<String>, request_id: Option<String>) -> Result<()> {
+    ///
+    /// 如果设置了 `filter`，请求 ID 对应的条目必须满足级别和组件条件。
+    ///
+    /// `format` 为 `Jsonl` 时，将匹配的条目输出为一行 JSON，不复制响应内容到剪贴板。
+    pub fn find_request_id(
+        jira_id: Option<String>,
+        request_id: Option<String>,
+        filter: LogFilter,
+        format: LogOutputFormat,
+    ) -> Result<()> {
         // 1. 获取 JIRA ID（从参数或交互式输入）
         let jira_id = if let Some(id) = jira_id {
             id
@@ -32,9 +42,31 @@ impl FindCommand {
                 .wrap_err("Failed to read request ID")?
         };
 
-        // 4. 提取响应内容
+        // 4. JSON Lines 输出：只输出匹配的条目
+        if format == LogOutputFormat::Jsonl {
+            if let Some(entry) = logs.find_request_id(&jira_id, &req_id)? {
+                if filter.matches(&entry) {
+                    log_message!("{}", entry.to_json_line()?);
+                }
+            }
+            return Ok(());
+        }
+
+        // 5. 按级别和组件过滤
         log_debug!("Searching for request ID: {}...", req_id);
 
+        if !filter.is_empty() {
+            let entry = logs.find_request_id(&jira_id, &req_id)?;
+            if !entry.map(|entry| filter.matches(&entry)).unwrap_or(false) {
+                log_warning!(
+                    "Request ID {} not found or filtered out by level/component filters",
+                    req_id
+                );
+                return Ok(());
+            }
+        }
+
+        // 6. 提取响应内容
         let response_content = logs.extract_response_content(&jira_id, &req_id).map_err(|e| {
             log_error!("Failed to extract response content: {}", e);
             e
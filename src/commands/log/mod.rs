@@ -1,11 +1,13 @@
 //! 日志操作命令模块
 //!
-//! 提供从 Jira ticket 下载日志、查找请求 ID、搜索关键词等功能。
+//! 提供从 Jira ticket 下载日志、查找请求 ID、搜索关键词、跟踪日志等功能。
 
 pub mod download;
 pub mod find;
 pub mod search;
+pub mod tail;
 
 pub use download::DownloadCommand;
 pub use find::FindCommand;
 pub use search::SearchCommand;
+pub use tail::TailCommand;
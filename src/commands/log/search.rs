@@ -1,17 +1,37 @@
 use crate::base::constants::errors::input_reading;
 use crate::base::dialog::InputDialog;
 use crate::base::table::{TableBuilder, TableStyle};
-use crate::jira::logs::JiraLogs;
+use crate::cli::LogOutputFormat;
 use crate::jira::logs::SearchResultRow;
-use crate::{log_break, log_debug, log_message, log_success, log_warning};
+use crate::jira::logs::{JiraLogs, LogEntry, LogFilter};
+use crate::{log_break, log_debug, log_info, log_message, log_success, log_warning};
 use color_eyre::{eyre::WrapErr, Result};
+use serde::Serialize;
+
+/// JSON Lines 输出记录（附带条目来源文件）
+#[derive(Serialize)]
+struct JsonlRecord<'a> {
+    source: &'a str,
+    #[serde(flatten)]
+    entry: &'a LogEntry,
+}
 
 /// 搜索关键词命令
 pub struct SearchCommand;
 
 impl SearchCommand {
     /// 搜索关键词
-    pub fn search(jira_id: Option<String>, search_term: Option<String>) -> Result<()> {
+    ///
+    /// 搜索结果会按 `filter` 中的级别和组件条件过滤；启用级别过滤时，
+    /// 无法识别级别的条目会被跳过，并在最后报告跳过的数量。
+    ///
+    /// `format` 为 `Jsonl` 时，每个匹配条目输出为一行 JSON，不输出其他提示信息。
+    pub fn search(
+        jira_id: Option<String>,
+        search_term: Option<String>,
+        filter: LogFilter,
+        format: LogOutputFormat,
+    ) -> Result<()> {
         // 1. 获取 JIRA ID（从参数或交互式输入）
         let jira_id = if let Some(id) = jira_id {
             id
@@ -36,17 +56,34 @@ impl SearchCommand {
         };
 
         // 4. 调用库函数执行搜索
-        log_debug!("Searching for: '{}'...", term);
+        if format == LogOutputFormat::Text {
+            log_debug!("Searching for: '{}'...", term);
+        }
 
         // 同时搜索两个文件
         let (api_results, flutter_api_results) = logs
             .search_keyword_both_files(&jira_id, &term)
             .unwrap_or_else(|_| (Vec::new(), Vec::new()));
 
+        // 按级别和组件过滤
+        let api_filtered = filter.apply(api_results);
+        let flutter_api_filtered = filter.apply(flutter_api_results);
+        let skipped_count =
+            api_filtered.skipped_unparseable + flutter_api_filtered.skipped_unparseable;
+        let api_results = api_filtered.entries;
+        let flutter_api_results = flutter_api_filtered.entries;
+
+        if format == LogOutputFormat::Jsonl {
+            Self::output_jsonl("api.log", &api_results)?;
+            Self::output_jsonl("flutter-api.log", &flutter_api_results)?;
+            return Ok(());
+        }
+
         let total_count = api_results.len() + flutter_api_results.len();
 
         if total_count == 0 {
             log_warning!("No matches found for '{}'", term);
+            Self::report_skipped(skipped_count);
             return Ok(());
         }
 
@@ -90,6 +127,32 @@ impl SearchCommand {
             );
         }
 
+        Self::report_skipped(skipped_count);
+
         Ok(())
     }
+
+    /// 以 JSON Lines 格式输出条目（每行一个 JSON 对象）
+    fn output_jsonl(source: &str, entries: &[LogEntry]) -> Result<()> {
+        for entry in entries {
+            let record = JsonlRecord { source, entry };
+            log_message!(
+                "{}",
+                serde_json::to_string(&record).wrap_err("Failed to serialize log entry")?
+            );
+        }
+        Ok(())
+    }
+
+    /// 报告因无法解析级别而被跳过的条目数量
+    fn report_skipped(skipped_count: usize) {
+        if skipped_count > 0 {
+            log_break!();
+            log_info!(
+                "Skipped {} entr{} without a recognizable log level",
+                skipped_count,
+                if skipped_count == 1 { "y" } else { "ies" }
+            );
+        }
+    }
 }
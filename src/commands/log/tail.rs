@@ -0,0 +1,72 @@
+use crate::base::constants::errors::input_reading;
+use crate::base::dialog::InputDialog;
+use crate::base::logger::LogLevel;
+use crate::jira::logs::{colorize_log_line, parse_log_entry, JiraLogs};
+use crate::{log_break, log_debug, log_info, log_message};
+use color_eyre::{eyre::WrapErr, Result};
+use std::time::Duration;
+
+/// 跟踪模式下的轮询间隔
+const FOLLOW_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// 跟踪日志命令
+pub struct TailCommand;
+
+impl TailCommand {
+    /// 显示日志文件末尾内容
+    ///
+    /// 先输出最后 `lines` 行，如果启用 `follow`，则持续轮询并输出新追加的行，
+    /// 日志文件被截断或替换时会自动重新打开。
+    pub fn tail(jira_id: Option<String>, lines: usize, follow: bool) -> Result<()> {
+        // 1. 获取 JIRA ID（从参数或交互式输入）
+        let jira_id = if let Some(id) = jira_id {
+            id
+        } else {
+            InputDialog::new("Enter Jira ticket ID (e.g., PROJ-123)")
+                .prompt()
+                .wrap_err(input_reading::READ_JIRA_TICKET_ID_FAILED)?
+        };
+
+        // 2. 打开日志文件
+        let logs = JiraLogs::new().wrap_err("Failed to initialize JiraLogs")?;
+        let mut tail = logs.open_log_file(&jira_id).wrap_err("Failed to open log file")?;
+        log_debug!("Tailing log file: {}", tail.path().display());
+
+        // 3. 输出末尾若干行
+        let mut current_level = None;
+        for line in tail.read_last_lines(lines)? {
+            Self::print_line(&line, &mut current_level)?;
+        }
+
+        if !follow {
+            return Ok(());
+        }
+
+        // 4. 持续跟踪新追加的行
+        log_break!();
+        log_info!(
+            "Following {} (press Ctrl+C to stop)...",
+            tail.path().display()
+        );
+        loop {
+            for line in tail.read_new_lines()? {
+                Self::print_line(&line, &mut current_level)?;
+            }
+            std::thread::sleep(FOLLOW_POLL_INTERVAL);
+        }
+    }
+
+    /// 按日志级别着色输出一行
+    ///
+    /// 条目起始行会更新当前级别，后续行沿用该级别，空行表示条目结束。
+    fn print_line(line: &str, current_level: &mut Option<LogLevel>) -> Result<()> {
+        if line.trim().is_empty() {
+            *current_level = None;
+        } else if let Some(level) = parse_log_entry(line)?.and_then(|entry| entry.level) {
+            *current_level = Some(level);
+        }
+
+        log_message!("{}", colorize_log_line(line, *current_level));
+        Ok(())
+    }
+}
@@ -0,0 +1,7 @@
+//! MCP server commands
+//!
+//! Commands for running Workflow as an MCP (Model Context Protocol) server.
+
+pub mod serve;
+
+pub use serve::{McpServeCommand, WorkflowTools};
@@ -0,0 +1,227 @@
+//! MCP 服务器命令
+//!
+//! `workflow mcp serve` 通过标准输入输出运行 MCP 服务器，供 AI 助手（如 Cursor、Claude Desktop）
+//! 调用 Workflow 的功能。协议处理见 `base::mcp::server`，本模块定义工具并委托给库中的业务逻辑。
+//!
+//! 标准输出专用于协议消息，因此运行期间日志切换为 JSON 格式（输出到标准错误）。
+
+use std::io;
+
+use color_eyre::{
+    eyre::{eyre, WrapErr},
+    Result,
+};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+use crate::base::logger::{LogFormat, LogLevel};
+use crate::base::mcp::server::{optional_str, required_str, McpServer, McpTool, McpToolHandler};
+use crate::git::{GitBranch, GitCommand};
+use crate::jira::logs::{JiraLogs, LogEntry, LogFilter};
+use crate::jira::Jira;
+use crate::log_info;
+use crate::pr::helpers::get_current_branch_pr_id;
+use crate::pr::llm::SummaryGenerator;
+use crate::pr::platform::create_provider_auto;
+
+/// MCP 服务器名称
+const SERVER_NAME: &str = "workflow";
+
+/// 工具：创建 PR
+pub const TOOL_CREATE_PR: &str = "create_pr";
+/// 工具：总结 PR
+pub const TOOL_SUMMARIZE_PR: &str = "summarize_pr";
+/// 工具：获取 Jira ticket 信息
+pub const TOOL_JIRA_INFO: &str = "jira_info";
+/// 工具：搜索 Jira ticket 的日志
+pub const TOOL_SEARCH_LOGS: &str = "search_logs";
+
+/// 日志搜索结果（附带条目来源文件）
+#[derive(Serialize)]
+struct SearchLogsRecord {
+    source: &'static str,
+    #[serde(flatten)]
+    entry: LogEntry,
+}
+
+/// Workflow 提供的 MCP 工具
+pub struct WorkflowTools;
+
+impl McpToolHandler for WorkflowTools {
+    fn tools(&self) -> Vec<McpTool> {
+        vec![
+            McpTool::new(
+                TOOL_CREATE_PR,
+                "Create a pull request for a branch (pushes the branch first if it is not on the \
+                 remote). Returns the PR URL.",
+                json!({
+                    "title": { "type": "string", "description": "PR title" },
+                    "body": { "type": "string", "description": "PR description (Markdown)" },
+                    "source_branch": {
+                        "type": "string",
+                        "description": "Source branch (defaults to the current branch)"
+                    },
+                    "target_branch": {
+                        "type": "string",
+                        "description": "Target branch (defaults to the repository default branch)"
+                    }
+                }),
+                &["title"],
+            ),
+            McpTool::new(
+                TOOL_SUMMARIZE_PR,
+                "Generate a Markdown summary of a pull request from its title and diff using the \
+                 configured LLM.",
+                json!({
+                    "pr_id": {
+                        "type": "string",
+                        "description": "PR ID (defaults to the PR of the current branch)"
+                    }
+                }),
+                &[],
+            ),
+            McpTool::new(
+                TOOL_JIRA_INFO,
+                "Get the details of a Jira ticket (summary, status, description, fields) as JSON.",
+                json!({
+                    "jira_id": { "type": "string", "description": "Jira ticket ID, e.g. PROJ-123" }
+                }),
+                &["jira_id"],
+            ),
+            McpTool::new(
+                TOOL_SEARCH_LOGS,
+                "Search the downloaded logs of a Jira ticket (api.log and flutter-api.log) for a \
+                 keyword. Returns matching entries as JSON.",
+                json!({
+                    "jira_id": { "type": "string", "description": "Jira ticket ID, e.g. PROJ-123" },
+                    "query": { "type": "string", "description": "Keyword to search for" },
+                    "min_level": {
+                        "type": "string",
+                        "enum": ["error", "warn", "info", "debug"],
+                        "description": "Only return entries at or above this level"
+                    },
+                    "component": {
+                        "type": "string",
+                        "description": "Only return entries from this component"
+                    }
+                }),
+                &["jira_id", "query"],
+            ),
+        ]
+    }
+
+    fn call(&self, name: &str, arguments: &Map<String, Value>) -> Result<String> {
+        match name {
+            TOOL_CREATE_PR => Self::create_pr(arguments),
+            TOOL_SUMMARIZE_PR => Self::summarize_pr(arguments),
+            TOOL_JIRA_INFO => Self::jira_info(arguments),
+            TOOL_SEARCH_LOGS => Self::search_logs(arguments),
+            _ => Err(eyre!("Unknown tool: {}", name)),
+        }
+    }
+}
+
+impl WorkflowTools {
+    /// 创建 PR
+    ///
+    /// 源分支不在远程时先推送（并设置 upstream），然后通过当前仓库的平台创建 PR。
+    fn create_pr(arguments: &Map<String, Value>) -> Result<String> {
+        let title = required_str(arguments, "title")?;
+        let body = optional_str(arguments, "body")?.unwrap_or_default();
+        let target_branch = optional_str(arguments, "target_branch")?;
+        let source_branch = match optional_str(arguments, "source_branch")? {
+            Some(branch) => branch.to_string(),
+            None => GitBranch::current_branch().wrap_err("Failed to get current branch")?,
+        };
+
+        if !GitBranch::has_remote_branch(&source_branch)? {
+            // 捕获 git 的输出，避免写入标准输出破坏协议消息
+            GitCommand::new(["push", "-u", "origin", source_branch.as_str()])
+                .read()
+                .wrap_err_with(|| format!("Failed to push branch: {}", source_branch))?;
+        }
+
+        let provider = create_provider_auto()?;
+        provider
+            .create_pull_request(title, body, &source_branch, target_branch)
+            .wrap_err("Failed to create pull request")
+    }
+
+    /// 总结 PR
+    fn summarize_pr(arguments: &Map<String, Value>) -> Result<String> {
+        let pr_id = match optional_str(arguments, "pr_id")? {
+            Some(id) => id.to_string(),
+            None => get_current_branch_pr_id()?
+                .ok_or_else(|| eyre!("No PR found for the current branch"))?,
+        };
+
+        let provider = create_provider_auto()?;
+        let title = provider
+            .get_pull_request_title(&pr_id)
+            .wrap_err_with(|| format!("Failed to get title of PR #{}", pr_id))?;
+        let diff = provider
+            .get_pull_request_diff(&pr_id)
+            .wrap_err_with(|| format!("Failed to get diff of PR #{}", pr_id))?;
+
+        let summary = SummaryGenerator::summarize_pr(&title, &diff)
+            .wrap_err("Failed to generate PR summary")?;
+        Ok(summary.summary)
+    }
+
+    /// 获取 Jira ticket 信息
+    fn jira_info(arguments: &Map<String, Value>) -> Result<String> {
+        let jira_id = required_str(arguments, "jira_id")?;
+        let issue = Jira::get_ticket_info(jira_id)
+            .wrap_err_with(|| format!("Failed to get ticket info: {}", jira_id))?;
+        serde_json::to_string_pretty(&issue).wrap_err("Failed to serialize ticket info")
+    }
+
+    /// 搜索 Jira ticket 的日志
+    ///
+    /// 日志文件不存在时会先下载（与 `workflow log search` 相同）。
+    fn search_logs(arguments: &Map<String, Value>) -> Result<String> {
+        let jira_id = required_str(arguments, "jira_id")?;
+        let query = required_str(arguments, "query")?;
+        let min_level = optional_str(arguments, "min_level")?
+            .map(|level| level.parse::<LogLevel>().map_err(|e| eyre!(e)))
+            .transpose()?;
+        let component = optional_str(arguments, "component")?.map(str::to_string);
+        let filter = LogFilter::new(Vec::new(), min_level, component);
+
+        let logs = JiraLogs::new().wrap_err("Failed to initialize JiraLogs")?;
+        logs.ensure_log_file_exists(jira_id)
+            .wrap_err("Failed to ensure log file exists")?;
+        let (api_results, flutter_api_results) = logs
+            .search_keyword_both_files(jira_id, query)
+            .wrap_err("Failed to search logs")?;
+
+        let records: Vec<SearchLogsRecord> = [
+            ("api.log", api_results),
+            ("flutter-api.log", flutter_api_results),
+        ]
+        .into_iter()
+        .flat_map(|(source, entries)| {
+            filter
+                .apply(entries)
+                .entries
+                .into_iter()
+                .map(move |entry| SearchLogsRecord { source, entry })
+        })
+        .collect();
+        serde_json::to_string_pretty(&records).wrap_err("Failed to serialize search results")
+    }
+}
+
+/// MCP 服务器命令
+pub struct McpServeCommand;
+
+impl McpServeCommand {
+    /// 通过标准输入输出运行 MCP 服务器，直到输入结束
+    pub fn serve() -> Result<()> {
+        LogFormat::set(LogFormat::Json);
+        log_info!("Workflow MCP server started (stdio)");
+
+        let server = McpServer::new(SERVER_NAME, env!("CARGO_PKG_VERSION"), WorkflowTools);
+        server.serve(io::stdin().lock(), io::stdout().lock())
+    }
+}
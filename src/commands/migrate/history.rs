@@ -23,7 +23,7 @@ pub struct MigrationEntry {
 }
 
 /// 获取迁移历史文件路径
-fn get_history_path() -> Result<PathBuf> {
+pub fn get_history_path() -> Result<PathBuf> {
     Ok(Paths::config_dir()?.join("migration-history.toml"))
 }
 
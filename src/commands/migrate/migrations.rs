@@ -3,11 +3,18 @@
 //! 管理所有可用的迁移版本，检测待迁移的版本。
 
 use color_eyre::Result;
+use std::path::PathBuf;
 
-use crate::commands::migrate::history;
+use crate::commands::migrate::{history, v1_1_0};
 
 /// 所有可用的迁移版本
-const ALL_MIGRATIONS: &[&str] = &[];
+const ALL_MIGRATIONS: &[&str] = &[v1_1_0::VERSION];
+
+/// 仓库级迁移版本
+///
+/// 这些迁移作用于当前仓库的配置，每个仓库都需要单独迁移，
+/// 因此不受迁移历史限制，只根据磁盘上的配置格式检测。
+const REPO_SCOPED_MIGRATIONS: &[&str] = &[v1_1_0::VERSION];
 
 /// 检测需要迁移的版本
 pub fn detect_pending_migrations() -> Result<Vec<String>> {
@@ -18,7 +25,7 @@ pub fn detect_pending_migrations() -> Result<Vec<String>> {
 
     let pending: Vec<String> = ALL_MIGRATIONS
         .iter()
-        .filter(|v| !completed.contains(**v))
+        .filter(|v| REPO_SCOPED_MIGRATIONS.contains(v) || !completed.contains(**v))
         .map(|v| v.to_string())
         .collect();
 
@@ -34,6 +41,28 @@ pub fn detect_pending_migrations() -> Result<Vec<String>> {
 }
 
 /// 检查特定版本是否需要迁移
-fn needs_migration(_version: &str) -> Result<bool> {
-    Ok(false)
+fn needs_migration(version: &str) -> Result<bool> {
+    match version {
+        v1_1_0::VERSION => v1_1_0::needs_migration(),
+        _ => Ok(false),
+    }
+}
+
+/// 获取迁移会改写的文件
+///
+/// 包含迁移历史文件和每个版本声明的配置文件（已去重），用于迁移前备份。
+pub fn affected_files(versions: &[String]) -> Result<Vec<PathBuf>> {
+    let mut files = vec![history::get_history_path()?];
+    for version in versions {
+        let version_files = match version.as_str() {
+            v1_1_0::VERSION => v1_1_0::affected_files()?,
+            _ => Vec::new(),
+        };
+        for file in version_files {
+            if !files.contains(&file) {
+                files.push(file);
+            }
+        }
+    }
+    Ok(files)
 }
@@ -6,7 +6,7 @@
 //!
 //! 迁移系统采用**版本化文件组织**，每个需要迁移的版本都有独立的文件：
 //! - `v1_0_0.rs` - v1.0.0 迁移实现（未来）
-//! - `v1_1_0.rs` - v1.1.0 迁移实现（未来）
+//! - `v1_1_0.rs` - v1.1.0 迁移实现（分支配置迁移到个人偏好配置）
 //! - `v2_0_0.rs` - v2.0.0 迁移实现（未来）
 //!
 //! **重要**：迁移版本号独立于软件版本号！
@@ -16,20 +16,28 @@
 //! 添加新迁移版本时，需要：
 //! 1. 创建新的迁移文件（如 `v1_1_0.rs`）
 //! 2. 在 `mod.rs` 中导出并添加路由
-//! 3. 在 `migrations.rs` 中注册新版本
+//! 3. 在 `migrations.rs` 中注册新版本，并在 `affected_files()` 中声明会改写的文件
+//!
+//! 执行迁移前会备份所有会被改写的文件（包括迁移历史），任一版本失败时自动恢复。
 //!
 //! 详细说明请参考 `README.md`。
 
-use crate::{log_info, log_success};
-use color_eyre::Result;
+use crate::rollback::{FileBackup, RollbackManager};
+use crate::{log_error, log_info, log_success, log_warning};
+use color_eyre::{eyre::WrapErr, Result};
+use std::path::PathBuf;
 
 pub mod history;
 pub mod migrations;
+pub mod v1_1_0;
 
 pub struct MigrateCommand;
 
 impl MigrateCommand {
     /// 执行迁移
+    ///
+    /// 执行前备份所有会被改写的文件，任一版本失败时自动从备份恢复。
+    /// `cleanup` 为 `true` 时，所有迁移成功后删除旧配置和备份。
     pub fn migrate(dry_run: bool, cleanup: bool) -> Result<()> {
         // 自动检测所有待迁移的版本
         let pending = migrations::detect_pending_migrations()?;
@@ -42,26 +50,86 @@ impl MigrateCommand {
             log_info!("Migration preview (dry-run mode):");
             log_info!("Pending migrations: {}", pending.join(", "));
             log_info!("");
-        } else {
-            log_info!("Starting migration...");
-            log_info!("Pending migrations: {}", pending.join(", "));
-            log_info!("");
-        }
 
-        // 执行所有待迁移的版本
-        for version in &pending {
-            Self::migrate_version(version, dry_run, cleanup)?;
+            for version in &pending {
+                Self::migrate_version(version, true, cleanup)?;
+            }
+            return Ok(());
         }
 
-        if !dry_run {
-            log_success!("Migration completed successfully!");
+        log_info!("Starting migration...");
+        log_info!("Pending migrations: {}", pending.join(", "));
+        log_info!("");
+
+        // 备份后执行所有待迁移的版本
+        let files = migrations::affected_files(&pending)?;
+        let backup = Self::run_with_backup(&pending, &files, |version| {
+            Self::migrate_version(version, false, cleanup)
+        })?;
+
+        if cleanup {
+            RollbackManager::cleanup_file_backup(&backup)?;
+        } else {
+            log_info!("Backup kept at {}", backup.backup_dir.display());
         }
 
+        log_success!("Migration completed successfully!");
         Ok(())
     }
 
+    /// 在备份保护下依次执行迁移
+    ///
+    /// 执行前备份 `files`；任一版本失败时从备份恢复所有文件，
+    /// 并返回包含失败版本号的错误。全部成功时返回备份信息。
+    pub fn run_with_backup<F>(
+        versions: &[String],
+        files: &[PathBuf],
+        mut migrate: F,
+    ) -> Result<FileBackup>
+    where
+        F: FnMut(&str) -> Result<()>,
+    {
+        let backup = RollbackManager::backup_files(files)
+            .wrap_err("Failed to back up configuration before migration")?;
+
+        for version in versions {
+            if let Err(e) = migrate(version) {
+                log_error!("Migration {} failed: {}", version, e);
+                match RollbackManager::restore_files(&backup) {
+                    Ok(()) => {
+                        log_warning!(
+                            "Configuration restored from backup ({})",
+                            backup.backup_dir.display()
+                        );
+                    }
+                    Err(restore_err) => {
+                        log_error!(
+                            "Failed to restore configuration: {}\nBackup kept at {}",
+                            restore_err,
+                            backup.backup_dir.display()
+                        );
+                    }
+                }
+                return Err(e.wrap_err(format!("Migration {} failed", version)));
+            }
+        }
+
+        Ok(backup)
+    }
+
     /// 执行特定版本的迁移
-    fn migrate_version(version: &str, _dry_run: bool, _cleanup: bool) -> Result<()> {
-        color_eyre::eyre::bail!("Unknown migration version: {}", version);
+    ///
+    /// 非 dry-run 模式下迁移成功后记录到迁移历史。
+    fn migrate_version(version: &str, dry_run: bool, cleanup: bool) -> Result<()> {
+        match version {
+            v1_1_0::VERSION => v1_1_0::migrate_v1_1_0(dry_run, cleanup)?,
+            _ => color_eyre::eyre::bail!("Unknown migration version: {}", version),
+        }
+
+        if !dry_run {
+            history::record_migration(version)?;
+        }
+
+        Ok(())
     }
 }
@@ -0,0 +1,213 @@
+//! v1.1.0 迁移实现
+//!
+//! 迁移描述：将分支配置（`prefix`、`ignore`）从项目级配置 `.workflow/config.toml` 的 `[branch]` 节
+//! 迁移到个人偏好配置 `~/.workflow/config/repository.toml`（通过 `RepoConfig` 管理）。
+//!
+//! 旧格式（`.workflow/config.toml`）：
+//!
+//! ```toml
+//! [branch]
+//! prefix = "feature"
+//! ignore = ["main", "develop"]
+//! ```
+//!
+//! 新格式（`repository.toml`，按仓库标识区分）：
+//!
+//! ```toml
+//! ["workflow.rs_12345678.branch"]
+//! prefix = "feature"
+//! ignore = ["main", "develop"]
+//! ```
+//!
+//! 个人配置中已有的值优先：已设置前缀时不覆盖，忽略列表只追加缺少的分支。
+
+use color_eyre::{eyre::WrapErr, Result};
+use std::path::PathBuf;
+use toml::Value;
+
+use crate::base::settings::paths::Paths;
+use crate::base::util::file::{FileReader, FileWriter};
+use crate::git::GitRepo;
+use crate::repo::config::BranchConfig;
+use crate::repo::RepoConfig;
+use crate::{log_info, log_message, log_success};
+
+/// 迁移版本
+pub const VERSION: &str = "v1.1.0";
+
+/// 项目级配置中旧的分支配置节名称
+const LEGACY_BRANCH_SECTION: &str = "branch";
+
+/// 分支配置迁移计划
+///
+/// 只包含需要写入个人偏好配置的字段。
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BranchMigrationPlan {
+    /// 需要迁移的分支前缀（个人配置中已设置前缀时为 `None`）
+    pub prefix: Option<String>,
+    /// 需要追加的忽略分支（个人配置中已存在的分支不重复添加）
+    pub ignore: Vec<String>,
+}
+
+impl BranchMigrationPlan {
+    /// 根据旧配置和当前个人配置生成迁移计划
+    pub fn new(legacy: &BranchConfig, current: Option<&BranchConfig>) -> Self {
+        let current_prefix = current.and_then(|c| c.prefix.as_ref());
+        let current_ignore = current.map(|c| c.ignore.as_slice()).unwrap_or_default();
+
+        let mut ignore: Vec<String> = Vec::new();
+        for branch in &legacy.ignore {
+            if !current_ignore.contains(branch) && !ignore.contains(branch) {
+                ignore.push(branch.clone());
+            }
+        }
+
+        Self {
+            prefix: if current_prefix.is_none() {
+                legacy.prefix.clone()
+            } else {
+                None
+            },
+            ignore,
+        }
+    }
+
+    /// 是否没有需要迁移的字段
+    pub fn is_empty(&self) -> bool {
+        self.prefix.is_none() && self.ignore.is_empty()
+    }
+
+    /// 将迁移计划应用到个人配置
+    pub fn apply(&self, current: Option<BranchConfig>) -> BranchConfig {
+        let mut branch = current.unwrap_or_default();
+        if let Some(ref prefix) = self.prefix {
+            branch.prefix = Some(prefix.clone());
+        }
+        branch.ignore.extend(self.ignore.iter().cloned());
+        branch
+    }
+
+    /// 描述计划中的字段迁移（用于预览和结果输出）
+    pub fn describe(&self) -> Vec<String> {
+        let mut moves = Vec::new();
+        if let Some(ref prefix) = self.prefix {
+            moves.push(format!("branch.prefix = {:?}", prefix));
+        }
+        for branch in &self.ignore {
+            moves.push(format!("branch.ignore += {:?}", branch));
+        }
+        moves
+    }
+}
+
+/// 读取项目级配置中旧的 `[branch]` 节
+///
+/// 没有 `[branch]` 节、格式不正确或内容为空时返回 `None`。
+pub fn read_legacy_branch_config(project_config: &Value) -> Option<BranchConfig> {
+    let branch: BranchConfig =
+        project_config.get(LEGACY_BRANCH_SECTION)?.clone().try_into().ok()?;
+    if branch.prefix.is_none() && branch.ignore.is_empty() {
+        return None;
+    }
+    Some(branch)
+}
+
+/// 从项目级配置中移除旧的 `[branch]` 节
+///
+/// 返回是否移除了内容。
+pub fn remove_legacy_branch_section(project_config: &mut Value) -> bool {
+    project_config
+        .as_table_mut()
+        .map(|table| table.remove(LEGACY_BRANCH_SECTION).is_some())
+        .unwrap_or(false)
+}
+
+/// 获取迁移会改写的文件（项目级配置和个人偏好配置）
+pub fn affected_files() -> Result<Vec<PathBuf>> {
+    Ok(vec![Paths::project_config()?, Paths::repository_config()?])
+}
+
+/// 检查当前仓库是否需要 v1.1.0 迁移
+///
+/// 项目级配置中存在 `[branch]` 节，且其中有个人偏好配置尚未包含的字段时需要迁移。
+pub fn needs_migration() -> Result<bool> {
+    Ok(load_plan()?.map(|plan| !plan.is_empty()).unwrap_or(false))
+}
+
+/// 加载当前仓库的迁移计划
+///
+/// 不在 Git 仓库中、项目级配置不存在或没有旧的 `[branch]` 节时返回 `None`。
+fn load_plan() -> Result<Option<BranchMigrationPlan>> {
+    if !GitRepo::is_git_repo() {
+        return Ok(None);
+    }
+
+    let project_path = Paths::project_config()?;
+    if !project_path.exists() {
+        return Ok(None);
+    }
+
+    let project_config: Value = FileReader::new(&project_path).toml()?;
+    let Some(legacy) = read_legacy_branch_config(&project_config) else {
+        return Ok(None);
+    };
+
+    let current = RepoConfig::load().wrap_err("Failed to load repository config")?;
+    Ok(Some(BranchMigrationPlan::new(
+        &legacy,
+        current.branch.as_ref(),
+    )))
+}
+
+/// v1.1.0 迁移实现
+pub fn migrate_v1_1_0(dry_run: bool, cleanup: bool) -> Result<()> {
+    let Some(plan) = load_plan()? else {
+        log_info!("No legacy branch configuration found, skipping {}", VERSION);
+        return Ok(());
+    };
+
+    let project_path = Paths::project_config()?;
+    let repository_path = Paths::repository_config()?;
+
+    if dry_run {
+        log_info!("Migration preview for {} (branch configuration):", VERSION);
+        log_message!("  From: {} [branch]", project_path.display());
+        log_message!("  To:   {}", repository_path.display());
+        for field_move in plan.describe() {
+            log_message!("    {}", field_move);
+        }
+        if cleanup {
+            log_message!(
+                "  [branch] section will be removed from {}",
+                project_path.display()
+            );
+        }
+        return Ok(());
+    }
+
+    log_info!("Migrating to {} (branch configuration)...", VERSION);
+
+    if !plan.is_empty() {
+        let mut config = RepoConfig::load().wrap_err("Failed to load repository config")?;
+        config.branch = Some(plan.apply(config.branch.take()));
+        RepoConfig::save(&config).wrap_err("Failed to save repository config")?;
+
+        log_message!("  Moved to {}:", repository_path.display());
+        for field_move in plan.describe() {
+            log_message!("    {}", field_move);
+        }
+    }
+
+    if cleanup {
+        let mut project_config: Value = FileReader::new(&project_path).toml()?;
+        if remove_legacy_branch_section(&mut project_config) {
+            FileWriter::new(&project_path)
+                .write_toml(&project_config)
+                .wrap_err("Failed to update project config")?;
+            log_message!("  Removed [branch] section from {}", project_path.display());
+        }
+    }
+
+    log_success!("Migration to {} completed!", VERSION);
+    Ok(())
+}
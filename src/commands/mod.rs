@@ -16,6 +16,7 @@
 //! ### 配置管理
 //! - `config/` - 配置管理命令（setup, show, log, completion）
 //! - `github/` - GitHub 账号管理命令（list, add, remove, switch, update, current）
+//! - `cache/` - 缓存管理命令（list, clean）
 //! - `check/` - 环境检查命令（git_status, network）
 //! - `proxy/` - 代理管理命令（on, off, check）
 //!
@@ -24,11 +25,13 @@
 //! - `log/` - 日志操作命令（download, find, search）
 //! - `jira/` - Jira 操作命令（info, attachments, clean）
 //! - `branch/` - 分支管理命令（clean, ignore）
+//! - `mcp/` - MCP 服务器命令（serve）
 
 // 生命周期管理
 pub mod lifecycle;
 
 // 配置管理
+pub mod cache;
 pub mod check;
 pub mod config;
 pub mod github;
@@ -41,6 +44,7 @@ pub mod branch;
 pub mod commit;
 pub mod jira;
 pub mod log;
+pub mod mcp;
 pub mod migrate;
 pub mod pr;
 pub mod repo;
@@ -1,6 +1,7 @@
 use crate::log_success;
 use crate::pr::create_provider_auto;
 use crate::pr::helpers::resolve_pull_request_id;
+use crate::pr::ReviewCommentTarget;
 use color_eyre::{eyre::WrapErr, Result};
 
 /// PR 评论命令
@@ -10,25 +11,54 @@ pub struct PullRequestCommentCommand;
 #[allow(dead_code)]
 impl PullRequestCommentCommand {
     /// 添加评论到 Pull Request
-    pub fn comment(pull_request_id: Option<String>, message: Vec<String>) -> Result<()> {
+    ///
+    /// 未指定 `file`/`line`/`reply_to` 时添加普通评论；指定 `file` 和 `line` 时在 PR diff
+    /// 的对应行添加评审评论；指定 `reply_to` 时回复已有的评审评论。
+    pub fn comment(
+        pull_request_id: Option<String>,
+        message: Vec<String>,
+        file: Option<String>,
+        line: Option<u32>,
+        reply_to: Option<u64>,
+    ) -> Result<()> {
         // 获取评论内容（将多个单词组合成一个字符串）
         if message.is_empty() {
             color_eyre::eyre::bail!("Comment message is required. Please provide a message.");
         }
         let comment_message = message.join(" ");
+        let target = ReviewCommentTarget::from_args(file, line, reply_to)?;
 
         // 获取 PR ID（从参数或当前分支）
         let pr_id = resolve_pull_request_id(pull_request_id)?;
 
-        log_success!("Adding comment to PR: #{}", pr_id);
-
         // 创建平台提供者并添加评论
         let provider = create_provider_auto()?;
-        provider
-            .add_comment(&pr_id, &comment_message)
-            .wrap_err(format!("Failed to add comment to PR #{}", pr_id))?;
-
-        log_success!("Comment added to PR #{} successfully!", pr_id);
+        match target {
+            None => {
+                log_success!("Adding comment to PR: #{}", pr_id);
+                provider
+                    .add_comment(&pr_id, &comment_message)
+                    .wrap_err(format!("Failed to add comment to PR #{}", pr_id))?;
+                log_success!("Comment added to PR #{} successfully!", pr_id);
+            }
+            Some(target) => {
+                let location = match target {
+                    ReviewCommentTarget::Line { ref path, line } => format!("{}:{}", path, line),
+                    ReviewCommentTarget::Reply { comment_id } => {
+                        format!("review comment {}", comment_id)
+                    }
+                };
+                log_success!("Adding review comment to PR #{} ({})", pr_id, location);
+                provider
+                    .add_review_comment(&pr_id, &target, &comment_message)
+                    .wrap_err(format!("Failed to add review comment to PR #{}", pr_id))?;
+                log_success!(
+                    "Review comment added to PR #{} ({}) successfully!",
+                    pr_id,
+                    location
+                );
+            }
+        }
 
         Ok(())
     }
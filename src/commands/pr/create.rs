@@ -1,16 +1,21 @@
-use color_eyre::{eyre::WrapErr, Result};
+use color_eyre::{
+    eyre::{ContextCompat, WrapErr},
+    Result,
+};
+use std::path::PathBuf;
 
-use crate::base::dialog::{ConfirmDialog, InputDialog};
+use crate::base::dialog::{ConfirmDialog, InputDialog, SelectDialog};
 use crate::base::indicator::Spinner;
+use crate::base::util::Clipboard;
 use crate::branch::{BranchNaming, BranchType};
 use crate::commands::check;
 use crate::commands::pr::helpers::{
     copy_and_open_pull_request, create_branch_from_default, create_or_get_pull_request,
-    ensure_jira_status, handle_stash_pop_result, resolve_description, resolve_title,
-    select_change_types, update_jira_ticket,
+    ensure_jira_status, handle_stash_pop_result, jira_ticket_if_configured, resolve_description,
+    resolve_title, select_change_types, update_jira_ticket,
 };
-use crate::git::{GitBranch, GitCommit, GitStash};
-use crate::jira::helpers::validate_jira_ticket_format;
+use crate::git::{GitBranch, GitCommit, GitRepo, GitStash};
+use crate::jira::helpers::{extract_jira_ticket_from_url, validate_jira_ticket_format};
 use crate::jira::Jira;
 use crate::pr::helpers::{generate_commit_title, generate_pull_request_body};
 use crate::pr::llm::CreateGenerator;
@@ -18,7 +23,7 @@ use crate::pr::{
     map_branch_type_to_change_type_index, map_branch_type_to_change_types, TYPES_OF_CHANGES,
 };
 use crate::repo::RepoConfig;
-use crate::{log_break, log_info, log_success, log_warning};
+use crate::{log_break, log_debug, log_info, log_success, log_warning};
 
 /// PR 创建命令
 #[allow(dead_code)]
@@ -27,10 +32,14 @@ pub struct PullRequestCreateCommand;
 #[allow(dead_code)]
 impl PullRequestCreateCommand {
     /// 创建 PR（完整流程）
+    ///
+    /// `from_commits` 为 `true` 时不使用 Jira ticket，根据当前分支上已有的 commit 创建 PR，
+    /// 参见 `create_from_commits`。
     pub fn create(
         jira_ticket: Option<String>,
         title: Option<String>,
         description: Option<String>,
+        from_commits: bool,
         dry_run: bool,
     ) -> Result<()> {
         // 0. 检查并确保仓库配置存在
@@ -41,6 +50,10 @@ impl PullRequestCreateCommand {
             check::CheckCommand::run_all()?;
         }
 
+        if from_commits {
+            return Self::create_from_commits(title, description, dry_run);
+        }
+
         // 2. 获取或输入 Jira ticket
         let jira_ticket = Self::resolve_jira_ticket(jira_ticket)?;
 
@@ -77,13 +90,15 @@ impl PullRequestCreateCommand {
             None
         };
 
-        // 9. 生成 PR body（使用模板系统）
+        // 9. 生成 PR body（仓库中有 PR 模板时以其为基础，否则使用模板系统）
+        let repo_template = Self::select_repo_pull_request_template()?;
         let pull_request_body = generate_pull_request_body(
             &selected_types,
             Some(&short_description),
             jira_ticket.as_deref(),
             None, // dependency 暂时为空
             jira_info.as_ref(),
+            repo_template.as_deref(),
         )?;
 
         if dry_run {
@@ -120,10 +135,140 @@ impl PullRequestCreateCommand {
         Ok(())
     }
 
+    /// 根据当前分支上的 commit 创建 PR（不使用 Jira ticket）
+    ///
+    /// 1. 获取当前分支相对于默认分支的 commit 消息和 diff（不在默认分支上、且有新 commit）
+    /// 2. 通过 `CreateGenerator::generate_from_commits` 生成标题、描述和 scope，
+    ///    LLM 失败时回退到 `CreateGenerator::from_commit_messages`
+    /// 3. 根据分支名推断分支类型（如 `feature/xxx`），用于选择变更类型和生成标题
+    /// 4. 生成 PR body，推送分支并创建 PR（跳过 Jira 状态配置和更新）
+    ///
+    /// 未提交的修改不会包含在 PR 中。
+    fn create_from_commits(
+        title: Option<String>,
+        description: Option<String>,
+        dry_run: bool,
+    ) -> Result<()> {
+        // 1. 获取分支上的 commit
+        let current_branch = GitBranch::current_branch()?;
+        let default_branch = GitBranch::get_default_branch()?;
+        if current_branch == default_branch {
+            color_eyre::eyre::bail!(
+                "--from-commits creates a PR from the current branch, but you are on default branch '{}'. Check out a feature branch first.",
+                default_branch
+            );
+        }
+
+        let commit_messages = GitCommit::get_commit_messages_in_range(&default_branch, "HEAD")
+            .wrap_err("Failed to get commits on current branch")?;
+        if commit_messages.is_empty() {
+            color_eyre::eyre::bail!(
+                "Current branch '{}' has no commits compared to '{}'. Cannot create PR without commits.",
+                current_branch,
+                default_branch
+            );
+        }
+        log_info!(
+            "Creating PR from {} commit(s) on '{}' (compared to '{}')",
+            commit_messages.len(),
+            current_branch,
+            default_branch
+        );
+        if GitCommit::has_commit()? {
+            log_warning!("Uncommitted changes will not be included in the PR");
+        }
+
+        // 2. 生成标题、描述和 scope
+        let git_diff = GitCommit::get_diff_in_range(&default_branch, "HEAD");
+        let content = match Spinner::with("Generating PR content from commits...", || {
+            CreateGenerator::generate_from_commits(&commit_messages, git_diff)
+        }) {
+            Ok(content) => {
+                log_success!("Generated PR title using LLM: {}", content.pr_title);
+                if let Some(chunking) = content.chunking {
+                    log_info!("{}", chunking.notice());
+                }
+                content
+            }
+            Err(e) => {
+                log_warning!(
+                    "Failed to generate PR content using LLM: {}, using commit messages",
+                    e
+                );
+                CreateGenerator::from_commit_messages(&commit_messages)
+                    .wrap_err("No commit messages to create PR from")?
+            }
+        };
+        if let Some(ref scope) = content.scope {
+            log_info!("Extracted scope: {}", scope);
+        }
+
+        // 3. 根据分支名推断分支类型（无法推断时不自动选择变更类型）
+        let branch_type = current_branch.split('/').find_map(BranchType::from_str);
+        let pr_title = generate_commit_title(
+            None,
+            title.as_deref().unwrap_or(&content.pr_title),
+            branch_type.map(|ty| ty.to_commit_type()),
+            content.scope.as_deref(),
+            None,
+        )
+        .unwrap_or_else(|_| title.clone().unwrap_or_else(|| content.pr_title.clone()));
+
+        let short_description = match description.or_else(|| content.description.clone()) {
+            Some(description) => description,
+            None => resolve_description(None)?,
+        };
+
+        // 4. 生成 PR body
+        let selected_types = Self::select_change_types_with_auto_select(branch_type)?;
+        let repo_template = Self::select_repo_pull_request_template()?;
+        let pull_request_body = generate_pull_request_body(
+            &selected_types,
+            Some(&short_description),
+            None,
+            None,
+            None,
+            repo_template.as_deref(),
+        )?;
+
+        if dry_run {
+            log_info!("[DRY RUN] Would create PR from branch: {}", current_branch);
+            log_info!("[DRY RUN] PR title: {}", pr_title);
+            log_info!("[DRY RUN] PR body:\n{}", pull_request_body);
+            return Ok(());
+        }
+
+        // 5. 推送分支并创建 PR
+        let exists_remote = GitBranch::has_remote_branch(&current_branch)
+            .wrap_err("Failed to check if branch exists on remote")?;
+        log_break!();
+        log_info!("Pushing to remote...");
+        log_break!();
+        GitBranch::push(&current_branch, !exists_remote)?;
+
+        let pull_request_url = create_or_get_pull_request(
+            &current_branch,
+            &default_branch,
+            &pr_title,
+            &pull_request_body,
+        )?;
+
+        copy_and_open_pull_request(&pull_request_url)?;
+
+        log_success!("PR created successfully!");
+        Ok(())
+    }
+
     /// 获取或输入 Jira ticket
     ///
     /// 步骤 2：如果提供了 ticket，验证其格式；如果没有提供，提示用户输入并验证。
+    /// 剪贴板中是 Jira URL 时，先询问是否使用其中的 ticket；输入 Jira URL 时同样提取 ticket。
+    /// 未配置 Jira 时不提示输入，也不使用指定的 ticket。
     fn resolve_jira_ticket(jira_ticket: Option<String>) -> Result<Option<String>> {
+        if !Jira::is_configured() {
+            return Ok(jira_ticket_if_configured(jira_ticket));
+        }
+
         let ticket = if let Some(t) = jira_ticket {
             let trimmed = t.trim().to_string();
             if trimmed.is_empty() {
@@ -132,10 +277,26 @@ impl PullRequestCreateCommand {
                 Some(trimmed)
             }
         } else {
-            let input = InputDialog::new("Jira ticket (optional)")
-                .allow_empty(true)
+            let mut input = String::new();
+            if let Some(ticket) = Self::jira_ticket_from_clipboard() {
+                let use_clipboard = ConfirmDialog::new(format!(
+                    "Use Jira ticket {} from the URL in clipboard?",
+                    ticket
+                ))
+                .with_default(true)
                 .prompt()
-                .wrap_err("Failed to get Jira ticket")?;
+                .wrap_err("Failed to confirm Jira ticket")?;
+                if use_clipboard {
+                    input = ticket;
+                }
+            }
+            if input.is_empty() {
+                input = InputDialog::new("Jira ticket or URL (optional)")
+                    .allow_empty(true)
+                    .prompt()
+                    .wrap_err("Failed to get Jira ticket")?;
+            }
+            let input = extract_jira_ticket_from_url(&input).unwrap_or(input);
             let trimmed = input.trim().to_string();
             if trimmed.is_empty() {
                 None
@@ -152,6 +313,17 @@ impl PullRequestCreateCommand {
         Ok(ticket)
     }
 
+    /// 从剪贴板中的 Jira URL 提取 ticket（剪贴板不可用或不是 Jira URL 时返回 `None`）
+    fn jira_ticket_from_clipboard() -> Option<String> {
+        match Clipboard::read() {
+            Ok(text) => text.and_then(|text| extract_jira_ticket_from_url(&text)),
+            Err(e) => {
+                log_debug!("Clipboard is not available: {}", e);
+                None
+            }
+        }
+    }
+
     /// 生成 commit title 和分支名
     ///
     /// 步骤 5：使用与 branch create 相同的流程生成分支名。
@@ -178,6 +350,9 @@ impl PullRequestCreateCommand {
                 Ok(content) => {
                     // LLM 成功：统一处理（不管是否有 jira_ticket）
                     log_success!("Generated branch name using LLM: {}", content.branch_name);
+                    if let Some(chunking) = content.chunking {
+                        log_info!("{}", chunking.notice());
+                    }
                     if let Some(ref scope) = content.scope {
                         log_info!("Extracted scope: {}", scope);
                     }
@@ -300,8 +475,8 @@ impl PullRequestCreateCommand {
                         change_type_name
                     ),
                     BranchType::Chore => {
-                        // Chore 没有自动选择，直接进入手动选择
-                        return select_change_types();
+                        // Chore 不询问确认，直接进入手动选择（预先选中 Chore）
+                        return select_change_types(&selected_types);
                     }
                     _ => format!(
                         "Auto-selected change type: {}\n\nUse this?",
@@ -318,11 +493,44 @@ impl PullRequestCreateCommand {
                     log_success!("Using auto-selected change type: {}", change_type_name);
                     return Ok(selected_types);
                 }
+
+                // 用户修改选择时，预先选中自动选择的类型
+                return select_change_types(&selected_types);
             }
         }
 
         // Step 3: 用户手动选择（原有逻辑）
-        select_change_types()
+        select_change_types(&[])
+    }
+
+    /// 选择仓库 PR 模板
+    ///
+    /// 步骤 9 的辅助方法：查找仓库中的 PR 模板（如 `.github/PULL_REQUEST_TEMPLATE.md`），
+    /// 找到多个模板时让用户选择。没有模板时返回 `None`，使用 workflow 的 PR 模板。
+    fn select_repo_pull_request_template() -> Result<Option<String>> {
+        let templates = GitRepo::find_pull_request_templates().unwrap_or_default();
+        let root_dir = GitRepo::get_root_dir().unwrap_or_default();
+        let display_path =
+            |path: &PathBuf| path.strip_prefix(&root_dir).unwrap_or(path).display().to_string();
+
+        let template_path = match templates.len() {
+            0 => return Ok(None),
+            1 => templates[0].clone(),
+            _ => {
+                let options: Vec<String> = templates.iter().map(display_path).collect();
+                let selected = SelectDialog::new("Select a PR template", options.clone())
+                    .with_default(0)
+                    .prompt()
+                    .wrap_err("Failed to select PR template")?;
+                let index = options.iter().position(|o| o == &selected).unwrap_or(0);
+                templates[index].clone()
+            }
+        };
+
+        log_info!("Using PR template: {}", display_path(&template_path));
+        let content = std::fs::read_to_string(&template_path)
+            .wrap_err_with(|| format!("Failed to read PR template: {}", template_path.display()))?;
+        Ok(Some(content))
     }
 
     /// 在当前分支上提交并推送
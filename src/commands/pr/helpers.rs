@@ -293,6 +293,27 @@ pub fn detect_base_branch(branch: &str, exclude_branch: &str) -> Result<Option<S
     Ok(None)
 }
 
+/// 未配置 Jira 时忽略 ticket
+///
+/// 只使用 GitHub 的用户没有配置 Jira，此时不使用 ticket（不生成 Jira 链接、不调用 Jira API），
+/// 指定了 ticket 时给出警告。已配置 Jira 时原样返回。
+///
+/// # 参数
+///
+/// * `jira_ticket` - Jira ticket ID（可选）
+pub fn jira_ticket_if_configured(jira_ticket: Option<String>) -> Option<String> {
+    if Jira::is_configured() {
+        return jira_ticket;
+    }
+    if let Some(ref ticket) = jira_ticket {
+        log_warning!(
+            "Jira is not configured, ignoring ticket {} (run 'workflow setup' to enable Jira integration)",
+            ticket
+        );
+    }
+    None
+}
+
 /// 配置 Jira ticket 状态
 ///
 /// 如果有 Jira ticket，检查并配置状态。如果已配置则读取，否则进行交互式配置。
@@ -385,17 +406,30 @@ pub fn resolve_description(description: Option<String>) -> Result<String> {
 /// 选择变更类型（手动选择）
 ///
 /// 提示用户手动选择变更类型，返回一个布尔向量，表示每个类型是否被选中。
+/// 至少需要选择一个类型。
+///
+/// # 参数
+///
+/// * `defaults` - 预先选中的变更类型（与 `TYPES_OF_CHANGES` 一一对应，为空时不预先选中）
 ///
 /// # 返回
 ///
 /// 返回布尔向量，表示每个 PR 变更类型是否被选中。
-pub fn select_change_types() -> Result<Vec<bool>> {
+pub fn select_change_types(defaults: &[bool]) -> Result<Vec<bool>> {
     log_info!("Types of changes:");
     let options: Vec<&str> = TYPES_OF_CHANGES.to_vec();
+    let default_indices: Vec<usize> = defaults
+        .iter()
+        .enumerate()
+        .filter(|(_, &selected)| selected)
+        .map(|(index, _)| index)
+        .collect();
     let selected_items = MultiSelectDialog::new(
         "Select change types (use space to select, enter to confirm)",
         options,
     )
+    .with_defaults(default_indices)
+    .with_min(1)
     .prompt()
     .wrap_err("Failed to select change types")?;
 
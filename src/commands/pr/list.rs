@@ -1,8 +1,20 @@
-use crate::base::table::{TableBuilder, TableStyle};
-use crate::pr::platform::create_provider_auto;
+use crate::base::table::{TableBuilder, TableOverflow, TableStyle};
+use crate::cli::{PRListFilterArgs, PRListSort, TableFormat};
+use crate::commands::jira::OutputFormat;
+use crate::pr::platform::{create_provider_auto, PlatformProvider};
+use crate::pr::{PrListFilter, PrListSort, PullRequestListOutput, PullRequestRow};
 use crate::{log_break, log_message};
 use color_eyre::Result;
 
+/// PR 标题列的最大宽度（超出时截断）
+const TITLE_MAX_WIDTH: usize = 60;
+
+/// PR 标题列的索引（见 `PullRequestRow`）
+const TITLE_COLUMN: usize = 3;
+
+/// 数量限制超过该值时边获取边显示（每获取一页显示一个表格）
+const STREAM_THRESHOLD: usize = 100;
+
 /// PR 列表命令
 #[allow(dead_code)]
 pub struct PullRequestListCommand;
@@ -10,30 +22,86 @@ pub struct PullRequestListCommand;
 #[allow(dead_code)]
 impl PullRequestListCommand {
     /// 列出 PR
-    pub fn list(state: Option<String>, limit: Option<usize>) -> Result<()> {
-        log_break!('=', 40, "PR List");
+    ///
+    /// `format` 不是 `table` 时只输出数据（Markdown/CSV/TSV），不输出标题和提示。
+    /// 表格格式且数量限制超过 `STREAM_THRESHOLD` 时边获取边显示，每页一个表格。
+    /// `json` 为 `true` 时获取全部结果后输出 `PullRequestListOutput`。
+    pub fn list(
+        state: Option<String>,
+        filters: PRListFilterArgs,
+        limit: Option<usize>,
+        format: TableFormat,
+        json: bool,
+    ) -> Result<()> {
+        if json {
+            OutputFormat::Json.route_diagnostics();
+        } else if format.is_table() {
+            log_break!('=', 40, "PR List");
+        }
         let provider = create_provider_auto()?;
 
-        // 默认只获取 open 状态的 PR
-        let state = state.as_deref().unwrap_or("open");
+        let filter = PrListFilter {
+            // 默认只获取 open 状态的 PR
+            state: Some(state.unwrap_or_else(|| "open".to_string())),
+            author: filters.author,
+            mine: filters.mine,
+            label: filters.label,
+            base: filters.base,
+            sort: match filters.sort {
+                PRListSort::Created => PrListSort::Created,
+                PRListSort::Updated => PrListSort::Updated,
+            },
+            limit,
+        };
+
+        if !json && format.is_table() && filter.limit() > STREAM_THRESHOLD {
+            return Self::list_streaming(provider.as_ref(), &filter);
+        }
 
         // 通过 trait 方法获取表格行数据
-        let rows = provider.get_pull_requests(Some(state), limit)?;
+        let rows = provider.get_pull_requests(&filter)?;
+
+        if json {
+            let output = PullRequestListOutput::new(rows);
+            log_message!("{}", serde_json::to_string_pretty(&output)?);
+            return Ok(());
+        }
 
-        if rows.is_empty() {
+        if rows.is_empty() && format.is_table() {
             log_message!("No PRs found.");
             return Ok(());
         }
 
         // 使用表格显示
-        log_message!(
-            "{}",
-            TableBuilder::new(rows)
-                .with_title("Pull Requests")
-                .with_style(TableStyle::Modern)
-                .render()
-        );
+        log_message!("{}", format.render(Self::table(rows, "Pull Requests")));
 
         Ok(())
     }
+
+    /// 边获取边显示：每获取一页显示一个表格
+    fn list_streaming(provider: &dyn PlatformProvider, filter: &PrListFilter) -> Result<()> {
+        let mut shown = 0;
+        provider.stream_pull_requests(filter, &mut |rows| {
+            let title = format!("Pull Requests ({}-{})", shown + 1, shown + rows.len());
+            shown += rows.len();
+            log_message!("{}", Self::table(rows, title).render());
+            Ok(())
+        })?;
+
+        if shown == 0 {
+            log_message!("No PRs found.");
+        } else {
+            log_message!("{} PR(s) listed.", shown);
+        }
+        Ok(())
+    }
+
+    fn table(rows: Vec<PullRequestRow>, title: impl Into<String>) -> TableBuilder<PullRequestRow> {
+        TableBuilder::new(rows)
+            .with_title(title)
+            .with_style(TableStyle::Modern)
+            .with_column_max_width(TITLE_COLUMN, TITLE_MAX_WIDTH)
+            .with_overflow(TableOverflow::Truncate)
+            .with_terminal_width()
+    }
 }
@@ -1,11 +1,15 @@
+use crate::base::logger::console::styled;
+use crate::cli::PRMergeMethod;
 use crate::commands::check;
 use crate::commands::pr::helpers;
 use crate::git::{GitBranch, GitRepo};
 use crate::jira::status::JiraStatus;
 use crate::jira::{extract_jira_ticket_id, Jira, JiraWorkHistory};
-use crate::pr::create_provider_auto;
 use crate::pr::helpers::resolve_pull_request_id;
-use crate::{log_break, log_info, log_success, log_warning};
+use crate::pr::platform::PlatformProvider;
+use crate::pr::{create_provider_auto, BranchDeletion, CheckState, MergeMethod, MergeReadiness};
+use crate::repo::RepoConfig;
+use crate::{log_break, log_debug, log_info, log_message, log_success, log_warning};
 use color_eyre::Result;
 
 /// PR 合并命令
@@ -15,13 +19,36 @@ pub struct PullRequestMergeCommand;
 #[allow(dead_code)]
 impl PullRequestMergeCommand {
     /// 合并 PR
-    pub fn merge(pull_request_id: Option<String>, _force: bool) -> Result<()> {
+    ///
+    /// # 参数
+    ///
+    /// * `pull_request_id` - PR ID（为空时根据当前分支自动检测）
+    /// * `force` - 删除本地源分支时跳过未推送提交的检查
+    /// * `dry_run` - 只检查合并条件（可合并状态、CI 检查、评审、允许的合并方式），不执行合并
+    /// * `delete_branch` - 合并后删除源分支（为 false 时使用仓库配置 `pr.delete_branch_on_merge`，未配置时删除）
+    /// * `keep_branch` - 合并后保留源分支（优先于仓库配置）
+    /// * `strategy` - 合并方式（为空时按目标分支使用仓库配置 `pr.merge_strategy`）
+    pub fn merge(
+        pull_request_id: Option<String>,
+        force: bool,
+        dry_run: bool,
+        delete_branch: bool,
+        keep_branch: bool,
+        strategy: Option<PRMergeMethod>,
+    ) -> Result<()> {
         // 1. 运行环境检查
         check::CheckCommand::run_all()?;
 
         // 2. 获取 PR ID
         let pull_request_id = resolve_pull_request_id(pull_request_id)?;
 
+        if dry_run {
+            return Self::check_readiness(&pull_request_id);
+        }
+
+        let delete_branch =
+            !keep_branch && (delete_branch || RepoConfig::get_delete_branch_on_merge());
+
         log_break!();
         log_success!("Merging PR: #{}", pull_request_id);
 
@@ -32,11 +59,16 @@ impl PullRequestMergeCommand {
         let default_branch = GitBranch::get_default_branch()?;
 
         // 5. 合并 PR（如果已合并，跳过合并步骤但继续执行后续步骤）
-        Self::merge_pull_request(&pull_request_id)?;
+        Self::merge_pull_request(&pull_request_id, strategy)?;
 
-        // 6. 合并后清理：切换到默认分支并删除当前分支
-        // 注意：如果 PR 已合并，远程分支可能已经被删除
-        Self::cleanup_after_merge(&current_branch, &default_branch)?;
+        // 6. 合并后删除源分支（远程和本地）
+        if delete_branch {
+            Self::delete_source_branch(&pull_request_id, &current_branch, &default_branch, force)?;
+        } else {
+            log_info!(
+                "Source branch kept (--keep-branch or pr.delete_branch_on_merge = false in repo config)"
+            );
+        }
 
         // 7. 更新 Jira 状态（如果关联了 ticket）
         Self::update_jira_status(&pull_request_id)?;
@@ -46,7 +78,7 @@ impl PullRequestMergeCommand {
 
     /// 合并 PR（根据仓库类型调用对应的实现）
     /// 返回 true 表示新合并，false 表示已经合并
-    fn merge_pull_request(pull_request_id: &str) -> Result<bool> {
+    fn merge_pull_request(pull_request_id: &str, strategy: Option<PRMergeMethod>) -> Result<bool> {
         let provider = create_provider_auto()?;
 
         // 先检查 PR 状态
@@ -63,7 +95,8 @@ impl PullRequestMergeCommand {
         }
 
         // 执行合并操作
-        match provider.merge_pull_request(pull_request_id, true) {
+        let method = Self::resolve_merge_method(provider.as_ref(), pull_request_id, strategy)?;
+        match provider.merge_pull_request(pull_request_id, false, method) {
             Ok(()) => {
                 log_success!("PR merged successfully");
                 Ok(true)
@@ -85,8 +118,206 @@ impl PullRequestMergeCommand {
         }
     }
 
-    /// 更新 Jira 状态（如果关联了 ticket）
+    /// 开启自动合并
+    ///
+    /// 必需的检查通过后由平台完成合并；合并尚未发生，因此不执行分支清理和 Jira 更新。
+    ///
+    /// # 参数
+    ///
+    /// * `pull_request_id` - PR ID（为空时根据当前分支自动检测）
+    /// * `strategy` - 合并方式（为空时按目标分支使用仓库配置 `pr.merge_strategy`，未配置时使用仓库允许的首选方式）
+    pub fn enable_auto_merge(
+        pull_request_id: Option<String>,
+        strategy: Option<PRMergeMethod>,
+    ) -> Result<()> {
+        check::CheckCommand::run_all()?;
+
+        let pull_request_id = resolve_pull_request_id(pull_request_id)?;
+
+        log_break!();
+        log_info!("Enabling auto-merge for PR #{}...", pull_request_id);
+        let provider = create_provider_auto()?;
+        let method = Self::resolve_merge_method(provider.as_ref(), &pull_request_id, strategy)?;
+        let method = provider.enable_auto_merge(&pull_request_id, method)?;
+
+        log_success!(
+            "Auto-merge enabled for PR #{} (method: {})",
+            pull_request_id,
+            method
+        );
+        log_info!("The PR will be merged automatically once all required checks pass");
+        Ok(())
+    }
+
+    /// 确定合并方式
+    ///
+    /// 优先使用 `--strategy`，否则按 PR 的目标分支读取仓库配置 `pr.merge_strategy`
+    /// （`pr.merge_strategy_by_branch` 中匹配的分支规则优先）。选择的合并方式不被仓库允许时，
+    /// 输出警告并回退到第一个允许的合并方式。
+    ///
+    /// # 返回
+    ///
+    /// 返回要使用的合并方式；未指定也未配置时返回 `None`，由平台使用首选方式。
+    fn resolve_merge_method(
+        provider: &dyn PlatformProvider,
+        pull_request_id: &str,
+        strategy: Option<PRMergeMethod>,
+    ) -> Result<Option<MergeMethod>> {
+        let (method, source) = match strategy {
+            Some(strategy) => {
+                let method = match strategy {
+                    PRMergeMethod::Squash => MergeMethod::Squash,
+                    PRMergeMethod::Rebase => MergeMethod::Rebase,
+                    PRMergeMethod::Merge => MergeMethod::Merge,
+                };
+                (method, "--strategy".to_string())
+            }
+            None => {
+                let target_branch =
+                    provider.get_pull_request_details(pull_request_id)?.target_branch;
+                let Some(name) = RepoConfig::get_merge_strategy(&target_branch) else {
+                    return Ok(None);
+                };
+                match MergeMethod::from_name(&name) {
+                    Some(method) => (method, format!("repo config for '{}'", target_branch)),
+                    None => {
+                        log_warning!(
+                            "Ignoring invalid merge strategy '{}' in repo config (expected squash, rebase or merge)",
+                            name
+                        );
+                        return Ok(None);
+                    }
+                }
+            }
+        };
+
+        let allowed = match provider.get_merge_readiness(pull_request_id) {
+            Ok(readiness) => readiness.allowed_merge_methods(),
+            Err(e) => {
+                log_debug!("Cannot check allowed merge methods: {}", e);
+                Vec::new()
+            }
+        };
+        match allowed.first() {
+            Some(&fallback) if !allowed.contains(&method) => {
+                log_warning!(
+                    "Merge strategy '{}' ({}) is not allowed by the repository, falling back to '{}'",
+                    method,
+                    source,
+                    fallback
+                );
+                Ok(Some(fallback))
+            }
+            _ => {
+                log_info!("Using merge strategy: {} ({})", method, source);
+                Ok(Some(method))
+            }
+        }
+    }
+
+    /// 检查并输出 PR 的合并条件（不执行合并）
+    fn check_readiness(pull_request_id: &str) -> Result<()> {
+        let provider = create_provider_auto()?;
+
+        log_break!();
+        log_info!("Checking merge readiness of PR #{}...", pull_request_id);
+        let readiness = provider.get_merge_readiness(pull_request_id)?;
+
+        log_break!();
+        Self::print_readiness(&readiness);
+
+        log_break!();
+        let blockers = readiness.blockers();
+        if blockers.is_empty() {
+            log_success!("PR #{} can be merged", pull_request_id);
+        } else {
+            log_warning!("PR #{} cannot be merged yet:", pull_request_id);
+            for blocker in &blockers {
+                log_message!("  - {}", blocker);
+            }
+        }
+        log_info!("Dry run mode: PR was not merged");
+        Ok(())
+    }
+
+    /// 输出合并条件报告
+    fn print_readiness(readiness: &MergeReadiness) {
+        let mergeable = match readiness.mergeable {
+            Some(true) => styled("yes").green(),
+            Some(false) => styled("no (conflicts)").red(),
+            None => styled("unknown (still computing)").yellow(),
+        };
+        log_message!("State:      {}", readiness.state);
+        match &readiness.mergeable_state {
+            Some(state) => {
+                log_message!("Mergeable:  {} [{}]", mergeable, state);
+            }
+            None => {
+                log_message!("Mergeable:  {}", mergeable);
+            }
+        }
+
+        if readiness.checks.is_empty() {
+            log_message!("Checks:     none reported");
+        } else {
+            log_message!(
+                "Checks:     {} total, {} failing, {} required pending",
+                readiness.checks.len(),
+                readiness.failing_checks().len(),
+                readiness.pending_required_checks().len()
+            );
+            for check in &readiness.checks {
+                let state = match check.state {
+                    CheckState::Passed => styled(check.state).green(),
+                    CheckState::Failed => styled(check.state).red(),
+                    CheckState::Pending | CheckState::Expected => styled(check.state).yellow(),
+                    CheckState::Skipped => styled(check.state).dim(),
+                };
+                let required = if check.required { " (required)" } else { "" };
+                log_message!("  - {}{}: {}", check.name, required, state);
+            }
+        }
+
+        let reviews = &readiness.reviews;
+        match reviews.required_approvals {
+            Some(required) => {
+                log_message!(
+                    "Reviews:    {} of {} required approvals",
+                    reviews.approvals,
+                    required
+                );
+            }
+            None => {
+                log_message!("Reviews:    {} approval(s)", reviews.approvals);
+            }
+        }
+        if reviews.changes_requested {
+            log_message!("  - {}", styled("changes requested").red());
+        }
+        for reviewer in &reviews.pending_reviewers {
+            log_message!("  - waiting for required reviewer: {}", reviewer);
+        }
+
+        if !readiness.merge_methods.is_empty() {
+            log_message!("Merge methods:");
+            for (method, allowed) in &readiness.merge_methods {
+                let allowed = if *allowed {
+                    styled("allowed").green()
+                } else {
+                    styled("not allowed").dim()
+                };
+                log_message!("  - {}: {}", method, allowed);
+            }
+        }
+    }
+
+    /// 更新 Jira 状态（如果关联了 ticket，未配置 Jira 时跳过）
     fn update_jira_status(pull_request_id: &str) -> Result<()> {
+        if !Jira::is_configured() {
+            log_debug!("Jira is not configured, skipping Jira status update");
+            return Ok(());
+        }
+
         // 获取当前仓库 URL
         let repository = GitRepo::get_remote_url().ok();
 
@@ -136,13 +367,73 @@ impl PullRequestMergeCommand {
         Ok(title.and_then(|t| extract_jira_ticket_id(&t)))
     }
 
-    /// 合并后清理：切换到默认分支并删除当前分支
-    fn cleanup_after_merge(current_branch: &str, default_branch: &str) -> Result<()> {
-        log_info!(
-            "Note: Remote branch '{}' may have already been deleted via API",
-            current_branch
-        );
-        helpers::cleanup_branch(current_branch, default_branch, "PR merge")?;
+    /// 合并后删除源分支
+    ///
+    /// 远程分支通过平台 API 删除（受保护的分支会被跳过）；本地分支为当前分支时，
+    /// 先切换到默认分支再删除。本地分支有未推送到远程的提交时保留（`force` 为 true 时仍然删除）。
+    /// 删除失败只输出警告，不影响后续步骤。
+    fn delete_source_branch(
+        pull_request_id: &str,
+        current_branch: &str,
+        default_branch: &str,
+        force: bool,
+    ) -> Result<()> {
+        let provider = create_provider_auto()?;
+        let deletion = match provider.delete_source_branch(pull_request_id) {
+            Ok(deletion) => deletion,
+            Err(e) => {
+                log_warning!("Failed to delete remote branch: {}", e);
+                return Ok(());
+            }
+        };
+
+        match &deletion {
+            BranchDeletion::Deleted(branch) => {
+                log_success!("Remote branch deleted: {}", branch);
+            }
+            BranchDeletion::NotFound(branch) => {
+                log_info!("Remote branch already deleted: {}", branch);
+            }
+            BranchDeletion::Skipped { branch, reason } => {
+                log_warning!("Skipped deleting branch '{}': {}", branch, reason);
+                return Ok(());
+            }
+        }
+
+        let branch = deletion.branch();
+        if branch == default_branch || !GitBranch::has_local_branch(branch)? {
+            return Ok(());
+        }
+        if !force {
+            // 合并的是远程分支上的提交，未推送的本地提交不在 PR 中，删除后会丢失
+            match GitBranch::get_unpushed_commits(branch) {
+                Ok(commits) if commits.is_empty() => {}
+                Ok(commits) => {
+                    log_warning!(
+                        "Kept local branch '{}': it has {} commit(s) not pushed to the remote (use --force to delete it anyway)",
+                        branch,
+                        commits.len()
+                    );
+                    return Ok(());
+                }
+                Err(e) => {
+                    log_warning!("Kept local branch '{}': {}", branch, e);
+                    return Ok(());
+                }
+            }
+        }
+        if branch == current_branch {
+            return helpers::cleanup_branch(current_branch, default_branch, "PR merge");
+        }
+        // 分支在其他 worktree 中检出时无法删除
+        match GitBranch::delete(branch, false).or_else(|_| GitBranch::delete(branch, true)) {
+            Ok(()) => {
+                log_success!("Local branch deleted: {}", branch);
+            }
+            Err(e) => {
+                log_warning!("Failed to delete local branch '{}': {}", branch, e);
+            }
+        }
         Ok(())
     }
 }
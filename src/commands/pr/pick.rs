@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 use color_eyre::{eyre::WrapErr, Result};
 
 use crate::base::dialog::{ConfirmDialog, InputDialog};
@@ -31,7 +33,7 @@ use crate::{log_break, log_error, log_info, log_success, log_warning};
 /// 1. 预检查
 /// 2. 验证分支存在
 /// 3. 拉取最新代码
-/// 4. 检测新提交
+/// 4. 检测新提交（跳过目标分支上已有等价提交的 commit）
 /// 5. 保存当前状态
 /// 6. 检查工作区状态
 /// 7. 切换到 TO_BRANCH
@@ -84,6 +86,30 @@ impl PullRequestPickCommand {
             return Ok(());
         }
 
+        // 跳过目标分支上已有等价提交（patch-id 相同）的 commit，避免重复提交或空提交错误
+        let (commits, skipped) = Self::partition_picked_commits(&from_branch, &to_branch, commits)?;
+        for commit in &skipped {
+            log_info!(
+                "Skipping {}: an equivalent commit already exists on '{}'",
+                Self::describe_commit(commit),
+                to_branch
+            );
+        }
+
+        // 预览模式（不切换分支、不修改工作区）
+        if dry_run {
+            return Self::dry_run(&from_branch, &to_branch, &commits, &skipped);
+        }
+
+        if commits.is_empty() {
+            log_success!(
+                "All {} commit(s) already exist on '{}', nothing to cherry-pick",
+                skipped.len(),
+                to_branch
+            );
+            return Ok(());
+        }
+
         log_success!("Found {} commit(s) to cherry-pick", commits.len());
 
         // 5. 保存当前分支
@@ -132,21 +158,10 @@ impl PullRequestPickCommand {
             }
         }
 
-        // 10. 预览模式
-        if dry_run {
-            Self::dry_run(&from_branch, &to_branch, &commits)?;
-            // 恢复原分支
-            GitBranch::checkout_branch(&current_branch)?;
-            if has_stashed {
-                handle_stash_pop_result(GitStash::stash_pop(None));
-            }
-            return Ok(());
-        }
-
-        // 11. 获取源 PR 信息（如果存在）
+        // 10. 获取源 PR 信息（如果存在）
         let source_pr_info = Self::get_source_pr_info(&from_branch)?;
 
-        // 12. 询问是否创建 PR
+        // 11. 询问是否创建 PR
         let should_create_pr = ConfirmDialog::new("Create PR for cherry-picked commits?")
             .with_default(true)
             .prompt()?;
@@ -172,11 +187,11 @@ impl PullRequestPickCommand {
             return Ok(());
         }
 
-        // 13. 交互式 PR 创建流程（复用 create 的逻辑）
+        // 12. 交互式 PR 创建流程（复用 create 的逻辑）
         // 如果失败，需要恢复原分支和 stash
         let pr_result = Self::create_pr_interactively(&from_branch, &source_pr_info);
 
-        // 14. 恢复原分支和 stash（无论 PR 创建是否成功）
+        // 13. 恢复原分支和 stash（无论 PR 创建是否成功）
         let restore_branch_result = GitBranch::checkout_branch(&current_branch);
         if let Err(e) = &restore_branch_result {
             log_error!("Failed to restore original branch: {}", e);
@@ -284,6 +299,48 @@ impl PullRequestPickCommand {
         })
     }
 
+    /// 按目标分支是否已有等价提交划分提交列表
+    ///
+    /// 使用 `git cherry` 按 patch-id 比较，已经 cherry-pick 到目标分支（或能从目标分支到达）的
+    /// 提交会被跳过。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `(要 cherry-pick 的提交, 跳过的提交)`，保持原有顺序。
+    fn partition_picked_commits(
+        from_branch: &str,
+        to_branch: &str,
+        commits: Vec<String>,
+    ) -> Result<(Vec<String>, Vec<String>)> {
+        let upstream = Self::branch_ref(to_branch)?;
+        let head = Self::branch_ref(from_branch)?;
+        let pending: HashSet<String> = GitCherryPick::cherry(&upstream, &head)?
+            .into_iter()
+            .filter(|commit| !commit.applied)
+            .map(|commit| commit.sha)
+            .collect();
+
+        Ok(commits.into_iter().partition(|commit| pending.contains(commit)))
+    }
+
+    /// 分支引用（分支只在远程存在时使用 `origin/<branch>`）
+    fn branch_ref(branch: &str) -> Result<String> {
+        if GitBranch::has_local_branch(branch)? {
+            Ok(branch.to_string())
+        } else {
+            Ok(format!("origin/{}", branch))
+        }
+    }
+
+    /// 提交的简短描述（短 SHA 和标题）
+    fn describe_commit(commit: &str) -> String {
+        let short_sha = &commit[..commit.len().min(8)];
+        match GitCommit::get_commit_info(commit) {
+            Ok(info) => format!("{} {}", short_sha, info.message),
+            Err(_) => short_sha.to_string(),
+        }
+    }
+
     /// 检查工作区状态
     fn check_working_directory() -> Result<bool> {
         let has_changes = !GitCommit::status()?.trim().is_empty();
@@ -570,8 +627,9 @@ impl PullRequestPickCommand {
         // 由于 create 的函数是私有的，我们需要重新实现或提取公共函数
         // 这里先实现核心逻辑
 
-        // 1. 从源 PR 提取信息（Jira ticket、描述、变更类型）
-        let extracted_info = extract_info_from_source_pr(source_pr_info);
+        // 1. 从源 PR 提取信息（Jira ticket、描述、变更类型），未配置 Jira 时不使用 ticket
+        let mut extracted_info = extract_info_from_source_pr(source_pr_info);
+        extracted_info.jira_ticket = extracted_info.jira_ticket.filter(|_| Jira::is_configured());
 
         // 2. 确定分支类型（优先使用 repository prefix）
         // 需要在生成分支名之前确定类型，以便使用模板系统
@@ -670,11 +728,11 @@ impl PullRequestPickCommand {
             if use_extracted {
                 types.clone()
             } else {
-                // 用户选择不使用，重新选择
-                select_change_types()?
+                // 用户选择不使用，重新选择（预先选中源 PR 的变更类型）
+                select_change_types(types)?
             }
         } else {
-            select_change_types()?
+            select_change_types(&[])?
         };
 
         // 10. 生成 PR body（添加 pick 说明）
@@ -698,6 +756,7 @@ impl PullRequestPickCommand {
             jira_ticket.as_deref(),
             Some(&pick_note),
             jira_info.as_ref(),
+            None,
         )?;
 
         // 11. 创建或更新分支
@@ -747,8 +806,13 @@ impl PullRequestPickCommand {
 
     /// 获取或输入 Jira ticket（复用 create 的逻辑）
     ///
-    /// 注意：在 pick 场景下，此函数只处理输入逻辑，确认逻辑已在调用处处理
+    /// 注意：在 pick 场景下，此函数只处理输入逻辑，确认逻辑已在调用处处理。
+    /// 未配置 Jira 时不提示输入。
     fn resolve_jira_ticket(jira_ticket: Option<String>) -> Result<Option<String>> {
+        if !Jira::is_configured() {
+            return Ok(None);
+        }
+
         // 在 pick 场景下，此函数只会在确认不使用提取的 ticket 后调用，传入 None
         // 因此这里只需要处理输入逻辑
         let input = InputDialog::new("Jira ticket (optional)")
@@ -792,6 +856,9 @@ impl PullRequestPickCommand {
             match CreateGenerator::generate(llm_input, exists_branches, git_diff) {
                 Ok(content) => {
                     log_success!("Generated branch name using LLM: {}", content.branch_name);
+                    if let Some(chunking) = content.chunking {
+                        log_info!("{}", chunking.notice());
+                    }
                     // 提取 slug（移除可能的前缀）
                     let slug = BranchNaming::sanitize(&content.branch_name);
                     (content.pr_title, slug)
@@ -911,13 +978,30 @@ impl PullRequestPickCommand {
     }
 
     /// 预览模式
-    fn dry_run(from_branch: &str, to_branch: &str, commits: &[String]) -> Result<()> {
+    fn dry_run(
+        from_branch: &str,
+        to_branch: &str,
+        commits: &[String],
+        skipped: &[String],
+    ) -> Result<()> {
         log_info!("[DRY RUN] Would execute the following operations:");
         log_info!("  From branch: {}", from_branch);
         log_info!("  To branch: {}", to_branch);
         log_info!("  Commits to cherry-pick: {}", commits.len());
         for (index, commit) in commits.iter().enumerate() {
-            log_info!("    {}. {}", index + 1, &commit[..8]);
+            log_info!("    {}. {}", index + 1, Self::describe_commit(commit));
+        }
+        log_info!(
+            "  Commits to skip (already on '{}'): {}",
+            to_branch,
+            skipped.len()
+        );
+        for (index, commit) in skipped.iter().enumerate() {
+            log_info!("    {}. {}", index + 1, Self::describe_commit(commit));
+        }
+        if commits.is_empty() {
+            log_info!("  Nothing to cherry-pick");
+            return Ok(());
         }
         log_info!("  Will switch to: {}", to_branch);
         log_info!("  Will cherry-pick (--no-commit) all commits");
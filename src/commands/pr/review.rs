@@ -0,0 +1,87 @@
+use crate::cli::PRReviewType;
+use crate::log_success;
+use crate::pr::create_provider_auto;
+use crate::pr::helpers::resolve_pull_request_id;
+use crate::pr::ReviewEvent;
+use color_eyre::{eyre::WrapErr, Result};
+
+/// PR 评审命令
+#[allow(dead_code)]
+pub struct PullRequestReviewCommand;
+
+#[allow(dead_code)]
+impl PullRequestReviewCommand {
+    /// 提交评审到 Pull Request
+    ///
+    /// # 参数
+    ///
+    /// * `pull_request_id` - PR ID（为空时从当前分支解析）
+    /// * `review_type` - 评审类型
+    /// * `message` - 评审内容（请求修改和评论必须提供）
+    pub fn review(
+        pull_request_id: Option<String>,
+        review_type: PRReviewType,
+        message: Option<String>,
+    ) -> Result<()> {
+        let event = match review_type {
+            PRReviewType::Approve => ReviewEvent::Approve,
+            PRReviewType::RequestChanges => ReviewEvent::RequestChanges,
+            PRReviewType::Comment => ReviewEvent::Comment,
+        };
+        Self::submit(pull_request_id, event, message)
+    }
+
+    /// 提交指定类型的评审（`pr approve` 和 `pr review` 共用）
+    pub fn submit(
+        pull_request_id: Option<String>,
+        event: ReviewEvent,
+        message: Option<String>,
+    ) -> Result<()> {
+        // 先校验评审内容，避免解析 PR ID 后才报错
+        let body = event.validate_body(message.as_deref())?;
+
+        // 获取 PR ID（从参数或当前分支）
+        let pr_id = resolve_pull_request_id(pull_request_id)?;
+
+        match event {
+            ReviewEvent::Approve => {
+                log_success!("Approving PR: #{}", pr_id);
+            }
+            ReviewEvent::RequestChanges => {
+                log_success!("Requesting changes on PR: #{}", pr_id);
+            }
+            ReviewEvent::Comment => {
+                log_success!("Submitting review comment to PR: #{}", pr_id);
+            }
+        }
+
+        // 创建平台提供者并提交评审
+        let provider = create_provider_auto()?;
+        if let Err(e) = provider.submit_review(&pr_id, event, body) {
+            // 检查是否是"不能评审自己的 PR"这种明确的业务错误
+            if e.to_string().contains("your own pull request") {
+                // 对于这种明确的业务错误，直接返回错误，不添加额外的上下文
+                return Err(e);
+            }
+            // 对于其他错误，添加上下文信息
+            return Err(e).wrap_err(format!(
+                "Failed to submit {} review to PR #{}",
+                event, pr_id
+            ));
+        }
+
+        match event {
+            ReviewEvent::Approve => {
+                log_success!("PR #{} approved successfully!", pr_id);
+            }
+            ReviewEvent::RequestChanges => {
+                log_success!("Requested changes on PR #{} successfully!", pr_id);
+            }
+            ReviewEvent::Comment => {
+                log_success!("Review comment submitted to PR #{} successfully!", pr_id);
+            }
+        }
+
+        Ok(())
+    }
+}
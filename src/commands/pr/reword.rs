@@ -273,8 +273,8 @@ impl PullRequestRewordCommand {
             vec![false; TYPES_OF_CHANGES.len()]
         };
 
-        // 获取 Jira 信息（如果存在）
-        let jira_info = if let Some(ticket) = jira_ticket {
+        // 获取 Jira 信息（如果存在，未配置 Jira 时不调用 Jira API）
+        let jira_info = if let Some(ticket) = jira_ticket.filter(|_| Jira::is_configured()) {
             Jira::get_ticket_info(ticket).ok()
         } else {
             None
@@ -290,6 +290,7 @@ impl PullRequestRewordCommand {
             jira_ticket,
             None, // dependency 暂时为空
             jira_info.as_ref(),
+            None, // reword 保持原有 PR body 结构，不使用仓库 PR 模板
         )
         .wrap_err("Failed to generate PR body")
     }
@@ -1,7 +1,15 @@
-use crate::pr::create_provider_auto;
+use std::time::Duration;
+
+use crate::base::http::{HttpClient, HttpRequestError};
+use crate::cli::OutputFormatArgs;
+use crate::commands::jira::OutputFormat;
+use crate::pr::{create_provider_auto, PullRequestStatusOutput};
 use crate::{log_break, log_message};
 use color_eyre::Result;
 
+/// 查询 PR 状态的超时时间（状态查询应快速返回，不使用默认超时时间）
+const STATUS_TIMEOUT: Duration = Duration::from_secs(10);
+
 /// PR 状态命令
 #[allow(dead_code)]
 pub struct PullRequestStatusCommand;
@@ -9,12 +17,26 @@ pub struct PullRequestStatusCommand;
 #[allow(dead_code)]
 impl PullRequestStatusCommand {
     /// 显示 PR 状态信息
-    pub fn show(pull_request_id_or_branch: Option<String>) -> Result<()> {
+    ///
+    /// JSON/YAML 格式输出 `PullRequestStatusOutput`，Markdown 和表格格式输出文本信息。
+    pub fn show(
+        pull_request_id_or_branch: Option<String>,
+        output_format: OutputFormatArgs,
+    ) -> Result<()> {
+        let format = OutputFormat::from(&output_format);
+        format.route_diagnostics();
+        HttpClient::set_default_timeout(STATUS_TIMEOUT);
+
         // 获取 PR ID 或标识符
         let pr_identifier = Self::get_pr_identifier(pull_request_id_or_branch)?;
 
         // 显示 PR 信息
-        Self::show_pr_info(&pr_identifier)?;
+        match format {
+            OutputFormat::Json | OutputFormat::Yaml => {
+                Self::show_pr_details(&pr_identifier, format)?
+            }
+            OutputFormat::Markdown | OutputFormat::Table => Self::show_pr_info(&pr_identifier)?,
+        }
 
         Ok(())
     }
@@ -39,11 +61,47 @@ impl PullRequestStatusCommand {
     /// 显示 PR 信息
     fn show_pr_info(pr_identifier: &str) -> Result<()> {
         let provider = create_provider_auto()?;
-        let info = provider.get_pull_request_info(pr_identifier)?;
+        let info =
+            provider.get_pull_request_info(pr_identifier).map_err(Self::with_network_hint)?;
 
         log_break!();
         log_break!('=', 40, "PR Information");
         log_message!("{}", info);
         Ok(())
     }
+
+    /// 以 JSON/YAML 格式输出 PR 信息
+    fn show_pr_details(pr_identifier: &str, format: OutputFormat) -> Result<()> {
+        let provider = create_provider_auto()?;
+        let details = provider
+            .get_pull_request_details(pr_identifier)
+            .map_err(Self::with_network_hint)?;
+        let output = PullRequestStatusOutput::from(details);
+
+        match format {
+            OutputFormat::Yaml => {
+                log_message!("{}", serde_saphyr::to_string(&output)?);
+            }
+            _ => {
+                log_message!("{}", serde_json::to_string_pretty(&output)?);
+            }
+        }
+        Ok(())
+    }
+
+    /// 为超时和连接失败的错误添加提示
+    fn with_network_hint(e: color_eyre::Report) -> color_eyre::Report {
+        let hint = match e.downcast_ref::<HttpRequestError>() {
+            Some(error) if error.is_timeout() => format!(
+                "PR service did not respond within {}s, please try again later",
+                error.timeout.as_secs()
+            ),
+            Some(error) if error.is_connect() => {
+                "Could not connect to PR service, please check your network or proxy settings"
+                    .to_string()
+            }
+            _ => return e,
+        };
+        e.wrap_err(hint)
+    }
 }
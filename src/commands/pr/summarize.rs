@@ -8,17 +8,17 @@ use color_eyre::{
 };
 use std::path::PathBuf;
 
+use crate::base::format::DisplayFormatter;
 use crate::base::indicator::Spinner;
 use crate::base::settings::settings::default_download_base_dir;
 use crate::base::settings::Settings;
 use crate::base::util::directory::DirectoryWalker;
 use crate::base::util::file::FileWriter;
 use crate::git::GitRepo;
-use crate::log_info;
-use crate::log_success;
 use crate::pr::helpers::get_current_branch_pr_id;
-use crate::pr::llm::{FileSummaryGenerator, SummaryGenerator};
+use crate::pr::llm::{FileSummaryCache, FileSummaryGenerator, SummaryGenerator};
 use crate::pr::platform::create_provider_auto;
+use crate::{log_break, log_info, log_message, log_success};
 
 /// PR 总结命令
 pub struct SummarizeCommand;
@@ -29,6 +29,7 @@ impl SummarizeCommand {
     /// # 参数
     ///
     /// * `pull_request_id` - PR ID（可选，如果不提供则自动检测当前分支的 PR）
+    /// * `no_cache` - 不读取也不写入文件修改总结缓存
     ///
     /// # 返回
     ///
@@ -37,7 +38,9 @@ impl SummarizeCommand {
     /// # 说明
     ///
     /// 语言设置从配置文件读取，如果未配置则使用默认值 "en"。
-    pub fn summarize(pull_request_id: Option<String>) -> Result<String> {
+    pub fn summarize(pull_request_id: Option<String>, no_cache: bool) -> Result<String> {
+        FileSummaryCache::set_enabled(!no_cache);
+
         // 检查是否在 Git 仓库中
         if !GitRepo::is_git_repo() {
             color_eyre::eyre::bail!(
@@ -77,6 +80,10 @@ impl SummarizeCommand {
         })
         .wrap_err("Failed to generate PR summary")?;
 
+        if let Some(chunking) = summary.chunking {
+            log_info!("{}", chunking.notice());
+        }
+
         // 解析 diff，提取所有文件的修改
         log_info!("Parsing PR diff to extract file changes...");
         log_info!("Diff length: {} characters", pr_diff.len());
@@ -159,11 +166,31 @@ impl SummarizeCommand {
             .write_str(&final_summary)
             .wrap_err_with(|| format!("Failed to write summary to: {:?}", output_path))?;
 
+        // 在终端显示总结（不含代码修改部分）
+        log_break!();
+        log_message!("{}", DisplayFormatter::render_markdown(&summary_with_title));
+        log_break!();
         log_success!("PR summary saved to: {}", output_path.display());
 
         Ok(output_path.to_string_lossy().to_string())
     }
 
+    /// 清空文件修改总结缓存
+    ///
+    /// # 返回
+    ///
+    /// 返回删除的缓存条目数
+    pub fn clear_cache() -> Result<usize> {
+        let cache = FileSummaryCache::open()?;
+        let removed = cache.clear()?;
+        log_success!(
+            "Cleared {} cached file summaries from: {}",
+            removed,
+            cache.dir().display()
+        );
+        Ok(removed)
+    }
+
     /// 构建输出路径
     ///
     /// 从 Document Base Directory 配置读取基础路径，如果未配置则使用默认值 `~/Documents/Workflow`。
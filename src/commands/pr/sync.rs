@@ -1,10 +1,13 @@
 use crate::base::dialog::ConfirmDialog;
-use crate::branch::sync::{BranchSync, BranchSyncCallbacks, BranchSyncOptions, BranchSyncResult};
+use crate::branch::sync::{
+    BranchSync, BranchSyncCallbacks, BranchSyncOptions, BranchSyncPlan, BranchSyncResult,
+    StackBranchStatus,
+};
 use crate::commands::check;
 use crate::git::GitBranch;
 use crate::pr::create_provider_auto;
 use crate::pr::helpers::get_current_branch_pr_id;
-use crate::{log_break, log_debug, log_info, log_success, log_warning};
+use crate::{log_break, log_debug, log_info, log_message, log_success, log_warning};
 use color_eyre::{eyre::WrapErr, Result};
 
 /// PR 分支同步的命令（合并了 integrate 和 sync 的功能）
@@ -34,6 +37,33 @@ impl BranchSyncCallbacks for PRSyncCallbacks {
         // 处理源分支的 PR 和分支清理
         PullRequestSyncCommand::handle_source_branch_cleanup(source_branch, &result.current_branch)
     }
+
+    fn on_plan(&self, plan: &BranchSyncPlan) -> Result<()> {
+        plan.report();
+        if plan.is_up_to_date() {
+            return Ok(());
+        }
+
+        log_break!();
+        log_info!("After a successful sync, workflow would:");
+        if plan.source_branch_info.is_remote {
+            log_message!(
+                "  - Push '{}' to update its PR (if one exists)",
+                plan.current_branch
+            );
+        }
+        log_message!(
+            "  - Ask whether to push '{}' to remote",
+            plan.current_branch
+        );
+        if plan.source_branch != plan.current_branch {
+            log_message!(
+                "  - Ask whether to close the PR of '{}' (if any) and delete the branch",
+                plan.source_branch
+            );
+        }
+        Ok(())
+    }
 }
 
 impl PullRequestSyncCommand {
@@ -45,8 +75,21 @@ impl PullRequestSyncCommand {
     /// * `rebase` - 是否使用 rebase（默认使用 merge）
     /// * `ff_only` - 是否只允许 fast-forward 合并
     /// * `squash` - 是否使用 squash 合并
+    /// * `dry_run` - 只预览同步计划，不执行同步（不推送、不处理 PR）
+    /// * `message` - squash 提交消息（未提供时打开编辑器）
+    /// * `no_push` - 本地同步完成后停止（不推送、不更新 PR、不清理源分支）
+    /// * `all` - 将当前分支所在的整个分支栈按顺序 rebase 到源分支之上
     #[allow(dead_code)]
-    pub fn sync(source_branch: String, rebase: bool, ff_only: bool, squash: bool) -> Result<()> {
+    pub fn sync(
+        source_branch: String,
+        rebase: bool,
+        ff_only: bool,
+        squash: bool,
+        dry_run: bool,
+        message: Option<String>,
+        no_push: bool,
+        all: bool,
+    ) -> Result<()> {
         // 1. 运行检查（可选，但建议运行）
         log_info!("Running pre-flight checks...");
         if let Err(e) = check::CheckCommand::run_all() {
@@ -62,18 +105,59 @@ impl PullRequestSyncCommand {
             rebase,
             ff_only,
             squash,
+            dry_run,
+            message,
+            no_push,
+            all,
         };
 
         // 使用 PR 回调
         let callbacks = Box::new(PRSyncCallbacks);
 
-        BranchSync::sync(options, Some(callbacks))?;
+        let result = BranchSync::sync(options, Some(callbacks))?;
 
         log_break!();
+        if all {
+            return Self::report_stack(&result);
+        }
+        if dry_run {
+            log_info!("Dry run mode: no changes were made");
+            return Ok(());
+        }
         log_success!("Sync completed successfully!");
         Ok(())
     }
 
+    /// 输出分支栈的同步结果
+    ///
+    /// 有分支产生冲突时返回错误（冲突的处理方式已由 `BranchSync` 输出）。
+    fn report_stack(result: &BranchSyncResult) -> Result<()> {
+        log_info!("Stack sync summary:");
+        for entry in &result.stack {
+            log_message!(
+                "  {} (onto '{}'): {}",
+                entry.branch,
+                entry.onto,
+                entry.status.description()
+            );
+        }
+
+        if result.dry_run {
+            log_info!("Dry run mode: no changes were made");
+            return Ok(());
+        }
+        if let Some(entry) =
+            result.stack.iter().find(|entry| entry.status == StackBranchStatus::Conflict)
+        {
+            color_eyre::eyre::bail!(
+                "Stack sync stopped at '{}' because of rebase conflicts",
+                entry.branch
+            );
+        }
+        log_success!("Stack synced successfully!");
+        Ok(())
+    }
+
     /// 检查并更新当前分支的 PR
     ///
     /// 如果当前分支已创建 PR，则推送代码以更新 PR。
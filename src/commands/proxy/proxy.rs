@@ -1,7 +1,9 @@
 use crate::base::util::Clipboard;
+use crate::cli::OutputFormatArgs;
+use crate::commands::jira::OutputFormat;
 use crate::{
-    log_break, log_debug, log_info, log_message, log_success, log_warning, ProxyManager,
-    SystemProxyReader,
+    log_break, log_debug, log_info, log_message, log_success, log_warning, ProxyCheckOutput,
+    ProxyManager, SystemProxyReader,
 };
 use color_eyre::{eyre::WrapErr, Result};
 
@@ -10,7 +12,12 @@ pub struct ProxyCommand;
 
 impl ProxyCommand {
     /// 检查代理状态和配置
-    pub fn check() -> Result<()> {
+    ///
+    /// JSON/YAML 格式输出 `ProxyCheckOutput`，Markdown 和表格格式输出文本信息。
+    pub fn check(output_format: OutputFormatArgs) -> Result<()> {
+        let format = OutputFormat::from(&output_format);
+        format.route_diagnostics();
+
         // 1. 获取系统代理设置
         log_debug!("Reading system proxy settings...");
         let proxy_info =
@@ -21,21 +28,37 @@ impl ProxyCommand {
         let shell_config_env =
             crate::base::shell::ShellConfigManager::load_env_vars().unwrap_or_default();
 
+        let output = ProxyCheckOutput::new(
+            &proxy_info,
+            &env_proxy,
+            &shell_config_env,
+            ProxyManager::is_proxy_configured(&proxy_info),
+        );
+
+        match format {
+            OutputFormat::Json => {
+                log_message!("{}", serde_json::to_string_pretty(&output)?);
+            }
+            OutputFormat::Yaml => {
+                log_message!("{}", serde_saphyr::to_string(&output)?);
+            }
+            OutputFormat::Markdown | OutputFormat::Table => Self::print_check(&output),
+        }
+
+        Ok(())
+    }
+
+    /// 以文本格式显示代理检查结果
+    fn print_check(output: &ProxyCheckOutput) {
         // 3. 显示系统代理设置
         log_success!("System proxy settings:");
-        for proxy_type in crate::ProxyType::all() {
-            if let Some(config) = proxy_info.get_config(proxy_type) {
-                if config.enable {
-                    if let (Some(addr), Some(port)) = (&config.address, &config.port) {
-                        let type_name = match proxy_type {
-                            crate::ProxyType::Http => "HTTP",
-                            crate::ProxyType::Https => "HTTPS",
-                            crate::ProxyType::Socks => "SOCKS",
-                        };
-                        log_info!("  {}: {}:{}", type_name, addr, port);
-                    }
-                }
-            }
+        for proxy in &output.system {
+            log_info!(
+                "  {}: {}:{}",
+                proxy.proxy_type.to_uppercase(),
+                proxy.address,
+                proxy.port
+            );
         }
 
         // 4. 显示当前环境变量设置
@@ -43,39 +66,24 @@ impl ProxyCommand {
         log_message!("Current environment variables:");
 
         // 合并显示：先显示环境变量，再显示配置文件中的（但不在环境变量中的）
-        let mut has_any_proxy = false;
-        let mut has_env_proxy = false;
-        let mut has_config_proxy = false;
-
-        for (key, value) in &env_proxy {
-            if key == "http_proxy" || key == "https_proxy" || key == "all_proxy" {
-                log_info!("  {}={} (current session)", key, value);
-                has_any_proxy = true;
-                has_env_proxy = true;
-            }
+        for (key, value) in &output.environment {
+            log_info!("  {}={} (current session)", key, value);
         }
-
-        for (key, value) in &shell_config_env {
-            if (key == "http_proxy" || key == "https_proxy" || key == "all_proxy")
-                && !env_proxy.contains_key(key)
-            {
-                log_info!("  {}={} (in shell config)", key, value);
-                has_any_proxy = true;
-                has_config_proxy = true;
-            }
+        for (key, value) in &output.shell_config {
+            log_info!("  {}={} (in shell config)", key, value);
         }
 
-        if !has_any_proxy {
+        if output.environment.is_empty() && output.shell_config.is_empty() {
             log_warning!("  No proxy environment variables set");
         } else {
             log_break!();
             log_message!("Proxy configuration status:");
-            if has_env_proxy {
+            if !output.environment.is_empty() {
                 log_info!("  Current shell: Enabled");
             } else {
                 log_info!("  Current shell: Disabled");
             }
-            if has_config_proxy {
+            if !output.shell_config.is_empty() {
                 log_info!("  Shell config file: Enabled (will be loaded in new shells)");
             } else {
                 log_info!("  Shell config file: Disabled");
@@ -83,7 +91,7 @@ impl ProxyCommand {
         }
 
         // 5. 检查代理是否已正确配置
-        if ProxyManager::is_proxy_configured(&proxy_info) {
+        if output.configured {
             log_break!();
             log_success!("  Proxy is configured correctly");
         } else {
@@ -92,8 +100,6 @@ impl ProxyCommand {
             log_message!("Run 'workflow proxy on' to enable proxy");
             log_message!("Or check macOS System Preferences > Network > Advanced > Proxies");
         }
-
-        Ok(())
     }
 
     /// 开启代理（设置环境变量）
@@ -122,6 +128,9 @@ impl ProxyCommand {
                 // 默认模式（持久化）
                 if let Some(ref shell_config_path) = result.shell_config_path {
                     log_success!("Proxy settings saved to {:?}", shell_config_path);
+                    if let Some(ref backup) = result.shell_config_backup {
+                        log_info!("Backup of previous config: {:?}", backup);
+                    }
                     log_message!("Mode: Persistent (saved to config file)");
                     log_message!("The proxy will be enabled when you start a new shell");
                     log_message!("Or run: source {:?}", shell_config_path);
@@ -161,6 +170,9 @@ impl ProxyCommand {
         // 显示从配置文件移除的结果
         if let Some(ref shell_config_path) = result.shell_config_path {
             log_success!("Proxy settings removed from {:?}", shell_config_path);
+            if let Some(ref backup) = result.shell_config_backup {
+                log_info!("Backup of previous config: {:?}", backup);
+            }
             log_message!("The proxy will be disabled when you start a new shell");
             log_message!("Or run: source {:?}", shell_config_path);
         }
@@ -9,7 +9,7 @@ use crate::base::settings::paths::Paths;
 use crate::base::settings::settings::{GitHubAccount, Settings};
 use crate::base::util::file::FileWriter;
 use crate::git::GitRepo;
-use crate::repo::config::{BranchConfig, PullRequestsConfig, RepoConfig};
+use crate::repo::config::{BranchConfig, RepoConfig};
 use crate::{log_break, log_debug, log_info, log_message, log_success, log_warning};
 use color_eyre::{eyre::WrapErr, Result};
 use std::collections::HashMap;
@@ -320,9 +320,9 @@ impl RepoSetupCommand {
 
         // 处理结果：Auto-accept change type
         if let Some(auto_accept) = form_result.get_bool("auto_accept_change_type") {
-            config.pr = Some(PullRequestsConfig {
-                auto_accept_change_type: Some(auto_accept),
-            });
+            let mut pr_config = config.pr.take().unwrap_or_default();
+            pr_config.auto_accept_change_type = Some(auto_accept);
+            config.pr = Some(pr_config);
         }
 
         // MCP Configuration (顺序交互式流程)
@@ -494,11 +494,10 @@ impl RepoSetupCommand {
                         })
                     })
                     .step(|f| {
-                        // JIRA API token (不设置默认值，显示 ***)
-                        let mut field = f.add_text("jira_api_token", jira_token_prompt);
+                        // JIRA API token（密码输入，不设置默认值，按 Enter 保留现有值）
+                        let mut field = f.add_password("jira_api_token", jira_token_prompt);
                         if has_jira_token {
                             field = field.allow_empty(true);
-                            // 不设置默认值，这样显示 *** 而不是明文
                         } else {
                             field = field.required();
                         }
@@ -620,6 +619,7 @@ impl RepoSetupCommand {
         };
 
         let github_api_token = InputDialog::new(&github_token_prompt)
+            .secret(true)
             .allow_empty(has_github_token)
             .with_validator(move |input: &str| {
                 if input.trim().is_empty() && !has_github_token {
@@ -779,6 +779,7 @@ impl RepoSetupCommand {
                 name: account_name,
                 email: account_email,
                 api_token: api_token.to_string(),
+                login: None,
             };
 
             updated_settings.github.accounts.push(github_account.clone());
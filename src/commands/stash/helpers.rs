@@ -20,29 +20,22 @@ pub fn select_stash_interactively() -> Result<String> {
         color_eyre::eyre::bail!("No stash entries available");
     }
 
-    // 构建选项列表，格式：stash@{n}: <message> (On <branch>)
-    let options: Vec<String> = entries
+    // 构建选项列表，显示格式：stash@{n}: <message> (On <branch>)，值为 stash 引用
+    let items: Vec<(String, String)> = entries
         .iter()
         .map(|entry| {
-            format!(
-                "stash@{{{}}}: {} (On {})",
-                entry.index, entry.message, entry.branch
+            let stash_ref = format!("stash@{{{}}}", entry.index);
+            (
+                format!("{}: {} (On {})", stash_ref, entry.message, entry.branch),
+                stash_ref,
             )
         })
         .collect();
 
-    let selected = SelectDialog::new("Select a stash entry", options)
+    SelectDialog::new_with_items("Select a stash entry", items)
         .with_default(0)
         .prompt()
-        .wrap_err("Failed to select stash entry")?;
-
-    // 从选中的字符串中提取 stash 引用
-    // 格式：stash@{n}: <message> (On <branch>)
-    if let Some(stash_ref) = selected.split(':').next() {
-        Ok(stash_ref.trim().to_string())
-    } else {
-        color_eyre::eyre::bail!("Failed to parse selected stash reference")
-    }
+        .wrap_err("Failed to select stash entry")
 }
 
 /// 格式化 stash 条目用于显示
@@ -3,6 +3,7 @@
 //! List all stash entries in a table format.
 
 use crate::base::table::{TableBuilder, TableStyle};
+use crate::cli::TableFormat;
 use crate::git::GitStash;
 use crate::{log_break, log_info, log_message, log_success};
 use color_eyre::{eyre::WrapErr, Result};
@@ -29,14 +30,17 @@ impl StashListCommand {
     ///
     /// # Arguments
     ///
-    /// * `show_stat` - Whether to show file change statistics
-    pub fn execute(show_stat: bool) -> Result<()> {
-        log_break!();
-        log_message!("Stash List");
+    /// * `show_stat` - Whether to show file change statistics (table format only)
+    /// * `format` - Output format; non-table formats print only the data
+    pub fn execute(show_stat: bool, format: TableFormat) -> Result<()> {
+        if format.is_table() {
+            log_break!();
+            log_message!("Stash List");
+        }
 
         let entries = GitStash::stash_list().wrap_err("Failed to list stash entries")?;
 
-        if entries.is_empty() {
+        if entries.is_empty() && format.is_table() {
             log_info!("No stash entries found");
             return Ok(());
         }
@@ -60,12 +64,17 @@ impl StashListCommand {
             .collect();
 
         // 显示表格
-        let table = TableBuilder::new(rows)
-            .with_title("Stash Entries")
-            .with_style(TableStyle::Modern)
-            .render();
+        let table = format.render(
+            TableBuilder::new(rows)
+                .with_title("Stash Entries")
+                .with_style(TableStyle::Modern)
+                .with_terminal_width(),
+        );
 
         log_message!("{}", table);
+        if !format.is_table() {
+            return Ok(());
+        }
 
         // 如果请求显示统计信息
         if show_stat {
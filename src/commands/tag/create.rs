@@ -0,0 +1,59 @@
+//! Tag create command
+//!
+//! Create an annotated Git tag and optionally push it to the remote.
+
+use crate::base::dialog::InputDialog;
+use crate::git::GitTag;
+use crate::{log_break, log_info, log_success};
+use color_eyre::{eyre::WrapErr, Result};
+
+/// Tag create command
+pub struct TagCreateCommand;
+
+impl TagCreateCommand {
+    /// Execute the tag create command
+    ///
+    /// # 参数
+    ///
+    /// * `tag_name` - tag 名称
+    /// * `message` - tag 消息（未提供时提示输入，默认为 tag 名称）
+    /// * `target` - tag 指向的提交（未提供时为 `HEAD`）
+    /// * `push` - 创建后是否推送到远程
+    pub fn execute(
+        tag_name: String,
+        message: Option<String>,
+        target: Option<String>,
+        push: bool,
+    ) -> Result<()> {
+        if !GitTag::is_valid_name(&tag_name) {
+            color_eyre::eyre::bail!("Invalid tag name: '{}'", tag_name);
+        }
+
+        // 获取 tag 消息
+        let message = match message {
+            Some(message) if !message.trim().is_empty() => message,
+            _ => InputDialog::new("Tag message")
+                .with_default(tag_name.clone())
+                .prompt()
+                .wrap_err("Failed to get tag message")?,
+        };
+
+        GitTag::create(&tag_name, message.trim(), target.as_deref())?;
+        log_success!(
+            "Created tag {} at {}",
+            tag_name,
+            target.as_deref().unwrap_or("HEAD")
+        );
+
+        if push {
+            log_break!();
+            log_info!("Pushing tag {} to origin...", tag_name);
+            GitTag::push(&tag_name)?;
+            log_success!("Pushed tag {} to origin", tag_name);
+        } else {
+            log_info!("Run 'workflow tag push {}' to publish it", tag_name);
+        }
+
+        Ok(())
+    }
+}
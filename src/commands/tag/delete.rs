@@ -6,7 +6,6 @@ use crate::base::dialog::{ConfirmDialog, MultiSelectDialog};
 use crate::git::GitTag;
 use crate::{log_break, log_info, log_message, log_success, log_warning};
 use color_eyre::{eyre::WrapErr, Result};
-use regex::Regex;
 
 /// Tag delete command
 pub struct TagDeleteCommand;
@@ -35,7 +34,10 @@ impl TagDeleteCommand {
         // 确定要删除的 tag 列表
         let tags_to_delete = if let Some(pattern_str) = pattern {
             // 模式匹配
-            Self::filter_tags_by_pattern(&all_tags, &pattern_str)?
+            GitTag::filter_by_pattern(&all_tags, &pattern_str)?
+                .into_iter()
+                .map(|tag| tag.name)
+                .collect()
         } else if let Some(tag) = tag_name {
             // 指定 tag 名称
             vec![tag]
@@ -178,23 +180,6 @@ impl TagDeleteCommand {
         Ok(())
     }
 
-    /// 通过模式匹配过滤 tag
-    fn filter_tags_by_pattern(tags: &[crate::git::TagInfo], pattern: &str) -> Result<Vec<String>> {
-        // 将 shell 通配符模式转换为正则表达式
-        let regex_pattern = pattern.replace(".", "\\.").replace("*", ".*").replace("?", ".");
-
-        let regex = Regex::new(&format!("^{}$", regex_pattern))
-            .wrap_err_with(|| format!("Invalid pattern: {}", pattern))?;
-
-        let matched: Vec<String> = tags
-            .iter()
-            .filter(|tag| regex.is_match(&tag.name))
-            .map(|tag| tag.name.clone())
-            .collect();
-
-        Ok(matched)
-    }
-
     /// 交互式选择 tag
     fn select_tags_interactively(tags: &[crate::git::TagInfo]) -> Result<Vec<String>> {
         // 构建选项列表，格式：<tag_name> (commit: <hash>, local/remote/both)
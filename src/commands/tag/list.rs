@@ -0,0 +1,49 @@
+//! Tag list command
+//!
+//! List local and remote Git tags with their commit, type and message.
+
+use crate::base::table::{TableBuilder, TableStyle};
+use crate::git::{GitTag, TagRow};
+use crate::{log_info, log_message};
+use color_eyre::{eyre::WrapErr, Result};
+
+/// Tag list command
+pub struct TagListCommand;
+
+impl TagListCommand {
+    /// Execute the tag list command
+    ///
+    /// # 参数
+    ///
+    /// * `pattern` - 只显示匹配的 tag（shell 通配符，如 `v1.*`）
+    /// * `local_only` - 只显示本地 tag（不访问远程）
+    pub fn execute(pattern: Option<String>, local_only: bool) -> Result<()> {
+        let mut tags = GitTag::list_tags(!local_only).wrap_err("Failed to list tags")?;
+        if let Some(ref pattern) = pattern {
+            tags = GitTag::filter_by_pattern(&tags, pattern)?;
+        }
+
+        if tags.is_empty() {
+            match pattern {
+                Some(pattern) => {
+                    log_info!("No tags matching '{}'", pattern);
+                }
+                None => {
+                    log_info!("No tags found");
+                }
+            }
+            return Ok(());
+        }
+
+        let rows: Vec<TagRow> = tags.iter().map(TagRow::from).collect();
+        log_message!(
+            "{}",
+            TableBuilder::new(rows)
+                .with_title(format!("Tags ({})", tags.len()))
+                .with_style(TableStyle::Modern)
+                .render()
+        );
+
+        Ok(())
+    }
+}
@@ -2,6 +2,14 @@
 //!
 //! Commands for managing Git tags.
 
+pub mod create;
 pub mod delete;
+pub mod list;
+pub mod next;
+pub mod push;
 
+pub use create::TagCreateCommand;
 pub use delete::TagDeleteCommand;
+pub use list::TagListCommand;
+pub use next::TagNextCommand;
+pub use push::TagPushCommand;
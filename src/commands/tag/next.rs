@@ -0,0 +1,88 @@
+//! Tag next command
+//!
+//! Suggest the next semantic version tag based on existing tags, and optionally create it.
+
+use crate::base::settings::Settings;
+use crate::cli::TagBump;
+use crate::commands::tag::TagCreateCommand;
+use crate::git::{GitCommit, GitTag, SemVer, VersionBump};
+use crate::{log_break, log_info, log_success, log_warning};
+use color_eyre::{eyre::WrapErr, Result};
+
+/// Tag next command
+pub struct TagNextCommand;
+
+impl TagNextCommand {
+    /// Execute the tag next command
+    ///
+    /// # 参数
+    ///
+    /// * `bump` - 版本递增方式
+    /// * `pre` - 预发布通道（如 `rc`，未提供时生成正式版本）
+    /// * `prefix` - tag 前缀（未提供时使用 `[tag] prefix` 配置）
+    /// * `create` - 是否在 HEAD 创建建议的 tag
+    /// * `message` - tag 消息（仅 `create` 时使用，未提供时提示输入）
+    /// * `push` - 创建后是否推送到远程
+    pub fn execute(
+        bump: TagBump,
+        pre: Option<String>,
+        prefix: Option<String>,
+        create: bool,
+        message: Option<String>,
+        push: bool,
+    ) -> Result<()> {
+        if let Some(ref channel) = pre {
+            SemVer::validate_channel(channel)?;
+        }
+        let prefix = prefix.unwrap_or_else(|| Settings::get().tag.prefix().to_string());
+
+        // 工作区有未提交的更改时，tag 不包含这些更改
+        if !GitCommit::status()?.trim().is_empty() {
+            log_warning!(
+                "Working tree has uncommitted changes, the tag will point to HEAD and not include them"
+            );
+        }
+
+        let versions: Vec<SemVer> = GitTag::list_local_tags()
+            .wrap_err("Failed to list local tags")?
+            .iter()
+            .filter_map(|tag| SemVer::parse_tag(tag, &prefix))
+            .collect();
+
+        match SemVer::latest_release(&versions) {
+            Some(latest) => {
+                log_info!("Latest release: {}", latest.to_tag(&prefix));
+            }
+            None => {
+                log_info!(
+                    "No release tags with prefix '{}' found, starting from {}",
+                    prefix,
+                    SemVer::new(0, 0, 0).to_tag(&prefix)
+                );
+            }
+        }
+        if let Some(latest) = SemVer::latest(&versions).filter(|latest| latest.is_pre_release()) {
+            log_info!("Latest pre-release: {}", latest.to_tag(&prefix));
+        }
+
+        let bump = match bump {
+            TagBump::Major => VersionBump::Major,
+            TagBump::Minor => VersionBump::Minor,
+            TagBump::Patch => VersionBump::Patch,
+        };
+        let next = SemVer::next(&versions, bump, pre.as_deref()).to_tag(&prefix);
+        log_success!("Next version: {}", next);
+
+        if create {
+            log_break!();
+            TagCreateCommand::execute(next, message, None, push)?;
+        } else {
+            log_info!(
+                "Run 'workflow tag create {}' or add --create to create it",
+                next
+            );
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+//! Tag push command
+//!
+//! Push local Git tags to the remote.
+
+use crate::base::dialog::MultiSelectDialog;
+use crate::git::GitTag;
+use crate::{log_info, log_success, log_warning};
+use color_eyre::{eyre::WrapErr, Result};
+
+/// Tag push command
+pub struct TagPushCommand;
+
+impl TagPushCommand {
+    /// Execute the tag push command
+    ///
+    /// 指定 tag 名称时推送该 tag；使用 `all` 时推送所有本地 tag；
+    /// 否则从只存在于本地的 tag 中选择（可用 `pattern` 筛选）。
+    pub fn execute(tag_name: Option<String>, all: bool, pattern: Option<String>) -> Result<()> {
+        if all {
+            log_info!("Pushing all tags to origin...");
+            GitTag::push_all()?;
+            log_success!("Pushed all tags to origin");
+            return Ok(());
+        }
+
+        let tag_names = match tag_name {
+            Some(tag_name) => vec![tag_name],
+            None => {
+                let mut tags: Vec<_> = GitTag::list_all_tags()
+                    .wrap_err("Failed to list tags")?
+                    .into_iter()
+                    .filter(|tag| tag.exists_local && !tag.exists_remote)
+                    .collect();
+                if let Some(ref pattern) = pattern {
+                    tags = GitTag::filter_by_pattern(&tags, pattern)?;
+                }
+                if tags.is_empty() {
+                    log_info!("No local-only tags to push");
+                    return Ok(());
+                }
+
+                let names: Vec<String> = tags.into_iter().map(|tag| tag.name).collect();
+                if pattern.is_some() {
+                    names
+                } else {
+                    MultiSelectDialog::new("Select tags to push", names)
+                        .prompt()
+                        .wrap_err("Failed to select tags")?
+                }
+            }
+        };
+
+        if tag_names.is_empty() {
+            log_info!("No tags selected");
+            return Ok(());
+        }
+
+        let mut failed = 0;
+        for tag_name in &tag_names {
+            match GitTag::push(tag_name) {
+                Ok(_) => {
+                    log_success!("Pushed tag: {}", tag_name);
+                }
+                Err(e) => {
+                    log_warning!("Failed to push tag {}: {}", tag_name, e);
+                    failed += 1;
+                }
+            }
+        }
+
+        if failed > 0 {
+            color_eyre::eyre::bail!("Failed to push {} of {} tag(s)", failed, tag_names.len());
+        }
+        Ok(())
+    }
+}
@@ -11,6 +11,8 @@
 pub mod base;
 #[path = "lib/branch/mod.rs"]
 pub mod branch;
+#[path = "lib/cache/mod.rs"]
+pub mod cache;
 #[path = "lib/cli/mod.rs"]
 pub mod cli;
 #[path = "lib/commit/mod.rs"]
@@ -42,14 +44,14 @@ pub use base::format::DisplayFormatter;
 pub use base::settings::{LLMSettings, Paths, Settings};
 pub use base::util::{mask_sensitive_value, Browser, Checksum, Clipboard, Unzip};
 pub use base::{
-    Authorization, Detect, HttpClient, HttpResponse, HttpRetry, HttpRetryConfig, LogLevel, Logger,
-    Reload, ShellConfigManager, Tracer,
+    Authorization, Detect, HttpClient, HttpResponse, HttpRetry, HttpRetryConfig, LogFormat,
+    LogLevel, Logger, Reload, ShellConfigManager, Tracer,
 };
 // 从 base::prompt 重新导出 Prompt 相关 API
 pub use base::prompt::{
-    find_language, generate_summarize_pr_system_prompt, get_language_instruction,
-    get_supported_language_codes, get_supported_language_display_names, SupportedLanguage,
-    GENERATE_BRANCH_SYSTEM_PROMPT, SUPPORTED_LANGUAGES,
+    find_language, generate_branch_system_prompt, generate_summarize_pr_system_prompt,
+    get_language_instruction, get_supported_language_codes, get_supported_language_display_names,
+    SupportedLanguage, GENERATE_BRANCH_SYSTEM_PROMPT, SUPPORTED_LANGUAGES,
 };
 // 从 base::llm 重新导出语言增强 API
 pub use base::llm::get_language_requirement;
@@ -78,8 +80,8 @@ pub use pr::{
     TYPES_OF_CHANGES,
 };
 pub use proxy::{
-    ProxyConfigGenerator, ProxyDisableResult, ProxyEnableResult, ProxyInfo, ProxyManager,
-    ProxyType, SystemProxyReader,
+    ProxyCheckOutput, ProxyConfigGenerator, ProxyDisableResult, ProxyEnableResult, ProxyInfo,
+    ProxyManager, ProxyType, SystemProxyReader,
 };
 pub use rollback::{BackupInfo, RollbackManager};
 pub use template::{
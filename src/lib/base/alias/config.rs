@@ -1,6 +1,8 @@
 //! 常用命令配置模块
 //!
-//! 管理用于交互式添加别名时的常用命令列表。
+//! 管理用于交互式添加别名时的常用命令列表，以及 `workflow alias install` 安装的 shell 别名。
+
+use std::collections::BTreeMap;
 
 use color_eyre::Result;
 use serde::{Deserialize, Serialize};
@@ -16,6 +18,9 @@ pub struct CommandsConfig {
     /// 常用命令列表（用于交互式添加别名时的命令选择）
     #[serde(default)]
     pub common_commands: Vec<String>,
+    /// `workflow alias install` 安装的 shell 别名（别名名称 -> 命令）
+    #[serde(default)]
+    pub shell_aliases: BTreeMap<String, String>,
 }
 
 /// 常用命令配置管理器类型别名
@@ -81,4 +86,36 @@ impl CommandsConfig {
 
         Ok(DEFAULT_COMMON_COMMANDS.iter().map(|s| s.to_string()).collect())
     }
+
+    /// 获取 `workflow alias install` 安装的 shell 别名（混合方式）
+    ///
+    /// 优先级：
+    /// 1. `commands.toml` 配置文件中的 `shell_aliases`
+    /// 2. 硬编码的默认 shell 别名
+    ///
+    /// # 返回
+    ///
+    /// 返回别名映射表（别名名称 -> 命令，按名称排序）。
+    pub fn get_shell_aliases() -> BTreeMap<String, String> {
+        // 1. 优先从 commands.toml 配置文件读取
+        if let Ok(config) = Self::load() {
+            if !config.shell_aliases.is_empty() {
+                return config.shell_aliases;
+            }
+        }
+
+        // 2. 使用硬编码的默认 shell 别名
+        const DEFAULT_SHELL_ALIASES: &[(&str, &str)] = &[
+            ("wf", "workflow"),
+            ("wpr", "workflow pr"),
+            ("wprc", "workflow pr create"),
+            ("wbr", "workflow branch"),
+            ("wji", "workflow jira info"),
+        ];
+
+        DEFAULT_SHELL_ALIASES
+            .iter()
+            .map(|(name, command)| (name.to_string(), command.to_string()))
+            .collect()
+    }
 }
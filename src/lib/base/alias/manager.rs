@@ -2,12 +2,12 @@
 //!
 //! 提供别名的加载、展开和管理功能。
 
-use crate::base::settings::paths::Paths;
 use crate::base::settings::Settings;
-use crate::base::util::file::FileWriter;
-use color_eyre::{eyre::WrapErr, Result};
+use crate::cli::Cli;
+use clap::CommandFactory;
+use color_eyre::Result;
 use std::collections::{HashMap, HashSet};
-use std::fs;
+use std::path::PathBuf;
 
 /// 别名管理器
 ///
@@ -160,22 +160,10 @@ impl AliasManager {
     ///
     /// 如果配置文件读写失败，返回相应的错误信息。
     pub fn add(name: &str, command: &str) -> Result<()> {
-        let config_path = Paths::workflow_config()?;
-        let mut settings = Settings::get().clone();
-
-        // 添加别名
-        settings.aliases.insert(name.to_string(), command.to_string());
-
-        // 保存配置
-        FileWriter::new(&config_path).write_toml(&settings)?;
-
-        // 设置文件权限（仅 Unix）
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            fs::set_permissions(&config_path, fs::Permissions::from_mode(0o600))
-                .wrap_err("Failed to set config file permissions")?;
-        }
+        // 保存到当前激活 Profile 的配置
+        Settings::update_active_profile(|settings| {
+            settings.aliases.insert(name.to_string(), command.to_string());
+        })?;
 
         Ok(())
     }
@@ -194,29 +182,17 @@ impl AliasManager {
     ///
     /// # 错误
     ///
-    /// 如果配置文件读写失败，返回相应的错误信息。
+    /// 如果配置文件读写失败，或在非 `default` Profile 中删除从基础配置继承的别名，返回相应的错误信息。
     pub fn remove(name: &str) -> Result<bool> {
-        let config_path = Paths::workflow_config()?;
-        let mut settings = Settings::get().clone();
-
-        // 检查别名是否存在
-        if !settings.aliases.contains_key(name) {
+        // 检查别名是否存在（读取当前激活 Profile 的配置文件，与删除时使用的配置一致）
+        if !Settings::load().aliases.contains_key(name) {
             return Ok(false);
         }
 
-        // 删除别名
-        settings.aliases.remove(name);
-
-        // 保存配置
-        FileWriter::new(&config_path).write_toml(&settings)?;
-
-        // 设置文件权限（仅 Unix）
-        #[cfg(unix)]
-        {
-            use std::os::unix::fs::PermissionsExt;
-            fs::set_permissions(&config_path, fs::Permissions::from_mode(0o600))
-                .wrap_err("Failed to set config file permissions")?;
-        }
+        // 从当前激活 Profile 的配置中删除别名
+        Settings::update_active_profile(|settings| {
+            settings.aliases.remove(name);
+        })?;
 
         Ok(true)
     }
@@ -254,6 +230,108 @@ impl AliasManager {
         Ok(aliases.contains_key(name))
     }
 
+    /// 获取内置的顶级命令名称
+    ///
+    /// 包括所有顶级子命令、它们的别名以及 `help`。
+    pub fn builtin_commands() -> Vec<String> {
+        let cli = Cli::command();
+        let mut names: Vec<String> = cli
+            .get_subcommands()
+            .flat_map(|sub| std::iter::once(sub.get_name()).chain(sub.get_all_aliases()))
+            .map(str::to_string)
+            .collect();
+        names.push("help".to_string());
+        names
+    }
+
+    /// 检查别名名称格式
+    ///
+    /// 别名名称只能包含字母、数字、`_`、`-` 和 `.`，且不能以 `-` 开头（避免与命令行选项混淆）。
+    ///
+    /// # 错误
+    ///
+    /// 如果名称为空或包含不支持的字符，返回相应的错误信息。
+    pub fn validate_name(name: &str) -> Result<()> {
+        if name.is_empty() {
+            return Err(color_eyre::eyre::eyre!("Alias name cannot be empty"));
+        }
+        if name.starts_with('-') {
+            return Err(color_eyre::eyre::eyre!(
+                "Alias name cannot start with '-': {}",
+                name
+            ));
+        }
+        if let Some(c) = name
+            .chars()
+            .find(|c| !(c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.')))
+        {
+            return Err(color_eyre::eyre::eyre!(
+                "Alias name '{}' contains unsupported character '{}' \
+                 (only letters, digits, '_', '-' and '.' are allowed)",
+                name,
+                c
+            ));
+        }
+        Ok(())
+    }
+
+    /// 检查 workflow 别名名称
+    ///
+    /// 别名在解析命令行之前展开，与内置命令同名的别名会覆盖内置命令，因此不允许。
+    ///
+    /// # 错误
+    ///
+    /// 如果名称格式不正确或与内置命令冲突，返回相应的错误信息。
+    pub fn validate_alias_name(name: &str) -> Result<()> {
+        Self::validate_name(name)?;
+        if Self::builtin_commands().iter().any(|command| command == name) {
+            return Err(color_eyre::eyre::eyre!(
+                "Alias name '{}' conflicts with the built-in command 'workflow {}'",
+                name,
+                name
+            ));
+        }
+        Ok(())
+    }
+
+    /// 检查 shell 别名名称
+    ///
+    /// shell 别名会覆盖 PATH 中的同名命令（如 `git`、`ls`），因此不允许与已有命令同名。
+    ///
+    /// # 错误
+    ///
+    /// 如果名称格式不正确或与 PATH 中的命令冲突，返回相应的错误信息。
+    pub fn validate_shell_alias_name(name: &str) -> Result<()> {
+        Self::validate_name(name)?;
+        if let Some(path) = Self::find_in_path(name) {
+            return Err(color_eyre::eyre::eyre!(
+                "Alias name '{}' conflicts with an existing command: {}",
+                name,
+                path.display()
+            ));
+        }
+        Ok(())
+    }
+
+    /// 在 PATH 中查找命令
+    ///
+    /// Windows 上同时查找 `.exe`、`.cmd`、`.bat` 扩展名。
+    ///
+    /// # 返回
+    ///
+    /// 找到时返回命令的完整路径，否则返回 `None`。
+    pub fn find_in_path(name: &str) -> Option<PathBuf> {
+        let extensions: &[&str] = if cfg!(windows) {
+            &["", ".exe", ".cmd", ".bat"]
+        } else {
+            &[""]
+        };
+        let path = std::env::var_os("PATH")?;
+        std::env::split_paths(&path)
+            .flat_map(|dir| extensions.iter().map(move |ext| dir.join(format!("{}{}", name, ext))))
+            .find(|candidate| candidate.is_file())
+    }
+
     /// 检查循环别名
     ///
     /// 检查添加新别名 `name` 指向 `target` 是否会导致循环引用。
@@ -67,13 +67,16 @@ impl ConcurrentExecutor {
 
     /// 执行多个任务（并行）
     ///
+    /// 最多启动 `max_concurrent` 个工作线程，每个线程完成一个任务后从队列中取下一个任务，
+    /// 因此同时执行的任务数不会超过 `max_concurrent`。单个任务失败不影响其他任务。
+    ///
     /// # 参数
     ///
     /// * `tasks` - 任务列表，每个任务是一个元组 `(标识符, 任务函数)`
     ///
     /// # 返回
     ///
-    /// 返回任务结果列表，每个结果是一个元组 `(标识符, 任务结果)`
+    /// 返回任务结果列表（按完成顺序），每个结果是一个元组 `(标识符, 任务结果)`
     ///
     /// # 错误
     ///
@@ -83,81 +86,13 @@ impl ConcurrentExecutor {
         T: Send + 'static,
         E: Send + 'static,
     {
-        if tasks.is_empty() {
-            return Ok(Vec::new());
-        }
-
-        // 如果只有一个任务，直接执行（避免线程开销）
-        if tasks.len() == 1 {
-            let (name, task) = tasks
-                .into_iter()
-                .next()
-                .ok_or_else(|| eyre!("Expected exactly one task, but got none"))?;
-            let result = match task() {
-                Ok(value) => TaskResult::Success(value),
-                Err(err) => TaskResult::Failure(err),
-            };
-            return Ok(vec![(name, result)]);
-        }
-
-        let max_concurrent = self.max_concurrent.min(tasks.len());
-
-        // 结果通道
-        let (tx, rx) = mpsc::channel();
-
-        // 分批处理：将任务分成多个批次，每批最多 max_concurrent 个并行执行
-        // 注意：我们不能直接 clone Box<dyn Fn()>，所以需要将任务移动到线程中
-        let mut handles = Vec::new();
-        let mut tasks_iter = tasks.into_iter();
-
-        loop {
-            let mut chunk = Vec::new();
-            for _ in 0..max_concurrent {
-                if let Some(task) = tasks_iter.next() {
-                    chunk.push(task);
-                } else {
-                    break;
-                }
-            }
-
-            if chunk.is_empty() {
-                break;
-            }
-
-            let tx = tx.clone();
-
-            let handle = thread::spawn(move || {
-                for (name, task) in chunk {
-                    let result = match task() {
-                        Ok(value) => TaskResult::Success(value),
-                        Err(err) => TaskResult::Failure(err),
-                    };
-                    tx.send((name, result)).ok();
-                }
-            });
-
-            handles.push(handle);
-        }
-
-        // 关闭发送端
-        drop(tx);
-
-        // 收集结果
-        let mut results = Vec::new();
-        for result in rx {
-            results.push(result);
-        }
-
-        // 等待所有线程完成
-        for handle in handles {
-            handle.join().map_err(|e| eyre!("Thread join error: {:?}", e))?;
-        }
-
-        Ok(results)
+        self.run(tasks, |_, _| {})
     }
 
     /// 执行多个任务（并行），带进度回调
     ///
+    /// 回调在工作线程中、任务完成时立即调用。
+    ///
     /// # 参数
     ///
     /// * `tasks` - 任务列表
@@ -175,40 +110,45 @@ impl ConcurrentExecutor {
         T: Send + 'static,
         E: Send + 'static + ToString,
         F: Fn(&str, bool, Option<&str>) + Send + Sync + 'static,
+    {
+        self.run(tasks, move |name, result| {
+            let Some(ref callback) = on_progress else {
+                return;
+            };
+            if let Ok(cb_guard) = callback.lock() {
+                if let Some(ref cb_fn) = *cb_guard {
+                    match result {
+                        TaskResult::Success(_) => cb_fn(name, true, None),
+                        TaskResult::Failure(err) => cb_fn(name, false, Some(&err.to_string())),
+                    }
+                }
+            }
+        })
+    }
+
+    /// 使用工作线程池执行任务，每个任务完成时调用 `on_finished`
+    fn run<T, E, H>(
+        &self,
+        tasks: TaskList<T, E>,
+        on_finished: H,
+    ) -> Result<Vec<(String, TaskResult<T, E>)>>
+    where
+        T: Send + 'static,
+        E: Send + 'static,
+        H: Fn(&str, &TaskResult<T, E>) + Send + Sync + 'static,
     {
         if tasks.is_empty() {
             return Ok(Vec::new());
         }
 
-        // 如果只有一个任务，直接执行
+        // 如果只有一个任务，直接执行（避免线程开销）
         if tasks.len() == 1 {
             let (name, task) = tasks
                 .into_iter()
                 .next()
                 .ok_or_else(|| eyre!("Expected exactly one task, but got none"))?;
-            let result = match task() {
-                Ok(value) => {
-                    if let Some(ref callback) = on_progress {
-                        if let Ok(cb_guard) = callback.lock() {
-                            if let Some(ref cb_fn) = *cb_guard {
-                                cb_fn(&name, true, None);
-                            }
-                        }
-                    }
-                    TaskResult::Success(value)
-                }
-                Err(err) => {
-                    let err_msg = err.to_string();
-                    if let Some(ref callback) = on_progress {
-                        if let Ok(cb_guard) = callback.lock() {
-                            if let Some(ref cb_fn) = *cb_guard {
-                                cb_fn(&name, false, Some(&err_msg));
-                            }
-                        }
-                    }
-                    TaskResult::Failure(err)
-                }
-            };
+            let result = Self::run_task(&task);
+            on_finished(&name, &result);
             return Ok(vec![(name, result)]);
         }
 
@@ -217,54 +157,27 @@ impl ConcurrentExecutor {
         // 结果通道
         let (tx, rx) = mpsc::channel();
 
-        // 分批处理
-        let mut handles = Vec::new();
-        let mut tasks_iter = tasks.into_iter();
-
-        loop {
-            let mut chunk = Vec::new();
-            for _ in 0..max_concurrent {
-                if let Some(task) = tasks_iter.next() {
-                    chunk.push(task);
-                } else {
-                    break;
-                }
-            }
-
-            if chunk.is_empty() {
-                break;
-            }
+        // 任务队列：工作线程逐个取出任务执行，直到队列为空
+        let queue = Arc::new(Mutex::new(tasks.into_iter()));
+        let on_finished = Arc::new(on_finished);
+        let mut handles = Vec::with_capacity(max_concurrent);
 
+        for _ in 0..max_concurrent {
+            let queue = Arc::clone(&queue);
+            let on_finished = Arc::clone(&on_finished);
             let tx = tx.clone();
-            let callback = on_progress.clone();
-
-            let handle = thread::spawn(move || {
-                for (name, task) in chunk {
-                    let result = match task() {
-                        Ok(value) => {
-                            if let Some(ref cb) = callback {
-                                if let Ok(cb_guard) = cb.lock() {
-                                    if let Some(ref cb_fn) = *cb_guard {
-                                        cb_fn(&name, true, None);
-                                    }
-                                }
-                            }
-                            TaskResult::Success(value)
-                        }
-                        Err(err) => {
-                            let err_msg = err.to_string();
-                            if let Some(ref cb) = callback {
-                                if let Ok(cb_guard) = cb.lock() {
-                                    if let Some(ref cb_fn) = *cb_guard {
-                                        cb_fn(&name, false, Some(&err_msg));
-                                    }
-                                }
-                            }
-                            TaskResult::Failure(err)
-                        }
-                    };
-                    tx.send((name, result)).ok();
-                }
+
+            let handle = thread::spawn(move || loop {
+                let next = match queue.lock() {
+                    Ok(mut queue) => queue.next(),
+                    Err(_) => None,
+                };
+                let Some((name, task)) = next else {
+                    break;
+                };
+                let result = Self::run_task(&task);
+                on_finished(&name, &result);
+                tx.send((name, result)).ok();
             });
 
             handles.push(handle);
@@ -286,6 +199,14 @@ impl ConcurrentExecutor {
 
         Ok(results)
     }
+
+    /// 执行单个任务并转换为 `TaskResult`
+    fn run_task<T, E>(task: &(dyn Fn() -> Result<T, E> + Send + Sync)) -> TaskResult<T, E> {
+        match task() {
+            Ok(value) => TaskResult::Success(value),
+            Err(err) => TaskResult::Failure(err),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -369,4 +290,75 @@ mod tests {
             TaskResult::Failure(_) => {}
         }
     }
+
+    #[test]
+    fn test_execute_respects_max_concurrent() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let tasks = (0..10)
+            .map(|i| {
+                let running = Arc::clone(&running);
+                let peak = Arc::clone(&peak);
+                (
+                    format!("task{}", i),
+                    Box::new(move || -> Result<usize, String> {
+                        let current = running.fetch_add(1, Ordering::SeqCst) + 1;
+                        peak.fetch_max(current, Ordering::SeqCst);
+                        sleep(Duration::from_millis(10));
+                        running.fetch_sub(1, Ordering::SeqCst);
+                        Ok(i)
+                    }) as Box<dyn Fn() -> Result<usize, String> + Send + Sync>,
+                )
+            })
+            .collect();
+
+        let results = ConcurrentExecutor::new(3).execute(tasks).unwrap();
+
+        assert_eq!(results.len(), 10);
+        assert!(peak.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[test]
+    fn test_execute_with_progress_reports_every_task() {
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let reported_clone = Arc::clone(&reported);
+        let callback = move |name: &str, success: bool, _: Option<&str>| {
+            reported_clone.lock().unwrap().push((name.to_string(), success));
+        };
+        let tasks = vec![
+            (
+                "ok".to_string(),
+                Box::new(|| -> Result<(), String> { Ok(()) })
+                    as Box<dyn Fn() -> Result<(), String> + Send + Sync>,
+            ),
+            (
+                "failed".to_string(),
+                Box::new(|| -> Result<(), String> { Err("error".to_string()) })
+                    as Box<dyn Fn() -> Result<(), String> + Send + Sync>,
+            ),
+            (
+                "ok2".to_string(),
+                Box::new(|| -> Result<(), String> { Ok(()) })
+                    as Box<dyn Fn() -> Result<(), String> + Send + Sync>,
+            ),
+        ];
+
+        let results = ConcurrentExecutor::new(2)
+            .execute_with_progress(tasks, Some(Arc::new(Mutex::new(Some(callback)))))
+            .unwrap();
+
+        assert_eq!(results.len(), 3);
+        let mut reported = reported.lock().unwrap().clone();
+        reported.sort();
+        assert_eq!(
+            reported,
+            vec![
+                ("failed".to_string(), false),
+                ("ok".to_string(), true),
+                ("ok2".to_string(), true),
+            ]
+        );
+    }
 }
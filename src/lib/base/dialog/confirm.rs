@@ -129,10 +129,29 @@ impl ConfirmDialog {
         let confirmed = confirm.interact().map_err(|e| eyre!("Confirmation error: {}", e))?;
 
         // 如果用户取消且设置了取消消息，返回错误
-        if !confirmed && self.cancel_message.is_some() {
-            color_eyre::eyre::bail!("{}", self.cancel_message.unwrap());
+        if let (false, Some(cancel_message)) = (confirmed, self.cancel_message) {
+            color_eyre::eyre::bail!("{}", cancel_message);
         }
 
         Ok(confirmed)
     }
+
+    /// 显示对话框并获取用户确认，支持按 Esc 取消
+    ///
+    /// 与 `prompt()` 不同，按 `Esc` 或 `q` 时返回 `Ok(None)`，不视为否定回答，
+    /// 也不使用 `cancel_message`。
+    ///
+    /// # 返回
+    ///
+    /// - 用户确认或否定：返回 `Ok(Some(bool))`
+    /// - 用户按 `Esc` 或 `q`：返回 `Ok(None)`
+    pub fn prompt_opt(self) -> Result<Option<bool>> {
+        let mut confirm = Confirm::new().with_prompt(&self.prompt).wait_for_newline(false);
+
+        if let Some(default) = self.default {
+            confirm = confirm.default(default);
+        }
+
+        confirm.interact_opt().map_err(|e| eyre!("Confirmation error: {}", e))
+    }
 }
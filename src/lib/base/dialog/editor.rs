@@ -0,0 +1,99 @@
+use color_eyre::{eyre::eyre, Result};
+use dialoguer::Editor;
+
+use crate::base::dialog::types::DialogCancelled;
+
+/// 编辑器对话框
+///
+/// 打开外部编辑器（`$VISUAL` 或 `$EDITOR`，都未设置时使用系统默认编辑器）编辑多行文本，
+/// 用于输入评论等较长的内容。
+///
+/// ## 特性
+///
+/// - **初始内容**：编辑器打开时显示的文本（如模板或草稿）
+/// - **文件扩展名**：临时文件的扩展名，编辑器可据此启用语法高亮
+/// - **未保存即取消**：关闭编辑器前没有保存时视为取消，返回 `DialogCancelled` 错误
+///
+/// # 示例
+///
+/// ```rust,no_run
+/// use workflow::base::dialog::EditorDialog;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let comment = EditorDialog::new()
+///     .with_extension(".txt")
+///     .prompt()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct EditorDialog {
+    initial: String,
+    extension: String,
+}
+
+impl Default for EditorDialog {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EditorDialog {
+    /// 创建新的编辑器对话框
+    ///
+    /// # 返回
+    ///
+    /// 返回 `EditorDialog` 实例（初始内容为空，扩展名为 `.txt`）
+    pub fn new() -> Self {
+        Self {
+            initial: String::new(),
+            extension: ".txt".to_string(),
+        }
+    }
+
+    /// 设置初始内容
+    ///
+    /// # 参数
+    ///
+    /// * `initial` - 编辑器打开时显示的文本
+    ///
+    /// # 返回
+    ///
+    /// 返回 `Self` 以支持链式调用
+    pub fn with_initial(mut self, initial: impl Into<String>) -> Self {
+        self.initial = initial.into();
+        self
+    }
+
+    /// 设置临时文件的扩展名
+    ///
+    /// # 参数
+    ///
+    /// * `extension` - 扩展名（包含 `.`，如 `.md`）
+    ///
+    /// # 返回
+    ///
+    /// 返回 `Self` 以支持链式调用
+    pub fn with_extension(mut self, extension: impl Into<String>) -> Self {
+        self.extension = extension.into();
+        self
+    }
+
+    /// 打开编辑器并获取编辑后的内容
+    ///
+    /// # 返回
+    ///
+    /// 返回编辑后的文本（去除末尾的换行）
+    ///
+    /// # 错误
+    ///
+    /// - 编辑器无法启动或异常退出时返回错误
+    /// - 关闭编辑器前没有保存时返回 `DialogCancelled` 错误
+    pub fn prompt(self) -> Result<String> {
+        Editor::new()
+            .extension(&self.extension)
+            .trim_newlines(true)
+            .edit(&self.initial)
+            .map_err(|e| eyre!("Editor error: {}", e))?
+            .ok_or_else(|| eyre!(DialogCancelled))
+    }
+}
@@ -2,15 +2,27 @@
 
 use std::collections::HashMap;
 
-use color_eyre::{eyre::eyre, Result};
-use dialoguer::Password;
+use color_eyre::Result;
 
 use crate::base::dialog::form::condition_evaluator::ConditionEvaluator;
 use crate::base::dialog::form::group_builder::GroupBuilder;
-use crate::base::dialog::form::types::{FormGroup, FormStep, GroupConfig, StepType};
+use crate::base::dialog::form::types::{
+    FormAnswer, FormGroup, FormPrompt, FormStep, GroupConfig, StepType,
+};
 use crate::base::dialog::form::{FieldDefaultValue, FormField, FormFieldType, FormResult};
+use crate::base::dialog::types::DialogCancelled;
 use crate::base::dialog::{ConfirmDialog, InputDialog, SelectDialog};
-use crate::{log_break, log_debug, log_message};
+use crate::base::indicator::Spinner;
+use crate::{log_break, log_debug, log_error, log_message, log_warning};
+
+/// 表单中的询问位置
+#[derive(Debug, Clone, Copy)]
+enum FormPosition {
+    /// 组的开始（可选组在此询问是否配置）
+    Group(usize),
+    /// 字段（组序号、步骤序号、字段序号）
+    Field(usize, usize, usize),
+}
 
 /// 表单构建器
 ///
@@ -44,12 +56,25 @@ use crate::{log_break, log_debug, log_message};
 pub struct FormBuilder {
     /// 表单组列表
     pub groups: Vec<FormGroup>,
+    /// 是否跳过字段的异步验证（如离线时跳过网络验证）
+    pub skip_async_validation: bool,
 }
 
 impl FormBuilder {
     /// 创建新的统一表单构建器
     pub fn new() -> Self {
-        Self { groups: Vec::new() }
+        Self {
+            groups: Vec::new(),
+            skip_async_validation: false,
+        }
+    }
+
+    /// 设置是否跳过字段的异步验证
+    ///
+    /// 跳过时，使用 `validate_async` 设置的验证器不会执行（例如离线运行 setup 时）。
+    pub fn skip_async_validation(mut self, skip: bool) -> Self {
+        self.skip_async_validation = skip;
+        self
     }
 
     /// 添加表单组
@@ -163,6 +188,8 @@ impl FormBuilder {
 
     /// 执行表单并收集用户输入
     ///
+    /// 按 Esc 返回上一个字段，已输入的值作为默认值保留。
+    ///
     /// # 返回
     ///
     /// 返回 `FormResult`，包含所有字段的值
@@ -171,55 +198,138 @@ impl FormBuilder {
     ///
     /// 如果用户取消或验证失败，返回错误
     pub fn run(self) -> Result<FormResult> {
+        if self.groups.iter().flat_map(|g| &g.steps).map(|s| s.fields.len()).sum::<usize>() > 1 {
+            log_debug!("Press Esc to go back to the previous field");
+        }
+        self.navigate(|prompt, field_values| match prompt {
+            FormPrompt::Group(group) => self.ask_group(group),
+            FormPrompt::Field(field) => self.ask_field(field, field_values),
+        })
+    }
+
+    /// 使用指定的回答函数执行表单（不显示对话框）
+    ///
+    /// 回答函数按顺序收到每次询问和已回答的字段，返回 `FormAnswer::Back` 时回到上一个询问。
+    /// 字段的验证器和异步验证器不会执行，适用于测试表单的导航和条件逻辑。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use workflow::base::dialog::{FormAnswer, FormBuilder, GroupConfig};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// // 输入 name 后返回修改，再输入 email
+    /// let mut answers = vec![
+    ///     FormAnswer::Value("alice".into()),
+    ///     FormAnswer::Back,
+    ///     FormAnswer::Value("bob".into()),
+    ///     FormAnswer::Value("bob@example.com".into()),
+    /// ]
+    /// .into_iter();
+    /// let result = FormBuilder::new()
+    ///     .add_group("user", |g| {
+    ///         g.step(|f| f.add_text("name", "Name").add_text("email", "Email"))
+    ///     }, GroupConfig::required())
+    ///     .run_with(|_prompt, _values| Ok(answers.next().unwrap()))?;
+    /// assert_eq!(result.get("name").map(String::as_str), Some("bob"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn run_with<F>(self, answer: F) -> Result<FormResult>
+    where
+        F: FnMut(FormPrompt<'_>, &HashMap<String, String>) -> Result<FormAnswer>,
+    {
+        self.navigate(answer)
+    }
+
+    /// 表单中所有询问位置（按顺序）
+    fn positions(&self) -> Vec<FormPosition> {
+        let mut positions = Vec::new();
+        for (group_idx, group) in self.groups.iter().enumerate() {
+            positions.push(FormPosition::Group(group_idx));
+            for (step_idx, step) in group.steps.iter().enumerate() {
+                for field_idx in 0..step.fields.len() {
+                    positions.push(FormPosition::Field(group_idx, step_idx, field_idx));
+                }
+            }
+        }
+        positions
+    }
+
+    /// 按顺序执行所有询问，支持返回上一个询问
+    ///
+    /// 每次到达一个字段时重新判断步骤和字段的条件，因此返回修改之前的回答后，
+    /// 之后的条件步骤会根据新的回答执行或跳过；跳过的字段会移除之前输入的值。
+    fn navigate<F>(&self, mut answer: F) -> Result<FormResult>
+    where
+        F: FnMut(FormPrompt<'_>, &HashMap<String, String>) -> Result<FormAnswer>,
+    {
         // 先验证配置
         self.validate()?;
 
+        let positions = self.positions();
         let mut field_values: HashMap<String, String> = HashMap::new();
-
-        // 按顺序执行每个组
-        for group in &self.groups {
-            // 如果是可选组，先询问是否配置
-            if group.optional {
-                let should_configure = if let Some(title) = &group.title {
-                    log_break!();
-                    log_message!("{}", title);
-                    log_break!('-', 40);
-                    if let Some(description) = &group.description {
-                        log_debug!("{}", description);
-                        log_break!();
+        // 记录字段值由哪个位置输入，多个条件步骤使用同名字段时，跳过其中一个不会移除另一个的值
+        let mut answered_at: HashMap<String, usize> = HashMap::new();
+        let mut enabled_groups = vec![false; self.groups.len()];
+        // 已回答的询问位置，用于返回上一个询问
+        let mut history: Vec<usize> = Vec::new();
+        let mut cursor = 0;
+
+        while let Some(&position) = positions.get(cursor) {
+            let response = match position {
+                FormPosition::Group(group_idx) => {
+                    let group = &self.groups[group_idx];
+                    if !group.optional || group.title.is_none() {
+                        // 必填组：显示标题和描述（如果有）；没有标题的可选组使用默认值，不询问
+                        if !group.optional {
+                            Self::print_group_header(group);
+                        }
+                        enabled_groups[group_idx] = !group.optional || group.default_enabled;
+                        cursor += 1;
+                        continue;
                     }
-                    ConfirmDialog::new(format!("Configure {}?", title))
-                        .with_default(group.default_enabled)
-                        .prompt()?
-                } else {
-                    group.default_enabled
-                };
-
-                if !should_configure {
-                    continue;
-                }
-            } else {
-                // 必填组：显示标题和描述（如果有）
-                if let Some(title) = &group.title {
-                    log_break!();
-                    log_message!("{}", title);
-                    log_break!('-', 40);
-                }
-                if let Some(description) = &group.description {
-                    log_debug!("{}", description);
-                    log_break!();
+                    let response = answer(FormPrompt::Group(group), &field_values)?;
+                    if let FormAnswer::Value(ref value) = response {
+                        enabled_groups[group_idx] = value == "yes";
+                    }
+                    response
                 }
-            }
-
-            // 执行组内的步骤
-            for step in &group.steps {
-                if self.should_execute_step(step, &field_values) {
-                    for field in &step.fields {
-                        if self.should_ask_field(field, &field_values) {
-                            self.ask_field(field, &mut field_values)?;
+                FormPosition::Field(group_idx, step_idx, field_idx) => {
+                    let step = &self.groups[group_idx].steps[step_idx];
+                    let field = &step.fields[field_idx];
+                    if !enabled_groups[group_idx]
+                        || !self.should_execute_step(step, &field_values)
+                        || !self.should_ask_field(field, &field_values)
+                    {
+                        // 之前回答过但现在不再适用的字段，移除其值
+                        if answered_at.get(&field.name) == Some(&cursor) {
+                            answered_at.remove(&field.name);
+                            field_values.remove(&field.name);
                         }
+                        cursor += 1;
+                        continue;
                     }
+                    let response = answer(FormPrompt::Field(field), &field_values)?;
+                    if let FormAnswer::Value(ref value) = response {
+                        field_values.insert(field.name.clone(), value.clone());
+                        answered_at.insert(field.name.clone(), cursor);
+                    }
+                    response
+                }
+            };
+
+            match response {
+                FormAnswer::Value(_) => {
+                    history.push(cursor);
+                    cursor += 1;
                 }
+                FormAnswer::Back => match history.pop() {
+                    Some(previous) => cursor = previous,
+                    None => {
+                        log_warning!("Already at the first field");
+                    }
+                },
             }
         }
 
@@ -228,6 +338,33 @@ impl FormBuilder {
         })
     }
 
+    /// 显示组的标题和描述
+    fn print_group_header(group: &FormGroup) {
+        if let Some(title) = &group.title {
+            log_break!();
+            log_message!("{}", title);
+            log_break!('-', 40);
+        }
+        if let Some(description) = &group.description {
+            log_debug!("{}", description);
+            log_break!();
+        }
+    }
+
+    /// 询问是否配置可选组
+    fn ask_group(&self, group: &FormGroup) -> Result<FormAnswer> {
+        Self::print_group_header(group);
+        let title = group.title.as_deref().unwrap_or(&group.id);
+        let answer = ConfirmDialog::new(format!("Configure {}?", title))
+            .with_default(group.default_enabled)
+            .prompt_opt()?;
+        Ok(match answer {
+            Some(true) => FormAnswer::Value("yes".to_string()),
+            Some(false) => FormAnswer::Value("no".to_string()),
+            None => FormAnswer::Back,
+        })
+    }
+
     /// 判断步骤是否应该执行
     fn should_execute_step(&self, step: &FormStep, field_values: &HashMap<String, String>) -> bool {
         match &step.step_type {
@@ -260,20 +397,82 @@ impl FormBuilder {
     }
 
     /// 询问单个字段
+    ///
+    /// 字段设置了异步验证器时，输入后显示 spinner 执行验证，验证失败时重新询问。
     fn ask_field(
         &self,
         field: &FormField,
-        field_values: &mut HashMap<String, String>,
-    ) -> Result<()> {
+        field_values: &HashMap<String, String>,
+    ) -> Result<FormAnswer> {
+        let answers = FormResult {
+            values: field_values.clone(),
+        };
+        // 返回修改时使用之前输入的值作为默认值，否则根据之前的回答计算默认值（动态默认值在此时求值）
+        let default_value =
+            Self::previous_value(field, field_values).or_else(|| field.default_for(&answers));
+
+        loop {
+            let value = match self.prompt_field(field, default_value.as_ref())? {
+                FormAnswer::Value(value) => value,
+                FormAnswer::Back => return Ok(FormAnswer::Back),
+            };
+
+            if let Some(ref validator) = field.async_validator {
+                if !self.skip_async_validation {
+                    if let Err(e) =
+                        Spinner::with(&validator.message, || validator.run(&value, &answers))
+                    {
+                        log_error!("{}", e);
+                        continue;
+                    }
+                }
+            }
+
+            return Ok(FormAnswer::Value(value));
+        }
+    }
+
+    /// 之前输入的字段值（返回修改时作为默认值，密码字段不回显）
+    fn previous_value(
+        field: &FormField,
+        field_values: &HashMap<String, String>,
+    ) -> Option<FieldDefaultValue> {
+        let previous = field_values.get(&field.name).filter(|v| !v.is_empty())?;
         match field.field_type {
-            FormFieldType::Text => {
-                let mut dialog = InputDialog::new(&field.message);
+            FormFieldType::Password => None,
+            FormFieldType::Confirmation => Some(FieldDefaultValue::Bool(previous == "yes")),
+            FormFieldType::Text | FormFieldType::Selection => {
+                Some(FieldDefaultValue::String(previous.clone()))
+            }
+        }
+    }
+
+    /// 将对话框结果转换为回答（按 Esc 取消视为返回上一个字段）
+    fn answer_from(result: Result<String>) -> Result<FormAnswer> {
+        match result {
+            Ok(value) => Ok(FormAnswer::Value(value)),
+            Err(e) if e.is::<DialogCancelled>() => Ok(FormAnswer::Back),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// 显示字段的输入对话框并返回回答
+    fn prompt_field(
+        &self,
+        field: &FormField,
+        default_value: Option<&FieldDefaultValue>,
+    ) -> Result<FormAnswer> {
+        match field.field_type {
+            FormFieldType::Text | FormFieldType::Password => {
+                // 密码字段使用敏感信息输入，输入内容不回显，也不显示默认值
+                let secret = field.field_type == FormFieldType::Password;
+                let mut dialog = InputDialog::new(&field.message)
+                    .secret(secret)
+                    .with_confirmation(secret && field.confirm_entry);
 
                 // 设置默认值
-                if let Some(ref default_value) = field.default_value {
-                    if let Some(default_str) = <FieldDefaultValue>::as_string(default_value) {
-                        dialog = dialog.with_default(default_str);
-                    }
+                if let Some(default_str) = default_value.and_then(|v| v.as_string()) {
+                    dialog = dialog.with_default(default_str);
                 }
 
                 // 设置验证器和空值处理
@@ -306,80 +505,36 @@ impl FormBuilder {
                     dialog = dialog.allow_empty(field_allow_empty);
                 }
 
-                let value = dialog.prompt()?;
-                field_values.insert(field.name.clone(), value);
-            }
-            FormFieldType::Password => {
-                let mut password_prompt = Password::new().with_prompt(&field.message);
-
-                // 如果允许空值，设置允许空密码
-                if field.allow_empty {
-                    password_prompt = password_prompt.allow_empty_password(true);
-                }
-
-                let password = password_prompt
-                    .interact()
-                    .map_err(|e| eyre!("Failed to get password: {}", e))?;
-
-                // 验证必填和空值
-                if field.required && password.is_empty() {
-                    color_eyre::eyre::bail!("Field '{}' is required", field.name);
-                }
-
-                // 如果允许空值且为空，跳过验证器
-                if field.allow_empty && password.is_empty() {
-                    // 允许空值，直接插入空字符串
-                } else {
-                    // 验证器
-                    if let Some(ref validator) = field.validator {
-                        validator(&password)
-                            .map_err(|e| color_eyre::eyre::eyre!("Validation error: {}", e))?;
-                    }
-                }
-
-                field_values.insert(field.name.clone(), password);
+                Self::answer_from(dialog.prompt())
             }
             FormFieldType::Selection => {
                 let mut dialog = SelectDialog::new(&field.message, field.choices.clone());
 
-                // 设置默认选项
-                if let Some(ref default_choice) = field.default_choice {
-                    if let Some(idx) = field.choices.iter().position(|c| c == default_choice) {
+                // 设置默认选项（default_choice 优先）
+                if let Some(default_str) = default_value.and_then(|v| v.as_string()) {
+                    if let Some(idx) = field.choices.iter().position(|c| c == &default_str) {
                         dialog = dialog.with_default(idx);
                     }
-                } else if let Some(ref default_value) = field.default_value {
-                    if let Some(default_str) = <FieldDefaultValue>::as_string(default_value) {
-                        if let Some(idx) = field.choices.iter().position(|c| c == &default_str) {
-                            dialog = dialog.with_default(idx);
-                        }
-                    }
                 }
 
-                let value = dialog.prompt()?;
-                field_values.insert(field.name.clone(), value);
+                Self::answer_from(dialog.prompt())
             }
             FormFieldType::Confirmation => {
                 let mut dialog = ConfirmDialog::new(&field.message);
 
                 // 设置默认值
-                if let Some(ref default_value) = field.default_value {
-                    if let Some(default_bool) = <FieldDefaultValue>::as_bool(default_value) {
-                        dialog = dialog.with_default(default_bool);
-                    }
+                if let Some(default_bool) = default_value.and_then(|v| v.as_bool()) {
+                    dialog = dialog.with_default(default_bool);
                 }
 
-                let confirmed = dialog.prompt()?;
-                // 将布尔值转换为字符串（"yes" 或 "no"）
-                let value = if confirmed {
-                    "yes".to_string()
-                } else {
-                    "no".to_string()
-                };
-                field_values.insert(field.name.clone(), value);
+                // 将布尔值转换为字符串（"yes" 或 "no"），按 Esc 返回上一个字段
+                Ok(match dialog.prompt_opt()? {
+                    Some(true) => FormAnswer::Value("yes".to_string()),
+                    Some(false) => FormAnswer::Value("no".to_string()),
+                    None => FormAnswer::Back,
+                })
             }
         }
-
-        Ok(())
     }
 }
 
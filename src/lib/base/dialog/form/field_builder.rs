@@ -2,7 +2,9 @@
 
 use std::sync::Arc;
 
-use crate::base::dialog::form::types::{FieldDefaultValue, FormField, FormFieldType};
+use crate::base::dialog::form::types::{
+    AsyncValidator, FieldDefaultValue, FormField, FormFieldType, FormResult,
+};
 
 /// 字段构建器
 ///
@@ -36,6 +38,8 @@ impl FieldBuilder {
             required: false,
             allow_empty: false,
             validator: None,
+            async_validator: None,
+            confirm_entry: false,
             condition: None,
         };
         self.current_field = Some(self.fields.len());
@@ -44,6 +48,9 @@ impl FieldBuilder {
     }
 
     /// 添加密码输入字段
+    ///
+    /// 输入内容以 `*` 显示，不会出现在终端或回滚记录中，适用于 token、API key 等敏感信息。
+    /// 密码字段不显示默认值，返回修改时也不回显之前的输入。
     pub fn add_password(mut self, name: impl Into<String>, message: impl Into<String>) -> Self {
         let field = FormField {
             name: name.into(),
@@ -55,6 +62,8 @@ impl FieldBuilder {
             required: false,
             allow_empty: false,
             validator: None,
+            async_validator: None,
+            confirm_entry: false,
             condition: None,
         };
         self.current_field = Some(self.fields.len());
@@ -79,6 +88,8 @@ impl FieldBuilder {
             required: false,
             allow_empty: false,
             validator: None,
+            async_validator: None,
+            confirm_entry: false,
             condition: None,
         };
         self.current_field = Some(self.fields.len());
@@ -98,6 +109,8 @@ impl FieldBuilder {
             required: false,
             allow_empty: false,
             validator: None,
+            async_validator: None,
+            confirm_entry: false,
             condition: None,
         };
         self.current_field = Some(self.fields.len());
@@ -132,6 +145,41 @@ impl FieldBuilder {
         self
     }
 
+    /// 设置当前字段的动态默认值
+    ///
+    /// 默认值在询问此字段时根据之前的回答计算，返回 `None` 表示没有默认值。
+    ///
+    /// # 示例
+    ///
+    /// ```rust,no_run
+    /// use workflow::base::dialog::{FormBuilder, GroupConfig};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// FormBuilder::new()
+    ///     .add_group("github", |g| {
+    ///         g.step(|f| {
+    ///             f.add_text("github_email", "GitHub email").required()
+    ///                 .add_text("github_name", "GitHub username")
+    ///                 .default_with(|answers| {
+    ///                     answers
+    ///                         .get("github_email")
+    ///                         .and_then(|email| email.split('@').next())
+    ///                         .map(|name| name.to_string())
+    ///                 })
+    ///         })
+    ///     }, GroupConfig::required())
+    ///     .run()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn default_with<F, T>(self, f: F) -> Self
+    where
+        F: Fn(&FormResult) -> Option<T> + Send + Sync + 'static,
+        T: Into<FieldDefaultValue>,
+    {
+        self.default(FieldDefaultValue::dynamic(f))
+    }
+
     /// 设置当前字段的验证器
     pub fn validate<F>(mut self, validator: F) -> Self
     where
@@ -145,6 +193,57 @@ impl FieldBuilder {
         self
     }
 
+    /// 设置当前字段的异步验证器（仅对文本和密码字段有效）
+    ///
+    /// 验证在用户输入并通过 `validate` 的验证后执行，期间显示 spinner；
+    /// 验证失败时输出错误消息并重新询问该字段。适用于需要网络请求的验证，
+    /// 表单使用 `FormBuilder::skip_async_validation(true)` 时跳过（如离线 setup）。
+    ///
+    /// # 参数
+    ///
+    /// * `message` - 验证时 spinner 显示的消息
+    /// * `validator` - 验证函数，接收字段值和已回答的字段
+    ///
+    /// # 示例
+    ///
+    /// ```rust,no_run
+    /// use workflow::base::dialog::{FormBuilder, GroupConfig};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// FormBuilder::new()
+    ///     .add_group("jira", |g| {
+    ///         g.step(|f| {
+    ///             f.add_text("jira_email", "Jira email address").required()
+    ///                 .add_text("jira_api_token", "Jira API token").required()
+    ///                 .validate_async("Verifying Jira credentials...", |token, answers| {
+    ///                     let email = answers.get("jira_email").cloned().unwrap_or_default();
+    ///                     // 使用 email 和 token 发起认证请求
+    ///                     if email.is_empty() || token.is_empty() {
+    ///                         return Err("Jira credentials are incomplete".to_string());
+    ///                     }
+    ///                     Ok(())
+    ///                 })
+    ///         })
+    ///     }, GroupConfig::required())
+    ///     .run()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_async<F>(mut self, message: impl Into<String>, validator: F) -> Self
+    where
+        F: Fn(&str, &FormResult) -> Result<(), String> + Send + Sync + 'static,
+    {
+        if let Some(idx) = self.current_field {
+            if let Some(field) = self.fields.get_mut(idx) {
+                field.async_validator = Some(AsyncValidator {
+                    message: message.into(),
+                    validate: Arc::new(validator),
+                });
+            }
+        }
+        self
+    }
+
     /// 允许当前字段为空（仅对文本和密码字段有效）
     pub fn allow_empty(mut self, allow: bool) -> Self {
         if let Some(idx) = self.current_field {
@@ -154,6 +253,18 @@ impl FieldBuilder {
         }
         self
     }
+
+    /// 要求当前字段再次输入确认（仅对密码字段有效）
+    ///
+    /// 两次输入不一致时提示重新输入，适用于设置新密码等输错后难以发现的场景。
+    pub fn confirm_entry(mut self) -> Self {
+        if let Some(idx) = self.current_field {
+            if let Some(field) = self.fields.get_mut(idx) {
+                field.confirm_entry = true;
+            }
+        }
+        self
+    }
 }
 
 impl Default for FieldBuilder {
@@ -9,6 +9,10 @@
 //! - **字段构建**：每个步骤可以包含多个字段（Field）
 //! - **条件逻辑**：支持多种条件类型（step_if, step_if_all, step_if_any, step_if_dynamic）
 //! - **可选组**：支持可选组，可以询问用户是否配置
+//! - **动态默认值**：字段默认值可以根据之前的回答计算（`default_with`）
+//! - **异步验证**：字段可以在输入后执行耗时验证（如网络请求），失败时重新询问（`validate_async`）
+//! - **返回上一步**：按 Esc（或在文本/密码字段中输入 `<`）返回上一个字段，已输入的值作为默认值保留，
+//!   之后的条件步骤根据新的回答重新判断
 //!
 //! ## 使用示例
 //!
@@ -50,6 +54,6 @@ mod types;
 
 pub use builder::FormBuilder;
 pub use types::{
-    Condition, ConditionOperator, ConditionValue, FieldDefaultValue, FormField, FormFieldType,
-    FormGroup, FormResult, FormStep, GroupConfig, StepType,
+    AsyncValidator, Condition, ConditionOperator, ConditionValue, FieldDefaultValue, FormAnswer,
+    FormField, FormFieldType, FormGroup, FormPrompt, FormResult, FormStep, GroupConfig, StepType,
 };
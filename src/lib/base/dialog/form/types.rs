@@ -2,6 +2,8 @@
 
 use color_eyre::Result;
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
 
 use crate::base::dialog::types::ValidatorFn;
 
@@ -65,33 +67,112 @@ impl ConditionValue {
     }
 }
 
+/// 动态默认值函数类型
+///
+/// 接收已回答字段组成的 `FormResult`，返回 `None` 表示没有默认值。
+pub type DynamicDefaultFn = Arc<dyn Fn(&FormResult) -> Option<FieldDefaultValue> + Send + Sync>;
+
 /// 字段默认值类型
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub enum FieldDefaultValue {
     /// 字符串默认值
     String(String),
     /// 布尔默认值
     Bool(bool),
+    /// 动态默认值（在询问字段时根据之前的回答计算）
+    Dynamic(DynamicDefaultFn),
+}
+
+impl fmt::Debug for FieldDefaultValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(s) => f.debug_tuple("String").field(s).finish(),
+            Self::Bool(b) => f.debug_tuple("Bool").field(b).finish(),
+            Self::Dynamic(_) => f.write_str("Dynamic(<fn>)"),
+        }
+    }
 }
 
 impl FieldDefaultValue {
-    /// 获取字符串值
+    /// 创建动态默认值
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use workflow::base::dialog::{FieldDefaultValue, FormResult};
+    ///
+    /// // 使用邮箱的用户名部分作为默认用户名
+    /// let default = FieldDefaultValue::dynamic(|answers: &FormResult| {
+    ///     answers
+    ///         .get("email")
+    ///         .and_then(|email| email.split('@').next())
+    ///         .map(|name| name.to_string())
+    /// });
+    ///
+    /// let mut answers = FormResult::new();
+    /// answers.values.insert("email".into(), "alice@example.com".into());
+    /// let resolved = default.resolve(&answers).unwrap();
+    /// assert_eq!(resolved.as_string(), Some("alice".to_string()));
+    /// ```
+    pub fn dynamic<F, T>(f: F) -> Self
+    where
+        F: Fn(&FormResult) -> Option<T> + Send + Sync + 'static,
+        T: Into<FieldDefaultValue>,
+    {
+        Self::Dynamic(Arc::new(move |answers| f(answers).map(Into::into)))
+    }
+
+    /// 根据已回答的字段解析默认值
+    ///
+    /// 静态默认值原样返回；动态默认值返回计算结果（结果仍为动态默认值时视为没有默认值）。
+    pub fn resolve(&self, answers: &FormResult) -> Option<FieldDefaultValue> {
+        match self {
+            Self::Dynamic(f) => f(answers).filter(|value| !matches!(value, Self::Dynamic(_))),
+            _ => Some(self.clone()),
+        }
+    }
+
+    /// 获取字符串值（动态默认值需要先调用 `resolve`）
     pub fn as_string(&self) -> Option<String> {
         match self {
             Self::String(s) => Some(s.clone()),
-            Self::Bool(_) => None,
+            Self::Bool(_) | Self::Dynamic(_) => None,
         }
     }
 
-    /// 获取布尔值
+    /// 获取布尔值（动态默认值需要先调用 `resolve`）
     pub fn as_bool(&self) -> Option<bool> {
         match self {
-            Self::String(_) => None,
             Self::Bool(b) => Some(*b),
+            Self::String(_) | Self::Dynamic(_) => None,
         }
     }
 }
 
+/// 异步验证函数类型
+///
+/// 接收字段值和已回答的字段，返回 `Err(错误消息)` 表示验证失败。
+pub type AsyncValidatorFn = Arc<dyn Fn(&str, &FormResult) -> Result<(), String> + Send + Sync>;
+
+/// 异步验证器
+///
+/// 用于需要耗时操作（如网络请求）的验证，例如在 setup 时验证 Jira 凭据是否有效。
+/// 验证在用户输入后执行，期间显示 spinner；验证失败时重新询问该字段。
+#[derive(Clone)]
+pub struct AsyncValidator {
+    /// 验证时 spinner 显示的消息
+    pub message: String,
+    /// 验证函数
+    pub validate: AsyncValidatorFn,
+}
+
+impl AsyncValidator {
+    /// 执行验证
+    pub fn run(&self, value: &str, answers: &FormResult) -> Result<(), String> {
+        (self.validate)(value, answers)
+    }
+}
+
 /// 表单字段
 #[derive(Clone)]
 pub struct FormField {
@@ -116,10 +197,44 @@ pub struct FormField {
     pub allow_empty: bool,
     /// 可选的验证函数
     pub validator: Option<ValidatorFn>,
+    /// 可选的异步验证器（输入后执行，可通过 `FormBuilder::skip_async_validation` 跳过）
+    pub async_validator: Option<AsyncValidator>,
+    /// 是否需要再次输入确认（仅对密码字段有效）
+    pub confirm_entry: bool,
     /// 条件：必须满足此条件才会显示此字段
     pub condition: Option<Condition>,
 }
 
+impl FormField {
+    /// 根据已回答的字段计算此字段的默认值
+    ///
+    /// 选择字段优先使用 `default_choice`，动态默认值在此时计算。
+    pub fn default_for(&self, answers: &FormResult) -> Option<FieldDefaultValue> {
+        if let Some(ref choice) = self.default_choice {
+            return Some(FieldDefaultValue::String(choice.clone()));
+        }
+        self.default_value.as_ref().and_then(|value| value.resolve(answers))
+    }
+}
+
+/// 表单执行过程中的一次询问
+#[derive(Clone, Copy)]
+pub enum FormPrompt<'a> {
+    /// 询问是否配置可选组
+    Group(&'a FormGroup),
+    /// 询问字段
+    Field(&'a FormField),
+}
+
+/// 对一次询问的回答
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormAnswer {
+    /// 字段值（确认字段和可选组使用 "yes" 或 "no"）
+    Value(String),
+    /// 返回上一个字段
+    Back,
+}
+
 /// 表单结果
 #[derive(Debug, Clone)]
 pub struct FormResult {
@@ -1,13 +1,14 @@
 use color_eyre::{eyre::eyre, Result};
-use inquire::{error::InquireError, validator::Validation, Text};
+use inquire::{error::InquireError, validator::Validation, Password, PasswordDisplayMode, Text};
 use std::error::Error;
 use std::sync::Arc;
 
-use crate::base::dialog::types::ValidatorFn;
+use crate::base::dialog::types::{DialogCancelled, ValidatorFn};
 
 /// 文本输入对话框
 ///
 /// 提供文本输入功能，支持默认值、验证器和空值处理。
+/// 输入 token、密码等敏感信息时使用 `secret(true)`，输入内容以 `*` 显示，不会出现在终端或回滚记录中。
 ///
 /// ## 样式示例
 ///
@@ -42,11 +43,27 @@ use crate::base::dialog::types::ValidatorFn;
 /// # Ok(())
 /// # }
 /// ```
+///
+/// 输入敏感信息：
+///
+/// ```rust,no_run
+/// use workflow::base::dialog::InputDialog;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let token = InputDialog::new("Enter API token")
+///     .secret(true)
+///     .with_confirmation(true)
+///     .prompt()?;
+/// # Ok(())
+/// # }
+/// ```
 pub struct InputDialog {
     prompt: String,
     default: Option<String>,
     validator: Option<ValidatorFn>,
     allow_empty: bool,
+    secret: bool,
+    confirmation: bool,
 }
 
 impl InputDialog {
@@ -65,6 +82,8 @@ impl InputDialog {
             default: None,
             validator: None,
             allow_empty: false,
+            secret: false,
+            confirmation: false,
         }
     }
 
@@ -113,6 +132,68 @@ impl InputDialog {
         self
     }
 
+    /// 设置是否为敏感信息输入
+    ///
+    /// 敏感信息输入使用 `inquire` 的密码输入框，输入内容以 `*` 显示。
+    /// 敏感信息不显示默认值，`with_default` 设置的值会被忽略。
+    ///
+    /// # 参数
+    ///
+    /// * `secret` - 是否为敏感信息输入（默认：false）
+    ///
+    /// # 返回
+    ///
+    /// 返回 `Self` 以支持链式调用
+    pub fn secret(mut self, secret: bool) -> Self {
+        self.secret = secret;
+        self
+    }
+
+    /// 设置是否需要再次输入确认（仅对敏感信息输入有效）
+    ///
+    /// 两次输入不一致时提示重新输入。
+    ///
+    /// # 参数
+    ///
+    /// * `confirmation` - 是否需要再次输入确认（默认：false）
+    ///
+    /// # 返回
+    ///
+    /// 返回 `Self` 以支持链式调用
+    pub fn with_confirmation(mut self, confirmation: bool) -> Self {
+        self.confirmation = confirmation;
+        self
+    }
+
+    /// 构建输入验证器
+    ///
+    /// - 设置了验证器：允许空值且输入为空时直接通过，否则使用验证器
+    /// - 未设置验证器且不允许空值：输入不能为空
+    /// - 未设置验证器且允许空值：总是通过
+    fn input_validator(
+        &self,
+    ) -> impl Fn(&str) -> Result<Validation, Box<dyn Error + Send + Sync>> + Clone + 'static {
+        let validator = self.validator.clone();
+        let allow_empty = self.allow_empty;
+        move |input: &str| -> Result<Validation, Box<dyn Error + Send + Sync>> {
+            if input.trim().is_empty() {
+                if allow_empty {
+                    return Ok(Validation::Valid);
+                }
+                if validator.is_none() {
+                    return Ok(Validation::Invalid("Input cannot be empty".into()));
+                }
+            }
+            match validator {
+                Some(ref validator) => match validator(input) {
+                    Ok(()) => Ok(Validation::Valid),
+                    Err(msg) => Ok(Validation::Invalid(msg.into())),
+                },
+                None => Ok(Validation::Valid),
+            }
+        }
+    }
+
     /// 显示对话框并获取用户输入
     ///
     /// # 返回
@@ -123,54 +204,33 @@ impl InputDialog {
     ///
     /// 如果用户取消输入或验证失败，返回错误
     pub fn prompt(self) -> Result<String> {
-        let mut text = Text::new(&self.prompt);
+        let validator = self.input_validator();
 
-        // 设置默认值
-        if let Some(ref default) = self.default {
-            text = text.with_default(default);
-        }
-
-        // 设置验证器
-        if let Some(validator) = self.validator {
-            let allow_empty = self.allow_empty;
-            let validator_clone = validator.clone();
-            text = text.with_validator(
-                move |input: &str| -> Result<Validation, Box<dyn Error + Send + Sync>> {
-                    // 如果允许空值且输入为空，直接通过
-                    if allow_empty && input.trim().is_empty() {
-                        return Ok(Validation::Valid);
-                    }
-                    // 否则使用验证器
-                    match validator_clone(input) {
-                        Ok(()) => Ok(Validation::Valid),
-                        Err(msg) => Ok(Validation::Invalid(msg.into())),
-                    }
-                },
-            );
-        } else if !self.allow_empty {
-            // 如果没有验证器但不允许空值，添加默认验证
-            text = text.with_validator(
-                |input: &str| -> Result<Validation, Box<dyn Error + Send + Sync>> {
-                    if input.trim().is_empty() {
-                        Ok(Validation::Invalid("Input cannot be empty".into()))
-                    } else {
-                        Ok(Validation::Valid)
-                    }
-                },
-            );
-        } else if self.allow_empty {
-            // 如果允许空值，添加一个总是通过的验证器
-            text = text.with_validator(
-                |_input: &str| -> Result<Validation, Box<dyn Error + Send + Sync>> {
-                    Ok(Validation::Valid)
-                },
-            );
-        }
+        let result = if self.secret {
+            let mut password = Password::new(&self.prompt)
+                .with_display_mode(PasswordDisplayMode::Masked)
+                .with_validator(validator);
+            if self.confirmation {
+                password = password
+                    .with_custom_confirmation_message("Confirm:")
+                    .with_custom_confirmation_error_message("The inputs don't match.");
+            } else {
+                password = password.without_confirmation();
+            }
+            password.prompt()
+        } else {
+            let mut text = Text::new(&self.prompt).with_validator(validator);
+            // 设置默认值
+            if let Some(ref default) = self.default {
+                text = text.with_default(default);
+            }
+            text.prompt()
+        };
 
-        text.prompt()
+        result
             .map_err(|e| match e {
                 InquireError::OperationCanceled => {
-                    eyre!("Operation cancelled by user")
+                    eyre!(DialogCancelled)
                 }
                 _ => eyre!("Input error: {}", e),
             })
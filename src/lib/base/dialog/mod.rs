@@ -4,8 +4,9 @@
 //! 支持链式调用，提供更好的用户体验和代码可读性。
 //!
 //! **后端实现：**
-//! - `InputDialog`, `SelectDialog`, `MultiSelectDialog`：使用 `inquire`
+//! - `InputDialog`（包括敏感信息输入）, `SelectDialog`, `MultiSelectDialog`：使用 `inquire`
 //! - `ConfirmDialog`：使用 `dialoguer`（支持单键自动完成和 Enter 使用默认值）
+//! - `EditorDialog`：使用 `dialoguer`（打开 `$VISUAL`/`$EDITOR` 编辑多行文本）
 //!
 //! ## 对话框类型
 //!
@@ -13,6 +14,7 @@
 //! - `SelectDialog` - 单选对话框
 //! - `MultiSelectDialog` - 多选对话框
 //! - `ConfirmDialog` - 确认对话框
+//! - `EditorDialog` - 编辑器对话框
 //!
 //! ## 使用示例
 //!
@@ -46,6 +48,11 @@
 //! let optional = InputDialog::new("Enter value (optional)")
 //!     .allow_empty(true)
 //!     .prompt()?;
+//!
+//! // 敏感信息（输入内容以 `*` 显示）
+//! let token = InputDialog::new("Enter API token")
+//!     .secret(true)
+//!     .prompt()?;
 //! # Ok(())
 //! # }
 //! ```
@@ -68,6 +75,10 @@
 //! let selected = SelectDialog::new("选择分支", branches)
 //!     // 模糊匹配默认启用，支持输入关键词过滤
 //!     .prompt()?;
+//!
+//! // 显示标签，返回标签对应的值
+//! let items = vec![("main [current]".to_string(), "main"), ("develop".to_string(), "develop")];
+//! let branch = SelectDialog::new_with_items("选择分支", items).prompt()?;
 //! # Ok(())
 //! # }
 //! ```
@@ -107,6 +118,7 @@
 //! ```
 
 mod confirm;
+mod editor;
 mod form;
 mod input;
 mod multi_select;
@@ -114,7 +126,12 @@ mod select;
 mod types;
 
 pub use confirm::ConfirmDialog;
-pub use form::{FormBuilder, FormGroup, FormResult, FormStep, GroupConfig, StepType};
+pub use editor::EditorDialog;
+pub use form::{
+    AsyncValidator, FieldDefaultValue, FormAnswer, FormBuilder, FormGroup, FormPrompt, FormResult,
+    FormStep, GroupConfig, StepType,
+};
 pub use input::InputDialog;
 pub use multi_select::MultiSelectDialog;
 pub use select::SelectDialog;
+pub use types::{DialogCancelled, SelectionConstraintError};
@@ -1,5 +1,9 @@
 use color_eyre::{eyre::eyre, Result};
-use inquire::{error::InquireError, MultiSelect};
+use inquire::list_option::ListOption;
+use inquire::validator::{MultiOptionValidator, Validation};
+use inquire::{error::InquireError, CustomUserError, MultiSelect};
+
+use crate::base::dialog::types::{DialogCancelled, SelectionConstraintError};
 
 /// 多选对话框
 ///
@@ -29,13 +33,21 @@ use inquire::{error::InquireError, MultiSelect};
 /// let selected = MultiSelectDialog::new("Choose options", options)
 ///     .prompt()?;
 /// // selected 是 Vec<&str>，包含选中的选项
+///
+/// // 预先选中第一个选项，并且至少选择一个
+/// let selected = MultiSelectDialog::new("Choose options", vec!["Option 1", "Option 2"])
+///     .with_defaults(vec![0])
+///     .with_min(1)
+///     .prompt()?;
 /// # Ok(())
 /// # }
 /// ```
 pub struct MultiSelectDialog<T> {
     prompt: String,
     options: Vec<T>,
-    default: Option<Vec<usize>>,
+    defaults: Vec<usize>,
+    min: usize,
+    max: Option<usize>,
 }
 
 impl<T> MultiSelectDialog<T>
@@ -56,12 +68,16 @@ where
         Self {
             prompt: prompt.into(),
             options,
-            default: None,
+            defaults: Vec::new(),
+            min: 0,
+            max: None,
         }
     }
 
     /// 设置默认选中的选项索引
     ///
+    /// 超出选项范围的索引会被忽略。
+    ///
     /// # 参数
     ///
     /// * `indices` - 默认选中的选项索引列表
@@ -69,11 +85,58 @@ where
     /// # 返回
     ///
     /// 返回 `Self` 以支持链式调用
-    pub fn with_default(mut self, indices: Vec<usize>) -> Self {
-        self.default = Some(indices);
+    pub fn with_defaults(mut self, indices: Vec<usize>) -> Self {
+        self.defaults = indices;
+        self
+    }
+
+    /// 设置默认选中的选项索引（等同于 `with_defaults`）
+    pub fn with_default(self, indices: Vec<usize>) -> Self {
+        self.with_defaults(indices)
+    }
+
+    /// 设置至少选择的数量
+    ///
+    /// 选择数量不足时显示错误提示并要求重新选择。
+    pub fn with_min(mut self, min: usize) -> Self {
+        self.min = min;
         self
     }
 
+    /// 设置最多选择的数量
+    ///
+    /// 选择数量超出时显示错误提示并要求重新选择。
+    pub fn with_max(mut self, max: usize) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// 检查选择数量限制能否满足
+    ///
+    /// 至少选择的数量大于选项数量或最多选择的数量时返回 `SelectionConstraintError`。
+    pub fn check_constraints(&self) -> std::result::Result<(), SelectionConstraintError> {
+        let satisfiable =
+            self.min <= self.options.len() && self.max.is_none_or(|max| self.min <= max);
+        if satisfiable {
+            Ok(())
+        } else {
+            Err(SelectionConstraintError {
+                min: self.min,
+                max: self.max,
+                available: self.options.len(),
+            })
+        }
+    }
+
+    /// 检查选择数量是否满足限制
+    ///
+    /// # 返回
+    ///
+    /// 满足时返回 `Ok(())`，否则返回显示给用户的错误提示。
+    pub fn validate_selection_count(&self, count: usize) -> std::result::Result<(), String> {
+        check_selection_count(self.min, self.max, count)
+    }
+
     /// 显示对话框并获取用户选择
     ///
     /// # 返回
@@ -82,24 +145,73 @@ where
     ///
     /// # 错误
     ///
-    /// 如果用户取消选择，返回错误
+    /// - 用户取消选择时返回 `DialogCancelled` 错误
+    /// - 选择数量限制无法满足时返回 `SelectionConstraintError` 错误，不显示对话框
     pub fn prompt(self) -> Result<Vec<T>> {
         if self.options.is_empty() {
             color_eyre::eyre::bail!("No options available");
         }
+        self.check_constraints().map_err(|e| eyre!(e))?;
+
+        let option_count = self.options.len();
+        let defaults: Vec<usize> =
+            self.defaults.into_iter().filter(|&index| index < option_count).collect();
+        let (min, max) = (self.min, self.max);
 
         let mut multi_select = MultiSelect::new(&self.prompt, self.options);
 
         // 设置默认值
-        if let Some(ref default_indices) = self.default {
-            multi_select = multi_select.with_default(default_indices.as_slice());
+        if !defaults.is_empty() {
+            multi_select = multi_select.with_default(defaults.as_slice());
+        }
+
+        // 选择数量不满足限制时，inquire 显示错误提示并保持对话框，直到用户重新选择
+        if min > 0 || max.is_some() {
+            multi_select = multi_select.with_validator(SelectionCountValidator { min, max });
         }
 
         multi_select.prompt().map_err(|e| match e {
             InquireError::OperationCanceled => {
-                eyre!("Operation cancelled by user")
+                eyre!(DialogCancelled)
             }
             _ => eyre!("Multi-selection error: {}", e),
         })
     }
 }
+
+/// 选择数量验证器
+///
+/// 不依赖选项类型 `T`，因此选项可以是借用的数据。
+#[derive(Clone)]
+struct SelectionCountValidator {
+    min: usize,
+    max: Option<usize>,
+}
+
+impl<T> MultiOptionValidator<T> for SelectionCountValidator {
+    fn validate(&self, input: &[ListOption<&T>]) -> Result<Validation, CustomUserError> {
+        Ok(
+            match check_selection_count(self.min, self.max, input.len()) {
+                Ok(()) => Validation::Valid,
+                Err(message) => Validation::Invalid(message.into()),
+            },
+        )
+    }
+}
+
+/// 检查选择数量是否在 `[min, max]` 范围内
+fn check_selection_count(
+    min: usize,
+    max: Option<usize>,
+    count: usize,
+) -> std::result::Result<(), String> {
+    if count < min {
+        return Err(format!("Please select at least {} option(s)", min));
+    }
+    if let Some(max) = max {
+        if count > max {
+            return Err(format!("Please select at most {} option(s)", max));
+        }
+    }
+    Ok(())
+}
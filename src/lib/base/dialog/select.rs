@@ -1,8 +1,33 @@
+use std::cmp::Reverse;
+
 use color_eyre::{eyre::eyre, Result};
 use fuzzy_matcher::skim::SkimMatcherV2;
 use fuzzy_matcher::FuzzyMatcher;
 use inquire::{error::InquireError, Select};
 
+use crate::base::dialog::types::DialogCancelled;
+
+/// 模糊匹配分数的放大倍数（低位用于按原始顺序排列同分选项）
+const TIE_BREAK_FACTOR: i64 = 1 << 32;
+
+thread_local! {
+    /// 模糊匹配器（每个线程复用一个，避免每个选项、每次按键都重新创建）
+    static MATCHER: SkimMatcherV2 = SkimMatcherV2::default();
+}
+
+/// 计算选项的模糊匹配分数
+///
+/// 不匹配时返回 `None`（选项被过滤掉）。输入为空时所有选项都匹配。
+/// 分数相同的选项按原始顺序排列（inquire 使用不稳定排序，需要在分数中区分）。
+fn fuzzy_score(input: &str, display: &str, index: usize) -> Option<i64> {
+    let score = if input.is_empty() {
+        0
+    } else {
+        MATCHER.with(|matcher| matcher.fuzzy_match(display, input))?
+    };
+    Some(score.saturating_mul(TIE_BREAK_FACTOR).saturating_sub(index as i64))
+}
+
 /// 单选对话框
 ///
 /// 提供单选功能，从选项列表中选择一个选项。
@@ -58,10 +83,49 @@ use inquire::{error::InquireError, Select};
 /// # Ok(())
 /// # }
 /// ```
+///
+/// ## 大量选项
+///
+/// 每页只渲染 `with_page_size` 指定数量的选项（默认 7 个），输入内容时逐个按键重新过滤，
+/// 适合从上百个分支或 Jira ticket 中选择：
+///
+/// ```rust,no_run
+/// use workflow::base::dialog::SelectDialog;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let tickets: Vec<String> = (1..=500).map(|i| format!("PROJ-{}", i)).collect();
+/// let ticket = SelectDialog::new("Select ticket", tickets)
+///     .with_page_size(15)
+///     .prompt()?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// ## 带数据的选项
+///
+/// 使用 `new_with_items` 时显示标签，返回标签对应的值，不需要再从显示字符串解析回原始数据：
+///
+/// ```rust,no_run
+/// use workflow::base::dialog::SelectDialog;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let items = vec![
+///     ("main [current]".to_string(), "main"),
+///     ("develop".to_string(), "develop"),
+/// ];
+/// let branch: &str = SelectDialog::new_with_items("Select branch", items).prompt()?;
+/// # Ok(())
+/// # }
+/// ```
 pub struct SelectDialog<T> {
     prompt: String,
-    options: Vec<T>,
+    /// 显示的标签
+    labels: Vec<String>,
+    /// 标签对应的值（与 `labels` 一一对应）
+    values: Vec<T>,
     default: Option<usize>,
+    /// 每页显示的选项数量（`None` 时使用 inquire 的默认值）
+    page_size: Option<usize>,
 }
 
 impl<T> SelectDialog<T>
@@ -70,6 +134,8 @@ where
 {
     /// 创建新的单选对话框
     ///
+    /// 选项本身作为返回值，通过 `Display` 显示。
+    ///
     /// # 参数
     ///
     /// * `prompt` - 提示信息
@@ -79,10 +145,38 @@ where
     ///
     /// 返回 `SelectDialog` 实例
     pub fn new(prompt: impl Into<String>, options: Vec<T>) -> Self {
+        let labels = options.iter().map(|option| option.to_string()).collect();
         Self {
             prompt: prompt.into(),
-            options,
+            labels,
+            values: options,
             default: None,
+            page_size: None,
+        }
+    }
+}
+
+impl<T> SelectDialog<T> {
+    /// 创建带数据的单选对话框
+    ///
+    /// 显示每一项的标签，返回用户选中的标签对应的值。
+    ///
+    /// # 参数
+    ///
+    /// * `prompt` - 提示信息
+    /// * `items` - `(标签, 值)` 列表
+    ///
+    /// # 返回
+    ///
+    /// 返回 `SelectDialog` 实例
+    pub fn new_with_items(prompt: impl Into<String>, items: Vec<(String, T)>) -> Self {
+        let (labels, values) = items.into_iter().unzip();
+        Self {
+            prompt: prompt.into(),
+            labels,
+            values,
+            default: None,
+            page_size: None,
         }
     }
 
@@ -100,59 +194,129 @@ where
         self
     }
 
+    /// 设置每页显示的选项数量
+    ///
+    /// 选项较多时只渲染当前页，使用方向键翻页。
+    ///
+    /// # 参数
+    ///
+    /// * `page_size` - 每页显示的选项数量（至少为 1）
+    ///
+    /// # 返回
+    ///
+    /// 返回 `Self` 以支持链式调用
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = Some(page_size.max(1));
+        self
+    }
+
+    /// 按输入内容过滤选项
+    ///
+    /// 使用与对话框相同的模糊匹配规则，结果顺序与对话框中显示的顺序一致
+    /// （匹配度高的在前，匹配度相同时保持原始顺序）。
+    ///
+    /// # 参数
+    ///
+    /// * `input` - 用户输入的搜索内容
+    ///
+    /// # 返回
+    ///
+    /// 返回匹配选项的原始索引
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::base::dialog::SelectDialog;
+    ///
+    /// let dialog = SelectDialog::new("Select branch", vec!["main", "feature/login", "fix/logout"]);
+    /// assert_eq!(dialog.filter(""), vec![0, 1, 2]);
+    /// assert_eq!(dialog.filter("login"), vec![1]);
+    /// ```
+    pub fn filter(&self, input: &str) -> Vec<usize> {
+        let mut scored: Vec<(usize, i64)> = self
+            .labels
+            .iter()
+            .enumerate()
+            .filter_map(|(index, label)| {
+                fuzzy_score(input, label, index).map(|score| (index, score))
+            })
+            .collect();
+        scored.sort_unstable_by_key(|(_, score)| Reverse(*score));
+        scored.into_iter().map(|(index, _)| index).collect()
+    }
+
+    /// 按输入内容过滤后选择指定位置的选项（不显示对话框）
+    ///
+    /// 结果与用户在对话框中输入 `input`、将光标移动到过滤结果的第 `position` 项后按 Enter 一致。
+    ///
+    /// # 参数
+    ///
+    /// * `input` - 搜索内容
+    /// * `position` - 过滤结果中的位置（从 0 开始）
+    ///
+    /// # 错误
+    ///
+    /// 如果过滤结果中没有该位置，返回错误
+    pub fn select_filtered(self, input: &str, position: usize) -> Result<T> {
+        let index = self
+            .filter(input)
+            .get(position)
+            .copied()
+            .ok_or_else(|| eyre!("No option at position {} matching '{}'", position, input))?;
+        self.take(index)
+    }
+
+    /// 取出指定索引的值
+    fn take(self, index: usize) -> Result<T> {
+        self.values
+            .into_iter()
+            .nth(index)
+            .ok_or_else(|| eyre!("Selection error: index {} out of range", index))
+    }
+
     /// 显示对话框并获取用户选择
     ///
     /// # 返回
     ///
-    /// 返回用户选中的选项（值的所有权）
+    /// 返回用户选中的选项（值的所有权）；使用 `new_with_items` 创建时返回选中标签对应的值
     ///
     /// # 错误
     ///
     /// 如果用户取消选择，返回错误
-    pub fn prompt(self) -> Result<T> {
-        if self.options.is_empty() {
+    pub fn prompt(mut self) -> Result<T> {
+        if self.labels.is_empty() {
             color_eyre::eyre::bail!("No options available");
         }
 
-        let mut select = Select::new(&self.prompt, self.options);
+        let labels = std::mem::take(&mut self.labels);
+        let mut select = Select::new(&self.prompt, labels);
 
         // 设置默认值
         if let Some(default_idx) = self.default {
             select = select.with_starting_cursor(default_idx);
         }
 
+        if let Some(page_size) = self.page_size {
+            select = select.with_page_size(page_size);
+        }
+
         // 应用模糊匹配过滤器（默认启用）
-        // 定义模糊匹配 scorer 函数
         // 注意：inquire 的 with_scorer 需要函数引用，且返回 Option<i64>
-        // 参数类型是 (&str, &T, &str, usize) -> Option<i64>
-        fn fuzzy_scorer<T: std::fmt::Display>(
-            input: &str,
-            option: &T,
-            _display: &str,
-            _index: usize,
-        ) -> Option<i64> {
-            // 如果输入为空，显示所有选项（返回高分）
-            if input.is_empty() {
-                return Some(1000);
-            }
-
-            // 创建匹配器并执行模糊匹配
-            // 注意：每次调用都创建新的 matcher，但这是轻量级操作
-            let matcher = SkimMatcherV2::default();
-            let option_str = option.to_string();
-
-            // 使用模糊匹配计算分数
-            // fuzzy_match 返回 Option<usize>，转换为 i64
-            matcher.fuzzy_match(&option_str, input)
+        // 参数类型是 (&str, &T, &str, usize) -> Option<i64>，选项即显示的标签，直接匹配 display
+        fn fuzzy_scorer<T>(input: &str, _option: &T, display: &str, index: usize) -> Option<i64> {
+            fuzzy_score(input, display, index)
         }
 
         select = select.with_scorer(&fuzzy_scorer);
 
-        select.prompt().map_err(|e| match e {
+        let selected = select.raw_prompt().map_err(|e| match e {
             InquireError::OperationCanceled => {
-                eyre!("Operation cancelled by user")
+                eyre!(DialogCancelled)
             }
             _ => eyre!("Selection error: {}", e),
-        })
+        })?;
+
+        // 按索引取出选中标签对应的值
+        self.take(selected.index)
     }
 }
@@ -1,5 +1,51 @@
 use color_eyre::Result;
+use std::fmt;
 use std::sync::Arc;
 
 /// Type alias for validator functions to reduce type complexity
 pub(crate) type ValidatorFn = Arc<dyn Fn(&str) -> Result<(), String> + Send + Sync>;
+
+/// 用户按 Esc 取消对话框时返回的错误
+///
+/// 错误消息为 "Operation cancelled by user"。调用方可以通过 `Report::is::<DialogCancelled>()`
+/// 区分取消和其他错误，例如 `FormBuilder` 将取消视为返回上一个字段。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialogCancelled;
+
+impl fmt::Display for DialogCancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Operation cancelled by user")
+    }
+}
+
+impl std::error::Error for DialogCancelled {}
+
+/// 多选对话框的选择数量限制无法满足时返回的错误
+///
+/// 例如至少选择的数量大于选项数量，或至少选择的数量大于最多选择的数量。调用方可以通过
+/// `Report::is::<SelectionConstraintError>()` 区分该错误和用户取消（`DialogCancelled`）。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelectionConstraintError {
+    /// 至少选择的数量
+    pub min: usize,
+    /// 最多选择的数量（`None` 表示不限制）
+    pub max: Option<usize>,
+    /// 选项数量
+    pub available: usize,
+}
+
+impl fmt::Display for SelectionConstraintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Selection constraint cannot be satisfied: at least {}",
+            self.min
+        )?;
+        if let Some(max) = self.max {
+            write!(f, " and at most {}", max)?;
+        }
+        write!(f, " of {} options", self.available)
+    }
+}
+
+impl std::error::Error for SelectionConstraintError {}
@@ -1,12 +1,33 @@
 //! 显示格式化器模块
 //!
-//! 提供显示相关的格式化功能，包括路径、列表项、键值对和文件大小的格式化。
+//! 提供显示相关的格式化功能，包括路径、列表项、键值对、文件大小和相对时间的格式化。
 
+use std::fmt::Display;
 use std::path::Path;
 
+use chrono::{DateTime, TimeZone, Utc};
+
+/// 未来时间在此范围内（秒）视为时钟偏差，显示为 "just now"
+pub const CLOCK_SKEW_TOLERANCE_SECS: u64 = 60;
+
+/// 过去时间在此范围内（秒）显示为 "just now"
+const JUST_NOW_SECS: u64 = 10;
+
+/// 超过此天数时不再显示相对时间，改为显示日期
+pub const MAX_RELATIVE_DAYS: u64 = 30;
+
+/// 相对时间的粒度（单位名称、秒数），从大到小排列
+const RELATIVE_TIME_UNITS: &[(&str, u64)] = &[
+    ("week", 7 * 24 * 60 * 60),
+    ("day", 24 * 60 * 60),
+    ("hour", 60 * 60),
+    ("minute", 60),
+    ("second", 1),
+];
+
 /// 显示格式化器
 ///
-/// 提供统一的显示格式化功能，包括路径、列表项、键值对和文件大小的格式化。
+/// 提供统一的显示格式化功能，包括路径、列表项、键值对、文件大小和相对时间的格式化。
 ///
 /// # 示例
 ///
@@ -155,6 +176,90 @@ impl DisplayFormatter {
             format!("{:.2} {}", size, UNITS[unit_index])
         }
     }
+
+    /// 格式化相对时间
+    ///
+    /// 相对于当前时间显示（如 "3 hours ago"），规则见 `relative_time_from`。
+    ///
+    /// # 参数
+    ///
+    /// * `timestamp` - 要显示的时间（任意时区）
+    ///
+    /// # 返回值
+    ///
+    /// 格式化后的相对时间字符串
+    pub fn relative_time<Tz>(timestamp: &DateTime<Tz>) -> String
+    where
+        Tz: TimeZone,
+        Tz::Offset: Display,
+    {
+        Self::relative_time_from(timestamp, &Utc::now())
+    }
+
+    /// 相对于指定时间格式化相对时间
+    ///
+    /// - 10 秒以内显示 "just now"；未来 60 秒以内同样显示 "just now"（容忍服务器和本机的时钟偏差）
+    /// - 按秒、分钟、小时、天、周取最大的整数单位（如 "1 minute ago"、"2 weeks ago"）
+    /// - 更远的未来时间显示为 "in 5 minutes"
+    /// - 超过 30 天时显示日期（`YYYY-MM-DD`，使用时间戳自身的时区）
+    ///
+    /// # 参数
+    ///
+    /// * `timestamp` - 要显示的时间
+    /// * `now` - 当前时间
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use chrono::{Duration, TimeZone, Utc};
+    /// use workflow::base::format::DisplayFormatter;
+    ///
+    /// let now = Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap();
+    /// let format = |offset: Duration| DisplayFormatter::relative_time_from(&(now - offset), &now);
+    ///
+    /// assert_eq!(format(Duration::seconds(3)), "just now");
+    /// assert_eq!(format(Duration::seconds(-30)), "just now");
+    /// assert_eq!(format(Duration::minutes(1)), "1 minute ago");
+    /// assert_eq!(format(Duration::hours(3)), "3 hours ago");
+    /// assert_eq!(format(Duration::days(15)), "2 weeks ago");
+    /// assert_eq!(format(Duration::minutes(-5)), "in 5 minutes");
+    /// assert_eq!(format(Duration::days(45)), "2024-04-17");
+    /// ```
+    pub fn relative_time_from<Tz>(timestamp: &DateTime<Tz>, now: &DateTime<Utc>) -> String
+    where
+        Tz: TimeZone,
+        Tz::Offset: Display,
+    {
+        let elapsed = now.signed_duration_since(timestamp).num_seconds();
+        let future = elapsed < 0;
+        let seconds = elapsed.unsigned_abs();
+
+        let tolerance = if future {
+            CLOCK_SKEW_TOLERANCE_SECS
+        } else {
+            JUST_NOW_SECS
+        };
+        if seconds <= tolerance {
+            return "just now".to_string();
+        }
+        if seconds > MAX_RELATIVE_DAYS * 24 * 60 * 60 {
+            return timestamp.format("%Y-%m-%d").to_string();
+        }
+
+        let (unit, unit_seconds) = RELATIVE_TIME_UNITS
+            .iter()
+            .find(|(_, unit_seconds)| seconds >= *unit_seconds)
+            .copied()
+            .unwrap_or(("second", 1));
+        let count = seconds / unit_seconds;
+        let plural = if count == 1 { "" } else { "s" };
+
+        if future {
+            format!("in {} {}{}", count, unit, plural)
+        } else {
+            format!("{} {}{} ago", count, unit, plural)
+        }
+    }
 }
 
 #[cfg(test)]
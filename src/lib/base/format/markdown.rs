@@ -0,0 +1,330 @@
+//! Markdown 终端渲染
+//!
+//! PR 描述、PR 总结等内容使用 Markdown，直接输出时会显示大量标记符号。
+//! `DisplayFormatter::render_markdown` 将常用的 Markdown 语法转换为终端中易读的形式：
+//! - 标题（`#` ~ `######`）→ 粗体
+//! - 代码块（```` ``` ````、`~~~`）→ 缩进显示，保留原有空白，不处理其中的标记
+//! - 列表（`-`、`*`、`+`、`1.`，可嵌套）→ 按层级缩进，无序列表使用 `•`，有序列表保留序号
+//! - 引用（`>`）→ 行首添加 `│`
+//! - 分隔线（`---`、`***`、`___`）→ 分隔线
+//! - 行内格式：`**粗体**`、`*斜体*`、`~~删除线~~`、`` `代码` ``、`[文本](链接)`、`![图片](链接)`、`<链接>`
+//!
+//! 颜色和样式通过 `styled()` 生成。输出不是终端（或禁用颜色输出）时不生成样式，
+//! 渲染结果为去除标记后的纯文本。
+
+use std::sync::OnceLock;
+
+use regex::{Captures, Regex};
+
+use super::DisplayFormatter;
+use crate::base::logger::console::{separator, styled};
+
+/// 分隔线的渲染长度
+const RULE_WIDTH: usize = 40;
+
+/// 代码块的缩进
+const CODE_INDENT: &str = "    ";
+
+/// 引用的行首标记
+const QUOTE_PREFIX: &str = "│ ";
+
+/// 列表每层的缩进
+const LIST_INDENT: &str = "  ";
+
+/// 行内格式的正则表达式
+struct InlinePatterns {
+    /// 不处理其中标记的片段：行内代码、图片、链接、自动链接
+    atom: Regex,
+    bold: Regex,
+    strikethrough: Regex,
+    italic: Regex,
+}
+
+impl InlinePatterns {
+    fn get() -> &'static InlinePatterns {
+        static PATTERNS: OnceLock<InlinePatterns> = OnceLock::new();
+        PATTERNS.get_or_init(|| InlinePatterns {
+            atom: Regex::new(concat!(
+                r"``\s?(?P<code2>.+?)\s?``",
+                r"|`(?P<code1>[^`]+)`",
+                r#"|!\[(?P<alt>[^\]]*)\]\((?P<src>[^)\s]+)(?:\s+"[^"]*")?\)"#,
+                r#"|\[(?P<text>[^\]]+)\]\((?P<href>[^)\s]+)(?:\s+"[^"]*")?\)"#,
+                r"|<(?P<auto>(?:https?|mailto):[^>\s]+)>",
+                r"|(?P<bare>https?://[^\s<>()]*[^\s<>().,;:!?'`])",
+            ))
+            .expect("Failed to compile markdown atom regex"),
+            bold: Regex::new(
+                r"(^|[^\w*_])(?:\*\*([^*\s](?:.*?[^*\s])?)\*\*|__([^_\s](?:.*?[^_\s])?)__)",
+            )
+            .expect("Failed to compile bold regex"),
+            strikethrough: Regex::new(r"(^|[^~])~~([^~\s](?:[^~]*?[^~\s])?)~~")
+                .expect("Failed to compile strikethrough regex"),
+            italic: Regex::new(
+                r"(^|[^\w*_])(?:\*([^*\s](?:[^*]*?[^*\s])?)\*|_([^_\s](?:[^_]*?[^_\s])?)_)",
+            )
+            .expect("Failed to compile italic regex"),
+        })
+    }
+}
+
+/// 正在处理的代码块（开始标记的字符和长度，结束标记至少同样长）
+#[derive(Debug, Clone, Copy)]
+struct Fence {
+    marker: char,
+    len: usize,
+}
+
+impl DisplayFormatter {
+    /// 将 Markdown 渲染为终端文本
+    ///
+    /// # 参数
+    ///
+    /// * `text` - Markdown 内容（如 PR 描述）
+    ///
+    /// # 返回
+    ///
+    /// 渲染后的文本（代码块的开始和结束标记行会被去除，其他行与原文一一对应）。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::base::format::DisplayFormatter;
+    /// use workflow::base::logger::console::set_colors_enabled;
+    ///
+    /// set_colors_enabled(false);
+    /// assert_eq!(DisplayFormatter::render_markdown("## Summary"), "Summary");
+    /// assert_eq!(
+    ///     DisplayFormatter::render_markdown("- **Fix** the [docs](https://example.com)"),
+    ///     "• Fix the docs (https://example.com)"
+    /// );
+    /// ```
+    pub fn render_markdown(text: &str) -> String {
+        let mut lines = Vec::new();
+        let mut fence: Option<Fence> = None;
+
+        for line in text.lines() {
+            let trimmed = line.trim();
+
+            if let Some(open) = fence {
+                if closes_fence(trimmed, open) {
+                    fence = None;
+                } else if line.is_empty() {
+                    lines.push(String::new());
+                } else {
+                    lines.push(format!("{}{}", CODE_INDENT, styled(line).cyan()));
+                }
+                continue;
+            }
+            if let Some(open) = opens_fence(trimmed) {
+                fence = Some(open);
+                continue;
+            }
+
+            lines.push(render_line(line));
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// 识别代码块开始标记（至少 3 个 `` ` `` 或 `~`，后面可以跟语言名称）
+fn opens_fence(trimmed: &str) -> Option<Fence> {
+    let marker = trimmed.chars().next().filter(|c| matches!(c, '`' | '~'))?;
+    let len = trimmed.chars().take_while(|c| *c == marker).count();
+    // 反引号代码块的语言名称中不能包含反引号（否则是行内代码）
+    let info = &trimmed[len..];
+    (len >= 3 && !(marker == '`' && info.contains('`'))).then_some(Fence { marker, len })
+}
+
+/// 判断是否为代码块结束标记
+fn closes_fence(trimmed: &str, fence: Fence) -> bool {
+    let len = trimmed.chars().take_while(|c| *c == fence.marker).count();
+    len >= fence.len && trimmed[len..].trim().is_empty()
+}
+
+/// 渲染代码块以外的一行（标题、分隔线、引用、列表和段落）
+fn render_line(line: &str) -> String {
+    let trimmed = line.trim();
+
+    if is_rule(trimmed) {
+        return separator('-', RULE_WIDTH);
+    }
+    if let Some(heading) = heading(trimmed) {
+        return styled(render_inline(heading)).bold().to_string();
+    }
+    if let Some((depth, quote)) = blockquote(trimmed) {
+        let prefix = styled(QUOTE_PREFIX.repeat(depth)).dim();
+        return format!("{}{}", prefix, render_line(quote));
+    }
+    if let Some((depth, marker, item)) = list_item(line) {
+        let marker = if marker.ends_with(['.', ')']) {
+            marker.to_string()
+        } else {
+            "•".to_string()
+        };
+        return format!(
+            "{}{} {}",
+            LIST_INDENT.repeat(depth),
+            marker,
+            render_inline(item)
+        );
+    }
+    render_inline(trimmed)
+}
+
+/// 识别分隔线（3 个以上相同的 `-`、`*` 或 `_`，中间可以有空格）
+fn is_rule(trimmed: &str) -> bool {
+    let chars: Vec<char> = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+    chars.len() >= 3 && matches!(chars[0], '-' | '*' | '_') && chars.iter().all(|c| *c == chars[0])
+}
+
+/// 识别标题（`#` ~ `######` 后跟空白），返回去除结尾 `#` 的标题文本
+fn heading(trimmed: &str) -> Option<&str> {
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let rest = rest.trim();
+    let without_closing = rest.trim_end_matches('#');
+    Some(
+        if without_closing.is_empty() || without_closing.ends_with(' ') {
+            without_closing.trim_end()
+        } else {
+            rest
+        },
+    )
+}
+
+/// 识别引用，返回（层级、内容）
+fn blockquote(trimmed: &str) -> Option<(usize, &str)> {
+    let mut rest = trimmed.strip_prefix('>')?;
+    let mut depth = 1;
+    loop {
+        let next = rest.trim_start();
+        match next.strip_prefix('>') {
+            Some(inner) => {
+                rest = inner;
+                depth += 1;
+            }
+            None => return Some((depth, next)),
+        }
+    }
+}
+
+/// 识别列表项，返回（层级、标记、内容）
+///
+/// 层级由行首缩进决定（每 2 个空格一层，制表符按 4 个空格计算）；
+/// 标记后必须有空白，避免将 `*斜体*` 开头的段落识别为列表。
+fn list_item(line: &str) -> Option<(usize, &str, &str)> {
+    let content = line.trim_start();
+    let indent: usize = line[..line.len() - content.len()]
+        .chars()
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum();
+
+    let marker_len = match content.chars().next()? {
+        '-' | '*' | '+' => 1,
+        c if c.is_ascii_digit() => {
+            let digits = content.chars().take_while(char::is_ascii_digit).count();
+            if digits > 9 || !content[digits..].starts_with(['.', ')']) {
+                return None;
+            }
+            digits + 1
+        }
+        _ => return None,
+    };
+
+    let rest = &content[marker_len..];
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    Some((indent / 2, &content[..marker_len], rest.trim_start()))
+}
+
+/// 渲染行内格式
+///
+/// 行内代码、图片和链接中的内容不处理粗体、斜体等标记（避免破坏代码和链接中的 `_`、`*`）。
+fn render_inline(text: &str) -> String {
+    let patterns = InlinePatterns::get();
+    let mut output = String::new();
+    let mut last = 0;
+
+    for caps in patterns.atom.captures_iter(text) {
+        let whole = caps.get(0).expect("group 0 always matches");
+        output.push_str(&render_emphasis(&text[last..whole.start()]));
+        output.push_str(&render_atom(&caps));
+        last = whole.end();
+    }
+    output.push_str(&render_emphasis(&text[last..]));
+    output
+}
+
+/// 渲染行内代码、图片和链接
+///
+/// 链接显示为 `文本 (链接)`；文本与链接相同时只显示链接。
+fn render_atom(caps: &Captures) -> String {
+    if let Some(code) = caps.name("code2").or_else(|| caps.name("code1")) {
+        return styled(code.as_str()).cyan().to_string();
+    }
+    if let (Some(alt), Some(src)) = (caps.name("alt"), caps.name("src")) {
+        let label = match alt.as_str().trim() {
+            "" => "[image]".to_string(),
+            alt => format!("[image: {}]", alt),
+        };
+        return format!(
+            "{} ({})",
+            styled(label).dim(),
+            styled(src.as_str()).underlined()
+        );
+    }
+    if let (Some(text), Some(href)) = (caps.name("text"), caps.name("href")) {
+        if text.as_str() == href.as_str() {
+            return styled(href.as_str()).underlined().to_string();
+        }
+        return format!(
+            "{} ({})",
+            render_emphasis(text.as_str()),
+            styled(href.as_str()).underlined()
+        );
+    }
+    let url = caps.name("auto").or_else(|| caps.name("bare"));
+    url.map(|url| styled(url.as_str()).underlined().to_string()).unwrap_or_default()
+}
+
+/// 渲染粗体、删除线和斜体
+fn render_emphasis(text: &str) -> String {
+    let patterns = InlinePatterns::get();
+    let text = replace_emphasis(&patterns.bold, text, |inner| {
+        styled(inner).bold().to_string()
+    });
+    let text = replace_emphasis(&patterns.strikethrough, &text, |inner| {
+        styled(inner).strikethrough().to_string()
+    });
+    replace_emphasis(&patterns.italic, &text, |inner| {
+        styled(inner).italic().to_string()
+    })
+}
+
+/// 替换成对的格式标记
+///
+/// 内容在第 2 或第 3 个捕获组中（分别对应 `*` 和 `_` 形式）。
+/// 结束标记后紧跟字母或数字时（如 `snake_case_name`）不视为格式标记，保留原文。
+fn replace_emphasis(pattern: &Regex, text: &str, render: impl Fn(&str) -> String) -> String {
+    pattern
+        .replace_all(text, |caps: &Captures| {
+            let whole = caps.get(0).expect("group 0 always matches");
+            let followed_by_word =
+                text[whole.end()..].chars().next().is_some_and(char::is_alphanumeric);
+            match caps.get(2).or_else(|| caps.get(3)) {
+                Some(inner) if !followed_by_word => {
+                    format!("{}{}", &caps[1], render(inner.as_str()))
+                }
+                _ => whole.as_str().to_string(),
+            }
+        })
+        .into_owned()
+}
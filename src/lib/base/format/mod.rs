@@ -6,8 +6,10 @@
 //!
 //! - `message` - 消息格式化器（错误消息、操作消息、进度信息）
 //! - `display` - 显示格式化器（路径、列表项、键值对、文件大小）
+//! - `markdown` - Markdown 终端渲染（`DisplayFormatter::render_markdown`）
 
 pub mod display;
+mod markdown;
 pub mod message;
 
 // 重新导出子模块的结构体
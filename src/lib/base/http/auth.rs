@@ -1,14 +1,25 @@
-//! Basic Authentication 认证信息
+//! HTTP 认证信息（Basic Authentication 和 Bearer token）
 
-/// Basic Authentication 认证信息
+use reqwest::blocking::RequestBuilder;
+
+/// HTTP 认证信息
 ///
-/// 用于 HTTP Basic Authentication 的用户名和密码。
+/// - `Basic`：HTTP Basic Authentication 的用户名和密码（如 Jira 的邮箱 + API token）
+/// - `Bearer`：`Authorization: Bearer <token>`（如 Personal Access Token、OAuth 2.0 access token）
 #[derive(Debug, Clone)]
-pub struct Authorization {
-    /// 用户名（通常是邮箱地址）
-    pub username: String,
-    /// 密码（通常是 API token）
-    pub password: String,
+pub enum Authorization {
+    /// HTTP Basic Authentication
+    Basic {
+        /// 用户名（通常是邮箱地址）
+        username: String,
+        /// 密码（通常是 API token）
+        password: String,
+    },
+    /// Bearer token
+    Bearer {
+        /// Access token
+        token: String,
+    },
 }
 
 impl Authorization {
@@ -23,11 +34,42 @@ impl Authorization {
     ///
     /// # 返回
     ///
-    /// 返回 `Authorization` 结构体。
+    /// 返回 `Authorization::Basic`。
     pub fn new(username: impl Into<String>, password: impl Into<String>) -> Self {
-        Self {
+        Self::Basic {
             username: username.into(),
             password: password.into(),
         }
     }
+
+    /// 创建 Bearer token 认证信息
+    ///
+    /// # 参数
+    ///
+    /// * `token` - Access token（如 Personal Access Token）
+    ///
+    /// # 返回
+    ///
+    /// 返回 `Authorization::Bearer`。
+    pub fn bearer(token: impl Into<String>) -> Self {
+        Self::Bearer {
+            token: token.into(),
+        }
+    }
+
+    /// 将认证信息添加到请求
+    ///
+    /// # 参数
+    ///
+    /// * `request` - 请求构建器
+    ///
+    /// # 返回
+    ///
+    /// 返回添加了 `Authorization` Header 的请求构建器。
+    pub fn apply(&self, request: RequestBuilder) -> RequestBuilder {
+        match self {
+            Self::Basic { username, password } => request.basic_auth(username, Some(password)),
+            Self::Bearer { token } => request.bearer_auth(token),
+        }
+    }
 }
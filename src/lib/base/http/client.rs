@@ -4,16 +4,31 @@
 //! 支持 Basic Authentication 和自定义 Headers。
 
 use color_eyre::{eyre::eyre, eyre::WrapErr, Result};
-use reqwest::blocking::Client;
+use reqwest::blocking::{Body, Client};
+use reqwest::header::CONTENT_TYPE;
 use serde::Serialize;
 use serde_json::Value;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::OnceLock;
 use std::time::Duration;
 
 use super::config::{MultipartRequestConfig, RequestConfig};
+use super::error::HttpRequestError;
 use super::method::HttpMethod;
+use super::multipart::{MultipartBody, MultipartFile};
 use super::response::HttpResponse;
+use super::trace::HttpTrace;
 use crate::base::constants::errors::http_client;
+use crate::base::settings::Settings;
+
+/// 进程级默认超时时间（秒，0 表示使用配置文件中的 `http.timeout_secs`）
+static DEFAULT_TIMEOUT_SECS: AtomicU64 = AtomicU64::new(0);
+
+/// Jira 跳过 XSRF 检查所需的 Header（multipart 请求默认带上）
+const ATLASSIAN_TOKEN_HEADER: &str = "X-Atlassian-Token";
+
+/// `X-Atlassian-Token` 的值
+const ATLASSIAN_TOKEN_NO_CHECK: &str = "no-check";
 
 /// HTTP 客户端
 ///
@@ -82,6 +97,26 @@ impl HttpClient {
             .map_err(|e| eyre!("{}: {}", http_client::CREATE_CLIENT_FAILED, e))
     }
 
+    /// 设置当前进程的默认超时时间
+    ///
+    /// 覆盖配置文件中的 `http.timeout_secs`，对未指定 `RequestConfig.timeout` 的请求生效。
+    /// 适用于需要快速返回的命令（如 `pr status`）。
+    /// 传入 `Duration::ZERO` 时恢复使用配置文件中的值。
+    pub fn set_default_timeout(timeout: Duration) {
+        DEFAULT_TIMEOUT_SECS.store(timeout.as_secs(), Ordering::Relaxed);
+    }
+
+    /// 获取默认超时时间
+    ///
+    /// 优先使用 `set_default_timeout` 设置的值，否则使用配置文件中的 `http.timeout_secs`
+    /// （未配置时为 30 秒）。
+    pub fn default_timeout() -> Duration {
+        match DEFAULT_TIMEOUT_SECS.load(Ordering::Relaxed) {
+            0 => Settings::get().http.timeout(),
+            secs => Duration::from_secs(secs),
+        }
+    }
+
     /// 构建 HTTP 请求（内部辅助方法）
     ///
     /// 根据指定的 HTTP 方法、URL 和配置构建请求。
@@ -90,7 +125,7 @@ impl HttpClient {
     ///
     /// * `method` - HTTP 方法
     /// * `url` - 请求 URL
-    /// * `config` - 请求配置，包含可选的请求体、查询参数、认证信息、Headers 和超时时间
+    /// * `config` - 请求配置，包含可选的请求体、查询参数、认证信息和 Headers
     ///
     /// # 类型参数
     ///
@@ -130,7 +165,7 @@ impl HttpClient {
 
         // 添加 auth
         if let Some(auth) = config.auth {
-            request = request.basic_auth(&auth.username, Some(&auth.password));
+            request = auth.apply(request);
         }
 
         // 添加 headers
@@ -140,13 +175,37 @@ impl HttpClient {
             }
         }
 
-        // 设置超时（如果提供了则使用，否则使用默认 30 秒）
-        let timeout_duration = config.timeout.unwrap_or_else(|| Duration::from_secs(30));
-        request = request.timeout(timeout_duration);
-
         request
     }
 
+    /// 发送请求
+    ///
+    /// 设置超时时间（未指定时使用 `default_timeout()`）后发送请求。
+    /// 启用 HTTP 跟踪时先构建请求并记录，再发送。
+    ///
+    /// # 错误
+    ///
+    /// 请求失败时返回 `HttpRequestError`，可以区分超时和连接失败。
+    fn send(
+        &self,
+        method: HttpMethod,
+        url: &str,
+        timeout: Option<Duration>,
+        request: reqwest::blocking::RequestBuilder,
+    ) -> Result<reqwest::blocking::Response> {
+        let timeout = timeout.unwrap_or_else(Self::default_timeout);
+        let request = request.timeout(timeout);
+        let result = if HttpTrace::is_enabled() {
+            request.build().and_then(|request| {
+                HttpTrace::trace_request(&request);
+                self.client.execute(request)
+            })
+        } else {
+            request.send()
+        };
+        result.map_err(|e| eyre!(HttpRequestError::new(method, url, timeout, e)))
+    }
+
     /// GET 请求
     ///
     /// # 示例
@@ -167,10 +226,12 @@ impl HttpClient {
     where
         Q: Serialize + ?Sized,
     {
-        let response = self
-            .build_request(HttpMethod::Get, url, config)
-            .send()
-            .wrap_err_with(|| format!("Failed to send GET request to: {}", url))?;
+        let response = self.send(
+            HttpMethod::Get,
+            url,
+            config.timeout,
+            self.build_request(HttpMethod::Get, url, config),
+        )?;
 
         HttpResponse::from_reqwest_response(response)
     }
@@ -196,10 +257,12 @@ impl HttpClient {
         B: Serialize,
         Q: Serialize + ?Sized,
     {
-        let response = self
-            .build_request(HttpMethod::Post, url, config)
-            .send()
-            .wrap_err_with(|| format!("Failed to send POST request to: {}", url))?;
+        let response = self.send(
+            HttpMethod::Post,
+            url,
+            config.timeout,
+            self.build_request(HttpMethod::Post, url, config),
+        )?;
 
         HttpResponse::from_reqwest_response(response)
     }
@@ -225,10 +288,12 @@ impl HttpClient {
         B: Serialize,
         Q: Serialize + ?Sized,
     {
-        let response = self
-            .build_request(HttpMethod::Put, url, config)
-            .send()
-            .wrap_err_with(|| format!("Failed to send PUT request to: {}", url))?;
+        let response = self.send(
+            HttpMethod::Put,
+            url,
+            config.timeout,
+            self.build_request(HttpMethod::Put, url, config),
+        )?;
 
         HttpResponse::from_reqwest_response(response)
     }
@@ -252,10 +317,12 @@ impl HttpClient {
     where
         Q: Serialize + ?Sized,
     {
-        let response = self
-            .build_request(HttpMethod::Delete, url, config)
-            .send()
-            .wrap_err_with(|| format!("Failed to send DELETE request to: {}", url))?;
+        let response = self.send(
+            HttpMethod::Delete,
+            url,
+            config.timeout,
+            self.build_request(HttpMethod::Delete, url, config),
+        )?;
 
         HttpResponse::from_reqwest_response(response)
     }
@@ -281,10 +348,12 @@ impl HttpClient {
         B: Serialize,
         Q: Serialize + ?Sized,
     {
-        let response = self
-            .build_request(HttpMethod::Patch, url, config)
-            .send()
-            .wrap_err_with(|| format!("Failed to send PATCH request to: {}", url))?;
+        let response = self.send(
+            HttpMethod::Patch,
+            url,
+            config.timeout,
+            self.build_request(HttpMethod::Patch, url, config),
+        )?;
 
         HttpResponse::from_reqwest_response(response)
     }
@@ -318,19 +387,35 @@ impl HttpClient {
         B: Serialize,
         Q: Serialize + ?Sized,
     {
-        self.build_request(method, url, config)
-            .send()
-            .wrap_err_with(|| format!("Failed to send {} request to: {}", method, url))
+        let response = self.send(
+            method,
+            url,
+            config.timeout,
+            self.build_request(method, url, config),
+        )?;
+
+        // 流式响应的响应体由调用方读取，只记录状态码和 Headers
+        if HttpTrace::is_enabled() {
+            HttpTrace::trace_response(
+                response.url().as_str(),
+                response.status().as_u16(),
+                response.headers(),
+                None,
+            );
+        }
+        Ok(response)
     }
 
     /// 构建 Multipart 请求（内部辅助方法）
     ///
-    /// 根据指定的 URL 和配置构建 multipart 请求。
+    /// 设置 multipart 请求体、`Content-Type`（包含 boundary）和 `X-Atlassian-Token: no-check`，
+    /// 再添加配置中的查询参数、认证信息和 Headers（配置中的 Headers 可以覆盖默认值）。
     ///
     /// # 参数
     ///
     /// * `url` - 请求 URL
-    /// * `config` - Multipart 请求配置，包含 multipart form 数据、查询参数、认证信息、Headers 和超时时间
+    /// * `body` - multipart 请求体
+    /// * `config` - Multipart 请求配置，包含查询参数、认证信息和 Headers
     ///
     /// # 类型参数
     ///
@@ -342,17 +427,20 @@ impl HttpClient {
     fn build_multipart_request<Q>(
         &self,
         url: &str,
-        mut config: MultipartRequestConfig<Q>,
+        body: MultipartBody,
+        config: MultipartRequestConfig<Q>,
     ) -> reqwest::blocking::RequestBuilder
     where
         Q: Serialize,
     {
-        let mut request = self.client.post(url);
-
-        // 添加 multipart form 数据（必须）
-        if let Some(multipart) = config.multipart.take() {
-            request = request.multipart(multipart);
-        }
+        let content_type = body.content_type();
+        let content_length = body.content_length();
+        let mut request = self
+            .client
+            .post(url)
+            .header(CONTENT_TYPE, content_type)
+            .header(ATLASSIAN_TOKEN_HEADER, ATLASSIAN_TOKEN_NO_CHECK)
+            .body(Body::sized(body.into_reader(), content_length));
 
         // 添加 query 参数
         if let Some(query) = config.query {
@@ -361,7 +449,7 @@ impl HttpClient {
 
         // 添加 auth
         if let Some(auth) = config.auth {
-            request = request.basic_auth(&auth.username, Some(&auth.password));
+            request = auth.apply(request);
         }
 
         // 添加 headers
@@ -371,21 +459,23 @@ impl HttpClient {
             }
         }
 
-        // 设置超时（如果提供了则使用，否则使用默认 30 秒）
-        let timeout_duration = config.timeout.unwrap_or_else(|| Duration::from_secs(30));
-        request = request.timeout(timeout_duration);
-
         request
     }
 
     /// POST Multipart 请求
     ///
-    /// 发送 multipart/form-data 请求，通常用于文件上传。
+    /// 发送 `multipart/form-data` 请求，通常用于文件上传。文件内容在发送时从磁盘流式读取，
+    /// 不会整个读入内存。
+    ///
+    /// 请求会带上 `X-Atlassian-Token: no-check`：Jira 的附件接口没有这个 Header 时会因
+    /// XSRF 检查拒绝请求，其他服务会忽略它。
     ///
     /// # 参数
     ///
     /// * `url` - 请求 URL
-    /// * `config` - Multipart 请求配置
+    /// * `fields` - 文本字段（名称、值）
+    /// * `files` - 要上传的文件
+    /// * `config` - Multipart 请求配置（查询参数、认证信息、Headers、超时时间）
     ///
     /// # 类型参数
     ///
@@ -397,35 +487,43 @@ impl HttpClient {
     ///
     /// # 错误
     ///
-    /// 如果请求失败，返回相应的错误信息。
+    /// 文件无法打开或请求失败时返回相应的错误信息。
     ///
     /// # 示例
     ///
     /// ```rust,no_run
-    /// use workflow::base::http::{HttpClient, MultipartRequestConfig};
-    /// use reqwest::blocking::multipart;
+    /// use workflow::base::http::{HttpClient, MultipartFile, MultipartRequestConfig};
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
     /// let client = HttpClient::global()?;
-    /// let form = multipart::Form::new();
-    /// let config = MultipartRequestConfig::<serde_json::Value>::new()
-    ///     .multipart(form);
-    /// let response = client.post_multipart("https://api.example.com/upload", config)?;
+    /// let files = [MultipartFile::new("file", "build.log").content_type("text/plain")];
+    /// let config = MultipartRequestConfig::<serde_json::Value>::new();
+    /// let response = client.post_multipart(
+    ///     "https://api.example.com/upload",
+    ///     &[("comment", "nightly build")],
+    ///     &files,
+    ///     config,
+    /// )?;
     /// # Ok(())
     /// # }
     /// ```
     pub fn post_multipart<Q>(
         &self,
         url: &str,
+        fields: &[(&str, &str)],
+        files: &[MultipartFile],
         config: MultipartRequestConfig<Q>,
     ) -> Result<HttpResponse>
     where
         Q: Serialize,
     {
-        let response = self
-            .build_multipart_request(url, config)
-            .send()
-            .wrap_err_with(|| format!("Failed to send POST multipart request to: {}", url))?;
+        let body = MultipartBody::new(fields, files)?;
+        let response = self.send(
+            HttpMethod::Post,
+            url,
+            config.timeout,
+            self.build_multipart_request(url, body, config),
+        )?;
 
         HttpResponse::from_reqwest_response(response)
     }
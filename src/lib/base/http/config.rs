@@ -1,6 +1,5 @@
 //! HTTP 请求配置
 
-use reqwest::blocking::multipart;
 use reqwest::header::HeaderMap;
 use serde::Serialize;
 use std::time::Duration;
@@ -33,7 +32,7 @@ pub struct RequestConfig<'a, B, Q: ?Sized> {
     pub auth: Option<&'a Authorization>,
     /// 可选的自定义 HTTP Headers
     pub headers: Option<&'a HeaderMap>,
-    /// 可选的请求超时时间（如果为 None，使用默认 30 秒）
+    /// 可选的请求超时时间（如果为 None，使用 `HttpClient::default_timeout()`）
     pub timeout: Option<Duration>,
 }
 
@@ -49,6 +48,16 @@ impl<'a, B, Q: ?Sized> Default for RequestConfig<'a, B, Q> {
     }
 }
 
+// 所有字段都是引用或 `Copy` 类型，手动实现以避免要求 `B`、`Q` 实现 `Clone`，
+// 便于在重试时重复使用同一份请求配置
+impl<'a, B, Q: ?Sized> Clone for RequestConfig<'a, B, Q> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, B, Q: ?Sized> Copy for RequestConfig<'a, B, Q> {}
+
 impl<'a, B, Q: ?Sized> RequestConfig<'a, B, Q> {
     /// 创建新的 RequestConfig，使用默认值
     ///
@@ -126,7 +135,7 @@ impl<'a, B, Q: ?Sized> RequestConfig<'a, B, Q> {
     ///
     /// # 参数
     ///
-    /// * `auth` - Basic Authentication 认证信息
+    /// * `auth` - 认证信息（Basic Authentication 或 Bearer token）
     ///
     /// # 返回
     ///
@@ -204,40 +213,35 @@ impl<'a, B, Q: ?Sized> RequestConfig<'a, B, Q> {
 
 /// Multipart 请求配置
 ///
-/// 用于 multipart/form-data 请求的配置，支持文件上传等功能。
+/// 用于 multipart/form-data 请求的配置（字段和文件由 `HttpClient::post_multipart` 的参数指定）。
 ///
 /// # 示例
 ///
 /// ```rust,no_run
-/// use workflow::base::http::{HttpClient, MultipartRequestConfig};
-/// use reqwest::blocking::multipart;
+/// use workflow::base::http::{HttpClient, MultipartFile, MultipartRequestConfig};
 ///
 /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
 /// let client = HttpClient::global()?;
-/// let form = multipart::Form::new();
-/// let config = MultipartRequestConfig::<()>::new()
-///     .multipart(form);
-/// let response = client.post_multipart("https://api.example.com/upload", config)?;
+/// let files = [MultipartFile::new("file", "build.log")];
+/// let config = MultipartRequestConfig::<()>::new();
+/// let response = client.post_multipart("https://api.example.com/upload", &[], &files, config)?;
 /// # Ok(())
 /// # }
 /// ```
 pub struct MultipartRequestConfig<Q> {
-    /// Multipart form 数据
-    pub multipart: Option<multipart::Form>,
     /// 可选的查询参数（实现 `Serialize` trait）
     pub query: Option<Q>,
     /// 可选的 Basic Authentication 认证信息
     pub auth: Option<Authorization>,
     /// 可选的自定义 HTTP Headers
     pub headers: Option<HeaderMap>,
-    /// 可选的请求超时时间（如果为 None，使用默认 30 秒）
+    /// 可选的请求超时时间（如果为 None，使用 `HttpClient::default_timeout()`）
     pub timeout: Option<Duration>,
 }
 
 impl<Q> Default for MultipartRequestConfig<Q> {
     fn default() -> Self {
         Self {
-            multipart: None,
             query: None,
             auth: None,
             headers: None,
@@ -264,31 +268,6 @@ impl<Q> MultipartRequestConfig<Q> {
         Self::default()
     }
 
-    /// 设置 multipart form 数据
-    ///
-    /// # 参数
-    ///
-    /// * `multipart` - Multipart form 数据
-    ///
-    /// # 返回
-    ///
-    /// 返回 `Self`，支持链式调用。
-    ///
-    /// # 示例
-    ///
-    /// ```rust,no_run
-    /// use workflow::base::http::MultipartRequestConfig;
-    /// use reqwest::blocking::multipart;
-    ///
-    /// let form = multipart::Form::new();
-    /// let config = MultipartRequestConfig::<serde_json::Value>::new()
-    ///     .multipart(form);
-    /// ```
-    pub fn multipart(mut self, multipart: multipart::Form) -> Self {
-        self.multipart = Some(multipart);
-        self
-    }
-
     /// 设置查询参数
     ///
     /// # 参数
@@ -310,7 +289,7 @@ impl<Q> MultipartRequestConfig<Q> {
     ///
     /// # 参数
     ///
-    /// * `auth` - Basic Authentication 认证信息
+    /// * `auth` - 认证信息（Basic Authentication 或 Bearer token）
     ///
     /// # 返回
     ///
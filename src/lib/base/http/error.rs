@@ -0,0 +1,155 @@
+//! HTTP 请求错误
+//!
+//! 请求没有收到响应时（超时、无法建立连接等），`HttpClient` 返回 `HttpRequestError`，
+//! 调用方可以通过 `downcast_ref::<HttpRequestError>()` 区分超时和连接失败，给出不同的提示。
+//! 连接失败时还可以通过 `is_dns_failure` / `is_tls_failure` 进一步区分 DNS 解析失败和 TLS 错误。
+
+use std::fmt;
+use std::time::Duration;
+
+use super::method::HttpMethod;
+
+/// HTTP 请求错误类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpRequestErrorKind {
+    /// 请求超时
+    Timeout,
+    /// 无法建立连接（DNS 解析失败、连接被拒绝等）
+    Connect,
+    /// 其他错误
+    Other,
+}
+
+/// HTTP 请求错误
+///
+/// # 示例
+///
+/// ```rust,no_run
+/// use serde_json::Value;
+/// use workflow::base::http::{HttpClient, HttpRequestError, RequestConfig};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = HttpClient::global()?;
+/// if let Err(e) = client.get("https://api.example.com", RequestConfig::<Value, Value>::new()) {
+///     match e.downcast_ref::<HttpRequestError>() {
+///         Some(error) if error.is_timeout() => eprintln!("Request timed out"),
+///         Some(error) if error.is_connect() => eprintln!("Check your network connection"),
+///         _ => eprintln!("{}", e),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct HttpRequestError {
+    /// 错误类型
+    pub kind: HttpRequestErrorKind,
+    /// HTTP 方法
+    pub method: HttpMethod,
+    /// 请求 URL
+    pub url: String,
+    /// 请求使用的超时时间
+    pub timeout: Duration,
+    source: reqwest::Error,
+}
+
+impl HttpRequestError {
+    /// 根据 reqwest 错误创建请求错误
+    pub(crate) fn new(
+        method: HttpMethod,
+        url: &str,
+        timeout: Duration,
+        source: reqwest::Error,
+    ) -> Self {
+        let kind = if source.is_timeout() {
+            HttpRequestErrorKind::Timeout
+        } else if source.is_connect() {
+            HttpRequestErrorKind::Connect
+        } else {
+            HttpRequestErrorKind::Other
+        };
+        Self {
+            kind,
+            method,
+            url: url.to_string(),
+            timeout,
+            source,
+        }
+    }
+
+    /// 是否为超时错误
+    pub fn is_timeout(&self) -> bool {
+        self.kind == HttpRequestErrorKind::Timeout
+    }
+
+    /// 是否为连接错误
+    pub fn is_connect(&self) -> bool {
+        self.kind == HttpRequestErrorKind::Connect
+    }
+
+    /// 是否为 DNS 解析失败
+    pub fn is_dns_failure(&self) -> bool {
+        self.is_connect()
+            && self.source_messages().iter().any(|message| {
+                message.contains("dns error") || message.contains("failed to lookup address")
+            })
+    }
+
+    /// 是否为 TLS 错误（握手失败、证书无效等）
+    pub fn is_tls_failure(&self) -> bool {
+        if !self.is_connect() || self.is_dns_failure() {
+            return false;
+        }
+        self.source_messages().iter().any(|message| {
+            [
+                "certificate",
+                "tls",
+                "handshake",
+                "corrupt message",
+                "alert",
+            ]
+            .iter()
+            .any(|keyword| message.contains(keyword))
+        })
+    }
+
+    /// 底层错误的信息（小写，不包含带有 URL 的 reqwest 错误信息，避免 URL 干扰关键字匹配）
+    fn source_messages(&self) -> Vec<String> {
+        let mut messages = Vec::new();
+        let mut source = std::error::Error::source(&self.source);
+        while let Some(error) = source {
+            messages.push(error.to_string().to_lowercase());
+            source = error.source();
+        }
+        messages
+    }
+
+    /// 是否可重试（超时、连接失败和请求发送失败）
+    pub fn is_retryable(&self) -> bool {
+        self.kind != HttpRequestErrorKind::Other || self.source.is_request()
+    }
+}
+
+impl fmt::Display for HttpRequestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            HttpRequestErrorKind::Timeout => write!(
+                f,
+                "{} request to {} timed out after {}s",
+                self.method,
+                self.url,
+                self.timeout.as_secs()
+            ),
+            HttpRequestErrorKind::Connect => write!(f, "Failed to connect to {}", self.url),
+            HttpRequestErrorKind::Other => {
+                write!(f, "Failed to send {} request to: {}", self.method, self.url)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HttpRequestError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
@@ -2,33 +2,45 @@
 //!
 //! 本模块提供了 HTTP 请求的完整功能，包括：
 //! - HTTP 客户端封装（GET、POST、PUT、DELETE、PATCH）
-//! - Basic Authentication 支持
+//! - Basic Authentication 和 Bearer token 认证支持
 //! - 自定义 Headers 支持
 //! - HTTP 响应封装和解析
+//! - multipart/form-data 文件上传（流式读取文件）
+//! - 可配置的请求超时时间
 //! - HTTP 请求重试机制
+//! - HTTP 请求/响应调试跟踪
 //!
 //! ## 模块结构
 //!
-//! - `auth` - Basic Authentication（`Authorization`）
+//! - `auth` - 认证信息（`Authorization`：Basic Authentication 或 Bearer token）
 //! - `client` - HTTP 客户端（`HttpClient`）
 //! - `method` - HTTP 方法（`HttpMethod`）
+//! - `multipart` - multipart/form-data 请求体（`MultipartBody`、`MultipartFile`）
 //! - `config` - HTTP 请求配置（`RequestConfig`）
-//! - `response` - HTTP 响应（`HttpResponse`）
+//! - `error` - HTTP 请求错误（`HttpRequestError`，区分超时和连接失败）
+//! - `response` - HTTP 响应（`HttpResponse`、`HttpStatusError`）
 //! - `parser` - HTTP 响应解析器（`ResponseParser`、`JsonParser`、`TextParser`）
 //! - `retry` - HTTP 重试工具（`HttpRetry`、`HttpRetryConfig`）
+//! - `trace` - HTTP 请求/响应调试跟踪（`HttpTrace`）
 
 pub mod auth;
 pub mod client;
 pub mod config;
+pub mod error;
 pub mod method;
+pub mod multipart;
 pub mod parser;
 pub mod response;
 pub mod retry;
+pub mod trace;
 
 pub use auth::Authorization;
 pub use client::HttpClient;
 pub use config::{MultipartRequestConfig, RequestConfig};
+pub use error::{HttpRequestError, HttpRequestErrorKind};
 pub use method::HttpMethod;
+pub use multipart::{MultipartBody, MultipartFile};
 pub use parser::{JsonParser, ResponseParser, TextParser};
-pub use response::HttpResponse;
+pub use response::{HttpResponse, HttpStatusError};
 pub use retry::{HttpRetry, HttpRetryConfig};
+pub use trace::HttpTrace;
@@ -0,0 +1,241 @@
+//! multipart/form-data 请求体
+//!
+//! `MultipartBody` 按 RFC 7578 构建 `multipart/form-data` 请求体：每个字段和文件都是一个 part，
+//! part 之间以 `--{boundary}` 分隔，最后以 `--{boundary}--` 结束，换行统一为 `\r\n`。
+//!
+//! 文件内容不会读入内存：请求体是由 part 头部、文件句柄和结束分隔符依次串联的 `Read`，
+//! 发送时边读边写。请求体长度在构建时根据文件大小计算，作为 `Content-Length` 发送。
+
+use std::fs::File;
+use std::io::{self, Cursor, Read};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use color_eyre::{
+    eyre::{ContextCompat, WrapErr},
+    Result,
+};
+
+/// 文件 part 未指定类型时使用的 Content-Type
+pub const DEFAULT_FILE_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// boundary 的最大长度（RFC 2046）
+const MAX_BOUNDARY_LEN: usize = 70;
+
+/// 生成 boundary 的计数器（同一进程内保证不重复）
+static BOUNDARY_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// multipart 请求中的文件
+///
+/// 只记录文件路径，构建请求体时才打开文件。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultipartFile {
+    /// 表单字段名称
+    pub name: String,
+    /// 文件路径
+    pub path: PathBuf,
+    /// 上传时使用的文件名（未指定时使用路径中的文件名）
+    pub file_name: Option<String>,
+    /// 文件的 Content-Type（未指定时为 `application/octet-stream`）
+    pub content_type: Option<String>,
+}
+
+impl MultipartFile {
+    /// 创建文件 part
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 表单字段名称（如 Jira 附件上传使用 `file`）
+    /// * `path` - 文件路径
+    pub fn new(name: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        Self {
+            name: name.into(),
+            path: path.into(),
+            file_name: None,
+            content_type: None,
+        }
+    }
+
+    /// 设置上传时使用的文件名
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = Some(file_name.into());
+        self
+    }
+
+    /// 设置文件的 Content-Type
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+}
+
+/// multipart/form-data 请求体
+///
+/// # 示例
+///
+/// ```
+/// use std::io::Read;
+/// use workflow::base::http::MultipartBody;
+///
+/// let body = MultipartBody::with_boundary("X-BOUNDARY", &[("comment", "hello")], &[]).unwrap();
+/// assert_eq!(body.content_type(), "multipart/form-data; boundary=X-BOUNDARY");
+///
+/// let length = body.content_length();
+/// let mut content = String::new();
+/// body.into_reader().read_to_string(&mut content).unwrap();
+/// assert_eq!(
+///     content,
+///     "--X-BOUNDARY\r\n\
+///      Content-Disposition: form-data; name=\"comment\"\r\n\r\n\
+///      hello\r\n\
+///      --X-BOUNDARY--\r\n"
+/// );
+/// assert_eq!(length, content.len() as u64);
+/// ```
+pub struct MultipartBody {
+    boundary: String,
+    reader: Box<dyn Read + Send>,
+    content_length: u64,
+}
+
+impl MultipartBody {
+    /// 使用随机生成的 boundary 构建请求体
+    ///
+    /// # 参数
+    ///
+    /// * `fields` - 文本字段（名称、值）
+    /// * `files` - 文件
+    ///
+    /// # 错误
+    ///
+    /// 文件无法打开或不是普通文件时返回错误。
+    pub fn new(fields: &[(&str, &str)], files: &[MultipartFile]) -> Result<Self> {
+        Self::with_boundary(Self::generate_boundary(), fields, files)
+    }
+
+    /// 使用指定的 boundary 构建请求体
+    ///
+    /// 字段在前、文件在后，各自保持传入的顺序。
+    ///
+    /// # 错误
+    ///
+    /// boundary 为空、超过 70 个字符或包含换行时返回错误；文件无法打开或不是普通文件时返回错误。
+    pub fn with_boundary(
+        boundary: impl Into<String>,
+        fields: &[(&str, &str)],
+        files: &[MultipartFile],
+    ) -> Result<Self> {
+        let boundary = boundary.into();
+        if boundary.is_empty()
+            || boundary.len() > MAX_BOUNDARY_LEN
+            || boundary.contains(['\r', '\n'])
+        {
+            color_eyre::eyre::bail!("Invalid multipart boundary: {:?}", boundary);
+        }
+
+        let mut segments: Vec<Box<dyn Read + Send>> = Vec::new();
+        let mut buffer = Vec::new();
+        let mut content_length = 0u64;
+
+        for (name, value) in fields {
+            buffer.extend_from_slice(
+                format!(
+                    "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                    boundary,
+                    escape_quoted(name),
+                    value
+                )
+                .as_bytes(),
+            );
+        }
+
+        for file in files {
+            let handle = File::open(&file.path)
+                .wrap_err_with(|| format!("Failed to open file: {}", file.path.display()))?;
+            let metadata = handle
+                .metadata()
+                .wrap_err_with(|| format!("Failed to read metadata: {}", file.path.display()))?;
+            if !metadata.is_file() {
+                color_eyre::eyre::bail!("Not a file: {}", file.path.display());
+            }
+
+            let file_name = match &file.file_name {
+                Some(file_name) => file_name.clone(),
+                None => file
+                    .path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .map(str::to_string)
+                    .wrap_err_with(|| format!("Invalid file name: {}", file.path.display()))?,
+            };
+            let content_type = file.content_type.as_deref().unwrap_or(DEFAULT_FILE_CONTENT_TYPE);
+
+            buffer.extend_from_slice(
+                format!(
+                    "--{}\r\nContent-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n\
+                     Content-Type: {}\r\n\r\n",
+                    boundary,
+                    escape_quoted(&file.name),
+                    escape_quoted(&file_name),
+                    content_type
+                )
+                .as_bytes(),
+            );
+            content_length += buffer.len() as u64 + metadata.len();
+            segments.push(Box::new(Cursor::new(std::mem::take(&mut buffer))));
+            segments.push(Box::new(handle.take(metadata.len())));
+            buffer.extend_from_slice(b"\r\n");
+        }
+
+        buffer.extend_from_slice(format!("--{}--\r\n", boundary).as_bytes());
+        content_length += buffer.len() as u64;
+        segments.push(Box::new(Cursor::new(buffer)));
+
+        let reader = segments.into_iter().fold(
+            Box::new(io::empty()) as Box<dyn Read + Send>,
+            |reader, segment| Box::new(reader.chain(segment)),
+        );
+
+        Ok(Self {
+            boundary,
+            reader,
+            content_length,
+        })
+    }
+
+    /// 生成 boundary（当前时间和进程内计数器，不依赖随机数）
+    fn generate_boundary() -> String {
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or_default();
+        let counter = BOUNDARY_COUNTER.fetch_add(1, Ordering::Relaxed);
+        format!("workflow-boundary-{:016x}{:08x}", nanos, counter)
+    }
+
+    /// boundary
+    pub fn boundary(&self) -> &str {
+        &self.boundary
+    }
+
+    /// 请求的 Content-Type（包含 boundary）
+    pub fn content_type(&self) -> String {
+        format!("multipart/form-data; boundary={}", self.boundary)
+    }
+
+    /// 请求体长度（字节）
+    pub fn content_length(&self) -> u64 {
+        self.content_length
+    }
+
+    /// 转换为请求体的 `Read`（文件内容在读取时才从磁盘读出）
+    pub fn into_reader(self) -> Box<dyn Read + Send> {
+        self.reader
+    }
+}
+
+/// 转义 Content-Disposition 中带引号的值（`"`、`\r`、`\n` 按 HTML 规范编码）
+fn escape_quoted(value: &str) -> String {
+    value.replace('"', "%22").replace('\r', "%0D").replace('\n', "%0A")
+}
@@ -3,11 +3,58 @@
 //! 本模块提供了 HTTP 响应的封装和解析功能。
 //! 响应体延迟解析，通过方法（as_json, as_text 等）来解析。
 
+use std::fmt;
+
 use color_eyre::Result;
 use reqwest::header::HeaderMap;
 use serde::Deserialize;
 
 use super::parser::{JsonParser, ResponseParser, TextParser};
+use super::trace::HttpTrace;
+
+/// HTTP 状态码错误
+///
+/// 携带响应的状态码，`HttpRetry` 据此判断错误是否可重试（429 和 5xx 可重试）。
+///
+/// # 示例
+///
+/// ```rust
+/// use workflow::base::http::response::HttpStatusError;
+///
+/// let error = HttpStatusError::new(503, "Service Unavailable");
+/// assert!(error.is_retryable());
+/// assert!(!HttpStatusError::new(401, "Unauthorized").is_retryable());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpStatusError {
+    /// HTTP 状态码
+    pub status: u16,
+    /// 错误消息
+    pub message: String,
+}
+
+impl HttpStatusError {
+    /// 创建 HTTP 状态码错误
+    pub fn new(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+
+    /// 是否可重试（429 Too Many Requests 和 5xx 服务器错误）
+    pub fn is_retryable(&self) -> bool {
+        self.status == 429 || (500..600).contains(&self.status)
+    }
+}
+
+impl fmt::Display for HttpStatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for HttpStatusError {}
 
 /// HTTP 响应格式
 ///
@@ -30,6 +77,7 @@ impl HttpResponse {
     ///
     /// 只提取元数据（status、status_text、headers），并缓存响应体字节。
     /// 响应体通过后续的方法（as_json, as_text 等）来解析。
+    /// 启用 HTTP 跟踪时记录响应。
     ///
     /// # 参数
     ///
@@ -46,10 +94,15 @@ impl HttpResponse {
         let status = response.status().as_u16();
         let status_text = response.status().canonical_reason().unwrap_or("Unknown").to_string();
         let headers = response.headers().clone();
+        let trace_url = HttpTrace::is_enabled().then(|| response.url().to_string());
 
         // 缓存响应体字节（可以多次解析）
         let body_bytes = response.bytes()?.to_vec();
 
+        if let Some(url) = trace_url {
+            HttpTrace::trace_response(&url, status, &headers, Some(&body_bytes));
+        }
+
         Ok(Self {
             status,
             status_text,
@@ -9,6 +9,8 @@ use std::time::{Duration, Instant};
 
 use color_eyre::{eyre::eyre, Result};
 
+use super::error::HttpRequestError;
+use super::response::HttpStatusError;
 use crate::base::constants::network::errors;
 use crate::base::dialog::ConfirmDialog;
 use crate::{trace_debug, trace_info, trace_warn};
@@ -130,6 +132,46 @@ impl HttpRetry {
     ) -> Result<RetryResult<T>>
     where
         F: Fn() -> Result<T>,
+    {
+        Self::retry_with_notify(operation, config, operation_name, |_| {})
+    }
+
+    /// 使用指数退避算法重试 HTTP 操作，并在每次重试前通知调用方
+    ///
+    /// 重试策略与 [`HttpRetry::retry`] 相同。
+    ///
+    /// # 参数
+    ///
+    /// * `operation` - 要执行的操作（闭包）
+    /// * `config` - 重试配置
+    /// * `operation_name` - 操作名称（用于日志输出）
+    /// * `on_retry` - 每次重试等待之前调用，参数为重试序号（从 1 开始）
+    ///
+    /// # 示例
+    ///
+    /// ```rust,no_run
+    /// use workflow::base::http::retry::{HttpRetry, HttpRetryConfig};
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = HttpRetryConfig::new();
+    /// let result = HttpRetry::retry_with_notify(
+    ///     || Ok(42),
+    ///     &config,
+    ///     "获取数据",
+    ///     |retry| eprintln!("retrying ({}/{})", retry, config.max_retries),
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn retry_with_notify<F, T, N>(
+        operation: F,
+        config: &HttpRetryConfig,
+        operation_name: &str,
+        on_retry: N,
+    ) -> Result<RetryResult<T>>
+    where
+        F: Fn() -> Result<T>,
+        N: Fn(u32),
     {
         let mut delay = config.initial_delay;
         let mut last_error = None;
@@ -182,6 +224,7 @@ impl HttpRetry {
                             attempt + 1,
                             config.max_retries + 1
                         );
+                        on_retry(attempt + 1);
 
                         // 交互式确认：询问用户是否继续重试
                         if config.interactive && attempt > 0 {
@@ -251,6 +294,8 @@ impl HttpRetry {
     /// - 网络错误（超时、连接失败、请求中断）
     /// - 5xx 服务器错误（500, 502, 503, 504）
     /// - 429 Too Many Requests（需要特殊处理，使用 Retry-After header）
+    /// - 状态码为 429 或 5xx 的 `HttpStatusError`
+    /// - 超时、连接失败或请求发送失败的 `HttpRequestError`
     ///
     /// 不可重试的错误包括：
     /// - 4xx 客户端错误（400, 401, 403, 404 等）
@@ -265,6 +310,16 @@ impl HttpRetry {
     ///
     /// 返回 `true` 如果错误可重试，否则返回 `false`。
     fn is_retryable_error(error: &color_eyre::eyre::Report) -> bool {
+        // 检查是否是携带状态码的 HTTP 错误
+        if let Some(status_error) = error.downcast_ref::<HttpStatusError>() {
+            return status_error.is_retryable();
+        }
+
+        // 检查是否是 HttpClient 返回的请求错误
+        if let Some(request_error) = error.downcast_ref::<HttpRequestError>() {
+            return request_error.is_retryable();
+        }
+
         // 检查是否是 reqwest 网络错误
         if let Some(reqwest_error) = error.downcast_ref::<reqwest::Error>() {
             // 检查是否是网络连接错误
@@ -354,6 +409,19 @@ impl HttpRetry {
     ///
     /// 返回错误的简短描述。
     fn get_error_description(error: &color_eyre::eyre::Report) -> String {
+        if let Some(status_error) = error.downcast_ref::<HttpStatusError>() {
+            return format!("HTTP {}", status_error.status);
+        }
+
+        if let Some(request_error) = error.downcast_ref::<HttpRequestError>() {
+            if request_error.is_timeout() {
+                return errors::TIMEOUT.to_string();
+            }
+            if request_error.is_connect() {
+                return errors::CONNECTION_FAILED.to_string();
+            }
+        }
+
         // 尝试从 reqwest 错误中提取状态码
         if let Some(reqwest_error) = error.downcast_ref::<reqwest::Error>() {
             if let Some(status) = reqwest_error.status() {
@@ -188,6 +188,27 @@ impl Progress {
         self.inner.set_position(pos);
     }
 
+    /// 设置总数
+    ///
+    /// 用于总数在执行过程中才能确定的场景（如并发下载多个文件时，总字节数随文件开始下载而增加）。
+    ///
+    /// # 参数
+    ///
+    /// * `len` - 新的总数
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use workflow::base::indicator::Progress;
+    ///
+    /// let progress = Progress::new_download(1024, "Downloading...");
+    /// progress.set_length(4096);
+    /// progress.set_position(2048);
+    /// ```
+    pub fn set_length(&self, len: u64) {
+        self.inner.set_length(len);
+    }
+
     /// 更新显示的消息
     ///
     /// # 参数
@@ -2,6 +2,7 @@
 //!
 //! 本模块提供了 LLM 客户端实现，支持所有遵循 OpenAI 兼容格式的提供商。
 
+use std::cell::RefCell;
 use std::sync::OnceLock;
 
 use color_eyre::{
@@ -14,7 +15,11 @@ use serde_json::{json, Value};
 
 use super::types::{ChatCompletionResponse, LLMRequestParams};
 use crate::{
-    base::{http::HttpResponse, settings::settings::LLMSettings},
+    base::{
+        http::{HttpResponse, HttpRetry, HttpRetryConfig, HttpStatusError},
+        indicator::Spinner,
+        settings::settings::LLMSettings,
+    },
     Settings,
 };
 
@@ -75,12 +80,6 @@ impl LLMClient {
     ///
     /// 如果 API 调用失败或响应格式不正确，返回相应的错误信息。
     pub fn call(&self, params: &LLMRequestParams) -> Result<String> {
-        // 创建带超时的 HTTP 客户端（60秒）
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(60))
-            .build()
-            .wrap_err("Failed to create HTTP client with timeout")?;
-
         // 构建请求体（统一格式）
         let payload = self.build_payload(params)?;
 
@@ -90,6 +89,59 @@ impl LLMClient {
         // 构建 URL（统一格式）
         let url = self.build_url()?;
 
+        let retry_config = Self::retry_config(Settings::get().llm.max_retries());
+        self.send_with_retry(&url, &headers, &payload, &retry_config)
+    }
+
+    /// LLM 请求的重试配置
+    ///
+    /// 非交互式重试（请求通常在 spinner 中执行），从 2 秒开始指数退避，最长等待 10 秒。
+    ///
+    /// # 参数
+    ///
+    /// * `max_retries` - 最大重试次数
+    pub fn retry_config(max_retries: u32) -> HttpRetryConfig {
+        HttpRetryConfig {
+            max_retries,
+            initial_delay: 2,
+            max_delay: 10,
+            backoff_multiplier: 2.0,
+            interactive: false,
+        }
+    }
+
+    /// 发送 LLM 请求，遇到临时错误时重试
+    ///
+    /// 429 和 5xx 错误以及网络错误会按 `config` 重试，重试期间显示
+    /// "Model overloaded, retrying (n/m)..." spinner；400、401 等客户端错误立即返回。
+    ///
+    /// # 参数
+    ///
+    /// * `url` - Chat Completions API URL
+    /// * `headers` - 请求头
+    /// * `payload` - 请求体
+    /// * `config` - 重试配置
+    ///
+    /// # 返回
+    ///
+    /// 返回 LLM 生成的文本内容（去除首尾空白）。
+    ///
+    /// # 错误
+    ///
+    /// 如果请求失败（重试耗尽或不可重试）或响应格式不正确，返回相应的错误信息。
+    pub fn send_with_retry(
+        &self,
+        url: &str,
+        headers: &HeaderMap,
+        payload: &Value,
+        config: &HttpRetryConfig,
+    ) -> Result<String> {
+        // 创建带超时的 HTTP 客户端（60秒）
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(60))
+            .build()
+            .wrap_err("Failed to create HTTP client with timeout")?;
+
         // 获取 provider 名称用于错误消息
         let provider = self.get_provider_name()?;
 
@@ -98,8 +150,47 @@ impl LLMClient {
         crate::trace_debug!("LLM headers: {:?}", headers);
         crate::trace_debug!("LLM provider: {}", provider);
 
+        // 第一次重试时创建 spinner，之后只更新消息；请求结束后自动清除
+        let retry_spinner: RefCell<Option<Spinner>> = RefCell::new(None);
+        let on_retry = |retry: u32| {
+            let message = format!(
+                "Model overloaded, retrying ({}/{})...",
+                retry, config.max_retries
+            );
+            retry_spinner
+                .borrow_mut()
+                .get_or_insert_with(|| Spinner::new(&message))
+                .update_message(&message);
+        };
+
+        let result = HttpRetry::retry_with_notify(
+            || self.send(&client, url, headers, payload, &provider),
+            config,
+            "LLM request",
+            on_retry,
+        );
+        if let Some(spinner) = retry_spinner.into_inner() {
+            spinner.finish();
+        }
+        let data = result?.result;
+
+        // 根据配置的响应格式提取内容
+        self.extract_content(&data)
+    }
+
+    /// 发送一次 LLM 请求
+    ///
+    /// 非 2xx 响应返回 `HttpStatusError`，以便 `HttpRetry` 判断是否重试。
+    fn send(
+        &self,
+        client: &Client,
+        url: &str,
+        headers: &HeaderMap,
+        payload: &Value,
+        provider: &str,
+    ) -> Result<Value> {
         // 发送请求
-        let mut request = client.post(&url);
+        let mut request = client.post(url);
 
         // 添加 headers
         for (key, value) in headers.iter() {
@@ -107,7 +198,7 @@ impl LLMClient {
         }
 
         let response = request
-            .json(&payload)
+            .json(payload)
             .send()
             .wrap_err_with(|| format!("Failed to send LLM request to {}", provider))?;
 
@@ -116,21 +207,18 @@ impl LLMClient {
 
         // 检查错误（使用 ensure_success_with 统一处理）
         let http_response = http_response.ensure_success_with(|r| {
-            let provider = self.get_provider_name().unwrap_or_else(|_| "unknown".to_string());
             let error_message = r.extract_error_message();
-            eyre!(
-                "LLM API request failed ({}): {} - {}",
-                provider,
+            HttpStatusError::new(
                 r.status,
-                error_message
+                format!(
+                    "LLM API request failed ({}): {} - {}",
+                    provider, r.status, error_message
+                ),
             )
         })?;
 
         // 解析 JSON 响应
-        let data: Value = http_response.as_json()?;
-
-        // 根据配置的响应格式提取内容
-        self.extract_content(&data)
+        http_response.as_json()
     }
 
     /// 构建 API URL
@@ -0,0 +1,247 @@
+//! 输出语言自动检测
+//!
+//! `llm.language = "auto"` 时，根据最近的 commit 消息推断仓库使用的主要语言，
+//! 让 PR 总结等 AI 生成内容使用与 commit 相同的语言。
+//!
+//! 检测逻辑：
+//! 1. 逐条判断 commit 消息的语言：
+//!    - 按文字系统区分：假名 → 日语，谚文 → 韩语，汉字 → 简体中文，西里尔字母 → 俄语，
+//!      越南语特有的字母（如 `ơ`、`ư`、`đ`、`ạ`）→ 越南语
+//!    - 拉丁字母的消息按常用词匹配英语、德语、法语、西班牙语、葡萄牙语
+//! 2. 忽略无法判断的消息（如 `v1.2.3` 这样没有文字和常用词的消息），统计各语言的票数
+//! 3. 票数最多的语言超过已判断消息的一半时采用该语言，否则使用英语
+
+use super::languages::{find_language, SupportedLanguage, SUPPORTED_LANGUAGES};
+
+/// 自动检测输出语言的配置值
+pub const AUTO_LANGUAGE: &str = "auto";
+
+/// 自动检测选项在语言选择列表中的显示名称（格式与 `get_supported_language_display_names` 一致）
+pub const AUTO_LANGUAGE_DISPLAY_NAME: &str = "Auto-detect from commits (Auto) - auto";
+
+/// 自动检测时读取的 commit 数量
+pub const AUTO_DETECT_COMMIT_COUNT: usize = 20;
+
+/// 越南语特有的字母（不含与葡萄牙语、法语共用的 `â`、`ê`、`ô` 和常见的重音字母）
+const VIETNAMESE_CHARS: &str = "ăđơưạảấầẩẫậắằẳẵặẹẻẽếềểễệỉịọỏốồổỗộớờởỡợụủứừửữựỳỵỷỹ";
+
+/// 拉丁字母语言的常用词（小写）
+const LATIN_STOPWORDS: &[(&str, &[&str])] = &[
+    (
+        "en",
+        &[
+            "the", "and", "for", "with", "to", "of", "in", "on", "when", "from", "add", "fix",
+            "update", "remove", "use", "support", "should", "is",
+        ],
+    ),
+    (
+        "de",
+        &[
+            "der",
+            "die",
+            "das",
+            "und",
+            "mit",
+            "für",
+            "nicht",
+            "ist",
+            "ein",
+            "eine",
+            "auf",
+            "beim",
+            "hinzufügen",
+            "beheben",
+            "entfernen",
+            "aktualisieren",
+        ],
+    ),
+    (
+        "fr",
+        &[
+            "le",
+            "les",
+            "et",
+            "pour",
+            "avec",
+            "des",
+            "une",
+            "du",
+            "dans",
+            "sur",
+            "ajout",
+            "ajouter",
+            "corriger",
+            "correction",
+            "supprimer",
+        ],
+    ),
+    (
+        "es",
+        &[
+            "el",
+            "los",
+            "las",
+            "y",
+            "con",
+            "una",
+            "del",
+            "en",
+            "al",
+            "agregar",
+            "añadir",
+            "corregir",
+            "eliminar",
+            "actualizar",
+        ],
+    ),
+    (
+        "pt",
+        &[
+            "os",
+            "e",
+            "com",
+            "uma",
+            "do",
+            "da",
+            "no",
+            "na",
+            "ao",
+            "não",
+            "adicionar",
+            "corrigir",
+            "remover",
+            "atualizar",
+        ],
+    ),
+];
+
+/// 判断单条文本的语言
+///
+/// # 返回
+///
+/// 返回语言代码（如 "zh-CN"、"ja"），无法判断时返回 `None`。
+///
+/// # 示例
+///
+/// ```
+/// use workflow::base::llm::detect_text_language;
+///
+/// assert_eq!(detect_text_language("feat: 添加用户登录"), Some("zh-CN"));
+/// assert_eq!(detect_text_language("fix: handle empty diff in the summary"), Some("en"));
+/// assert_eq!(detect_text_language("v1.2.3"), None);
+/// ```
+pub fn detect_text_language(text: &str) -> Option<&'static str> {
+    let text = strip_commit_prefix(text);
+
+    let (mut kana, mut hangul, mut han, mut cyrillic, mut vietnamese) = (0, 0, 0, 0, 0);
+    for ch in text.chars() {
+        match ch {
+            '\u{3040}'..='\u{30FF}' => kana += 1,
+            '\u{AC00}'..='\u{D7AF}' | '\u{1100}'..='\u{11FF}' => hangul += 1,
+            '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}' => han += 1,
+            '\u{0400}'..='\u{04FF}' => cyrillic += 1,
+            _ if VIETNAMESE_CHARS.contains(ch.to_lowercase().next().unwrap_or(ch)) => {
+                vietnamese += 1
+            }
+            _ => {}
+        }
+    }
+
+    // 日文通常同时包含汉字和假名，优先判断假名
+    if kana > 0 {
+        return Some("ja");
+    }
+    if hangul > 0 {
+        return Some("ko");
+    }
+    if han > 0 {
+        return Some("zh-CN");
+    }
+    if cyrillic > 0 {
+        return Some("ru");
+    }
+    if vietnamese > 0 {
+        return Some("vi");
+    }
+
+    detect_latin_language(text)
+}
+
+/// 根据常用词判断拉丁字母文本的语言（最高分不唯一时返回 `None`）
+fn detect_latin_language(text: &str) -> Option<&'static str> {
+    let words: Vec<String> = text
+        .split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+        .map(|word| word.to_lowercase())
+        .collect();
+
+    let mut scores: Vec<(&'static str, usize)> = LATIN_STOPWORDS
+        .iter()
+        .map(|(code, stopwords)| {
+            let score = words.iter().filter(|word| stopwords.contains(&word.as_str())).count();
+            (*code, score)
+        })
+        .filter(|(_, score)| *score > 0)
+        .collect();
+    scores.sort_by_key(|s| std::cmp::Reverse(s.1));
+
+    match scores.as_slice() {
+        [] => None,
+        [(code, _)] => Some(*code),
+        [(code, best), (_, second), ..] if best > second => Some(*code),
+        _ => None,
+    }
+}
+
+/// 去除 Conventional Commits 前缀（如 `feat(api): `、`fix!: `）
+fn strip_commit_prefix(text: &str) -> &str {
+    let first_line = text.lines().next().unwrap_or_default();
+    match first_line.split_once(": ") {
+        Some((prefix, rest))
+            if !prefix.is_empty()
+                && prefix.chars().all(|c| {
+                    c.is_ascii_alphanumeric() || matches!(c, '(' | ')' | '!' | '-' | '_' | '/')
+                }) =>
+        {
+            rest
+        }
+        _ => first_line,
+    }
+}
+
+/// 根据多条文本（如 commit 消息）推断主要语言
+///
+/// 票数最多的语言超过已判断文本的一半时采用该语言；没有可判断的文本或无法确定时使用英语。
+///
+/// # 示例
+///
+/// ```
+/// use workflow::base::llm::detect_language;
+///
+/// let commits = ["feat: 添加用户登录", "fix: 修复空指针", "chore: bump version to 1.2.0"];
+/// assert_eq!(detect_language(&commits).code, "zh-CN");
+/// assert_eq!(detect_language::<&str>(&[]).code, "en");
+/// ```
+pub fn detect_language<S: AsRef<str>>(samples: &[S]) -> &'static SupportedLanguage {
+    let mut votes: Vec<(&'static str, usize)> = Vec::new();
+    let mut total = 0;
+    for code in samples.iter().filter_map(|sample| detect_text_language(sample.as_ref())) {
+        total += 1;
+        match votes.iter_mut().find(|(voted, _)| *voted == code) {
+            Some((_, count)) => *count += 1,
+            None => votes.push((code, 1)),
+        }
+    }
+
+    votes
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .filter(|(_, count)| count * 2 > total)
+        .and_then(|(code, _)| find_language(code))
+        .unwrap_or(&SUPPORTED_LANGUAGES[0])
+}
+
+/// 判断配置的语言是否为自动检测
+pub fn is_auto_language(code: &str) -> bool {
+    code.trim().eq_ignore_ascii_case(AUTO_LANGUAGE)
+}
@@ -2,7 +2,14 @@
 //!
 //! 定义了支持的语言列表及其对应的 instruction，用于增强 LLM prompt 中的语言要求。
 
+use std::sync::OnceLock;
+
+use super::language_detect::{
+    detect_language, is_auto_language, AUTO_DETECT_COMMIT_COUNT, AUTO_LANGUAGE_DISPLAY_NAME,
+};
 use crate::base::settings::Settings;
+use crate::git::GitCommit;
+use crate::trace_debug;
 
 /// 支持的语言信息
 #[derive(Debug, Clone)]
@@ -16,71 +23,97 @@ pub struct SupportedLanguage {
     /// 语言 instruction 模板
     /// 使用 {language_name} 作为占位符
     pub instruction_template: &'static str,
+    /// 常用别名（小写，`_` 写作 `-`，如 "zh"、"pt-br"、"brazilian portuguese"）
+    pub aliases: &'static [&'static str],
 }
 
 /// 支持的语言列表
 ///
-/// 包含主流语言：英语、中文（简体/繁体）、日语、韩语、德语、法语、西班牙语等
+/// 包含主流语言：英语、中文（简体/繁体）、日语、韩语、德语、法语、西班牙语、葡萄牙语（含巴西葡萄牙语）、越南语等
 pub const SUPPORTED_LANGUAGES: &[SupportedLanguage] = &[
     SupportedLanguage {
         code: "en",
         name: "English",
         native_name: "English",
         instruction_template: "**All outputs MUST be in English only.** If the PR title or content contains non-English text (like Chinese), translate it to English in the summary.",
+        aliases: &["en-us", "en-gb"],
     },
     SupportedLanguage {
         code: "zh-CN",
         name: "Simplified Chinese",
         native_name: "简体中文",
         instruction_template: "**所有输出必须使用简体中文。** 如果 PR 标题或内容包含非中文文本（如英文），请在总结中翻译为中文。",
+        aliases: &["zh", "zh-hans", "chinese"],
     },
     SupportedLanguage {
         code: "zh-TW",
         name: "Traditional Chinese",
         native_name: "繁體中文",
         instruction_template: "**所有輸出必須使用繁體中文。** 如果 PR 標題或內容包含非中文文本（如英文），請在總結中翻譯為繁體中文。",
+        aliases: &["zh-hant", "zh-hk"],
     },
     SupportedLanguage {
         code: "ja",
         name: "Japanese",
         native_name: "日本語",
         instruction_template: "**すべての出力は日本語のみで行う必要があります。** PR タイトルまたはコンテンツに非日本語テキスト（英語など）が含まれている場合は、要約で日本語に翻訳してください。",
+        aliases: &["ja-jp", "jp"],
     },
     SupportedLanguage {
         code: "ko",
         name: "Korean",
         native_name: "한국어",
         instruction_template: "**모든 출력은 한국어로만 작성해야 합니다.** PR 제목이나 내용에 비한국어 텍스트(예: 영어)가 포함된 경우 요약에서 한국어로 번역하세요.",
+        aliases: &["ko-kr", "kr"],
     },
     SupportedLanguage {
         code: "de",
         name: "German",
         native_name: "Deutsch",
         instruction_template: "**Alle Ausgaben MÜSSEN ausschließlich auf Deutsch sein.** Wenn der PR-Titel oder Inhalt nicht-deutschen Text (z.B. Englisch) enthält, übersetzen Sie ihn in der Zusammenfassung ins Deutsche.",
+        aliases: &["de-de"],
     },
     SupportedLanguage {
         code: "fr",
         name: "French",
         native_name: "Français",
         instruction_template: "**Toutes les sorties DOIVENT être uniquement en français.** Si le titre ou le contenu de la PR contient du texte non français (comme l'anglais), traduisez-le en français dans le résumé.",
+        aliases: &["fr-fr"],
     },
     SupportedLanguage {
         code: "es",
         name: "Spanish",
         native_name: "Español",
         instruction_template: "**Todas las salidas DEBEN estar únicamente en español.** Si el título o el contenido de la PR contiene texto no español (como inglés), tradúzcalo al español en el resumen.",
+        aliases: &["es-es"],
     },
     SupportedLanguage {
         code: "pt",
         name: "Portuguese",
         native_name: "Português",
         instruction_template: "**Todas as saídas DEVEM estar exclusivamente em português.** Se o título ou o conteúdo da PR contiver texto não português (como inglês), traduza-o para português no resumo.",
+        aliases: &["pt-pt"],
+    },
+    SupportedLanguage {
+        code: "pt-BR",
+        name: "Brazilian Portuguese",
+        native_name: "Português (Brasil)",
+        instruction_template: "**Todas as saídas DEVEM estar exclusivamente em português do Brasil.** Se o título ou o conteúdo do PR contiver texto em outro idioma (como inglês), traduza-o para o português do Brasil no resumo.",
+        aliases: &["brazilian portuguese", "portuguese (brazil)"],
     },
     SupportedLanguage {
         code: "ru",
         name: "Russian",
         native_name: "Русский",
         instruction_template: "**Все выходные данные ДОЛЖНЫ быть только на русском языке.** Если заголовок или содержимое PR содержит текст не на русском языке (например, английский), переведите его на русский в резюме.",
+        aliases: &["ru-ru"],
+    },
+    SupportedLanguage {
+        code: "vi",
+        name: "Vietnamese",
+        native_name: "Tiếng Việt",
+        instruction_template: "**Tất cả đầu ra PHẢI được viết hoàn toàn bằng tiếng Việt.** Nếu tiêu đề hoặc nội dung của PR chứa văn bản không phải tiếng Việt (như tiếng Anh), hãy dịch sang tiếng Việt trong phần tóm tắt.",
+        aliases: &["vi-vn", "vn"],
     },
 ];
 
@@ -88,7 +121,7 @@ pub const SUPPORTED_LANGUAGES: &[SupportedLanguage] = &[
 ///
 /// # 参数
 ///
-/// * `code` - 语言代码（如 "en", "zh-CN", "zh" 等）
+/// * `code` - 语言代码或别名（如 "en", "zh-CN", "zh", "pt_br", "portuguese" 等）
 ///
 /// # 返回
 ///
@@ -96,20 +129,46 @@ pub const SUPPORTED_LANGUAGES: &[SupportedLanguage] = &[
 ///
 /// # 说明
 ///
-/// 支持的语言代码变体：
-/// - "zh" 和 "zh-CN" 都匹配简体中文
-/// - "zh-TW" 匹配繁体中文
-/// - 其他语言代码精确匹配
+/// 匹配时不区分大小写，`_` 视为 `-`，按以下顺序查找：
+/// 1. 语言代码（如 "pt-BR"、"pt_br"）
+/// 2. 常用别名（如 "zh" 匹配简体中文，"brazilian portuguese" 匹配巴西葡萄牙语）
+/// 3. 英文名称或本地化名称（如 "portuguese"、"tiếng việt"）
+///
+/// # 示例
+///
+/// ```
+/// use workflow::base::llm::find_language;
+///
+/// assert_eq!(find_language("pt_br").map(|lang| lang.code), Some("pt-BR"));
+/// assert_eq!(find_language("zh").map(|lang| lang.code), Some("zh-CN"));
+/// assert_eq!(find_language("Vietnamese").map(|lang| lang.code), Some("vi"));
+/// assert!(find_language("klingon").is_none());
+/// ```
 pub fn find_language(code: &str) -> Option<&SupportedLanguage> {
-    let code_lower = code.to_lowercase();
-
-    // 特殊处理：zh 和 zh-cn 都匹配简体中文
-    if code_lower == "zh" || code_lower == "zh-cn" {
-        return SUPPORTED_LANGUAGES.iter().find(|lang| lang.code == "zh-CN");
+    let normalized = normalize_language_code(code);
+    if normalized.is_empty() {
+        return None;
     }
 
-    // 精确匹配
-    SUPPORTED_LANGUAGES.iter().find(|lang| lang.code.to_lowercase() == code_lower)
+    SUPPORTED_LANGUAGES
+        .iter()
+        .find(|lang| lang.code.to_lowercase() == normalized)
+        .or_else(|| {
+            SUPPORTED_LANGUAGES
+                .iter()
+                .find(|lang| lang.aliases.contains(&normalized.as_str()))
+        })
+        .or_else(|| {
+            SUPPORTED_LANGUAGES.iter().find(|lang| {
+                lang.name.to_lowercase() == normalized
+                    || lang.native_name.to_lowercase() == normalized
+            })
+        })
+}
+
+/// 规范化语言代码：去除首尾空白、转为小写、`_` 替换为 `-`
+fn normalize_language_code(code: &str) -> String {
+    code.trim().to_lowercase().replace('_', "-")
 }
 
 /// 获取语言的 instruction
@@ -145,6 +204,7 @@ pub fn get_language_instruction(code: &str) -> String {
 ///
 /// 语言选择优先级：配置文件 > 默认值（"en"）
 /// 如果配置文件中的语言代码不在支持列表中，将使用英文作为默认语言。
+/// 配置为 `auto` 时根据最近的 commit 消息检测语言（见 `resolve_language_code`）。
 ///
 /// # 示例
 ///
@@ -159,14 +219,14 @@ pub fn get_language_requirement(system_prompt: &str) -> String {
     // 从配置文件读取语言设置
     let settings = Settings::get();
     let language_code = if settings.llm.language.is_empty() {
-        "en"
+        "en".to_string()
     } else {
-        settings.llm.language.as_str()
+        resolve_language_code(&settings.llm.language)
     };
 
-    let language_instruction = get_language_instruction(language_code);
+    let language_instruction = get_language_instruction(&language_code);
     let language_info =
-        find_language(language_code).map(|lang| lang.native_name).unwrap_or("English");
+        find_language(&language_code).map(|lang| lang.native_name).unwrap_or("English");
 
     format!(
         r#"## CRITICAL LANGUAGE REQUIREMENT
@@ -188,6 +248,32 @@ Remember: ALL output must be in {} only. No exceptions."#,
     )
 }
 
+/// 解析配置的输出语言代码
+///
+/// 配置为 `auto` 时，根据当前仓库最近的 commit 消息检测主要语言（结果在进程内缓存）；
+/// 无法读取 commit（如不在 git 仓库中）或无法确定时使用英语。其他值原样返回。
+pub fn resolve_language_code(code: &str) -> String {
+    if !is_auto_language(code) {
+        return code.to_string();
+    }
+
+    static DETECTED: OnceLock<&'static str> = OnceLock::new();
+    DETECTED
+        .get_or_init(|| {
+            let messages: Vec<String> = GitCommit::get_branch_commits(AUTO_DETECT_COMMIT_COUNT)
+                .map(|commits| commits.into_iter().map(|commit| commit.message).collect())
+                .unwrap_or_default();
+            let language = detect_language(&messages);
+            trace_debug!(
+                "Auto-detected output language from {} commits: {}",
+                messages.len(),
+                language.code
+            );
+            language.code
+        })
+        .to_string()
+}
+
 /// 获取所有支持的语言代码列表
 ///
 /// # 返回
@@ -210,3 +296,25 @@ pub fn get_supported_language_display_names() -> Vec<String> {
         .map(|lang| format!("{} ({}) - {}", lang.native_name, lang.name, lang.code))
         .collect()
 }
+
+/// 获取输出语言选择列表
+///
+/// 在 `get_supported_language_display_names` 的基础上，末尾追加自动检测选项
+/// （`AUTO_LANGUAGE_DISPLAY_NAME`）。显示名称格式相同，可以按 `" - "` 拆分出语言代码。
+pub fn get_output_language_display_names() -> Vec<String> {
+    let mut display_names = get_supported_language_display_names();
+    display_names.push(AUTO_LANGUAGE_DISPLAY_NAME.to_string());
+    display_names
+}
+
+/// 获取语言代码在输出语言选择列表中的显示名称
+///
+/// # 返回
+///
+/// `auto` 返回自动检测选项；支持的语言（包括别名）返回对应的显示名称；否则返回 `None`。
+pub fn get_output_language_display_name(code: &str) -> Option<String> {
+    if is_auto_language(code) {
+        return Some(AUTO_LANGUAGE_DISPLAY_NAME.to_string());
+    }
+    find_language(code).map(|lang| format!("{} ({}) - {}", lang.native_name, lang.name, lang.code))
+}
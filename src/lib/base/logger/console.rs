@@ -11,10 +11,94 @@
 //! - 支持多种日志级别样式（success, error, warning, info, debug）
 //! - 支持分隔线样式（separator, separator_with_text）
 //! - 使用 ASCII 字符作为图标（✓✗⚠ℹ⚙）
+//!
+//! ## 颜色开关
+//!
+//! 是否输出颜色由 `colors_enabled()` 统一决定，所有带颜色的输出都应该通过 `styled()` 生成样式，
+//! 而不是各自判断。以下情况不输出颜色：
+//! - 指定了全局参数 `--no-color`
+//! - 设置了非空的 `NO_COLOR` 环境变量（<https://no-color.org>）
+//! - stdout 不是终端（如重定向到文件或管道）
 
+use crate::base::logger::log_format::{json_record, LogFormat};
 use crate::base::logger::log_level::LogLevel;
-use console::style;
+use crate::base::util::sensitive::SensitiveRegistry;
+use console::{style, StyledObject};
 use std::fmt;
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// ============================================================================
+// 颜色开关
+// ============================================================================
+
+/// 禁用颜色输出的环境变量
+pub const NO_COLOR_ENV: &str = "NO_COLOR";
+
+/// 颜色开关状态：尚未确定
+const COLORS_UNSET: u8 = 0;
+/// 颜色开关状态：启用
+const COLORS_ON: u8 = 1;
+/// 颜色开关状态：禁用
+const COLORS_OFF: u8 = 2;
+
+/// 当前进程的颜色开关
+static COLORS: AtomicU8 = AtomicU8::new(COLORS_UNSET);
+
+/// 根据参数、环境变量和终端检测是否应该输出颜色
+///
+/// # 参数
+/// * `no_color` - 是否指定了 `--no-color`
+pub fn detect_colors(no_color: bool) -> bool {
+    let env_disabled = std::env::var_os(NO_COLOR_ENV).is_some_and(|value| !value.is_empty());
+    !no_color && !env_disabled && std::io::stdout().is_terminal()
+}
+
+/// 初始化颜色开关（在解析命令行参数后调用）
+///
+/// # 参数
+/// * `no_color` - 是否指定了 `--no-color`
+pub fn init_colors(no_color: bool) {
+    set_colors_enabled(detect_colors(no_color));
+}
+
+/// 启用或禁用颜色输出（对当前进程生效）
+///
+/// 同时设置 console crate 的全局开关，使进度条和交互式对话框保持一致。
+pub fn set_colors_enabled(enabled: bool) {
+    COLORS.store(
+        if enabled { COLORS_ON } else { COLORS_OFF },
+        Ordering::Relaxed,
+    );
+    console::set_colors_enabled(enabled);
+    console::set_colors_enabled_stderr(enabled);
+}
+
+/// 是否输出颜色
+///
+/// 未调用 `init_colors` 或 `set_colors_enabled` 时，按 `detect_colors(false)` 检测。
+pub fn colors_enabled() -> bool {
+    match COLORS.load(Ordering::Relaxed) {
+        COLORS_ON => true,
+        COLORS_OFF => false,
+        _ => detect_colors(false),
+    }
+}
+
+/// 创建遵循颜色开关的样式
+///
+/// 与 `console::style` 用法相同，但是否输出颜色由 `colors_enabled()` 决定。
+///
+/// # 示例
+/// ```
+/// use workflow::base::logger::console::{set_colors_enabled, styled};
+///
+/// set_colors_enabled(false);
+/// assert_eq!(styled("done").green().to_string(), "done");
+/// ```
+pub fn styled<D>(val: D) -> StyledObject<D> {
+    style(val).force_styling(colors_enabled())
+}
 
 // ============================================================================
 // 颜色格式化函数
@@ -36,7 +120,7 @@ use std::fmt;
 /// log_message!("{}", msg);
 /// ```
 pub fn success(text: impl fmt::Display) -> String {
-    style(format!("✓ {}", text)).green().to_string()
+    styled(format!("✓ {}", text)).green().to_string()
 }
 
 /// 错误消息样式（红色 ✗）
@@ -55,7 +139,7 @@ pub fn success(text: impl fmt::Display) -> String {
 /// log_error!("{}", msg);
 /// ```
 pub fn error(text: impl fmt::Display) -> String {
-    style(format!("✗ {}", text)).red().to_string()
+    styled(format!("✗ {}", text)).red().to_string()
 }
 
 /// 警告消息样式（黄色 ⚠）
@@ -74,7 +158,7 @@ pub fn error(text: impl fmt::Display) -> String {
 /// log_warning!("{}", msg);
 /// ```
 pub fn warning(text: impl fmt::Display) -> String {
-    style(format!("⚠ {}", text)).yellow().to_string()
+    styled(format!("⚠ {}", text)).yellow().to_string()
 }
 
 /// 信息消息样式（蓝色 ℹ）
@@ -93,7 +177,7 @@ pub fn warning(text: impl fmt::Display) -> String {
 /// log_info!("{}", msg);
 /// ```
 pub fn info(text: impl fmt::Display) -> String {
-    style(format!("ℹ {}", text)).blue().to_string()
+    styled(format!("ℹ {}", text)).blue().to_string()
 }
 
 /// 调试消息样式（灰色 ⚙）
@@ -112,7 +196,7 @@ pub fn info(text: impl fmt::Display) -> String {
 /// log_debug!("{}", msg);
 /// ```
 pub fn debug(text: impl fmt::Display) -> String {
-    style(format!("⚙ {}", text)).bright().black().to_string()
+    styled(format!("⚙ {}", text)).bright().black().to_string()
 }
 
 /// 分隔线样式（灰色）
@@ -132,7 +216,7 @@ pub fn debug(text: impl fmt::Display) -> String {
 /// log_message!("{}", sep);
 /// ```
 pub fn separator(char: char, length: usize) -> String {
-    style(char.to_string().repeat(length)).bright().black().to_string()
+    styled(char.to_string().repeat(length)).bright().black().to_string()
 }
 
 /// 带文本的分隔线样式
@@ -161,7 +245,7 @@ pub fn separator_with_text(char: char, length: usize, text: impl fmt::Display) -
 
     // 如果文本长度大于等于总长度，直接输出文本
     if text_len >= length {
-        return style(text_str).bright().black().to_string();
+        return styled(text_str).bright().black().to_string();
     }
 
     // 计算左右两侧需要填充的字符数
@@ -175,12 +259,17 @@ pub fn separator_with_text(char: char, length: usize, text: impl fmt::Display) -
 
     format!(
         "{}{}{}",
-        style(left_sep).bright().black(),
+        styled(left_sep).bright().black(),
         text_str,
-        style(right_sep).bright().black()
+        styled(right_sep).bright().black()
     )
 }
 
+/// 隐藏日志内容中的敏感信息（见 `SensitiveRegistry`）
+fn scrub(message: impl fmt::Display) -> String {
+    SensitiveRegistry::global().scrub(&message.to_string())
+}
+
 // ============================================================================
 // Logger 结构体和实现
 // ============================================================================
@@ -189,21 +278,38 @@ pub fn separator_with_text(char: char, length: usize, text: impl fmt::Display) -
 ///
 /// 提供带颜色的日志输出功能，用于 Commands 层。
 /// 所有方法都会根据当前日志级别决定是否输出。
+///
+/// 成功、错误、警告、信息和调试消息会隐藏其中的敏感信息；使用 JSON 格式（`--log-format json`）时，
+/// 这些消息以 JSON 对象输出到 stderr，`print_message` 输出的命令结果仍输出到 stdout。
 pub struct Logger;
 
 impl Logger {
-    /// 打印成功消息（总是输出，不受日志级别限制）
+    /// JSON 格式日志的 target
+    pub const TARGET: &'static str = "workflow";
+
+    /// 打印成功消息（仅在日志级别 >= INFO 时输出）
     ///
-    /// 成功消息是命令执行结果的重要反馈，应该始终显示给用户。
+    /// 与信息消息同级：`-q` 或 `log.level` 为 warn/error/off 时不输出，只保留警告和错误。
     pub fn print_success(message: impl fmt::Display) {
-        println!("{}", success(message));
+        let current_level = LogLevel::current();
+        if current_level.should_log(LogLevel::Info) {
+            if LogFormat::current().is_json() {
+                Self::print_json("info", "success", message);
+                return;
+            }
+            println!("{}", success(scrub(message)));
+        }
     }
 
     /// 打印错误消息（仅在日志级别 >= ERROR 时输出）
     pub fn print_error(message: impl fmt::Display) {
         let current_level = LogLevel::current();
         if current_level.should_log(LogLevel::Error) {
-            eprintln!("{}", error(message));
+            if LogFormat::current().is_json() {
+                Self::print_json("error", "error", message);
+                return;
+            }
+            eprintln!("{}", error(scrub(message)));
         }
     }
 
@@ -211,7 +317,11 @@ impl Logger {
     pub fn print_warning(message: impl fmt::Display) {
         let current_level = LogLevel::current();
         if current_level.should_log(LogLevel::Warn) {
-            println!("{}", warning(message));
+            if LogFormat::current().is_json() {
+                Self::print_json("warn", "warning", message);
+                return;
+            }
+            println!("{}", warning(scrub(message)));
         }
     }
 
@@ -219,7 +329,11 @@ impl Logger {
     pub fn print_info(message: impl fmt::Display) {
         let current_level = LogLevel::current();
         if current_level.should_log(LogLevel::Info) {
-            println!("{}", info(message));
+            if LogFormat::current().is_json() {
+                Self::print_json("info", "info", message);
+                return;
+            }
+            println!("{}", info(scrub(message)));
         }
     }
 
@@ -227,10 +341,24 @@ impl Logger {
     pub fn print_debug(message: impl fmt::Display) {
         let current_level = LogLevel::current();
         if current_level.should_log(LogLevel::Debug) {
-            println!("{}", debug(message));
+            if LogFormat::current().is_json() {
+                Self::print_json("debug", "debug", message);
+                return;
+            }
+            println!("{}", debug(scrub(message)));
         }
     }
 
+    /// 以 JSON 格式输出一条日志到 stderr（`fields.kind` 为消息类型，如 `success`）
+    ///
+    /// 消息中的颜色控制字符会被去除。
+    fn print_json(level: &str, kind: &str, message: impl fmt::Display) {
+        let message = console::strip_ansi_codes(&message.to_string()).into_owned();
+        let mut fields = serde_json::Map::new();
+        fields.insert("kind".to_string(), serde_json::Value::from(kind));
+        eprintln!("{}", json_record(level, Self::TARGET, &message, fields));
+    }
+
     /// 打印说明信息（总是输出，不受日志级别限制）
     ///
     /// 用于输出 setup/check 等命令的说明信息，这些信息是指令性的，
@@ -240,7 +368,12 @@ impl Logger {
     }
 
     /// 打印分隔线
+    ///
+    /// JSON 格式下不输出（分隔线只用于终端中的排版）。
     pub fn print_separator(char: Option<char>, length: Option<usize>) {
+        if LogFormat::current().is_json() {
+            return;
+        }
         let char = char.unwrap_or('-');
         let length = length.unwrap_or(80);
         println!("{}", separator(char, length));
@@ -256,12 +389,20 @@ impl Logger {
     /// * `char` - 分隔符字符
     /// * `length` - 总长度
     /// * `text` - 要插入的文本
+    ///
+    /// JSON 格式下不输出。
     pub fn print_separator_with_text(char: char, length: usize, text: impl fmt::Display) {
+        if LogFormat::current().is_json() {
+            return;
+        }
         println!("{}", separator_with_text(char, length, text));
     }
 
-    /// 打印换行符
+    /// 打印换行符（JSON 格式下不输出）
     pub fn print_newline() {
+        if LogFormat::current().is_json() {
+            return;
+        }
         println!();
     }
 }
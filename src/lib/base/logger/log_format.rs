@@ -0,0 +1,126 @@
+//! 日志输出格式
+//!
+//! - `text`（默认）：带颜色的控制台输出，适合在终端中阅读
+//! - `json`：每条日志输出为一行 JSON 对象（输出到 stderr），适合在自动化脚本、CI 中采集
+//!
+//! 两种格式的日志内容都会经过 `SensitiveRegistry::global()` 处理，隐藏其中的密钥。
+
+use chrono::{Local, SecondsFormat};
+use serde_json::{Map, Value};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use crate::base::util::sensitive::SensitiveRegistry;
+
+/// 全局日志输出格式（存储 `LogFormat as u8`）
+static LOG_FORMAT: AtomicU8 = AtomicU8::new(LogFormat::Text as u8);
+
+/// 日志输出格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogFormat {
+    /// 带颜色的文本（默认）
+    #[default]
+    Text = 0,
+    /// 每行一个 JSON 对象
+    Json = 1,
+}
+
+impl LogFormat {
+    /// 设置当前进程的日志输出格式（在解析命令行参数后、初始化 tracing 之前调用）
+    pub fn set(format: Self) {
+        LOG_FORMAT.store(format as u8, Ordering::Relaxed);
+    }
+
+    /// 获取当前进程的日志输出格式（未设置时为 `Text`）
+    pub fn current() -> Self {
+        match LOG_FORMAT.load(Ordering::Relaxed) {
+            1 => LogFormat::Json,
+            _ => LogFormat::Text,
+        }
+    }
+
+    /// 是否为 JSON 格式
+    pub fn is_json(&self) -> bool {
+        *self == LogFormat::Json
+    }
+
+    /// 将 LogFormat 转换为字符串
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogFormat::Text => "text",
+            LogFormat::Json => "json",
+        }
+    }
+}
+
+impl fmt::Display for LogFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LogFormat {
+    type Err = String;
+
+    /// 从字符串转换为 LogFormat（不区分大小写）："text" 或 "json"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "text" => Ok(LogFormat::Text),
+            "json" => Ok(LogFormat::Json),
+            _ => Err(format!("Invalid log format: {}. Expected: text, json", s)),
+        }
+    }
+}
+
+/// 生成一条 JSON 格式的日志记录（不包含换行）
+///
+/// 记录包含 `timestamp`（本地时间，RFC 3339）、`level`、`target`、`message` 和 `fields`。
+/// `message` 和 `fields` 中的字符串会经过 `SensitiveRegistry::global()` 处理。
+///
+/// # 参数
+///
+/// * `level` - 日志级别（如 `"info"`）
+/// * `target` - 日志来源（如 `"workflow"`、`"workflow::http"`）
+/// * `message` - 日志内容
+/// * `fields` - 附加字段
+///
+/// # 示例
+///
+/// ```
+/// use serde_json::{Map, Value};
+/// use workflow::base::logger::log_format::json_record;
+///
+/// let mut fields = Map::new();
+/// fields.insert("kind".to_string(), Value::from("success"));
+///
+/// let record: Value = serde_json::from_str(&json_record("info", "workflow", "Done", fields)).unwrap();
+/// assert_eq!(record["level"], "info");
+/// assert_eq!(record["message"], "Done");
+/// assert_eq!(record["fields"]["kind"], "success");
+/// ```
+pub fn json_record(level: &str, target: &str, message: &str, fields: Map<String, Value>) -> String {
+    let registry = SensitiveRegistry::global();
+    let fields: Map<String, Value> = fields
+        .into_iter()
+        .map(|(key, value)| match value {
+            Value::String(text) => (key, Value::String(registry.scrub(&text))),
+            other => (key, other),
+        })
+        .collect();
+
+    let mut record = Map::new();
+    record.insert(
+        "timestamp".to_string(),
+        Value::String(Local::now().to_rfc3339_opts(SecondsFormat::Millis, false)),
+    );
+    record.insert("level".to_string(), Value::String(level.to_string()));
+    record.insert("target".to_string(), Value::String(target.to_string()));
+    record.insert(
+        "message".to_string(),
+        Value::String(registry.scrub(message)),
+    );
+    record.insert("fields".to_string(), Value::Object(fields));
+
+    Value::Object(record).to_string()
+}
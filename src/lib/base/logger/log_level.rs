@@ -1,3 +1,4 @@
+use serde::{Serialize, Serializer};
 use std::str::FromStr;
 use std::sync::Mutex;
 
@@ -94,6 +95,16 @@ impl LogLevel {
     }
 }
 
+impl Serialize for LogLevel {
+    /// 序列化为与 `as_str()` 相同的小写字符串（如 `"error"`），可被 `FromStr` 解析回来
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
 impl FromStr for LogLevel {
     type Err = String;
 
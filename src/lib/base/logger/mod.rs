@@ -2,15 +2,18 @@
 //!
 //! 本模块提供了日志相关的功能，包括：
 //! - `LogLevel` - 日志级别管理
+//! - `LogFormat` - 日志输出格式（text/json）
 //! - `Logger` - 用户友好的控制台日志输出（用于 Commands 层）
 //! - `Tracer` - 结构化日志记录（用于 Lib 层）
 //! - `console` - 颜色输出工具函数和 Logger 实现
 
 pub mod console;
+pub mod log_format;
 pub mod log_level;
 pub mod tracing;
 
 // 重新导出主要类型
 pub use console::Logger;
+pub use log_format::LogFormat;
 pub use log_level::LogLevel;
 pub use tracing::Tracer;
@@ -38,15 +38,32 @@
 //! // 从配置文件读取日志级别并初始化
 //! Tracer::init();
 //! ```
+//!
+//! ## 输出格式与敏感信息
+//!
+//! - 日志文件始终使用文本格式
+//! - 控制台（stderr）默认使用文本格式，`--log-format json` 时每条日志输出为一行 JSON 对象
+//!   （字段见 `log_format::json_record`）
+//! - 两种格式的输出都会经过 `SensitiveRegistry::global()` 处理，隐藏其中的密钥
 
+use crate::base::http::HttpTrace;
+use crate::base::logger::log_format::{json_record, LogFormat};
 use crate::base::settings::paths::Paths;
 use crate::base::settings::Settings;
 use crate::base::util::directory::DirectoryWalker;
+use crate::base::util::sensitive::SensitiveRegistry;
 use crate::base::LogLevel;
 use chrono::Local;
 use color_eyre::eyre::WrapErr;
+use serde_json::{Map, Value};
+use std::fmt;
 use std::fs::OpenOptions;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+use std::io::{self, Write};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::fmt::MakeWriter;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
 
 /// Tracing 封装结构体
 ///
@@ -55,6 +72,9 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilte
 pub struct Tracer;
 
 impl Tracer {
+    /// HTTP 跟踪日志的 tracing target
+    pub const HTTP_TARGET: &'static str = "workflow::http";
+
     /// 初始化 tracing subscriber（从配置读取日志级别）
     ///
     /// 根据配置的日志级别决定是否输出到文件或完全丢弃。
@@ -64,6 +84,10 @@ impl Tracer {
     /// 如果启用了 `enable_trace_console` 配置（为 `true`），tracing 日志会同时输出到文件和控制台（stderr）。
     /// 如果配置文件中不存在此字段（为 `None`），默认为 `false`（只输出到文件）。
     ///
+    /// 如果启用了 HTTP 跟踪（`HttpTrace`），无论日志级别如何，HTTP 跟踪日志都会输出到文件和控制台。
+    ///
+    /// 控制台输出的格式由 `LogFormat::current()` 决定，需在调用前通过 `LogFormat::set` 设置。
+    ///
     /// 日志级别从 `~/.workflow/config/workflow.toml` 配置文件中的 `log.level` 字段读取。
     /// 如果配置文件中未设置，则默认使用 "off"（不输出）。
     ///
@@ -76,57 +100,80 @@ impl Tracer {
     /// Tracer::init();
     /// ```
     pub fn init() {
+        Self::init_with_trace(false);
+    }
+
+    /// 初始化 tracing subscriber，`trace` 为 `true` 时（全局参数 `-vv`）忽略配置的日志级别，
+    /// 以 `debug` 级别同时输出到日志文件和控制台
+    pub fn init_with_trace(trace: bool) {
         let settings = Settings::get();
 
         // 从配置文件读取日志级别并解析为 LogLevel
-        let log_level = settings
-            .log
-            .level
-            .as_deref()
-            .and_then(|s| s.parse::<LogLevel>().ok())
-            .unwrap_or(LogLevel::None);
+        let log_level = if trace {
+            LogLevel::Debug
+        } else {
+            settings
+                .log
+                .level
+                .as_deref()
+                .and_then(|s| s.parse::<LogLevel>().ok())
+                .unwrap_or(LogLevel::None)
+        };
 
-        // 将 LogLevel 转换为 tracing 格式字符串
-        let tracing_filter = log_level.as_str();
+        // 将 LogLevel 转换为 tracing 格式字符串（启用 HTTP 跟踪时额外放开 HTTP 跟踪日志）
+        let http_trace = HttpTrace::is_enabled();
+        let tracing_filter = Self::filter_directives(log_level, http_trace);
 
         // 根据配置决定输出目标
-        if log_level != LogLevel::None {
+        if log_level != LogLevel::None || http_trace {
             // 决定是否同时输出到控制台
-            // 如果配置文件中设置了 enable_trace_console 为 true，则启用；否则默认为 false
-            let enable_console = settings.log.enable_trace_console.unwrap_or(false);
+            // 如果指定了 `-vv` 或配置文件中设置了 enable_trace_console 为 true，则启用；否则默认为 false
+            let enable_console = trace || settings.log.enable_trace_console.unwrap_or(false);
+
+            // 控制台 layer：启用 enable_trace_console 时输出所有日志，只启用 HTTP 跟踪时只输出 HTTP 跟踪日志
+            let console_filter = if enable_console {
+                Some(tracing_filter.clone())
+            } else if http_trace {
+                Some(Self::filter_directives(LogLevel::None, true))
+            } else {
+                None
+            };
+            let json = LogFormat::current().is_json();
+            let text_console_layer = console_filter.clone().filter(|_| !json).map(|filter| {
+                tracing_subscriber::fmt::layer()
+                    .with_writer(ScrubWriter(std::io::stderr))
+                    .with_filter(EnvFilter::new(filter))
+            });
+            let json_console_layer = console_filter
+                .filter(|_| json)
+                .map(|filter| JsonLayer.with_filter(EnvFilter::new(filter)));
 
             // 总是尝试输出到文件
             if let Ok(file_path) = Self::get_log_file_path() {
                 if let Ok(file) = OpenOptions::new().create(true).append(true).open(&file_path) {
-                    // 构建 registry，先添加 EnvFilter
-                    let registry =
-                        tracing_subscriber::registry().with(EnvFilter::new(tracing_filter));
-
-                    // 添加文件 layer
-                    let registry =
-                        registry.with(tracing_subscriber::fmt::layer().with_writer(file));
-
-                    // 如果启用了控制台输出，同时添加 console layer
-                    if enable_console {
-                        let _ = registry
-                            .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
-                            .try_init();
-                    } else {
-                        let _ = registry.try_init();
-                    }
+                    // 构建 registry，先添加 EnvFilter，再添加文件 layer 和控制台 layer
+                    let _ = tracing_subscriber::registry()
+                        .with(EnvFilter::new(&tracing_filter))
+                        .with(tracing_subscriber::fmt::layer().with_writer(ScrubWriter(file)))
+                        .with(text_console_layer)
+                        .with(json_console_layer)
+                        .try_init();
                     return;
                 }
             }
 
             // 如果文件创建失败，回退到 stderr
             let _ = tracing_subscriber::registry()
-                .with(EnvFilter::new(tracing_filter))
-                .with(tracing_subscriber::fmt::layer().with_writer(std::io::stderr))
+                .with(EnvFilter::new(&tracing_filter))
+                .with((!json).then(|| {
+                    tracing_subscriber::fmt::layer().with_writer(ScrubWriter(std::io::stderr))
+                }))
+                .with(json.then_some(JsonLayer))
                 .try_init();
         } else {
             // 否则输出到 /dev/null（完全丢弃）
             let _ = tracing_subscriber::registry()
-                .with(EnvFilter::new(tracing_filter))
+                .with(EnvFilter::new(&tracing_filter))
                 .with(tracing_subscriber::fmt::layer().with_writer(std::io::sink))
                 .try_init();
         }
@@ -202,6 +249,124 @@ impl Tracer {
     pub fn error_fmt(args: std::fmt::Arguments) {
         tracing::error!("{}", args);
     }
+
+    /// 记录 HTTP 跟踪日志（使用独立的 target `HTTP_TARGET`，见 `HttpTrace`）
+    #[inline]
+    pub fn http_fmt(args: std::fmt::Arguments) {
+        tracing::debug!(target: Tracer::HTTP_TARGET, "{}", args);
+    }
+
+    /// 生成 tracing 过滤指令
+    ///
+    /// 启用 HTTP 跟踪时，在配置的日志级别之外额外放开 `HTTP_TARGET` 的调试日志。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use workflow::{LogLevel, Tracer};
+    ///
+    /// assert_eq!(Tracer::filter_directives(LogLevel::Warn, false), "warn");
+    /// assert_eq!(Tracer::filter_directives(LogLevel::None, true), "off,workflow::http=debug");
+    /// ```
+    pub fn filter_directives(log_level: LogLevel, http_trace: bool) -> String {
+        if http_trace {
+            format!("{},{}=debug", log_level.as_str(), Self::HTTP_TARGET)
+        } else {
+            log_level.as_str().to_string()
+        }
+    }
+}
+
+/// 以 JSON 格式输出日志到 stderr 的 tracing layer
+///
+/// 每个事件输出一行（见 `log_format::json_record`），事件中除 `message` 以外的字段放入 `fields`。
+struct JsonLayer;
+
+impl<S: Subscriber> Layer<S> for JsonLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = JsonVisitor::default();
+        event.record(&mut visitor);
+
+        let metadata = event.metadata();
+        let line = json_record(
+            &metadata.level().to_string().to_lowercase(),
+            metadata.target(),
+            &visitor.message,
+            visitor.fields,
+        );
+        let _ = writeln!(io::stderr().lock(), "{}", line);
+    }
+}
+
+/// 收集事件的消息和字段
+#[derive(Default)]
+struct JsonVisitor {
+    message: String,
+    fields: Map<String, Value>,
+}
+
+impl JsonVisitor {
+    fn insert(&mut self, field: &Field, value: Value) {
+        if field.name() == "message" {
+            self.message = match value {
+                Value::String(text) => text,
+                other => other.to_string(),
+            };
+        } else {
+            self.fields.insert(field.name().to_string(), value);
+        }
+    }
+}
+
+impl Visit for JsonVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.insert(field, Value::from(value));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.insert(field, Value::from(format!("{:?}", value)));
+    }
+}
+
+/// 隐藏敏感信息的 writer（包装 tracing 的 `MakeWriter`）
+///
+/// fmt layer 每个事件只写入一次，因此可以按写入的内容整体处理。
+struct ScrubWriter<M>(M);
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for ScrubWriter<M> {
+    type Writer = ScrubWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        ScrubWriter(self.0.make_writer())
+    }
+}
+
+impl<W: Write> Write for ScrubWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let text = String::from_utf8_lossy(buf);
+        self.0.write_all(SensitiveRegistry::global().scrub(&text).as_bytes())?;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.0.flush()
+    }
 }
 
 /// 格式化并记录调试级别的日志
@@ -0,0 +1,283 @@
+//! MCP 服务器（stdio 传输）
+//!
+//! 实现 MCP（Model Context Protocol）服务器端的协议处理：
+//! - 传输：标准输入输出，每条 JSON-RPC 2.0 消息占一行（消息内不包含换行符）
+//! - 生命周期：`initialize` 协商协议版本，`notifications/initialized` 等通知不需要响应
+//! - 工具：`tools/list` 返回工具定义，`tools/call` 调用工具
+//! - 其他：`ping`
+//!
+//! 具体的工具由 `McpToolHandler` 提供，本模块只负责消息的解析、分发和响应的构造。
+//! 工具执行失败时按 MCP 规范返回 `isError: true` 的结果（而不是 JSON-RPC 错误），
+//! 便于调用方把错误信息交给模型处理。
+
+use std::io::{BufRead, Write};
+
+use color_eyre::{eyre::WrapErr, Result};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+
+/// JSON-RPC 版本
+pub const JSONRPC_VERSION: &str = "2.0";
+
+/// 服务器支持的 MCP 协议版本（从新到旧）
+pub const SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &["2025-06-18", "2025-03-26", "2024-11-05"];
+
+/// JSON-RPC 错误码：无法解析的 JSON
+pub const PARSE_ERROR: i64 = -32700;
+/// JSON-RPC 错误码：不是有效的请求对象
+pub const INVALID_REQUEST: i64 = -32600;
+/// JSON-RPC 错误码：方法不存在
+pub const METHOD_NOT_FOUND: i64 = -32601;
+/// JSON-RPC 错误码：参数无效（如未知的工具名称）
+pub const INVALID_PARAMS: i64 = -32602;
+
+/// 工具定义（`tools/list` 返回的条目）
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct McpTool {
+    /// 工具名称（如 `jira_info`）
+    pub name: String,
+    /// 工具说明（供模型选择工具）
+    pub description: String,
+    /// 参数的 JSON Schema（`type` 为 `object`）
+    pub input_schema: Value,
+}
+
+impl McpTool {
+    /// 创建工具定义
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 工具名称
+    /// * `description` - 工具说明
+    /// * `properties` - 参数定义（JSON Schema 的 `properties`）
+    /// * `required` - 必需的参数名称
+    pub fn new(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        properties: Value,
+        required: &[&str],
+    ) -> Self {
+        Self {
+            name: name.into(),
+            description: description.into(),
+            input_schema: json!({
+                "type": "object",
+                "properties": properties,
+                "required": required,
+            }),
+        }
+    }
+}
+
+/// MCP 工具提供者
+///
+/// 由命令层实现，将工具调用委托给库中的业务逻辑。
+pub trait McpToolHandler {
+    /// 所有可用的工具定义
+    fn tools(&self) -> Vec<McpTool>;
+
+    /// 调用工具
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 工具名称（已确认在 `tools()` 中）
+    /// * `arguments` - 工具参数（JSON 对象，调用方未提供时为空对象）
+    ///
+    /// # 返回
+    ///
+    /// 返回工具的文本输出。
+    ///
+    /// # 错误
+    ///
+    /// 参数无效或执行失败时返回错误，错误信息会作为 `isError: true` 的结果返回给调用方。
+    fn call(&self, name: &str, arguments: &Map<String, Value>) -> Result<String>;
+}
+
+/// MCP 服务器
+pub struct McpServer<H: McpToolHandler> {
+    /// 服务器名称（`initialize` 响应中的 `serverInfo.name`）
+    name: String,
+    /// 服务器版本
+    version: String,
+    /// 工具提供者
+    handler: H,
+}
+
+impl<H: McpToolHandler> McpServer<H> {
+    /// 创建 MCP 服务器
+    pub fn new(name: impl Into<String>, version: impl Into<String>, handler: H) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+            handler,
+        }
+    }
+
+    /// 在指定的输入输出上运行服务器
+    ///
+    /// 逐行读取消息并写出响应（每个响应一行，写出后立即 flush），输入结束（EOF）时返回。
+    /// 空行会被忽略。
+    ///
+    /// # 错误
+    ///
+    /// 读取输入或写出响应失败时返回错误。
+    pub fn serve(&self, reader: impl BufRead, mut writer: impl Write) -> Result<()> {
+        for line in reader.lines() {
+            let line = line.wrap_err("Failed to read MCP message")?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(response) = self.handle_message(&line) {
+                writeln!(writer, "{}", response).wrap_err("Failed to write MCP response")?;
+                writer.flush().wrap_err("Failed to flush MCP response")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 处理一条消息
+    ///
+    /// # 参数
+    ///
+    /// * `message` - 一行 JSON-RPC 消息
+    ///
+    /// # 返回
+    ///
+    /// 返回序列化后的响应（单行 JSON）；通知和客户端发来的响应不需要回复，返回 `None`。
+    pub fn handle_message(&self, message: &str) -> Option<String> {
+        let response = match serde_json::from_str::<Value>(message) {
+            Ok(value) => self.handle_value(value)?,
+            Err(e) => error_response(Value::Null, PARSE_ERROR, format!("Parse error: {}", e)),
+        };
+        Some(response.to_string())
+    }
+
+    /// 处理解析后的消息
+    fn handle_value(&self, value: Value) -> Option<Value> {
+        let Value::Object(mut message) = value else {
+            return Some(error_response(
+                Value::Null,
+                INVALID_REQUEST,
+                "Invalid request: expected a JSON object",
+            ));
+        };
+
+        let id = message.remove("id");
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            // 没有 method 的消息是客户端的响应（本服务器不发送请求），忽略
+            return match id {
+                Some(id) if !message.contains_key("result") && !message.contains_key("error") => {
+                    Some(error_response(
+                        id,
+                        INVALID_REQUEST,
+                        "Invalid request: missing method",
+                    ))
+                }
+                _ => None,
+            };
+        };
+
+        // 没有 id 的消息是通知，不需要响应
+        let id = id?;
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        Some(match self.dispatch(method, &params) {
+            Ok(result) => json!({ "jsonrpc": JSONRPC_VERSION, "id": id, "result": result }),
+            Err((code, message)) => error_response(id, code, message),
+        })
+    }
+
+    /// 按方法分发请求
+    fn dispatch(&self, method: &str, params: &Value) -> std::result::Result<Value, (i64, String)> {
+        match method {
+            "initialize" => Ok(self.initialize(params)),
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": self.handler.tools() })),
+            "tools/call" => self.call_tool(params),
+            _ => Err((METHOD_NOT_FOUND, format!("Method not found: {}", method))),
+        }
+    }
+
+    /// 处理 `initialize` 请求
+    ///
+    /// 客户端请求的协议版本受支持时使用该版本，否则返回服务器支持的最新版本。
+    fn initialize(&self, params: &Value) -> Value {
+        let requested = params.get("protocolVersion").and_then(Value::as_str);
+        let protocol_version = requested
+            .filter(|version| SUPPORTED_PROTOCOL_VERSIONS.contains(version))
+            .unwrap_or(SUPPORTED_PROTOCOL_VERSIONS[0]);
+
+        json!({
+            "protocolVersion": protocol_version,
+            "capabilities": { "tools": { "listChanged": false } },
+            "serverInfo": { "name": self.name, "version": self.version },
+        })
+    }
+
+    /// 处理 `tools/call` 请求
+    fn call_tool(&self, params: &Value) -> std::result::Result<Value, (i64, String)> {
+        let name = params
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or((INVALID_PARAMS, "Missing tool name".to_string()))?;
+        if !self.handler.tools().iter().any(|tool| tool.name == name) {
+            return Err((INVALID_PARAMS, format!("Unknown tool: {}", name)));
+        }
+
+        let arguments = match params.get("arguments") {
+            None | Some(Value::Null) => Map::new(),
+            Some(Value::Object(arguments)) => arguments.clone(),
+            Some(_) => {
+                return Err((
+                    INVALID_PARAMS,
+                    "Tool arguments must be a JSON object".to_string(),
+                ))
+            }
+        };
+
+        let (text, is_error) = match self.handler.call(name, &arguments) {
+            Ok(output) => (output, false),
+            Err(e) => (format!("{:#}", e), true),
+        };
+        Ok(json!({
+            "content": [{ "type": "text", "text": text }],
+            "isError": is_error,
+        }))
+    }
+}
+
+/// 构造 JSON-RPC 错误响应
+fn error_response(id: Value, code: i64, message: impl Into<String>) -> Value {
+    json!({
+        "jsonrpc": JSONRPC_VERSION,
+        "id": id,
+        "error": { "code": code, "message": message.into() },
+    })
+}
+
+/// 读取必需的字符串参数
+///
+/// # 错误
+///
+/// 参数不存在、不是字符串或为空字符串时返回错误。
+pub fn required_str<'a>(arguments: &'a Map<String, Value>, key: &str) -> Result<&'a str> {
+    optional_str(arguments, key)?
+        .ok_or_else(|| color_eyre::eyre::eyre!("Missing required argument: {}", key))
+}
+
+/// 读取可选的字符串参数（空字符串视为未提供）
+///
+/// # 错误
+///
+/// 参数存在但不是字符串时返回错误。
+pub fn optional_str<'a>(arguments: &'a Map<String, Value>, key: &str) -> Result<Option<&'a str>> {
+    match arguments.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(value)) => Ok((!value.trim().is_empty()).then_some(value.as_str())),
+        Some(_) => Err(color_eyre::eyre::eyre!(
+            "Argument '{}' must be a string",
+            key
+        )),
+    }
+}
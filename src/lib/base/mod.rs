@@ -2,7 +2,7 @@
 //!
 //! 本模块包含所有不关联业务的通用基础设施代码，包括：
 //! - HTTP 客户端和网络工具
-//! - 日志功能（LogLevel, Logger, Tracer）
+//! - 日志功能（LogLevel, LogFormat, Logger, Tracer）
 //! - 格式化工具（format - MessageFormatter, DisplayFormatter）
 //! - 工具函数（string, platform, browser, clipboard, checksum, unzip）
 //! - 交互式对话框（InputDialog, SelectDialog, MultiSelectDialog, ConfirmDialog）
@@ -33,14 +33,15 @@ pub mod util;
 pub use alias::{AliasManager, CommandsConfig};
 pub use concurrent::{ConcurrentExecutor, TaskResult};
 pub use dialog::{
-    ConfirmDialog, FormBuilder, FormResult, InputDialog, MultiSelectDialog, SelectDialog,
+    ConfirmDialog, EditorDialog, FormBuilder, FormResult, InputDialog, MultiSelectDialog,
+    SelectDialog,
 };
 pub use format::DisplayFormatter;
 pub use http::{Authorization, HttpClient, HttpResponse, HttpRetry, HttpRetryConfig};
 pub use indicator::{Progress, Spinner};
-pub use logger::{LogLevel, Logger, Tracer};
+pub use logger::{LogFormat, LogLevel, Logger, Tracer};
 pub use prompt::GENERATE_BRANCH_SYSTEM_PROMPT;
 pub use settings::{LLMSettings, Paths, Settings};
-pub use shell::{Detect, Reload, ShellConfigManager};
-pub use table::{TableBuilder, TableStyle};
+pub use shell::{Detect, Reload, Shell, ShellConfigEdit, ShellConfigManager, ShellConfigPreview};
+pub use table::{TableBuilder, TableOverflow, TableStyle};
 pub use util::{mask_sensitive_value, Browser, Checksum, Clipboard, Unzip};
@@ -0,0 +1,61 @@
+//! 自定义 prompt 文件
+//!
+//! `[prompt]` 配置可以指向用户自己的 prompt 文件，在运行时替换内置的 system prompt：
+//!
+//! ```toml
+//! [prompt]
+//! generate_branch_path = "~/.workflow/prompts/generate_branch.md"
+//! summarize_pr_path = "~/.workflow/prompts/summarize_pr.md"
+//! ```
+//!
+//! 文件不存在、无法读取或内容为空时输出警告，并回退到内置 prompt，不会中断命令。
+
+use color_eyre::{eyre::WrapErr, Result};
+
+use crate::base::settings::paths::Paths;
+use crate::base::util::file::FileReader;
+use crate::log_warning;
+
+/// 读取自定义 prompt 文件
+///
+/// 路径支持 `~` 展开。文件不存在、不是普通文件或内容为空（只包含空白字符）时返回错误。
+///
+/// # 返回
+///
+/// 返回去除首尾空白后的 prompt 内容。
+pub fn read_prompt_file(path: &str) -> Result<String> {
+    let expanded =
+        Paths::expand(path).wrap_err_with(|| format!("Failed to expand path '{}'", path))?;
+    if !expanded.is_file() {
+        color_eyre::eyre::bail!("Prompt file does not exist: {}", expanded.display());
+    }
+
+    let content = FileReader::new(&expanded).to_string()?;
+    let content = content.trim();
+    if content.is_empty() {
+        color_eyre::eyre::bail!("Prompt file is empty: {}", expanded.display());
+    }
+    Ok(content.to_string())
+}
+
+/// 加载自定义 prompt
+///
+/// # 参数
+///
+/// * `field` - 配置键（如 `prompt.summarize_pr_path`），用于警告信息
+/// * `path` - 配置的文件路径，未配置时为 `None`
+///
+/// # 返回
+///
+/// 文件可用时返回其内容；未配置时返回 `None`；文件不可用时输出警告并返回 `None`，
+/// 由调用方使用内置 prompt。
+pub fn load_prompt_override(field: &str, path: Option<&str>) -> Option<String> {
+    let path = path.map(str::trim).filter(|path| !path.is_empty())?;
+    match read_prompt_file(path) {
+        Ok(content) => Some(content),
+        Err(e) => {
+            log_warning!("{}: {}, using built-in prompt", field, e);
+            None
+        }
+    }
+}
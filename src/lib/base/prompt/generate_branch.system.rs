@@ -2,6 +2,9 @@
 //!
 //! 用于根据 commit 标题和 git 变更生成分支名、PR 标题和描述。
 
+use super::custom::load_prompt_override;
+use crate::base::settings::Settings;
+
 /// 生成分支名的 system prompt
 ///
 /// 用于根据 commit 标题和 git 变更生成分支名、PR 标题和描述。
@@ -110,3 +113,15 @@ Return your response in JSON format with four fields: `branch_name`, `pr_title`,
   "description": "- Update README with new features\n- Fix typos in API documentation"
 }
 ```"#;
+
+/// 获取生成分支名的 system prompt
+///
+/// 配置了 `prompt.generate_branch_path` 且文件可用时使用自定义 prompt，
+/// 否则使用内置的 `GENERATE_BRANCH_SYSTEM_PROMPT`。
+pub fn generate_branch_system_prompt() -> String {
+    load_prompt_override(
+        "prompt.generate_branch_path",
+        Settings::get().prompt.generate_branch_path.as_deref(),
+    )
+    .unwrap_or_else(|| GENERATE_BRANCH_SYSTEM_PROMPT.to_string())
+}
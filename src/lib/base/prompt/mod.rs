@@ -3,6 +3,9 @@
 //! 本模块提供了 Prompt 常量定义。
 //! Prompt 内容作为编译时常量直接嵌入到二进制文件中。
 //!
+//! 分支名生成和 PR 总结的 prompt 可以通过 `[prompt]` 配置替换为用户自己的 prompt 文件，
+//! 内置常量作为默认值。
+//!
 //! ## 使用示例
 //!
 //! ```rust
@@ -11,6 +14,15 @@
 //! // 直接使用编译时嵌入的 prompt
 //! let system_prompt = GENERATE_BRANCH_SYSTEM_PROMPT.to_string();
 //! ```
+//!
+//! ```rust,no_run
+//! use workflow::base::prompt::generate_branch_system_prompt;
+//!
+//! // 优先使用 `prompt.generate_branch_path` 指向的自定义 prompt
+//! let system_prompt = generate_branch_system_prompt();
+//! ```
+
+pub mod custom;
 
 #[path = "generate_branch.system.rs"]
 pub mod generate_branch_system;
@@ -24,7 +36,8 @@ pub mod summarize_pr_system;
 pub mod translate_system;
 
 // 重新导出公共 API
-pub use generate_branch_system::GENERATE_BRANCH_SYSTEM_PROMPT;
+pub use custom::{load_prompt_override, read_prompt_file};
+pub use generate_branch_system::{generate_branch_system_prompt, GENERATE_BRANCH_SYSTEM_PROMPT};
 pub use reword_pr_system::REWORD_PR_SYSTEM_PROMPT;
 // 从 LLM 模块重新导出语言相关 API（保持向后兼容）
 pub use crate::base::llm::{
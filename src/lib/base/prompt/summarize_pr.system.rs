@@ -2,7 +2,9 @@
 //!
 //! 用于根据 PR 的 diff 内容生成总结文档。
 
+use super::custom::load_prompt_override;
 use crate::base::llm::get_language_requirement;
+use crate::base::settings::Settings;
 
 /// 根据语言生成 PR 总结的 system prompt
 ///
@@ -14,7 +16,17 @@ use crate::base::llm::get_language_requirement;
 ///
 /// 语言选择优先级：配置文件 > 默认值（"en"）
 /// 如果配置文件中的语言代码不在支持列表中，将使用英文作为默认语言。
+///
+/// 配置了 `prompt.summarize_pr_path` 且文件可用时，使用自定义 prompt 替换内置 prompt，
+/// 语言要求仍会追加到自定义 prompt 上。
 pub fn generate_summarize_pr_system_prompt() -> String {
+    if let Some(custom_prompt) = load_prompt_override(
+        "prompt.summarize_pr_path",
+        Settings::get().prompt.summarize_pr_path.as_deref(),
+    ) {
+        return get_language_requirement(&custom_prompt);
+    }
+
     // 获取 JSON 响应示例
     let summarize_response_example =     "{
       \"summary\": \"# Add User Authentication\\n\\n## Overview\\nThis PR adds user authentication functionality to the application.\\n\\n## Requirements Analysis\\n\\n### Business Requirements\\nDevelopers need a secure way to authenticate users...\\n\\n### Functional Requirements\\nThe system accepts user credentials and returns authentication tokens...\\n\\n## Key Changes\\n- Added login endpoint\\n- Implemented JWT token generation\\n\\n## Files Changed\\n- `src/auth/login.ts`: Added login handler\\n- `src/auth/jwt.ts`: Added token generation\\n\\n## Technical Details\\nImplemented JWT-based authentication:\\n\\n```typescript\\nfunction generateToken(user: User): string {\\n  return jwt.sign({ userId: user.id }, secret);\\n}\\n```\\n\\n## Testing\\nAdded unit tests for authentication flow.\\n\\n## Usage Instructions\\nRun `npm run test` to execute tests.\",
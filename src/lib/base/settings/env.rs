@@ -0,0 +1,171 @@
+//! 环境变量配置覆盖
+//!
+//! 在加载 `workflow.toml` 之后，使用 `WORKFLOW_` 前缀的环境变量覆盖对应配置字段，
+//! 方便在 CI 和容器环境中无需修改配置文件即可调整配置。
+//!
+//! ## 命名规则
+//!
+//! - 前缀：`WORKFLOW_`
+//! - 嵌套分隔符：`__`（双下划线），字段名不区分大小写
+//! - 例如：`WORKFLOW_JIRA__SERVICE_ADDRESS` 覆盖 `jira.service_address`
+//!
+//! 对于 LLM 配置，`WORKFLOW_LLM__API_KEY`（或 `WORKFLOW_LLM__KEY`）、`WORKFLOW_LLM__MODEL`、
+//! `WORKFLOW_LLM__URL` 和 `WORKFLOW_LLM__MAX_DIFF_CHARS` 会作用于当前 provider（`llm.provider`）的配置。
+//!
+//! 不包含嵌套分隔符的变量（如 `WORKFLOW_CONFIG_DIR`）不会被视为配置覆盖。
+
+use toml::Value;
+
+use super::settings::Settings;
+
+/// 环境变量前缀
+pub const ENV_PREFIX: &str = "WORKFLOW_";
+
+/// 环境变量嵌套分隔符
+pub const ENV_NESTING_SEPARATOR: &str = "__";
+
+/// 单个生效的环境变量覆盖
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvOverride {
+    /// 环境变量名称（如 `WORKFLOW_JIRA__SERVICE_ADDRESS`）
+    pub var: String,
+    /// 被覆盖的配置字段路径（如 `jira.service_address`）
+    pub key: String,
+}
+
+impl Settings {
+    /// 使用当前进程的环境变量覆盖配置
+    ///
+    /// # 返回
+    ///
+    /// 返回实际生效的覆盖列表（无法解析或字段不存在的变量会被忽略）。
+    pub fn apply_env_overrides(&mut self) -> Vec<EnvOverride> {
+        self.apply_overrides_from(std::env::vars())
+    }
+
+    /// 使用指定的变量列表覆盖配置
+    ///
+    /// 只处理带有 `WORKFLOW_` 前缀且包含 `__` 嵌套分隔符的变量。
+    /// 值优先按字符串处理，如果目标字段不是字符串类型，再尝试按布尔值或整数解析。
+    ///
+    /// # 参数
+    ///
+    /// * `vars` - `(变量名, 变量值)` 列表
+    ///
+    /// # 返回
+    ///
+    /// 返回实际生效的覆盖列表。
+    pub fn apply_overrides_from<I>(&mut self, vars: I) -> Vec<EnvOverride>
+    where
+        I: IntoIterator<Item = (String, String)>,
+    {
+        let mut candidates: Vec<(String, Vec<String>, String)> = vars
+            .into_iter()
+            .filter_map(|(var, value)| {
+                let path = Self::parse_env_key(&var)?;
+                Some((var, path, value))
+            })
+            .collect();
+
+        // 先应用 provider 等普通字段，再应用依赖当前 provider 的 LLM 简写字段
+        candidates.sort_by_key(|(var, path, _)| (Self::is_llm_shorthand(path), var.clone()));
+
+        let mut applied = Vec::new();
+        for (var, path, value) in candidates {
+            let path = self.resolve_llm_shorthand(path);
+            if let Some(updated) = Self::try_override(self, &path, &value) {
+                *self = updated;
+                applied.push(EnvOverride {
+                    var,
+                    key: path.join("."),
+                });
+            }
+        }
+
+        applied
+    }
+
+    /// 将环境变量名解析为配置字段路径
+    fn parse_env_key(var: &str) -> Option<Vec<String>> {
+        let rest = var.strip_prefix(ENV_PREFIX)?;
+        if !rest.contains(ENV_NESTING_SEPARATOR) {
+            return None;
+        }
+
+        let path: Vec<String> = rest
+            .split(ENV_NESTING_SEPARATOR)
+            .map(|segment| segment.to_lowercase())
+            .collect();
+        if path.iter().any(|segment| segment.is_empty()) {
+            return None;
+        }
+        Some(path)
+    }
+
+    /// 是否为作用于当前 LLM provider 的简写字段
+    fn is_llm_shorthand(path: &[String]) -> bool {
+        path.len() == 2
+            && path[0] == "llm"
+            && matches!(
+                path[1].as_str(),
+                "api_key" | "key" | "model" | "url" | "max_diff_chars"
+            )
+    }
+
+    /// 将 LLM 简写字段解析为当前 provider 的字段路径
+    fn resolve_llm_shorthand(&self, path: Vec<String>) -> Vec<String> {
+        if !Self::is_llm_shorthand(&path) {
+            return path;
+        }
+
+        let field = match path[1].as_str() {
+            "api_key" => "key".to_string(),
+            other => other.to_string(),
+        };
+        vec!["llm".to_string(), self.llm.provider.clone(), field]
+    }
+
+    /// 尝试将单个覆盖应用到配置上
+    ///
+    /// 依次尝试字符串、布尔值、整数，返回第一个能成功反序列化的结果。
+    fn try_override(settings: &Settings, path: &[String], raw: &str) -> Option<Settings> {
+        let base = Value::try_from(settings).ok()?;
+
+        let mut candidates = vec![Value::String(raw.to_string())];
+        if let Ok(value) = raw.parse::<bool>() {
+            candidates.push(Value::Boolean(value));
+        }
+        if let Ok(value) = raw.parse::<i64>() {
+            candidates.push(Value::Integer(value));
+        }
+
+        candidates.into_iter().find_map(|candidate| {
+            let mut root = base.clone();
+            Self::set_value(&mut root, path, candidate)?;
+            let updated: Settings = root.try_into().ok()?;
+            // 确保字段确实存在（未知字段会被忽略，需检查值是否写入）
+            let check = Value::try_from(&updated).ok()?;
+            Self::get_value(&check, path)?;
+            Some(updated)
+        })
+    }
+
+    /// 在 TOML 值中按路径设置字段（自动创建中间表）
+    fn set_value(root: &mut Value, path: &[String], value: Value) -> Option<()> {
+        let (last, parents) = path.split_last()?;
+        let mut current = root;
+        for segment in parents {
+            let table = current.as_table_mut()?;
+            current = table
+                .entry(segment.clone())
+                .or_insert_with(|| Value::Table(toml::map::Map::new()));
+        }
+        current.as_table_mut()?.insert(last.clone(), value);
+        Some(())
+    }
+
+    /// 在 TOML 值中按路径读取字段
+    fn get_value<'a>(root: &'a Value, path: &[String]) -> Option<&'a Value> {
+        path.iter().try_fold(root, |current, segment| current.get(segment))
+    }
+}
@@ -1,12 +1,20 @@
 //! Settings 模块
 //! 用于管理应用程序的各种设置和配置
 
+pub mod env;
 pub mod paths;
+pub mod profile;
+pub mod secrets;
 #[allow(clippy::module_inception)]
 pub mod settings;
 pub mod table;
+pub mod validation;
 
 // 导出公共类型和函数
-pub use paths::Paths;
-pub use settings::{LLMSettings, Settings};
-pub use table::{GitHubAccountListRow, GitHubAccountRow, JiraConfigRow, LLMConfigRow};
+pub use env::{EnvOverride, ENV_NESTING_SEPARATOR, ENV_PREFIX};
+pub use paths::{Paths, CONFIG_ENV};
+pub use profile::{Profile, DEFAULT_PROFILE, PROFILE_ENV};
+pub use secrets::OMITTED_SECRET;
+pub use settings::{JiraAuthType, LLMSettings, Settings};
+pub use table::{GitHubAccountListRow, GitHubAccountRow, JiraConfigRow, LLMConfigRow, ProfileRow};
+pub use validation::{ConfigIssue, IssueSeverity};
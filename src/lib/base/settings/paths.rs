@@ -1,10 +1,12 @@
 //! 路径管理
 //!
 //! 统一管理所有路径信息，包括：
-//! - 配置文件路径（存储在 `~/.workflow/config/` 目录下）
+//! - 配置文件路径（存储在 `~/.workflow/config/` 目录下，可通过 `--config` 或 `WORKFLOW_CONFIG` 指定其他位置）
 //! - 安装路径（二进制文件和补全脚本的安装路径和名称）
 //! - Shell 相关路径（shell 配置文件和 completion 目录）
 
+use super::profile::Profile;
+use crate::base::shell::Shell;
 use crate::base::util::directory::DirectoryWalker;
 
 // 配置文件和目录名称常量
@@ -14,14 +16,18 @@ pub const WORKFLOW_CONFIG_FILE: &str = "workflow.toml";
 pub const JIRA_CONFIG_FILE: &str = "jira.toml";
 pub const LLM_CONFIG_FILE: &str = "llm.toml";
 pub const COMPLETIONS_FILE: &str = ".completions";
-use clap_complete::shells::Shell;
+pub const PROFILES_DIR: &str = "profiles";
+pub const ACTIVE_PROFILE_FILE: &str = "active_profile";
+
+/// 指定配置文件或配置目录的环境变量（命令行参数 `--config` 在入口处写入此变量）
+pub const CONFIG_ENV: &str = "WORKFLOW_CONFIG";
 use color_eyre::{
-    eyre::{ContextCompat, WrapErr},
+    eyre::{eyre, ContextCompat, WrapErr},
     Result,
 };
 use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
@@ -93,10 +99,9 @@ impl Paths {
 
     /// 非 macOS 平台：总是返回 None
     ///
-    /// 注意：此函数在非 macOS 平台上不会被调用（调用处被 `#[cfg(target_os = "macos")]` 包裹），
-    /// 但为了保持 trait 实现的一致性，需要提供此实现。
+    /// 注意：`config_base_dir()` 中的调用被 `#[cfg(target_os = "macos")]` 包裹，
+    /// `workflow_dirs()` 则在所有平台上调用此函数。
     #[cfg(not(target_os = "macos"))]
-    #[allow(dead_code)] // 在非 macOS 平台上不会被调用，但需要提供实现以保持一致性
     fn try_icloud_base_dir() -> Option<PathBuf> {
         None
     }
@@ -241,11 +246,71 @@ impl Paths {
 
     // ==================== 配置路径相关方法 ====================
 
+    /// 获取 `--config`/`WORKFLOW_CONFIG` 指定的配置路径
+    ///
+    /// 路径可以是配置文件（替代 `workflow.toml`，其他配置文件从同一目录读取），
+    /// 也可以是配置目录（替代 `~/.workflow/config/`）。支持 `~` 展开。
+    ///
+    /// # 返回
+    ///
+    /// 未设置或为空时返回 `None`。
+    pub fn config_override() -> Option<PathBuf> {
+        let value = env::var(CONFIG_ENV).ok()?;
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+        Some(Self::expand(value).unwrap_or_else(|_| PathBuf::from(value)))
+    }
+
+    /// 校验 `--config`/`WORKFLOW_CONFIG` 指定的配置路径
+    ///
+    /// # 错误
+    ///
+    /// 路径不存在或无法读取时返回错误。
+    pub fn validate_config_override(path: &Path) -> Result<()> {
+        if !path.exists() {
+            return Err(eyre!(
+                "Config path does not exist: {} (set by --config or {})",
+                path.display(),
+                CONFIG_ENV
+            ));
+        }
+        let readable = if path.is_dir() {
+            fs::read_dir(path).map(|_| ())
+        } else {
+            fs::File::open(path).map(|_| ())
+        };
+        readable.wrap_err_with(|| format!("Config path is not readable: {}", path.display()))
+    }
+
+    /// 从原始命令行参数中提取 `--config` 的值
+    ///
+    /// 配置需要在解析命令行之前加载（如别名展开），因此入口处先扫描原始参数。
+    /// 支持 `--config <path>` 和 `--config=<path>` 两种形式，遇到 `--` 后停止扫描。
+    pub fn config_from_args(args: &[String]) -> Option<String> {
+        let mut iter = args.iter().skip(1);
+        while let Some(arg) = iter.next() {
+            if arg == "--" {
+                break;
+            }
+            if arg == "--config" {
+                return iter.next().cloned();
+            }
+            if let Some(path) = arg.strip_prefix("--config=") {
+                return Some(path.to_string());
+            }
+        }
+        None
+    }
+
     /// 获取配置目录路径（支持 iCloud 同步）
     ///
     /// 返回配置文件存储目录。在 macOS 上，如果 iCloud Drive 可用，
     /// 配置将保存到 iCloud 并自动同步到其他设备。
     ///
+    /// 指定了 `--config`/`WORKFLOW_CONFIG` 时，返回指定的目录（指定的是文件时返回其所在目录）。
+    ///
     /// # 路径示例
     ///
     /// - macOS + iCloud：`~/Library/Mobile Documents/com~apple~CloudDocs/.workflow/config/`
@@ -259,6 +324,16 @@ impl Paths {
     ///
     /// 如果环境变量未设置或无法创建目录，返回相应的错误信息。
     pub fn config_dir() -> Result<PathBuf> {
+        if let Some(path) = Self::config_override() {
+            if path.is_file() {
+                return path
+                    .parent()
+                    .map(Path::to_path_buf)
+                    .wrap_err_with(|| format!("Invalid config path: {}", path.display()));
+            }
+            return Ok(path);
+        }
+
         // 使用支持 iCloud 的配置基础目录
         let config_dir = Self::config_base_dir()?.join(CONFIG_DIR);
 
@@ -277,8 +352,12 @@ impl Paths {
 
     /// 获取主配置文件路径
     ///
-    /// 返回 `~/.workflow/config/workflow.toml` 的路径。
+    /// 返回 `~/.workflow/config/workflow.toml` 的路径；
+    /// `--config`/`WORKFLOW_CONFIG` 指定的是文件时返回该文件。
     pub fn workflow_config() -> Result<PathBuf> {
+        if let Some(path) = Self::config_override().filter(|path| path.is_file()) {
+            return Ok(path);
+        }
         Ok(Self::config_dir()?.join(WORKFLOW_CONFIG_FILE))
     }
 
@@ -304,6 +383,38 @@ impl Paths {
         Ok(Self::config_dir()?.join("commands.toml"))
     }
 
+    /// 获取 Profile 目录路径
+    ///
+    /// 返回 `~/.workflow/config/profiles/` 的路径。
+    pub fn profiles_dir() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join(PROFILES_DIR))
+    }
+
+    /// 获取指定 Profile 的配置文件路径
+    ///
+    /// - `default` Profile：返回基础配置 `~/.workflow/config/workflow.toml`
+    /// - 其他 Profile：返回 `~/.workflow/config/profiles/<name>/workflow.toml`
+    pub fn profile_config(name: &str) -> Result<PathBuf> {
+        if Profile::is_default(name) {
+            return Self::workflow_config();
+        }
+        Ok(Self::profiles_dir()?.join(name).join(WORKFLOW_CONFIG_FILE))
+    }
+
+    /// 获取当前激活 Profile 的配置文件路径
+    ///
+    /// 写入配置时应使用此路径，读取时应使用 `Settings::load()`（会合并基础配置）。
+    pub fn active_profile_config() -> Result<PathBuf> {
+        Self::profile_config(&Profile::active())
+    }
+
+    /// 获取保存当前激活 Profile 名称的文件路径
+    ///
+    /// 返回 `~/.workflow/config/active_profile` 的路径。
+    pub fn active_profile_file() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join(ACTIVE_PROFILE_FILE))
+    }
+
     /// 获取项目级配置文件路径
     ///
     /// 返回当前工作目录下的 `.workflow/config.toml` 路径。
@@ -439,6 +550,66 @@ impl Paths {
         Ok(logs_dir)
     }
 
+    /// 获取缓存目录路径（强制本地，不同步）
+    ///
+    /// 返回 `~/.workflow/cache/`（总是本地路径）。
+    ///
+    /// 缓存内容（如 LLM 文件修改总结）可以随时删除并重新生成，不需要跨设备同步。
+    ///
+    /// # 路径示例
+    ///
+    /// - 所有平台：`~/.workflow/cache/`
+    ///
+    /// # 返回
+    ///
+    /// 返回缓存目录的 `PathBuf`。
+    ///
+    /// # 错误
+    ///
+    /// 如果无法创建目录，返回相应的错误信息。
+    pub fn cache_dir() -> Result<PathBuf> {
+        // 强制使用本地路径，不使用 iCloud
+        let cache_dir = Self::local_base_dir()?.join("cache");
+
+        // 确保目录存在
+        DirectoryWalker::new(&cache_dir).ensure_exists()?;
+
+        // 设置目录权限为 700（仅用户可访问，仅 Unix）
+        #[cfg(unix)]
+        {
+            fs::set_permissions(&cache_dir, fs::Permissions::from_mode(0o700))
+                .wrap_err("Failed to set cache directory permissions")?;
+        }
+
+        Ok(cache_dir)
+    }
+
+    /// 获取 Workflow CLI 使用的目录（不创建目录）
+    ///
+    /// 返回配置目录、补全脚本目录、日志目录和工作历史目录的路径，
+    /// 与 `config_dir()`、`completion_dir()`、`logs_dir()`、`work_history_dir()` 一一对应。
+    /// 这些方法会自动创建目录，此方法只计算路径，用于检查目录是否缺失。
+    ///
+    /// # 错误
+    ///
+    /// 如果无法确定主目录，返回相应的错误信息。
+    pub fn workflow_dirs() -> Result<Vec<PathBuf>> {
+        let local_base = Self::home_dir()?.join(WORKFLOW_DIR);
+        let config_base = if std::env::var("WORKFLOW_DISABLE_ICLOUD").is_ok() {
+            None
+        } else {
+            Self::try_icloud_base_dir()
+        }
+        .unwrap_or_else(|| local_base.clone());
+
+        Ok(vec![
+            config_base.join(CONFIG_DIR),
+            local_base.join("completions"),
+            local_base.join("logs"),
+            local_base.join("work-history"),
+        ])
+    }
+
     // ==================== 安装路径相关方法 ====================
     /// 获取所有命令名称
     ///
@@ -661,6 +832,7 @@ impl Paths {
     /// - fish → `~/.config/fish/config.fish`
     /// - powershell → `~/.config/powershell/Microsoft.PowerShell_profile.ps1`
     /// - elvish → `~/.elvish/rc.elv`
+    /// - nushell → `config.nu`（目录见 `nushell_config_dir()`）
     ///
     /// 注意：对于 bash，macOS 通常使用 `.bash_profile`，Linux 使用 `.bashrc`。
     /// 此方法会优先使用 `.bash_profile`，如果不存在则使用 `.bashrc`。
@@ -680,10 +852,10 @@ impl Paths {
     /// # 示例
     ///
     /// ```
-    /// use clap_complete::shells::Shell;
+    /// use workflow::base::shell::Shell;
     /// ```
     /// use std::path::PathBuf;
-    /// use clap_complete::shells::Shell;
+    /// use workflow::base::shell::Shell;
     /// use workflow::base::settings::paths::Paths;
     ///
     /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -741,11 +913,37 @@ impl Paths {
             #[cfg(not(target_os = "windows"))]
             Shell::Elvish => home.join(".elvish/rc.elv"),
 
+            Shell::Nushell => Self::nushell_config_dir(&home).join("config.nu"),
+
+            #[cfg(target_os = "windows")]
             _ => color_eyre::eyre::bail!("Unsupported shell type"),
         };
 
         Ok(config_file)
     }
+
+    /// 获取 nushell 配置目录（与 nushell 的 `$nu.default-config-dir` 一致）
+    ///
+    /// - 设置了 `XDG_CONFIG_HOME`（绝对路径）时：`$XDG_CONFIG_HOME/nushell`
+    /// - macOS：`~/Library/Application Support/nushell`
+    /// - Windows：`~/AppData/Roaming/nushell`
+    /// - 其他系统：`~/.config/nushell`
+    fn nushell_config_dir(home: &Path) -> PathBuf {
+        let xdg_config_home = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .filter(|path| path.is_absolute());
+        if let Some(config_home) = xdg_config_home {
+            return config_home.join("nushell");
+        }
+
+        if cfg!(target_os = "macos") {
+            home.join("Library/Application Support/nushell")
+        } else if cfg!(target_os = "windows") {
+            home.join("AppData").join("Roaming").join("nushell")
+        } else {
+            home.join(".config/nushell")
+        }
+    }
 }
 
 #[cfg(test)]
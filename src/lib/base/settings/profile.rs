@@ -0,0 +1,271 @@
+//! 配置 Profile 管理
+//!
+//! 支持将配置保存在多个命名 Profile 下（如工作账号和个人账号），无需反复运行 setup。
+//!
+//! ## 存储结构
+//!
+//! - `default` Profile 即共享的基础配置 `~/.workflow/config/workflow.toml`
+//! - 其他 Profile 保存在 `~/.workflow/config/profiles/<name>/workflow.toml`，
+//!   只记录与基础配置不同的字段，未覆盖的字段从基础配置继承
+//! - 当前激活的 Profile 名称保存在 `~/.workflow/config/active_profile`
+//!
+//! ## 选择顺序
+//!
+//! 1. 命令行参数 `--profile <name>`（在入口处写入 `WORKFLOW_PROFILE`）
+//! 2. 环境变量 `WORKFLOW_PROFILE`
+//! 3. `workflow config profile use <name>` 保存的 Profile
+//! 4. `default`
+
+use color_eyre::{
+    eyre::{eyre, WrapErr},
+    Result,
+};
+use std::fs;
+use toml::{map::Map, Value};
+
+use super::paths::Paths;
+use crate::base::util::file::{FileReader, FileWriter};
+
+/// 默认 Profile 名称
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// 选择 Profile 的环境变量
+pub const PROFILE_ENV: &str = "WORKFLOW_PROFILE";
+
+/// 配置 Profile 管理器
+pub struct Profile;
+
+impl Profile {
+    /// 获取当前激活的 Profile 名称
+    ///
+    /// 按 `WORKFLOW_PROFILE` 环境变量、已保存的 Profile、`default` 的顺序解析。
+    pub fn active() -> String {
+        if let Ok(name) = std::env::var(PROFILE_ENV) {
+            let name = name.trim();
+            if !name.is_empty() {
+                return name.to_string();
+            }
+        }
+
+        Self::saved().unwrap_or_else(|| DEFAULT_PROFILE.to_string())
+    }
+
+    /// 读取 `workflow config profile use` 保存的 Profile 名称
+    fn saved() -> Option<String> {
+        let path = Paths::active_profile_file().ok()?;
+        if !path.exists() {
+            return None;
+        }
+        let content = FileReader::new(&path).to_string().ok()?;
+        let name = content.trim();
+        (!name.is_empty()).then(|| name.to_string())
+    }
+
+    /// 是否为默认 Profile
+    pub fn is_default(name: &str) -> bool {
+        name == DEFAULT_PROFILE
+    }
+
+    /// 检查 Profile 是否存在（`default` 总是存在）
+    pub fn exists(name: &str) -> bool {
+        Self::is_default(name)
+            || Paths::profile_config(name).map(|path| path.exists()).unwrap_or(false)
+    }
+
+    /// 列出所有 Profile 名称
+    ///
+    /// `default` 总是排在第一位，其余按名称排序。
+    pub fn list() -> Result<Vec<String>> {
+        let mut names = Vec::new();
+        let profiles_dir = Paths::profiles_dir()?;
+        if profiles_dir.exists() {
+            for entry in fs::read_dir(&profiles_dir).wrap_err_with(|| {
+                format!(
+                    "Failed to read profiles directory: {}",
+                    profiles_dir.display()
+                )
+            })? {
+                let entry = entry.wrap_err("Failed to read profile entry")?;
+                if !entry.path().is_dir() {
+                    continue;
+                }
+                let name = entry.file_name().to_string_lossy().to_string();
+                if !Self::is_default(&name) && Self::exists(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names.sort();
+        names.insert(0, DEFAULT_PROFILE.to_string());
+        Ok(names)
+    }
+
+    /// 创建新的 Profile
+    ///
+    /// 创建空的 Profile 配置文件，所有字段默认从基础配置继承。
+    ///
+    /// # 错误
+    ///
+    /// 如果名称无效或 Profile 已存在，返回相应的错误信息。
+    pub fn create(name: &str) -> Result<()> {
+        Self::validate_name(name)?;
+        if Self::exists(name) {
+            return Err(eyre!("Profile '{}' already exists", name));
+        }
+
+        let config_path = Paths::profile_config(name)?;
+        FileWriter::new(&config_path)
+            .write_toml_secure(&Value::Table(Map::new()))
+            .wrap_err_with(|| format!("Failed to create profile '{}'", name))?;
+        Ok(())
+    }
+
+    /// 切换当前激活的 Profile（持久化保存）
+    ///
+    /// 切换到 `default` 时会删除保存的 Profile 记录。
+    ///
+    /// # 错误
+    ///
+    /// 如果 Profile 不存在，返回相应的错误信息。
+    pub fn set_active(name: &str) -> Result<()> {
+        if !Self::exists(name) {
+            return Err(eyre!(
+                "Profile '{}' does not exist. Create it with 'workflow config profile create {}'",
+                name,
+                name
+            ));
+        }
+
+        let path = Paths::active_profile_file()?;
+        if Self::is_default(name) {
+            if path.exists() {
+                fs::remove_file(&path).wrap_err("Failed to reset active profile")?;
+            }
+            return Ok(());
+        }
+        FileWriter::new(&path)
+            .write_str_with_dir(name)
+            .wrap_err("Failed to save active profile")
+    }
+
+    /// 校验 Profile 名称（只允许字母、数字、`-` 和 `_`）
+    pub fn validate_name(name: &str) -> Result<()> {
+        if name.is_empty() {
+            return Err(eyre!("Profile name cannot be empty"));
+        }
+        if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            return Err(eyre!(
+                "Invalid profile name '{}': only letters, digits, '-' and '_' are allowed",
+                name
+            ));
+        }
+        Ok(())
+    }
+
+    /// 从原始命令行参数中提取 `--profile` 的值
+    ///
+    /// 配置需要在解析命令行之前加载（如日志级别），因此入口处先扫描原始参数。
+    /// 支持 `--profile <name>` 和 `--profile=<name>` 两种形式，遇到 `--` 后停止扫描。
+    pub fn from_args(args: &[String]) -> Option<String> {
+        let mut iter = args.iter().skip(1);
+        while let Some(arg) = iter.next() {
+            if arg == "--" {
+                break;
+            }
+            if arg == "--profile" {
+                return iter.next().cloned();
+            }
+            if let Some(name) = arg.strip_prefix("--profile=") {
+                return Some(name.to_string());
+            }
+        }
+        None
+    }
+
+    /// 将 Profile 配置合并到基础配置上
+    ///
+    /// 表会递归合并，其他类型的值（包括数组）直接使用 Profile 中的值。
+    pub fn merge(base: &mut Value, overlay: Value) {
+        match (base, overlay) {
+            (Value::Table(base_table), Value::Table(overlay_table)) => {
+                for (key, value) in overlay_table {
+                    match base_table.get_mut(&key) {
+                        Some(existing) => Self::merge(existing, value),
+                        None => {
+                            base_table.insert(key, value);
+                        }
+                    }
+                }
+            }
+            (base, overlay) => *base = overlay,
+        }
+    }
+
+    /// 计算相对于基础配置需要保存到 Profile 的字段
+    ///
+    /// 只保留与基础配置不同的字段，表会递归比较。
+    /// 返回的总是一个表（没有差异时为空表）。
+    pub fn diff(base: &Value, updated: &Value) -> Value {
+        let (Some(base_table), Some(updated_table)) = (base.as_table(), updated.as_table()) else {
+            return updated.clone();
+        };
+
+        let mut result = Map::new();
+        for (key, value) in updated_table {
+            match base_table.get(key) {
+                Some(base_value) if base_value == value => {}
+                Some(base_value) if base_value.is_table() && value.is_table() => {
+                    let nested = Self::diff(base_value, value);
+                    if nested.as_table().map(|t| !t.is_empty()).unwrap_or(true) {
+                        result.insert(key.clone(), nested);
+                    }
+                }
+                _ => {
+                    result.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        Value::Table(result)
+    }
+
+    /// 找出基础配置中存在、但更新后的配置中已被删除的字段
+    ///
+    /// Profile 只记录覆盖的字段，无法表示删除；这些字段在合并后仍会从基础配置继承。
+    /// 返回以 `.` 连接的字段路径（如 `aliases.co`），表会递归比较；
+    /// 整个表被删除时返回其中的叶子字段。
+    pub fn removed_keys(base: &Value, updated: &Value) -> Vec<String> {
+        let mut removed = Vec::new();
+        Self::collect_removed_keys(base, updated, "", &mut removed);
+        removed
+    }
+
+    fn collect_removed_keys(
+        base: &Value,
+        updated: &Value,
+        prefix: &str,
+        removed: &mut Vec<String>,
+    ) {
+        let (Some(base_table), Some(updated_table)) = (base.as_table(), updated.as_table()) else {
+            return;
+        };
+
+        for (key, base_value) in base_table {
+            let path = if prefix.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", prefix, key)
+            };
+            match (updated_table.get(key), base_value.as_table()) {
+                (Some(value), _) => Self::collect_removed_keys(base_value, value, &path, removed),
+                // 空表在序列化时会被省略，整个表被删除时报告其中的叶子字段
+                (None, Some(table)) if !table.is_empty() => Self::collect_removed_keys(
+                    base_value,
+                    &Value::Table(Default::default()),
+                    &path,
+                    removed,
+                ),
+                (None, _) => removed.push(path),
+            }
+        }
+    }
+}
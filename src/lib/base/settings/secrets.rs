@@ -0,0 +1,101 @@
+//! 配置中的敏感信息
+//!
+//! 导出配置时默认省略所有密钥（Jira API token、GitHub token、LLM key），
+//! 并用 [`OMITTED_SECRET`] 标记被省略的字段；导入时遇到该标记则保留本地已有的值。
+
+use super::settings::Settings;
+
+/// 被省略的敏感字段的占位值
+pub const OMITTED_SECRET: &str = "<omitted>";
+
+/// 判断字段值是否为省略标记
+pub fn is_omitted(value: &str) -> bool {
+    value == OMITTED_SECRET
+}
+
+impl Settings {
+    /// 所有已配置的密钥（非空的 Jira/Azure DevOps/GitHub token 和 LLM key）
+    ///
+    /// 用于在日志等输出中隐藏这些值（见 `SensitiveRegistry`）。
+    pub fn secret_values(&self) -> Vec<&str> {
+        [
+            &self.jira.api_token,
+            &self.azure_devops.api_token,
+            &self.llm.openai.key,
+            &self.llm.deepseek.key,
+            &self.llm.proxy.key,
+        ]
+        .into_iter()
+        .filter_map(|value| value.as_deref())
+        .chain(self.github.accounts.iter().map(|account| account.api_token.as_str()))
+        .filter(|value| !value.is_empty() && !is_omitted(value))
+        .collect()
+    }
+
+    /// 省略所有敏感字段
+    ///
+    /// 返回省略后的配置和被省略的字段数量。未设置的字段保持不变。
+    pub fn redact_secrets(&self) -> (Settings, usize) {
+        let mut redacted = self.clone();
+        let mut count = 0;
+
+        let mut redact = |value: &mut Option<String>| {
+            if value.as_deref().is_some_and(|v| !v.is_empty()) {
+                *value = Some(OMITTED_SECRET.to_string());
+                count += 1;
+            }
+        };
+        redact(&mut redacted.jira.api_token);
+        redact(&mut redacted.azure_devops.api_token);
+        redact(&mut redacted.llm.openai.key);
+        redact(&mut redacted.llm.deepseek.key);
+        redact(&mut redacted.llm.proxy.key);
+
+        for account in &mut redacted.github.accounts {
+            if !account.api_token.is_empty() {
+                account.api_token = OMITTED_SECRET.to_string();
+                count += 1;
+            }
+        }
+
+        (redacted, count)
+    }
+
+    /// 用本地配置还原被省略的敏感字段
+    ///
+    /// 带有省略标记的字段会替换为 `current` 中对应的值（GitHub token 按账号名匹配），
+    /// `current` 中没有对应值时清空该字段。
+    ///
+    /// 返回本地没有对应 token 的 GitHub 账号名（token 被清空）。
+    pub fn restore_omitted_secrets(&mut self, current: &Settings) -> Vec<String> {
+        let restore = |value: &mut Option<String>, local: &Option<String>| {
+            if value.as_deref().is_some_and(is_omitted) {
+                *value = local.clone();
+            }
+        };
+        restore(&mut self.jira.api_token, &current.jira.api_token);
+        restore(
+            &mut self.azure_devops.api_token,
+            &current.azure_devops.api_token,
+        );
+        restore(&mut self.llm.openai.key, &current.llm.openai.key);
+        restore(&mut self.llm.deepseek.key, &current.llm.deepseek.key);
+        restore(&mut self.llm.proxy.key, &current.llm.proxy.key);
+
+        let mut missing = Vec::new();
+        for account in &mut self.github.accounts {
+            if !is_omitted(&account.api_token) {
+                continue;
+            }
+            match current.github.accounts.iter().find(|acc| acc.name == account.name) {
+                Some(local) => account.api_token = local.api_token.clone(),
+                None => {
+                    account.api_token.clear();
+                    missing.push(account.name.clone());
+                }
+            }
+        }
+
+        missing
+    }
+}
@@ -1,19 +1,27 @@
 use std::sync::OnceLock;
 
-use crate::base::util::file::FileReader;
-use color_eyre::Result;
+use crate::base::util::file::{FileReader, FileWriter};
+use color_eyre::{eyre::WrapErr, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use serde_with::skip_serializing_none;
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 
+use super::env::EnvOverride;
 use super::paths::Paths;
+use super::profile::Profile;
 use crate::base::http::{Authorization, HttpClient, RequestConfig};
+use crate::base::util::date::Timezone;
+use crate::jira::config::ConfigManager;
 use crate::jira::types::JiraUser;
 use crate::mask_sensitive_value;
 use crate::pr::GitHub;
 use std::collections::HashMap;
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
 
 // ==================== 返回结构体 ====================
 
@@ -53,8 +61,10 @@ pub struct JiraVerificationResult {
 /// Jira 配置信息
 #[derive(Debug, Clone)]
 pub struct JiraConfigInfo {
-    /// 邮箱
+    /// 邮箱（Bearer 认证时可能为空）
     pub email: String,
+    /// 认证方式
+    pub auth_type: JiraAuthType,
     /// 服务地址
     pub service_address: String,
     /// API Token（掩码显示）
@@ -127,26 +137,152 @@ pub struct VerificationResult {
 
 // ==================== TOML 配置结构体 ====================
 
+/// Jira 认证方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JiraAuthType {
+    /// Basic Authentication（邮箱 + API token，默认）
+    #[default]
+    Basic,
+    /// Bearer token（Personal Access Token 或 OAuth 2.0 access token，不需要邮箱）
+    Bearer,
+}
+
+impl JiraAuthType {
+    /// 所有认证方式（用于交互式选择）
+    pub const ALL: [JiraAuthType; 2] = [JiraAuthType::Basic, JiraAuthType::Bearer];
+
+    /// 将 JiraAuthType 转换为配置值
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JiraAuthType::Basic => "basic",
+            JiraAuthType::Bearer => "bearer",
+        }
+    }
+}
+
+impl fmt::Display for JiraAuthType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for JiraAuthType {
+    type Err = String;
+
+    /// 从配置值转换（不区分大小写）："basic"、"bearer"
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "basic" => Ok(JiraAuthType::Basic),
+            "bearer" => Ok(JiraAuthType::Bearer),
+            _ => Err(format!(
+                "Invalid Jira auth type: {}. Expected: basic, bearer",
+                s
+            )),
+        }
+    }
+}
+
 /// Jira 配置（TOML）
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct JiraSettings {
-    /// Jira 用户邮箱（用于 API 认证）
+    /// Jira 用户邮箱（用于 Basic Authentication）
     pub email: Option<String>,
-    /// Jira API Token
+    /// Jira API Token（Basic Authentication）或 Personal Access Token / OAuth access token（Bearer）
     pub api_token: Option<String>,
+    /// 认证方式（`basic` 或 `bearer`），未配置或配置无效时使用 `basic`
+    pub auth_type: Option<String>,
     /// Jira 服务地址
     pub service_address: Option<String>,
+    /// 显示 Jira 时间（如变更历史、评论）使用的时区（`local` 或 `utc`）
+    /// 未配置或配置无效时使用本地时区
+    pub timezone: Option<String>,
+    /// Sprint 自定义字段的 ID（如 `customfield_10020`），不同 Jira 实例的字段 ID 不同
+    /// 未配置时使用 `JiraSettings::DEFAULT_SPRINT_FIELD`
+    pub sprint_field: Option<String>,
 }
 
 impl JiraSettings {
+    /// 默认的 Sprint 自定义字段 ID（Jira Cloud 的默认值）
+    pub const DEFAULT_SPRINT_FIELD: &'static str = "customfield_10020";
+
     /// 检查 JIRA 配置是否为空
     pub fn is_empty(&self) -> bool {
-        self.email.is_none() && self.api_token.is_none() && self.service_address.is_none()
+        !self.has_credentials()
+            && self.auth_type.is_none()
+            && self.timezone.is_none()
+            && self.sprint_field.is_none()
+    }
+
+    /// 是否配置了任一认证信息（邮箱、API Token 或服务地址）
+    pub fn has_credentials(&self) -> bool {
+        self.email.is_some() || self.api_token.is_some() || self.service_address.is_some()
+    }
+
+    /// Jira 集成是否可用
+    ///
+    /// 需要配置服务地址和 API Token，`basic` 认证还需要配置邮箱。
+    /// 未配置时（如只使用 GitHub 的用户）各命令跳过 ticket 相关的步骤，不调用 Jira API。
+    pub fn is_configured(&self) -> bool {
+        let non_empty =
+            |value: &Option<String>| value.as_deref().is_some_and(|v| !v.trim().is_empty());
+        non_empty(&self.service_address)
+            && non_empty(&self.api_token)
+            && (self.auth_type() == JiraAuthType::Bearer || non_empty(&self.email))
+    }
+
+    /// 获取认证方式（未配置或配置无效时使用 `basic`）
+    pub fn auth_type(&self) -> JiraAuthType {
+        self.auth_type.as_deref().and_then(|t| t.parse().ok()).unwrap_or_default()
+    }
+
+    /// 根据认证方式构建认证信息
+    ///
+    /// - `basic`：邮箱 + API token
+    /// - `bearer`：API token 作为 Bearer token（不使用邮箱）
+    ///
+    /// 未配置的值使用空字符串（由服务器返回认证错误）。
+    pub fn authorization(&self) -> Authorization {
+        let token = self.api_token.clone().unwrap_or_default();
+        match self.auth_type() {
+            JiraAuthType::Basic => {
+                Authorization::new(self.email.clone().unwrap_or_default(), token)
+            }
+            JiraAuthType::Bearer => Authorization::bearer(token),
+        }
+    }
+
+    /// 获取显示时间使用的时区（未配置或配置无效时使用本地时区）
+    pub fn timezone(&self) -> Timezone {
+        self.timezone.as_deref().and_then(|tz| tz.parse().ok()).unwrap_or_default()
+    }
+
+    /// 获取 Sprint 自定义字段的 ID（未配置时使用默认值）
+    pub fn sprint_field(&self) -> &str {
+        self.sprint_field
+            .as_deref()
+            .filter(|field| !field.trim().is_empty())
+            .unwrap_or(Self::DEFAULT_SPRINT_FIELD)
+    }
+}
+
+/// Azure DevOps 配置（TOML）
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AzureDevOpsSettings {
+    /// Azure DevOps Personal Access Token（需要 Code 读写权限）
+    pub api_token: Option<String>,
+}
+
+impl AzureDevOpsSettings {
+    /// 检查 Azure DevOps 配置是否为空
+    pub fn is_empty(&self) -> bool {
+        self.api_token.is_none()
     }
 }
 
 /// GitHub 账号配置
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct GitHubAccount {
     /// 账号名称（用于标识和切换）
     pub name: String,
@@ -154,6 +290,9 @@ pub struct GitHubAccount {
     pub email: String,
     /// GitHub API Token
     pub api_token: String,
+    /// token 对应的 GitHub 登录名（添加或更新账号时校验 token 得到）
+    #[serde(default)]
+    pub login: Option<String>,
 }
 
 /// GitHub 配置（TOML）
@@ -165,12 +304,107 @@ pub struct GitHubSettings {
     pub accounts: Vec<GitHubAccount>,
     /// 当前激活的账号名称
     pub current: Option<String>,
+    /// GitHub API 基础 URL（GitHub Enterprise Server，如 `https://github.mycorp.com/api/v3`）
+    /// 未配置时根据 `web_base_url` 推导，都未配置时使用 `https://api.github.com`
+    pub api_base_url: Option<String>,
+    /// GitHub 网站基础 URL（GitHub Enterprise Server，如 `https://github.mycorp.com`）
+    /// 用于浏览器链接和识别仓库地址，未配置时使用 `https://github.com`
+    pub web_base_url: Option<String>,
 }
 
 impl GitHubSettings {
+    /// GitHub Enterprise Server 的 REST API 路径
+    const ENTERPRISE_API_PATH: &'static str = "/api/v3";
+
+    /// GitHub Enterprise Server 的 GraphQL API 路径
+    const ENTERPRISE_GRAPHQL_PATH: &'static str = "/api/graphql";
+
     /// 检查 GitHub 配置是否为空
     pub fn is_empty(&self) -> bool {
-        self.accounts.is_empty() && self.current.is_none()
+        self.accounts.is_empty()
+            && self.current.is_none()
+            && self.api_base_url.is_none()
+            && self.web_base_url.is_none()
+    }
+
+    /// 获取 GitHub API 基础 URL（不带末尾的 `/`）
+    ///
+    /// 优先使用 `api_base_url`；只配置了 `web_base_url` 时使用 `{web_base_url}/api/v3`
+    /// （GitHub Enterprise Server 的 API 地址）；都未配置时使用 `https://api.github.com`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::base::settings::settings::GitHubSettings;
+    ///
+    /// let settings = GitHubSettings {
+    ///     web_base_url: Some("https://github.mycorp.com/".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(settings.api_base_url(), "https://github.mycorp.com/api/v3");
+    /// assert_eq!(GitHubSettings::default().api_base_url(), "https://api.github.com");
+    /// ```
+    pub fn api_base_url(&self) -> String {
+        if let Some(url) = Self::non_empty_url(self.api_base_url.as_deref()) {
+            return url;
+        }
+        match Self::non_empty_url(self.web_base_url.as_deref()) {
+            Some(web) => format!("{}{}", web, Self::ENTERPRISE_API_PATH),
+            None => crate::git::github::API_BASE.to_string(),
+        }
+    }
+
+    /// 获取 GitHub 网站基础 URL（不带末尾的 `/`）
+    ///
+    /// 优先使用 `web_base_url`；只配置了以 `/api/v3` 结尾的 `api_base_url` 时去掉该路径；
+    /// 否则使用 `https://github.com`。
+    pub fn web_base_url(&self) -> String {
+        if let Some(url) = Self::non_empty_url(self.web_base_url.as_deref()) {
+            return url;
+        }
+        Self::non_empty_url(self.api_base_url.as_deref())
+            .and_then(|api| api.strip_suffix(Self::ENTERPRISE_API_PATH).map(str::to_string))
+            .unwrap_or_else(|| crate::git::github::BASE.to_string())
+    }
+
+    /// 获取 GitHub GraphQL API 的 URL
+    ///
+    /// GitHub Enterprise Server 的 GraphQL API 为 `/api/graphql`（而不是 `/api/v3/graphql`）。
+    pub fn graphql_url(&self) -> String {
+        let api = self.api_base_url();
+        match api.strip_suffix(Self::ENTERPRISE_API_PATH) {
+            Some(web) => format!("{}{}", web, Self::ENTERPRISE_GRAPHQL_PATH),
+            None => format!("{}/graphql", api),
+        }
+    }
+
+    /// 获取 GitHub Enterprise Server 的主机名（不含协议和端口）
+    ///
+    /// 网站基础 URL 为 `github.com` 或无法解析时返回 `None`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::base::settings::settings::GitHubSettings;
+    ///
+    /// let settings = GitHubSettings {
+    ///     web_base_url: Some("https://github.mycorp.com:8443".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert_eq!(settings.enterprise_host().as_deref(), Some("github.mycorp.com"));
+    /// assert_eq!(GitHubSettings::default().enterprise_host(), None);
+    /// ```
+    pub fn enterprise_host(&self) -> Option<String> {
+        let url = reqwest::Url::parse(&self.web_base_url()).ok()?;
+        let host = url.host_str()?.to_lowercase();
+        (host != crate::git::github::DOMAIN).then_some(host)
+    }
+
+    /// 去除首尾空白和末尾的 `/`，空字符串视为未配置
+    fn non_empty_url(url: Option<&str>) -> Option<String> {
+        url.map(|url| url.trim().trim_end_matches('/'))
+            .filter(|url| !url.is_empty())
+            .map(str::to_string)
     }
 
     /// 获取当前激活的账号
@@ -232,6 +466,12 @@ pub struct LogSettings {
     /// 如果配置文件中不存在此字段，默认为 `false`（只输出到文件）
     /// 注意：只有设置为 `true` 时才会写入配置文件，设置为 `false` 时从配置文件中删除
     pub enable_trace_console: Option<bool>,
+    /// 是否记录 HTTP 请求和响应（方法、URL、隐藏敏感信息后的 Headers、截断后的 Body）
+    /// 也可以通过全局参数 `--http-trace` 临时启用
+    pub http_trace: Option<bool>,
+    /// 日志中需要额外隐藏的 token 格式（正则表达式，名为 `secret` 的捕获组为需要隐藏的部分）
+    /// 配置中的密钥和常见 token 格式（如 `ghp_`、`Bearer`）总是会被隐藏
+    pub redact_patterns: Option<Vec<String>>,
 }
 
 impl LogSettings {
@@ -242,6 +482,8 @@ impl LogSettings {
             && self.download_base_dir == default.download_base_dir
             && self.level == default.level
             && self.enable_trace_console == default.enable_trace_console
+            && self.http_trace == default.http_trace
+            && self.redact_patterns == default.redact_patterns
     }
 
     /// 默认日志文件夹名称
@@ -273,9 +515,193 @@ impl Default for LogSettings {
             download_base_dir: Self::default_download_base_dir_option(), // None
             level: None,
             enable_trace_console: None,
+            http_trace: None,
+            redact_patterns: None,
+        }
+    }
+}
+
+/// 回滚备份配置（TOML）
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RollbackSettings {
+    /// 是否将更新前的备份压缩为 tar.gz
+    /// 如果配置文件中不存在此字段，默认为 `true`（压缩备份）
+    pub compress_backups: Option<bool>,
+}
+
+impl RollbackSettings {
+    /// 检查回滚配置是否为空
+    pub fn is_empty(&self) -> bool {
+        self.compress_backups.is_none()
+    }
+
+    /// 是否启用备份压缩（未配置时默认启用）
+    pub fn is_compression_enabled(&self) -> bool {
+        self.compress_backups.unwrap_or(true)
+    }
+}
+
+/// 提交配置（TOML）
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CommitSettings {
+    /// 是否对 Workflow 创建的提交签名（GPG 或 SSH，按 Git 的 `gpg.format` 选择）
+    /// 如果配置文件中不存在此字段，默认为 `false`
+    pub sign: Option<bool>,
+}
+
+impl CommitSettings {
+    /// 检查提交配置是否为空
+    pub fn is_empty(&self) -> bool {
+        self.sign.is_none()
+    }
+
+    /// 是否启用提交签名（未配置时默认不启用）
+    pub fn is_signing_enabled(&self) -> bool {
+        self.sign.unwrap_or(false)
+    }
+}
+
+/// Tag 配置（TOML）
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TagSettings {
+    /// 语义化版本 tag 的前缀（如 `v1.2.3` 中的 `v`），用于 `workflow tag next`
+    /// 如果配置文件中不存在此字段，默认为 `TagSettings::DEFAULT_PREFIX`；配置为空字符串时不使用前缀
+    pub prefix: Option<String>,
+}
+
+impl TagSettings {
+    /// 默认 tag 前缀
+    pub const DEFAULT_PREFIX: &'static str = "v";
+
+    /// 检查 Tag 配置是否为空
+    pub fn is_empty(&self) -> bool {
+        self.prefix.is_none()
+    }
+
+    /// 获取 tag 前缀（未配置时使用默认值）
+    pub fn prefix(&self) -> &str {
+        self.prefix.as_deref().unwrap_or(Self::DEFAULT_PREFIX)
+    }
+}
+
+/// 网络检查目标
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkTarget {
+    /// 显示名称（如 "GitHub API"）
+    pub name: String,
+    /// 探测地址（http/https）
+    pub url: String,
+}
+
+impl NetworkTarget {
+    /// 创建网络检查目标
+    pub fn new(name: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            url: url.into(),
         }
     }
 }
+
+/// 环境检查配置（TOML）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CheckSettings {
+    /// `workflow check` 网络检查的目标（TOML: `[[check.network_targets]]`）
+    /// 未配置时根据已配置的服务生成，参见 `Settings::network_targets`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub network_targets: Vec<NetworkTarget>,
+}
+
+impl CheckSettings {
+    /// 检查环境检查配置是否为空
+    pub fn is_empty(&self) -> bool {
+        self.network_targets.is_empty()
+    }
+}
+
+/// HTTP 请求配置（TOML）
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct HttpSettings {
+    /// 普通 API 请求的超时时间（秒）
+    /// 未配置时使用 `HttpSettings::DEFAULT_TIMEOUT_SECS`
+    pub timeout_secs: Option<u64>,
+    /// 下载文件（如 Jira 附件、更新包）的超时时间（秒）
+    /// 未配置时使用 `HttpSettings::DEFAULT_DOWNLOAD_TIMEOUT_SECS`
+    pub download_timeout_secs: Option<u64>,
+    /// 同时下载的最大文件数（如 Jira 附件）
+    /// 未配置或配置为 0 时使用 `HttpSettings::DEFAULT_MAX_CONCURRENT_DOWNLOADS`，
+    /// 超过 `HttpSettings::MAX_CONCURRENT_DOWNLOADS` 时使用上限
+    pub max_concurrent_downloads: Option<usize>,
+}
+
+impl HttpSettings {
+    /// 默认请求超时时间（秒）
+    pub const DEFAULT_TIMEOUT_SECS: u64 = 30;
+
+    /// 默认下载超时时间（秒）
+    pub const DEFAULT_DOWNLOAD_TIMEOUT_SECS: u64 = 600;
+
+    /// 默认最大并发下载数
+    pub const DEFAULT_MAX_CONCURRENT_DOWNLOADS: usize = 4;
+
+    /// 最大并发下载数的上限
+    pub const MAX_CONCURRENT_DOWNLOADS: usize = 20;
+
+    /// 检查 HTTP 配置是否为空
+    pub fn is_empty(&self) -> bool {
+        self.timeout_secs.is_none()
+            && self.download_timeout_secs.is_none()
+            && self.max_concurrent_downloads.is_none()
+    }
+
+    /// 获取请求超时时间（未配置或配置为 0 时使用默认值）
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(
+            self.timeout_secs.filter(|secs| *secs > 0).unwrap_or(Self::DEFAULT_TIMEOUT_SECS),
+        )
+    }
+
+    /// 获取下载超时时间（未配置或配置为 0 时使用默认值）
+    pub fn download_timeout(&self) -> Duration {
+        Duration::from_secs(
+            self.download_timeout_secs
+                .filter(|secs| *secs > 0)
+                .unwrap_or(Self::DEFAULT_DOWNLOAD_TIMEOUT_SECS),
+        )
+    }
+
+    /// 获取最大并发下载数（未配置或配置为 0 时使用默认值，超过上限时使用上限）
+    pub fn max_concurrent_downloads(&self) -> usize {
+        self.max_concurrent_downloads
+            .filter(|max| *max > 0)
+            .unwrap_or(Self::DEFAULT_MAX_CONCURRENT_DOWNLOADS)
+            .min(Self::MAX_CONCURRENT_DOWNLOADS)
+    }
+}
+
+/// 自定义 prompt 配置（TOML）
+///
+/// 指向用户自己的 prompt 文件，配置后在运行时替换内置的 system prompt；
+/// 未配置或文件不可用时使用内置 prompt。
+#[skip_serializing_none]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PromptSettings {
+    /// 生成分支名/PR 标题的 system prompt 文件路径（支持 `~`）
+    pub generate_branch_path: Option<String>,
+    /// 总结 PR 的 system prompt 文件路径（支持 `~`）
+    pub summarize_pr_path: Option<String>,
+}
+
+impl PromptSettings {
+    /// 检查 prompt 配置是否为空
+    pub fn is_empty(&self) -> bool {
+        self.generate_branch_path.is_none() && self.summarize_pr_path.is_none()
+    }
+}
 //         self.project_id.is_none() && self.csrf_token.is_none() && self.cookie.is_none()
 //     }
 // }
@@ -292,12 +718,18 @@ pub struct LLMProviderSettings {
     pub key: Option<String>,
     /// 模型名称
     pub model: Option<String>,
+    /// 单次请求可发送的 diff 最大字符数
+    /// 超出时按文件分块总结后再生成最终内容，未配置时使用 `LLMSettings::default_max_diff_chars`
+    pub max_diff_chars: Option<usize>,
 }
 
 impl LLMProviderSettings {
     /// 检查 Provider 配置是否为空
     pub fn is_empty(&self) -> bool {
-        self.url.is_none() && self.key.is_none() && self.model.is_none()
+        self.url.is_none()
+            && self.key.is_none()
+            && self.model.is_none()
+            && self.max_diff_chars.is_none()
     }
 }
 
@@ -308,7 +740,7 @@ pub struct LLMSettings {
     /// 当前使用的 LLM Provider (openai, deepseek, proxy)
     #[serde(default = "LLMSettings::default_provider")]
     pub provider: String,
-    /// LLM 输出语言（en, zh, zh-CN, zh-TW 等，默认 en），用于控制 AI 生成内容（如 PR 总结等）的语言
+    /// LLM 输出语言（en, zh, zh-CN, zh-TW, pt-BR, vi 等，默认 en），用于控制 AI 生成内容（如 PR 总结等）的语言
     /// 所有 provider 共享此语言设置
     #[serde(
         default = "LLMSettings::default_language",
@@ -324,6 +756,10 @@ pub struct LLMSettings {
     /// Proxy 配置
     #[serde(default, skip_serializing_if = "LLMProviderSettings::is_empty")]
     pub proxy: LLMProviderSettings,
+    /// 请求遇到 429 或 5xx 错误时的最大重试次数
+    /// 如果配置文件中不存在此字段，使用 `LLMSettings::default_max_retries`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<u32>,
 }
 
 impl Default for LLMSettings {
@@ -334,6 +770,7 @@ impl Default for LLMSettings {
             openai: LLMProviderSettings::default(),
             deepseek: LLMProviderSettings::default(),
             proxy: LLMProviderSettings::default(),
+            max_retries: None,
         }
     }
 }
@@ -353,6 +790,19 @@ impl LLMSettings {
         }
     }
 
+    /// 根据 Provider 获取默认的 diff 字符数预算
+    pub fn default_max_diff_chars(provider: &str) -> usize {
+        match provider {
+            "openai" | "deepseek" => 30000,
+            _ => 15000, // proxy 的模型上下文未知，使用较保守的预算
+        }
+    }
+
+    /// 默认最大重试次数
+    pub fn default_max_retries() -> u32 {
+        3
+    }
+
     /// 默认 LLM 输出语言
     pub fn default_language() -> String {
         "en".to_string()
@@ -378,11 +828,32 @@ impl LLMSettings {
         }
     }
 
+    /// 获取当前 provider 的模型（未配置时使用默认模型，proxy 没有默认模型）
+    pub fn model(&self) -> String {
+        self.current_provider()
+            .model
+            .clone()
+            .unwrap_or_else(|| Self::default_model(&self.provider))
+    }
+
+    /// 获取当前 provider 的 diff 字符数预算
+    pub fn max_diff_chars(&self) -> usize {
+        self.current_provider()
+            .max_diff_chars
+            .unwrap_or_else(|| Self::default_max_diff_chars(&self.provider))
+    }
+
+    /// 获取最大重试次数（未配置时使用默认值）
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries.unwrap_or_else(Self::default_max_retries)
+    }
+
     /// 检查 LLM 配置是否为空
     fn is_empty(&self) -> bool {
         self.openai.is_empty()
             && self.deepseek.is_empty()
             && self.proxy.is_empty()
+            && self.max_retries.is_none()
             && self.provider == Self::default_provider()
             && self.language == Self::default_language()
     }
@@ -398,12 +869,33 @@ pub struct Settings {
     /// GitHub 配置
     #[serde(default, skip_serializing_if = "GitHubSettings::is_empty")]
     pub github: GitHubSettings,
+    /// Azure DevOps 配置
+    #[serde(default, skip_serializing_if = "AzureDevOpsSettings::is_empty")]
+    pub azure_devops: AzureDevOpsSettings,
     /// 日志配置
     #[serde(default, skip_serializing_if = "LogSettings::is_empty")]
     pub log: LogSettings,
     /// LLM 配置
     #[serde(default, skip_serializing_if = "LLMSettings::is_empty")]
     pub llm: LLMSettings,
+    /// 回滚备份配置
+    #[serde(default, skip_serializing_if = "RollbackSettings::is_empty")]
+    pub rollback: RollbackSettings,
+    /// 提交配置
+    #[serde(default, skip_serializing_if = "CommitSettings::is_empty")]
+    pub commit: CommitSettings,
+    /// Tag 配置
+    #[serde(default, skip_serializing_if = "TagSettings::is_empty")]
+    pub tag: TagSettings,
+    /// 自定义 prompt 配置
+    #[serde(default, skip_serializing_if = "PromptSettings::is_empty")]
+    pub prompt: PromptSettings,
+    /// HTTP 请求配置
+    #[serde(default, skip_serializing_if = "HttpSettings::is_empty")]
+    pub http: HttpSettings,
+    /// 环境检查配置
+    #[serde(default, skip_serializing_if = "CheckSettings::is_empty")]
+    pub check: CheckSettings,
     /// 别名配置（TOML section: [aliases]）
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub aliases: HashMap<String, String>,
@@ -412,27 +904,104 @@ pub struct Settings {
 impl Settings {
     /// 获取缓存的 Settings 实例
     /// 从 workflow.toml 配置文件加载，如果文件不存在则返回默认值
+    /// 加载后会应用 `WORKFLOW_` 前缀的环境变量覆盖（环境变量优先于配置文件）
     pub fn get() -> &'static Settings {
         static SETTINGS: OnceLock<Settings> = OnceLock::new();
-        SETTINGS.get_or_init(Self::load)
+        SETTINGS.get_or_init(|| Self::load_with_env_overrides().0)
+    }
+
+    /// 加载配置并应用环境变量覆盖
+    ///
+    /// 与 `load()` 不同，返回的是实际生效的配置（配置文件 + 环境变量），
+    /// 因此不应将其写回配置文件，避免把环境变量中的密钥持久化。
+    ///
+    /// # 返回
+    ///
+    /// 返回生效的配置以及被环境变量覆盖的字段列表。
+    pub fn load_with_env_overrides() -> (Self, Vec<EnvOverride>) {
+        let mut settings = Self::load();
+        let overrides = settings.apply_env_overrides();
+        (settings, overrides)
     }
 
     /// 从 workflow.toml 配置文件加载设置
     /// 如果配置文件不存在或字段缺失，使用默认值
+    ///
+    /// 如果当前激活的不是 `default` Profile，会将 Profile 配置合并到基础配置上，
+    /// Profile 中未设置的字段从基础配置继承。
     pub fn load() -> Self {
-        match Paths::workflow_config() {
-            Ok(config_path) => {
-                if !config_path.exists() {
-                    Self::default()
-                } else {
-                    match FileReader::new(&config_path).to_string() {
-                        Ok(content) => toml::from_str::<Self>(&content).unwrap_or_default(),
-                        Err(_) => Self::default(),
-                    }
-                }
-            }
-            Err(_) => Self::default(),
+        Self::load_profile(&Profile::active())
+    }
+
+    /// 加载指定 Profile 的配置（不应用环境变量覆盖）
+    ///
+    /// 将 Profile 配置合并到基础配置上，`default` Profile 只读取基础配置。
+    /// 用于在不切换 Profile 的情况下查看其他 Profile 的配置（如 `workflow config profile list`）。
+    pub fn load_profile(profile: &str) -> Self {
+        let mut value = Self::read_toml_value(Paths::workflow_config().ok());
+
+        if !Profile::is_default(profile) {
+            let overlay = Self::read_toml_value(Paths::profile_config(profile).ok());
+            Profile::merge(&mut value, overlay);
+        }
+
+        value.try_into::<Self>().unwrap_or_default()
+    }
+
+    /// 读取 TOML 配置文件为 `toml::Value`
+    /// 如果文件不存在或解析失败，返回空表
+    fn read_toml_value(path: Option<PathBuf>) -> toml::Value {
+        path.filter(|path| path.exists())
+            .and_then(|path| FileReader::new(&path).to_string().ok())
+            .and_then(|content| toml::from_str::<toml::Value>(&content).ok())
+            .unwrap_or_else(|| toml::Value::Table(toml::map::Map::new()))
+    }
+
+    /// 更新当前激活 Profile 的配置
+    ///
+    /// - `default` Profile：直接更新基础配置 `workflow.toml`
+    /// - 其他 Profile：在合并后的配置上应用更新，只将与基础配置不同的字段写入 Profile 配置文件
+    ///
+    /// # 参数
+    ///
+    /// * `f` - 更新函数，接收可变的配置对象引用
+    ///
+    /// # 错误
+    ///
+    /// 如果读取、序列化或写入失败，返回相应的错误信息。
+    /// 非 `default` Profile 中删除了从基础配置继承的字段时（Profile 无法记录删除）返回错误，
+    /// 需要在 `default` Profile 中删除。
+    pub fn update_active_profile<F>(f: F) -> Result<()>
+    where
+        F: FnOnce(&mut Settings),
+    {
+        let profile = Profile::active();
+        if Profile::is_default(&profile) {
+            return ConfigManager::<Settings>::new(Paths::workflow_config()?).update(f);
         }
+
+        // 两侧都经过 Settings 序列化后再比较，避免默认值被写入 Profile
+        let base: Settings = Self::read_toml_value(Some(Paths::workflow_config()?))
+            .try_into()
+            .unwrap_or_default();
+        let base = toml::Value::try_from(&base).wrap_err("Failed to serialize settings")?;
+
+        let mut settings = Self::load();
+        f(&mut settings);
+        let updated = toml::Value::try_from(&settings).wrap_err("Failed to serialize settings")?;
+        let removed = Profile::removed_keys(&base, &updated);
+        if !removed.is_empty() {
+            color_eyre::eyre::bail!(
+                "Cannot remove {} in profile '{}': inherited from the default profile. Remove it from the default profile instead (--profile default)",
+                removed.join(", "),
+                profile
+            );
+        }
+        let overrides = Profile::diff(&base, &updated);
+        let config_path = Paths::profile_config(&profile)?;
+        FileWriter::new(&config_path)
+            .write_toml_secure(&overrides)
+            .wrap_err_with(|| format!("Failed to write profile config: {:?}", config_path))
     }
 
     /// 检查配置文件权限（仅 Unix 系统）
@@ -530,15 +1099,72 @@ impl Settings {
         }
     }
 
+    /// 获取网络检查的目标
+    ///
+    /// 配置了 `check.network_targets` 时直接使用；否则根据已配置的服务生成：
+    /// GitHub API（始终检查）、Jira 地址（配置了 `jira.service_address` 时）、
+    /// LLM 地址（配置了当前 provider 的 key 时；proxy 需要同时配置 URL）。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::base::settings::settings::{NetworkTarget, Settings};
+    ///
+    /// let mut settings = Settings::default();
+    /// settings.jira.service_address = Some("https://example.atlassian.net".to_string());
+    /// assert_eq!(
+    ///     settings.network_targets(),
+    ///     vec![
+    ///         NetworkTarget::new("GitHub API", "https://api.github.com"),
+    ///         NetworkTarget::new("Jira", "https://example.atlassian.net"),
+    ///     ]
+    /// );
+    /// ```
+    pub fn network_targets(&self) -> Vec<NetworkTarget> {
+        if !self.check.network_targets.is_empty() {
+            return self.check.network_targets.clone();
+        }
+
+        let non_empty = |value: Option<&str>| {
+            value.map(str::trim).filter(|v| !v.is_empty()).map(str::to_string)
+        };
+        let mut targets = vec![NetworkTarget::new("GitHub API", self.github.api_base_url())];
+        if let Some(address) = non_empty(self.jira.service_address.as_deref()) {
+            targets.push(NetworkTarget::new("Jira", address));
+        }
+
+        let current = self.llm.current_provider();
+        if non_empty(current.key.as_deref()).is_some() {
+            let url = match self.llm.provider.as_str() {
+                "openai" => Some("https://api.openai.com/v1".to_string()),
+                "deepseek" => Some("https://api.deepseek.com".to_string()),
+                "proxy" => non_empty(current.url.as_deref()),
+                _ => None,
+            };
+            if let Some(url) = url {
+                targets.push(NetworkTarget::new(
+                    format!("LLM ({})", self.llm.provider),
+                    url,
+                ));
+            }
+        }
+        targets
+    }
+
     /// 验证 Jira 配置并返回结果
     pub fn verify_jira(&self) -> Result<JiraVerificationResult> {
-        if let (Some(email), Some(api_token), Some(service_address)) = (
-            &self.jira.email,
-            &self.jira.api_token,
-            &self.jira.service_address,
-        ) {
+        let auth_type = self.jira.auth_type();
+        // Bearer 认证不需要邮箱
+        let email = match auth_type {
+            JiraAuthType::Basic => self.jira.email.clone(),
+            JiraAuthType::Bearer => Some(self.jira.email.clone().unwrap_or_default()),
+        };
+        if let (Some(email), Some(api_token), Some(service_address)) =
+            (&email, &self.jira.api_token, &self.jira.service_address)
+        {
             let config = JiraConfigInfo {
                 email: email.clone(),
+                auth_type,
                 service_address: service_address.clone(),
                 api_token: mask_sensitive_value(api_token),
             };
@@ -548,7 +1174,7 @@ impl Settings {
 
             let verification = match HttpClient::global() {
                 Ok(client) => {
-                    let auth = Authorization::new(email, api_token);
+                    let auth = self.jira.authorization();
                     let config = RequestConfig::<Value, Value>::new().auth(&auth);
                     match client.get(&url, config) {
                         Ok(response) => {
@@ -557,7 +1183,11 @@ impl Settings {
                                 Ok(success_response) => {
                                     match success_response.as_json::<JiraUser>() {
                                         Ok(user) => Some(JiraVerificationStatus::Success {
-                                            email: email.clone(),
+                                            email: if email.is_empty() {
+                                                user.email_address.clone().unwrap_or_default()
+                                            } else {
+                                                email.clone()
+                                            },
                                             account_id: user.account_id,
                                         }),
                                         Err(e) => Some(JiraVerificationStatus::Failed {
@@ -570,7 +1200,7 @@ impl Settings {
                                     reason: "Failed to verify Jira configuration".to_string(),
                                     details: vec![
                                         format!("Error: {}", e),
-                                        "Please check your Jira service address, email, and API token.".to_string(),
+                                        "Please check your Jira service address, auth type, email, and API token.".to_string(),
                                     ],
                                 }),
                             }
@@ -579,7 +1209,7 @@ impl Settings {
                             reason: "Failed to verify Jira configuration".to_string(),
                             details: vec![
                                 format!("Error: {}", e),
-                                "Please check your Jira service address, email, and API token."
+                                "Please check your Jira service address, auth type, email, and API token."
                                     .to_string(),
                             ],
                         }),
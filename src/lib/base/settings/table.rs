@@ -26,6 +26,8 @@ pub struct LLMConfigRow {
 pub struct JiraConfigRow {
     #[tabled(rename = "Email")]
     pub email: String,
+    #[tabled(rename = "Auth")]
+    pub auth_type: String,
     #[tabled(rename = "Service Address")]
     pub service_address: String,
     #[tabled(rename = "API Token")]
@@ -65,3 +67,48 @@ pub struct GitHubAccountListRow {
     #[tabled(rename = "Status")]
     pub status: String,
 }
+
+/// GitHub API 速率限制表格行
+///
+/// 用于在表格中显示各资源的速率限制配额。
+#[derive(Tabled)]
+pub struct GitHubRateLimitRow {
+    #[tabled(rename = "Resource")]
+    pub resource: String,
+    #[tabled(rename = "Used")]
+    pub used: String,
+    #[tabled(rename = "Remaining")]
+    pub remaining: String,
+    #[tabled(rename = "Limit")]
+    pub limit: String,
+    #[tabled(rename = "Resets At")]
+    pub reset: String,
+}
+
+/// 配置 Profile 列表表格行
+///
+/// 用于在表格中显示各 Profile 的 Jira 和 GitHub 配置（标记当前激活的 Profile）。
+#[derive(Tabled)]
+pub struct ProfileRow {
+    #[tabled(rename = "Active")]
+    pub active: String,
+    #[tabled(rename = "Profile")]
+    pub name: String,
+    #[tabled(rename = "Jira")]
+    pub jira: String,
+    #[tabled(rename = "GitHub Account")]
+    pub github: String,
+}
+
+/// Git 配置表格行
+///
+/// 用于在表格中显示 `workflow config git get` 管理的配置项（指定作用域的值和当前生效的值）。
+#[derive(Tabled)]
+pub struct GitConfigRow {
+    #[tabled(rename = "Key")]
+    pub key: String,
+    #[tabled(rename = "Value")]
+    pub value: String,
+    #[tabled(rename = "Effective")]
+    pub effective: String,
+}
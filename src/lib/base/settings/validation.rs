@@ -0,0 +1,539 @@
+//! 配置校验
+//!
+//! 在使用配置之前检查常见错误，并将每个问题定位到具体的配置键（如 `llm.provider`），
+//! 避免配置文件中的拼写错误在命令深处表现为含糊的反序列化错误。
+//!
+//! 校验内容包括：
+//! - 必填字段（Jira 配置不完整、GitHub 账号字段为空、proxy provider 缺少 URL/模型）
+//! - URL 格式（Jira 服务地址、GitHub Enterprise Server 地址、LLM 服务地址、网络检查目标）
+//! - 枚举值（LLM provider、日志级别、输出语言）
+//! - 正则表达式（日志中需要隐藏的 token 格式）
+//! - 路径是否存在（日志下载目录、自定义 prompt 文件）
+//! - 数值范围（HTTP 超时时间）
+//! - 配置文件语法和字段类型
+
+use std::fmt;
+use std::path::Path;
+
+use regex::Regex;
+
+use super::paths::Paths;
+use super::profile::Profile;
+use super::settings::{HttpSettings, JiraAuthType, Settings};
+use crate::base::llm::{find_language, is_auto_language};
+use crate::base::logger::LogLevel;
+use crate::base::prompt::read_prompt_file;
+use crate::base::util::date::Timezone;
+use crate::base::util::file::FileReader;
+
+/// 支持的 LLM Provider
+pub const SUPPORTED_PROVIDERS: &[&str] = &["openai", "deepseek", "proxy"];
+
+/// 配置问题严重程度
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueSeverity {
+    /// 错误：相关功能无法正常工作
+    Error,
+    /// 警告：配置可用，但可能不符合预期
+    Warning,
+}
+
+impl IssueSeverity {
+    /// 将严重程度转换为字符串
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IssueSeverity::Error => "error",
+            IssueSeverity::Warning => "warning",
+        }
+    }
+}
+
+/// 配置问题
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigIssue {
+    /// 配置键路径（如 `llm.provider`、`github.accounts[0].email`）
+    pub field: String,
+    /// 问题描述
+    pub message: String,
+    /// 严重程度
+    pub severity: IssueSeverity,
+}
+
+impl ConfigIssue {
+    /// 创建错误级别的问题
+    pub fn error(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+            severity: IssueSeverity::Error,
+        }
+    }
+
+    /// 创建警告级别的问题
+    pub fn warning(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+            severity: IssueSeverity::Warning,
+        }
+    }
+
+    /// 是否为错误级别
+    pub fn is_error(&self) -> bool {
+        self.severity == IssueSeverity::Error
+    }
+}
+
+impl fmt::Display for ConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{}] {}: {}",
+            self.severity.as_str(),
+            self.field,
+            self.message
+        )
+    }
+}
+
+impl Settings {
+    /// 校验配置
+    ///
+    /// 返回发现的所有问题；没有问题时返回空列表。
+    pub fn validate(&self) -> Vec<ConfigIssue> {
+        let mut issues = Vec::new();
+        self.validate_jira(&mut issues);
+        self.validate_github(&mut issues);
+        self.validate_llm(&mut issues);
+        self.validate_log(&mut issues);
+        self.validate_prompt(&mut issues);
+        self.validate_http(&mut issues);
+        self.validate_check(&mut issues);
+        issues
+    }
+
+    /// 校验当前生效的配置
+    ///
+    /// 先检查基础配置文件和当前激活 Profile 的配置文件能否解析；
+    /// 都能解析时，再校验合并后的配置（包含环境变量覆盖）。
+    pub fn validate_active() -> Vec<ConfigIssue> {
+        let mut paths: Vec<_> = Paths::workflow_config().into_iter().collect();
+        let profile = Profile::active();
+        if !Profile::is_default(&profile) {
+            paths.extend(Paths::profile_config(&profile));
+        }
+
+        let parse_issues: Vec<ConfigIssue> =
+            paths.iter().filter_map(|path| Self::parse_issue(path)).collect();
+        if !parse_issues.is_empty() {
+            return parse_issues;
+        }
+
+        Self::load_with_env_overrides().0.validate()
+    }
+
+    /// 校验配置文件
+    ///
+    /// 先检查 TOML 语法和字段类型（解析失败时定位到出错的配置键），
+    /// 解析成功后再执行 `validate()`。文件不存在时返回空列表。
+    pub fn validate_file(path: &Path) -> Vec<ConfigIssue> {
+        match Self::parse_issue(path) {
+            Some(issue) => vec![issue],
+            None => FileReader::new(path)
+                .to_string()
+                .ok()
+                .and_then(|content| toml::from_str::<Settings>(&content).ok())
+                .map(|settings| settings.validate())
+                .unwrap_or_default(),
+        }
+    }
+
+    /// 检查配置文件的 TOML 语法和字段类型
+    ///
+    /// 解析失败时返回定位到出错配置键的问题；文件不存在或解析成功时返回 `None`。
+    fn parse_issue(path: &Path) -> Option<ConfigIssue> {
+        if !path.exists() {
+            return None;
+        }
+
+        let content = match FileReader::new(path).to_string() {
+            Ok(content) => content,
+            Err(e) => {
+                return Some(ConfigIssue::error(
+                    path.display().to_string(),
+                    format!("Failed to read config file: {}", e),
+                ))
+            }
+        };
+
+        let e = toml::from_str::<Settings>(&content).err()?;
+        let field = e
+            .span()
+            .and_then(|span| key_path_at(&content, span.start))
+            .unwrap_or_else(|| path.display().to_string());
+        Some(ConfigIssue::error(field, e.message().trim().to_string()))
+    }
+
+    /// 校验 Jira 配置（部分配置视为不完整）
+    fn validate_jira(&self, issues: &mut Vec<ConfigIssue>) {
+        let jira = &self.jira;
+        if let Some(ref timezone) = jira.timezone {
+            if let Err(e) = timezone.parse::<Timezone>() {
+                issues.push(ConfigIssue::warning(
+                    "jira.timezone",
+                    format!("{} (using local timezone)", e),
+                ));
+            }
+        }
+
+        if let Some(ref auth_type) = jira.auth_type {
+            if let Err(e) = auth_type.parse::<JiraAuthType>() {
+                issues.push(ConfigIssue::warning(
+                    "jira.auth_type",
+                    format!("{} (using basic)", e),
+                ));
+            }
+        }
+
+        if !jira.has_credentials() {
+            return;
+        }
+
+        // Bearer 认证不需要邮箱
+        let mut required = vec![
+            ("jira.api_token", &jira.api_token),
+            ("jira.service_address", &jira.service_address),
+        ];
+        if jira.auth_type() == JiraAuthType::Basic {
+            required.insert(0, ("jira.email", &jira.email));
+        }
+        for (field, value) in required {
+            if value.as_deref().map(str::trim).unwrap_or_default().is_empty() {
+                issues.push(ConfigIssue::error(
+                    field,
+                    "Missing required field (Jira configuration is incomplete)",
+                ));
+            }
+        }
+
+        if let Some(email) = jira.email.as_deref().filter(|e| !e.trim().is_empty()) {
+            if !email.contains('@') {
+                issues.push(ConfigIssue::error(
+                    "jira.email",
+                    format!("Invalid email format: '{}'", email),
+                ));
+            }
+        }
+
+        if let Some(address) = jira.service_address.as_deref().filter(|a| !a.trim().is_empty()) {
+            if let Some(issue) = check_url("jira.service_address", address) {
+                issues.push(issue);
+            }
+        }
+    }
+
+    /// 校验 GitHub 账号配置和 GitHub Enterprise Server 地址
+    fn validate_github(&self, issues: &mut Vec<ConfigIssue>) {
+        for (idx, account) in self.github.accounts.iter().enumerate() {
+            let fields = [
+                ("name", &account.name),
+                ("email", &account.email),
+                ("api_token", &account.api_token),
+            ];
+            for (name, value) in fields {
+                if value.trim().is_empty() {
+                    issues.push(ConfigIssue::error(
+                        format!("github.accounts[{}].{}", idx, name),
+                        "Missing required field",
+                    ));
+                }
+            }
+            if !account.email.trim().is_empty() && !account.email.contains('@') {
+                issues.push(ConfigIssue::error(
+                    format!("github.accounts[{}].email", idx),
+                    format!("Invalid email format: '{}'", account.email),
+                ));
+            }
+        }
+
+        if let Some(ref current) = self.github.current {
+            if !self.github.accounts.iter().any(|acc| acc.name == *current) {
+                issues.push(ConfigIssue::warning(
+                    "github.current",
+                    format!(
+                        "Current account '{}' does not exist in accounts list",
+                        current
+                    ),
+                ));
+            }
+        }
+
+        let urls = [
+            ("github.api_base_url", &self.github.api_base_url),
+            ("github.web_base_url", &self.github.web_base_url),
+        ];
+        for (field, value) in urls {
+            if let Some(url) = value.as_deref().filter(|url| !url.trim().is_empty()) {
+                if let Some(issue) = check_url(field, url) {
+                    issues.push(issue);
+                }
+            }
+        }
+    }
+
+    /// 校验 LLM 配置
+    fn validate_llm(&self, issues: &mut Vec<ConfigIssue>) {
+        let llm = &self.llm;
+        if !SUPPORTED_PROVIDERS.contains(&llm.provider.as_str()) {
+            issues.push(ConfigIssue::error(
+                "llm.provider",
+                format!(
+                    "Invalid provider: '{}' (expected: {})",
+                    llm.provider,
+                    SUPPORTED_PROVIDERS.join(", ")
+                ),
+            ));
+        }
+
+        if !llm.language.is_empty()
+            && find_language(&llm.language).is_none()
+            && !is_auto_language(&llm.language)
+        {
+            issues.push(ConfigIssue::warning(
+                "llm.language",
+                format!("Unsupported language: '{}'", llm.language),
+            ));
+        }
+
+        let providers = [
+            ("openai", &llm.openai),
+            ("deepseek", &llm.deepseek),
+            ("proxy", &llm.proxy),
+        ];
+        for (name, provider) in providers {
+            if let Some(url) = provider.url.as_deref().filter(|u| !u.trim().is_empty()) {
+                if let Some(issue) = check_url(&format!("llm.{}.url", name), url) {
+                    issues.push(issue);
+                }
+            }
+            if provider.max_diff_chars == Some(0) {
+                issues.push(ConfigIssue::error(
+                    format!("llm.{}.max_diff_chars", name),
+                    "Diff budget must be greater than 0",
+                ));
+            }
+        }
+
+        if llm.provider == "proxy" {
+            let proxy = &llm.proxy;
+            if proxy.url.as_deref().map(str::trim).unwrap_or_default().is_empty() {
+                issues.push(ConfigIssue::error(
+                    "llm.proxy.url",
+                    "Missing required field (required when provider is 'proxy')",
+                ));
+            }
+            if proxy.model.as_deref().map(str::trim).unwrap_or_default().is_empty() {
+                issues.push(ConfigIssue::error(
+                    "llm.proxy.model",
+                    "Missing required field (required when provider is 'proxy')",
+                ));
+            }
+        }
+    }
+
+    /// 校验日志配置
+    fn validate_log(&self, issues: &mut Vec<ConfigIssue>) {
+        if let Some(ref level) = self.log.level {
+            if level.parse::<LogLevel>().is_err() {
+                issues.push(ConfigIssue::error(
+                    "log.level",
+                    format!(
+                        "Invalid log level: '{}' (expected: off, error, warn, info, debug)",
+                        level
+                    ),
+                ));
+            }
+        }
+
+        if let Some(ref dir) = self.log.download_base_dir {
+            match Paths::expand(dir) {
+                Ok(path) if path.exists() && !path.is_dir() => {
+                    issues.push(ConfigIssue::error(
+                        "log.download_base_dir",
+                        format!("Path is not a directory: {}", path.display()),
+                    ));
+                }
+                Ok(path) if !path.exists() => {
+                    issues.push(ConfigIssue::warning(
+                        "log.download_base_dir",
+                        format!(
+                            "Directory does not exist and will be created on first download: {}",
+                            path.display()
+                        ),
+                    ));
+                }
+                Ok(_) => {}
+                Err(e) => {
+                    issues.push(ConfigIssue::error(
+                        "log.download_base_dir",
+                        format!("Failed to expand path '{}': {}", dir, e),
+                    ));
+                }
+            }
+        }
+
+        // 无效的正则表达式会被忽略（配置中的密钥和内置 token 格式仍然会被隐藏）
+        for pattern in self.log.redact_patterns.iter().flatten() {
+            if let Err(e) = Regex::new(pattern) {
+                issues.push(ConfigIssue::warning(
+                    "log.redact_patterns",
+                    format!("Invalid pattern '{}', it will be ignored: {}", pattern, e),
+                ));
+            }
+        }
+    }
+
+    /// 校验自定义 prompt 配置
+    ///
+    /// 文件不可用时回退到内置 prompt，因此只报告警告。
+    fn validate_prompt(&self, issues: &mut Vec<ConfigIssue>) {
+        let paths = [
+            (
+                "prompt.generate_branch_path",
+                &self.prompt.generate_branch_path,
+            ),
+            ("prompt.summarize_pr_path", &self.prompt.summarize_pr_path),
+        ];
+        for (field, path) in paths {
+            if let Some(path) = path.as_deref().filter(|path| !path.trim().is_empty()) {
+                if let Err(e) = read_prompt_file(path.trim()) {
+                    issues.push(ConfigIssue::warning(
+                        field,
+                        format!("{}, the built-in prompt will be used", e),
+                    ));
+                }
+            }
+        }
+    }
+
+    /// 校验 HTTP 配置
+    ///
+    /// 超时时间或最大并发下载数为 0 时使用默认值，并发数超过上限时使用上限，因此只报告警告。
+    fn validate_http(&self, issues: &mut Vec<ConfigIssue>) {
+        let timeouts = [
+            (
+                "http.timeout_secs",
+                self.http.timeout_secs,
+                HttpSettings::DEFAULT_TIMEOUT_SECS,
+            ),
+            (
+                "http.download_timeout_secs",
+                self.http.download_timeout_secs,
+                HttpSettings::DEFAULT_DOWNLOAD_TIMEOUT_SECS,
+            ),
+        ];
+        for (field, value, default) in timeouts {
+            if value == Some(0) {
+                issues.push(ConfigIssue::warning(
+                    field,
+                    format!(
+                        "Timeout must be greater than 0, the default ({}s) will be used",
+                        default
+                    ),
+                ));
+            }
+        }
+
+        match self.http.max_concurrent_downloads {
+            Some(0) => issues.push(ConfigIssue::warning(
+                "http.max_concurrent_downloads",
+                format!(
+                    "Must be greater than 0, the default ({}) will be used",
+                    HttpSettings::DEFAULT_MAX_CONCURRENT_DOWNLOADS
+                ),
+            )),
+            Some(max) if max > HttpSettings::MAX_CONCURRENT_DOWNLOADS => {
+                issues.push(ConfigIssue::warning(
+                    "http.max_concurrent_downloads",
+                    format!(
+                        "{} exceeds the limit, {} will be used",
+                        max,
+                        HttpSettings::MAX_CONCURRENT_DOWNLOADS
+                    ),
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    /// 校验环境检查配置（网络检查目标的名称不能为空，地址需要是 http/https URL）
+    fn validate_check(&self, issues: &mut Vec<ConfigIssue>) {
+        for (index, target) in self.check.network_targets.iter().enumerate() {
+            if target.name.trim().is_empty() {
+                issues.push(ConfigIssue::error(
+                    format!("check.network_targets[{}].name", index),
+                    "Target name is required",
+                ));
+            }
+            if let Some(issue) = check_url(
+                &format!("check.network_targets[{}].url", index),
+                &target.url,
+            ) {
+                issues.push(issue);
+            }
+        }
+    }
+}
+
+/// 检查 URL 格式（必须是带主机名的 http/https 地址）
+fn check_url(field: &str, url: &str) -> Option<ConfigIssue> {
+    let invalid = |reason: &str| {
+        Some(ConfigIssue::error(
+            field,
+            format!("Invalid URL '{}': {}", url, reason),
+        ))
+    };
+
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if !matches!(parsed.scheme(), "http" | "https") => {
+            invalid("must start with http:// or https://")
+        }
+        Ok(parsed) if parsed.host_str().map(str::is_empty).unwrap_or(true) => {
+            invalid("missing host")
+        }
+        Ok(_) => None,
+        Err(_) if !url.contains("://") => invalid("must start with http:// or https://"),
+        Err(e) => invalid(&e.to_string()),
+    }
+}
+
+/// 根据字节偏移定位所在的配置键路径（如 `llm.provider`）
+///
+/// 使用出错位置所在行的键名和其前面最近的 `[section]` 表头拼接路径。
+fn key_path_at(content: &str, offset: usize) -> Option<String> {
+    let offset = offset.min(content.len());
+    let line_start = content[..offset].rfind('\n').map(|i| i + 1).unwrap_or(0);
+    let line_end = content[offset..].find('\n').map(|i| offset + i).unwrap_or(content.len());
+    let line = content[line_start..line_end].trim();
+
+    let section = content[..line_start]
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|l| l.starts_with('['))
+        .map(|l| l.trim_matches(|c| c == '[' || c == ']').trim().to_string());
+
+    // 出错位置本身就是表头（如整个表的类型错误）
+    if line.starts_with('[') {
+        return Some(line.trim_matches(|c| c == '[' || c == ']').trim().to_string());
+    }
+
+    let key = line.split_once('=').map(|(key, _)| key.trim().trim_matches('"'))?;
+    if key.is_empty() {
+        return section;
+    }
+    Some(match section {
+        Some(section) if !section.is_empty() => format!("{}.{}", section, key),
+        _ => key.to_string(),
+    })
+}
@@ -2,19 +2,99 @@
 //!
 //! 提供通用的 shell 配置文件管理功能，包括：
 //! - 环境变量管理（export 语句）
-//! - Source 语句管理
-//! - 配置块管理
+//! - Source 语句管理（`# >>> workflow source >>>` / `# <<< workflow source <<<` 包围的托管配置块）
+//! - 配置块管理（`# >>> workflow >>>` / `# <<< workflow <<<` 包围的托管配置块）
+//! - 别名管理（`# >>> workflow aliases >>>` / `# <<< workflow aliases <<<` 包围的托管配置块）
 //!
-//! 支持 zsh、bash、fish、powershell、elvish 等 shell 的配置文件。
+//! 支持 zsh、bash、fish、powershell、elvish、nushell 等 shell 的配置文件。
+//! 所有修改都是幂等的：重复执行不会产生重复的配置块、export 或 source 语句。
+//!
+//! 修改配置文件前会在同一目录下创建带时间戳的备份（如 `.zshrc.workflow-backup-20250101-120000`），
+//! 新内容先写入临时文件再替换原文件，写入失败时原文件保持不变。
+//! 清理旧备份时总是保留本次运行的第一个备份（即修改前的原始内容）。
 
 use crate::base::settings::paths::Paths;
+use crate::base::util::diff::render_line_diff;
 use crate::base::util::file::{FileReader, FileWriter};
-use clap_complete::Shell;
-use color_eyre::{eyre::WrapErr, Result};
-use std::collections::HashMap;
-use std::path::PathBuf;
+use color_eyre::{
+    eyre::{eyre, WrapErr},
+    Result,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
 
 use super::detect::Detect;
+use super::Shell;
+
+/// 托管配置块开始标记
+const MANAGED_BLOCK_START: &str = "# >>> workflow >>>";
+/// 托管配置块结束标记
+const MANAGED_BLOCK_END: &str = "# <<< workflow <<<";
+/// 旧版本配置块标记（读取、更新和移除时兼容，更新时替换为新标记）
+const LEGACY_BLOCK_START: &str = "# Workflow CLI Configuration - Start";
+const LEGACY_BLOCK_END: &str = "# Workflow CLI Configuration - End";
+const SHORT_BLOCK_START: &str = "# >>> workflow";
+const SHORT_BLOCK_END: &str = "# <<< workflow";
+
+/// 所有可识别的配置块标记（开始标记，结束标记）
+const BLOCK_MARKERS: [(&str, &str); 3] = [
+    (MANAGED_BLOCK_START, MANAGED_BLOCK_END),
+    (SHORT_BLOCK_START, SHORT_BLOCK_END),
+    (LEGACY_BLOCK_START, LEGACY_BLOCK_END),
+];
+
+/// 托管 source 配置块开始标记
+const SOURCE_BLOCK_START: &str = "# >>> workflow source >>>";
+/// 托管 source 配置块结束标记
+const SOURCE_BLOCK_END: &str = "# <<< workflow source <<<";
+
+/// 托管别名配置块开始标记
+const ALIAS_BLOCK_START: &str = "# >>> workflow aliases >>>";
+/// 托管别名配置块结束标记
+const ALIAS_BLOCK_END: &str = "# <<< workflow aliases <<<";
+
+/// nushell 设置环境变量的语句前缀（`$env.KEY = "VALUE"`）
+const NUSHELL_ENV_PREFIX: &str = "$env.";
+
+/// 备份文件名中的标记（`<配置文件名>.workflow-backup-<时间戳>`）
+const BACKUP_MARKER: &str = ".workflow-backup-";
+/// 每个配置文件最多保留的备份数量（超出时删除最旧的备份）
+const MAX_BACKUPS: usize = 5;
+
+/// 本次运行中每个配置文件的第一个备份（修改前的原始内容），清理旧备份时总是保留
+fn run_backups() -> &'static Mutex<HashMap<PathBuf, PathBuf>> {
+    static RUN_BACKUPS: OnceLock<Mutex<HashMap<PathBuf, PathBuf>>> = OnceLock::new();
+    RUN_BACKUPS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Shell 配置文件修改结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShellConfigEdit {
+    /// 被修改的配置文件路径
+    pub config_path: PathBuf,
+    /// 修改前的备份文件路径（修改前文件不存在时为 `None`），可用于手动恢复
+    pub backup_path: Option<PathBuf>,
+}
+
+/// Shell 配置文件修改预览（不写入文件，用于 dry-run）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShellConfigPreview {
+    /// 将被修改的配置文件路径
+    pub config_path: PathBuf,
+    /// 修改前的内容
+    pub before: String,
+    /// 修改后的内容
+    pub after: String,
+}
+
+impl ShellConfigPreview {
+    /// 生成修改前后的行级差异（`- ` 为删除的行，`+ ` 为新增的行，保留 2 行上下文）
+    pub fn diff(&self) -> String {
+        render_line_diff(&self.before, &self.after, 2)
+    }
+}
 
 /// Shell 配置管理器
 ///
@@ -43,39 +123,115 @@ impl ShellConfigManager {
         }
 
         let content = Self::read_config_file(&config_path)?;
-        let (env_vars, _) = Self::parse_config_block(&content)?;
-
-        Ok(env_vars)
+        Ok(Self::parse_config_block(&content))
     }
 
     /// 保存环境变量到配置块
     ///
     /// 将环境变量保存到 shell 配置文件的配置块中。
-    /// 如果 key 已存在则覆盖，不存在则新增。
+    /// 如果 key 已存在则覆盖，不存在则新增（见 `upsert_env_vars_in_content_for_shell`）。
+    /// 内容没有变化时不写入文件。
     ///
     /// # 参数
     ///
     /// * `env_vars` - 要保存的环境变量 HashMap
     ///
+    /// # 返回
+    ///
+    /// 修改了配置文件时返回 `ShellConfigEdit`（包含备份路径）；内容没有变化时返回 `None`。
+    ///
     /// # 错误
     ///
     /// 如果写入配置文件失败，返回相应的错误信息。
-    pub fn save_env_vars(env_vars: &HashMap<String, String>) -> Result<()> {
-        let config_path = Self::get_config_path()?;
+    pub fn save_env_vars(env_vars: &HashMap<String, String>) -> Result<Option<ShellConfigEdit>> {
+        let shell = Detect::shell()?;
+        let config_path = Paths::config_file(&shell)?;
+        let content = Self::read_config_file(&config_path)?;
 
-        // 读取现有配置
-        let existing = Self::load_existing_config(&config_path)?;
+        let new_content = Self::upsert_env_vars_in_content_for_shell(&content, &shell, env_vars);
+        if new_content == content {
+            return Ok(None);
+        }
+
+        Self::write_config_file(&config_path, &new_content).map(Some)
+    }
 
-        // 合并环境变量
-        let merged = Self::merge_env_vars(&existing.env_in_block, env_vars);
+    /// 在配置内容中更新或添加环境变量（`export KEY="VALUE"` 语法）
+    ///
+    /// 见 `upsert_env_vars_in_content_for_shell`。
+    ///
+    /// # 参数
+    ///
+    /// * `content` - shell 配置文件内容
+    /// * `env_vars` - 要设置的环境变量
+    ///
+    /// # 返回
+    ///
+    /// 返回更新后的配置文件内容。
+    pub fn upsert_env_vars_in_content(content: &str, env_vars: &HashMap<String, String>) -> String {
+        Self::upsert_env_vars_in_content_for_shell(content, &Shell::Zsh, env_vars)
+    }
 
-        // 构建新内容
-        let new_content = Self::build_config_content(&existing.content_without_block, &merged)?;
+    /// 在配置内容中更新或添加环境变量（指定 shell 类型）
+    ///
+    /// nushell 使用 `$env.KEY = "VALUE"` 语法，其他 shell 使用 `export KEY="VALUE"` 语法：
+    /// - 已有配置块时原地更新（保持配置块在文件中的位置），多余的重复配置块会被合并移除
+    /// - 配置块外与要设置的 key 相同的 export 语句（忽略多余空白）会被移除，避免重复定义
+    /// - 没有配置块时追加到文件末尾
+    ///
+    /// 对同一内容重复调用的结果相同（幂等）。
+    ///
+    /// # 参数
+    ///
+    /// * `content` - shell 配置文件内容
+    /// * `shell` - Shell 类型
+    /// * `env_vars` - 要设置的环境变量
+    ///
+    /// # 返回
+    ///
+    /// 返回更新后的配置文件内容。
+    pub fn upsert_env_vars_in_content_for_shell(
+        content: &str,
+        shell: &Shell,
+        env_vars: &HashMap<String, String>,
+    ) -> String {
+        let segments = Self::split_config_blocks(content);
+        let merged = Self::merge_env_vars(&Self::env_vars_in_segments(&segments), env_vars);
+        let config_block = Self::build_config_block(shell, &merged);
+        let block_lines: Vec<&str> = config_block.lines().collect();
+        // 去掉开始和结束标记行
+        let block_body = &block_lines[1..block_lines.len() - 1];
+
+        // 第一个配置块原地替换为新配置块，其余配置块清空（渲染时移除）
+        let mut block_written = false;
+        let mut segments: Vec<ConfigSegment> = segments
+            .into_iter()
+            .filter_map(|segment| match segment {
+                ConfigSegment::Line(line) => {
+                    let duplicated =
+                        Self::export_key(line).is_some_and(|key| env_vars.contains_key(key));
+                    (!duplicated).then_some(ConfigSegment::Line(line))
+                }
+                ConfigSegment::Block(_) if !block_written => {
+                    block_written = true;
+                    Some(ConfigSegment::Block(block_body.to_vec()))
+                }
+                ConfigSegment::Block(_) => Some(ConfigSegment::Block(Vec::new())),
+            })
+            .collect();
 
-        // 写入文件
-        Self::write_config_file(&config_path, &new_content)?;
+        if !block_written && !merged.is_empty() {
+            while matches!(segments.last(), Some(ConfigSegment::Line(line)) if line.trim().is_empty())
+            {
+                segments.pop();
+            }
+            if !segments.is_empty() {
+                segments.push(ConfigSegment::Line(""));
+            }
+            segments.push(ConfigSegment::Block(block_body.to_vec()));
+        }
 
-        Ok(())
+        Self::render_segments(&segments, Self::has_exports)
     }
 
     /// 批量设置环境变量
@@ -86,10 +242,14 @@ impl ShellConfigManager {
     ///
     /// * `env_vars` - 要设置的环境变量 HashMap
     ///
+    /// # 返回
+    ///
+    /// 修改了配置文件时返回 `ShellConfigEdit`（包含备份路径）；内容没有变化时返回 `None`。
+    ///
     /// # 错误
     ///
     /// 如果写入配置文件失败，返回相应的错误信息。
-    pub fn set_env_vars(env_vars: &HashMap<String, String>) -> Result<()> {
+    pub fn set_env_vars(env_vars: &HashMap<String, String>) -> Result<Option<ShellConfigEdit>> {
         Self::save_env_vars(env_vars)
     }
 
@@ -103,160 +263,160 @@ impl ShellConfigManager {
     ///
     /// # 返回
     ///
-    /// 返回 `true` 如果移除了任何内容，否则返回 `false`。
+    /// 移除了任何内容时返回 `ShellConfigEdit`（包含备份路径），否则返回 `None`。
     ///
     /// # 错误
     ///
     /// 如果读取或写入配置文件失败，返回相应的错误信息。
-    pub fn remove_env_vars(keys: &[&str]) -> Result<bool> {
+    pub fn remove_env_vars(keys: &[&str]) -> Result<Option<ShellConfigEdit>> {
         let config_path = Self::get_config_path()?;
 
         if !config_path.exists() {
-            return Ok(false);
+            return Ok(None);
         }
 
         let content = Self::read_config_file(&config_path)?;
+        Self::remove_env_vars_from_content(&content, keys)
+            .map(|new_content| Self::write_config_file(&config_path, &new_content))
+            .transpose()
+    }
 
-        let marker_start = "# Workflow CLI Configuration - Start";
-        let marker_end = "# Workflow CLI Configuration - End";
-
-        // 使用 filter 直接过滤掉需要删除的行，避免先收集再删除
-        let lines: Vec<&str> = content
-            .lines()
-            .filter(|line| {
-                let trimmed = line.trim();
+    /// 预览移除 export 语句的修改（不写入文件）
+    ///
+    /// # 参数
+    ///
+    /// * `keys` - 要移除的环境变量键名数组
+    ///
+    /// # 返回
+    ///
+    /// 会移除任何内容时返回修改预览，否则返回 `None`。
+    ///
+    /// # 错误
+    ///
+    /// 如果读取配置文件失败，返回相应的错误信息。
+    pub fn preview_remove_env_vars(keys: &[&str]) -> Result<Option<ShellConfigPreview>> {
+        let config_path = Self::get_config_path()?;
+        let content = Self::read_config_file(&config_path)?;
 
-                // 跳过配置块的标记行
-                if trimmed == marker_start || trimmed == marker_end {
-                    return true;
-                }
+        Ok(
+            Self::remove_env_vars_from_content(&content, keys).map(|after| ShellConfigPreview {
+                config_path,
+                before: content,
+                after,
+            }),
+        )
+    }
 
-                // 如果行以 export 开头，检查是否匹配要删除的键
-                if let Some(rest) = trimmed.strip_prefix("export ") {
-                    for key in keys {
-                        // 检查是否是 export KEY= 或 export KEY="
-                        if let Some(after_key) = rest.strip_prefix(key) {
-                            let after_key = after_key.trim_start();
-                            if after_key.starts_with('=') {
-                                // 找到匹配的 export 行，过滤掉
-                                return false;
-                            }
-                        }
-                    }
+    /// 从配置内容中移除指定的 export 语句
+    ///
+    /// 移除配置块内外匹配的 export 语句（忽略多余空白），不再包含 export 语句的配置块会被整体移除。
+    ///
+    /// # 参数
+    ///
+    /// * `content` - shell 配置文件内容
+    /// * `keys` - 要移除的环境变量键名数组
+    ///
+    /// # 返回
+    ///
+    /// 移除了任何内容时返回新内容，否则返回 `None`。
+    pub fn remove_env_vars_from_content(content: &str, keys: &[&str]) -> Option<String> {
+        // 过滤掉匹配的 export 行（配置块内外），之后移除不再包含 export 语句的配置块
+        let segments: Vec<ConfigSegment> = Self::split_config_blocks(content)
+            .into_iter()
+            .filter_map(|segment| match segment {
+                ConfigSegment::Line(line) => {
+                    let matched = Self::export_key(line).is_some_and(|key| keys.contains(&key));
+                    (!matched).then_some(ConfigSegment::Line(line))
                 }
-
-                true
+                ConfigSegment::Block(block) => Some(ConfigSegment::Block(
+                    block
+                        .into_iter()
+                        .filter(|line| {
+                            Self::export_key(line).is_none_or(|key| !keys.contains(&key))
+                        })
+                        .collect(),
+                )),
             })
             .collect();
 
-        let removed_any = lines.len() < content.lines().count();
-
-        if removed_any {
-            // 重新构建文件内容
-            let new_content = lines.join("\n");
-
-            // 检查配置块是否为空（只有标记和注释，没有 export 语句）
-            // 如果为空，则完全移除配置块
-            let final_content =
-                Self::remove_empty_config_block(&new_content, marker_start, marker_end);
-
-            // 确保文件以换行符结尾
-            let final_content = if final_content.ends_with('\n') {
-                final_content
-            } else {
-                format!("{}\n", final_content)
-            };
-
-            Self::write_config_file(&config_path, &final_content)?;
+        if Self::segment_line_count(&segments) == content.lines().count() {
+            return None;
         }
 
-        Ok(removed_any)
+        Some(Self::render_segments(&segments, Self::has_exports))
     }
 
-    /// 移除空的配置块
+    /// 移除托管配置块
+    ///
+    /// 从 shell 配置文件中移除所有由 `# >>> workflow >>>` / `# <<< workflow <<<` 包围的配置块
+    /// （包括旧版本的 `# >>> workflow`、`# Workflow CLI Configuration - Start/End` 配置块），
+    /// 配置块外的内容（包括 source 配置块）保持不变。
+    ///
+    /// # 返回
+    ///
+    /// 移除了配置块时返回 `ShellConfigEdit`（包含备份路径），否则返回 `None`。
+    ///
+    /// # 错误
     ///
-    /// 如果配置块内没有任何 export 语句（只有标记和注释），则完全移除配置块。
-    fn remove_empty_config_block(content: &str, marker_start: &str, marker_end: &str) -> String {
-        // 查找配置块
-        if let Some(start_pos) = content.find(marker_start) {
-            if let Some(end_pos) = content[start_pos..].find(marker_end) {
-                let block_end = start_pos + end_pos + marker_end.len();
-                let block_content = &content[start_pos + marker_start.len()..start_pos + end_pos];
+    /// 如果读取或写入配置文件失败，返回相应的错误信息。
+    pub fn remove_managed_block() -> Result<Option<ShellConfigEdit>> {
+        let config_path = Self::get_config_path()?;
 
-                // 检查块内是否有任何 export 语句
-                let has_exports = block_content.lines().any(|line| {
-                    let trimmed = line.trim();
-                    !trimmed.is_empty()
-                        && !trimmed.starts_with('#')
-                        && trimmed.starts_with("export ")
-                });
+        if !config_path.exists() {
+            return Ok(None);
+        }
 
-                // 如果没有 export 语句，移除整个配置块
-                if !has_exports {
-                    let before = content[..start_pos].trim_end();
-                    let after = content[block_end..].trim_start();
+        let content = Self::read_config_file(&config_path)?;
+        Self::remove_managed_block_from_content(&content)
+            .map(|new_content| Self::write_config_file(&config_path, &new_content))
+            .transpose()
+    }
 
-                    return if before.is_empty() {
-                        after.to_string()
-                    } else if after.is_empty() {
-                        format!("{}\n", before)
-                    } else {
-                        format!("{}\n{}", before, after)
-                    };
-                }
-            }
+    /// 从配置内容中移除托管配置块
+    ///
+    /// 未闭合的开始标记不视为配置块，原样保留。
+    ///
+    /// # 参数
+    ///
+    /// * `content` - shell 配置文件内容
+    ///
+    /// # 返回
+    ///
+    /// 如果存在配置块，返回移除后的内容；否则返回 `None`。
+    pub fn remove_managed_block_from_content(content: &str) -> Option<String> {
+        let segments = Self::split_config_blocks(content);
+        if !segments.iter().any(|segment| matches!(segment, ConfigSegment::Block(_))) {
+            return None;
         }
 
-        content.to_string()
+        Some(Self::render_segments(&segments, |_| false))
     }
 
     // === Source 语句管理 ===
 
     /// 添加 source 语句
     ///
-    /// 在 shell 配置文件中添加 source 语句。如果已存在则跳过。
+    /// 在当前 shell 的配置文件中添加 source 语句（见 `add_source_for_shell`）。如果已存在则跳过。
     ///
     /// # 参数
     ///
     /// * `source_path` - source 文件路径（支持相对路径如 `$HOME/.workflow/.completions` 或绝对路径）
     /// * `comment` - 可选的注释文本
     ///
+    /// # 返回
+    ///
+    /// 添加成功返回 `ShellConfigEdit`（包含备份路径）；已存在时返回 `None`。
+    ///
     /// # 错误
     ///
     /// 如果读取或写入配置文件失败，返回相应的错误信息。
-    pub fn add_source(source_path: &str, comment: Option<&str>) -> Result<bool> {
-        let config_path = Self::get_config_path()?;
-        let content = Self::read_config_file(&config_path).unwrap_or_default();
-
-        // 检查是否已存在
-        if Self::has_source_in_content(&content, source_path)? {
-            return Ok(false);
-        }
-
-        // 添加 source 语句
-        let mut new_content = content;
-        if !new_content.is_empty() && !new_content.ends_with('\n') {
-            new_content.push('\n');
-        }
-
-        if let Some(comment_text) = comment {
-            new_content.push_str("# ");
-            new_content.push_str(comment_text);
-            new_content.push('\n');
-        }
-        new_content.push_str("source ");
-        new_content.push_str(source_path);
-        new_content.push('\n');
-        new_content.push('\n');
-
-        Self::write_config_file(&config_path, &new_content)?;
-
-        Ok(true)
+    pub fn add_source(source_path: &str, comment: Option<&str>) -> Result<Option<ShellConfigEdit>> {
+        Self::add_source_for_shell(&Detect::shell()?, source_path, comment)
     }
 
     /// 移除 source 语句
     ///
-    /// 从 shell 配置文件中移除指定的 source 语句。
+    /// 从当前 shell 的配置文件中移除指定的 source 语句（见 `remove_source_for_shell`）。
     ///
     /// # 参数
     ///
@@ -264,26 +424,13 @@ impl ShellConfigManager {
     ///
     /// # 返回
     ///
-    /// 返回 `true` 如果移除了 source 语句，否则返回 `false`。
+    /// 移除了 source 语句时返回 `ShellConfigEdit`（包含备份路径），否则返回 `None`。
     ///
     /// # 错误
     ///
     /// 如果读取或写入配置文件失败，返回相应的错误信息。
-    pub fn remove_source(source_path: &str) -> Result<bool> {
-        let config_path = Self::get_config_path()?;
-        let content = Self::read_config_file(&config_path).unwrap_or_default();
-
-        // 检查是否存在
-        if !Self::has_source_in_content(&content, source_path)? {
-            return Ok(false);
-        }
-
-        // 移除 source 语句和相关注释
-        let new_content = Self::remove_source_from_content(&content, source_path)?;
-
-        Self::write_config_file(&config_path, &new_content)?;
-
-        Ok(true)
+    pub fn remove_source(source_path: &str) -> Result<Option<ShellConfigEdit>> {
+        Self::remove_source_for_shell(&Detect::shell()?, source_path)
     }
 
     /// 检查 source 语句是否存在
@@ -319,7 +466,8 @@ impl ShellConfigManager {
 
     /// 添加 source 语句（指定 shell 类型）
     ///
-    /// 在指定 shell 类型的配置文件中添加 source 语句。如果已存在则跳过。
+    /// 在指定 shell 类型的配置文件中添加由 `# >>> workflow source >>>` / `# <<< workflow source <<<`
+    /// 包围的 source 配置块。如果已存在则跳过。
     /// 根据 shell 类型自动使用正确的关键字（PowerShell 使用 `.`，其他使用 `source`）。
     ///
     /// # 参数
@@ -328,6 +476,10 @@ impl ShellConfigManager {
     /// * `source_path` - source 文件路径（支持相对路径如 `$HOME/.workflow/.completions` 或绝对路径）
     /// * `comment` - 可选的注释文本
     ///
+    /// # 返回
+    ///
+    /// 添加成功返回 `ShellConfigEdit`（包含备份路径）；已存在时返回 `None`。
+    ///
     /// # 错误
     ///
     /// 如果读取或写入配置文件失败，返回相应的错误信息。
@@ -335,42 +487,90 @@ impl ShellConfigManager {
         shell: &Shell,
         source_path: &str,
         comment: Option<&str>,
-    ) -> Result<bool> {
+    ) -> Result<Option<ShellConfigEdit>> {
         let config_path = Paths::config_file(shell)?;
         let content = Self::read_config_file(&config_path).unwrap_or_default();
 
-        // 检查是否已存在（支持不同格式）
-        if Self::has_source_in_content_for_shell(&content, shell, source_path)? {
-            return Ok(false);
-        }
-
-        // 添加 source 语句
-        let mut new_content = content;
-        if !new_content.is_empty() && !new_content.ends_with('\n') {
-            new_content.push('\n');
-        }
-
-        if let Some(comment_text) = comment {
-            new_content.push_str("# ");
-            new_content.push_str(comment_text);
-            new_content.push('\n');
-        }
-        let source_keyword = Self::get_source_keyword(shell);
-        new_content.push_str(source_keyword);
-        new_content.push(' ');
-        new_content.push_str(source_path);
-        new_content.push('\n');
-        new_content.push('\n');
+        Self::add_source_to_content(&content, shell, source_path, comment)?
+            .map(|new_content| Self::write_config_file(&config_path, &new_content))
+            .transpose()
+    }
 
-        Self::write_config_file(&config_path, &new_content)?;
+    /// 在配置内容中添加 source 语句（指定 shell 类型）
+    ///
+    /// source 语句写入托管 source 配置块中：
+    /// - 已存在包含该 source 语句的配置块时，第一个配置块原地替换为新内容，重复的配置块被移除
+    /// - 配置块外已存在等价的 source 语句（忽略多余空白，支持 `$HOME` 展开后的绝对路径，
+    ///   如手动添加或旧版本写入的语句）时不做修改
+    /// - 否则在内容末尾追加新的配置块
+    ///
+    /// # 参数
+    ///
+    /// * `content` - shell 配置文件内容
+    /// * `shell` - Shell 类型
+    /// * `source_path` - source 文件路径
+    /// * `comment` - 可选的注释文本
+    ///
+    /// # 返回
+    ///
+    /// 内容有变化时返回新内容；已存在相同的配置块或等价的 source 语句时返回 `None`。
+    ///
+    /// # 错误
+    ///
+    /// 如果无法获取 HOME 目录，返回相应的错误信息。
+    pub fn add_source_to_content(
+        content: &str,
+        shell: &Shell,
+        source_path: &str,
+        comment: Option<&str>,
+    ) -> Result<Option<String>> {
+        let patterns = Self::source_patterns(shell, source_path)?;
+        let lines: Vec<&str> = content.lines().collect();
+        let blocks = Self::source_blocks(&lines, &patterns);
+
+        let source_line = format!("{} {}", Self::get_source_keyword(shell), source_path);
+        let comment_line = comment.map(|text| format!("# {}", text));
+        let mut block = vec![SOURCE_BLOCK_START];
+        block.extend(comment_line.as_deref());
+        block.push(&source_line);
+        block.push(SOURCE_BLOCK_END);
+
+        let new_lines = match blocks.as_slice() {
+            [] if lines.iter().any(|line| Self::is_source_line(line, &patterns)) => {
+                return Ok(None);
+            }
+            [] => {
+                let mut new_lines = lines;
+                while new_lines.last().is_some_and(|line| line.trim().is_empty()) {
+                    new_lines.pop();
+                }
+                if !new_lines.is_empty() {
+                    new_lines.push("");
+                }
+                new_lines.extend(block);
+                new_lines
+            }
+            [(start, end)] if lines[*start..=*end] == block[..] => return Ok(None),
+            [(first, _), ..] => {
+                // 第一个配置块原地替换，其余重复的配置块移除
+                let first = *first;
+                Self::replace_line_ranges(&lines, &blocks, |start| {
+                    if start == first {
+                        block.clone()
+                    } else {
+                        Vec::new()
+                    }
+                })
+            }
+        };
 
-        Ok(true)
+        Ok(Some(Self::join_lines(&new_lines)))
     }
 
     /// 移除 source 语句（指定 shell 类型）
     ///
-    /// 从指定 shell 类型的配置文件中移除指定的 source 语句。
-    /// 支持不同 shell 的 source 语句格式（PowerShell 使用 `.`，其他使用 `source`）。
+    /// 从指定 shell 类型的配置文件中移除包含该 source 语句的托管配置块，以及配置块外的同一 source 语句
+    /// （旧版本写入的语句）。支持不同 shell 的 source 语句格式（PowerShell 使用 `.`，其他使用 `source`）。
     ///
     /// # 参数
     ///
@@ -379,26 +579,82 @@ impl ShellConfigManager {
     ///
     /// # 返回
     ///
-    /// 返回 `true` 如果移除了 source 语句，否则返回 `false`。
+    /// 移除了 source 语句时返回 `ShellConfigEdit`（包含备份路径），否则返回 `None`。
     ///
     /// # 错误
     ///
     /// 如果读取或写入配置文件失败，返回相应的错误信息。
-    pub fn remove_source_for_shell(shell: &Shell, source_path: &str) -> Result<bool> {
+    pub fn remove_source_for_shell(
+        shell: &Shell,
+        source_path: &str,
+    ) -> Result<Option<ShellConfigEdit>> {
         let config_path = Paths::config_file(shell)?;
         let content = Self::read_config_file(&config_path).unwrap_or_default();
 
+        Self::remove_source_from_content(&content, shell, source_path)?
+            .map(|new_content| Self::write_config_file(&config_path, &new_content))
+            .transpose()
+    }
+
+    /// 从配置内容中移除 source 语句（指定 shell 类型）
+    ///
+    /// 移除包含该 source 语句的托管配置块，以及配置块外的同一 source 语句及其相关的注释块（旧版本写入的语句）。
+    ///
+    /// # 参数
+    ///
+    /// * `content` - shell 配置文件内容
+    /// * `shell` - Shell 类型
+    /// * `source_path` - source 文件路径
+    ///
+    /// # 返回
+    ///
+    /// 存在对应的 source 语句时返回移除后的内容，否则返回 `None`。
+    ///
+    /// # 错误
+    ///
+    /// 如果无法获取 HOME 目录，返回相应的错误信息。
+    pub fn remove_source_from_content(
+        content: &str,
+        shell: &Shell,
+        source_path: &str,
+    ) -> Result<Option<String>> {
         // 检查是否存在（支持不同格式）
-        if !Self::has_source_in_content_for_shell(&content, shell, source_path)? {
-            return Ok(false);
+        if !Self::has_source_in_content_for_shell(content, shell, source_path)? {
+            return Ok(None);
         }
 
-        // 移除 source 语句和相关注释
-        let new_content = Self::remove_source_from_content_for_shell(&content, shell, source_path)?;
+        Self::remove_source_lines(content, shell, source_path).map(Some)
+    }
 
-        Self::write_config_file(&config_path, &new_content)?;
+    /// 预览移除 source 语句的修改（指定 shell 类型，不写入文件）
+    ///
+    /// # 参数
+    ///
+    /// * `shell` - Shell 类型
+    /// * `source_path` - source 文件路径
+    ///
+    /// # 返回
+    ///
+    /// 存在对应的 source 语句时返回修改预览，否则返回 `None`。
+    ///
+    /// # 错误
+    ///
+    /// 如果读取配置文件或获取 HOME 目录失败，返回相应的错误信息。
+    pub fn preview_remove_source_for_shell(
+        shell: &Shell,
+        source_path: &str,
+    ) -> Result<Option<ShellConfigPreview>> {
+        let config_path = Paths::config_file(shell)?;
+        let content = Self::read_config_file(&config_path).unwrap_or_default();
 
-        Ok(true)
+        let Some(after) = Self::remove_source_from_content(&content, shell, source_path)? else {
+            return Ok(None);
+        };
+        Ok(Some(ShellConfigPreview {
+            config_path,
+            before: content,
+            after,
+        }))
     }
 
     /// 检查 source 语句是否存在（指定 shell 类型）
@@ -421,112 +677,567 @@ impl ShellConfigManager {
         Self::has_source_in_content(&content, source_path)
     }
 
-    // === 配置块管理 ===
+    // === 别名管理 ===
 
-    /// 解析配置块
+    /// 读取当前 shell 配置文件中的托管别名
     ///
-    /// 从配置内容中解析配置块，返回配置块内的环境变量和移除配置块后的内容。
-    fn parse_config_block(content: &str) -> Result<(HashMap<String, String>, String)> {
-        let marker_start = "# Workflow CLI Configuration - Start";
-        let marker_end = "# Workflow CLI Configuration - End";
-
-        // 提取配置块内的环境变量
-        let env_in_block = if let Some(start_pos) = content.find(marker_start) {
-            if let Some(end_pos) = content[start_pos..].find(marker_end) {
-                let block_content = &content[start_pos + marker_start.len()..start_pos + end_pos];
-                Self::parse_shell_config_block(block_content).unwrap_or_default()
-            } else {
-                HashMap::new()
-            }
-        } else {
-            HashMap::new()
-        };
-
-        // 移除配置块
-        let content_without_block = Self::remove_config_block(content, marker_start, marker_end);
-
-        Ok((env_in_block, content_without_block))
-    }
-
-    /// 移除配置块
+    /// # 返回
     ///
-    /// 从内容中移除配置块（包括标记行）。
-    fn remove_config_block(content: &str, marker_start: &str, marker_end: &str) -> String {
-        if let Some(start_pos) = content.find(marker_start) {
-            if let Some(end_pos) = content[start_pos..].find(marker_end) {
-                let end_pos = start_pos + end_pos + marker_end.len();
-                let before = content[..start_pos].trim_end();
-                let after = content[end_pos..].trim_start();
-
-                if before.is_empty() {
-                    after.to_string()
-                } else if after.is_empty() {
-                    format!("{}\n", before)
-                } else {
-                    format!("{}\n{}", before, after)
-                }
-            } else {
-                content.to_string()
-            }
-        } else {
-            content.to_string()
-        }
+    /// 返回别名映射表（别名名称 -> 命令，按名称排序）。配置文件不存在或没有别名配置块时返回空映射表。
+    ///
+    /// # 错误
+    ///
+    /// 如果无法检测 shell 类型或读取配置文件失败，返回相应的错误信息。
+    pub fn load_aliases() -> Result<BTreeMap<String, String>> {
+        let shell = Detect::shell()?;
+        let content = Self::read_config_file(&Paths::config_file(&shell)?)?;
+        Ok(Self::aliases_in_content(&content, &shell))
     }
 
-    /// 构建配置块
+    /// 读取当前 shell 配置文件中托管别名配置块外定义的别名名称
     ///
-    /// 根据环境变量构建配置块内容。
-    fn build_config_block(env_vars: &HashMap<String, String>) -> String {
-        let marker_start = "# Workflow CLI Configuration - Start";
-        let marker_end = "# Workflow CLI Configuration - End";
-
-        let mut config_block = String::new();
-        config_block.push_str(marker_start);
-        config_block.push_str("\n# Generated by Workflow CLI - DO NOT edit manually\n");
-        config_block.push_str(
-            "# These environment variables will be loaded when you start a new shell\n\n",
-        );
-
-        // 按字母顺序排序键
-        let mut keys: Vec<&String> = env_vars.keys().collect();
-        keys.sort();
-
-        for key in keys {
-            let value = &env_vars[key];
-            // 转义特殊字符
-            let escaped_value = value
-                .replace('\\', "\\\\")
-                .replace('"', "\\\"")
-                .replace('$', "\\$")
-                .replace('`', "\\`");
-            config_block.push_str("export ");
-            config_block.push_str(key);
-            config_block.push_str("=\"");
-            config_block.push_str(&escaped_value);
-            config_block.push_str("\"\n");
-        }
-
-        config_block.push('\n');
-        config_block.push_str(marker_end);
-        config_block.push('\n');
-
-        config_block
+    /// 用于在添加托管别名前检查是否与用户自己定义的别名冲突（见 `unmanaged_alias_names_in_content`）。
+    ///
+    /// # 错误
+    ///
+    /// 如果无法检测 shell 类型或读取配置文件失败，返回相应的错误信息。
+    pub fn load_unmanaged_alias_names() -> Result<BTreeSet<String>> {
+        let shell = Detect::shell()?;
+        let content = Self::read_config_file(&Paths::config_file(&shell)?)?;
+        Ok(Self::unmanaged_alias_names_in_content(&content))
     }
 
-    // === 工具方法 ===
-
-    /// 获取 shell 配置文件路径
+    /// 添加或更新 shell 别名
     ///
-    /// 使用 `Detect::shell()` 自动检测 shell 类型，并通过 `Paths::config_file()` 获取对应的配置文件路径。
+    /// 别名写入当前 shell 配置文件的托管别名配置块中（见 `upsert_alias_in_content`）。
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 别名名称
+    /// * `command` - 别名展开后的命令（如 `workflow pr create`）
     ///
     /// # 返回
     ///
-    /// 返回 shell 配置文件的路径：
-    /// - zsh → `~/.zshrc`
+    /// 修改了配置文件时返回 `ShellConfigEdit`（包含备份路径）；别名已存在且命令相同时返回 `None`。
+    ///
+    /// # 错误
+    ///
+    /// 如果无法检测 shell 类型、读取或写入配置文件失败，返回相应的错误信息。
+    pub fn set_alias(name: &str, command: &str) -> Result<Option<ShellConfigEdit>> {
+        let aliases = BTreeMap::from([(name.to_string(), command.to_string())]);
+        Self::set_aliases(&aliases)
+    }
+
+    /// 批量添加或更新 shell 别名
+    ///
+    /// 所有别名在一次修改中写入托管别名配置块（只创建一个备份）。
+    ///
+    /// # 参数
+    ///
+    /// * `aliases` - 别名映射表（别名名称 -> 命令）
+    ///
+    /// # 返回
+    ///
+    /// 修改了配置文件时返回 `ShellConfigEdit`（包含备份路径）；所有别名都已存在且命令相同时返回 `None`。
+    ///
+    /// # 错误
+    ///
+    /// 如果无法检测 shell 类型、读取或写入配置文件失败，返回相应的错误信息。
+    pub fn set_aliases(aliases: &BTreeMap<String, String>) -> Result<Option<ShellConfigEdit>> {
+        let shell = Detect::shell()?;
+        let config_path = Paths::config_file(&shell)?;
+        let content = Self::read_config_file(&config_path)?;
+
+        Self::upsert_aliases_in_content(&content, &shell, aliases)
+            .map(|new_content| Self::write_config_file(&config_path, &new_content))
+            .transpose()
+    }
+
+    /// 移除 shell 别名
+    ///
+    /// 从当前 shell 配置文件的托管别名配置块中移除别名，不再包含别名的配置块会被整体移除。
+    ///
+    /// # 参数
+    ///
+    /// * `name` - 要移除的别名名称
+    ///
+    /// # 返回
+    ///
+    /// 移除了别名时返回 `ShellConfigEdit`（包含备份路径）；别名不存在时返回 `None`。
+    ///
+    /// # 错误
+    ///
+    /// 如果无法检测 shell 类型、读取或写入配置文件失败，返回相应的错误信息。
+    pub fn remove_alias(name: &str) -> Result<Option<ShellConfigEdit>> {
+        let shell = Detect::shell()?;
+        let config_path = Paths::config_file(&shell)?;
+        let content = Self::read_config_file(&config_path)?;
+
+        Self::remove_alias_from_content(&content, &shell, name)
+            .map(|new_content| Self::write_config_file(&config_path, &new_content))
+            .transpose()
+    }
+
+    /// 移除所有托管的 shell 别名
+    ///
+    /// 整体移除托管别名配置块，配置块外用户自己定义的别名不受影响。
+    ///
+    /// # 返回
+    ///
+    /// 移除了配置块时返回 `ShellConfigEdit`（包含备份路径）；没有配置块时返回 `None`。
+    ///
+    /// # 错误
+    ///
+    /// 如果无法检测 shell 类型、读取或写入配置文件失败，返回相应的错误信息。
+    pub fn remove_all_aliases() -> Result<Option<ShellConfigEdit>> {
+        let shell = Detect::shell()?;
+        let config_path = Paths::config_file(&shell)?;
+        let content = Self::read_config_file(&config_path)?;
+
+        Self::remove_all_aliases_from_content(&content, &shell)
+            .map(|new_content| Self::write_config_file(&config_path, &new_content))
+            .transpose()
+    }
+
+    /// 读取配置内容中的托管别名（指定 shell 类型）
+    ///
+    /// 只读取托管别名配置块中的别名，配置块外用户自己定义的别名不受管理。
+    ///
+    /// # 参数
+    ///
+    /// * `content` - shell 配置文件内容
+    /// * `shell` - Shell 类型
+    ///
+    /// # 返回
+    ///
+    /// 返回别名映射表（别名名称 -> 命令，按名称排序）。
+    pub fn aliases_in_content(content: &str, shell: &Shell) -> BTreeMap<String, String> {
+        let lines: Vec<&str> = content.lines().collect();
+        Self::alias_blocks(&lines)
+            .iter()
+            .flat_map(|&(start, end)| &lines[start + 1..end])
+            .filter_map(|line| Self::parse_alias_line(shell, line))
+            .collect()
+    }
+
+    /// 在配置内容中添加或更新别名（指定 shell 类型）
+    ///
+    /// 所有托管别名按名称排序写入同一个别名配置块：
+    /// - 已有配置块时，第一个配置块原地替换为新内容，重复的配置块被移除
+    /// - 没有配置块时追加到内容末尾
+    ///
+    /// 对同一内容重复调用的结果相同（幂等）。
+    ///
+    /// # 参数
+    ///
+    /// * `content` - shell 配置文件内容
+    /// * `shell` - Shell 类型（决定别名语法，见 `alias_line`）
+    /// * `name` - 别名名称
+    /// * `command` - 别名展开后的命令
+    ///
+    /// # 返回
+    ///
+    /// 内容有变化时返回新内容；别名已存在且命令相同时返回 `None`。
+    pub fn upsert_alias_in_content(
+        content: &str,
+        shell: &Shell,
+        name: &str,
+        command: &str,
+    ) -> Option<String> {
+        let aliases = BTreeMap::from([(name.to_string(), command.to_string())]);
+        Self::upsert_aliases_in_content(content, shell, &aliases)
+    }
+
+    /// 在配置内容中批量添加或更新别名（指定 shell 类型）
+    ///
+    /// 见 `upsert_alias_in_content`。
+    ///
+    /// # 返回
+    ///
+    /// 内容有变化时返回新内容；所有别名都已存在且命令相同时返回 `None`。
+    pub fn upsert_aliases_in_content(
+        content: &str,
+        shell: &Shell,
+        aliases: &BTreeMap<String, String>,
+    ) -> Option<String> {
+        let mut merged = Self::aliases_in_content(content, shell);
+        merged.extend(aliases.iter().map(|(name, command)| (name.clone(), command.clone())));
+        Self::write_alias_block(content, shell, &merged)
+    }
+
+    /// 从配置内容中移除别名（指定 shell 类型）
+    ///
+    /// # 参数
+    ///
+    /// * `content` - shell 配置文件内容
+    /// * `shell` - Shell 类型
+    /// * `name` - 要移除的别名名称
+    ///
+    /// # 返回
+    ///
+    /// 移除了别名时返回新内容（不再包含别名的配置块会被整体移除）；别名不存在时返回 `None`。
+    pub fn remove_alias_from_content(content: &str, shell: &Shell, name: &str) -> Option<String> {
+        let mut aliases = Self::aliases_in_content(content, shell);
+        aliases.remove(name)?;
+        Self::write_alias_block(content, shell, &aliases)
+    }
+
+    /// 从配置内容中移除所有托管别名配置块（指定 shell 类型）
+    ///
+    /// # 返回
+    ///
+    /// 移除了配置块时返回新内容；没有配置块时返回 `None`。
+    pub fn remove_all_aliases_from_content(content: &str, shell: &Shell) -> Option<String> {
+        Self::write_alias_block(content, shell, &BTreeMap::new())
+    }
+
+    /// 读取配置内容中托管别名配置块外定义的别名名称
+    ///
+    /// 识别各 shell 常见的定义方式：`alias NAME=...`、`alias NAME ...`（fish、nushell）、
+    /// `abbr --add NAME ...`（fish）、`function NAME ...`（fish、powershell）和 `fn NAME ...`（elvish），
+    /// 忽略注释行。
+    ///
+    /// # 参数
+    ///
+    /// * `content` - shell 配置文件内容
+    ///
+    /// # 返回
+    ///
+    /// 返回别名名称集合（按名称排序）。
+    pub fn unmanaged_alias_names_in_content(content: &str) -> BTreeSet<String> {
+        let lines: Vec<&str> = content.lines().collect();
+        let blocks = Self::alias_blocks(&lines);
+        lines
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| !blocks.iter().any(|&(start, end)| (start..=end).contains(i)))
+            .filter_map(|(_, line)| Self::defined_alias_name(line))
+            .collect()
+    }
+
+    /// 解析一行中定义的别名（或函数）名称，不是定义语句时返回 `None`
+    fn defined_alias_name(line: &str) -> Option<String> {
+        let mut words = line.split_whitespace();
+        let name = match words.next()? {
+            "alias" => words.find(|word| !word.starts_with('-'))?.split('=').next()?,
+            "abbr" => words.find(|word| !word.starts_with('-'))?,
+            "function" | "fn" => words.next()?.split(['(', '{']).next()?,
+            _ => return None,
+        };
+        (!name.is_empty()).then(|| name.to_string())
+    }
+
+    /// 使用新的别名列表重写别名配置块
+    ///
+    /// 别名列表为空时移除所有别名配置块；内容没有变化时返回 `None`。
+    fn write_alias_block(
+        content: &str,
+        shell: &Shell,
+        aliases: &BTreeMap<String, String>,
+    ) -> Option<String> {
+        let lines: Vec<&str> = content.lines().collect();
+        let blocks = Self::alias_blocks(&lines);
+
+        let alias_lines: Vec<String> = aliases
+            .iter()
+            .map(|(name, command)| Self::alias_line(shell, name, command))
+            .collect();
+        let mut block = Vec::new();
+        if !alias_lines.is_empty() {
+            block.push(ALIAS_BLOCK_START);
+            block.extend(alias_lines.iter().map(String::as_str));
+            block.push(ALIAS_BLOCK_END);
+        }
+
+        let new_lines = match blocks.first() {
+            None if block.is_empty() => return None,
+            None => {
+                let mut new_lines = lines;
+                while new_lines.last().is_some_and(|line| line.trim().is_empty()) {
+                    new_lines.pop();
+                }
+                if !new_lines.is_empty() {
+                    new_lines.push("");
+                }
+                new_lines.extend(block);
+                new_lines
+            }
+            Some(&(first, _)) => {
+                // 第一个配置块原地替换，其余重复的配置块移除
+                Self::replace_line_ranges(&lines, &blocks, |start| {
+                    if start == first {
+                        block.clone()
+                    } else {
+                        Vec::new()
+                    }
+                })
+            }
+        };
+
+        let new_content = Self::join_lines(&new_lines);
+        (new_content != content).then_some(new_content)
+    }
+
+    /// 查找托管别名配置块
+    ///
+    /// 返回每个配置块的（开始标记行号，结束标记行号）；没有结束标记的开始标记不视为配置块。
+    fn alias_blocks(lines: &[&str]) -> Vec<(usize, usize)> {
+        let mut blocks = Vec::new();
+        let mut i = 0;
+
+        while i < lines.len() {
+            let end = (lines[i].trim() == ALIAS_BLOCK_START)
+                .then(|| lines[i + 1..].iter().position(|l| l.trim() == ALIAS_BLOCK_END))
+                .flatten()
+                .map(|pos| i + 1 + pos);
+
+            match end {
+                Some(end) => {
+                    blocks.push((i, end));
+                    i = end + 1;
+                }
+                None => i += 1,
+            }
+        }
+
+        blocks
+    }
+
+    /// 生成定义别名的语句
+    ///
+    /// - zsh、bash：`alias NAME='COMMAND'`（单引号转义为 `'\''`）
+    /// - fish：`abbr --add NAME 'COMMAND'`（输入时展开为完整命令，引号转义同上）
+    /// - nushell：`alias NAME = COMMAND`
+    /// - powershell：`function NAME { COMMAND @args }`（`Set-Alias` 不支持带参数的命令）
+    /// - elvish：`fn NAME {|@args| COMMAND $@args }`
+    fn alias_line(shell: &Shell, name: &str, command: &str) -> String {
+        match shell {
+            Shell::Fish => format!("abbr --add {} '{}'", name, command.replace('\'', "'\\''")),
+            Shell::Nushell => format!("alias {} = {}", name, command),
+            Shell::PowerShell => format!("function {} {{ {} @args }}", name, command),
+            Shell::Elvish => format!("fn {} {{|@args| {} $@args }}", name, command),
+            _ => format!("alias {}='{}'", name, command.replace('\'', "'\\''")),
+        }
+    }
+
+    /// 解析 `alias_line` 生成的别名语句，不是别名语句时返回 `None`
+    ///
+    /// fish 同时识别旧版本写入的 `alias NAME='COMMAND'`，更新配置块时会改写为 `abbr`。
+    fn parse_alias_line(shell: &Shell, line: &str) -> Option<(String, String)> {
+        let line = line.trim();
+        let (name, command) = match shell {
+            Shell::Fish if line.starts_with("abbr ") => {
+                let (name, quoted) = line.strip_prefix("abbr --add ")?.split_once(' ')?;
+                let command = quoted.strip_prefix('\'')?.strip_suffix('\'')?;
+                return Some((name.trim().to_string(), command.replace("'\\''", "'")));
+            }
+            Shell::Nushell => line.strip_prefix("alias ")?.split_once(" = ")?,
+            Shell::PowerShell => {
+                let (name, body) = line.strip_prefix("function ")?.split_once(" { ")?;
+                (name, body.strip_suffix(" @args }")?)
+            }
+            Shell::Elvish => {
+                let (name, body) = line.strip_prefix("fn ")?.split_once(" {|@args| ")?;
+                (name, body.strip_suffix(" $@args }")?)
+            }
+            _ => {
+                let (name, quoted) = line.strip_prefix("alias ")?.split_once('=')?;
+                let command = quoted.strip_prefix('\'')?.strip_suffix('\'')?;
+                return Some((name.trim().to_string(), command.replace("'\\''", "'")));
+            }
+        };
+        Some((name.trim().to_string(), command.trim().to_string()))
+    }
+
+    // === 配置块管理 ===
+
+    /// 解析配置块
+    ///
+    /// 从配置内容的所有配置块中读取环境变量（后出现的配置块覆盖先出现的）。
+    fn parse_config_block(content: &str) -> HashMap<String, String> {
+        Self::env_vars_in_segments(&Self::split_config_blocks(content))
+    }
+
+    /// 读取配置块中的环境变量
+    fn env_vars_in_segments(segments: &[ConfigSegment]) -> HashMap<String, String> {
+        segments
+            .iter()
+            .filter_map(|segment| match segment {
+                ConfigSegment::Block(block) => Some(Self::parse_shell_config_block(block)),
+                ConfigSegment::Line(_) => None,
+            })
+            .flatten()
+            .collect()
+    }
+
+    /// 按行拆分配置内容
+    ///
+    /// 将配置块（包括旧版本标记的配置块）拆分为 `ConfigSegment::Block`，其余行为 `ConfigSegment::Line`。
+    /// 标记行比较时忽略首尾空白；只有开始标记、没有对应结束标记时按普通行处理。
+    fn split_config_blocks(content: &str) -> Vec<ConfigSegment<'_>> {
+        let lines: Vec<&str> = content.lines().collect();
+        let mut segments = Vec::new();
+        let mut i = 0;
+
+        while i < lines.len() {
+            let line = lines[i];
+            let block_end = BLOCK_MARKERS.iter().find(|(start, _)| line.trim() == *start).and_then(
+                |(_, end)| {
+                    lines[i + 1..].iter().position(|l| l.trim() == *end).map(|pos| i + 1 + pos)
+                },
+            );
+
+            match block_end {
+                Some(end) => {
+                    segments.push(ConfigSegment::Block(lines[i + 1..end].to_vec()));
+                    i = end + 1;
+                }
+                None => {
+                    segments.push(ConfigSegment::Line(line));
+                    i += 1;
+                }
+            }
+        }
+
+        segments
+    }
+
+    /// 将拆分后的配置内容重新组合为文本
+    ///
+    /// `keep_block` 返回 `false` 的配置块会被移除（连同其前面的空行），保留的配置块使用新版本标记输出。
+    fn render_segments(segments: &[ConfigSegment], keep_block: impl Fn(&[&str]) -> bool) -> String {
+        let mut lines: Vec<&str> = Vec::new();
+        let mut skipped_block = false;
+
+        for segment in segments {
+            match segment {
+                ConfigSegment::Line(line) => {
+                    // 文件开头的配置块被移除时，跳过其后的空行
+                    if skipped_block && lines.is_empty() && line.trim().is_empty() {
+                        continue;
+                    }
+                    lines.push(*line);
+                }
+                ConfigSegment::Block(block) if keep_block(block) => {
+                    lines.push(MANAGED_BLOCK_START);
+                    lines.extend(block.iter().copied());
+                    lines.push(MANAGED_BLOCK_END);
+                }
+                ConfigSegment::Block(_) => {
+                    while lines.last().is_some_and(|line| line.trim().is_empty()) {
+                        lines.pop();
+                    }
+                    skipped_block = true;
+                }
+            }
+        }
+
+        Self::join_lines(&lines)
+    }
+
+    /// 配置块中是否包含 export 语句
+    fn has_exports(block: &[&str]) -> bool {
+        block.iter().any(|line| Self::export_key(line).is_some())
+    }
+
+    /// 拆分后内容的总行数（配置块计入标记行）
+    fn segment_line_count(segments: &[ConfigSegment]) -> usize {
+        segments
+            .iter()
+            .map(|segment| match segment {
+                ConfigSegment::Line(_) => 1,
+                ConfigSegment::Block(block) => block.len() + 2,
+            })
+            .sum()
+    }
+
+    /// 按行组合内容，去掉末尾多余空行并确保以换行符结尾（内容为空时返回空字符串）
+    fn join_lines(lines: &[&str]) -> String {
+        let end = lines.iter().rposition(|line| !line.trim().is_empty()).map_or(0, |pos| pos + 1);
+        if end == 0 {
+            return String::new();
+        }
+        format!("{}\n", lines[..end].join("\n"))
+    }
+
+    /// 解析 export 语句的变量名
+    ///
+    /// 忽略多余空白，匹配 `export KEY=VALUE`、`export  KEY = VALUE` 等形式，
+    /// 以及 nushell 的 `$env.KEY = VALUE`；不是 export 语句时返回 `None`。
+    fn export_key(line: &str) -> Option<&str> {
+        let line = line.trim();
+        let rest = match line.strip_prefix(NUSHELL_ENV_PREFIX) {
+            Some(rest) => rest,
+            None => {
+                let rest = line.strip_prefix("export")?;
+                if !rest.starts_with(char::is_whitespace) {
+                    return None;
+                }
+                rest
+            }
+        };
+        let (key, _) = rest.split_once('=')?;
+        let key = key.trim();
+        (!key.is_empty() && !key.contains(char::is_whitespace)).then_some(key)
+    }
+
+    /// 构建配置块
+    ///
+    /// 根据环境变量构建配置块内容（语法见 `env_var_line`）。
+    fn build_config_block(shell: &Shell, env_vars: &HashMap<String, String>) -> String {
+        let mut config_block = String::new();
+        config_block.push_str(MANAGED_BLOCK_START);
+        config_block.push_str("\n# Generated by Workflow CLI - DO NOT edit manually\n");
+        config_block.push_str(
+            "# These environment variables will be loaded when you start a new shell\n\n",
+        );
+
+        // 按字母顺序排序键
+        let mut keys: Vec<&String> = env_vars.keys().collect();
+        keys.sort();
+
+        for key in keys {
+            config_block.push_str(&Self::env_var_line(shell, key, &env_vars[key]));
+            config_block.push('\n');
+        }
+
+        config_block.push('\n');
+        config_block.push_str(MANAGED_BLOCK_END);
+        config_block.push('\n');
+
+        config_block
+    }
+
+    /// 生成设置环境变量的语句
+    ///
+    /// - nushell：`$env.KEY = "VALUE"`（双引号字符串中只需转义 `\` 和 `"`）
+    /// - 其他 shell：`export KEY="VALUE"`（同时转义 `$` 和反引号，避免被 shell 展开）
+    fn env_var_line(shell: &Shell, key: &str, value: &str) -> String {
+        let escaped_value = value.replace('\\', "\\\\").replace('"', "\\\"");
+        match shell {
+            Shell::Nushell => format!("{}{} = \"{}\"", NUSHELL_ENV_PREFIX, key, escaped_value),
+            _ => {
+                let escaped_value = escaped_value.replace('$', "\\$").replace('`', "\\`");
+                format!("export {}=\"{}\"", key, escaped_value)
+            }
+        }
+    }
+
+    // === 工具方法 ===
+
+    /// 获取 shell 配置文件路径
+    ///
+    /// 使用 `Detect::shell()` 自动检测 shell 类型，并通过 `Paths::config_file()` 获取对应的配置文件路径。
+    ///
+    /// # 返回
+    ///
+    /// 返回 shell 配置文件的路径：
+    /// - zsh → `~/.zshrc`
     /// - bash → `~/.bash_profile`（如果不存在则使用 `~/.bashrc`）
     /// - fish → `~/.config/fish/config.fish`
     /// - powershell → `~/.config/powershell/Microsoft.PowerShell_profile.ps1`
     /// - elvish → `~/.elvish/rc.elv`
+    /// - nushell → `~/.config/nushell/config.nu`（macOS 为 `~/Library/Application Support/nushell/config.nu`）
     ///
     /// # 错误
     ///
@@ -537,7 +1248,7 @@ impl ShellConfigManager {
     }
 
     /// 读取配置文件内容
-    fn read_config_file(path: &std::path::Path) -> Result<String> {
+    fn read_config_file(path: &Path) -> Result<String> {
         if path.exists() {
             FileReader::new(path).to_string().wrap_err("Failed to read shell config file")
         } else {
@@ -545,59 +1256,176 @@ impl ShellConfigManager {
         }
     }
 
-    /// 写入配置文件内容
-    fn write_config_file(path: &std::path::Path, content: &str) -> Result<()> {
-        FileWriter::new(path)
-            .write_str(content)
-            .wrap_err("Failed to write to shell config file")?;
-        Ok(())
-    }
+    /// 写入配置文件内容（修改前自动备份）
+    ///
+    /// 1. 文件已存在时，在同一目录下创建带时间戳的备份（`<文件名>.workflow-backup-<时间戳>`），
+    ///    并只保留最近的 `MAX_BACKUPS` 个备份（本次运行的第一个备份总是保留，
+    ///    一次命令多次修改同一文件时不会删除修改前的原始内容）
+    /// 2. 新内容先写入同一目录下的临时文件（保留原文件权限），再重命名替换原文件
+    ///
+    /// 任何一步失败时原文件都保持不变。配置文件是符号链接（如 dotfiles 仓库）时，
+    /// 备份和替换的是链接指向的文件，链接本身保持不变。
+    ///
+    /// # 参数
+    ///
+    /// * `path` - 配置文件路径
+    /// * `content` - 新的文件内容
+    ///
+    /// # 返回
+    ///
+    /// 返回 `ShellConfigEdit`，包含配置文件路径和备份路径（文件原本不存在时没有备份）。
+    ///
+    /// # 错误
+    ///
+    /// 如果创建备份或写入文件失败，返回相应的错误信息。
+    pub fn write_config_file(path: &Path, content: &str) -> Result<ShellConfigEdit> {
+        let target = if path.is_symlink() {
+            fs::canonicalize(path)
+                .wrap_err_with(|| format!("Failed to resolve symlink: {}", path.display()))?
+        } else {
+            path.to_path_buf()
+        };
 
-    /// 加载现有配置
-    fn load_existing_config(path: &std::path::Path) -> Result<ExistingConfig> {
-        let content = Self::read_config_file(path)?;
-        let (env_in_block, content_without_block) = Self::parse_config_block(&content)?;
+        let backup_path = if target.exists() {
+            let backup_path = Self::backup_config_file(&target)?;
+            let original_backup = run_backups()
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .entry(target.clone())
+                .or_insert_with(|| backup_path.clone())
+                .clone();
+            Self::prune_backups(&target, &original_backup);
+            Some(backup_path)
+        } else {
+            None
+        };
+
+        let file_name = target
+            .file_name()
+            .ok_or_else(|| eyre!("Invalid shell config path: {}", target.display()))?;
+        let temp_path =
+            target.with_file_name(format!(".{}.workflow-tmp", file_name.to_string_lossy()));
+
+        let result = (|| -> Result<()> {
+            FileWriter::new(&temp_path).write_str_with_dir(content)?;
+            if let Ok(metadata) = fs::metadata(&target) {
+                fs::set_permissions(&temp_path, metadata.permissions())
+                    .wrap_err("Failed to preserve shell config file permissions")?;
+            }
+            fs::rename(&temp_path, &target).wrap_err_with(|| {
+                format!("Failed to replace shell config file: {}", target.display())
+            })
+        })();
 
-        Ok(ExistingConfig {
-            env_in_block,
-            content_without_block,
+        if let Err(e) = result {
+            let _ = fs::remove_file(&temp_path);
+            return Err(e.wrap_err("Failed to write to shell config file"));
+        }
+
+        Ok(ShellConfigEdit {
+            config_path: path.to_path_buf(),
+            backup_path,
         })
     }
 
+    /// 备份配置文件
+    ///
+    /// 备份文件位于同一目录下，文件名为 `<文件名>.workflow-backup-<时间戳>`；
+    /// 同一秒内多次备份时追加序号，避免覆盖更早的备份。
+    fn backup_config_file(path: &Path) -> Result<PathBuf> {
+        let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+        let base = format!("{}{}{}", path.display(), BACKUP_MARKER, timestamp);
+
+        let backup_path = std::iter::once(PathBuf::from(&base))
+            .chain((1..).map(|n| PathBuf::from(format!("{}-{}", base, n))))
+            .find(|candidate| !candidate.exists())
+            .expect("backup candidates are unbounded");
+
+        fs::copy(path, &backup_path).wrap_err_with(|| {
+            format!(
+                "Failed to backup shell config file: {} -> {}",
+                path.display(),
+                backup_path.display()
+            )
+        })?;
+
+        Ok(backup_path)
+    }
+
+    /// 列出配置文件的所有备份（按创建时间从旧到新排序）
+    ///
+    /// # 参数
+    ///
+    /// * `path` - 配置文件路径
+    ///
+    /// # 返回
+    ///
+    /// 返回备份文件路径列表（没有备份时返回空列表）。
+    pub fn list_backups(path: &Path) -> Vec<PathBuf> {
+        let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+            return Vec::new();
+        };
+        let prefix = format!("{}{}", file_name.to_string_lossy(), BACKUP_MARKER);
+
+        let mut backups: Vec<PathBuf> = fs::read_dir(dir)
+            .map(|entries| {
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter(|p| {
+                        p.file_name()
+                            .is_some_and(|name| name.to_string_lossy().starts_with(&prefix))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        // 时间戳格式固定，按文件名排序即按时间排序
+        backups.sort();
+        backups
+    }
+
+    /// 删除超出数量限制的旧备份（失败时忽略），`keep` 指定的备份总是保留
+    fn prune_backups(path: &Path, keep: &Path) {
+        let backups = Self::list_backups(path);
+        let excess = backups.len().saturating_sub(MAX_BACKUPS);
+        for old in backups.iter().filter(|backup| backup.as_path() != keep).take(excess) {
+            let _ = fs::remove_file(old);
+        }
+    }
+
     /// 解析 shell 配置块中的 export KEY="VALUE" 格式
-    fn parse_shell_config_block(block_content: &str) -> Result<HashMap<String, String>> {
+    fn parse_shell_config_block(block: &[&str]) -> HashMap<String, String> {
         let mut env_vars = HashMap::new();
 
-        for line in block_content.lines() {
+        for line in block {
             let line = line.trim();
             if line.is_empty() || line.starts_with('#') {
                 continue;
             }
             // 匹配 export KEY="VALUE" 或 export KEY=VALUE
-            if let Some(rest) = line.strip_prefix("export ") {
-                if let Some(equal_pos) = rest.find('=') {
-                    let key = rest[..equal_pos].trim();
-                    let mut value = rest[equal_pos + 1..].trim();
-                    // 移除引号（如果有）
-                    if (value.starts_with('"') && value.ends_with('"'))
-                        || (value.starts_with('\'') && value.ends_with('\''))
-                    {
-                        value = &value[1..value.len() - 1];
-                    }
-                    // 反转义
-                    let unescaped_value = value
-                        .replace("\\\\", "\\")
-                        .replace("\\\"", "\"")
-                        .replace("\\$", "$")
-                        .replace("\\`", "`");
-                    if !key.is_empty() {
-                        env_vars.insert(key.to_string(), unescaped_value);
-                    }
-                }
+            let (Some(key), Some((_, value))) = (Self::export_key(line), line.split_once('='))
+            else {
+                continue;
+            };
+            let mut value = value.trim();
+            // 移除引号（如果有）
+            if value.len() >= 2
+                && ((value.starts_with('"') && value.ends_with('"'))
+                    || (value.starts_with('\'') && value.ends_with('\'')))
+            {
+                value = &value[1..value.len() - 1];
             }
+            // 反转义
+            let unescaped_value = value
+                .replace("\\\\", "\\")
+                .replace("\\\"", "\"")
+                .replace("\\$", "$")
+                .replace("\\`", "`");
+            env_vars.insert(key.to_string(), unescaped_value);
         }
 
-        Ok(env_vars)
+        env_vars
     }
 
     /// 合并环境变量
@@ -610,30 +1438,6 @@ impl ShellConfigManager {
         merged
     }
 
-    /// 构建配置内容
-    fn build_config_content(
-        content_without_block: &str,
-        env_vars: &HashMap<String, String>,
-    ) -> Result<String> {
-        // 如果没有环境变量，不创建配置块，直接返回原内容
-        if env_vars.is_empty() {
-            return Ok(content_without_block.to_string());
-        }
-
-        let config_block = Self::build_config_block(env_vars);
-
-        // 合并内容
-        let final_content = if content_without_block.is_empty() {
-            config_block
-        } else if content_without_block.ends_with('\n') {
-            format!("{}{}", content_without_block, config_block)
-        } else {
-            format!("{}\n{}", content_without_block, config_block)
-        };
-
-        Ok(final_content)
-    }
-
     /// 检查内容中是否包含 source 语句
     fn has_source_in_content(content: &str, source_path: &str) -> Result<bool> {
         // 检查相对路径模式
@@ -655,127 +1459,117 @@ impl ShellConfigManager {
 
     /// 检查内容中是否包含 source 语句（指定 shell 类型）
     ///
-    /// 支持不同 shell 的 source 语句格式（PowerShell 使用 `.`，其他使用 `source`）。
+    /// 支持不同 shell 的 source 语句格式（PowerShell 使用 `.`，其他使用 `source`），
+    /// 比较前将每行的连续空白压缩为一个空格，因此 `source   path` 与 `source path` 视为等价。
     fn has_source_in_content_for_shell(
         content: &str,
         shell: &Shell,
         source_path: &str,
     ) -> Result<bool> {
-        let source_keyword = Self::get_source_keyword(shell);
-
-        // 检查 source 语句（支持不同关键字）
-        let patterns = vec![
-            format!("{} {}", source_keyword, source_path),
-            format!("{}  {}", source_keyword, source_path), // 支持多个空格
-        ];
+        let patterns = Self::source_patterns(shell, source_path)?;
+        Ok(content.lines().any(|line| Self::is_source_line(line, &patterns)))
+    }
 
-        for pattern in &patterns {
-            if content.contains(pattern) {
-                return Ok(true);
-            }
-        }
+    /// 构建 source 语句的匹配模式（`<关键字> <路径>`，`$HOME` 路径同时匹配展开后的绝对路径）
+    fn source_patterns(shell: &Shell, source_path: &str) -> Result<Vec<String>> {
+        let source_keyword = Self::get_source_keyword(shell);
 
+        let mut patterns = vec![format!("{} {}", source_keyword, source_path)];
         // 检查绝对路径（如果 source_path 是相对路径）
         if source_path.contains("$HOME") {
             let home = Paths::home_dir()?;
             let abs_path = source_path.replace("$HOME", &home.to_string_lossy());
-            let abs_patterns = vec![
-                format!("{} {}", source_keyword, abs_path),
-                format!("{}  {}", source_keyword, abs_path),
-            ];
-
-            for pattern in &abs_patterns {
-                if content.contains(pattern) {
-                    return Ok(true);
+            patterns.push(format!("{} {}", source_keyword, abs_path));
+        }
+        Ok(patterns)
+    }
+
+    /// 判断一行是否为匹配的 source 语句（比较前将连续空白压缩为一个空格）
+    fn is_source_line(line: &str, patterns: &[String]) -> bool {
+        let normalized = line.split_whitespace().collect::<Vec<_>>().join(" ");
+        patterns.iter().any(|pattern| normalized.contains(pattern.as_str()))
+    }
+
+    /// 查找包含匹配 source 语句的托管 source 配置块
+    ///
+    /// 返回每个配置块的（开始标记行号，结束标记行号）；没有结束标记的开始标记不视为配置块。
+    fn source_blocks(lines: &[&str], patterns: &[String]) -> Vec<(usize, usize)> {
+        let mut blocks = Vec::new();
+        let mut i = 0;
+
+        while i < lines.len() {
+            let end = (lines[i].trim() == SOURCE_BLOCK_START)
+                .then(|| lines[i + 1..].iter().position(|l| l.trim() == SOURCE_BLOCK_END))
+                .flatten()
+                .map(|pos| i + 1 + pos);
+
+            match end {
+                Some(end) => {
+                    if lines[i + 1..end].iter().any(|line| Self::is_source_line(line, patterns)) {
+                        blocks.push((i, end));
+                    }
+                    i = end + 1;
                 }
+                None => i += 1,
             }
         }
 
-        Ok(false)
+        blocks
     }
 
-    /// 从内容中移除 source 语句
+    /// 替换指定的行范围（包含两端）
     ///
-    /// 移除 source 语句及其相关的注释块（如果存在）。
-    fn remove_source_from_content(content: &str, source_path: &str) -> Result<String> {
-        let home = Paths::home_dir()?;
-        let abs_path = source_path.replace("$HOME", &home.to_string_lossy());
-
-        let mut new_content = String::new();
-        let lines: Vec<&str> = content.lines().collect();
+    /// `replacement` 根据范围的开始行号返回替换内容；返回空内容时该范围被移除（连同其前面的空行）。
+    fn replace_line_ranges<'a>(
+        lines: &[&'a str],
+        ranges: &[(usize, usize)],
+        replacement: impl Fn(usize) -> Vec<&'a str>,
+    ) -> Vec<&'a str> {
+        let mut new_lines: Vec<&str> = Vec::new();
+        let mut removed = false;
         let mut i = 0;
 
         while i < lines.len() {
-            let line = lines[i];
-
-            // 检查是否是配置块开始（包含 Workflow CLI 的注释）
-            if line.contains("# Workflow CLI")
-                && (line.contains("completions") || line.contains("Configuration"))
-            {
-                // 跳过整个配置块
-                i += 1; // 跳过注释行
-                        // 跳过 source 行
-                while i < lines.len() {
-                    let current_line = lines[i];
-                    if current_line.contains(source_path) || current_line.contains(&abs_path) {
-                        i += 1; // 跳过 source 行
-                                // 跳过后续的空行
-                        while i < lines.len() && lines[i].trim().is_empty() {
-                            i += 1;
+            match ranges.iter().find(|(start, _)| *start == i) {
+                Some(&(start, end)) => {
+                    let replaced = replacement(start);
+                    if replaced.is_empty() {
+                        while new_lines.last().is_some_and(|line| line.trim().is_empty()) {
+                            new_lines.pop();
                         }
-                        break;
+                        removed = true;
                     }
-                    // 如果遇到空行，停止
-                    if current_line.trim().is_empty() {
-                        i += 1;
-                        break;
-                    }
-                    i += 1;
+                    new_lines.extend(replaced);
+                    i = end + 1;
                 }
-                continue;
-            }
-
-            // 跳过独立的 source 行（不在配置块内）
-            if line.contains(source_path) || line.contains(&abs_path) {
-                i += 1;
-                // 跳过后续的空行
-                while i < lines.len() && lines[i].trim().is_empty() {
+                None => {
+                    // 文件开头的配置块被移除时，跳过其后的空行
+                    if !(removed && new_lines.is_empty() && lines[i].trim().is_empty()) {
+                        new_lines.push(lines[i]);
+                    }
                     i += 1;
                 }
-                continue;
             }
-
-            new_content.push_str(line);
-            new_content.push('\n');
-            i += 1;
-        }
-
-        // 清理末尾的多个空行
-        while new_content.ends_with("\n\n") {
-            new_content.pop();
-        }
-        if !new_content.is_empty() && !new_content.ends_with('\n') {
-            new_content.push('\n');
         }
 
-        Ok(new_content)
+        new_lines
     }
 
-    /// 从内容中移除 source 语句（指定 shell 类型）
+    /// 移除 source 语句
     ///
-    /// 移除 source 语句及其相关的注释块（如果存在）。
+    /// 先移除包含该 source 语句的托管配置块，再移除配置块外的 source 语句及其相关的注释块（如果存在）。
     /// 支持不同 shell 的 source 语句格式（PowerShell 使用 `.`，其他使用 `source`）。
-    fn remove_source_from_content_for_shell(
-        content: &str,
-        shell: &Shell,
-        source_path: &str,
-    ) -> Result<String> {
+    fn remove_source_lines(content: &str, shell: &Shell, source_path: &str) -> Result<String> {
         let home = Paths::home_dir()?;
         let abs_path = source_path.replace("$HOME", &home.to_string_lossy());
         let source_keyword = Self::get_source_keyword(shell);
 
-        let mut new_content = String::new();
+        let patterns = Self::source_patterns(shell, source_path)?;
         let lines: Vec<&str> = content.lines().collect();
+        let blocks = Self::source_blocks(&lines, &patterns);
+        let lines = Self::replace_line_ranges(&lines, &blocks, |_| Vec::new());
+
+        let mut new_content = String::new();
         let mut i = 0;
 
         while i < lines.len() {
@@ -845,8 +1639,10 @@ impl ShellConfigManager {
     }
 }
 
-/// 现有配置
-struct ExistingConfig {
-    env_in_block: HashMap<String, String>,
-    content_without_block: String,
+/// 拆分后的配置内容片段
+enum ConfigSegment<'a> {
+    /// 配置块外的一行
+    Line(&'a str),
+    /// 配置块（不含标记行）
+    Block(Vec<&'a str>),
 }
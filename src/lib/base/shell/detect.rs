@@ -1,8 +1,11 @@
-use clap_complete::shells::Shell;
 use color_eyre::{eyre::eyre, Result};
 
+use super::Shell;
 use crate::base::util::file::FileReader;
 
+/// nushell 为其启动的进程设置的环境变量
+const NU_VERSION_ENV: &str = "NU_VERSION";
+
 /// Shell 检测工具
 ///
 /// 提供 Shell 类型检测功能。
@@ -11,8 +14,8 @@ pub struct Detect;
 impl Detect {
     /// 检测当前 shell 类型并返回 Shell
     ///
-    /// 根据 `SHELL` 环境变量检测当前 shell 类型。
-    /// 支持的 shell 类型：zsh、bash、fish、powershell、elvish。
+    /// 先检查是否运行在 nushell 中（见 `is_nushell`），否则根据 `SHELL` 环境变量检测当前 shell 类型。
+    /// 支持的 shell 类型：zsh、bash、fish、powershell、elvish、nushell。
     ///
     /// # 返回
     ///
@@ -22,15 +25,53 @@ impl Detect {
     ///
     /// 如果 shell 类型不支持，返回相应的错误信息。
     pub fn shell() -> Result<Shell> {
-        Shell::from_env()
-            .or_else(|| {
-                // 如果 from_env() 失败，尝试从 SHELL 环境变量解析
-                std::env::var("SHELL").ok().and_then(Shell::from_shell_path)
-            })
-            .ok_or_else(|| {
-                let shell = std::env::var("SHELL").unwrap_or_else(|_| "unknown".to_string());
-                eyre!("Unsupported shell: {}", shell)
-            })
+        if Self::is_nushell() {
+            return Ok(Shell::Nushell);
+        }
+
+        Shell::from_env().ok_or_else(|| {
+            let shell = std::env::var("SHELL").unwrap_or_else(|_| "unknown".to_string());
+            eyre!("Unsupported shell: {}", shell)
+        })
+    }
+
+    /// 检测当前是否运行在 nushell 中
+    ///
+    /// nushell 通常不作为登录 shell，`SHELL` 仍指向登录 shell，因此：
+    /// - 父进程是已知的 shell 时，以父进程为准（在 nushell 中启动 bash 后运行，不视为 nushell）
+    /// - 无法识别父进程时，检查 nushell 设置的 `NU_VERSION` 环境变量
+    pub fn is_nushell() -> bool {
+        match Self::parent_shell() {
+            Some(shell) => shell == Shell::Nushell,
+            None => std::env::var_os(NU_VERSION_ENV).is_some(),
+        }
+    }
+
+    /// 父进程的 shell 类型（父进程不是已知的 shell 时返回 `None`）
+    fn parent_shell() -> Option<Shell> {
+        Self::parent_process_name().and_then(Shell::from_shell_path)
+    }
+
+    /// 获取父进程名称
+    ///
+    /// Linux 读取 `/proc/<ppid>/comm`，其他 Unix 系统使用 `ps` 命令。
+    #[cfg(unix)]
+    fn parent_process_name() -> Option<String> {
+        let ppid = std::os::unix::process::parent_id();
+        let name = match std::fs::read_to_string(format!("/proc/{}/comm", ppid)) {
+            Ok(name) => name,
+            Err(_) => duct::cmd("ps", &["-o", "comm=", "-p", &ppid.to_string()])
+                .stderr_null()
+                .read()
+                .ok()?,
+        };
+        let name = name.trim();
+        (!name.is_empty()).then(|| name.to_string())
+    }
+
+    #[cfg(not(unix))]
+    fn parent_process_name() -> Option<String> {
+        None
     }
 
     /// 检测系统中已安装的 shell
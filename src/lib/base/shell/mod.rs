@@ -1,7 +1,7 @@
 //! Shell 检测与管理工具
 //!
 //! 本模块提供了 Shell 相关的检测和管理功能，包括：
-//! - 检测当前 shell 类型（zsh、bash、fish、powershell、elvish）
+//! - 检测当前 shell 类型（zsh、bash、fish、powershell、elvish、nushell）
 //! - 重新加载 shell 配置
 //! - Shell 配置文件管理（环境变量、source 语句等）
 //!
@@ -12,7 +12,9 @@
 mod config;
 mod detect;
 mod reload;
+mod types;
 
-pub use config::ShellConfigManager;
+pub use config::{ShellConfigEdit, ShellConfigManager, ShellConfigPreview};
 pub use detect::Detect;
 pub use reload::Reload;
+pub use types::Shell;
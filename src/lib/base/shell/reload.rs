@@ -1,7 +1,7 @@
-use clap_complete::Shell;
 use color_eyre::{eyre::eyre, Result};
 use duct::cmd;
 
+use super::Shell;
 use crate::base::settings::paths::Paths;
 use crate::trace_warn;
 
@@ -24,7 +24,7 @@ pub struct Reload;
 impl Reload {
     /// 重新加载 shell 配置（在子进程中执行 source 命令）
     ///
-    /// 在子 shell 中执行 `source` 命令（Unix）或 `.` 命令（PowerShell）来重新加载配置文件。
+    /// 在子 shell 中执行 `source` 命令（Unix、nushell）或 `.` 命令（PowerShell）来重新加载配置文件。
     /// 注意：这不会影响当前 shell，但可以验证配置文件是否有效。
     ///
     /// # 参数
@@ -43,30 +43,34 @@ impl Reload {
         let config_file_str = config_file.display().to_string();
 
         // PowerShell 使用 `.` 而不是 `source`
-        let (shell_cmd, reload_hint) = if shell == &Shell::PowerShell {
-            (
+        let (shell_cmd, reload_hint) = match shell {
+            Shell::PowerShell => (
                 format!(". {}", config_file_str),
                 format!(". {}", config_file_str),
-            )
-        } else {
-            (
+            ),
+            // nushell 的配置目录可能包含空格（如 macOS 的 `Application Support`），路径需要加引号
+            Shell::Nushell => (
+                format!("source '{}'", config_file_str),
+                "source $nu.config-path".to_string(),
+            ),
+            _ => (
                 format!("source {}", config_file_str),
                 format!("source {}", config_file_str),
-            )
+            ),
         };
 
         // 尝试在子 shell 中执行 source 命令
         // 注意：这不会影响当前 shell，但可以验证配置文件是否有效
-        let shell_type = shell.to_string();
+        let shell_type = shell.executable();
 
         // PowerShell 使用不同的参数格式
         let status = if shell == &Shell::PowerShell {
-            cmd(&shell_type, &["-NoProfile", "-Command", &shell_cmd])
+            cmd(shell_type, &["-NoProfile", "-Command", &shell_cmd])
                 .run()
                 .map(|_| ())
                 .map_err(|e| eyre!("Failed to reload config: {}", e))
         } else {
-            cmd(&shell_type, &["-c", &shell_cmd])
+            cmd(shell_type, &["-c", &shell_cmd])
                 .run()
                 .map(|_| ())
                 .map_err(|e| eyre!("Failed to reload config: {}", e))
@@ -0,0 +1,105 @@
+//! Shell 类型
+//!
+//! `clap_complete::Shell` 只包含 clap 能生成 completion 脚本的 shell，不包含 nushell，
+//! 因此 shell 检测、配置文件路径和重载使用本模块的 `Shell`；
+//! 生成 completion 脚本时通过 `Shell::completion_shell()` 转换为 `clap_complete::Shell`。
+
+use std::fmt;
+use std::path::Path;
+
+/// Shell 类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Shell {
+    /// Bourne Again SHell (bash)
+    Bash,
+    /// Elvish shell
+    Elvish,
+    /// Friendly Interactive SHell (fish)
+    Fish,
+    /// PowerShell
+    PowerShell,
+    /// Z SHell (zsh)
+    Zsh,
+    /// Nushell (nu)
+    Nushell,
+}
+
+impl Shell {
+    /// 从 shell 可执行文件路径解析 shell 类型
+    ///
+    /// 按文件名（不含扩展名）匹配，如 `/bin/zsh`、`/opt/homebrew/bin/nu`、`pwsh.exe`。
+    /// 登录 shell 的进程名以 `-` 开头（如 `-zsh`），解析时会忽略开头的 `-`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::base::shell::Shell;
+    ///
+    /// assert_eq!(Shell::from_shell_path("/bin/zsh"), Some(Shell::Zsh));
+    /// assert_eq!(Shell::from_shell_path("/usr/local/bin/nu"), Some(Shell::Nushell));
+    /// assert_eq!(Shell::from_shell_path("/bin/sh"), None);
+    /// ```
+    pub fn from_shell_path(path: impl AsRef<Path>) -> Option<Self> {
+        let name = path.as_ref().file_stem()?.to_str()?.trim_start_matches('-');
+        match name {
+            "bash" => Some(Self::Bash),
+            "elvish" => Some(Self::Elvish),
+            "fish" => Some(Self::Fish),
+            "powershell" | "powershell_ise" | "pwsh" => Some(Self::PowerShell),
+            "zsh" => Some(Self::Zsh),
+            "nu" | "nushell" => Some(Self::Nushell),
+            _ => None,
+        }
+    }
+
+    /// 根据 `SHELL` 环境变量获取 shell 类型
+    ///
+    /// 没有设置 `SHELL` 时，Windows 上返回 PowerShell，其他平台返回 `None`。
+    pub fn from_env() -> Option<Self> {
+        match std::env::var_os("SHELL") {
+            Some(shell) => Self::from_shell_path(shell),
+            None if cfg!(windows) => Some(Self::PowerShell),
+            None => None,
+        }
+    }
+
+    /// 对应的 clap completion 生成器
+    ///
+    /// clap 不支持生成 nushell 的 completion 脚本，nushell 返回 `None`。
+    pub fn completion_shell(&self) -> Option<clap_complete::Shell> {
+        match self {
+            Self::Bash => Some(clap_complete::Shell::Bash),
+            Self::Elvish => Some(clap_complete::Shell::Elvish),
+            Self::Fish => Some(clap_complete::Shell::Fish),
+            Self::PowerShell => Some(clap_complete::Shell::PowerShell),
+            Self::Zsh => Some(clap_complete::Shell::Zsh),
+            Self::Nushell => None,
+        }
+    }
+
+    /// shell 的可执行文件名
+    pub fn executable(&self) -> &'static str {
+        match self {
+            Self::Nushell => "nu",
+            _ => self.name(),
+        }
+    }
+
+    /// shell 名称（与 `clap_complete::Shell` 的名称一致，nushell 为 `nushell`）
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Bash => "bash",
+            Self::Elvish => "elvish",
+            Self::Fish => "fish",
+            Self::PowerShell => "powershell",
+            Self::Zsh => "zsh",
+            Self::Nushell => "nushell",
+        }
+    }
+}
+
+impl fmt::Display for Shell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -7,6 +7,10 @@
 //! - 自动格式化表格
 //! - 支持自定义样式和边框
 //! - 支持列对齐和宽度控制
+//! - 支持按终端宽度限制表格宽度，超出时截断（省略号）或换行
+//! - 计算宽度时忽略 ANSI 颜色代码（带颜色的 Jira 状态、修改类型等不会导致列错位）
+//! - 禁用颜色输出时（`--no-color`、`NO_COLOR` 或非终端，见 `colors_enabled()`）去除 ANSI 颜色代码
+//! - 支持导出为 Markdown、CSV、TSV（去除 ANSI 颜色代码，并转义分隔符）
 //! - 支持紧凑模式和完整模式
 //! - 支持链式配置
 //!
@@ -38,13 +42,70 @@
 //! println!("{}", TableBuilder::new(users));
 //! ```
 
+use std::borrow::Cow;
 use std::fmt;
 
 use tabled::{
-    settings::{object::Columns, object::Rows, panel::Panel, Alignment, Modify, Style, Width},
+    settings::{
+        object::{Columns, Rows},
+        panel::Panel,
+        peaker::PriorityMax,
+        Alignment, Modify, Style, Width,
+    },
     Table, Tabled,
 };
 
+use crate::base::logger::console::colors_enabled;
+
+/// 截断单元格时添加的省略号
+pub const ELLIPSIS: &str = "…";
+
+/// 检测终端宽度（列数）
+///
+/// stdout 不是终端（如重定向到文件或管道）时返回 `None`。
+pub fn terminal_width() -> Option<usize> {
+    console::Term::stdout().size_checked().map(|(_, columns)| columns as usize)
+}
+
+/// 单元格超出最大宽度时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableOverflow {
+    /// 截断，并在末尾添加省略号（`…`）
+    Truncate,
+    /// 自动换行（尽量在单词边界换行）
+    #[default]
+    Wrap,
+}
+
+impl TableOverflow {
+    /// 将单列最大宽度应用到 Table
+    ///
+    /// 需要在添加标题行之前调用，避免跨越所有列的标题行被截断。
+    fn apply_to_column(&self, table: &mut Table, column: usize, width: usize) {
+        let target = Columns::single(column);
+        match self {
+            TableOverflow::Truncate => {
+                table.with(Modify::new(target).with(Width::truncate(width).suffix(ELLIPSIS)));
+            }
+            TableOverflow::Wrap => {
+                table.with(Modify::new(target).with(Width::wrap(width).keep_words()));
+            }
+        }
+    }
+
+    /// 将表格总宽度应用到 Table（优先缩减最宽的列）
+    fn apply_to_table(&self, table: &mut Table, width: usize) {
+        match self {
+            TableOverflow::Truncate => {
+                table.with(Width::truncate(width).suffix(ELLIPSIS).priority::<PriorityMax>());
+            }
+            TableOverflow::Wrap => {
+                table.with(Width::wrap(width).keep_words().priority::<PriorityMax>());
+            }
+        }
+    }
+}
+
 /// 表格样式配置
 #[derive(Debug, Clone, Copy)]
 pub enum TableStyle {
@@ -83,6 +144,32 @@ impl TableStyle {
     }
 }
 
+/// 去除 ANSI 颜色代码
+fn plain(cell: &str) -> Cow<'_, str> {
+    console::strip_ansi_codes(cell)
+}
+
+/// 转义 CSV/TSV 单元格
+///
+/// 包含分隔符、双引号或换行时用双引号包裹，内部的双引号写为两个双引号（RFC 4180）。
+fn escape_delimited(cell: &str, delimiter: char) -> Cow<'_, str> {
+    if cell.contains([delimiter, '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", cell.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(cell)
+    }
+}
+
+/// 转义 Markdown 表格单元格
+///
+/// 转义反斜杠和 `|`，换行替换为 `<br>`（Markdown 表格的单元格不能跨行）。
+fn escape_markdown(cell: &str) -> String {
+    cell.replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
 /// 修复表格边框格式
 /// 1. 顶部边框：除了 ┌ 和 ┐ 之外，中间都应该是 ─
 /// 2. 标题行下方的分隔线：从 ├─┼─┼─┤ 格式改为 ├─┬─┬─┤ 格式
@@ -157,11 +244,39 @@ fn fix_title_separator(table_output: String) -> String {
 ///     .render();
 /// println!("{}", output);
 /// ```
+///
+/// ## 限制列宽
+///
+/// ```rust
+/// use tabled::Tabled;
+/// use workflow::base::table::{TableBuilder, TableOverflow};
+///
+/// #[derive(Tabled)]
+/// struct Commit {
+///     sha: String,
+///     subject: String,
+/// }
+///
+/// let commits = vec![Commit {
+///     sha: "a1b2c3d".to_string(),
+///     subject: "Add width-aware wrapping to the table builder".to_string(),
+/// }];
+///
+/// let output = TableBuilder::new(commits)
+///     .with_column_max_width(1, 20)
+///     .with_overflow(TableOverflow::Truncate)
+///     .with_terminal_width()
+///     .render();
+/// assert!(output.contains('…'));
+/// ```
 pub struct TableBuilder<T> {
     data: Vec<T>,
     title: Option<String>,
     style: Option<TableStyle>,
     max_width: Option<usize>,
+    fit_terminal: bool,
+    column_max_widths: Vec<(usize, usize)>,
+    overflow: TableOverflow,
     alignments: Vec<Alignment>,
 }
 
@@ -192,6 +307,9 @@ impl<T: Tabled> TableBuilder<T> {
             title: None,
             style: None,
             max_width: None,
+            fit_terminal: false,
+            column_max_widths: Vec::new(),
+            overflow: TableOverflow::default(),
             alignments: Vec::new(),
         }
     }
@@ -232,7 +350,9 @@ impl<T: Tabled> TableBuilder<T> {
         self
     }
 
-    /// 设置最大宽度（自动换行）
+    /// 设置表格最大宽度
+    ///
+    /// 超出时按 `with_overflow` 指定的方式（默认换行）缩减最宽的列。
     ///
     /// # 参数
     ///
@@ -250,6 +370,62 @@ impl<T: Tabled> TableBuilder<T> {
         self
     }
 
+    /// 限制表格宽度不超过终端宽度
+    ///
+    /// stdout 不是终端时不限制。同时设置了 `with_max_width` 时取两者中较小的值。
+    ///
+    /// # 示例
+    ///
+    /// ```rust,no_run
+    /// use workflow::base::table::TableBuilder;
+    /// # let data = vec![("name", "value")];
+    /// TableBuilder::new(data).with_terminal_width();
+    /// ```
+    pub fn with_terminal_width(mut self) -> Self {
+        self.fit_terminal = true;
+        self
+    }
+
+    /// 设置单列最大宽度
+    ///
+    /// 超出时按 `with_overflow` 指定的方式截断或换行。可以多次调用，为不同的列设置宽度。
+    ///
+    /// # 参数
+    ///
+    /// * `column` - 列索引（从 0 开始）
+    /// * `width` - 最大宽度（显示宽度，不包括 ANSI 颜色代码）
+    ///
+    /// # 示例
+    ///
+    /// ```rust,no_run
+    /// use workflow::base::table::TableBuilder;
+    /// # let data = vec![("name", "value")];
+    /// TableBuilder::new(data).with_column_max_width(1, 50);
+    /// ```
+    pub fn with_column_max_width(mut self, column: usize, width: usize) -> Self {
+        self.column_max_widths.retain(|(existing, _)| *existing != column);
+        self.column_max_widths.push((column, width));
+        self
+    }
+
+    /// 设置单元格超出最大宽度时的处理方式（默认换行）
+    ///
+    /// # 参数
+    ///
+    /// * `overflow` - 截断（`TableOverflow::Truncate`）或换行（`TableOverflow::Wrap`）
+    ///
+    /// # 示例
+    ///
+    /// ```rust,no_run
+    /// use workflow::base::table::{TableBuilder, TableOverflow};
+    /// # let data = vec![("name", "value")];
+    /// TableBuilder::new(data).with_overflow(TableOverflow::Truncate);
+    /// ```
+    pub fn with_overflow(mut self, overflow: TableOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
     /// 设置列对齐方式
     ///
     /// # 参数
@@ -293,47 +469,121 @@ impl<T: Tabled> TableBuilder<T> {
             return String::new();
         }
 
-        // 构建表格
-        let mut table = Table::new(&self.data);
+        self.build()
+    }
 
-        // 应用样式（边框）
-        if let Some(style) = self.style {
-            style.apply_to_table(&mut table);
-        }
+    /// 导出为 Markdown 表格
+    ///
+    /// 只包含表头和数据（不包括标题），去除 ANSI 颜色代码，转义单元格中的 `|` 和换行。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use tabled::Tabled;
+    /// use workflow::base::table::TableBuilder;
+    ///
+    /// #[derive(Tabled)]
+    /// struct Row {
+    ///     name: String,
+    ///     value: String,
+    /// }
+    ///
+    /// let rows = vec![Row { name: "a|b".to_string(), value: "c".to_string() }];
+    /// assert_eq!(
+    ///     TableBuilder::new(rows).to_markdown(),
+    ///     "| name | value |\n| --- | --- |\n| a\\|b | c |"
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let (headers, rows) = self.records();
+        let line = |cells: &[String]| {
+            let cells: Vec<String> = cells.iter().map(|cell| escape_markdown(cell)).collect();
+            format!("| {} |", cells.join(" | "))
+        };
 
-        // 添加标题行（在边框内）
-        if let Some(ref title) = self.title {
-            table.with(Panel::header(title));
-            // 设置标题行居中对齐
-            table.with(Modify::new(Rows::first()).with(Alignment::center()));
-        }
+        let mut lines = vec![
+            line(&headers),
+            format!("|{}|", vec![" --- "; headers.len()].join("|")),
+        ];
+        lines.extend(rows.iter().map(|row| line(row)));
+        lines.join("\n")
+    }
 
-        // 应用最大宽度
-        if let Some(width) = self.max_width {
-            table.with(Width::wrap(width));
-        }
+    /// 导出为 CSV
+    ///
+    /// 第一行为表头，去除 ANSI 颜色代码；包含逗号、双引号或换行的单元格用双引号包裹。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use tabled::Tabled;
+    /// use workflow::base::table::TableBuilder;
+    ///
+    /// #[derive(Tabled)]
+    /// struct Row {
+    ///     name: String,
+    ///     value: String,
+    /// }
+    ///
+    /// let rows = vec![Row { name: "Fix \"login\", logout".to_string(), value: "ok".to_string() }];
+    /// assert_eq!(
+    ///     TableBuilder::new(rows).to_csv(),
+    ///     "name,value\n\"Fix \"\"login\"\", logout\",ok"
+    /// );
+    /// ```
+    pub fn to_csv(&self) -> String {
+        self.to_delimited(',')
+    }
 
-        // 应用列对齐
-        for (col_idx, alignment) in self.alignments.iter().enumerate() {
-            table.with(Modify::new(Columns::single(col_idx)).with(*alignment));
-        }
+    /// 导出为 TSV
+    ///
+    /// 与 `to_csv` 相同，使用 Tab 分隔；包含 Tab、双引号或换行的单元格用双引号包裹。
+    pub fn to_tsv(&self) -> String {
+        self.to_delimited('\t')
+    }
 
-        // 渲染表格并修复标题行下方的分隔线
-        let table_output = format!("{}", table);
-        if self.title.is_some() {
-            fix_title_separator(table_output)
-        } else {
-            table_output
-        }
+    /// 导出为使用指定分隔符的文本
+    fn to_delimited(&self, delimiter: char) -> String {
+        let (headers, rows) = self.records();
+        std::iter::once(&headers)
+            .chain(rows.iter())
+            .map(|cells| {
+                cells
+                    .iter()
+                    .map(|cell| escape_delimited(cell, delimiter))
+                    .collect::<Vec<_>>()
+                    .join(&delimiter.to_string())
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
     }
-}
 
-impl<T: Tabled> fmt::Display for TableBuilder<T> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        if self.data.is_empty() {
-            return Ok(());
+    /// 获取表头和数据（去除 ANSI 颜色代码）
+    fn records(&self) -> (Vec<String>, Vec<Vec<String>>) {
+        let headers = T::headers().iter().map(|header| plain(header).into_owned()).collect();
+        let rows = self
+            .data
+            .iter()
+            .map(|item| item.fields().iter().map(|field| plain(field).into_owned()).collect())
+            .collect();
+        (headers, rows)
+    }
+
+    /// 表格总宽度限制（`with_max_width` 和终端宽度中较小的值）
+    fn effective_max_width(&self) -> Option<usize> {
+        let terminal = if self.fit_terminal {
+            terminal_width()
+        } else {
+            None
+        };
+        match (self.max_width, terminal) {
+            (Some(max), Some(terminal)) => Some(max.min(terminal)),
+            (max, terminal) => max.or(terminal),
         }
+    }
 
+    /// 构建表格并渲染为字符串（数据不能为空）
+    fn build(&self) -> String {
         let mut table = Table::new(&self.data);
 
         // 应用样式（边框）
@@ -341,6 +591,11 @@ impl<T: Tabled> fmt::Display for TableBuilder<T> {
             style.apply_to_table(&mut table);
         }
 
+        // 应用单列最大宽度（在添加标题行之前，标题行不受单列宽度限制）
+        for (column, width) in &self.column_max_widths {
+            self.overflow.apply_to_column(&mut table, *column, *width);
+        }
+
         // 添加标题行（在边框内）
         if let Some(ref title) = self.title {
             table.with(Panel::header(title));
@@ -348,9 +603,9 @@ impl<T: Tabled> fmt::Display for TableBuilder<T> {
             table.with(Modify::new(Rows::first()).with(Alignment::center()));
         }
 
-        // 应用最大宽度
-        if let Some(width) = self.max_width {
-            table.with(Width::wrap(width));
+        // 应用表格最大宽度
+        if let Some(width) = self.effective_max_width() {
+            self.overflow.apply_to_table(&mut table, width);
         }
 
         // 应用列对齐
@@ -360,12 +615,27 @@ impl<T: Tabled> fmt::Display for TableBuilder<T> {
 
         // 渲染表格并修复标题行下方的分隔线
         let table_output = format!("{}", table);
-        let fixed_output = if self.title.is_some() {
+        let table_output = if self.title.is_some() {
             fix_title_separator(table_output)
         } else {
             table_output
         };
 
-        write!(f, "{}", fixed_output)
+        // 禁用颜色输出时去除单元格中的 ANSI 颜色代码
+        if colors_enabled() {
+            table_output
+        } else {
+            plain(&table_output).into_owned()
+        }
+    }
+}
+
+impl<T: Tabled> fmt::Display for TableBuilder<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.data.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, "{}", self.build())
     }
 }
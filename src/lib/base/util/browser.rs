@@ -1,9 +1,51 @@
 //! 浏览器操作模块
 //!
 //! 本模块提供了在浏览器中打开 URL 的功能。
+//!
+//! 打开 URL 时按顺序尝试以下方式，直到成功：
+//! 1. `BROWSER` 环境变量中的命令（多个命令用 `:` 分隔，`%s` 会被替换为 URL）
+//! 2. `wslview`（WSL 中）
+//! 3. `xdg-open`（Linux）或系统默认浏览器（macOS、Windows）
+//! 4. `powershell.exe Start-Process`（WSL 中）
+//!
+//! 全部失败时输出 URL，由用户手动打开。
+
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use color_eyre::Result;
 
+use crate::base::util::platform::Platform;
+use crate::{log_message, log_warning, trace_debug};
+
+/// 等待浏览器命令退出的时间（超过时间仍在运行视为已打开浏览器）
+const LAUNCH_WAIT: Duration = Duration::from_secs(2);
+
+/// 打开浏览器的方式
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BrowserLauncher {
+    /// 执行外部命令（参数中已包含 URL）
+    Command {
+        /// 程序名
+        program: String,
+        /// 参数
+        args: Vec<String>,
+    },
+    /// 系统默认浏览器（`open` crate）
+    System,
+}
+
+impl BrowserLauncher {
+    /// 创建执行外部命令的方式
+    fn command(program: impl Into<String>, args: &[&str]) -> Self {
+        Self::Command {
+            program: program.into(),
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+        }
+    }
+}
+
 /// 浏览器操作模块
 ///
 /// 提供在默认浏览器中打开 URL 的功能。
@@ -12,17 +54,150 @@ pub struct Browser;
 impl Browser {
     /// 在浏览器中打开 URL
     ///
-    /// 使用系统默认浏览器打开指定的 URL。
+    /// 按 `Browser::launchers` 的顺序尝试打开浏览器；全部失败时输出 URL 供用户手动打开，
+    /// 不返回错误（无图形界面的环境中不影响后续流程）。
+    ///
+    /// # 参数
+    ///
+    /// * `url` - 要打开的 URL
+    pub fn open(url: &str) -> Result<()> {
+        let platform = Platform::detect();
+        let browser_env = std::env::var("BROWSER").ok();
+        let launchers = Self::launchers(url, browser_env.as_deref(), &platform, platform.is_wsl());
+
+        for launcher in &launchers {
+            match Self::launch(launcher, url) {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    trace_debug!("Failed to open browser with {:?}: {}", launcher, e);
+                }
+            }
+        }
+
+        log_warning!("Could not open a browser, please open the URL manually:");
+        log_message!("  {}", url);
+        Ok(())
+    }
+
+    /// 生成打开 URL 的方式（按优先级排列）
     ///
     /// # 参数
     ///
     /// * `url` - 要打开的 URL
+    /// * `browser_env` - `BROWSER` 环境变量
+    /// * `platform` - 当前平台
+    /// * `wsl` - 是否运行在 WSL 中
     ///
-    /// # 错误
+    /// # 示例
     ///
-    /// 如果无法打开浏览器，返回相应的错误信息。
-    pub fn open(url: &str) -> Result<()> {
-        open::that(url)?;
+    /// ```
+    /// use workflow::base::util::browser::{Browser, BrowserLauncher};
+    /// use workflow::base::util::Platform;
+    ///
+    /// let launchers = Browser::launchers(
+    ///     "https://example.com",
+    ///     Some("firefox"),
+    ///     &Platform::new("linux", "x86_64"),
+    ///     false,
+    /// );
+    /// assert_eq!(
+    ///     launchers,
+    ///     vec![
+    ///         BrowserLauncher::Command {
+    ///             program: "firefox".to_string(),
+    ///             args: vec!["https://example.com".to_string()],
+    ///         },
+    ///         BrowserLauncher::Command {
+    ///             program: "xdg-open".to_string(),
+    ///             args: vec!["https://example.com".to_string()],
+    ///         },
+    ///     ]
+    /// );
+    /// ```
+    pub fn launchers(
+        url: &str,
+        browser_env: Option<&str>,
+        platform: &Platform,
+        wsl: bool,
+    ) -> Vec<BrowserLauncher> {
+        let mut launchers: Vec<BrowserLauncher> = browser_env
+            .unwrap_or_default()
+            .split(':')
+            .filter_map(|command| Self::parse_browser_command(command, url))
+            .collect();
+
+        if !platform.is_linux() {
+            launchers.push(BrowserLauncher::System);
+            return launchers;
+        }
+
+        if wsl {
+            launchers.push(BrowserLauncher::command("wslview", &[url]));
+        }
+        launchers.push(BrowserLauncher::command("xdg-open", &[url]));
+        if wsl {
+            // Start-Process 的参数需要加引号，避免 URL 中的 `&` 被 PowerShell 解析
+            let argument = format!("'{}'", url.replace('\'', "''"));
+            launchers.push(BrowserLauncher::command(
+                "powershell.exe",
+                &[
+                    "-NoProfile",
+                    "-NonInteractive",
+                    "-Command",
+                    "Start-Process",
+                    &argument,
+                ],
+            ));
+        }
+        launchers
+    }
+
+    /// 解析 `BROWSER` 环境变量中的单个命令
+    ///
+    /// 命令中包含 `%s` 时替换为 URL，否则将 URL 作为最后一个参数。
+    fn parse_browser_command(command: &str, url: &str) -> Option<BrowserLauncher> {
+        let mut parts = command.split_whitespace();
+        let program = parts.next()?;
+        let mut args: Vec<String> = parts.map(|part| part.replace("%s", url)).collect();
+        if !command.contains("%s") {
+            args.push(url.to_string());
+        }
+        Some(BrowserLauncher::Command {
+            program: program.to_string(),
+            args,
+        })
+    }
+
+    /// 使用指定方式打开 URL
+    ///
+    /// 外部命令在 `LAUNCH_WAIT` 内以非 0 状态退出时视为失败；
+    /// 超过时间仍在运行（如直接启动浏览器进程）视为成功。
+    fn launch(launcher: &BrowserLauncher, url: &str) -> Result<()> {
+        let (program, args) = match launcher {
+            BrowserLauncher::System => {
+                open::that(url)?;
+                return Ok(());
+            }
+            BrowserLauncher::Command { program, args } => (program, args),
+        };
+
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+
+        let started = Instant::now();
+        while started.elapsed() < LAUNCH_WAIT {
+            if let Some(status) = child.try_wait()? {
+                if status.success() {
+                    return Ok(());
+                }
+                color_eyre::eyre::bail!("'{}' exited with {}", program, status);
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
         Ok(())
     }
 }
@@ -1,7 +1,7 @@
 //! 校验和工具模块
 //!
 //! 本模块提供了文件校验和计算和验证功能，包括：
-//! - 计算文件的 SHA256 哈希值
+//! - 计算文件和内存数据的 SHA256 哈希值
 //! - 解析校验和文件内容
 //! - 验证文件完整性
 //! - 构建校验和 URL（纯字符串操作）
@@ -78,6 +78,31 @@ impl Checksum {
         Ok(format!("{:x}", hash))
     }
 
+    /// 计算内存中数据的 SHA256 哈希值
+    ///
+    /// # 参数
+    ///
+    /// * `data` - 要计算哈希值的数据
+    ///
+    /// # 返回
+    ///
+    /// 返回数据的 SHA256 哈希值（十六进制字符串），与对相同内容的文件调用
+    /// `calculate_file_sha256` 的结果一致。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::base::util::checksum::Checksum;
+    ///
+    /// assert_eq!(
+    ///     Checksum::calculate_sha256(b"abc"),
+    ///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    /// );
+    /// ```
+    pub fn calculate_sha256(data: &[u8]) -> String {
+        format!("{:x}", Sha256::digest(data))
+    }
+
     /// 从校验和文件内容中提取哈希值
     ///
     /// 解析校验和文件内容（通常是 "hash  filename" 格式或只有 "hash"），
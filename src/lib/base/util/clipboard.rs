@@ -1,19 +1,22 @@
 //! 剪贴板操作模块
 //!
 //! 本模块提供了剪贴板的读写功能。
+//!
+//! 读取时优先使用 `clipboard` crate；crate 不可用（musl、Linux ARM64）或读取失败时
+//! （如 Wayland 会话中没有 X11 剪贴板），回退到平台的命令行工具：
+//! - macOS：`pbpaste`
+//! - Linux：`wl-paste`（Wayland）、`xclip`
+//! - Windows：PowerShell `Get-Clipboard`
 
-#[cfg(all(
-    not(target_env = "musl"),
-    not(all(target_arch = "aarch64", target_os = "linux", target_env = "gnu"))
-))]
-use clipboard::{ClipboardContext, ClipboardProvider};
-use color_eyre::Result;
+use std::process::Command;
 
 #[cfg(all(
     not(target_env = "musl"),
     not(all(target_arch = "aarch64", target_os = "linux", target_env = "gnu"))
 ))]
+use clipboard::{ClipboardContext, ClipboardProvider};
 use color_eyre::eyre::eyre;
+use color_eyre::Result;
 
 /// 剪贴板操作模块
 ///
@@ -62,4 +65,105 @@ impl Clipboard {
         // 静默失败，不影响其他功能
         Ok(())
     }
+
+    /// 读取剪贴板中的文本
+    ///
+    /// # 返回
+    ///
+    /// 返回剪贴板中的文本（保持原样，不去除首尾空白）；
+    /// 剪贴板为空、只有空白或不是文本（如图片）时返回 `None`。
+    ///
+    /// # 错误
+    ///
+    /// 无法访问剪贴板（没有图形会话，或没有可用的命令行工具）时返回错误。
+    pub fn read() -> Result<Option<String>> {
+        #[cfg(all(
+            not(target_env = "musl"),
+            not(all(target_arch = "aarch64", target_os = "linux", target_env = "gnu"))
+        ))]
+        {
+            let contents =
+                ClipboardProvider::new().and_then(|mut ctx: ClipboardContext| ctx.get_contents());
+            if let Ok(text) = contents {
+                return Ok(Self::text_from_bytes(text.as_bytes()));
+            }
+        }
+
+        Self::read_with_command()
+    }
+
+    /// 将剪贴板的原始内容转换为文本
+    ///
+    /// 内容为空、只有空白、不是有效的 UTF-8 或包含 NUL 字符（二进制数据）时返回 `None`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::base::util::Clipboard;
+    ///
+    /// assert_eq!(Clipboard::text_from_bytes(b"PROJ-123\n"), Some("PROJ-123\n".to_string()));
+    /// assert_eq!(Clipboard::text_from_bytes(b"  \n"), None);
+    /// assert_eq!(Clipboard::text_from_bytes(&[0x89, 0x50, 0x4e, 0x47, 0x00]), None);
+    /// ```
+    pub fn text_from_bytes(bytes: &[u8]) -> Option<String> {
+        let text = std::str::from_utf8(bytes).ok()?;
+        if text.trim().is_empty() || text.contains('\0') {
+            return None;
+        }
+        Some(text.to_string())
+    }
+
+    /// 当前平台读取剪贴板的命令（程序名、参数），按优先级排列
+    pub fn paste_commands() -> Vec<(&'static str, Vec<&'static str>)> {
+        if cfg!(target_os = "macos") {
+            vec![("pbpaste", vec![])]
+        } else if cfg!(windows) {
+            // 使用 UTF-8 输出，并避免 PowerShell 在末尾追加换行
+            vec![(
+                "powershell",
+                vec![
+                    "-NoProfile",
+                    "-NonInteractive",
+                    "-Command",
+                    "[Console]::OutputEncoding = [Text.Encoding]::UTF8; \
+                     [Console]::Out.Write((Get-Clipboard -Raw))",
+                ],
+            )]
+        } else {
+            let mut commands = Vec::new();
+            if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+                commands.push(("wl-paste", vec!["--no-newline"]));
+            }
+            commands.push(("xclip", vec!["-selection", "clipboard", "-out"]));
+            commands
+        }
+    }
+
+    /// 使用命令行工具读取剪贴板
+    ///
+    /// 依次尝试 `paste_commands()` 中的命令：未安装的命令会被跳过；
+    /// 命令执行失败（如剪贴板为空时 `wl-paste` 返回非 0）时继续尝试下一个。
+    fn read_with_command() -> Result<Option<String>> {
+        let commands = Self::paste_commands();
+        let mut found = false;
+
+        for (program, args) in &commands {
+            let Ok(output) = Command::new(program).args(args).output() else {
+                continue;
+            };
+            found = true;
+            if output.status.success() {
+                return Ok(Self::text_from_bytes(&output.stdout));
+            }
+        }
+
+        if !found {
+            let programs: Vec<&str> = commands.iter().map(|(program, _)| *program).collect();
+            return Err(eyre!(
+                "Failed to read clipboard: none of [{}] is available",
+                programs.join(", ")
+            ));
+        }
+        Ok(None)
+    }
 }
@@ -2,7 +2,8 @@
 //!
 //! 提供文档时间戳生成功能，支持时区和格式配置。
 
-use chrono::{Local, Utc};
+use chrono::{DateTime, FixedOffset, Local, Utc};
+use std::str::FromStr;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 /// 文档时间戳格式选项
@@ -20,14 +21,56 @@ pub enum DateFormat {
 }
 
 /// 时区选项
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum Timezone {
     /// 使用本地时区
+    #[default]
     Local,
     /// 使用 UTC 时区
     Utc,
 }
 
+impl Timezone {
+    /// 配置值（`local` 或 `utc`）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Timezone::Local => "local",
+            Timezone::Utc => "utc",
+        }
+    }
+
+    /// 将时间转换到该时区
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// use chrono::DateTime;
+    /// use workflow::base::util::date::Timezone;
+    ///
+    /// let time = DateTime::parse_from_rfc3339("2024-01-15T18:30:00+08:00").unwrap();
+    /// assert_eq!(Timezone::Utc.convert(&time).to_rfc3339(), "2024-01-15T10:30:00+00:00");
+    /// ```
+    pub fn convert(&self, datetime: &DateTime<FixedOffset>) -> DateTime<FixedOffset> {
+        match self {
+            Timezone::Local => datetime.with_timezone(&Local).fixed_offset(),
+            Timezone::Utc => datetime.with_timezone(&Utc).fixed_offset(),
+        }
+    }
+}
+
+impl FromStr for Timezone {
+    type Err = String;
+
+    /// 从配置值转换（不区分大小写）："local"、"utc"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "local" => Ok(Timezone::Local),
+            "utc" => Ok(Timezone::Utc),
+            _ => Err(format!("Invalid timezone: {}. Expected: local, utc", s)),
+        }
+    }
+}
+
 /// 生成文档时间戳
 ///
 /// # 参数
@@ -0,0 +1,124 @@
+//! 文本行级差异
+//!
+//! 用于在修改文件前预览变更（如卸载时对 shell 配置文件的修改）。
+//! 基于最长公共子序列（LCS）逐行比较，适用于配置文件这类较小的文本。
+
+/// 差异中的一行
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLine<'a> {
+    /// 修改前后都存在的行
+    Context(&'a str),
+    /// 被删除的行
+    Removed(&'a str),
+    /// 新增的行
+    Added(&'a str),
+}
+
+/// 逐行比较两段文本
+///
+/// # 参数
+///
+/// * `old` - 修改前的文本
+/// * `new` - 修改后的文本
+///
+/// # 返回
+///
+/// 返回按顺序排列的差异行（删除的行排在同一位置新增的行之前）。
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let (n, m) = (old_lines.len(), new_lines.len());
+
+    // lcs[i][j]：old_lines[i..] 和 new_lines[j..] 的最长公共子序列长度
+    let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if old_lines[i] == new_lines[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut result = Vec::with_capacity(n.max(m));
+    let (mut i, mut j) = (0, 0);
+    while i < n && j < m {
+        if old_lines[i] == new_lines[j] {
+            result.push(DiffLine::Context(old_lines[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            result.push(DiffLine::Removed(old_lines[i]));
+            i += 1;
+        } else {
+            result.push(DiffLine::Added(new_lines[j]));
+            j += 1;
+        }
+    }
+    result.extend(old_lines[i..].iter().map(|line| DiffLine::Removed(line)));
+    result.extend(new_lines[j..].iter().map(|line| DiffLine::Added(line)));
+    result
+}
+
+/// 生成可读的行级差异
+///
+/// 删除的行以 `- ` 开头，新增的行以 `+ ` 开头，未变化的行以两个空格开头；
+/// 只保留变更行前后 `context` 行的上下文，省略的部分用 `...` 表示。
+///
+/// # 参数
+///
+/// * `old` - 修改前的文本
+/// * `new` - 修改后的文本
+/// * `context` - 变更行前后保留的上下文行数
+///
+/// # 返回
+///
+/// 返回差异文本（每行以换行结尾）；两段文本相同时返回空字符串。
+///
+/// # 示例
+///
+/// ```
+/// use workflow::base::util::diff::render_line_diff;
+///
+/// let diff = render_line_diff("a\nb\nc\n", "a\nc\n", 1);
+/// assert_eq!(diff, "  a\n- b\n  c\n");
+/// ```
+pub fn render_line_diff(old: &str, new: &str, context: usize) -> String {
+    let lines = diff_lines(old, new);
+    let changed: Vec<usize> = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, DiffLine::Context(_)))
+        .map(|(idx, _)| idx)
+        .collect();
+
+    if changed.is_empty() {
+        return String::new();
+    }
+
+    let visible = |idx: usize| changed.iter().any(|&c| idx + context >= c && idx <= c + context);
+
+    let mut output = String::new();
+    let mut skipped = false;
+    for (idx, line) in lines.iter().enumerate() {
+        if !visible(idx) {
+            skipped = true;
+            continue;
+        }
+        if skipped && !output.is_empty() {
+            output.push_str("...\n");
+        }
+        skipped = false;
+
+        let (prefix, text) = match line {
+            DiffLine::Context(text) => ("  ", text),
+            DiffLine::Removed(text) => ("- ", text),
+            DiffLine::Added(text) => ("+ ", text),
+        };
+        output.push_str(prefix);
+        output.push_str(text);
+        output.push('\n');
+    }
+    output
+}
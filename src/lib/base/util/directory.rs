@@ -61,6 +61,29 @@ impl DirectoryWalker {
         Ok(files)
     }
 
+    /// 递归计算目录总大小（字节）和文件数量，目录不存在时返回 `(0, 0)`。
+    pub fn calculate_dir_info(&self) -> Result<(u64, usize)> {
+        let mut total_size = 0u64;
+        let mut file_count = 0usize;
+
+        if !self.root.exists() {
+            return Ok((0, 0));
+        }
+
+        for entry in WalkDir::new(&self.root) {
+            let entry = entry
+                .wrap_err_with(|| format!("Failed to read directory entry: {:?}", self.root))?;
+            let metadata = entry.metadata().wrap_err("Failed to get file metadata")?;
+
+            if metadata.is_file() {
+                total_size += metadata.len();
+                file_count += 1;
+            }
+        }
+
+        Ok((total_size, file_count))
+    }
+
     /// 非递归列出直接子目录。
     pub fn list_direct_dirs(&self) -> Result<Vec<PathBuf>> {
         let entries = PathAccess::new(&self.root).read_dir_safe()?;
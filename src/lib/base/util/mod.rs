@@ -3,6 +3,7 @@
 //! 本模块提供了各种工具函数和实用工具，包括：
 //! - 日志输出（带颜色的日志宏）
 //! - 字符串处理（敏感值隐藏）
+//! - 文本中敏感信息的隐藏（`SensitiveRegistry`）
 //! - 浏览器和剪贴板操作
 //! - 文件解压和校验和验证
 //! - 重试机制
@@ -10,11 +11,14 @@
 //! ## 模块结构
 //!
 //! - `string` - 字符串处理工具
+//! - `sensitive` - 敏感信息注册表（隐藏文本中的密钥和 token）
 //! - `platform` - 平台检测工具（操作系统和架构检测）
 //! - `browser` - 浏览器操作（`Browser`）
 //! - `clipboard` - 剪贴板操作（`Clipboard`）
 //! - `unzip` - 解压工具（tar.gz 文件解压）
 //! - `checksum` - 校验和工具（SHA256 计算和验证）
+//! - `diff` - 文本行级差异（修改文件前预览变更）
+//! - `version` - 版本和构建信息（`VersionInfo`）
 //!
 //! 注意：以下模块已迁移到独立的目录：
 //! - `lib/base/logger` - 日志相关功能（`LogLevel`、`Logger`、`Tracer`、`colors`）
@@ -30,18 +34,24 @@ pub mod browser;
 pub mod checksum;
 pub mod clipboard;
 pub mod date;
+pub mod diff;
 pub mod directory;
 pub mod file;
 pub mod path;
 pub mod platform;
+pub mod sensitive;
 pub mod string;
 pub mod unzip;
+pub mod version;
 
 // 重新导出 string 模块的函数，保持向后兼容
 pub use string::mask_sensitive_value;
 
+// 重新导出 sensitive
+pub use sensitive::SensitiveRegistry;
+
 // 重新导出 platform 模块的结构体和函数
-pub use platform::{detect_release_platform, Platform};
+pub use platform::{detect_release_platform, is_wsl_kernel, Platform};
 
 // 重新导出 browser 和 clipboard
 pub use browser::Browser;
@@ -53,6 +63,9 @@ pub use unzip::Unzip;
 // 重新导出 checksum
 pub use checksum::Checksum;
 
+// 重新导出 version
+pub use version::VersionInfo;
+
 // 重新导出 date
 pub use date::{
     format_document_timestamp, format_last_updated, format_last_updated_with_time, DateFormat,
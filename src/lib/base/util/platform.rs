@@ -1,6 +1,7 @@
 //! 平台检测工具模块
 //!
-//! 提供平台检测相关的工具函数，用于识别当前运行的操作系统和架构。
+//! 提供平台检测相关的工具函数，用于识别当前运行的操作系统和架构，
+//! 以及是否运行在 WSL（Windows Subsystem for Linux）中。
 
 use color_eyre::Result;
 use std::env;
@@ -8,6 +9,9 @@ use std::process::Command;
 
 use crate::base::util::file::FileReader;
 
+/// 内核版本信息文件（用于检测 WSL）
+const PROC_VERSION_PATH: &str = "/proc/version";
+
 /// 平台信息结构体
 ///
 /// 封装操作系统和架构信息，提供平台检测和标识符生成功能。
@@ -94,6 +98,16 @@ impl Platform {
         self.os == "windows"
     }
 
+    /// 检查是否运行在 WSL 中
+    ///
+    /// 只在 Linux 平台上检测：读取 `/proc/version`，WSL 的内核版本中包含 `Microsoft` 或 `WSL`。
+    pub fn is_wsl(&self) -> bool {
+        self.is_linux()
+            && FileReader::new(PROC_VERSION_PATH)
+                .to_string()
+                .is_ok_and(|version| is_wsl_kernel(&version))
+    }
+
     /// 检查是否为 x86_64 架构
     pub fn is_x86_64(&self) -> bool {
         self.arch == "x86_64"
@@ -194,6 +208,27 @@ impl Platform {
     }
 }
 
+/// 根据内核版本信息判断是否为 WSL
+///
+/// WSL 1 的内核版本形如 `4.4.0-19041-Microsoft`，WSL 2 形如 `5.15.90.1-microsoft-standard-WSL2`。
+///
+/// # 参数
+///
+/// * `proc_version` - `/proc/version` 的内容
+///
+/// # 示例
+///
+/// ```rust
+/// use workflow::base::util::platform::is_wsl_kernel;
+///
+/// assert!(is_wsl_kernel("Linux version 5.15.90.1-microsoft-standard-WSL2 (gcc 11.2.0)"));
+/// assert!(!is_wsl_kernel("Linux version 6.5.0-14-generic (buildd@lcy02-amd64-031)"));
+/// ```
+pub fn is_wsl_kernel(proc_version: &str) -> bool {
+    let version = proc_version.to_lowercase();
+    version.contains("microsoft") || version.contains("wsl")
+}
+
 /// 检测当前平台并返回 GitHub Releases 格式的平台标识符
 ///
 /// 这是一个便捷函数，等价于 `Platform::detect().release_identifier()`。
@@ -1,11 +1,13 @@
 //! 解压工具模块
-//! 提供 tar.gz 和 zip 文件解压功能
+//! 提供 tar.gz 和 zip 文件解压功能，以及 tar.gz 文件打包功能
 
 use color_eyre::{eyre::WrapErr, Result};
 use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use std::fs::File;
 use std::path::Path;
-use tar::Archive;
+use tar::{Archive, Builder};
 use zip::ZipArchive;
 
 use crate::base::util::directory::DirectoryWalker;
@@ -61,6 +63,63 @@ impl Unzip {
         Ok(())
     }
 
+    /// 创建 tar.gz 文件
+    ///
+    /// 将 `base_dir` 下的指定文件打包并压缩为 tar.gz 文件，归档内使用相对于 `base_dir` 的文件名。
+    ///
+    /// # 参数
+    ///
+    /// * `tar_gz_path` - 要创建的 tar.gz 文件路径
+    /// * `base_dir` - 待打包文件所在目录
+    /// * `file_names` - 待打包的文件名列表（相对于 `base_dir`）
+    ///
+    /// # 返回
+    ///
+    /// 如果打包成功，返回 `Ok(())`，否则返回相应的错误信息。
+    ///
+    /// # 示例
+    ///
+    /// ```no_run
+    /// use workflow::base::util::unzip::Unzip;
+    /// use std::path::Path;
+    ///
+    /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// Unzip::create_tar_gz(
+    ///     Path::new("./backup/backup.tar.gz"),
+    ///     Path::new("./backup"),
+    ///     &["workflow", "workflow.bash"],
+    /// )?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_tar_gz(tar_gz_path: &Path, base_dir: &Path, file_names: &[&str]) -> Result<()> {
+        if let Some(parent) = tar_gz_path.parent() {
+            DirectoryWalker::new(parent).ensure_exists()?;
+        }
+
+        let file = File::create(tar_gz_path)
+            .wrap_err_with(|| format!("Failed to create tar.gz file: {}", tar_gz_path.display()))?;
+
+        // 创建 Gzip 编码器
+        let encoder = GzEncoder::new(file, Compression::default());
+        let mut builder = Builder::new(encoder);
+
+        for file_name in file_names {
+            let source = base_dir.join(file_name);
+            builder
+                .append_path_with_name(&source, file_name)
+                .wrap_err_with(|| format!("Failed to add file to archive: {}", source.display()))?;
+        }
+
+        // 完成归档并刷新 Gzip 数据
+        builder
+            .into_inner()
+            .and_then(|encoder| encoder.finish())
+            .wrap_err("Failed to finish tar.gz archive")?;
+
+        Ok(())
+    }
+
     /// 解压 zip 文件
     ///
     /// 将 zip 文件解压到指定目录。
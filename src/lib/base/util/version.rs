@@ -0,0 +1,70 @@
+//! 版本信息模块
+//!
+//! 提供 Workflow CLI 的版本和构建信息。构建信息由构建脚本（`build.rs`）在编译时注入，
+//! 不在 Git 仓库中构建等情况下无法获取的字段为 `None`。
+
+use color_eyre::{eyre::WrapErr, Result};
+use serde::Serialize;
+use std::fmt;
+
+/// 版本和构建信息
+///
+/// 用于 `workflow version` 的输出（包括 `--json`），以及问题反馈时提供准确的构建来源。
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct VersionInfo {
+    /// 语义化版本号（如 `1.6.9`）
+    pub version: &'static str,
+    /// 构建时的 Git commit hash
+    pub git_commit: Option<&'static str>,
+    /// 构建日期（UTC，`YYYY-MM-DD`）
+    pub build_date: Option<&'static str>,
+    /// 编译使用的 rustc 版本
+    pub rustc_version: Option<&'static str>,
+    /// 目标平台三元组（如 `aarch64-apple-darwin`）
+    pub target: Option<&'static str>,
+}
+
+impl VersionInfo {
+    /// 获取当前二进制文件的版本信息
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::base::util::VersionInfo;
+    ///
+    /// let info = VersionInfo::current();
+    /// assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    /// ```
+    pub fn current() -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION"),
+            git_commit: option_env!("WORKFLOW_GIT_COMMIT"),
+            build_date: option_env!("WORKFLOW_BUILD_DATE"),
+            rustc_version: option_env!("WORKFLOW_RUSTC_VERSION"),
+            target: option_env!("WORKFLOW_TARGET"),
+        }
+    }
+
+    /// Git commit hash 的短格式（前 7 位）
+    pub fn short_commit(&self) -> Option<&'static str> {
+        self.git_commit.map(|commit| commit.get(..7).unwrap_or(commit))
+    }
+
+    /// 转换为格式化的 JSON 字符串
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string_pretty(self).wrap_err("Failed to serialize version info")
+    }
+}
+
+/// 显示格式：`workflow v1.6.9 (abc1234 2026-01-01)`，缺少构建信息时省略括号中的对应部分
+impl fmt::Display for VersionInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "workflow v{}", self.version)?;
+        let details: Vec<&str> =
+            [self.short_commit(), self.build_date].into_iter().flatten().collect();
+        if !details.is_empty() {
+            write!(f, " ({})", details.join(" "))?;
+        }
+        Ok(())
+    }
+}
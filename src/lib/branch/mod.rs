@@ -42,7 +42,7 @@ pub mod types;
 pub use llm::BranchLLM;
 pub use naming::BranchNaming;
 pub use sync::{
-    BranchSync, BranchSyncCallbacks, BranchSyncOptions, BranchSyncResult, SourceBranchInfo,
-    SyncStrategy,
+    BranchSync, BranchSyncCallbacks, BranchSyncOptions, BranchSyncPlan, BranchSyncResult,
+    ConflictRisk, SourceBranchInfo, StackBranchResult, StackBranchStatus, SyncStrategy,
 };
 pub use types::BranchType;
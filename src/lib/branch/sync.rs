@@ -2,14 +2,27 @@
 //!
 //! This module provides the core branch synchronization functionality,
 //! which can be used by both `branch sync` and `pr sync` commands.
+//!
+//! With `BranchSyncOptions::dry_run`, only a plan is computed (strategy, commits
+//! that would be applied and conflict risk) using read-only Git operations.
+//!
+//! With `BranchSyncOptions::all`, the whole stack of dependent branches around the
+//! current branch (each based on the previous one) is rebased in order onto the
+//! updated source branch, stopping on the first conflict (see `detect_stack`).
 
-use crate::base::dialog::{ConfirmDialog, SelectDialog};
+use crate::base::dialog::{ConfirmDialog, EditorDialog, SelectDialog};
 use crate::base::indicator::Spinner;
 use crate::commands::pr::helpers::handle_stash_pop_result;
-use crate::git::{GitBranch, GitCommit, GitRepo, GitStash};
-use crate::{log_break, log_error, log_info, log_success, log_warning};
+use crate::git::{CommitInfo, GitBranch, GitCommit, GitRepo, GitStash};
+use crate::{log_break, log_error, log_info, log_message, log_success, log_warning};
 use color_eyre::{eyre::WrapErr, Result};
 
+/// dry-run 预览中最多列出的提交数量
+const MAX_PLAN_COMMITS: usize = 20;
+
+/// 共同作者 trailer 的前缀
+const CO_AUTHORED_BY: &str = "Co-authored-by:";
+
 /// 源分支信息
 #[derive(Debug, Clone)]
 pub struct SourceBranchInfo {
@@ -28,6 +41,18 @@ pub enum SyncStrategy {
     Rebase,
 }
 
+impl SyncStrategy {
+    /// 策略的可读描述
+    pub fn description(&self) -> &'static str {
+        match self {
+            SyncStrategy::Merge(crate::MergeStrategy::Merge) => "merge",
+            SyncStrategy::Merge(crate::MergeStrategy::Squash) => "squash merge",
+            SyncStrategy::Merge(crate::MergeStrategy::FastForwardOnly) => "fast-forward only merge",
+            SyncStrategy::Rebase => "rebase",
+        }
+    }
+}
+
 /// 同步选项
 #[derive(Debug, Clone)]
 pub struct BranchSyncOptions {
@@ -39,6 +64,14 @@ pub struct BranchSyncOptions {
     pub ff_only: bool,
     /// 是否使用 squash
     pub squash: bool,
+    /// 是否只预览同步计划（不修改工作区、不 fetch、不推送）
+    pub dry_run: bool,
+    /// squash 提交的消息（未提供时打开编辑器，以被 squash 的提交消息作为初始内容）
+    pub message: Option<String>,
+    /// 同步后不推送（在本地同步完成后停止，不执行推送和后续回调）
+    pub no_push: bool,
+    /// 同步当前分支所在的整个分支栈（按顺序 rebase 到源分支之上，忽略 merge 相关选项）
+    pub all: bool,
 }
 
 /// 同步结果
@@ -52,8 +85,439 @@ pub struct BranchSyncResult {
     pub strategy: SyncStrategy,
     /// 是否执行了 stash
     pub has_stashed: bool,
-    /// 同步是否成功
+    /// 同步是否成功（dry-run 时为 `false`）
     pub success: bool,
+    /// 是否为 dry-run（只预览，未执行同步）
+    pub dry_run: bool,
+    /// 分支栈中各分支的同步结果（按 rebase 顺序；只在 `BranchSyncOptions::all` 时非空）
+    pub stack: Vec<StackBranchResult>,
+}
+
+/// 分支栈中单个分支的同步状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackBranchStatus {
+    /// 已 rebase 到新的基础之上
+    Rebased,
+    /// 已经基于最新的基础，无需 rebase
+    UpToDate,
+    /// rebase 时产生冲突（同步在此停止，rebase 仍在进行中）
+    Conflict,
+    /// 尚未同步（dry-run，或前面的分支产生了冲突）
+    Pending,
+}
+
+impl StackBranchStatus {
+    /// 状态的可读描述
+    pub fn description(&self) -> &'static str {
+        match self {
+            StackBranchStatus::Rebased => "rebased",
+            StackBranchStatus::UpToDate => "up to date",
+            StackBranchStatus::Conflict => "conflict",
+            StackBranchStatus::Pending => "pending",
+        }
+    }
+}
+
+/// 分支栈中单个分支的同步结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackBranchResult {
+    /// 分支名称
+    pub branch: String,
+    /// rebase 的目标（源分支，或分支栈中的前一个分支）
+    pub onto: String,
+    /// 同步前分支的最新提交（用于手动继续同步：`git rebase --onto <onto> <sha> <branch>`）
+    pub original_sha: String,
+    /// 同步状态
+    pub status: StackBranchStatus,
+}
+
+/// 冲突风险（基于 merge-base 分析）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConflictRisk {
+    /// 只有一边有改动（如可以 fast-forward），不会冲突
+    None,
+    /// 两边都有改动，但修改的文件没有重叠
+    Low,
+    /// 两边修改了相同的文件，可能产生冲突
+    Likely(Vec<String>),
+}
+
+/// 根据两边自 merge-base 以来修改的文件评估冲突风险
+///
+/// # 参数
+///
+/// * `local_files` - 当前分支自 merge-base 以来修改的文件
+/// * `incoming_files` - 源分支自 merge-base 以来修改的文件
+///
+/// # 返回
+///
+/// 任意一边没有改动时返回 `ConflictRisk::None`；两边修改了相同的文件时返回
+/// `ConflictRisk::Likely`（文件按路径排序、去重），否则返回 `ConflictRisk::Low`。
+///
+/// # 示例
+///
+/// ```
+/// use workflow::branch::sync::{assess_conflict_risk, ConflictRisk};
+///
+/// let local = vec!["src/main.rs".to_string(), "README.md".to_string()];
+/// let incoming = vec!["src/main.rs".to_string()];
+/// assert_eq!(
+///     assess_conflict_risk(&local, &incoming),
+///     ConflictRisk::Likely(vec!["src/main.rs".to_string()])
+/// );
+/// assert_eq!(assess_conflict_risk(&local, &[]), ConflictRisk::None);
+/// ```
+pub fn assess_conflict_risk(local_files: &[String], incoming_files: &[String]) -> ConflictRisk {
+    if local_files.is_empty() || incoming_files.is_empty() {
+        return ConflictRisk::None;
+    }
+
+    let mut overlapping: Vec<String> = local_files
+        .iter()
+        .filter(|file| incoming_files.contains(file))
+        .cloned()
+        .collect();
+    overlapping.sort();
+    overlapping.dedup();
+
+    if overlapping.is_empty() {
+        ConflictRisk::Low
+    } else {
+        ConflictRisk::Likely(overlapping)
+    }
+}
+
+/// 确定需要同步的分支栈
+///
+/// 分支栈由当前分支、它所基于的分支和基于它的分支组成，每个分支都基于前一个分支；
+/// 已经包含在源分支中的分支（已合并）不属于分支栈。
+///
+/// # 参数
+///
+/// * `base` - 源分支（分支栈将被 rebase 到它之上）
+/// * `current` - 当前分支
+/// * `branches` - 所有本地分支
+/// * `is_ancestor` - `is_ancestor(ancestor, descendant)`：`ancestor` 的最新提交是否为
+///   `descendant` 的祖先
+///
+/// # 返回
+///
+/// 返回按 rebase 顺序排列的分支（最靠近源分支的在前）。
+///
+/// # 错误
+///
+/// 当前分支已包含在源分支中，或相关分支不是一条链（有分叉，或多个分支指向同一个提交）时返回错误。
+///
+/// # 示例
+///
+/// ```
+/// use workflow::branch::sync::detect_stack;
+///
+/// // main <- part-1 <- part-2 <- part-3，old 已合并到 main
+/// let order = ["old", "part-1", "part-2", "part-3"];
+/// let is_ancestor = |a: &str, b: &str| {
+///     let rank = |name: &str| order.iter().position(|n| *n == name);
+///     match (a, b) {
+///         ("old", _) => true,
+///         (_, "main") => false,
+///         _ => rank(a) < rank(b),
+///     }
+/// };
+/// let branches: Vec<String> =
+///     ["main", "old", "part-3", "part-1", "part-2"].iter().map(|b| b.to_string()).collect();
+///
+/// let stack = detect_stack("main", "part-2", &branches, is_ancestor).unwrap();
+/// assert_eq!(stack, vec!["part-1", "part-2", "part-3"]);
+/// ```
+pub fn detect_stack(
+    base: &str,
+    current: &str,
+    branches: &[String],
+    is_ancestor: impl Fn(&str, &str) -> bool,
+) -> Result<Vec<String>> {
+    let related: Vec<&str> = branches
+        .iter()
+        .map(String::as_str)
+        .filter(|branch| *branch != base)
+        .filter(|branch| {
+            *branch == current || is_ancestor(branch, current) || is_ancestor(current, branch)
+        })
+        .filter(|branch| !is_ancestor(branch, base))
+        .collect();
+
+    if !related.contains(&current) {
+        color_eyre::eyre::bail!(
+            "Current branch '{}' is already contained in '{}', there is no stack to sync",
+            current,
+            base
+        );
+    }
+
+    // 每个分支在分支栈中的位置 = 分支栈中是它祖先的分支数量；
+    // 位置恰好为 0..n 时，每个分支都基于前一个分支
+    let mut ranked: Vec<(usize, &str)> = related
+        .iter()
+        .map(|branch| {
+            let depth = related
+                .iter()
+                .filter(|other| *other != branch && is_ancestor(other, branch))
+                .count();
+            (depth, *branch)
+        })
+        .collect();
+    ranked.sort();
+
+    if ranked.iter().enumerate().any(|(index, (depth, _))| *depth != index) {
+        let names: Vec<&str> = ranked.iter().map(|(_, branch)| *branch).collect();
+        color_eyre::eyre::bail!(
+            "Branches related to '{}' do not form a single chain: {}. Sync them one at a time instead",
+            current,
+            names.join(", ")
+        );
+    }
+
+    Ok(ranked.into_iter().map(|(_, branch)| branch.to_string()).collect())
+}
+
+/// 判断一行是否为共同作者 trailer（`Co-authored-by: ...`，不区分大小写）
+fn co_author_value(line: &str) -> Option<&str> {
+    let line = line.trim();
+    let prefix = line.get(..CO_AUTHORED_BY.len())?;
+    prefix
+        .eq_ignore_ascii_case(CO_AUTHORED_BY)
+        .then(|| line[CO_AUTHORED_BY.len()..].trim())
+        .filter(|value| !value.is_empty())
+}
+
+/// 去掉消息中的共同作者 trailer（并去除首尾空白）
+fn strip_co_authors(message: &str) -> String {
+    message
+        .lines()
+        .filter(|line| co_author_value(line).is_none())
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// 从提交消息中收集共同作者 trailer
+///
+/// 按出现顺序返回去重后的 `Co-authored-by: Name <email>` 行（忽略大小写和多余空白）。
+///
+/// # 参数
+///
+/// * `messages` - 提交消息列表
+///
+/// # 示例
+///
+/// ```
+/// use workflow::branch::sync::collect_co_authors;
+///
+/// let messages = [
+///     "Fix login\n\nCo-authored-by: Alice <alice@example.com>",
+///     "Add test\n\nco-authored-by: alice <ALICE@example.com>\nCo-authored-by: Bob <bob@example.com>",
+/// ];
+/// assert_eq!(
+///     collect_co_authors(&messages),
+///     vec![
+///         "Co-authored-by: Alice <alice@example.com>".to_string(),
+///         "Co-authored-by: Bob <bob@example.com>".to_string(),
+///     ]
+/// );
+/// ```
+pub fn collect_co_authors<S: AsRef<str>>(messages: &[S]) -> Vec<String> {
+    let mut seen = Vec::new();
+    let mut co_authors = Vec::new();
+    for line in messages.iter().flat_map(|message| message.as_ref().lines()) {
+        if let Some(value) = co_author_value(line) {
+            let key = value.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+            if !seen.contains(&key) {
+                seen.push(key);
+                co_authors.push(format!("{} {}", CO_AUTHORED_BY, value));
+            }
+        }
+    }
+    co_authors
+}
+
+/// 生成 squash 提交消息
+///
+/// 去掉消息中已有的共同作者 trailer，在末尾统一追加去重后的 trailer
+/// （消息中的和 `co_authors` 中的）。
+///
+/// # 参数
+///
+/// * `message` - 用户提供的提交消息
+/// * `co_authors` - 从被 squash 的提交中收集的共同作者 trailer（见 `collect_co_authors`）
+pub fn build_squash_message(message: &str, co_authors: &[String]) -> String {
+    let body = strip_co_authors(message);
+
+    let mut sources = vec![message.to_string()];
+    sources.extend(co_authors.iter().cloned());
+    let trailers = collect_co_authors(&sources);
+
+    if trailers.is_empty() {
+        body
+    } else {
+        format!("{}\n\n{}", body, trailers.join("\n"))
+    }
+}
+
+/// 生成编辑器中 squash 提交消息的初始内容
+///
+/// 第一行为默认标题，之后依次列出被 squash 的提交消息（去掉共同作者 trailer），
+/// 末尾为去重后的共同作者 trailer。
+///
+/// # 参数
+///
+/// * `source_branch` - 被 squash 合并的源分支
+/// * `messages` - 被 squash 的提交消息（从旧到新）
+pub fn squash_message_template(source_branch: &str, messages: &[String]) -> String {
+    let mut template = format!("Squash merge branch '{}'", source_branch);
+    for message in messages {
+        template.push_str("\n\n* ");
+        template.push_str(&strip_co_authors(message));
+    }
+    build_squash_message(&template, &collect_co_authors(messages))
+}
+
+/// 同步计划（dry-run 预览）
+#[derive(Debug, Clone)]
+pub struct BranchSyncPlan {
+    /// 当前分支名称
+    pub current_branch: String,
+    /// 源分支名称
+    pub source_branch: String,
+    /// 源分支信息
+    pub source_branch_info: SourceBranchInfo,
+    /// 将使用的同步策略
+    pub strategy: SyncStrategy,
+    /// 当前分支和源分支的共同祖先
+    pub merge_base: String,
+    /// 源分支上有、当前分支上没有的提交（从旧到新）
+    pub incoming_commits: Vec<CommitInfo>,
+    /// 当前分支自 merge-base 以来的提交（从旧到新）
+    pub local_commits: Vec<CommitInfo>,
+    /// 冲突风险
+    pub conflict_risk: ConflictRisk,
+    /// 工作区是否有未提交的更改（同步前需要 stash）
+    pub has_uncommitted: bool,
+}
+
+impl BranchSyncPlan {
+    /// 将被应用的提交
+    ///
+    /// merge 时为源分支上的新提交；rebase 时为当前分支上将被重新应用到源分支之上的提交。
+    pub fn applied_commits(&self) -> &[CommitInfo] {
+        match self.strategy {
+            SyncStrategy::Merge(_) => &self.incoming_commits,
+            SyncStrategy::Rebase => &self.local_commits,
+        }
+    }
+
+    /// 当前分支是否已包含源分支的所有提交（无需同步）
+    pub fn is_up_to_date(&self) -> bool {
+        self.incoming_commits.is_empty()
+    }
+
+    /// 是否可以 fast-forward（当前分支自 merge-base 以来没有新提交）
+    pub fn can_fast_forward(&self) -> bool {
+        self.local_commits.is_empty()
+    }
+
+    /// 输出同步计划
+    pub fn report(&self) {
+        log_break!();
+        log_info!(
+            "Sync plan: {} '{}' into '{}'",
+            self.strategy.description(),
+            self.source_branch_info.merge_ref,
+            self.current_branch
+        );
+        log_message!("  Merge base: {}", short_sha(&self.merge_base));
+        log_message!(
+            "  Incoming commits: {}, local commits since merge base: {}",
+            self.incoming_commits.len(),
+            self.local_commits.len()
+        );
+
+        if self.source_branch_info.is_remote {
+            log_info!(
+                "Source branch '{}' only exists on remote; using the last fetched state (run 'git fetch' to refresh)",
+                self.source_branch
+            );
+        }
+        if self.has_uncommitted {
+            log_warning!(
+                "Working directory has uncommitted changes; they would be stashed before sync"
+            );
+        }
+
+        if self.is_up_to_date() {
+            log_success!(
+                "'{}' is already up to date with '{}', nothing to sync",
+                self.current_branch,
+                self.source_branch_info.merge_ref
+            );
+            return;
+        }
+
+        if matches!(
+            self.strategy,
+            SyncStrategy::Merge(crate::MergeStrategy::FastForwardOnly)
+        ) && !self.can_fast_forward()
+        {
+            log_warning!(
+                "Fast-forward is not possible: '{}' has {} commit(s) not in '{}', the merge would fail",
+                self.current_branch,
+                self.local_commits.len(),
+                self.source_branch_info.merge_ref
+            );
+        }
+
+        let applied = self.applied_commits();
+        match self.strategy {
+            SyncStrategy::Merge(_) => {
+                log_info!("Commits that would be merged ({}):", applied.len());
+            }
+            SyncStrategy::Rebase => {
+                log_info!(
+                    "Commits that would be replayed onto '{}' ({}):",
+                    self.source_branch_info.merge_ref,
+                    applied.len()
+                );
+            }
+        }
+        for commit in applied.iter().take(MAX_PLAN_COMMITS) {
+            log_message!("  {} {}", short_sha(&commit.sha), commit.message);
+        }
+        if applied.len() > MAX_PLAN_COMMITS {
+            log_message!("  ... and {} more", applied.len() - MAX_PLAN_COMMITS);
+        }
+
+        match &self.conflict_risk {
+            ConflictRisk::None => {
+                log_success!("Conflicts: none expected");
+            }
+            ConflictRisk::Low => {
+                log_success!("Conflicts: unlikely (no file changed on both branches)");
+            }
+            ConflictRisk::Likely(files) => {
+                log_warning!(
+                    "Conflicts: likely, {} file(s) changed on both branches:",
+                    files.len()
+                );
+                for file in files {
+                    log_message!("  {}", file);
+                }
+            }
+        }
+    }
+}
+
+/// 截取提交 SHA 的前 8 位
+fn short_sha(sha: &str) -> &str {
+    &sha[..sha.len().min(8)]
 }
 
 /// 同步后回调
@@ -75,6 +539,14 @@ pub trait BranchSyncCallbacks {
     /// - `result`: 同步结果
     /// - `source_branch`: 源分支名称
     fn on_after_sync(&self, result: &BranchSyncResult, source_branch: &str) -> Result<()>;
+
+    /// dry-run 时调用，报告同步计划（代替实际同步，之后不会调用其他回调）
+    ///
+    /// 默认实现输出计划摘要（策略、将应用的提交和冲突风险）。
+    fn on_plan(&self, plan: &BranchSyncPlan) -> Result<()> {
+        plan.report();
+        Ok(())
+    }
 }
 
 /// 分支同步核心逻辑
@@ -82,10 +554,19 @@ pub struct BranchSync;
 
 impl BranchSync {
     /// 执行分支同步（核心逻辑）
+    ///
+    /// `options.all` 时同步整个分支栈（见 `sync_stack`），不调用回调。
     pub fn sync(
         options: BranchSyncOptions,
         callbacks: Option<Box<dyn BranchSyncCallbacks>>,
     ) -> Result<BranchSyncResult> {
+        if options.all {
+            return Self::sync_stack(&options);
+        }
+        if options.dry_run {
+            return Self::dry_run(&options, callbacks);
+        }
+
         // 1. 获取当前分支
         let current_branch = GitBranch::current_branch()?;
         log_success!("Current branch: {}", current_branch);
@@ -100,13 +581,30 @@ impl BranchSync {
         let strategy =
             Self::determine_sync_strategy(options.rebase, options.ff_only, options.squash);
 
-        // 5. 执行同步
+        // 5. squash 时先确定提交消息（在修改工作区之前，取消时不留下暂存的更改）
+        let squash_message = match strategy {
+            SyncStrategy::Merge(crate::MergeStrategy::Squash) => {
+                match Self::prepare_squash_message(&options, &source_branch_info) {
+                    Ok(message) => Some(message),
+                    Err(e) => {
+                        if has_stashed {
+                            handle_stash_pop_result(GitStash::stash_pop(None));
+                        }
+                        return Err(e);
+                    }
+                }
+            }
+            _ => None,
+        };
+
+        // 6. 执行同步
         let success = Self::execute_sync(
             &options.source_branch,
             &current_branch,
             &source_branch_info,
             strategy,
             has_stashed,
+            squash_message.as_deref(),
         )?;
 
         let result = BranchSyncResult {
@@ -115,22 +613,32 @@ impl BranchSync {
             strategy,
             has_stashed,
             success,
+            dry_run: false,
+            stack: Vec::new(),
         };
 
-        // 6. 调用回调处理同步后的操作
-        let skip_stash_pop = if let Some(ref callbacks) = callbacks {
-            callbacks.on_sync_success(&result, source_branch_info.is_remote)?
-        } else {
-            false
+        // 7. 调用回调处理同步后的操作（--no-push 时跳过，回调可能推送）
+        let skip_stash_pop = match callbacks {
+            Some(ref callbacks) if !options.no_push => {
+                callbacks.on_sync_success(&result, source_branch_info.is_remote)?
+            }
+            _ => false,
         };
 
-        // 7. 恢复 stash（如果需要）
+        // 8. 恢复 stash（如果需要）
         if !skip_stash_pop && has_stashed {
             log_info!("Restoring stashed changes...");
             handle_stash_pop_result(GitStash::stash_pop(None));
         }
 
-        // 8. 推送（如果当前分支在远程存在，使用 ConfirmDialog 确认）
+        // --no-push：本地同步完成后停止
+        if options.no_push {
+            log_info!("Skipping push and follow-up steps (--no-push)");
+            log_info!("You can push manually when ready: git push");
+            return Ok(result);
+        }
+
+        // 9. 推送（如果当前分支在远程存在，使用 ConfirmDialog 确认）
         // 注意：如果回调已经推送了（skip_stash_pop == true），可能已经推送过了
         // 但为了确保所有情况都覆盖，我们仍然检查并询问用户
         let current_branch_exists_remote = GitBranch::has_remote_branch(&result.current_branch)
@@ -153,7 +661,7 @@ impl BranchSync {
             }
         }
 
-        // 9. 调用清理回调
+        // 10. 调用清理回调
         if let Some(ref callbacks) = callbacks {
             callbacks.on_after_sync(&result, &options.source_branch)?;
         }
@@ -161,6 +669,286 @@ impl BranchSync {
         Ok(result)
     }
 
+    /// 计算同步计划（只读，不修改工作区）
+    ///
+    /// 只使用只读的 Git 操作：不 stash、不 fetch，源分支只在远程存在时使用本地已有的
+    /// 远程跟踪分支（`origin/<branch>`）。
+    ///
+    /// # 错误
+    ///
+    /// 源分支不存在，或当前分支和源分支没有共同祖先时返回错误。
+    pub fn plan(options: &BranchSyncOptions) -> Result<BranchSyncPlan> {
+        let current_branch = GitBranch::current_branch()?;
+        let has_uncommitted =
+            GitCommit::has_commit().wrap_err("Failed to check working directory status")?;
+
+        let source_branch_info = Self::resolve_source_branch(&options.source_branch)?;
+        let merge_ref = &source_branch_info.merge_ref;
+
+        let strategy =
+            Self::determine_sync_strategy(options.rebase, options.ff_only, options.squash);
+        let merge_base = GitBranch::merge_base("HEAD", merge_ref)?;
+        let incoming_commits = GitCommit::get_commits_in_range("HEAD", merge_ref)
+            .wrap_err("Failed to get commits from source branch")?;
+        let local_commits = GitCommit::get_commits_in_range(merge_ref, "HEAD")
+            .wrap_err("Failed to get commits from current branch")?;
+
+        let local_files = GitBranch::get_changed_files_between(&merge_base, "HEAD")?;
+        let incoming_files = GitBranch::get_changed_files_between(&merge_base, merge_ref)?;
+        let conflict_risk = assess_conflict_risk(&local_files, &incoming_files);
+
+        Ok(BranchSyncPlan {
+            current_branch,
+            source_branch: options.source_branch.clone(),
+            source_branch_info,
+            strategy,
+            merge_base,
+            incoming_commits,
+            local_commits,
+            conflict_risk,
+            has_uncommitted,
+        })
+    }
+
+    /// dry-run：计算并报告同步计划，不执行同步
+    fn dry_run(
+        options: &BranchSyncOptions,
+        callbacks: Option<Box<dyn BranchSyncCallbacks>>,
+    ) -> Result<BranchSyncResult> {
+        let plan = Self::plan(options)?;
+
+        match callbacks {
+            Some(callbacks) => callbacks.on_plan(&plan)?,
+            None => plan.report(),
+        }
+
+        Ok(BranchSyncResult {
+            current_branch: plan.current_branch,
+            source_branch_info: plan.source_branch_info,
+            strategy: plan.strategy,
+            has_stashed: false,
+            success: false,
+            dry_run: true,
+            stack: Vec::new(),
+        })
+    }
+
+    /// 同步分支栈
+    ///
+    /// 按 `detect_stack` 的顺序将每个分支 rebase 到前一个分支（第一个分支 rebase 到源分支）之上，
+    /// 只重放分支自己的提交（`git rebase --onto <前一个分支> <前一个分支原来的提交> <分支>`）。
+    /// 遇到冲突时停止：冲突的分支保持 rebase 进行中的状态，并输出手动完成同步的命令；
+    /// 此时返回的结果中 `success` 为 `false`。
+    ///
+    /// 全部成功后切换回原来的分支、恢复 stash，并询问是否推送已 rebase 的远程分支。
+    fn sync_stack(options: &BranchSyncOptions) -> Result<BranchSyncResult> {
+        let current_branch = GitBranch::current_branch()?;
+        log_success!("Current branch: {}", current_branch);
+
+        let has_stashed = if options.dry_run {
+            false
+        } else {
+            Self::check_working_directory()?
+        };
+
+        // dry-run 不 fetch，使用本地已有的远程跟踪分支
+        let source_branch_info = if options.dry_run {
+            Self::resolve_source_branch(&options.source_branch)
+        } else {
+            Self::prepare_source_branch(&options.source_branch)
+        };
+        let stack = source_branch_info.and_then(|info| {
+            // 源分支和默认分支不属于分支栈（即使本地分支落后于远程）
+            let default_branch = GitBranch::get_default_branch().ok();
+            let branches: Vec<String> = GitBranch::get_local_branches()?
+                .into_iter()
+                .filter(|branch| {
+                    *branch != options.source_branch && Some(branch) != default_branch.as_ref()
+                })
+                .collect();
+            let stack = detect_stack(&info.merge_ref, &current_branch, &branches, |a, b| {
+                GitBranch::is_branch_based_on(b, a).unwrap_or(false)
+            })?;
+            let mut results = Vec::new();
+            let mut onto = info.merge_ref.clone();
+            for branch in stack {
+                let original_sha = GitCommit::parse_commit_ref(&branch)?;
+                results.push(StackBranchResult {
+                    branch: branch.clone(),
+                    onto,
+                    original_sha,
+                    status: StackBranchStatus::Pending,
+                });
+                onto = branch;
+            }
+            Ok((info, results))
+        });
+        let (source_branch_info, mut stack) = match stack {
+            Ok(stack) => stack,
+            Err(e) => {
+                if has_stashed {
+                    handle_stash_pop_result(GitStash::stash_pop(None));
+                }
+                return Err(e);
+            }
+        };
+
+        log_info!(
+            "Stack of {} branch(es) to rebase onto '{}':",
+            stack.len(),
+            source_branch_info.merge_ref
+        );
+        for entry in &stack {
+            log_message!("  {} (onto '{}')", entry.branch, entry.onto);
+        }
+
+        let mut result = BranchSyncResult {
+            current_branch: current_branch.clone(),
+            source_branch_info,
+            strategy: SyncStrategy::Rebase,
+            has_stashed,
+            success: false,
+            dry_run: options.dry_run,
+            stack: Vec::new(),
+        };
+        if options.dry_run {
+            result.stack = stack;
+            return Ok(result);
+        }
+
+        for index in 0..stack.len() {
+            let entry = &stack[index];
+            // 第一个分支重放源分支之外的提交；之后的分支只重放前一个分支原来的提交之后的提交
+            let upstream = match index {
+                0 => entry.onto.clone(),
+                _ => stack[index - 1].original_sha.clone(),
+            };
+
+            if GitBranch::is_branch_based_on(&entry.branch, &entry.onto)? {
+                log_success!(
+                    "'{}' is already up to date with '{}'",
+                    entry.branch,
+                    entry.onto
+                );
+                stack[index].status = StackBranchStatus::UpToDate;
+                continue;
+            }
+
+            let rebase_result = Spinner::with(
+                format!("Rebasing '{}' onto '{}'...", entry.branch, entry.onto),
+                || GitBranch::rebase_onto_with_upstream(&entry.onto, &upstream, &entry.branch),
+            );
+            match rebase_result {
+                Ok(()) => {
+                    log_success!("Rebased '{}' onto '{}'", entry.branch, entry.onto);
+                    stack[index].status = StackBranchStatus::Rebased;
+                }
+                Err(e) => {
+                    if !GitBranch::is_rebase_in_progress() {
+                        return Err(e);
+                    }
+                    stack[index].status = StackBranchStatus::Conflict;
+                    Self::report_stack_conflict(&stack, index, has_stashed);
+                    result.stack = stack;
+                    return Ok(result);
+                }
+            }
+        }
+
+        // rebase --onto 会切换到被 rebase 的分支，完成后切换回原来的分支
+        GitBranch::checkout_branch(&current_branch)?;
+        if has_stashed {
+            log_info!("Restoring stashed changes...");
+            handle_stash_pop_result(GitStash::stash_pop(None));
+        }
+        result.success = true;
+        result.stack = stack;
+
+        if options.no_push {
+            log_info!("Skipping push (--no-push)");
+            log_info!(
+                "You can push each branch manually: git push --force-with-lease origin <branch>"
+            );
+            return Ok(result);
+        }
+        Self::prompt_and_push_stack(&result.stack)?;
+
+        Ok(result)
+    }
+
+    /// 输出分支栈冲突时的状态和手动完成同步的命令
+    fn report_stack_conflict(stack: &[StackBranchResult], index: usize, has_stashed: bool) {
+        let entry = &stack[index];
+        log_error!(
+            "Rebase conflicts detected while rebasing '{}' onto '{}'!",
+            entry.branch,
+            entry.onto
+        );
+        for done in &stack[..index] {
+            log_message!("  {}: {}", done.branch, done.status.description());
+        }
+        log_message!("  {}: rebase in progress", entry.branch);
+        for pending in &stack[index + 1..] {
+            log_message!("  {}: not synced", pending.branch);
+        }
+
+        log_info!("To finish syncing the stack:");
+        log_info!("  1. Resolve conflicts and stage resolved files: git add <files>");
+        log_info!("  2. Continue the rebase: git rebase --continue");
+        for (step, pending) in stack[index + 1..].iter().enumerate() {
+            log_info!(
+                "  {}. git rebase --onto {} {} {}",
+                step + 3,
+                pending.onto,
+                stack[index + step].original_sha,
+                pending.branch
+            );
+        }
+        log_info!("Or abort the current rebase: git rebase --abort");
+        if has_stashed {
+            log_info!("Your uncommitted changes are stashed; restore them with: git stash pop");
+        }
+    }
+
+    /// 询问用户并推送已 rebase 的分支（只推送远程已存在的分支）
+    fn prompt_and_push_stack(stack: &[StackBranchResult]) -> Result<()> {
+        let mut branches = Vec::new();
+        for entry in stack.iter().filter(|entry| entry.status == StackBranchStatus::Rebased) {
+            if GitBranch::has_remote_branch(&entry.branch)
+                .wrap_err("Failed to check if branch exists on remote")?
+            {
+                branches.push(entry.branch.as_str());
+            }
+        }
+        if branches.is_empty() {
+            log_info!("No rebased branch exists on remote, nothing to push");
+            return Ok(());
+        }
+
+        let should_push = ConfirmDialog::new(format!(
+            "Push {} rebased branch(es) to remote (force-with-lease): {}?",
+            branches.len(),
+            branches.join(", ")
+        ))
+        .with_default(true)
+        .with_cancel_message("Push cancelled by user")
+        .prompt()?;
+        if !should_push {
+            log_info!("Skipping push as requested by user");
+            log_info!(
+                "You can push each branch manually: git push --force-with-lease origin <branch>"
+            );
+            return Ok(());
+        }
+
+        for branch in branches {
+            GitBranch::push_force_with_lease(branch)
+                .wrap_err_with(|| format!("Failed to push '{}' (force-with-lease)", branch))?;
+            log_success!("Pushed '{}'", branch);
+        }
+        Ok(())
+    }
+
     /// 检查工作区状态
     ///
     /// 检查是否有未提交的更改，如果有则提示用户处理。
@@ -202,6 +990,28 @@ impl BranchSync {
         }
     }
 
+    /// 确定源分支的引用（只读，不 fetch）
+    ///
+    /// 源分支只在远程存在时使用本地已有的远程跟踪分支（`origin/<branch>`）。
+    fn resolve_source_branch(source_branch: &str) -> Result<SourceBranchInfo> {
+        let (exists_local, exists_remote) = GitBranch::is_branch_exists(source_branch)
+            .wrap_err("Failed to check if source branch exists")?;
+        if !exists_local && !exists_remote {
+            color_eyre::eyre::bail!(
+                "Source branch '{}' does not exist locally or remotely",
+                source_branch
+            );
+        }
+        Ok(SourceBranchInfo {
+            is_remote: !exists_local,
+            merge_ref: if exists_local {
+                source_branch.to_string()
+            } else {
+                format!("origin/{}", source_branch)
+            },
+        })
+    }
+
     /// 验证并准备源分支
     ///
     /// 检查要合并到当前分支的源分支是否存在（本地或远程），如果只在远程则先 fetch。
@@ -254,6 +1064,33 @@ impl BranchSync {
         }
     }
 
+    /// 确定 squash 提交消息
+    ///
+    /// 收集被 squash 的提交中的共同作者 trailer；提供了 `options.message` 时直接使用，
+    /// 否则打开编辑器，以被 squash 的提交消息作为初始内容。
+    fn prepare_squash_message(
+        options: &BranchSyncOptions,
+        source_branch_info: &SourceBranchInfo,
+    ) -> Result<String> {
+        let messages =
+            GitCommit::get_commit_messages_in_range("HEAD", &source_branch_info.merge_ref)
+                .wrap_err("Failed to get commits to squash")?;
+        let co_authors = collect_co_authors(&messages);
+
+        let message = match &options.message {
+            Some(message) => message.clone(),
+            None => EditorDialog::new()
+                .with_initial(squash_message_template(&options.source_branch, &messages))
+                .prompt()
+                .wrap_err("Failed to get squash commit message")?,
+        };
+
+        if strip_co_authors(&message).is_empty() {
+            color_eyre::eyre::bail!("Squash commit message is empty");
+        }
+        Ok(build_squash_message(&message, &co_authors))
+    }
+
     /// 执行同步操作
     fn execute_sync(
         source_branch: &str,
@@ -261,6 +1098,7 @@ impl BranchSync {
         source_branch_info: &SourceBranchInfo,
         strategy: SyncStrategy,
         has_stashed: bool,
+        squash_message: Option<&str>,
     ) -> Result<bool> {
         match strategy {
             SyncStrategy::Merge(merge_strategy) => {
@@ -277,6 +1115,14 @@ impl BranchSync {
 
                 match merge_result {
                     Ok(()) => {
+                        // squash 合并只暂存更改，需要使用 squash 消息提交
+                        if let Some(message) = squash_message {
+                            let commit_result = GitCommit::commit_staged(message)
+                                .wrap_err("Failed to commit squashed changes")?;
+                            if !commit_result.committed {
+                                log_info!("Nothing to commit after squash merge");
+                            }
+                        }
                         log_success!("Merge completed successfully");
                         Ok(true)
                     }
@@ -5,7 +5,7 @@
 use crate::base::dialog::SelectDialog;
 use crate::log_info;
 use crate::repo::config::RepoConfig;
-use color_eyre::{eyre::eyre, eyre::WrapErr, Result};
+use color_eyre::{eyre::WrapErr, Result};
 use std::fmt;
 
 /// Branch type enumeration
@@ -92,20 +92,13 @@ impl BranchType {
 
     /// Prompt user to select branch type interactively
     pub fn prompt_selection() -> Result<Self> {
-        let options: Vec<BranchType> = Self::all();
-        let display_options: Vec<String> =
-            options.iter().map(|ty| ty.display_name().to_string()).collect();
+        let items: Vec<(String, BranchType)> =
+            Self::all().into_iter().map(|ty| (ty.display_name().to_string(), ty)).collect();
 
-        let selected = SelectDialog::new("选择分支类型 (Select branch type)", display_options)
+        SelectDialog::new_with_items("选择分支类型 (Select branch type)", items)
             .with_default(0) // Default to feature
             .prompt()
-            .wrap_err("Failed to select branch type")?;
-
-        // Find the corresponding BranchType
-        options
-            .into_iter()
-            .find(|ty| ty.display_name() == selected)
-            .ok_or_else(|| eyre!("Invalid branch type selection"))
+            .wrap_err("Failed to select branch type")
     }
 
     /// Resolve branch type with repository prefix fallback
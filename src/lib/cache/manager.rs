@@ -0,0 +1,302 @@
+//! 缓存管理器
+//!
+//! 汇总 Workflow CLI 在本地累积的各类缓存（日志文件、Jira 下载内容、LLM 文件修改总结、Jira 用户信息），
+//! 计算每个缓存的位置和大小，并按缓存类型、修改时间选择性清理。
+//!
+//! 清理分为两步：`plan` 只读取缓存，计算将要删除的内容；`remove` 删除 `plan` 返回的内容。
+//! 调用方可以在两步之间展示预览并确认。
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use color_eyre::{eyre::WrapErr, Result};
+use walkdir::WalkDir;
+
+use crate::base::settings::paths::Paths;
+use crate::base::util::directory::DirectoryWalker;
+use crate::jira::attachments::AttachmentPaths;
+use crate::jira::config::{ConfigManager, JiraConfig};
+use crate::pr::llm::FileSummaryCache;
+
+/// 缓存类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CacheKind {
+    /// Workflow CLI 日志文件（`~/.workflow/logs/`）
+    Logs,
+    /// 下载的 Jira 附件和日志（`{download_base_dir}/jira/`）
+    JiraDownloads,
+    /// LLM 文件修改总结缓存（`~/.workflow/cache/file-summaries/`）
+    FileSummaries,
+    /// Jira 用户信息缓存（`jira.toml` 中的 `users`）
+    JiraUsers,
+}
+
+impl CacheKind {
+    /// 所有缓存类型
+    pub fn all() -> impl Iterator<Item = Self> {
+        [
+            Self::Logs,
+            Self::JiraDownloads,
+            Self::FileSummaries,
+            Self::JiraUsers,
+        ]
+        .into_iter()
+    }
+
+    /// 缓存名称（用于命令行参数和显示）
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Logs => "logs",
+            Self::JiraDownloads => "jira-downloads",
+            Self::FileSummaries => "file-summaries",
+            Self::JiraUsers => "jira-users",
+        }
+    }
+
+    /// 缓存描述
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Logs => "Workflow CLI log files",
+            Self::JiraDownloads => "Downloaded Jira attachments and logs",
+            Self::FileSummaries => "LLM file summaries (pr summarize)",
+            Self::JiraUsers => "Jira user info (jira.toml)",
+        }
+    }
+
+    /// 缓存是否为目录（Jira 用户信息保存在 `jira.toml` 中，不是目录）
+    pub fn is_dir(&self) -> bool {
+        !matches!(self, Self::JiraUsers)
+    }
+
+    /// 缓存位置
+    ///
+    /// 目录类型的缓存返回目录路径，Jira 用户信息返回 `jira.toml` 的路径。
+    ///
+    /// # 错误
+    ///
+    /// 如果无法确定路径，返回相应的错误信息。
+    pub fn path(&self) -> Result<PathBuf> {
+        match self {
+            Self::Logs => Paths::logs_dir(),
+            Self::JiraDownloads => AttachmentPaths::jira_base_dir(),
+            Self::FileSummaries => Ok(FileSummaryCache::open()?.dir().to_path_buf()),
+            Self::JiraUsers => Paths::jira_config(),
+        }
+    }
+}
+
+/// 缓存信息
+#[derive(Debug, Clone)]
+pub struct CacheInfo {
+    /// 缓存类型
+    pub kind: CacheKind,
+    /// 缓存位置
+    pub path: PathBuf,
+    /// 缓存大小（字节，Jira 用户信息为 `None`）
+    pub size: Option<u64>,
+    /// 条目数量（目录为文件数量，Jira 用户信息为用户数量）
+    pub entries: usize,
+}
+
+/// 缓存清理计划
+#[derive(Debug, Clone)]
+pub struct CachePlan {
+    /// 缓存类型
+    pub kind: CacheKind,
+    /// 缓存位置
+    pub path: PathBuf,
+    /// 将要删除的文件（Jira 用户信息为空）
+    pub files: Vec<PathBuf>,
+    /// 可释放的空间（字节）
+    pub size: u64,
+    /// 将要删除的条目数量（目录为文件数量，Jira 用户信息为用户数量）
+    pub entries: usize,
+}
+
+impl CachePlan {
+    /// 是否没有需要删除的内容
+    pub fn is_empty(&self) -> bool {
+        self.entries == 0
+    }
+}
+
+/// 缓存管理器
+///
+/// 提供所有缓存的列表、清理预览和清理功能。
+pub struct CacheManager;
+
+impl CacheManager {
+    /// 获取所有缓存的信息（按 `CacheKind::all()` 的顺序）
+    ///
+    /// # 错误
+    ///
+    /// 如果无法确定缓存位置或读取缓存失败，返回相应的错误信息。
+    pub fn list() -> Result<Vec<CacheInfo>> {
+        CacheKind::all().map(Self::info).collect()
+    }
+
+    /// 获取指定缓存的信息
+    ///
+    /// # 错误
+    ///
+    /// 如果无法确定缓存位置或读取缓存失败，返回相应的错误信息。
+    pub fn info(kind: CacheKind) -> Result<CacheInfo> {
+        let path = kind.path()?;
+        if !kind.is_dir() {
+            return Ok(CacheInfo {
+                kind,
+                entries: Self::jira_users_count(&path)?,
+                path,
+                size: None,
+            });
+        }
+
+        let (size, entries) = DirectoryWalker::new(&path).calculate_dir_info()?;
+        Ok(CacheInfo {
+            kind,
+            path,
+            size: Some(size),
+            entries,
+        })
+    }
+
+    /// 计算清理指定缓存时将要删除的内容
+    ///
+    /// 只读取缓存，不会删除任何内容。`older_than` 不为空时只选择修改时间早于该时长的文件；
+    /// Jira 用户信息没有修改时间，指定 `older_than` 时不会被清理。
+    ///
+    /// # 参数
+    ///
+    /// * `kind` - 缓存类型
+    /// * `older_than` - 只清理修改时间早于该时长的文件（`None` 表示全部清理）
+    ///
+    /// # 错误
+    ///
+    /// 如果无法确定缓存位置或读取缓存失败，返回相应的错误信息。
+    pub fn plan(kind: CacheKind, older_than: Option<Duration>) -> Result<CachePlan> {
+        let path = kind.path()?;
+        if kind.is_dir() {
+            return Self::plan_dir(kind, &path, older_than);
+        }
+
+        let entries = match older_than {
+            Some(_) => 0,
+            None => Self::jira_users_count(&path)?,
+        };
+        Ok(CachePlan {
+            kind,
+            path,
+            files: Vec::new(),
+            size: 0,
+            entries,
+        })
+    }
+
+    /// 计算清理缓存目录时将要删除的文件
+    ///
+    /// 递归选择目录下的所有文件，`older_than` 不为空时只选择修改时间早于该时长的文件。
+    /// 目录不存在时返回空的清理计划。
+    ///
+    /// # 参数
+    ///
+    /// * `kind` - 缓存类型
+    /// * `dir` - 缓存目录
+    /// * `older_than` - 只清理修改时间早于该时长的文件（`None` 表示全部清理）
+    ///
+    /// # 错误
+    ///
+    /// 如果读取目录失败，返回相应的错误信息。
+    pub fn plan_dir(
+        kind: CacheKind,
+        dir: &Path,
+        older_than: Option<Duration>,
+    ) -> Result<CachePlan> {
+        let cutoff = older_than.and_then(|age| SystemTime::now().checked_sub(age));
+        let mut plan = CachePlan {
+            kind,
+            path: dir.to_path_buf(),
+            files: Vec::new(),
+            size: 0,
+            entries: 0,
+        };
+
+        if !dir.exists() {
+            return Ok(plan);
+        }
+
+        for entry in WalkDir::new(dir).sort_by_file_name() {
+            let entry =
+                entry.wrap_err_with(|| format!("Failed to read directory entry: {:?}", dir))?;
+            let metadata = entry.metadata().wrap_err("Failed to get file metadata")?;
+            if !metadata.is_file() {
+                continue;
+            }
+            if let Some(cutoff) = cutoff {
+                let modified = metadata.modified().wrap_err("Failed to get file modified time")?;
+                if modified > cutoff {
+                    continue;
+                }
+            }
+
+            plan.size += metadata.len();
+            plan.entries += 1;
+            plan.files.push(entry.into_path());
+        }
+
+        Ok(plan)
+    }
+
+    /// 删除 `plan` 计算出的内容
+    ///
+    /// 目录类型的缓存逐个删除文件，然后移除清理后变为空的子目录（缓存目录本身保留）；
+    /// 已经不存在的文件会被跳过。Jira 用户信息清空 `jira.toml` 中的 `users`，其他配置不受影响。
+    ///
+    /// # 错误
+    ///
+    /// 如果删除文件或写入配置失败，返回相应的错误信息。
+    pub fn remove(plan: &CachePlan) -> Result<()> {
+        if plan.is_empty() {
+            return Ok(());
+        }
+
+        if !plan.kind.is_dir() {
+            return ConfigManager::<JiraConfig>::new(plan.path.clone())
+                .update(|config| config.users.clear());
+        }
+
+        for file in &plan.files {
+            if let Err(e) = fs::remove_file(file) {
+                if e.kind() != std::io::ErrorKind::NotFound {
+                    return Err(e).wrap_err_with(|| format!("Failed to delete file: {:?}", file));
+                }
+            }
+        }
+
+        Self::remove_empty_dirs(&plan.path)
+    }
+
+    /// 移除目录下的空子目录（不包括目录本身）
+    fn remove_empty_dirs(dir: &Path) -> Result<()> {
+        if !dir.exists() {
+            return Ok(());
+        }
+
+        for entry in WalkDir::new(dir).min_depth(1).contents_first(true) {
+            let entry =
+                entry.wrap_err_with(|| format!("Failed to read directory entry: {:?}", dir))?;
+            if entry.file_type().is_dir() {
+                // 非空目录删除失败，保留即可
+                let _ = fs::remove_dir(entry.path());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 读取 `jira.toml` 中缓存的用户数量（文件不存在时为 0）
+    fn jira_users_count(path: &Path) -> Result<usize> {
+        let config = ConfigManager::<JiraConfig>::new(path.to_path_buf()).read()?;
+        Ok(config.users.len())
+    }
+}
@@ -0,0 +1,7 @@
+//! 缓存管理模块
+//!
+//! 本模块汇总 Workflow CLI 在本地累积的各类缓存，提供统一的列表和清理功能（`workflow cache`）。
+
+mod manager;
+
+pub use manager::{CacheInfo, CacheKind, CacheManager, CachePlan};
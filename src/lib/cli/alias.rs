@@ -1,31 +1,62 @@
 //! Alias management subcommands
 //!
-//! Used to manage command aliases.
+//! Used to manage command aliases and shell aliases.
 
 use clap::Subcommand;
 
+use super::args::{DryRunArgs, TableFormatArgs};
+
 /// Alias management subcommands
 ///
-/// Used to list, add, and remove command aliases.
+/// Used to list, add, install, and remove command aliases.
 #[derive(Subcommand)]
 pub enum AliasSubcommand {
     /// List all aliases
     ///
-    /// Display all defined aliases in a table format.
-    List,
+    /// Display all defined aliases (workflow aliases and shell aliases)
+    /// with their expanded commands in a table format.
+    List {
+        #[command(flatten)]
+        output: TableFormatArgs,
+    },
     /// Add a new alias
     ///
     /// Add a new alias mapping a short name to a full command.
     /// If name and command are not provided, enter interactive mode.
+    /// Alias names must not conflict with built-in commands (or, for shell
+    /// aliases, with existing commands in PATH).
     ///
     /// Examples:
-    ///   workflow alias add ci "pr create"    # Direct mode
-    ///   workflow alias add                    # Interactive mode
+    ///   workflow alias add ci "pr create"                        # Direct mode
+    ///   workflow alias add                                       # Interactive mode
+    ///   workflow alias add --shell wf workflow                   # Shell alias
+    ///   workflow alias add --shell prc "workflow pr create"      # Shell alias
     Add {
         /// Alias name (optional, will enter interactive mode if not provided)
         name: Option<String>,
         /// Command to map to (optional, will enter interactive mode if not provided)
         command: Option<String>,
+        /// Add a shell alias to the shell config file instead of a workflow alias
+        #[arg(long)]
+        shell: bool,
+    },
+    /// Install the default shell aliases
+    ///
+    /// Write the default shell aliases (e.g. wf, wpr, wprc) into the managed
+    /// alias block of the detected shell's config file, using the shell's own
+    /// syntax (`alias` for zsh/bash, `abbr` for fish, `function` for PowerShell).
+    /// Names that conflict with existing aliases or commands are skipped with a warning.
+    /// The default set can be overridden with `shell_aliases` in commands.toml.
+    ///
+    /// Examples:
+    ///   workflow alias install                # Install all default shell aliases
+    ///   workflow alias install wpr wprc       # Install selected shell aliases
+    ///   workflow alias install --dry-run      # Preview without writing
+    Install {
+        /// Shell alias names to install (optional, installs all defaults if not provided)
+        names: Vec<String>,
+        #[command(flatten)]
+        dry_run: DryRunArgs,
     },
     /// Remove an alias
     ///
@@ -35,8 +66,16 @@ pub enum AliasSubcommand {
     /// Examples:
     ///   workflow alias remove ci              # Direct mode
     ///   workflow alias remove                 # Interactive mode
+    ///   workflow alias remove --shell prc     # Shell alias
+    ///   workflow alias remove --shell --all   # All shell aliases
     Remove {
         /// Alias name to remove (optional, will enter interactive mode if not provided)
         name: Option<String>,
+        /// Remove a shell alias from the shell config file instead of a workflow alias
+        #[arg(long)]
+        shell: bool,
+        /// Remove all shell aliases (strips the whole managed alias block)
+        #[arg(long, requires = "shell", conflicts_with = "name")]
+        all: bool,
     },
 }
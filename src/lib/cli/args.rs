@@ -4,7 +4,10 @@
 //!
 //! 使用 clap 的 `Args` trait 和 `#[command(flatten)]` 特性来实现参数复用。
 
-use clap::Args;
+use clap::{Args, ValueEnum};
+use tabled::Tabled;
+
+use crate::base::table::TableBuilder;
 
 /// 输出格式选项
 ///
@@ -29,6 +32,50 @@ pub struct OutputFormatArgs {
     pub markdown: bool,
 }
 
+/// 列表输出格式
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableFormat {
+    /// Table for the terminal (default)
+    #[default]
+    Table,
+    /// Markdown table
+    #[value(alias = "markdown")]
+    Md,
+    /// Comma-separated values
+    Csv,
+    /// Tab-separated values
+    Tsv,
+}
+
+impl TableFormat {
+    /// 是否为终端表格格式
+    ///
+    /// 其他格式只输出数据（不输出标题、提示等），便于粘贴到文档或重定向到文件。
+    pub fn is_table(&self) -> bool {
+        *self == TableFormat::Table
+    }
+
+    /// 按格式渲染表格
+    pub fn render<T: Tabled>(&self, table: TableBuilder<T>) -> String {
+        match self {
+            TableFormat::Table => table.render(),
+            TableFormat::Md => table.to_markdown(),
+            TableFormat::Csv => table.to_csv(),
+            TableFormat::Tsv => table.to_tsv(),
+        }
+    }
+}
+
+/// 列表输出格式参数
+///
+/// 用于列表命令（如 `pr list`、`stash list`、`alias list`）选择输出格式。
+#[derive(Args, Debug, Clone)]
+pub struct TableFormatArgs {
+    /// Output format (md/csv/tsv output plain data without colors, for docs and spreadsheets)
+    #[arg(long, value_enum, value_name = "FORMAT", default_value_t = TableFormat::Table)]
+    pub format: TableFormat,
+}
+
 /// Dry run 模式选项
 ///
 /// 预览操作而不实际执行。
@@ -46,6 +93,32 @@ impl DryRunArgs {
     }
 }
 
+/// 卸载时可保留的组件
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UninstallComponent {
+    /// TOML configuration files (workflow.toml, jira-users.toml)
+    Config,
+    /// Shell completion scripts and their source lines in shell config files
+    Completions,
+}
+
+/// 时间戳显示选项
+///
+/// 列表中的时间默认显示为相对时间（如 "3 hours ago"）。
+#[derive(Args, Debug, Clone)]
+pub struct TimestampArgs {
+    /// Show absolute timestamps (YYYY-MM-DD HH:MM:SS) instead of relative times
+    #[arg(long, action = clap::ArgAction::SetTrue)]
+    pub absolute: bool,
+}
+
+impl TimestampArgs {
+    /// 是否显示绝对时间
+    pub fn is_absolute(&self) -> bool {
+        self.absolute
+    }
+}
+
 /// 可选 JIRA ID 参数
 ///
 /// JIRA ticket ID，如果未提供则交互式输入。
@@ -70,7 +143,7 @@ impl JiraIdArg {
     /// # 返回
     ///
     /// 成功时返回验证后的字符串，失败时返回格式化的错误信息。
-    fn validate_jira_id(value: &str) -> Result<String, String> {
+    pub(crate) fn validate_jira_id(value: &str) -> Result<String, String> {
         // 使用现有的验证函数
         match crate::jira::helpers::validate_jira_ticket_format(value) {
             Ok(_) => Ok(value.to_string()),
@@ -155,24 +228,32 @@ impl ForceArgs {
     }
 }
 
-/// 通用日志级别参数
+/// 全局日志级别参数
 ///
-/// 控制输出的详细程度，支持静默模式和详细模式。
-#[derive(Args, Debug, Clone)]
+/// 临时调整单次执行的输出详细程度，优先级高于环境变量 `WORKFLOW_LOG__LEVEL` 和配置文件 `log.level`：
+/// - `-v`：输出调试日志（`debug`）
+/// - `-vv`：在 `-v` 的基础上启用 tracing 调试输出和 HTTP 跟踪（同 `--http-trace`）
+/// - `-q`：只输出警告和错误（隐藏信息和成功消息）
+#[derive(Args, Debug, Clone, Default)]
 pub struct VerbosityArgs {
-    /// Verbose output (show detailed information)
-    #[arg(long, short = 'v', action = clap::ArgAction::SetTrue)]
-    pub verbose: bool,
+    /// Verbose output (-v: debug logs, -vv: also trace logs and HTTP requests)
+    #[arg(long, short = 'v', global = true, action = clap::ArgAction::Count, conflicts_with = "quiet")]
+    pub verbose: u8,
 
-    /// Quiet mode (minimal output)
-    #[arg(long, short = 'q', action = clap::ArgAction::SetTrue)]
+    /// Quiet mode (hide info and success messages, only show warnings and errors)
+    #[arg(long, short = 'q', global = true, action = clap::ArgAction::SetTrue)]
     pub quiet: bool,
 }
 
 impl VerbosityArgs {
-    /// 获取详细模式标志
+    /// 获取详细模式标志（`-v` 及以上）
     pub fn is_verbose(&self) -> bool {
-        self.verbose
+        self.verbose > 0
+    }
+
+    /// 获取跟踪模式标志（`-vv` 及以上）
+    pub fn is_trace(&self) -> bool {
+        self.verbose > 1
     }
 
     /// 获取静默模式标志
@@ -180,27 +261,69 @@ impl VerbosityArgs {
         self.quiet
     }
 
-    /// 获取日志级别（优先级：quiet > verbose > normal）
+    /// 获取日志级别（优先级：quiet > trace > verbose > normal）
     pub fn get_log_level(&self) -> LogLevel {
         if self.quiet {
             LogLevel::Quiet
-        } else if self.verbose {
+        } else if self.is_trace() {
+            LogLevel::Trace
+        } else if self.is_verbose() {
             LogLevel::Verbose
         } else {
             LogLevel::Normal
         }
     }
+
+    /// 覆盖配置的日志级别（未指定 `-v`/`-q` 时返回 `None`，使用配置）
+    pub fn level_override(&self) -> Option<crate::base::logger::LogLevel> {
+        match self.get_log_level() {
+            LogLevel::Quiet => Some(crate::base::logger::LogLevel::Warn),
+            LogLevel::Normal => None,
+            LogLevel::Verbose | LogLevel::Trace => Some(crate::base::logger::LogLevel::Debug),
+        }
+    }
 }
 
 /// 日志级别枚举
 #[derive(Debug, Clone, PartialEq)]
 pub enum LogLevel {
-    /// 静默模式 - 仅显示错误
+    /// 静默模式 - 仅显示警告和错误
     Quiet,
     /// 正常模式 - 显示基本信息
     Normal,
-    /// 详细模式 - 显示详细信息
+    /// 详细模式 - 显示调试信息
     Verbose,
+    /// 跟踪模式 - 显示调试信息、tracing 日志和 HTTP 请求
+    Trace,
+}
+
+/// 日志条目过滤参数
+///
+/// 按日志级别和组件过滤解析后的日志条目。
+#[derive(Args, Debug, Clone)]
+pub struct LogFilterArgs {
+    /// Only include entries with the given levels (comma-separated, e.g. error,warn)
+    #[arg(long, value_name = "LEVELS", value_delimiter = ',')]
+    pub level: Vec<crate::base::logger::LogLevel>,
+
+    /// Only include entries at or above the given severity (error > warn > info > debug)
+    #[arg(long, value_name = "LEVEL")]
+    pub min_level: Option<crate::base::logger::LogLevel>,
+
+    /// Only include entries from the given component (case-insensitive)
+    #[arg(long, value_name = "NAME")]
+    pub component: Option<String>,
+}
+
+impl LogFilterArgs {
+    /// 转换为日志过滤器
+    pub fn to_filter(&self) -> crate::jira::logs::LogFilter {
+        crate::jira::logs::LogFilter::new(
+            self.level.clone(),
+            self.min_level,
+            self.component.clone(),
+        )
+    }
 }
 
 /// 通用确认参数
@@ -224,7 +347,8 @@ impl ConfirmationArgs {
 
 /// 常用的查询和显示参数组合
 ///
-/// 包含输出格式、分页和详细程度控制，适用于大多数查询命令。
+/// 包含输出格式和分页控制，适用于大多数查询命令。
+/// 输出的详细程度由全局参数 `-v`/`-q` 控制（见 `VerbosityArgs`）。
 #[derive(Args, Debug, Clone)]
 pub struct QueryDisplayArgs {
     #[command(flatten)]
@@ -232,9 +356,6 @@ pub struct QueryDisplayArgs {
 
     #[command(flatten)]
     pub pagination: PaginationArgs,
-
-    #[command(flatten)]
-    pub verbosity: VerbosityArgs,
 }
 
 /// 操作执行参数组合
@@ -59,6 +59,7 @@ pub enum BranchSubcommand {
     ///   workflow branch sync master                    # Merge master into current branch
     ///   workflow branch sync master --rebase          # Rebase current branch onto master
     ///   workflow branch sync feature-branch --squash  # Squash merge feature-branch
+    ///   workflow branch sync master --dry-run         # Preview the sync plan only
     Sync {
         /// Source branch name to sync (required)
         #[arg(value_name = "SOURCE_BRANCH")]
@@ -75,6 +76,10 @@ pub enum BranchSubcommand {
         /// Use squash merge (compress all commits into one)
         #[arg(long, action = clap::ArgAction::SetTrue)]
         squash: bool,
+
+        /// Preview the sync plan (strategy, commits, conflict risk) without changing anything
+        #[command(flatten)]
+        dry_run: DryRunArgs,
     },
     /// Delete a branch
     ///
@@ -0,0 +1,113 @@
+//! Cache management subcommands
+//!
+//! Used to inspect and clean local caches (logs, Jira downloads, LLM file summaries, Jira user info).
+
+use std::time::Duration;
+
+use clap::{Subcommand, ValueEnum};
+
+use super::args::{DryRunArgs, ForceArgs, TableFormatArgs};
+use crate::cache::CacheKind;
+
+/// Cache management subcommands
+///
+/// Used to list cache locations with their sizes and to clean them selectively.
+#[derive(Subcommand)]
+pub enum CacheSubcommand {
+    /// List all caches
+    ///
+    /// Display each cache location with its size and number of entries.
+    List {
+        #[command(flatten)]
+        output: TableFormatArgs,
+    },
+    /// Clean caches
+    ///
+    /// Delete the contents of all caches, or only the given cache.
+    /// With --older-than, only files last modified before the given age are deleted.
+    ///
+    /// Examples:
+    ///   workflow cache clean                              # Clean all caches
+    ///   workflow cache clean logs                         # Clean log files only
+    ///   workflow cache clean --older-than 30d             # Delete files older than 30 days
+    ///   workflow cache clean jira-downloads --dry-run     # Preview without deleting
+    Clean {
+        /// Cache to clean (optional, cleans all caches if not provided)
+        #[arg(value_enum, value_name = "CACHE")]
+        cache: Option<CacheName>,
+
+        /// Only delete files older than this age (e.g. 12h, 7d, 2w; units: s, m, h, d, w)
+        #[arg(long, value_name = "AGE", value_parser = parse_older_than)]
+        older_than: Option<Duration>,
+
+        #[command(flatten)]
+        dry_run: DryRunArgs,
+
+        #[command(flatten)]
+        force: ForceArgs,
+    },
+}
+
+/// 可清理的缓存
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheName {
+    /// Workflow CLI log files
+    Logs,
+    /// Downloaded Jira attachments and logs
+    JiraDownloads,
+    /// LLM file summaries (pr summarize)
+    FileSummaries,
+    /// Jira user info cached in jira.toml
+    JiraUsers,
+}
+
+/// 解析 `--older-than` 参数
+///
+/// 格式为数字加单位：`s`（秒）、`m`（分钟）、`h`（小时）、`d`（天）、`w`（周），如 `7d`。
+///
+/// # 示例
+///
+/// ```
+/// use std::time::Duration;
+/// use workflow::cli::parse_older_than;
+///
+/// assert_eq!(parse_older_than("7d"), Ok(Duration::from_secs(7 * 24 * 60 * 60)));
+/// assert!(parse_older_than("7").is_err());
+/// ```
+pub fn parse_older_than(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let invalid = || {
+        format!(
+            "Invalid age '{}': expected a number followed by a unit (s, m, h, d, w), e.g. 7d",
+            value
+        )
+    };
+
+    let unit_start = value.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+    let (amount, unit) = value.split_at(unit_start);
+    let amount: u64 = amount.parse().map_err(|_| invalid())?;
+    let unit_secs = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(invalid()),
+    };
+    if amount == 0 {
+        return Err(format!("Invalid age '{}': must be greater than 0", value));
+    }
+
+    amount.checked_mul(unit_secs).map(Duration::from_secs).ok_or_else(invalid)
+}
+
+impl From<CacheName> for CacheKind {
+    fn from(name: CacheName) -> Self {
+        match name {
+            CacheName::Logs => Self::Logs,
+            CacheName::JiraDownloads => Self::JiraDownloads,
+            CacheName::FileSummaries => Self::FileSummaries,
+            CacheName::JiraUsers => Self::JiraUsers,
+        }
+    }
+}
@@ -5,9 +5,10 @@
 use clap::Subcommand;
 
 use super::{
-    AliasSubcommand, BranchSubcommand, CommitSubcommand, CompletionSubcommand, ConfigSubcommand,
-    DryRunArgs, GitHubSubcommand, JiraSubcommand, LLMSubcommand, LogLevelSubcommand, PRCommands,
-    ProxySubcommand, RepoSubcommand, StashSubcommand, TagSubcommand,
+    AliasSubcommand, BranchSubcommand, CacheSubcommand, CommitSubcommand, CompletionSubcommand,
+    ConfigSubcommand, DryRunArgs, GitHubSubcommand, JiraSubcommand, LLMSubcommand,
+    LogLevelSubcommand, McpSubcommand, PRCommands, ProxySubcommand, RepoSubcommand,
+    StashSubcommand, TagSubcommand, UninstallComponent,
 };
 
 /// 主命令枚举
@@ -27,12 +28,51 @@ pub enum Commands {
     },
     /// Run environment checks
     ///
-    /// Check Git repository status and network connectivity (GitHub).
-    Check,
+    /// Check Git repository status, network connectivity (GitHub), configuration, API credentials, and LLM health.
+    /// With --watch, re-run the checks on an interval until all of them pass (or Ctrl+C).
+    Check {
+        /// Keep re-running the checks and redraw a status table until all checks pass
+        #[arg(long, short = 'w')]
+        watch: bool,
+
+        /// Seconds between checks in watch mode
+        #[arg(long, value_name = "SECONDS", default_value_t = 5, requires = "watch", value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+
+        /// Skip the Jira API connectivity check
+        #[arg(long)]
+        skip_jira: bool,
+
+        /// Skip the GitHub API connectivity check
+        #[arg(long)]
+        skip_github: bool,
+
+        /// Skip the LLM API connectivity and health checks
+        #[arg(long)]
+        skip_llm: bool,
+
+        /// Offer to fix detected problems (missing directories, completions, required config fields) before checking
+        #[arg(long, conflicts_with = "watch")]
+        fix: bool,
+    },
     /// Initialize or update configuration
     ///
     /// Interactively set up various configuration items required by Workflow CLI (e.g., Jira, GitHub, etc.).
-    Setup,
+    /// With --non-interactive, read values from environment variables (e.g. WORKFLOW_JIRA_EMAIL)
+    /// or an answers file instead of prompting.
+    Setup {
+        /// Read configuration from environment variables and/or an answers file instead of prompting
+        #[arg(long)]
+        non_interactive: bool,
+
+        /// Answers file (TOML, keys like jira_email) for non-interactive setup; environment variables take precedence
+        #[arg(long, value_name = "PATH", requires = "non_interactive")]
+        answers: Option<String>,
+
+        /// Skip online verification of credentials (e.g. Jira) while answering prompts, for offline setup
+        #[arg(long, conflicts_with = "non_interactive")]
+        skip_verify: bool,
+    },
     /// Manage configuration
     ///
     /// View, validate, export, and import configuration files.
@@ -43,20 +83,44 @@ pub enum Commands {
     /// Uninstall Workflow CLI configuration
     ///
     /// Remove all related files: binaries, completion scripts, configuration files, etc.
-    Uninstall,
+    /// With --dry-run, list every file and shell config edit (as a diff) without removing anything.
+    Uninstall {
+        #[command(flatten)]
+        dry_run: DryRunArgs,
+        /// Components to keep (comma-separated): config, completions
+        #[arg(long, value_enum, value_delimiter = ',', value_name = "COMPONENTS")]
+        keep: Vec<UninstallComponent>,
+    },
     /// Show Workflow CLI version
     ///
     /// Display the current installed version of Workflow CLI.
-    Version,
+    Version {
+        /// Output version and build metadata (git commit, build date, rustc version, target) as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Update Workflow CLI
     ///
-    /// Rebuild release version and update all binaries and shell completion scripts.
+    /// Download the release package for the current platform and update binaries.
+    /// Installer installs also update shell completion scripts; standalone binaries
+    /// are replaced in place; Homebrew installs should use `brew upgrade`.
     Update {
         /// Specify the version number to update to (e.g., 1.1.2)
         ///
         /// If not specified, will update to the latest version.
-        #[arg(long, short = 'v')]
+        #[arg(long)]
         version: Option<String>,
+        /// Only check whether a newer version is available, without installing
+        ///
+        /// Prints the latest version and its changelog link.
+        /// Exits with code 10 when an update is available.
+        #[arg(long, conflicts_with = "version")]
+        check: bool,
+        /// Replace a standalone binary even if the release has no checksum file
+        ///
+        /// Standalone binaries are only replaced after SHA256 verification by default.
+        #[arg(long, conflicts_with = "check")]
+        skip_verify: bool,
     },
     /// Manage log level (set/check)
     ///
@@ -150,11 +214,27 @@ pub enum Commands {
         #[command(subcommand)]
         subcommand: AliasSubcommand,
     },
+    /// Manage local caches
+    ///
+    /// List cache locations (logs, Jira downloads, LLM file summaries, Jira user info)
+    /// with their sizes, and clean them selectively.
+    Cache {
+        #[command(subcommand)]
+        subcommand: CacheSubcommand,
+    },
     /// Manage Git tags
     ///
-    /// Delete local and remote tags.
+    /// List, create, push, and delete local and remote tags, and suggest the
+    /// next semantic version tag.
     Tag {
         #[command(subcommand)]
         subcommand: TagSubcommand,
     },
+    /// Run Workflow as an MCP server
+    ///
+    /// Expose PR, Jira, and log operations to AI assistants over the Model Context Protocol.
+    Mcp {
+        #[command(subcommand)]
+        subcommand: McpSubcommand,
+    },
 }
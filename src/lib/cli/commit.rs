@@ -15,6 +15,10 @@ pub enum CommitSubcommand {
     ///   workflow commit amend                              # Interactive amend
     ///   workflow commit amend --message "New message"      # Modify message only
     ///   workflow commit amend --no-edit                    # Don't edit message
+    ///   workflow commit amend --co-author "Alice <alice@example.com>"  # Add a co-author
+    ///
+    /// When the message is edited, offers to add `Refs: <ticket>` (from the branch name)
+    /// and `Co-authored-by:` trailers using the commit body template.
     Amend {
         /// New commit message
         #[arg(short, long)]
@@ -25,6 +29,13 @@ pub enum CommitSubcommand {
         /// Skip pre-commit hooks
         #[arg(long)]
         no_verify: bool,
+        /// Add a co-author as a `Co-authored-by:` trailer (can be repeated)
+        #[arg(
+            long = "co-author",
+            value_name = "NAME <EMAIL>",
+            conflicts_with = "no_edit"
+        )]
+        co_authors: Vec<String>,
     },
     /// Reword a commit message
     ///
@@ -32,18 +32,94 @@ pub enum LogLevelSubcommand {
 pub enum CompletionSubcommand {
     /// Generate completion scripts
     ///
-    /// Auto-detect current shell type, generate corresponding completion scripts and apply to configuration files.
-    Generate,
+    /// Auto-detect current shell type and generate corresponding completion scripts,
+    /// without modifying shell configuration files (use `install` to enable them).
+    Generate {
+        /// Output directory (defaults to ~/.workflow/completions)
+        #[arg(long, value_name = "DIR")]
+        output: Option<String>,
+    },
+    /// Install completion scripts
+    ///
+    /// Auto-detect current shell type, write completion scripts to the completion directory
+    /// and enable them in the shell configuration file (safe to run repeatedly).
+    Install,
     /// Check completion status
     ///
-    /// Check installed shell types and shells with configured completion.
-    Check,
+    /// Check installed shell types, shells with configured completion,
+    /// and whether installed completion scripts match the current CLI
+    /// (compared by checksum: up to date / stale / missing).
+    Check {
+        /// Exit with status 10 when the current shell's completion scripts are stale or missing
+        #[arg(long, action = clap::ArgAction::SetTrue)]
+        exit_code: bool,
+    },
     /// Remove completion configuration
     ///
     /// Interactively select and remove configured shell completion configuration.
     Remove,
 }
 
+/// Configuration profile subcommands
+///
+/// Used to manage named configuration profiles (e.g., work and personal accounts).
+#[derive(Subcommand)]
+pub enum ProfileSubcommand {
+    /// List all profiles
+    ///
+    /// Display all configuration profiles and mark the active one.
+    List,
+    /// Switch the active profile
+    ///
+    /// Persist the selected profile as the default for subsequent commands.
+    Use {
+        /// Profile name
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+    /// Create a new profile
+    ///
+    /// Fields not set in the profile are inherited from the shared base configuration.
+    Create {
+        /// Profile name
+        #[arg(value_name = "NAME")]
+        name: String,
+    },
+}
+
+/// Git configuration subcommands
+///
+/// Used to read and write the git identity and signing settings used by Workflow
+/// (user.name, user.email, user.signingkey, gpg.format, commit.gpgsign).
+#[derive(Subcommand)]
+pub enum GitConfigSubcommand {
+    /// Show git configuration
+    ///
+    /// Display the global and effective value of a key, or of all managed keys when omitted.
+    Get {
+        /// Configuration key (e.g., user.email)
+        #[arg(value_name = "KEY")]
+        key: Option<String>,
+        /// Read the repository config (.git/config) instead of the global config
+        #[arg(long)]
+        local: bool,
+    },
+    /// Set git configuration
+    ///
+    /// The value is validated before it is written (e.g., user.email must be an email address).
+    Set {
+        /// Configuration key (e.g., user.email)
+        #[arg(value_name = "KEY")]
+        key: String,
+        /// Configuration value
+        #[arg(value_name = "VALUE")]
+        value: String,
+        /// Write to the repository config (.git/config) instead of the global config
+        #[arg(long)]
+        local: bool,
+    },
+}
+
 /// Configuration management subcommands
 ///
 /// Used to manage configuration files (validate, export, import).
@@ -71,6 +147,7 @@ pub enum ConfigSubcommand {
     /// Export configuration file
     ///
     /// Export configuration to a file for backup or migration.
+    /// Secrets are omitted by default and marked as `<omitted>`.
     Export {
         /// Output file path
         #[arg(value_name = "OUTPUT_PATH")]
@@ -78,9 +155,9 @@ pub enum ConfigSubcommand {
         /// Only export specific section (e.g., jira, pr)
         #[arg(long)]
         section: Option<String>,
-        /// Exclude sensitive information
+        /// Include sensitive information (API tokens and keys)
         #[arg(long)]
-        no_secrets: bool,
+        include_secrets: bool,
         /// Export as TOML format (default)
         #[arg(long)]
         toml: bool,
@@ -94,6 +171,8 @@ pub enum ConfigSubcommand {
     /// Import configuration file
     ///
     /// Import configuration from a file (merge or overwrite mode).
+    /// The file is validated and changes are confirmed before being applied.
+    /// Omitted secrets keep their current values.
     Import {
         /// Input file path
         #[arg(value_name = "INPUT_PATH")]
@@ -107,4 +186,18 @@ pub enum ConfigSubcommand {
         #[command(flatten)]
         dry_run: DryRunArgs,
     },
+    /// Manage configuration profiles
+    ///
+    /// List, switch, and create named configuration profiles.
+    Profile {
+        #[command(subcommand)]
+        subcommand: ProfileSubcommand,
+    },
+    /// Manage git configuration
+    ///
+    /// Get and set the git identity and commit signing settings (global by default).
+    Git {
+        #[command(subcommand)]
+        subcommand: GitConfigSubcommand,
+    },
 }
@@ -25,10 +25,20 @@ pub enum GitHubSubcommand {
     Remove,
     /// Switch current GitHub account
     ///
-    /// Switch between multiple GitHub accounts.
-    Switch,
+    /// Switch between multiple GitHub accounts and update the git identity (user.name/user.email)
+    /// of the current repository to match. Switching back restores the identity used before.
+    Switch {
+        /// Update the global git identity (~/.gitconfig) instead of the current repository's
+        #[arg(long)]
+        global: bool,
+    },
     /// Update GitHub account information
     ///
     /// Update existing GitHub account configuration.
     Update,
+    /// Show GitHub API rate limit quota
+    ///
+    /// Display the remaining API quota of the current account for each resource
+    /// (core, search, graphql, ...) and when it resets.
+    RateLimit,
 }
@@ -1,8 +1,12 @@
 //! Jira operations subcommands
 
+use std::path::PathBuf;
+
 use clap::Subcommand;
 
-use super::args::{DryRunArgs, JiraIdArg, JiraQueryArgs, OutputFormatArgs, PaginationArgs};
+use super::args::{
+    DryRunArgs, JiraIdArg, JiraQueryArgs, OutputFormatArgs, PaginationArgs, TimestampArgs,
+};
 use super::log::LogSubcommand;
 
 /// Jira operations subcommands
@@ -17,31 +21,54 @@ pub enum JiraSubcommand {
         #[command(flatten)]
         args: JiraQueryArgs,
     },
-    /// Show related PRs and branches for a Jira ticket
+    /// Show related tickets, PRs and branches for a Jira ticket
     ///
-    /// Display all Pull Requests and Git branches associated with a Jira ticket.
+    /// Display the epic/parent, subtasks and linked tickets as a tree (key, status and summary),
+    /// along with all Pull Requests and Git branches associated with a Jira ticket.
     Related {
         #[command(flatten)]
         args: JiraQueryArgs,
+
+        /// Select a related ticket and download its attachments or logs
+        #[arg(long)]
+        open: bool,
     },
     /// Show changelog (change history) for a Jira ticket
     ///
-    /// Display change history for a Jira ticket.
+    /// Display a timeline of field changes (who changed what and when) for a Jira ticket.
+    /// By default only status transitions are shown.
     Changelog {
         #[command(flatten)]
         args: JiraQueryArgs,
+
+        /// Fields to include in the timeline (comma-separated, e.g. status,assignee)
+        #[arg(
+            long,
+            value_name = "FIELDS",
+            value_delimiter = ',',
+            default_value = "status"
+        )]
+        field: Vec<String>,
+
+        #[command(flatten)]
+        timestamps: TimestampArgs,
     },
     /// Add a comment to a Jira ticket
     ///
-    /// Add a comment to a Jira ticket interactively.
-    /// You will be prompted to enter the comment message and optionally attach a file.
+    /// Post the message given with -m. Without -m, the comment is written in $EDITOR
+    /// and you can optionally attach files.
     Comment {
         #[command(flatten)]
         jira_id: JiraIdArg,
+
+        /// Comment message (Jira text formatting); opens $EDITOR when omitted
+        #[arg(short = 'm', long, value_name = "MESSAGE")]
+        message: Option<String>,
     },
     /// Show comments for a Jira ticket
     ///
-    /// Display all comments for a Jira ticket with filtering and pagination options.
+    /// Display all comments for a Jira ticket (oldest first, newest last)
+    /// with filtering and pagination options.
     Comments {
         #[command(flatten)]
         jira_id: JiraIdArg,
@@ -59,6 +86,37 @@ pub enum JiraSubcommand {
 
         #[command(flatten)]
         output_format: OutputFormatArgs,
+
+        #[command(flatten)]
+        timestamps: TimestampArgs,
+    },
+    /// Show the active sprint of a Jira project
+    ///
+    /// Find the project's Agile board, then list the issues in its active sprint
+    /// (key, status, assignee and summary).
+    Sprint {
+        /// Jira project key (e.g., PROJ)
+        #[arg(value_name = "PROJECT")]
+        project: Option<String>,
+
+        /// Board ID to use when the project has several boards
+        #[arg(long, value_name = "ID")]
+        board: Option<u64>,
+
+        #[command(flatten)]
+        output_format: OutputFormatArgs,
+    },
+    /// Upload a file as an attachment to a Jira ticket
+    ///
+    /// The file is streamed from disk, so large log archives are not loaded into memory.
+    Attach {
+        /// Jira ticket ID (e.g., PROJ-123)
+        #[arg(value_name = "JIRA_ID", value_parser = JiraIdArg::validate_jira_id)]
+        jira_id: String,
+
+        /// Path of the file to upload
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
     },
     /// Download all attachments from Jira ticket
     ///
@@ -85,7 +143,7 @@ pub enum JiraSubcommand {
         #[arg(long, short = 'l')]
         list: bool,
     },
-    /// Log operations (download, find, search)
+    /// Log operations (download, find, search, tail)
     ///
     /// Download log files from Jira tickets, search and find content in logs.
     Log {
@@ -1,8 +1,18 @@
 //! Log operations subcommands
 
-use clap::Subcommand;
+use clap::{Subcommand, ValueEnum};
 
-use super::args::JiraIdArg;
+use super::args::{JiraIdArg, LogFilterArgs};
+
+/// Output format for matched log entries
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LogOutputFormat {
+    /// Human-readable output (default)
+    #[default]
+    Text,
+    /// One JSON object per matched entry (JSON Lines)
+    Jsonl,
+}
 
 /// Log operations subcommands
 ///
@@ -28,6 +38,13 @@ pub enum LogSubcommand {
         /// Request ID (optional, will prompt interactively if not provided)
         #[arg(value_name = "REQUEST_ID")]
         request_id: Option<String>,
+
+        #[command(flatten)]
+        filter: LogFilterArgs,
+
+        /// Output format (jsonl emits one JSON object per matched entry)
+        #[arg(long, value_enum, value_name = "FORMAT", default_value_t = LogOutputFormat::Text)]
+        format: LogOutputFormat,
     },
     /// Search for keywords in log files
     ///
@@ -39,5 +56,28 @@ pub enum LogSubcommand {
         /// Search keyword (optional, will prompt interactively if not provided)
         #[arg(value_name = "SEARCH_TERM")]
         search_term: Option<String>,
+
+        #[command(flatten)]
+        filter: LogFilterArgs,
+
+        /// Output format (jsonl emits one JSON object per matched entry)
+        #[arg(long, value_enum, value_name = "FORMAT", default_value_t = LogOutputFormat::Text)]
+        format: LogOutputFormat,
+    },
+    /// Show the end of the log file and optionally follow new entries
+    ///
+    /// Print the last lines of the downloaded log file with level-based coloring.
+    /// With --follow, keep polling for appended lines (log rotation is handled by reopening the file).
+    Tail {
+        #[command(flatten)]
+        jira_id: JiraIdArg,
+
+        /// Number of lines to show from the end of the file
+        #[arg(long, short = 'n', default_value_t = 20)]
+        lines: usize,
+
+        /// Keep following the file for newly appended lines
+        #[arg(long, short = 'f')]
+        follow: bool,
     },
 }
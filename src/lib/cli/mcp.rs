@@ -0,0 +1,21 @@
+//! MCP server subcommands
+//!
+//! Subcommands for running Workflow as an MCP (Model Context Protocol) server.
+
+use clap::Subcommand;
+
+/// MCP server subcommands
+///
+/// Expose Workflow operations to AI assistants over MCP.
+#[derive(Subcommand)]
+pub enum McpSubcommand {
+    /// Run an MCP server over stdio
+    ///
+    /// Reads newline-delimited JSON-RPC messages from stdin and writes responses to stdout.
+    /// Logs are written to stderr as JSON. Exposed tools: create_pr, summarize_pr, jira_info,
+    /// search_logs.
+    ///
+    /// Example MCP client configuration (e.g., .cursor/mcp.json):
+    ///   { "mcpServers": { "workflow": { "command": "workflow", "args": ["mcp", "serve"] } } }
+    Serve,
+}
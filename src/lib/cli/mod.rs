@@ -9,6 +9,7 @@ use clap::Parser;
 mod alias;
 mod args;
 mod branch;
+mod cache;
 mod commands;
 mod commit;
 mod config;
@@ -16,6 +17,7 @@ mod github;
 mod jira;
 mod llm;
 mod log;
+mod mcp;
 mod pr;
 mod proxy;
 mod repo;
@@ -26,22 +28,28 @@ mod tag;
 // 这些导出是必需的，因为 bin/workflow.rs 需要使用它们进行命令分发
 pub use alias::AliasSubcommand;
 pub use args::{
-    ConfirmationArgs, DryRunArgs, ForceArgs, JiraIdArg, JiraOperationArgs, JiraQueryArgs, LogLevel,
-    OperationArgs, OutputFormatArgs, PaginationArgs, QueryDisplayArgs, VerbosityArgs,
+    ConfirmationArgs, DryRunArgs, ForceArgs, JiraIdArg, JiraOperationArgs, JiraQueryArgs,
+    LogFilterArgs, LogLevel, OperationArgs, OutputFormatArgs, PaginationArgs, QueryDisplayArgs,
+    TableFormat, TableFormatArgs, TimestampArgs, UninstallComponent, VerbosityArgs,
 };
 pub use branch::{BranchSubcommand, IgnoreSubcommand};
+pub use cache::{parse_older_than, CacheName, CacheSubcommand};
 pub use commands::Commands;
 pub use commit::CommitSubcommand;
-pub use config::{CompletionSubcommand, ConfigSubcommand, LogLevelSubcommand};
+pub use config::{
+    CompletionSubcommand, ConfigSubcommand, GitConfigSubcommand, LogLevelSubcommand,
+    ProfileSubcommand,
+};
 pub use github::GitHubSubcommand;
 pub use jira::JiraSubcommand;
 pub use llm::LLMSubcommand;
-pub use log::LogSubcommand;
-pub use pr::PRCommands;
+pub use log::{LogOutputFormat, LogSubcommand};
+pub use mcp::McpSubcommand;
+pub use pr::{PRCommands, PRListFilterArgs, PRListSort, PRMergeMethod, PRReviewType};
 pub use proxy::ProxySubcommand;
 pub use repo::RepoSubcommand;
 pub use stash::StashSubcommand;
-pub use tag::TagSubcommand;
+pub use tag::{TagBump, TagSubcommand};
 
 /// CLI 主结构体
 ///
@@ -51,6 +59,29 @@ pub use tag::TagSubcommand;
 #[command(about = "Workflow CLI tool", long_about = None)]
 #[command(version)]
 pub struct Cli {
+    /// Use the specified configuration profile (overrides WORKFLOW_PROFILE)
+    #[arg(long, global = true, value_name = "NAME")]
+    pub profile: Option<String>,
+
+    /// Use an alternate config file or directory (overrides WORKFLOW_CONFIG)
+    #[arg(long = "config", global = true, value_name = "PATH")]
+    pub config_path: Option<String>,
+
+    /// Log HTTP requests and responses with credentials masked (same as log.http_trace = true)
+    #[arg(long, global = true)]
+    pub http_trace: bool,
+
+    /// Disable colored output (same as setting NO_COLOR)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Log output format: text (default) or json (one JSON object per line on stderr)
+    #[arg(long, global = true, value_name = "FORMAT")]
+    pub log_format: Option<crate::base::logger::LogFormat>,
+
+    #[command(flatten)]
+    pub verbosity: VerbosityArgs,
+
     #[command(subcommand)]
     pub command: Option<Commands>,
 }
@@ -1,8 +1,10 @@
 //! Pull Request operations subcommands
 
-use clap::Subcommand;
+use clap::{Args, Subcommand, ValueEnum};
 
-use super::args::{DryRunArgs, ForceArgs, JiraIdArg, PaginationArgs};
+use super::args::{
+    DryRunArgs, ForceArgs, JiraIdArg, OutputFormatArgs, PaginationArgs, TableFormatArgs,
+};
 
 /// PR commands enumeration
 ///
@@ -13,6 +15,7 @@ pub enum PRCommands {
     ///
     /// Supports auto-detection of repository type (GitHub), and optionally uses AI to generate PR title.
     /// If a Jira ticket is provided, will automatically update Jira status.
+    /// Use `--from-commits` to create a PR from the commits on the current branch without a Jira ticket.
     Create {
         #[command(flatten)]
         jira_id: JiraIdArg,
@@ -25,6 +28,11 @@ pub enum PRCommands {
         #[arg(short, long)]
         description: Option<String>,
 
+        /// Create the PR from the commits on the current branch (no Jira ticket),
+        /// generating title and description from commit messages and the branch diff
+        #[arg(long, conflicts_with = "jira_id")]
+        from_commits: bool,
+
         #[command(flatten)]
         dry_run: DryRunArgs,
     },
@@ -32,6 +40,9 @@ pub enum PRCommands {
     ///
     /// Auto-detect PR corresponding to current branch, or manually specify PR ID.
     /// Will automatically update corresponding Jira ticket status after merging.
+    /// Use `--dry-run` to check mergeability, required checks and reviews without merging.
+    /// Use `--auto` to enable auto-merge so the PR is merged once required checks pass (GitHub only).
+    /// The source branch (remote and local) is deleted after merging; use `--keep-branch` to keep it.
     Merge {
         /// PR ID (optional, auto-detect from current branch if not provided)
         #[arg(value_name = "PR_ID")]
@@ -39,6 +50,25 @@ pub enum PRCommands {
 
         #[command(flatten)]
         force: ForceArgs,
+
+        #[command(flatten)]
+        dry_run: DryRunArgs,
+
+        /// Enable auto-merge: merge automatically when required checks pass
+        #[arg(long, action = clap::ArgAction::SetTrue, conflicts_with = "dry_run")]
+        auto: bool,
+
+        /// Merge strategy (default: pr.merge_strategy for the target branch in repo config, then first allowed of squash, rebase, merge)
+        #[arg(long, visible_alias = "method", value_enum, conflicts_with = "dry_run")]
+        strategy: Option<PRMergeMethod>,
+
+        /// Delete the source branch after merging (default: pr.delete_branch_on_merge in repo config, true if not set)
+        #[arg(long, action = clap::ArgAction::SetTrue, conflicts_with_all = ["auto", "dry_run"])]
+        delete_branch: bool,
+
+        /// Keep the source branch after merging (overrides pr.delete_branch_on_merge)
+        #[arg(long, action = clap::ArgAction::SetTrue, conflicts_with_all = ["delete_branch", "auto", "dry_run"])]
+        keep_branch: bool,
     },
     /// Show PR status information
     ///
@@ -47,17 +77,31 @@ pub enum PRCommands {
         /// PR ID or branch name (optional, auto-detect from current branch if not provided)
         #[arg(value_name = "PR_ID_OR_BRANCH")]
         pull_request_id_or_branch: Option<String>,
+
+        #[command(flatten)]
+        output_format: OutputFormatArgs,
     },
     /// List Pull Requests
     ///
-    /// List all PRs in the repository, supports filtering by status and limiting the number of results.
+    /// List all PRs in the repository, supports filtering by status, author, label and base branch,
+    /// sorting by creation or update time, and limiting the number of results.
     List {
         /// Filter by state (open, closed, merged)
         #[arg(short, long)]
         state: Option<String>,
 
+        #[command(flatten)]
+        filters: PRListFilterArgs,
+
         #[command(flatten)]
         pagination: PaginationArgs,
+
+        #[command(flatten)]
+        output: TableFormatArgs,
+
+        /// Output a JSON document instead of a table (for scripts)
+        #[arg(long, conflicts_with = "format")]
+        json: bool,
     },
     /// Update code (use PR title as commit message)
     ///
@@ -69,6 +113,8 @@ pub enum PRCommands {
     /// This is a local Git operation, different from the `merge` command (which merges PR via API).
     /// Merged functionality from `integrate` and `sync` commands.
     /// Will prompt for confirmation before pushing to remote.
+    /// Use `--dry-run` to preview the plan without changing anything.
+    /// Use `--all` to rebase a whole stack of dependent branches onto the source branch.
     Sync {
         /// Source branch name to sync (required)
         #[arg(value_name = "SOURCE_BRANCH")]
@@ -85,6 +131,23 @@ pub enum PRCommands {
         /// Use squash merge (compress all commits into one)
         #[arg(long, action = clap::ArgAction::SetTrue)]
         squash: bool,
+
+        /// Squash commit message (opens $EDITOR with the squashed commit messages if omitted)
+        #[arg(short = 'm', long, value_name = "MESSAGE", requires = "squash")]
+        message: Option<String>,
+
+        /// Stop after the local sync (do not push, update the PR or clean up the source branch)
+        #[arg(long, action = clap::ArgAction::SetTrue)]
+        no_push: bool,
+
+        /// Rebase the whole stack of dependent branches (each based on the previous one)
+        /// onto the source branch in order, stopping on the first conflict
+        #[arg(long, action = clap::ArgAction::SetTrue, conflicts_with_all = ["ff_only", "squash"])]
+        all: bool,
+
+        /// Preview the sync plan (strategy, commits, conflict risk) without changing anything
+        #[command(flatten)]
+        dry_run: DryRunArgs,
     },
     /// Rebase current branch onto target branch and update PR base
     ///
@@ -117,27 +180,77 @@ pub enum PRCommands {
     /// The document will be saved to ~/Documents/Workflow/{PR_ID}/{filename}.md
     /// where filename is automatically generated by AI based on PR content.
     /// Language is determined by the config file (defaults to "en" if not configured).
+    /// Per-file summaries are cached under ~/.workflow/cache, so unchanged files are not re-summarized.
     Summarize {
         /// PR ID (optional, auto-detect from current branch if not provided)
         #[arg(value_name = "PR_ID")]
         pull_request_id: Option<String>,
+
+        /// Do not read or write the file summary cache
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Clear the file summary cache and exit
+        #[arg(long, conflicts_with_all = ["pull_request_id", "no_cache"])]
+        clear_cache: bool,
     },
     /// Approve a Pull Request
     ///
-    /// Approve a PR by adding a 👍 comment.
+    /// Approve a PR with the given message, or with a 👍 if no message is given.
+    /// Shortcut for `pr review --type approve`.
     Approve {
         /// PR ID (optional, auto-detect from current branch if not provided)
         #[arg(value_name = "PR_ID")]
         pull_request_id: Option<String>,
+
+        /// Review message (default: 👍)
+        #[arg(short, long, value_name = "MESSAGE")]
+        message: Option<String>,
+    },
+    /// Submit a review to a Pull Request
+    ///
+    /// Approve, request changes, or comment on a PR. Requesting changes and
+    /// commenting require a message.
+    Review {
+        /// PR ID (optional, auto-detect from current branch if not provided)
+        #[arg(value_name = "PR_ID")]
+        pull_request_id: Option<String>,
+
+        /// Review type
+        #[arg(long = "type", value_enum, value_name = "TYPE")]
+        review_type: PRReviewType,
+
+        /// Review message (required for request-changes and comment)
+        #[arg(
+            short,
+            long,
+            value_name = "MESSAGE",
+            required_if_eq_any = [("review_type", "request-changes"), ("review_type", "comment")]
+        )]
+        message: Option<String>,
     },
     /// Add a comment to a Pull Request
     ///
-    /// Add a comment to a PR.
+    /// Add a comment to a PR. With --file and --line the comment is attached to
+    /// that line of the PR diff; with --reply-to it replies to an existing review
+    /// comment thread. Options must come before the message.
     Comment {
         /// PR ID (optional, auto-detect from current branch if not provided)
         #[arg(value_name = "PR_ID")]
         pull_request_id: Option<String>,
 
+        /// File to comment on (path relative to the repository root, must be changed in the PR)
+        #[arg(long, value_name = "PATH", requires = "line")]
+        file: Option<String>,
+
+        /// Line number in the new version of the file (must be part of the PR diff)
+        #[arg(long, value_name = "LINE", requires = "file", value_parser = clap::value_parser!(u32).range(1..))]
+        line: Option<u32>,
+
+        /// Reply to an existing review comment by its ID
+        #[arg(long, value_name = "COMMENT_ID", conflicts_with_all = ["file", "line"])]
+        reply_to: Option<u64>,
+
         /// Comment message (required, can be multiple words)
         #[arg(value_name = "MESSAGE", trailing_var_arg = true)]
         message: Vec<String>,
@@ -185,3 +298,59 @@ pub enum PRCommands {
         dry_run: DryRunArgs,
     },
 }
+
+/// 合并方式
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PRMergeMethod {
+    /// Squash all commits into one
+    Squash,
+    /// Rebase commits onto the base branch
+    Rebase,
+    /// Create a merge commit
+    Merge,
+}
+
+/// PR 评审类型
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PRReviewType {
+    /// Approve the PR
+    Approve,
+    /// Request changes before the PR can be merged
+    RequestChanges,
+    /// Comment without approving or requesting changes
+    Comment,
+}
+
+/// PR 列表筛选参数
+#[derive(Args, Debug, Clone, Default)]
+pub struct PRListFilterArgs {
+    /// Only list PRs created by the author (GitHub login, or Azure DevOps unique name/display name)
+    #[arg(long, value_name = "AUTHOR", conflicts_with = "mine")]
+    pub author: Option<String>,
+
+    /// Only list PRs created by the current user
+    #[arg(long)]
+    pub mine: bool,
+
+    /// Only list PRs with the label
+    #[arg(long, value_name = "LABEL")]
+    pub label: Option<String>,
+
+    /// Only list PRs targeting the base branch
+    #[arg(long, value_name = "BRANCH")]
+    pub base: Option<String>,
+
+    /// Sort order, newest first
+    #[arg(long, value_enum, default_value_t = PRListSort::Created)]
+    pub sort: PRListSort,
+}
+
+/// PR 列表排序方式
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PRListSort {
+    /// Sort by creation time
+    #[default]
+    Created,
+    /// Sort by last update time
+    Updated,
+}
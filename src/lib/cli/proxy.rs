@@ -2,6 +2,8 @@
 
 use clap::Subcommand;
 
+use super::args::OutputFormatArgs;
+
 /// Proxy management subcommands
 ///
 /// Used to manage HTTP/HTTPS proxy environment variable configuration.
@@ -18,5 +20,8 @@ pub enum ProxySubcommand {
     /// Check proxy status and configuration
     ///
     /// Display current proxy environment variable status and configuration information.
-    Check,
+    Check {
+        #[command(flatten)]
+        output_format: OutputFormatArgs,
+    },
 }
@@ -2,6 +2,8 @@
 
 use clap::Subcommand;
 
+use super::args::TableFormatArgs;
+
 /// Stash management subcommands
 ///
 /// Manage Git stash entries: list, apply, drop, and pop.
@@ -15,6 +17,9 @@ pub enum StashSubcommand {
         /// Show file change statistics for each stash
         #[arg(long)]
         stat: bool,
+
+        #[command(flatten)]
+        output: TableFormatArgs,
     },
     /// Apply a stash (keep the stash entry)
     ///
@@ -2,15 +2,120 @@
 //!
 //! Subcommands for managing Git tags.
 
-use clap::Subcommand;
+use clap::{Subcommand, ValueEnum};
 
 use super::args::{DryRunArgs, ForceArgs};
 
 /// Tag management subcommands
 ///
-/// Manage Git tags: delete local and remote tags.
+/// Manage Git tags: list, create, push, and delete local and remote tags, and
+/// suggest the next semantic version tag.
 #[derive(Subcommand)]
 pub enum TagSubcommand {
+    /// List tags
+    ///
+    /// Show local and remote tags with their commit, type (annotated or
+    /// lightweight), locations and message.
+    ///
+    /// Examples:
+    ///   workflow tag list                          # List local and remote tags
+    ///   workflow tag list --pattern "v1.*"         # List tags matching pattern
+    ///   workflow tag list --local                  # List local tags only (no remote access)
+    #[command(alias = "ls")]
+    List {
+        /// Pattern to match tags (e.g., "v1.*")
+        #[arg(long)]
+        pattern: Option<String>,
+
+        /// List only local tags
+        #[arg(long)]
+        local: bool,
+    },
+
+    /// Create an annotated tag
+    ///
+    /// Examples:
+    ///   workflow tag create v1.2.0                       # Tag HEAD, prompt for message
+    ///   workflow tag create v1.2.0 -m "Release 1.2.0"    # Tag HEAD with message
+    ///   workflow tag create v1.2.0 --target main --push  # Tag main and push to origin
+    Create {
+        /// Tag name
+        tag_name: String,
+
+        /// Tag message (prompted if not provided)
+        #[arg(short, long)]
+        message: Option<String>,
+
+        /// Commit, branch or other revision to tag (default: HEAD)
+        #[arg(long, value_name = "REV")]
+        target: Option<String>,
+
+        /// Push the tag to origin after creating it
+        #[arg(long)]
+        push: bool,
+    },
+
+    /// Suggest the next semantic version tag
+    ///
+    /// Parse existing local tags as semantic versions (`<prefix>MAJOR.MINOR.PATCH[-CHANNEL.N]`),
+    /// bump the latest release and print the next tag. The prefix defaults to the
+    /// `[tag] prefix` setting ("v" if not configured). Fetch remote tags first
+    /// (`git fetch --tags`) if they are not available locally.
+    ///
+    /// Examples:
+    ///   workflow tag next                            # Next patch version (v1.2.3 -> v1.2.4)
+    ///   workflow tag next --bump minor               # Next minor version (v1.2.3 -> v1.3.0)
+    ///   workflow tag next --bump minor --pre rc      # Next release candidate (v1.3.0-rc.1, v1.3.0-rc.2, ...)
+    ///   workflow tag next --bump major --create --push  # Create and push the next major version tag
+    Next {
+        /// Version component to bump
+        #[arg(long, value_enum, default_value_t = TagBump::Patch)]
+        bump: TagBump,
+
+        /// Pre-release channel (e.g., "alpha", "beta", "rc")
+        #[arg(long, value_name = "CHANNEL")]
+        pre: Option<String>,
+
+        /// Tag prefix (overrides the `[tag] prefix` setting, use "" for no prefix)
+        #[arg(long)]
+        prefix: Option<String>,
+
+        /// Create the suggested tag at HEAD
+        #[arg(long)]
+        create: bool,
+
+        /// Tag message (requires --create, prompted if not provided)
+        #[arg(short, long, requires = "create")]
+        message: Option<String>,
+
+        /// Push the tag to origin after creating it (requires --create)
+        #[arg(long, requires = "create")]
+        push: bool,
+    },
+
+    /// Push tags to origin
+    ///
+    /// If tag name is not provided, will show an interactive list of local-only tags.
+    ///
+    /// Examples:
+    ///   workflow tag push v1.2.0                   # Push specified tag
+    ///   workflow tag push --all                    # Push all local tags
+    ///   workflow tag push --pattern "v1.*"         # Push local-only tags matching pattern
+    ///   workflow tag push                          # Interactive selection
+    Push {
+        /// Tag name (optional, will enter interactive mode if not provided)
+        #[arg(conflicts_with_all = ["all", "pattern"])]
+        tag_name: Option<String>,
+
+        /// Push all local tags
+        #[arg(long, conflicts_with = "pattern")]
+        all: bool,
+
+        /// Pattern to match local-only tags (e.g., "v1.*")
+        #[arg(long)]
+        pattern: Option<String>,
+    },
+
     /// Delete one or more tags
     ///
     /// Delete local and/or remote tags.
@@ -46,3 +151,15 @@ pub enum TagSubcommand {
         force: ForceArgs,
     },
 }
+
+/// 版本递增方式
+#[derive(ValueEnum, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TagBump {
+    /// Bump the major version (1.2.3 -> 2.0.0)
+    Major,
+    /// Bump the minor version (1.2.3 -> 1.3.0)
+    Minor,
+    /// Bump the patch version (1.2.3 -> 1.2.4)
+    #[default]
+    Patch,
+}
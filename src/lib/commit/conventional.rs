@@ -0,0 +1,88 @@
+//! Conventional Commits 校验
+//!
+//! 提交消息的标题需要符合 `<type>(<scope>)!: <subject>` 格式（scope 和 `!` 可选），
+//! 正文（如果有）与标题之间需要空一行。
+//! 启用 `template.commit.validate_conventional` 时，渲染后的提交消息会经过此校验。
+
+use color_eyre::Result;
+use regex::Regex;
+
+/// 允许的提交类型
+pub const CONVENTIONAL_COMMIT_TYPES: [&str; 11] = [
+    "feat", "fix", "docs", "style", "refactor", "perf", "test", "build", "ci", "chore", "revert",
+];
+
+/// 解析后的 Conventional Commits 标题
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConventionalCommit {
+    /// 提交类型（如 "feat"、"fix"）
+    pub commit_type: String,
+    /// 作用域（可选）
+    pub scope: Option<String>,
+    /// 是否为破坏性变更（标题中带有 `!`）
+    pub breaking: bool,
+    /// 标题描述
+    pub subject: String,
+}
+
+impl ConventionalCommit {
+    /// 解析提交标题（不检查提交类型是否允许）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::commit::ConventionalCommit;
+    ///
+    /// let header = ConventionalCommit::parse("feat(auth)!: drop legacy tokens").unwrap();
+    /// assert_eq!(header.commit_type, "feat");
+    /// assert_eq!(header.scope.as_deref(), Some("auth"));
+    /// assert!(header.breaking);
+    /// assert_eq!(header.subject, "drop legacy tokens");
+    /// assert!(ConventionalCommit::parse("PROJ-123: Fix login").is_none());
+    /// ```
+    pub fn parse(header: &str) -> Option<Self> {
+        let re = Regex::new(r"^([a-z]+)(?:\(([^()\s]+)\))?(!)?: (\S.*)$").ok()?;
+        let caps = re.captures(header.trim())?;
+        Some(Self {
+            commit_type: caps[1].to_string(),
+            scope: caps.get(2).map(|m| m.as_str().to_string()),
+            breaking: caps.get(3).is_some(),
+            subject: caps[4].trim().to_string(),
+        })
+    }
+
+    /// 校验完整的提交消息
+    ///
+    /// # 错误
+    ///
+    /// 消息为空、标题不符合格式、提交类型不在 `CONVENTIONAL_COMMIT_TYPES` 中，
+    /// 或正文与标题之间没有空行时返回错误。
+    pub fn validate(message: &str) -> Result<Self> {
+        let mut lines = message.trim().lines();
+        let header = lines.next().unwrap_or_default();
+        if header.is_empty() {
+            color_eyre::eyre::bail!("Commit message is empty");
+        }
+
+        let commit = Self::parse(header).ok_or_else(|| {
+            color_eyre::eyre::eyre!(
+                "Commit message header '{}' does not follow Conventional Commits, expected '<type>(<scope>): <subject>'",
+                header
+            )
+        })?;
+        if !CONVENTIONAL_COMMIT_TYPES.contains(&commit.commit_type.as_str()) {
+            color_eyre::eyre::bail!(
+                "Unknown commit type '{}', expected one of: {}",
+                commit.commit_type,
+                CONVENTIONAL_COMMIT_TYPES.join(", ")
+            );
+        }
+        if lines.next().is_some_and(|line| !line.trim().is_empty()) {
+            color_eyre::eyre::bail!(
+                "Commit message body must be separated from the header by a blank line"
+            );
+        }
+
+        Ok(commit)
+    }
+}
@@ -4,13 +4,16 @@
 //! - Amend 操作的业务逻辑
 //! - Reword 操作的业务逻辑
 //! - Squash 操作的业务逻辑
+//! - Conventional Commits 校验
 //! - 格式化显示逻辑
 //! - 预览信息生成
 
 mod amend;
+mod conventional;
 mod reword;
 mod squash;
 
 pub use amend::{AmendPreview, CommitAmend};
+pub use conventional::{ConventionalCommit, CONVENTIONAL_COMMIT_TYPES};
 pub use reword::{CommitReword, RewordHistoryOptions, RewordHistoryResult, RewordPreview};
 pub use squash::{CommitSquash, SquashOptions, SquashPreview, SquashResult};
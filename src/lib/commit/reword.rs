@@ -323,6 +323,7 @@ cp "{}" "$1"
         let rebase_result = Command::new("git")
             .arg("rebase")
             .arg("-i")
+            .args(GitCommit::sign_args())
             .arg(parent_sha)
             .env(GIT_SEQUENCE_EDITOR, &config.sequence_editor_script)
             .env(GIT_EDITOR, &config.message_editor_script)
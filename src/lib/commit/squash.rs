@@ -328,6 +328,7 @@ cp "{}" "$1"
         let rebase_result = Command::new("git")
             .arg("rebase")
             .arg("-i")
+            .args(GitCommit::sign_args())
             .arg(base_sha)
             .env(GIT_SEQUENCE_EDITOR, &config.sequence_editor_script)
             .env(GIT_EDITOR, &config.message_editor_script)
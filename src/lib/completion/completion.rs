@@ -10,11 +10,11 @@
 use std::fs;
 use std::path::PathBuf;
 
-use clap_complete::Shell;
 use color_eyre::{eyre::WrapErr, Result};
 
+use super::generate::{CompletionGenerator, CompletionScriptStatus};
 use crate::base::settings::paths::Paths;
-use crate::base::shell::ShellConfigManager;
+use crate::base::shell::{Shell, ShellConfigManager, ShellConfigPreview};
 use crate::base::util::file::FileWriter;
 use crate::trace_debug;
 use crate::trace_info;
@@ -36,6 +36,8 @@ pub struct CompletionConfigResult {
     pub added: bool,
     /// 配置文件路径（如果适用）
     pub config_file: Option<PathBuf>,
+    /// 修改前的 shell 配置文件备份路径（未修改 shell 配置文件时为 `None`）
+    pub backup_path: Option<PathBuf>,
 }
 
 /// Completion 文件删除结果
@@ -124,7 +126,7 @@ impl Completion {
 
                 // 使用 ShellConfigManager 添加 source 语句（指定 shell 类型）
                 let source_pattern = COMPLETIONS_SOURCE_PATH;
-                let added = ShellConfigManager::add_source_for_shell(
+                let edit = ShellConfigManager::add_source_for_shell(
                     shell,
                     source_pattern,
                     Some("Workflow CLI completions"),
@@ -135,27 +137,25 @@ impl Completion {
 
                 CompletionConfigResult {
                     shell: *shell,
-                    already_exists: !added,
-                    added,
+                    already_exists: edit.is_none(),
+                    added: edit.is_some(),
                     config_file: workflow_config_file,
+                    backup_path: edit.and_then(|edit| edit.backup_path),
                 }
             }
             Shell::Fish | Shell::PowerShell | Shell::Elvish => {
                 // 获取每个 shell 的 completion 文件路径
-                let workflow_source = match shell {
-                    Shell::Fish => "$HOME/.workflow/completions/workflow.fish",
-                    Shell::PowerShell => "$HOME/.workflow/completions/_workflow.ps1",
-                    Shell::Elvish => "$HOME/.workflow/completions/workflow.elv",
-                    _ => unreachable!(),
-                };
+                let workflow_source =
+                    Self::shell_source_path(shell).expect("supported shell has a source path");
 
                 // 检查是否已配置
                 let already_exists =
                     ShellConfigManager::has_source_for_shell(shell, workflow_source)?;
 
+                let mut backup_path = None;
                 if !already_exists {
                     // 使用 ShellConfigManager 为 completion 文件添加 source 语句
-                    ShellConfigManager::add_source_for_shell(
+                    let edit = ShellConfigManager::add_source_for_shell(
                         shell,
                         workflow_source,
                         Some("Workflow CLI completions"),
@@ -166,6 +166,7 @@ impl Completion {
                             shell
                         )
                     })?;
+                    backup_path = edit.and_then(|edit| edit.backup_path);
                     trace_debug!("Completion config written to {} config file", shell);
                 } else {
                     trace_debug!("Completion config already exists in {} config file", shell);
@@ -176,6 +177,7 @@ impl Completion {
                     already_exists,
                     added: !already_exists,
                     config_file: None,
+                    backup_path,
                 }
             }
             _ => {
@@ -196,7 +198,7 @@ impl Completion {
             Shell::Zsh | Shell::Bash => {
                 // zsh 和 bash 使用统一配置文件，移除 source 语句（指定 shell 类型）
                 let source_pattern = COMPLETIONS_SOURCE_PATH;
-                let removed = ShellConfigManager::remove_source_for_shell(shell, source_pattern)
+                let edit = ShellConfigManager::remove_source_for_shell(shell, source_pattern)
                     .wrap_err_with(|| {
                         format!(
                             "Failed to remove completion source from {} config file",
@@ -204,10 +206,17 @@ impl Completion {
                         )
                     })?;
 
-                if !removed {
-                    trace_debug!("Completion config not found in {} config file", shell);
-                } else {
-                    trace_debug!("Completion config removed from {} config file", shell);
+                match edit {
+                    Some(edit) => {
+                        trace_debug!(
+                            "Completion config removed from {} config file (backup: {:?})",
+                            shell,
+                            edit.backup_path
+                        );
+                    }
+                    None => {
+                        trace_debug!("Completion config not found in {} config file", shell);
+                    }
                 }
             }
             Shell::Fish | Shell::PowerShell | Shell::Elvish => {
@@ -277,11 +286,8 @@ impl Completion {
             }
             Shell::Fish | Shell::PowerShell | Shell::Elvish => {
                 // fish, powershell, elvish 直接写入配置文件，检查第一个 completion 文件
-                let workflow_source = match shell {
-                    Shell::Fish => "$HOME/.workflow/completions/workflow.fish",
-                    Shell::PowerShell => "$HOME/.workflow/completions/_workflow.ps1",
-                    Shell::Elvish => "$HOME/.workflow/completions/workflow.elv",
-                    _ => return Ok((false, config_path)),
+                let Some(workflow_source) = Self::shell_source_path(shell) else {
+                    return Ok((false, config_path));
                 };
                 ShellConfigManager::has_source_for_shell(shell, workflow_source).unwrap_or(false)
             }
@@ -297,14 +303,14 @@ impl Completion {
     fn remove_completion_block_from_config(shell: &Shell) -> Result<()> {
         // 获取每个 shell 的 completion 文件路径
         let workflow_source = match shell {
-            Shell::Fish => "$HOME/.workflow/completions/workflow.fish",
-            Shell::PowerShell => "$HOME/.workflow/completions/_workflow.ps1",
-            Shell::Elvish => "$HOME/.workflow/completions/workflow.elv",
+            Shell::Fish | Shell::PowerShell | Shell::Elvish => {
+                Self::shell_source_path(shell).expect("supported shell has a source path")
+            }
             _ => return Ok(()), // zsh 和 bash 不使用此方法
         };
 
         // 使用 ShellConfigManager 移除 completion 文件的 source 语句
-        let removed = ShellConfigManager::remove_source_for_shell(shell, workflow_source)
+        let edit = ShellConfigManager::remove_source_for_shell(shell, workflow_source)
             .wrap_err_with(|| {
                 format!(
                     "Failed to remove workflow completion source from {} config",
@@ -312,15 +318,98 @@ impl Completion {
                 )
             })?;
 
-        if removed {
-            trace_debug!("Completion config removed from {} config file", shell);
-        } else {
-            trace_debug!("Completion config not found in {} config file", shell);
+        match edit {
+            Some(edit) => {
+                trace_debug!(
+                    "Completion config removed from {} config file (backup: {:?})",
+                    shell,
+                    edit.backup_path
+                );
+            }
+            None => {
+                trace_debug!("Completion config not found in {} config file", shell);
+            }
         }
 
         Ok(())
     }
 
+    /// 获取 shell 配置文件中 completion 的 source 路径
+    ///
+    /// - zsh 和 bash：统一配置文件 `$HOME/.workflow/.completions`
+    /// - fish, powershell, elvish：各自的 completion 文件
+    ///
+    /// 不支持的 shell 类型返回 `None`。
+    fn shell_source_path(shell: &Shell) -> Option<&'static str> {
+        match shell {
+            Shell::Zsh | Shell::Bash => Some(COMPLETIONS_SOURCE_PATH),
+            Shell::Fish => Some("$HOME/.workflow/completions/workflow.fish"),
+            Shell::PowerShell => Some("$HOME/.workflow/completions/_workflow.ps1"),
+            Shell::Elvish => Some("$HOME/.workflow/completions/workflow.elv"),
+            _ => None,
+        }
+    }
+
+    /// 预览移除所有 shell 的 completion 配置（不写入文件）
+    ///
+    /// 与 `remove_all_completion_configs()` 对应，读取失败的 shell 会被跳过。
+    ///
+    /// # 返回
+    ///
+    /// 返回每个将被修改的 shell 配置文件的修改预览。
+    pub fn preview_remove_all_completion_configs() -> Vec<ShellConfigPreview> {
+        [
+            Shell::Zsh,
+            Shell::Bash,
+            Shell::Fish,
+            Shell::PowerShell,
+            Shell::Elvish,
+        ]
+        .iter()
+        .filter_map(|shell| {
+            let source_path = Self::shell_source_path(shell)?;
+            ShellConfigManager::preview_remove_source_for_shell(shell, source_path).unwrap_or_else(
+                |e| {
+                    trace_debug!("Failed to preview completion config for {}: {}", shell, e);
+                    None
+                },
+            )
+        })
+        .collect()
+    }
+
+    /// 获取卸载时将删除的 completion 文件和目录
+    ///
+    /// 包括所有 shell 类型已存在的 completion 脚本、completion 目录和 `.completions` 配置文件。
+    ///
+    /// # 返回
+    ///
+    /// 返回已存在的文件和目录路径列表。
+    ///
+    /// # 错误
+    ///
+    /// 如果无法获取 completion 目录或本地数据目录，返回相应的错误信息。
+    pub fn installed_completion_paths() -> Result<Vec<PathBuf>> {
+        let completion_dir = Paths::completion_dir()?;
+        let commands = Paths::command_names();
+
+        let mut paths: Vec<PathBuf> = super::helpers::get_all_completion_files(commands)
+            .iter()
+            .map(|name| completion_dir.join(name))
+            .filter(|path| path.exists())
+            .collect();
+        if completion_dir.exists() {
+            paths.push(completion_dir);
+        }
+
+        let config_file = Paths::local_base_dir()?.join(COMPLETIONS_FILE);
+        if config_file.exists() {
+            paths.push(config_file);
+        }
+
+        Ok(paths)
+    }
+
     /// 检查 shell 是否已配置 completion（用于移除）
     ///
     /// 这是一个简化版本，只检查是否存在配置，不返回路径。
@@ -417,6 +506,49 @@ impl Completion {
         }
     }
 
+    /// 安装 completion
+    ///
+    /// 将 completion 脚本生成到 completion 目录（`Paths::completion_dir()`），
+    /// 并在 shell 配置文件中启用（见 `configure_shell_config`，已配置时不重复添加）。
+    ///
+    /// # 参数
+    ///
+    /// * `shell` - Shell 类型
+    ///
+    /// # 返回
+    ///
+    /// 返回 shell 配置文件的配置结果。
+    ///
+    /// # 错误
+    ///
+    /// 如果 clap 不支持该 shell（如 nushell）、生成脚本或修改配置文件失败，返回相应的错误信息。
+    pub fn install(shell: &Shell) -> Result<CompletionConfigResult> {
+        if shell.completion_shell().is_none() {
+            color_eyre::eyre::bail!("Completion scripts cannot be generated for {}", shell);
+        }
+
+        let completion_dir = Paths::completion_dir()?;
+        Self::generate_all_completions(
+            Some(shell.to_string()),
+            Some(completion_dir.to_string_lossy().to_string()),
+        )?;
+
+        Self::configure_shell_config(shell)
+    }
+
+    /// 检查 completion 目录中的脚本是否与当前 CLI 定义一致
+    ///
+    /// # 参数
+    ///
+    /// * `shell` - Shell 类型
+    ///
+    /// # 错误
+    ///
+    /// 如果 clap 不支持该 shell（如 nushell）或读取脚本失败，返回相应的错误信息。
+    pub fn script_status(shell: &Shell) -> Result<CompletionScriptStatus> {
+        CompletionGenerator::new(Some(shell.to_string()), None)?.script_status()
+    }
+
     /// 生成所有 completion 脚本文件
     ///
     /// 为所有命令生成 completion 脚本：
@@ -425,8 +557,7 @@ impl Completion {
         shell_type: Option<String>,
         output_dir: Option<String>,
     ) -> Result<()> {
-        let _ =
-            super::generate::CompletionGenerator::new(shell_type, output_dir)?.generate_all()?;
+        let _ = CompletionGenerator::new(shell_type, output_dir)?.generate_all()?;
         Ok(())
     }
 }
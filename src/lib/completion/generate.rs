@@ -2,6 +2,7 @@
 //!
 //! 提供生成各种 shell 的 completion 脚本文件的功能。
 
+use std::fmt;
 use std::path::PathBuf;
 
 use clap::{Command, CommandFactory};
@@ -11,6 +12,7 @@ use color_eyre::{eyre::WrapErr, Result};
 use super::helpers::get_completion_filename;
 use crate::base::alias::AliasManager;
 use crate::base::settings::paths::Paths;
+use crate::base::util::checksum::Checksum;
 use crate::base::util::directory::DirectoryWalker;
 use crate::base::util::file::FileWriter;
 
@@ -21,6 +23,39 @@ pub struct GenerateResult {
     pub messages: Vec<String>,
 }
 
+/// 已安装的 completion 脚本状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionScriptStatus {
+    /// 脚本与当前 CLI 定义一致
+    UpToDate,
+    /// 脚本已过期（CLI 命令或别名有变化，需要重新安装）
+    Stale,
+    /// 脚本不存在
+    Missing,
+}
+
+impl CompletionScriptStatus {
+    /// 状态描述（`up to date` / `stale` / `missing`）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UpToDate => "up to date",
+            Self::Stale => "stale",
+            Self::Missing => "missing",
+        }
+    }
+
+    /// 是否需要重新生成脚本
+    pub fn needs_regeneration(&self) -> bool {
+        !matches!(self, Self::UpToDate)
+    }
+}
+
+impl fmt::Display for CompletionScriptStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Completion 脚本生成器
 ///
 /// 提供生成各种 shell 的 completion 脚本文件的功能。
@@ -140,6 +175,37 @@ impl CompletionGenerator {
         })
     }
 
+    /// 检查输出目录中的 completion 脚本是否与当前 CLI 定义一致
+    ///
+    /// 按当前的 CLI 定义和别名配置重新生成脚本内容（不写入文件），
+    /// 比较其 SHA256 校验和与已安装文件的校验和。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `CompletionScriptStatus`。
+    ///
+    /// # 错误
+    ///
+    /// 如果计算已有脚本的校验和失败，返回相应的错误信息。
+    pub fn script_status(&self) -> Result<CompletionScriptStatus> {
+        let command_name = "workflow";
+        let output_file = self.output_file(command_name)?;
+        if !output_file.exists() {
+            return Ok(CompletionScriptStatus::Missing);
+        }
+
+        let installed = Checksum::calculate_file_sha256(&output_file)?;
+        let expected = Checksum::calculate_sha256(
+            &self.render_completion(&mut crate::cli::Cli::command(), command_name)?,
+        );
+
+        Ok(if installed == expected {
+            CompletionScriptStatus::UpToDate
+        } else {
+            CompletionScriptStatus::Stale
+        })
+    }
+
     /// 生成 workflow 命令的 completion
     ///
     /// 使用实际的 CLI 结构体自动生成补全脚本，确保补全脚本与实际命令结构保持同步。
@@ -152,13 +218,13 @@ impl CompletionGenerator {
         self.generate_completion(&mut cmd, "workflow")
     }
 
-    /// 生成单个命令的 completion（通用方法）
+    /// 生成单个命令的 completion 脚本内容（不写入文件）
     ///
     /// # 参数
     ///
     /// * `cmd` - clap Command 实例
     /// * `command_name` - 命令名称（"workflow"）
-    fn generate_completion(&self, cmd: &mut Command, command_name: &str) -> Result<()> {
+    fn render_completion(&self, cmd: &mut Command, command_name: &str) -> Result<Vec<u8>> {
         let mut buffer = Vec::new();
         generate(self.shell, cmd, command_name, &mut buffer);
 
@@ -170,9 +236,24 @@ impl CompletionGenerator {
             self.append_dynamic_completion(&mut buffer, command_name)?;
         }
 
-        let shell_type_str = self.shell.to_string();
-        let filename = get_completion_filename(&shell_type_str, command_name)?;
-        let output_file = self.output_dir.join(&filename);
+        Ok(buffer)
+    }
+
+    /// 单个命令的 completion 脚本文件路径
+    fn output_file(&self, command_name: &str) -> Result<PathBuf> {
+        let filename = get_completion_filename(&self.shell.to_string(), command_name)?;
+        Ok(self.output_dir.join(filename))
+    }
+
+    /// 生成单个命令的 completion（通用方法）
+    ///
+    /// # 参数
+    ///
+    /// * `cmd` - clap Command 实例
+    /// * `command_name` - 命令名称（"workflow"）
+    fn generate_completion(&self, cmd: &mut Command, command_name: &str) -> Result<()> {
+        let buffer = self.render_completion(cmd, command_name)?;
+        let output_file = self.output_file(command_name)?;
 
         FileWriter::new(&output_file).write_bytes(&buffer).wrap_err_with(|| {
             format!(
@@ -220,6 +301,15 @@ impl CompletionGenerator {
         Ok(())
     }
 
+    /// 按别名排序的别名列表
+    fn sorted_aliases(
+        aliases: &std::collections::HashMap<String, String>,
+    ) -> Vec<(&String, &String)> {
+        let mut sorted: Vec<_> = aliases.iter().collect();
+        sorted.sort();
+        sorted
+    }
+
     /// 生成 zsh 别名补全函数
     ///
     /// 在 zsh 补全脚本中添加别名展开支持。
@@ -244,8 +334,8 @@ impl CompletionGenerator {
         code.push_str("    local first_arg=${words[2]}\n");
         code.push_str("    case \"$first_arg\" in\n");
 
-        // 为每个别名生成 case 分支
-        for (alias, command) in aliases {
+        // 为每个别名生成 case 分支（按别名排序，保证生成结果稳定）
+        for (alias, command) in Self::sorted_aliases(aliases) {
             code.push_str(&format!("      {})\n", alias));
             code.push_str("        # Expand alias and rebuild words array\n");
             // 将别名展开为命令，并分割为多个词
@@ -302,8 +392,8 @@ impl CompletionGenerator {
         code.push_str("    local first_arg=${COMP_WORDS[1]}\n");
         code.push_str("    case \"$first_arg\" in\n");
 
-        // 为每个别名生成 case 分支
-        for (alias, command) in aliases {
+        // 为每个别名生成 case 分支（按别名排序，保证生成结果稳定）
+        for (alias, command) in Self::sorted_aliases(aliases) {
             code.push_str(&format!("      {})\n", alias));
             code.push_str("        # Expand alias and rebuild COMP_WORDS array\n");
             // 将别名展开为命令，并分割为多个词
@@ -4,7 +4,7 @@ pub mod generate;
 pub mod helpers;
 
 pub use completion::{Completion, CompletionConfigResult, CompletionRemovalResult};
-pub use generate::CompletionGenerator;
+pub use generate::{CompletionGenerator, CompletionScriptStatus};
 pub use helpers::{
     get_all_completion_files, get_completion_filename, get_completion_files_for_shell,
 };
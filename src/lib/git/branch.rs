@@ -1,7 +1,7 @@
 use color_eyre::{eyre::WrapErr, Result};
 use std::collections::HashSet;
 
-use super::GitCommand;
+use super::{GitCommand, GitCommit};
 
 const COMMON_DEFAULT_BRANCHES: &[&str] = &["main", "master", "develop", "dev"];
 /// 尝试使用 git switch，失败时回退到 git checkout
@@ -734,7 +734,8 @@ impl GitBranch {
 
         match strategy {
             MergeStrategy::Merge => {
-                // 普通合并，不需要额外参数
+                // 普通合并会创建合并 commit，按配置签名
+                args.extend(GitCommit::sign_args());
             }
             MergeStrategy::Squash => {
                 args.push("--squash");
@@ -816,6 +817,13 @@ impl GitBranch {
         Ok(false)
     }
 
+    /// 检查是否有进行中的 rebase（如因冲突停止）
+    ///
+    /// 使用 `REBASE_HEAD` 判断：rebase 停止在某个提交上时 Git 会创建它。
+    pub fn is_rebase_in_progress() -> bool {
+        GitCommand::new(["rev-parse", "--verify", "--quiet", "REBASE_HEAD"]).quiet_success()
+    }
+
     /// 检查分支是否已合并到指定分支
     ///
     /// 使用 `git branch --merged` 检查指定分支是否已合并到基础分支。
@@ -875,6 +883,37 @@ impl GitBranch {
         Ok(commits)
     }
 
+    /// 获取分支上尚未推送到远程的提交
+    ///
+    /// 使用 `git rev-list <branch> --not --remotes` 获取不被任何远程跟踪分支包含的提交。
+    /// 远程分支通过平台 API 删除后，本地的远程跟踪分支在 `git fetch --prune` 之前仍然保留，
+    /// 因此可以在删除远程分支后判断本地分支是否还有未推送的提交。
+    ///
+    /// # 参数
+    ///
+    /// * `branch_name` - 本地分支名称
+    ///
+    /// # 返回
+    ///
+    /// 返回提交哈希列表（从新到旧），没有未推送的提交时返回空列表。
+    ///
+    /// # 错误
+    ///
+    /// 如果分支不存在或命令执行失败，返回相应的错误信息。
+    pub fn get_unpushed_commits(branch_name: &str) -> Result<Vec<String>> {
+        let output = GitCommand::new(["rev-list", branch_name, "--not", "--remotes"])
+            .read()
+            .wrap_err_with(|| {
+                format!("Failed to get unpushed commits of branch: {}", branch_name)
+            })?;
+
+        Ok(output
+            .lines()
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .collect())
+    }
+
     /// 获取两个分支的共同祖先（merge base）
     ///
     /// 使用 `git merge-base` 获取两个分支的共同祖先提交。
@@ -912,6 +951,38 @@ impl GitBranch {
         Ok(merge_base)
     }
 
+    /// 获取两个引用之间修改过的文件列表
+    ///
+    /// 使用 `git diff --name-only <base> <head>` 获取从 base 到 head 修改过的文件。
+    ///
+    /// # 参数
+    ///
+    /// * `base` - 起始引用（通常是 merge-base）
+    /// * `head` - 结束引用（分支名、远程分支或 commit SHA）
+    ///
+    /// # 返回
+    ///
+    /// 返回修改过的文件路径列表（相对于仓库根目录）。
+    ///
+    /// # 错误
+    ///
+    /// 如果引用不存在或命令执行失败，返回相应的错误信息。
+    pub fn get_changed_files_between(base: &str, head: &str) -> Result<Vec<String>> {
+        let output =
+            GitCommand::new(["diff", "--name-only", base, head]).read().wrap_err_with(|| {
+                format!(
+                    "Failed to get changed files between '{}' and '{}'",
+                    base, head
+                )
+            })?;
+
+        Ok(output
+            .lines()
+            .map(|line| line.trim().to_string())
+            .filter(|line| !line.is_empty())
+            .collect())
+    }
+
     /// 检查一个分支是否直接基于另一个分支创建
     ///
     /// 通过比较 merge-base 和候选分支的 HEAD 来判断 from_branch 是否直接基于 candidate_branch 创建。
@@ -5,11 +5,55 @@
 //! - 应用提交但不提交（保留在工作区）
 //! - 继续或中止 cherry-pick 操作
 //! - 检查 cherry-pick 操作状态
+//! - 检测已经应用到目标分支的提交（`git cherry`）
 
 use color_eyre::{eyre::WrapErr, Result};
 
 use super::GitCommand;
 
+/// `git cherry` 输出中的一个提交
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CherryCommit {
+    /// 提交 SHA
+    pub sha: String,
+    /// 上游分支是否已包含等价的提交（patch-id 相同，`git cherry` 输出中以 `-` 开头）
+    pub applied: bool,
+}
+
+/// 解析 `git cherry` 的输出
+///
+/// 每行格式为 `+ <sha>`（上游没有等价提交）或 `- <sha>`（上游已有等价提交），
+/// 无法识别的行会被忽略。
+///
+/// # 示例
+///
+/// ```
+/// use workflow::git::parse_cherry_output;
+///
+/// let commits = parse_cherry_output("+ abc123\n- def456\n");
+/// assert_eq!(commits.len(), 2);
+/// assert!(!commits[0].applied);
+/// assert!(commits[1].applied);
+/// assert_eq!(commits[1].sha, "def456");
+/// ```
+pub fn parse_cherry_output(output: &str) -> Vec<CherryCommit> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let (mark, sha) = line.trim().split_once(' ')?;
+            let applied = match mark {
+                "+" => false,
+                "-" => true,
+                _ => return None,
+            };
+            Some(CherryCommit {
+                sha: sha.trim().to_string(),
+                applied,
+            })
+        })
+        .collect()
+}
+
 /// Git Cherry-pick 管理
 ///
 /// 提供 cherry-pick 相关的操作功能，包括：
@@ -90,6 +134,30 @@ impl GitCherryPick {
             .wrap_err("Failed to abort cherry-pick")
     }
 
+    /// 比较 head 上的提交是否已经应用到 upstream
+    ///
+    /// 使用 `git cherry <upstream> <head>` 按 patch-id 比较：列出 head 上所有不能从
+    /// upstream 到达的提交（从旧到新），并标记 upstream 上是否已有等价的提交
+    /// （例如已经被 cherry-pick 过）。能从 upstream 到达的提交不会出现在结果中。
+    ///
+    /// # 参数
+    ///
+    /// * `upstream` - 上游分支（要应用提交的目标分支）
+    /// * `head` - 提交来源分支
+    ///
+    /// # 错误
+    ///
+    /// 如果分支不存在或命令执行失败，返回相应的错误信息。
+    pub fn cherry(upstream: &str, head: &str) -> Result<Vec<CherryCommit>> {
+        let output = GitCommand::new(["cherry", upstream, head]).read().wrap_err_with(|| {
+            format!(
+                "Failed to compare commits of '{}' with '{}'",
+                head, upstream
+            )
+        })?;
+        Ok(parse_cherry_output(&output))
+    }
+
     /// 检查是否正在进行 cherry-pick 操作
     ///
     /// 通过检查 `.git/CHERRY_PICK_HEAD` 文件是否存在来判断。
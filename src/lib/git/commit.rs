@@ -11,6 +11,7 @@ use color_eyre::{eyre::WrapErr, Result};
 
 use super::pre_commit::GitPreCommit;
 use super::GitCommand;
+use crate::base::settings::Settings;
 
 /// Git 提交结果
 #[derive(Debug, Clone)]
@@ -165,6 +166,7 @@ impl GitCommit {
         if no_verify {
             args.push("--no-verify");
         }
+        args.extend(Self::sign_args());
 
         // 如果已经通过 Rust 代码执行了检查，设置环境变量告诉 hook 脚本跳过执行
         if should_skip_hook {
@@ -350,6 +352,18 @@ impl GitCommit {
         GitCommand::new(args).run().wrap_err("Failed to add files")
     }
 
+    /// 启用提交签名（`[commit] sign = true`）时追加的参数
+    ///
+    /// `-S` 按 Git 的 `gpg.format` 使用 GPG 或 SSH 签名，密钥来自 `user.signingkey`；
+    /// 未启用时返回空列表，由 Git 自己的 `commit.gpgsign` 配置决定。
+    pub fn sign_args() -> &'static [&'static str] {
+        if Settings::get().commit.is_signing_enabled() {
+            &["-S"]
+        } else {
+            &[]
+        }
+    }
+
     /// 执行 commit amend
     ///
     /// # 参数
@@ -388,6 +402,7 @@ impl GitCommit {
         if no_verify {
             args.push("--no-verify");
         }
+        args.extend(Self::sign_args());
 
         // 如果已经通过 Rust 代码执行了检查，设置环境变量告诉 hook 脚本跳过执行
         if should_skip_hook {
@@ -553,12 +568,25 @@ impl GitCommit {
     ///
     /// 返回 CommitInfo 列表，按时间顺序排列（从旧到新，第一个是最接近 from_commit 的 commit）。
     pub fn get_commits_from_to_head(from_commit: &str) -> Result<Vec<CommitInfo>> {
-        // 使用 git log 获取从 from_commit 到 HEAD 的所有 commits
-        // git log from_commit..HEAD --format="%H|%s|%an <%ae>|%ai" --reverse
+        Self::get_commits_in_range(from_commit, "HEAD")
+    }
+
+    /// 获取 `to` 上有、`from` 上没有的所有 commits（`from..to`）
+    ///
+    /// # 参数
+    ///
+    /// * `from` - 起始引用（不包括此引用可达的 commits）
+    /// * `to` - 结束引用（分支名、远程分支或 commit SHA）
+    ///
+    /// # 返回
+    ///
+    /// 返回 CommitInfo 列表，按时间顺序排列（从旧到新）。
+    pub fn get_commits_in_range(from: &str, to: &str) -> Result<Vec<CommitInfo>> {
+        // git log from..to --format="%H|%s|%an <%ae>|%ai" --reverse
         // --reverse 表示从旧到新排列
         let output = GitCommand::new([
             "log",
-            &format!("{}..HEAD", from_commit),
+            &format!("{}..{}", from, to),
             "--format=%H|%s|%an <%ae>|%ai",
             "--reverse",
         ])
@@ -584,6 +612,82 @@ impl GitCommit {
         Ok(commits)
     }
 
+    /// 获取 `from..to` 范围内所有 commits 的完整提交消息
+    ///
+    /// # 参数
+    ///
+    /// * `from` - 起始引用（不包括此引用可达的 commits）
+    /// * `to` - 结束引用
+    ///
+    /// # 返回
+    ///
+    /// 返回完整提交消息（标题和正文，去除首尾空白）列表，按时间顺序排列（从旧到新）。
+    pub fn get_commit_messages_in_range(from: &str, to: &str) -> Result<Vec<String>> {
+        // 使用 NUL 分隔每个 commit 的消息，消息正文中可能包含任意换行
+        let output = GitCommand::new([
+            "log",
+            &format!("{}..{}", from, to),
+            "--format=%B%x00",
+            "--reverse",
+        ])
+        .read()?;
+
+        Ok(output
+            .split('\0')
+            .map(|message| message.trim().to_string())
+            .filter(|message| !message.is_empty())
+            .collect())
+    }
+
+    /// 获取 `to` 相对于 `from` 的修改内容（`git diff from...to`）
+    ///
+    /// 使用三点语法，比较 `to` 与两者的合并基准，`from` 上新增的提交不会出现在 diff 中。
+    ///
+    /// # 参数
+    ///
+    /// * `from` - 基准引用（如默认分支）
+    /// * `to` - 结束引用（如 `HEAD`）
+    ///
+    /// # 返回
+    ///
+    /// 返回 diff 内容，没有修改或 Git 命令执行失败时返回 `None`。
+    pub fn get_diff_in_range(from: &str, to: &str) -> Option<String> {
+        GitCommand::new(["diff", &format!("{}...{}", from, to)])
+            .read()
+            .ok()
+            .filter(|diff| !diff.trim().is_empty())
+    }
+
+    /// 提交暂存区的更改（不自动暂存其他文件）
+    ///
+    /// 用于 `git merge --squash` 之后提交已暂存的合并结果。
+    ///
+    /// # 参数
+    ///
+    /// * `message` - 提交消息
+    ///
+    /// # 返回
+    ///
+    /// 返回 `CommitResult`；暂存区没有更改时不提交（`committed` 为 `false`）。
+    pub fn commit_staged(message: &str) -> Result<CommitResult> {
+        // git diff --cached --quiet 在暂存区没有更改时成功
+        if GitCommand::new(["diff", "--cached", "--quiet"]).quiet_success() {
+            return Ok(CommitResult {
+                committed: false,
+                message: Some("Nothing staged to commit".to_string()),
+            });
+        }
+
+        let mut args = vec!["commit", "-m", message];
+        args.extend(Self::sign_args());
+        GitCommand::new(args).run().wrap_err("Failed to commit")?;
+
+        Ok(CommitResult {
+            committed: true,
+            message: None,
+        })
+    }
+
     /// 获取工作区状态统计
     ///
     /// 解析 Git status 输出，统计已修改、已暂存和未跟踪的文件数量。
@@ -2,13 +2,180 @@
 //!
 //! 本模块提供了 Git 配置相关的功能，包括：
 //! - 设置全局 user.email 和 user.name
+//! - 设置仓库级（local）或全局（global）的 Git 用户身份
+//! - 切换 Git 用户身份（记录切换前的身份，切换回来时恢复）
 //! - 读取 Git 配置
+//! - 读取提交签名配置（`gpg.format`、`user.signingkey`）
+//! - 读写受支持的配置项（`MANAGED_GIT_CONFIG_KEYS`，写入前校验）
+
+use std::fmt;
 
 use color_eyre::{eyre::WrapErr, Result};
 
 use super::GitCommand;
 use crate::trace_info;
 
+/// 可以通过 `workflow config git` 读写的配置项
+pub const MANAGED_GIT_CONFIG_KEYS: [&str; 5] = [
+    "user.name",
+    "user.email",
+    "user.signingkey",
+    "gpg.format",
+    "commit.gpgsign",
+];
+
+/// 切换前身份的邮箱（保存在同一作用域的 Git 配置中）
+const PREVIOUS_EMAIL_KEY: &str = "workflow.previousUserEmail";
+/// 切换前身份的名称（保存在同一作用域的 Git 配置中）
+const PREVIOUS_NAME_KEY: &str = "workflow.previousUserName";
+
+/// Git 配置作用域
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitConfigScope {
+    /// 当前仓库（`.git/config`）
+    Local,
+    /// 当前用户（`~/.gitconfig`）
+    Global,
+}
+
+impl GitConfigScope {
+    /// `git config` 的作用域参数
+    fn flag(&self) -> &'static str {
+        match self {
+            Self::Local => "--local",
+            Self::Global => "--global",
+        }
+    }
+
+    /// 作用域名称（用于显示）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Local => "local",
+            Self::Global => "global",
+        }
+    }
+}
+
+/// Git 用户身份（user.email 和 user.name）
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GitUserIdentity {
+    /// 用户邮箱（未设置时为 `None`）
+    pub email: Option<String>,
+    /// 用户名称（未设置时为 `None`）
+    pub name: Option<String>,
+}
+
+impl GitUserIdentity {
+    /// 是否未设置任何身份信息
+    pub fn is_empty(&self) -> bool {
+        self.email.is_none() && self.name.is_none()
+    }
+}
+
+impl fmt::Display for GitUserIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "(not set)");
+        }
+        write!(
+            f,
+            "{} <{}>",
+            self.name.as_deref().unwrap_or("(no name)"),
+            self.email.as_deref().unwrap_or("no email")
+        )
+    }
+}
+
+/// Git 用户身份切换结果
+#[derive(Debug, Clone)]
+pub struct GitUserSwitch {
+    /// 切换的作用域
+    pub scope: GitConfigScope,
+    /// 切换前的身份
+    pub before: GitUserIdentity,
+    /// 切换后的身份
+    pub after: GitUserIdentity,
+    /// 是否恢复了之前记录的身份
+    pub restored: bool,
+}
+
+/// 提交签名格式（`gpg.format`）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GitSigningFormat {
+    /// GPG（`openpgp`，Git 的默认值）
+    Gpg,
+    /// SSH 密钥
+    Ssh,
+    /// X.509 证书（gpgsm）
+    X509,
+}
+
+impl GitSigningFormat {
+    /// 解析 `gpg.format` 的值（未设置或无法识别时与 Git 一致，使用 GPG）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::git::GitSigningFormat;
+    ///
+    /// assert_eq!(GitSigningFormat::parse(None), GitSigningFormat::Gpg);
+    /// assert_eq!(GitSigningFormat::parse(Some("openpgp")), GitSigningFormat::Gpg);
+    /// assert_eq!(GitSigningFormat::parse(Some("SSH")), GitSigningFormat::Ssh);
+    /// ```
+    pub fn parse(value: Option<&str>) -> Self {
+        match value.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+            Some("ssh") => Self::Ssh,
+            Some("x509") => Self::X509,
+            _ => Self::Gpg,
+        }
+    }
+
+    /// 格式名称（用于显示）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Gpg => "gpg",
+            Self::Ssh => "ssh",
+            Self::X509 => "x509",
+        }
+    }
+}
+
+/// 提交签名配置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitSigningConfig {
+    /// 签名格式（`gpg.format`）
+    pub format: GitSigningFormat,
+    /// 签名密钥（`user.signingkey`，未设置时为 `None`）
+    pub key: Option<String>,
+}
+
+impl GitSigningConfig {
+    /// 检查能否签名，返回无法签名的原因
+    ///
+    /// 设置了 `user.signingkey` 时视为可以签名。未设置时，SSH 签名无法选择密钥；
+    /// GPG 和 X.509 签名由 gpg/gpgsm 按提交者邮箱选择私钥，需要存在可用的私钥。
+    ///
+    /// # 参数
+    ///
+    /// * `has_default_key` - gpg/gpgsm 中是否存在私钥（SSH 签名时忽略）
+    pub fn issue(&self, has_default_key: bool) -> Option<String> {
+        if self.key.is_some() {
+            return None;
+        }
+        match self.format {
+            GitSigningFormat::Ssh => Some(
+                "SSH signing requires user.signingkey (e.g. git config user.signingkey ~/.ssh/id_ed25519.pub)"
+                    .to_string(),
+            ),
+            GitSigningFormat::Gpg | GitSigningFormat::X509 if !has_default_key => Some(format!(
+                "user.signingkey is not set and no {} secret key was found",
+                self.format.as_str()
+            )),
+            GitSigningFormat::Gpg | GitSigningFormat::X509 => None,
+        }
+    }
+}
+
 /// Git 配置结果
 #[derive(Debug, Clone)]
 pub struct GitConfigResult {
@@ -39,19 +206,41 @@ impl GitConfig {
     ///
     /// 如果 Git 命令执行失败，返回相应的错误信息。
     pub fn set_global_user(email: &str, name: &str) -> Result<GitConfigResult> {
-        trace_info!("Updating Git global config: email={}, name={}", email, name);
+        Self::set_user(GitConfigScope::Global, email, name)
+    }
+
+    /// 设置指定作用域的 Git 用户身份（email 和 name）
+    ///
+    /// # 参数
+    ///
+    /// * `scope` - 配置作用域（`Local` 需要在 Git 仓库中执行）
+    /// * `email` - 用户邮箱地址
+    /// * `name` - 用户名称
+    ///
+    /// # 返回
+    ///
+    /// 返回 `GitConfigResult`，包含设置后的 email 和 name。
+    ///
+    /// # 错误
+    ///
+    /// 如果 Git 命令执行失败（如 `Local` 作用域不在 Git 仓库中），返回相应的错误信息。
+    pub fn set_user(scope: GitConfigScope, email: &str, name: &str) -> Result<GitConfigResult> {
+        trace_info!(
+            "Updating Git {} config: email={}, name={}",
+            scope.as_str(),
+            email,
+            name
+        );
 
-        // 设置全局 user.email
-        GitCommand::new(["config", "--global", "user.email", email])
+        GitCommand::new(["config", scope.flag(), "user.email", email])
             .run()
-            .wrap_err("Failed to set git global user.email")?;
+            .wrap_err_with(|| format!("Failed to set git {} user.email", scope.as_str()))?;
 
-        // 设置全局 user.name
-        GitCommand::new(["config", "--global", "user.name", name])
+        GitCommand::new(["config", scope.flag(), "user.name", name])
             .run()
-            .wrap_err("Failed to set git global user.name")?;
+            .wrap_err_with(|| format!("Failed to set git {} user.name", scope.as_str()))?;
 
-        trace_info!("Git global config updated successfully");
+        trace_info!("Git {} config updated successfully", scope.as_str());
 
         Ok(GitConfigResult {
             email: email.to_string(),
@@ -59,28 +248,191 @@ impl GitConfig {
         })
     }
 
-    /// 读取 Git 全局配置
+    /// 读取指定作用域的 Git 用户身份
     ///
-    /// 读取 Git 的全局 user.email 和 user.name 配置。
+    /// 只读取该作用域中直接设置的值（不包含从其他作用域继承的值）。
+    ///
+    /// # 参数
+    ///
+    /// * `scope` - 配置作用域
+    pub fn get_user(scope: GitConfigScope) -> GitUserIdentity {
+        GitUserIdentity {
+            email: Self::get(scope, "user.email"),
+            name: Self::get(scope, "user.name"),
+        }
+    }
+
+    /// 切换指定作用域的 Git 用户身份
+    ///
+    /// - 第一次切换时，在同一作用域中记录切换前的身份（`workflow.previousUserEmail` / `workflow.previousUserName`）
+    /// - 切换到与记录的身份邮箱相同的账号时，恢复记录的身份（包括原来的 user.name）并清除记录
+    /// - 切换到其他账号时，保留最早记录的身份，直到切换回来
+    ///
+    /// # 参数
+    ///
+    /// * `scope` - 配置作用域
+    /// * `email` - 目标账号邮箱
+    /// * `name` - 目标账号名称
     ///
     /// # 返回
     ///
-    /// 返回一个元组 `(email, name)`，如果配置不存在则返回 `None`。
+    /// 返回 `GitUserSwitch`，包含切换前后的身份和是否恢复了记录的身份。
     ///
     /// # 错误
     ///
     /// 如果 Git 命令执行失败，返回相应的错误信息。
-    pub fn get_global_user() -> Result<(Option<String>, Option<String>)> {
-        let email = GitCommand::new(["config", "--global", "user.email"])
-            .read()
-            .ok()
-            .filter(|s| !s.is_empty());
+    pub fn switch_user(scope: GitConfigScope, email: &str, name: &str) -> Result<GitUserSwitch> {
+        let before = Self::get_user(scope);
+        let previous = GitUserIdentity {
+            email: Self::get(scope, PREVIOUS_EMAIL_KEY),
+            name: Self::get(scope, PREVIOUS_NAME_KEY),
+        };
+
+        let restored = previous.email.as_deref() == Some(email);
+        if restored {
+            Self::set_user(scope, email, previous.name.as_deref().unwrap_or(name))?;
+            for key in [PREVIOUS_EMAIL_KEY, PREVIOUS_NAME_KEY] {
+                // 键不存在时 git 返回非零退出码，忽略
+                GitCommand::new(["config", scope.flag(), "--unset", key]).quiet_success();
+            }
+        } else {
+            if previous.is_empty() && !before.is_empty() && before.email.as_deref() != Some(email) {
+                for (key, value) in [
+                    (PREVIOUS_EMAIL_KEY, &before.email),
+                    (PREVIOUS_NAME_KEY, &before.name),
+                ] {
+                    if let Some(value) = value {
+                        GitCommand::new(["config", scope.flag(), key, value])
+                            .run()
+                            .wrap_err("Failed to record previous git identity")?;
+                    }
+                }
+            }
+            Self::set_user(scope, email, name)?;
+        }
+
+        Ok(GitUserSwitch {
+            scope,
+            before,
+            after: Self::get_user(scope),
+            restored,
+        })
+    }
+
+    /// 读取当前生效的提交签名配置（`gpg.format` 和 `user.signingkey`，包含所有作用域）
+    pub fn get_signing_config() -> GitSigningConfig {
+        GitSigningConfig {
+            format: GitSigningFormat::parse(Self::get_effective("gpg.format").as_deref()),
+            key: Self::get_effective("user.signingkey"),
+        }
+    }
 
-        let name = GitCommand::new(["config", "--global", "user.name"])
+    /// 读取当前生效的 Git 用户身份（包含所有作用域，仓库配置优先）
+    pub fn get_effective_user() -> GitUserIdentity {
+        GitUserIdentity {
+            email: Self::get_effective("user.email"),
+            name: Self::get_effective("user.name"),
+        }
+    }
+
+    /// 读取当前生效的配置值（包含所有作用域，不存在或为空时返回 `None`）
+    pub fn get_effective(key: &str) -> Option<String> {
+        GitCommand::new(["config", "--get", key]).read().ok().filter(|s| !s.is_empty())
+    }
+
+    /// 读取指定作用域的配置值（不存在或为空时返回 `None`）
+    pub fn get(scope: GitConfigScope, key: &str) -> Option<String> {
+        GitCommand::new(["config", scope.flag(), key])
             .read()
             .ok()
-            .filter(|s| !s.is_empty());
+            .filter(|s| !s.is_empty())
+    }
+
+    /// 校验并写入指定作用域的配置值
+    ///
+    /// 只支持 `MANAGED_GIT_CONFIG_KEYS` 中的配置项，写入前通过 `validate_value` 校验。
+    ///
+    /// # 返回
+    ///
+    /// 返回实际写入的值（如 `commit.gpgsign` 的 `yes` 会写入为 `true`）。
+    ///
+    /// # 错误
+    ///
+    /// 配置项不受支持、值无效或 Git 命令执行失败时返回错误。
+    pub fn set(scope: GitConfigScope, key: &str, value: &str) -> Result<String> {
+        let value = Self::validate_value(key, value)?;
+        GitCommand::new(["config", scope.flag(), key, &value])
+            .run()
+            .wrap_err_with(|| format!("Failed to set git config {}", key))?;
+        trace_info!("Set git config {} ({}) = {}", key, scope.as_str(), value);
+        Ok(value)
+    }
 
-        Ok((email, name))
+    /// 校验配置值，返回规范化后的值
+    ///
+    /// - `user.name`、`user.signingkey`：不能为空
+    /// - `user.email`：需要包含 `@` 且不包含空白字符
+    /// - `gpg.format`：`openpgp`、`ssh` 或 `x509`（`gpg` 视为 `openpgp`）
+    /// - `commit.gpgsign`：布尔值（`true`/`false`、`yes`/`no`、`on`/`off`、`1`/`0`）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::git::GitConfig;
+    ///
+    /// assert_eq!(GitConfig::validate_value("gpg.format", "SSH").unwrap(), "ssh");
+    /// assert_eq!(GitConfig::validate_value("commit.gpgsign", "yes").unwrap(), "true");
+    /// assert!(GitConfig::validate_value("user.email", "not-an-email").is_err());
+    /// assert!(GitConfig::validate_value("core.editor", "vim").is_err());
+    /// ```
+    pub fn validate_value(key: &str, value: &str) -> Result<String> {
+        let value = value.trim();
+        match key {
+            "user.name" | "user.signingkey" if value.is_empty() => {
+                color_eyre::eyre::bail!("{} cannot be empty", key)
+            }
+            "user.name" | "user.signingkey" => Ok(value.to_string()),
+            "user.email" if !value.contains('@') || value.contains(char::is_whitespace) => {
+                color_eyre::eyre::bail!("Invalid email address '{}'", value)
+            }
+            "user.email" => Ok(value.to_string()),
+            "gpg.format" => match value.to_ascii_lowercase().as_str() {
+                "openpgp" | "gpg" => Ok("openpgp".to_string()),
+                format @ ("ssh" | "x509") => Ok(format.to_string()),
+                _ => color_eyre::eyre::bail!(
+                    "Invalid gpg.format '{}', expected one of: openpgp, ssh, x509",
+                    value
+                ),
+            },
+            "commit.gpgsign" => match value.to_ascii_lowercase().as_str() {
+                "true" | "yes" | "on" | "1" => Ok("true".to_string()),
+                "false" | "no" | "off" | "0" => Ok("false".to_string()),
+                _ => color_eyre::eyre::bail!(
+                    "Invalid commit.gpgsign '{}', expected true or false",
+                    value
+                ),
+            },
+            _ => color_eyre::eyre::bail!(
+                "Unsupported git config key '{}', expected one of: {}",
+                key,
+                MANAGED_GIT_CONFIG_KEYS.join(", ")
+            ),
+        }
+    }
+
+    /// 读取 Git 全局配置
+    ///
+    /// 读取 Git 的全局 user.email 和 user.name 配置。
+    ///
+    /// # 返回
+    ///
+    /// 返回一个元组 `(email, name)`，如果配置不存在则返回 `None`。
+    ///
+    /// # 错误
+    ///
+    /// 如果 Git 命令执行失败，返回相应的错误信息。
+    pub fn get_global_user() -> Result<(Option<String>, Option<String>)> {
+        let identity = Self::get_user(GitConfigScope::Global);
+        Ok((identity.email, identity.name))
     }
 }
@@ -9,6 +9,7 @@
 //! - Pre-commit hooks 支持（检测和执行）
 //! - 配置管理（设置和读取 Git 全局配置）
 //! - Tag 管理（列出、删除本地和远程 tag）
+//! - 语义化版本 tag（解析 tag、计算下一个版本）
 //!
 //! ## 模块结构
 //!
@@ -18,6 +19,7 @@
 //! - `repo` - 仓库检测和类型识别
 //! - `stash` - 暂存管理
 //! - `tag` - Tag 管理（`GitTag` 结构体）
+//! - `semver` - 语义化版本 tag（`SemVer` 结构体）
 //! - `pre_commit` - Pre-commit hooks 支持
 //! - `config` - Git 配置管理（`GitConfig` 结构体）
 //! - `types` - 类型定义（`RepoType` 枚举）
@@ -29,6 +31,7 @@ mod commit;
 mod config;
 mod pre_commit;
 mod repo;
+mod semver;
 mod stash;
 mod table;
 mod tag;
@@ -36,19 +39,25 @@ mod types;
 
 // 重新导出所有公共 API
 pub use branch::{GitBranch, MergeStrategy};
-pub use cherry_pick::GitCherryPick;
+pub use cherry_pick::{parse_cherry_output, CherryCommit, GitCherryPick};
 pub(crate) use command::GitCommand;
 pub use commit::{CommitInfo, GitCommit, WorktreeStatus};
-pub use config::GitConfig;
+pub use config::{
+    GitConfig, GitConfigScope, GitSigningConfig, GitSigningFormat, GitUserIdentity, GitUserSwitch,
+    MANAGED_GIT_CONFIG_KEYS,
+};
 pub use pre_commit::GitPreCommit;
 pub use repo::GitRepo;
+pub use semver::{PreRelease, SemVer, VersionBump};
 pub use stash::{GitStash, StashApplyResult, StashEntry, StashPopResult, StashStat};
-pub use table::BranchRow;
+pub use table::{BranchRow, TagRow};
 pub use tag::{GitTag, TagInfo};
 pub use types::RepoType;
 
 // GitHub 服务相关常量
 pub mod github {
+    use regex::Regex;
+
     /// GitHub API 基础 URL
     pub const API_BASE: &str = "https://api.github.com";
 
@@ -57,4 +66,32 @@ pub mod github {
 
     /// GitHub 域名
     pub const DOMAIN: &str = "github.com";
+
+    /// 从 GitHub Enterprise Server 仓库地址中提取 `owner/repo`
+    ///
+    /// 支持 `git@{host}:owner/repo.git`、`ssh://git@{host}[:port]/owner/repo.git`
+    /// 和 `https://{host}[:port]/owner/repo.git`，主机名不区分大小写。
+    /// 地址不属于 `host` 时返回 `None`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::git::github::extract_enterprise_repo;
+    ///
+    /// assert_eq!(
+    ///     extract_enterprise_repo("git@github.mycorp.com:team/app.git", "github.mycorp.com").as_deref(),
+    ///     Some("team/app")
+    /// );
+    /// assert_eq!(extract_enterprise_repo("git@github.com:team/app.git", "github.mycorp.com"), None);
+    /// ```
+    pub fn extract_enterprise_repo(url: &str, host: &str) -> Option<String> {
+        let host = regex::escape(host);
+        let pattern = format!(
+            r"(?i)^(?:(?:https?|ssh)://(?:[^@/]+@)?{host}(?::\d+)?/|(?:[^@/:]+@)?{host}:)(.+?)(?:\.git)?/?$"
+        );
+        let re = Regex::new(&pattern).ok()?;
+        re.captures(url.trim())
+            .and_then(|caps| caps.get(1))
+            .map(|m| m.as_str().to_string())
+    }
 }
@@ -4,12 +4,16 @@
 //! - 检测当前目录是否为 Git 仓库
 //! - 检测远程仓库类型（GitHub 等）
 //! - 获取远程仓库 URL
+//! - 查找仓库中的 PR 模板
+
+use std::path::{Path, PathBuf};
 
 use color_eyre::{eyre::eyre, eyre::WrapErr, Result};
 use regex::Regex;
 
 use super::types::RepoType;
-use super::GitCommand;
+use super::{github, GitCommand};
+use crate::base::settings::Settings;
 
 /// Git 仓库管理
 ///
@@ -18,8 +22,18 @@ use super::GitCommand;
 /// - 检测远程仓库类型（GitHub 等）
 /// - 获取远程仓库 URL
 /// - 从远程获取更新
+/// - 查找 PR 模板
 pub struct GitRepo;
 
+/// PR 模板所在的目录（相对于仓库根目录，按优先级排列）
+const PULL_REQUEST_TEMPLATE_DIRS: &[&str] = &[".github", "", "docs"];
+
+/// PR 模板文件名（不区分大小写）
+const PULL_REQUEST_TEMPLATE_FILE: &str = "pull_request_template.md";
+
+/// 多模板目录名（不区分大小写）
+const PULL_REQUEST_TEMPLATE_SUBDIR: &str = "pull_request_template";
+
 impl GitRepo {
     /// 检查是否在 Git 仓库中
     ///
@@ -32,7 +46,7 @@ impl GitRepo {
         GitCommand::new(["rev-parse", "--git-dir", "--quiet"]).quiet_success()
     }
 
-    /// 检测远程仓库类型（GitHub）
+    /// 检测远程仓库类型（GitHub、Azure DevOps 等）
     ///
     /// 通过解析远程仓库 URL 来识别仓库类型。
     ///
@@ -51,7 +65,8 @@ impl GitRepo {
     /// 从 URL 解析仓库类型
     ///
     /// 通过检查 URL 中是否包含特定域名来识别仓库类型。
-    /// 支持识别 SSH Host 别名（如 `github-brainim`）。
+    /// 支持识别 SSH Host 别名（如 `github-brainim`）和配置的 GitHub Enterprise Server
+    /// 主机（`github.web_base_url`）。
     ///
     /// # 参数
     ///
@@ -59,19 +74,44 @@ impl GitRepo {
     ///
     /// # 返回
     ///
+    /// 返回对应的 `RepoType`，规则见 `parse_repo_type_from_url_with_github_host()`。
+    pub fn parse_repo_type_from_url(url: &str) -> RepoType {
+        let enterprise_host = Settings::get().github.enterprise_host();
+        Self::parse_repo_type_from_url_with_github_host(url, enterprise_host.as_deref())
+    }
+
+    /// 从 URL 解析仓库类型（指定 GitHub Enterprise Server 主机）
+    ///
+    /// # 参数
+    ///
+    /// * `url` - 远程仓库 URL
+    /// * `enterprise_host` - GitHub Enterprise Server 主机名（如 `github.mycorp.com`）
+    ///
+    /// # 返回
+    ///
     /// 返回对应的 `RepoType`：
-    /// - 包含 `github.com` 或 host 以 `github` 开头 → `RepoType::GitHub`
+    /// - 属于 `enterprise_host`、包含 `github.com` 或 host 以 `github` 开头 → `RepoType::GitHub`
     /// - 包含 `codeup.aliyun.com` → `RepoType::Codeup`（检测支持，但 PR 功能不支持）
+    /// - 包含 `dev.azure.com` 或 `visualstudio.com` → `RepoType::AzureDevOps`
     /// - 其他 → `RepoType::Unknown`
-    fn parse_repo_type_from_url(url: &str) -> RepoType {
+    pub fn parse_repo_type_from_url_with_github_host(
+        url: &str,
+        enterprise_host: Option<&str>,
+    ) -> RepoType {
+        let is_enterprise = enterprise_host
+            .is_some_and(|host| github::extract_enterprise_repo(url, host).is_some());
+
         // 检查 GitHub：包含 github.com 或 SSH host 以 github 开头（处理 SSH Host 别名，如 git@github-brainim:user/repo.git）
-        if url.contains("github.com")
+        if is_enterprise
+            || url.contains("github.com")
             || url.starts_with("git@github")
             || url.starts_with("ssh://git@github")
         {
             RepoType::GitHub
         } else if url.contains("codeup.aliyun.com") {
             RepoType::Codeup
+        } else if url.contains("dev.azure.com") || url.contains("visualstudio.com") {
+            RepoType::AzureDevOps
         } else {
             RepoType::Unknown
         }
@@ -111,6 +151,82 @@ impl GitRepo {
             .wrap_err("Failed to get git directory")
     }
 
+    /// 获取仓库根目录
+    ///
+    /// 使用 `git rev-parse --show-toplevel` 获取工作区根目录。
+    ///
+    /// # 错误
+    ///
+    /// 如果不在 Git 仓库中或命令执行失败，返回相应的错误信息。
+    pub fn get_root_dir() -> Result<PathBuf> {
+        GitCommand::new(["rev-parse", "--show-toplevel"])
+            .read()
+            .map(PathBuf::from)
+            .wrap_err("Failed to get repository root directory")
+    }
+
+    /// 查找当前仓库中的 PR 模板
+    ///
+    /// 参见 [`GitRepo::find_pull_request_templates_in`]。
+    pub fn find_pull_request_templates() -> Result<Vec<PathBuf>> {
+        Ok(Self::find_pull_request_templates_in(&Self::get_root_dir()?))
+    }
+
+    /// 查找指定目录中的 PR 模板
+    ///
+    /// 依次查找 `.github/`、仓库根目录和 `docs/` 中的：
+    /// - `PULL_REQUEST_TEMPLATE.md`（文件名不区分大小写）
+    /// - `PULL_REQUEST_TEMPLATE/` 目录下的所有 `.md` 文件（多模板，按文件名排序）
+    ///
+    /// # 参数
+    ///
+    /// * `root` - 仓库根目录
+    ///
+    /// # 返回
+    ///
+    /// 返回找到的模板路径列表，没有模板时返回空列表。
+    pub fn find_pull_request_templates_in(root: &Path) -> Vec<PathBuf> {
+        let mut templates = Vec::new();
+
+        for dir in PULL_REQUEST_TEMPLATE_DIRS {
+            let Ok(entries) = std::fs::read_dir(root.join(dir)) else {
+                continue;
+            };
+            let mut entries: Vec<PathBuf> = entries.flatten().map(|e| e.path()).collect();
+            entries.sort();
+
+            for path in entries {
+                let name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().to_lowercase())
+                    .unwrap_or_default();
+                if path.is_file() && name == PULL_REQUEST_TEMPLATE_FILE {
+                    templates.push(path);
+                } else if path.is_dir() && name == PULL_REQUEST_TEMPLATE_SUBDIR {
+                    templates.extend(Self::list_markdown_files(&path));
+                }
+            }
+        }
+
+        templates
+    }
+
+    /// 列出目录中的 Markdown 文件（按文件名排序）
+    fn list_markdown_files(dir: &Path) -> Vec<PathBuf> {
+        let Ok(entries) = std::fs::read_dir(dir) else {
+            return Vec::new();
+        };
+        let mut files: Vec<PathBuf> = entries
+            .flatten()
+            .map(|e| e.path())
+            .filter(|path| {
+                path.is_file() && path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("md"))
+            })
+            .collect();
+        files.sort();
+        files
+    }
+
     /// 从远程仓库获取更新
     ///
     /// 使用 `git fetch origin` 从远程仓库获取最新的分支和提交信息。
@@ -160,6 +276,7 @@ impl GitRepo {
     /// - GitHub SSH: git@github.com:owner/repo.git
     /// - GitHub SSH (别名): git@github-brainim:owner/repo.git
     /// - GitHub HTTPS: https://github.com/owner/repo.git
+    /// - GitHub Enterprise Server（`github.web_base_url` 配置的主机）: git@github.mycorp.com:owner/repo.git
     /// - Codeup SSH: git@codeup.aliyun.com:owner/repo.git
     /// - Codeup HTTPS: https://codeup.aliyun.com/owner/repo.git
     /// - Codeup HTTP: http://codeup.aliyun.com/owner/repo
@@ -176,6 +293,13 @@ impl GitRepo {
     ///
     /// 如果无法从 URL 中提取仓库名，返回相应的错误信息。
     pub fn extract_repo_name_from_url(url: &str) -> Result<String> {
+        // GitHub Enterprise Server（主机名可能不以 github 开头）
+        if let Some(host) = Settings::get().github.enterprise_host() {
+            if let Some(repo) = github::extract_enterprise_repo(url, &host) {
+                return Ok(repo);
+            }
+        }
+
         // GitHub SSH 格式: git@github.com:owner/repo.git 或 git@github-xxx:owner/repo.git
         let github_ssh_re =
             Regex::new(r"git@github[^:]*:(.+?)(?:\.git)?$").wrap_err("Invalid regex pattern")?;
@@ -0,0 +1,261 @@
+//! 语义化版本 tag
+//!
+//! 解析形如 `<prefix>MAJOR.MINOR.PATCH[-CHANNEL[.N]]` 的 tag（如 `v1.2.3`、`v1.3.0-rc.2`），
+//! 并根据已有 tag 计算下一个版本，供 `workflow tag next` 使用。
+//!
+//! 下一个版本的计算规则：
+//! - 以最新的正式版本（不带预发布标识）为基准按 `major`/`minor`/`patch` 递增，没有正式版本时从 `0.0.0` 开始
+//! - 指定预发布通道（如 `rc`）时，在递增后的版本上追加 `-<channel>.N`，
+//!   `N` 为该版本在同一通道下已有的最大序号加 1（从 1 开始）
+
+use std::cmp::Ordering;
+use std::fmt;
+
+use color_eyre::Result;
+
+/// 版本递增方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionBump {
+    /// 主版本号（`1.2.3` → `2.0.0`）
+    Major,
+    /// 次版本号（`1.2.3` → `1.3.0`）
+    Minor,
+    /// 修订号（`1.2.3` → `1.2.4`）
+    Patch,
+}
+
+/// 预发布标识（`-<channel>.<number>`）
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct PreRelease {
+    /// 预发布通道（如 `alpha`、`beta`、`rc`）
+    pub channel: String,
+    /// 通道内的序号（`-rc` 视为 0）
+    pub number: u64,
+}
+
+/// 语义化版本
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SemVer {
+    /// 主版本号
+    pub major: u64,
+    /// 次版本号
+    pub minor: u64,
+    /// 修订号
+    pub patch: u64,
+    /// 预发布标识（正式版本为 `None`）
+    pub pre: Option<PreRelease>,
+}
+
+impl SemVer {
+    /// 创建正式版本
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            pre: None,
+        }
+    }
+
+    /// 从 tag 名称解析版本
+    ///
+    /// tag 需要以 `prefix` 开头，去掉前缀后为 `MAJOR.MINOR.PATCH`，可选 `-CHANNEL` 或 `-CHANNEL.N` 预发布标识。
+    /// 不符合格式的 tag（如 `release-2024`、`v1.2`）返回 `None`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::git::{PreRelease, SemVer};
+    ///
+    /// assert_eq!(SemVer::parse_tag("v1.2.3", "v"), Some(SemVer::new(1, 2, 3)));
+    /// assert_eq!(
+    ///     SemVer::parse_tag("v1.3.0-rc.2", "v").and_then(|v| v.pre),
+    ///     Some(PreRelease {
+    ///         channel: "rc".to_string(),
+    ///         number: 2
+    ///     })
+    /// );
+    /// assert_eq!(SemVer::parse_tag("1.2.3", "v"), None);
+    /// assert_eq!(SemVer::parse_tag("v1.2", "v"), None);
+    /// ```
+    pub fn parse_tag(tag: &str, prefix: &str) -> Option<Self> {
+        let version = tag.strip_prefix(prefix)?;
+        let (core, pre) = match version.split_once('-') {
+            Some((core, pre)) => (core, Some(Self::parse_pre(pre)?)),
+            None => (version, None),
+        };
+
+        let mut parts = core.split('.');
+        let major = Self::parse_number(parts.next()?)?;
+        let minor = Self::parse_number(parts.next()?)?;
+        let patch = Self::parse_number(parts.next()?)?;
+        if parts.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            major,
+            minor,
+            patch,
+            pre,
+        })
+    }
+
+    /// 是否为预发布版本
+    pub fn is_pre_release(&self) -> bool {
+        self.pre.is_some()
+    }
+
+    /// 生成带前缀的 tag 名称
+    pub fn to_tag(&self, prefix: &str) -> String {
+        format!("{}{}", prefix, self)
+    }
+
+    /// 按递增方式计算下一个正式版本（忽略预发布标识）
+    pub fn bump(&self, bump: VersionBump) -> Self {
+        match bump {
+            VersionBump::Major => Self::new(self.major + 1, 0, 0),
+            VersionBump::Minor => Self::new(self.major, self.minor + 1, 0),
+            VersionBump::Patch => Self::new(self.major, self.minor, self.patch + 1),
+        }
+    }
+
+    /// 根据已有版本计算下一个版本
+    ///
+    /// # 参数
+    ///
+    /// * `versions` - 已有的版本（顺序不限）
+    /// * `bump` - 递增方式
+    /// * `channel` - 预发布通道（`None` 时生成正式版本）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::git::{SemVer, VersionBump};
+    ///
+    /// let versions: Vec<SemVer> = ["v1.2.3", "v1.3.0-rc.1", "v1.3.0-rc.2"]
+    ///     .iter()
+    ///     .filter_map(|tag| SemVer::parse_tag(tag, "v"))
+    ///     .collect();
+    ///
+    /// let next = SemVer::next(&versions, VersionBump::Minor, None);
+    /// assert_eq!(next.to_string(), "1.3.0");
+    ///
+    /// let next = SemVer::next(&versions, VersionBump::Minor, Some("rc"));
+    /// assert_eq!(next.to_string(), "1.3.0-rc.3");
+    ///
+    /// let next = SemVer::next(&versions, VersionBump::Major, Some("beta"));
+    /// assert_eq!(next.to_string(), "2.0.0-beta.1");
+    /// ```
+    pub fn next(versions: &[SemVer], bump: VersionBump, channel: Option<&str>) -> Self {
+        let base = Self::latest_release(versions).cloned().unwrap_or_else(|| Self::new(0, 0, 0));
+        let mut next = base.bump(bump);
+
+        if let Some(channel) = channel {
+            let number = versions
+                .iter()
+                .filter(|version| version.same_core(&next))
+                .filter_map(|version| version.pre.as_ref())
+                .filter(|pre| pre.channel == channel)
+                .map(|pre| pre.number)
+                .max()
+                .unwrap_or(0);
+            next.pre = Some(PreRelease {
+                channel: channel.to_string(),
+                number: number + 1,
+            });
+        }
+
+        next
+    }
+
+    /// 最新的正式版本
+    pub fn latest_release(versions: &[SemVer]) -> Option<&SemVer> {
+        versions.iter().filter(|version| !version.is_pre_release()).max()
+    }
+
+    /// 最新的版本（包括预发布版本）
+    pub fn latest(versions: &[SemVer]) -> Option<&SemVer> {
+        versions.iter().max()
+    }
+
+    /// 检查预发布通道名称是否有效（字母、数字和 `-`，不能为纯数字）
+    pub fn is_valid_channel(channel: &str) -> bool {
+        !channel.is_empty()
+            && channel.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            && !channel.chars().all(|c| c.is_ascii_digit())
+    }
+
+    /// 检查预发布通道名称
+    ///
+    /// # 错误
+    ///
+    /// 通道名称无效时返回错误。
+    pub fn validate_channel(channel: &str) -> Result<()> {
+        if !Self::is_valid_channel(channel) {
+            color_eyre::eyre::bail!(
+                "Invalid pre-release channel: '{}' (use letters, digits and '-', e.g. 'rc' or 'beta')",
+                channel
+            );
+        }
+        Ok(())
+    }
+
+    /// 主版本号、次版本号和修订号是否相同
+    fn same_core(&self, other: &SemVer) -> bool {
+        self.major == other.major && self.minor == other.minor && self.patch == other.patch
+    }
+
+    /// 解析数字（不允许前导 0）
+    fn parse_number(part: &str) -> Option<u64> {
+        if part.is_empty()
+            || !part.chars().all(|c| c.is_ascii_digit())
+            || (part.len() > 1 && part.starts_with('0'))
+        {
+            return None;
+        }
+        part.parse().ok()
+    }
+
+    /// 解析预发布标识（`CHANNEL` 或 `CHANNEL.N`）
+    fn parse_pre(pre: &str) -> Option<PreRelease> {
+        let (channel, number) = match pre.split_once('.') {
+            Some((channel, number)) => (channel, Self::parse_number(number)?),
+            None => (pre, 0),
+        };
+        Self::is_valid_channel(channel).then(|| PreRelease {
+            channel: channel.to_string(),
+            number,
+        })
+    }
+}
+
+impl Ord for SemVer {
+    /// 按语义化版本规则排序：预发布版本低于同版本号的正式版本
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(b),
+            })
+    }
+}
+
+impl PartialOrd for SemVer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for SemVer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(ref pre) = self.pre {
+            write!(f, "-{}.{}", pre.channel, pre.number)?;
+        }
+        Ok(())
+    }
+}
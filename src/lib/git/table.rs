@@ -4,6 +4,8 @@
 
 use tabled::Tabled;
 
+use super::TagInfo;
+
 /// 分支表格行
 ///
 /// 用于在表格中显示分支信息。
@@ -14,3 +16,43 @@ pub struct BranchRow {
     #[tabled(rename = "Branch Name")]
     pub name: String,
 }
+
+/// Tag 表格行
+///
+/// 用于在表格中显示 tag 信息。
+#[derive(Tabled)]
+pub struct TagRow {
+    #[tabled(rename = "Tag")]
+    pub name: String,
+    #[tabled(rename = "Commit")]
+    pub commit: String,
+    #[tabled(rename = "Type")]
+    pub kind: String,
+    #[tabled(rename = "Local")]
+    pub local: String,
+    #[tabled(rename = "Remote")]
+    pub remote: String,
+    #[tabled(rename = "Message")]
+    pub message: String,
+}
+
+impl From<&TagInfo> for TagRow {
+    fn from(tag: &TagInfo) -> Self {
+        let mark = |exists: bool| if exists { "✓" } else { "-" }.to_string();
+        Self {
+            name: tag.name.clone(),
+            commit: tag.commit_hash.chars().take(8).collect(),
+            kind: if !tag.exists_local {
+                "-"
+            } else if tag.annotated {
+                "annotated"
+            } else {
+                "lightweight"
+            }
+            .to_string(),
+            local: mark(tag.exists_local),
+            remote: mark(tag.exists_remote),
+            message: tag.message.clone().unwrap_or_default(),
+        }
+    }
+}
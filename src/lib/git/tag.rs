@@ -2,11 +2,14 @@
 //!
 //! 本模块提供了 Git tag 相关的操作功能，包括：
 //! - 列出所有 tag
+//! - 创建附注 tag、推送 tag
 //! - 删除本地和远程 tag
 //! - 检查 tag 是否存在
 //! - 获取 tag 信息
+//! - 按通配符模式筛选 tag
 
 use color_eyre::{eyre::WrapErr, Result};
+use regex::Regex;
 
 use super::GitCommand;
 
@@ -21,12 +24,25 @@ pub struct TagInfo {
     pub exists_local: bool,
     /// Tag 是否在远程存在
     pub exists_remote: bool,
+    /// 是否为附注 tag（只对本地 tag 有效）
+    pub annotated: bool,
+    /// 附注 tag 的消息标题
+    pub message: Option<String>,
+}
+
+/// 本地 tag 引用信息（`git for-each-ref` 的输出）
+struct LocalTagRef {
+    name: String,
+    commit_hash: String,
+    annotated: bool,
+    message: Option<String>,
 }
 
 /// Git Tag 管理
 ///
 /// 提供 tag 相关的操作功能，包括：
 /// - 列出所有 tag
+/// - 创建和推送 tag
 /// - 删除本地和远程 tag
 /// - 检查 tag 是否存在
 pub struct GitTag;
@@ -106,39 +122,54 @@ impl GitTag {
     ///
     /// 如果 Git 命令执行失败，返回相应的错误信息。
     pub fn list_all_tags() -> Result<Vec<TagInfo>> {
-        let local_tags = Self::list_local_tags()?;
-        let remote_tags = Self::list_remote_tags()?;
-
-        // 合并本地和远程 tag，去重
-        let all_tag_names: std::collections::HashSet<String> =
-            local_tags.iter().chain(remote_tags.iter()).cloned().collect();
+        Self::list_tags(true)
+    }
 
-        let mut tags = Vec::new();
-        for tag_name in all_tag_names {
-            let exists_local = local_tags.contains(&tag_name);
-            let exists_remote = remote_tags.contains(&tag_name);
-
-            // 获取 tag 指向的 commit hash
-            let commit_hash = if exists_local {
-                GitCommand::new(["rev-parse", &tag_name])
-                    .read()
-                    .unwrap_or_else(|_| String::new())
-            } else if exists_remote {
-                // 从远程获取 commit hash
-                let output =
-                    GitCommand::new(["ls-remote", "origin", &format!("refs/tags/{}", tag_name)])
-                        .read()
-                        .unwrap_or_else(|_| String::new());
-                output.split_whitespace().next().unwrap_or("").to_string()
-            } else {
-                String::new()
-            };
+    /// 列出 tag
+    ///
+    /// 本地 tag 的信息通过一次 `git for-each-ref` 读取（附注 tag 解析到指向的 commit）；
+    /// 只在远程存在的 tag 通过 `git ls-remote` 获取 commit hash。
+    ///
+    /// # 参数
+    ///
+    /// * `include_remote` - 是否查询远程 tag（为 `false` 时不访问远程，所有 tag 的 `exists_remote` 为 `false`）
+    ///
+    /// # 错误
+    ///
+    /// 如果 Git 命令执行失败，返回相应的错误信息。
+    pub fn list_tags(include_remote: bool) -> Result<Vec<TagInfo>> {
+        let local_tags = Self::local_tag_refs("refs/tags")?;
+        let remote_tags = if include_remote {
+            Self::list_remote_tags()?
+        } else {
+            Vec::new()
+        };
 
+        let mut tags: Vec<TagInfo> = local_tags
+            .into_iter()
+            .map(|local| TagInfo {
+                exists_remote: remote_tags.contains(&local.name),
+                name: local.name,
+                commit_hash: local.commit_hash,
+                exists_local: true,
+                annotated: local.annotated,
+                message: local.message,
+            })
+            .collect();
+
+        for tag_name in remote_tags {
+            if tags.iter().any(|tag| tag.name == tag_name) {
+                continue;
+            }
+            // 从远程获取 commit hash
+            let commit_hash = Self::remote_commit_hash(&tag_name).unwrap_or_default();
             tags.push(TagInfo {
                 name: tag_name,
                 commit_hash,
-                exists_local,
-                exists_remote,
+                exists_local: false,
+                exists_remote: true,
+                annotated: false,
+                message: None,
             });
         }
 
@@ -148,6 +179,47 @@ impl GitTag {
         Ok(tags)
     }
 
+    /// 按通配符模式筛选 tag
+    ///
+    /// `*` 匹配任意字符，`?` 匹配单个字符，其他字符按字面匹配，模式需要匹配完整的 tag 名称。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::git::{GitTag, TagInfo};
+    ///
+    /// let tag = |name: &str| TagInfo {
+    ///     name: name.to_string(),
+    ///     commit_hash: String::new(),
+    ///     exists_local: true,
+    ///     exists_remote: false,
+    ///     annotated: false,
+    ///     message: None,
+    /// };
+    /// let tags = vec![tag("v1.0.0"), tag("v1.10.0"), tag("v2.0.0"), tag("v1x0")];
+    ///
+    /// let names: Vec<String> = GitTag::filter_by_pattern(&tags, "v1.*")
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|tag| tag.name)
+    ///     .collect();
+    /// assert_eq!(names, vec!["v1.0.0", "v1.10.0"]);
+    /// ```
+    pub fn filter_by_pattern(tags: &[TagInfo], pattern: &str) -> Result<Vec<TagInfo>> {
+        let regex_pattern: String = pattern
+            .chars()
+            .map(|c| match c {
+                '*' => ".*".to_string(),
+                '?' => ".".to_string(),
+                c => regex::escape(&c.to_string()),
+            })
+            .collect();
+        let regex = Regex::new(&format!("^{}$", regex_pattern))
+            .wrap_err_with(|| format!("Invalid pattern: {}", pattern))?;
+
+        Ok(tags.iter().filter(|tag| regex.is_match(&tag.name)).cloned().collect())
+    }
+
     /// 检查 tag 是否存在（本地或远程）
     ///
     /// # 参数
@@ -203,28 +275,169 @@ impl GitTag {
             return Err(color_eyre::eyre::eyre!("Tag '{}' does not exist", tag_name));
         }
 
-        // 获取 commit hash
-        let commit_hash = if exists_local {
-            GitCommand::new(["rev-parse", tag_name])
-                .read()
-                .wrap_err("Failed to get tag commit hash")?
-        } else {
-            // 从远程获取
-            let output =
-                GitCommand::new(["ls-remote", "origin", &format!("refs/tags/{}", tag_name)])
-                    .read()
-                    .wrap_err("Failed to get remote tag commit hash")?;
-            output.split_whitespace().next().unwrap_or("").to_string()
-        };
+        if exists_local {
+            if let Some(local) = Self::local_tag_refs(&format!("refs/tags/{}", tag_name))?
+                .into_iter()
+                .find(|local| local.name == tag_name)
+            {
+                return Ok(TagInfo {
+                    name: local.name,
+                    commit_hash: local.commit_hash,
+                    exists_local,
+                    exists_remote,
+                    annotated: local.annotated,
+                    message: local.message,
+                });
+            }
+        }
+
+        // 从远程获取
+        let commit_hash =
+            Self::remote_commit_hash(tag_name).wrap_err("Failed to get remote tag commit hash")?;
 
         Ok(TagInfo {
             name: tag_name.to_string(),
             commit_hash,
             exists_local,
             exists_remote,
+            annotated: false,
+            message: None,
         })
     }
 
+    /// 创建附注 tag
+    ///
+    /// 使用 `git tag -a <tag_name> -m <message> [target]` 创建 tag。
+    ///
+    /// # 参数
+    ///
+    /// * `tag_name` - tag 名称
+    /// * `message` - tag 消息
+    /// * `target` - tag 指向的提交（分支名、commit hash 等，`None` 时为 `HEAD`）
+    ///
+    /// # 错误
+    ///
+    /// 如果 tag 名称无效、本地已存在同名 tag 或创建失败，返回相应的错误信息。
+    pub fn create(tag_name: &str, message: &str, target: Option<&str>) -> Result<()> {
+        if !Self::is_valid_name(tag_name) {
+            color_eyre::eyre::bail!("Invalid tag name: '{}'", tag_name);
+        }
+        if Self::exists_local(tag_name) {
+            color_eyre::eyre::bail!("Tag '{}' already exists locally", tag_name);
+        }
+
+        let mut args = vec!["tag", "-a", tag_name, "-m", message];
+        if let Some(target) = target {
+            args.push(target);
+        }
+        GitCommand::new(args)
+            .run()
+            .wrap_err_with(|| format!("Failed to create tag: {}", tag_name))
+    }
+
+    /// 推送 tag 到远程
+    ///
+    /// 使用 `git push origin refs/tags/<tag_name>` 推送单个 tag。
+    ///
+    /// # 错误
+    ///
+    /// 如果推送失败（如远程已存在指向其他提交的同名 tag），返回相应的错误信息。
+    pub fn push(tag_name: &str) -> Result<()> {
+        GitCommand::new(["push", "origin", &format!("refs/tags/{}", tag_name)])
+            .run()
+            .wrap_err_with(|| format!("Failed to push tag: {}", tag_name))
+    }
+
+    /// 推送所有本地 tag 到远程
+    ///
+    /// 使用 `git push origin --tags`。
+    ///
+    /// # 错误
+    ///
+    /// 如果推送失败，返回相应的错误信息。
+    pub fn push_all() -> Result<()> {
+        GitCommand::new(["push", "origin", "--tags"])
+            .run()
+            .wrap_err("Failed to push tags")
+    }
+
+    /// 检查 tag 名称是否有效（`git check-ref-format`）
+    pub fn is_valid_name(tag_name: &str) -> bool {
+        !tag_name.trim().is_empty()
+            && !tag_name.starts_with('-')
+            && GitCommand::new(["check-ref-format", &format!("refs/tags/{}", tag_name)])
+                .quiet_success()
+    }
+
+    /// 检查本地是否存在 tag
+    fn exists_local(tag_name: &str) -> bool {
+        GitCommand::new([
+            "rev-parse",
+            "--verify",
+            "--quiet",
+            &format!("refs/tags/{}", tag_name),
+        ])
+        .quiet_success()
+    }
+
+    /// 读取本地 tag 引用信息
+    ///
+    /// `%(*objectname)` 为附注 tag 指向的 commit（轻量 tag 为空），`%(contents:subject)` 为附注 tag 消息标题。
+    fn local_tag_refs(pattern: &str) -> Result<Vec<LocalTagRef>> {
+        let output = GitCommand::new([
+            "for-each-ref",
+            "--format=%(refname:short)%09%(objecttype)%09%(objectname)%09%(*objectname)%09%(contents:subject)",
+            pattern,
+        ])
+        .read()
+        .wrap_err("Failed to list local tags")?;
+
+        Ok(output
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(5, '\t');
+                let name = fields.next()?.trim().to_string();
+                let annotated = fields.next()? == "tag";
+                let object = fields.next().unwrap_or_default();
+                let peeled = fields.next().unwrap_or_default();
+                let message = fields
+                    .next()
+                    .map(|m| m.trim().to_string())
+                    .filter(|m| annotated && !m.is_empty());
+                let commit_hash = if peeled.is_empty() { object } else { peeled }.to_string();
+                (!name.is_empty()).then_some(LocalTagRef {
+                    name,
+                    commit_hash,
+                    annotated,
+                    message,
+                })
+            })
+            .collect())
+    }
+
+    /// 从远程获取 tag 指向的 commit hash（附注 tag 优先使用解析后的 `^{}` 引用）
+    fn remote_commit_hash(tag_name: &str) -> Result<String> {
+        let tag_ref = format!("refs/tags/{}", tag_name);
+        let output = GitCommand::new([
+            "ls-remote",
+            "origin",
+            &tag_ref,
+            &format!("{}^{{}}", tag_ref),
+        ])
+        .read()?;
+        let mut hash = String::new();
+        for line in output.lines() {
+            let mut parts = line.split_whitespace();
+            let (Some(commit), Some(name)) = (parts.next(), parts.next()) else {
+                continue;
+            };
+            if name.ends_with("^{}") || hash.is_empty() {
+                hash = commit.to_string();
+            }
+        }
+        Ok(hash)
+    }
+
     /// 删除本地 tag
     ///
     /// 使用 `git tag -d` 删除本地 tag。
@@ -9,6 +9,8 @@ pub enum RepoType {
     GitHub,
     /// Codeup 仓库（检测支持，但 PR 功能不支持）
     Codeup,
+    /// Azure DevOps 仓库（`dev.azure.com` 或 `*.visualstudio.com`）
+    AzureDevOps,
     /// 未知类型的仓库
     Unknown,
 }
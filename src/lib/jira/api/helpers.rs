@@ -4,7 +4,7 @@
 //! 这些函数使用 OnceLock 缓存配置信息，避免重复读取配置文件。
 
 use crate::base::http::Authorization;
-use crate::jira::helpers::{get_auth, get_base_url};
+use crate::jira::helpers::{get_authorization, get_base_url};
 use color_eyre::{eyre::eyre, Result};
 use std::sync::OnceLock;
 
@@ -77,10 +77,41 @@ pub fn build_jira_url(path: &str) -> Result<String> {
     Ok(format!("{}/{}", base_url, path))
 }
 
+/// 构建完整的 Jira Agile API URL
+///
+/// 看板、Sprint 等接口属于 Agile API（`/rest/agile/1.0`），与 REST API v2 使用相同的服务地址和认证。
+///
+/// # 参数
+///
+/// * `path` - API 路径（相对于 Agile API），如 `"board?projectKeyOrId=PROJ"`
+///
+/// # 返回
+///
+/// 返回完整的 Jira Agile API URL 字符串。
+///
+/// # 错误
+///
+/// 如果基础 URL 未配置，返回错误。
+///
+/// # 示例
+///
+/// ```rust,no_run
+/// use workflow::jira::api::helpers::build_agile_url;
+///
+/// let url = build_agile_url("board/42/sprint?state=active")?;
+/// // 返回: "https://jira.example.com/rest/agile/1.0/board/42/sprint?state=active"
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+pub fn build_agile_url(path: &str) -> Result<String> {
+    let base_url = jira_base_url()?;
+    let service_address = base_url.strip_suffix("/rest/api/2").unwrap_or(base_url);
+    Ok(format!("{}/rest/agile/1.0/{}", service_address, path))
+}
+
 /// 获取 Jira 认证配置（使用 OnceLock 缓存）
 ///
-/// 从配置文件中读取 Jira API 认证所需的 email 和 api_token，
-/// 并创建 `Authorization` 对象。
+/// 从配置文件中读取 Jira API 认证信息，按 `jira.auth_type` 创建 `Authorization` 对象
+/// （`basic`：邮箱 + API token；`bearer`：Bearer token）。
 ///
 /// 使用 `OnceLock` 缓存结果，避免重复读取配置文件。
 ///
@@ -107,10 +138,7 @@ pub fn build_jira_url(path: &str) -> Result<String> {
 /// ```
 pub fn jira_auth_config() -> Result<&'static Authorization> {
     static AUTH: OnceLock<Result<Authorization>> = OnceLock::new();
-    AUTH.get_or_init(|| {
-        let (email, api_token) = get_auth()?;
-        Ok(Authorization::new(email, api_token))
-    })
-    .as_ref()
-    .map_err(|e| eyre!("Failed to get Jira auth: {}", e))
+    AUTH.get_or_init(get_authorization)
+        .as_ref()
+        .map_err(|e| eyre!("Failed to get Jira auth: {}", e))
 }
@@ -6,18 +6,16 @@ use color_eyre::{
     eyre::{ContextCompat, WrapErr},
     Result,
 };
-use reqwest::blocking::multipart;
-use reqwest::header::HeaderMap;
-use reqwest::header::HeaderValue;
 use serde::Serialize;
 use serde_json::Value;
 use std::path::Path;
 
-use super::helpers::{build_jira_url, jira_auth_config};
-use crate::base::http::{HttpClient, MultipartRequestConfig, RequestConfig};
-use crate::base::util::FileReader;
+use super::helpers::{build_agile_url, build_jira_url, jira_auth_config};
+use crate::base::http::{HttpClient, MultipartFile, MultipartRequestConfig, RequestConfig};
+use crate::jira::changelog::{ChangelogPage, CHANGELOG_PAGE_SIZE};
+use crate::jira::related::LinkedIssue;
 use crate::jira::types::{
-    JiraAttachment, JiraChangelog, JiraChangelogHistory, JiraChangelogItem, JiraIssue,
+    JiraAttachment, JiraChangelog, JiraComment, JiraComments, JiraIssue, JiraIssueLink, JiraSprint,
     JiraTransition,
 };
 
@@ -46,6 +44,12 @@ struct AssigneeRequest {
     account_id: String,
 }
 
+/// 获取评论时每页的数量（Jira 允许的最大值）
+const COMMENT_PAGE_SIZE: u64 = 100;
+
+/// 获取 Sprint 中的 issue 时每页的数量（Agile API 允许的最大值）
+const SPRINT_ISSUE_PAGE_SIZE: u64 = 50;
+
 /// 评论请求体
 ///
 /// 用于添加评论到 issue 的请求体结构。
@@ -190,39 +194,90 @@ impl JiraIssueApi {
         Ok(())
     }
 
+    /// 获取 issue 的所有评论
+    ///
+    /// 通过 `issue/{key}/comment` 分页接口按创建时间升序获取全部评论
+    /// （`issue/{key}` 中的 `comment` 字段只包含部分评论）。
+    ///
+    /// # 参数
+    ///
+    /// * `issue_key` - Jira ticket ID，格式如 `PROJ-123`
+    ///
+    /// # 返回
+    ///
+    /// 返回 `JiraComments` 结构体，`comments` 按创建时间升序排列，`total` 为评论总数。
+    ///
+    /// # 错误
+    ///
+    /// 如果 ticket 不存在或无法访问，返回错误。
+    pub fn get_comments(issue_key: &str) -> Result<JiraComments> {
+        let mut comments: Vec<JiraComment> = Vec::new();
+        let mut start_at = 0;
+
+        loop {
+            let url = build_jira_url(&format!(
+                "issue/{}/comment?startAt={}&maxResults={}&orderBy=created",
+                issue_key, start_at, COMMENT_PAGE_SIZE
+            ))?;
+            let data = Self::get_json(&url)
+                .wrap_err_with(|| format!("Failed to get comments for ticket: {}", issue_key))?;
+            let page: JiraComments =
+                serde_json::from_value(data).wrap_err("Invalid comments JSON structure")?;
+
+            let fetched = page.comments.len() as u64;
+            let total = page.total.unwrap_or(0);
+            comments.extend(page.comments);
+            start_at += fetched;
+            if fetched == 0 || start_at >= total {
+                break;
+            }
+        }
+
+        let total = comments.len() as u64;
+        Ok(JiraComments {
+            comments,
+            max_results: Some(total),
+            start_at: Some(0),
+            total: Some(total),
+        })
+    }
+
     /// 添加评论到 issue
     ///
     /// # 参数
     ///
-    /// * `ticket` - Jira ticket ID，格式如 `PROJ-123`
-    /// * `comment` - 评论内容
+    /// * `issue_key` - Jira ticket ID，格式如 `PROJ-123`
+    /// * `body` - 评论内容（Jira 文本格式）
     ///
     /// # 返回
     ///
-    /// 成功时返回 `Ok(())`。
-    pub fn add_issue_comment(ticket: &str, comment: &str) -> Result<()> {
-        let url = build_jira_url(&format!("issue/{}/comment", ticket))?;
+    /// 成功时返回创建的评论。
+    pub fn add_comment(issue_key: &str, body: &str) -> Result<JiraComment> {
+        let url = build_jira_url(&format!("issue/{}/comment", issue_key))?;
         let client = HttpClient::global()?;
         let auth = jira_auth_config()?;
 
-        let body = CommentRequest {
-            body: comment.to_string(),
+        let request = CommentRequest {
+            body: body.to_string(),
         };
 
-        let config = RequestConfig::<CommentRequest, Value>::new().body(&body).auth(auth);
+        let config = RequestConfig::<CommentRequest, Value>::new().body(&request).auth(auth);
         let response = client.post(&url, config)?;
         response
             .ensure_success()
-            .wrap_err(format!("Failed to add comment to issue {}", ticket))?;
-        Ok(())
+            .wrap_err(format!("Failed to add comment to issue {}", issue_key))?
+            .as_json()
+            .wrap_err("Failed to parse comment response")
     }
 
     /// 上传附件到 issue
     ///
+    /// 以 `multipart/form-data` 上传文件（字段名为 `file`），文件内容从磁盘流式读取。
+    ///
     /// # 参数
     ///
-    /// * `ticket` - Jira ticket ID，格式如 `PROJ-123`
-    /// * `file_path` - 要上传的文件路径
+    /// * `issue_key` - Jira ticket ID，格式如 `PROJ-123`
+    /// * `path` - 要上传的文件路径
     ///
     /// # 返回
     ///
@@ -231,43 +286,20 @@ impl JiraIssueApi {
     /// # 错误
     ///
     /// 如果文件不存在、无法读取或上传失败，返回相应的错误信息。
-    pub fn upload_attachment(ticket: &str, file_path: &str) -> Result<Vec<JiraAttachment>> {
-        let url = build_jira_url(&format!("issue/{}/attachments", ticket))?;
+    pub fn add_attachment(issue_key: &str, path: &Path) -> Result<Vec<JiraAttachment>> {
+        let url = build_jira_url(&format!("issue/{}/attachments", issue_key))?;
         let auth = jira_auth_config()?;
 
-        // 读取文件
-        let path = Path::new(file_path);
-        let file_name = path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .wrap_err_with(|| format!("Invalid file name: {}", file_path))?;
-
-        let file_data = FileReader::new(path).bytes()?;
-
-        // 创建 multipart form
         // 根据文件扩展名确定 MIME 类型
-        let mime_type = Self::guess_mime_type(path);
-        let part = multipart::Part::bytes(file_data)
-            .file_name(file_name.to_string())
-            .mime_str(&mime_type)
-            .wrap_err_with(|| format!("Failed to create multipart part for: {}", file_path))?;
+        let file = MultipartFile::new("file", path).content_type(Self::guess_mime_type(path));
 
-        let form = multipart::Form::new().part("file", part);
-
-        // 设置自定义 headers（Jira 需要 X-Atlassian-Token）
-        let mut headers = HeaderMap::new();
-        headers.insert("X-Atlassian-Token", HeaderValue::from_static("no-check"));
-
-        // 使用 HttpClient 发送 multipart 请求
+        // 使用 HttpClient 发送 multipart 请求（自动带上 Jira 需要的 X-Atlassian-Token）
         let client = HttpClient::global()?;
-        let config = MultipartRequestConfig::<Value>::new()
-            .multipart(form)
-            .auth(auth.clone())
-            .headers(headers);
+        let config = MultipartRequestConfig::<Value>::new().auth(auth.clone());
 
         let response = client
-            .post_multipart(&url, config)
-            .wrap_err_with(|| format!("Failed to upload attachment to issue {}", ticket))?;
+            .post_multipart(&url, &[], &[file], config)
+            .wrap_err_with(|| format!("Failed to upload attachment to issue {}", issue_key))?;
 
         // 检查响应状态并解析
         let response = response.ensure_success_with(|r| {
@@ -316,11 +348,14 @@ impl JiraIssueApi {
         .to_string()
     }
 
-    /// 获取 issue 的变更历史（changelog）
+    /// 获取 issue 的完整变更历史（changelog）
+    ///
+    /// 先通过 `issue/{key}?expand=changelog` 获取 issue 和第一页变更历史（同时验证 ticket 是否存在），
+    /// 变更历史超过一页时，再通过 `issue/{key}/changelog` 分页接口获取剩余的记录。
     ///
     /// # 参数
     ///
-    /// * `ticket` - Jira ticket ID，格式如 `PROJ-123`
+    /// * `issue_key` - Jira ticket ID，格式如 `PROJ-123`
     ///
     /// # 返回
     ///
@@ -329,56 +364,150 @@ impl JiraIssueApi {
     /// # 错误
     ///
     /// 如果 ticket 不存在或无法访问，返回错误。
-    pub fn get_issue_changelog(ticket: &str) -> Result<JiraChangelog> {
-        // 先验证 ticket 是否存在
-        let _issue = Self::get_issue(ticket)
-            .wrap_err_with(|| format!("Ticket {} does not exist or is not accessible", ticket))?;
-
-        // 使用专门的 changelog 端点
-        // API v2: /rest/api/2/issue/{issueIdOrKey}/changelog
-        let url = build_jira_url(&format!("issue/{}/changelog", ticket))?;
+    pub fn get_changelog(issue_key: &str) -> Result<JiraChangelog> {
+        let url = build_jira_url(&format!(
+            "issue/{}?expand=changelog&fields=summary",
+            issue_key
+        ))?;
+        let data = Self::get_json(&url).wrap_err_with(|| {
+            format!(
+                "Failed to get changelog for ticket: {}. The ticket may not exist or you may not have permission to view it.",
+                issue_key
+            )
+        })?;
+        let id = data.get("id").and_then(|id| id.as_str()).unwrap_or(issue_key).to_string();
+
+        let mut page = ChangelogPage::from_value(data.get("changelog").unwrap_or(&Value::Null));
+        let mut histories = std::mem::take(&mut page.histories);
+        let mut next_start = page.next_start();
+
+        while let Some(start_at) = next_start {
+            let url = build_jira_url(&format!(
+                "issue/{}/changelog?startAt={}&maxResults={}",
+                issue_key, start_at, CHANGELOG_PAGE_SIZE
+            ))?;
+            let data = Self::get_json(&url).wrap_err_with(|| {
+                format!(
+                    "Failed to get changelog page (startAt={}) for ticket: {}",
+                    start_at, issue_key
+                )
+            })?;
+            let mut page = ChangelogPage::from_value(&data);
+            next_start = page.next_start().filter(|next| *next > start_at);
+            histories.append(&mut page.histories);
+        }
+
+        Ok(JiraChangelog { id, histories })
+    }
+
+    /// 获取 issue 的链接（`issuelinks` 字段）
+    ///
+    /// 只请求 `issuelinks` 字段，并解析为带关系类型的 `LinkedIssue`
+    /// （blocks、is blocked by、relates to、duplicates 等）。
+    ///
+    /// # 参数
+    ///
+    /// * `issue_key` - Jira ticket ID，格式如 `PROJ-123`
+    ///
+    /// # 返回
+    ///
+    /// 返回链接的 ticket 列表（包含 key、标题和状态），没有链接时返回空列表。
+    ///
+    /// # 错误
+    ///
+    /// 如果 ticket 不存在或无法访问，返回错误。
+    pub fn get_issue_links(issue_key: &str) -> Result<Vec<LinkedIssue>> {
+        let url = build_jira_url(&format!("issue/{}?fields=issuelinks", issue_key))?;
+        let data = Self::get_json(&url)
+            .wrap_err_with(|| format!("Failed to get issue links for ticket: {}", issue_key))?;
+
+        let links: Vec<JiraIssueLink> = match data.pointer("/fields/issuelinks") {
+            Some(links) if !links.is_null() => serde_json::from_value(links.clone())
+                .wrap_err_with(|| {
+                    format!("Failed to parse issue links for ticket: {}", issue_key)
+                })?,
+            _ => Vec::new(),
+        };
+
+        Ok(links.iter().filter_map(LinkedIssue::from_link).collect())
+    }
+
+    /// 获取看板中进行中的 Sprint
+    ///
+    /// 通过 Agile API 的 `board/{id}/sprint?state=active` 获取。
+    /// 看板同时有多个进行中的 Sprint 时返回第一个。
+    ///
+    /// # 参数
+    ///
+    /// * `board_id` - 看板 ID
+    ///
+    /// # 返回
+    ///
+    /// 返回进行中的 Sprint，没有进行中的 Sprint 时返回 `None`。
+    ///
+    /// # 错误
+    ///
+    /// 如果看板不存在、不支持 Sprint（如 Kanban 看板）或 API 调用失败，返回错误。
+    pub fn get_active_sprint(board_id: u64) -> Result<Option<JiraSprint>> {
+        let url = build_agile_url(&format!("board/{}/sprint?state=active", board_id))?;
+        let data = Self::get_json(&url)
+            .wrap_err_with(|| format!("Failed to get active sprint for board: {}", board_id))?;
+
+        let sprints: Vec<JiraSprint> =
+            serde_json::from_value(data.get("values").cloned().unwrap_or(Value::Array(Vec::new())))
+                .wrap_err("Invalid sprints JSON structure")?;
+
+        Ok(sprints.into_iter().next())
+    }
+
+    /// 获取 Sprint 中的所有 issue
+    ///
+    /// 通过 Agile API 的 `sprint/{id}/issue` 分页接口获取，只请求标题、状态和经办人字段。
+    ///
+    /// # 参数
+    ///
+    /// * `sprint_id` - Sprint ID
+    ///
+    /// # 返回
+    ///
+    /// 返回 Sprint 中的 issue 列表。
+    ///
+    /// # 错误
+    ///
+    /// 如果 Sprint 不存在或 API 调用失败，返回错误。
+    pub fn get_sprint_issues(sprint_id: u64) -> Result<Vec<JiraIssue>> {
+        let mut issues: Vec<JiraIssue> = Vec::new();
+        let mut start_at = 0;
+
+        loop {
+            let url = build_agile_url(&format!(
+                "sprint/{}/issue?fields=summary,status,assignee&startAt={}&maxResults={}",
+                sprint_id, start_at, SPRINT_ISSUE_PAGE_SIZE
+            ))?;
+            let data = Self::get_json(&url)
+                .wrap_err_with(|| format!("Failed to get issues for sprint: {}", sprint_id))?;
+
+            let page: Vec<JiraIssue> = serde_json::from_value(
+                data.get("issues").cloned().unwrap_or(Value::Array(Vec::new())),
+            )
+            .wrap_err("Invalid sprint issues JSON structure")?;
+            let fetched = page.len() as u64;
+            let total = data.get("total").and_then(|t| t.as_u64()).unwrap_or(0);
+            issues.extend(page);
+            start_at += fetched;
+            if fetched == 0 || start_at >= total {
+                break;
+            }
+        }
+
+        Ok(issues)
+    }
+
+    /// 发送 GET 请求并解析 JSON 响应
+    fn get_json(url: &str) -> Result<Value> {
         let client = HttpClient::global()?;
         let auth = jira_auth_config()?;
         let config = RequestConfig::<Value, Value>::new().auth(auth);
-        let response = client.get(&url, config)?;
-        let data: Value = response
-            .ensure_success()
-            .wrap_err_with(|| format!("Failed to get changelog for ticket: {}. The ticket may not exist or you may not have permission to view it.", ticket))?
-            .as_json()
-            .wrap_err(format!("Failed to parse changelog response for ticket: {}", ticket))?;
-
-        // 提取 changelog 数据
-        // API v2 返回格式: { "id": "...", "histories": [...] }
-        let histories = data
-            .get("values")
-            .or_else(|| data.get("histories"))
-            .and_then(|h| h.as_array())
-            .unwrap_or(&vec![])
-            .iter()
-            .filter_map(|h| {
-                let id = h.get("id")?.as_str()?.to_string();
-                let created = h.get("created")?.as_str()?.to_string();
-                let author = h.get("author").and_then(|a| serde_json::from_value(a.clone()).ok());
-                let items: Vec<JiraChangelogItem> = h
-                    .get("items")
-                    .and_then(|i| i.as_array())
-                    .unwrap_or(&vec![])
-                    .iter()
-                    .filter_map(|item| serde_json::from_value(item.clone()).ok())
-                    .collect();
-
-                Some(JiraChangelogHistory {
-                    id,
-                    created,
-                    author,
-                    items,
-                })
-            })
-            .collect();
-
-        Ok(JiraChangelog {
-            id: ticket.to_string(),
-            histories,
-        })
+        client.get(url, config)?.ensure_success()?.as_json()
     }
 }
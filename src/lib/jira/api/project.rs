@@ -9,8 +9,12 @@ use color_eyre::{
 use serde_json::Value;
 use std::time::Duration;
 
-use super::helpers::{build_jira_url, jira_auth_config};
+use super::helpers::{build_agile_url, build_jira_url, jira_auth_config};
 use crate::base::http::{HttpClient, RequestConfig};
+use crate::jira::types::JiraBoard;
+
+/// 获取看板时每页的数量（Agile API 允许的最大值）
+const BOARD_PAGE_SIZE: u64 = 50;
 
 pub struct JiraProjectApi;
 
@@ -63,4 +67,53 @@ impl JiraProjectApi {
 
         Ok(status_names)
     }
+
+    /// 获取项目的 Agile 看板列表
+    ///
+    /// 通过 Agile API 的 `board?projectKeyOrId={project}` 分页接口获取项目的全部看板。
+    ///
+    /// # 参数
+    ///
+    /// * `project` - Jira 项目名称，如 `"PROJ"`
+    ///
+    /// # 返回
+    ///
+    /// 返回看板列表，项目没有看板时返回空列表。
+    ///
+    /// # 错误
+    ///
+    /// 如果项目不存在、无访问权限或 API 调用失败，返回相应的错误信息。
+    pub fn get_boards(project: &str) -> Result<Vec<JiraBoard>> {
+        let client = HttpClient::global()?;
+        let auth = jira_auth_config()?;
+        let mut boards: Vec<JiraBoard> = Vec::new();
+        let mut start_at = 0;
+
+        loop {
+            let url = build_agile_url(&format!(
+                "board?projectKeyOrId={}&startAt={}&maxResults={}",
+                project, start_at, BOARD_PAGE_SIZE
+            ))?;
+            let config = RequestConfig::<Value, Value>::new().auth(auth);
+            let data: Value = client
+                .get(&url, config)?
+                .ensure_success()?
+                .as_json()
+                .wrap_err_with(|| format!("Failed to fetch boards for project: {}", project))?;
+
+            let values = data.get("values").cloned().unwrap_or(Value::Array(Vec::new()));
+            let page: Vec<JiraBoard> =
+                serde_json::from_value(values).wrap_err("Invalid boards JSON structure")?;
+            let fetched = page.len() as u64;
+            boards.extend(page);
+            start_at += fetched;
+
+            let is_last = data.get("isLast").and_then(|v| v.as_bool()).unwrap_or(true);
+            if fetched == 0 || is_last {
+                break;
+            }
+        }
+
+        Ok(boards)
+    }
 }
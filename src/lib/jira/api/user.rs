@@ -6,7 +6,7 @@ use color_eyre::{eyre::WrapErr, Result};
 use serde_json::Value;
 
 use super::helpers::{build_jira_url, jira_auth_config};
-use crate::base::http::{HttpClient, RequestConfig};
+use crate::base::http::{HttpClient, HttpStatusError, RequestConfig};
 use crate::jira::types::JiraUser;
 
 pub struct JiraUserApi;
@@ -19,12 +19,28 @@ impl JiraUserApi {
     /// # 返回
     ///
     /// 返回 `JiraUser` 结构体，包含用户的完整信息。
+    ///
+    /// # 错误
+    ///
+    /// 非 2xx 响应返回 `HttpStatusError`，调用方可以据此区分认证失败（401）和地址错误（404）。
     pub fn get_current_user() -> Result<JiraUser> {
         let url = build_jira_url("myself")?;
         let client = HttpClient::global()?;
         let auth = jira_auth_config()?;
         let config = RequestConfig::<Value, Value>::new().auth(auth);
         let response = client.get(&url, config)?;
-        response.ensure_success()?.as_json().wrap_err("Failed to get current Jira user")
+        response
+            .ensure_success_with(|r| {
+                HttpStatusError::new(
+                    r.status,
+                    format!(
+                        "Jira API request failed: {} - {}",
+                        r.status,
+                        r.extract_error_message()
+                    ),
+                )
+            })?
+            .as_json()
+            .wrap_err("Failed to get current Jira user")
     }
 }
@@ -5,6 +5,9 @@
 //! - 清理整个基础目录
 //! - 预览操作（dry-run）
 //! - 列出将要删除的内容（list-only）
+//!
+//! 清理分为两步：`plan` 只读取目录，计算将要删除的内容（每个 ticket 的文件数和大小）；
+//! `remove` 删除 `plan` 返回的目录。调用方可以在两步之间展示预览并确认。
 
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -14,6 +17,7 @@ use walkdir::WalkDir;
 
 use crate::base::dialog::ConfirmDialog;
 use crate::base::format::DisplayFormatter;
+use crate::base::util::directory::DirectoryWalker;
 use crate::trace_info;
 
 use super::paths::AttachmentPaths;
@@ -37,6 +41,22 @@ pub struct DirInfo {
     pub jira_id: Option<String>,
     /// 目录内容（文件列表）
     pub contents: Vec<DirEntry>,
+    /// 各 ticket 目录的信息（仅基础目录，按 JIRA ID 排序）
+    pub tickets: Vec<DirInfo>,
+}
+
+impl DirInfo {
+    /// 各 ticket 目录的信息，按大小从大到小排序（大小相同时按 JIRA ID 排序）
+    ///
+    /// 单个 ticket 目录返回其自身，便于统一展示清理预览。
+    pub fn tickets_by_size(&self) -> Vec<&DirInfo> {
+        if !self.is_base_dir {
+            return vec![self];
+        }
+        let mut tickets: Vec<&DirInfo> = self.tickets.iter().collect();
+        tickets.sort_by(|a, b| b.size.cmp(&a.size).then_with(|| a.jira_id.cmp(&b.jira_id)));
+        tickets
+    }
 }
 
 /// 目录条目
@@ -48,6 +68,10 @@ pub struct DirEntry {
     pub name: String,
     /// 条目大小（如果是文件）
     pub size: Option<String>,
+    /// 条目路径
+    pub path: PathBuf,
+    /// 条目大小（字节，如果是文件）
+    pub bytes: Option<u64>,
 }
 
 /// 清理结果
@@ -67,6 +91,27 @@ pub struct CleanResult {
     pub list_only: bool,
 }
 
+impl CleanResult {
+    /// 可释放的空间（字节）
+    pub fn reclaimable_bytes(&self) -> u64 {
+        self.dir_info.as_ref().map_or(0, |info| info.size)
+    }
+
+    /// 将要删除的文件数量
+    pub fn file_count(&self) -> usize {
+        self.dir_info.as_ref().map_or(0, |info| info.file_count)
+    }
+
+    /// 实际释放的空间（字节，未删除时为 0）
+    pub fn reclaimed_bytes(&self) -> u64 {
+        if self.deleted {
+            self.reclaimable_bytes()
+        } else {
+            0
+        }
+    }
+}
+
 /// 附件清理器
 ///
 /// 提供清理附件下载目录的功能。
@@ -86,7 +131,7 @@ impl AttachmentCleaner {
 
     /// 清理指定 JIRA ID 的附件目录
     ///
-    /// 自动构建目录路径，然后清理该目录。
+    /// 自动构建目录路径，然后清理该目录。删除前会显示确认对话框。
     ///
     /// # 参数
     ///
@@ -94,6 +139,41 @@ impl AttachmentCleaner {
     /// * `dry_run` - 如果为 true，只预览操作，不实际删除
     /// * `list_only` - 如果为 true，只列出将要删除的内容
     pub fn clean_dir(&self, jira_id: &str, dry_run: bool, list_only: bool) -> Result<CleanResult> {
+        let mut result = self.plan(jira_id)?;
+        result.dry_run = dry_run;
+        result.list_only = list_only;
+
+        let Some(dir_info) = result.dir_info.as_ref().filter(|_| !dry_run && !list_only) else {
+            return Ok(result);
+        };
+
+        let confirmed = ConfirmDialog::new(format!(
+            "Are you sure you want to delete {}? This will remove {} files ({}).",
+            dir_info.dir_name,
+            dir_info.file_count,
+            DisplayFormatter::size(dir_info.size)
+        ))
+        .with_default(false)
+        .with_cancel_message("Operation cancelled")
+        .prompt()?;
+
+        if !confirmed {
+            trace_info!("Clean operation cancelled.");
+            result.cancelled = true;
+            return Ok(result);
+        }
+
+        self.remove(result)
+    }
+
+    /// 计算清理指定 JIRA ID 的附件目录时将要删除的内容
+    ///
+    /// 只读取目录，不会删除任何内容，也不会提示确认。返回的结果中 `dry_run` 为 `true`。
+    ///
+    /// # 参数
+    ///
+    /// * `jira_id` - JIRA ID（如 "PROJ-123"）。如果为空字符串，计算整个 jira 目录（包含所有 ticket）
+    pub fn plan(&self, jira_id: &str) -> Result<CleanResult> {
         let dir = if jira_id.is_empty() {
             // 如果 jira_id 为空，清理整个 jira 目录
             AttachmentPaths::jira_base_dir()?
@@ -113,8 +193,8 @@ impl AttachmentCleaner {
                 dir_exists: false,
                 dir_info: None,
                 cancelled: false,
-                dry_run,
-                list_only,
+                dry_run: true,
+                list_only: false,
             });
         }
 
@@ -122,67 +202,41 @@ impl AttachmentCleaner {
         let is_base_dir = jira_id.is_empty();
         let dir_info = Self::display_dir_info(&dir_name, &dir, size, file_count, is_base_dir)?;
 
-        if list_only {
-            return Ok(CleanResult {
-                deleted: false,
-                dir_exists: true,
-                dir_info: Some(dir_info),
-                cancelled: false,
-                dry_run,
-                list_only,
-            });
-        }
-
-        if dry_run {
-            trace_info!("[DRY RUN] Would delete {}: {:?}", dir_name, dir);
-            trace_info!("[DRY RUN] Total size: {}", DisplayFormatter::size(size));
-            trace_info!("[DRY RUN] Total files: {}", file_count);
-            return Ok(CleanResult {
-                deleted: false,
-                dir_exists: true,
-                dir_info: Some(dir_info),
-                cancelled: false,
-                dry_run,
-                list_only,
-            });
-        }
-
-        let confirmed = ConfirmDialog::new(format!(
-            "Are you sure you want to delete {}? This will remove {} files ({}).",
-            dir_name,
-            file_count,
-            DisplayFormatter::size(size)
-        ))
-        .with_default(false)
-        .with_cancel_message("Operation cancelled")
-        .prompt()?;
-
-        if !confirmed {
-            trace_info!("Clean operation cancelled.");
-            return Ok(CleanResult {
-                deleted: false,
-                dir_exists: true,
-                dir_info: Some(dir_info),
-                cancelled: true,
-                dry_run,
-                list_only,
-            });
-        }
-
-        std::fs::remove_dir_all(&dir)
-            .wrap_err_with(|| format!("Failed to delete {}: {:?}", dir_name, dir))?;
-
-        trace_info!("{} deleted successfully: {:?}", dir_name, dir);
+        trace_info!("[DRY RUN] Would delete {}: {:?}", dir_name, dir);
+        trace_info!("[DRY RUN] Total size: {}", DisplayFormatter::size(size));
+        trace_info!("[DRY RUN] Total files: {}", file_count);
         Ok(CleanResult {
-            deleted: true,
+            deleted: false,
             dir_exists: true,
             dir_info: Some(dir_info),
             cancelled: false,
-            dry_run,
-            list_only,
+            dry_run: true,
+            list_only: false,
         })
     }
 
+    /// 删除 `plan` 计算出的目录
+    ///
+    /// 目录不存在时不做任何操作，返回的结果中 `deleted` 为 `false`。
+    pub fn remove(&self, mut result: CleanResult) -> Result<CleanResult> {
+        result.dry_run = false;
+        let Some(dir_info) = result.dir_info.as_ref() else {
+            return Ok(result);
+        };
+
+        std::fs::remove_dir_all(&dir_info.dir).wrap_err_with(|| {
+            format!("Failed to delete {}: {:?}", dir_info.dir_name, dir_info.dir)
+        })?;
+
+        trace_info!(
+            "{} deleted successfully: {:?}",
+            dir_info.dir_name,
+            dir_info.dir
+        );
+        result.deleted = true;
+        Ok(result)
+    }
+
     /// 显示目录信息
     fn display_dir_info(
         dir_name: &str,
@@ -198,31 +252,49 @@ impl AttachmentCleaner {
         };
 
         let mut contents = Vec::new();
+        let mut tickets = Vec::new();
 
         if is_base_dir {
             // 按 ticket 分区显示
-            let ticket_contents = Self::get_base_dir_contents(dir)?;
-            contents = ticket_contents;
+            for (ticket_id, ticket_dir) in Self::list_ticket_dirs(dir)? {
+                let (ticket_size, ticket_file_count) = Self::calculate_dir_info(&ticket_dir)?;
+                let ticket_info = Self::display_dir_info(
+                    &format!("the directory for {}", ticket_id),
+                    &ticket_dir,
+                    ticket_size,
+                    ticket_file_count,
+                    false,
+                )?;
+                contents.extend(ticket_info.contents.iter().cloned().map(|mut entry| {
+                    entry.entry_type = format!("{} ({})", entry.entry_type, ticket_id);
+                    entry
+                }));
+                tickets.push(ticket_info);
+            }
         } else {
-            // 单个 ticket 目录，直接列出内容
+            // 单个 ticket 目录，直接列出内容（不包含目录本身）
             let dir_contents = Self::list_dir_contents(dir)?;
             for path in dir_contents {
+                if path == dir {
+                    continue;
+                }
+                let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("-").to_string();
                 if path.is_file() {
-                    let size_str = if let Ok(metadata) = std::fs::metadata(&path) {
-                        Some(DisplayFormatter::size(metadata.len()))
-                    } else {
-                        None
-                    };
+                    let bytes = std::fs::metadata(&path).ok().map(|metadata| metadata.len());
                     contents.push(DirEntry {
                         entry_type: "📄 File".to_string(),
-                        name: path.file_name().and_then(|n| n.to_str()).unwrap_or("-").to_string(),
-                        size: size_str,
+                        name,
+                        size: bytes.map(DisplayFormatter::size),
+                        path,
+                        bytes,
                     });
                 } else if path.is_dir() {
                     contents.push(DirEntry {
                         entry_type: "📁 Directory".to_string(),
-                        name: path.file_name().and_then(|n| n.to_str()).unwrap_or("-").to_string(),
+                        name,
                         size: None,
+                        path,
+                        bytes: None,
                     });
                 }
             }
@@ -236,11 +308,12 @@ impl AttachmentCleaner {
             is_base_dir,
             jira_id,
             contents,
+            tickets,
         })
     }
 
-    /// 获取基础目录内容（按 ticket 分区）
-    fn get_base_dir_contents(base_dir: &Path) -> Result<Vec<DirEntry>> {
+    /// 列出基础目录下的 ticket 目录（按 JIRA ID 排序）
+    fn list_ticket_dirs(base_dir: &Path) -> Result<Vec<(String, PathBuf)>> {
         // 读取基础目录下的所有条目
         let entries = fs::read_dir(base_dir)
             .wrap_err_with(|| format!("Failed to read directory: {:?}", base_dir))?;
@@ -261,64 +334,14 @@ impl AttachmentCleaner {
         // 按 ticket ID 排序
         ticket_dirs.sort_by(|a, b| a.0.cmp(&b.0));
 
-        let mut all_contents = Vec::new();
-
-        // 为每个 ticket 收集内容
-        for (ticket_id, ticket_dir) in ticket_dirs {
-            // 列出该 ticket 目录下的所有文件（不包含 ticket 目录本身）
-            let contents = Self::list_dir_contents(&ticket_dir)?;
-
-            for path in contents {
-                // 跳过 ticket 目录本身
-                if path == ticket_dir {
-                    continue;
-                }
-                if path.is_file() {
-                    let size_str = if let Ok(metadata) = std::fs::metadata(&path) {
-                        Some(DisplayFormatter::size(metadata.len()))
-                    } else {
-                        None
-                    };
-                    all_contents.push(DirEntry {
-                        entry_type: format!("📄 File ({})", ticket_id),
-                        name: path.file_name().and_then(|n| n.to_str()).unwrap_or("-").to_string(),
-                        size: size_str,
-                    });
-                } else if path.is_dir() {
-                    all_contents.push(DirEntry {
-                        entry_type: format!("📁 Directory ({})", ticket_id),
-                        name: path.file_name().and_then(|n| n.to_str()).unwrap_or("-").to_string(),
-                        size: None,
-                    });
-                }
-            }
-        }
-
-        Ok(all_contents)
+        Ok(ticket_dirs)
     }
 
     // ==================== 辅助函数 ====================
 
     /// 计算目录大小和文件数量
     fn calculate_dir_info(dir: &Path) -> Result<(u64, usize)> {
-        let mut total_size = 0u64;
-        let mut file_count = 0usize;
-
-        if !dir.exists() {
-            return Ok((0, 0));
-        }
-
-        for entry in WalkDir::new(dir) {
-            let entry = entry.wrap_err("Failed to read directory entry")?;
-            let metadata = entry.metadata().wrap_err("Failed to get file metadata")?;
-
-            if metadata.is_file() {
-                total_size += metadata.len();
-                file_count += 1;
-            }
-        }
-
-        Ok((total_size, file_count))
+        DirectoryWalker::new(dir).calculate_dir_info()
     }
 
     /// 列出目录内容
@@ -17,3 +17,9 @@ pub const LOG_ZIP_SPLIT_PREFIX: &str = "log.z";
 
 /// 日志文件扩展名
 pub const LOG_EXTENSIONS: &[&str] = &[".log", ".txt", ".zip"];
+
+/// 未完成下载的临时文件后缀（存在时通过 HTTP `Range` 请求续传）
+pub const PART_FILE_SUFFIX: &str = ".part";
+
+/// 下载时每次读取的字节数
+pub const DOWNLOAD_BUFFER_SIZE: usize = 64 * 1024;
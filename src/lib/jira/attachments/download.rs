@@ -1,26 +1,35 @@
 //! Jira 附件下载实现
 
-use color_eyre::{eyre::eyre, eyre::WrapErr, Result};
+use color_eyre::{eyre::WrapErr, Result};
 use std::path::{Path, PathBuf};
-use std::sync::mpsc;
-use std::thread;
+use std::sync::{Arc, Mutex};
 
-use crate::base::concurrent::TaskResult;
+use crate::base::concurrent::{ConcurrentExecutor, TaskResult};
+use crate::base::settings::Settings;
 use crate::{trace_debug, Jira, JiraAttachment};
 
 use super::constants::*;
 use super::directory::DirectoryManager;
 use super::filter::AttachmentFilter;
 use super::http_client::AttachmentDownloader;
+use super::progress::{DownloadProgress, DOWNLOADED_MESSAGE_PREFIX, FAILED_MESSAGE_PREFIX};
 use super::url_resolver::UrlResolver;
 use super::zip::ZipProcessor;
 
 /// 进度回调函数类型
+///
+/// 除状态消息外，下载过程中还会收到字节进度消息（见 `DownloadProgress`）。
 pub type ProgressCallback = Box<dyn Fn(&str) + Send + Sync>;
 
 /// 下载操作结果类型（成功文件列表，失败文件列表）
 type DownloadOperationResult = (Vec<PathBuf>, Vec<(String, String)>);
 
+/// 下载任务列表类型
+type DownloadTaskList = Vec<(
+    String,
+    Box<dyn Fn() -> Result<PathBuf, String> + Send + Sync>,
+)>;
+
 /// 下载结果
 #[derive(Debug, Clone)]
 pub struct DownloadResult {
@@ -53,8 +62,8 @@ impl JiraAttachmentDownloader {
     /// * `base_dir` - 基础目录路径（用于创建下载目录）
     /// * `output_folder_name` - 输出文件夹名称（用于日志解压）
     /// * `download_all_attachments` - 是否下载所有附件（true）或仅日志附件（false）
-    /// * `callback` - 进度回调函数
-    /// * `max_concurrent` - 最大并发数（可选，默认 5）
+    /// * `callback` - 进度回调函数（并发下载时会在工作线程中调用）
+    /// * `max_concurrent` - 最大并发数（可选，默认使用配置 `http.max_concurrent_downloads`）
     /// * `attachments` - 可选的附件列表（如果提供，将跳过 API 调用）
     ///
     /// # 返回
@@ -71,7 +80,9 @@ impl JiraAttachmentDownloader {
         max_concurrent: Option<usize>,
         attachments: Option<Vec<JiraAttachment>>,
     ) -> Result<DownloadResult> {
-        self.call_callback(callback.as_ref(), "Preparing download directory...");
+        // 回调需要在下载任务（工作线程）中共享
+        let callback = callback.map(Arc::new);
+        self.call_callback(callback.as_deref(), "Preparing download directory...");
 
         // 1. 准备下载目录
         let (download_base_dir, download_dir) =
@@ -89,10 +100,10 @@ impl JiraAttachmentDownloader {
         let download_result = (|| -> Result<()> {
             // 2. 获取并过滤附件（如果未提供，则从 API 获取）
             let attachments = if let Some(attachments) = attachments {
-                self.call_callback(callback.as_ref(), "Using provided attachments...");
+                self.call_callback(callback.as_deref(), "Using provided attachments...");
                 attachments
             } else {
-                self.call_callback(callback.as_ref(), "Fetching attachments...");
+                self.call_callback(callback.as_deref(), "Fetching attachments...");
                 Jira::get_attachments(jira_id).wrap_err("Failed to get attachments from Jira")?
             };
 
@@ -124,11 +135,13 @@ impl JiraAttachmentDownloader {
             };
 
             // 4. 下载附件
-            let max_concurrent = max_concurrent.unwrap_or(5).clamp(1, 20); // 默认 5，范围 1-20
+            let max_concurrent = max_concurrent
+                .unwrap_or_else(|| Settings::get().http.max_concurrent_downloads())
+                .max(1);
             if download_all_attachments {
-                self.call_callback(callback.as_ref(), "Downloading all attachments...");
+                self.call_callback(callback.as_deref(), "Downloading all attachments...");
             } else {
-                self.call_callback(callback.as_ref(), "Downloading log attachments...");
+                self.call_callback(callback.as_deref(), "Downloading log attachments...");
             }
 
             let (downloaded, failed) = self.download_attachments(
@@ -141,7 +154,7 @@ impl JiraAttachmentDownloader {
             result.downloaded_files.extend(downloaded);
             result.failed_files.extend(failed);
 
-            self.call_callback(callback.as_ref(), "Processing downloaded logs...");
+            self.call_callback(callback.as_deref(), "Processing downloaded logs...");
 
             // 5. 处理下载的日志（合并分片、解压）
             self.process_downloaded_logs(
@@ -164,13 +177,23 @@ impl JiraAttachmentDownloader {
     }
 
     /// 尝试下载单个附件（使用多个 URL 重试）
+    ///
+    /// 字节进度通过 `on_progress` 报告；某个 URL 中途失败时，下一个 URL 会续传已下载的部分。
     fn try_download_attachment(
         attachment: &JiraAttachment,
         file_path: &Path,
         urls: &[String],
+        on_progress: &dyn Fn(DownloadProgress),
     ) -> Result<PathBuf, String> {
+        let report = |downloaded, total| {
+            on_progress(DownloadProgress {
+                file: attachment.filename.clone(),
+                downloaded,
+                total,
+            })
+        };
         for url in urls {
-            match AttachmentDownloader::download_file(url, file_path) {
+            match AttachmentDownloader::download_file_with_progress(url, file_path, &report) {
                 Ok(()) => return Ok(file_path.to_path_buf()),
                 Err(e) => {
                     trace_debug!(
@@ -189,191 +212,72 @@ impl JiraAttachmentDownloader {
     }
 
     /// 下载附件（使用并发执行器）
+    ///
+    /// 同时最多下载 `max_concurrent` 个附件。单个附件下载失败不影响其他附件，
+    /// 失败信息汇总到返回的失败文件列表中。
     fn download_attachments(
         &self,
         attachments: &[JiraAttachment],
         download_dir: &Path,
         url_resolver: Option<&UrlResolver>,
-        callback: Option<&ProgressCallback>,
+        callback: Option<&Arc<ProgressCallback>>,
         max_concurrent: usize,
     ) -> Result<DownloadOperationResult> {
-        let max_concurrent = max_concurrent.max(1).min(attachments.len().max(1));
-
-        // 优化：小批量文件使用串行下载，避免线程开销
-        if attachments.len() <= 1 {
-            return self.download_attachments_sequential(
-                attachments,
-                download_dir,
-                url_resolver,
-                callback,
-            );
+        if attachments.is_empty() {
+            return Ok((Vec::new(), Vec::new()));
         }
 
-        let download_dir = download_dir.to_path_buf();
-
         // 准备任务列表
-        let mut tasks = Vec::new();
+        let mut tasks: DownloadTaskList = Vec::new();
         for attachment in attachments {
-            let download_dir = download_dir.clone();
-            let filename_for_result = attachment.filename.clone();
-            let attachment_clone = attachment.clone();
+            let name = attachment.filename.clone();
+            let file_path = download_dir.join(&attachment.filename);
+            let attachment = attachment.clone();
+            let callback = callback.cloned();
 
             // 如果有 URL 解析器，获取所有可能的 URL；否则只使用原始 URL
             let urls = if let Some(resolver) = url_resolver {
-                resolver.get_download_urls(&attachment_clone)
+                resolver.get_download_urls(&attachment)
             } else {
-                vec![attachment_clone.content_url.clone()]
+                vec![attachment.content_url.clone()]
             };
 
             let task = Box::new(move || -> Result<PathBuf, String> {
-                let file_path = download_dir.join(&attachment_clone.filename);
-                Self::try_download_attachment(&attachment_clone, &file_path, &urls)
+                Self::try_download_attachment(&attachment, &file_path, &urls, &|progress| {
+                    if let Some(cb) = &callback {
+                        cb(&progress.message());
+                    }
+                })
             }) as Box<dyn Fn() -> Result<PathBuf, String> + Send + Sync>;
 
-            tasks.push((filename_for_result, task));
-        }
-
-        // 使用自定义执行逻辑，在接收结果时实时调用回调
-        // 这样可以确保进度条实时更新，而不是等待所有任务完成
-        let max_concurrent = max_concurrent.max(1).min(tasks.len().max(1));
-
-        if tasks.is_empty() {
-            return Ok((Vec::new(), Vec::new()));
-        }
-
-        // 如果只有一个任务，直接执行
-        if tasks.len() == 1 {
-            let (name, task) = tasks
-                .into_iter()
-                .next()
-                .ok_or_else(|| eyre!("Expected exactly one task, but got none"))?;
-            let result = match task() {
-                Ok(value) => {
-                    if let Some(cb) = callback {
-                        cb(&format!("Downloaded: {}", name));
-                    }
-                    TaskResult::Success(value)
-                }
-                Err(err) => {
-                    if let Some(cb) = callback {
-                        cb(&format!("Failed to download: {} - {}", name, err));
-                    }
-                    TaskResult::Failure(err)
-                }
-            };
-            let (downloaded, failed) = self.collect_download_results(vec![(name, result)]);
-            return Ok((downloaded, failed));
+            tasks.push((name, task));
         }
 
-        // 结果通道
-        let (tx, rx) = mpsc::channel();
-
-        // 分批处理：将任务分成多个批次，每批最多 max_concurrent 个并行执行
-        let mut handles = Vec::new();
-        let mut tasks_iter = tasks.into_iter();
-
-        loop {
-            let mut chunk = Vec::new();
-            for _ in 0..max_concurrent {
-                if let Some(task) = tasks_iter.next() {
-                    chunk.push(task);
+        // 每个附件下载结束时立即报告（成功和失败都计入进度）
+        let finished_callback = callback.cloned();
+        let on_finished = move |name: &str, success: bool, error: Option<&str>| {
+            if let Some(cb) = &finished_callback {
+                if success {
+                    cb(&format!("{}{}", DOWNLOADED_MESSAGE_PREFIX, name));
                 } else {
-                    break;
-                }
-            }
-
-            if chunk.is_empty() {
-                break;
-            }
-
-            let tx = tx.clone();
-
-            let handle = thread::spawn(move || {
-                for (name, task) in chunk {
-                    let result = match task() {
-                        Ok(value) => TaskResult::Success(value),
-                        Err(err) => TaskResult::Failure(err),
-                    };
-                    tx.send((name, result)).ok();
-                }
-            });
-
-            handles.push(handle);
-        }
-
-        // 关闭发送端
-        drop(tx);
-
-        // 收集结果，并在接收时实时调用回调
-        let mut results = Vec::new();
-        for (name, result) in rx {
-            // 实时调用回调
-            if let Some(cb) = callback {
-                match &result {
-                    TaskResult::Success(_) => {
-                        cb(&format!("Downloaded: {}", name));
-                    }
-                    TaskResult::Failure(err) => {
-                        cb(&format!("Failed to download: {} - {}", name, err));
-                    }
+                    cb(&format!(
+                        "{}{} - {}",
+                        FAILED_MESSAGE_PREFIX,
+                        name,
+                        error.unwrap_or_default()
+                    ));
                 }
             }
-            results.push((name, result));
-        }
+        };
 
-        // 等待所有线程完成
-        for handle in handles {
-            handle.join().map_err(|e| eyre!("Thread join error: {:?}", e))?;
-        }
+        let executor = ConcurrentExecutor::new(max_concurrent);
+        let results =
+            executor.execute_with_progress(tasks, Some(Arc::new(Mutex::new(Some(on_finished)))))?;
 
         // 收集结果
         let (downloaded, failed) = self.collect_download_results(results);
 
-        self.report_failed_downloads(&failed, callback);
-        Ok((downloaded, failed))
-    }
-
-    /// 串行下载附件（小批量优化）
-    fn download_attachments_sequential(
-        &self,
-        attachments: &[JiraAttachment],
-        download_dir: &Path,
-        url_resolver: Option<&UrlResolver>,
-        callback: Option<&ProgressCallback>,
-    ) -> Result<DownloadOperationResult> {
-        let mut downloaded = Vec::new();
-        let mut failed = Vec::new();
-
-        for attachment in attachments {
-            let file_path = download_dir.join(&attachment.filename);
-
-            // 获取所有可能的 URL
-            let urls = if let Some(resolver) = url_resolver {
-                resolver.get_download_urls(attachment)
-            } else {
-                vec![attachment.content_url.clone()]
-            };
-
-            // 尝试下载
-            match Self::try_download_attachment(attachment, &file_path, &urls) {
-                Ok(path) => {
-                    downloaded.push(path);
-                    self.call_callback(callback, &format!("Downloaded: {}", attachment.filename));
-                }
-                Err(error_msg) => {
-                    failed.push((attachment.filename.clone(), error_msg.clone()));
-                    self.call_callback(
-                        callback,
-                        &format!(
-                            "Failed to download: {} - {}",
-                            attachment.filename, error_msg
-                        ),
-                    );
-                }
-            }
-        }
-
-        self.report_failed_downloads(&failed, callback);
+        self.report_failed_downloads(&failed, callback.map(|cb| &**cb));
         Ok((downloaded, failed))
     }
 
@@ -1,12 +1,17 @@
 //! HTTP 客户端适配器（用于附件下载）
 
-use crate::base::http::{Authorization, HttpClient, HttpMethod, RequestConfig};
-use crate::jira::helpers::{get_auth, get_base_url};
+use crate::base::http::{HttpClient, HttpMethod, RequestConfig};
+use crate::base::settings::Settings;
+use crate::jira::helpers::{get_authorization, get_base_url};
 use crate::trace_debug;
 use color_eyre::{eyre::WrapErr, Result};
-use reqwest::header::HeaderMap;
-use std::fs::File;
-use std::path::Path;
+use reqwest::header::{HeaderMap, HeaderValue, RANGE};
+use reqwest::StatusCode;
+use std::fs::{self, OpenOptions};
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+use super::constants::{DOWNLOAD_BUFFER_SIZE, PART_FILE_SUFFIX};
 
 /// 附件下载器
 ///
@@ -21,21 +26,49 @@ impl AttachmentDownloader {
         url.contains("cloudfront.net") && url.contains("Expires=") && url.contains("Signature=")
     }
 
+    /// 未完成下载的临时文件路径（`{output_path}.part`）
+    pub fn part_path(output_path: &Path) -> PathBuf {
+        let mut path = output_path.as_os_str().to_owned();
+        path.push(PART_FILE_SUFFIX);
+        PathBuf::from(path)
+    }
+
     /// 下载文件到指定路径
     ///
+    /// 不报告进度的 `download_file_with_progress`。
+    pub fn download_file(url: &str, output_path: &Path) -> Result<()> {
+        Self::download_file_with_progress(url, output_path, &|_, _| {})
+    }
+
+    /// 下载文件到指定路径，并报告字节进度
+    ///
     /// 利用现有的 `base::http::HttpClient` 进行流式下载。
-    /// 支持 CloudFront 签名 URL 的特殊处理（先尝试不使用 Basic Auth，失败后重试）。
+    /// 支持 CloudFront 签名 URL 的特殊处理（先尝试不使用 Jira 认证，失败后重试）。
+    ///
+    /// 下载内容先写入 `{output_path}.part`，完成后重命名为 `output_path`。
+    /// `.part` 文件已存在时发送 `Range: bytes={已下载字节数}-` 续传：
+    /// - 服务器返回 `206 Partial Content` 时追加到 `.part` 文件
+    /// - 服务器忽略 `Range` 返回 `200` 时从头下载
+    /// - 服务器返回 `416 Range Not Satisfiable` 时删除 `.part` 文件后重新下载
     ///
     /// # 参数
     ///
     /// * `url` - 下载 URL
     /// * `output_path` - 输出文件路径
+    /// * `on_progress` - 进度回调（已下载字节数、总字节数），总字节数来自 `Content-Length`，
+    ///   没有该 Header 时为 `None`；续传时两者都包含之前已下载的部分
     ///
     /// # 返回
     ///
-    /// 如果下载成功，返回 `Ok(())`；否则返回错误。
-    pub fn download_file(url: &str, output_path: &Path) -> Result<()> {
+    /// 如果下载成功，返回 `Ok(())`；否则返回错误。下载中断时 `.part` 文件会保留，用于下次续传。
+    pub fn download_file_with_progress(
+        url: &str,
+        output_path: &Path,
+        on_progress: &dyn Fn(u64, Option<u64>),
+    ) -> Result<()> {
         let client = HttpClient::global()?;
+        let part_path = Self::part_path(output_path);
+        let resume_from = fs::metadata(&part_path).map(|metadata| metadata.len()).unwrap_or(0);
 
         // 构建请求头
         let mut headers = HeaderMap::new();
@@ -44,20 +77,31 @@ impl AttachmentDownloader {
                 headers.insert("Referer", referer_header);
             }
         }
+        if resume_from > 0 {
+            trace_debug!(
+                "Resuming download of {:?} from byte {}",
+                output_path,
+                resume_from
+            );
+            headers.insert(
+                RANGE,
+                HeaderValue::from_str(&format!("bytes={}-", resume_from))
+                    .wrap_err("Failed to build Range header")?,
+            );
+        }
 
         let is_cloudfront = Self::is_cloudfront_signed_url(url);
 
-        // 构建第一次请求配置
+        // 构建第一次请求配置（附件可能很大，使用下载超时时间）
         let mut config: RequestConfig<'_, serde_json::Value, serde_json::Value> =
-            RequestConfig::new().timeout(std::time::Duration::from_secs(60));
+            RequestConfig::new().timeout(Settings::get().http.download_timeout());
         if !headers.is_empty() {
             config = config.headers(&headers);
         }
 
-        // 对于非 CloudFront URL，添加 Basic Auth
+        // 对于非 CloudFront URL，添加 Jira 认证（Basic 或 Bearer）
         let auth = if !is_cloudfront {
-            let (email, api_token) = get_auth()?;
-            Some(Authorization::new(email, api_token))
+            Some(get_authorization()?)
         } else {
             None
         };
@@ -70,11 +114,22 @@ impl AttachmentDownloader {
             .stream(HttpMethod::Get, url, config)
             .wrap_err_with(|| format!("Failed to download: {}", url))?;
 
-        // 如果失败且是 CloudFront URL，重试时使用 Basic Auth
+        // 已下载的部分无效（如服务器上的文件已变化），删除后重新下载
+        if resume_from > 0 && response.status() == StatusCode::RANGE_NOT_SATISFIABLE {
+            trace_debug!(
+                "Range not satisfiable, restarting download of {:?}",
+                output_path
+            );
+            fs::remove_file(&part_path)
+                .wrap_err_with(|| format!("Failed to remove partial file: {:?}", part_path))?;
+            return Self::download_file_with_progress(url, output_path, on_progress);
+        }
+
+        // 如果失败且是 CloudFront URL，重试时使用 Jira 认证
         if !response.status().is_success() && is_cloudfront {
             let status = response.status();
             trace_debug!(
-                "CloudFront URL failed (status: {}), retrying with Basic Auth",
+                "CloudFront URL failed (status: {}), retrying with Jira auth",
                 status
             );
 
@@ -89,11 +144,10 @@ impl AttachmentDownloader {
                 trace_debug!("Error response: {}", preview);
             }
 
-            // 重试，这次使用 Basic Auth
-            let (email, api_token) = get_auth()?;
-            let auth_retry = Authorization::new(email, api_token);
+            // 重试，这次使用 Jira 认证
+            let auth_retry = get_authorization()?;
             let mut config_with_auth: RequestConfig<'_, serde_json::Value, serde_json::Value> =
-                RequestConfig::new().timeout(std::time::Duration::from_secs(60));
+                RequestConfig::new().timeout(Settings::get().http.download_timeout());
             if !headers.is_empty() {
                 config_with_auth = config_with_auth.headers(&headers);
             }
@@ -101,7 +155,7 @@ impl AttachmentDownloader {
 
             response = client
                 .stream(HttpMethod::Get, url, config_with_auth)
-                .wrap_err_with(|| format!("Failed to download with Basic Auth: {}", url))?;
+                .wrap_err_with(|| format!("Failed to download with Jira auth: {}", url))?;
 
             if !response.status().is_success() {
                 let status = response.status();
@@ -116,12 +170,47 @@ impl AttachmentDownloader {
             color_eyre::eyre::bail!("{}", error_msg);
         }
 
-        // 写入文件
-        let mut file = File::create(output_path)
-            .wrap_err_with(|| format!("Failed to create file: {:?}", output_path))?;
+        // 服务器可能忽略 Range 返回完整内容，此时从头写入
+        let resumed = resume_from > 0 && response.status() == StatusCode::PARTIAL_CONTENT;
+        if resume_from > 0 && !resumed {
+            trace_debug!(
+                "Server ignored Range request, restarting download of {:?}",
+                output_path
+            );
+        }
+        let offset = if resumed { resume_from } else { 0 };
+        let total = response.content_length().map(|length| offset + length);
+
+        // 写入 .part 文件
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resumed)
+            .truncate(!resumed)
+            .open(&part_path)
+            .wrap_err_with(|| format!("Failed to create file: {:?}", part_path))?;
+
+        let mut downloaded = offset;
+        on_progress(downloaded, total);
+        let mut buffer = vec![0u8; DOWNLOAD_BUFFER_SIZE];
+        loop {
+            let read = response
+                .read(&mut buffer)
+                .wrap_err_with(|| format!("Failed to read response: {}", url))?;
+            if read == 0 {
+                break;
+            }
+            file.write_all(&buffer[..read])
+                .wrap_err_with(|| format!("Failed to write file: {:?}", part_path))?;
+            downloaded += read as u64;
+            on_progress(downloaded, total);
+        }
+        file.flush()
+            .wrap_err_with(|| format!("Failed to write file: {:?}", part_path))?;
+        drop(file);
 
-        std::io::copy(&mut response, &mut file)
-            .wrap_err_with(|| format!("Failed to write file: {:?}", output_path))?;
+        fs::rename(&part_path, output_path)
+            .wrap_err_with(|| format!("Failed to rename {:?} to {:?}", part_path, output_path))?;
 
         Ok(())
     }
@@ -3,6 +3,7 @@
 //! 提供从 Jira 下载附件的功能，包括：
 //! - 下载所有附件
 //! - 下载日志附件（带重试逻辑）
+//! - 下载进度报告和断点续传（`.part` 文件 + HTTP `Range`）
 //! - ZIP 文件处理（合并分片、解压）
 //! - 清理附件目录
 //!
@@ -12,6 +13,7 @@
 //! - `filter` - 附件过滤逻辑
 //! - `url_resolver` - URL 解析和重试策略
 //! - `http_client` - HTTP 客户端适配器（利用 base::http）
+//! - `progress` - 下载进度消息和进度显示
 //! - `directory` - 目录管理
 //! - `zip` - ZIP 文件处理
 //! - `clean` - 清理功能
@@ -24,6 +26,7 @@ mod download;
 mod filter;
 mod http_client;
 mod paths;
+mod progress;
 mod url_resolver;
 mod zip;
 
@@ -31,4 +34,10 @@ mod zip;
 pub use clean::{AttachmentCleaner, CleanResult, DirEntry, DirInfo};
 pub use constants::*;
 pub use download::{DownloadResult, JiraAttachmentDownloader, ProgressCallback};
+pub use http_client::AttachmentDownloader;
+pub use paths::AttachmentPaths;
+pub use progress::{
+    DownloadProgress, DownloadProgressDisplay, DOWNLOADED_MESSAGE_PREFIX, FAILED_MESSAGE_PREFIX,
+    PROGRESS_MESSAGE_PREFIX,
+};
 pub use zip::ZipProcessor;
@@ -0,0 +1,238 @@
+//! 附件下载进度
+//!
+//! 下载器通过 `ProgressCallback` 发送文本消息，字节进度也以消息的形式发送：
+//! `Progress: {文件名} ({已下载字节数}/{总字节数})`，总字节数未知时为 `?`。
+//! `DownloadProgress` 负责生成和解析这种消息。
+//!
+//! `DownloadProgressDisplay` 根据这些消息驱动一个 `Progress` 字节进度条。
+//! 并发下载时显示所有文件的合计进度：总字节数为已结束的文件和正在下载的文件的大小之和
+//! （随文件开始下载而增加），响应没有 `Content-Length` 的文件按已下载的字节数计算；
+//! 消息中显示已结束的文件数（成功和失败都计入）。
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::base::indicator::Progress;
+
+use super::download::ProgressCallback;
+
+/// 字节进度消息前缀
+pub const PROGRESS_MESSAGE_PREFIX: &str = "Progress: ";
+
+/// 下载完成消息前缀
+pub const DOWNLOADED_MESSAGE_PREFIX: &str = "Downloaded: ";
+
+/// 下载失败消息前缀
+pub const FAILED_MESSAGE_PREFIX: &str = "Failed to download: ";
+
+/// 单个文件的下载进度
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// 文件名
+    pub file: String,
+    /// 已下载字节数（续传时包含之前已下载的部分）
+    pub downloaded: u64,
+    /// 总字节数（响应没有 `Content-Length` 时为 `None`）
+    pub total: Option<u64>,
+}
+
+impl DownloadProgress {
+    /// 转换为回调消息
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::jira::attachments::DownloadProgress;
+    ///
+    /// let progress = DownloadProgress {
+    ///     file: "log.zip".to_string(),
+    ///     downloaded: 512,
+    ///     total: Some(2048),
+    /// };
+    /// assert_eq!(progress.message(), "Progress: log.zip (512/2048)");
+    /// assert_eq!(DownloadProgress::parse(&progress.message()), Some(progress));
+    /// ```
+    pub fn message(&self) -> String {
+        let total = self.total.map(|total| total.to_string()).unwrap_or_else(|| "?".to_string());
+        format!(
+            "{}{} ({}/{})",
+            PROGRESS_MESSAGE_PREFIX, self.file, self.downloaded, total
+        )
+    }
+
+    /// 从回调消息解析进度（不是进度消息时返回 `None`）
+    pub fn parse(message: &str) -> Option<Self> {
+        let rest = message.strip_prefix(PROGRESS_MESSAGE_PREFIX)?;
+        let (file, counts) = rest.strip_suffix(')')?.rsplit_once(" (")?;
+        let (downloaded, total) = counts.split_once('/')?;
+        let total = match total {
+            "?" => None,
+            total => Some(total.parse().ok()?),
+        };
+        Some(Self {
+            file: file.to_string(),
+            downloaded: downloaded.parse().ok()?,
+            total,
+        })
+    }
+}
+
+/// 进度显示状态
+struct DisplayState {
+    message: String,
+    total_files: u64,
+    finished_files: u64,
+    /// 已结束的文件的字节数
+    finished_bytes: u64,
+    /// 正在下载的文件（文件名 → 已下载字节数、总字节数）
+    active: HashMap<String, (u64, Option<u64>)>,
+    progress: Option<Progress>,
+}
+
+impl DisplayState {
+    /// 处理一条回调消息
+    fn handle(&mut self, message: &str) {
+        if let Some(event) = DownloadProgress::parse(message) {
+            self.active.insert(event.file, (event.downloaded, event.total));
+            self.render();
+            return;
+        }
+
+        let finished = message
+            .strip_prefix(DOWNLOADED_MESSAGE_PREFIX)
+            .or_else(|| message.strip_prefix(FAILED_MESSAGE_PREFIX));
+        if let Some(rest) = finished {
+            self.finished_files += 1;
+            // 失败消息的格式为 `{文件名} - {错误}`
+            let file = self
+                .active
+                .keys()
+                .find(|file| {
+                    rest == file.as_str()
+                        || rest.strip_prefix(file.as_str()).is_some_and(|r| r.starts_with(" - "))
+                })
+                .cloned();
+            if let Some((downloaded, _)) = file.and_then(|file| self.active.remove(&file)) {
+                self.finished_bytes += downloaded;
+            }
+            self.render();
+        }
+    }
+
+    /// 已下载的字节数（包括已结束的文件）
+    fn downloaded_bytes(&self) -> u64 {
+        self.finished_bytes + self.active.values().map(|(downloaded, _)| downloaded).sum::<u64>()
+    }
+
+    /// 总字节数（总大小未知的文件按已下载的字节数计算）
+    fn total_bytes(&self) -> u64 {
+        self.finished_bytes
+            + self
+                .active
+                .values()
+                .map(|(downloaded, total)| total.unwrap_or(*downloaded).max(*downloaded))
+                .sum::<u64>()
+    }
+
+    /// 进度条消息：`{消息} [{已结束}/{总数}] {文件名}`，多个文件同时下载时显示文件数
+    fn label(&self) -> String {
+        let files = match self.active.len() {
+            0 => String::new(),
+            1 => format!(
+                " {}",
+                self.active.keys().next().map(String::as_str).unwrap_or_default()
+            ),
+            n => format!(" ({} files in progress)", n),
+        };
+        format!(
+            "{} [{}/{}]{}",
+            self.message, self.finished_files, self.total_files, files
+        )
+    }
+
+    /// 刷新进度条（收到第一条字节进度消息时才创建）
+    fn render(&mut self) {
+        let (downloaded, total, label) =
+            (self.downloaded_bytes(), self.total_bytes(), self.label());
+        match &self.progress {
+            Some(progress) => {
+                progress.set_length(total);
+                progress.set_position(downloaded);
+                progress.update_message(label);
+            }
+            None if !self.active.is_empty() => {
+                let progress = Progress::new_download(total, label);
+                progress.set_position(downloaded);
+                self.progress = Some(progress);
+            }
+            None => {}
+        }
+    }
+}
+
+/// 根据下载回调消息驱动 `Progress` 指示器
+///
+/// # 示例
+///
+/// ```rust,no_run
+/// use workflow::jira::attachments::DownloadProgressDisplay;
+///
+/// let display = DownloadProgressDisplay::new(3, "Downloading attachments...");
+/// let callback = display.callback();
+/// // 将 callback 传给下载器 ...
+/// display.finish();
+/// ```
+#[derive(Clone)]
+pub struct DownloadProgressDisplay {
+    state: Arc<Mutex<DisplayState>>,
+}
+
+impl DownloadProgressDisplay {
+    /// 创建进度显示
+    ///
+    /// # 参数
+    ///
+    /// * `total_files` - 要下载的文件数
+    /// * `message` - 显示在文件名前的消息
+    pub fn new(total_files: u64, message: impl Into<String>) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(DisplayState {
+                message: message.into(),
+                total_files,
+                finished_files: 0,
+                finished_bytes: 0,
+                active: HashMap::new(),
+                progress: None,
+            })),
+        }
+    }
+
+    /// 创建传给下载器的回调
+    pub fn callback(&self) -> ProgressCallback {
+        let state = self.state.clone();
+        Box::new(move |message| {
+            if let Ok(mut state) = state.lock() {
+                state.handle(message);
+            }
+        })
+    }
+
+    /// 已完成（包括失败）的文件数
+    pub fn finished_files(&self) -> u64 {
+        self.state.lock().map(|state| state.finished_files).unwrap_or_default()
+    }
+
+    /// 已下载的字节数（所有文件合计，包括已结束的文件）
+    pub fn downloaded_bytes(&self) -> u64 {
+        self.state.lock().map(|state| state.downloaded_bytes()).unwrap_or_default()
+    }
+
+    /// 结束并清除进度条
+    pub fn finish(&self) {
+        if let Ok(mut state) = self.state.lock() {
+            if let Some(progress) = state.progress.take() {
+                progress.finish();
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Jira 变更时间线
+//!
+//! 将 Jira changelog API 返回的变更历史整理为按时间排序的字段变更事件，
+//! 用于显示 ticket 的状态流转（谁在什么时候把 ticket 从哪个状态改到哪个状态）。
+//!
+//! 变更历史较多时 Jira 会分页返回，`ChangelogPage` 负责解析单页数据并计算下一页的起始位置。
+
+use chrono::{DateTime, FixedOffset};
+use serde::Serialize;
+use serde_json::Value;
+
+use super::types::{JiraChangelog, JiraChangelogHistory, JiraChangelogItem};
+
+/// 默认跟踪的字段
+pub const DEFAULT_TRACKED_FIELD: &str = "status";
+
+/// 每页请求的变更历史数量（Jira 允许的最大值）
+pub const CHANGELOG_PAGE_SIZE: usize = 100;
+
+/// 一页变更历史
+///
+/// 支持两种响应格式：
+/// - `GET /issue/{key}?expand=changelog` 中的 `changelog` 对象：`{ startAt, maxResults, total, histories }`
+/// - `GET /issue/{key}/changelog` 分页接口：`{ startAt, maxResults, total, isLast, values }`
+#[derive(Debug, Clone)]
+pub struct ChangelogPage {
+    /// 本页的变更历史
+    pub histories: Vec<JiraChangelogHistory>,
+    /// 本页的起始位置
+    pub start_at: usize,
+    /// 变更历史总数（响应中没有时为 `None`）
+    pub total: Option<usize>,
+    /// 是否为最后一页（响应中没有时为 `None`）
+    pub is_last: Option<bool>,
+}
+
+impl ChangelogPage {
+    /// 解析一页变更历史（无法解析的记录会被忽略）
+    pub fn from_value(value: &Value) -> Self {
+        let histories = value
+            .get("values")
+            .or_else(|| value.get("histories"))
+            .and_then(|h| h.as_array())
+            .map(|histories| histories.iter().filter_map(parse_history).collect())
+            .unwrap_or_default();
+        let number = |key: &str| value.get(key).and_then(|v| v.as_u64()).map(|v| v as usize);
+
+        Self {
+            histories,
+            start_at: number("startAt").unwrap_or(0),
+            total: number("total"),
+            is_last: value.get("isLast").and_then(|v| v.as_bool()),
+        }
+    }
+
+    /// 下一页的起始位置（没有下一页时返回 `None`）
+    ///
+    /// 优先使用 `isLast`，否则根据 `total` 判断；两者都没有或本页为空时视为最后一页。
+    pub fn next_start(&self) -> Option<usize> {
+        if self.histories.is_empty() || self.is_last == Some(true) {
+            return None;
+        }
+        let next = self.start_at + self.histories.len();
+        match (self.is_last, self.total) {
+            (Some(false), _) => Some(next),
+            (_, Some(total)) if next < total => Some(next),
+            _ => None,
+        }
+    }
+}
+
+/// 解析单条变更历史（缺少 ID 或时间时返回 `None`，无法解析的变更项会被忽略）
+fn parse_history(value: &Value) -> Option<JiraChangelogHistory> {
+    let id = value.get("id")?.as_str()?.to_string();
+    let created = value.get("created")?.as_str()?.to_string();
+    let author = value.get("author").and_then(|a| serde_json::from_value(a.clone()).ok());
+    let items: Vec<JiraChangelogItem> = value
+        .get("items")
+        .and_then(|i| i.as_array())
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|item| serde_json::from_value(item.clone()).ok())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(JiraChangelogHistory {
+        id,
+        created,
+        author,
+        items,
+    })
+}
+
+/// 单个字段变更事件
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChangelogEvent {
+    /// 变更时间（Jira 返回的原始时间字符串）
+    pub created: String,
+    /// 执行变更的用户（显示名称，未知时为 `None`）
+    pub actor: Option<String>,
+    /// 字段名称
+    pub field: String,
+    /// 变更前的值
+    pub from: Option<String>,
+    /// 变更后的值
+    pub to: Option<String>,
+}
+
+impl JiraChangelog {
+    /// 生成按时间排序的变更时间线
+    ///
+    /// 只包含 `fields` 中指定的字段（不区分大小写，同时匹配字段名和字段 ID）。
+    /// `fields` 为空时包含所有字段。
+    pub fn timeline(&self, fields: &[String]) -> Vec<ChangelogEvent> {
+        let mut histories: Vec<_> = self.histories.iter().collect();
+        // 按时间排序（稳定排序，无法解析的时间排在最前并保持原有顺序）
+        histories.sort_by_key(|history| parse_timestamp(&history.created));
+
+        histories
+            .into_iter()
+            .flat_map(|history| {
+                let actor = history.author.as_ref().map(|author| author.display_name.clone());
+                history.items.iter().filter(|item| is_tracked(item, fields)).map(move |item| {
+                    ChangelogEvent {
+                        created: history.created.clone(),
+                        actor: actor.clone(),
+                        field: item.field.clone(),
+                        from: item.from_string.clone().or_else(|| item.from.clone()),
+                        to: item.to_string.clone().or_else(|| item.to.clone()),
+                    }
+                })
+            })
+            .collect()
+    }
+}
+
+/// 检查变更项是否属于跟踪的字段
+fn is_tracked(item: &JiraChangelogItem, fields: &[String]) -> bool {
+    fields.is_empty()
+        || fields.iter().any(|field| {
+            item.field.eq_ignore_ascii_case(field)
+                || item
+                    .field_id
+                    .as_deref()
+                    .map(|id| id.eq_ignore_ascii_case(field))
+                    .unwrap_or(false)
+        })
+}
+
+/// 解析 Jira 时间戳（如 `2024-01-15T10:30:00.000+0000`）
+fn parse_timestamp(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.3f%z"))
+        .ok()
+}
@@ -8,8 +8,11 @@
 
 use color_eyre::Result;
 
+use crate::base::settings::Settings;
+
+use super::api::user::JiraUserApi;
 use super::ticket::JiraTicket;
-use super::types::{JiraAttachment, JiraIssue, JiraUser};
+use super::types::{JiraAttachment, JiraComment, JiraComments, JiraIssue, JiraUser};
 use super::users::JiraUsers;
 
 /// Jira REST API 客户端
@@ -19,6 +22,13 @@ use super::users::JiraUsers;
 pub struct JiraClient;
 
 impl JiraClient {
+    /// Jira 集成是否可用（见 `JiraSettings::is_configured`）
+    ///
+    /// 未配置 Jira 时，调用方应跳过 ticket 相关的步骤，而不是调用 Jira API 后报错。
+    pub fn is_configured() -> bool {
+        Settings::get().jira.is_configured()
+    }
+
     /// 获取当前 Jira 用户信息
     ///
     /// 先检查本地缓存，如果缓存不存在或读取失败，则从 Jira API 获取并保存到本地。
@@ -30,6 +40,18 @@ impl JiraClient {
         JiraUsers::get()
     }
 
+    /// 从 Jira API 获取当前用户信息（不读取也不写入本地缓存）
+    ///
+    /// 调用 `/myself` 接口，用于验证服务地址和认证信息是否有效。
+    ///
+    /// # 错误
+    ///
+    /// 非 2xx 响应返回 `HttpStatusError`（如 401 表示 token 无效或已过期）；
+    /// 请求失败返回 `HttpRequestError`（如 DNS 解析失败、连接超时）。
+    pub fn get_current_user() -> Result<JiraUser> {
+        JiraUserApi::get_current_user()
+    }
+
     /// 获取 ticket 信息
     ///
     /// 从 Jira API 获取指定 ticket 的完整信息，包括：
@@ -97,10 +119,25 @@ impl JiraClient {
     ///
     /// * `ticket` - Jira ticket ID，格式如 `PROJ-123`
     /// * `comment` - 评论内容
-    pub fn add_comment(ticket: &str, comment: &str) -> Result<()> {
+    ///
+    /// # 返回
+    ///
+    /// 成功时返回创建的评论。
+    pub fn add_comment(ticket: &str, comment: &str) -> Result<JiraComment> {
         JiraTicket::add_comment(ticket, comment)
     }
 
+    /// 获取 ticket 的所有评论
+    ///
+    /// 评论按创建时间升序排列（最新的评论在最后）。
+    ///
+    /// # 参数
+    ///
+    /// * `ticket` - Jira ticket ID，格式如 `PROJ-123`
+    pub fn get_comments(ticket: &str) -> Result<JiraComments> {
+        JiraTicket::get_comments(ticket)
+    }
+
     /// 上传附件到 ticket
     ///
     /// 将文件作为附件上传到指定的 ticket。
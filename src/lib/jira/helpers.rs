@@ -1,13 +1,14 @@
 //! Jira 辅助函数
 //!
 //! 本模块提供了 Jira 相关的辅助函数，包括：
-//! - 字符串处理（提取项目名、提取 ticket ID、验证格式）
+//! - 字符串处理（提取项目名、提取 ticket ID、从 Jira URL 提取 ticket、验证格式）
 //! - 文件名处理（邮箱地址清理）
 //! - 认证和 URL 构建（获取认证信息、构建基础 URL）
 //!
 //! 注意：日志处理相关的辅助函数已迁移到 `jira::logs::helpers` 模块。
 
 use crate::base::constants::errors::validation_errors;
+use crate::base::http::Authorization;
 use crate::base::settings::Settings;
 use color_eyre::Result;
 use regex::Regex;
@@ -91,6 +92,54 @@ pub fn extract_jira_ticket_id(pull_request_title: &str) -> Option<String> {
         .map(|m| m.as_str().to_string())
 }
 
+/// 从分支名提取 Jira ticket ID
+///
+/// 依次检查以 `/` 分隔的每一段，返回第一个以 ticket ID 开头的段中的 ticket
+/// （见 `extract_jira_ticket_id`），如 `feature/PROJ-123-fix-login`、`zw/PROJ-123_fix`。
+///
+/// # 示例
+/// ```
+/// use workflow::jira::helpers::extract_jira_ticket_from_branch;
+/// assert_eq!(
+///     extract_jira_ticket_from_branch("feature/PROJ-123-fix-login"),
+///     Some("PROJ-123".to_string())
+/// );
+/// assert_eq!(extract_jira_ticket_from_branch("PROJ-45"), Some("PROJ-45".to_string()));
+/// assert_eq!(extract_jira_ticket_from_branch("feature/update-deps"), None);
+/// ```
+pub fn extract_jira_ticket_from_branch(branch_name: &str) -> Option<String> {
+    branch_name.trim().split('/').find_map(extract_jira_ticket_id)
+}
+
+/// 从 Jira URL 提取 ticket ID
+///
+/// 支持 issue 页面（`/browse/PROJ-123`）和看板中选中的 issue（`selectedIssue=PROJ-123`），
+/// 忽略首尾空白；不是 URL 或 URL 中没有 ticket 时返回 `None`。
+///
+/// # 示例
+/// ```
+/// use workflow::jira::helpers::extract_jira_ticket_from_url;
+/// assert_eq!(
+///     extract_jira_ticket_from_url("https://jira.example.com/browse/PROJ-123"),
+///     Some("PROJ-123".to_string())
+/// );
+/// assert_eq!(
+///     extract_jira_ticket_from_url(
+///         "https://example.atlassian.net/jira/software/projects/PROJ/boards/1?selectedIssue=PROJ-45"
+///     ),
+///     Some("PROJ-45".to_string())
+/// );
+/// assert_eq!(extract_jira_ticket_from_url("PROJ-123"), None);
+/// ```
+pub fn extract_jira_ticket_from_url(url: &str) -> Option<String> {
+    let url = url.trim();
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return None;
+    }
+    let re = Regex::new(r"(?:/browse/|[?&]selectedIssue=)([A-Z][A-Z0-9_]*-\d+)\b").ok()?;
+    re.captures(url).and_then(|caps| caps.get(1)).map(|m| m.as_str().to_string())
+}
+
 /// 清理邮箱地址作为文件名（方案1：简单替换）
 ///
 /// 将邮箱地址中的特殊字符替换为安全的文件名字符：
@@ -122,6 +171,20 @@ pub fn get_auth() -> Result<(String, String)> {
     Ok((email, api_token))
 }
 
+/// 获取认证信息（按 `jira.auth_type` 选择认证方式）
+///
+/// - `basic`（默认）：邮箱 + API token 的 Basic Authentication
+/// - `bearer`：API token 作为 Bearer token（Personal Access Token 或 OAuth 2.0 access token）
+///
+/// 所有 Jira API 请求都使用此函数构建的认证信息。
+///
+/// # 返回
+///
+/// 返回 `Authorization`。
+pub fn get_authorization() -> Result<Authorization> {
+    Ok(Settings::get().jira.authorization())
+}
+
 /// 获取 Jira API 基础 URL
 ///
 /// 从配置文件中读取 Jira 服务地址，并构建 REST API 基础 URL。
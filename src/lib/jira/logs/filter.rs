@@ -0,0 +1,104 @@
+//! 日志条目过滤功能
+//!
+//! 根据日志级别和组件过滤解析后的日志条目。
+
+use crate::base::logger::LogLevel;
+
+use super::helpers::LogEntry;
+
+/// 日志条目过滤器
+///
+/// 所有条件同时生效（AND 关系），未设置的条件不参与过滤。
+#[derive(Debug, Clone, Default)]
+pub struct LogFilter {
+    /// 只保留指定级别的条目（为空表示不限制）
+    pub levels: Vec<LogLevel>,
+    /// 只保留严重程度不低于该级别的条目（如 `warn` 包含 warn 和 error）
+    pub min_level: Option<LogLevel>,
+    /// 只保留指定组件的条目（不区分大小写）
+    pub component: Option<String>,
+}
+
+/// 过滤结果
+#[derive(Debug, Clone, Default)]
+pub struct FilterResult {
+    /// 通过过滤的条目
+    pub entries: Vec<LogEntry>,
+    /// 因无法解析级别而被跳过的条目数量（仅在启用级别过滤时统计）
+    pub skipped_unparseable: usize,
+}
+
+impl LogFilter {
+    /// 创建新的过滤器
+    pub fn new(
+        levels: Vec<LogLevel>,
+        min_level: Option<LogLevel>,
+        component: Option<String>,
+    ) -> Self {
+        Self {
+            levels,
+            min_level,
+            component,
+        }
+    }
+
+    /// 是否启用了级别过滤
+    pub fn has_level_filter(&self) -> bool {
+        !self.levels.is_empty() || self.min_level.is_some()
+    }
+
+    /// 是否未设置任何过滤条件
+    pub fn is_empty(&self) -> bool {
+        !self.has_level_filter() && self.component.is_none()
+    }
+
+    /// 检查条目是否通过过滤
+    ///
+    /// 启用级别过滤时，没有识别出级别的条目不会通过。
+    pub fn matches(&self, entry: &LogEntry) -> bool {
+        if self.has_level_filter() {
+            let Some(level) = entry.level else {
+                return false;
+            };
+            if !self.levels.is_empty() && !self.levels.contains(&level) {
+                return false;
+            }
+            // 级别数值越小越严重（Error < Warn < Info < Debug）
+            if let Some(min_level) = self.min_level {
+                if level > min_level {
+                    return false;
+                }
+            }
+        }
+
+        if let Some(ref component) = self.component {
+            let matched = entry
+                .component
+                .as_ref()
+                .map(|c| c.eq_ignore_ascii_case(component))
+                .unwrap_or(false);
+            if !matched {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// 过滤条目列表
+    ///
+    /// 启用级别过滤时，统计因无法解析级别而被跳过的条目数量。
+    pub fn apply(&self, entries: Vec<LogEntry>) -> FilterResult {
+        let mut result = FilterResult::default();
+        for entry in entries {
+            if self.has_level_filter() && entry.level.is_none() {
+                result.skipped_unparseable += 1;
+                continue;
+            }
+            if self.matches(&entry) {
+                result.entries.push(entry);
+            }
+        }
+        result
+    }
+}
@@ -1,20 +1,138 @@
 //! 日志处理辅助函数
 //!
 //! 本模块提供了日志处理相关的辅助函数，包括：
-//! - 日志条目解析和 URL 提取
+//! - 日志条目解析（ID、URL、时间戳、日志级别、组件）
 //! - 路径处理
 //! - 文件操作
 
+use chrono::NaiveDateTime;
 use color_eyre::Result;
 use regex::Regex;
+use serde::{Serialize, Serializer};
 use std::collections::HashSet;
 use std::sync::OnceLock;
 
+use crate::base::logger::LogLevel;
+
+/// ISO-8601 时间戳格式（秒以下部分仅在存在时输出）
+const ISO_8601_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
 /// 日志条目信息
-#[derive(Debug, Clone)]
+///
+/// 可序列化为 JSON（用于 `--format jsonl` 输出），时间戳使用 ISO-8601 格式，
+/// `raw` 保留条目的原始行，便于下游工具无损处理。
+#[derive(Debug, Clone, Serialize)]
 pub struct LogEntry {
     pub id: Option<String>,
     pub url: Option<String>,
+    /// 时间戳（从行中的 `YYYY-MM-DD HH:MM:SS[.fff]` 或 ISO-8601 格式识别）
+    #[serde(serialize_with = "serialize_timestamp")]
+    pub timestamp: Option<NaiveDateTime>,
+    /// 日志级别（从 emoji 前缀或 `ERROR`/`WARN`/`INFO`/`DEBUG` 标记识别）
+    pub level: Option<LogLevel>,
+    /// 组件名称（从 `[Component]` 标记识别）
+    pub component: Option<String>,
+    /// 条目的原始行
+    pub raw: String,
+}
+
+impl LogEntry {
+    /// 序列化为单行 JSON（JSON Lines 格式的一行）
+    pub fn to_json_line(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+/// 将时间戳序列化为 ISO-8601 字符串
+fn serialize_timestamp<S>(
+    timestamp: &Option<NaiveDateTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match timestamp {
+        Some(ts) => serializer.serialize_str(&ts.format(ISO_8601_FORMAT).to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// 从行中提取时间戳
+///
+/// 支持 `2024-01-15 10:30:45`、`2024-01-15T10:30:45.123` 等格式，时区后缀会被忽略
+/// （原始行保存在 `LogEntry::raw` 中）。
+pub(crate) fn extract_timestamp_from_line(line: &str) -> Option<NaiveDateTime> {
+    // 使用静态正则表达式避免重复编译
+    static TIMESTAMP_PATTERN: OnceLock<Regex> = OnceLock::new();
+    let timestamp_pattern = TIMESTAMP_PATTERN.get_or_init(|| {
+        Regex::new(r"(\d{4}-\d{2}-\d{2})[T ](\d{2}:\d{2}:\d{2}(?:[.,]\d+)?)")
+            .expect("Failed to compile timestamp pattern regex")
+    });
+
+    let caps = timestamp_pattern.captures(line)?;
+    let normalized = format!("{}T{}", &caps[1], caps[2].replace(',', "."));
+    NaiveDateTime::parse_from_str(&normalized, ISO_8601_FORMAT).ok()
+}
+
+/// 从行中提取组件名称
+///
+/// 组件使用方括号标记（如 `[HttpClient]`），跳过级别标记（如 `[ERROR]`）。
+pub(crate) fn extract_component_from_line(line: &str) -> Option<String> {
+    // 使用静态正则表达式避免重复编译
+    static COMPONENT_PATTERN: OnceLock<Regex> = OnceLock::new();
+    let component_pattern = COMPONENT_PATTERN.get_or_init(|| {
+        Regex::new(r"\[([A-Za-z][\w.\-/ ]*)\]").expect("Failed to compile component pattern regex")
+    });
+
+    component_pattern
+        .captures_iter(line)
+        .filter_map(|caps| caps.get(1))
+        .map(|m| m.as_str().trim())
+        .find(|name| {
+            !matches!(
+                name.to_uppercase().as_str(),
+                "ERROR" | "WARNING" | "WARN" | "INFO" | "DEBUG"
+            )
+        })
+        .map(|name| name.to_string())
+}
+
+/// 从行中提取日志级别
+///
+/// 识别规则：
+/// 1. Flutter logger 的 emoji 前缀：⛔/❌ 为 error，⚠️ 为 warn，💡/ℹ️ 为 info，🐛 为 debug
+/// 2. 大写的级别标记：`ERROR`、`WARN`/`WARNING`、`INFO`、`DEBUG`（可带方括号）
+pub(crate) fn extract_level_from_line(line: &str) -> Option<LogLevel> {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with('⛔') || trimmed.starts_with('❌') {
+        return Some(LogLevel::Error);
+    }
+    if trimmed.starts_with('⚠') {
+        return Some(LogLevel::Warn);
+    }
+    if trimmed.starts_with('💡') || trimmed.starts_with('ℹ') {
+        return Some(LogLevel::Info);
+    }
+    if trimmed.starts_with('🐛') {
+        return Some(LogLevel::Debug);
+    }
+
+    // 使用静态正则表达式避免重复编译
+    static LEVEL_PATTERN: OnceLock<Regex> = OnceLock::new();
+    let level_pattern = LEVEL_PATTERN.get_or_init(|| {
+        Regex::new(r"\b(ERROR|WARNING|WARN|INFO|DEBUG)\b")
+            .expect("Failed to compile level pattern regex")
+    });
+
+    level_pattern
+        .captures(line)
+        .and_then(|caps| caps.get(1))
+        .map(|m| match m.as_str() {
+            "ERROR" => LogLevel::Error,
+            "WARNING" | "WARN" => LogLevel::Warn,
+            "INFO" => LogLevel::Info,
+            _ => LogLevel::Debug,
+        })
 }
 
 /// 从行中提取 URL
@@ -74,7 +192,19 @@ pub(crate) fn parse_log_entry(line: &str) -> Result<Option<LogEntry>> {
     // 尝试提取 URL
     let url = extract_url_from_line(line);
 
-    Ok(Some(LogEntry { id, url }))
+    // 提取时间戳、日志级别和组件
+    let timestamp = extract_timestamp_from_line(line);
+    let level = extract_level_from_line(line);
+    let component = extract_component_from_line(line);
+
+    Ok(Some(LogEntry {
+        id,
+        url,
+        timestamp,
+        level,
+        component,
+        raw: line.to_string(),
+    }))
 }
 
 /// 添加条目到结果列表（如果未重复）
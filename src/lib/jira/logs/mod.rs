@@ -1,5 +1,5 @@
 //! Jira 日志处理结构体
-//! 提供从 Jira 下载的日志文件的下载、搜索、查找和处理功能
+//! 提供从 Jira 下载的日志文件的下载、搜索、查找、跟踪和处理功能
 
 use color_eyre::{eyre::WrapErr, Result};
 use std::path::PathBuf;
@@ -11,14 +11,23 @@ use crate::base::settings::Settings;
 // 子模块
 mod constants;
 mod download;
+mod filter;
 mod helpers;
 mod path;
 mod search;
 mod table;
+mod tail;
 
 // 重新导出 LogEntry 作为公共 API
+pub(crate) use helpers::parse_log_entry;
 pub use helpers::LogEntry;
 
+// 重新导出过滤相关类型
+pub use filter::{FilterResult, LogFilter};
+
+// 重新导出日志跟踪相关类型
+pub use tail::{colorize_log_line, LogTail};
+
 // 重新导出下载相关的类型（从 attachments 模块）
 pub use crate::jira::attachments::{DownloadResult, ProgressCallback};
 
@@ -0,0 +1,159 @@
+//! 日志跟踪（tail）功能相关实现
+//!
+//! 提供打开日志文件、读取末尾若干行以及轮询追加内容的功能，
+//! 并在日志文件被截断或替换（日志轮转）时自动重新打开。
+
+use color_eyre::{eyre::WrapErr, Result};
+use std::collections::VecDeque;
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+
+use crate::base::logger::console::styled;
+use crate::base::logger::LogLevel;
+use crate::base::util::FileReader;
+
+use super::JiraLogs;
+
+impl JiraLogs {
+    /// 打开日志文件
+    ///
+    /// 自动根据 `jira_id` 解析日志文件路径，并返回用于跟踪的 `LogTail`。
+    /// 如果日志文件不存在，返回错误。
+    pub fn open_log_file(&self, jira_id: &str) -> Result<LogTail> {
+        let log_file = self.ensure_log_file_exists(jira_id)?;
+        LogTail::open(&log_file)
+    }
+}
+
+/// 日志文件跟踪器
+///
+/// 记录已读取的位置，每次调用 [`LogTail::read_new_lines`] 返回新追加的完整行。
+pub struct LogTail {
+    /// 日志文件路径
+    path: PathBuf,
+    /// 当前打开的文件读取器
+    reader: BufReader<File>,
+    /// 已读取的字节位置
+    position: u64,
+    /// 尚未以换行符结束的行内容
+    pending: String,
+    /// 打开时的文件标识（用于检测文件被替换）
+    file_id: Option<u64>,
+}
+
+impl LogTail {
+    /// 打开日志文件（从文件开头读取）
+    pub fn open(path: &Path) -> Result<Self> {
+        let reader = FileReader::new(path).open()?;
+        let file_id = Self::file_id(path);
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            reader,
+            position: 0,
+            pending: String::new(),
+            file_id,
+        })
+    }
+
+    /// 获取日志文件路径
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 读取当前文件末尾的最多 `count` 行，并将读取位置移动到文件末尾
+    pub fn read_last_lines(&mut self, count: usize) -> Result<Vec<String>> {
+        let mut last_lines = VecDeque::with_capacity(count);
+        for line in self.read_new_lines()? {
+            if count == 0 {
+                continue;
+            }
+            if last_lines.len() == count {
+                last_lines.pop_front();
+            }
+            last_lines.push_back(line);
+        }
+        Ok(last_lines.into_iter().collect())
+    }
+
+    /// 读取自上次读取以来新追加的完整行
+    ///
+    /// 如果日志文件被截断（长度小于已读取位置）或被替换（文件标识变化），
+    /// 会重新打开文件并从头读取。文件暂时不存在时返回空列表。
+    pub fn read_new_lines(&mut self) -> Result<Vec<String>> {
+        let metadata = match fs::metadata(&self.path) {
+            Ok(metadata) => metadata,
+            Err(_) => return Ok(Vec::new()),
+        };
+
+        let replaced = Self::file_id(&self.path) != self.file_id;
+        let truncated = metadata.len() < self.position;
+        if replaced || truncated {
+            crate::trace_debug!(
+                "Log file {} was {}, reopening",
+                self.path.display(),
+                if replaced { "replaced" } else { "truncated" }
+            );
+            self.reopen()?;
+        }
+
+        let mut lines = Vec::new();
+        loop {
+            let mut buffer = String::new();
+            let bytes = self.reader.read_line(&mut buffer).wrap_err("Failed to read line")?;
+            if bytes == 0 {
+                break;
+            }
+            self.position += bytes as u64;
+
+            if buffer.ends_with('\n') {
+                self.pending.push_str(buffer.trim_end_matches(['\r', '\n']));
+                lines.push(std::mem::take(&mut self.pending));
+            } else {
+                // 行尚未写完，等待下次读取
+                self.pending.push_str(&buffer);
+            }
+        }
+
+        Ok(lines)
+    }
+
+    /// 重新打开日志文件并从头读取
+    fn reopen(&mut self) -> Result<()> {
+        self.reader = FileReader::new(&self.path).open()?;
+        self.position = 0;
+        self.pending.clear();
+        self.file_id = Self::file_id(&self.path);
+        Ok(())
+    }
+
+    /// 获取文件标识（Unix 使用 inode，其他平台使用创建时间）
+    #[cfg(unix)]
+    fn file_id(path: &Path) -> Option<u64> {
+        use std::os::unix::fs::MetadataExt;
+        fs::metadata(path).ok().map(|metadata| metadata.ino())
+    }
+
+    /// 获取文件标识（Unix 使用 inode，其他平台使用创建时间）
+    #[cfg(not(unix))]
+    fn file_id(path: &Path) -> Option<u64> {
+        fs::metadata(path)
+            .and_then(|metadata| metadata.created())
+            .ok()
+            .and_then(|time| time.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|duration| duration.as_nanos() as u64)
+    }
+}
+
+/// 根据日志级别为日志行着色
+///
+/// error 为红色，warn 为黄色，info 保持默认颜色，debug 为灰色。
+pub fn colorize_log_line(line: &str, level: Option<LogLevel>) -> String {
+    match level {
+        Some(LogLevel::Error) => styled(line).red().to_string(),
+        Some(LogLevel::Warn) => styled(line).yellow().to_string(),
+        Some(LogLevel::Debug) => styled(line).dim().to_string(),
+        _ => line.to_string(),
+    }
+}
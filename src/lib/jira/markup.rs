@@ -0,0 +1,312 @@
+//! Jira 文本格式（wiki markup）终端渲染
+//!
+//! Jira REST API v2 返回的评论、描述等内容使用 Jira 文本格式，直接输出时会显示大量标记符号。
+//! `render_jira_markup` 将常用的标记转换为终端中易读的形式：
+//! - 标题（`h1.` ~ `h6.`）→ 粗体
+//! - 引用（`bq.`、`{quote}`）→ 行首添加 `│`
+//! - 代码块（`{code}`、`{noformat}`）→ 缩进显示，不处理其中的标记
+//! - 列表（`*`、`-`、`#`，可嵌套）→ 按层级缩进，无序列表使用 `•`，有序列表使用序号
+//! - 分隔线（`----`）→ 分隔线
+//! - 行内格式：`*粗体*`、`_斜体_`、`{{等宽}}`、`[文本|链接]`、`[~用户]`、`!图片.png!`，
+//!   `{color}` 标记会被去除
+//!
+//! 颜色和样式通过 `styled()` 生成，禁用颜色输出时只保留文本。
+//!
+//! 通过 API 或集成工具（如 PR 同步）添加的评论可能使用 Markdown，
+//! `render_comment_body` 根据内容选择 Markdown 或 Jira 文本格式的渲染方式。
+
+use std::sync::OnceLock;
+
+use regex::{Captures, Regex};
+
+use crate::base::format::DisplayFormatter;
+use crate::base::logger::console::{separator, styled};
+
+/// 分隔线（`----`）的渲染长度
+const RULE_WIDTH: usize = 40;
+
+/// 代码块的缩进
+const CODE_INDENT: &str = "    ";
+
+/// 引用的行首标记
+const QUOTE_PREFIX: &str = "│ ";
+
+/// 行内格式的正则表达式
+struct InlinePatterns {
+    link: Regex,
+    mention: Regex,
+    bare_link: Regex,
+    image: Regex,
+    color: Regex,
+    monospace: Regex,
+    bold: Regex,
+    italic: Regex,
+}
+
+impl InlinePatterns {
+    fn get() -> &'static InlinePatterns {
+        static PATTERNS: OnceLock<InlinePatterns> = OnceLock::new();
+        PATTERNS.get_or_init(|| InlinePatterns {
+            link: Regex::new(r"\[([^\[\]|]+)\|([^\[\]]+)\]").expect("Failed to compile link regex"),
+            mention: Regex::new(r"\[~(?:accountid:)?([^\[\]]+)\]")
+                .expect("Failed to compile mention regex"),
+            bare_link: Regex::new(r"\[((?:https?|mailto|file):[^\[\]\s]+)\]")
+                .expect("Failed to compile bare link regex"),
+            image: Regex::new(r"!([^!\s|]+\.[A-Za-z0-9]+)(?:\|[^!]*)?!")
+                .expect("Failed to compile image regex"),
+            color: Regex::new(r"\{color(?::[^}]*)?\}").expect("Failed to compile color regex"),
+            monospace: Regex::new(r"\{\{(.+?)\}\}").expect("Failed to compile monospace regex"),
+            bold: Regex::new(r"(^|[^\w*])\*([^*\s](?:[^*]*?[^*\s])?)\*")
+                .expect("Failed to compile bold regex"),
+            italic: Regex::new(r"(^|[^\w_])_([^_\s](?:[^_]*?[^_\s])?)_")
+                .expect("Failed to compile italic regex"),
+        })
+    }
+}
+
+/// 块级格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    /// `{code}` 或 `{noformat}` 代码块
+    Code,
+    /// `{quote}` 引用块
+    Quote,
+}
+
+/// 将 Jira 文本格式渲染为终端文本
+///
+/// # 参数
+///
+/// * `text` - Jira 文本格式的内容（如评论正文）
+///
+/// # 返回
+///
+/// 渲染后的文本（按行输出，行数与原文中的非标记行一致）。
+///
+/// # 示例
+///
+/// ```
+/// use workflow::base::logger::console::set_colors_enabled;
+/// use workflow::jira::markup::render_jira_markup;
+///
+/// set_colors_enabled(false);
+/// assert_eq!(render_jira_markup("h2. Steps"), "Steps");
+/// assert_eq!(render_jira_markup("* first\n** nested"), "• first\n  • nested");
+/// assert_eq!(
+///     render_jira_markup("See [the docs|https://example.com] for *details*"),
+///     "See the docs (https://example.com) for details"
+/// );
+/// ```
+pub fn render_jira_markup(text: &str) -> String {
+    let mut lines = Vec::new();
+    let mut block: Option<Block> = None;
+    let mut counters: Vec<usize> = Vec::new();
+
+    for line in text.lines() {
+        let trimmed = line.trim();
+
+        if let Some(tag) = block_tag(trimmed) {
+            match (block, tag) {
+                // 代码块中只有结束标记有效
+                (Some(Block::Code), Block::Code) => block = None,
+                (Some(Block::Code), Block::Quote) => lines.push(render_code(line)),
+                (Some(Block::Quote), Block::Quote) => block = None,
+                _ => block = Some(tag),
+            }
+            counters.clear();
+            continue;
+        }
+
+        match block {
+            Some(Block::Code) => {
+                lines.push(render_code(line));
+                continue;
+            }
+            Some(Block::Quote) => {
+                lines.push(format!(
+                    "{}{}",
+                    styled(QUOTE_PREFIX).dim(),
+                    render_inline(trimmed)
+                ));
+                continue;
+            }
+            None => {}
+        }
+
+        if let Some((depth, numbered, item)) = list_item(trimmed) {
+            counters.truncate(depth);
+            counters.resize(depth, 0);
+            let marker = if numbered {
+                counters[depth - 1] += 1;
+                format!("{}.", counters[depth - 1])
+            } else {
+                counters[depth - 1] = 0;
+                "•".to_string()
+            };
+            lines.push(format!(
+                "{}{} {}",
+                "  ".repeat(depth - 1),
+                marker,
+                render_inline(item)
+            ));
+            continue;
+        }
+        counters.clear();
+
+        lines.push(render_line(line, trimmed));
+    }
+
+    lines.join("\n")
+}
+
+/// 判断内容是否使用 Markdown（而不是 Jira 文本格式）
+///
+/// 只根据 Jira 文本格式中不会出现的标记判断：代码块（```` ``` ````）、链接（`[文本](链接)`）
+/// 和粗体（`**粗体**`）。`#` 开头的行和 `** ` 开头的行在 Jira 中表示列表，不作为判断依据。
+///
+/// # 示例
+///
+/// ```
+/// use workflow::jira::markup::is_markdown;
+///
+/// assert!(is_markdown("See [the docs](https://example.com)"));
+/// assert!(!is_markdown("See [the docs|https://example.com]"));
+/// assert!(!is_markdown("* first\n** nested"));
+/// ```
+pub fn is_markdown(text: &str) -> bool {
+    text.lines().any(|line| line.trim_start().starts_with("```"))
+        || text.contains("](")
+        || markdown_bold_regex().is_match(text)
+}
+
+/// Markdown 粗体（`**粗体**`）的正则表达式
+fn markdown_bold_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"\*\*[^*\s](?:[^*]*[^*\s])?\*\*").expect("Failed to compile bold regex")
+    })
+}
+
+/// 渲染评论内容
+///
+/// 内容使用 Markdown 时按 Markdown 渲染，否则按 Jira 文本格式渲染。
+pub fn render_comment_body(text: &str) -> String {
+    if is_markdown(text) {
+        DisplayFormatter::render_markdown(text)
+    } else {
+        render_jira_markup(text)
+    }
+}
+
+/// 渲染代码块中的一行（不处理标记）
+fn render_code(line: &str) -> String {
+    format!("{}{}", CODE_INDENT, styled(line).cyan())
+}
+
+/// 渲染普通行（标题、引用、分隔线和段落）
+fn render_line(line: &str, trimmed: &str) -> String {
+    if trimmed.len() >= 4 && trimmed.chars().all(|c| c == '-') {
+        return separator('-', RULE_WIDTH);
+    }
+    if let Some(heading) = heading(trimmed) {
+        return styled(render_inline(heading)).bold().to_string();
+    }
+    if let Some(quote) = trimmed.strip_prefix("bq.") {
+        return format!(
+            "{}{}",
+            styled(QUOTE_PREFIX).dim(),
+            render_inline(quote.trim_start())
+        );
+    }
+    render_inline(line)
+}
+
+/// 识别块级标记（`{code}`、`{code:java}`、`{noformat}`、`{quote}`）
+fn block_tag(trimmed: &str) -> Option<Block> {
+    let name = trimmed.strip_prefix('{')?.strip_suffix('}')?;
+    let name = name.split_once(':').map_or(name, |(name, _)| name);
+    match name {
+        "code" | "noformat" => Some(Block::Code),
+        "quote" => Some(Block::Quote),
+        _ => None,
+    }
+}
+
+/// 识别标题（`h1.` ~ `h6.`），返回标题文本
+fn heading(trimmed: &str) -> Option<&str> {
+    let rest = trimmed.strip_prefix('h')?;
+    let mut chars = rest.chars();
+    let level = chars.next()?;
+    if !('1'..='6').contains(&level) {
+        return None;
+    }
+    chars.as_str().strip_prefix('.').map(str::trim_start)
+}
+
+/// 识别列表项，返回（层级、是否为有序列表、内容）
+///
+/// 列表标记后必须有空白，避免将 `*粗体*` 开头的段落识别为列表。
+fn list_item(trimmed: &str) -> Option<(usize, bool, &str)> {
+    let markers = trimmed.chars().take_while(|c| matches!(c, '*' | '#' | '-')).count();
+    if markers == 0 {
+        return None;
+    }
+    let rest = &trimmed[markers..];
+    if !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    // `-` 只作为单层列表标记（`--` 等在 Jira 中表示删除线或破折号）
+    let marker_str = &trimmed[..markers];
+    if marker_str.contains('-') && markers > 1 {
+        return None;
+    }
+    let numbered = marker_str.ends_with('#');
+    Some((markers, numbered, rest.trim_start()))
+}
+
+/// 渲染行内格式
+fn render_inline(text: &str) -> String {
+    let patterns = InlinePatterns::get();
+
+    let text = patterns.color.replace_all(text, "");
+    let text = patterns.mention.replace_all(&text, |caps: &Captures| {
+        styled(format!("@{}", &caps[1])).bold().to_string()
+    });
+    let text = patterns.link.replace_all(&text, |caps: &Captures| {
+        format!("{} ({})", &caps[1], styled(&caps[2]).underlined())
+    });
+    let text = patterns.bare_link.replace_all(&text, |caps: &Captures| {
+        styled(&caps[1]).underlined().to_string()
+    });
+    let text = patterns.image.replace_all(&text, |caps: &Captures| {
+        styled(format!("[image: {}]", &caps[1])).dim().to_string()
+    });
+    let text = patterns
+        .monospace
+        .replace_all(&text, |caps: &Captures| styled(&caps[1]).cyan().to_string());
+
+    let text = replace_emphasis(&patterns.bold, &text, |inner| {
+        styled(inner).bold().to_string()
+    });
+    replace_emphasis(&patterns.italic, &text, |inner| {
+        styled(inner).italic().to_string()
+    })
+}
+
+/// 替换粗体、斜体等成对标记
+///
+/// 结束标记后紧跟字母或数字时（如 `a*b*c`）不视为格式标记，保留原文。
+fn replace_emphasis(pattern: &Regex, text: &str, render: impl Fn(&str) -> String) -> String {
+    pattern
+        .replace_all(text, |caps: &Captures| {
+            let whole = caps.get(0).expect("group 0 always matches");
+            let followed_by_word =
+                text[whole.end()..].chars().next().is_some_and(char::is_alphanumeric);
+            if followed_by_word {
+                whole.as_str().to_string()
+            } else {
+                format!("{}{}", &caps[1], render(&caps[2]))
+            }
+        })
+        .into_owned()
+}
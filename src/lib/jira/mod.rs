@@ -14,16 +14,26 @@
 //! - `ticket` - Ticket/Issue 相关 API（查询、更新、分配、评论）
 //! - `status` - 状态管理（项目状态获取、状态配置）
 //! - `history` - 工作历史记录管理（PR 创建/合并记录）
+//! - `changelog` - 变更时间线（状态流转等字段变更）
+//! - `related` - 关联 ticket（Epic/父任务、子任务、Issue 链接）
+//! - `markup` - Jira 文本格式的终端渲染（评论等内容）
+//! - `output` - 命令的机器可读输出（`jira info --json`）
+//! - `sprint` - Sprint 自定义字段解析（对象和字符串两种格式）
 //! - `types` - 数据模型定义
 //! - `helpers` - 辅助函数（字符串处理、认证、URL 构建）
 
 pub mod api;
 pub mod attachments;
+pub mod changelog;
 pub mod client;
 pub mod config;
 pub mod helpers;
 pub mod history;
 pub mod logs;
+pub mod markup;
+pub mod output;
+pub mod related;
+pub mod sprint;
 pub mod status;
 pub mod table;
 pub mod ticket;
@@ -33,24 +43,29 @@ pub mod users;
 // 重新导出所有公共 API，保持向后兼容
 pub use api::{JiraIssueApi, JiraProjectApi, JiraUserApi};
 pub use attachments::{DownloadResult, JiraAttachmentDownloader, ProgressCallback};
+pub use changelog::{ChangelogEvent, ChangelogPage, CHANGELOG_PAGE_SIZE, DEFAULT_TRACKED_FIELD};
 pub use client::JiraClient;
 pub use config::ConfigManager;
 pub use helpers::{
-    extract_jira_project, extract_jira_ticket_id, sanitize_email_for_filename,
-    validate_jira_ticket_format,
+    extract_jira_project, extract_jira_ticket_from_url, extract_jira_ticket_id,
+    sanitize_email_for_filename, validate_jira_ticket_format,
 };
 pub use logs::{JiraLogs, LogEntry};
+pub use markup::{is_markdown, render_comment_body, render_jira_markup};
+pub use output::{JiraIssueOutput, JiraSprintOutput, JiraUserOutput};
+pub use related::{IssueRelation, LinkedIssue, RelatedGroup, RelatedIssue, RelatedIssues};
+pub use sprint::{parse_sprint_field, parse_sprint_string, ACTIVE_SPRINT_STATE};
 // 导出 types 模块的类型
 // 注意：types::JiraStatus 是数据模型（用于序列化），status::JiraStatus 是管理结构体
 // 如果需要访问数据模型，请使用 jira::types::JiraStatus
 pub use history::{JiraWorkHistory, WorkHistoryEntry};
 pub use status::{JiraStatus, JiraStatusConfig, ProjectStatusConfig};
-pub use table::{AttachmentRow, FileRow};
+pub use table::{AttachmentRow, ChangelogRow, FileRow, RelatedIssueRow, SprintIssueRow};
 pub use types::{
-    JiraAttachment, JiraChangelog, JiraChangelogHistory, JiraChangelogItem, JiraComment,
+    JiraAttachment, JiraBoard, JiraChangelog, JiraChangelogHistory, JiraChangelogItem, JiraComment,
     JiraComments, JiraComponent, JiraIssue, JiraIssueFields, JiraIssueLink, JiraIssueLinkType,
-    JiraIssueRef, JiraPriority, JiraSubtask, JiraSubtaskFields, JiraTimeTracking, JiraTransition,
-    JiraUser, JiraVersion,
+    JiraIssueRef, JiraIssueType, JiraPriority, JiraSprint, JiraSubtask, JiraSubtaskFields,
+    JiraTimeTracking, JiraTransition, JiraUser, JiraVersion,
 };
 
 /// Jira 客户端（向后兼容别名）
@@ -0,0 +1,112 @@
+//! Jira 命令的机器可读输出
+//!
+//! 定义 `jira info --json` 输出的 JSON 文档结构。与直接序列化 Jira API 返回的 `JiraIssue` 不同，
+//! 这里的字段名和类型是对脚本的约定：只新增字段，不修改或删除已有字段；没有值的字段输出为 `null`，
+//! 列表字段没有值时输出为空数组。
+
+use serde::Serialize;
+
+use crate::jira::types::{JiraIssue, JiraUser};
+
+/// `jira info --json` 输出
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JiraIssueOutput {
+    /// Issue key（如 `PROJ-123`）
+    pub key: String,
+    /// Issue ID
+    pub id: String,
+    /// 标题
+    pub summary: String,
+    /// 描述（原始 Jira 文本）
+    pub description: Option<String>,
+    /// 状态名称
+    pub status: String,
+    /// 优先级名称
+    pub priority: Option<String>,
+    /// 报告人
+    pub reporter: Option<JiraUserOutput>,
+    /// 经办人（未分配时为 `None`）
+    pub assignee: Option<JiraUserOutput>,
+    /// 标签
+    pub labels: Vec<String>,
+    /// 组件名称
+    pub components: Vec<String>,
+    /// 修复版本名称
+    pub fix_versions: Vec<String>,
+    /// 当前所在的 Sprint
+    pub sprint: Option<JiraSprintOutput>,
+    /// 创建时间（Jira 返回的原始时间字符串）
+    pub created: Option<String>,
+    /// 更新时间（Jira 返回的原始时间字符串）
+    pub updated: Option<String>,
+    /// 附件数量
+    pub attachments: usize,
+    /// 评论数量
+    pub comments: usize,
+    /// Issue 网页地址（未配置 `jira.service_address` 时为 `None`）
+    pub url: Option<String>,
+}
+
+/// Jira 用户
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JiraUserOutput {
+    /// 显示名称
+    pub name: String,
+    /// 邮箱（Jira 隐藏邮箱时为 `None`）
+    pub email: Option<String>,
+}
+
+/// Sprint
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct JiraSprintOutput {
+    /// Sprint 名称
+    pub name: String,
+    /// Sprint 状态（如 `active`、`closed`）
+    pub state: Option<String>,
+}
+
+impl JiraIssueOutput {
+    /// 从 Jira Issue 创建输出
+    ///
+    /// # 参数
+    ///
+    /// * `issue` - Jira Issue
+    /// * `sprint_field` - Sprint 自定义字段 ID（`jira.sprint_field` 配置）
+    /// * `service_address` - Jira 服务地址（用于生成 Issue 网页地址）
+    pub fn new(issue: &JiraIssue, sprint_field: &str, service_address: Option<&str>) -> Self {
+        let fields = &issue.fields;
+        Self {
+            key: issue.key.clone(),
+            id: issue.id.clone(),
+            summary: fields.summary.clone(),
+            description: fields.description.clone().filter(|d| !d.trim().is_empty()),
+            status: fields.status.name.clone(),
+            priority: fields.priority.as_ref().map(|p| p.name.clone()),
+            reporter: fields.reporter.as_ref().map(JiraUserOutput::from),
+            assignee: fields.assignee.as_ref().map(JiraUserOutput::from),
+            labels: fields.labels.clone().unwrap_or_default(),
+            components: fields.components.iter().flatten().map(|c| c.name.clone()).collect(),
+            fix_versions: fields.fix_versions.iter().flatten().map(|v| v.name.clone()).collect(),
+            sprint: issue.current_sprint(sprint_field).map(|sprint| JiraSprintOutput {
+                name: sprint.name,
+                state: sprint.state,
+            }),
+            created: fields.created.clone(),
+            updated: fields.updated.clone(),
+            attachments: fields.attachment.as_ref().map_or(0, Vec::len),
+            comments: fields.comment.as_ref().map_or(0, |c| c.comments.len()),
+            url: service_address
+                .filter(|address| !address.is_empty())
+                .map(|address| format!("{}/browse/{}", address, issue.key)),
+        }
+    }
+}
+
+impl From<&JiraUser> for JiraUserOutput {
+    fn from(user: &JiraUser) -> Self {
+        Self {
+            name: user.display_name.clone(),
+            email: user.email_address.clone(),
+        }
+    }
+}
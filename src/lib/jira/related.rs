@@ -0,0 +1,320 @@
+//! Jira 关联 ticket
+//!
+//! 从 Issue 的 `parent`、`subtasks` 和 `issuelinks` 字段整理出关联 ticket，
+//! 并渲染为树形结构，便于排查跨多个 ticket 的问题。
+//!
+//! Issue 链接按关系描述解析为 `IssueRelation`（blocks、is blocked by、relates to、duplicates 等），
+//! 无法识别的描述（如自定义链接类型）保留原文。
+
+use std::fmt;
+
+use serde::{Serialize, Serializer};
+
+use super::types::{JiraIssue, JiraIssueLink, JiraIssueRef, JiraSubtask};
+
+/// Epic 类型名称（父任务为该类型时显示为 Epic）
+const EPIC_ISSUE_TYPE: &str = "Epic";
+
+/// 未知状态的显示文本
+const UNKNOWN_STATUS: &str = "Unknown";
+
+/// 关联 ticket 的基本信息
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RelatedIssue {
+    /// Ticket key（如 `PROJ-123`）
+    pub key: String,
+    /// 状态名称
+    pub status: Option<String>,
+    /// 标题
+    pub summary: Option<String>,
+}
+
+impl RelatedIssue {
+    /// 格式化为单行显示（`KEY [Status] Summary`）
+    pub fn label(&self) -> String {
+        let status = self.status.as_deref().unwrap_or(UNKNOWN_STATUS);
+        match self.summary.as_deref().filter(|s| !s.is_empty()) {
+            Some(summary) => format!("{} [{}] {}", self.key, status, summary),
+            None => format!("{} [{}]", self.key, status),
+        }
+    }
+}
+
+impl From<&JiraIssueRef> for RelatedIssue {
+    fn from(issue: &JiraIssueRef) -> Self {
+        let fields = issue.fields.as_ref();
+        Self {
+            key: issue.key.clone(),
+            status: fields.and_then(|f| f.status.as_ref()).map(|s| s.name.clone()),
+            summary: fields.and_then(|f| f.summary.clone()),
+        }
+    }
+}
+
+impl From<&JiraSubtask> for RelatedIssue {
+    fn from(subtask: &JiraSubtask) -> Self {
+        let fields = subtask.fields.as_ref();
+        Self {
+            key: subtask.key.clone(),
+            status: fields.and_then(|f| f.status.as_ref()).map(|s| s.name.clone()),
+            summary: fields.and_then(|f| f.summary.clone()),
+        }
+    }
+}
+
+/// Issue 链接关系
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IssueRelation {
+    /// 阻塞另一个 ticket
+    Blocks,
+    /// 被另一个 ticket 阻塞
+    IsBlockedBy,
+    /// 相关
+    RelatesTo,
+    /// 与另一个 ticket 重复
+    Duplicates,
+    /// 被另一个 ticket 重复
+    IsDuplicatedBy,
+    /// 其他链接类型（保留关系描述原文）
+    Other(String),
+}
+
+impl IssueRelation {
+    /// 从链接关系描述解析（不区分大小写）
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::jira::IssueRelation;
+    ///
+    /// assert_eq!(IssueRelation::from_description("is blocked by"), IssueRelation::IsBlockedBy);
+    /// assert_eq!(IssueRelation::from_description("Relates to"), IssueRelation::RelatesTo);
+    /// assert_eq!(
+    ///     IssueRelation::from_description("clones"),
+    ///     IssueRelation::Other("clones".to_string())
+    /// );
+    /// ```
+    pub fn from_description(description: &str) -> Self {
+        match description.trim().to_lowercase().as_str() {
+            "blocks" => IssueRelation::Blocks,
+            "is blocked by" => IssueRelation::IsBlockedBy,
+            "relates to" => IssueRelation::RelatesTo,
+            "duplicates" => IssueRelation::Duplicates,
+            "is duplicated by" => IssueRelation::IsDuplicatedBy,
+            _ => IssueRelation::Other(description.trim().to_string()),
+        }
+    }
+
+    /// 关系描述（如 `blocks`、`is blocked by`）
+    pub fn as_str(&self) -> &str {
+        match self {
+            IssueRelation::Blocks => "blocks",
+            IssueRelation::IsBlockedBy => "is blocked by",
+            IssueRelation::RelatesTo => "relates to",
+            IssueRelation::Duplicates => "duplicates",
+            IssueRelation::IsDuplicatedBy => "is duplicated by",
+            IssueRelation::Other(description) => description,
+        }
+    }
+}
+
+impl fmt::Display for IssueRelation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for IssueRelation {
+    /// 序列化为关系描述（与 `as_str()` 相同）
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+/// 通过 Issue 链接关联的 ticket
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LinkedIssue {
+    /// 链接关系
+    pub relation: IssueRelation,
+    #[serde(flatten)]
+    pub issue: RelatedIssue,
+}
+
+impl LinkedIssue {
+    /// 从 Issue 链接解析关联 ticket
+    ///
+    /// 链接另一端为 outward 时使用 outward 描述，为 inward 时使用 inward 描述，
+    /// 描述缺失时使用链接类型名称。链接两端都缺失时返回 `None`。
+    pub fn from_link(link: &JiraIssueLink) -> Option<Self> {
+        let link_type = link.link_type.as_ref();
+        let (issue, description) = match (&link.outward_issue, &link.inward_issue) {
+            (Some(issue), _) => (issue, link_type.and_then(|t| t.outward.clone())),
+            (None, Some(issue)) => (issue, link_type.and_then(|t| t.inward.clone())),
+            (None, None) => return None,
+        };
+
+        let relation = description
+            .or_else(|| link_type.map(|t| t.name.clone()))
+            .map(|description| IssueRelation::from_description(&description))
+            .unwrap_or(IssueRelation::RelatesTo);
+        Some(Self {
+            relation,
+            issue: RelatedIssue::from(issue),
+        })
+    }
+}
+
+/// 树形结构中的一组关联 ticket
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelatedGroup {
+    /// 分组名称（如 `Epic`、`Subtasks`、`blocks`）
+    pub name: String,
+    /// 分组内的 ticket
+    pub issues: Vec<RelatedIssue>,
+}
+
+/// Ticket 的所有关联 ticket
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RelatedIssues {
+    /// 当前 ticket
+    #[serde(flatten)]
+    pub issue: RelatedIssue,
+    /// 所属 Epic（父任务类型为 Epic 时）
+    pub epic: Option<RelatedIssue>,
+    /// 父任务（父任务不是 Epic 时）
+    pub parent: Option<RelatedIssue>,
+    /// 子任务
+    pub subtasks: Vec<RelatedIssue>,
+    /// Issue 链接
+    pub links: Vec<LinkedIssue>,
+}
+
+impl RelatedIssues {
+    /// 从 Issue 中整理关联 ticket
+    pub fn from_issue(issue: &JiraIssue) -> Self {
+        let fields = &issue.fields;
+
+        let (epic, parent) = match fields.parent.as_ref() {
+            Some(parent) if is_epic(parent) => (Some(RelatedIssue::from(parent)), None),
+            Some(parent) => (None, Some(RelatedIssue::from(parent))),
+            None => (None, None),
+        };
+
+        Self {
+            issue: RelatedIssue {
+                key: issue.key.clone(),
+                status: Some(fields.status.name.clone()),
+                summary: Some(fields.summary.clone()),
+            },
+            epic,
+            parent,
+            subtasks: fields.subtasks.iter().flatten().map(RelatedIssue::from).collect(),
+            links: fields.issuelinks.iter().flatten().filter_map(LinkedIssue::from_link).collect(),
+        }
+    }
+
+    /// 是否没有任何关联 ticket
+    pub fn is_empty(&self) -> bool {
+        self.epic.is_none()
+            && self.parent.is_none()
+            && self.subtasks.is_empty()
+            && self.links.is_empty()
+    }
+
+    /// 按关联关系分组（Epic、Parent、Subtasks，然后按链接关系描述分组）
+    pub fn groups(&self) -> Vec<RelatedGroup> {
+        let mut groups = Vec::new();
+        if let Some(ref epic) = self.epic {
+            groups.push(RelatedGroup {
+                name: "Epic".to_string(),
+                issues: vec![epic.clone()],
+            });
+        }
+        if let Some(ref parent) = self.parent {
+            groups.push(RelatedGroup {
+                name: "Parent".to_string(),
+                issues: vec![parent.clone()],
+            });
+        }
+        if !self.subtasks.is_empty() {
+            groups.push(RelatedGroup {
+                name: "Subtasks".to_string(),
+                issues: self.subtasks.clone(),
+            });
+        }
+
+        // 链接按关系描述分组，保持首次出现的顺序
+        let mut link_groups: Vec<RelatedGroup> = Vec::new();
+        for link in &self.links {
+            match link_groups.iter_mut().find(|group| group.name == link.relation.as_str()) {
+                Some(group) => group.issues.push(link.issue.clone()),
+                None => link_groups.push(RelatedGroup {
+                    name: link.relation.to_string(),
+                    issues: vec![link.issue.clone()],
+                }),
+            }
+        }
+        groups.extend(link_groups);
+
+        groups
+    }
+
+    /// 所有关联 ticket（按树形顺序，按 key 去重）
+    pub fn related(&self) -> Vec<RelatedIssue> {
+        let mut related: Vec<RelatedIssue> = Vec::new();
+        for issue in self.groups().into_iter().flat_map(|group| group.issues) {
+            if !related.iter().any(|r| r.key == issue.key) {
+                related.push(issue);
+            }
+        }
+        related
+    }
+
+    /// 渲染为树形结构
+    ///
+    /// ```text
+    /// PROJ-123 [In Progress] Fix login crash
+    /// ├── Epic
+    /// │   └── PROJ-100 [Open] Auth revamp
+    /// └── blocks
+    ///     └── PROJ-200 [Open] Release 2.0
+    /// ```
+    pub fn tree(&self) -> String {
+        let mut lines = vec![self.issue.label()];
+        let groups = self.groups();
+
+        for (group_idx, group) in groups.iter().enumerate() {
+            let last_group = group_idx + 1 == groups.len();
+            let (branch, indent) = if last_group {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            lines.push(format!("{}{}", branch, group.name));
+
+            for (issue_idx, issue) in group.issues.iter().enumerate() {
+                let leaf = if issue_idx + 1 == group.issues.len() {
+                    "└── "
+                } else {
+                    "├── "
+                };
+                lines.push(format!("{}{}{}", indent, leaf, issue.label()));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// 检查父任务是否为 Epic
+fn is_epic(parent: &JiraIssueRef) -> bool {
+    parent
+        .fields
+        .as_ref()
+        .and_then(|f| f.issuetype.as_ref())
+        .map(|t| t.name.eq_ignore_ascii_case(EPIC_ISSUE_TYPE))
+        .unwrap_or(false)
+}
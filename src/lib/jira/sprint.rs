@@ -0,0 +1,147 @@
+//! Jira Sprint 字段解析
+//!
+//! Issue 的 Sprint 信息保存在自定义字段中（字段 ID 因实例而异，通过 `jira.sprint_field` 配置），
+//! 不同版本的 Jira 返回的格式不同：
+//! - Jira Cloud / 新版 Jira Server：对象数组，如 `[{"id": 1, "name": "Sprint 1", "state": "active"}]`
+//! - 旧版 Jira Server：Java 对象的字符串形式，如
+//!   `com.atlassian.greenhopper.service.sprint.Sprint@1a2b[id=1,rapidViewId=2,state=ACTIVE,name=Sprint 1,...]`
+//!
+//! 两种格式都解析为 `JiraSprint`，状态统一转换为小写（`active`、`closed`、`future`）。
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde_json::Value;
+
+use super::types::{JiraIssue, JiraSprint};
+
+/// 进行中的 Sprint 状态
+pub const ACTIVE_SPRINT_STATE: &str = "active";
+
+/// 字符串形式中表示空值的文本
+const NULL_VALUE: &str = "<null>";
+
+/// 字符串形式中属性名的正则表达式（`key=` 出现在开头或逗号之后）
+fn attribute_regex() -> &'static Regex {
+    static REGEX: OnceLock<Regex> = OnceLock::new();
+    REGEX.get_or_init(|| {
+        Regex::new(r"(?:^|,)([A-Za-z]+)=").expect("Failed to compile sprint attribute regex")
+    })
+}
+
+/// 解析 Sprint 字段的值
+///
+/// 支持对象数组、字符串数组，以及单个对象或字符串；无法解析的元素会被忽略。
+///
+/// # 参数
+///
+/// * `value` - Issue 中 Sprint 自定义字段的值
+///
+/// # 返回
+///
+/// 返回 Sprint 列表（字段为空时返回空列表）。
+pub fn parse_sprint_field(value: &Value) -> Vec<JiraSprint> {
+    match value {
+        Value::Array(items) => items.iter().filter_map(parse_sprint_value).collect(),
+        Value::Null => Vec::new(),
+        other => parse_sprint_value(other).into_iter().collect(),
+    }
+}
+
+/// 解析单个 Sprint（对象或字符串形式）
+fn parse_sprint_value(value: &Value) -> Option<JiraSprint> {
+    match value {
+        Value::String(text) => parse_sprint_string(text),
+        Value::Object(_) => {
+            let mut sprint: JiraSprint = serde_json::from_value(value.clone()).ok()?;
+            sprint.state = sprint.state.map(|state| state.to_lowercase());
+            Some(sprint)
+        }
+        _ => None,
+    }
+}
+
+/// 解析 Sprint 的字符串形式
+///
+/// 旧版 Jira Server 返回的格式为 `...Sprint@hash[key=value,key=value,...]`，
+/// 值中可能包含逗号（如 Sprint 名称或目标），因此按 `,key=` 切分属性；`<null>` 和空值视为未设置。
+///
+/// # 参数
+///
+/// * `text` - Sprint 的字符串形式
+///
+/// # 返回
+///
+/// 解析成功返回 `JiraSprint`，缺少 `id` 或 `name` 时返回 `None`。
+///
+/// # 示例
+///
+/// ```
+/// use workflow::jira::sprint::parse_sprint_string;
+///
+/// let sprint = parse_sprint_string(
+///     "com.atlassian.greenhopper.service.sprint.Sprint@1a2b[id=12,rapidViewId=3,state=ACTIVE,name=Sprint 7,goal=<null>]",
+/// )
+/// .unwrap();
+/// assert_eq!(sprint.id, 12);
+/// assert_eq!(sprint.name, "Sprint 7");
+/// assert_eq!(sprint.state.as_deref(), Some("active"));
+/// assert_eq!(sprint.goal, None);
+/// ```
+pub fn parse_sprint_string(text: &str) -> Option<JiraSprint> {
+    let start = text.find('[')?;
+    let end = text.rfind(']').filter(|end| *end > start)?;
+    let body = &text[start + 1..end];
+
+    let keys: Vec<_> = attribute_regex().captures_iter(body).collect();
+    let attribute = |name: &str| -> Option<String> {
+        keys.iter().enumerate().find_map(|(idx, caps)| {
+            if &caps[1] != name {
+                return None;
+            }
+            let value_start = caps.get(0)?.end();
+            let value_end = keys
+                .get(idx + 1)
+                .and_then(|next| next.get(0))
+                .map_or(body.len(), |next| next.start());
+            let value = body[value_start..value_end].trim();
+            (!value.is_empty() && value != NULL_VALUE).then(|| value.to_string())
+        })
+    };
+
+    Some(JiraSprint {
+        id: attribute("id")?.parse().ok()?,
+        name: attribute("name")?,
+        state: attribute("state").map(|state| state.to_lowercase()),
+        start_date: attribute("startDate"),
+        end_date: attribute("endDate"),
+        complete_date: attribute("completeDate"),
+        goal: attribute("goal"),
+    })
+}
+
+impl JiraIssue {
+    /// 获取 Issue 所在的 Sprint 列表
+    ///
+    /// # 参数
+    ///
+    /// * `field` - Sprint 自定义字段的 ID（见 `JiraSettings::sprint_field()`）
+    ///
+    /// # 返回
+    ///
+    /// 返回 Sprint 列表（Issue 不在任何 Sprint 中或字段不存在时返回空列表）。
+    pub fn sprints(&self, field: &str) -> Vec<JiraSprint> {
+        self.fields.extra_fields.get(field).map(parse_sprint_field).unwrap_or_default()
+    }
+
+    /// 获取 Issue 当前所在的 Sprint
+    ///
+    /// 优先返回进行中的 Sprint，没有时返回最后一个 Sprint（Issue 跨多个 Sprint 时 Jira 按时间顺序返回）。
+    pub fn current_sprint(&self, field: &str) -> Option<JiraSprint> {
+        let mut sprints = self.sprints(field);
+        match sprints.iter().position(|s| s.state.as_deref() == Some(ACTIVE_SPRINT_STATE)) {
+            Some(idx) => Some(sprints.swap_remove(idx)),
+            None => sprints.pop(),
+        }
+    }
+}
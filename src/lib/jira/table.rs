@@ -31,3 +31,61 @@ pub struct FileRow {
     #[tabled(rename = "Size")]
     pub size: String,
 }
+
+/// JIRA 变更时间线表格行
+///
+/// 用于在表格中显示字段变更事件（时间、操作人、字段、变更前后的值）。
+#[derive(Tabled)]
+pub struct ChangelogRow {
+    #[tabled(rename = "Time")]
+    pub time: String,
+    #[tabled(rename = "Actor")]
+    pub actor: String,
+    #[tabled(rename = "Field")]
+    pub field: String,
+    #[tabled(rename = "Change")]
+    pub change: String,
+}
+
+/// JIRA 清理预览表格行
+///
+/// 用于在表格中显示每个 ticket 目录将要删除的文件数和大小。
+#[derive(Tabled)]
+pub struct CleanSummaryRow {
+    #[tabled(rename = "JIRA ID")]
+    pub jira_id: String,
+    #[tabled(rename = "Files")]
+    pub files: String,
+    #[tabled(rename = "Size")]
+    pub size: String,
+}
+
+/// JIRA 关联 ticket 表格行
+///
+/// 用于在表格中显示关联 ticket（关联关系、key、状态和标题）。
+#[derive(Tabled)]
+pub struct RelatedIssueRow {
+    #[tabled(rename = "Relation")]
+    pub relation: String,
+    #[tabled(rename = "Key")]
+    pub key: String,
+    #[tabled(rename = "Status")]
+    pub status: String,
+    #[tabled(rename = "Summary")]
+    pub summary: String,
+}
+
+/// JIRA Sprint ticket 表格行
+///
+/// 用于在表格中显示 Sprint 中的 ticket（key、状态、经办人和标题）。
+#[derive(Tabled)]
+pub struct SprintIssueRow {
+    #[tabled(rename = "Key")]
+    pub key: String,
+    #[tabled(rename = "Status")]
+    pub status: String,
+    #[tabled(rename = "Assignee")]
+    pub assignee: String,
+    #[tabled(rename = "Summary")]
+    pub summary: String,
+}
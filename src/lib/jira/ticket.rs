@@ -12,9 +12,10 @@ use color_eyre::{
     Result,
 };
 use regex::Regex;
+use std::path::Path;
 
 use super::api::issue::JiraIssueApi;
-use super::types::{JiraAttachment, JiraIssue, JiraTransition};
+use super::types::{JiraAttachment, JiraComment, JiraComments, JiraIssue, JiraTransition};
 
 /// Jira Ticket/Issue 操作
 ///
@@ -194,11 +195,23 @@ impl JiraTicket {
     ///
     /// * `ticket` - Jira ticket ID，格式如 `PROJ-123`
     /// * `comment` - 评论内容
-    pub fn add_comment(ticket: &str, comment: &str) -> Result<()> {
-        JiraIssueApi::add_issue_comment(ticket, comment)
+    pub fn add_comment(ticket: &str, comment: &str) -> Result<JiraComment> {
+        JiraIssueApi::add_comment(ticket, comment)
             .wrap_err(format!("Failed to add comment to ticket {}", ticket))
     }
 
+    /// 获取 ticket 的所有评论
+    ///
+    /// 评论按创建时间升序排列（最新的评论在最后）。
+    ///
+    /// # 参数
+    ///
+    /// * `ticket` - Jira ticket ID，格式如 `PROJ-123`
+    pub fn get_comments(ticket: &str) -> Result<JiraComments> {
+        JiraIssueApi::get_comments(ticket)
+            .wrap_err(format!("Failed to get comments for ticket {}", ticket))
+    }
+
     /// 上传附件到 ticket
     ///
     /// 将文件作为附件上传到指定的 ticket。
@@ -212,7 +225,7 @@ impl JiraTicket {
     ///
     /// 成功时返回上传的附件信息列表。
     pub fn upload_attachment(ticket: &str, file_path: &str) -> Result<Vec<JiraAttachment>> {
-        JiraIssueApi::upload_attachment(ticket, file_path)
+        JiraIssueApi::add_attachment(ticket, Path::new(file_path))
             .wrap_err(format!("Failed to upload attachment to ticket {}", ticket))
     }
 }
@@ -7,7 +7,9 @@
 //! 可以直接与 Jira API 的 JSON 格式进行序列化/反序列化。
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use serde_with::skip_serializing_none;
+use std::collections::HashMap;
 
 /// Jira Issue 完整信息
 ///
@@ -42,7 +44,11 @@ pub struct JiraIssueFields {
     pub fix_versions: Option<Vec<JiraVersion>>,
     pub issuelinks: Option<Vec<JiraIssueLink>>,
     pub subtasks: Option<Vec<JiraSubtask>>,
+    pub parent: Option<JiraIssueRef>,
     pub time_tracking: Option<JiraTimeTracking>,
+    /// 其他字段（包括 `customfield_*` 自定义字段，如 Sprint），字段 ID 因实例而异，不参与序列化
+    #[serde(flatten, skip_serializing)]
+    pub extra_fields: HashMap<String, Value>,
 }
 
 /// Jira 附件信息
@@ -163,6 +169,7 @@ pub struct JiraVersion {
 #[serde(rename_all = "camelCase")]
 pub struct JiraIssueLink {
     pub id: String,
+    #[serde(rename = "type")]
     pub link_type: Option<JiraIssueLinkType>,
     pub inward_issue: Option<JiraIssueRef>,
     pub outward_issue: Option<JiraIssueRef>,
@@ -197,6 +204,19 @@ pub struct JiraIssueRef {
 pub struct JiraIssueRefFields {
     pub summary: Option<String>,
     pub status: Option<JiraStatus>,
+    pub issuetype: Option<JiraIssueType>,
+}
+
+/// Jira Issue 类型
+///
+/// 包含类型名称（如 Epic、Story、Bug）以及是否为子任务类型。
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraIssueType {
+    pub id: Option<String>,
+    pub name: String,
+    pub subtask: Option<bool>,
 }
 
 /// Jira 子任务信息
@@ -268,3 +288,32 @@ pub struct JiraChangelogItem {
     pub to: Option<String>,
     pub to_string: Option<String>,
 }
+
+/// Jira Agile 看板信息
+///
+/// 包含看板的 ID、名称和类型（`scrum` 或 `kanban`）。
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraBoard {
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub board_type: Option<String>,
+}
+
+/// Jira Agile Sprint 信息
+///
+/// 包含 Sprint 的 ID、名称、状态（`active`、`closed`、`future`）、起止时间和目标。
+#[skip_serializing_none]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JiraSprint {
+    pub id: u64,
+    pub name: String,
+    pub state: Option<String>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub complete_date: Option<String>,
+    pub goal: Option<String>,
+}
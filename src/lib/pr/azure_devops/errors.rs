@@ -0,0 +1,53 @@
+use crate::base::http::HttpResponse;
+use color_eyre::eyre::{eyre, Report};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Azure DevOps 错误响应结构
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AzureDevOpsErrorResponse {
+    pub message: String,
+    pub type_key: Option<String>,
+}
+
+/// 处理 Azure DevOps API 错误
+///
+/// 尝试解析 Azure DevOps 错误格式，如果无法解析则返回通用错误信息。
+/// PAT 无效时 Azure DevOps 会返回 203（登录页面），需要单独处理。
+pub fn handle_azure_devops_error(response: &HttpResponse) -> Report {
+    if response.status == 203 || response.status == 401 {
+        return eyre!(
+            "Azure DevOps authentication failed (Status: {}). Please check that the personal access token is valid and has Code (Read & Write) scope",
+            response.status
+        );
+    }
+
+    if let Ok(data) = response.as_json::<Value>() {
+        if let Ok(error) = serde_json::from_value::<AzureDevOpsErrorResponse>(data.clone()) {
+            let mut msg = format!(
+                "Azure DevOps API error: {} (Status: {})",
+                error.message, response.status
+            );
+            if let Some(type_key) = error.type_key {
+                msg.push_str(&format!("\n  - {}", type_key));
+            }
+            return eyre!(msg);
+        }
+
+        if let Ok(json_str) = serde_json::to_string_pretty(&data) {
+            return eyre!(
+                "Azure DevOps API request failed: {} - {}\n\nResponse:\n{}",
+                response.status,
+                response.status_text,
+                json_str
+            );
+        }
+    }
+
+    eyre!(
+        "Azure DevOps API request failed: {} - {}",
+        response.status,
+        response.status_text
+    )
+}
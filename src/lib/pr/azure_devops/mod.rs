@@ -0,0 +1,9 @@
+pub mod errors;
+pub mod platform;
+pub mod repository;
+pub mod requests;
+pub mod responses;
+
+pub use errors::{handle_azure_devops_error, AzureDevOpsErrorResponse};
+pub use platform::{map_pull_request_status, with_api_version, AzureDevOps, API_VERSION};
+pub use repository::AzureDevOpsRepo;
@@ -0,0 +1,785 @@
+use std::fmt::Write;
+use std::sync::OnceLock;
+
+use color_eyre::{
+    eyre::{eyre, ContextCompat, WrapErr},
+    Result,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::base::constants::errors::validation_errors;
+use crate::base::format::DisplayFormatter;
+use crate::base::http::{Authorization, HttpClient, HttpMethod, HttpResponse, RequestConfig};
+use crate::base::settings::Settings;
+use crate::git::{GitBranch, GitRepo};
+use crate::jira::history::JiraWorkHistory;
+use crate::pr::azure_devops::errors::handle_azure_devops_error;
+use crate::pr::body_parser::parse_change_types_from_body;
+use crate::pr::list_filter::{PrListFilter, PrListSort};
+use crate::pr::platform::{
+    map_change_types_to_labels, BranchDeletion, PlatformProvider, PullRequestDetails,
+    PullRequestStatus,
+};
+use crate::pr::readiness::{MergeMethod, MergeReadiness, ReviewStatus};
+use crate::pr::review::ReviewEvent;
+use crate::pr::PullRequestRow;
+
+use super::repository::AzureDevOpsRepo;
+use super::requests::{
+    CommentRequest, CommitRefRequest, CompletionOptions, CreatePullRequestRequest,
+    CreateThreadRequest, GitRefUpdate, ReviewerVoteRequest, UpdatePullRequestRequest,
+    WebApiTagDefinition,
+};
+use super::responses::{
+    ConnectionData, GitRefList, GitRefUpdateResultList, PolicyConfigurationList, PullRequestInfo,
+    PullRequestList,
+};
+
+/// Azure DevOps REST API 版本（所有请求都必须携带 `api-version` 参数）
+pub const API_VERSION: &str = "7.1";
+
+/// `connectionData` 接口只有预览版本
+const CONNECTION_DATA_API_VERSION: &str = "7.1-preview";
+
+/// PR 描述的最大长度（Azure DevOps 限制）
+const MAX_DESCRIPTION_LENGTH: usize = 4000;
+
+/// 分支引用前缀
+const REFS_HEADS: &str = "refs/heads/";
+
+/// 删除引用时使用的目标对象 ID
+const ZERO_OBJECT_ID: &str = "0000000000000000000000000000000000000000";
+
+/// 批准的投票值
+const VOTE_APPROVED: i8 = 10;
+
+/// 等待作者修改的投票值（请求修改）
+const VOTE_WAITING_FOR_AUTHOR: i8 = -5;
+
+/// 为查询参数添加 `api-version`
+///
+/// # 示例
+/// ```
+/// use workflow::pr::azure_devops::{with_api_version, API_VERSION};
+/// let query = with_api_version(&[("$top", "10")]);
+/// assert_eq!(query, vec![("api-version", API_VERSION), ("$top", "10")]);
+/// ```
+pub fn with_api_version<'a>(params: &[(&'a str, &'a str)]) -> Vec<(&'a str, &'a str)> {
+    let mut query = vec![("api-version", API_VERSION)];
+    query.extend(params.iter().filter(|(key, _)| *key != "api-version").copied());
+    query
+}
+
+/// 将 Azure DevOps 的 PR 状态映射为 `PullRequestStatus`
+///
+/// - `completed` → `merged`（`closed_date` 作为合并时间）
+/// - `abandoned` → `closed`
+/// - 其他（`active` 等）→ `open`
+pub fn map_pull_request_status(status: &str, closed_date: Option<&str>) -> PullRequestStatus {
+    match status {
+        "completed" => PullRequestStatus {
+            state: "merged".to_string(),
+            merged: true,
+            merged_at: closed_date.map(|d| d.to_string()),
+        },
+        "abandoned" => PullRequestStatus {
+            state: "closed".to_string(),
+            merged: false,
+            merged_at: None,
+        },
+        _ => PullRequestStatus {
+            state: "open".to_string(),
+            merged: false,
+            merged_at: None,
+        },
+    }
+}
+
+/// Azure DevOps 平台实现
+///
+/// 实现 `PlatformProvider` trait，使用 Personal Access Token 调用 Azure DevOps Git REST API
+pub struct AzureDevOps;
+
+impl PlatformProvider for AzureDevOps {
+    /// 创建 Pull Request
+    ///
+    /// PR body 中勾选的变更类型会作为 PR 标签添加。
+    fn create_pull_request(
+        &self,
+        title: &str,
+        body: &str,
+        source_branch: &str,
+        target_branch: Option<&str>,
+    ) -> Result<String> {
+        let repo = Self::get_repo()?;
+
+        let target_branch = if let Some(branch) = target_branch {
+            branch.to_string()
+        } else {
+            GitBranch::get_default_branch()?
+        };
+
+        let labels = parse_change_types_from_body(body)
+            .map(|selected| map_change_types_to_labels(&selected))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|label| WebApiTagDefinition {
+                name: label.to_string(),
+            })
+            .collect();
+
+        let request = CreatePullRequestRequest {
+            source_ref_name: Self::to_ref_name(source_branch),
+            target_ref_name: Self::to_ref_name(&target_branch),
+            title: title.to_string(),
+            description: Self::truncate_description(body),
+            labels,
+        };
+
+        let response = Self::send(
+            HttpMethod::Post,
+            &repo.api_url("/pullrequests"),
+            &[],
+            Some(&request),
+        )?;
+        let pr: PullRequestInfo = response.as_json()?;
+
+        Ok(repo.pull_request_web_url(pr.pull_request_id))
+    }
+
+    /// 合并 Pull Request
+    ///
+    /// Azure DevOps 通过将 PR 状态更新为 `completed` 完成合并，
+    /// 需要提供最新的源分支提交以避免合并过期的内容。
+    /// 合并方式对应 Azure DevOps 的 `squash`、`rebase` 和 `noFastForward`（默认 `squash`）。
+    fn merge_pull_request(
+        &self,
+        pull_request_id: &str,
+        delete_branch: bool,
+        merge_method: Option<MergeMethod>,
+    ) -> Result<()> {
+        let pr = Self::fetch_pr_info_internal(pull_request_id)?;
+        let last_commit = pr
+            .last_merge_source_commit
+            .wrap_err("Pull request has no source commit to merge")?;
+
+        let request = UpdatePullRequestRequest {
+            status: Some("completed".to_string()),
+            last_merge_source_commit: Some(CommitRefRequest {
+                commit_id: last_commit.commit_id,
+            }),
+            completion_options: Some(CompletionOptions {
+                delete_source_branch: delete_branch,
+                merge_strategy: match merge_method.unwrap_or(MergeMethod::Squash) {
+                    MergeMethod::Squash => "squash",
+                    MergeMethod::Rebase => "rebase",
+                    MergeMethod::Merge => "noFastForward",
+                }
+                .to_string(),
+            }),
+            ..Default::default()
+        };
+
+        Self::update_pull_request_internal(pr.pull_request_id, &request)
+    }
+
+    /// 获取 PR 信息
+    fn get_pull_request_info(&self, pull_request_id: &str) -> Result<String> {
+        let repo = Self::get_repo()?;
+        let pr = Self::fetch_pr_info_internal(pull_request_id)?;
+        let status = map_pull_request_status(&pr.status, pr.closed_date.as_deref());
+
+        let mut info = String::new();
+        writeln!(info, "Title: {}", pr.title)?;
+        if let Some(description) = pr.description.filter(|body| !body.trim().is_empty()) {
+            // 描述为 Markdown，渲染后缩进显示
+            writeln!(info, "Description:")?;
+            for line in DisplayFormatter::render_markdown(&description).lines() {
+                writeln!(info, "  {}", line)?;
+            }
+        }
+        writeln!(info, "State: {}", status.state)?;
+        writeln!(
+            info,
+            "Source Branch: {}",
+            Self::from_ref_name(&pr.source_ref_name)
+        )?;
+        writeln!(
+            info,
+            "Target Branch: {}",
+            Self::from_ref_name(&pr.target_ref_name)
+        )?;
+        writeln!(
+            info,
+            "URL: {}",
+            repo.pull_request_web_url(pr.pull_request_id)
+        )?;
+
+        Ok(info)
+    }
+
+    /// 获取 PR URL
+    fn get_pull_request_url(&self, pull_request_id: &str) -> Result<String> {
+        let repo = Self::get_repo()?;
+        let pr = Self::fetch_pr_info_internal(pull_request_id)?;
+        Ok(repo.pull_request_web_url(pr.pull_request_id))
+    }
+
+    /// 获取 PR 标题
+    fn get_pull_request_title(&self, pull_request_id: &str) -> Result<String> {
+        let pr = Self::fetch_pr_info_internal(pull_request_id)?;
+        Ok(pr.title)
+    }
+
+    /// 获取 PR body 内容
+    fn get_pull_request_body(&self, pull_request_id: &str) -> Result<Option<String>> {
+        let pr = Self::fetch_pr_info_internal(pull_request_id)?;
+        Ok(pr.description)
+    }
+
+    /// 获取当前分支的 PR
+    fn get_current_branch_pull_request(&self) -> Result<Option<String>> {
+        let current_branch = GitBranch::current_branch()?;
+        let source_ref = Self::to_ref_name(&current_branch);
+
+        // 首先查找 active 状态的 PR，找不到时查找所有状态（包括已合并、已放弃）
+        for status in ["active", "all"] {
+            let prs = Self::search_pull_requests(&[
+                ("searchCriteria.sourceRefName", source_ref.as_str()),
+                ("searchCriteria.status", status),
+            ])?;
+            if let Some(pr) = prs.first() {
+                crate::trace_debug!(
+                    "Found PR #{} for branch '{}' (status: {})",
+                    pr.pull_request_id,
+                    current_branch,
+                    pr.status
+                );
+                return Ok(Some(pr.pull_request_id.to_string()));
+            }
+        }
+
+        // 如果 API 查询没有找到，尝试从 work-history 文件中查找
+        let remote_url = GitRepo::get_remote_url().ok();
+        if let Some(pr_id) =
+            JiraWorkHistory::find_pr_id_by_branch(&current_branch, remote_url.as_deref())?
+        {
+            crate::trace_debug!(
+                "Found PR #{} for branch '{}' from work-history",
+                pr_id,
+                current_branch
+            );
+            return Ok(Some(pr_id));
+        }
+
+        Ok(None)
+    }
+
+    /// 列出 PR
+    fn get_pull_requests(&self, filter: &PrListFilter) -> Result<Vec<PullRequestRow>> {
+        if filter.sort == PrListSort::Updated {
+            return Err(eyre!(
+                "Azure DevOps does not support sorting pull requests by update time, use --sort created"
+            ));
+        }
+        let repo = Self::get_repo()?;
+
+        // 转换 state 参数：Azure DevOps 支持 active、abandoned、completed、all
+        let status = match filter.state.as_deref() {
+            Some("open") => "active",
+            Some("closed") => "abandoned",
+            Some("merged") => "completed",
+            _ => "all",
+        };
+        let mut criteria = vec![("searchCriteria.status", status.to_string())];
+        if let Some(base) = &filter.base {
+            criteria.push(("searchCriteria.targetRefName", Self::to_ref_name(base)));
+        }
+        if filter.mine {
+            let user_id = Self::get_current_user_id(&repo).wrap_err(
+                "Cannot resolve the current Azure DevOps user for --mine; check the personal access token or use --author instead",
+            )?;
+            criteria.push(("searchCriteria.creatorId", user_id));
+        }
+
+        // 作者和标签在本地筛选：逐页请求，直到满足数量限制
+        let limit = filter.limit();
+        let local_filter = filter.needs_local_filter();
+        let page_size = if local_filter { 100 } else { limit.max(1) };
+        let max_pages = if local_filter {
+            PrListFilter::MAX_PAGES
+        } else {
+            1
+        };
+
+        let mut prs = Vec::new();
+        for page in 0..max_pages {
+            let top = page_size.to_string();
+            let skip = (page * page_size).to_string();
+            let mut params: Vec<(&str, &str)> =
+                criteria.iter().map(|(key, value)| (*key, value.as_str())).collect();
+            params.push(("$top", top.as_str()));
+            params.push(("$skip", skip.as_str()));
+
+            let batch = Self::search_pull_requests(&params)?;
+            let last_page = batch.len() < page_size;
+            prs.extend(batch.into_iter().filter(|pr| {
+                let authors: Vec<&str> = pr
+                    .created_by
+                    .iter()
+                    .flat_map(|u| [u.unique_name.as_deref(), u.display_name.as_deref()])
+                    .flatten()
+                    .collect();
+                let labels: Vec<&str> = pr.labels.iter().map(|l| l.name.as_str()).collect();
+                filter.matches(&authors, &labels)
+            }));
+            if prs.len() >= limit || last_page {
+                break;
+            }
+        }
+        prs.truncate(limit);
+
+        let rows = prs
+            .into_iter()
+            .map(|pr| PullRequestRow {
+                number: pr.pull_request_id.to_string(),
+                state: map_pull_request_status(&pr.status, pr.closed_date.as_deref()).state,
+                branch: Self::from_ref_name(&pr.source_ref_name).to_string(),
+                title: pr.title,
+                author: pr
+                    .created_by
+                    .as_ref()
+                    .and_then(|u| u.unique_name.clone().or_else(|| u.display_name.clone()))
+                    .unwrap_or_else(|| "N/A".to_string()),
+                url: repo.pull_request_web_url(pr.pull_request_id),
+            })
+            .collect();
+        Ok(rows)
+    }
+
+    /// 获取 PR 状态
+    fn get_pull_request_status(&self, pull_request_id: &str) -> Result<PullRequestStatus> {
+        let pr = Self::fetch_pr_info_internal(pull_request_id)?;
+        Ok(map_pull_request_status(
+            &pr.status,
+            pr.closed_date.as_deref(),
+        ))
+    }
+
+    /// 获取 PR 详细信息
+    fn get_pull_request_details(&self, pull_request_id: &str) -> Result<PullRequestDetails> {
+        let repo = Self::get_repo()?;
+        let pr = Self::fetch_pr_info_internal(pull_request_id)?;
+        let status = map_pull_request_status(&pr.status, pr.closed_date.as_deref());
+
+        Ok(PullRequestDetails {
+            id: pr.pull_request_id.to_string(),
+            title: pr.title,
+            description: pr.description.filter(|body| !body.trim().is_empty()),
+            state: status.state,
+            merged: status.merged,
+            merged_at: status.merged_at,
+            source_branch: Self::from_ref_name(&pr.source_ref_name).to_string(),
+            target_branch: Self::from_ref_name(&pr.target_ref_name).to_string(),
+            author: pr.created_by.and_then(|u| u.unique_name.or(u.display_name)),
+            url: repo.pull_request_web_url(pr.pull_request_id),
+        })
+    }
+
+    /// 获取 PR 的合并前检查结果
+    ///
+    /// 使用 PR 的合并检查状态和评审人投票；分支策略（构建验证等）的评估结果不在 PR 信息中，
+    /// 被策略阻止时合并检查状态为 `rejectedByPolicy`。`pr merge` 在 Azure DevOps 中总是使用 squash。
+    fn get_merge_readiness(&self, pull_request_id: &str) -> Result<MergeReadiness> {
+        let pr = Self::fetch_pr_info_internal(pull_request_id)?;
+        let status = map_pull_request_status(&pr.status, pr.closed_date.as_deref());
+
+        let mergeable = match pr.merge_status.as_deref() {
+            Some("succeeded") => Some(true),
+            Some("conflicts") | Some("failure") | Some("rejectedByPolicy") => Some(false),
+            _ => None,
+        };
+        // 最少评审人数由分支策略配置，不在 PR 信息中，只检查必需的评审人是否已批准
+        let reviews = ReviewStatus {
+            approvals: pr.reviewers.iter().filter(|r| r.vote >= 5).count() as u32,
+            required_approvals: None,
+            changes_requested: pr.reviewers.iter().any(|r| r.vote < 0),
+            pending_reviewers: pr
+                .reviewers
+                .iter()
+                .filter(|r| r.is_required && r.vote < 5)
+                .map(|r| r.display_name.clone().unwrap_or_else(|| r.id.clone()))
+                .collect(),
+        };
+
+        Ok(MergeReadiness {
+            state: status.state,
+            merged: status.merged,
+            mergeable,
+            mergeable_state: pr.merge_status,
+            checks: Vec::new(),
+            reviews,
+            // 合并方式由分支策略限制，这里不查询分支策略，视为全部允许
+            merge_methods: MergeMethod::ALL.iter().map(|method| (*method, true)).collect(),
+        })
+    }
+
+    /// 删除 PR 的远程源分支
+    ///
+    /// PR 必须已完成合并。以下情况不删除：源分支在 fork 中、是仓库的默认分支、被锁定或配置了阻止性的分支策略。
+    fn delete_source_branch(&self, pull_request_id: &str) -> Result<BranchDeletion> {
+        let repo = Self::get_repo()?;
+        let pr = Self::fetch_pr_info_internal(pull_request_id)?;
+        let branch = Self::from_ref_name(&pr.source_ref_name).to_string();
+
+        // 合并在后台完成，合并完成前删除源分支会导致合并失败
+        if pr.status != "completed" {
+            return Err(eyre!(
+                "PR #{} is {}, the source branch can only be deleted after the merge completes",
+                pr.pull_request_id,
+                pr.status
+            ));
+        }
+        if let Some(fork) = &pr.fork_source {
+            return Ok(BranchDeletion::Skipped {
+                branch,
+                reason: format!("branch belongs to a fork ({})", fork.name),
+            });
+        }
+        let repository = pr.repository.as_ref();
+        if repository.and_then(|r| r.default_branch.as_deref()) == Some(pr.source_ref_name.as_str())
+        {
+            return Ok(BranchDeletion::Skipped {
+                branch,
+                reason: "branch is the default branch".to_string(),
+            });
+        }
+
+        let filter = format!("heads/{}", branch);
+        let response = Self::send::<Value>(
+            HttpMethod::Get,
+            &repo.api_url("/refs"),
+            &[("filter", filter.as_str())],
+            None,
+        )?;
+        let refs: GitRefList = response.as_json()?;
+        // filter 按前缀匹配，需要找到名称完全相同的分支
+        let Some(git_ref) = refs.value.into_iter().find(|r| r.name == pr.source_ref_name) else {
+            return Ok(BranchDeletion::NotFound(branch));
+        };
+        if git_ref.is_locked {
+            return Ok(BranchDeletion::Skipped {
+                branch,
+                reason: "branch is locked".to_string(),
+            });
+        }
+        if let Some(repository) = repository {
+            if Self::has_blocking_policies(&repo, &repository.id, &pr.source_ref_name)? {
+                return Ok(BranchDeletion::Skipped {
+                    branch,
+                    reason: "branch is protected by branch policies".to_string(),
+                });
+            }
+        }
+
+        let updates = [GitRefUpdate {
+            name: pr.source_ref_name.clone(),
+            old_object_id: git_ref.object_id,
+            new_object_id: ZERO_OBJECT_ID.to_string(),
+        }];
+        let response = Self::send(
+            HttpMethod::Post,
+            &repo.api_url("/refs"),
+            &[],
+            Some(&updates),
+        )?;
+        let results: GitRefUpdateResultList = response.as_json()?;
+        match results.value.first() {
+            Some(result) if result.success => Ok(BranchDeletion::Deleted(branch)),
+            Some(result) => Err(eyre!(
+                "Failed to delete branch '{}': {}",
+                branch,
+                result.update_status
+            )),
+            None => Err(eyre!(
+                "Failed to delete branch '{}': empty response",
+                branch
+            )),
+        }
+    }
+
+    /// 关闭 Pull Request（Azure DevOps 中为放弃 PR）
+    fn close_pull_request(&self, pull_request_id: &str) -> Result<()> {
+        let request = UpdatePullRequestRequest {
+            status: Some("abandoned".to_string()),
+            ..Default::default()
+        };
+        Self::update_pull_request_internal(Self::parse_id(pull_request_id)?, &request)
+    }
+
+    /// 添加评论到 Pull Request
+    ///
+    /// Azure DevOps 的评论属于评论线程，这里为每条评论创建一个新线程。
+    fn add_comment(&self, pull_request_id: &str, comment: &str) -> Result<()> {
+        let repo = Self::get_repo()?;
+        let pr_id = Self::parse_id(pull_request_id)?;
+
+        let request = CreateThreadRequest {
+            comments: vec![CommentRequest {
+                parent_comment_id: 0,
+                content: comment.to_string(),
+                comment_type: 1, // text
+            }],
+            status: 1, // active
+        };
+
+        Self::send(
+            HttpMethod::Post,
+            &repo.api_url(&format!("/pullrequests/{}/threads", pr_id)),
+            &[],
+            Some(&request),
+        )?;
+        Ok(())
+    }
+
+    /// 提交评审（批准、请求修改或评论）
+    ///
+    /// 以当前 PAT 对应的用户作为审核人投票：批准为 `approved`，请求修改为 `waiting for author`，
+    /// 评论不投票。评审内容作为新的评论线程添加。
+    fn submit_review(
+        &self,
+        pull_request_id: &str,
+        event: ReviewEvent,
+        body: Option<&str>,
+    ) -> Result<()> {
+        let body = event.validate_body(body)?;
+        let vote = match event {
+            ReviewEvent::Approve => Some(VOTE_APPROVED),
+            ReviewEvent::RequestChanges => Some(VOTE_WAITING_FOR_AUTHOR),
+            ReviewEvent::Comment => None,
+        };
+
+        if let Some(vote) = vote {
+            let repo = Self::get_repo()?;
+            let pr_id = Self::parse_id(pull_request_id)?;
+            let user_id = Self::get_current_user_id(&repo)?;
+
+            let request = ReviewerVoteRequest { vote };
+
+            Self::send(
+                HttpMethod::Put,
+                &repo.api_url(&format!("/pullrequests/{}/reviewers/{}", pr_id, user_id)),
+                &[],
+                Some(&request),
+            )?;
+        }
+
+        if let Some(body) = body {
+            self.add_comment(pull_request_id, body)?;
+        }
+        Ok(())
+    }
+
+    /// 更新 PR 的 base 分支
+    fn update_pr_base(&self, pull_request_id: &str, new_base: &str) -> Result<()> {
+        let request = UpdatePullRequestRequest {
+            target_ref_name: Some(Self::to_ref_name(new_base)),
+            ..Default::default()
+        };
+        Self::update_pull_request_internal(Self::parse_id(pull_request_id)?, &request)
+    }
+
+    /// 更新 Pull Request 的标题和/或描述
+    fn update_pull_request(
+        &self,
+        pull_request_id: &str,
+        title: Option<&str>,
+        body: Option<&str>,
+    ) -> Result<()> {
+        let request = UpdatePullRequestRequest {
+            title: title.map(|s| s.to_string()),
+            description: body.map(Self::truncate_description),
+            ..Default::default()
+        };
+        Self::update_pull_request_internal(Self::parse_id(pull_request_id)?, &request)
+    }
+}
+
+impl AzureDevOps {
+    /// 获取缓存的仓库信息
+    pub fn get_repo() -> Result<AzureDevOpsRepo> {
+        static REPO: OnceLock<Result<AzureDevOpsRepo>> = OnceLock::new();
+        match REPO.get_or_init(|| {
+            let remote_url = GitRepo::get_remote_url().wrap_err("Failed to get remote URL")?;
+            AzureDevOpsRepo::from_remote_url(&remote_url)
+        }) {
+            Ok(repo) => Ok(repo.clone()),
+            Err(e) => Err(eyre!("{}", e)),
+        }
+    }
+
+    /// 获取 PAT 认证信息（Basic 认证，用户名为空）
+    fn get_auth() -> Result<Authorization> {
+        let settings = Settings::get();
+        let token = settings
+            .azure_devops
+            .api_token
+            .as_deref()
+            .filter(|token| !token.is_empty())
+            .wrap_err(
+                "Azure DevOps personal access token is not configured. Please run 'workflow setup' or set WORKFLOW_AZURE_DEVOPS__API_TOKEN",
+            )?;
+        Ok(Authorization::new("", token))
+    }
+
+    /// 发送 Azure DevOps API 请求
+    ///
+    /// 统一添加 `api-version` 参数和认证信息，并处理错误响应。
+    fn send<B: Serialize>(
+        method: HttpMethod,
+        url: &str,
+        params: &[(&str, &str)],
+        body: Option<&B>,
+    ) -> Result<HttpResponse> {
+        let query = with_api_version(params);
+        Self::send_with_query(method, url, &query, body)
+    }
+
+    /// 使用完整的查询参数发送请求（内部方法）
+    fn send_with_query<B: Serialize>(
+        method: HttpMethod,
+        url: &str,
+        query: &[(&str, &str)],
+        body: Option<&B>,
+    ) -> Result<HttpResponse> {
+        let client = HttpClient::global()?;
+        let auth = Self::get_auth()?;
+
+        let response = match method {
+            HttpMethod::Get => client.get(
+                url,
+                RequestConfig::<Value, _>::new().query(query).auth(&auth),
+            )?,
+            HttpMethod::Delete => client.delete(
+                url,
+                RequestConfig::<Value, _>::new().query(query).auth(&auth),
+            )?,
+            HttpMethod::Post | HttpMethod::Put | HttpMethod::Patch => {
+                let mut config = RequestConfig::<B, _>::new().query(query).auth(&auth);
+                if let Some(body) = body {
+                    config = config.body(body);
+                }
+                match method {
+                    HttpMethod::Post => client.post(url, config)?,
+                    HttpMethod::Put => client.put(url, config)?,
+                    _ => client.patch(url, config)?,
+                }
+            }
+        };
+
+        // PAT 无效时 Azure DevOps 返回 203 和登录页面，而不是 401
+        if response.status == 203 {
+            return Err(handle_azure_devops_error(&response));
+        }
+        response.ensure_success_with(handle_azure_devops_error)
+    }
+
+    /// 获取 PR 信息（不缓存，避免数据不一致）
+    fn fetch_pr_info_internal(pull_request_id: &str) -> Result<PullRequestInfo> {
+        let repo = Self::get_repo()?;
+        let pr_id = Self::parse_id(pull_request_id)?;
+        let response = Self::send::<Value>(
+            HttpMethod::Get,
+            &repo.api_url(&format!("/pullrequests/{}", pr_id)),
+            &[],
+            None,
+        )?;
+        response.as_json()
+    }
+
+    /// 按条件搜索 PR
+    fn search_pull_requests(params: &[(&str, &str)]) -> Result<Vec<PullRequestInfo>> {
+        let repo = Self::get_repo()?;
+        let response = Self::send::<Value>(
+            HttpMethod::Get,
+            &repo.api_url("/pullrequests"),
+            params,
+            None,
+        )?;
+        let list: PullRequestList = response.as_json()?;
+        Ok(list.value)
+    }
+
+    /// 更新 PR（内部方法）
+    fn update_pull_request_internal(pr_id: u64, request: &UpdatePullRequestRequest) -> Result<()> {
+        let repo = Self::get_repo()?;
+        Self::send(
+            HttpMethod::Patch,
+            &repo.api_url(&format!("/pullrequests/{}", pr_id)),
+            &[],
+            Some(request),
+        )?;
+        Ok(())
+    }
+
+    /// 分支是否配置了启用且阻止性的分支策略（如必需的评审人、构建验证）
+    fn has_blocking_policies(
+        repo: &AzureDevOpsRepo,
+        repository_id: &str,
+        ref_name: &str,
+    ) -> Result<bool> {
+        let response = Self::send::<Value>(
+            HttpMethod::Get,
+            &repo.project_api_url("/git/policy/configurations"),
+            &[("repositoryId", repository_id), ("refName", ref_name)],
+            None,
+        )?;
+        let policies: PolicyConfigurationList = response.as_json()?;
+        Ok(policies.value.iter().any(|policy| policy.is_enabled && policy.is_blocking))
+    }
+
+    /// 获取当前 PAT 对应的用户 ID
+    fn get_current_user_id(repo: &AzureDevOpsRepo) -> Result<String> {
+        let query = [("api-version", CONNECTION_DATA_API_VERSION)];
+        let response = Self::send_with_query::<Value>(
+            HttpMethod::Get,
+            &repo.organization_api_url("/connectionData"),
+            &query,
+            None,
+        )?;
+        let data: ConnectionData = response.as_json()?;
+        Ok(data.authenticated_user.id)
+    }
+
+    /// 解析 PR ID
+    fn parse_id(pull_request_id: &str) -> Result<u64> {
+        pull_request_id.parse::<u64>().wrap_err(validation_errors::INVALID_PR_NUMBER)
+    }
+
+    /// 分支名转换为引用名（`main` → `refs/heads/main`）
+    fn to_ref_name(branch: &str) -> String {
+        if branch.starts_with(REFS_HEADS) {
+            branch.to_string()
+        } else {
+            format!("{}{}", REFS_HEADS, branch)
+        }
+    }
+
+    /// 引用名转换为分支名（`refs/heads/main` → `main`）
+    fn from_ref_name(ref_name: &str) -> &str {
+        ref_name.strip_prefix(REFS_HEADS).unwrap_or(ref_name)
+    }
+
+    /// 截断超过 Azure DevOps 长度限制的 PR 描述
+    fn truncate_description(body: &str) -> String {
+        if body.chars().count() <= MAX_DESCRIPTION_LENGTH {
+            return body.to_string();
+        }
+        crate::trace_warn!(
+            "PR description exceeds {} characters, truncating",
+            MAX_DESCRIPTION_LENGTH
+        );
+        body.chars().take(MAX_DESCRIPTION_LENGTH).collect()
+    }
+}
@@ -0,0 +1,120 @@
+//! Azure DevOps 仓库信息
+//!
+//! 从 Git remote URL 解析 organization、project 和 repository，并构建 REST API 和网页 URL。
+
+use color_eyre::{eyre::WrapErr, Result};
+use regex::Regex;
+
+/// Azure DevOps 服务地址
+pub const BASE_URL: &str = "https://dev.azure.com";
+
+/// Azure DevOps 仓库信息
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AzureDevOpsRepo {
+    /// 组织名称
+    pub organization: String,
+    /// 项目名称（保持 remote URL 中的编码，如 `My%20Project`）
+    pub project: String,
+    /// 仓库名称
+    pub repository: String,
+}
+
+impl AzureDevOpsRepo {
+    /// 从 Git remote URL 解析仓库信息
+    ///
+    /// 支持以下格式：
+    /// - `https://[user@]dev.azure.com/{org}/{project}/_git/{repo}`
+    /// - `git@ssh.dev.azure.com:v3/{org}/{project}/{repo}`
+    /// - `https://{org}.visualstudio.com/[DefaultCollection/]{project}/_git/{repo}`
+    /// - `{org}@vs-ssh.visualstudio.com:v3/{org}/{project}/{repo}`
+    ///
+    /// URL 中省略项目时（仓库与项目同名），项目名称使用仓库名称。
+    ///
+    /// # 示例
+    /// ```
+    /// use workflow::pr::AzureDevOpsRepo;
+    /// let repo = AzureDevOpsRepo::from_remote_url("git@ssh.dev.azure.com:v3/org/project/repo").unwrap();
+    /// assert_eq!(repo.organization, "org");
+    /// assert_eq!(repo.project, "project");
+    /// assert_eq!(repo.repository, "repo");
+    /// ```
+    pub fn from_remote_url(url: &str) -> Result<Self> {
+        // SSH 格式: git@ssh.dev.azure.com:v3/org/project/repo 或 org@vs-ssh.visualstudio.com:v3/org/project/repo
+        let ssh_re = Regex::new(
+            r"^(?:ssh://)?[^@/]+@(?:ssh\.dev\.azure\.com|vs-ssh\.visualstudio\.com)(?::22)?[:/]v3/([^/]+)/([^/]+)/([^/]+?)(?:\.git)?/?$",
+        )
+        .wrap_err("Invalid regex pattern")?;
+        if let Some(caps) = ssh_re.captures(url) {
+            return Ok(Self::new(&caps[1], Some(&caps[2]), &caps[3]));
+        }
+
+        // HTTPS 格式: https://[user@]dev.azure.com/org/[project/]_git/repo
+        let https_re = Regex::new(
+            r"^https?://(?:[^@/]+@)?dev\.azure\.com/([^/]+)/(?:([^/]+)/)?_git/([^/]+?)(?:\.git)?/?$",
+        )
+        .wrap_err("Invalid regex pattern")?;
+        if let Some(caps) = https_re.captures(url) {
+            return Ok(Self::new(
+                &caps[1],
+                caps.get(2).map(|m| m.as_str()),
+                &caps[3],
+            ));
+        }
+
+        // 旧版 HTTPS 格式: https://org.visualstudio.com/[DefaultCollection/][project/]_git/repo
+        let legacy_re = Regex::new(
+            r"^https?://(?:[^@/]+@)?([^./]+)\.visualstudio\.com/(?:DefaultCollection/)?(?:([^/]+)/)?_git/([^/]+?)(?:\.git)?/?$",
+        )
+        .wrap_err("Invalid regex pattern")?;
+        if let Some(caps) = legacy_re.captures(url) {
+            return Ok(Self::new(
+                &caps[1],
+                caps.get(2).map(|m| m.as_str()),
+                &caps[3],
+            ));
+        }
+
+        color_eyre::eyre::bail!("Failed to extract Azure DevOps repo from URL: {}", url)
+    }
+
+    fn new(organization: &str, project: Option<&str>, repository: &str) -> Self {
+        Self {
+            organization: organization.to_string(),
+            project: project.unwrap_or(repository).to_string(),
+            repository: repository.to_string(),
+        }
+    }
+
+    /// 构建 Git 仓库 REST API URL
+    ///
+    /// # 参数
+    ///
+    /// * `path` - 相对于仓库的路径，如 `/pullrequests/1`
+    pub fn api_url(&self, path: &str) -> String {
+        format!(
+            "{}/{}/{}/_apis/git/repositories/{}{}",
+            BASE_URL, self.organization, self.project, self.repository, path
+        )
+    }
+
+    /// 构建项目级 REST API URL（如分支策略配置）
+    pub fn project_api_url(&self, path: &str) -> String {
+        format!(
+            "{}/{}/{}/_apis{}",
+            BASE_URL, self.organization, self.project, path
+        )
+    }
+
+    /// 构建组织级 REST API URL（如 `connectionData`）
+    pub fn organization_api_url(&self, path: &str) -> String {
+        format!("{}/{}/_apis{}", BASE_URL, self.organization, path)
+    }
+
+    /// 构建 PR 的网页 URL
+    pub fn pull_request_web_url(&self, pull_request_id: u64) -> String {
+        format!(
+            "{}/{}/{}/_git/{}/pullrequest/{}",
+            BASE_URL, self.organization, self.project, self.repository, pull_request_id
+        )
+    }
+}
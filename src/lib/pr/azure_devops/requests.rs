@@ -0,0 +1,79 @@
+use serde::Serialize;
+use serde_with::skip_serializing_none;
+
+/// PR 标签
+#[derive(Debug, Serialize)]
+pub struct WebApiTagDefinition {
+    pub name: String,
+}
+
+/// 创建 Pull Request 请求
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePullRequestRequest {
+    pub source_ref_name: String,
+    pub target_ref_name: String,
+    pub title: String,
+    pub description: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<WebApiTagDefinition>,
+}
+
+/// 合并选项
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompletionOptions {
+    pub delete_source_branch: bool,
+    pub merge_strategy: String,
+}
+
+/// 提交引用
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitRefRequest {
+    pub commit_id: String,
+}
+
+/// 更新 Pull Request 请求（包括合并和关闭）
+#[skip_serializing_none]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdatePullRequestRequest {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    pub target_ref_name: Option<String>,
+    pub last_merge_source_commit: Option<CommitRefRequest>,
+    pub completion_options: Option<CompletionOptions>,
+}
+
+/// 评论
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentRequest {
+    pub parent_comment_id: u64,
+    pub content: String,
+    pub comment_type: u8,
+}
+
+/// 创建评论线程请求
+#[derive(Debug, Serialize)]
+pub struct CreateThreadRequest {
+    pub comments: Vec<CommentRequest>,
+    pub status: u8,
+}
+
+/// 审核人投票请求
+#[derive(Debug, Serialize)]
+pub struct ReviewerVoteRequest {
+    pub vote: i8,
+}
+
+/// 引用更新请求（`newObjectId` 为全 0 时删除引用）
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitRefUpdate {
+    pub name: String,
+    pub old_object_id: String,
+    pub new_object_id: String,
+}
@@ -0,0 +1,151 @@
+use serde::Deserialize;
+
+/// Pull Request 信息
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PullRequestInfo {
+    pub pull_request_id: u64,
+    pub title: String,
+    pub description: Option<String>,
+    /// 状态：active、abandoned、completed
+    pub status: String,
+    pub source_ref_name: String,
+    pub target_ref_name: String,
+    pub closed_date: Option<String>,
+    pub created_by: Option<IdentityRef>,
+    pub last_merge_source_commit: Option<CommitRef>,
+    /// 合并检查状态：notSet、queued、conflicts、succeeded、rejectedByPolicy、failure
+    #[serde(default)]
+    pub merge_status: Option<String>,
+    /// 评审人
+    #[serde(default)]
+    pub reviewers: Vec<ReviewerRef>,
+    /// 标签
+    #[serde(default)]
+    pub labels: Vec<LabelRef>,
+    /// 目标仓库
+    #[serde(default)]
+    pub repository: Option<RepositoryRef>,
+    /// 源分支所在的 fork（源分支在当前仓库中时为空）
+    #[serde(default)]
+    pub fork_source: Option<ForkRef>,
+}
+
+/// 仓库引用
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RepositoryRef {
+    pub id: String,
+    /// 默认分支（如 `refs/heads/main`）
+    pub default_branch: Option<String>,
+}
+
+/// fork 中的分支引用
+#[derive(Debug, Deserialize)]
+pub struct ForkRef {
+    pub name: String,
+}
+
+/// PR 标签
+#[derive(Debug, Deserialize)]
+pub struct LabelRef {
+    pub name: String,
+}
+
+/// 评审人及其投票
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewerRef {
+    pub id: String,
+    pub display_name: Option<String>,
+    /// 投票：10 批准，5 批准但有建议，0 未投票，-5 等待作者修改，-10 拒绝
+    #[serde(default)]
+    pub vote: i32,
+    /// 是否为必需的评审人
+    #[serde(default)]
+    pub is_required: bool,
+}
+
+/// Pull Request 列表
+#[derive(Debug, Deserialize)]
+pub struct PullRequestList {
+    pub value: Vec<PullRequestInfo>,
+}
+
+/// 用户信息
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IdentityRef {
+    pub id: String,
+    pub display_name: Option<String>,
+    pub unique_name: Option<String>,
+}
+
+/// 提交引用
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitRef {
+    pub commit_id: String,
+}
+
+/// 连接信息（用于获取当前认证用户）
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionData {
+    pub authenticated_user: AuthenticatedUser,
+}
+
+/// 当前认证用户
+#[derive(Debug, Deserialize)]
+pub struct AuthenticatedUser {
+    pub id: String,
+}
+
+/// Git 引用（分支）
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitRef {
+    pub name: String,
+    pub object_id: String,
+    /// 是否被锁定
+    #[serde(default)]
+    pub is_locked: bool,
+}
+
+/// Git 引用列表
+#[derive(Debug, Deserialize)]
+pub struct GitRefList {
+    pub value: Vec<GitRef>,
+}
+
+/// 引用更新结果
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GitRefUpdateResult {
+    pub success: bool,
+    /// 更新状态（如 `succeeded`、`rejectedByPolicy`）
+    pub update_status: String,
+}
+
+/// 引用更新结果列表
+#[derive(Debug, Deserialize)]
+pub struct GitRefUpdateResultList {
+    pub value: Vec<GitRefUpdateResult>,
+}
+
+/// 分支策略配置
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyConfiguration {
+    #[serde(default)]
+    pub is_enabled: bool,
+    /// 是否阻止不满足策略的操作
+    #[serde(default)]
+    pub is_blocking: bool,
+}
+
+/// 分支策略配置列表
+#[derive(Debug, Deserialize)]
+pub struct PolicyConfigurationList {
+    pub value: Vec<PolicyConfiguration>,
+}
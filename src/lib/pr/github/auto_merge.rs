@@ -0,0 +1,281 @@
+//! GitHub 自动合并（auto-merge）
+//!
+//! REST API 不支持开启自动合并，需要使用 GraphQL API：
+//! 1. 查询仓库设置、PR 的 node ID 和 base 分支的保护规则（`AUTO_MERGE_CONTEXT_QUERY`）
+//! 2. 检查能否开启自动合并并确定合并方式（`AutoMergeRepository::validate_auto_merge`）
+//! 3. 调用 `enablePullRequestAutoMerge` mutation（`ENABLE_AUTO_MERGE_MUTATION`）
+//!
+//! 自动合并会在必需的检查通过后由 GitHub 完成合并，因此要求 base 分支配置了必需的状态检查。
+
+use color_eyre::eyre::eyre;
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::pr::readiness::MergeMethod;
+
+/// 查询开启自动合并所需的信息
+pub const AUTO_MERGE_CONTEXT_QUERY: &str = r#"
+query($owner: String!, $name: String!, $number: Int!) {
+  repository(owner: $owner, name: $name) {
+    autoMergeAllowed
+    squashMergeAllowed
+    rebaseMergeAllowed
+    mergeCommitAllowed
+    pullRequest(number: $number) {
+      id
+      number
+      state
+      isDraft
+      baseRefName
+      baseRef {
+        branchProtectionRule {
+          requiresStatusChecks
+          requiredStatusCheckContexts
+        }
+      }
+    }
+  }
+}
+"#;
+
+/// 开启自动合并
+pub const ENABLE_AUTO_MERGE_MUTATION: &str = r#"
+mutation($pullRequestId: ID!, $mergeMethod: PullRequestMergeMethod!) {
+  enablePullRequestAutoMerge(input: { pullRequestId: $pullRequestId, mergeMethod: $mergeMethod }) {
+    pullRequest {
+      autoMergeRequest {
+        mergeMethod
+      }
+    }
+  }
+}
+"#;
+
+/// GraphQL 请求
+#[derive(Debug, Serialize)]
+pub struct GraphQLRequest<'a, V> {
+    pub query: &'a str,
+    pub variables: V,
+}
+
+/// GraphQL 响应
+///
+/// GraphQL 的错误以 HTTP 200 返回，错误信息在 `errors` 中。
+#[derive(Debug, Deserialize)]
+pub struct GraphQLResponse<T> {
+    pub data: Option<T>,
+    #[serde(default)]
+    pub errors: Vec<GraphQLError>,
+}
+
+/// GraphQL 错误
+#[derive(Debug, Deserialize)]
+pub struct GraphQLError {
+    pub message: String,
+}
+
+impl<T> GraphQLResponse<T> {
+    /// 获取响应数据（响应中有错误时返回所有错误信息）
+    pub fn into_data(self) -> Result<T> {
+        if !self.errors.is_empty() {
+            let messages: Vec<&str> = self.errors.iter().map(|e| e.message.as_str()).collect();
+            return Err(eyre!("GitHub GraphQL error: {}", messages.join("; ")));
+        }
+        self.data.ok_or_else(|| eyre!("GitHub GraphQL response has no data"))
+    }
+}
+
+/// `AUTO_MERGE_CONTEXT_QUERY` 的变量
+#[derive(Debug, Serialize)]
+pub struct AutoMergeContextVariables<'a> {
+    pub owner: &'a str,
+    pub name: &'a str,
+    pub number: u64,
+}
+
+/// `ENABLE_AUTO_MERGE_MUTATION` 的变量
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnableAutoMergeVariables<'a> {
+    pub pull_request_id: &'a str,
+    pub merge_method: &'a str,
+}
+
+/// `AUTO_MERGE_CONTEXT_QUERY` 的响应数据
+#[derive(Debug, Deserialize)]
+pub struct AutoMergeContextData {
+    pub repository: Option<AutoMergeRepository>,
+}
+
+/// 仓库的合并设置
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoMergeRepository {
+    pub auto_merge_allowed: bool,
+    pub squash_merge_allowed: bool,
+    pub rebase_merge_allowed: bool,
+    pub merge_commit_allowed: bool,
+    pub pull_request: Option<AutoMergePullRequest>,
+}
+
+/// 开启自动合并的 PR
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoMergePullRequest {
+    /// GraphQL node ID
+    pub id: String,
+    pub number: u64,
+    /// OPEN、CLOSED、MERGED
+    pub state: String,
+    #[serde(default)]
+    pub is_draft: bool,
+    pub base_ref_name: String,
+    /// base 分支（分支已删除时为 `null`）
+    pub base_ref: Option<AutoMergeBaseRef>,
+}
+
+/// PR 的 base 分支
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoMergeBaseRef {
+    /// 分支保护规则（未受保护时为 `null`）
+    pub branch_protection_rule: Option<AutoMergeProtectionRule>,
+}
+
+/// 分支保护规则中的状态检查设置
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoMergeProtectionRule {
+    #[serde(default)]
+    pub requires_status_checks: bool,
+    #[serde(default)]
+    pub required_status_check_contexts: Vec<String>,
+}
+
+/// `ENABLE_AUTO_MERGE_MUTATION` 的响应数据
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnableAutoMergeData {
+    pub enable_pull_request_auto_merge: Option<EnableAutoMergePayload>,
+}
+
+/// `enablePullRequestAutoMerge` 的返回
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnableAutoMergePayload {
+    pub pull_request: Option<EnabledAutoMergePullRequest>,
+}
+
+/// 开启自动合并后的 PR
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnabledAutoMergePullRequest {
+    pub auto_merge_request: Option<AutoMergeRequest>,
+}
+
+/// 自动合并请求
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoMergeRequest {
+    /// MERGE、SQUASH、REBASE
+    pub merge_method: String,
+}
+
+/// 合并方式在 GraphQL `PullRequestMergeMethod` 中的名称
+pub fn graphql_merge_method(method: MergeMethod) -> &'static str {
+    match method {
+        MergeMethod::Squash => "SQUASH",
+        MergeMethod::Rebase => "REBASE",
+        MergeMethod::Merge => "MERGE",
+    }
+}
+
+/// 解析 GraphQL `PullRequestMergeMethod`
+pub fn parse_graphql_merge_method(name: &str) -> Option<MergeMethod> {
+    match name {
+        "SQUASH" => Some(MergeMethod::Squash),
+        "REBASE" => Some(MergeMethod::Rebase),
+        "MERGE" => Some(MergeMethod::Merge),
+        _ => None,
+    }
+}
+
+impl AutoMergeRepository {
+    /// 仓库是否允许指定的合并方式
+    pub fn allows(&self, method: MergeMethod) -> bool {
+        match method {
+            MergeMethod::Squash => self.squash_merge_allowed,
+            MergeMethod::Rebase => self.rebase_merge_allowed,
+            MergeMethod::Merge => self.merge_commit_allowed,
+        }
+    }
+
+    /// 检查能否开启自动合并并确定合并方式
+    ///
+    /// 以下情况返回错误：PR 不存在、不是打开状态或是草稿；仓库没有开启 "Allow auto-merge"；
+    /// base 分支没有配置必需的状态检查；指定的合并方式不被允许。
+    ///
+    /// # 参数
+    ///
+    /// * `requested` - 指定的合并方式（`None` 时按 squash > rebase > merge 选择仓库允许的方式）
+    ///
+    /// # 返回
+    ///
+    /// 返回 PR 的 node ID 和使用的合并方式。
+    pub fn validate_auto_merge(
+        &self,
+        requested: Option<MergeMethod>,
+    ) -> Result<(&str, MergeMethod)> {
+        let pr = self.pull_request.as_ref().ok_or_else(|| eyre!("Pull request not found"))?;
+
+        if pr.state != "OPEN" {
+            return Err(eyre!(
+                "PR #{} is {}; auto-merge can only be enabled on open PRs",
+                pr.number,
+                pr.state.to_lowercase()
+            ));
+        }
+        if pr.is_draft {
+            return Err(eyre!(
+                "PR #{} is a draft; mark it as ready for review before enabling auto-merge",
+                pr.number
+            ));
+        }
+        if !self.auto_merge_allowed {
+            return Err(eyre!(
+                "Auto-merge is not allowed in this repository. \
+                 Enable 'Allow auto-merge' in the repository settings (Settings > General > Pull Requests)"
+            ));
+        }
+
+        let has_required_checks = pr
+            .base_ref
+            .as_ref()
+            .and_then(|base| base.branch_protection_rule.as_ref())
+            .is_some_and(|rule| {
+                rule.requires_status_checks && !rule.required_status_check_contexts.is_empty()
+            });
+        if !has_required_checks {
+            return Err(eyre!(
+                "Branch '{}' has no required status checks configured, so auto-merge has nothing to wait for. \
+                 Add required status checks to the branch protection rule, or merge directly with 'workflow pr merge'",
+                pr.base_ref_name
+            ));
+        }
+
+        let method = match requested {
+            Some(method) if self.allows(method) => method,
+            Some(method) => {
+                return Err(eyre!(
+                    "Merge method '{}' is not allowed in this repository",
+                    method
+                ))
+            }
+            None => MergeMethod::ALL.into_iter().find(|method| self.allows(*method)).ok_or_else(
+                || eyre!("Repository does not support squash, rebase, or merge commit methods"),
+            )?,
+        };
+
+        Ok((pr.id.as_str(), method))
+    }
+}
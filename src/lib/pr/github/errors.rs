@@ -1,4 +1,6 @@
 use crate::base::http::HttpResponse;
+use crate::pr::github::rate_limit::detect_rate_limit;
+use chrono::Utc;
 use color_eyre::eyre::{eyre, Report};
 use serde::Deserialize;
 use serde_json::Value;
@@ -58,8 +60,18 @@ pub fn format_error(error: &GitHubErrorResponse, response: &HttpResponse) -> Rep
 
 /// 处理 GitHub API 错误
 ///
-/// 尝试解析 GitHub 错误格式，如果无法解析则返回通用错误信息
+/// 被速率限制拒绝时返回区分主要限制和二级限制的说明（见 `rate_limit::detect_rate_limit`）；
+/// 否则尝试解析 GitHub 错误格式，如果无法解析则返回通用错误信息
 pub fn handle_github_error(response: &HttpResponse) -> Report {
+    if let Some(hit) = detect_rate_limit(
+        response.status,
+        &response.headers,
+        &response.as_text().unwrap_or_default(),
+        Utc::now().timestamp(),
+    ) {
+        return eyre!(hit.message());
+    }
+
     // 尝试解析 JSON 错误
     if let Ok(data) = response.as_json::<Value>() {
         // 尝试解析为 GitHub 错误格式
@@ -1,8 +1,14 @@
+pub mod auto_merge;
 pub mod errors;
+pub mod pagination;
 pub mod platform;
+pub mod rate_limit;
 pub mod requests;
 pub mod responses;
+pub mod token;
 
 pub use errors::{format_error, GitHubError, GitHubErrorResponse};
 pub use platform::GitHub;
-pub use responses::GitHubUser;
+pub use rate_limit::{RateLimitHit, RateLimitInfo, RateLimitKind};
+pub use responses::{GitHubUser, RateLimitQuota, RateLimitResponse};
+pub use token::{GitHubTokenInfo, REQUIRED_TOKEN_SCOPES};
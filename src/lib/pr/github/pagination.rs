@@ -0,0 +1,105 @@
+//! GitHub API 分页
+//!
+//! GitHub 的列表接口通过响应头 `Link` 返回下一页地址，例如：
+//!
+//! ```text
+//! Link: <https://api.github.com/repositories/1/pulls?state=open&per_page=100&page=2>; rel="next",
+//!       <https://api.github.com/repositories/1/pulls?state=open&per_page=100&page=5>; rel="last"
+//! ```
+//!
+//! 下一页地址保留了第一页的所有查询参数（状态、排序、每页数量等），
+//! 因此沿着 `rel="next"` 翻页时筛选条件在各页之间保持一致。没有 `rel="next"` 时表示已是最后一页。
+
+use color_eyre::Result;
+use reqwest::header::{HeaderMap, LINK};
+use serde::de::DeserializeOwned;
+
+use crate::base::http::HttpResponse;
+
+use super::errors::handle_github_error;
+
+/// 从 `Link` 头中解析 `rel="next"` 的地址
+///
+/// # 示例
+///
+/// ```
+/// use workflow::pr::github::pagination::parse_next_link;
+///
+/// let link = r#"<https://api.github.com/repos/o/r/pulls?page=2>; rel="next", <https://api.github.com/repos/o/r/pulls?page=5>; rel="last""#;
+/// assert_eq!(
+///     parse_next_link(link).as_deref(),
+///     Some("https://api.github.com/repos/o/r/pulls?page=2")
+/// );
+/// assert_eq!(
+///     parse_next_link(r#"<https://api.github.com/repos/o/r/pulls?page=1>; rel="prev""#),
+///     None
+/// );
+/// ```
+pub fn parse_next_link(link: &str) -> Option<String> {
+    // 按 `<url>` 切分（地址中可能包含逗号，不能直接按逗号切分）
+    let mut rest = link;
+    while let Some(start) = rest.find('<') {
+        let after = &rest[start + 1..];
+        let end = after.find('>')?;
+        let params_end = after[end..].find('<').map_or(after.len(), |i| end + i);
+        let is_next =
+            after[end + 1..params_end].split(';').any(|param| {
+                param.trim().trim_end_matches(',').strip_prefix("rel=").is_some_and(|rel| {
+                    rel.trim_matches('"').split_whitespace().any(|r| r == "next")
+                })
+            });
+        if is_next {
+            return Some(after[..end].to_string());
+        }
+        rest = &after[params_end..];
+    }
+    None
+}
+
+/// 获取响应的下一页地址（没有下一页时返回 `None`）
+pub fn next_page_url(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get(LINK)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_next_link)
+}
+
+/// 依次读取列表接口的每一页
+///
+/// # 参数
+///
+/// * `max_pages` - 最多请求的页数
+/// * `fetch_page` - 发送请求：参数为 `None` 时请求第一页，为 `Some(url)` 时请求 `Link` 头中的下一页地址
+/// * `on_page` - 处理一页数据，返回 `false` 时停止翻页（如已达到数量限制）
+///
+/// # 返回
+///
+/// 实际请求的页数
+///
+/// # 错误
+///
+/// 请求失败、响应不是成功状态码或响应无法解析时返回错误（已请求的页已经交给 `on_page` 处理）。
+pub fn for_each_page<T, F, C>(max_pages: usize, mut fetch_page: F, mut on_page: C) -> Result<usize>
+where
+    T: DeserializeOwned,
+    F: FnMut(Option<&str>) -> Result<HttpResponse>,
+    C: FnMut(Vec<T>) -> Result<bool>,
+{
+    let mut next: Option<String> = None;
+    let mut pages = 0;
+    while pages < max_pages {
+        let response = fetch_page(next.as_deref())?;
+        pages += 1;
+
+        let next_url = next_page_url(&response.headers);
+        let items: Vec<T> = response.ensure_success_with(handle_github_error)?.as_json()?;
+        if !on_page(items)? {
+            break;
+        }
+        match next_url {
+            Some(url) => next = Some(url),
+            None => break,
+        }
+    }
+    Ok(pages)
+}
@@ -1,6 +1,9 @@
 use std::fmt::Write;
 use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant};
 
+use chrono::Utc;
 use color_eyre::{
     eyre::{eyre, ContextCompat, WrapErr},
     Result,
@@ -9,21 +12,44 @@ use reqwest::header::{HeaderMap, HeaderValue, ACCEPT};
 use serde_json::Value;
 
 use crate::base::constants::{errors::validation_errors, messages::pull_requests};
-use crate::base::http::{HttpClient, RequestConfig};
+use crate::base::format::DisplayFormatter;
+use crate::base::http::{HttpClient, HttpResponse, HttpStatusError, RequestConfig};
+use crate::base::indicator::Spinner;
 use crate::base::settings::Settings;
-use crate::git::{self, GitBranch, GitRepo};
+use crate::git::{GitBranch, GitRepo};
 use crate::jira::history::JiraWorkHistory;
 use crate::pr::github::errors::handle_github_error;
 use crate::pr::helpers::url::extract_github_repo_from_url;
-use crate::pr::platform::{PlatformProvider, PullRequestStatus};
+use crate::pr::list_filter::PrListFilter;
+use crate::pr::platform::{
+    BranchDeletion, PlatformProvider, PullRequestDetails, PullRequestStatus,
+};
+use crate::pr::readiness::{collect_checks, CheckState, MergeMethod, MergeReadiness, ReviewStatus};
+use crate::pr::review::ReviewEvent;
+use crate::pr::review_comment::{ensure_line_in_diff, ChangedFile, ReviewCommentTarget};
 use crate::pr::PullRequestRow;
+use crate::trace_warn;
 
+use super::auto_merge::{
+    graphql_merge_method, parse_graphql_merge_method, AutoMergeContextData,
+    AutoMergeContextVariables, EnableAutoMergeData, EnableAutoMergeVariables, GraphQLRequest,
+    GraphQLResponse, AUTO_MERGE_CONTEXT_QUERY, ENABLE_AUTO_MERGE_MUTATION,
+};
+use super::pagination;
+use super::rate_limit::{self, RateLimitInfo, RateLimitKind};
 use super::requests::{
-    CreatePullRequestRequest, MergePullRequestRequest, UpdatePullRequestRequest,
+    CreatePullRequestRequest, CreateReviewCommentRequest, MergePullRequestRequest,
+    ReplyReviewCommentRequest, UpdatePullRequestRequest,
 };
 use super::responses::{
-    CreatePullRequestResponse, GitHubUser, PullRequestFile, PullRequestInfo, RepositoryInfo,
+    BranchInfo, BranchProtection, CheckRunList, CombinedStatus, CreatePullRequestResponse,
+    GitHubUser, PullRequestFile, PullRequestHead, PullRequestHeadCommit, PullRequestInfo,
+    PullRequestMergeability, PullRequestReview, RateLimitResponse, RepositoryInfo,
 };
+use super::token::{parse_oauth_scopes, GitHubTokenInfo, OAUTH_SCOPES_HEADER};
+
+/// 被速率限制拒绝时的最大自动重试次数
+const MAX_RATE_LIMIT_RETRIES: u32 = 2;
 
 /// GitHub 平台实现
 ///
@@ -65,7 +91,7 @@ impl PlatformProvider for GitHub {
         let headers = Self::get_headers(None)?;
         let config = RequestConfig::<_, Value>::new().body(&request).headers(&headers);
 
-        let response = client.post(&url, config)?;
+        let response = Self::send(|| client.post(&url, config))?;
         let response_data: CreatePullRequestResponse =
             response.ensure_success_with(handle_github_error)?.as_json()?;
 
@@ -73,13 +99,23 @@ impl PlatformProvider for GitHub {
     }
 
     /// 合并 Pull Request
-    fn merge_pull_request(&self, pull_request_id: &str, delete_branch: bool) -> Result<()> {
+    fn merge_pull_request(
+        &self,
+        pull_request_id: &str,
+        delete_branch: bool,
+        merge_method: Option<MergeMethod>,
+    ) -> Result<()> {
         let (owner, repo_name) = Self::get_owner_and_repo()?;
         let pr_number =
             pull_request_id.parse::<u64>().wrap_err(validation_errors::INVALID_PR_NUMBER)?;
 
-        // 检测仓库支持的合并方法：优先使用 squash，否则使用 merge
-        let merge_method = Self::get_preferred_merge_method(&owner, &repo_name)?;
+        // 未指定合并方式时检测仓库支持的合并方法：优先使用 squash，否则使用 merge
+        let merge_method = match merge_method {
+            Some(MergeMethod::Squash) => "squash".to_string(),
+            Some(MergeMethod::Rebase) => "rebase".to_string(),
+            Some(MergeMethod::Merge) => "merge".to_string(),
+            None => Self::get_preferred_merge_method(&owner, &repo_name)?,
+        };
         crate::trace_debug!("Using merge method: {}", merge_method);
 
         let url = format!(
@@ -100,41 +136,14 @@ impl PlatformProvider for GitHub {
         let headers = Self::get_headers(None)?;
         let config = RequestConfig::<_, Value>::new().body(&request).headers(&headers);
 
-        let response = client.put(&url, config)?;
+        let response = Self::send(|| client.put(&url, config))?;
         // GitHub API 返回合并结果，但我们不需要使用响应
         let _: serde_json::Value = response.ensure_success_with(handle_github_error)?.as_json()?;
 
-        // 如果需要删除分支，调用删除分支 API
+        // 如果需要删除分支，调用删除分支 API（失败时不影响合并结果）
         if delete_branch {
-            // 先获取 PR 信息以获取源分支名
-            let pr_info_url = format!(
-                "{}/repos/{}/{}/pulls/{}",
-                Self::base_url(),
-                owner,
-                repo_name,
-                pr_number
-            );
-            let client = HttpClient::global()?;
-            let headers = Self::get_headers(None)?;
-            let config = RequestConfig::<Value, Value>::new().headers(&headers);
-
-            let response = client.get(&pr_info_url, config)?;
-            // 获取 PR 信息以获取源分支名
-            if let Ok(pr_info) = response
-                .ensure_success_with(handle_github_error)
-                .and_then(|r| r.as_json::<PullRequestInfo>())
-            {
-                let branch_name = pr_info.head.ref_name;
-                let branch_url = format!(
-                    "{}/repos/{}/{}/git/refs/heads/{}",
-                    Self::base_url(),
-                    owner,
-                    repo_name,
-                    branch_name
-                );
-                // 尝试删除分支，忽略 404 错误（分支可能已经被删除）
-                let delete_config = RequestConfig::<Value, Value>::new().headers(&headers);
-                let _ = client.delete(&branch_url, delete_config);
+            if let Err(e) = self.delete_source_branch(pull_request_id) {
+                trace_warn!("Failed to delete source branch of PR #{}: {}", pr_number, e);
             }
         }
 
@@ -149,8 +158,12 @@ impl PlatformProvider for GitHub {
 
         let mut info = String::new();
         writeln!(info, "Title: {}", pr.title)?;
-        if let Some(body) = pr.body {
-            writeln!(info, "Description: {}", body)?;
+        if let Some(body) = pr.body.filter(|body| !body.trim().is_empty()) {
+            // 描述为 Markdown，渲染后缩进显示
+            writeln!(info, "Description:")?;
+            for line in DisplayFormatter::render_markdown(&body).lines() {
+                writeln!(info, "  {}", line)?;
+            }
         }
         writeln!(info, "State: {}", pr.state)?;
         writeln!(info, "Source Branch: {}", pr.head.ref_name)?;
@@ -196,31 +209,197 @@ impl PlatformProvider for GitHub {
         })
     }
 
-    /// 列出 PR
-    fn get_pull_requests(
-        &self,
-        state: Option<&str>,
-        limit: Option<usize>,
-    ) -> Result<Vec<PullRequestRow>> {
-        let prs = Self::get_pull_requests_raw(state, limit)?;
-        let rows: Vec<PullRequestRow> = prs
+    /// 获取 PR 详细信息
+    fn get_pull_request_details(&self, pull_request_id: &str) -> Result<PullRequestDetails> {
+        let pr_number =
+            pull_request_id.parse::<u64>().wrap_err(validation_errors::INVALID_PR_NUMBER)?;
+        Ok(Self::fetch_pr_info_internal(pr_number)?.into())
+    }
+
+    /// 获取 PR 的合并前检查结果
+    ///
+    /// 分支保护规则需要仓库管理员权限才能读取，无法获取时按没有保护规则处理
+    /// （此时 GitHub 返回的 `mergeable_state` 为 `blocked` 仍会提示被阻止）。
+    fn get_merge_readiness(&self, pull_request_id: &str) -> Result<MergeReadiness> {
+        let (owner, repo_name) = Self::get_owner_and_repo()?;
+        let pr_number =
+            pull_request_id.parse::<u64>().wrap_err(validation_errors::INVALID_PR_NUMBER)?;
+        let repo_url = format!("{}/repos/{}/{}", Self::base_url(), owner, repo_name);
+
+        let pr: PullRequestMergeability =
+            Self::get_json(&format!("{}/pulls/{}", repo_url, pr_number))?;
+        let check_runs: CheckRunList = Self::get_json(&format!(
+            "{}/commits/{}/check-runs?per_page=100",
+            repo_url, pr.head.sha
+        ))?;
+        let statuses: CombinedStatus =
+            Self::get_json(&format!("{}/commits/{}/status", repo_url, pr.head.sha))?;
+        let reviews: Vec<PullRequestReview> = Self::get_json(&format!(
+            "{}/pulls/{}/reviews?per_page=100",
+            repo_url, pr_number
+        ))?;
+        let repo_info = Self::get_repository_info(&owner, &repo_name)?;
+        let protection = Self::get_branch_protection(&repo_url, &pr.base.ref_name);
+
+        let required_checks = protection
+            .as_ref()
+            .and_then(|p| p.required_status_checks.as_ref())
+            .map(|checks| checks.contexts.clone())
+            .unwrap_or_default();
+        let results = check_runs
+            .check_runs
             .into_iter()
-            .map(|pr| PullRequestRow {
-                number: pr.number.to_string(),
-                state: pr.state,
-                branch: pr.head.ref_name,
-                title: pr.title,
-                author: pr
-                    .user
-                    .as_ref()
-                    .map(|u| u.login.clone())
-                    .unwrap_or_else(|| "N/A".to_string()),
-                url: pr.html_url,
+            .map(|run| {
+                let state = CheckState::from_check_run(&run.status, run.conclusion.as_deref());
+                (run.name, state)
             })
-            .collect();
+            .chain(statuses.statuses.into_iter().map(|status| {
+                let state = CheckState::from_commit_status(&status.state);
+                (status.context, state)
+            }));
+
+        let required_approvals = protection
+            .as_ref()
+            .and_then(|p| p.required_pull_request_reviews.as_ref())
+            .map(|reviews| reviews.required_approving_review_count);
+        let reviews = ReviewStatus::from_reviews(
+            reviews.iter().filter_map(|review| {
+                let login = review.user.as_ref()?.login.as_str();
+                Some((login, review.state.as_str()))
+            }),
+            required_approvals,
+        );
+
+        // 要求线性历史时不能创建合并提交
+        let linear_history = protection
+            .as_ref()
+            .and_then(|p| p.required_linear_history.as_ref())
+            .is_some_and(|setting| setting.enabled);
+
+        Ok(MergeReadiness {
+            state: if pr.merged {
+                "merged".to_string()
+            } else {
+                pr.state
+            },
+            merged: pr.merged,
+            mergeable: pr.mergeable,
+            mergeable_state: pr.mergeable_state,
+            checks: collect_checks(results, &required_checks),
+            reviews,
+            merge_methods: vec![
+                (
+                    MergeMethod::Squash,
+                    repo_info.allow_squash_merge.unwrap_or(false),
+                ),
+                (
+                    MergeMethod::Rebase,
+                    repo_info.allow_rebase_merge.unwrap_or(false),
+                ),
+                (
+                    MergeMethod::Merge,
+                    repo_info.allow_merge_commit.unwrap_or(false) && !linear_history,
+                ),
+            ],
+        })
+    }
+
+    /// 开启自动合并
+    ///
+    /// 先查询仓库设置和 base 分支的保护规则，检查通过后调用 `enablePullRequestAutoMerge`。
+    fn enable_auto_merge(
+        &self,
+        pull_request_id: &str,
+        merge_method: Option<MergeMethod>,
+    ) -> Result<MergeMethod> {
+        let (owner, repo_name) = Self::get_owner_and_repo()?;
+        let pr_number =
+            pull_request_id.parse::<u64>().wrap_err(validation_errors::INVALID_PR_NUMBER)?;
+
+        let context: AutoMergeContextData = Self::graphql(
+            AUTO_MERGE_CONTEXT_QUERY,
+            AutoMergeContextVariables {
+                owner: &owner,
+                name: &repo_name,
+                number: pr_number,
+            },
+        )?;
+        let repository = context
+            .repository
+            .ok_or_else(|| eyre!("Repository {}/{} not found", owner, repo_name))?;
+        let (node_id, method) = repository
+            .validate_auto_merge(merge_method)
+            .wrap_err_with(|| format!("Cannot enable auto-merge for PR #{}", pr_number))?;
+        crate::trace_debug!(
+            "Enabling auto-merge for PR #{} with method: {}",
+            pr_number,
+            method
+        );
+
+        let result: EnableAutoMergeData = Self::graphql(
+            ENABLE_AUTO_MERGE_MUTATION,
+            EnableAutoMergeVariables {
+                pull_request_id: node_id,
+                merge_method: graphql_merge_method(method),
+            },
+        )?;
+
+        // 以 GitHub 返回的合并方式为准
+        Ok(result
+            .enable_pull_request_auto_merge
+            .and_then(|payload| payload.pull_request)
+            .and_then(|pr| pr.auto_merge_request)
+            .and_then(|request| parse_graphql_merge_method(&request.merge_method))
+            .unwrap_or(method))
+    }
+
+    /// 列出 PR（收集 `stream_pull_requests` 的所有页）
+    fn get_pull_requests(&self, filter: &PrListFilter) -> Result<Vec<PullRequestRow>> {
+        let mut rows = Vec::new();
+        self.stream_pull_requests(filter, &mut |page| {
+            rows.extend(page);
+            Ok(())
+        })?;
         Ok(rows)
     }
 
+    /// 分页列出 PR，每获取一页就调用一次 `on_page`
+    ///
+    /// `filter.mine` 为 true 时使用当前 token 对应用户的 login 作为作者条件。
+    fn stream_pull_requests(
+        &self,
+        filter: &PrListFilter,
+        on_page: &mut dyn FnMut(Vec<PullRequestRow>) -> Result<()>,
+    ) -> Result<()> {
+        let mut filter = filter.clone();
+        if filter.mine {
+            let user = Self::get_user_info(None).wrap_err(
+                "Cannot resolve the current GitHub user for --mine; check the GitHub token or use --author instead",
+            )?;
+            filter.author = Some(user.login);
+        }
+
+        Self::stream_pull_requests_raw(&filter, |prs| {
+            on_page(
+                prs.into_iter()
+                    .map(|pr| PullRequestRow {
+                        number: pr.number.to_string(),
+                        state: pr.state,
+                        branch: pr.head.ref_name,
+                        title: pr.title,
+                        author: pr
+                            .user
+                            .as_ref()
+                            .map(|u| u.login.clone())
+                            .unwrap_or_else(|| "N/A".to_string()),
+                        url: pr.html_url,
+                    })
+                    .collect(),
+            )
+        })?;
+        Ok(())
+    }
+
     /// 获取当前分支的 PR
     fn get_current_branch_pull_request(&self) -> Result<Option<String>> {
         let (owner, repo_name) = Self::get_owner_and_repo()?;
@@ -240,7 +419,7 @@ impl PlatformProvider for GitHub {
         );
 
         let config = RequestConfig::<Value, Value>::new().headers(&headers);
-        let response = client.get(&url, config)?;
+        let response = Self::send(|| client.get(&url, config))?;
         let prs: Vec<PullRequestInfo> =
             response.ensure_success_with(handle_github_error)?.as_json()?;
         if let Some(pr) = prs.first() {
@@ -259,7 +438,7 @@ impl PlatformProvider for GitHub {
         );
 
         let config_all = RequestConfig::<Value, Value>::new().headers(&headers);
-        let response_all = client.get(&url_all, config_all)?;
+        let response_all = Self::send(|| client.get(&url_all, config_all))?;
         let prs_all: Vec<PullRequestInfo> =
             response_all.ensure_success_with(handle_github_error)?.as_json()?;
         if let Some(pr) = prs_all.first() {
@@ -322,7 +501,7 @@ impl PlatformProvider for GitHub {
 
         let config = RequestConfig::<Value, Value>::new().headers(&headers);
 
-        let response = client.get(&url, config)?;
+        let response = Self::send(|| client.get(&url, config))?;
 
         // 检查是否是 406 错误（diff too large）
         if response.status == 406 {
@@ -356,6 +535,61 @@ impl PlatformProvider for GitHub {
         Ok(diff)
     }
 
+    /// 删除 PR 的远程源分支
+    ///
+    /// 以下情况不删除：源分支在 fork 仓库中、是仓库的默认分支或受分支保护。
+    fn delete_source_branch(&self, pull_request_id: &str) -> Result<BranchDeletion> {
+        let (owner, repo_name) = Self::get_owner_and_repo()?;
+        let pr_number =
+            pull_request_id.parse::<u64>().wrap_err(validation_errors::INVALID_PR_NUMBER)?;
+        let repo_url = format!("{}/repos/{}/{}", Self::base_url(), owner, repo_name);
+
+        let pr: PullRequestHead = Self::get_json(&format!("{}/pulls/{}", repo_url, pr_number))?;
+        let branch = pr.head.ref_name;
+        let Some(head_repo) = pr.head.repo else {
+            // 源仓库已删除，分支也随之删除
+            return Ok(BranchDeletion::NotFound(branch));
+        };
+        if !head_repo.full_name.eq_ignore_ascii_case(&format!("{}/{}", owner, repo_name)) {
+            return Ok(BranchDeletion::Skipped {
+                branch,
+                reason: format!("branch belongs to fork {}", head_repo.full_name),
+            });
+        }
+        if head_repo.default_branch.as_deref() == Some(branch.as_str()) {
+            return Ok(BranchDeletion::Skipped {
+                branch,
+                reason: "branch is the default branch".to_string(),
+            });
+        }
+
+        let client = HttpClient::global()?;
+        let headers = Self::get_headers(None)?;
+        let config = RequestConfig::<Value, Value>::new().headers(&headers);
+
+        let branch_url = format!("{}/branches/{}", repo_url, branch);
+        let response = Self::send(|| client.get(&branch_url, config))?;
+        if response.status == 404 {
+            return Ok(BranchDeletion::NotFound(branch));
+        }
+        let info: BranchInfo = response.ensure_success_with(handle_github_error)?.as_json()?;
+        if info.protected {
+            return Ok(BranchDeletion::Skipped {
+                branch,
+                reason: "branch is protected".to_string(),
+            });
+        }
+
+        let ref_url = format!("{}/git/refs/heads/{}", repo_url, branch);
+        let response = Self::send(|| client.delete(&ref_url, config))?;
+        // 分支在检查后已被删除时返回 422（Reference does not exist）
+        if matches!(response.status, 404 | 422) {
+            return Ok(BranchDeletion::NotFound(branch));
+        }
+        response.ensure_success_with(handle_github_error)?;
+        Ok(BranchDeletion::Deleted(branch))
+    }
+
     /// 关闭 Pull Request
     fn close_pull_request(&self, pull_request_id: &str) -> Result<()> {
         let (owner, repo_name) = Self::get_owner_and_repo()?;
@@ -381,7 +615,7 @@ impl PlatformProvider for GitHub {
         let headers = Self::get_headers(None)?;
         let config = RequestConfig::<_, Value>::new().body(&request).headers(&headers);
 
-        let response = client.patch(&url, config)?;
+        let response = Self::send(|| client.patch(&url, config))?;
         // GitHub API 返回更新后的 PR 对象，但我们不需要使用响应
         let _: serde_json::Value = response.ensure_success_with(handle_github_error)?.as_json()?;
 
@@ -417,28 +651,127 @@ impl PlatformProvider for GitHub {
         let headers = Self::get_headers(None)?;
         let config = RequestConfig::<_, Value>::new().body(&request).headers(&headers);
 
-        let response = client.post(&url, config)?;
+        let response = Self::send(|| client.post(&url, config))?;
+        let _: serde_json::Value = response.ensure_success_with(handle_github_error)?.as_json()?;
+
+        Ok(())
+    }
+
+    /// 添加评审评论
+    ///
+    /// 行评论先检查文件和行号是否在 PR diff 中，再评论到 head 提交的对应行；
+    /// 回复通过 replies 端点添加到被回复评论所在的讨论中。
+    fn add_review_comment(
+        &self,
+        pull_request_id: &str,
+        target: &ReviewCommentTarget,
+        comment: &str,
+    ) -> Result<()> {
+        let (owner, repo_name) = Self::get_owner_and_repo()?;
+        let pr_number =
+            pull_request_id.parse::<u64>().wrap_err(validation_errors::INVALID_PR_NUMBER)?;
+        let pulls_url = format!(
+            "{}/repos/{}/{}/pulls/{}",
+            Self::base_url(),
+            owner,
+            repo_name,
+            pr_number
+        );
+
+        let client = HttpClient::global()?;
+        let headers = Self::get_headers(None)?;
+        let response = match target {
+            ReviewCommentTarget::Line { path, line } => {
+                let files: Vec<ChangedFile> =
+                    Self::get_pull_request_files_internal(&owner, &repo_name, pr_number)?
+                        .into_iter()
+                        .map(|file| ChangedFile {
+                            path: file.filename,
+                            patch: file.patch,
+                        })
+                        .collect();
+                ensure_line_in_diff(&files, path, *line)?;
+
+                let pr: PullRequestHeadCommit = Self::get_json(&pulls_url)?;
+                // GitHub API: POST /repos/{owner}/{repo}/pulls/{pull_number}/comments
+                let url = format!("{}/comments", pulls_url);
+                let request = CreateReviewCommentRequest {
+                    body: comment.to_string(),
+                    commit_id: pr.head.sha,
+                    path: path.clone(),
+                    line: *line,
+                    side: "RIGHT".to_string(),
+                };
+                let config = RequestConfig::<_, Value>::new().body(&request).headers(&headers);
+                Self::send(|| client.post(&url, config))?
+            }
+            ReviewCommentTarget::Reply { comment_id } => {
+                // GitHub API: POST /repos/{owner}/{repo}/pulls/{pull_number}/comments/{comment_id}/replies
+                let url = format!("{}/comments/{}/replies", pulls_url, comment_id);
+                let request = ReplyReviewCommentRequest {
+                    body: comment.to_string(),
+                };
+                let config = RequestConfig::<_, Value>::new().body(&request).headers(&headers);
+                let response = Self::send(|| client.post(&url, config))?;
+                if response.status == 404 {
+                    color_eyre::eyre::bail!(
+                        "Review comment {} not found in PR #{}",
+                        comment_id,
+                        pr_number
+                    );
+                }
+                response
+            }
+        };
         let _: serde_json::Value = response.ensure_success_with(handle_github_error)?.as_json()?;
 
         Ok(())
     }
 
-    /// 批准 Pull Request
-    fn approve_pull_request(&self, pull_request_id: &str) -> Result<()> {
+    /// 提交评审（批准、请求修改或评论）
+    ///
+    /// 批准时未提供评审内容则使用 👍。GitHub 不允许批准自己的 PR 或对自己的 PR 请求修改，
+    /// 提交前先检查 PR 的创建者。
+    fn submit_review(
+        &self,
+        pull_request_id: &str,
+        event: ReviewEvent,
+        body: Option<&str>,
+    ) -> Result<()> {
+        let body = event.validate_body(body)?;
         let (owner, repo_name) = Self::get_owner_and_repo()?;
         let pr_number =
             pull_request_id.parse::<u64>().wrap_err(validation_errors::INVALID_PR_NUMBER)?;
 
-        // 先获取 PR 信息以检查是否是自己的 PR
-        let pr_info = Self::fetch_pr_info_internal(pr_number)?;
-        let current_user = Self::get_user_info(None)?;
+        let own_pull_request_error = || {
+            match event {
+            ReviewEvent::RequestChanges => eyre!(
+                "Cannot request changes on your own pull request. GitHub does not allow users to request changes on their own PRs."
+            ),
+            _ => eyre!(
+                "Cannot approve your own pull request. GitHub does not allow users to approve their own PRs."
+            ),
+        }
+        };
 
-        // 检查是否是自己的 PR
-        if let Some(ref pr_user) = pr_info.user {
-            if pr_user.login == current_user.login {
-                color_eyre::eyre::bail!(
-                    "Cannot approve your own pull request. GitHub does not allow users to approve their own PRs."
-                );
+        if event != ReviewEvent::Comment {
+            // 先获取 PR 信息以检查是否是自己的 PR
+            let pr_info = Self::fetch_pr_info_internal(pr_number)?;
+            // 优先使用添加账号时保存的 login，避免每次都请求 /user
+            let saved_login = Settings::get()
+                .github
+                .get_current_account()
+                .and_then(|account| account.login.clone());
+            let current_login = match saved_login {
+                Some(login) => login,
+                None => Self::get_user_info(None)?.login,
+            };
+
+            // 检查是否是自己的 PR
+            if let Some(ref pr_user) = pr_info.user {
+                if pr_user.login == current_login {
+                    return Err(own_pull_request_error());
+                }
             }
         }
 
@@ -458,28 +791,27 @@ impl PlatformProvider for GitHub {
         }
 
         let request = ReviewRequest {
-            event: pull_requests::APPROVE_EVENT.to_string(),
-            body: pull_requests::APPROVE_EMOJI.to_string(),
+            event: event.github_event().to_string(),
+            body: body.unwrap_or(pull_requests::APPROVE_EMOJI).to_string(),
         };
 
         let client = HttpClient::global()?;
         let headers = Self::get_headers(None)?;
         let config = RequestConfig::<_, Value>::new().body(&request).headers(&headers);
 
-        let response = client.post(&url, config)?;
+        let response = Self::send(|| client.post(&url, config))?;
 
         // 处理可能的错误（例如，如果 API 仍然返回错误，提供更友好的消息）
         match response.ensure_success_with(handle_github_error) {
             Ok(_) => Ok(()),
             Err(e) => {
-                // 检查是否是"不能批准自己的 PR"的错误
+                // 检查是否是"不能评审自己的 PR"的错误
                 let error_msg = e.to_string().to_lowercase();
-                if error_msg.contains("can not approve your own pull request")
+                if error_msg.contains("on your own pull request")
+                    || error_msg.contains("can not approve your own pull request")
                     || error_msg.contains("cannot approve your own")
                 {
-                    color_eyre::eyre::bail!(
-                        "Cannot approve your own pull request. GitHub does not allow users to approve their own PRs."
-                    );
+                    return Err(own_pull_request_error());
                 }
                 Err(e)
             }
@@ -508,7 +840,7 @@ impl PlatformProvider for GitHub {
         let headers = Self::get_headers(None)?;
         let config = RequestConfig::<_, Value>::new().body(&request).headers(&headers);
 
-        let response = client.patch(&url, config)?;
+        let response = Self::send(|| client.patch(&url, config))?;
         let _: serde_json::Value = response.ensure_success_with(handle_github_error)?.as_json()?;
 
         Ok(())
@@ -544,7 +876,7 @@ impl PlatformProvider for GitHub {
         let headers = Self::get_headers(None)?;
         let config = RequestConfig::<_, Value>::new().body(&request).headers(&headers);
 
-        let response = client.patch(&url, config)?;
+        let response = Self::send(|| client.patch(&url, config))?;
         let _: serde_json::Value = response.ensure_success_with(handle_github_error)?.as_json()?;
 
         Ok(())
@@ -552,9 +884,9 @@ impl PlatformProvider for GitHub {
 }
 
 impl GitHub {
-    /// 获取 GitHub API 基础 URL
-    fn base_url() -> &'static str {
-        git::github::API_BASE
+    /// 获取 GitHub API 基础 URL（`github.api_base_url`，默认 `https://api.github.com`）
+    fn base_url() -> String {
+        Settings::get().github.api_base_url()
     }
 
     /// 创建 GitHub API 请求的 headers（内部方法）
@@ -632,7 +964,7 @@ impl GitHub {
         let headers = Self::get_headers(None)?;
         let config = RequestConfig::<Value, Value>::new().headers(&headers);
 
-        let response = client.get(&url, config)?;
+        let response = Self::send(|| client.get(&url, config))?;
         let repo_info: RepositoryInfo =
             response.ensure_success_with(handle_github_error)?.as_json()?;
         Ok(repo_info)
@@ -640,48 +972,113 @@ impl GitHub {
 
     /// 获取 PR 列表原始数据（不格式化）
     ///
+    /// 分页规则见 `stream_pull_requests_raw`，返回所有页的结果。
+    ///
     /// # 参数
     ///
-    /// * `state` - PR 状态筛选（如 "open", "closed"）
-    /// * `limit` - 返回数量限制
+    /// * `filter` - 筛选条件（`mine` 需要先由调用方解析为 `author`）
     ///
     /// # 返回
     ///
     /// PR 信息列表
-    pub fn get_pull_requests_raw(
-        state: Option<&str>,
-        limit: Option<usize>,
-    ) -> Result<Vec<PullRequestInfo>> {
+    pub fn get_pull_requests_raw(filter: &PrListFilter) -> Result<Vec<PullRequestInfo>> {
+        let mut prs = Vec::new();
+        Self::stream_pull_requests_raw(filter, |page| {
+            prs.extend(page);
+            Ok(())
+        })?;
+        Ok(prs)
+    }
+
+    /// 分页获取 PR 列表原始数据，每获取一页就调用一次 `on_page`
+    ///
+    /// 状态、目标分支和排序在服务端筛选，沿着响应头 `Link` 中的 `rel="next"` 翻页，
+    /// 下一页地址保留第一页的查询参数，各页的筛选条件一致；累计达到数量限制后停止请求。
+    /// GitHub 的 pulls API 不支持按作者、标签和已合并状态筛选，有这些条件时每页取最大数量
+    /// 并在本地筛选，直到满足数量限制（最多 `PrListFilter::MAX_PAGES` 页）。
+    ///
+    /// # 参数
+    ///
+    /// * `filter` - 筛选条件（`mine` 需要先由调用方解析为 `author`）
+    /// * `on_page` - 处理一页筛选后的 PR（不会收到空页，总数不超过数量限制）
+    ///
+    /// # 返回
+    ///
+    /// 获取到的 PR 总数
+    pub fn stream_pull_requests_raw(
+        filter: &PrListFilter,
+        mut on_page: impl FnMut(Vec<PullRequestInfo>) -> Result<()>,
+    ) -> Result<usize> {
         let (owner, repo_name) = Self::get_owner_and_repo()?;
 
         // 转换 state 参数：GitHub API 支持 "open", "closed", "all"
-        let state = match state {
+        let state = match filter.state.as_deref() {
             Some("open") => "open",
-            Some("closed") => "closed",
-            Some("merged") => "closed", // GitHub API 中 merged 是 closed 状态的一种
-            Some("all") | None => "all",
+            // GitHub API 中 merged 是 closed 状态的一种，在本地筛选
+            Some("closed") | Some("merged") => "closed",
             _ => "all", // 默认显示所有状态
         };
-        let per_page = limit.unwrap_or(30).min(100); // GitHub API 限制最多 100
-
-        let url = format!(
-            "{}/repos/{}/{}/pulls?state={}&per_page={}",
-            Self::base_url(),
-            owner,
-            repo_name,
-            state,
-            per_page
-        );
+        let limit = filter.limit();
+        let local_filter = filter.needs_local_filter() || filter.state.as_deref() == Some("merged");
+        // GitHub API 限制每页最多 100 条；本地筛选时每页取最大数量，减少请求次数
+        let per_page = if local_filter {
+            100
+        } else {
+            limit.clamp(1, 100)
+        };
+        let max_pages = if local_filter {
+            PrListFilter::MAX_PAGES
+        } else {
+            limit.div_ceil(per_page).max(1)
+        };
 
+        let url = format!("{}/repos/{}/{}/pulls", Self::base_url(), owner, repo_name);
         let client = HttpClient::global()?;
         let headers = Self::get_headers(None)?;
-        let config = RequestConfig::<Value, Value>::new().headers(&headers);
+        let mut query = vec![
+            ("state", state.to_string()),
+            ("sort", filter.sort.to_string()),
+            ("direction", "desc".to_string()),
+            ("per_page", per_page.to_string()),
+        ];
+        if let Some(base) = &filter.base {
+            query.push(("base", base.clone()));
+        }
 
-        let response = client.get(&url, config)?;
-        let prs: Vec<PullRequestInfo> =
-            response.ensure_success_with(handle_github_error)?.as_json()?;
+        let mut total = 0;
+        pagination::for_each_page(
+            max_pages,
+            |next_url| match next_url {
+                None => {
+                    let config = RequestConfig::<Value, _>::new().query(&query).headers(&headers);
+                    Self::send(|| client.get(&url, config))
+                }
+                // 下一页地址已包含所有查询参数
+                Some(next_url) => {
+                    let config = RequestConfig::<Value, Value>::new().headers(&headers);
+                    Self::send(|| client.get(next_url, config))
+                }
+            },
+            |batch: Vec<PullRequestInfo>| {
+                let page: Vec<PullRequestInfo> = batch
+                    .into_iter()
+                    .filter(|pr| {
+                        let authors: Vec<&str> = pr.user.iter().map(|u| u.login.as_str()).collect();
+                        let labels: Vec<&str> = pr.labels.iter().map(|l| l.name.as_str()).collect();
+                        filter.matches_state(&pr.state, pr.merged || pr.merged_at.is_some())
+                            && filter.matches(&authors, &labels)
+                    })
+                    .take(limit - total)
+                    .collect();
+                total += page.len();
+                if !page.is_empty() {
+                    on_page(page)?;
+                }
+                Ok(total < limit)
+            },
+        )?;
 
-        Ok(prs)
+        Ok(total)
     }
 
     /// 获取首选的合并方法：优先使用 squash，其次 rebase，最后 merge
@@ -710,6 +1107,53 @@ impl GitHub {
         );
     }
 
+    /// 发送 GET 请求并解析 JSON 响应
+    fn get_json<T: serde::de::DeserializeOwned>(url: &str) -> Result<T> {
+        let client = HttpClient::global()?;
+        let headers = Self::get_headers(None)?;
+        let config = RequestConfig::<Value, Value>::new().headers(&headers);
+
+        let response = Self::send(|| client.get(url, config))?;
+        response.ensure_success_with(handle_github_error)?.as_json()
+    }
+
+    /// 发送 GraphQL 请求并解析响应数据
+    fn graphql<V: serde::Serialize, T: serde::de::DeserializeOwned>(
+        query: &str,
+        variables: V,
+    ) -> Result<T> {
+        let url = Settings::get().github.graphql_url();
+        let request = GraphQLRequest { query, variables };
+
+        let client = HttpClient::global()?;
+        let headers = Self::get_headers(None)?;
+        let config = RequestConfig::<_, Value>::new().body(&request).headers(&headers);
+
+        let response = Self::send(|| client.post(&url, config))?;
+        let response: GraphQLResponse<T> =
+            response.ensure_success_with(handle_github_error)?.as_json()?;
+        response.into_data()
+    }
+
+    /// 获取分支保护规则（分支未受保护或没有权限读取时返回 `None`）
+    fn get_branch_protection(repo_url: &str, branch: &str) -> Option<BranchProtection> {
+        let url = format!("{}/branches/{}/protection", repo_url, branch);
+        let client = HttpClient::global().ok()?;
+        let headers = Self::get_headers(None).ok()?;
+        let config = RequestConfig::<Value, Value>::new().headers(&headers);
+
+        let response = Self::send(|| client.get(&url, config)).ok()?;
+        if !response.is_success() {
+            crate::trace_debug!(
+                "Branch protection for '{}' is not available (HTTP {})",
+                branch,
+                response.status
+            );
+            return None;
+        }
+        response.as_json().ok()
+    }
+
     /// 内部方法：获取 PR 信息（不缓存，避免数据不一致）
     fn fetch_pr_info_internal(pr_number: u64) -> Result<PullRequestInfo> {
         let (owner, repo_name) = Self::get_owner_and_repo()?;
@@ -726,12 +1170,101 @@ impl GitHub {
         let headers = Self::get_headers(None)?;
         let config = RequestConfig::<Value, Value>::new().headers(&headers);
 
-        let response = client.get(&url, config)?;
+        let response = Self::send(|| client.get(&url, config))?;
         let pr_info: PullRequestInfo =
             response.ensure_success_with(handle_github_error)?.as_json()?;
         Ok(pr_info)
     }
 
+    /// 发送 GitHub API 请求（处理速率限制）
+    ///
+    /// - 上一次响应显示配额已耗尽（`X-RateLimit-Remaining` 为 0）时，先等待配额重置
+    /// - 请求被速率限制拒绝（403/429）时，按 `Retry-After` 或 `X-RateLimit-Reset` 等待后重试，
+    ///   最多重试 `MAX_RATE_LIMIT_RETRIES` 次；需要等待超过 `rate_limit::MAX_AUTO_WAIT` 时不再等待
+    ///
+    /// 最终仍被拒绝时返回原响应，由 `handle_github_error` 生成区分主要限制和二级限制的错误信息。
+    fn send(request: impl Fn() -> Result<HttpResponse>) -> Result<HttpResponse> {
+        if let Some(wait) = rate_limit::pending_wait(Utc::now().timestamp())
+            .filter(|wait| *wait <= rate_limit::MAX_AUTO_WAIT)
+        {
+            Self::wait_for_rate_limit("GitHub API rate limit reached (primary limit)", wait);
+        }
+
+        let mut retries = 0;
+        loop {
+            let response = request()?;
+            rate_limit::record(RateLimitInfo::from_headers(&response.headers));
+
+            let hit = if matches!(response.status, 403 | 429) {
+                rate_limit::detect_rate_limit(
+                    response.status,
+                    &response.headers,
+                    &response.as_text().unwrap_or_default(),
+                    Utc::now().timestamp(),
+                )
+            } else {
+                None
+            };
+
+            match hit {
+                Some(hit)
+                    if retries < MAX_RATE_LIMIT_RETRIES
+                        && hit.wait <= rate_limit::MAX_AUTO_WAIT =>
+                {
+                    retries += 1;
+                    trace_warn!("{}", hit.message());
+                    let reason = match hit.kind {
+                        RateLimitKind::Primary => "GitHub API rate limit exceeded (primary limit)",
+                        RateLimitKind::Secondary => "GitHub secondary rate limit hit",
+                    };
+                    Self::wait_for_rate_limit(reason, hit.wait);
+                }
+                _ => return Ok(response),
+            }
+        }
+    }
+
+    /// 等待速率限制解除（显示剩余等待时间）
+    fn wait_for_rate_limit(reason: &str, wait: Duration) {
+        let message = |left: Duration| {
+            format!(
+                "{}, waiting {} before retrying...",
+                reason,
+                rate_limit::format_duration(left)
+            )
+        };
+        let spinner = Spinner::new(message(wait));
+        let deadline = Instant::now() + wait;
+        while let Some(left) = deadline.checked_duration_since(Instant::now()) {
+            if left.is_zero() {
+                break;
+            }
+            spinner.update_message(message(Duration::from_secs(
+                left.as_secs_f64().ceil() as u64
+            )));
+            thread::sleep(left.min(Duration::from_secs(1)));
+        }
+        spinner.finish();
+    }
+
+    /// 查询当前 GitHub 账号的 API 速率限制配额
+    ///
+    /// 调用 GitHub API 的 /rate_limit 端点（该请求不消耗配额）。
+    ///
+    /// # 返回
+    ///
+    /// 返回各资源（`core`、`search`、`graphql` 等）的配额。
+    pub fn rate_limit() -> Result<RateLimitResponse> {
+        let url = format!("{}/rate_limit", Self::base_url());
+        let client = HttpClient::global()?;
+        let headers = Self::get_headers(None)?;
+        let config = RequestConfig::<Value, Value>::new().headers(&headers);
+
+        let response = client.get(&url, config)?;
+        rate_limit::record(RateLimitInfo::from_headers(&response.headers));
+        response.ensure_success_with(handle_github_error)?.as_json()
+    }
+
     /// 获取 GitHub 用户信息
     ///
     /// 调用 GitHub API 的 /user 端点获取用户信息。
@@ -755,12 +1288,49 @@ impl GitHub {
         };
 
         let config = RequestConfig::<Value, Value>::new().headers(&headers);
-        let response = client.get(&url, config)?;
+        let response = Self::send(|| client.get(&url, config))?;
         let user: GitHubUser = response.ensure_success_with(handle_github_error)?.as_json()?;
 
         Ok(user)
     }
 
+    /// 校验 GitHub API token
+    ///
+    /// 调用 GitHub API 的 /user 端点，返回 token 对应的用户以及 `X-OAuth-Scopes` 响应头中的权限范围。
+    ///
+    /// # 参数
+    ///
+    /// * `token` - 待校验的 GitHub API token
+    ///
+    /// # 错误
+    ///
+    /// token 无效或已过期（401）时返回 `HttpStatusError`，调用方可据此提示重新输入；
+    /// 其他失败（网络错误、速率限制等）返回普通错误。
+    pub fn validate_token(token: &str) -> Result<GitHubTokenInfo> {
+        let url = format!("{}/user", Self::base_url());
+        let client = HttpClient::global()?;
+        let headers = Self::get_headers(Some(token))?;
+
+        let config = RequestConfig::<Value, Value>::new().headers(&headers);
+        let response = Self::send(|| client.get(&url, config))?;
+        if response.status == 401 {
+            return Err(HttpStatusError::new(
+                401,
+                "GitHub rejected the API token (401 Unauthorized): it is invalid, expired or revoked",
+            )
+            .into());
+        }
+
+        let scopes = response
+            .headers
+            .get(OAUTH_SCOPES_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(parse_oauth_scopes);
+        let user: GitHubUser = response.ensure_success_with(handle_github_error)?.as_json()?;
+
+        Ok(GitHubTokenInfo { user, scopes })
+    }
+
     /// 获取 PR diff 的替代方案（当 diff 超过 20000 行时）
     ///
     /// 通过 `/pulls/{pr_number}/files` API 获取文件列表，然后获取部分文件的 diff。
@@ -878,12 +1448,46 @@ impl GitHub {
 
         let client = HttpClient::global()?;
         let headers = Self::get_headers(None)?;
-        let config = RequestConfig::<Value, Value>::new().headers(&headers);
 
-        let response = client.get(&url, config)?;
-        let files: Vec<PullRequestFile> =
-            response.ensure_success_with(handle_github_error)?.as_json()?;
+        // GitHub API 每页最多 100 个文件，最多返回 3000 个文件
+        const MAX_PAGES: usize = 30;
+        let query = [("per_page", "100")];
+        let mut files: Vec<PullRequestFile> = Vec::new();
+        pagination::for_each_page(
+            MAX_PAGES,
+            |next_url| match next_url {
+                None => {
+                    let config = RequestConfig::<Value, _>::new().query(&query).headers(&headers);
+                    Self::send(|| client.get(&url, config))
+                }
+                Some(next_url) => {
+                    let config = RequestConfig::<Value, Value>::new().headers(&headers);
+                    Self::send(|| client.get(next_url, config))
+                }
+            },
+            |batch: Vec<PullRequestFile>| {
+                files.extend(batch);
+                Ok(true)
+            },
+        )?;
 
         Ok(files)
     }
 }
+
+impl From<PullRequestInfo> for PullRequestDetails {
+    fn from(pr: PullRequestInfo) -> Self {
+        Self {
+            id: pr.number.to_string(),
+            title: pr.title,
+            description: pr.body.filter(|body| !body.trim().is_empty()),
+            state: pr.state,
+            merged: pr.merged,
+            merged_at: pr.merged_at,
+            source_branch: pr.head.ref_name,
+            target_branch: pr.base.ref_name,
+            author: pr.user.map(|user| user.login),
+            url: pr.html_url,
+        }
+    }
+}
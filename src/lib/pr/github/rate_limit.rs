@@ -0,0 +1,255 @@
+//! GitHub API 速率限制
+//!
+//! GitHub 有两类速率限制：
+//! - 主要限制（primary）：每小时的请求配额，响应头 `X-RateLimit-Remaining` 为 0 时触发，
+//!   `X-RateLimit-Reset` 给出配额重置的时间（Unix 时间戳）
+//! - 二级限制（secondary，又称 abuse 限制）：短时间内请求过多或并发过高时触发，
+//!   响应通常带有 `Retry-After` 头，错误消息中包含 "secondary rate limit"
+//!
+//! 两类限制都以 403 或 429 响应返回。本模块只负责解析响应头和判断限制类型，
+//! 等待和重试由 `GitHub` 平台实现处理。
+
+use std::sync::Mutex;
+use std::time::Duration;
+
+use chrono::{Local, TimeZone};
+use reqwest::header::HeaderMap;
+
+/// 请求配额上限
+pub const RATE_LIMIT_LIMIT_HEADER: &str = "x-ratelimit-limit";
+/// 剩余请求配额
+pub const RATE_LIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+/// 已使用的请求配额
+pub const RATE_LIMIT_USED_HEADER: &str = "x-ratelimit-used";
+/// 配额重置时间（Unix 时间戳，秒）
+pub const RATE_LIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+/// 配额所属的资源（如 `core`、`search`、`graphql`）
+pub const RATE_LIMIT_RESOURCE_HEADER: &str = "x-ratelimit-resource";
+/// 建议的重试等待时间（秒）
+pub const RETRY_AFTER_HEADER: &str = "retry-after";
+
+/// 触发二级限制但没有 `Retry-After` 时的等待时间（GitHub 建议至少等待 1 分钟）
+pub const SECONDARY_LIMIT_DEFAULT_WAIT: Duration = Duration::from_secs(60);
+/// 自动等待的最长时间，需要等待更久时直接返回错误
+pub const MAX_AUTO_WAIT: Duration = Duration::from_secs(15 * 60);
+
+/// 最近一次响应中的速率限制信息（用于在配额耗尽时，下一次请求前等待重置）
+static LAST_RATE_LIMIT: Mutex<Option<RateLimitInfo>> = Mutex::new(None);
+
+/// 响应头中的速率限制信息
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RateLimitInfo {
+    /// 配额上限
+    pub limit: Option<u64>,
+    /// 剩余配额
+    pub remaining: Option<u64>,
+    /// 已使用的配额
+    pub used: Option<u64>,
+    /// 配额重置时间（Unix 时间戳，秒）
+    pub reset: Option<i64>,
+    /// 配额所属的资源
+    pub resource: Option<String>,
+}
+
+impl RateLimitInfo {
+    /// 从响应头解析速率限制信息
+    ///
+    /// # 返回
+    ///
+    /// 响应中没有任何速率限制头时返回 `None`。
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let text = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+        let number = |name: &str| text(name).and_then(|value| value.trim().parse::<u64>().ok());
+
+        let info = Self {
+            limit: number(RATE_LIMIT_LIMIT_HEADER),
+            remaining: number(RATE_LIMIT_REMAINING_HEADER),
+            used: number(RATE_LIMIT_USED_HEADER),
+            reset: text(RATE_LIMIT_RESET_HEADER).and_then(|value| value.trim().parse().ok()),
+            resource: text(RATE_LIMIT_RESOURCE_HEADER).map(str::to_string),
+        };
+        (info != Self::default()).then_some(info)
+    }
+
+    /// 配额是否已耗尽
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == Some(0)
+    }
+
+    /// 距离配额重置的时间（已经重置或没有重置时间时返回 `None`）
+    ///
+    /// # 参数
+    ///
+    /// * `now` - 当前时间（Unix 时间戳，秒）
+    pub fn reset_in(&self, now: i64) -> Option<Duration> {
+        let reset = self.reset?;
+        (reset > now).then(|| Duration::from_secs((reset - now) as u64))
+    }
+}
+
+/// 速率限制类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RateLimitKind {
+    /// 主要限制（每小时配额耗尽）
+    Primary,
+    /// 二级限制（短时间内请求过多）
+    Secondary,
+}
+
+/// 被速率限制拒绝的请求
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RateLimitHit {
+    /// 限制类型
+    pub kind: RateLimitKind,
+    /// 重试前需要等待的时间
+    pub wait: Duration,
+    /// 响应头中的速率限制信息
+    pub info: Option<RateLimitInfo>,
+}
+
+impl RateLimitHit {
+    /// 生成面向用户的说明（区分主要限制和二级限制）
+    pub fn message(&self) -> String {
+        match self.kind {
+            RateLimitKind::Primary => {
+                let mut message = "GitHub API rate limit exceeded".to_string();
+                if let Some(info) = &self.info {
+                    if let (Some(used), Some(limit)) = (info.used, info.limit) {
+                        message.push_str(&format!(" ({}/{} requests used", used, limit));
+                        if let Some(resource) = &info.resource {
+                            message.push_str(&format!(" for '{}'", resource));
+                        }
+                        message.push(')');
+                    }
+                    if let Some(reset) = info.reset {
+                        message.push_str(&format!("; quota resets at {}", format_reset(reset)));
+                    }
+                }
+                message.push_str(&format!(" (in {})", format_duration(self.wait)));
+                message
+            }
+            RateLimitKind::Secondary => format!(
+                "GitHub secondary rate limit triggered (too many requests in a short time); \
+                 retry after {}",
+                format_duration(self.wait)
+            ),
+        }
+    }
+}
+
+/// 判断响应是否被速率限制拒绝
+///
+/// 只有 403 和 429 响应可能是速率限制：
+/// - 错误消息包含 "secondary rate limit" / "abuse"，或带有 `Retry-After` 且配额未耗尽：二级限制
+/// - `X-RateLimit-Remaining` 为 0：主要限制，等待到 `X-RateLimit-Reset`
+/// - 其他 429 响应按二级限制处理；其他 403 响应（如权限不足）不是速率限制
+///
+/// # 参数
+///
+/// * `status` - HTTP 状态码
+/// * `headers` - 响应头
+/// * `body` - 响应体文本
+/// * `now` - 当前时间（Unix 时间戳，秒）
+///
+/// # 示例
+///
+/// ```
+/// use reqwest::header::HeaderMap;
+/// use workflow::pr::github::rate_limit::{detect_rate_limit, RateLimitKind};
+///
+/// let mut headers = HeaderMap::new();
+/// headers.insert("x-ratelimit-remaining", "0".parse().unwrap());
+/// headers.insert("x-ratelimit-reset", "1030".parse().unwrap());
+///
+/// let hit = detect_rate_limit(403, &headers, "API rate limit exceeded", 1000).unwrap();
+/// assert_eq!(hit.kind, RateLimitKind::Primary);
+/// assert_eq!(hit.wait.as_secs(), 30);
+/// assert!(detect_rate_limit(403, &HeaderMap::new(), "Forbidden", 1000).is_none());
+/// ```
+pub fn detect_rate_limit(
+    status: u16,
+    headers: &HeaderMap,
+    body: &str,
+    now: i64,
+) -> Option<RateLimitHit> {
+    if status != 403 && status != 429 {
+        return None;
+    }
+
+    let info = RateLimitInfo::from_headers(headers);
+    let exhausted = info.as_ref().is_some_and(RateLimitInfo::is_exhausted);
+    let retry_after = headers
+        .get(RETRY_AFTER_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(parse_retry_after);
+    let body = body.to_lowercase();
+    let secondary_message = body.contains("secondary rate limit") || body.contains("abuse");
+
+    let (kind, wait) = if secondary_message || (retry_after.is_some() && !exhausted) {
+        (
+            RateLimitKind::Secondary,
+            retry_after.unwrap_or(SECONDARY_LIMIT_DEFAULT_WAIT),
+        )
+    } else if exhausted {
+        let reset_in = info.as_ref().and_then(|info| info.reset_in(now));
+        (
+            RateLimitKind::Primary,
+            retry_after.or(reset_in).unwrap_or(Duration::from_secs(1)),
+        )
+    } else if status == 429 {
+        (RateLimitKind::Secondary, SECONDARY_LIMIT_DEFAULT_WAIT)
+    } else {
+        return None;
+    };
+
+    Some(RateLimitHit { kind, wait, info })
+}
+
+/// 解析 `Retry-After` 响应头（秒数）
+///
+/// GitHub 返回的 `Retry-After` 为秒数；无法解析时返回 `None`。
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    value.trim().parse::<u64>().ok().map(Duration::from_secs)
+}
+
+/// 记录最近一次响应的速率限制信息
+pub fn record(info: Option<RateLimitInfo>) {
+    if let (Some(info), Ok(mut last)) = (info, LAST_RATE_LIMIT.lock()) {
+        *last = Some(info);
+    }
+}
+
+/// 下一次请求前需要等待的时间
+///
+/// 最近一次响应显示配额已耗尽、且尚未重置时，返回距离重置的时间。
+///
+/// # 参数
+///
+/// * `now` - 当前时间（Unix 时间戳，秒）
+pub fn pending_wait(now: i64) -> Option<Duration> {
+    let last = LAST_RATE_LIMIT.lock().ok()?;
+    last.as_ref()
+        .filter(|info| info.is_exhausted())
+        .and_then(|info| info.reset_in(now))
+}
+
+/// 格式化配额重置时间（本地时间）
+pub fn format_reset(reset: i64) -> String {
+    Local
+        .timestamp_opt(reset, 0)
+        .single()
+        .map(|time| time.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_else(|| reset.to_string())
+}
+
+/// 格式化等待时间（如 `45s`、`3m 20s`）
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs < 60 {
+        format!("{}s", secs)
+    } else if secs.is_multiple_of(60) {
+        format!("{}m", secs / 60)
+    } else {
+        format!("{}m {}s", secs / 60, secs % 60)
+    }
+}
@@ -28,3 +28,21 @@ pub struct UpdatePullRequestRequest {
     pub state: Option<String>,
     pub base: Option<String>,
 }
+
+/// 创建评审评论请求（`POST /repos/{owner}/{repo}/pulls/{number}/comments`）
+#[derive(Debug, Serialize)]
+pub struct CreateReviewCommentRequest {
+    pub body: String,
+    /// PR head 提交的 SHA
+    pub commit_id: String,
+    pub path: String,
+    pub line: u32,
+    /// 评论所在的 diff 一侧（`RIGHT` 表示变更后的版本）
+    pub side: String,
+}
+
+/// 回复评审评论请求（`POST /repos/{owner}/{repo}/pulls/{number}/comments/{comment_id}/replies`）
+#[derive(Debug, Serialize)]
+pub struct ReplyReviewCommentRequest {
+    pub body: String,
+}
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use serde::Deserialize;
 use serde_with::skip_serializing_none;
 
@@ -22,6 +24,14 @@ pub struct PullRequestInfo {
     pub head: PullRequestBranch,
     pub base: PullRequestBranch,
     pub user: Option<GitHubUser>,
+    #[serde(default)]
+    pub labels: Vec<PullRequestLabel>,
+}
+
+/// Pull Request 标签
+#[derive(Debug, Deserialize)]
+pub struct PullRequestLabel {
+    pub name: String,
 }
 
 /// Pull Request 分支信息
@@ -51,6 +61,26 @@ pub struct GitHubUser {
     pub email: Option<String>,
 }
 
+/// GitHub API 速率限制查询响应（`GET /rate_limit`）
+#[derive(Debug, Deserialize)]
+pub struct RateLimitResponse {
+    /// 各资源（`core`、`search`、`graphql` 等）的配额
+    pub resources: BTreeMap<String, RateLimitQuota>,
+}
+
+/// 单个资源的速率限制配额
+#[derive(Debug, Clone, Deserialize)]
+pub struct RateLimitQuota {
+    /// 配额上限
+    pub limit: u64,
+    /// 已使用的配额
+    pub used: u64,
+    /// 剩余配额
+    pub remaining: u64,
+    /// 配额重置时间（Unix 时间戳，秒）
+    pub reset: i64,
+}
+
 /// Pull Request 文件信息
 #[derive(Debug, Deserialize)]
 pub struct PullRequestFile {
@@ -77,3 +107,127 @@ pub struct PullRequestFile {
     #[serde(default)]
     pub patch: Option<String>,
 }
+
+/// Pull Request 的合并相关信息（`GET /repos/{owner}/{repo}/pulls/{number}`）
+#[derive(Debug, Deserialize)]
+pub struct PullRequestMergeability {
+    pub state: String,
+    #[serde(default)]
+    pub merged: bool,
+    /// 是否可以合并（GitHub 在后台计算，计算完成前为 `null`）
+    pub mergeable: Option<bool>,
+    /// 可合并状态：clean、blocked、behind、dirty、unstable、draft 等
+    pub mergeable_state: Option<String>,
+    pub head: PullRequestCommit,
+    pub base: PullRequestBranch,
+}
+
+/// Pull Request 的 head 提交
+#[derive(Debug, Deserialize)]
+pub struct PullRequestCommit {
+    pub sha: String,
+}
+
+/// PR 的 head 提交（用于添加评审评论）
+#[derive(Debug, Deserialize)]
+pub struct PullRequestHeadCommit {
+    pub head: PullRequestCommit,
+}
+
+/// Check run 列表（`GET /repos/{owner}/{repo}/commits/{ref}/check-runs`）
+#[derive(Debug, Deserialize)]
+pub struct CheckRunList {
+    #[serde(default)]
+    pub check_runs: Vec<CheckRun>,
+}
+
+/// Check run
+#[derive(Debug, Deserialize)]
+pub struct CheckRun {
+    pub name: String,
+    /// queued、in_progress、completed
+    pub status: String,
+    /// success、failure、neutral、cancelled、skipped、timed_out、action_required 等
+    pub conclusion: Option<String>,
+}
+
+/// 提交的组合状态（`GET /repos/{owner}/{repo}/commits/{ref}/status`）
+#[derive(Debug, Deserialize)]
+pub struct CombinedStatus {
+    #[serde(default)]
+    pub statuses: Vec<CommitStatus>,
+}
+
+/// Commit status
+#[derive(Debug, Deserialize)]
+pub struct CommitStatus {
+    pub context: String,
+    /// success、failure、error、pending
+    pub state: String,
+}
+
+/// 分支信息（`GET /repos/{owner}/{repo}/branches/{branch}`）
+#[derive(Debug, Deserialize)]
+pub struct BranchInfo {
+    pub name: String,
+    #[serde(default)]
+    pub protected: bool,
+}
+
+/// PR 的源分支及其所在仓库（用于合并后删除源分支）
+#[derive(Debug, Deserialize)]
+pub struct PullRequestHead {
+    pub head: PullRequestHeadRef,
+}
+
+/// PR 源分支
+#[derive(Debug, Deserialize)]
+pub struct PullRequestHeadRef {
+    #[serde(rename = "ref")]
+    pub ref_name: String,
+    /// 源分支所在仓库（仓库已删除时为 `null`）
+    pub repo: Option<HeadRepository>,
+}
+
+/// 源分支所在仓库
+#[derive(Debug, Deserialize)]
+pub struct HeadRepository {
+    pub full_name: String,
+    pub default_branch: Option<String>,
+}
+
+/// 分支保护规则（`GET /repos/{owner}/{repo}/branches/{branch}/protection`）
+#[derive(Debug, Deserialize)]
+pub struct BranchProtection {
+    pub required_status_checks: Option<RequiredStatusChecks>,
+    pub required_pull_request_reviews: Option<RequiredPullRequestReviews>,
+    pub required_linear_history: Option<ProtectionSetting>,
+}
+
+/// 必需的状态检查
+#[derive(Debug, Deserialize)]
+pub struct RequiredStatusChecks {
+    #[serde(default)]
+    pub contexts: Vec<String>,
+}
+
+/// 必需的评审
+#[derive(Debug, Deserialize)]
+pub struct RequiredPullRequestReviews {
+    #[serde(default)]
+    pub required_approving_review_count: u32,
+}
+
+/// 开关类型的保护规则
+#[derive(Debug, Deserialize)]
+pub struct ProtectionSetting {
+    pub enabled: bool,
+}
+
+/// Pull Request 评审（`GET /repos/{owner}/{repo}/pulls/{number}/reviews`）
+#[derive(Debug, Deserialize)]
+pub struct PullRequestReview {
+    pub user: Option<GitHubUser>,
+    /// APPROVED、CHANGES_REQUESTED、COMMENTED、DISMISSED、PENDING
+    pub state: String,
+}
@@ -0,0 +1,85 @@
+//! GitHub API Token 校验
+//!
+//! 添加或更新 GitHub 账号时调用 `/user` 端点校验 token，
+//! 并根据响应头 `X-OAuth-Scopes` 检查 token 是否具备 workflow 需要的权限。
+//! Fine-grained token 不返回该响应头，此时无法检查权限范围。
+
+use super::responses::GitHubUser;
+
+/// workflow 需要的 token 权限范围
+pub const REQUIRED_TOKEN_SCOPES: &[&str] = &["repo", "read:org"];
+
+/// 返回 token 权限范围的响应头
+pub const OAUTH_SCOPES_HEADER: &str = "x-oauth-scopes";
+
+/// Token 校验结果
+#[derive(Debug)]
+pub struct GitHubTokenInfo {
+    /// token 对应的 GitHub 用户
+    pub user: GitHubUser,
+    /// token 的权限范围（响应中没有 `X-OAuth-Scopes` 时为 `None`，如 fine-grained token）
+    pub scopes: Option<Vec<String>>,
+}
+
+impl GitHubTokenInfo {
+    /// 获取缺少的必需权限范围
+    ///
+    /// 无法获取权限范围时返回空列表。
+    pub fn missing_scopes(&self) -> Vec<&'static str> {
+        match &self.scopes {
+            Some(scopes) => missing_scopes(scopes),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// 解析 `X-OAuth-Scopes` 响应头
+///
+/// # 示例
+///
+/// ```
+/// use workflow::pr::github::token::parse_oauth_scopes;
+///
+/// assert_eq!(parse_oauth_scopes("repo, read:org"), vec!["repo", "read:org"]);
+/// assert!(parse_oauth_scopes("").is_empty());
+/// ```
+pub fn parse_oauth_scopes(header: &str) -> Vec<String> {
+    header
+        .split(',')
+        .map(str::trim)
+        .filter(|scope| !scope.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// 检查缺少的必需权限范围
+///
+/// 更高级别的权限包含较低级别的权限（如 `admin:org`、`write:org` 包含 `read:org`）。
+///
+/// # 参数
+///
+/// * `scopes` - token 已授予的权限范围
+///
+/// # 返回
+///
+/// 返回 `REQUIRED_TOKEN_SCOPES` 中未被授予的权限范围。
+pub fn missing_scopes(scopes: &[String]) -> Vec<&'static str> {
+    REQUIRED_TOKEN_SCOPES
+        .iter()
+        .copied()
+        .filter(|required| !scopes.iter().any(|scope| scope_satisfies(scope, required)))
+        .collect()
+}
+
+/// 判断已授予的权限范围是否满足所需权限
+fn scope_satisfies(granted: &str, required: &str) -> bool {
+    if granted == required {
+        return true;
+    }
+    match required.split_once(':') {
+        Some(("read", resource)) => {
+            granted == format!("write:{}", resource) || granted == format!("admin:{}", resource)
+        }
+        _ => false,
+    }
+}
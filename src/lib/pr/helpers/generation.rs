@@ -1,8 +1,9 @@
 //! 内容生成相关辅助函数
 //!
-//! 提供生成 commit 标题和 PR body 的函数。
+//! 提供生成 commit 标题、commit 消息和 PR body 的函数。
 
 use crate::base::settings::Settings;
+use crate::commit::ConventionalCommit;
 use crate::template::{
     ChangeTypeItem, CommitTemplateVars, CommitTemplates, PullRequestTemplateVars,
     PullRequestsTemplates, TemplateConfig, TemplateEngine,
@@ -13,19 +14,44 @@ use super::super::platform::TYPES_OF_CHANGES;
 
 /// 生成 PR body（使用模板系统）
 ///
+/// 提供仓库 PR 模板（如 `.github/PULL_REQUEST_TEMPLATE.md`）时，以该模板为基础，
+/// 将描述、变更类型和 Jira 链接填入模板对应的章节；否则使用 workflow 的 PR 模板渲染。
+///
 /// # Arguments
 /// * `selected_change_types` - 选中的变更类型数组
 /// * `short_description` - 简短描述（可选）
 /// * `jira_ticket` - Jira ticket ID（可选）
 /// * `dependency` - 依赖信息（可选）
 /// * `jira_info` - Optional JIRA issue information (for template variables)
+/// * `repo_template` - 仓库 PR 模板内容（可选）
 pub fn generate_pull_request_body(
     selected_change_types: &[bool],
     short_description: Option<&str>,
     jira_ticket: Option<&str>,
     dependency: Option<&str>,
     jira_info: Option<&crate::jira::JiraIssue>,
+    repo_template: Option<&str>,
 ) -> Result<String> {
+    if let Some(template) = repo_template {
+        let jira_link =
+            jira_ticket.map(
+                |key| match Settings::get().jira.service_address.as_deref() {
+                    Some(address) if !address.is_empty() => format!("{}/browse/{}", address, key),
+                    _ => key.to_string(),
+                },
+            );
+        let mut body = fill_pull_request_template(
+            template,
+            selected_change_types,
+            short_description,
+            jira_link.as_deref(),
+        );
+        if let Some(dependency) = dependency {
+            body.push_str(&format!("\n{}", dependency));
+        }
+        return Ok(body);
+    }
+
     // Load PR template
     let template_str = TemplateConfig::load_pull_request_template().unwrap_or_else(|_| {
         // Fallback to default template if loading fails
@@ -64,6 +90,174 @@ pub fn generate_pull_request_body(
         .wrap_err("Failed to render PR body template")
 }
 
+/// 仓库 PR 模板中可填充的章节
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TemplateSection {
+    /// 描述（如 `## Description`、`## Summary`）
+    Description,
+    /// 变更类型（如 `## Type of change`）
+    ChangeTypes,
+    /// 关联 ticket（如 `## Related issue`、`## Jira`）
+    Jira,
+}
+
+impl TemplateSection {
+    /// 根据 Markdown 标题判断章节类型
+    fn from_heading(line: &str) -> Option<Self> {
+        let heading = line.trim_start_matches('#').trim().to_lowercase();
+        if heading.contains("type") {
+            Some(Self::ChangeTypes)
+        } else if ["jira", "ticket", "issue", "related"].iter().any(|k| heading.contains(k)) {
+            Some(Self::Jira)
+        } else if ["description", "summary", "what", "overview", "changes"]
+            .iter()
+            .any(|k| heading.contains(k))
+        {
+            Some(Self::Description)
+        } else {
+            None
+        }
+    }
+}
+
+/// 将生成的内容填入仓库 PR 模板
+///
+/// 按 Markdown 标题识别模板章节，每类章节只填充第一个匹配的标题：
+/// - 描述章节：在标题后插入描述
+/// - 变更类型章节：模板中已有复选框时勾选匹配的项，否则插入变更类型列表
+/// - 关联 ticket 章节：在标题后插入 Jira 链接
+///
+/// 模板中的其他内容（说明注释、检查清单等）保持不变。模板没有描述或关联 ticket 章节时，
+/// 对应内容插入到模板开头。
+///
+/// # Arguments
+/// * `template` - 仓库 PR 模板内容
+/// * `selected_change_types` - 选中的变更类型数组
+/// * `short_description` - 简短描述（可选）
+/// * `jira_link` - Jira 链接或 ticket ID（可选）
+pub fn fill_pull_request_template(
+    template: &str,
+    selected_change_types: &[bool],
+    short_description: Option<&str>,
+    jira_link: Option<&str>,
+) -> String {
+    let selected_names: Vec<&str> = TYPES_OF_CHANGES
+        .iter()
+        .zip(selected_change_types)
+        .filter(|(_, &selected)| selected)
+        .map(|(name, _)| *name)
+        .collect();
+
+    let lines: Vec<&str> = template.lines().collect();
+    let mut output: Vec<String> = Vec::new();
+    let mut filled: Vec<TemplateSection> = Vec::new();
+    let mut in_code_block = false;
+    let mut index = 0;
+
+    while index < lines.len() {
+        let line = lines[index];
+        index += 1;
+
+        if line.trim_start().starts_with("```") {
+            in_code_block = !in_code_block;
+        }
+        output.push(line.to_string());
+
+        let section = match TemplateSection::from_heading(line) {
+            Some(section) if !in_code_block && line.starts_with('#') => section,
+            _ => continue,
+        };
+        if filled.contains(&section) {
+            continue;
+        }
+
+        // 章节正文：直到下一个标题
+        let body_end = lines[index..]
+            .iter()
+            .position(|l| l.starts_with('#'))
+            .map(|offset| index + offset)
+            .unwrap_or(lines.len());
+        let body = &lines[index..body_end];
+
+        match section {
+            TemplateSection::Description => {
+                let Some(description) = short_description else {
+                    continue;
+                };
+                output.push(String::new());
+                output.push(description.to_string());
+            }
+            TemplateSection::Jira => {
+                let Some(link) = jira_link else {
+                    continue;
+                };
+                output.push(String::new());
+                output.push(link.to_string());
+            }
+            TemplateSection::ChangeTypes => {
+                if body.iter().any(|l| is_checkbox(l)) {
+                    output.extend(body.iter().map(|l| check_matching_box(l, &selected_names)));
+                    index = body_end;
+                } else {
+                    output.push(String::new());
+                    output.extend(TYPES_OF_CHANGES.iter().enumerate().map(|(i, name)| {
+                        let selected = selected_change_types.get(i).copied().unwrap_or(false);
+                        format!("- [{}] {}", if selected { "x" } else { " " }, name)
+                    }));
+                }
+            }
+        }
+        filled.push(section);
+    }
+
+    // 模板中没有对应章节的内容插入到开头
+    let mut header: Vec<String> = Vec::new();
+    if let Some(description) = short_description {
+        if !filled.contains(&TemplateSection::Description) {
+            header.push(description.to_string());
+            header.push(String::new());
+        }
+    }
+    if let Some(link) = jira_link {
+        if !filled.contains(&TemplateSection::Jira) {
+            header.push(format!("Jira: {}", link));
+            header.push(String::new());
+        }
+    }
+
+    header.extend(output);
+    let mut body = header.join("\n");
+    body.push('\n');
+    body
+}
+
+/// 是否为 Markdown 复选框（`- [ ] xxx` 或 `- [x] xxx`）
+fn is_checkbox(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    ["- [ ]", "- [x]", "- [X]", "* [ ]", "* [x]", "* [X]"]
+        .iter()
+        .any(|prefix| trimmed.starts_with(prefix))
+}
+
+/// 勾选与选中的变更类型匹配的复选框
+///
+/// 使用变更类型的简称（括号前的部分，如 `Bug fix`）进行不区分大小写的匹配。
+fn check_matching_box(line: &str, selected_names: &[&str]) -> String {
+    if !is_checkbox(line) {
+        return line.to_string();
+    }
+    let text = line.to_lowercase();
+    let matched = selected_names.iter().any(|name| {
+        let short_name = name.split(" (").next().unwrap_or(name).to_lowercase();
+        text.contains(&short_name)
+    });
+    if matched {
+        line.replacen("[ ]", "[x]", 1)
+    } else {
+        line.to_string()
+    }
+}
+
 /// 生成 commit 标题（使用模板系统）
 ///
 /// # Arguments
@@ -102,6 +296,8 @@ pub fn generate_commit_title(
         body: body.map(|s| s.to_string()),
         jira_key: jira_ticket.map(|s| s.to_string()),
         use_scope: config.commit.use_scope,
+        refs: None,
+        co_authors: Vec::new(),
     };
 
     // Render template
@@ -110,3 +306,48 @@ pub fn generate_commit_title(
         .render_string(&template_str, &vars)
         .wrap_err("Failed to render commit title template")
 }
+
+/// 生成完整的 commit 消息（标题 + 使用模板渲染的正文）
+///
+/// 正文使用 `template.commit.body` 模板渲染，默认包含 `body`、`Refs: <ticket>` 和
+/// `Co-authored-by:` trailer。启用 `template.commit.validate_conventional` 时，
+/// 校验渲染后的消息是否符合 Conventional Commits。
+///
+/// # Arguments
+/// * `title` - commit 标题（为 Conventional Commits 格式时，解析出的类型、作用域和描述也会传给模板）
+/// * `body` - Optional commit body
+/// * `refs` - 引用的 ticket（如从当前分支提取的 Jira ticket）
+/// * `co_authors` - 共同作者（`Name <email>`）
+pub fn generate_commit_message(
+    title: &str,
+    body: Option<&str>,
+    refs: Option<&str>,
+    co_authors: &[String],
+) -> Result<String> {
+    let config = TemplateConfig::load().unwrap_or_default();
+
+    let header = ConventionalCommit::parse(title);
+    let vars = CommitTemplateVars {
+        commit_type: header.as_ref().map(|h| h.commit_type.clone()).unwrap_or_default(),
+        scope: header.as_ref().and_then(|h| h.scope.clone()),
+        subject: header.map(|h| h.subject).unwrap_or_else(|| title.trim().to_string()),
+        body: body.map(|s| s.trim().to_string()).filter(|s| !s.is_empty()),
+        jira_key: None,
+        use_scope: config.commit.use_scope,
+        refs: refs.map(|s| s.to_string()),
+        co_authors: co_authors.to_vec(),
+    };
+
+    let rendered_body = TemplateEngine::new()
+        .render_string(&config.commit.body, &vars)
+        .wrap_err("Failed to render commit body template")?;
+    let message = match rendered_body.trim() {
+        "" => title.trim().to_string(),
+        rendered_body => format!("{}\n\n{}", title.trim(), rendered_body),
+    };
+
+    if config.commit.validate_conventional {
+        ConventionalCommit::validate(&message)?;
+    }
+    Ok(message)
+}
@@ -1,10 +1,15 @@
 //! PR ID 解析相关辅助函数
 //!
-//! 提供解析和获取 PR ID 的函数。
+//! 提供解析和获取 PR ID 的函数（参数、当前分支的 PR 或交互式选择）。
 
+use std::io::{self, IsTerminal};
+
+use crate::base::dialog::SelectDialog;
 use crate::git::{GitRepo, RepoType};
-use crate::pr::platform::create_provider_auto;
-use color_eyre::Result;
+use crate::pr::list_filter::{PrListFilter, PrListSort};
+use crate::pr::platform::{create_provider_auto, PlatformProvider};
+use crate::pr::table::PullRequestRow;
+use color_eyre::{eyre::WrapErr, Result};
 
 /// 获取当前分支的 PR ID
 ///
@@ -18,10 +23,17 @@ pub fn get_current_branch_pr_id() -> Result<Option<String>> {
     provider.get_current_branch_pull_request()
 }
 
-/// 解析 PR ID（从参数或当前分支）
+/// 交互式选择时最多列出的 PR 数量
+const PICKER_LIMIT: usize = 20;
+
+/// 解析 PR ID（从参数、当前分支或交互式选择）
 ///
-/// 如果提供了 `pull_request_id`，直接返回。
-/// 否则，尝试从当前分支自动检测 PR ID。
+/// 优先级：
+/// 1. 提供了 `pull_request_id` 时直接返回
+/// 2. 当前分支有关联的 PR 时返回该 PR
+/// 3. 交互式终端中列出最近更新的 open PR，通过选择对话框选择
+///
+/// 非交互式环境（stdin 或 stdout 不是终端）中当前分支没有 PR 时直接报错。
 ///
 /// # 参数
 ///
@@ -33,24 +45,104 @@ pub fn get_current_branch_pr_id() -> Result<Option<String>> {
 ///
 /// # 错误
 ///
-/// 如果无法自动检测 PR ID 且未提供参数，返回错误。
+/// 如果无法自动检测 PR ID、未提供参数且无法交互式选择，返回错误。
 pub fn resolve_pull_request_id(pull_request_id: Option<String>) -> Result<String> {
     if let Some(id) = pull_request_id {
         return Ok(id);
     }
 
     let provider = create_provider_auto()?;
-    match provider.get_current_branch_pull_request()? {
-        Some(id) => Ok(id),
-        None => {
-            let repo_type = GitRepo::detect_repo_type()?;
-            let error_msg = match repo_type {
-                RepoType::GitHub => "No PR found for current branch. Please specify PR ID.",
-                RepoType::Codeup | RepoType::Unknown => {
-                    "Unsupported repository type. Only GitHub is currently supported."
-                }
-            };
-            color_eyre::eyre::bail!("{}", error_msg);
-        }
+    resolve_pull_request_id_with(
+        None,
+        || provider.get_current_branch_pull_request(),
+        || {
+            if !io::stdin().is_terminal() || !io::stdout().is_terminal() {
+                bail_no_pull_request()?;
+            }
+            pick_open_pull_request(provider.as_ref())
+        },
+    )
+}
+
+/// 按优先级解析 PR ID（参数 > 当前分支的 PR > 交互式选择）
+///
+/// 只有前一个来源没有结果时才会调用下一个来源，`resolve_pull_request_id` 使用平台 API 和选择对话框调用此函数。
+///
+/// # 参数
+///
+/// * `pull_request_id` - 可选的 PR ID（从命令行参数传入）
+/// * `current_branch` - 获取当前分支关联的 PR ID
+/// * `pick` - 交互式选择 PR ID（非交互式环境中应返回错误）
+///
+/// # 示例
+///
+/// ```
+/// use workflow::pr::helpers::resolution::resolve_pull_request_id_with;
+///
+/// let id = resolve_pull_request_id_with(None, || Ok(Some("42".to_string())), || {
+///     unreachable!("picker is not used when the branch has a PR")
+/// });
+/// assert_eq!(id.unwrap(), "42");
+/// ```
+pub fn resolve_pull_request_id_with(
+    pull_request_id: Option<String>,
+    current_branch: impl FnOnce() -> Result<Option<String>>,
+    pick: impl FnOnce() -> Result<String>,
+) -> Result<String> {
+    if let Some(id) = pull_request_id {
+        return Ok(id);
+    }
+    if let Some(id) = current_branch()? {
+        return Ok(id);
+    }
+    pick()
+}
+
+/// 列出最近更新的 open PR，通过选择对话框选择一个（显示编号、标题和创建者）
+fn pick_open_pull_request(provider: &dyn PlatformProvider) -> Result<String> {
+    let filter = PrListFilter {
+        state: Some("open".to_string()),
+        sort: PrListSort::Updated,
+        limit: Some(PICKER_LIMIT),
+        ..Default::default()
+    };
+    let rows = provider.get_pull_requests(&filter).wrap_err("Failed to list open PRs")?;
+    if rows.is_empty() {
+        color_eyre::eyre::bail!(
+            "No PR found for current branch and no open PRs in this repository. Please specify PR ID."
+        );
     }
+
+    let items: Vec<(String, String)> =
+        rows.into_iter().map(|row| (pull_request_label(&row), row.number)).collect();
+
+    SelectDialog::new_with_items(
+        "No PR found for current branch. Select a pull request",
+        items,
+    )
+    .with_default(0)
+    .prompt()
+    .wrap_err("Failed to select pull request")
+}
+
+/// 选择对话框中的 PR 显示文本，格式：`#12 Add login page (alice)`
+fn pull_request_label(row: &PullRequestRow) -> String {
+    if row.author == "N/A" {
+        format!("#{} {}", row.number, row.title)
+    } else {
+        format!("#{} {} ({})", row.number, row.title, row.author)
+    }
+}
+
+/// 当前分支没有 PR 且无法交互式选择时的错误
+fn bail_no_pull_request() -> Result<()> {
+    let error_msg = match GitRepo::detect_repo_type()? {
+        RepoType::GitHub | RepoType::AzureDevOps => {
+            "No PR found for current branch. Please specify PR ID."
+        }
+        RepoType::Codeup | RepoType::Unknown => {
+            "Unsupported repository type. Only GitHub and Azure DevOps are currently supported."
+        }
+    };
+    color_eyre::eyre::bail!("{}", error_msg);
 }
@@ -8,14 +8,27 @@ use color_eyre::{
 };
 use regex::Regex;
 
+use crate::base::settings::Settings;
+use crate::git::github;
+
 /// 从 PR URL 提取 PR ID
 ///
+/// 优先匹配 `/pull/<id>`（GitHub，包括 GitHub Enterprise Server）和 `/pullrequest/<id>`（Azure DevOps），
+/// 否则使用 URL 中第一个纯数字的路径段，与主机名无关。
+///
 /// # 示例
 /// ```
 /// use workflow::pr::helpers::url::extract_pull_request_id_from_url;
 /// assert_eq!(extract_pull_request_id_from_url("https://github.com/owner/repo/pull/123").unwrap(), "123".to_string());
+/// assert_eq!(extract_pull_request_id_from_url("https://github.mycorp.com/2024/repo/pull/7").unwrap(), "7".to_string());
 /// ```
 pub fn extract_pull_request_id_from_url(url: &str) -> Result<String> {
+    let pull_re =
+        Regex::new(r"/(?:pull|pullrequest)/(\d+)(?:[/?#]|$)").wrap_err("Invalid regex pattern")?;
+    if let Some(id) = pull_re.captures(url).and_then(|caps| caps.get(1)) {
+        return Ok(id.as_str().to_string());
+    }
+
     let re = Regex::new(r"/(\d+)(?:/|$)").wrap_err("Invalid regex pattern")?;
     let caps = re.captures(url).wrap_err("Failed to extract PR ID from URL")?;
 
@@ -28,7 +41,8 @@ pub fn extract_pull_request_id_from_url(url: &str) -> Result<String> {
 
 /// 从 Git remote URL 提取 GitHub 仓库的 owner/repo
 ///
-/// 支持标准格式和 SSH host 别名格式（如 github-brainim）
+/// 支持标准格式、SSH host 别名格式（如 github-brainim），以及 `github.web_base_url`
+/// 配置的 GitHub Enterprise Server 主机（见 `extract_github_repo_from_url_with_host()`）。
 ///
 /// # 示例
 /// ```
@@ -38,6 +52,35 @@ pub fn extract_pull_request_id_from_url(url: &str) -> Result<String> {
 /// assert_eq!(extract_github_repo_from_url("https://github.com/owner/repo.git").unwrap(), "owner/repo");
 /// ```
 pub fn extract_github_repo_from_url(url: &str) -> Result<String> {
+    let enterprise_host = Settings::get().github.enterprise_host();
+    extract_github_repo_from_url_with_host(url, enterprise_host.as_deref())
+}
+
+/// 从 Git remote URL 提取 GitHub 仓库的 owner/repo（指定 GitHub Enterprise Server 主机）
+///
+/// 先匹配 `enterprise_host`（SSH、`ssh://` 和 HTTPS 格式，允许端口），再匹配 `github.com`。
+///
+/// # 参数
+///
+/// * `url` - Git remote URL
+/// * `enterprise_host` - GitHub Enterprise Server 主机名（如 `github.mycorp.com`）
+///
+/// # 示例
+/// ```
+/// use workflow::pr::helpers::url::extract_github_repo_from_url_with_host;
+/// let host = Some("github.mycorp.com");
+/// assert_eq!(extract_github_repo_from_url_with_host("https://github.mycorp.com/owner/repo.git", host).unwrap(), "owner/repo");
+/// assert_eq!(extract_github_repo_from_url_with_host("git@github.com:owner/repo.git", host).unwrap(), "owner/repo");
+/// ```
+pub fn extract_github_repo_from_url_with_host(
+    url: &str,
+    enterprise_host: Option<&str>,
+) -> Result<String> {
+    if let Some(repo) = enterprise_host.and_then(|host| github::extract_enterprise_repo(url, host))
+    {
+        return Ok(repo);
+    }
+
     // 匹配 SSH 格式: git@github.com:owner/repo.git 或 git@github-xxx:owner/repo.git (支持 SSH host 别名)
     // 使用 git@github[^:]*: 来匹配 git@github 开头的所有 SSH host（包括别名）
     let ssh_re =
@@ -0,0 +1,123 @@
+//! PR 列表筛选条件
+//!
+//! `pr list` 的筛选条件由 `PrListFilter` 描述，各平台通过 `PlatformProvider::get_pull_requests`
+//! 处理：平台 API 支持的条件（状态、目标分支、排序）在服务端筛选，
+//! 其余条件（作者、标签）由 `PrListFilter::matches` 在本地筛选。
+
+use std::fmt;
+
+/// PR 列表排序方式（均为倒序，最新的在前）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PrListSort {
+    /// 按创建时间
+    #[default]
+    Created,
+    /// 按更新时间
+    Updated,
+}
+
+impl fmt::Display for PrListSort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Created => "created",
+            Self::Updated => "updated",
+        })
+    }
+}
+
+/// PR 列表筛选条件
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PrListFilter {
+    /// 状态（open、closed、merged、all；`None` 表示全部）
+    pub state: Option<String>,
+    /// 作者（GitHub 为 login，Azure DevOps 为 unique name 或显示名）
+    pub author: Option<String>,
+    /// 只列出当前用户创建的 PR（当前用户由平台解析，不能与 `author` 同时使用）
+    pub mine: bool,
+    /// 标签
+    pub label: Option<String>,
+    /// 目标分支
+    pub base: Option<String>,
+    /// 排序方式
+    pub sort: PrListSort,
+    /// 返回数量限制（`None` 时使用平台默认值）
+    pub limit: Option<usize>,
+}
+
+impl PrListFilter {
+    /// 未指定 `limit` 时返回的数量
+    pub const DEFAULT_LIMIT: usize = 30;
+
+    /// 本地筛选时最多请求的页数（避免在大仓库中无限翻页）
+    pub const MAX_PAGES: usize = 10;
+
+    /// 返回数量限制
+    pub fn limit(&self) -> usize {
+        self.limit.unwrap_or(Self::DEFAULT_LIMIT)
+    }
+
+    /// 是否有需要在本地筛选的条件（作者、标签）
+    pub fn needs_local_filter(&self) -> bool {
+        self.author.is_some() || self.label.is_some()
+    }
+
+    /// 判断 PR 是否满足状态条件
+    ///
+    /// `open`、`closed` 按平台返回的状态匹配（`closed` 包含已合并的 PR），
+    /// `merged` 只匹配已合并的 PR，`all`、未指定或无法识别的状态全部匹配。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::pr::PrListFilter;
+    ///
+    /// let merged = PrListFilter {
+    ///     state: Some("merged".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert!(merged.matches_state("closed", true));
+    /// assert!(!merged.matches_state("closed", false));
+    /// assert!(PrListFilter::default().matches_state("open", false));
+    /// ```
+    pub fn matches_state(&self, state: &str, merged: bool) -> bool {
+        match self.state.as_deref() {
+            Some("open") => state.eq_ignore_ascii_case("open"),
+            Some("closed") => state.eq_ignore_ascii_case("closed"),
+            Some("merged") => merged,
+            _ => true,
+        }
+    }
+
+    /// 判断 PR 是否满足作者和标签条件（不区分大小写）
+    ///
+    /// # 参数
+    ///
+    /// * `authors` - PR 作者的所有名称（如 login、unique name、显示名），任意一个匹配即可
+    /// * `labels` - PR 的标签
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::pr::PrListFilter;
+    ///
+    /// let filter = PrListFilter {
+    ///     author: Some("Alice".to_string()),
+    ///     label: Some("bug-fix".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// assert!(filter.matches(&["alice"], &["Bug-Fix", "feature"]));
+    /// assert!(!filter.matches(&["bob"], &["bug-fix"]));
+    /// assert!(!filter.matches(&["alice"], &[]));
+    /// ```
+    pub fn matches(&self, authors: &[&str], labels: &[&str]) -> bool {
+        let author_matches = self
+            .author
+            .as_deref()
+            .is_none_or(|author| authors.iter().any(|name| name.eq_ignore_ascii_case(author)));
+        let label_matches = self
+            .label
+            .as_deref()
+            .is_none_or(|label| labels.iter().any(|name| name.eq_ignore_ascii_case(label)));
+        author_matches && label_matches
+    }
+}
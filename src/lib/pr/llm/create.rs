@@ -1,6 +1,9 @@
 //! PR 创建内容生成
 //!
 //! 用于创建 PR 时生成分支名、PR 标题、描述和 scope。
+//!
+//! - `generate` - 根据 commit 标题（通常来自 Jira ticket 或用户输入）和工作区修改生成
+//! - `generate_from_commits` - 根据分支上已有的 commit 消息和分支 diff 生成（`pr create --from-commits`，不需要 Jira ticket）
 
 use color_eyre::{
     eyre::{ContextCompat, WrapErr},
@@ -9,10 +12,11 @@ use color_eyre::{
 use serde_json::Value;
 
 use crate::base::llm::{LLMClient, LLMRequestParams};
-use crate::base::prompt::GENERATE_BRANCH_SYSTEM_PROMPT;
+use crate::base::prompt::generate_branch_system_prompt;
 use crate::branch::BranchNaming;
+use crate::commit::ConventionalCommit;
 
-use super::helpers::extract_json_from_markdown;
+use super::helpers::{extract_json_from_markdown, prepare_diff, DiffChunking, PreparedDiff};
 
 /// PR 内容，包含分支名、PR 标题、描述和 scope
 ///
@@ -30,6 +34,10 @@ pub struct PullRequestContent {
     /// Scope 表示变更涉及的模块或功能区域，例如 "api", "auth", "jira" 等。
     /// 如果无法确定 scope，此字段为 `None`。
     pub scope: Option<String>,
+    /// git diff 超出预算时的分块处理信息
+    ///
+    /// 不为 `None` 时，描述和 scope 基于按文件生成的修改总结，而不是完整的 diff。
+    pub chunking: Option<DiffChunking>,
 }
 
 /// PR 创建内容生成器
@@ -54,6 +62,7 @@ impl CreateGenerator {
     /// - `pr_title` - PR 标题（简洁，不超过 8 个单词）
     /// - `description` - PR 描述（基于 Git 修改内容生成，可选）
     /// - `scope` - Commit scope（从 git diff 提取，用于 Conventional Commits 格式，可选）
+    /// - `chunking` - git diff 超出预算、按文件分块总结时的分块信息
     ///
     /// # 错误
     ///
@@ -62,14 +71,125 @@ impl CreateGenerator {
         commit_title: &str,
         exists_branches: Option<Vec<String>>,
         git_diff: Option<String>,
+    ) -> Result<PullRequestContent> {
+        // 超出预算的 diff 按文件总结后再使用
+        let prepared_diff = Self::prepare_git_diff(git_diff)?;
+
+        // 构建请求参数
+        let user_prompt = Self::user_prompt(commit_title, exists_branches, prepared_diff.as_ref());
+        Self::request(user_prompt, prepared_diff).wrap_err_with(|| {
+            format!(
+                "Failed to generate branch name from commit title: '{}'",
+                commit_title
+            )
+        })
+    }
+
+    /// 根据分支上的 commit 消息和分支 diff 生成 PR 标题、描述和 scope（通过一个 LLM 请求）
+    ///
+    /// 用于不使用 Jira 的仓库：不需要 ticket 或 commit 标题，直接以 commit 消息作为主要输入。
+    /// 返回的 `branch_name` 为 LLM 建议的分支名，在已有分支上创建 PR 时可以忽略。
+    ///
+    /// # 参数
+    ///
+    /// * `commit_messages` - 分支上的 commit 消息（按时间顺序，从旧到新）
+    /// * `git_diff` - 分支相对于目标分支的修改内容（可选，用于生成描述和提取 scope）
+    ///
+    /// # 错误
+    ///
+    /// 如果没有 commit 消息、LLM API 调用失败或响应格式不正确，返回相应的错误信息。
+    pub fn generate_from_commits(
+        commit_messages: &[String],
+        git_diff: Option<String>,
+    ) -> Result<PullRequestContent> {
+        if commit_messages.is_empty() {
+            color_eyre::eyre::bail!("No commit messages to generate PR content from");
+        }
+
+        let prepared_diff = Self::prepare_git_diff(git_diff)?;
+        let user_prompt = Self::commits_user_prompt(commit_messages, prepared_diff.as_ref());
+        Self::request(user_prompt, prepared_diff).wrap_err_with(|| {
+            format!(
+                "Failed to generate PR content from {} commit(s)",
+                commit_messages.len()
+            )
+        })
+    }
+
+    /// 不调用 LLM，直接根据 commit 消息生成 PR 内容（LLM 不可用时的回退）
+    ///
+    /// - 标题：第一个 commit 的标题（符合 Conventional Commits 时去掉类型和 scope）
+    /// - scope：第一个 commit 的 scope（如果有）
+    /// - 描述：多个 commit 时为所有 commit 标题的列表
+    ///
+    /// 没有 commit 消息时返回 `None`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::pr::llm::CreateGenerator;
+    ///
+    /// let messages = vec![
+    ///     "feat(api): add retry support\n\nRetry 429 responses.".to_string(),
+    ///     "fix typo in retry docs".to_string(),
+    /// ];
+    /// let content = CreateGenerator::from_commit_messages(&messages).unwrap();
+    /// assert_eq!(content.pr_title, "add retry support");
+    /// assert_eq!(content.scope.as_deref(), Some("api"));
+    /// assert_eq!(
+    ///     content.description.as_deref(),
+    ///     Some("- feat(api): add retry support\n- fix typo in retry docs")
+    /// );
+    /// ```
+    pub fn from_commit_messages(commit_messages: &[String]) -> Option<PullRequestContent> {
+        let subjects: Vec<&str> = commit_messages
+            .iter()
+            .filter_map(|message| message.lines().next())
+            .map(str::trim)
+            .filter(|subject| !subject.is_empty())
+            .collect();
+        let first = *subjects.first()?;
+
+        let (pr_title, scope) = match ConventionalCommit::parse(first) {
+            Some(commit) => (commit.subject, commit.scope),
+            None => (first.to_string(), None),
+        };
+        let description = (subjects.len() > 1).then(|| {
+            subjects
+                .iter()
+                .map(|subject| format!("- {}", subject))
+                .collect::<Vec<_>>()
+                .join("\n")
+        });
+
+        Some(PullRequestContent {
+            branch_name: BranchNaming::slugify(&pr_title),
+            pr_title,
+            description,
+            scope,
+            chunking: None,
+        })
+    }
+
+    /// 按预算处理 git diff（空 diff 视为没有 diff）
+    fn prepare_git_diff(git_diff: Option<String>) -> Result<Option<PreparedDiff>> {
+        git_diff
+            .filter(|diff| !diff.trim().is_empty())
+            .map(|diff| prepare_diff(&diff))
+            .transpose()
+            .wrap_err("Failed to prepare git diff for LLM")
+    }
+
+    /// 发送 LLM 请求并解析响应
+    fn request(
+        user_prompt: String,
+        prepared_diff: Option<PreparedDiff>,
     ) -> Result<PullRequestContent> {
         // 使用统一的 v2 客户端
         let client = LLMClient::global();
 
-        // 构建请求参数
-        let user_prompt = Self::user_prompt(commit_title, exists_branches, git_diff);
-        // 使用编译时嵌入的 system prompt
-        let system_prompt = GENERATE_BRANCH_SYSTEM_PROMPT.to_string();
+        // 优先使用自定义 prompt，未配置时使用编译时嵌入的 system prompt
+        let system_prompt = generate_branch_system_prompt();
 
         let params = LLMRequestParams {
             system_prompt,
@@ -80,27 +200,19 @@ impl CreateGenerator {
         };
 
         // 调用 LLM API
-        let response = client.call(&params).wrap_err_with(|| {
-            format!(
-                "Failed to call LLM API for generating branch name from commit title: '{}'",
-                commit_title
-            )
-        })?;
+        let response = client.call(&params).wrap_err("Failed to call LLM API")?;
 
         // 解析响应
-        Self::parse_llm_response(response).wrap_err_with(|| {
-            format!(
-                "Failed to parse LLM response for commit title: '{}'",
-                commit_title
-            )
-        })
+        let mut content = Self::parse_llm_response(response)?;
+        content.chunking = prepared_diff.and_then(|diff| diff.chunking);
+        Ok(content)
     }
 
     /// 生成同时生成分支名和 PR 标题的 user prompt
     fn user_prompt(
         commit_title: &str,
         exists_branches: Option<Vec<String>>,
-        git_diff: Option<String>,
+        git_diff: Option<&PreparedDiff>,
     ) -> String {
         // 提取分支列表，如果没有或为空则使用空数组
         // 注意：exists_branches 已经通过 get_all_branches(true) 获取，已经去掉了前缀
@@ -127,38 +239,53 @@ impl CreateGenerator {
         }
 
         if let Some(diff) = git_diff {
-            if !diff.trim().is_empty() {
-                // 限制 git diff 长度，避免超过 LLM token 限制
-                // create 主要用于生成标题和描述，不需要完整 diff
-                const MAX_DIFF_LENGTH: usize = 10000; // create 只需要了解主要变更
-                let diff_trimmed = {
-                    let char_count = diff.chars().count();
-                    if char_count > MAX_DIFF_LENGTH {
-                        // 使用字符边界安全截取
-                        let mut char_boundary = diff.len();
-                        for (idx, _) in diff.char_indices().take(MAX_DIFF_LENGTH + 1) {
-                            char_boundary = idx;
-                        }
-                        let truncated = &diff[..char_boundary];
-                        // 尝试在最后一个换行符处截断
-                        let last_newline = truncated.rfind('\n').unwrap_or(0);
-                        let truncated_diff = if last_newline > 0 {
-                            &diff[..last_newline]
-                        } else {
-                            truncated
-                        };
-                        format!(
-                            "{}\n... (git diff truncated, {} characters total)",
-                            truncated_diff, char_count
-                        )
-                    } else {
-                        diff.to_string()
-                    }
-                };
-                parts.push(String::new());
+            parts.push(String::new());
+            if diff.chunking.is_some() {
+                parts.push(
+                    "Git changes (per-file summaries, the full diff is too large; for verification only):"
+                        .to_string(),
+                );
+            } else {
                 parts.push("Git changes (for verification only):".to_string());
-                parts.push(diff_trimmed);
             }
+            parts.push(diff.content.clone());
+        }
+
+        parts.join("\n")
+    }
+
+    /// 生成根据 commit 消息生成 PR 内容的 user prompt
+    fn commits_user_prompt(commit_messages: &[String], git_diff: Option<&PreparedDiff>) -> String {
+        let mut parts =
+            vec!["Commit messages on the branch (PRIMARY INPUT, oldest first):".to_string()];
+        for message in commit_messages {
+            let mut lines = message.trim().lines();
+            parts.push(format!("- {}", lines.next().unwrap_or_default().trim()));
+            parts.extend(
+                lines
+                    .filter(|line| !line.trim().is_empty())
+                    .map(|line| format!("  {}", line.trim())),
+            );
+        }
+        parts.extend([
+            String::new(),
+            "Instructions:".to_string(),
+            "- Generate one PR title that summarizes all commits above".to_string(),
+            "- Describe the overall change in the description, not each commit separately"
+                .to_string(),
+            "- There is no Jira ticket, do not invent one".to_string(),
+        ]);
+
+        if let Some(diff) = git_diff {
+            parts.push(String::new());
+            if diff.chunking.is_some() {
+                parts.push(
+                    "Branch changes (per-file summaries, the full diff is too large):".to_string(),
+                );
+            } else {
+                parts.push("Branch changes:".to_string());
+            }
+            parts.push(diff.content.clone());
         }
 
         parts.join("\n")
@@ -226,6 +353,7 @@ impl CreateGenerator {
             pr_title: pr_title.trim().to_string(),
             description,
             scope,
+            chunking: None,
         })
     }
 }
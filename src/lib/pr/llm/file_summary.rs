@@ -1,13 +1,16 @@
 //! 单个文件修改总结
 //!
-//! 用于生成单个文件的修改总结。
+//! 用于生成单个文件的修改总结。总结结果会写入 [`FileSummaryCache`]，
+//! 相同的文件 diff、模型和 prompt 再次总结时直接使用缓存，不调用 LLM API。
 
 use color_eyre::{eyre::WrapErr, Result};
 
 use crate::base::llm::{LLMClient, LLMRequestParams};
 use crate::base::prompt::generate_summarize_file_change_system_prompt;
+use crate::base::settings::Settings;
 
-use super::helpers::extract_json_from_markdown;
+use super::file_summary_cache::FileSummaryCache;
+use super::helpers::{extract_json_from_markdown, truncate_diff};
 
 /// 文件修改总结生成器
 pub struct FileSummaryGenerator;
@@ -16,6 +19,7 @@ impl FileSummaryGenerator {
     /// 生成单个文件的修改总结
     ///
     /// 根据文件的 diff 内容生成该文件的修改总结。
+    /// 缓存启用时（见 [`FileSummaryCache::set_enabled`]），命中缓存直接返回，未命中时调用 API 并写入缓存。
     ///
     /// # 参数
     ///
@@ -39,6 +43,23 @@ impl FileSummaryGenerator {
         // 根据语言生成 system prompt（语言选择逻辑在 prompt 生成函数内部处理）
         let system_prompt = generate_summarize_file_change_system_prompt();
 
+        // 缓存键包含完整的 prompt，prompt 模板或语言变化时不会命中旧的总结
+        let cache = if FileSummaryCache::is_enabled() {
+            FileSummaryCache::open().ok()
+        } else {
+            None
+        };
+        let cache_key = FileSummaryCache::key(
+            file_path,
+            file_diff,
+            &Settings::get().llm.model(),
+            &format!("{}\n{}", system_prompt, user_prompt),
+        );
+        if let Some(summary) = cache.as_ref().and_then(|cache| cache.get(&cache_key)) {
+            crate::trace_debug!("File summary cache hit: {}", file_path);
+            return Ok(summary);
+        }
+
         let params = LLMRequestParams {
             system_prompt,
             user_prompt,
@@ -58,6 +79,13 @@ impl FileSummaryGenerator {
         // 清理响应（移除可能的 markdown 代码块包装）
         let summary = Self::clean_file_change_summary_response(response);
 
+        // 写入缓存失败不影响总结结果
+        if let Some(cache) = cache {
+            if let Err(e) = cache.put(&cache_key, &summary) {
+                crate::trace_warn!("Failed to cache file summary for '{}': {}", file_path, e);
+            }
+        }
+
         Ok(summary)
     }
 
@@ -65,30 +93,7 @@ impl FileSummaryGenerator {
     fn summarize_file_change_user_prompt(file_path: &str, file_diff: &str) -> String {
         // 限制单个文件的 diff 长度，避免超过 LLM token 限制
         const MAX_FILE_DIFF_LENGTH: usize = 8000; // 单个文件的总结不需要太多上下文
-        let diff_trimmed = {
-            let char_count = file_diff.chars().count();
-            if char_count > MAX_FILE_DIFF_LENGTH {
-                // 使用字符边界安全截取
-                let mut char_boundary = file_diff.len();
-                for (idx, _) in file_diff.char_indices().take(MAX_FILE_DIFF_LENGTH + 1) {
-                    char_boundary = idx;
-                }
-                let truncated = &file_diff[..char_boundary];
-                // 尝试在最后一个换行符处截断
-                let last_newline = truncated.rfind('\n').unwrap_or(0);
-                let truncated_diff = if last_newline > 0 {
-                    &file_diff[..last_newline]
-                } else {
-                    truncated
-                };
-                format!(
-                    "{}\n... (file diff truncated, {} characters total)",
-                    truncated_diff, char_count
-                )
-            } else {
-                file_diff.to_string()
-            }
-        };
+        let diff_trimmed = truncate_diff(file_diff, MAX_FILE_DIFF_LENGTH, "file diff");
         format!("File path: {}\n\nFile diff:\n{}", file_path, diff_trimmed)
     }
 
@@ -0,0 +1,129 @@
+//! 文件修改总结缓存
+//!
+//! 以内容寻址的方式缓存单个文件的修改总结，重新总结同一个 PR 时，未修改的文件直接使用缓存。
+//!
+//! 缓存键为（文件路径、文件 diff、模型、prompt）的 SHA256 哈希。prompt 包含完整的
+//! system prompt 和 user prompt，prompt 模板变化时缓存键随之变化，旧的总结不会被再次使用。
+//!
+//! 缓存文件保存在 `~/.workflow/cache/file-summaries/{key}.txt`。
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use color_eyre::{eyre::WrapErr, Result};
+use sha2::{Digest, Sha256};
+
+use crate::base::settings::paths::Paths;
+use crate::base::util::file::{FileReader, FileWriter};
+
+/// 缓存子目录名称
+const CACHE_DIR_NAME: &str = "file-summaries";
+
+/// 缓存文件扩展名
+const CACHE_FILE_EXTENSION: &str = "txt";
+
+/// 是否启用缓存（`--no-cache` 时关闭）
+static CACHE_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// 文件修改总结缓存
+#[derive(Debug, Clone)]
+pub struct FileSummaryCache {
+    dir: PathBuf,
+}
+
+impl FileSummaryCache {
+    /// 使用指定目录创建缓存
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// 打开默认缓存目录（`~/.workflow/cache/file-summaries/`）
+    pub fn open() -> Result<Self> {
+        Ok(Self::new(Paths::cache_dir()?.join(CACHE_DIR_NAME)))
+    }
+
+    /// 启用或关闭缓存（对当前进程生效）
+    pub fn set_enabled(enabled: bool) {
+        CACHE_ENABLED.store(enabled, Ordering::Relaxed);
+    }
+
+    /// 缓存是否启用
+    pub fn is_enabled() -> bool {
+        CACHE_ENABLED.load(Ordering::Relaxed)
+    }
+
+    /// 缓存目录
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// 计算缓存键
+    ///
+    /// 每个字段前写入长度，避免不同字段组合拼接后产生相同的内容。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::pr::llm::FileSummaryCache;
+    ///
+    /// let key = FileSummaryCache::key("src/main.rs", "+fn main() {}", "gpt-4.0", "prompt");
+    /// assert_eq!(key.len(), 64);
+    /// assert_ne!(key, FileSummaryCache::key("src/main.rs", "+fn main() {}", "gpt-4.0", "prompt v2"));
+    /// ```
+    pub fn key(file_path: &str, file_diff: &str, model: &str, prompt: &str) -> String {
+        let mut hasher = Sha256::new();
+        for field in [file_path, file_diff, model, prompt] {
+            hasher.update((field.len() as u64).to_le_bytes());
+            hasher.update(field.as_bytes());
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// 缓存文件路径
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir.join(format!("{}.{}", key, CACHE_FILE_EXTENSION))
+    }
+
+    /// 读取缓存的总结（未命中或读取失败时返回 `None`）
+    pub fn get(&self, key: &str) -> Option<String> {
+        let path = self.entry_path(key);
+        if !path.is_file() {
+            return None;
+        }
+        FileReader::new(&path).to_string().ok()
+    }
+
+    /// 写入总结
+    pub fn put(&self, key: &str, summary: &str) -> Result<()> {
+        FileWriter::new(self.entry_path(key))
+            .write_str_with_dir(summary)
+            .wrap_err("Failed to write file summary cache")
+    }
+
+    /// 清空缓存
+    ///
+    /// # 返回
+    ///
+    /// 返回删除的缓存条目数，缓存目录不存在时返回 0。
+    pub fn clear(&self) -> Result<usize> {
+        if !self.dir.is_dir() {
+            return Ok(0);
+        }
+
+        let mut removed = 0;
+        for entry in fs::read_dir(&self.dir)
+            .wrap_err_with(|| format!("Failed to read cache directory: {:?}", self.dir))?
+        {
+            let path = entry.wrap_err("Failed to read cache entry")?.path();
+            if path.is_file()
+                && path.extension().and_then(|ext| ext.to_str()) == Some(CACHE_FILE_EXTENSION)
+            {
+                fs::remove_file(&path)
+                    .wrap_err_with(|| format!("Failed to remove cache entry: {:?}", path))?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
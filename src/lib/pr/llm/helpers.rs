@@ -1,6 +1,54 @@
 //! 辅助工具函数
 //!
-//! 提供 LLM 响应解析的公共方法。
+//! 提供 LLM 响应解析和 diff 预算控制的公共方法。
+//!
+//! diff 超出当前 provider 的字符数预算（`llm.<provider>.max_diff_chars`）时，
+//! 按文件拆分并发生成每个文件的修改总结，再以这些总结代替 diff 生成最终内容。
+
+use color_eyre::{eyre::WrapErr, Result};
+
+use crate::base::settings::Settings;
+use crate::base::{ConcurrentExecutor, TaskResult};
+
+use super::file_summary::FileSummaryGenerator;
+
+/// 并发生成文件修改总结的最大并发数
+const MAX_CONCURRENT_FILE_SUMMARIES: usize = 5;
+
+/// 文件任务列表类型别名
+type FileSummaryTasks = Vec<(String, Box<dyn Fn() -> Result<String> + Send + Sync>)>;
+
+/// diff 分块处理信息
+///
+/// diff 超出预算、改为按文件总结时生成，用于提示用户结果基于分层总结。
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiffChunking {
+    /// diff 总字符数
+    pub diff_chars: usize,
+    /// diff 字符数预算
+    pub budget: usize,
+    /// 单独总结的文件数
+    pub files: usize,
+}
+
+impl DiffChunking {
+    /// 分块处理的提示信息
+    pub fn notice(&self) -> String {
+        format!(
+            "Diff exceeds the LLM budget ({} > {} characters), summarized {} file(s) separately and generated the result from the per-file summaries",
+            self.diff_chars, self.budget, self.files
+        )
+    }
+}
+
+/// 按预算处理后的 diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PreparedDiff {
+    /// 发送给 LLM 的内容（diff 原文，或超出预算时的文件修改总结）
+    pub content: String,
+    /// 分块处理信息（未分块时为 `None`）
+    pub chunking: Option<DiffChunking>,
+}
 
 /// 从 markdown 代码块中提取 JSON 字符串（公共方法）
 ///
@@ -34,3 +82,188 @@ pub fn extract_json_from_markdown(response: String) -> String {
         trimmed.to_string()
     }
 }
+
+/// 检查 diff 是否超出字符数预算
+pub fn exceeds_budget(diff: &str, budget: usize) -> bool {
+    diff.chars().count() > budget
+}
+
+/// 按字符数截断 diff
+///
+/// 在字符边界截断，并尽量在最后一个换行符处截断，末尾追加截断说明。
+///
+/// # 参数
+///
+/// * `diff` - diff 内容
+/// * `max_chars` - 最大字符数
+/// * `label` - 截断说明中的内容名称（如 `git diff`）
+pub fn truncate_diff(diff: &str, max_chars: usize, label: &str) -> String {
+    let char_count = diff.chars().count();
+    if char_count <= max_chars {
+        return diff.to_string();
+    }
+
+    let char_boundary =
+        diff.char_indices().nth(max_chars).map(|(idx, _)| idx).unwrap_or(diff.len());
+    let truncated = &diff[..char_boundary];
+    // 尝试在最后一个换行符处截断
+    let truncated = match truncated.rfind('\n') {
+        Some(last_newline) if last_newline > 0 => &truncated[..last_newline],
+        _ => truncated,
+    };
+    format!(
+        "{}\n... ({} truncated, {} characters total)",
+        truncated, label, char_count
+    )
+}
+
+/// 按文件拆分 diff
+///
+/// 以 `diff --git a/... b/...` 行为边界拆分，每个文件块保留完整的头部信息。
+/// 第一个 `diff --git` 行之前的内容会被忽略。
+///
+/// # 返回
+///
+/// 返回 `(文件路径, 文件 diff)` 列表，顺序与 diff 中一致
+pub fn split_diff_by_file(diff: &str) -> Vec<(String, String)> {
+    let mut files: Vec<(String, Vec<&str>)> = Vec::new();
+
+    for line in diff.lines() {
+        if let Some(header) = line.strip_prefix("diff --git ") {
+            // 使用新文件路径（b/ 之后的部分）
+            let path = header
+                .find(" b/")
+                .map(|pos| &header[pos + 3..])
+                .unwrap_or(header)
+                .trim()
+                .to_string();
+            files.push((path, vec![line]));
+        } else if let Some((_, lines)) = files.last_mut() {
+            lines.push(line);
+        }
+    }
+
+    files.into_iter().map(|(path, lines)| (path, lines.join("\n"))).collect()
+}
+
+/// 将文件修改总结格式化为 LLM 输入
+///
+/// 每个文件一节，节标题为文件路径。
+pub fn format_file_summaries(summaries: &[(String, String)]) -> String {
+    summaries
+        .iter()
+        .map(|(path, summary)| format!("### {}\n{}", path, summary.trim()))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// 按当前 provider 的预算处理 diff
+///
+/// 预算从 `Settings` 读取，见 [`prepare_diff_with_budget`]。
+pub fn prepare_diff(diff: &str) -> Result<PreparedDiff> {
+    prepare_diff_with_budget(diff, Settings::get().llm.max_diff_chars())
+}
+
+/// 按指定预算处理 diff
+///
+/// - 未超出预算：原样返回
+/// - 超出预算：按文件拆分，并发生成每个文件的修改总结，返回合并后的总结（仍超出预算时截断）
+/// - 超出预算但无法按文件拆分：直接截断
+///
+/// # 错误
+///
+/// 所有文件的修改总结都生成失败时，返回第一个错误。
+pub fn prepare_diff_with_budget(diff: &str, budget: usize) -> Result<PreparedDiff> {
+    if !exceeds_budget(diff, budget) {
+        return Ok(PreparedDiff {
+            content: diff.to_string(),
+            chunking: None,
+        });
+    }
+
+    let files = split_diff_by_file(diff);
+    if files.is_empty() {
+        return Ok(PreparedDiff {
+            content: truncate_diff(diff, budget, "diff"),
+            chunking: None,
+        });
+    }
+
+    let summaries = summarize_files(&files)?;
+    Ok(PreparedDiff {
+        content: truncate_diff(&format_file_summaries(&summaries), budget, "file summaries"),
+        chunking: Some(DiffChunking {
+            diff_chars: diff.chars().count(),
+            budget,
+            files: files.len(),
+        }),
+    })
+}
+
+/// 并发生成每个文件的修改总结
+///
+/// 单个文件总结失败时使用增删行数代替，结果顺序与输入一致。
+fn summarize_files(files: &[(String, String)]) -> Result<Vec<(String, String)>> {
+    // 使用序号作为任务标识，便于按原顺序整理结果
+    let tasks: FileSummaryTasks = files
+        .iter()
+        .enumerate()
+        .map(|(index, (path, file_diff))| {
+            let path = path.clone();
+            let file_diff = file_diff.clone();
+            let task: Box<dyn Fn() -> Result<String> + Send + Sync> =
+                Box::new(move || FileSummaryGenerator::summarize_file_change(&path, &file_diff));
+            (index.to_string(), task)
+        })
+        .collect();
+
+    let results = ConcurrentExecutor::new(MAX_CONCURRENT_FILE_SUMMARIES)
+        .execute(tasks)
+        .wrap_err("Failed to summarize file changes")?;
+
+    let mut summaries: Vec<Option<String>> = vec![None; files.len()];
+    let mut first_error = None;
+    for (name, result) in results {
+        let Some(slot) = name.parse::<usize>().ok().and_then(|index| summaries.get_mut(index))
+        else {
+            continue;
+        };
+        match result {
+            TaskResult::Success(summary) => *slot = Some(summary),
+            TaskResult::Failure(e) => {
+                first_error.get_or_insert(e);
+            }
+        }
+    }
+
+    if summaries.iter().all(Option::is_none) {
+        if let Some(e) = first_error {
+            return Err(e.wrap_err("Failed to summarize file changes"));
+        }
+    }
+
+    Ok(files
+        .iter()
+        .zip(summaries)
+        .map(|((path, file_diff), summary)| {
+            let summary = summary.unwrap_or_else(|| fallback_file_summary(file_diff));
+            (path.clone(), summary)
+        })
+        .collect())
+}
+
+/// 文件修改总结生成失败时的替代内容（增删行数）
+fn fallback_file_summary(file_diff: &str) -> String {
+    let added = file_diff
+        .lines()
+        .filter(|line| line.starts_with('+') && !line.starts_with("+++"))
+        .count();
+    let removed = file_diff
+        .lines()
+        .filter(|line| line.starts_with('-') && !line.starts_with("---"))
+        .count();
+    format!(
+        "(summary unavailable) {} line(s) added, {} line(s) removed",
+        added, removed
+    )
+}
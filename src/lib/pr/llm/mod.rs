@@ -35,6 +35,7 @@
 
 mod create;
 mod file_summary;
+mod file_summary_cache;
 mod helpers;
 mod reword;
 mod summary;
@@ -42,6 +43,10 @@ mod summary;
 // 重新导出公共 API
 pub use create::{CreateGenerator, PullRequestContent};
 pub use file_summary::FileSummaryGenerator;
-pub use helpers::extract_json_from_markdown;
+pub use file_summary_cache::FileSummaryCache;
+pub use helpers::{
+    exceeds_budget, extract_json_from_markdown, format_file_summaries, prepare_diff,
+    prepare_diff_with_budget, split_diff_by_file, truncate_diff, DiffChunking, PreparedDiff,
+};
 pub use reword::{PullRequestReword, RewordGenerator};
 pub use summary::{PullRequestSummary, SummaryGenerator};
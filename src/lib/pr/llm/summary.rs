@@ -11,7 +11,7 @@ use serde_json::Value;
 use crate::base::llm::{LLMClient, LLMRequestParams};
 use crate::base::prompt::generate_summarize_pr_system_prompt;
 
-use super::helpers::extract_json_from_markdown;
+use super::helpers::{extract_json_from_markdown, prepare_diff, DiffChunking, PreparedDiff};
 
 /// PR 总结结果，包含总结文档和文件名
 ///
@@ -22,6 +22,10 @@ pub struct PullRequestSummary {
     pub summary: String,
     /// 文件名（不含路径和扩展名）
     pub filename: String,
+    /// PR diff 超出预算时的分块处理信息
+    ///
+    /// 不为 `None` 时，总结文档由按文件生成的修改总结综合而成（分层总结）。
+    pub chunking: Option<DiffChunking>,
 }
 
 /// PR 总结生成器
@@ -43,6 +47,7 @@ impl SummaryGenerator {
     /// 返回 `PullRequestSummary` 结构体，包含：
     /// - `summary` - PR 总结文档（Markdown 格式）
     /// - `filename` - 文件名（不含路径和扩展名）
+    /// - `chunking` - PR diff 超出预算、按文件分块总结时的分块信息
     ///
     /// # 错误
     ///
@@ -51,8 +56,11 @@ impl SummaryGenerator {
         // 使用统一的 v2 客户端
         let client = LLMClient::global();
 
+        // 超出预算的 diff 按文件总结后再使用
+        let prepared_diff = prepare_diff(pr_diff).wrap_err("Failed to prepare PR diff for LLM")?;
+
         // 构建请求参数
-        let user_prompt = Self::summarize_user_prompt(pr_title, pr_diff);
+        let user_prompt = Self::summarize_user_prompt(pr_title, &prepared_diff);
         // 根据语言生成 system prompt（语言选择逻辑在 prompt 生成函数内部处理）
         let system_prompt = generate_summarize_pr_system_prompt();
 
@@ -70,47 +78,29 @@ impl SummaryGenerator {
         })?;
 
         // 解析响应
-        Self::parse_summary_response(response).wrap_err_with(|| {
+        let mut summary = Self::parse_summary_response(response).wrap_err_with(|| {
             format!(
                 "Failed to parse LLM response for PR summary: '{}'",
                 pr_title
             )
-        })
+        })?;
+        summary.chunking = prepared_diff.chunking;
+        Ok(summary)
     }
 
     /// 生成 PR 总结的 user prompt
-    fn summarize_user_prompt(pr_title: &str, pr_diff: &str) -> String {
+    fn summarize_user_prompt(pr_title: &str, pr_diff: &PreparedDiff) -> String {
         let mut parts = vec![format!("PR Title: {}", pr_title)];
 
-        if !pr_diff.trim().is_empty() {
-            // 限制 diff 长度，避免请求过大
-            // 对于总结，我们需要更多的 diff 内容，但也要避免超过 token 限制
-            const MAX_DIFF_LENGTH: usize = 15000; // 增加长度，因为总结需要更多上下文
-            let diff_trimmed = {
-                let char_count = pr_diff.chars().count();
-                if char_count > MAX_DIFF_LENGTH {
-                    // 使用字符边界安全截取
-                    let mut char_boundary = pr_diff.len();
-                    for (idx, _) in pr_diff.char_indices().take(MAX_DIFF_LENGTH + 1) {
-                        char_boundary = idx;
-                    }
-                    let truncated = &pr_diff[..char_boundary];
-                    // 尝试在最后一个换行符处截断
-                    let last_newline = truncated.rfind('\n').unwrap_or(0);
-                    let truncated_diff = if last_newline > 0 {
-                        &pr_diff[..last_newline]
-                    } else {
-                        truncated
-                    };
-                    format!(
-                        "{}\n... (diff truncated, {} characters total)",
-                        truncated_diff, char_count
-                    )
-                } else {
-                    pr_diff.to_string()
-                }
-            };
-            parts.push(format!("PR Diff:\n{}", diff_trimmed));
+        if !pr_diff.content.trim().is_empty() {
+            if pr_diff.chunking.is_some() {
+                parts.push(format!(
+                    "PR Changes (per-file summaries, the full diff is too large):\n{}",
+                    pr_diff.content
+                ));
+            } else {
+                parts.push(format!("PR Diff:\n{}", pr_diff.content));
+            }
         }
 
         parts.join("\n\n")
@@ -175,6 +165,7 @@ impl SummaryGenerator {
         Ok(PullRequestSummary {
             summary: summary.trim().to_string(),
             filename: cleaned_filename,
+            chunking: None,
         })
     }
 }
@@ -1,27 +1,43 @@
+pub mod azure_devops;
 pub mod body_parser;
 pub mod github;
 pub mod helpers;
+pub mod list_filter;
 pub mod llm;
+pub mod output;
 pub mod platform;
+pub mod readiness;
+pub mod review;
+pub mod review_comment;
 pub mod table;
 
+pub use azure_devops::{AzureDevOps, AzureDevOpsRepo};
 pub use body_parser::{
     extract_description_from_body, extract_info_from_source_pr, extract_jira_ticket_from_body,
     parse_change_types_from_body, ExtractedPrInfo, SourcePrInfo,
 };
 pub use github::errors::{GitHubError, GitHubErrorResponse};
-pub use github::{GitHub, GitHubUser};
+pub use github::{GitHub, GitHubTokenInfo, GitHubUser};
 pub use helpers::{
-    extract_pull_request_id_from_url, generate_commit_title, generate_pull_request_body,
-    get_current_branch_pr_id, resolve_pull_request_id,
+    extract_pull_request_id_from_url, fill_pull_request_template, generate_commit_message,
+    generate_commit_title, generate_pull_request_body, get_current_branch_pr_id,
+    resolve_pull_request_id, resolve_pull_request_id_with,
 };
+pub use list_filter::{PrListFilter, PrListSort};
 pub use llm::{
-    CreateGenerator, FileSummaryGenerator, PullRequestContent, PullRequestReword,
-    PullRequestSummary, RewordGenerator, SummaryGenerator,
+    CreateGenerator, DiffChunking, FileSummaryCache, FileSummaryGenerator, PullRequestContent,
+    PullRequestReword, PullRequestSummary, RewordGenerator, SummaryGenerator,
 };
+pub use output::{PullRequestListItem, PullRequestListOutput, PullRequestStatusOutput};
 pub use platform::{
     create_provider, create_provider_auto, get_all_change_types, get_change_type_by_index,
     get_change_type_by_name, map_branch_type_to_change_type_index, map_branch_type_to_change_types,
-    ChangeType, PlatformProvider, PullRequestStatus, CHANGE_TYPES, TYPES_OF_CHANGES,
+    map_change_types_to_labels, BranchDeletion, ChangeType, PlatformProvider, PullRequestDetails,
+    PullRequestStatus, CHANGE_TYPES, TYPES_OF_CHANGES,
+};
+pub use readiness::{CheckState, MergeCheck, MergeMethod, MergeReadiness, ReviewStatus};
+pub use review::ReviewEvent;
+pub use review_comment::{
+    ensure_line_in_diff, normalize_path, patch_contains_line, ChangedFile, ReviewCommentTarget,
 };
 pub use table::PullRequestRow;
@@ -0,0 +1,102 @@
+//! PR 命令的机器可读输出
+//!
+//! 定义 `pr status --json` 和 `pr list --json` 输出的 JSON 文档结构。
+//! 字段名和类型是对脚本的约定：只新增字段，不修改或删除已有字段；没有值的字段输出为 `null`。
+
+use serde::Serialize;
+
+use crate::pr::platform::PullRequestDetails;
+use crate::pr::PullRequestRow;
+
+/// `pr status --json` 输出
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PullRequestStatusOutput {
+    /// PR ID（GitHub 为 PR 编号）
+    pub id: String,
+    /// PR 标题
+    pub title: String,
+    /// PR 描述（原始 Markdown）
+    pub description: Option<String>,
+    /// PR 状态（如 "open", "closed", "merged"）
+    pub state: String,
+    /// 是否已合并
+    pub merged: bool,
+    /// 合并时间
+    pub merged_at: Option<String>,
+    /// 源分支
+    pub source_branch: String,
+    /// 目标分支
+    pub target_branch: String,
+    /// 创建者
+    pub author: Option<String>,
+    /// PR 网页地址
+    pub url: String,
+}
+
+impl From<PullRequestDetails> for PullRequestStatusOutput {
+    fn from(details: PullRequestDetails) -> Self {
+        Self {
+            id: details.id,
+            title: details.title,
+            description: details.description,
+            state: details.state,
+            merged: details.merged,
+            merged_at: details.merged_at,
+            source_branch: details.source_branch,
+            target_branch: details.target_branch,
+            author: details.author,
+            url: details.url,
+        }
+    }
+}
+
+/// `pr list --json` 输出
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PullRequestListOutput {
+    /// PR 数量
+    pub count: usize,
+    /// PR 列表（按平台返回的顺序）
+    pub pull_requests: Vec<PullRequestListItem>,
+}
+
+/// `pr list --json` 中的一个 PR
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PullRequestListItem {
+    /// PR ID（GitHub 为 PR 编号）
+    pub id: String,
+    /// PR 状态
+    pub state: String,
+    /// 源分支
+    pub branch: String,
+    /// PR 标题
+    pub title: String,
+    /// 创建者（平台没有返回时为 `None`）
+    pub author: Option<String>,
+    /// PR 网页地址
+    pub url: String,
+}
+
+impl From<PullRequestRow> for PullRequestListItem {
+    fn from(row: PullRequestRow) -> Self {
+        Self {
+            id: row.number,
+            state: row.state,
+            branch: row.branch,
+            title: row.title,
+            // 表格中没有创建者时显示为 N/A
+            author: Some(row.author).filter(|author| author != "N/A"),
+            url: row.url,
+        }
+    }
+}
+
+impl PullRequestListOutput {
+    /// 从表格行创建输出
+    pub fn new(rows: Vec<PullRequestRow>) -> Self {
+        let pull_requests: Vec<PullRequestListItem> = rows.into_iter().map(Into::into).collect();
+        Self {
+            count: pull_requests.len(),
+            pull_requests,
+        }
+    }
+}
@@ -1,6 +1,11 @@
 use crate::branch::BranchType;
 use crate::git::{GitRepo, RepoType};
+use crate::pr::azure_devops::AzureDevOps;
 use crate::pr::github::GitHub;
+use crate::pr::list_filter::PrListFilter;
+use crate::pr::readiness::{MergeMethod, MergeReadiness};
+use crate::pr::review::ReviewEvent;
+use crate::pr::review_comment::ReviewCommentTarget;
 use crate::pr::PullRequestRow;
 use color_eyre::Result;
 
@@ -15,6 +20,8 @@ pub struct ChangeType {
     pub description: &'static str,
     /// 使用示例
     pub example: &'static str,
+    /// 标签名称（用于支持标签的平台，如 Azure DevOps 的 PR 标签）
+    pub label: &'static str,
 }
 
 /// PR 变更类型定义（扩展版）
@@ -25,26 +32,31 @@ pub const CHANGE_TYPES: &[ChangeType] = &[
         name: "Bug fix (non-breaking change which fixes an issue)",
         description: "Fix errors or issues in code without changing existing functionality interfaces or behavior",
         example: "Fix null pointer exception in login validation logic",
+        label: "bug-fix",
     },
     ChangeType {
         name: "New feature (non-breaking change which adds functionality)",
         description: "Add new features or capabilities without affecting existing functionality",
         example: "Add user avatar upload functionality",
+        label: "feature",
     },
     ChangeType {
         name: "Refactoring (non-breaking change which does not change functionality)",
         description: "Restructure code to improve quality without changing functional behavior",
         example: "Extract duplicate code into common functions and optimize code structure",
+        label: "refactoring",
     },
     ChangeType {
         name: "Hotfix (urgent fix for production issues)",
         description: "Urgent fix for critical production issues that require immediate deployment",
         example: "Fix critical security vulnerability in authentication system",
+        label: "hotfix",
     },
     ChangeType {
         name: "Chore (maintenance tasks and non-functional changes)",
         description: "Maintenance tasks, dependency updates, configuration changes, or other non-functional improvements",
         example: "Update dependencies, improve build configuration, or update documentation",
+        label: "chore",
     },
 ];
 
@@ -119,6 +131,22 @@ pub fn get_change_type_by_name(name: &str) -> Option<&'static ChangeType> {
     CHANGE_TYPES.iter().find(|ct| ct.name == name)
 }
 
+/// 将选中的变更类型映射为 PR 标签
+///
+/// # Arguments
+/// * `selected` - 每个变更类型是否被选中（与 `CHANGE_TYPES` 顺序一致）
+///
+/// # Returns
+/// 返回选中的变更类型对应的标签名称
+pub fn map_change_types_to_labels(selected: &[bool]) -> Vec<&'static str> {
+    CHANGE_TYPES
+        .iter()
+        .zip(selected)
+        .filter(|(_, &is_selected)| is_selected)
+        .map(|(change_type, _)| change_type.label)
+        .collect()
+}
+
 /// 获取所有变更类型的完整信息
 ///
 /// # Returns
@@ -138,8 +166,59 @@ pub struct PullRequestStatus {
     pub merged_at: Option<String>,
 }
 
+/// PR 详细信息（平台无关）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullRequestDetails {
+    /// PR ID（GitHub 为 PR 编号）
+    pub id: String,
+    /// PR 标题
+    pub title: String,
+    /// PR 描述（为空时为 `None`）
+    pub description: Option<String>,
+    /// PR 状态（如 "open", "closed", "merged"）
+    pub state: String,
+    /// 是否已合并
+    pub merged: bool,
+    /// 合并时间（如果已合并）
+    pub merged_at: Option<String>,
+    /// 源分支
+    pub source_branch: String,
+    /// 目标分支
+    pub target_branch: String,
+    /// 创建者（GitHub 为 login，Azure DevOps 为 unique name）
+    pub author: Option<String>,
+    /// PR 网页地址
+    pub url: String,
+}
+
+/// 删除 PR 源分支的结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BranchDeletion {
+    /// 已删除远程分支
+    Deleted(String),
+    /// 远程分支已不存在（如合并时已被平台删除）
+    NotFound(String),
+    /// 跳过删除（如受保护的分支、fork 仓库中的分支）
+    Skipped {
+        /// 分支名
+        branch: String,
+        /// 跳过的原因
+        reason: String,
+    },
+}
+
+impl BranchDeletion {
+    /// 分支名
+    pub fn branch(&self) -> &str {
+        match self {
+            Self::Deleted(branch) | Self::NotFound(branch) => branch,
+            Self::Skipped { branch, .. } => branch,
+        }
+    }
+}
+
 /// PR 平台接口 trait
-/// 定义所有 PR 平台（GitHub 等）必须实现的共同方法
+/// 定义所有 PR 平台（GitHub、Azure DevOps 等）必须实现的共同方法
 pub trait PlatformProvider {
     /// 创建 Pull Request
     ///
@@ -164,7 +243,13 @@ pub trait PlatformProvider {
     /// # Arguments
     /// * `pull_request_id` - PR ID
     /// * `delete_branch` - 是否删除源分支
-    fn merge_pull_request(&self, pull_request_id: &str, delete_branch: bool) -> Result<()>;
+    /// * `merge_method` - 合并方式（`None` 时使用平台的首选方式）
+    fn merge_pull_request(
+        &self,
+        pull_request_id: &str,
+        delete_branch: bool,
+        merge_method: Option<MergeMethod>,
+    ) -> Result<()>;
 
     /// 获取 PR 信息
     ///
@@ -211,20 +296,39 @@ pub trait PlatformProvider {
     /// 列出 PR（可选方法，某些平台可能不支持）
     ///
     /// # Arguments
-    /// * `state` - PR 状态筛选（如 "open", "closed"）
-    /// * `limit` - 返回数量限制
+    /// * `filter` - 筛选条件（状态、作者、标签、目标分支、排序和数量限制）
     ///
     /// # Returns
     /// PR 列表的表格行数据
-    fn get_pull_requests(
-        &self,
-        _state: Option<&str>,
-        _limit: Option<usize>,
-    ) -> Result<Vec<PullRequestRow>> {
+    ///
+    /// # Errors
+    /// `filter.mine` 为 true 但无法获取当前用户，或平台不支持某个筛选条件时返回错误
+    fn get_pull_requests(&self, _filter: &PrListFilter) -> Result<Vec<PullRequestRow>> {
         // 默认实现：返回不支持的错误
         color_eyre::eyre::bail!("get_pull_requests is not supported by this platform")
     }
 
+    /// 分页列出 PR，每获取一页就调用一次 `on_page`（可用于边获取边显示）
+    ///
+    /// # Arguments
+    /// * `filter` - 筛选条件（同 `get_pull_requests`）
+    /// * `on_page` - 处理一页 PR（不会收到空页，所有页的总数不超过数量限制）
+    ///
+    /// # Errors
+    /// 同 `get_pull_requests`；出错前已获取的页已经交给 `on_page` 处理
+    fn stream_pull_requests(
+        &self,
+        filter: &PrListFilter,
+        on_page: &mut dyn FnMut(Vec<PullRequestRow>) -> Result<()>,
+    ) -> Result<()> {
+        // 默认实现：一次性获取所有结果
+        let rows = self.get_pull_requests(filter)?;
+        if !rows.is_empty() {
+            on_page(rows)?;
+        }
+        Ok(())
+    }
+
     /// 获取 PR 状态
     ///
     /// # Arguments
@@ -234,6 +338,58 @@ pub trait PlatformProvider {
     /// PR 状态信息，包含是否已合并等信息
     fn get_pull_request_status(&self, pull_request_id: &str) -> Result<PullRequestStatus>;
 
+    /// 获取 PR 详细信息
+    ///
+    /// # Arguments
+    /// * `pull_request_id` - PR ID
+    ///
+    /// # Returns
+    /// 标题、描述、状态、分支、创建者和 URL 等结构化信息（用于 `pr status --json`）
+    fn get_pull_request_details(&self, pull_request_id: &str) -> Result<PullRequestDetails>;
+
+    /// 获取 PR 的合并前检查结果（不执行合并）
+    ///
+    /// # Arguments
+    /// * `pull_request_id` - PR ID
+    ///
+    /// # Returns
+    /// 可合并状态、CI 检查、评审状态和允许的合并方式
+    fn get_merge_readiness(&self, _pull_request_id: &str) -> Result<MergeReadiness> {
+        // 默认实现：返回不支持的错误
+        color_eyre::eyre::bail!("get_merge_readiness is not supported by this platform")
+    }
+
+    /// 开启自动合并（必需的检查通过后由平台自动合并）
+    ///
+    /// # Arguments
+    /// * `pull_request_id` - PR ID
+    /// * `merge_method` - 合并方式（`None` 时使用仓库允许的首选方式）
+    ///
+    /// # Returns
+    /// 自动合并使用的合并方式
+    fn enable_auto_merge(
+        &self,
+        _pull_request_id: &str,
+        _merge_method: Option<MergeMethod>,
+    ) -> Result<MergeMethod> {
+        // 默认实现：返回不支持的错误
+        color_eyre::eyre::bail!("Auto-merge is not supported by this platform")
+    }
+
+    /// 删除 PR 的远程源分支
+    ///
+    /// 受保护的分支和不在当前仓库中的分支（如 fork）不会被删除。
+    ///
+    /// # Arguments
+    /// * `pull_request_id` - PR ID
+    ///
+    /// # Returns
+    /// 删除结果（包含源分支名）
+    fn delete_source_branch(&self, _pull_request_id: &str) -> Result<BranchDeletion> {
+        // 默认实现：返回不支持的错误
+        color_eyre::eyre::bail!("Deleting the source branch is not supported by this platform")
+    }
+
     /// 关闭 Pull Request
     ///
     /// # Arguments
@@ -259,11 +415,40 @@ pub trait PlatformProvider {
     /// * `comment` - 评论内容
     fn add_comment(&self, pull_request_id: &str, comment: &str) -> Result<()>;
 
-    /// 批准 Pull Request
+    /// 添加评审评论（文件中某一行的评论，或回复已有的评审评论）
     ///
     /// # Arguments
     /// * `pull_request_id` - PR ID
-    fn approve_pull_request(&self, pull_request_id: &str) -> Result<()>;
+    /// * `target` - 评论位置
+    /// * `comment` - 评论内容
+    ///
+    /// # Errors
+    /// 文件或行号不在 PR diff 中、被回复的评论不存在，或平台不支持评审评论时返回错误
+    fn add_review_comment(
+        &self,
+        _pull_request_id: &str,
+        _target: &ReviewCommentTarget,
+        _comment: &str,
+    ) -> Result<()> {
+        // 默认实现：返回不支持的错误
+        color_eyre::eyre::bail!("Review comments are not supported by this platform")
+    }
+
+    /// 提交评审（批准、请求修改或评论）
+    ///
+    /// # Arguments
+    /// * `pull_request_id` - PR ID
+    /// * `event` - 评审类型
+    /// * `body` - 评审内容（请求修改和评论必须提供，见 `ReviewEvent::validate_body`）
+    ///
+    /// # Errors
+    /// 评审内容缺失、评审自己的 PR（批准和请求修改）或平台返回错误时返回错误
+    fn submit_review(
+        &self,
+        pull_request_id: &str,
+        event: ReviewEvent,
+        body: Option<&str>,
+    ) -> Result<()>;
 
     /// 更新 PR 的 base 分支
     ///
@@ -328,9 +513,10 @@ pub trait PlatformProvider {
 pub fn create_provider(repo_type: RepoType) -> Result<Box<dyn PlatformProvider>> {
     match repo_type {
         RepoType::GitHub => Ok(Box::new(GitHub)),
+        RepoType::AzureDevOps => Ok(Box::new(AzureDevOps)),
         RepoType::Codeup | RepoType::Unknown => {
             color_eyre::eyre::bail!(
-                "Unsupported repository type. Only GitHub is currently supported."
+                "Unsupported repository type. Only GitHub and Azure DevOps are currently supported."
             )
         }
     }
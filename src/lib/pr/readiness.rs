@@ -0,0 +1,363 @@
+//! PR 合并前检查
+//!
+//! `pr merge --dry-run` 在不执行合并的情况下，汇总平台返回的合并条件：
+//! - 可合并状态（是否有冲突、是否被分支保护阻止）
+//! - CI 检查结果（失败、进行中以及尚未上报的必需检查）
+//! - 评审状态（已批准数量、必需的批准数量、是否有人要求修改）
+//! - 仓库允许的合并方式（squash、rebase、merge commit）
+//!
+//! 各平台通过 `PlatformProvider::get_merge_readiness` 提供 `MergeReadiness`，
+//! 是否会被阻止由 `MergeReadiness::blockers` 统一判断。
+
+use std::collections::HashMap;
+use std::fmt;
+
+/// 合并方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeMethod {
+    /// 压缩为一个提交后合并
+    Squash,
+    /// 变基后合并
+    Rebase,
+    /// 创建合并提交
+    Merge,
+}
+
+impl MergeMethod {
+    /// 所有合并方式（按 `pr merge` 的优先级排列）
+    pub const ALL: [MergeMethod; 3] = [Self::Squash, Self::Rebase, Self::Merge];
+
+    /// 根据名称解析合并方式（`squash`、`rebase`、`merge`，不区分大小写）
+    ///
+    /// 用于解析仓库配置中的 `merge_strategy`，无法识别时返回 `None`。
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "squash" => Some(Self::Squash),
+            "rebase" => Some(Self::Rebase),
+            "merge" => Some(Self::Merge),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MergeMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Squash => "squash",
+            Self::Rebase => "rebase",
+            Self::Merge => "merge commit",
+        })
+    }
+}
+
+/// CI 检查状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckState {
+    /// 通过
+    Passed,
+    /// 失败（包括取消、超时和需要手动处理）
+    Failed,
+    /// 进行中或排队中
+    Pending,
+    /// 必需的检查尚未上报结果
+    Expected,
+    /// 跳过或不适用
+    Skipped,
+}
+
+impl CheckState {
+    /// 根据 GitHub check run 的 `status` 和 `conclusion` 判断状态
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::pr::readiness::CheckState;
+    ///
+    /// assert_eq!(CheckState::from_check_run("completed", Some("success")), CheckState::Passed);
+    /// assert_eq!(CheckState::from_check_run("completed", Some("timed_out")), CheckState::Failed);
+    /// assert_eq!(CheckState::from_check_run("in_progress", None), CheckState::Pending);
+    /// ```
+    pub fn from_check_run(status: &str, conclusion: Option<&str>) -> Self {
+        if status != "completed" {
+            return Self::Pending;
+        }
+        match conclusion {
+            Some("success") => Self::Passed,
+            Some("neutral") | Some("skipped") => Self::Skipped,
+            Some(_) => Self::Failed,
+            None => Self::Pending,
+        }
+    }
+
+    /// 根据 GitHub commit status 的 `state` 判断状态
+    pub fn from_commit_status(state: &str) -> Self {
+        match state {
+            "success" => Self::Passed,
+            "failure" | "error" => Self::Failed,
+            _ => Self::Pending,
+        }
+    }
+}
+
+impl fmt::Display for CheckState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Passed => "passed",
+            Self::Failed => "failed",
+            Self::Pending => "pending",
+            Self::Expected => "expected",
+            Self::Skipped => "skipped",
+        })
+    }
+}
+
+/// 单个 CI 检查
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeCheck {
+    /// 检查名称（check run 名称或 commit status 的 context）
+    pub name: String,
+    /// 检查状态
+    pub state: CheckState,
+    /// 是否为分支保护要求的检查
+    pub required: bool,
+}
+
+/// 汇总 CI 检查结果
+///
+/// 同名的检查只保留第一个（平台按时间倒序返回时即为最新结果）；
+/// 分支保护要求、但没有上报结果的检查以 `CheckState::Expected` 加入列表。
+///
+/// # 参数
+///
+/// * `results` - （检查名称、状态）
+/// * `required` - 分支保护要求的检查名称
+///
+/// # 示例
+///
+/// ```
+/// use workflow::pr::readiness::{collect_checks, CheckState};
+///
+/// let checks = collect_checks(
+///     [("build".to_string(), CheckState::Passed)],
+///     &["build".to_string(), "lint".to_string()],
+/// );
+/// assert_eq!(checks.len(), 2);
+/// assert!(checks[0].required);
+/// assert_eq!(checks[1].name, "lint");
+/// assert_eq!(checks[1].state, CheckState::Expected);
+/// ```
+pub fn collect_checks(
+    results: impl IntoIterator<Item = (String, CheckState)>,
+    required: &[String],
+) -> Vec<MergeCheck> {
+    let mut checks: Vec<MergeCheck> = Vec::new();
+    for (name, state) in results {
+        if checks.iter().any(|check| check.name == name) {
+            continue;
+        }
+        let required = required.contains(&name);
+        checks.push(MergeCheck {
+            name,
+            state,
+            required,
+        });
+    }
+
+    for name in required {
+        if !checks.iter().any(|check| &check.name == name) {
+            checks.push(MergeCheck {
+                name: name.clone(),
+                state: CheckState::Expected,
+                required: true,
+            });
+        }
+    }
+    checks
+}
+
+/// 评审状态
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ReviewStatus {
+    /// 已批准的评审人数（每个评审人只计算最新的评审）
+    pub approvals: u32,
+    /// 分支保护要求的批准数量（`None` 表示没有要求或无法获取）
+    pub required_approvals: Option<u32>,
+    /// 是否有评审人要求修改
+    pub changes_requested: bool,
+    /// 必须批准但尚未批准的评审人
+    pub pending_reviewers: Vec<String>,
+}
+
+impl ReviewStatus {
+    /// 根据评审记录统计评审状态
+    ///
+    /// 每个评审人只计算最新的有效评审（`APPROVED`、`CHANGES_REQUESTED` 或 `DISMISSED`），
+    /// `COMMENTED` 和 `PENDING` 不会覆盖之前的评审结果。
+    ///
+    /// # 参数
+    ///
+    /// * `reviews` - 按时间升序排列的（评审人、评审状态）
+    /// * `required_approvals` - 要求的批准数量
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::pr::readiness::ReviewStatus;
+    ///
+    /// let status = ReviewStatus::from_reviews(
+    ///     [("alice", "CHANGES_REQUESTED"), ("bob", "APPROVED"), ("alice", "APPROVED")],
+    ///     Some(2),
+    /// );
+    /// assert_eq!(status.approvals, 2);
+    /// assert!(!status.changes_requested);
+    /// assert!(status.is_satisfied());
+    /// ```
+    pub fn from_reviews<'a>(
+        reviews: impl IntoIterator<Item = (&'a str, &'a str)>,
+        required_approvals: Option<u32>,
+    ) -> Self {
+        let mut latest: HashMap<&str, &str> = HashMap::new();
+        for (reviewer, state) in reviews {
+            if matches!(state, "APPROVED" | "CHANGES_REQUESTED" | "DISMISSED") {
+                latest.insert(reviewer, state);
+            }
+        }
+
+        Self {
+            approvals: latest.values().filter(|state| **state == "APPROVED").count() as u32,
+            required_approvals,
+            changes_requested: latest.values().any(|state| *state == "CHANGES_REQUESTED"),
+            pending_reviewers: Vec::new(),
+        }
+    }
+
+    /// 是否满足评审要求
+    pub fn is_satisfied(&self) -> bool {
+        !self.changes_requested
+            && self.pending_reviewers.is_empty()
+            && self.approvals >= self.required_approvals.unwrap_or(0)
+    }
+}
+
+/// PR 合并前检查结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MergeReadiness {
+    /// PR 状态（如 "open", "closed", "merged"）
+    pub state: String,
+    /// 是否已合并
+    pub merged: bool,
+    /// 是否可以合并（没有冲突）；`None` 表示平台仍在计算
+    pub mergeable: Option<bool>,
+    /// 平台返回的详细可合并状态（如 GitHub 的 `clean`、`blocked`、`behind`、`dirty`）
+    pub mergeable_state: Option<String>,
+    /// CI 检查
+    pub checks: Vec<MergeCheck>,
+    /// 评审状态
+    pub reviews: ReviewStatus,
+    /// 各合并方式是否允许
+    pub merge_methods: Vec<(MergeMethod, bool)>,
+}
+
+impl MergeReadiness {
+    /// 失败的检查
+    pub fn failing_checks(&self) -> Vec<&MergeCheck> {
+        self.checks.iter().filter(|check| check.state == CheckState::Failed).collect()
+    }
+
+    /// 未完成的必需检查（进行中或尚未上报）
+    pub fn pending_required_checks(&self) -> Vec<&MergeCheck> {
+        self.checks
+            .iter()
+            .filter(|check| {
+                check.required && matches!(check.state, CheckState::Pending | CheckState::Expected)
+            })
+            .collect()
+    }
+
+    /// 允许的合并方式
+    pub fn allowed_merge_methods(&self) -> Vec<MergeMethod> {
+        self.merge_methods
+            .iter()
+            .filter(|(_, allowed)| *allowed)
+            .map(|(method, _)| *method)
+            .collect()
+    }
+
+    /// 阻止合并的原因
+    ///
+    /// # 返回
+    ///
+    /// 返回所有阻止合并的原因；为空表示可以合并。
+    /// 非必需检查失败不会阻止合并（只在检查列表中显示）。
+    pub fn blockers(&self) -> Vec<String> {
+        let mut blockers = Vec::new();
+
+        if self.merged {
+            blockers.push("PR has already been merged".to_string());
+            return blockers;
+        }
+        if self.state != "open" {
+            blockers.push(format!("PR is {}", self.state));
+            return blockers;
+        }
+
+        match self.mergeable {
+            Some(false) => blockers.push("PR has merge conflicts".to_string()),
+            None => blockers.push("Mergeability is still being computed, try again".to_string()),
+            Some(true) => {}
+        }
+        if self.mergeable_state.as_deref() == Some("behind") {
+            blockers.push("Branch is behind the base branch and must be updated".to_string());
+        }
+
+        for check in self.checks.iter().filter(|check| check.required) {
+            match check.state {
+                CheckState::Failed => {
+                    blockers.push(format!("Required check '{}' failed", check.name))
+                }
+                CheckState::Pending => {
+                    blockers.push(format!("Required check '{}' is still running", check.name))
+                }
+                CheckState::Expected => blockers.push(format!(
+                    "Required check '{}' has not reported yet",
+                    check.name
+                )),
+                CheckState::Passed | CheckState::Skipped => {}
+            }
+        }
+
+        if self.reviews.changes_requested {
+            blockers.push("A reviewer requested changes".to_string());
+        }
+        if !self.reviews.pending_reviewers.is_empty() {
+            blockers.push(format!(
+                "Waiting for approval from required reviewers: {}",
+                self.reviews.pending_reviewers.join(", ")
+            ));
+        }
+        if let Some(required) = self.reviews.required_approvals {
+            if self.reviews.approvals < required {
+                blockers.push(format!(
+                    "{} of {} required approvals",
+                    self.reviews.approvals, required
+                ));
+            }
+        }
+
+        if !self.merge_methods.is_empty() && self.allowed_merge_methods().is_empty() {
+            blockers.push("Repository does not allow any merge method".to_string());
+        }
+
+        // 平台报告被阻止，但无法确定具体原因（如缺少查看分支保护的权限）
+        if blockers.is_empty() && self.mergeable_state.as_deref() == Some("blocked") {
+            blockers.push("Blocked by branch protection rules".to_string());
+        }
+
+        blockers
+    }
+
+    /// 是否可以合并
+    pub fn can_merge(&self) -> bool {
+        self.blockers().is_empty()
+    }
+}
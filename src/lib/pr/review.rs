@@ -0,0 +1,78 @@
+//! PR 评审
+//!
+//! `pr approve` 和 `pr review` 通过 `PlatformProvider::submit_review` 提交评审，评审类型包括
+//! 批准、请求修改和评论。GitHub 对应 `POST /pulls/{number}/reviews` 的 `event`；
+//! Azure DevOps 对应审核人投票（评论不投票），评审内容作为新的评论线程添加。
+
+use std::fmt;
+
+use crate::base::constants::messages::pull_requests;
+use color_eyre::Result;
+
+/// 评审类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewEvent {
+    /// 批准
+    Approve,
+    /// 请求修改
+    RequestChanges,
+    /// 评论（不批准也不请求修改）
+    Comment,
+}
+
+impl ReviewEvent {
+    /// GitHub 评审 API 的 `event` 值
+    pub fn github_event(&self) -> &'static str {
+        match self {
+            Self::Approve => pull_requests::APPROVE_EVENT,
+            Self::RequestChanges => pull_requests::REQUEST_CHANGES_EVENT,
+            Self::Comment => pull_requests::COMMENT_EVENT,
+        }
+    }
+
+    /// 是否必须提供评审内容（请求修改和评论必须说明原因，批准可以省略）
+    pub fn requires_body(&self) -> bool {
+        !matches!(self, Self::Approve)
+    }
+
+    /// 校验评审内容
+    ///
+    /// 去除首尾空白，空内容视为未提供。
+    ///
+    /// # 错误
+    ///
+    /// 请求修改和评论类型未提供评审内容时返回错误。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::pr::ReviewEvent;
+    ///
+    /// assert_eq!(ReviewEvent::Approve.validate_body(Some("  ")).unwrap(), None);
+    /// assert_eq!(
+    ///     ReviewEvent::RequestChanges.validate_body(Some(" Needs tests ")).unwrap(),
+    ///     Some("Needs tests")
+    /// );
+    /// assert!(ReviewEvent::Comment.validate_body(None).is_err());
+    /// ```
+    pub fn validate_body(self, body: Option<&str>) -> Result<Option<&str>> {
+        let body = body.map(str::trim).filter(|body| !body.is_empty());
+        if body.is_none() && self.requires_body() {
+            color_eyre::eyre::bail!(
+                "A message is required for {} reviews. Please specify it with --message",
+                self
+            );
+        }
+        Ok(body)
+    }
+}
+
+impl fmt::Display for ReviewEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Approve => "approve",
+            Self::RequestChanges => "request-changes",
+            Self::Comment => "comment",
+        })
+    }
+}
@@ -0,0 +1,148 @@
+//! PR 评审评论
+//!
+//! `pr comment` 默认添加普通评论；指定 `--file` 和 `--line` 时在 PR diff 的某一行添加评审评论，
+//! 指定 `--reply-to` 时回复已有的评审评论。评审评论由 `PlatformProvider::add_review_comment` 发送。
+//!
+//! 平台只接受位于 PR diff 中的行（否则 GitHub 返回 422），发送前通过 `ensure_line_in_diff`
+//! 检查文件是否在 PR 的变更文件中、行号是否在某个 diff 片段内。
+
+use color_eyre::Result;
+use regex::Regex;
+
+/// 评审评论的位置
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReviewCommentTarget {
+    /// 文件中的某一行（变更后的版本）
+    Line {
+        /// 文件路径（相对于仓库根目录）
+        path: String,
+        /// 行号（从 1 开始）
+        line: u32,
+    },
+    /// 回复已有的评审评论
+    Reply {
+        /// 被回复的评论 ID
+        comment_id: u64,
+    },
+}
+
+impl ReviewCommentTarget {
+    /// 根据命令行参数构造评审评论的位置
+    ///
+    /// 三个参数都未指定时返回 `None`（添加普通评论）。
+    ///
+    /// # 错误
+    ///
+    /// `file` 和 `line` 只指定了一个、行号为 0，或同时指定了 `reply_to` 和 `file`/`line` 时返回错误。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use workflow::pr::ReviewCommentTarget;
+    ///
+    /// let target =
+    ///     ReviewCommentTarget::from_args(Some("./src/main.rs".to_string()), Some(42), None).unwrap();
+    /// assert_eq!(
+    ///     target,
+    ///     Some(ReviewCommentTarget::Line {
+    ///         path: "src/main.rs".to_string(),
+    ///         line: 42
+    ///     })
+    /// );
+    /// assert_eq!(ReviewCommentTarget::from_args(None, None, None).unwrap(), None);
+    /// assert!(ReviewCommentTarget::from_args(Some("src/main.rs".to_string()), None, None).is_err());
+    /// ```
+    pub fn from_args(
+        file: Option<String>,
+        line: Option<u32>,
+        reply_to: Option<u64>,
+    ) -> Result<Option<Self>> {
+        match (file, line, reply_to) {
+            (None, None, None) => Ok(None),
+            (None, None, Some(comment_id)) => Ok(Some(Self::Reply { comment_id })),
+            (_, _, Some(_)) => {
+                color_eyre::eyre::bail!("--reply-to cannot be used together with --file or --line")
+            }
+            (Some(_), Some(0), None) => {
+                color_eyre::eyre::bail!("Line numbers start at 1")
+            }
+            (Some(file), Some(line), None) => Ok(Some(Self::Line {
+                path: normalize_path(&file),
+                line,
+            })),
+            (Some(_), None, None) => color_eyre::eyre::bail!("--file requires --line"),
+            (None, Some(_), None) => color_eyre::eyre::bail!("--line requires --file"),
+        }
+    }
+}
+
+/// PR 中变更的文件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangedFile {
+    /// 文件路径（相对于仓库根目录）
+    pub path: String,
+    /// 文件的 diff 片段（二进制文件或过大的文件没有）
+    pub patch: Option<String>,
+}
+
+/// 将命令行中的文件路径转换为平台使用的格式（`/` 分隔、去掉开头的 `./`）
+pub fn normalize_path(path: &str) -> String {
+    let path = path.trim().replace('\\', "/");
+    let mut path = path.as_str();
+    while let Some(rest) = path.strip_prefix("./") {
+        path = rest;
+    }
+    path.trim_start_matches('/').to_string()
+}
+
+/// 判断变更后的行号是否位于某个 diff 片段内
+///
+/// 根据片段头 `@@ -a,b +c,d @@` 中变更后的范围判断（包括新增行和上下文行）。
+///
+/// # 示例
+///
+/// ```
+/// use workflow::pr::patch_contains_line;
+///
+/// let patch = "@@ -10,3 +10,4 @@ fn main() {\n context\n+added\n context\n context";
+/// assert!(patch_contains_line(patch, 10));
+/// assert!(patch_contains_line(patch, 13));
+/// assert!(!patch_contains_line(patch, 14));
+/// assert!(!patch_contains_line(patch, 9));
+/// ```
+pub fn patch_contains_line(patch: &str, line: u32) -> bool {
+    let Ok(re) = Regex::new(r"^@@ -\d+(?:,\d+)? \+(\d+)(?:,(\d+))? @@") else {
+        return false;
+    };
+    patch.lines().filter_map(|l| re.captures(l)).any(|caps| {
+        let start: u32 = caps[1].parse().unwrap_or(0);
+        let count: u32 = caps.get(2).and_then(|m| m.as_str().parse().ok()).unwrap_or(1);
+        count > 0 && line >= start && line < start.saturating_add(count)
+    })
+}
+
+/// 检查文件和行号是否位于 PR diff 中
+///
+/// 没有 diff 片段的文件（如过大的文件）只检查文件是否变更。
+///
+/// # 错误
+///
+/// 文件不在 PR 的变更文件中，或行号不在任何 diff 片段内时返回错误。
+pub fn ensure_line_in_diff(files: &[ChangedFile], path: &str, line: u32) -> Result<()> {
+    let Some(file) = files.iter().find(|file| file.path == path) else {
+        color_eyre::eyre::bail!(
+            "File '{}' is not changed in this PR, review comments can only be added to changed files",
+            path
+        );
+    };
+    if let Some(ref patch) = file.patch {
+        if !patch_contains_line(patch, line) {
+            color_eyre::eyre::bail!(
+                "Line {} of '{}' is not part of the PR diff, review comments can only be added to changed or context lines",
+                line,
+                path
+            );
+        }
+    }
+    Ok(())
+}
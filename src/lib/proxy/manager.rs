@@ -2,12 +2,10 @@
 //!
 //! 负责协调系统代理读取器和配置生成器，提供高级代理管理功能。
 
-use std::collections::HashMap;
-use std::path::PathBuf;
-
 use color_eyre::{eyre::WrapErr, Result};
+use std::collections::HashMap;
 
-use crate::base::shell::ShellConfigManager;
+use crate::base::shell::{ShellConfigEdit, ShellConfigManager};
 use crate::proxy::config_generator::ProxyConfigGenerator;
 use crate::proxy::system_reader::SystemProxyReader;
 use crate::proxy::{ProxyDisableResult, ProxyEnableResult, ProxyInfo, ProxyType};
@@ -135,6 +133,7 @@ impl ProxyManager {
                 already_configured: true,
                 proxy_command: None,
                 shell_config_path: None,
+                shell_config_backup: None,
             });
         }
 
@@ -143,24 +142,28 @@ impl ProxyManager {
 
         // 4. 根据 temporary 参数决定是否写入配置文件
         let env_vars = ProxyConfigGenerator::generate_env_vars(&proxy_info);
-        let shell_config_path = if !temporary && !env_vars.is_empty() {
+        let (shell_config_path, shell_config_backup) = if !temporary && !env_vars.is_empty() {
             // 默认行为：写入 shell 配置文件
-            ShellConfigManager::set_env_vars(&env_vars)
+            let edit = ShellConfigManager::set_env_vars(&env_vars)
                 .wrap_err("Failed to save proxy settings to shell config")?;
 
-            Some(
-                ShellConfigManager::get_config_path()
-                    .wrap_err("Failed to get shell config path")?,
+            (
+                Some(
+                    ShellConfigManager::get_config_path()
+                        .wrap_err("Failed to get shell config path")?,
+                ),
+                edit.and_then(|edit| edit.backup_path),
             )
         } else {
             // 临时模式：不写入配置文件
-            None
+            (None, None)
         };
 
         Ok(ProxyEnableResult {
             already_configured: false,
             proxy_command: proxy_cmd,
             shell_config_path,
+            shell_config_backup,
         })
     }
 
@@ -184,13 +187,18 @@ impl ProxyManager {
             return Ok(ProxyDisableResult {
                 found_proxy: false,
                 shell_config_path: None,
+                shell_config_backup: None,
                 unset_command: None,
                 current_env_proxy: current_proxy.env_proxy,
             });
         }
 
         // 3. 从配置文件移除
-        let shell_config_path = Self::remove_from_config_file(&current_proxy)?;
+        let edit = Self::remove_from_config_file(&current_proxy)?;
+        let (shell_config_path, shell_config_backup) = match edit {
+            Some(edit) => (Some(edit.config_path), edit.backup_path),
+            None => (None, None),
+        };
 
         // 4. 生成 unset 命令
         let unset_command = Self::generate_unset_command(&current_proxy.env_proxy);
@@ -198,6 +206,7 @@ impl ProxyManager {
         Ok(ProxyDisableResult {
             found_proxy: true,
             shell_config_path,
+            shell_config_backup,
             unset_command,
             current_env_proxy: current_proxy.env_proxy,
         })
@@ -226,12 +235,14 @@ impl ProxyManager {
     ///
     /// # 返回
     ///
-    /// 如果从配置文件移除了代理设置，返回配置文件路径；否则返回 `None`。
+    /// 如果从配置文件移除了代理设置，返回修改结果（备份为第一次修改前的原始文件）；否则返回 `None`。
     ///
     /// # 错误
     ///
     /// 如果读取或写入配置文件失败，返回相应的错误信息。
-    fn remove_from_config_file(current_proxy: &CurrentProxyState) -> Result<Option<PathBuf>> {
+    fn remove_from_config_file(
+        current_proxy: &CurrentProxyState,
+    ) -> Result<Option<ShellConfigEdit>> {
         // 检查是否有配置需要移除
         let has_config =
             ProxyType::all().any(|pt| current_proxy.shell_config_env.contains_key(pt.env_key()));
@@ -246,23 +257,19 @@ impl ProxyManager {
 
         let removed_from_block = proxy_keys.iter().any(|key| env_vars.remove(*key).is_some());
 
-        if removed_from_block {
+        let block_edit = if removed_from_block {
             ShellConfigManager::save_env_vars(&env_vars)
-                .wrap_err("Failed to remove proxy settings from shell config")?;
-        }
+                .wrap_err("Failed to remove proxy settings from shell config")?
+        } else {
+            None
+        };
 
         // 从整个文件移除
-        let has_in_file = ShellConfigManager::remove_env_vars(&proxy_keys)
+        let file_edit = ShellConfigManager::remove_env_vars(&proxy_keys)
             .wrap_err("Failed to remove proxy settings from shell config")?;
 
-        if removed_from_block || has_in_file {
-            Ok(Some(
-                ShellConfigManager::get_config_path()
-                    .wrap_err("Failed to get shell config path")?,
-            ))
-        } else {
-            Ok(None)
-        }
+        // 两次修改时保留第一次修改前的备份（即原始文件）
+        Ok(block_edit.or(file_edit))
     }
 
     /// 生成 unset 命令
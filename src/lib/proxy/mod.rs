@@ -4,11 +4,13 @@
 
 mod config_generator;
 mod manager;
+mod output;
 #[allow(clippy::module_inception)]
 mod proxy;
 mod system_reader;
 
 pub use config_generator::ProxyConfigGenerator;
 pub use manager::ProxyManager;
+pub use output::{ProxyCheckOutput, SystemProxyOutput};
 pub use proxy::{ProxyConfig, ProxyDisableResult, ProxyEnableResult, ProxyInfo, ProxyType};
 pub use system_reader::SystemProxyReader;
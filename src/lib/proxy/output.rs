@@ -0,0 +1,82 @@
+//! 代理命令的机器可读输出
+//!
+//! 定义 `proxy check --json` 输出的 JSON 文档结构。字段名和类型是对脚本的约定：
+//! 只新增字段，不修改或删除已有字段。
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+
+use super::proxy::{ProxyInfo, ProxyType};
+
+/// `proxy check --json` 输出
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProxyCheckOutput {
+    /// 系统设置中已启用的代理
+    pub system: Vec<SystemProxyOutput>,
+    /// 当前 shell 中的代理环境变量
+    pub environment: BTreeMap<String, String>,
+    /// shell 配置文件中的代理环境变量（不包括当前 shell 中已设置的变量）
+    pub shell_config: BTreeMap<String, String>,
+    /// 代理是否已正确配置（环境变量与系统代理一致）
+    pub configured: bool,
+}
+
+/// 系统代理
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SystemProxyOutput {
+    /// 代理类型（`http`、`https`、`socks`）
+    #[serde(rename = "type")]
+    pub proxy_type: String,
+    /// 代理地址
+    pub address: String,
+    /// 代理端口
+    pub port: u16,
+}
+
+impl ProxyCheckOutput {
+    /// 从系统代理设置和环境变量创建输出
+    ///
+    /// 只保留代理相关的环境变量（`http_proxy`、`https_proxy`、`all_proxy`）。
+    ///
+    /// # 参数
+    ///
+    /// * `proxy_info` - 系统代理设置
+    /// * `env_proxy` - 当前 shell 中的环境变量
+    /// * `shell_config_env` - shell 配置文件中的环境变量
+    /// * `configured` - 代理是否已正确配置
+    pub fn new(
+        proxy_info: &ProxyInfo,
+        env_proxy: &HashMap<String, String>,
+        shell_config_env: &HashMap<String, String>,
+        configured: bool,
+    ) -> Self {
+        let system = ProxyType::all()
+            .filter_map(|proxy_type| {
+                let config = proxy_info.get_config(proxy_type).filter(|c| c.enable)?;
+                Some(SystemProxyOutput {
+                    proxy_type: proxy_type.as_str().to_string(),
+                    address: config.address.clone()?,
+                    port: config.port?,
+                })
+            })
+            .collect();
+
+        let proxy_vars = |vars: &HashMap<String, String>| -> BTreeMap<String, String> {
+            vars.iter()
+                .filter(|(key, _)| ProxyType::all_env_keys().contains(&key.as_str()))
+                .map(|(key, value)| (key.clone(), value.clone()))
+                .collect()
+        };
+        let environment = proxy_vars(env_proxy);
+        let mut shell_config = proxy_vars(shell_config_env);
+        shell_config.retain(|key, _| !environment.contains_key(key));
+
+        Self {
+            system,
+            environment,
+            shell_config,
+            configured,
+        }
+    }
+}
@@ -28,6 +28,15 @@ impl ProxyType {
         [Self::Http, Self::Https, Self::Socks].iter().copied()
     }
 
+    /// 返回代理类型名称（`http`、`https`、`socks`）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Http => "http",
+            Self::Https => "https",
+            Self::Socks => "socks",
+        }
+    }
+
     /// 返回对应的环境变量键名
     pub fn env_key(&self) -> &'static str {
         match self {
@@ -126,6 +135,8 @@ pub struct ProxyEnableResult {
     pub proxy_command: Option<String>,
     /// Shell 配置文件路径
     pub shell_config_path: Option<std::path::PathBuf>,
+    /// 修改前的 Shell 配置文件备份路径（未修改配置文件时为 `None`）
+    pub shell_config_backup: Option<std::path::PathBuf>,
 }
 
 /// 关闭代理的结果
@@ -135,6 +146,8 @@ pub struct ProxyDisableResult {
     pub found_proxy: bool,
     /// Shell 配置文件路径
     pub shell_config_path: Option<std::path::PathBuf>,
+    /// 修改前的 Shell 配置文件备份路径（未修改配置文件时为 `None`）
+    pub shell_config_backup: Option<std::path::PathBuf>,
     /// Unset 命令（用于当前 shell 会话）
     pub unset_command: Option<String>,
     /// 当前环境变量中的代理设置
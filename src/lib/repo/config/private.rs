@@ -167,6 +167,38 @@ impl PrivateRepoConfig {
                 } else {
                     tracing::debug!("auto_accept_change_type not found in [{}] section", pr_key);
                 }
+                if let Some(delete_branch) = table.get("delete_branch_on_merge") {
+                    if let Some(delete_branch_bool) = delete_branch.as_bool() {
+                        pr_config.delete_branch_on_merge = Some(delete_branch_bool);
+                    } else {
+                        tracing::warn!(
+                            "delete_branch_on_merge is not a boolean for repo_id: {}",
+                            repo_id
+                        );
+                    }
+                }
+                if let Some(strategy) = table.get("merge_strategy") {
+                    if let Some(strategy_str) = strategy.as_str() {
+                        pr_config.merge_strategy = Some(strategy_str.to_string());
+                    } else {
+                        tracing::warn!("merge_strategy is not a string for repo_id: {}", repo_id);
+                    }
+                }
+                if let Some(by_branch) = table.get("merge_strategy_by_branch") {
+                    if let Some(by_branch_table) = by_branch.as_table() {
+                        pr_config.merge_strategy_by_branch = by_branch_table
+                            .iter()
+                            .filter_map(|(pattern, strategy)| {
+                                strategy.as_str().map(|s| (pattern.clone(), s.to_string()))
+                            })
+                            .collect();
+                    } else {
+                        tracing::warn!(
+                            "merge_strategy_by_branch is not a table for repo_id: {}",
+                            repo_id
+                        );
+                    }
+                }
                 config.pr = Some(pr_config);
             } else {
                 tracing::warn!("[{}] section is not a table", pr_key);
@@ -233,7 +265,11 @@ impl PrivateRepoConfig {
 
             // Update [${repo_id}.pr]
             if let Some(ref pr_config) = self.pr {
-                if pr_config.auto_accept_change_type.is_some() {
+                if pr_config.auto_accept_change_type.is_some()
+                    || pr_config.delete_branch_on_merge.is_some()
+                    || pr_config.merge_strategy.is_some()
+                    || !pr_config.merge_strategy_by_branch.is_empty()
+                {
                     let pr_key = format!("{}.pr", repo_id);
                     let pr_table = table.entry(pr_key).or_insert_with(|| Value::Table(Map::new()));
 
@@ -244,6 +280,31 @@ impl PrivateRepoConfig {
                                 Value::Boolean(auto_accept),
                             );
                         }
+                        if let Some(delete_branch) = pr_config.delete_branch_on_merge {
+                            pr_map.insert(
+                                "delete_branch_on_merge".to_string(),
+                                Value::Boolean(delete_branch),
+                            );
+                        }
+                        if let Some(ref strategy) = pr_config.merge_strategy {
+                            pr_map.insert(
+                                "merge_strategy".to_string(),
+                                Value::String(strategy.clone()),
+                            );
+                        }
+                        if !pr_config.merge_strategy_by_branch.is_empty() {
+                            let by_branch: Map<String, Value> = pr_config
+                                .merge_strategy_by_branch
+                                .iter()
+                                .map(|(pattern, strategy)| {
+                                    (pattern.clone(), Value::String(strategy.clone()))
+                                })
+                                .collect();
+                            pr_map.insert(
+                                "merge_strategy_by_branch".to_string(),
+                                Value::Table(by_branch),
+                            );
+                        }
                     }
                 }
             }
@@ -140,6 +140,44 @@ impl RepoConfig {
         }
     }
 
+    /// Get whether `pr merge` deletes the source branch by default (only reads from PrivateRepoConfig, personal preference)
+    ///
+    /// # Returns
+    ///
+    /// Returns `false` only if `delete_branch_on_merge` is explicitly disabled; otherwise (enabled,
+    /// not set or failed to load) returns `true`.
+    pub fn get_delete_branch_on_merge() -> bool {
+        match PrivateRepoConfig::load() {
+            Ok(config) => config.pr.unwrap_or_default().should_delete_branch_on_merge(),
+            Err(e) => {
+                tracing::debug!(
+                    "Failed to load repository config for delete_branch_on_merge: {}",
+                    e
+                );
+                true
+            }
+        }
+    }
+
+    /// Get the configured merge strategy for a target branch (only reads from PrivateRepoConfig, personal preference)
+    ///
+    /// See [`PullRequestsConfig::merge_strategy_for`] for the precedence of branch globs.
+    ///
+    /// # Returns
+    ///
+    /// Returns the strategy name as written in the configuration; `None` if not set or failed to load.
+    pub fn get_merge_strategy(target_branch: &str) -> Option<String> {
+        match PrivateRepoConfig::load() {
+            Ok(config) => config
+                .pr
+                .and_then(|pr| pr.merge_strategy_for(target_branch).map(str::to_string)),
+            Err(e) => {
+                tracing::debug!("Failed to load repository config for merge_strategy: {}", e);
+                None
+            }
+        }
+    }
+
     /// Get template commit configuration (only reads from PublicRepoConfig, project standard)
     pub fn get_template_commit() -> Map<String, Value> {
         PublicRepoConfig::load().map(|c| c.template_commit).unwrap_or_default()
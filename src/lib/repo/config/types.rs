@@ -2,6 +2,8 @@
 //!
 //! Common types used for repository configuration.
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 
@@ -22,4 +24,87 @@ pub struct BranchConfig {
 pub struct PullRequestsConfig {
     /// Auto-accept change type selection (personal preference)
     pub auto_accept_change_type: Option<bool>,
+    /// Delete the source branch after `pr merge` (personal preference, `true` if not set)
+    pub delete_branch_on_merge: Option<bool>,
+    /// Default merge strategy for `pr merge` (`squash`, `rebase` or `merge`)
+    pub merge_strategy: Option<String>,
+    /// Merge strategy overrides keyed by target branch glob (e.g. `"release/*" = "merge"`)
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub merge_strategy_by_branch: BTreeMap<String, String>,
+}
+
+impl PullRequestsConfig {
+    /// Whether `pr merge` deletes the source branch
+    ///
+    /// An unset `delete_branch_on_merge` keeps the original behaviour of `pr merge`,
+    /// which always deleted the source branch.
+    pub fn should_delete_branch_on_merge(&self) -> bool {
+        self.delete_branch_on_merge.unwrap_or(true)
+    }
+
+    /// Resolve the configured merge strategy for a target branch
+    ///
+    /// Precedence:
+    /// 1. A pattern without wildcards that equals the target branch
+    /// 2. The matching glob with the most literal characters (ties resolved by the pattern
+    ///    that sorts first alphabetically, since patterns are kept in a `BTreeMap`)
+    /// 3. The repository default `merge_strategy`
+    ///
+    /// In patterns, `*` matches any sequence of characters (including `/`) and `?` matches
+    /// a single character.
+    ///
+    /// # Returns
+    ///
+    /// Returns the strategy name as written in the configuration, or `None` if nothing is configured.
+    pub fn merge_strategy_for(&self, target_branch: &str) -> Option<&str> {
+        if let Some(strategy) = self.merge_strategy_by_branch.get(target_branch) {
+            return Some(strategy);
+        }
+
+        self.merge_strategy_by_branch
+            .iter()
+            .filter(|(pattern, _)| glob_match(pattern, target_branch))
+            // max_by_key keeps the last maximum; reverse so that the alphabetically first pattern wins ties
+            .rev()
+            .max_by_key(|(pattern, _)| literal_len(pattern))
+            .map(|(_, strategy)| strategy.as_str())
+            .or(self.merge_strategy.as_deref())
+    }
+}
+
+/// Number of non-wildcard characters in a glob pattern
+fn literal_len(pattern: &str) -> usize {
+    pattern.chars().filter(|c| !matches!(c, '*' | '?')).count()
+}
+
+/// Match text against a glob pattern supporting `*` and `?`
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` in the pattern and the text position it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
 }
@@ -0,0 +1,173 @@
+//! 配置文件备份
+//!
+//! 为原地改写配置文件的操作（如配置迁移）提供备份和恢复：
+//! - 备份时记录每个文件在备份前是否存在
+//! - 恢复时还原文件内容，并删除备份前不存在的文件
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use color_eyre::{eyre::WrapErr, Result};
+
+use crate::base::util::directory::DirectoryWalker;
+use crate::{trace_debug, trace_info, trace_warn};
+
+use super::RollbackManager;
+
+/// 单个文件的备份
+#[derive(Debug, Clone)]
+pub struct FileBackupEntry {
+    /// 原文件路径
+    pub original: PathBuf,
+    /// 备份文件路径（备份前原文件不存在时为 `None`）
+    pub backup: Option<PathBuf>,
+}
+
+/// 文件备份信息
+#[derive(Debug, Clone)]
+pub struct FileBackup {
+    /// 备份目录
+    pub backup_dir: PathBuf,
+    /// 备份的文件
+    pub entries: Vec<FileBackupEntry>,
+}
+
+impl RollbackManager {
+    /// 备份指定文件
+    ///
+    /// 在临时目录中创建唯一的备份目录，复制所有存在的文件；
+    /// 不存在的文件也会被记录，恢复时会删除之后创建的同名文件。
+    ///
+    /// # 参数
+    ///
+    /// * `paths` - 要备份的文件路径列表
+    ///
+    /// # 返回
+    ///
+    /// 返回 `FileBackup` 结构体，包含备份目录和每个文件的备份路径。
+    pub fn backup_files(paths: &[PathBuf]) -> Result<FileBackup> {
+        let backup_dir = std::env::temp_dir().join(format!(
+            "workflow-config-backup-{}-{}",
+            std::process::id(),
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap()
+                .as_nanos()
+        ));
+        DirectoryWalker::new(&backup_dir).ensure_exists()?;
+        trace_debug!("Created config backup directory: {}", backup_dir.display());
+
+        let mut entries = Vec::new();
+        for (index, original) in paths.iter().enumerate() {
+            if !original.exists() {
+                trace_debug!(
+                    "File does not exist, recording as absent: {}",
+                    original.display()
+                );
+                entries.push(FileBackupEntry {
+                    original: original.clone(),
+                    backup: None,
+                });
+                continue;
+            }
+
+            // 使用序号前缀，避免不同目录下的同名文件互相覆盖
+            let file_name = original.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+            let backup_path = backup_dir.join(format!("{}-{}", index, file_name));
+            fs::copy(original, &backup_path).wrap_err_with(|| {
+                format!(
+                    "Failed to backup file: {} -> {}",
+                    original.display(),
+                    backup_path.display()
+                )
+            })?;
+
+            trace_debug!(
+                "Backed up file: {} -> {}",
+                original.display(),
+                backup_path.display()
+            );
+            entries.push(FileBackupEntry {
+                original: original.clone(),
+                backup: Some(backup_path),
+            });
+        }
+
+        trace_info!("Backed up {} file(s)", entries.len());
+        Ok(FileBackup {
+            backup_dir,
+            entries,
+        })
+    }
+
+    /// 从备份恢复文件
+    ///
+    /// 尝试恢复所有文件，全部处理完后如果有失败的文件则返回错误。
+    ///
+    /// # 参数
+    ///
+    /// * `backup` - 文件备份信息
+    pub fn restore_files(backup: &FileBackup) -> Result<()> {
+        let mut failed = Vec::new();
+
+        for entry in &backup.entries {
+            let result = match entry.backup {
+                Some(ref backup_path) => Self::restore_file(backup_path, &entry.original),
+                None if entry.original.exists() => fs::remove_file(&entry.original)
+                    .wrap_err_with(|| format!("Failed to remove {}", entry.original.display())),
+                None => Ok(()),
+            };
+
+            if let Err(e) = result {
+                trace_warn!("Failed to restore {}: {}", entry.original.display(), e);
+                failed.push(format!("{}: {}", entry.original.display(), e));
+            }
+        }
+
+        if !failed.is_empty() {
+            color_eyre::eyre::bail!(
+                "Failed to restore {} file(s):\n  {}",
+                failed.len(),
+                failed.join("\n  ")
+            );
+        }
+
+        trace_info!("Restored {} file(s) from backup", backup.entries.len());
+        Ok(())
+    }
+
+    /// 恢复单个文件
+    fn restore_file(backup_path: &Path, target: &Path) -> Result<()> {
+        if let Some(parent) = target.parent() {
+            DirectoryWalker::new(parent).ensure_exists()?;
+        }
+        fs::copy(backup_path, target).wrap_err_with(|| {
+            format!(
+                "Failed to restore file: {} -> {}",
+                backup_path.display(),
+                target.display()
+            )
+        })?;
+        Ok(())
+    }
+
+    /// 清理文件备份
+    ///
+    /// 删除备份目录及其所有内容。
+    ///
+    /// # 参数
+    ///
+    /// * `backup` - 文件备份信息
+    pub fn cleanup_file_backup(backup: &FileBackup) -> Result<()> {
+        if backup.backup_dir.exists() {
+            fs::remove_dir_all(&backup.backup_dir).wrap_err_with(|| {
+                format!(
+                    "Failed to remove backup directory: {}",
+                    backup.backup_dir.display()
+                )
+            })?;
+            trace_debug!("Config backup directory cleaned up");
+        }
+        Ok(())
+    }
+}
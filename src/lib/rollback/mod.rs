@@ -1,4 +1,6 @@
+pub mod files;
 #[allow(clippy::module_inception)]
 pub mod rollback;
 
+pub use files::{FileBackup, FileBackupEntry};
 pub use rollback::{BackupInfo, BackupResult, RollbackManager, RollbackResult};
@@ -2,6 +2,7 @@
 //!
 //! 本模块提供了更新失败时的回滚机制，包括：
 //! - 备份当前版本的二进制文件和补全脚本
+//! - 将备份压缩为 tar.gz（可通过 `[rollback]` 配置关闭）
 //! - 在更新失败时恢复备份的文件
 //! - 清理备份文件
 
@@ -11,14 +12,22 @@ use std::path::{Path, PathBuf};
 #[cfg(unix)]
 use std::process::Command;
 
+use crate::base::format::DisplayFormatter;
 use crate::base::util::directory::DirectoryWalker;
+use crate::base::util::Unzip;
 use color_eyre::{eyre::WrapErr, Result};
 
 use crate::completion::get_all_completion_files;
 use crate::{
-    base::settings::paths::Paths, base::shell::Reload, trace_debug, trace_info, trace_warn, Detect,
+    base::settings::paths::Paths, base::settings::Settings, base::shell::Reload, trace_debug,
+    trace_info, trace_warn, Detect,
 };
 
+// ==================== 常量 ====================
+
+/// 压缩备份的归档文件名
+const BACKUP_ARCHIVE_NAME: &str = "backup.tar.gz";
+
 // ==================== 类型别名 ====================
 
 /// 恢复结果类型：成功列表和失败列表（文件名，错误信息）
@@ -57,6 +66,9 @@ pub struct RollbackResult {
 /// 备份信息
 ///
 /// 存储备份的文件路径和备份目录。
+///
+/// 当 `compressed` 为 `true` 时，备份文件被打包在备份目录下的 `backup.tar.gz` 中，
+/// 恢复前会先解压回各自的备份路径；为 `false` 时按原样（未压缩）恢复。
 #[derive(Debug, Clone)]
 pub struct BackupInfo {
     /// 备份目录
@@ -65,9 +77,16 @@ pub struct BackupInfo {
     pub binary_backups: Vec<(String, PathBuf)>, // (binary_name, backup_path)
     /// 备份的补全脚本路径
     pub completion_backups: Vec<(String, PathBuf)>, // (completion_name, backup_path)
+    /// 备份文件是否已压缩为 tar.gz
+    pub compressed: bool,
 }
 
 impl BackupInfo {
+    /// 压缩备份的归档文件路径
+    pub fn archive_path(&self) -> PathBuf {
+        self.backup_dir.join(BACKUP_ARCHIVE_NAME)
+    }
+
     // 这些方法目前未使用，但保留以备将来扩展
     #[allow(dead_code)]
     /// 创建新的备份信息
@@ -76,6 +95,7 @@ impl BackupInfo {
             backup_dir,
             binary_backups: Vec::new(),
             completion_backups: Vec::new(),
+            compressed: false,
         }
     }
 
@@ -273,9 +293,90 @@ impl RollbackManager {
         Ok(backups)
     }
 
+    /// 压缩备份文件
+    ///
+    /// 将备份目录中的文件打包为 `backup.tar.gz`，归档完整写入后再删除未压缩的备份文件。
+    /// 打包失败时删除不完整的压缩包并返回错误，未压缩的备份文件保持不变；
+    /// 归档完成后备份以压缩包为准，未压缩文件删除失败只给出警告（解压时会被覆盖）。
+    ///
+    /// # 参数
+    ///
+    /// * `backup_dir` - 备份目录
+    /// * `file_names` - 要压缩的备份文件名列表（相对于备份目录）
+    fn compress_backup(backup_dir: &Path, file_names: &[&str]) -> Result<()> {
+        let archive_path = backup_dir.join(BACKUP_ARCHIVE_NAME);
+
+        let original_size: u64 = file_names
+            .iter()
+            .filter_map(|name| fs::metadata(backup_dir.join(name)).ok())
+            .map(|metadata| metadata.len())
+            .sum();
+
+        if let Err(e) = Unzip::create_tar_gz(&archive_path, backup_dir, file_names) {
+            // 删除不完整的压缩包，保留未压缩的备份文件
+            if archive_path.exists() {
+                let _ = fs::remove_file(&archive_path);
+            }
+            return Err(e.wrap_err("Failed to compress backup files"));
+        }
+
+        for name in file_names {
+            let path = backup_dir.join(name);
+            if let Err(e) = fs::remove_file(&path) {
+                trace_warn!(
+                    "Failed to remove uncompressed backup file {}: {}",
+                    path.display(),
+                    e
+                );
+            }
+        }
+
+        let compressed_size = fs::metadata(&archive_path).map(|m| m.len()).unwrap_or(0);
+        let saved = original_size.saturating_sub(compressed_size);
+        let ratio = if original_size > 0 {
+            saved as f64 / original_size as f64 * 100.0
+        } else {
+            0.0
+        };
+        trace_info!(
+            "Compressed backup: {} -> {} (saved {}, {:.1}%)",
+            DisplayFormatter::size(original_size),
+            DisplayFormatter::size(compressed_size),
+            DisplayFormatter::size(saved),
+            ratio
+        );
+
+        Ok(())
+    }
+
+    /// 解压备份文件
+    ///
+    /// 将 `backup.tar.gz` 解压回备份目录，使各备份路径重新可用。
+    /// 未压缩的备份无需处理。
+    ///
+    /// # 参数
+    ///
+    /// * `backup_info` - 备份信息
+    fn decompress_backup(backup_info: &BackupInfo) -> Result<()> {
+        if !backup_info.compressed {
+            return Ok(());
+        }
+
+        let archive_path = backup_info.archive_path();
+        trace_debug!("Decompressing backup archive: {}", archive_path.display());
+        Unzip::extract_tar_gz(&archive_path, &backup_info.backup_dir).wrap_err_with(|| {
+            format!(
+                "Failed to decompress backup archive: {}",
+                archive_path.display()
+            )
+        })
+    }
+
     /// 创建备份
     ///
     /// 备份当前版本的二进制文件和补全脚本。
+    /// 如果启用了备份压缩（默认启用），备份文件会被打包为 tar.gz；
+    /// 压缩失败时保留未压缩的备份。
     ///
     /// # 返回
     ///
@@ -296,10 +397,32 @@ impl RollbackManager {
         let completion_backups = Self::backup_completions(&backup_dir, &completion_dir)
             .wrap_err("Failed to backup completions")?;
 
+        // 压缩备份文件（压缩失败不影响备份本身）
+        let mut compressed = false;
+        if Settings::get().rollback.is_compression_enabled() {
+            let file_names: Vec<&str> = binary_backups
+                .iter()
+                .chain(completion_backups.iter())
+                .map(|(name, _)| name.as_str())
+                .collect();
+            if !file_names.is_empty() {
+                match Self::compress_backup(&backup_dir, &file_names) {
+                    Ok(_) => compressed = true,
+                    Err(e) => {
+                        trace_warn!(
+                            "Failed to compress backup, keeping uncompressed files: {}",
+                            e
+                        );
+                    }
+                }
+            }
+        }
+
         let backup_info = BackupInfo {
             backup_dir,
             binary_backups: binary_backups.clone(),
             completion_backups: completion_backups.clone(),
+            compressed,
         };
 
         let binary_count = binary_backups.len();
@@ -492,6 +615,25 @@ impl RollbackManager {
         let mut failed_binaries = Vec::new();
         let mut failed_completions = Vec::new();
 
+        // 解压压缩的备份（旧的未压缩备份直接恢复）
+        if let Err(e) = Self::decompress_backup(backup_info) {
+            trace_warn!("{}", e);
+            for (binary_name, _) in &backup_info.binary_backups {
+                failed_binaries.push((binary_name.clone(), format!("{}", e)));
+            }
+            for (file_name, _) in &backup_info.completion_backups {
+                failed_completions.push((file_name.clone(), format!("{}", e)));
+            }
+            return Ok(RollbackResult {
+                restored_binaries,
+                restored_completions,
+                failed_binaries,
+                failed_completions,
+                shell_reload_success: None,
+                shell_config_file: None,
+            });
+        }
+
         // 恢复二进制文件
         if !backup_info.binary_backups.is_empty() {
             trace_info!("Restoring binary files");
@@ -77,6 +77,14 @@ pub struct CommitTemplates {
     /// When `false`, uses simple format: `# {title}`
     #[serde(default = "default_use_scope")]
     pub use_scope: bool,
+    /// Commit body template
+    ///
+    /// Rendered below the commit title, including the `Refs:` and `Co-authored-by:` trailers.
+    #[serde(default = "CommitTemplates::default_body_template")]
+    pub body: String,
+    /// Whether to validate rendered commit messages against Conventional Commits
+    #[serde(default)]
+    pub validate_conventional: bool,
 }
 
 fn default_use_scope() -> bool {
@@ -93,6 +101,16 @@ impl CommitTemplates {
 {{#if jira_key}}Closes {{jira_key}}{{/if}}"#
             .to_string()
     }
+
+    /// Get default commit body template
+    pub fn default_body_template() -> String {
+        r#"{{#if body}}{{body}}
+
+{{/if}}{{#if refs}}Refs: {{refs}}
+{{/if}}{{#each co_authors}}Co-authored-by: {{this}}
+{{/each}}"#
+            .to_string()
+    }
 }
 
 impl Default for CommitTemplates {
@@ -100,6 +118,8 @@ impl Default for CommitTemplates {
         Self {
             default: CommitTemplates::default_commit_template(),
             use_scope: default_use_scope(),
+            body: CommitTemplates::default_body_template(),
+            validate_conventional: false,
         }
     }
 }
@@ -37,6 +37,10 @@ pub struct CommitTemplateVars {
     ///
     /// This value comes from configuration and is passed to the template
     pub use_scope: bool,
+    /// Ticket referenced by the `Refs:` trailer (e.g., "PROJ-123", from the current branch)
+    pub refs: Option<String>,
+    /// Co-authors for the `Co-authored-by:` trailers (e.g., "Alice <alice@example.com>")
+    pub co_authors: Vec<String>,
 }
 
 /// Template variables for PR body
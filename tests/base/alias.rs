@@ -3,7 +3,7 @@
 //! 测试别名管理器的核心业务逻辑，包括：
 //! - 别名展开算法（递归展开、循环检测）
 //! - 命令行参数处理
-//! - 别名验证和管理
+//! - 别名验证和管理（名称格式、与内置命令的冲突）
 //! - 错误处理和边界条件
 //!
 //! 注意：这些测试主要关注算法逻辑，不依赖实际的配置文件
@@ -12,6 +12,7 @@ use std::collections::{HashMap, HashSet};
 
 use color_eyre::Result;
 use rstest::rstest;
+use workflow::base::alias::AliasManager;
 
 // 由于 AliasManager 依赖 Settings，我们需要模拟别名数据进行测试
 // 这里我们测试核心的展开算法逻辑
@@ -631,4 +632,65 @@ mod tests {
 
         Ok(())
     }
+
+    // ==================== 别名名称校验测试 ====================
+
+    #[rstest]
+    #[case("ci")]
+    #[case("prc")]
+    #[case("pr-create")]
+    #[case("pr_create.v2")]
+    fn test_validate_name_accepts_valid_names(#[case] name: &str) {
+        assert!(AliasManager::validate_name(name).is_ok());
+    }
+
+    #[rstest]
+    #[case("")]
+    #[case("-x")]
+    #[case("pr create")]
+    #[case("a=b")]
+    #[case("a'b")]
+    fn test_validate_name_rejects_invalid_names(#[case] name: &str) {
+        assert!(AliasManager::validate_name(name).is_err());
+    }
+
+    #[test]
+    fn test_builtin_commands_include_top_level_commands() {
+        let commands = AliasManager::builtin_commands();
+        for name in ["pr", "branch", "alias", "help"] {
+            assert!(
+                commands.iter().any(|command| command == name),
+                "missing {}",
+                name
+            );
+        }
+    }
+
+    #[rstest]
+    #[case("pr")]
+    #[case("alias")]
+    #[case("help")]
+    fn test_validate_alias_name_rejects_builtin_commands(#[case] name: &str) {
+        let error = AliasManager::validate_alias_name(name).unwrap_err();
+        assert!(error.to_string().contains("built-in command"));
+    }
+
+    #[test]
+    fn test_validate_alias_name_accepts_unused_name() {
+        assert!(AliasManager::validate_alias_name("prc").is_ok());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_validate_shell_alias_name_rejects_command_in_path() {
+        // `sh` 在所有 Unix 系统的 PATH 中都存在
+        assert!(AliasManager::find_in_path("sh").is_some());
+        let error = AliasManager::validate_shell_alias_name("sh").unwrap_err();
+        assert!(error.to_string().contains("existing command"));
+    }
+
+    #[test]
+    fn test_find_in_path_returns_none_for_unknown_command() {
+        assert!(AliasManager::find_in_path("workflow-no-such-command-xyz").is_none());
+    }
 }
@@ -0,0 +1,388 @@
+//! Base/Dialog 表单默认值测试
+//!
+//! 测试 FormBuilder 中字段默认值的解析逻辑，包括：
+//! - 静态默认值
+//! - 根据之前的回答计算的动态默认值
+//! - 选择字段的默认选项
+//! - 异步验证器的设置和执行
+//! - 密码字段的输入确认设置
+//! - 返回上一个字段的导航，以及修改回答后条件步骤的重新判断
+//!
+//! 注意：我们不测试实际的UI交互，只测试默认值的计算
+
+use std::collections::HashMap;
+
+use pretty_assertions::assert_eq;
+use workflow::base::dialog::{
+    FieldDefaultValue, FormAnswer, FormBuilder, FormPrompt, FormResult, GroupConfig,
+};
+
+/// 构建包含邮箱和用户名字段的表单，用户名默认取邮箱 `@` 之前的部分
+fn build_account_form() -> FormBuilder {
+    FormBuilder::new().add_group(
+        "account",
+        |g| {
+            g.step(|f| {
+                f.add_text("email", "Email")
+                    .required()
+                    .add_text("username", "Username")
+                    .default_with(|answers| {
+                        answers
+                            .get("email")
+                            .and_then(|email| email.split('@').next())
+                            .filter(|name| !name.is_empty())
+                            .map(|name| name.to_string())
+                    })
+            })
+        },
+        GroupConfig::required(),
+    )
+}
+
+fn answers(pairs: &[(&str, &str)]) -> FormResult {
+    FormResult {
+        values: pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect::<HashMap<_, _>>(),
+    }
+}
+
+// ==================== 动态默认值测试 ====================
+
+#[test]
+fn test_dynamic_default_depends_on_previous_answer() {
+    let form = build_account_form();
+    let fields = &form.groups[0].steps[0].fields;
+    let username = &fields[1];
+
+    let resolved = username.default_for(&answers(&[("email", "alice@example.com")]));
+
+    assert_eq!(
+        resolved.and_then(|v| v.as_string()),
+        Some("alice".to_string())
+    );
+}
+
+#[test]
+fn test_dynamic_default_without_previous_answer() {
+    let form = build_account_form();
+    let username = &form.groups[0].steps[0].fields[1];
+
+    assert!(username.default_for(&FormResult::new()).is_none());
+    assert!(username.default_for(&answers(&[("email", "@example.com")])).is_none());
+}
+
+#[test]
+fn test_dynamic_default_is_not_resolved_eagerly() {
+    let default = FieldDefaultValue::dynamic(|_: &FormResult| Some(true));
+
+    assert_eq!(default.as_bool(), None);
+    assert_eq!(
+        default.resolve(&FormResult::new()).and_then(|v| v.as_bool()),
+        Some(true)
+    );
+}
+
+#[test]
+fn test_nested_dynamic_default_is_ignored() {
+    let default = FieldDefaultValue::dynamic(|_: &FormResult| {
+        Some(FieldDefaultValue::dynamic(|_: &FormResult| Some("inner")))
+    });
+
+    assert!(default.resolve(&FormResult::new()).is_none());
+}
+
+// ==================== 静态默认值测试 ====================
+
+#[test]
+fn test_static_default_ignores_answers() {
+    let form = FormBuilder::new().add_group(
+        "group",
+        |g| g.step(|f| f.add_text("name", "Name").default("fixed")),
+        GroupConfig::required(),
+    );
+    let field = &form.groups[0].steps[0].fields[0];
+
+    let resolved = field.default_for(&answers(&[("email", "alice@example.com")]));
+
+    assert_eq!(
+        resolved.and_then(|v| v.as_string()),
+        Some("fixed".to_string())
+    );
+}
+
+#[test]
+fn test_selection_dynamic_default() {
+    let form = FormBuilder::new().add_group(
+        "llm",
+        |g| {
+            g.step(|f| {
+                f.add_selection(
+                    "provider",
+                    "Provider",
+                    vec!["openai".into(), "deepseek".into(), "proxy".into()],
+                )
+                .default_with(|answers| answers.get("url").map(|_| "proxy"))
+            })
+        },
+        GroupConfig::required(),
+    );
+    let field = &form.groups[0].steps[0].fields[0];
+
+    assert!(field.default_choice.is_none());
+    assert_eq!(
+        field
+            .default_for(&answers(&[("url", "https://llm.example.com")]))
+            .and_then(|v| v.as_string()),
+        Some("proxy".to_string())
+    );
+    assert!(field.default_for(&FormResult::new()).is_none());
+}
+
+// ==================== 异步验证测试 ====================
+
+#[test]
+fn test_async_validator_receives_previous_answers() {
+    let form = FormBuilder::new().add_group(
+        "jira",
+        |g| {
+            g.step(|f| {
+                f.add_text("jira_email", "Jira email")
+                    .required()
+                    .add_text("jira_api_token", "Jira API token")
+                    .required()
+                    .validate_async(
+                        "Verifying Jira credentials...",
+                        |token, answers| match answers.get("jira_email") {
+                            Some(email) if token == "valid" && email.contains('@') => Ok(()),
+                            _ => Err("Invalid credentials".to_string()),
+                        },
+                    )
+            })
+        },
+        GroupConfig::required(),
+    );
+    let fields = &form.groups[0].steps[0].fields;
+    assert!(fields[0].async_validator.is_none());
+
+    let validator = fields[1].async_validator.as_ref().expect("async validator");
+    let previous = answers(&[("jira_email", "alice@example.com")]);
+
+    assert_eq!(validator.message, "Verifying Jira credentials...");
+    assert_eq!(validator.run("valid", &previous), Ok(()));
+    assert_eq!(
+        validator.run("invalid", &previous),
+        Err("Invalid credentials".to_string())
+    );
+    assert!(validator.run("valid", &FormResult::new()).is_err());
+}
+
+#[test]
+fn test_skip_async_validation() {
+    assert!(!FormBuilder::new().skip_async_validation);
+    assert!(FormBuilder::new().skip_async_validation(true).skip_async_validation);
+}
+
+// ==================== 密码字段测试 ====================
+
+#[test]
+fn test_password_field_confirm_entry() {
+    let form = FormBuilder::new().add_group(
+        "github",
+        |g| {
+            g.step(|f| {
+                f.add_password("github_api_token", "GitHub API token")
+                    .required()
+                    .add_password("new_password", "New password")
+                    .confirm_entry()
+                    .add_text("github_name", "GitHub username")
+                    .confirm_entry()
+            })
+        },
+        GroupConfig::required(),
+    );
+    let fields = &form.groups[0].steps[0].fields;
+
+    // 默认不需要再次输入确认
+    assert!(!fields[0].confirm_entry);
+    assert!(fields[1].confirm_entry);
+    // 文本字段会记录设置，但只有密码字段会使用
+    assert!(fields[2].confirm_entry);
+}
+
+// ==================== 导航测试 ====================
+
+fn prompt_name(prompt: FormPrompt<'_>) -> String {
+    match prompt {
+        FormPrompt::Group(group) => group.id.clone(),
+        FormPrompt::Field(field) => field.name.clone(),
+    }
+}
+
+fn value(v: &str) -> FormAnswer {
+    FormAnswer::Value(v.to_string())
+}
+
+/// 按脚本回答表单，返回结果和依次询问的名称
+fn run_script(
+    form: FormBuilder,
+    script: Vec<(&'static str, FormAnswer)>,
+) -> (FormResult, Vec<String>) {
+    let mut script = script.into_iter();
+    let mut asked = Vec::new();
+    let result = form
+        .run_with(|prompt, _values| {
+            let name = prompt_name(prompt);
+            let (expected, answer) = script.next().expect("unexpected prompt");
+            assert_eq!(name, expected);
+            asked.push(name);
+            Ok(answer)
+        })
+        .unwrap();
+    assert!(
+        script.next().is_none(),
+        "not all scripted answers were used"
+    );
+    (result, asked)
+}
+
+/// provider 为 proxy 时询问 proxy_url，最后询问 language
+fn build_llm_form() -> FormBuilder {
+    FormBuilder::new().add_group(
+        "llm",
+        |g| {
+            g.step(|f| {
+                f.add_selection(
+                    "provider",
+                    "Provider",
+                    vec!["openai".into(), "proxy".into()],
+                )
+            })
+            .step_if("provider", "proxy", |f| {
+                f.add_text("proxy_url", "Proxy URL")
+            })
+            .step(|f| f.add_text("language", "Language"))
+        },
+        GroupConfig::required(),
+    )
+}
+
+#[test]
+fn test_back_re_evaluates_conditional_steps() {
+    let (result, asked) = run_script(
+        build_llm_form(),
+        vec![
+            ("provider", value("proxy")),
+            ("proxy_url", value("https://llm.example.com")),
+            ("language", FormAnswer::Back),
+            ("proxy_url", FormAnswer::Back),
+            ("provider", value("openai")),
+            ("language", value("en")),
+        ],
+    );
+
+    assert_eq!(
+        asked,
+        vec![
+            "provider",
+            "proxy_url",
+            "language",
+            "proxy_url",
+            "provider",
+            "language"
+        ]
+    );
+    assert_eq!(result.get("provider"), Some(&"openai".to_string()));
+    assert_eq!(result.get("language"), Some(&"en".to_string()));
+    // proxy 步骤不再执行，之前输入的值被移除
+    assert!(!result.has("proxy_url"));
+}
+
+#[test]
+fn test_back_preserves_entered_values() {
+    let form = FormBuilder::new().add_group(
+        "user",
+        |g| g.step(|f| f.add_text("name", "Name").add_text("email", "Email")),
+        GroupConfig::required(),
+    );
+    let mut seen = Vec::new();
+    let mut script = vec![
+        value("alice"),
+        FormAnswer::Back,
+        value("bob"),
+        value("bob@example.com"),
+    ]
+    .into_iter();
+
+    let result = form
+        .run_with(|prompt, values| {
+            seen.push((prompt_name(prompt), values.get("name").cloned()));
+            Ok(script.next().unwrap())
+        })
+        .unwrap();
+
+    // 返回 name 时仍能看到之前输入的值
+    assert_eq!(
+        seen,
+        vec![
+            ("name".to_string(), None),
+            ("email".to_string(), Some("alice".to_string())),
+            ("name".to_string(), Some("alice".to_string())),
+            ("email".to_string(), Some("bob".to_string())),
+        ]
+    );
+    assert_eq!(result.get("name"), Some(&"bob".to_string()));
+    assert_eq!(result.get("email"), Some(&"bob@example.com".to_string()));
+}
+
+#[test]
+fn test_back_to_optional_group_prompt() {
+    let form = FormBuilder::new()
+        .add_group(
+            "jira",
+            |g| g.step(|f| f.add_text("jira_email", "Jira email")),
+            GroupConfig::required(),
+        )
+        .add_group(
+            "llm",
+            |g| g.step(|f| f.add_text("llm_key", "LLM key").add_text("llm_model", "LLM model")),
+            GroupConfig::optional().with_title("LLM Configuration"),
+        );
+
+    let (result, _) = run_script(
+        form,
+        vec![
+            ("jira_email", value("dev@example.com")),
+            ("llm", value("yes")),
+            ("llm_key", value("sk-test")),
+            ("llm_model", FormAnswer::Back),
+            ("llm_key", FormAnswer::Back),
+            ("llm", value("no")),
+        ],
+    );
+
+    assert_eq!(
+        result.get("jira_email"),
+        Some(&"dev@example.com".to_string())
+    );
+    // 组被关闭后，组内之前输入的值被移除
+    assert!(!result.has("llm_key"));
+}
+
+#[test]
+fn test_back_on_first_field_stays() {
+    let form = FormBuilder::new().add_group(
+        "user",
+        |g| g.step(|f| f.add_text("name", "Name")),
+        GroupConfig::required(),
+    );
+
+    let (result, asked) = run_script(
+        form,
+        vec![("name", FormAnswer::Back), ("name", value("alice"))],
+    );
+
+    assert_eq!(asked, vec!["name", "name"]);
+    assert_eq!(result.get("name"), Some(&"alice".to_string()));
+}
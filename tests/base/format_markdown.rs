@@ -0,0 +1,123 @@
+//! Markdown 终端渲染测试
+//!
+//! 测试 `DisplayFormatter::render_markdown` 将 Markdown（标题、列表、代码块、引用、行内格式）
+//! 渲染为终端文本。测试中禁用颜色输出，只比较渲染后的文本。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use serial_test::serial;
+use workflow::base::format::DisplayFormatter;
+use workflow::base::logger::console::set_colors_enabled;
+
+/// 禁用颜色后渲染
+fn render(text: &str) -> String {
+    set_colors_enabled(false);
+    DisplayFormatter::render_markdown(text)
+}
+
+// ==================== 块级格式测试 ====================
+
+#[rstest]
+#[case("# Summary", "Summary")]
+#[case("### Steps ###", "Steps")]
+#[case("## C#", "C#")]
+#[case("#hashtag", "#hashtag")]
+#[case("> Quoted **text**", "│ Quoted text")]
+#[case("plain text", "plain text")]
+#[serial]
+fn test_render_line_formats(#[case] input: &str, #[case] expected: &str) {
+    assert_eq!(render(input), expected);
+}
+
+#[test]
+#[serial]
+fn test_render_horizontal_rule() {
+    let output = render("above\n---\nbelow");
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].chars().all(|c| c == '-'));
+    assert!(lines[1].len() > 3);
+}
+
+#[test]
+#[serial]
+fn test_render_nested_lists() {
+    let input = "- first\n  - nested\n- second\n1. one\n2. two\n   * two-a";
+
+    assert_eq!(
+        render(input),
+        "• first\n  • nested\n• second\n1. one\n2. two\n  • two-a"
+    );
+}
+
+#[test]
+#[serial]
+fn test_render_emphasis_at_line_start_is_not_list() {
+    assert_eq!(render("*Note* this"), "Note this");
+}
+
+// ==================== 代码块测试 ====================
+
+#[test]
+#[serial]
+fn test_render_code_block_preserves_whitespace() {
+    let input = "```rust\nfn main() {\n    let  x = 1;\n\n\tprintln!(\"{}\", x);\n}\n```";
+
+    assert_eq!(
+        render(input),
+        "    fn main() {\n        let  x = 1;\n\n    \tprintln!(\"{}\", x);\n    }"
+    );
+}
+
+#[test]
+#[serial]
+fn test_render_code_block_ignores_markup() {
+    let input = "~~~\n**not bold** [link](url)\n# not heading\n~~~\nafter **bold**";
+
+    assert_eq!(
+        render(input),
+        "    **not bold** [link](url)\n    # not heading\nafter bold"
+    );
+}
+
+#[test]
+#[serial]
+fn test_render_inline_code_ignores_markup() {
+    assert_eq!(
+        render("Run `cargo test -- --nocapture` on **main**"),
+        "Run cargo test -- --nocapture on main"
+    );
+}
+
+// ==================== 链接测试 ====================
+
+#[rstest]
+#[case(
+    "See [the docs](https://example.com/a_b_c) for details",
+    "See the docs (https://example.com/a_b_c) for details"
+)]
+#[case("[https://example.com](https://example.com)", "https://example.com")]
+#[case("<https://example.com/x_y_z>", "https://example.com/x_y_z")]
+#[case(
+    "![screenshot](https://example.com/s.png)",
+    "[image: screenshot] (https://example.com/s.png)"
+)]
+#[case("Visit https://example.com/a_b_c.", "Visit https://example.com/a_b_c.")]
+#[serial]
+fn test_render_links_show_url(#[case] input: &str, #[case] expected: &str) {
+    assert_eq!(render(input), expected);
+}
+
+// ==================== 行内格式测试 ====================
+
+#[rstest]
+#[case("**bold** and __bold__", "bold and bold")]
+#[case("*italic* and _italic_", "italic and italic")]
+#[case("~~removed~~ text", "removed text")]
+#[case("keep snake_case_name", "keep snake_case_name")]
+#[case("2 * 3 * 4", "2 * 3 * 4")]
+#[serial]
+fn test_render_inline_formats(#[case] input: &str, #[case] expected: &str) {
+    assert_eq!(render(input), expected);
+}
@@ -0,0 +1,97 @@
+//! Base/HTTP/Client 模块测试
+//!
+//! 测试 HTTP 客户端的超时处理，包括：
+//! - 请求超时返回 `HttpRequestError`（超时类型）
+//! - 连接失败返回 `HttpRequestError`（连接类型），并区分 DNS 解析失败
+//! - 进程级默认超时时间
+//! - 超时和连接错误的重试判断
+
+use std::net::TcpListener;
+use std::time::Duration;
+
+use serde_json::Value;
+use serial_test::serial;
+
+use workflow::base::http::{HttpClient, HttpRequestError, HttpRequestErrorKind, RequestConfig};
+use workflow::base::settings::Settings;
+
+use crate::common::http_helpers::unused_local_url;
+
+// ==================== 错误类型测试 ====================
+
+#[test]
+fn test_request_timeout_returns_timeout_error() {
+    // 接受连接但从不响应的服务器
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind");
+    let url = format!(
+        "http://{}/",
+        listener.local_addr().expect("Failed to get address")
+    );
+
+    let config = RequestConfig::<Value, Value>::new().timeout(Duration::from_millis(200));
+    let error = HttpClient::global()
+        .expect("Failed to create client")
+        .get(&url, config)
+        .expect_err("Request should time out");
+
+    let request_error =
+        error.downcast_ref::<HttpRequestError>().expect("Expected HttpRequestError");
+    assert_eq!(request_error.kind, HttpRequestErrorKind::Timeout);
+    assert!(request_error.is_timeout());
+    assert!(!request_error.is_connect());
+    assert!(request_error.is_retryable());
+    assert_eq!(request_error.url, url);
+    assert!(error.to_string().contains("timed out"));
+    drop(listener);
+}
+
+#[test]
+fn test_connection_refused_returns_connect_error() {
+    let url = unused_local_url();
+
+    let config = RequestConfig::<Value, Value>::new().timeout(Duration::from_secs(5));
+    let error = HttpClient::global()
+        .expect("Failed to create client")
+        .get(&url, config)
+        .expect_err("Connection should be refused");
+
+    let request_error =
+        error.downcast_ref::<HttpRequestError>().expect("Expected HttpRequestError");
+    assert_eq!(request_error.kind, HttpRequestErrorKind::Connect);
+    assert!(request_error.is_connect());
+    assert!(!request_error.is_timeout());
+    assert!(!request_error.is_dns_failure());
+    assert!(!request_error.is_tls_failure());
+    assert!(request_error.is_retryable());
+    assert_eq!(error.to_string(), format!("Failed to connect to {}", url));
+}
+
+#[test]
+fn test_unknown_host_returns_dns_failure() {
+    let config = RequestConfig::<Value, Value>::new().timeout(Duration::from_secs(5));
+    let error = HttpClient::global()
+        .expect("Failed to create client")
+        .get("http://workflow-test.invalid/", config)
+        .expect_err("Host should not resolve");
+
+    let request_error =
+        error.downcast_ref::<HttpRequestError>().expect("Expected HttpRequestError");
+    assert!(request_error.is_connect());
+    assert!(request_error.is_dns_failure());
+    assert!(!request_error.is_tls_failure());
+}
+
+// ==================== 默认超时时间测试 ====================
+
+#[test]
+#[serial]
+fn test_default_timeout_override() {
+    HttpClient::set_default_timeout(Duration::from_secs(5));
+    assert_eq!(HttpClient::default_timeout(), Duration::from_secs(5));
+
+    HttpClient::set_default_timeout(Duration::ZERO);
+    assert_eq!(
+        HttpClient::default_timeout(),
+        Settings::get().http.timeout()
+    );
+}
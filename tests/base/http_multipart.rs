@@ -0,0 +1,165 @@
+//! Base/HTTP/Multipart 模块测试
+//!
+//! 测试 multipart/form-data 请求体的构建和发送，包括：
+//! - 请求体格式（与 fixture 逐字节比较）
+//! - 请求体长度与实际内容一致
+//! - 字段名和文件名的转义
+//! - boundary 和文件的校验
+//! - `HttpClient::post_multipart` 发送的 Headers 和请求体
+
+use std::fs;
+use std::io::Read;
+
+use mockito::Matcher;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use tempfile::tempdir;
+
+use workflow::base::http::{HttpClient, MultipartBody, MultipartFile, MultipartRequestConfig};
+
+use crate::common::helpers::fixture_path;
+use crate::common::http_helpers::MockServer;
+
+/// fixture 使用的 boundary
+const FIXTURE_BOUNDARY: &str = "workflow-test-boundary";
+
+/// 读取请求体的全部内容
+fn read_body(body: MultipartBody) -> Vec<u8> {
+    let mut content = Vec::new();
+    body.into_reader().read_to_end(&mut content).expect("Failed to read body");
+    content
+}
+
+// ==================== 请求体格式测试 ====================
+
+#[test]
+fn test_multipart_body_matches_fixture() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let log_path = dir.path().join("build.log");
+    let dump_path = dir.path().join("dump.bin");
+    fs::write(&log_path, "line 1\nline 2\n").expect("Failed to write file");
+    fs::write(&dump_path, [0u8, 1, 2]).expect("Failed to write file");
+
+    let files = [
+        MultipartFile::new("file", &log_path).content_type("text/plain"),
+        MultipartFile::new("file", &dump_path),
+    ];
+    let body = MultipartBody::with_boundary(
+        FIXTURE_BOUNDARY,
+        &[("comment", "Build log for PROJ-123"), ("minorEdit", "true")],
+        &files,
+    )
+    .expect("Failed to build body");
+
+    let expected = fs::read(fixture_path("multipart_body.txt")).expect("Failed to read fixture");
+    assert_eq!(body.content_length(), expected.len() as u64);
+    assert_eq!(
+        String::from_utf8_lossy(&read_body(body)),
+        String::from_utf8_lossy(&expected)
+    );
+}
+
+#[test]
+fn test_multipart_body_without_parts_only_has_closing_boundary() {
+    let body = MultipartBody::with_boundary("b", &[], &[]).expect("Failed to build body");
+
+    assert_eq!(body.content_length(), 7);
+    assert_eq!(read_body(body), b"--b--\r\n");
+}
+
+#[test]
+fn test_multipart_body_uses_custom_file_name_and_escapes_quotes() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let path = dir.path().join("report.txt");
+    fs::write(&path, "ok").expect("Failed to write file");
+
+    let files = [MultipartFile::new("file", &path).file_name("final \"v2\".txt")];
+    let body = MultipartBody::with_boundary("b", &[("a\"b", "value")], &files)
+        .expect("Failed to build body");
+    let content = String::from_utf8(read_body(body)).expect("Body should be UTF-8");
+
+    assert!(content.contains("name=\"a%22b\""));
+    assert!(content.contains("filename=\"final %22v2%22.txt\""));
+}
+
+#[test]
+fn test_multipart_body_generated_boundaries_are_unique() {
+    let first = MultipartBody::new(&[], &[]).expect("Failed to build body");
+    let second = MultipartBody::new(&[], &[]).expect("Failed to build body");
+
+    assert_ne!(first.boundary(), second.boundary());
+    assert_eq!(
+        first.content_type(),
+        format!("multipart/form-data; boundary={}", first.boundary())
+    );
+}
+
+// ==================== 校验测试 ====================
+
+#[test]
+fn test_multipart_body_rejects_invalid_boundary() {
+    assert!(MultipartBody::with_boundary("", &[], &[]).is_err());
+    assert!(MultipartBody::with_boundary("a\r\nb", &[], &[]).is_err());
+    assert!(MultipartBody::with_boundary("x".repeat(71), &[], &[]).is_err());
+}
+
+#[test]
+fn test_multipart_body_missing_file_returns_error() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let files = [MultipartFile::new("file", dir.path().join("missing.log"))];
+
+    let error = MultipartBody::new(&[], &files).err().expect("Missing file should fail");
+    assert!(error.to_string().contains("Failed to open file"));
+}
+
+#[test]
+fn test_multipart_body_directory_returns_error() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let files = [MultipartFile::new("file", dir.path())];
+
+    assert!(MultipartBody::new(&[], &files).is_err());
+}
+
+// ==================== 发送测试 ====================
+
+#[test]
+fn test_post_multipart_sends_headers_and_body() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let path = dir.path().join("build.log");
+    fs::write(&path, "hello").expect("Failed to write file");
+
+    let mut mock_server = MockServer::new();
+    let mock = mock_server
+        .server
+        .as_mut()
+        .mock("POST", "/upload")
+        .match_header("x-atlassian-token", "no-check")
+        .match_header(
+            "content-type",
+            Matcher::Regex(r"^multipart/form-data; boundary=workflow-boundary-".to_string()),
+        )
+        .match_header("content-length", Matcher::Any)
+        .match_body(Matcher::AllOf(vec![
+            Matcher::Regex(r#"name="comment"\r\n\r\nnightly\r\n"#.to_string()),
+            Matcher::Regex(
+                r#"filename="build.log"\r\nContent-Type: text/plain\r\n\r\nhello\r\n"#.to_string(),
+            ),
+        ]))
+        .with_status(200)
+        .with_body("[]")
+        .create();
+
+    let files = [MultipartFile::new("file", &path).content_type("text/plain")];
+    let response = HttpClient::global()
+        .expect("Failed to create client")
+        .post_multipart(
+            &format!("{}/upload", mock_server.base_url),
+            &[("comment", "nightly")],
+            &files,
+            MultipartRequestConfig::<Value>::new(),
+        )
+        .expect("Request should succeed");
+
+    assert_eq!(response.status, 200);
+    mock.assert();
+}
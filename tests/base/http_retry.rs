@@ -14,6 +14,7 @@ use color_eyre::{eyre::eyre, Result};
 use rstest::rstest;
 
 use workflow::base::http::retry::{HttpRetry, HttpRetryConfig};
+use workflow::base::http::HttpStatusError;
 
 use std::sync::{Arc, Mutex};
 
@@ -290,6 +291,62 @@ mod tests {
         assert!(error_msg.contains("failed after 1 retries"));
     }
 
+    #[rstest]
+    #[case(429, true)]
+    #[case(500, true)]
+    #[case(503, true)]
+    #[case(400, false)]
+    #[case(401, false)]
+    #[case(404, false)]
+    fn test_http_status_error_retryable(#[case] status: u16, #[case] retryable: bool) {
+        let config = HttpRetryConfig {
+            max_retries: 1,
+            initial_delay: 0,
+            max_delay: 30,
+            backoff_multiplier: 2.0,
+            interactive: false,
+        };
+        let attempts = Arc::new(Mutex::new(0u32));
+        let counter = attempts.clone();
+
+        let result = HttpRetry::retry(
+            move || -> Result<String> {
+                *counter.lock().unwrap() += 1;
+                Err(HttpStatusError::new(status, format!("HTTP {}", status)).into())
+            },
+            &config,
+            "status test",
+        );
+
+        assert!(result.is_err());
+        assert_eq!(HttpStatusError::new(status, "").is_retryable(), retryable);
+        let expected_attempts = if retryable { 2 } else { 1 };
+        assert_eq!(*attempts.lock().unwrap(), expected_attempts);
+    }
+
+    #[test]
+    fn test_retry_with_notify_reports_each_retry() {
+        let config = HttpRetryConfig {
+            max_retries: 3,
+            initial_delay: 0,
+            max_delay: 30,
+            backoff_multiplier: 2.0,
+            interactive: false,
+        };
+        let retries = Mutex::new(Vec::new());
+
+        let result = HttpRetry::retry_with_notify(
+            create_success_after_attempts(3),
+            &config,
+            "notify test",
+            |retry| retries.lock().unwrap().push(retry),
+        )
+        .unwrap();
+
+        assert_eq!(result.retry_count, 2);
+        assert_eq!(*retries.lock().unwrap(), vec![1, 2]);
+    }
+
     // ==================== 边界条件测试 ====================
 
     #[test]
@@ -1,11 +1,16 @@
 //! LLM 客户端测试
 //!
-//! 测试 LLM 客户端的 JSON 解析功能，支持多种 OpenAI 兼容格式。
+//! 测试 LLM 客户端的 JSON 解析功能，支持多种 OpenAI 兼容格式；
+//! 以及遇到临时错误（429/5xx）时的重试行为。
 
 use insta::assert_json_snapshot;
 use pretty_assertions::assert_eq;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use rstest::rstest;
 
-use serde_json::json;
+use crate::common::http_helpers::MockServer;
+use serde_json::{json, Value};
+use workflow::base::http::HttpRetryConfig;
 use workflow::base::llm::client::LLMClient;
 
 #[test]
@@ -123,3 +128,145 @@ fn test_extract_from_cerebras_proxy() {
     // 使用快照测试验证 JSON 结构
     assert_json_snapshot!("cerebras_proxy_response", json);
 }
+
+// ==================== 重试测试 ====================
+
+/// 测试用的重试配置（不等待）
+fn no_delay_retry_config(max_retries: u32) -> HttpRetryConfig {
+    HttpRetryConfig {
+        initial_delay: 0,
+        ..LLMClient::retry_config(max_retries)
+    }
+}
+
+fn request_headers() -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    headers
+}
+
+fn request_payload() -> Value {
+    json!({
+        "model": "gpt-4",
+        "messages": [{ "role": "user", "content": "Hello" }]
+    })
+}
+
+fn completion_body(content: &str) -> String {
+    json!({
+        "id": "chatcmpl-test",
+        "object": "chat.completion",
+        "created": 1234567890,
+        "model": "gpt-4",
+        "choices": [{
+            "index": 0,
+            "message": { "role": "assistant", "content": content },
+            "finish_reason": "stop"
+        }],
+        "usage": { "prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15 }
+    })
+    .to_string()
+}
+
+#[test]
+fn test_retry_config_is_non_interactive() {
+    let config = LLMClient::retry_config(3);
+
+    assert_eq!(config.max_retries, 3);
+    assert!(!config.interactive);
+}
+
+#[test]
+fn test_send_with_retry_recovers_from_503() {
+    let mut mock_server = MockServer::new();
+    let url = format!("{}/chat/completions", mock_server.base_url);
+
+    // 前两次返回 503，第三次返回 200
+    let overloaded = mock_server
+        .server
+        .as_mut()
+        .mock("POST", "/chat/completions")
+        .with_status(503)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"error":{"message":"Model overloaded"}}"#)
+        .expect(2)
+        .create();
+    let success = mock_server
+        .server
+        .as_mut()
+        .mock("POST", "/chat/completions")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(completion_body("Recovered"))
+        .expect(1)
+        .create();
+
+    let result = LLMClient::global()
+        .send_with_retry(
+            &url,
+            &request_headers(),
+            &request_payload(),
+            &no_delay_retry_config(3),
+        )
+        .unwrap();
+
+    assert_eq!(result, "Recovered");
+    overloaded.assert();
+    success.assert();
+}
+
+#[test]
+fn test_send_with_retry_gives_up_after_max_retries() {
+    let mut mock_server = MockServer::new();
+    let url = format!("{}/chat/completions", mock_server.base_url);
+
+    let overloaded = mock_server
+        .server
+        .as_mut()
+        .mock("POST", "/chat/completions")
+        .with_status(502)
+        .with_body("Bad Gateway")
+        .expect(3)
+        .create();
+
+    let error = LLMClient::global()
+        .send_with_retry(
+            &url,
+            &request_headers(),
+            &request_payload(),
+            &no_delay_retry_config(2),
+        )
+        .unwrap_err();
+
+    assert!(error.to_string().contains("LLM request failed after 2 retries"));
+    overloaded.assert();
+}
+
+#[rstest]
+#[case(400, "Bad Request")]
+#[case(401, "Unauthorized")]
+fn test_send_with_retry_does_not_retry_client_errors(#[case] status: usize, #[case] body: &str) {
+    let mut mock_server = MockServer::new();
+    let url = format!("{}/chat/completions", mock_server.base_url);
+
+    let rejected = mock_server
+        .server
+        .as_mut()
+        .mock("POST", "/chat/completions")
+        .with_status(status)
+        .with_body(body)
+        .expect(1)
+        .create();
+
+    let error = LLMClient::global()
+        .send_with_retry(
+            &url,
+            &request_headers(),
+            &request_payload(),
+            &no_delay_retry_config(3),
+        )
+        .unwrap_err();
+
+    assert!(error.to_string().contains(&status.to_string()));
+    rejected.assert();
+}
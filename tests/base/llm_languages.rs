@@ -0,0 +1,199 @@
+//! Base/LLM 语言支持测试
+//!
+//! 测试 `SUPPORTED_LANGUAGES` 中的语言定义和查找逻辑：
+//! - 每个支持的语言代码都能查找到，且 instruction 非空
+//! - 语言代码不区分大小写，`_` 视为 `-`
+//! - 常用别名和语言名称匹配
+//! - 根据 commit 消息自动检测输出语言（包括混合语言的 commit）
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use std::collections::HashSet;
+use workflow::base::llm::{
+    detect_language, detect_text_language, find_language, get_language_instruction,
+    get_output_language_display_name, get_output_language_display_names,
+    get_supported_language_codes, get_supported_language_display_names, is_auto_language,
+    resolve_language_code, AUTO_LANGUAGE_DISPLAY_NAME, SUPPORTED_LANGUAGES,
+};
+
+// ==================== 语言定义测试 ====================
+
+#[test]
+fn test_every_supported_code_resolves() {
+    for lang in SUPPORTED_LANGUAGES {
+        let found = find_language(lang.code).expect("supported code should resolve");
+        assert_eq!(found.code, lang.code);
+        assert!(!lang.name.is_empty(), "{} has no name", lang.code);
+        assert!(
+            !lang.native_name.is_empty(),
+            "{} has no native name",
+            lang.code
+        );
+        assert!(
+            !get_language_instruction(lang.code).trim().is_empty(),
+            "{} has no instruction",
+            lang.code
+        );
+    }
+}
+
+#[test]
+fn test_language_codes_and_aliases_are_unique() {
+    let mut seen = HashSet::new();
+    for lang in SUPPORTED_LANGUAGES {
+        assert!(
+            seen.insert(lang.code.to_lowercase()),
+            "duplicate code: {}",
+            lang.code
+        );
+        for alias in lang.aliases {
+            assert_eq!(*alias, alias.to_lowercase().replace('_', "-"));
+            assert!(seen.insert(alias.to_string()), "duplicate alias: {}", alias);
+        }
+    }
+}
+
+#[test]
+fn test_new_languages_are_supported() {
+    let codes = get_supported_language_codes();
+    for code in ["pt-BR", "ko", "vi"] {
+        assert!(codes.contains(&code), "missing language: {}", code);
+    }
+
+    let display_names = get_supported_language_display_names();
+    assert!(
+        display_names.contains(&"Português (Brasil) (Brazilian Portuguese) - pt-BR".to_string())
+    );
+    assert!(display_names.contains(&"Tiếng Việt (Vietnamese) - vi".to_string()));
+}
+
+// ==================== 语言查找测试 ====================
+
+#[rstest]
+#[case("pt-BR", "pt-BR")]
+#[case("pt_br", "pt-BR")]
+#[case("PT-br", "pt-BR")]
+#[case("brazilian portuguese", "pt-BR")]
+#[case("portuguese", "pt")]
+#[case("ko", "ko")]
+#[case("ko_KR", "ko")]
+#[case("korean", "ko")]
+#[case("한국어", "ko")]
+#[case("vi", "vi")]
+#[case("vi-VN", "vi")]
+#[case("Vietnamese", "vi")]
+#[case("zh", "zh-CN")]
+#[case("zh_cn", "zh-CN")]
+#[case(" en ", "en")]
+fn test_find_language_matches_codes_and_aliases(#[case] input: &str, #[case] expected: &str) {
+    assert_eq!(find_language(input).map(|lang| lang.code), Some(expected));
+}
+
+#[rstest]
+#[case("")]
+#[case("   ")]
+#[case("klingon")]
+#[case("pt-XX")]
+fn test_find_language_unknown(#[case] input: &str) {
+    assert!(find_language(input).is_none());
+}
+
+#[test]
+fn test_unknown_language_falls_back_to_english_instruction() {
+    assert_eq!(
+        get_language_instruction("klingon"),
+        get_language_instruction("en")
+    );
+}
+
+// ==================== 语言自动检测测试 ====================
+
+#[rstest]
+#[case("feat(api): add retry support for the client", Some("en"))]
+#[case("fix: 修复 PR 总结中的空指针", Some("zh-CN"))]
+#[case("feat: ログイン機能を追加", Some("ja"))]
+#[case("fix: 로그인 오류 수정", Some("ko"))]
+#[case("feat: добавить поддержку прокси", Some("ru"))]
+#[case("fix: sửa lỗi đăng nhập", Some("vi"))]
+#[case("fix: Fehler beim Laden der Konfiguration beheben", Some("de"))]
+#[case("feat: ajouter la prise en charge des proxys", Some("fr"))]
+#[case("fix: corregir el error con los tokens", Some("es"))]
+#[case("feat: adicionar suporte ao proxy com uma nova opção", Some("pt"))]
+#[case("v1.2.3", None)]
+#[case("WIP", None)]
+#[case("", None)]
+fn test_detect_text_language(#[case] message: &str, #[case] expected: Option<&str>) {
+    assert_eq!(detect_text_language(message), expected);
+}
+
+#[test]
+fn test_detect_language_dominant_language() {
+    // 混合语言：中文占多数，英文的版本号 commit 不影响结果
+    let commits = [
+        "feat: 添加用户登录",
+        "fix: 修复空指针异常",
+        "chore: bump version to 1.2.0",
+        "docs: 更新 README",
+        "Merge branch 'main' into feature/login",
+    ];
+    assert_eq!(detect_language(&commits).code, "zh-CN");
+}
+
+#[test]
+fn test_detect_language_ignores_undetectable_messages() {
+    let commits = [
+        "v1.2.3",
+        "WIP",
+        "fix: sửa lỗi đăng nhập",
+        "feat: thêm hỗ trợ proxy",
+    ];
+    assert_eq!(detect_language(&commits).code, "vi");
+}
+
+#[test]
+fn test_detect_language_falls_back_to_english_when_uncertain() {
+    // 没有可判断的 commit
+    assert_eq!(detect_language::<&str>(&[]).code, "en");
+    assert_eq!(detect_language(&["v1.0.0", "WIP"]).code, "en");
+
+    // 没有语言超过一半
+    let commits = [
+        "feat: 添加用户登录",
+        "fix: ログイン機能を修正",
+        "fix: 로그인 오류 수정",
+    ];
+    assert_eq!(detect_language(&commits).code, "en");
+
+    // 两种语言各占一半
+    let commits = ["feat: 添加用户登录", "fix: handle the empty diff"];
+    assert_eq!(detect_language(&commits).code, "en");
+}
+
+#[test]
+fn test_auto_language_option() {
+    assert!(is_auto_language("auto"));
+    assert!(is_auto_language(" AUTO "));
+    assert!(!is_auto_language("en"));
+
+    // 非 auto 的语言代码原样返回
+    assert_eq!(resolve_language_code("zh-CN"), "zh-CN");
+
+    // 自动检测选项位于选择列表末尾，可以按 " - " 拆分出 auto
+    let display_names = get_output_language_display_names();
+    assert_eq!(display_names.len(), SUPPORTED_LANGUAGES.len() + 1);
+    assert_eq!(
+        display_names.last().map(String::as_str),
+        Some(AUTO_LANGUAGE_DISPLAY_NAME)
+    );
+    assert_eq!(AUTO_LANGUAGE_DISPLAY_NAME.split(" - ").nth(1), Some("auto"));
+
+    assert_eq!(
+        get_output_language_display_name("auto").as_deref(),
+        Some(AUTO_LANGUAGE_DISPLAY_NAME)
+    );
+    assert_eq!(
+        get_output_language_display_name("pt_br").as_deref(),
+        Some("Português (Brasil) (Brazilian Portuguese) - pt-BR")
+    );
+    assert_eq!(get_output_language_display_name("klingon"), None);
+}
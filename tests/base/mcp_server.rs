@@ -0,0 +1,212 @@
+//! MCP 服务器测试
+//!
+//! 使用模拟的工具提供者测试 `McpServer` 的消息处理：生命周期、工具列表、工具调用和错误响应。
+
+use color_eyre::{eyre::eyre, Result};
+use pretty_assertions::assert_eq;
+use serde_json::{json, Map, Value};
+use workflow::base::mcp::server::{
+    required_str, McpServer, McpTool, McpToolHandler, INVALID_PARAMS, INVALID_REQUEST,
+    METHOD_NOT_FOUND, PARSE_ERROR,
+};
+
+/// 模拟的工具提供者：`echo` 返回 `text` 参数，`fail` 总是失败
+struct MockTools;
+
+impl McpToolHandler for MockTools {
+    fn tools(&self) -> Vec<McpTool> {
+        vec![
+            McpTool::new(
+                "echo",
+                "Echo the text",
+                json!({ "text": { "type": "string" } }),
+                &["text"],
+            ),
+            McpTool::new("fail", "Always fails", json!({}), &[]),
+        ]
+    }
+
+    fn call(&self, name: &str, arguments: &Map<String, Value>) -> Result<String> {
+        match name {
+            "echo" => Ok(required_str(arguments, "text")?.to_string()),
+            _ => Err(eyre!("Tool failed")),
+        }
+    }
+}
+
+fn server() -> McpServer<MockTools> {
+    McpServer::new("workflow", "1.0.0", MockTools)
+}
+
+/// 处理一条请求并解析响应
+fn request(message: Value) -> Value {
+    let response = server()
+        .handle_message(&message.to_string())
+        .expect("request should have a response");
+    serde_json::from_str(&response).expect("response should be valid JSON")
+}
+
+// ==================== 生命周期测试 ====================
+
+#[test]
+fn test_initialize_returns_server_info_and_capabilities() {
+    let response = request(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": { "protocolVersion": "2024-11-05", "capabilities": {} }
+    }));
+
+    assert_eq!(response["id"], 1);
+    assert_eq!(response["result"]["protocolVersion"], "2024-11-05");
+    assert_eq!(response["result"]["serverInfo"]["name"], "workflow");
+    assert_eq!(response["result"]["serverInfo"]["version"], "1.0.0");
+    assert!(response["result"]["capabilities"]["tools"].is_object());
+}
+
+#[test]
+fn test_initialize_with_unsupported_version_returns_latest() {
+    let response = request(json!({
+        "jsonrpc": "2.0",
+        "id": 1,
+        "method": "initialize",
+        "params": { "protocolVersion": "1999-01-01" }
+    }));
+
+    assert_eq!(response["result"]["protocolVersion"], "2025-06-18");
+}
+
+#[test]
+fn test_notifications_have_no_response() {
+    let message = json!({ "jsonrpc": "2.0", "method": "notifications/initialized" });
+
+    assert_eq!(server().handle_message(&message.to_string()), None);
+}
+
+#[test]
+fn test_ping_returns_empty_result() {
+    let response = request(json!({ "jsonrpc": "2.0", "id": "a", "method": "ping" }));
+
+    assert_eq!(response["id"], "a");
+    assert_eq!(response["result"], json!({}));
+}
+
+// ==================== 工具测试 ====================
+
+#[test]
+fn test_tools_list_returns_tool_schemas() {
+    let response = request(json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" }));
+
+    let tools = response["result"]["tools"].as_array().unwrap();
+    assert_eq!(tools.len(), 2);
+    assert_eq!(tools[0]["name"], "echo");
+    assert_eq!(tools[0]["inputSchema"]["type"], "object");
+    assert_eq!(tools[0]["inputSchema"]["required"], json!(["text"]));
+}
+
+#[test]
+fn test_tools_call_returns_text_content() {
+    let response = request(json!({
+        "jsonrpc": "2.0",
+        "id": 3,
+        "method": "tools/call",
+        "params": { "name": "echo", "arguments": { "text": "hello" } }
+    }));
+
+    assert_eq!(
+        response["result"],
+        json!({ "content": [{ "type": "text", "text": "hello" }], "isError": false })
+    );
+}
+
+#[test]
+fn test_tools_call_failure_returns_error_result() {
+    let response = request(json!({
+        "jsonrpc": "2.0",
+        "id": 4,
+        "method": "tools/call",
+        "params": { "name": "fail" }
+    }));
+
+    assert_eq!(response["result"]["isError"], true);
+    assert_eq!(response["result"]["content"][0]["text"], "Tool failed");
+}
+
+#[test]
+fn test_tools_call_missing_required_argument_returns_error_result() {
+    let response = request(json!({
+        "jsonrpc": "2.0",
+        "id": 5,
+        "method": "tools/call",
+        "params": { "name": "echo", "arguments": {} }
+    }));
+
+    assert_eq!(response["result"]["isError"], true);
+    assert_eq!(
+        response["result"]["content"][0]["text"],
+        "Missing required argument: text"
+    );
+}
+
+#[test]
+fn test_tools_call_unknown_tool_returns_invalid_params() {
+    let response = request(json!({
+        "jsonrpc": "2.0",
+        "id": 6,
+        "method": "tools/call",
+        "params": { "name": "missing" }
+    }));
+
+    assert_eq!(response["error"]["code"], INVALID_PARAMS);
+}
+
+// ==================== 错误响应测试 ====================
+
+#[test]
+fn test_invalid_json_returns_parse_error() {
+    let response: Value = serde_json::from_str(&server().handle_message("{not json").unwrap())
+        .expect("response should be valid JSON");
+
+    assert_eq!(response["id"], Value::Null);
+    assert_eq!(response["error"]["code"], PARSE_ERROR);
+}
+
+#[test]
+fn test_non_object_message_returns_invalid_request() {
+    let response = request(json!([1, 2, 3]));
+
+    assert_eq!(response["error"]["code"], INVALID_REQUEST);
+}
+
+#[test]
+fn test_unknown_method_returns_method_not_found() {
+    let response = request(json!({ "jsonrpc": "2.0", "id": 7, "method": "resources/list" }));
+
+    assert_eq!(response["id"], 7);
+    assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+}
+
+// ==================== serve 测试 ====================
+
+#[test]
+fn test_serve_writes_one_response_per_request() -> Result<()> {
+    let input = [
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "ping" }).to_string(),
+        String::new(),
+        json!({ "jsonrpc": "2.0", "method": "notifications/initialized" }).to_string(),
+        json!({ "jsonrpc": "2.0", "id": 2, "method": "tools/list" }).to_string(),
+    ]
+    .join("\n");
+    let mut output = Vec::new();
+
+    server().serve(input.as_bytes(), &mut output)?;
+
+    let lines: Vec<Value> = String::from_utf8(output)?
+        .lines()
+        .map(|line| serde_json::from_str(line).expect("each line should be valid JSON"))
+        .collect();
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0]["id"], 1);
+    assert_eq!(lines[1]["id"], 2);
+    Ok(())
+}
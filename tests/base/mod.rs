@@ -4,11 +4,30 @@
 
 pub mod alias;
 pub mod concurrent;
+pub mod dialog_form;
 pub mod dialog_validators;
+pub mod format_markdown;
+pub mod http_client;
+pub mod http_multipart;
 pub mod http_retry;
+pub mod http_trace;
 pub mod llm_client;
+pub mod llm_languages;
 pub mod logger;
+pub mod mcp_server;
+pub mod prompt_custom;
 pub mod settings;
+pub mod settings_secrets;
+pub mod settings_validation;
+pub mod setup_answers;
+pub mod shell_config;
+pub mod shell_types;
+pub mod table;
+pub mod util_browser;
+pub mod util_clipboard;
 pub mod util_dialog;
+pub mod util_diff;
 pub mod util_format;
 pub mod util_platform;
+pub mod util_sensitive;
+pub mod util_version;
@@ -0,0 +1,107 @@
+//! 自定义 prompt 文件测试
+//!
+//! 测试 `[prompt]` 配置指向的 prompt 文件的读取和回退行为：
+//! - 文件存在且非空时使用文件内容
+//! - 文件不存在或为空时返回错误 / 回退到内置 prompt
+//! - `[prompt]` 配置的序列化
+
+use pretty_assertions::assert_eq;
+use std::fs;
+use tempfile::tempdir;
+use workflow::base::prompt::{load_prompt_override, read_prompt_file};
+use workflow::base::settings::settings::PromptSettings;
+use workflow::base::settings::Settings;
+
+// ==================== read_prompt_file 测试 ====================
+
+#[test]
+fn test_read_prompt_file_trims_content() {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join("prompt.md");
+    fs::write(&path, "\nYou're a git assistant.\n\n").unwrap();
+
+    let content = read_prompt_file(&path.to_string_lossy()).unwrap();
+    assert_eq!(content, "You're a git assistant.");
+}
+
+#[test]
+fn test_read_prompt_file_missing() {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join("missing.md");
+
+    let error = read_prompt_file(&path.to_string_lossy()).unwrap_err();
+    assert!(error.to_string().contains("does not exist"));
+}
+
+#[test]
+fn test_read_prompt_file_empty() {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join("empty.md");
+    fs::write(&path, " \n\t\n").unwrap();
+
+    let error = read_prompt_file(&path.to_string_lossy()).unwrap_err();
+    assert!(error.to_string().contains("empty"));
+}
+
+#[test]
+fn test_read_prompt_file_directory() {
+    let temp_dir = tempdir().unwrap();
+
+    // 目录不是 prompt 文件
+    assert!(read_prompt_file(&temp_dir.path().to_string_lossy()).is_err());
+}
+
+// ==================== load_prompt_override 测试 ====================
+
+#[test]
+fn test_load_prompt_override_not_configured() {
+    assert_eq!(load_prompt_override("prompt.summarize_pr_path", None), None);
+    assert_eq!(
+        load_prompt_override("prompt.summarize_pr_path", Some("  ")),
+        None
+    );
+}
+
+#[test]
+fn test_load_prompt_override_uses_file() {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join("summarize.md");
+    fs::write(&path, "Summarize the PR for the payments team.").unwrap();
+
+    let content = load_prompt_override("prompt.summarize_pr_path", Some(&path.to_string_lossy()));
+    assert_eq!(
+        content.as_deref(),
+        Some("Summarize the PR for the payments team.")
+    );
+}
+
+#[test]
+fn test_load_prompt_override_falls_back_when_missing() {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join("missing.md");
+
+    // 文件不存在时只输出警告，由调用方使用内置 prompt
+    let content =
+        load_prompt_override("prompt.generate_branch_path", Some(&path.to_string_lossy()));
+    assert_eq!(content, None);
+}
+
+// ==================== PromptSettings 测试 ====================
+
+#[test]
+fn test_prompt_settings_serialization() {
+    // 未配置时不写入 [prompt] 段
+    let toml_str = toml::to_string(&Settings::default()).unwrap();
+    assert!(!toml_str.contains("[prompt]"));
+
+    let settings: Settings =
+        toml::from_str("[prompt]\ngenerate_branch_path = \"~/.workflow/prompts/branch.md\"\n")
+            .unwrap();
+    assert_eq!(
+        settings.prompt.generate_branch_path.as_deref(),
+        Some("~/.workflow/prompts/branch.md")
+    );
+    assert_eq!(settings.prompt.summarize_pr_path, None);
+    assert!(!settings.prompt.is_empty());
+    assert!(PromptSettings::default().is_empty());
+}
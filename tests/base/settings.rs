@@ -4,17 +4,28 @@
 //! - 配置结构体创建和字段访问
 //! - 配置加载和默认值处理
 //! - 配置验证和序列化
+//! - 环境变量覆盖
+//! - 配置 Profile 合并
+//! - 配置路径覆盖（`--config`/`WORKFLOW_CONFIG`）
 //! - 表格显示结构测试
 
 use pretty_assertions::assert_eq;
+use rstest::rstest;
+use serial_test::serial;
 use std::collections::HashMap;
+use std::time::Duration;
+use workflow::base::alias::AliasManager;
+use workflow::base::http::Authorization;
 use workflow::base::settings::settings::{
-    default_download_base_dir, GitHubAccount, GitHubSettings, JiraSettings, LLMProviderSettings,
-    LogSettings,
+    default_download_base_dir, AzureDevOpsSettings, CheckSettings, CommitSettings, GitHubAccount,
+    GitHubSettings, HttpSettings, JiraSettings, LLMProviderSettings, LogSettings, PromptSettings,
+    RollbackSettings, TagSettings,
 };
 use workflow::base::settings::{
-    GitHubAccountListRow, GitHubAccountRow, JiraConfigRow, LLMConfigRow, LLMSettings, Settings,
+    GitHubAccountListRow, GitHubAccountRow, JiraAuthType, JiraConfigRow, LLMConfigRow, LLMSettings,
+    Paths, Profile, Settings, CONFIG_ENV, PROFILE_ENV,
 };
+use workflow::base::util::date::Timezone;
 
 // ==================== Helper Functions ====================
 
@@ -24,6 +35,9 @@ fn create_test_jira_settings() -> JiraSettings {
         email: Some("test@example.com".to_string()),
         api_token: Some("test_token_123".to_string()),
         service_address: Some("https://company.atlassian.net".to_string()),
+        timezone: None,
+        sprint_field: None,
+        auth_type: None,
     }
 }
 
@@ -35,14 +49,18 @@ fn create_test_github_settings() -> GitHubSettings {
                 name: "personal".to_string(),
                 email: "personal@example.com".to_string(),
                 api_token: "ghp_personal_token".to_string(),
+                login: None,
             },
             GitHubAccount {
                 name: "work".to_string(),
                 email: "work@company.com".to_string(),
                 api_token: "ghp_work_token".to_string(),
+                login: None,
             },
         ],
         current: Some("personal".to_string()),
+        api_base_url: None,
+        web_base_url: None,
     }
 }
 
@@ -55,17 +73,21 @@ fn create_test_llm_settings() -> LLMSettings {
             url: None,
             key: Some("sk-test_openai_key".to_string()),
             model: Some("gpt-4".to_string()),
+            max_diff_chars: None,
         },
         deepseek: LLMProviderSettings {
             url: None,
             key: Some("sk-test_deepseek_key".to_string()),
             model: Some("deepseek-chat".to_string()),
+            max_diff_chars: None,
         },
         proxy: LLMProviderSettings {
             url: Some("https://api.proxy.com".to_string()),
             key: Some("proxy_key".to_string()),
             model: Some("proxy-model".to_string()),
+            max_diff_chars: None,
         },
+        max_retries: None,
     }
 }
 
@@ -165,6 +187,8 @@ fn test_github_settings_empty_accounts() {
     let empty_github = GitHubSettings {
         accounts: vec![],
         current: None,
+        api_base_url: None,
+        web_base_url: None,
     };
 
     assert!(empty_github.get_current_account().is_none());
@@ -178,6 +202,96 @@ fn test_github_settings_default() {
 
     assert!(default_github.accounts.is_empty());
     assert_eq!(default_github.current, None);
+    assert!(default_github.is_empty());
+}
+
+/// 测试 GitHub API 和网站基础 URL（默认 github.com，GitHub Enterprise Server 由配置推导）
+#[rstest]
+#[case(
+    None,
+    None,
+    "https://api.github.com",
+    "https://github.com",
+    "https://api.github.com/graphql"
+)]
+#[case(
+    None,
+    Some("https://github.mycorp.com/"),
+    "https://github.mycorp.com/api/v3",
+    "https://github.mycorp.com",
+    "https://github.mycorp.com/api/graphql"
+)]
+#[case(
+    Some("https://github.mycorp.com/api/v3"),
+    None,
+    "https://github.mycorp.com/api/v3",
+    "https://github.mycorp.com",
+    "https://github.mycorp.com/api/graphql"
+)]
+#[case(
+    Some("https://api.mycorp.com"),
+    Some("https://github.mycorp.com"),
+    "https://api.mycorp.com",
+    "https://github.mycorp.com",
+    "https://api.mycorp.com/graphql"
+)]
+fn test_github_settings_base_urls(
+    #[case] api_base_url: Option<&str>,
+    #[case] web_base_url: Option<&str>,
+    #[case] expected_api: &str,
+    #[case] expected_web: &str,
+    #[case] expected_graphql: &str,
+) {
+    let github = GitHubSettings {
+        api_base_url: api_base_url.map(str::to_string),
+        web_base_url: web_base_url.map(str::to_string),
+        ..Default::default()
+    };
+
+    assert_eq!(github.api_base_url(), expected_api);
+    assert_eq!(github.web_base_url(), expected_web);
+    assert_eq!(github.graphql_url(), expected_graphql);
+}
+
+/// 测试 GitHub Enterprise Server 主机名
+#[rstest]
+#[case(None, None)]
+#[case(Some("https://github.com"), None)]
+#[case(Some("https://github.mycorp.com"), Some("github.mycorp.com"))]
+#[case(Some("https://GitHub.MyCorp.com:8443/"), Some("github.mycorp.com"))]
+#[case(Some("not a url"), None)]
+fn test_github_settings_enterprise_host(
+    #[case] web_base_url: Option<&str>,
+    #[case] expected: Option<&str>,
+) {
+    let github = GitHubSettings {
+        web_base_url: web_base_url.map(str::to_string),
+        ..Default::default()
+    };
+
+    assert_eq!(github.enterprise_host().as_deref(), expected);
+}
+
+/// 测试 GitHubAccount 的 login 字段序列化（未设置时省略，旧配置可正常读取）
+#[test]
+fn test_github_account_login_serialization() {
+    let mut account = GitHubAccount {
+        name: "work".to_string(),
+        email: "work@example.com".to_string(),
+        api_token: "ghp_work".to_string(),
+        login: None,
+    };
+
+    let toml_str = toml::to_string(&account).unwrap();
+    assert!(!toml_str.contains("login"));
+    let parsed: GitHubAccount = toml::from_str(&toml_str).unwrap();
+    assert_eq!(parsed, account);
+
+    account.login = Some("octocat".to_string());
+    let toml_str = toml::to_string(&account).unwrap();
+    assert!(toml_str.contains("login = \"octocat\""));
+    let parsed: GitHubAccount = toml::from_str(&toml_str).unwrap();
+    assert_eq!(parsed.login.as_deref(), Some("octocat"));
 }
 
 // ==================== LLMSettings 测试 ====================
@@ -224,6 +338,27 @@ fn test_llm_settings_defaults() {
     assert_eq!(LLMSettings::default_model("openai"), "gpt-4.0");
     assert_eq!(LLMSettings::default_model("deepseek"), "deepseek-chat");
     assert_eq!(LLMSettings::default_model("unknown"), ""); // proxy 必须输入，没有默认值
+    assert_eq!(LLMSettings::default_max_retries(), 3);
+    assert_eq!(LLMSettings::default().max_retries(), 3);
+}
+
+/// 测试 diff 字符数预算（未配置时使用 provider 默认值）
+#[test]
+fn test_llm_settings_max_diff_chars() {
+    let mut llm_settings = create_test_llm_settings();
+    assert_eq!(
+        llm_settings.max_diff_chars(),
+        LLMSettings::default_max_diff_chars("openai")
+    );
+
+    llm_settings.openai.max_diff_chars = Some(8000);
+    assert_eq!(llm_settings.max_diff_chars(), 8000);
+
+    llm_settings.provider = "proxy".to_string();
+    assert_eq!(
+        llm_settings.max_diff_chars(),
+        LLMSettings::default_max_diff_chars("proxy")
+    );
 }
 
 /// 测试 LLMProviderSettings 创建
@@ -233,6 +368,7 @@ fn test_llm_provider_settings_creation() {
         url: Some("https://api.example.com".to_string()),
         key: Some("test_key".to_string()),
         model: Some("test_model".to_string()),
+        max_diff_chars: None,
     };
 
     assert_eq!(
@@ -259,6 +395,8 @@ fn test_log_settings_creation() {
         download_base_dir: Some("/custom/path".to_string()),
         level: Some("debug".to_string()),
         enable_trace_console: Some(true),
+        http_trace: None,
+        redact_patterns: None,
     };
 
     assert_eq!(log_settings.get_output_folder_name(), "custom_logs");
@@ -282,6 +420,158 @@ fn test_log_settings_default() {
     assert_eq!(default_log.enable_trace_console, None);
 }
 
+/// 测试 RollbackSettings 默认启用压缩
+#[test]
+fn test_rollback_settings_default() {
+    let default_rollback = RollbackSettings::default();
+
+    assert!(default_rollback.is_empty());
+    assert!(default_rollback.is_compression_enabled());
+
+    let disabled = RollbackSettings {
+        compress_backups: Some(false),
+    };
+    assert!(!disabled.is_empty());
+    assert!(!disabled.is_compression_enabled());
+}
+
+/// 测试 CommitSettings 默认不签名
+#[test]
+fn test_commit_settings_default() {
+    let default_commit = CommitSettings::default();
+
+    assert!(default_commit.is_empty());
+    assert!(!default_commit.is_signing_enabled());
+
+    let signed: CommitSettings = toml::from_str("sign = true").unwrap();
+    assert!(!signed.is_empty());
+    assert!(signed.is_signing_enabled());
+}
+
+/// 测试 TagSettings 默认前缀为 v，可配置为空
+#[rstest]
+#[case("", "v", true)]
+#[case("prefix = \"release-\"", "release-", false)]
+#[case("prefix = \"\"", "", false)]
+fn test_tag_settings_prefix(
+    #[case] content: &str,
+    #[case] expected_prefix: &str,
+    #[case] expected_empty: bool,
+) {
+    let tag: TagSettings = toml::from_str(content).unwrap();
+
+    assert_eq!(tag.prefix(), expected_prefix);
+    assert_eq!(tag.is_empty(), expected_empty);
+}
+
+/// 测试 HttpSettings 超时时间
+#[test]
+fn test_http_settings_timeouts() {
+    let default_http = HttpSettings::default();
+
+    assert!(default_http.is_empty());
+    assert_eq!(default_http.timeout(), Duration::from_secs(30));
+    assert_eq!(default_http.download_timeout(), Duration::from_secs(600));
+
+    let custom = HttpSettings {
+        timeout_secs: Some(5),
+        download_timeout_secs: Some(0),
+        max_concurrent_downloads: None,
+    };
+    assert!(!custom.is_empty());
+    assert_eq!(custom.timeout(), Duration::from_secs(5));
+    // 配置为 0 时使用默认值
+    assert_eq!(custom.download_timeout(), Duration::from_secs(600));
+}
+
+/// 测试 HttpSettings 最大并发下载数
+#[rstest]
+#[case(None, 4)]
+#[case(Some(0), 4)]
+#[case(Some(8), 8)]
+#[case(Some(100), 20)]
+fn test_http_settings_max_concurrent_downloads(
+    #[case] configured: Option<usize>,
+    #[case] expected: usize,
+) {
+    let http = HttpSettings {
+        max_concurrent_downloads: configured,
+        ..HttpSettings::default()
+    };
+    assert_eq!(http.max_concurrent_downloads(), expected);
+}
+
+/// 测试 JiraSettings 时区
+#[rstest]
+#[case(None, Timezone::Local)]
+#[case(Some("utc"), Timezone::Utc)]
+#[case(Some("UTC"), Timezone::Utc)]
+#[case(Some("local"), Timezone::Local)]
+#[case(Some("Asia/Shanghai"), Timezone::Local)]
+fn test_jira_settings_timezone(#[case] configured: Option<&str>, #[case] expected: Timezone) {
+    let jira = JiraSettings {
+        timezone: configured.map(str::to_string),
+        ..JiraSettings::default()
+    };
+    assert_eq!(jira.timezone(), expected);
+    // 只配置时区时不视为空配置（保存时需要保留）
+    assert_eq!(jira.is_empty(), configured.is_none());
+    assert!(!jira.has_credentials());
+}
+
+/// 测试 JiraSettings 认证方式
+#[rstest]
+#[case(None, JiraAuthType::Basic)]
+#[case(Some("basic"), JiraAuthType::Basic)]
+#[case(Some("BEARER"), JiraAuthType::Bearer)]
+#[case(Some("oauth"), JiraAuthType::Basic)]
+fn test_jira_settings_auth_type(#[case] configured: Option<&str>, #[case] expected: JiraAuthType) {
+    let jira = JiraSettings {
+        auth_type: configured.map(str::to_string),
+        ..JiraSettings::default()
+    };
+    assert_eq!(jira.auth_type(), expected);
+    assert_eq!(jira.is_empty(), configured.is_none());
+}
+
+/// 测试 JiraSettings 根据认证方式创建认证信息
+#[test]
+fn test_jira_settings_authorization() {
+    let mut jira = create_test_jira_settings();
+    assert!(matches!(
+        jira.authorization(),
+        Authorization::Basic { ref username, .. } if username == "test@example.com"
+    ));
+
+    jira.auth_type = Some("bearer".to_string());
+    jira.email = None;
+    assert!(matches!(
+        jira.authorization(),
+        Authorization::Bearer { ref token } if Some(token) == jira.api_token.as_ref()
+    ));
+    assert_eq!("bearer".parse::<JiraAuthType>(), Ok(JiraAuthType::Bearer));
+    assert!("token".parse::<JiraAuthType>().is_err());
+}
+
+/// 测试 JiraSettings 是否可用（未配置时 Jira 集成是可选的）
+#[test]
+fn test_jira_settings_is_configured() {
+    let mut jira = create_test_jira_settings();
+    assert!(jira.is_configured());
+
+    // basic 认证需要邮箱，bearer 认证不需要
+    jira.email = None;
+    assert!(!jira.is_configured());
+    jira.auth_type = Some("bearer".to_string());
+    assert!(jira.is_configured());
+
+    // 缺少服务地址或 API Token 时不可用（空字符串视为未配置）
+    jira.service_address = Some("  ".to_string());
+    assert!(!jira.is_configured());
+
+    assert!(!JiraSettings::default().is_configured());
+}
+
 /// 测试 LogSettings 默认方法
 #[test]
 fn test_log_settings_default_methods() {
@@ -304,8 +594,15 @@ fn test_settings_creation() {
     let settings = Settings {
         jira: create_test_jira_settings(),
         github: create_test_github_settings(),
+        azure_devops: AzureDevOpsSettings::default(),
         log: LogSettings::default(),
         llm: create_test_llm_settings(),
+        rollback: RollbackSettings::default(),
+        commit: CommitSettings::default(),
+        tag: TagSettings::default(),
+        prompt: PromptSettings::default(),
+        http: HttpSettings::default(),
+        check: CheckSettings::default(),
         aliases: {
             let mut aliases = HashMap::new();
             aliases.insert("st".to_string(), "status".to_string());
@@ -351,6 +648,7 @@ fn test_table_row_structures() {
     // 测试 JiraConfigRow
     let jira_row = JiraConfigRow {
         email: "jira@example.com".to_string(),
+        auth_type: "basic".to_string(),
         service_address: "https://jira.company.com".to_string(),
         api_token: "****".to_string(),
     };
@@ -397,6 +695,9 @@ fn test_complex_configuration_scenario() {
             email: Some("complex@jira.com".to_string()),
             api_token: Some("complex_jira_token".to_string()),
             service_address: Some("https://complex.atlassian.net".to_string()),
+            timezone: None,
+            sprint_field: None,
+            auth_type: None,
         },
         github: GitHubSettings {
             accounts: vec![
@@ -404,25 +705,33 @@ fn test_complex_configuration_scenario() {
                     name: "main".to_string(),
                     email: "main@github.com".to_string(),
                     api_token: "ghp_main_token".to_string(),
+                    login: None,
                 },
                 GitHubAccount {
                     name: "backup".to_string(),
                     email: "backup@github.com".to_string(),
                     api_token: "ghp_backup_token".to_string(),
+                    login: None,
                 },
                 GitHubAccount {
                     name: "test".to_string(),
                     email: "test@github.com".to_string(),
                     api_token: "ghp_test_token".to_string(),
+                    login: None,
                 },
             ],
             current: Some("main".to_string()),
+            api_base_url: None,
+            web_base_url: None,
         },
+        azure_devops: AzureDevOpsSettings::default(),
         log: LogSettings {
             output_folder_name: Some("complex_logs".to_string()),
             download_base_dir: Some("/complex/logs/path".to_string()),
             level: Some("info".to_string()),
             enable_trace_console: Some(false),
+            http_trace: None,
+            redact_patterns: None,
         },
         llm: LLMSettings {
             provider: "proxy".to_string(),
@@ -431,18 +740,32 @@ fn test_complex_configuration_scenario() {
                 url: None,
                 key: Some("sk-openai_complex".to_string()),
                 model: Some("gpt-4-turbo".to_string()),
+                max_diff_chars: None,
             },
             deepseek: LLMProviderSettings {
                 url: None,
                 key: Some("sk-deepseek_complex".to_string()),
                 model: Some("deepseek-coder".to_string()),
+                max_diff_chars: None,
             },
             proxy: LLMProviderSettings {
                 url: Some("https://complex.proxy.api.com".to_string()),
                 key: Some("proxy_complex_key".to_string()),
                 model: Some("complex-model".to_string()),
+                max_diff_chars: Some(50000),
             },
+            max_retries: Some(5),
+        },
+        rollback: RollbackSettings {
+            compress_backups: Some(false),
+        },
+        commit: CommitSettings { sign: Some(true) },
+        tag: TagSettings {
+            prefix: Some("release-".to_string()),
         },
+        prompt: PromptSettings::default(),
+        http: HttpSettings::default(),
+        check: CheckSettings::default(),
         aliases,
     };
 
@@ -452,6 +775,8 @@ fn test_complex_configuration_scenario() {
     assert_eq!(complex_settings.log.level, Some("info".to_string()));
     assert_eq!(complex_settings.llm.provider, "proxy");
     assert_eq!(complex_settings.aliases.len(), 3);
+    assert!(!complex_settings.rollback.is_compression_enabled());
+    assert!(complex_settings.commit.is_signing_enabled());
 
     // 验证 GitHub 当前账号功能
     let current_account = complex_settings.github.get_current_account();
@@ -464,6 +789,8 @@ fn test_complex_configuration_scenario() {
         current_llm.url,
         Some("https://complex.proxy.api.com".to_string())
     );
+    assert_eq!(complex_settings.llm.max_diff_chars(), 50000);
+    assert_eq!(complex_settings.llm.max_retries(), 5);
 
     // 验证别名功能
     assert_eq!(
@@ -476,3 +803,380 @@ fn test_complex_configuration_scenario() {
     );
     assert_eq!(complex_settings.aliases.get("p"), Some(&"push".to_string()));
 }
+
+// ==================== 环境变量覆盖测试 ====================
+
+/// 测试环境变量覆盖 Jira 和 LLM 配置（环境变量优先于文件值）
+#[test]
+#[serial]
+fn test_settings_env_overrides_merge() {
+    std::env::set_var(
+        "WORKFLOW_JIRA__SERVICE_ADDRESS",
+        "https://env.atlassian.net",
+    );
+    std::env::set_var("WORKFLOW_JIRA__API_TOKEN", "env_jira_token");
+    std::env::set_var("WORKFLOW_LLM__API_KEY", "sk-env-key");
+
+    let mut settings = Settings {
+        jira: create_test_jira_settings(),
+        llm: create_test_llm_settings(),
+        ..Settings::default()
+    };
+    let overrides = settings.apply_env_overrides();
+
+    std::env::remove_var("WORKFLOW_JIRA__SERVICE_ADDRESS");
+    std::env::remove_var("WORKFLOW_JIRA__API_TOKEN");
+    std::env::remove_var("WORKFLOW_LLM__API_KEY");
+
+    assert_eq!(
+        settings.jira.service_address,
+        Some("https://env.atlassian.net".to_string())
+    );
+    assert_eq!(settings.jira.api_token, Some("env_jira_token".to_string()));
+    // 未被覆盖的字段保留文件中的值
+    assert_eq!(settings.jira.email, Some("test@example.com".to_string()));
+    // LLM 简写字段作用于当前 provider
+    let provider = settings.llm.provider.clone();
+    assert_eq!(
+        settings.llm.current_provider().key,
+        Some("sk-env-key".to_string())
+    );
+
+    let keys: Vec<&str> = overrides.iter().map(|o| o.key.as_str()).collect();
+    assert!(keys.contains(&"jira.service_address"));
+    assert!(keys.contains(&"jira.api_token"));
+    assert!(keys.contains(&format!("llm.{}.key", provider).as_str()));
+}
+
+/// 测试环境变量覆盖的类型推断、provider 顺序和忽略规则
+#[test]
+fn test_settings_overrides_from_vars() {
+    let mut settings = Settings::default();
+    let vars = vec![
+        (
+            "WORKFLOW_LLM__MODEL".to_string(),
+            "deepseek-coder".to_string(),
+        ),
+        ("WORKFLOW_LLM__PROVIDER".to_string(), "deepseek".to_string()),
+        (
+            "WORKFLOW_LLM__MAX_DIFF_CHARS".to_string(),
+            "12000".to_string(),
+        ),
+        (
+            "WORKFLOW_LOG__ENABLE_TRACE_CONSOLE".to_string(),
+            "true".to_string(),
+        ),
+        (
+            "WORKFLOW_ROLLBACK__COMPRESS_BACKUPS".to_string(),
+            "false".to_string(),
+        ),
+        ("WORKFLOW_ALIASES__CO".to_string(), "checkout".to_string()),
+        (
+            "WORKFLOW_JIRA__UNKNOWN_FIELD".to_string(),
+            "ignored".to_string(),
+        ),
+        (
+            "WORKFLOW_CONFIG_DIR".to_string(),
+            "/tmp/ignored".to_string(),
+        ),
+        (
+            "OTHER_JIRA__EMAIL".to_string(),
+            "ignored@example.com".to_string(),
+        ),
+    ];
+
+    let overrides = settings.apply_overrides_from(vars);
+
+    assert_eq!(settings.llm.provider, "deepseek");
+    assert_eq!(
+        settings.llm.deepseek.model,
+        Some("deepseek-coder".to_string())
+    );
+    assert_eq!(settings.llm.deepseek.max_diff_chars, Some(12000));
+    assert_eq!(settings.log.enable_trace_console, Some(true));
+    assert!(!settings.rollback.is_compression_enabled());
+    assert_eq!(settings.aliases.get("co"), Some(&"checkout".to_string()));
+    assert_eq!(settings.jira.email, None);
+    assert_eq!(overrides.len(), 6);
+    assert!(overrides.iter().all(|o| o.var.starts_with("WORKFLOW_") && o.var.contains("__")));
+}
+
+// ==================== 配置 Profile 测试 ====================
+
+/// 测试 Profile 配置合并到基础配置（未覆盖的字段从基础配置继承）
+#[test]
+fn test_profile_merge_inherits_base_fields() {
+    let base = Settings {
+        jira: create_test_jira_settings(),
+        github: create_test_github_settings(),
+        ..Settings::default()
+    };
+    let mut value = toml::Value::try_from(&base).unwrap();
+    let overlay: toml::Value = toml::from_str(
+        r#"
+[jira]
+email = "personal@example.com"
+
+[github]
+current = "personal"
+"#,
+    )
+    .unwrap();
+
+    Profile::merge(&mut value, overlay);
+    let merged: Settings = value.try_into().unwrap();
+
+    assert_eq!(merged.jira.email, Some("personal@example.com".to_string()));
+    assert_eq!(merged.jira.api_token, base.jira.api_token);
+    assert_eq!(merged.jira.service_address, base.jira.service_address);
+    assert_eq!(merged.github.current, Some("personal".to_string()));
+    assert_eq!(merged.github.accounts.len(), base.github.accounts.len());
+}
+
+/// 测试只有与基础配置不同的字段会写入 Profile
+#[test]
+fn test_profile_diff_keeps_only_changed_fields() {
+    let base = Settings {
+        jira: create_test_jira_settings(),
+        ..Settings::default()
+    };
+    let mut updated = base.clone();
+    updated.jira.email = Some("work@example.com".to_string());
+
+    let base_value = toml::Value::try_from(&base).unwrap();
+    let updated_value = toml::Value::try_from(&updated).unwrap();
+    let diff = Profile::diff(&base_value, &updated_value);
+
+    let expected: toml::Value = toml::from_str(
+        r#"
+[jira]
+email = "work@example.com"
+"#,
+    )
+    .unwrap();
+    assert_eq!(diff, expected);
+
+    // 没有差异时返回空表
+    let empty = Profile::diff(&base_value, &base_value);
+    assert_eq!(empty, toml::Value::Table(toml::map::Map::new()));
+}
+
+/// 测试找出基础配置中被删除的字段
+#[test]
+fn test_profile_removed_keys() {
+    let base: toml::Value = toml::from_str(
+        r#"
+[aliases]
+co = "checkout"
+st = "status"
+
+[jira]
+email = "work@example.com"
+"#,
+    )
+    .unwrap();
+    let updated: toml::Value = toml::from_str(
+        r#"
+[aliases]
+st = "status"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        Profile::removed_keys(&base, &updated),
+        vec!["aliases.co", "jira.email"]
+    );
+    assert!(Profile::removed_keys(&base, &base).is_empty());
+
+    // 整个表被删除时报告其中的叶子字段
+    let emptied: toml::Value = toml::from_str(r#"[jira]"#).unwrap();
+    assert_eq!(
+        Profile::removed_keys(&base, &emptied),
+        vec!["aliases.co", "aliases.st", "jira.email"]
+    );
+}
+
+/// 测试在非 default Profile 中删除继承的别名时返回错误，且别名保留
+#[test]
+#[serial]
+fn test_alias_remove_inherited_under_profile() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("workflow.toml"),
+        r#"
+[aliases]
+co = "checkout"
+"#,
+    )
+    .unwrap();
+    std::fs::create_dir_all(dir.path().join("profiles").join("personal")).unwrap();
+    std::env::set_var(CONFIG_ENV, dir.path());
+    std::env::set_var(PROFILE_ENV, "personal");
+
+    let error = AliasManager::remove("co").unwrap_err();
+    assert!(error.to_string().contains("aliases.co"));
+    assert!(error.to_string().contains("default profile"));
+    assert_eq!(
+        Settings::load().aliases.get("co").map(String::as_str),
+        Some("checkout")
+    );
+
+    // Profile 自己的别名可以正常删除
+    AliasManager::add("st", "status").unwrap();
+    assert!(AliasManager::remove("st").unwrap());
+    assert!(!Settings::load().aliases.contains_key("st"));
+    assert!(Settings::load().aliases.contains_key("co"));
+
+    std::env::remove_var(PROFILE_ENV);
+    std::env::remove_var(CONFIG_ENV);
+}
+
+/// 测试加载指定 Profile 的配置（使用隔离的配置目录）
+#[test]
+#[serial]
+fn test_settings_load_profile() {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(
+        dir.path().join("workflow.toml"),
+        r#"
+[jira]
+email = "work@example.com"
+service_address = "https://work.atlassian.net"
+
+[github]
+current = "work"
+"#,
+    )
+    .unwrap();
+    let profile_dir = dir.path().join("profiles").join("personal");
+    std::fs::create_dir_all(&profile_dir).unwrap();
+    std::fs::write(
+        profile_dir.join("workflow.toml"),
+        r#"
+[jira]
+email = "me@example.com"
+
+[github]
+current = "personal"
+"#,
+    )
+    .unwrap();
+    std::env::set_var(CONFIG_ENV, dir.path());
+
+    let base = Settings::load_profile("default");
+    assert_eq!(base.jira.email.as_deref(), Some("work@example.com"));
+    assert_eq!(base.github.current.as_deref(), Some("work"));
+
+    let personal = Settings::load_profile("personal");
+    assert_eq!(personal.jira.email.as_deref(), Some("me@example.com"));
+    assert_eq!(
+        personal.jira.service_address.as_deref(),
+        Some("https://work.atlassian.net")
+    );
+    assert_eq!(personal.github.current.as_deref(), Some("personal"));
+    assert_eq!(Profile::list().unwrap(), vec!["default", "personal"]);
+
+    std::env::remove_var(CONFIG_ENV);
+}
+
+/// 测试从原始命令行参数中提取 `--profile`
+#[test]
+fn test_profile_from_args() {
+    let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(
+        Profile::from_args(&args(&["workflow", "--profile", "work", "check"])),
+        Some("work".to_string())
+    );
+    assert_eq!(
+        Profile::from_args(&args(&["workflow", "github", "list", "--profile=personal"])),
+        Some("personal".to_string())
+    );
+    assert_eq!(
+        Profile::from_args(&args(&["workflow", "--", "--profile", "work"])),
+        None
+    );
+    assert_eq!(Profile::from_args(&args(&["workflow", "check"])), None);
+}
+
+/// 测试 Profile 名称校验
+#[test]
+fn test_profile_validate_name() {
+    assert!(Profile::validate_name("work").is_ok());
+    assert!(Profile::validate_name("personal_2-dev").is_ok());
+    assert!(Profile::validate_name("").is_err());
+    assert!(Profile::validate_name("../etc").is_err());
+    assert!(Profile::validate_name("my profile").is_err());
+}
+
+// ==================== 配置路径覆盖测试 ====================
+
+/// 测试从原始命令行参数中提取 `--config`
+#[test]
+fn test_config_from_args() {
+    let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(
+        Paths::config_from_args(&args(&["workflow", "--config", "/tmp/wf", "check"])),
+        Some("/tmp/wf".to_string())
+    );
+    assert_eq!(
+        Paths::config_from_args(&args(&["workflow", "check", "--config=/tmp/wf.toml"])),
+        Some("/tmp/wf.toml".to_string())
+    );
+    assert_eq!(
+        Paths::config_from_args(&args(&["workflow", "--", "--config", "/tmp/wf"])),
+        None
+    );
+    assert_eq!(
+        Paths::config_from_args(&args(&["workflow", "config", "show"])),
+        None
+    );
+}
+
+/// 测试 `WORKFLOW_CONFIG` 指定配置目录
+#[test]
+#[serial]
+fn test_config_override_with_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    std::env::set_var(CONFIG_ENV, dir.path());
+
+    assert_eq!(Paths::config_override(), Some(dir.path().to_path_buf()));
+    assert_eq!(Paths::config_dir().unwrap(), dir.path());
+    assert_eq!(
+        Paths::workflow_config().unwrap(),
+        dir.path().join("workflow.toml")
+    );
+    assert_eq!(Paths::jira_config().unwrap(), dir.path().join("jira.toml"));
+
+    std::env::remove_var(CONFIG_ENV);
+}
+
+/// 测试 `WORKFLOW_CONFIG` 指定配置文件（其他配置文件从同一目录读取）
+#[test]
+#[serial]
+fn test_config_override_with_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("custom.toml");
+    std::fs::write(&file, "[log]\nlevel = \"debug\"\n").unwrap();
+    std::env::set_var(CONFIG_ENV, &file);
+
+    assert_eq!(Paths::workflow_config().unwrap(), file);
+    assert_eq!(Paths::config_dir().unwrap(), dir.path());
+    assert!(Paths::validate_config_override(&file).is_ok());
+
+    std::env::remove_var(CONFIG_ENV);
+    assert_eq!(Paths::config_override(), None);
+}
+
+/// 测试配置路径不存在时返回错误
+#[test]
+fn test_validate_config_override_missing_path() {
+    let dir = tempfile::tempdir().unwrap();
+    let missing = dir.path().join("missing.toml");
+
+    let error = Paths::validate_config_override(&missing).unwrap_err();
+    assert!(error.to_string().contains("Config path does not exist"));
+    assert!(error.to_string().contains("missing.toml"));
+}
@@ -0,0 +1,142 @@
+//! 配置导出/导入的敏感信息处理测试
+//!
+//! 测试 `Settings::redact_secrets()` 和 `Settings::restore_omitted_secrets()`，
+//! 以及导出后再导入（TOML、JSON、YAML）能得到等价的配置。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use std::path::Path;
+use workflow::base::settings::settings::{GitHubAccount, JiraSettings};
+use workflow::base::settings::{Settings, OMITTED_SECRET};
+use workflow::commands::config::helpers::parse_config;
+
+// ==================== Helper Functions ====================
+
+/// 创建包含所有敏感字段的配置
+fn full_settings() -> Settings {
+    let mut settings = Settings {
+        jira: JiraSettings {
+            email: Some("test@example.com".to_string()),
+            api_token: Some("jira_token".to_string()),
+            service_address: Some("https://company.atlassian.net".to_string()),
+            timezone: None,
+            sprint_field: None,
+            auth_type: None,
+        },
+        ..Settings::default()
+    };
+    settings.github.accounts.push(GitHubAccount {
+        name: "work".to_string(),
+        email: "work@example.com".to_string(),
+        api_token: "ghp_work".to_string(),
+        login: None,
+    });
+    settings.github.current = Some("work".to_string());
+    settings.llm.provider = "deepseek".to_string();
+    settings.llm.openai.key = Some("sk-openai".to_string());
+    settings.llm.deepseek.key = Some("sk-deepseek".to_string());
+    settings.llm.deepseek.model = Some("deepseek-chat".to_string());
+    settings.log.level = Some("info".to_string());
+    settings
+}
+
+/// 以指定格式序列化配置（模拟导出）
+fn export(settings: &Settings, format: &str) -> String {
+    match format {
+        "toml" => toml::to_string_pretty(settings).unwrap(),
+        "json" => serde_json::to_string_pretty(settings).unwrap(),
+        "yaml" => serde_saphyr::to_string(settings).unwrap(),
+        _ => unreachable!(),
+    }
+}
+
+/// 转换为 TOML 值用于比较
+fn as_value(settings: &Settings) -> toml::Value {
+    toml::Value::try_from(settings).unwrap()
+}
+
+// ==================== 省略敏感信息测试 ====================
+
+#[test]
+fn test_redact_secrets_marks_all_secret_fields() {
+    let (redacted, count) = full_settings().redact_secrets();
+
+    assert_eq!(count, 4);
+    assert_eq!(redacted.jira.api_token.as_deref(), Some(OMITTED_SECRET));
+    assert_eq!(redacted.github.accounts[0].api_token, OMITTED_SECRET);
+    assert_eq!(redacted.llm.openai.key.as_deref(), Some(OMITTED_SECRET));
+    assert_eq!(redacted.llm.deepseek.key.as_deref(), Some(OMITTED_SECRET));
+    // 未设置的字段保持不变，非敏感字段保留
+    assert_eq!(redacted.llm.proxy.key, None);
+    assert_eq!(redacted.jira.email.as_deref(), Some("test@example.com"));
+    assert_eq!(
+        redacted.llm.deepseek.model.as_deref(),
+        Some("deepseek-chat")
+    );
+}
+
+#[test]
+fn test_redacted_export_does_not_contain_secrets() {
+    let (redacted, _) = full_settings().redact_secrets();
+    let content = export(&redacted, "toml");
+
+    for secret in ["jira_token", "ghp_work", "sk-openai", "sk-deepseek"] {
+        assert!(!content.contains(secret), "Export leaked '{}'", secret);
+    }
+    assert!(content.contains(OMITTED_SECRET));
+}
+
+#[test]
+fn test_restore_omitted_secrets_skips_unknown_github_accounts() {
+    let (mut imported, _) = full_settings().redact_secrets();
+    imported.github.accounts.push(GitHubAccount {
+        name: "personal".to_string(),
+        email: "me@example.com".to_string(),
+        api_token: OMITTED_SECRET.to_string(),
+        login: None,
+    });
+
+    // 本地配置中没有 jira token，也没有 personal 账号
+    let mut current = full_settings();
+    current.jira.api_token = None;
+
+    let missing = imported.restore_omitted_secrets(&current);
+
+    assert_eq!(missing, vec!["personal".to_string()]);
+    assert_eq!(imported.jira.api_token, None);
+    assert_eq!(imported.github.accounts[0].api_token, "ghp_work");
+    assert_eq!(imported.github.accounts[1].api_token, "");
+    assert_eq!(imported.llm.openai.key.as_deref(), Some("sk-openai"));
+}
+
+// ==================== 导出/导入往返测试 ====================
+
+#[rstest]
+#[case("toml")]
+#[case("json")]
+#[case("yaml")]
+fn test_round_trip_with_secrets(#[case] format: &str) {
+    let settings = full_settings();
+    let content = export(&settings, format);
+
+    let imported = parse_config(&content, Path::new(&format!("config.{}", format))).unwrap();
+
+    assert_eq!(as_value(&imported), as_value(&settings));
+}
+
+#[rstest]
+#[case("toml")]
+#[case("json")]
+#[case("yaml")]
+fn test_round_trip_redacted_keeps_local_secrets(#[case] format: &str) {
+    let settings = full_settings();
+    let (redacted, _) = settings.redact_secrets();
+    let content = export(&redacted, format);
+
+    let mut imported = parse_config(&content, Path::new(&format!("config.{}", format))).unwrap();
+    assert!(imported.validate().is_empty());
+    let missing = imported.restore_omitted_secrets(&settings);
+
+    assert!(missing.is_empty());
+    assert_eq!(as_value(&imported), as_value(&settings));
+}
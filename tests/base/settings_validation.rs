@@ -0,0 +1,323 @@
+//! Settings 配置校验测试
+//!
+//! 测试 `Settings::validate()` 和 `Settings::validate_file()` 对各类配置问题的检测：
+//! - 必填字段缺失
+//! - URL 格式错误
+//! - 枚举值错误（provider、日志级别）
+//! - 路径不存在（日志下载目录、自定义 prompt 文件）
+//! - 配置文件语法和类型错误
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use std::fs;
+use tempfile::tempdir;
+use workflow::base::settings::settings::{GitHubAccount, JiraSettings, NetworkTarget};
+use workflow::base::settings::{ConfigIssue, IssueSeverity, Settings};
+
+// ==================== Helper Functions ====================
+
+/// 创建完整且有效的 Jira 配置
+fn valid_jira_settings() -> JiraSettings {
+    JiraSettings {
+        email: Some("test@example.com".to_string()),
+        api_token: Some("test_token_123".to_string()),
+        service_address: Some("https://company.atlassian.net".to_string()),
+        timezone: None,
+        sprint_field: None,
+        auth_type: None,
+    }
+}
+
+/// 查找指定字段的问题
+fn find_issue<'a>(issues: &'a [ConfigIssue], field: &str) -> Option<&'a ConfigIssue> {
+    issues.iter().find(|issue| issue.field == field)
+}
+
+// ==================== 校验测试 ====================
+
+#[test]
+fn test_validate_default_settings_has_no_issues() {
+    // 默认配置（未配置任何内容）不应产生问题
+    assert_eq!(Settings::default().validate(), Vec::new());
+}
+
+#[test]
+fn test_validate_missing_required_fields() {
+    // Jira 配置不完整、GitHub 账号字段为空、proxy provider 缺少 URL 和模型
+    let mut settings = Settings {
+        jira: JiraSettings {
+            email: Some("test@example.com".to_string()),
+            api_token: None,
+            service_address: None,
+            timezone: None,
+            sprint_field: None,
+            auth_type: None,
+        },
+        ..Settings::default()
+    };
+    settings.github.accounts.push(GitHubAccount {
+        name: "work".to_string(),
+        email: "work@example.com".to_string(),
+        api_token: String::new(),
+        login: None,
+    });
+    settings.llm.provider = "proxy".to_string();
+
+    let issues = settings.validate();
+
+    for field in [
+        "jira.api_token",
+        "jira.service_address",
+        "github.accounts[0].api_token",
+        "llm.proxy.url",
+        "llm.proxy.model",
+    ] {
+        let issue =
+            find_issue(&issues, field).unwrap_or_else(|| panic!("Expected issue for '{}'", field));
+        assert_eq!(issue.severity, IssueSeverity::Error);
+    }
+    assert!(find_issue(&issues, "jira.email").is_none());
+}
+
+#[rstest]
+#[case("company.atlassian.net")]
+#[case("ftp://company.atlassian.net")]
+#[case("https://")]
+fn test_validate_invalid_jira_url(#[case] address: &str) {
+    let settings = Settings {
+        jira: JiraSettings {
+            service_address: Some(address.to_string()),
+            ..valid_jira_settings()
+        },
+        ..Settings::default()
+    };
+
+    let issues = settings.validate();
+    let issue = find_issue(&issues, "jira.service_address").expect("Expected URL issue");
+    assert!(issue.is_error());
+    assert!(issue.message.contains(address));
+}
+
+#[test]
+fn test_validate_invalid_llm_url() {
+    let mut settings = Settings::default();
+    settings.llm.provider = "proxy".to_string();
+    settings.llm.proxy.url = Some("not a url".to_string());
+    settings.llm.proxy.model = Some("model".to_string());
+
+    let issues = settings.validate();
+    assert!(find_issue(&issues, "llm.proxy.url").map(|i| i.is_error()).unwrap_or(false));
+}
+
+#[test]
+fn test_validate_invalid_enum_values() {
+    // 无效的 provider 和日志级别
+    let mut settings = Settings::default();
+    settings.llm.provider = "opneai".to_string();
+    settings.log.level = Some("verbose".to_string());
+
+    let issues = settings.validate();
+
+    let provider = find_issue(&issues, "llm.provider").expect("Expected provider issue");
+    assert!(provider.is_error());
+    assert!(provider.message.contains("opneai"));
+    let level = find_issue(&issues, "log.level").expect("Expected log level issue");
+    assert!(level.is_error());
+}
+
+#[test]
+fn test_validate_auto_language() {
+    let mut settings = Settings::default();
+    settings.llm.language = "auto".to_string();
+    assert!(find_issue(&settings.validate(), "llm.language").is_none());
+
+    settings.llm.language = "klingon".to_string();
+    assert!(find_issue(&settings.validate(), "llm.language").is_some());
+}
+
+#[test]
+fn test_validate_download_dir_existence() {
+    let temp_dir = tempdir().unwrap();
+
+    // 目录不存在：警告
+    let mut settings = Settings::default();
+    let missing = temp_dir.path().join("missing");
+    settings.log.download_base_dir = Some(missing.to_string_lossy().to_string());
+    let issues = settings.validate();
+    let issue = find_issue(&issues, "log.download_base_dir").expect("Expected path issue");
+    assert_eq!(issue.severity, IssueSeverity::Warning);
+
+    // 路径是文件：错误
+    let file = temp_dir.path().join("file");
+    fs::write(&file, "").unwrap();
+    settings.log.download_base_dir = Some(file.to_string_lossy().to_string());
+    let issues = settings.validate();
+    let issue = find_issue(&issues, "log.download_base_dir").expect("Expected path issue");
+    assert_eq!(issue.severity, IssueSeverity::Error);
+
+    // 目录存在：无问题
+    settings.log.download_base_dir = Some(temp_dir.path().to_string_lossy().to_string());
+    assert!(find_issue(&settings.validate(), "log.download_base_dir").is_none());
+}
+
+#[test]
+fn test_validate_prompt_files() {
+    let temp_dir = tempdir().unwrap();
+
+    // 文件不存在：警告
+    let mut settings = Settings::default();
+    let missing = temp_dir.path().join("missing.md");
+    settings.prompt.summarize_pr_path = Some(missing.to_string_lossy().to_string());
+    let issues = settings.validate();
+    let issue = find_issue(&issues, "prompt.summarize_pr_path").expect("Expected prompt issue");
+    assert_eq!(issue.severity, IssueSeverity::Warning);
+
+    // 文件为空：警告
+    let empty = temp_dir.path().join("empty.md");
+    fs::write(&empty, "  \n").unwrap();
+    settings.prompt.generate_branch_path = Some(empty.to_string_lossy().to_string());
+    let issues = settings.validate();
+    let issue = find_issue(&issues, "prompt.generate_branch_path").expect("Expected prompt issue");
+    assert_eq!(issue.severity, IssueSeverity::Warning);
+
+    // 文件存在且非空：无问题
+    let prompt = temp_dir.path().join("prompt.md");
+    fs::write(&prompt, "You're a git assistant.").unwrap();
+    settings.prompt.generate_branch_path = Some(prompt.to_string_lossy().to_string());
+    settings.prompt.summarize_pr_path = Some(prompt.to_string_lossy().to_string());
+    let issues = settings.validate();
+    assert!(find_issue(&issues, "prompt.generate_branch_path").is_none());
+    assert!(find_issue(&issues, "prompt.summarize_pr_path").is_none());
+}
+
+#[test]
+fn test_validate_http_timeouts() {
+    let mut settings = Settings::default();
+    settings.http.timeout_secs = Some(0);
+    settings.http.download_timeout_secs = Some(900);
+
+    let issues = settings.validate();
+
+    let issue = find_issue(&issues, "http.timeout_secs").expect("Expected timeout issue");
+    assert_eq!(issue.severity, IssueSeverity::Warning);
+    assert!(find_issue(&issues, "http.download_timeout_secs").is_none());
+}
+
+#[test]
+fn test_validate_network_targets() {
+    let mut settings = Settings::default();
+    settings.check.network_targets = vec![
+        NetworkTarget::new("GitHub API", "https://api.github.com"),
+        NetworkTarget::new("", "ftp://mirror.example.com"),
+    ];
+
+    let issues = settings.validate();
+    assert!(find_issue(&issues, "check.network_targets[0].url").is_none());
+    assert!(find_issue(&issues, "check.network_targets[1].name").is_some_and(|i| i.is_error()));
+    assert!(find_issue(&issues, "check.network_targets[1].url").is_some_and(|i| i.is_error()));
+}
+
+#[rstest]
+#[case(Some(0), true)]
+#[case(Some(4), false)]
+#[case(Some(100), true)]
+fn test_validate_http_max_concurrent_downloads(
+    #[case] configured: Option<usize>,
+    #[case] has_warning: bool,
+) {
+    let mut settings = Settings::default();
+    settings.http.max_concurrent_downloads = configured;
+
+    let issues = settings.validate();
+
+    let issue = find_issue(&issues, "http.max_concurrent_downloads");
+    assert_eq!(issue.is_some(), has_warning);
+    if let Some(issue) = issue {
+        assert_eq!(issue.severity, IssueSeverity::Warning);
+    }
+}
+
+#[rstest]
+#[case("utc", false)]
+#[case("Local", false)]
+#[case("Asia/Shanghai", true)]
+fn test_validate_jira_timezone(#[case] timezone: &str, #[case] has_warning: bool) {
+    let mut settings = Settings::default();
+    settings.jira.timezone = Some(timezone.to_string());
+
+    let issues = settings.validate();
+
+    let issue = find_issue(&issues, "jira.timezone");
+    assert_eq!(issue.is_some(), has_warning);
+    if let Some(issue) = issue {
+        assert_eq!(issue.severity, IssueSeverity::Warning);
+    }
+    // 只配置时区时不要求 Jira 认证信息
+    assert!(find_issue(&issues, "jira.email").is_none());
+}
+
+#[rstest]
+#[case("basic", false)]
+#[case("Bearer", false)]
+#[case("oauth", true)]
+fn test_validate_jira_auth_type(#[case] auth_type: &str, #[case] has_warning: bool) {
+    let mut settings = Settings::default();
+    settings.jira.auth_type = Some(auth_type.to_string());
+
+    let issue = find_issue(&settings.validate(), "jira.auth_type").cloned();
+    assert_eq!(issue.is_some(), has_warning);
+    if let Some(issue) = issue {
+        assert_eq!(issue.severity, IssueSeverity::Warning);
+    }
+}
+
+#[test]
+fn test_validate_jira_bearer_does_not_require_email() {
+    let settings = Settings {
+        jira: JiraSettings {
+            email: None,
+            auth_type: Some("bearer".to_string()),
+            ..valid_jira_settings()
+        },
+        ..Settings::default()
+    };
+
+    assert_eq!(settings.validate(), Vec::new());
+}
+
+// ==================== 配置文件校验测试 ====================
+
+#[rstest]
+#[case("[llm]\nprovider = 1\n", "llm.provider")]
+#[case("[jira]\nemail = \"a@b.c\"\napi_token = true\n", "jira.api_token")]
+#[case("[log]\nenable_trace_console = \"yes\"\n", "log.enable_trace_console")]
+fn test_validate_file_reports_key_path(#[case] content: &str, #[case] field: &str) {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join("workflow.toml");
+    fs::write(&path, content).unwrap();
+
+    let issues = Settings::validate_file(&path);
+
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].field, field);
+    assert!(issues[0].is_error());
+}
+
+#[test]
+fn test_validate_file_runs_settings_validation() {
+    let temp_dir = tempdir().unwrap();
+    let path = temp_dir.path().join("workflow.toml");
+    fs::write(&path, "[llm]\nprovider = \"unknown\"\n").unwrap();
+
+    let issues = Settings::validate_file(&path);
+    assert!(find_issue(&issues, "llm.provider").is_some());
+
+    // 文件不存在时没有问题
+    assert!(Settings::validate_file(&temp_dir.path().join("missing.toml")).is_empty());
+}
+
+#[test]
+fn test_config_issue_display() {
+    let issue = ConfigIssue::error("llm.provider", "Invalid provider");
+    assert_eq!(issue.to_string(), "[error] llm.provider: Invalid provider");
+}
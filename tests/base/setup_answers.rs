@@ -0,0 +1,252 @@
+//! 非交互式初始化设置的配置值测试
+//!
+//! 测试 `SetupAnswers` 从环境变量和配置值文件读取配置、校验必填项，
+//! 以及转换为与交互式表单相同的 `FormResult`。
+
+use pretty_assertions::assert_eq;
+use std::collections::HashMap;
+use workflow::base::settings::settings::GitHubAccount;
+use workflow::base::settings::Settings;
+use workflow::commands::config::setup_answers::SetupAnswers;
+
+// ==================== Helper Functions ====================
+
+/// 从环境变量映射创建配置值
+fn answers_from_env(vars: &[(&str, &str)]) -> SetupAnswers {
+    let vars: HashMap<String, String> =
+        vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    SetupAnswers::from_env_with(|name| vars.get(name).cloned())
+}
+
+/// 完整的必填配置值
+fn complete_answers() -> SetupAnswers {
+    answers_from_env(&[
+        ("WORKFLOW_JIRA_EMAIL", "dev@example.com"),
+        (
+            "WORKFLOW_JIRA_SERVICE_ADDRESS",
+            "https://example.atlassian.net",
+        ),
+        ("WORKFLOW_JIRA_API_TOKEN", "jira_token"),
+        ("WORKFLOW_GITHUB_NAME", "work"),
+        ("WORKFLOW_GITHUB_EMAIL", "work@example.com"),
+        ("WORKFLOW_GITHUB_API_TOKEN", "ghp_work"),
+    ])
+}
+
+// ==================== 读取配置值测试 ====================
+
+#[test]
+fn test_env_var_name() {
+    assert_eq!(SetupAnswers::env_var("jira_email"), "WORKFLOW_JIRA_EMAIL");
+}
+
+#[test]
+fn test_from_env_reads_known_keys() {
+    let answers = complete_answers();
+
+    assert_eq!(answers.get("jira_email"), Some("dev@example.com"));
+    assert_eq!(
+        answers.github_account(),
+        Some(GitHubAccount {
+            name: "work".to_string(),
+            email: "work@example.com".to_string(),
+            api_token: "ghp_work".to_string(),
+            login: None,
+        })
+    );
+}
+
+#[test]
+fn test_from_toml_and_env_precedence() {
+    let file_answers = SetupAnswers::from_toml(
+        r#"
+jira_email = "file@example.com"
+enable_trace_console = true
+"#,
+    )
+    .unwrap();
+    let env_answers = answers_from_env(&[("WORKFLOW_JIRA_EMAIL", "env@example.com")]);
+
+    let answers = file_answers.merge(env_answers);
+    assert_eq!(answers.get("jira_email"), Some("env@example.com"));
+    assert_eq!(answers.get("enable_trace_console"), Some("true"));
+}
+
+#[test]
+fn test_from_toml_rejects_unknown_keys() {
+    let result = SetupAnswers::from_toml(r#"jira_emial = "dev@example.com""#);
+    assert!(result.is_err());
+}
+
+// ==================== 校验测试 ====================
+
+#[test]
+fn test_missing_required_lists_all_missing_values() {
+    let answers = answers_from_env(&[("WORKFLOW_JIRA_EMAIL", "dev@example.com")]);
+
+    assert_eq!(
+        answers.missing_required(&Settings::default()),
+        vec![
+            "jira_service_address",
+            "jira_api_token",
+            "github_name",
+            "github_email",
+            "github_api_token",
+        ]
+    );
+}
+
+#[test]
+fn test_missing_required_uses_existing_values() {
+    let mut existing = Settings::default();
+    existing.jira.email = Some("dev@example.com".to_string());
+    existing.jira.service_address = Some("https://example.atlassian.net".to_string());
+    existing.jira.api_token = Some("jira_token".to_string());
+    existing.github.accounts.push(GitHubAccount {
+        name: "work".to_string(),
+        email: "work@example.com".to_string(),
+        api_token: "ghp_work".to_string(),
+        login: None,
+    });
+
+    assert!(SetupAnswers::default().missing_required(&existing).is_empty());
+}
+
+#[test]
+fn test_missing_required_for_proxy_provider() {
+    let answers = complete_answers().merge(answers_from_env(&[
+        ("WORKFLOW_LLM_PROVIDER", "proxy"),
+        ("WORKFLOW_LLM_PROXY_URL", "https://llm.example.com"),
+    ]));
+
+    assert_eq!(
+        answers.missing_required(&Settings::default()),
+        vec!["llm_proxy_key", "llm_proxy_model"]
+    );
+}
+
+#[test]
+fn test_missing_required_bearer_skips_jira_email() {
+    let answers = answers_from_env(&[
+        ("WORKFLOW_JIRA_AUTH_TYPE", "bearer"),
+        ("WORKFLOW_JIRA_SERVICE_ADDRESS", "https://jira.example.com"),
+        ("WORKFLOW_JIRA_API_TOKEN", "jira_pat"),
+        ("WORKFLOW_GITHUB_NAME", "work"),
+        ("WORKFLOW_GITHUB_EMAIL", "work@example.com"),
+        ("WORKFLOW_GITHUB_API_TOKEN", "ghp_work"),
+    ]);
+
+    assert!(answers.missing_required(&Settings::default()).is_empty());
+    assert_eq!(
+        answers.to_form_result("openai").get("jira_auth_type").map(String::as_str),
+        Some("bearer")
+    );
+}
+
+#[test]
+fn test_missing_required_skips_jira_when_not_provided() {
+    let answers = answers_from_env(&[
+        ("WORKFLOW_GITHUB_NAME", "work"),
+        ("WORKFLOW_GITHUB_EMAIL", "work@example.com"),
+        ("WORKFLOW_GITHUB_API_TOKEN", "ghp_work"),
+    ]);
+
+    // Jira 为可选配置：未提供任何 Jira 配置值时跳过
+    assert!(answers.missing_required(&Settings::default()).is_empty());
+    assert!(answers.validate(&Settings::default()).is_ok());
+
+    let form_result = answers.to_form_result("openai");
+    assert!(!form_result.has("jira_email"));
+    assert!(!form_result.has("jira_service_address"));
+    assert!(!form_result.has("jira_api_token"));
+}
+
+#[test]
+fn test_missing_required_jira_auth_type_requires_jira_values() {
+    let answers = answers_from_env(&[
+        ("WORKFLOW_JIRA_AUTH_TYPE", "bearer"),
+        ("WORKFLOW_GITHUB_NAME", "work"),
+        ("WORKFLOW_GITHUB_EMAIL", "work@example.com"),
+        ("WORKFLOW_GITHUB_API_TOKEN", "ghp_work"),
+    ]);
+
+    assert_eq!(
+        answers.missing_required(&Settings::default()),
+        vec!["jira_service_address", "jira_api_token"]
+    );
+}
+
+#[test]
+fn test_validate_reports_invalid_jira_auth_type() {
+    let answers =
+        complete_answers().merge(answers_from_env(&[("WORKFLOW_JIRA_AUTH_TYPE", "oauth")]));
+
+    let invalid = answers.invalid_values();
+    assert_eq!(invalid.len(), 1);
+    assert!(invalid[0].starts_with("jira_auth_type:"));
+}
+
+#[test]
+fn test_validate_reports_invalid_values() {
+    let answers = complete_answers().merge(answers_from_env(&[
+        ("WORKFLOW_JIRA_SERVICE_ADDRESS", "example.atlassian.net"),
+        ("WORKFLOW_LLM_PROVIDER", "claude"),
+    ]));
+
+    assert_eq!(answers.invalid_values().len(), 2);
+    let error = answers.validate(&Settings::default()).unwrap_err().to_string();
+    assert!(error.contains("Non-interactive setup failed"));
+}
+
+#[test]
+fn test_validate_error_names_env_vars() {
+    let error = SetupAnswers::default().validate(&Settings::default()).unwrap_err();
+    let message = error.to_string();
+    assert!(message.contains("github_name (env: WORKFLOW_GITHUB_NAME)"));
+}
+
+// ==================== FormResult 转换测试 ====================
+
+#[test]
+fn test_to_form_result() {
+    let answers = complete_answers().merge(answers_from_env(&[
+        ("WORKFLOW_LLM_OPENAI_KEY", "sk-test"),
+        ("WORKFLOW_LLM_LANGUAGE", "en"),
+        ("WORKFLOW_ENABLE_TRACE_CONSOLE", "true"),
+    ]));
+
+    let form_result = answers.to_form_result("openai");
+    assert_eq!(
+        form_result.get("jira_email").map(String::as_str),
+        Some("dev@example.com")
+    );
+    assert_eq!(
+        form_result.get("llm_provider").map(String::as_str),
+        Some("openai")
+    );
+    assert_eq!(
+        form_result.get("llm_openai_key").map(String::as_str),
+        Some("sk-test")
+    );
+    assert!(form_result.get("llm_language_display").unwrap().ends_with(" - en"));
+    assert!(form_result.get("trace_console_mode").unwrap().starts_with("Enable"));
+    assert!(!form_result.has("should_configure_log_folder"));
+}
+
+#[test]
+fn test_to_form_result_keeps_existing_jira_values() {
+    let form_result = SetupAnswers::default().to_form_result("openai");
+
+    // 未提供的 Jira 字段不出现在表单结果中，保留现有值
+    assert!(!form_result.has("jira_email"));
+    assert!(!form_result.has("llm_provider"));
+}
+
+#[test]
+fn test_to_form_result_auto_language() {
+    let answers = complete_answers().merge(answers_from_env(&[("WORKFLOW_LLM_LANGUAGE", "auto")]));
+
+    assert!(answers.invalid_values().is_empty());
+    let form_result = answers.to_form_result("openai");
+    assert!(form_result.get("llm_language_display").unwrap().ends_with(" - auto"));
+}
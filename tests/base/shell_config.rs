@@ -0,0 +1,767 @@
+//! Shell 配置管理器测试
+//!
+//! 测试 `ShellConfigManager` 对 shell 配置内容的修改是否幂等：
+//! - 托管配置块（`# >>> workflow >>>` / `# <<< workflow <<<`）的创建、原地更新和移除
+//! - 重复的 export 语句和配置块的合并（包括旧版本标记的配置块）
+//! - source 配置块（`# >>> workflow source >>>`）的创建、原地替换和精确移除
+//! - 等价 source 语句的识别（忽略多余空白）
+//! - 别名配置块（`# >>> workflow aliases >>>`）的创建、更新和移除，以及各 shell 的别名语法
+//! - 配置块外用户自己定义的别名的识别（用于冲突检查）
+//! - 写入配置文件前的备份和写入失败时的保护
+
+use pretty_assertions::assert_eq;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use tempfile::tempdir;
+use workflow::base::{Shell, ShellConfigManager};
+
+// ==================== Helper Functions ====================
+
+/// 创建环境变量映射
+fn env_vars(vars: &[(&str, &str)]) -> HashMap<String, String> {
+    vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+}
+
+/// 统计托管配置块的数量
+fn count_managed_blocks(content: &str) -> usize {
+    content.lines().filter(|line| line.trim() == "# >>> workflow >>>").count()
+}
+
+/// 统计 source 配置块的数量
+fn count_source_blocks(content: &str) -> usize {
+    content
+        .lines()
+        .filter(|line| line.trim() == "# >>> workflow source >>>")
+        .count()
+}
+
+// ==================== 环境变量配置块测试 ====================
+
+#[test]
+fn test_upsert_env_vars_twice_yields_one_managed_block() {
+    let vars = env_vars(&[("http_proxy", "http://127.0.0.1:7890")]);
+
+    let first = ShellConfigManager::upsert_env_vars_in_content("alias ll='ls -l'\n", &vars);
+    let second = ShellConfigManager::upsert_env_vars_in_content(&first, &vars);
+
+    assert_eq!(second, first);
+    assert_eq!(count_managed_blocks(&second), 1);
+    assert!(second.starts_with("alias ll='ls -l'\n\n# >>> workflow >>>\n"));
+    assert!(second.contains("export http_proxy=\"http://127.0.0.1:7890\""));
+    assert!(second.ends_with("# <<< workflow <<<\n"));
+}
+
+#[test]
+fn test_upsert_env_vars_updates_block_in_place() {
+    let vars = env_vars(&[("http_proxy", "http://old:1")]);
+    let content = format!(
+        "export PATH=\"$HOME/bin:$PATH\"\n{}alias ll='ls -l'\n",
+        ShellConfigManager::upsert_env_vars_in_content("", &vars)
+    );
+
+    let updated = ShellConfigManager::upsert_env_vars_in_content(
+        &content,
+        &env_vars(&[
+            ("http_proxy", "http://new:2"),
+            ("https_proxy", "http://new:2"),
+        ]),
+    );
+
+    assert_eq!(count_managed_blocks(&updated), 1);
+    assert!(updated.starts_with("export PATH=\"$HOME/bin:$PATH\"\n# >>> workflow >>>\n"));
+    assert!(updated.ends_with("# <<< workflow <<<\nalias ll='ls -l'\n"));
+    assert!(updated.contains("export http_proxy=\"http://new:2\"\nexport https_proxy="));
+    assert!(!updated.contains("http://old:1"));
+}
+
+#[test]
+fn test_upsert_env_vars_removes_equivalent_export_lines() {
+    let content = "export   http_proxy =  \"http://manual:1\"\nexport PATH=\"/usr/bin\"\n";
+
+    let updated = ShellConfigManager::upsert_env_vars_in_content(
+        content,
+        &env_vars(&[("http_proxy", "http://127.0.0.1:7890")]),
+    );
+
+    assert!(!updated.contains("http://manual:1"));
+    assert!(updated.starts_with("export PATH=\"/usr/bin\"\n"));
+    assert_eq!(updated.matches("http_proxy").count(), 1);
+}
+
+#[test]
+fn test_upsert_env_vars_merges_duplicate_and_legacy_blocks() {
+    let content = "\
+# Workflow CLI Configuration - Start
+export http_proxy=\"http://legacy:1\"
+# Workflow CLI Configuration - End
+
+# >>> workflow
+export https_proxy=\"http://new:2\"
+# <<< workflow
+";
+
+    let updated = ShellConfigManager::upsert_env_vars_in_content(content, &HashMap::new());
+
+    assert_eq!(count_managed_blocks(&updated), 1);
+    assert!(!updated.contains("Workflow CLI Configuration"));
+    assert!(updated.contains("export http_proxy=\"http://legacy:1\""));
+    assert!(updated.contains("export https_proxy=\"http://new:2\""));
+}
+
+#[test]
+fn test_upsert_env_vars_upgrades_short_markers() {
+    let content = "# >>> workflow\nexport http_proxy=\"http://old:1\"\n# <<< workflow\n";
+
+    let updated = ShellConfigManager::upsert_env_vars_in_content(
+        content,
+        &env_vars(&[("http_proxy", "http://new:2")]),
+    );
+
+    assert_eq!(count_managed_blocks(&updated), 1);
+    assert!(updated.starts_with("# >>> workflow >>>\n"));
+    assert!(updated.ends_with("# <<< workflow <<<\n"));
+    assert!(!updated.contains("http://old:1"));
+}
+
+#[test]
+fn test_upsert_env_vars_keeps_source_block() {
+    let content = ShellConfigManager::add_source_to_content(
+        "",
+        &Shell::Zsh,
+        "/opt/workflow/completions.zsh",
+        None,
+    )
+    .unwrap()
+    .unwrap();
+    let vars = env_vars(&[("http_proxy", "http://127.0.0.1:7890")]);
+
+    let first = ShellConfigManager::upsert_env_vars_in_content(&content, &vars);
+    let second = ShellConfigManager::upsert_env_vars_in_content(&first, &vars);
+
+    assert_eq!(second, first);
+    assert!(first.starts_with(&content));
+    assert_eq!(count_source_blocks(&first), 1);
+    assert_eq!(count_managed_blocks(&first), 1);
+}
+
+#[test]
+fn test_upsert_env_vars_without_values_keeps_content() {
+    let content = "alias ll='ls -l'\n";
+
+    assert_eq!(
+        ShellConfigManager::upsert_env_vars_in_content(content, &HashMap::new()),
+        content
+    );
+}
+
+#[test]
+fn test_upsert_env_vars_uses_nushell_syntax() {
+    let vars = env_vars(&[
+        ("http_proxy", "http://127.0.0.1:7890"),
+        ("NOTE", "say \"hi\" $USER"),
+    ]);
+
+    let first = ShellConfigManager::upsert_env_vars_in_content_for_shell(
+        "$env.config.show_banner = false\n",
+        &Shell::Nushell,
+        &vars,
+    );
+    let second =
+        ShellConfigManager::upsert_env_vars_in_content_for_shell(&first, &Shell::Nushell, &vars);
+
+    assert_eq!(second, first);
+    assert_eq!(count_managed_blocks(&first), 1);
+    assert!(first.starts_with("$env.config.show_banner = false\n\n# >>> workflow >>>\n"));
+    assert!(first.contains("$env.NOTE = \"say \\\"hi\\\" $USER\"\n"));
+    assert!(first.contains("$env.http_proxy = \"http://127.0.0.1:7890\"\n"));
+    assert!(!first.contains("export "));
+}
+
+#[test]
+fn test_remove_env_vars_from_content_removes_nushell_block() {
+    let content = ShellConfigManager::upsert_env_vars_in_content_for_shell(
+        "$env.config.show_banner = false\n",
+        &Shell::Nushell,
+        &env_vars(&[("http_proxy", "http://127.0.0.1:7890")]),
+    );
+
+    let result = ShellConfigManager::remove_env_vars_from_content(&content, &["http_proxy"]);
+
+    assert_eq!(result.as_deref(), Some("$env.config.show_banner = false\n"));
+}
+
+// ==================== 托管配置块移除测试 ====================
+
+#[test]
+fn test_remove_managed_block_from_content() {
+    let vars = env_vars(&[("http_proxy", "http://127.0.0.1:7890")]);
+    let content = ShellConfigManager::upsert_env_vars_in_content("alias ll='ls -l'\n", &vars);
+    let content = format!("{}\nalias gs='git status'\n", content);
+
+    let removed = ShellConfigManager::remove_managed_block_from_content(&content).unwrap();
+
+    assert_eq!(removed, "alias ll='ls -l'\n\nalias gs='git status'\n");
+    assert_eq!(
+        ShellConfigManager::remove_managed_block_from_content(&removed),
+        None
+    );
+}
+
+#[test]
+fn test_remove_managed_block_removes_legacy_block() {
+    let content = "\
+# Workflow CLI Configuration - Start
+export http_proxy=\"http://legacy:1\"
+# Workflow CLI Configuration - End
+
+alias ll='ls -l'
+";
+
+    assert_eq!(
+        ShellConfigManager::remove_managed_block_from_content(content).as_deref(),
+        Some("alias ll='ls -l'\n")
+    );
+}
+
+#[test]
+fn test_remove_managed_block_keeps_source_block() {
+    let content = "\
+# >>> workflow >>>
+export http_proxy=\"http://127.0.0.1:7890\"
+# <<< workflow <<<
+
+# >>> workflow source >>>
+source /opt/workflow/completions.zsh
+# <<< workflow source <<<
+";
+
+    assert_eq!(
+        ShellConfigManager::remove_managed_block_from_content(content).as_deref(),
+        Some("# >>> workflow source >>>\nsource /opt/workflow/completions.zsh\n# <<< workflow source <<<\n")
+    );
+}
+
+#[test]
+fn test_remove_managed_block_keeps_unterminated_marker() {
+    let content = "# >>> workflow\nexport http_proxy=\"http://127.0.0.1:7890\"\n";
+
+    assert_eq!(
+        ShellConfigManager::remove_managed_block_from_content(content),
+        None
+    );
+}
+
+// ==================== Source 语句测试 ====================
+
+#[test]
+fn test_add_source_to_content_twice_yields_one_block() {
+    let source_path = "/opt/workflow/completions.zsh";
+
+    let first = ShellConfigManager::add_source_to_content(
+        "alias ll='ls -l'\n",
+        &Shell::Zsh,
+        source_path,
+        Some("Workflow"),
+    )
+    .unwrap()
+    .unwrap();
+    let second = ShellConfigManager::add_source_to_content(
+        &first,
+        &Shell::Zsh,
+        source_path,
+        Some("Workflow"),
+    )
+    .unwrap();
+
+    assert_eq!(
+        first,
+        "\
+alias ll='ls -l'
+
+# >>> workflow source >>>
+# Workflow
+source /opt/workflow/completions.zsh
+# <<< workflow source <<<
+"
+    );
+    assert_eq!(second, None);
+    assert_eq!(count_source_blocks(&first), 1);
+}
+
+#[test]
+fn test_add_source_to_content_replaces_block_in_place() {
+    let source_path = "/opt/workflow/completions.zsh";
+    let block = ShellConfigManager::add_source_to_content("", &Shell::Zsh, source_path, None)
+        .unwrap()
+        .unwrap();
+    let content = format!("{}alias ll='ls -l'\n", block);
+
+    let updated =
+        ShellConfigManager::add_source_to_content(&content, &Shell::Zsh, source_path, Some("New"))
+            .unwrap()
+            .unwrap();
+
+    assert_eq!(count_source_blocks(&updated), 1);
+    assert!(updated.starts_with("# >>> workflow source >>>\n# New\nsource "));
+    assert!(updated.ends_with("# <<< workflow source <<<\nalias ll='ls -l'\n"));
+}
+
+#[test]
+fn test_add_source_to_content_merges_duplicate_blocks() {
+    let source_path = "/opt/workflow/completions.zsh";
+    let block = ShellConfigManager::add_source_to_content("", &Shell::Zsh, source_path, None)
+        .unwrap()
+        .unwrap();
+    let content = format!("{}\n{}export EDITOR=vim\n", block, block);
+
+    let updated =
+        ShellConfigManager::add_source_to_content(&content, &Shell::Zsh, source_path, None)
+            .unwrap()
+            .unwrap();
+
+    assert_eq!(updated, format!("{}export EDITOR=vim\n", block));
+    assert_eq!(count_source_blocks(&updated), 1);
+}
+
+#[test]
+fn test_add_source_to_content_ignores_extra_whitespace() {
+    let content = "  source\t  /opt/workflow/completions.zsh  \n";
+
+    let result = ShellConfigManager::add_source_to_content(
+        content,
+        &Shell::Zsh,
+        "/opt/workflow/completions.zsh",
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(result, None);
+}
+
+#[test]
+fn test_add_source_to_content_uses_shell_keyword() {
+    let result = ShellConfigManager::add_source_to_content(
+        "",
+        &Shell::PowerShell,
+        "/opt/workflow/completions.ps1",
+        None,
+    )
+    .unwrap();
+
+    assert_eq!(
+        result.as_deref(),
+        Some("# >>> workflow source >>>\n. /opt/workflow/completions.ps1\n# <<< workflow source <<<\n")
+    );
+}
+
+#[test]
+fn test_add_source_to_content_keeps_unmarked_source_line() {
+    // 手动添加或旧版本写入的 source 语句视为已存在，不再追加配置块
+    let content = "# Workflow CLI completions\nsource /opt/workflow/completions.zsh\n";
+
+    let result = ShellConfigManager::add_source_to_content(
+        content,
+        &Shell::Zsh,
+        "/opt/workflow/completions.zsh",
+        Some("Workflow CLI completions"),
+    )
+    .unwrap();
+
+    assert_eq!(result, None);
+}
+
+#[test]
+fn test_remove_source_from_content_removes_exactly_the_block() {
+    let source_path = "/opt/workflow/completions.zsh";
+    let with_block = ShellConfigManager::add_source_to_content(
+        "alias ll='ls -l'\n",
+        &Shell::Zsh,
+        source_path,
+        Some("Workflow CLI completions"),
+    )
+    .unwrap()
+    .unwrap();
+    let content = format!("{}alias gs='git status'\n", with_block);
+
+    let removed =
+        ShellConfigManager::remove_source_from_content(&content, &Shell::Zsh, source_path)
+            .unwrap()
+            .unwrap();
+
+    assert_eq!(removed, "alias ll='ls -l'\nalias gs='git status'\n");
+    assert_eq!(
+        ShellConfigManager::remove_source_from_content(&removed, &Shell::Zsh, source_path).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_remove_source_from_content_keeps_other_source_blocks() {
+    let first =
+        ShellConfigManager::add_source_to_content("", &Shell::Zsh, "/opt/workflow/a.zsh", None)
+            .unwrap()
+            .unwrap();
+    let both =
+        ShellConfigManager::add_source_to_content(&first, &Shell::Zsh, "/opt/workflow/b.zsh", None)
+            .unwrap()
+            .unwrap();
+
+    let removed =
+        ShellConfigManager::remove_source_from_content(&both, &Shell::Zsh, "/opt/workflow/b.zsh")
+            .unwrap()
+            .unwrap();
+
+    assert_eq!(removed, first);
+}
+
+#[test]
+fn test_remove_source_from_content_removes_unmarked_source_line() {
+    let content =
+        "# Workflow CLI completions\nsource /opt/workflow/completions.zsh\n\nalias ll='ls -l'\n";
+
+    let removed = ShellConfigManager::remove_source_from_content(
+        content,
+        &Shell::Zsh,
+        "/opt/workflow/completions.zsh",
+    )
+    .unwrap();
+
+    assert_eq!(removed.as_deref(), Some("alias ll='ls -l'\n"));
+}
+
+#[test]
+fn test_remove_env_vars_from_content_removes_empty_block() {
+    let content = "alias ll='ls -l'\n\n# >>> workflow\nexport http_proxy=\"http://127.0.0.1:7890\"\n# <<< workflow\nexport  http_proxy=old\n";
+
+    let result = ShellConfigManager::remove_env_vars_from_content(content, &["http_proxy"]);
+
+    assert_eq!(result.as_deref(), Some("alias ll='ls -l'\n"));
+}
+
+#[test]
+fn test_remove_env_vars_from_content_without_match_returns_none() {
+    let content = "export EDITOR=vim\n";
+
+    assert_eq!(
+        ShellConfigManager::remove_env_vars_from_content(content, &["http_proxy"]),
+        None
+    );
+}
+
+// ==================== 别名配置块测试 ====================
+
+#[test]
+fn test_upsert_alias_twice_yields_one_block() {
+    let content = "alias ll='ls -l'\n";
+
+    let first = ShellConfigManager::upsert_alias_in_content(content, &Shell::Zsh, "wf", "workflow")
+        .unwrap();
+    let second = ShellConfigManager::upsert_alias_in_content(
+        &first,
+        &Shell::Zsh,
+        "prc",
+        "workflow pr create",
+    )
+    .unwrap();
+
+    assert_eq!(
+        second,
+        "alias ll='ls -l'\n\n# >>> workflow aliases >>>\nalias prc='workflow pr create'\n\
+         alias wf='workflow'\n# <<< workflow aliases <<<\n"
+    );
+    assert_eq!(
+        ShellConfigManager::upsert_alias_in_content(&second, &Shell::Zsh, "wf", "workflow"),
+        None
+    );
+}
+
+#[test]
+fn test_upsert_alias_updates_existing_alias_in_place() {
+    let content = "# >>> workflow aliases >>>\nalias wf='workflow'\n# <<< workflow aliases <<<\n\
+                   export PATH=\"$HOME/bin:$PATH\"\n";
+
+    let updated =
+        ShellConfigManager::upsert_alias_in_content(content, &Shell::Bash, "wf", "workflow pr")
+            .unwrap();
+
+    assert_eq!(
+        updated,
+        "# >>> workflow aliases >>>\nalias wf='workflow pr'\n# <<< workflow aliases <<<\n\
+         export PATH=\"$HOME/bin:$PATH\"\n"
+    );
+}
+
+#[test]
+fn test_aliases_in_content_roundtrips_quotes() {
+    let updated =
+        ShellConfigManager::upsert_alias_in_content("", &Shell::Bash, "hi", "echo 'hello world'")
+            .unwrap();
+
+    assert!(updated.contains("alias hi='echo '\\''hello world'\\'''"));
+    let aliases = ShellConfigManager::aliases_in_content(&updated, &Shell::Bash);
+    assert_eq!(
+        aliases.get("hi").map(String::as_str),
+        Some("echo 'hello world'")
+    );
+}
+
+#[test]
+fn test_aliases_in_content_ignores_aliases_outside_block() {
+    let content = "alias ll='ls -l'\n# >>> workflow aliases >>>\nalias wf='workflow'\n\
+                   # <<< workflow aliases <<<\n";
+
+    let aliases = ShellConfigManager::aliases_in_content(content, &Shell::Zsh);
+
+    assert_eq!(aliases.len(), 1);
+    assert_eq!(aliases.get("wf").map(String::as_str), Some("workflow"));
+}
+
+#[test]
+fn test_upsert_alias_uses_shell_syntax() {
+    let cases = [
+        (Shell::Fish, "abbr --add prc 'workflow pr create'"),
+        (Shell::Nushell, "alias prc = workflow pr create"),
+        (
+            Shell::PowerShell,
+            "function prc { workflow pr create @args }",
+        ),
+        (Shell::Elvish, "fn prc {|@args| workflow pr create $@args }"),
+    ];
+
+    for (shell, expected) in cases {
+        let content =
+            ShellConfigManager::upsert_alias_in_content("", &shell, "prc", "workflow pr create")
+                .unwrap();
+        assert!(content.contains(expected), "{}: {}", shell, content);
+        let aliases = ShellConfigManager::aliases_in_content(&content, &shell);
+        assert_eq!(
+            aliases.get("prc").map(String::as_str),
+            Some("workflow pr create")
+        );
+    }
+}
+
+#[test]
+fn test_upsert_alias_merges_duplicate_blocks() {
+    let block_a = "# >>> workflow aliases >>>\nalias a='x'\n# <<< workflow aliases <<<\n";
+    let block_b = "# >>> workflow aliases >>>\nalias b='y'\n# <<< workflow aliases <<<\n";
+    let content = format!("{}\nalias ll='ls -l'\n\n{}", block_a, block_b);
+
+    let updated =
+        ShellConfigManager::upsert_alias_in_content(&content, &Shell::Zsh, "a", "x").unwrap();
+
+    assert_eq!(
+        updated,
+        "# >>> workflow aliases >>>\nalias a='x'\nalias b='y'\n# <<< workflow aliases <<<\n\n\
+         alias ll='ls -l'\n"
+    );
+}
+
+#[test]
+fn test_remove_alias_from_content_removes_empty_block() {
+    let content = "alias ll='ls -l'\n";
+    let added = ShellConfigManager::upsert_alias_in_content(content, &Shell::Zsh, "wf", "workflow")
+        .unwrap();
+
+    let removed = ShellConfigManager::remove_alias_from_content(&added, &Shell::Zsh, "wf");
+
+    assert_eq!(removed.as_deref(), Some(content));
+    assert_eq!(
+        ShellConfigManager::remove_alias_from_content(content, &Shell::Zsh, "wf"),
+        None
+    );
+}
+
+#[test]
+fn test_fish_legacy_alias_is_rewritten_as_abbr() {
+    let content = "# >>> workflow aliases >>>\nalias wf='workflow'\n# <<< workflow aliases <<<\n";
+    assert_eq!(
+        ShellConfigManager::aliases_in_content(content, &Shell::Fish)
+            .get("wf")
+            .map(String::as_str),
+        Some("workflow")
+    );
+
+    let updated = ShellConfigManager::upsert_alias_in_content(
+        content,
+        &Shell::Fish,
+        "prc",
+        "workflow pr create",
+    )
+    .unwrap();
+
+    assert_eq!(
+        updated,
+        "# >>> workflow aliases >>>\nabbr --add prc 'workflow pr create'\n\
+         abbr --add wf 'workflow'\n# <<< workflow aliases <<<\n"
+    );
+}
+
+#[test]
+fn test_upsert_aliases_in_content_writes_batch_once() {
+    let aliases = BTreeMap::from([
+        ("wpr".to_string(), "workflow pr".to_string()),
+        ("wf".to_string(), "workflow".to_string()),
+    ]);
+
+    let updated =
+        ShellConfigManager::upsert_aliases_in_content("alias ll='ls -l'\n", &Shell::Zsh, &aliases)
+            .unwrap();
+
+    assert_eq!(
+        updated,
+        "alias ll='ls -l'\n\n# >>> workflow aliases >>>\nalias wf='workflow'\n\
+         alias wpr='workflow pr'\n# <<< workflow aliases <<<\n"
+    );
+    assert_eq!(
+        ShellConfigManager::upsert_aliases_in_content(&updated, &Shell::Zsh, &aliases),
+        None
+    );
+}
+
+#[test]
+fn test_remove_all_aliases_from_content_strips_block() {
+    let aliases = BTreeMap::from([
+        ("wf".to_string(), "workflow".to_string()),
+        ("wpr".to_string(), "workflow pr".to_string()),
+    ]);
+    let content = "alias ll='ls -l'\n";
+    let added =
+        ShellConfigManager::upsert_aliases_in_content(content, &Shell::Bash, &aliases).unwrap();
+
+    assert_eq!(
+        ShellConfigManager::remove_all_aliases_from_content(&added, &Shell::Bash).as_deref(),
+        Some(content)
+    );
+    assert_eq!(
+        ShellConfigManager::remove_all_aliases_from_content(content, &Shell::Bash),
+        None
+    );
+}
+
+#[test]
+fn test_unmanaged_alias_names_in_content() {
+    let content = "alias ll='ls -l'\n\
+                   alias -g G='| grep'\n\
+                   # alias old='commented out'\n\
+                   abbr --add gs 'git status'\n\
+                   function wpr { workflow pr @args }\n\
+                   export EDITOR=vim\n\
+                   # >>> workflow aliases >>>\nalias wf='workflow'\n# <<< workflow aliases <<<\n";
+
+    let names = ShellConfigManager::unmanaged_alias_names_in_content(content);
+
+    let expected: BTreeSet<String> =
+        ["G", "gs", "ll", "wpr"].iter().map(|name| name.to_string()).collect();
+    assert_eq!(names, expected);
+}
+
+#[test]
+fn test_alias_block_is_independent_of_env_block() {
+    let vars = env_vars(&[("http_proxy", "http://127.0.0.1:7890")]);
+    let content = ShellConfigManager::upsert_env_vars_in_content("", &vars);
+    let with_alias =
+        ShellConfigManager::upsert_alias_in_content(&content, &Shell::Zsh, "wf", "workflow")
+            .unwrap();
+
+    assert_eq!(count_managed_blocks(&with_alias), 1);
+    assert_eq!(
+        ShellConfigManager::upsert_env_vars_in_content(&with_alias, &vars),
+        with_alias
+    );
+    assert_eq!(
+        ShellConfigManager::remove_managed_block_from_content(&with_alias).as_deref(),
+        ShellConfigManager::upsert_alias_in_content("", &Shell::Zsh, "wf", "workflow").as_deref()
+    );
+}
+
+// ==================== 配置文件备份测试 ====================
+
+#[test]
+fn test_write_config_file_creates_one_backup_with_original_content() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let config_path = dir.path().join(".zshrc");
+    let original = "export PATH=\"$HOME/bin:$PATH\"\n";
+    fs::write(&config_path, original).unwrap();
+
+    let edit = ShellConfigManager::write_config_file(&config_path, "alias ll='ls -l'\n").unwrap();
+
+    let backups = ShellConfigManager::list_backups(&config_path);
+    assert_eq!(backups.len(), 1);
+    assert_eq!(edit.config_path, config_path);
+    assert_eq!(edit.backup_path.as_ref(), Some(&backups[0]));
+    assert_eq!(fs::read_to_string(&backups[0]).unwrap(), original);
+    assert_eq!(
+        fs::read_to_string(&config_path).unwrap(),
+        "alias ll='ls -l'\n"
+    );
+}
+
+#[test]
+fn test_write_config_file_without_existing_file_has_no_backup() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let config_path = dir.path().join(".bashrc");
+
+    let edit = ShellConfigManager::write_config_file(&config_path, "source ~/.profile\n").unwrap();
+
+    assert_eq!(edit.backup_path, None);
+    assert!(ShellConfigManager::list_backups(&config_path).is_empty());
+    assert_eq!(
+        fs::read_to_string(&config_path).unwrap(),
+        "source ~/.profile\n"
+    );
+}
+
+#[test]
+fn test_write_config_file_keeps_earlier_backups_within_same_second() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let config_path = dir.path().join(".zshrc");
+    fs::write(&config_path, "first\n").unwrap();
+
+    let first = ShellConfigManager::write_config_file(&config_path, "second\n").unwrap();
+    let second = ShellConfigManager::write_config_file(&config_path, "third\n").unwrap();
+
+    assert_ne!(first.backup_path, second.backup_path);
+    assert_eq!(
+        fs::read_to_string(first.backup_path.unwrap()).unwrap(),
+        "first\n"
+    );
+    assert_eq!(
+        fs::read_to_string(second.backup_path.unwrap()).unwrap(),
+        "second\n"
+    );
+}
+
+#[test]
+fn test_write_config_file_keeps_original_backup_when_pruning() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let config_path = dir.path().join(".zshrc");
+    fs::write(&config_path, "original\n").unwrap();
+
+    let first = ShellConfigManager::write_config_file(&config_path, "edit 0\n").unwrap();
+    for i in 1..8 {
+        ShellConfigManager::write_config_file(&config_path, &format!("edit {}\n", i)).unwrap();
+    }
+
+    // 超出数量限制（5 个）时删除旧备份，但本次运行的第一个备份（修改前的原始内容）总是保留
+    let backups = ShellConfigManager::list_backups(&config_path);
+    assert_eq!(backups.len(), 5);
+    let original_backup = first.backup_path.unwrap();
+    assert!(backups.contains(&original_backup));
+    assert_eq!(fs::read_to_string(&original_backup).unwrap(), "original\n");
+}
+
+#[test]
+fn test_write_config_file_failure_leaves_original_untouched() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let config_path = dir.path().join(".zshrc");
+    let original = "export EDITOR=vim\n";
+    fs::write(&config_path, original).unwrap();
+    // 临时文件路径被目录占用，写入新内容会失败
+    fs::create_dir(dir.path().join("..zshrc.workflow-tmp")).unwrap();
+
+    let result = ShellConfigManager::write_config_file(&config_path, "broken\n");
+
+    assert!(result.is_err());
+    assert_eq!(fs::read_to_string(&config_path).unwrap(), original);
+}
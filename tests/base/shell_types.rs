@@ -0,0 +1,64 @@
+//! Shell 类型测试
+//!
+//! 测试 `Shell` 从可执行文件路径的解析、名称显示，以及与 clap completion 生成器的对应关系。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use workflow::base::shell::Shell;
+
+// ==================== 路径解析测试 ====================
+
+#[rstest]
+#[case("/bin/zsh", Some(Shell::Zsh))]
+#[case("/usr/local/bin/bash", Some(Shell::Bash))]
+#[case("/usr/bin/fish", Some(Shell::Fish))]
+#[case("/usr/bin/elvish", Some(Shell::Elvish))]
+#[case("/usr/bin/pwsh", Some(Shell::PowerShell))]
+#[case("powershell.exe", Some(Shell::PowerShell))]
+#[case("/opt/homebrew/bin/nu", Some(Shell::Nushell))]
+#[case("nu.exe", Some(Shell::Nushell))]
+#[case("-zsh", Some(Shell::Zsh))]
+#[case("/bin/sh", None)]
+#[case("", None)]
+fn test_shell_from_shell_path(#[case] path: &str, #[case] expected: Option<Shell>) {
+    assert_eq!(Shell::from_shell_path(path), expected);
+}
+
+// ==================== 名称测试 ====================
+
+#[rstest]
+#[case(Shell::Zsh, "zsh", "zsh")]
+#[case(Shell::Bash, "bash", "bash")]
+#[case(Shell::Fish, "fish", "fish")]
+#[case(Shell::PowerShell, "powershell", "powershell")]
+#[case(Shell::Elvish, "elvish", "elvish")]
+#[case(Shell::Nushell, "nushell", "nu")]
+fn test_shell_name_and_executable(
+    #[case] shell: Shell,
+    #[case] name: &str,
+    #[case] executable: &str,
+) {
+    assert_eq!(shell.to_string(), name);
+    assert_eq!(shell.executable(), executable);
+}
+
+// ==================== Completion 生成器测试 ====================
+
+#[test]
+fn test_shell_completion_shell_matches_clap_names() {
+    for shell in [
+        Shell::Zsh,
+        Shell::Bash,
+        Shell::Fish,
+        Shell::PowerShell,
+        Shell::Elvish,
+    ] {
+        let clap_shell = shell.completion_shell().expect("clap supports this shell");
+        assert_eq!(clap_shell.to_string(), shell.to_string());
+    }
+}
+
+#[test]
+fn test_shell_completion_shell_nushell_is_unsupported() {
+    assert_eq!(Shell::Nushell.completion_shell(), None);
+}
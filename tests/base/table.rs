@@ -0,0 +1,245 @@
+//! Base/Table 模块测试
+//!
+//! 测试表格构建器的宽度控制，包括：
+//! - 单列最大宽度（截断和换行）
+//! - 表格最大宽度
+//! - 带 ANSI 颜色代码的单元格宽度计算
+//! - 禁用颜色输出时去除 ANSI 颜色代码
+//! - 导出为 Markdown、CSV、TSV（转义分隔符）
+
+use console::{measure_text_width, style};
+use serial_test::serial;
+use tabled::Tabled;
+
+use workflow::base::logger::console::set_colors_enabled;
+use workflow::base::table::{TableBuilder, TableOverflow, TableStyle, ELLIPSIS};
+
+#[derive(Tabled)]
+struct CommitRow {
+    #[tabled(rename = "SHA")]
+    sha: String,
+    #[tabled(rename = "Subject")]
+    subject: String,
+    #[tabled(rename = "Status")]
+    status: String,
+}
+
+const LONG_SUBJECT: &str =
+    "Add colorized width aware wrapping to the table builder for long titles";
+
+fn rows() -> Vec<CommitRow> {
+    vec![
+        CommitRow {
+            sha: "a1b2c3d".to_string(),
+            subject: LONG_SUBJECT.to_string(),
+            status: "Done".to_string(),
+        },
+        CommitRow {
+            sha: "e4f5a6b".to_string(),
+            subject: "Fix typo".to_string(),
+            status: "Open".to_string(),
+        },
+    ]
+}
+
+/// 每一行的显示宽度（不包括 ANSI 颜色代码）
+fn line_widths(output: &str) -> Vec<usize> {
+    output.lines().map(measure_text_width).collect()
+}
+
+// ==================== 单列最大宽度测试 ====================
+
+#[test]
+fn test_column_max_width_truncate() {
+    let output = TableBuilder::new(rows())
+        .with_column_max_width(1, 20)
+        .with_overflow(TableOverflow::Truncate)
+        .render();
+
+    assert!(output.contains(ELLIPSIS));
+    assert!(!output.contains(LONG_SUBJECT));
+    assert!(output.contains("Fix typo"));
+    // 截断后不会产生额外的行（边框 + 表头 + 2 行数据）
+    assert_eq!(output.lines().count(), 7);
+    let widths = line_widths(&output);
+    assert!(widths.iter().all(|width| *width == widths[0]));
+}
+
+#[test]
+fn test_column_max_width_wrap() {
+    let output = TableBuilder::new(rows()).with_column_max_width(1, 20).render();
+
+    assert!(!output.contains(ELLIPSIS));
+    // 长标题被拆分到多行
+    assert!(output.lines().count() > 7);
+    assert!(output.contains("Add colorized width"));
+    let widths = line_widths(&output);
+    assert!(widths.iter().all(|width| *width == widths[0]));
+}
+
+#[test]
+fn test_column_max_width_does_not_truncate_title() {
+    let output = TableBuilder::new(rows())
+        .with_title("Recent commits in the current branch")
+        .with_style(TableStyle::Modern)
+        .with_column_max_width(0, 3)
+        .with_overflow(TableOverflow::Truncate)
+        .render();
+
+    assert!(output.contains("Recent commits in the current branch"));
+}
+
+// ==================== 表格最大宽度测试 ====================
+
+#[test]
+fn test_max_width_limits_every_line() {
+    for overflow in [TableOverflow::Truncate, TableOverflow::Wrap] {
+        let output = TableBuilder::new(rows())
+            .with_style(TableStyle::Modern)
+            .with_max_width(50)
+            .with_overflow(overflow)
+            .render();
+
+        assert!(
+            line_widths(&output).iter().all(|width| *width <= 50),
+            "{:?}: {}",
+            overflow,
+            output
+        );
+        // 较短的列保持完整
+        assert!(output.contains("a1b2c3d"));
+    }
+}
+
+#[test]
+fn test_without_width_limits_keeps_full_content() {
+    let output = TableBuilder::new(rows()).render();
+
+    assert!(output.contains(LONG_SUBJECT));
+}
+
+// ==================== ANSI 颜色代码测试 ====================
+
+#[test]
+#[serial]
+fn test_colored_cells_are_aligned() {
+    set_colors_enabled(true);
+    let mut rows = rows();
+    rows[0].status = style("In Progress").green().force_styling(true).to_string();
+
+    let output = TableBuilder::new(rows).with_style(TableStyle::Modern).render();
+    set_colors_enabled(false);
+
+    // 保留颜色代码，且颜色代码不计入列宽
+    assert!(output.contains("\u{1b}[32m"));
+    let widths = line_widths(&output);
+    assert!(widths.iter().all(|width| *width == widths[0]), "{}", output);
+}
+
+#[test]
+#[serial]
+fn test_colored_cells_truncate_by_display_width() {
+    set_colors_enabled(true);
+    let mut rows = rows();
+    rows[0].subject = style(LONG_SUBJECT).yellow().force_styling(true).to_string();
+
+    let output = TableBuilder::new(rows)
+        .with_column_max_width(1, 20)
+        .with_overflow(TableOverflow::Truncate)
+        .render();
+    set_colors_enabled(false);
+
+    assert!(output.contains(ELLIPSIS));
+    assert!(output.contains("\u{1b}[33m"));
+    let widths = line_widths(&output);
+    assert!(widths.iter().all(|width| *width == widths[0]), "{}", output);
+}
+
+#[test]
+#[serial]
+fn test_disabled_colors_strip_ansi_codes() {
+    set_colors_enabled(false);
+    let mut rows = rows();
+    rows[0].status = style("In Progress").green().force_styling(true).to_string();
+
+    let output = TableBuilder::new(rows).with_style(TableStyle::Modern).render();
+
+    assert!(!output.contains('\u{1b}'), "{}", output);
+    assert!(output.contains("In Progress"));
+    let widths = line_widths(&output);
+    assert!(widths.iter().all(|width| *width == widths[0]), "{}", output);
+}
+
+// ==================== 导出测试 ====================
+
+fn export_rows() -> Vec<CommitRow> {
+    vec![
+        CommitRow {
+            sha: "a1b2c3d".to_string(),
+            subject: "Fix \"login\", logout | session".to_string(),
+            status: style("Done").green().force_styling(true).to_string(),
+        },
+        CommitRow {
+            sha: "e4f5a6b".to_string(),
+            subject: "Multi-line\nsubject\twith tab".to_string(),
+            status: "Open".to_string(),
+        },
+    ]
+}
+
+#[test]
+fn test_to_markdown_escapes_pipes_and_newlines() {
+    let output = TableBuilder::new(export_rows()).with_title("Commits").to_markdown();
+
+    assert_eq!(
+        output,
+        "| SHA | Subject | Status |\n\
+         | --- | --- | --- |\n\
+         | a1b2c3d | Fix \"login\", logout \\| session | Done |\n\
+         | e4f5a6b | Multi-line<br>subject\twith tab | Open |"
+    );
+}
+
+#[test]
+fn test_to_csv_quotes_separators() {
+    let output = TableBuilder::new(export_rows()).to_csv();
+
+    assert_eq!(
+        output,
+        "SHA,Subject,Status\n\
+         a1b2c3d,\"Fix \"\"login\"\", logout | session\",Done\n\
+         e4f5a6b,\"Multi-line\nsubject\twith tab\",Open"
+    );
+}
+
+#[test]
+fn test_to_tsv_quotes_tabs() {
+    let output = TableBuilder::new(export_rows()).to_tsv();
+
+    assert_eq!(
+        output,
+        "SHA\tSubject\tStatus\n\
+         a1b2c3d\t\"Fix \"\"login\"\", logout | session\"\tDone\n\
+         e4f5a6b\t\"Multi-line\nsubject\twith tab\"\tOpen"
+    );
+}
+
+#[test]
+fn test_export_strips_ansi_codes() {
+    let builder = TableBuilder::new(export_rows());
+
+    for output in [builder.to_markdown(), builder.to_csv(), builder.to_tsv()] {
+        assert!(!output.contains('\u{1b}'), "{}", output);
+    }
+}
+
+#[test]
+fn test_export_empty_data_outputs_headers() {
+    let builder = TableBuilder::new(Vec::<CommitRow>::new());
+
+    assert_eq!(builder.to_csv(), "SHA,Subject,Status");
+    assert_eq!(
+        builder.to_markdown(),
+        "| SHA | Subject | Status |\n| --- | --- | --- |"
+    );
+}
@@ -0,0 +1,119 @@
+//! Browser 模块测试
+//!
+//! 测试打开浏览器的方式（`BROWSER` 环境变量、WSL、Linux 和其他平台）及其顺序。
+
+use pretty_assertions::assert_eq;
+use workflow::base::util::browser::{Browser, BrowserLauncher};
+use workflow::base::util::Platform;
+
+const URL: &str = "https://github.com/owner/repo/pull/1?a=1&b=2";
+
+fn command(program: &str, args: &[&str]) -> BrowserLauncher {
+    BrowserLauncher::Command {
+        program: program.to_string(),
+        args: args.iter().map(|arg| arg.to_string()).collect(),
+    }
+}
+
+fn linux() -> Platform {
+    Platform::new("linux", "x86_64")
+}
+
+// ==================== Linux 测试 ====================
+
+#[test]
+fn test_launchers_linux_without_browser_env() {
+    let launchers = Browser::launchers(URL, None, &linux(), false);
+
+    assert_eq!(launchers, vec![command("xdg-open", &[URL])]);
+}
+
+#[test]
+fn test_launchers_wsl_order() {
+    let launchers = Browser::launchers(URL, None, &linux(), true);
+
+    assert_eq!(
+        launchers,
+        vec![
+            command("wslview", &[URL]),
+            command("xdg-open", &[URL]),
+            command(
+                "powershell.exe",
+                &[
+                    "-NoProfile",
+                    "-NonInteractive",
+                    "-Command",
+                    "Start-Process",
+                    &format!("'{}'", URL),
+                ],
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_launchers_wsl_escapes_single_quotes_for_powershell() {
+    let url = "https://example.com/it's";
+    let launchers = Browser::launchers(url, None, &linux(), true);
+
+    assert_eq!(
+        launchers.last(),
+        Some(&command(
+            "powershell.exe",
+            &[
+                "-NoProfile",
+                "-NonInteractive",
+                "-Command",
+                "Start-Process",
+                "'https://example.com/it''s'",
+            ],
+        ))
+    );
+}
+
+// ==================== BROWSER 环境变量测试 ====================
+
+#[test]
+fn test_launchers_browser_env_comes_first() {
+    let launchers = Browser::launchers(URL, Some("firefox"), &linux(), true);
+
+    assert_eq!(launchers[0], command("firefox", &[URL]));
+    assert_eq!(launchers[1], command("wslview", &[URL]));
+}
+
+#[test]
+fn test_launchers_browser_env_placeholder_and_list() {
+    let launchers =
+        Browser::launchers(URL, Some("firefox --new-tab %s::chromium"), &linux(), false);
+
+    assert_eq!(
+        launchers,
+        vec![
+            command("firefox", &["--new-tab", URL]),
+            command("chromium", &[URL]),
+            command("xdg-open", &[URL]),
+        ]
+    );
+}
+
+// ==================== 其他平台测试 ====================
+
+#[test]
+fn test_launchers_macos_and_windows_use_system_browser() {
+    for os in ["macos", "windows"] {
+        let launchers = Browser::launchers(
+            URL,
+            Some("open -a Safari"),
+            &Platform::new(os, "aarch64"),
+            false,
+        );
+
+        assert_eq!(
+            launchers,
+            vec![
+                command("open", &["-a", "Safari", URL]),
+                BrowserLauncher::System
+            ]
+        );
+    }
+}
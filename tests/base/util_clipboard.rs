@@ -0,0 +1,81 @@
+//! Clipboard 模块测试
+//!
+//! 测试剪贴板内容的文本判断和读取命令；读写系统剪贴板的测试在没有剪贴板工具或图形会话时跳过。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use serial_test::serial;
+use std::process::{Command, Stdio};
+use workflow::base::util::Clipboard;
+
+/// 当前平台是否有可用的剪贴板读取命令
+fn paste_tool_available() -> bool {
+    Clipboard::paste_commands().iter().any(|(program, args)| {
+        Command::new(program)
+            .args(args)
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .is_ok()
+    })
+}
+
+// ==================== 文本判断测试 ====================
+
+#[rstest]
+#[case(b"PROJ-123".as_slice(), Some("PROJ-123"))]
+#[case(b"https://jira.example.com/browse/PROJ-1\n".as_slice(), Some("https://jira.example.com/browse/PROJ-1\n"))]
+#[case("中文描述".as_bytes(), Some("中文描述"))]
+#[case(b"".as_slice(), None)]
+#[case(b" \n\t".as_slice(), None)]
+#[case(&[0xff, 0xfe, 0x00][..], None)]
+#[case(b"text\0with nul".as_slice(), None)]
+fn test_text_from_bytes(#[case] bytes: &[u8], #[case] expected: Option<&str>) {
+    assert_eq!(
+        Clipboard::text_from_bytes(bytes),
+        expected.map(str::to_string)
+    );
+}
+
+// ==================== 读取命令测试 ====================
+
+#[test]
+fn test_paste_commands_for_current_platform() {
+    let programs: Vec<&str> =
+        Clipboard::paste_commands().iter().map(|(program, _)| *program).collect();
+
+    if cfg!(target_os = "macos") {
+        assert_eq!(programs, vec!["pbpaste"]);
+    } else if cfg!(windows) {
+        assert_eq!(programs, vec!["powershell"]);
+    } else {
+        assert_eq!(programs.last(), Some(&"xclip"));
+    }
+}
+
+// ==================== 系统剪贴板测试 ====================
+
+#[test]
+#[serial]
+fn test_read_returns_copied_text() {
+    if !paste_tool_available() || Clipboard::read().is_err() {
+        eprintln!("Skipping: clipboard is not available on this machine");
+        return;
+    }
+
+    let previous = Clipboard::read().ok().flatten();
+    let marker = "workflow clipboard test: PROJ-123";
+    if Clipboard::copy(marker).is_err() {
+        eprintln!("Skipping: clipboard is read-only on this machine");
+        return;
+    }
+
+    let result = Clipboard::read();
+    if let Some(previous) = previous {
+        let _ = Clipboard::copy(&previous);
+    }
+    assert_eq!(
+        result.expect("Should read clipboard").as_deref(),
+        Some(marker)
+    );
+}
@@ -5,7 +5,9 @@
 //! 注意：由于对话框需要用户交互，部分测试可能需要模拟或跳过实际交互。
 //! 本测试主要关注对话框的构建、配置和验证逻辑。
 
-use workflow::base::dialog::{ConfirmDialog, InputDialog, MultiSelectDialog, SelectDialog};
+use workflow::base::dialog::{
+    ConfirmDialog, InputDialog, MultiSelectDialog, SelectDialog, SelectionConstraintError,
+};
 
 // ==================== InputDialog 测试 ====================
 
@@ -70,6 +72,18 @@ fn test_input_dialog_chain_calls() {
     assert!(true, "InputDialog chain calls should work");
 }
 
+#[test]
+fn test_input_dialog_secret() {
+    // 测试敏感信息输入（token、密码等）
+    let _dialog = InputDialog::new("Enter API token")
+        .secret(true)
+        .with_confirmation(true)
+        .allow_empty(true);
+
+    // 验证可以创建带敏感信息输入的对话框
+    assert!(true, "InputDialog with secret should be created");
+}
+
 #[test]
 fn test_input_dialog_validator_logic() {
     // 测试验证器逻辑（不实际显示对话框）
@@ -130,6 +144,98 @@ fn test_select_dialog_string_options() {
     assert!(true, "SelectDialog with String options should be created");
 }
 
+#[test]
+fn test_select_dialog_with_items() {
+    // 测试带数据的选项（显示标签，返回对应的值）
+    #[derive(Debug, PartialEq)]
+    enum Action {
+        Open,
+        Close,
+    }
+    let items = vec![
+        ("Open the ticket".to_string(), Action::Open),
+        ("Close the ticket".to_string(), Action::Close),
+    ];
+    let _dialog = SelectDialog::new_with_items("Choose an action", items).with_default(1);
+
+    // 验证值类型不需要实现 Display
+    assert!(true, "SelectDialog with items should be created");
+}
+
+#[test]
+fn test_select_dialog_with_empty_items() {
+    // 测试空的带数据选项列表：不显示对话框，直接返回错误
+    let items: Vec<(String, u32)> = vec![];
+
+    let result = SelectDialog::new_with_items("Choose", items).prompt();
+
+    assert_eq!(result.unwrap_err().to_string(), "No options available");
+}
+
+#[test]
+fn test_select_dialog_with_page_size() {
+    // 测试设置每页显示数量（0 视为 1，不会 panic）
+    let options = vec!["Option 1", "Option 2", "Option 3"];
+    let _dialog = SelectDialog::new("Choose", options.clone()).with_page_size(2);
+    let _dialog = SelectDialog::new("Choose", options).with_page_size(0);
+
+    assert!(true, "SelectDialog with page size should be created");
+}
+
+#[test]
+fn test_select_dialog_filter_empty_input_keeps_order() {
+    // 测试输入为空时显示全部选项，并保持原始顺序
+    let options: Vec<String> = (0..1000).map(|i| format!("item-{:04}", i)).collect();
+    let dialog = SelectDialog::new("Choose", options);
+
+    let filtered = dialog.filter("");
+
+    assert_eq!(filtered, (0..1000).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_select_dialog_filter_large_list() {
+    // 测试 1000 个选项按输入过滤后得到预期的子集
+    let options: Vec<String> = (0..1000)
+        .map(|i| {
+            if i % 100 == 42 {
+                format!("feature/PROJ-{}-login", i)
+            } else {
+                format!("bugfix/PROJ-{}", i)
+            }
+        })
+        .collect();
+    let dialog = SelectDialog::new("Select branch", options.clone());
+
+    let filtered = dialog.filter("login");
+
+    let mut matched: Vec<usize> = filtered.clone();
+    matched.sort_unstable();
+    assert_eq!(matched, (0..10).map(|i| i * 100 + 42).collect::<Vec<_>>());
+    assert!(filtered.iter().all(|&index| options[index].contains("login")));
+}
+
+#[test]
+fn test_select_dialog_select_filtered_returns_highlighted_value() {
+    // 测试过滤后返回的值与高亮（光标所在）的选项一致
+    let items: Vec<(String, u32)> = (0..1000).map(|i| (format!("PROJ-{}", i), i)).collect();
+    let dialog = SelectDialog::new_with_items("Select ticket", items);
+
+    let filtered = dialog.filter("PROJ-999");
+    assert_eq!(filtered.first(), Some(&999));
+
+    let value = dialog.select_filtered("PROJ-999", 0).expect("Expected a selection");
+    assert_eq!(value, 999);
+}
+
+#[test]
+fn test_select_dialog_select_filtered_out_of_range() {
+    // 测试过滤结果中没有指定位置时返回错误
+    let dialog = SelectDialog::new("Choose", vec!["main", "develop"]);
+
+    assert!(dialog.select_filtered("xyz", 0).is_err());
+}
+
 // ==================== MultiSelectDialog 测试 ====================
 
 #[test]
@@ -165,6 +271,69 @@ fn test_multi_select_dialog_empty_options() {
     );
 }
 
+#[test]
+fn test_multi_select_dialog_selection_count() {
+    // 测试选择数量限制
+    let dialog = MultiSelectDialog::new("Choose options", vec!["A", "B", "C"])
+        .with_defaults(vec![0])
+        .with_min(1)
+        .with_max(2);
+
+    assert_eq!(
+        dialog.validate_selection_count(0),
+        Err("Please select at least 1 option(s)".to_string())
+    );
+    assert_eq!(dialog.validate_selection_count(1), Ok(()));
+    assert_eq!(dialog.validate_selection_count(2), Ok(()));
+    assert_eq!(
+        dialog.validate_selection_count(3),
+        Err("Please select at most 2 option(s)".to_string())
+    );
+}
+
+#[test]
+fn test_multi_select_dialog_without_constraints_accepts_any_count() {
+    // 测试未设置限制时任意数量都有效
+    let dialog = MultiSelectDialog::new("Choose options", vec!["A", "B"]);
+
+    assert_eq!(dialog.check_constraints(), Ok(()));
+    assert_eq!(dialog.validate_selection_count(0), Ok(()));
+    assert_eq!(dialog.validate_selection_count(2), Ok(()));
+}
+
+#[test]
+fn test_multi_select_dialog_unsatisfiable_constraints() {
+    // 测试无法满足的选择数量限制
+    let too_few_options = MultiSelectDialog::new("Choose options", vec!["A", "B"]).with_min(3);
+    assert_eq!(
+        too_few_options.check_constraints(),
+        Err(SelectionConstraintError {
+            min: 3,
+            max: None,
+            available: 2,
+        })
+    );
+
+    let min_above_max = MultiSelectDialog::new("Choose options", vec!["A", "B", "C"])
+        .with_min(2)
+        .with_max(1);
+    let error = min_above_max.check_constraints().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Selection constraint cannot be satisfied: at least 2 and at most 1 of 3 options"
+    );
+}
+
+#[test]
+fn test_multi_select_dialog_constraint_error_is_not_cancellation() {
+    // 测试限制无法满足时 prompt 直接返回可区分的错误，而不是取消错误
+    let result = MultiSelectDialog::new("Choose options", vec!["A"]).with_min(2).prompt();
+
+    let error = result.unwrap_err();
+    assert!(error.is::<SelectionConstraintError>());
+    assert!(!error.is::<workflow::base::dialog::DialogCancelled>());
+}
+
 // ==================== ConfirmDialog 测试 ====================
 
 #[test]
@@ -0,0 +1,56 @@
+//! 文本行级差异测试
+//!
+//! 测试 `diff_lines` 的逐行比较结果和 `render_line_diff` 的上下文裁剪。
+
+use pretty_assertions::assert_eq;
+use workflow::base::util::diff::{diff_lines, render_line_diff, DiffLine};
+
+// ==================== diff_lines 测试 ====================
+
+#[test]
+fn test_diff_lines_marks_removed_and_added_lines() {
+    let lines = diff_lines("a\nb\nc\n", "a\nx\nc\n");
+
+    assert_eq!(
+        lines,
+        vec![
+            DiffLine::Context("a"),
+            DiffLine::Removed("b"),
+            DiffLine::Added("x"),
+            DiffLine::Context("c"),
+        ]
+    );
+}
+
+#[test]
+fn test_diff_lines_with_empty_input() {
+    assert_eq!(diff_lines("", "a\n"), vec![DiffLine::Added("a")]);
+    assert_eq!(diff_lines("a\n", ""), vec![DiffLine::Removed("a")]);
+    assert!(diff_lines("", "").is_empty());
+}
+
+// ==================== render_line_diff 测试 ====================
+
+#[test]
+fn test_render_line_diff_identical_text_is_empty() {
+    assert_eq!(render_line_diff("a\nb\n", "a\nb\n", 2), "");
+}
+
+#[test]
+fn test_render_line_diff_keeps_context_around_changes() {
+    let old = "1\n2\n3\n4\n5\n6\nexport http_proxy=x\n7\n";
+    let new = "1\n2\n3\n4\n5\n6\n7\n";
+
+    assert_eq!(
+        render_line_diff(old, new, 2),
+        "  5\n  6\n- export http_proxy=x\n  7\n"
+    );
+}
+
+#[test]
+fn test_render_line_diff_marks_skipped_lines() {
+    let old = "x\n1\n2\n3\n4\n5\ny\n";
+    let new = "1\n2\n3\n4\n5\n";
+
+    assert_eq!(render_line_diff(old, new, 1), "- x\n  1\n...\n  5\n- y\n");
+}
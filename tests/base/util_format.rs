@@ -4,6 +4,7 @@
 //! - 文件大小格式化算法
 //! - 敏感信息掩码处理
 //! - 日期时间格式化
+//! - 相对时间格式化
 //! - 校验和计算和验证
 //! - 字符串处理工具
 
@@ -287,6 +288,86 @@ mod date_format_tests {
     }
 }
 
+#[cfg(test)]
+mod relative_time_tests {
+    use super::*;
+    use chrono::{DateTime, Duration, FixedOffset, TimeZone, Utc};
+
+    // ==================== 相对时间格式化测试 ====================
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 6, 1, 12, 0, 0).unwrap()
+    }
+
+    /// 格式化 `now() - offset`
+    fn relative(offset: Duration) -> String {
+        let now = now();
+        DisplayFormatter::relative_time_from(&(now - offset), &now)
+    }
+
+    #[rstest]
+    #[case(Duration::seconds(0), "just now")]
+    #[case(Duration::seconds(10), "just now")]
+    #[case(Duration::seconds(11), "11 seconds ago")]
+    #[case(Duration::seconds(59), "59 seconds ago")]
+    #[case(Duration::seconds(60), "1 minute ago")]
+    #[case(Duration::seconds(119), "1 minute ago")]
+    #[case(Duration::minutes(45), "45 minutes ago")]
+    #[case(Duration::hours(1), "1 hour ago")]
+    #[case(Duration::hours(23), "23 hours ago")]
+    #[case(Duration::days(1), "1 day ago")]
+    #[case(Duration::days(6), "6 days ago")]
+    #[case(Duration::days(7), "1 week ago")]
+    #[case(Duration::days(30), "4 weeks ago")]
+    fn test_relative_time_past_buckets(#[case] offset: Duration, #[case] expected: &str) {
+        assert_eq!(relative(offset), expected);
+    }
+
+    #[rstest]
+    #[case(Duration::seconds(-1), "just now")]
+    #[case(Duration::seconds(-60), "just now")]
+    #[case(Duration::seconds(-61), "in 1 minute")]
+    #[case(Duration::hours(-2), "in 2 hours")]
+    #[case(Duration::days(-3), "in 3 days")]
+    fn test_relative_time_future_and_clock_skew(#[case] offset: Duration, #[case] expected: &str) {
+        assert_eq!(relative(offset), expected);
+    }
+
+    #[test]
+    fn test_relative_time_falls_back_to_date_after_30_days() {
+        assert_eq!(relative(Duration::days(31)), "2024-05-01");
+        assert_eq!(relative(Duration::days(-60)), "2024-07-31");
+    }
+
+    #[test]
+    fn test_relative_time_uses_timestamp_offset() {
+        // 同一时刻在不同时区表示，相对时间相同
+        let timestamp = DateTime::<FixedOffset>::parse_from_rfc3339("2024-06-01T17:00:00+08:00")
+            .expect("Failed to parse timestamp");
+        assert_eq!(
+            DisplayFormatter::relative_time_from(&timestamp, &now()),
+            "3 hours ago"
+        );
+
+        // 回退为日期时使用时间戳自身的时区
+        let old = DateTime::<FixedOffset>::parse_from_rfc3339("2024-01-31T23:30:00-05:00")
+            .expect("Failed to parse timestamp");
+        assert_eq!(
+            DisplayFormatter::relative_time_from(&old, &now()),
+            "2024-01-31"
+        );
+    }
+
+    #[test]
+    fn test_relative_time_against_current_time() {
+        assert_eq!(DisplayFormatter::relative_time(&Utc::now()), "just now");
+        assert_eq!(
+            DisplayFormatter::relative_time(&(Utc::now() - Duration::hours(5))),
+            "5 hours ago"
+        );
+    }
+}
+
 #[cfg(test)]
 mod checksum_tests {
     use super::*;
@@ -356,9 +437,24 @@ mod checksum_tests {
         assert_eq!(hash.len(), 64);
         assert!(hash.chars().all(|c| c.is_ascii_hexdigit()));
 
+        // 与直接计算内存数据的哈希值一致
+        assert_eq!(hash, Checksum::calculate_sha256(data.as_bytes()));
+
         Ok(())
     }
 
+    #[test]
+    fn test_calculate_sha256_of_bytes() {
+        assert_eq!(
+            Checksum::calculate_sha256(b"Hello, World!"),
+            "dffd6021bb2bd5b0af676290809ec3a53191dd81c7f70a4b28688a362182986f"
+        );
+        assert_eq!(
+            Checksum::calculate_sha256(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
     #[test]
     fn test_parse_hash_from_content() -> Result<()> {
         // 测试标准格式：hash  filename
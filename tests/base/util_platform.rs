@@ -1,10 +1,11 @@
 //! Platform 模块测试
 //!
-//! 测试平台检测、路径处理和系统信息获取功能。
+//! 测试平台检测、WSL 检测、路径处理和系统信息获取功能。
 
 use pretty_assertions::assert_eq;
+use rstest::rstest;
 use std::env;
-use workflow::base::util::platform::detect_release_platform;
+use workflow::base::util::platform::{detect_release_platform, is_wsl_kernel, Platform};
 
 // ==================== 平台检测测试 ====================
 
@@ -192,3 +193,49 @@ fn test_platform_identifier_case() {
         assert_eq!(&platform[0..7], "Windows");
     }
 }
+
+// ==================== WSL 检测测试 ====================
+
+#[rstest]
+#[case(
+    "Linux version 4.4.0-19041-Microsoft (Microsoft@Microsoft.com) (gcc version 5.4.0)",
+    true
+)]
+#[case(
+    "Linux version 5.15.90.1-microsoft-standard-WSL2 (oe-user@oe-host) (gcc 11.2.0)",
+    true
+)]
+#[case(
+    "Linux version 6.6.36.3-microsoft-standard-WSL2+ #1 SMP PREEMPT_DYNAMIC",
+    true
+)]
+#[case(
+    "Linux version 6.5.0-14-generic (buildd@lcy02-amd64-031) (gcc 12.3.0)",
+    false
+)]
+#[case("Linux version 6.1.0-18-amd64 (debian-kernel@lists.debian.org)", false)]
+#[case("", false)]
+fn test_is_wsl_kernel(#[case] proc_version: &str, #[case] expected: bool) {
+    assert_eq!(is_wsl_kernel(proc_version), expected);
+}
+
+#[rstest]
+#[case("macos")]
+#[case("windows")]
+fn test_is_wsl_false_on_non_linux(#[case] os: &str) {
+    // 非 Linux 平台不读取 /proc/version，始终不是 WSL
+    assert!(!Platform::new(os, "x86_64").is_wsl());
+}
+
+#[test]
+fn test_is_wsl_matches_proc_version() {
+    // 在 Linux 上，检测结果应与 /proc/version 的内容一致
+    let platform = Platform::detect();
+    if !platform.is_linux() {
+        return;
+    }
+    let expected = std::fs::read_to_string("/proc/version")
+        .map(|version| is_wsl_kernel(&version))
+        .unwrap_or(false);
+    assert_eq!(platform.is_wsl(), expected);
+}
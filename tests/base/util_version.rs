@@ -0,0 +1,60 @@
+//! 版本信息测试
+//!
+//! 测试 `VersionInfo` 的构建信息、显示格式和 JSON 输出。
+
+use pretty_assertions::assert_eq;
+use workflow::base::util::VersionInfo;
+
+// ==================== Helper Functions ====================
+
+/// 创建测试用的版本信息
+fn create_test_version_info() -> VersionInfo {
+    VersionInfo {
+        version: "1.6.9",
+        git_commit: Some("0123456789abcdef0123456789abcdef01234567"),
+        build_date: Some("2026-01-01"),
+        rustc_version: Some("rustc 1.89.0 (29483883e 2025-08-04)"),
+        target: Some("aarch64-apple-darwin"),
+    }
+}
+
+// ==================== VersionInfo 测试 ====================
+
+#[test]
+fn test_version_info_current() {
+    let info = VersionInfo::current();
+
+    assert_eq!(info.version, env!("CARGO_PKG_VERSION"));
+    // 构建脚本总是注入构建日期和目标平台
+    assert!(info.build_date.is_some());
+    assert!(info.target.is_some_and(|target| !target.is_empty()));
+}
+
+#[test]
+fn test_version_info_display() {
+    let mut info = create_test_version_info();
+    assert_eq!(info.to_string(), "workflow v1.6.9 (0123456 2026-01-01)");
+
+    info.git_commit = None;
+    info.build_date = None;
+    assert_eq!(info.to_string(), "workflow v1.6.9");
+}
+
+#[test]
+fn test_version_info_to_json() {
+    let mut info = create_test_version_info();
+    info.rustc_version = None;
+
+    let value: serde_json::Value = serde_json::from_str(&info.to_json().unwrap()).unwrap();
+
+    assert_eq!(
+        value,
+        serde_json::json!({
+            "version": "1.6.9",
+            "git_commit": "0123456789abcdef0123456789abcdef01234567",
+            "build_date": "2026-01-01",
+            "rustc_version": null,
+            "target": "aarch64-apple-darwin",
+        })
+    );
+}
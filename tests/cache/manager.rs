@@ -0,0 +1,124 @@
+//! 缓存管理器测试
+//!
+//! 测试 `CacheManager` 的清理计划（全部清理和按修改时间清理）以及清理后的目录状态。
+
+use pretty_assertions::assert_eq;
+use std::fs::{self, File};
+use std::path::Path;
+use std::time::{Duration, SystemTime};
+use tempfile::tempdir;
+use workflow::cache::{CacheKind, CacheManager};
+
+// ==================== Helper Functions ====================
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// 创建文件并设置修改时间
+fn write_file(path: &Path, content: &str, age: Duration) {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).unwrap();
+    }
+    fs::write(path, content).unwrap();
+    File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(SystemTime::now() - age)
+        .unwrap();
+}
+
+// ==================== CacheKind 测试 ====================
+
+#[test]
+fn test_cache_kind_names_are_unique() {
+    let mut names: Vec<&str> = CacheKind::all().map(|kind| kind.name()).collect();
+    names.sort();
+    names.dedup();
+
+    assert_eq!(
+        names,
+        vec!["file-summaries", "jira-downloads", "jira-users", "logs"]
+    );
+    assert!(!CacheKind::JiraUsers.is_dir());
+}
+
+// ==================== 清理计划测试 ====================
+
+#[test]
+fn test_plan_dir_selects_all_files() {
+    let dir = tempdir().unwrap();
+    write_file(&dir.path().join("a.log"), "12345", Duration::ZERO);
+    write_file(&dir.path().join("PROJ-1/b.log"), "123", 30 * DAY);
+
+    let plan = CacheManager::plan_dir(CacheKind::Logs, dir.path(), None).unwrap();
+
+    assert_eq!(plan.entries, 2);
+    assert_eq!(plan.size, 8);
+    assert_eq!(
+        plan.files,
+        vec![dir.path().join("PROJ-1/b.log"), dir.path().join("a.log")]
+    );
+}
+
+#[test]
+fn test_plan_dir_older_than_skips_recent_files() {
+    let dir = tempdir().unwrap();
+    write_file(&dir.path().join("recent.log"), "12345", DAY);
+    write_file(&dir.path().join("old.log"), "123", 10 * DAY);
+
+    let plan = CacheManager::plan_dir(CacheKind::Logs, dir.path(), Some(7 * DAY)).unwrap();
+
+    assert_eq!(plan.entries, 1);
+    assert_eq!(plan.size, 3);
+    assert_eq!(plan.files, vec![dir.path().join("old.log")]);
+}
+
+#[test]
+fn test_plan_dir_missing_dir_is_empty() {
+    let dir = tempdir().unwrap();
+
+    let plan = CacheManager::plan_dir(CacheKind::FileSummaries, &dir.path().join("missing"), None)
+        .unwrap();
+
+    assert!(plan.is_empty());
+    assert_eq!(plan.size, 0);
+}
+
+// ==================== 清理测试 ====================
+
+#[test]
+fn test_remove_deletes_planned_files_and_empty_dirs() {
+    let dir = tempdir().unwrap();
+    write_file(&dir.path().join("PROJ-1/old.log"), "old", 10 * DAY);
+    write_file(&dir.path().join("PROJ-2/old.log"), "old", 10 * DAY);
+    write_file(
+        &dir.path().join("PROJ-2/recent.log"),
+        "recent",
+        Duration::ZERO,
+    );
+
+    let plan = CacheManager::plan_dir(CacheKind::JiraDownloads, dir.path(), Some(7 * DAY)).unwrap();
+    CacheManager::remove(&plan).unwrap();
+
+    // 清理后变为空的目录被移除，仍有文件的目录和缓存目录本身保留
+    assert!(!dir.path().join("PROJ-1").exists());
+    assert!(!dir.path().join("PROJ-2/old.log").exists());
+    assert!(dir.path().join("PROJ-2/recent.log").exists());
+    assert!(dir.path().exists());
+
+    let remaining = CacheManager::plan_dir(CacheKind::JiraDownloads, dir.path(), None).unwrap();
+    assert_eq!(remaining.entries, 1);
+}
+
+#[test]
+fn test_remove_skips_files_already_deleted() {
+    let dir = tempdir().unwrap();
+    write_file(&dir.path().join("a.txt"), "a", Duration::ZERO);
+    write_file(&dir.path().join("b.txt"), "b", Duration::ZERO);
+
+    let plan = CacheManager::plan_dir(CacheKind::FileSummaries, dir.path(), None).unwrap();
+    fs::remove_file(dir.path().join("a.txt")).unwrap();
+
+    assert!(CacheManager::remove(&plan).is_ok());
+    assert!(fs::read_dir(dir.path()).unwrap().next().is_none());
+}
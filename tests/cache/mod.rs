@@ -0,0 +1,5 @@
+//! 缓存模块测试
+//!
+//! 包含缓存列表和清理相关的测试文件。
+
+pub mod manager;
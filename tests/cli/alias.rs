@@ -0,0 +1,72 @@
+//! Alias CLI 命令测试
+//!
+//! 测试 Alias CLI 命令的参数解析和参数约束。
+
+use clap::Parser;
+use pretty_assertions::assert_eq;
+use workflow::cli::AliasSubcommand;
+
+// 创建一个测试用的 CLI 结构来测试参数解析
+#[derive(Parser)]
+#[command(name = "test-alias")]
+struct TestAliasCli {
+    #[command(subcommand)]
+    command: AliasSubcommand,
+}
+
+// ==================== Install 命令测试 ====================
+
+#[test]
+fn test_alias_install_command_defaults() {
+    let cli = TestAliasCli::try_parse_from(&["test-alias", "install"]).unwrap();
+
+    match cli.command {
+        AliasSubcommand::Install { names, dry_run } => {
+            assert!(names.is_empty());
+            assert!(!dry_run.is_dry_run());
+        }
+        _ => panic!("Expected Install command"),
+    }
+}
+
+#[test]
+fn test_alias_install_command_with_names_and_dry_run() {
+    let cli =
+        TestAliasCli::try_parse_from(&["test-alias", "install", "wpr", "wprc", "-n"]).unwrap();
+
+    match cli.command {
+        AliasSubcommand::Install { names, dry_run } => {
+            assert_eq!(names, vec!["wpr".to_string(), "wprc".to_string()]);
+            assert!(dry_run.is_dry_run());
+        }
+        _ => panic!("Expected Install command"),
+    }
+}
+
+// ==================== Remove 命令测试 ====================
+
+#[test]
+fn test_alias_remove_all_shell_aliases() {
+    let cli = TestAliasCli::try_parse_from(&["test-alias", "remove", "--shell", "--all"]).unwrap();
+
+    match cli.command {
+        AliasSubcommand::Remove { name, shell, all } => {
+            assert_eq!(name, None);
+            assert!(shell);
+            assert!(all);
+        }
+        _ => panic!("Expected Remove command"),
+    }
+}
+
+#[test]
+fn test_alias_remove_all_requires_shell() {
+    assert!(TestAliasCli::try_parse_from(&["test-alias", "remove", "--all"]).is_err());
+}
+
+#[test]
+fn test_alias_remove_all_conflicts_with_name() {
+    assert!(
+        TestAliasCli::try_parse_from(&["test-alias", "remove", "--shell", "--all", "prc"]).is_err()
+    );
+}
@@ -2,6 +2,11 @@
 //!
 //! 使用新的测试工具进行基础 CLI 功能测试。
 
+use clap::Parser;
+use pretty_assertions::assert_eq;
+use workflow::base::logger::LogFormat;
+use workflow::cli::{Cli, LogLevel};
+
 use crate::common::cli_helpers::{
     contains_error, is_json_format, CliCommandBuilder, CliTestEnv, TestDataGenerator,
 };
@@ -194,6 +199,179 @@ fn test_missing_required_argument() {
     assert!(contains_error(&stderr) || stderr.contains("required") || stderr.contains("missing"));
 }
 
+// ==================== 全局参数测试 ====================
+
+#[test]
+fn test_global_verbosity_flags() {
+    let cli = Cli::try_parse_from(["workflow", "-v", "config", "show"]).unwrap();
+    assert!(cli.verbosity.is_verbose());
+    assert!(!cli.verbosity.is_trace());
+    assert_eq!(
+        cli.verbosity.level_override(),
+        Some(workflow::LogLevel::Debug)
+    );
+
+    // 全局参数可以放在子命令之后
+    let cli = Cli::try_parse_from(["workflow", "config", "show", "-vv"]).unwrap();
+    assert!(cli.verbosity.is_trace());
+    assert_eq!(cli.verbosity.get_log_level(), LogLevel::Trace);
+
+    let cli = Cli::try_parse_from(["workflow", "-q", "config", "show"]).unwrap();
+    assert!(cli.verbosity.is_quiet());
+    assert_eq!(
+        cli.verbosity.level_override(),
+        Some(workflow::LogLevel::Warn)
+    );
+
+    let cli = Cli::try_parse_from(["workflow", "config", "show"]).unwrap();
+    assert_eq!(cli.verbosity.get_log_level(), LogLevel::Normal);
+    assert_eq!(cli.verbosity.level_override(), None);
+}
+
+#[test]
+fn test_global_no_color_flag() {
+    let cli = Cli::try_parse_from(["workflow", "config", "show", "--no-color"]).unwrap();
+    assert!(cli.no_color);
+
+    let cli = Cli::try_parse_from(["workflow", "config", "show"]).unwrap();
+    assert!(!cli.no_color);
+}
+
+#[test]
+fn test_no_color_output_has_no_escape_sequences() {
+    let env = CliTestEnv::new();
+    let missing = env.path().join("missing.toml");
+
+    let binding = CliCommandBuilder::new()
+        .arg("--no-color")
+        .args(["config", "validate"])
+        .arg(&missing)
+        .env("WORKFLOW_CONFIG_DIR", env.path())
+        .assert_success();
+    let output = binding.get_output();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stdout.contains("Configuration file does not exist"));
+    assert!(!stdout.contains('\u{1b}'));
+    assert!(!stderr.contains('\u{1b}'));
+}
+
+#[test]
+fn test_no_color_env_output_has_no_escape_sequences() {
+    let env = CliTestEnv::new();
+    let missing = env.path().join("missing.toml");
+
+    let binding = CliCommandBuilder::new()
+        .args(["config", "validate"])
+        .arg(&missing)
+        .env("WORKFLOW_CONFIG_DIR", env.path())
+        .env("NO_COLOR", "1")
+        .assert_success();
+    let output = binding.get_output();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(!stdout.contains('\u{1b}'));
+    assert!(!stderr.contains('\u{1b}'));
+}
+
+#[test]
+fn test_quiet_keeps_warnings() {
+    let env = CliTestEnv::new();
+    let missing = env.path().join("missing.toml");
+
+    // `-q` 隐藏信息和成功消息，但警告仍然输出
+    let binding = CliCommandBuilder::new()
+        .args(["-q", "config", "validate"])
+        .arg(&missing)
+        .env("WORKFLOW_CONFIG_DIR", env.path())
+        .assert_success();
+    let output = binding.get_output();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Configuration file does not exist"));
+}
+
+#[test]
+fn test_config_validate_uses_settings_validation() {
+    let env = CliTestEnv::new();
+    let config = env.path().join("workflow.toml");
+    std::fs::write(&config, "[log]\nlevel = \"loud\"\n").unwrap();
+
+    // 日志级别只由 `Settings::validate()` 校验，`config validate` 与启动时校验使用同一套规则
+    let binding = CliCommandBuilder::new()
+        .args(["config", "validate"])
+        .arg(&config)
+        .env("WORKFLOW_CONFIG_DIR", env.path())
+        .assert_failure();
+    let output = binding.get_output();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("log.level"));
+}
+
+#[test]
+fn test_config_validate_checks_active_config() {
+    let env = CliTestEnv::new();
+    let config = env.path().join("workflow.toml");
+    std::fs::write(&config, "").unwrap();
+
+    // 未指定文件时校验当前生效的配置（包含环境变量覆盖），与启动时校验一致
+    let binding = CliCommandBuilder::new()
+        .args(["config", "validate"])
+        .env("WORKFLOW_CONFIG", &config)
+        .env("WORKFLOW_LOG__LEVEL", "loud")
+        .assert_failure();
+    let output = binding.get_output();
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("log.level"));
+}
+
+#[test]
+fn test_global_config_flag() {
+    let cli = Cli::try_parse_from(["workflow", "config", "show", "--config", "/tmp/wf"]).unwrap();
+    assert_eq!(cli.config_path.as_deref(), Some("/tmp/wf"));
+
+    let cli = Cli::try_parse_from(["workflow", "config", "show"]).unwrap();
+    assert_eq!(cli.config_path, None);
+}
+
+#[test]
+fn test_missing_config_path_fails() {
+    let env = CliTestEnv::new();
+    let missing = env.path().join("missing.toml");
+
+    let binding = CliCommandBuilder::new()
+        .arg("--config")
+        .arg(&missing)
+        .args(["config", "show"])
+        .assert_failure();
+    let output = binding.get_output();
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("Config path does not exist"));
+}
+
+#[test]
+fn test_global_log_format_flag() {
+    let cli = Cli::try_parse_from(["workflow", "config", "show", "--log-format", "json"]).unwrap();
+    assert_eq!(cli.log_format, Some(LogFormat::Json));
+
+    let cli = Cli::try_parse_from(["workflow", "config", "show"]).unwrap();
+    assert_eq!(cli.log_format, None);
+
+    let result = Cli::try_parse_from(["workflow", "--log-format", "xml", "config", "show"]);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_global_verbose_conflicts_with_quiet() {
+    let result = Cli::try_parse_from(["workflow", "-v", "-q", "config", "show"]);
+    assert!(result.is_err());
+}
+
 // ==================== 环境变量测试 ====================
 
 #[test]
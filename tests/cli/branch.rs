@@ -117,3 +117,30 @@ fn test_branch_create_command_with_dry_run() {
         _ => panic!("Expected Create command"),
     }
 }
+
+// ==================== Sync 命令测试 ====================
+
+#[test]
+fn test_branch_sync_command_with_dry_run() {
+    // 测试 Sync 命令带 --dry-run 参数
+    let cli =
+        TestBranchCli::try_parse_from(&["test-branch", "sync", "master", "--rebase", "--dry-run"])
+            .unwrap();
+
+    match cli.command {
+        BranchSubcommand::Sync {
+            source_branch,
+            rebase,
+            ff_only,
+            squash,
+            dry_run,
+        } => {
+            assert_eq!(source_branch, "master");
+            assert!(rebase);
+            assert!(!ff_only);
+            assert!(!squash);
+            assert!(dry_run.is_dry_run());
+        }
+        _ => panic!("Expected Sync command"),
+    }
+}
@@ -0,0 +1,97 @@
+//! Cache CLI 命令测试
+//!
+//! 测试 Cache CLI 命令的参数解析和 `--older-than` 时长解析。
+
+use clap::Parser;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use std::time::Duration;
+use workflow::cache::CacheKind;
+use workflow::cli::{parse_older_than, CacheName, CacheSubcommand};
+
+// 创建一个测试用的 CLI 结构来测试参数解析
+#[derive(Parser)]
+#[command(name = "test-cache")]
+struct TestCacheCli {
+    #[command(subcommand)]
+    command: CacheSubcommand,
+}
+
+// ==================== Clean 命令测试 ====================
+
+#[test]
+fn test_cache_clean_command_defaults() {
+    let cli = TestCacheCli::try_parse_from(&["test-cache", "clean"]).unwrap();
+
+    match cli.command {
+        CacheSubcommand::Clean {
+            cache,
+            older_than,
+            dry_run,
+            force,
+        } => {
+            assert_eq!(cache, None);
+            assert_eq!(older_than, None);
+            assert!(!dry_run.is_dry_run());
+            assert!(!force.is_force());
+        }
+        _ => panic!("Expected Clean command"),
+    }
+}
+
+#[test]
+fn test_cache_clean_command_with_cache_and_older_than() {
+    let cli = TestCacheCli::try_parse_from(&[
+        "test-cache",
+        "clean",
+        "jira-downloads",
+        "--older-than",
+        "2w",
+        "--dry-run",
+    ])
+    .unwrap();
+
+    match cli.command {
+        CacheSubcommand::Clean {
+            cache,
+            older_than,
+            dry_run,
+            ..
+        } => {
+            assert_eq!(cache, Some(CacheName::JiraDownloads));
+            assert_eq!(cache.map(CacheKind::from), Some(CacheKind::JiraDownloads));
+            assert_eq!(older_than, Some(Duration::from_secs(14 * 24 * 60 * 60)));
+            assert!(dry_run.is_dry_run());
+        }
+        _ => panic!("Expected Clean command"),
+    }
+}
+
+#[test]
+fn test_cache_clean_command_rejects_unknown_cache() {
+    assert!(TestCacheCli::try_parse_from(&["test-cache", "clean", "unknown"]).is_err());
+}
+
+// ==================== --older-than 解析测试 ====================
+
+#[rstest]
+#[case("30s", 30)]
+#[case("15m", 15 * 60)]
+#[case("12h", 12 * 60 * 60)]
+#[case("7d", 7 * 24 * 60 * 60)]
+#[case("1w", 7 * 24 * 60 * 60)]
+fn test_parse_older_than_valid(#[case] input: &str, #[case] secs: u64) {
+    assert_eq!(parse_older_than(input), Ok(Duration::from_secs(secs)));
+}
+
+#[rstest]
+#[case("")]
+#[case("7")]
+#[case("d")]
+#[case("0d")]
+#[case("7y")]
+#[case("-1d")]
+#[case("1.5h")]
+fn test_parse_older_than_invalid(#[case] input: &str) {
+    assert!(parse_older_than(input).is_err());
+}
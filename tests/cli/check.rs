@@ -3,7 +3,26 @@
 //! 测试 Check CLI 命令的参数解析、命令执行流程和错误处理。
 
 use clap::Parser;
+use pretty_assertions::assert_eq;
+use std::collections::HashMap;
+use std::net::TcpListener;
+use std::time::Duration;
+use workflow::base::http::HttpRequestErrorKind;
+use workflow::base::settings::settings::{GitHubAccount, NetworkTarget};
+use workflow::base::settings::{ConfigIssue, Settings};
 use workflow::cli::Commands;
+use workflow::commands::check::api::{
+    check_jira, jira_failure_hint, redact_secret, ApiCheckOptions, ApiCheckResult, ApiCheckState,
+    ApiProbe, ApiService, ProbeAuth,
+};
+use workflow::commands::check::fix::{missing_dirs, missing_required_fields};
+use workflow::commands::check::llm::{
+    check_llm_health, ollama_base_url, LlmHealthResult, LlmHealthState, OllamaTags,
+};
+use workflow::commands::check::network::{probe_target, proxy_for_url, NetworkState};
+use workflow::commands::check::watch::{api_status, check_tokens, format_latency, network_status};
+
+use crate::common::http_helpers::{unused_local_url, MockServer};
 
 // 创建一个测试用的 CLI 结构来测试参数解析
 #[derive(Parser)]
@@ -21,8 +40,18 @@ fn test_check_command_structure() {
     let cli = TestCheckCli::try_parse_from(&["test-workflow", "check"]).unwrap();
 
     match cli.command {
-        Some(Commands::Check) => {
-            assert!(true, "Check command parsed successfully");
+        Some(Commands::Check {
+            watch,
+            interval,
+            skip_jira,
+            skip_github,
+            skip_llm,
+            fix,
+        }) => {
+            assert!(!watch);
+            assert_eq!(interval, 5);
+            assert!(!skip_jira && !skip_github && !skip_llm);
+            assert!(!fix);
         }
         _ => panic!("Expected Check command"),
     }
@@ -34,7 +63,7 @@ fn test_check_command_structure() {
 fn test_check_command_parsing() {
     // 测试 Check 命令可以正确解析
     let cli = TestCheckCli::try_parse_from(&["test-workflow", "check"]).unwrap();
-    assert!(matches!(cli.command, Some(Commands::Check)));
+    assert!(matches!(cli.command, Some(Commands::Check { .. })));
 }
 
 #[test]
@@ -43,3 +72,532 @@ fn test_check_command_error_handling_extra_arguments() {
     let result = TestCheckCli::try_parse_from(&["test-workflow", "check", "extra-arg"]);
     assert!(result.is_err(), "Should fail on extra arguments");
 }
+
+// ==================== 监视模式测试 ====================
+
+#[test]
+fn test_check_command_with_watch_and_interval() {
+    let cli =
+        TestCheckCli::try_parse_from(&["test-workflow", "check", "--watch", "--interval", "10"])
+            .unwrap();
+
+    match cli.command {
+        Some(Commands::Check {
+            watch, interval, ..
+        }) => {
+            assert!(watch);
+            assert_eq!(interval, 10);
+        }
+        _ => panic!("Expected Check command"),
+    }
+}
+
+#[test]
+fn test_check_command_interval_requires_watch() {
+    let result = TestCheckCli::try_parse_from(&["test-workflow", "check", "--interval", "10"]);
+    assert!(result.is_err(), "--interval should require --watch");
+}
+
+#[test]
+fn test_check_command_rejects_zero_interval() {
+    let result =
+        TestCheckCli::try_parse_from(&["test-workflow", "check", "--watch", "--interval", "0"]);
+    assert!(result.is_err(), "Should fail on zero interval");
+}
+
+#[test]
+fn test_check_tokens_reports_missing_tokens() {
+    let status = check_tokens(&Settings::default());
+
+    assert!(!status.passed);
+    assert_eq!(status.detail, "Missing token: github, jira");
+}
+
+#[test]
+fn test_check_tokens_passes_with_tokens() {
+    let mut settings = Settings::default();
+    settings.jira.api_token = Some("jira_token".to_string());
+    settings.github.accounts.push(GitHubAccount {
+        name: "work".to_string(),
+        email: "work@example.com".to_string(),
+        api_token: "ghp_work".to_string(),
+        login: None,
+    });
+
+    assert!(check_tokens(&settings).passed);
+}
+
+#[test]
+fn test_format_latency() {
+    assert_eq!(format_latency(Duration::from_millis(120)), "120 ms");
+    assert_eq!(format_latency(Duration::from_millis(1250)), "1.25 s");
+}
+
+// ==================== API 连通性检查测试 ====================
+
+#[test]
+fn test_check_command_with_skip_flags() {
+    let cli =
+        TestCheckCli::try_parse_from(&["test-workflow", "check", "--skip-jira", "--skip-llm"])
+            .unwrap();
+
+    match cli.command {
+        Some(Commands::Check {
+            skip_jira,
+            skip_github,
+            skip_llm,
+            ..
+        }) => {
+            let options = ApiCheckOptions {
+                skip_jira,
+                skip_github,
+                skip_llm,
+            };
+            assert_eq!(options.services(), vec![ApiService::GitHub]);
+        }
+        _ => panic!("Expected Check command"),
+    }
+}
+
+#[test]
+fn test_api_check_state_from_status() {
+    assert_eq!(
+        ApiCheckState::from_status(200),
+        ApiCheckState::Authenticated
+    );
+    assert_eq!(ApiCheckState::from_status(401), ApiCheckState::Reachable);
+    assert_eq!(ApiCheckState::from_status(403), ApiCheckState::Reachable);
+    assert_eq!(ApiCheckState::from_status(500), ApiCheckState::Failed);
+    assert!(!ApiCheckState::NotConfigured.is_failure());
+}
+
+#[test]
+fn test_api_probe_urls_from_settings() {
+    let mut settings = Settings::default();
+    settings.jira.email = Some("test@example.com".to_string());
+    settings.jira.api_token = Some("jira_token".to_string());
+    settings.jira.service_address = Some("https://company.atlassian.net/".to_string());
+    settings.llm.provider = "proxy".to_string();
+    settings.llm.proxy.url = Some("https://llm.example.com/v1/".to_string());
+    settings.llm.proxy.key = Some("sk-proxy".to_string());
+
+    let jira = ApiProbe::from_settings(ApiService::Jira, &settings).unwrap();
+    assert_eq!(jira.url, "https://company.atlassian.net/rest/api/2/myself");
+
+    let llm = ApiProbe::from_settings(ApiService::Llm, &settings).unwrap();
+    assert_eq!(llm.url, "https://llm.example.com/v1/models");
+    assert_eq!(llm.auth, ProbeAuth::Bearer("sk-proxy".to_string()));
+
+    assert_eq!(
+        ApiProbe::from_settings(ApiService::GitHub, &settings).unwrap_err(),
+        "github.accounts[].api_token"
+    );
+}
+
+#[test]
+fn test_api_probe_jira() {
+    let probe = ApiProbe::jira(
+        " https://company.atlassian.net/ ",
+        "test@example.com",
+        "jira_token\n",
+    );
+
+    assert_eq!(probe.service, ApiService::Jira);
+    assert_eq!(probe.url, "https://company.atlassian.net/rest/api/2/myself");
+    assert_eq!(
+        probe.auth,
+        ProbeAuth::Basic {
+            username: "test@example.com".to_string(),
+            token: "jira_token".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_api_probe_jira_bearer() {
+    let probe = ApiProbe::jira_bearer(" https://jira.company.com/ ", "jira_pat\n");
+
+    assert_eq!(probe.service, ApiService::Jira);
+    assert_eq!(probe.url, "https://jira.company.com/rest/api/2/myself");
+    assert_eq!(probe.auth, ProbeAuth::Bearer("jira_pat".to_string()));
+}
+
+#[test]
+fn test_api_probe_jira_bearer_from_settings() {
+    let mut settings = Settings::default();
+    settings.jira.auth_type = Some("bearer".to_string());
+    settings.jira.api_token = Some("jira_pat".to_string());
+    settings.jira.service_address = Some("https://jira.company.com".to_string());
+
+    let jira = ApiProbe::from_settings(ApiService::Jira, &settings).unwrap();
+    assert_eq!(jira.auth, ProbeAuth::Bearer("jira_pat".to_string()));
+}
+
+#[test]
+fn test_api_probe_debug_masks_token() {
+    let probe = ApiProbe {
+        service: ApiService::GitHub,
+        url: "https://api.github.com/user".to_string(),
+        auth: ProbeAuth::Bearer("ghp_1234567890abcdef".to_string()),
+    };
+
+    let debug = format!("{:?}", probe);
+
+    assert!(!debug.contains("ghp_1234567890abcdef"));
+    assert!(debug.contains("ghp_***cdef"));
+}
+
+#[test]
+fn test_check_jira_not_configured_is_optional() {
+    // 完全未配置 Jira（只使用 GitHub）时不算失败，也不提示缺少的配置项
+    let result = check_jira(&Settings::default());
+
+    assert_eq!(result.service, ApiService::Jira);
+    assert_eq!(result.state, ApiCheckState::Optional);
+    assert!(!result.state.is_failure());
+    assert_eq!(result.summary(), "Jira: not configured (optional)");
+}
+
+#[test]
+fn test_check_jira_partially_configured() {
+    // 只配置了部分认证信息时提示缺少的配置项
+    let mut settings = Settings::default();
+    settings.jira.email = Some("user@example.com".to_string());
+    let result = check_jira(&settings);
+
+    assert_eq!(result.state, ApiCheckState::NotConfigured);
+    assert_eq!(
+        result.summary(),
+        "Jira: not configured, skipped - jira.service_address is not configured"
+    );
+}
+
+#[test]
+fn test_jira_failure_hint_distinguishes_failures() {
+    let expired = jira_failure_hint(Some(401), None).unwrap();
+    let not_found = jira_failure_hint(Some(404), None).unwrap();
+    let unreachable = jira_failure_hint(None, Some(HttpRequestErrorKind::Connect)).unwrap();
+    let timeout = jira_failure_hint(None, Some(HttpRequestErrorKind::Timeout)).unwrap();
+
+    assert!(expired.contains("invalid or expired"));
+    assert!(not_found.contains("jira.service_address"));
+    assert!(unreachable.contains("DNS"));
+    assert!(timeout.contains("did not respond in time"));
+    assert!(jira_failure_hint(Some(403), None).is_some());
+    assert_eq!(jira_failure_hint(Some(500), None), None);
+    assert_eq!(
+        jira_failure_hint(None, Some(HttpRequestErrorKind::Other)),
+        None
+    );
+}
+
+#[test]
+fn test_redact_secret() {
+    assert_eq!(
+        redact_secret("error: bad token sk-1234567890abcd", "sk-1234567890abcd"),
+        "error: bad token sk-1***abcd"
+    );
+    assert_eq!(redact_secret("no secret here", ""), "no secret here");
+}
+
+#[test]
+fn test_api_status_reports_http_status() {
+    let failed = api_status(&ApiCheckResult {
+        service: ApiService::Jira,
+        state: ApiCheckState::Reachable,
+        status: Some(401),
+        detail: None,
+    });
+    assert!(!failed.passed);
+    assert_eq!(failed.name, "Jira API");
+    assert_eq!(failed.detail, "reachable, authentication failed (HTTP 401)");
+
+    let not_configured = api_status(&ApiCheckResult {
+        service: ApiService::Llm,
+        state: ApiCheckState::NotConfigured,
+        status: None,
+        detail: Some("llm.key is not configured".to_string()),
+    });
+    assert!(not_configured.passed);
+}
+
+// ==================== LLM 健康检查测试 ====================
+
+#[test]
+fn test_llm_health_not_configured_without_key() {
+    let result = check_llm_health(&Settings::default());
+
+    assert_eq!(result.state, LlmHealthState::NotConfigured);
+    assert!(!result.state.is_failure());
+    assert_eq!(
+        result.summary(),
+        "LLM (openai): not configured, skipped - llm.key is not configured"
+    );
+}
+
+#[test]
+fn test_llm_health_not_configured_without_proxy_model() {
+    let mut settings = Settings::default();
+    settings.llm.provider = "proxy".to_string();
+    settings.llm.proxy.url = Some("http://localhost:11434/v1".to_string());
+    settings.llm.proxy.key = Some("ollama".to_string());
+
+    let result = check_llm_health(&settings);
+
+    assert_eq!(result.state, LlmHealthState::NotConfigured);
+    assert_eq!(
+        result.detail.as_deref(),
+        Some("llm.model is not configured")
+    );
+}
+
+#[test]
+fn test_llm_health_summary() {
+    let healthy = LlmHealthResult {
+        provider: "openai".to_string(),
+        model: Some("gpt-4o-mini".to_string()),
+        state: LlmHealthState::Healthy,
+        latency: Some(Duration::from_millis(820)),
+        detail: None,
+    };
+    assert_eq!(
+        healthy.summary(),
+        "LLM (openai, gpt-4o-mini): healthy in 820 ms"
+    );
+
+    let missing = LlmHealthResult {
+        provider: "proxy".to_string(),
+        model: Some("llama3".to_string()),
+        state: LlmHealthState::ModelMissing,
+        latency: None,
+        detail: Some("run 'ollama pull llama3'".to_string()),
+    };
+    assert!(missing.state.is_failure());
+    assert_eq!(
+        missing.summary(),
+        "LLM (proxy, llama3): model not pulled - run 'ollama pull llama3'"
+    );
+}
+
+#[test]
+fn test_ollama_base_url() {
+    assert_eq!(
+        ollama_base_url("proxy", Some("http://localhost:11434/v1")),
+        Some("http://localhost:11434".to_string())
+    );
+    assert_eq!(
+        ollama_base_url("proxy", Some("https://ollama.internal.example.com/")),
+        Some("https://ollama.internal.example.com".to_string())
+    );
+    assert_eq!(
+        ollama_base_url("proxy", Some("https://llm.example.com/v1")),
+        None
+    );
+    assert_eq!(ollama_base_url("deepseek", None), None);
+}
+
+#[test]
+fn test_ollama_tags_has_model() {
+    let tags: OllamaTags = serde_json::from_str(
+        r#"{"models":[{"name":"llama3:latest","size":4661224676},{"name":"qwen2.5:7b"}]}"#,
+    )
+    .unwrap();
+
+    assert!(tags.has_model("llama3"));
+    assert!(tags.has_model("llama3:latest"));
+    assert!(tags.has_model("qwen2.5:7b"));
+    assert!(!tags.has_model("qwen2.5"));
+    assert!(!OllamaTags::default().has_model("llama3"));
+}
+
+// ==================== 网络检查测试 ====================
+
+/// 网络检查的超时时间
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[test]
+fn test_network_targets_derived_from_configured_services() {
+    let mut settings = Settings::default();
+    assert_eq!(
+        settings.network_targets(),
+        vec![NetworkTarget::new("GitHub API", "https://api.github.com")]
+    );
+
+    settings.jira.service_address = Some("https://example.atlassian.net".to_string());
+    settings.llm.provider = "proxy".to_string();
+    settings.llm.proxy.key = Some("sk-test".to_string());
+    settings.llm.proxy.url = Some("http://localhost:11434/v1".to_string());
+    assert_eq!(
+        settings.network_targets(),
+        vec![
+            NetworkTarget::new("GitHub API", "https://api.github.com"),
+            NetworkTarget::new("Jira", "https://example.atlassian.net"),
+            NetworkTarget::new("LLM (proxy)", "http://localhost:11434/v1"),
+        ]
+    );
+}
+
+#[test]
+fn test_network_targets_configured_list_replaces_defaults() {
+    let mut settings = Settings::default();
+    settings.jira.service_address = Some("https://example.atlassian.net".to_string());
+    settings.check.network_targets =
+        vec![NetworkTarget::new("Mirror", "https://mirror.example.com")];
+
+    assert_eq!(
+        settings.network_targets(),
+        vec![NetworkTarget::new("Mirror", "https://mirror.example.com")]
+    );
+}
+
+#[test]
+fn test_probe_target_reachable() {
+    let mut mock_server = MockServer::new();
+    let _ok = mock_server.server.as_mut().mock("GET", "/").with_status(200).create();
+    let _missing = mock_server.server.as_mut().mock("GET", "/missing").with_status(404).create();
+
+    let target = NetworkTarget::new("Mock", format!("{}/", mock_server.base_url));
+    let result = probe_target(&target, PROBE_TIMEOUT, &HashMap::new());
+    assert_eq!(result.state, NetworkState::Reachable);
+    assert_eq!(result.status, Some(200));
+    assert_eq!(result.proxy, None);
+    assert!(result.is_reachable());
+
+    // 收到任何 HTTP 响应都视为可达
+    let target = NetworkTarget::new("Mock", format!("{}/missing", mock_server.base_url));
+    let result = probe_target(&target, PROBE_TIMEOUT, &HashMap::new());
+    assert_eq!(result.state, NetworkState::Reachable);
+    assert_eq!(result.status, Some(404));
+}
+
+#[test]
+fn test_probe_target_unreachable() {
+    let target = NetworkTarget::new("Closed port", unused_local_url());
+    let result = probe_target(&target, PROBE_TIMEOUT, &HashMap::new());
+
+    assert_eq!(result.state, NetworkState::Unreachable);
+    assert_eq!(result.status, None);
+    assert!(result.detail.is_some());
+    assert!(!network_status(&result).passed);
+}
+
+#[test]
+fn test_probe_target_timeout() {
+    // 接受连接但从不响应的服务器
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind");
+    let url = format!(
+        "http://{}/",
+        listener.local_addr().expect("Failed to get address")
+    );
+
+    let target = NetworkTarget::new("Silent", url);
+    let result = probe_target(&target, Duration::from_millis(200), &HashMap::new());
+    assert_eq!(result.state, NetworkState::Timeout);
+    drop(listener);
+}
+
+#[test]
+fn test_probe_target_dns_failure() {
+    let target = NetworkTarget::new("Unknown host", "http://workflow-check.invalid/");
+    let result = probe_target(&target, PROBE_TIMEOUT, &HashMap::new());
+
+    assert_eq!(result.state, NetworkState::DnsFailure);
+}
+
+#[test]
+fn test_probe_target_tls_error() {
+    // 对只支持 HTTP 的服务器发起 HTTPS 请求，TLS 握手失败
+    let mut mock_server = MockServer::new();
+    let _mock = mock_server.server.as_mut().mock("GET", "/").with_status(200).create();
+    let url = mock_server.base_url.replacen("http://", "https://", 1);
+
+    let target = NetworkTarget::new("Plain HTTP", format!("{}/", url));
+    let result = probe_target(&target, PROBE_TIMEOUT, &HashMap::new());
+    assert_eq!(result.state, NetworkState::TlsError);
+}
+
+#[test]
+fn test_network_state_labels_and_hints() {
+    assert_eq!(NetworkState::Reachable.label(), "reachable");
+    assert_eq!(NetworkState::Reachable.hint(), None);
+    for state in [
+        NetworkState::DnsFailure,
+        NetworkState::TlsError,
+        NetworkState::Timeout,
+        NetworkState::Unreachable,
+    ] {
+        assert!(state.hint().is_some(), "{:?} should have a hint", state);
+    }
+}
+
+#[test]
+fn test_proxy_for_url() {
+    let env = HashMap::from([
+        (
+            "https_proxy".to_string(),
+            "http://127.0.0.1:7890".to_string(),
+        ),
+        (
+            "HTTP_PROXY".to_string(),
+            "http://127.0.0.1:8080".to_string(),
+        ),
+    ]);
+
+    assert_eq!(
+        proxy_for_url("https://api.github.com", &env).as_deref(),
+        Some("http://127.0.0.1:7890")
+    );
+    assert_eq!(
+        proxy_for_url("http://localhost:11434", &env).as_deref(),
+        Some("http://127.0.0.1:8080")
+    );
+}
+
+// ==================== 自动修复测试 ====================
+
+#[test]
+fn test_check_command_with_fix() {
+    let cli = TestCheckCli::try_parse_from(&["test-workflow", "check", "--fix"]).unwrap();
+
+    match cli.command {
+        Some(Commands::Check { fix, watch, .. }) => {
+            assert!(fix);
+            assert!(!watch);
+        }
+        _ => panic!("Expected Check command"),
+    }
+}
+
+#[test]
+fn test_check_command_fix_conflicts_with_watch() {
+    let result = TestCheckCli::try_parse_from(&["test-workflow", "check", "--fix", "--watch"]);
+    assert!(result.is_err(), "--fix should conflict with --watch");
+}
+
+#[test]
+fn test_missing_dirs() {
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let existing = temp_dir.path().join("config");
+    let missing = temp_dir.path().join("logs");
+    std::fs::create_dir(&existing).unwrap();
+
+    assert_eq!(missing_dirs(&[existing, missing.clone()]), vec![missing]);
+}
+
+#[test]
+fn test_missing_required_fields() {
+    let issues = vec![
+        ConfigIssue::error(
+            "jira.api_token",
+            "Missing required field (Jira configuration is incomplete)",
+        ),
+        ConfigIssue::error("github.accounts[0].email", "Missing required field"),
+        ConfigIssue::error("llm.provider", "Unsupported provider: 'foo'"),
+        ConfigIssue::warning("log.level", "Missing required field"),
+    ];
+
+    assert_eq!(
+        missing_required_fields(&issues),
+        vec!["jira.api_token", "github.accounts[0].email"]
+    );
+}
@@ -47,6 +47,7 @@ fn test_commit_amend_command(
             message: m,
             no_edit: ne,
             no_verify: nv,
+            ..
         } => {
             assert_eq!(m, message.map(|s| s.to_string()));
             assert_eq!(ne, no_edit);
@@ -56,6 +57,44 @@ fn test_commit_amend_command(
     }
 }
 
+#[test]
+fn test_commit_amend_command_with_co_authors() {
+    let cli = TestCommitCli::try_parse_from(&[
+        "test-commit",
+        "amend",
+        "--co-author",
+        "Alice <alice@example.com>",
+        "--co-author",
+        "Bob <bob@example.com>",
+    ])
+    .unwrap();
+
+    match cli.command {
+        CommitSubcommand::Amend { co_authors, .. } => {
+            assert_eq!(
+                co_authors,
+                vec![
+                    "Alice <alice@example.com>".to_string(),
+                    "Bob <bob@example.com>".to_string(),
+                ]
+            );
+        }
+        _ => panic!("Expected Amend command"),
+    }
+}
+
+#[test]
+fn test_commit_amend_command_co_author_conflicts_with_no_edit() {
+    let result = TestCommitCli::try_parse_from(&[
+        "test-commit",
+        "amend",
+        "--no-edit",
+        "--co-author",
+        "Alice <alice@example.com>",
+    ]);
+    assert!(result.is_err());
+}
+
 // ==================== Reword 命令测试 ====================
 
 #[rstest]
@@ -5,7 +5,7 @@
 use clap::Parser;
 use pretty_assertions::assert_eq;
 use rstest::rstest;
-use workflow::cli::ConfigSubcommand;
+use workflow::cli::{ConfigSubcommand, GitConfigSubcommand, ProfileSubcommand};
 
 // 创建一个测试用的 CLI 结构来测试参数解析
 #[derive(Parser)]
@@ -93,14 +93,14 @@ fn test_config_export_command_structure() {
         ConfigSubcommand::Export {
             output_path,
             section,
-            no_secrets,
+            include_secrets,
             toml,
             json,
             yaml,
         } => {
             assert_eq!(output_path, "output.toml");
             assert_eq!(section, None);
-            assert!(!no_secrets);
+            assert!(!include_secrets);
             assert!(!toml);
             assert!(!json);
             assert!(!yaml);
@@ -121,7 +121,7 @@ fn test_config_export_command_structure() {
 fn test_config_export_command(
     #[case] output_path: &str,
     #[case] section: Option<&str>,
-    #[case] no_secrets: bool,
+    #[case] include_secrets: bool,
     #[case] toml: bool,
     #[case] json: bool,
     #[case] yaml: bool,
@@ -131,8 +131,8 @@ fn test_config_export_command(
         args.push("--section");
         args.push(s);
     }
-    if no_secrets {
-        args.push("--no-secrets");
+    if include_secrets {
+        args.push("--include-secrets");
     }
     if toml {
         args.push("--toml");
@@ -150,14 +150,14 @@ fn test_config_export_command(
         ConfigSubcommand::Export {
             output_path: op,
             section: s,
-            no_secrets: ns,
+            include_secrets: is,
             toml: t,
             json: j,
             yaml: y,
         } => {
             assert_eq!(op, output_path);
             assert_eq!(s, section.map(|s| s.to_string()));
-            assert_eq!(ns, no_secrets);
+            assert_eq!(is, include_secrets);
             assert_eq!(t, toml);
             assert_eq!(j, json);
             assert_eq!(y, yaml);
@@ -238,6 +238,98 @@ fn test_config_import_command(
     }
 }
 
+// ==================== Profile 命令测试 ====================
+
+#[rstest]
+#[case("use", "work")]
+#[case("create", "personal")]
+fn test_config_profile_command_with_name(#[case] action: &str, #[case] name: &str) {
+    let cli = TestConfigCli::try_parse_from(&["test-config", "profile", action, name]).unwrap();
+
+    match cli.command {
+        ConfigSubcommand::Profile {
+            subcommand: ProfileSubcommand::Use { name: n },
+        } if action == "use" => assert_eq!(n, name),
+        ConfigSubcommand::Profile {
+            subcommand: ProfileSubcommand::Create { name: n },
+        } if action == "create" => assert_eq!(n, name),
+        _ => panic!("Expected Profile {} command", action),
+    }
+}
+
+#[test]
+fn test_config_profile_list_command() {
+    let cli = TestConfigCli::try_parse_from(&["test-config", "profile", "list"]).unwrap();
+
+    assert!(matches!(
+        cli.command,
+        ConfigSubcommand::Profile {
+            subcommand: ProfileSubcommand::List
+        }
+    ));
+}
+
+#[test]
+fn test_config_profile_use_requires_name() {
+    let result = TestConfigCli::try_parse_from(&["test-config", "profile", "use"]);
+    assert!(result.is_err(), "Should fail when profile name is missing");
+}
+
+// ==================== Git 命令测试 ====================
+
+#[rstest]
+#[case(&["git", "get"], None, false)]
+#[case(&["git", "get", "user.email"], Some("user.email"), false)]
+#[case(&["git", "get", "gpg.format", "--local"], Some("gpg.format"), true)]
+fn test_config_git_get_command(
+    #[case] args: &[&str],
+    #[case] expected_key: Option<&str>,
+    #[case] expected_local: bool,
+) {
+    let mut argv = vec!["test-config"];
+    argv.extend_from_slice(args);
+    let cli = TestConfigCli::try_parse_from(&argv).unwrap();
+
+    match cli.command {
+        ConfigSubcommand::Git {
+            subcommand: GitConfigSubcommand::Get { key, local },
+        } => {
+            assert_eq!(key.as_deref(), expected_key);
+            assert_eq!(local, expected_local);
+        }
+        _ => panic!("Expected Git Get command"),
+    }
+}
+
+#[test]
+fn test_config_git_set_command() {
+    let cli = TestConfigCli::try_parse_from(&[
+        "test-config",
+        "git",
+        "set",
+        "user.email",
+        "dev@example.com",
+    ])
+    .unwrap();
+
+    match cli.command {
+        ConfigSubcommand::Git {
+            subcommand: GitConfigSubcommand::Set { key, value, local },
+        } => {
+            assert_eq!(key, "user.email");
+            assert_eq!(value, "dev@example.com");
+            assert!(!local);
+        }
+        _ => panic!("Expected Git Set command"),
+    }
+}
+
+#[test]
+fn test_config_git_set_requires_value() {
+    let result = TestConfigCli::try_parse_from(&["test-config", "git", "set", "user.email"]);
+    assert!(result.is_err(), "Should fail when value is missing");
+}
+
 // ==================== Config 命令通用测试 ====================
 
 #[rstest]
@@ -85,14 +85,26 @@ fn test_github_switch_command_structure() {
     let cli = TestGitHubCli::try_parse_from(&["test-github", "switch"]).unwrap();
 
     match cli.command {
-        GitHubSubcommand::Switch => {
-            // Switch 命令没有参数，只需要验证可以匹配
-            assert!(true, "Switch command parsed successfully");
+        GitHubSubcommand::Switch { global } => {
+            assert!(
+                !global,
+                "Switch should update the local identity by default"
+            );
         }
         _ => panic!("Expected Switch command"),
     }
 }
 
+#[test]
+fn test_github_switch_command_global() {
+    let cli = TestGitHubCli::try_parse_from(&["test-github", "switch", "--global"]).unwrap();
+
+    assert!(matches!(
+        cli.command,
+        GitHubSubcommand::Switch { global: true }
+    ));
+}
+
 #[test]
 fn test_github_update_command_structure() {
     // 测试 Update 命令结构
@@ -129,11 +141,15 @@ fn test_github_command_parsing_all_subcommands() {
 
     // Switch
     let cli = TestGitHubCli::try_parse_from(&["test-github", "switch"]).unwrap();
-    assert!(matches!(cli.command, GitHubSubcommand::Switch));
+    assert!(matches!(cli.command, GitHubSubcommand::Switch { .. }));
 
     // Update
     let cli = TestGitHubCli::try_parse_from(&["test-github", "update"]).unwrap();
     assert!(matches!(cli.command, GitHubSubcommand::Update));
+
+    // RateLimit
+    let cli = TestGitHubCli::try_parse_from(&["test-github", "rate-limit"]).unwrap();
+    assert!(matches!(cli.command, GitHubSubcommand::RateLimit));
 }
 
 #[test]
@@ -153,7 +169,15 @@ fn test_github_command_error_handling_missing_subcommand() {
 #[test]
 fn test_github_all_commands_no_extra_arguments() {
     // 测试所有命令都不接受额外参数
-    let commands = ["list", "current", "add", "remove", "switch", "update"];
+    let commands = [
+        "list",
+        "current",
+        "add",
+        "remove",
+        "switch",
+        "update",
+        "rate-limit",
+    ];
 
     for cmd in commands.iter() {
         let result = TestGitHubCli::try_parse_from(&["test-github", cmd, "extra-arg"]);
@@ -212,7 +236,7 @@ fn test_github_command_enum_variants() {
             GitHubSubcommand::Current,
             GitHubSubcommand::Add,
             GitHubSubcommand::Remove,
-            GitHubSubcommand::Switch,
+            GitHubSubcommand::Switch { .. },
             GitHubSubcommand::Update,
         ) => {
             assert!(true, "All expected enum variants exist");
@@ -56,8 +56,12 @@ fn test_jira_command_with_id(#[case] subcommand: &str, #[case] jira_id: &str) {
         JiraSubcommand::Changelog { args, .. } => {
             assert_eq!(args.jira_id.jira_id, Some(jira_id.to_string()));
         }
-        JiraSubcommand::Comment { jira_id: id } => {
+        JiraSubcommand::Comment {
+            jira_id: id,
+            message,
+        } => {
             assert_eq!(id.jira_id, Some(jira_id.to_string()));
+            assert_eq!(*message, None);
         }
         JiraSubcommand::Comments { jira_id: id, .. } => {
             assert_eq!(id.jira_id, Some(jira_id.to_string()));
@@ -90,7 +94,7 @@ fn test_jira_command_without_id(#[case] subcommand: &str) {
         JiraSubcommand::Changelog { args, .. } => {
             assert_eq!(args.jira_id.jira_id, None);
         }
-        JiraSubcommand::Comment { jira_id } => {
+        JiraSubcommand::Comment { jira_id, .. } => {
             assert_eq!(jira_id.jira_id, None);
         }
         JiraSubcommand::Comments { jira_id, .. } => {
@@ -106,6 +110,21 @@ fn test_jira_command_without_id(#[case] subcommand: &str) {
     }
 }
 
+#[rstest]
+#[case(&["test-jira", "comment", "PROJ-123", "-m", "Deployed to staging"])]
+#[case(&["test-jira", "comment", "PROJ-123", "--message", "Deployed to staging"])]
+fn test_jira_comment_with_message(#[case] args: &[&str]) {
+    let cli = TestJiraCli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        JiraSubcommand::Comment { jira_id, message } => {
+            assert_eq!(jira_id.jira_id, Some("PROJ-123".to_string()));
+            assert_eq!(message, Some("Deployed to staging".to_string()));
+        }
+        _ => panic!("Expected Comment command"),
+    }
+}
+
 // ==================== 输出格式测试 ====================
 
 #[rstest]
@@ -552,17 +571,129 @@ fn test_jira_command_error_handling_invalid_subcommand() {
 // ==================== Changelog 命令测试 ====================
 
 #[test]
-fn test_jira_changelog_command_with_field_filter() {
-    // 测试 --field 参数
-    // 注意：当前 Changelog 命令的枚举定义中没有 field 字段
-    // 如果将来添加了 field 字段，这个测试需要更新
+fn test_jira_changelog_command_default_field() {
+    // 测试默认只跟踪 status 字段
     let cli = TestJiraCli::try_parse_from(&["test-jira", "changelog", "PROJ-123"]).unwrap();
 
     match cli.command {
-        JiraSubcommand::Changelog { args, .. } => {
+        JiraSubcommand::Changelog { args, field, .. } => {
             assert_eq!(args.jira_id.jira_id, Some("PROJ-123".to_string()));
-            // field 字段当前不存在于枚举定义中
+            assert_eq!(field, vec!["status".to_string()]);
+        }
+        _ => panic!("Expected Changelog command"),
+    }
+}
+
+#[test]
+fn test_jira_changelog_command_with_field_filter() {
+    // 测试 --field 参数（逗号分隔）
+    let cli = TestJiraCli::try_parse_from(&[
+        "test-jira",
+        "changelog",
+        "PROJ-123",
+        "--field",
+        "status,assignee",
+    ])
+    .unwrap();
+
+    match cli.command {
+        JiraSubcommand::Changelog { field, .. } => {
+            assert_eq!(field, vec!["status".to_string(), "assignee".to_string()]);
         }
         _ => panic!("Expected Changelog command"),
     }
 }
+
+// ==================== Related 命令测试 ====================
+
+#[rstest]
+#[case(&["test-jira", "related", "PROJ-123"], false)]
+#[case(&["test-jira", "related", "PROJ-123", "--open"], true)]
+fn test_jira_related_command_open_flag(#[case] args: &[&str], #[case] expected: bool) {
+    let cli = TestJiraCli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        JiraSubcommand::Related { args, open } => {
+            assert_eq!(args.jira_id.jira_id, Some("PROJ-123".to_string()));
+            assert_eq!(open, expected);
+        }
+        _ => panic!("Expected Related command"),
+    }
+}
+
+// ==================== Sprint 命令测试 ====================
+
+#[rstest]
+#[case(&["test-jira", "sprint"], None, None)]
+#[case(&["test-jira", "sprint", "PROJ"], Some("PROJ"), None)]
+#[case(&["test-jira", "sprint", "PROJ", "--board", "42"], Some("PROJ"), Some(42))]
+fn test_jira_sprint_command_parses_project_and_board(
+    #[case] args: &[&str],
+    #[case] expected_project: Option<&str>,
+    #[case] expected_board: Option<u64>,
+) {
+    let cli = TestJiraCli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        JiraSubcommand::Sprint {
+            project,
+            board,
+            output_format,
+        } => {
+            assert_eq!(project.as_deref(), expected_project);
+            assert_eq!(board, expected_board);
+            assert!(!output_format.json);
+        }
+        _ => panic!("Expected Sprint command"),
+    }
+}
+
+#[test]
+fn test_jira_sprint_command_rejects_invalid_board() {
+    assert!(
+        TestJiraCli::try_parse_from(&["test-jira", "sprint", "PROJ", "--board", "abc"]).is_err()
+    );
+}
+
+// ==================== Attach 命令测试 ====================
+
+#[test]
+fn test_jira_attach_command_parses_key_and_file() {
+    let cli = TestJiraCli::try_parse_from(&["test-jira", "attach", "PROJ-123", "logs/build.log"])
+        .unwrap();
+
+    match cli.command {
+        JiraSubcommand::Attach { jira_id, file } => {
+            assert_eq!(jira_id, "PROJ-123");
+            assert_eq!(file, std::path::PathBuf::from("logs/build.log"));
+        }
+        _ => panic!("Expected Attach command"),
+    }
+}
+
+#[rstest]
+#[case(&["test-jira", "attach"])]
+#[case(&["test-jira", "attach", "PROJ-123"])]
+#[case(&["test-jira", "attach", "invalid id", "build.log"])]
+fn test_jira_attach_command_rejects_invalid_args(#[case] args: &[&str]) {
+    assert!(TestJiraCli::try_parse_from(args).is_err());
+}
+
+// ==================== 时间戳显示测试 ====================
+
+#[rstest]
+#[case(&["test-jira", "comments", "PROJ-123"], false)]
+#[case(&["test-jira", "comments", "PROJ-123", "--absolute"], true)]
+#[case(&["test-jira", "changelog", "PROJ-123"], false)]
+#[case(&["test-jira", "changelog", "PROJ-123", "--absolute"], true)]
+fn test_jira_absolute_timestamp_flag(#[case] args: &[&str], #[case] expected: bool) {
+    let cli = TestJiraCli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        JiraSubcommand::Comments { timestamps, .. }
+        | JiraSubcommand::Changelog { timestamps, .. } => {
+            assert_eq!(timestamps.is_absolute(), expected);
+        }
+        _ => panic!("Expected Comments or Changelog command"),
+    }
+}
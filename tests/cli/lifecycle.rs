@@ -5,7 +5,19 @@
 
 use clap::Parser;
 use pretty_assertions::assert_eq;
-use workflow::cli::Commands;
+use rstest::rstest;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+use workflow::base::settings::paths::Paths;
+use workflow::base::settings::JiraAuthType;
+use workflow::cli::{Commands, UninstallComponent};
+use workflow::commands::config::setup::SetupCommand;
+use workflow::commands::lifecycle::update::{
+    InstallMethod, UpdateCheck, UpdateCommand, UPDATE_AVAILABLE_EXIT_CODE,
+};
+
+use crate::common::http_helpers::MockServer;
 
 // 创建一个测试用的 CLI 结构来测试参数解析
 #[derive(Parser)]
@@ -23,13 +35,152 @@ fn test_setup_command_structure() {
     let cli = TestLifecycleCli::try_parse_from(&["test-workflow", "setup"]).unwrap();
 
     match cli.command {
-        Some(Commands::Setup) => {
-            assert!(true, "Setup command parsed successfully");
+        Some(Commands::Setup {
+            non_interactive,
+            answers,
+            skip_verify,
+        }) => {
+            assert!(!non_interactive);
+            assert_eq!(answers, None);
+            assert!(!skip_verify);
         }
         _ => panic!("Expected Setup command"),
     }
 }
 
+#[test]
+fn test_setup_command_non_interactive_with_answers() {
+    let cli = TestLifecycleCli::try_parse_from(&[
+        "test-workflow",
+        "setup",
+        "--non-interactive",
+        "--answers",
+        "answers.toml",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Commands::Setup {
+            non_interactive,
+            answers,
+            ..
+        }) => {
+            assert!(non_interactive);
+            assert_eq!(answers.as_deref(), Some("answers.toml"));
+        }
+        _ => panic!("Expected Setup command"),
+    }
+}
+
+#[test]
+fn test_setup_command_answers_requires_non_interactive() {
+    let result =
+        TestLifecycleCli::try_parse_from(&["test-workflow", "setup", "--answers", "answers.toml"]);
+    assert!(
+        result.is_err(),
+        "--answers should require --non-interactive"
+    );
+}
+
+#[test]
+fn test_setup_command_skip_verify() {
+    let cli =
+        TestLifecycleCli::try_parse_from(&["test-workflow", "setup", "--skip-verify"]).unwrap();
+
+    match cli.command {
+        Some(Commands::Setup { skip_verify, .. }) => assert!(skip_verify),
+        _ => panic!("Expected Setup command"),
+    }
+}
+
+#[test]
+fn test_setup_command_skip_verify_conflicts_with_non_interactive() {
+    let result = TestLifecycleCli::try_parse_from(&[
+        "test-workflow",
+        "setup",
+        "--non-interactive",
+        "--skip-verify",
+    ]);
+    assert!(
+        result.is_err(),
+        "--skip-verify should conflict with --non-interactive"
+    );
+}
+
+#[test]
+fn test_verify_jira_credentials_authenticated() {
+    let mut mock_server = MockServer::new();
+    let mock = mock_server
+        .server
+        .as_mut()
+        .mock("GET", "/rest/api/2/myself")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"emailAddress":"test@example.com"}"#)
+        .expect(1)
+        .create();
+
+    let result = SetupCommand::verify_jira_credentials(
+        &format!("{}/", mock_server.base_url),
+        JiraAuthType::Basic,
+        "test@example.com",
+        "jira_token_1234567890",
+    );
+
+    assert_eq!(result, Ok(()));
+    mock.assert();
+}
+
+#[test]
+fn test_verify_jira_credentials_rejected() {
+    let mut mock_server = MockServer::new();
+    let mock = mock_server
+        .server
+        .as_mut()
+        .mock("GET", "/rest/api/2/myself")
+        .with_status(401)
+        .expect(1)
+        .create();
+
+    let error = SetupCommand::verify_jira_credentials(
+        &mock_server.base_url,
+        JiraAuthType::Basic,
+        "test@example.com",
+        "jira_token_1234567890",
+    )
+    .unwrap_err();
+
+    assert!(error.contains("HTTP 401"));
+    assert!(error.contains("--skip-verify"));
+    assert!(!error.contains("jira_token_1234567890"));
+    mock.assert();
+}
+
+#[test]
+fn test_verify_jira_credentials_bearer() {
+    let mut mock_server = MockServer::new();
+    let mock = mock_server
+        .server
+        .as_mut()
+        .mock("GET", "/rest/api/2/myself")
+        .match_header("authorization", "Bearer jira_pat_1234567890")
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"emailAddress":"test@example.com"}"#)
+        .expect(1)
+        .create();
+
+    let result = SetupCommand::verify_jira_credentials(
+        &mock_server.base_url,
+        JiraAuthType::Bearer,
+        "",
+        "jira_pat_1234567890",
+    );
+
+    assert_eq!(result, Ok(()));
+    mock.assert();
+}
+
 // ==================== Uninstall 命令测试 ====================
 
 #[test]
@@ -38,13 +189,58 @@ fn test_uninstall_command_structure() {
     let cli = TestLifecycleCli::try_parse_from(&["test-workflow", "uninstall"]).unwrap();
 
     match cli.command {
-        Some(Commands::Uninstall) => {
-            assert!(true, "Uninstall command parsed successfully");
+        Some(Commands::Uninstall { dry_run, keep }) => {
+            assert!(!dry_run.is_dry_run());
+            assert!(keep.is_empty());
         }
         _ => panic!("Expected Uninstall command"),
     }
 }
 
+#[rstest]
+#[case(&["--keep", "config"], vec![UninstallComponent::Config])]
+#[case(&["--keep", "completions"], vec![UninstallComponent::Completions])]
+#[case(&["--keep", "config,completions"], vec![UninstallComponent::Config, UninstallComponent::Completions])]
+#[case(&["--keep", "config", "--keep", "completions"], vec![UninstallComponent::Config, UninstallComponent::Completions])]
+fn test_uninstall_command_keep(#[case] args: &[&str], #[case] expected: Vec<UninstallComponent>) {
+    let cli =
+        TestLifecycleCli::try_parse_from(["test-workflow", "uninstall"].iter().chain(args.iter()))
+            .unwrap();
+
+    match cli.command {
+        Some(Commands::Uninstall { keep, .. }) => assert_eq!(keep, expected),
+        _ => panic!("Expected Uninstall command"),
+    }
+}
+
+#[test]
+fn test_uninstall_command_dry_run() {
+    let cli = TestLifecycleCli::try_parse_from(&[
+        "test-workflow",
+        "uninstall",
+        "--dry-run",
+        "--keep",
+        "config",
+    ])
+    .unwrap();
+
+    match cli.command {
+        Some(Commands::Uninstall { dry_run, keep }) => {
+            assert!(dry_run.is_dry_run());
+            assert_eq!(keep, vec![UninstallComponent::Config]);
+        }
+        _ => panic!("Expected Uninstall command"),
+    }
+}
+
+#[test]
+fn test_uninstall_command_rejects_unknown_component() {
+    let result =
+        TestLifecycleCli::try_parse_from(&["test-workflow", "uninstall", "--keep", "binaries"]);
+
+    assert!(result.is_err());
+}
+
 // ==================== Version 命令测试 ====================
 
 #[test]
@@ -53,13 +249,23 @@ fn test_version_command_structure() {
     let cli = TestLifecycleCli::try_parse_from(&["test-workflow", "version"]).unwrap();
 
     match cli.command {
-        Some(Commands::Version) => {
-            assert!(true, "Version command parsed successfully");
+        Some(Commands::Version { json }) => {
+            assert!(!json, "json should be false by default");
         }
         _ => panic!("Expected Version command"),
     }
 }
 
+#[test]
+fn test_version_command_json() {
+    let cli = TestLifecycleCli::try_parse_from(&["test-workflow", "version", "--json"]).unwrap();
+
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Version { json: true })
+    ));
+}
+
 // ==================== Update 命令测试 ====================
 
 #[test]
@@ -69,25 +275,25 @@ fn test_update_command_structure_with_version() {
         .unwrap();
 
     match cli.command {
-        Some(Commands::Update { version }) => {
+        Some(Commands::Update {
+            version,
+            check,
+            skip_verify,
+        }) => {
             assert_eq!(version, Some("1.2.3".to_string()), "version should be set");
+            assert!(!check, "check should be false by default");
+            assert!(!skip_verify, "skip_verify should be false by default");
         }
         _ => panic!("Expected Update command"),
     }
 }
 
 #[test]
-fn test_update_command_structure_with_short_version() {
-    // 测试 Update 命令结构（带 -v 参数）
-    let cli =
-        TestLifecycleCli::try_parse_from(&["test-workflow", "update", "-v", "1.2.3"]).unwrap();
+fn test_update_command_short_v_is_not_version() {
+    // 测试 Update 命令不再支持 -v（-v 是全局详细模式参数）
+    let result = TestLifecycleCli::try_parse_from(&["test-workflow", "update", "-v", "1.2.3"]);
 
-    match cli.command {
-        Some(Commands::Update { version }) => {
-            assert_eq!(version, Some("1.2.3".to_string()), "version should be set");
-        }
-        _ => panic!("Expected Update command"),
-    }
+    assert!(result.is_err(), "-v should not be accepted as --version");
 }
 
 #[test]
@@ -96,13 +302,142 @@ fn test_update_command_structure_minimal() {
     let cli = TestLifecycleCli::try_parse_from(&["test-workflow", "update"]).unwrap();
 
     match cli.command {
-        Some(Commands::Update { version }) => {
+        Some(Commands::Update { version, check, .. }) => {
             assert_eq!(version, None, "version should be None by default");
+            assert!(!check, "check should be false by default");
         }
         _ => panic!("Expected Update command"),
     }
 }
 
+#[test]
+fn test_update_command_check() {
+    let cli = TestLifecycleCli::try_parse_from(&["test-workflow", "update", "--check"]).unwrap();
+
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Update {
+            version: None,
+            check: true,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn test_update_command_check_conflicts_with_version() {
+    let result = TestLifecycleCli::try_parse_from(&[
+        "test-workflow",
+        "update",
+        "--check",
+        "--version",
+        "1.2.3",
+    ]);
+
+    assert!(result.is_err(), "--check should conflict with --version");
+}
+
+#[test]
+fn test_update_command_skip_verify() {
+    let cli =
+        TestLifecycleCli::try_parse_from(&["test-workflow", "update", "--skip-verify"]).unwrap();
+
+    assert!(matches!(
+        cli.command,
+        Some(Commands::Update {
+            skip_verify: true,
+            ..
+        })
+    ));
+
+    let result =
+        TestLifecycleCli::try_parse_from(&["test-workflow", "update", "--check", "--skip-verify"]);
+    assert!(
+        result.is_err(),
+        "--skip-verify should conflict with --check"
+    );
+}
+
+#[rstest]
+#[case("1.2.3", "1.2.4", true)]
+#[case("1.2.3", "1.10.0", true)]
+#[case("1.2.3", "1.2.3", false)]
+#[case("1.2.3", "1.2", false)]
+#[case("2.0.0", "1.9.9", false)]
+fn test_update_check_update_available(
+    #[case] current: &str,
+    #[case] latest: &str,
+    #[case] expected: bool,
+) {
+    let check = UpdateCheck {
+        current_version: current.to_string(),
+        latest_version: latest.to_string(),
+        release_url: UpdateCommand::release_url(latest),
+    };
+
+    assert_eq!(check.update_available(), expected);
+}
+
+#[test]
+fn test_update_release_url() {
+    assert_eq!(
+        UpdateCommand::release_url("1.2.3"),
+        "https://github.com/zevwings/workflow.rs/releases/tag/v1.2.3"
+    );
+    assert_ne!(UPDATE_AVAILABLE_EXIT_CODE, 1);
+}
+
+#[rstest]
+#[case(
+    "/opt/homebrew/Cellar/workflow/1.6.7/bin/workflow",
+    InstallMethod::Homebrew
+)]
+#[case("/home/dev/workflow.rs/target/release/workflow", InstallMethod::Source)]
+#[case("/home/dev/workflow.rs/target/debug/workflow", InstallMethod::Source)]
+#[case("/home/dev/bin/workflow", InstallMethod::Standalone)]
+fn test_install_method_detect(#[case] exe: &str, #[case] expected: InstallMethod) {
+    assert_eq!(InstallMethod::detect(Path::new(exe)), expected);
+}
+
+#[test]
+fn test_install_method_detect_installer() {
+    let exe = Path::new(&Paths::binary_install_dir()).join(Paths::binary_name("workflow"));
+    assert_eq!(InstallMethod::detect(&exe), InstallMethod::Installer);
+}
+
+#[test]
+fn test_replace_executable() {
+    let dir = tempdir().unwrap();
+    let target = dir.path().join("workflow");
+    let new_binary = dir.path().join("workflow-new");
+    fs::write(&target, "old").unwrap();
+    fs::write(&new_binary, "new").unwrap();
+
+    UpdateCommand::replace_executable(&new_binary, &target).unwrap();
+
+    assert_eq!(fs::read_to_string(&target).unwrap(), "new");
+    assert!(!dir.path().join(".workflow.new").exists());
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&target).unwrap().permissions().mode();
+        assert_eq!(mode & 0o111, 0o111);
+    }
+}
+
+#[test]
+fn test_replace_executable_keeps_target_on_failure() {
+    let dir = tempdir().unwrap();
+    let target = dir.path().join("workflow");
+    fs::write(&target, "old").unwrap();
+
+    let result = UpdateCommand::replace_executable(&dir.path().join("missing"), &target);
+
+    assert!(result.is_err());
+    assert_eq!(fs::read_to_string(&target).unwrap(), "old");
+    assert!(!dir.path().join(".workflow.new").exists());
+}
+
 // ==================== 命令解析完整性测试 ====================
 
 #[test]
@@ -111,15 +446,15 @@ fn test_lifecycle_commands_parsing() {
 
     // Setup
     let cli = TestLifecycleCli::try_parse_from(&["test-workflow", "setup"]).unwrap();
-    assert!(matches!(cli.command, Some(Commands::Setup)));
+    assert!(matches!(cli.command, Some(Commands::Setup { .. })));
 
     // Uninstall
     let cli = TestLifecycleCli::try_parse_from(&["test-workflow", "uninstall"]).unwrap();
-    assert!(matches!(cli.command, Some(Commands::Uninstall)));
+    assert!(matches!(cli.command, Some(Commands::Uninstall { .. })));
 
     // Version
     let cli = TestLifecycleCli::try_parse_from(&["test-workflow", "version"]).unwrap();
-    assert!(matches!(cli.command, Some(Commands::Version)));
+    assert!(matches!(cli.command, Some(Commands::Version { .. })));
 
     // Update
     let cli = TestLifecycleCli::try_parse_from(&["test-workflow", "update"]).unwrap();
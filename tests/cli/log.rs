@@ -6,7 +6,8 @@
 
 use clap::Parser;
 use pretty_assertions::assert_eq;
-use workflow::cli::{JiraSubcommand, LogSubcommand};
+use workflow::cli::{JiraSubcommand, LogOutputFormat, LogSubcommand};
+use workflow::LogLevel;
 
 // 创建一个测试用的 CLI 结构来测试参数解析（通过 Jira 命令）
 #[derive(Parser)]
@@ -69,6 +70,7 @@ fn test_log_find_command_structure() {
         LogSubcommand::Find {
             jira_id,
             request_id,
+            ..
         } => {
             assert_eq!(jira_id.jira_id, Some("PROJ-456".to_string()));
             assert_eq!(request_id, Some("req-12345".to_string()));
@@ -86,6 +88,7 @@ fn test_log_find_command_with_jira_id_only() {
         LogSubcommand::Find {
             jira_id,
             request_id,
+            ..
         } => {
             assert_eq!(jira_id.jira_id, Some("PROJ-456".to_string()));
             assert_eq!(request_id, None);
@@ -103,6 +106,7 @@ fn test_log_find_command_without_parameters() {
         LogSubcommand::Find {
             jira_id,
             request_id,
+            ..
         } => {
             assert_eq!(jira_id.jira_id, None);
             assert_eq!(request_id, None);
@@ -120,6 +124,7 @@ fn test_log_search_command_structure() {
         LogSubcommand::Search {
             jira_id,
             search_term,
+            ..
         } => {
             assert_eq!(jira_id.jira_id, Some("PROJ-789".to_string()));
             assert_eq!(search_term, Some("error".to_string()));
@@ -137,6 +142,7 @@ fn test_log_search_command_with_jira_id_only() {
         LogSubcommand::Search {
             jira_id,
             search_term,
+            ..
         } => {
             assert_eq!(jira_id.jira_id, Some("PROJ-789".to_string()));
             assert_eq!(search_term, None);
@@ -154,6 +160,7 @@ fn test_log_search_command_without_parameters() {
         LogSubcommand::Search {
             jira_id,
             search_term,
+            ..
         } => {
             assert_eq!(jira_id.jira_id, None);
             assert_eq!(search_term, None);
@@ -162,6 +169,120 @@ fn test_log_search_command_without_parameters() {
     }
 }
 
+#[test]
+fn test_log_tail_command_structure() {
+    // 测试 Tail 命令结构（带 JIRA ID、行数和跟踪选项）
+    let cli = TestLogCli::try_parse_from(["test-log", "tail", "PROJ-789", "-n", "50", "--follow"])
+        .unwrap();
+
+    match cli.command {
+        LogSubcommand::Tail {
+            jira_id,
+            lines,
+            follow,
+        } => {
+            assert_eq!(jira_id.jira_id, Some("PROJ-789".to_string()));
+            assert_eq!(lines, 50);
+            assert!(follow);
+        }
+        _ => panic!("Expected Tail command"),
+    }
+}
+
+#[test]
+fn test_log_tail_command_defaults() {
+    // 测试 Tail 命令默认值
+    let cli = TestLogCli::try_parse_from(["test-log", "tail"]).unwrap();
+
+    match cli.command {
+        LogSubcommand::Tail {
+            jira_id,
+            lines,
+            follow,
+        } => {
+            assert_eq!(jira_id.jira_id, None);
+            assert_eq!(lines, 20);
+            assert!(!follow);
+        }
+        _ => panic!("Expected Tail command"),
+    }
+}
+
+#[test]
+fn test_log_search_command_with_filters() {
+    // 测试 Search 命令的级别和组件过滤参数
+    let cli = TestLogCli::try_parse_from([
+        "test-log",
+        "search",
+        "PROJ-789",
+        "timeout",
+        "--level",
+        "error,warn",
+        "--min-level",
+        "warn",
+        "--component",
+        "HttpClient",
+    ])
+    .unwrap();
+
+    match cli.command {
+        LogSubcommand::Search { filter, .. } => {
+            assert_eq!(filter.level, vec![LogLevel::Error, LogLevel::Warn]);
+            assert_eq!(filter.min_level, Some(LogLevel::Warn));
+            assert_eq!(filter.component, Some("HttpClient".to_string()));
+        }
+        _ => panic!("Expected Search command"),
+    }
+}
+
+#[test]
+fn test_log_find_command_filters_default_empty() {
+    // 测试 Find 命令默认不启用过滤
+    let cli = TestLogCli::try_parse_from(["test-log", "find", "PROJ-456", "123"]).unwrap();
+
+    match cli.command {
+        LogSubcommand::Find { filter, .. } => {
+            assert!(filter.to_filter().is_empty());
+        }
+        _ => panic!("Expected Find command"),
+    }
+}
+
+#[test]
+fn test_log_search_command_format_jsonl() {
+    // 测试 Search 命令的 JSON Lines 输出格式
+    let cli = TestLogCli::try_parse_from([
+        "test-log", "search", "PROJ-789", "timeout", "--format", "jsonl",
+    ])
+    .unwrap();
+
+    match cli.command {
+        LogSubcommand::Search { format, .. } => assert_eq!(format, LogOutputFormat::Jsonl),
+        _ => panic!("Expected Search command"),
+    }
+}
+
+#[test]
+fn test_log_find_command_format_default_text() {
+    // 测试 Find 命令默认使用文本输出，且拒绝未知格式
+    let cli = TestLogCli::try_parse_from(["test-log", "find", "PROJ-456", "123"]).unwrap();
+
+    match cli.command {
+        LogSubcommand::Find { format, .. } => assert_eq!(format, LogOutputFormat::Text),
+        _ => panic!("Expected Find command"),
+    }
+
+    let result = TestLogCli::try_parse_from(["test-log", "find", "--format", "csv"]);
+    assert!(result.is_err(), "Should fail on unknown format");
+}
+
+#[test]
+fn test_log_search_command_invalid_level() {
+    // 测试无效的日志级别
+    let result = TestLogCli::try_parse_from(["test-log", "search", "--level", "verbose"]);
+    assert!(result.is_err(), "Should fail on invalid level");
+}
+
 #[test]
 fn test_log_command_parsing_all_subcommands() {
     // 测试所有子命令都可以正确解析
@@ -177,6 +298,10 @@ fn test_log_command_parsing_all_subcommands() {
     // Search
     let cli = TestLogCli::try_parse_from(&["test-log", "search"]).unwrap();
     assert!(matches!(cli.command, LogSubcommand::Search { .. }));
+
+    // Tail
+    let cli = TestLogCli::try_parse_from(["test-log", "tail"]).unwrap();
+    assert!(matches!(cli.command, LogSubcommand::Tail { .. }));
 }
 
 #[test]
@@ -221,6 +346,7 @@ fn test_log_find_with_request_id_only() {
         LogSubcommand::Find {
             jira_id,
             request_id,
+            ..
         } => {
             // 第一个参数会被解析为 jira_id
             assert_eq!(jira_id.jira_id, Some("req-12345".to_string()));
@@ -240,6 +366,7 @@ fn test_log_search_with_search_term_only() {
         LogSubcommand::Search {
             jira_id,
             search_term,
+            ..
         } => {
             // 第一个参数会被解析为 jira_id
             assert_eq!(jira_id.jira_id, Some("error".to_string()));
@@ -2,8 +2,10 @@
 //!
 //! 包含 CLI 命令层的所有测试文件。
 
+pub mod alias;
 pub mod basic_cli;
 pub mod branch;
+pub mod cache;
 pub mod check;
 pub mod commit;
 pub mod config;
@@ -17,3 +19,4 @@ pub mod pr;
 pub mod proxy;
 pub mod repo;
 pub mod stash;
+pub mod tag;
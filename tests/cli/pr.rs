@@ -5,7 +5,7 @@
 use clap::Parser;
 use pretty_assertions::assert_eq;
 use rstest::{fixture, rstest};
-use workflow::cli::{JiraIdArg, PRCommands};
+use workflow::cli::{JiraIdArg, PRCommands, PRListSort, PRMergeMethod, PRReviewType, TableFormat};
 
 // 创建一个测试用的 CLI 结构来测试参数解析
 #[derive(Parser)]
@@ -62,17 +62,46 @@ fn test_pr_create_command(
             jira_id: JiraIdArg { jira_id: ticket },
             title: t,
             description: d,
+            from_commits,
             dry_run: dr,
         } => {
             assert_eq!(ticket, jira_ticket.map(|s| s.to_string()));
             assert_eq!(t, title.map(|s| s.to_string()));
             assert_eq!(d, description.map(|s| s.to_string()));
+            assert!(!from_commits);
             assert_eq!(dr.dry_run, dry_run);
         }
         _ => panic!("Expected Create command"),
     }
 }
 
+#[test]
+fn test_pr_create_command_from_commits() {
+    let cli =
+        TestPRCli::try_parse_from(&["test-pr", "create", "--from-commits", "--dry-run"]).unwrap();
+
+    match cli.command {
+        PRCommands::Create {
+            jira_id: JiraIdArg { jira_id },
+            from_commits,
+            dry_run,
+            ..
+        } => {
+            assert_eq!(jira_id, None);
+            assert!(from_commits);
+            assert!(dry_run.dry_run);
+        }
+        _ => panic!("Expected Create command"),
+    }
+}
+
+#[test]
+fn test_pr_create_command_from_commits_conflicts_with_jira_id() {
+    let result = TestPRCli::try_parse_from(&["test-pr", "create", "PROJ-123", "--from-commits"]);
+
+    assert!(result.is_err());
+}
+
 // ==================== Merge 命令测试 ====================
 
 #[rstest]
@@ -94,14 +123,86 @@ fn test_pr_merge_command(#[case] pull_request_id: Option<&str>, #[case] force: b
         PRCommands::Merge {
             pull_request_id: id,
             force: f,
+            delete_branch,
+            keep_branch,
+            ..
         } => {
             assert_eq!(id, pull_request_id.map(|s| s.to_string()));
             assert_eq!(f.is_force(), force);
+            assert!(!delete_branch);
+            assert!(!keep_branch);
+        }
+        _ => panic!("Expected Merge command"),
+    }
+}
+
+#[test]
+fn test_pr_merge_command_delete_branch() {
+    let cli = TestPRCli::try_parse_from(&["test-pr", "merge", "123", "--delete-branch"]).unwrap();
+
+    match cli.command {
+        PRCommands::Merge { delete_branch, .. } => assert!(delete_branch),
+        _ => panic!("Expected Merge command"),
+    }
+}
+
+#[test]
+fn test_pr_merge_command_keep_branch() {
+    let cli = TestPRCli::try_parse_from(&["test-pr", "merge", "123", "--keep-branch"]).unwrap();
+
+    match cli.command {
+        PRCommands::Merge {
+            delete_branch,
+            keep_branch,
+            ..
+        } => {
+            assert!(keep_branch);
+            assert!(!delete_branch);
         }
         _ => panic!("Expected Merge command"),
     }
 }
 
+#[rstest]
+#[case(&["--delete-branch", "--auto"])]
+#[case(&["--delete-branch", "--dry-run"])]
+#[case(&["--keep-branch", "--delete-branch"])]
+#[case(&["--keep-branch", "--auto"])]
+#[case(&["--keep-branch", "--dry-run"])]
+fn test_pr_merge_command_delete_branch_conflicts(#[case] extra: &[&str]) {
+    let mut args = vec!["test-pr", "merge"];
+    args.extend_from_slice(extra);
+
+    assert!(TestPRCli::try_parse_from(&args).is_err());
+}
+
+#[rstest]
+#[case(&["--strategy", "merge"], Some(PRMergeMethod::Merge))]
+#[case(&["--strategy", "squash", "--auto"], Some(PRMergeMethod::Squash))]
+#[case(&["--method", "rebase"], Some(PRMergeMethod::Rebase))]
+#[case(&[], None)]
+fn test_pr_merge_command_strategy(#[case] extra: &[&str], #[case] expected: Option<PRMergeMethod>) {
+    let mut args = vec!["test-pr", "merge", "123"];
+    args.extend_from_slice(extra);
+
+    let cli = TestPRCli::try_parse_from(&args).unwrap();
+
+    match cli.command {
+        PRCommands::Merge { strategy, .. } => assert_eq!(strategy, expected),
+        _ => panic!("Expected Merge command"),
+    }
+}
+
+#[rstest]
+#[case(&["--strategy", "fast-forward"])]
+#[case(&["--strategy", "merge", "--dry-run"])]
+fn test_pr_merge_command_strategy_invalid(#[case] extra: &[&str]) {
+    let mut args = vec!["test-pr", "merge"];
+    args.extend_from_slice(extra);
+
+    assert!(TestPRCli::try_parse_from(&args).is_err());
+}
+
 // ==================== Status 命令测试 ====================
 
 #[rstest]
@@ -119,6 +220,7 @@ fn test_pr_status_command(#[case] pull_request_id_or_branch: Option<&str>) {
     match cli.command {
         PRCommands::Status {
             pull_request_id_or_branch: id,
+            ..
         } => {
             assert_eq!(id, pull_request_id_or_branch.map(|s| s.to_string()));
         }
@@ -126,6 +228,16 @@ fn test_pr_status_command(#[case] pull_request_id_or_branch: Option<&str>) {
     }
 }
 
+#[test]
+fn test_pr_status_command_json() {
+    let cli = TestPRCli::try_parse_from(&["test-pr", "status", "123", "--json"]).unwrap();
+
+    match cli.command {
+        PRCommands::Status { output_format, .. } => assert!(output_format.json),
+        _ => panic!("Expected Status command"),
+    }
+}
+
 // ==================== List 命令测试 ====================
 
 #[rstest]
@@ -151,6 +263,7 @@ fn test_pr_list_command(#[case] state: Option<&str>, #[case] limit: Option<usize
         PRCommands::List {
             state: s,
             pagination,
+            ..
         } => {
             assert_eq!(s, state.map(|s| s.to_string()));
             assert_eq!(pagination.limit, limit);
@@ -159,6 +272,95 @@ fn test_pr_list_command(#[case] state: Option<&str>, #[case] limit: Option<usize
     }
 }
 
+#[rstest]
+#[case(&[], TableFormat::Table)]
+#[case(&["--format", "md"], TableFormat::Md)]
+#[case(&["--format", "markdown"], TableFormat::Md)]
+#[case(&["--format", "csv"], TableFormat::Csv)]
+#[case(&["--format", "tsv"], TableFormat::Tsv)]
+fn test_pr_list_command_format(#[case] extra: &[&str], #[case] expected: TableFormat) {
+    let mut args = vec!["test-pr", "list"];
+    args.extend_from_slice(extra);
+
+    let cli = TestPRCli::try_parse_from(&args).unwrap();
+
+    match cli.command {
+        PRCommands::List { output, .. } => assert_eq!(output.format, expected),
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[test]
+fn test_pr_list_command_json() {
+    let cli = TestPRCli::try_parse_from(&["test-pr", "list", "--json"]).unwrap();
+
+    match cli.command {
+        PRCommands::List { json, output, .. } => {
+            assert!(json);
+            assert_eq!(output.format, TableFormat::Table);
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[test]
+fn test_pr_list_command_json_conflicts_with_format() {
+    let result = TestPRCli::try_parse_from(&["test-pr", "list", "--json", "--format", "csv"]);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_pr_list_command_invalid_format() {
+    let result = TestPRCli::try_parse_from(&["test-pr", "list", "--format", "xml"]);
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_pr_list_command_filters() {
+    let cli = TestPRCli::try_parse_from(&[
+        "test-pr", "list", "--author", "alice", "--label", "bug-fix", "--base", "develop",
+        "--sort", "updated",
+    ])
+    .unwrap();
+
+    match cli.command {
+        PRCommands::List { filters, .. } => {
+            assert_eq!(filters.author, Some("alice".to_string()));
+            assert!(!filters.mine);
+            assert_eq!(filters.label, Some("bug-fix".to_string()));
+            assert_eq!(filters.base, Some("develop".to_string()));
+            assert_eq!(filters.sort, PRListSort::Updated);
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[test]
+fn test_pr_list_command_filters_default() {
+    let cli = TestPRCli::try_parse_from(&["test-pr", "list", "--mine"]).unwrap();
+
+    match cli.command {
+        PRCommands::List { filters, .. } => {
+            assert!(filters.mine);
+            assert_eq!(filters.author, None);
+            assert_eq!(filters.sort, PRListSort::Created);
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[rstest]
+#[case(&["--mine", "--author", "alice"])]
+#[case(&["--sort", "merged"])]
+fn test_pr_list_command_invalid_filters(#[case] extra: &[&str]) {
+    let mut args = vec!["test-pr", "list"];
+    args.extend_from_slice(extra);
+
+    assert!(TestPRCli::try_parse_from(&args).is_err());
+}
+
 // ==================== Update 命令测试 ====================
 
 #[test]
@@ -178,16 +380,18 @@ fn test_pr_update_command_structure() {
 // ==================== Sync 命令测试 ====================
 
 #[rstest]
-#[case("feature/source", false, false, false)]
-#[case("feature/source", true, false, false)]
-#[case("feature/source", false, true, false)]
-#[case("feature/source", false, false, true)]
-#[case("feature/source", true, true, true)]
+#[case("feature/source", false, false, false, false)]
+#[case("feature/source", true, false, false, false)]
+#[case("feature/source", false, true, false, false)]
+#[case("feature/source", false, false, true, false)]
+#[case("feature/source", true, true, true, false)]
+#[case("feature/source", true, false, false, true)]
 fn test_pr_sync_command(
     #[case] source_branch: &str,
     #[case] rebase: bool,
     #[case] ff_only: bool,
     #[case] squash: bool,
+    #[case] dry_run: bool,
 ) {
     let mut args = vec!["test-pr", "sync", source_branch];
     if rebase {
@@ -199,6 +403,9 @@ fn test_pr_sync_command(
     if squash {
         args.push("--squash");
     }
+    if dry_run {
+        args.push("--dry-run");
+    }
 
     let cli = TestPRCli::try_parse_from(&args).unwrap();
 
@@ -208,16 +415,76 @@ fn test_pr_sync_command(
             rebase: r,
             ff_only: ff,
             squash: s,
+            dry_run: d,
+            ..
         } => {
             assert_eq!(sb, source_branch);
             assert_eq!(r, rebase);
             assert_eq!(ff, ff_only);
             assert_eq!(s, squash);
+            assert_eq!(d.is_dry_run(), dry_run);
+        }
+        _ => panic!("Expected Sync command"),
+    }
+}
+
+#[test]
+fn test_pr_sync_command_with_squash_message_and_no_push() {
+    let cli = TestPRCli::try_parse_from(&[
+        "test-pr",
+        "sync",
+        "feature/source",
+        "--squash",
+        "-m",
+        "Squash feature",
+        "--no-push",
+    ])
+    .unwrap();
+
+    match cli.command {
+        PRCommands::Sync {
+            squash,
+            message,
+            no_push,
+            ..
+        } => {
+            assert!(squash);
+            assert_eq!(message, Some("Squash feature".to_string()));
+            assert!(no_push);
         }
         _ => panic!("Expected Sync command"),
     }
 }
 
+#[test]
+fn test_pr_sync_command_with_all() {
+    let cli = TestPRCli::try_parse_from(&["test-pr", "sync", "main", "--all"]).unwrap();
+
+    match cli.command {
+        PRCommands::Sync {
+            source_branch, all, ..
+        } => {
+            assert_eq!(source_branch, "main");
+            assert!(all);
+        }
+        _ => panic!("Expected Sync command"),
+    }
+}
+
+#[rstest]
+#[case("--squash")]
+#[case("--ff-only")]
+fn test_pr_sync_command_all_conflicts_with_merge_options(#[case] flag: &str) {
+    let result = TestPRCli::try_parse_from(&["test-pr", "sync", "main", "--all", flag]);
+    assert!(result.is_err(), "--all should conflict with {}", flag);
+}
+
+#[test]
+fn test_pr_sync_command_message_requires_squash() {
+    let result = TestPRCli::try_parse_from(&["test-pr", "sync", "feature/source", "-m", "msg"]);
+    assert!(result.is_err(), "-m should require --squash");
+}
+
 // ==================== Rebase 命令测试 ====================
 
 #[rstest]
@@ -293,13 +560,35 @@ fn test_pr_summarize_command(#[case] pull_request_id: Option<&str>) {
     match cli.command {
         PRCommands::Summarize {
             pull_request_id: id,
+            no_cache,
+            clear_cache,
         } => {
             assert_eq!(id, pull_request_id.map(|s| s.to_string()));
+            assert!(!no_cache);
+            assert!(!clear_cache);
         }
         _ => panic!("Expected Summarize command"),
     }
 }
 
+#[test]
+fn test_pr_summarize_command_cache_flags() {
+    let cli = TestPRCli::try_parse_from(&["test-pr", "summarize", "123", "--no-cache"]).unwrap();
+    match cli.command {
+        PRCommands::Summarize { no_cache, .. } => assert!(no_cache),
+        _ => panic!("Expected Summarize command"),
+    }
+
+    let cli = TestPRCli::try_parse_from(&["test-pr", "summarize", "--clear-cache"]).unwrap();
+    match cli.command {
+        PRCommands::Summarize { clear_cache, .. } => assert!(clear_cache),
+        _ => panic!("Expected Summarize command"),
+    }
+
+    let result = TestPRCli::try_parse_from(&["test-pr", "summarize", "123", "--clear-cache"]);
+    assert!(result.is_err(), "--clear-cache should conflict with PR_ID");
+}
+
 // ==================== Approve 命令测试 ====================
 
 #[rstest]
@@ -316,13 +605,85 @@ fn test_pr_approve_command(#[case] pull_request_id: Option<&str>) {
     match cli.command {
         PRCommands::Approve {
             pull_request_id: id,
+            message,
         } => {
             assert_eq!(id, pull_request_id.map(|s| s.to_string()));
+            assert_eq!(message, None);
         }
         _ => panic!("Expected Approve command"),
     }
 }
 
+#[test]
+fn test_pr_approve_command_with_message() {
+    let cli =
+        TestPRCli::try_parse_from(&["test-pr", "approve", "123", "-m", "Looks good"]).unwrap();
+
+    match cli.command {
+        PRCommands::Approve {
+            pull_request_id,
+            message,
+        } => {
+            assert_eq!(pull_request_id, Some("123".to_string()));
+            assert_eq!(message, Some("Looks good".to_string()));
+        }
+        _ => panic!("Expected Approve command"),
+    }
+}
+
+// ==================== Review 命令测试 ====================
+
+#[rstest]
+#[case("approve", None, PRReviewType::Approve)]
+#[case("approve", Some("LGTM"), PRReviewType::Approve)]
+#[case("request-changes", Some("Needs tests"), PRReviewType::RequestChanges)]
+#[case("comment", Some("A few nits"), PRReviewType::Comment)]
+fn test_pr_review_command(
+    #[case] review_type: &str,
+    #[case] message: Option<&str>,
+    #[case] expected_type: PRReviewType,
+) {
+    let mut args = vec!["test-pr", "review", "123", "--type", review_type];
+    if let Some(message) = message {
+        args.extend(["--message", message]);
+    }
+
+    let cli = TestPRCli::try_parse_from(&args).unwrap();
+
+    match cli.command {
+        PRCommands::Review {
+            pull_request_id,
+            review_type,
+            message: parsed_message,
+        } => {
+            assert_eq!(pull_request_id, Some("123".to_string()));
+            assert_eq!(review_type, expected_type);
+            assert_eq!(parsed_message, message.map(str::to_string));
+        }
+        _ => panic!("Expected Review command"),
+    }
+}
+
+#[rstest]
+#[case("request-changes")]
+#[case("comment")]
+fn test_pr_review_command_requires_message(#[case] review_type: &str) {
+    let result = TestPRCli::try_parse_from(&["test-pr", "review", "--type", review_type]);
+
+    assert!(
+        result.is_err(),
+        "--type {} should require --message",
+        review_type
+    );
+}
+
+#[test]
+fn test_pr_review_command_requires_type() {
+    let result = TestPRCli::try_parse_from(&["test-pr", "review", "123", "-m", "LGTM"]);
+
+    assert!(result.is_err(), "--type should be required");
+}
+
 // ==================== Comment 命令测试 ====================
 
 #[test]
@@ -334,6 +695,7 @@ fn test_pr_comment_command_structure() {
         PRCommands::Comment {
             pull_request_id,
             message,
+            ..
         } => {
             assert_eq!(pull_request_id, Some("123".to_string()));
             assert_eq!(message, vec!["This is a comment"]);
@@ -360,6 +722,7 @@ fn test_pr_comment_command_with_multiple_words() {
         PRCommands::Comment {
             pull_request_id,
             message,
+            ..
         } => {
             assert_eq!(pull_request_id, Some("123".to_string()));
             assert_eq!(message, vec!["This", "is", "a", "multi-word", "comment"]);
@@ -377,6 +740,7 @@ fn test_pr_comment_command_without_id() {
         PRCommands::Comment {
             pull_request_id,
             message,
+            ..
         } => {
             // 单个参数会被解析为 PR ID
             assert_eq!(pull_request_id, Some("single-arg".to_string()));
@@ -389,6 +753,72 @@ fn test_pr_comment_command_without_id() {
     }
 }
 
+#[test]
+fn test_pr_comment_command_with_file_and_line() {
+    let cli = TestPRCli::try_parse_from(&[
+        "test-pr",
+        "comment",
+        "123",
+        "--file",
+        "src/main.rs",
+        "--line",
+        "42",
+        "Needs",
+        "a",
+        "test",
+    ])
+    .unwrap();
+
+    match cli.command {
+        PRCommands::Comment {
+            pull_request_id,
+            file,
+            line,
+            reply_to,
+            message,
+        } => {
+            assert_eq!(pull_request_id, Some("123".to_string()));
+            assert_eq!(file, Some("src/main.rs".to_string()));
+            assert_eq!(line, Some(42));
+            assert_eq!(reply_to, None);
+            assert_eq!(message, vec!["Needs", "a", "test"]);
+        }
+        _ => panic!("Expected Comment command"),
+    }
+}
+
+#[test]
+fn test_pr_comment_command_with_reply_to() {
+    let cli =
+        TestPRCli::try_parse_from(&["test-pr", "comment", "123", "--reply-to", "987", "Done"])
+            .unwrap();
+
+    match cli.command {
+        PRCommands::Comment {
+            file,
+            line,
+            reply_to,
+            message,
+            ..
+        } => {
+            assert_eq!(file, None);
+            assert_eq!(line, None);
+            assert_eq!(reply_to, Some(987));
+            assert_eq!(message, vec!["Done"]);
+        }
+        _ => panic!("Expected Comment command"),
+    }
+}
+
+#[rstest]
+#[case(&["test-pr", "comment", "123", "--file", "src/main.rs", "msg"])]
+#[case(&["test-pr", "comment", "123", "--line", "42", "msg"])]
+#[case(&["test-pr", "comment", "123", "--file", "src/main.rs", "--line", "0", "msg"])]
+#[case(&["test-pr", "comment", "123", "--reply-to", "987", "--file", "src/main.rs", "--line", "42", "msg"])]
+fn test_pr_comment_command_invalid_review_args(#[case] args: &[&str]) {
+    assert!(TestPRCli::try_parse_from(args).is_err());
+}
+
 // ==================== Pick 命令测试 ====================
 
 #[rstest]
@@ -476,6 +906,7 @@ fn test_pr_reword_command(
 #[case("close", |cmd: &PRCommands| matches!(cmd, PRCommands::Close { .. }))]
 #[case("summarize", |cmd: &PRCommands| matches!(cmd, PRCommands::Summarize { .. }))]
 #[case("approve", |cmd: &PRCommands| matches!(cmd, PRCommands::Approve { .. }))]
+#[case("review", |cmd: &PRCommands| matches!(cmd, PRCommands::Review { .. }))]
 #[case("comment", |cmd: &PRCommands| matches!(cmd, PRCommands::Comment { .. }))]
 #[case("pick", |cmd: &PRCommands| matches!(cmd, PRCommands::Pick { .. }))]
 #[case("reword", |cmd: &PRCommands| matches!(cmd, PRCommands::Reword { .. }))]
@@ -492,6 +923,7 @@ fn test_pr_commands_enum_all_variants(
             args.push("from");
             args.push("to");
         }
+        "review" => args.extend(["--type", "approve"]),
         _ => {}
     }
 
@@ -57,14 +57,24 @@ fn test_proxy_check_command_structure() {
     let cli = TestProxyCli::try_parse_from(&["test-proxy", "check"]).unwrap();
 
     match cli.command {
-        ProxySubcommand::Check => {
-            // Check 命令没有参数，只需要验证可以匹配
-            assert!(true, "Check command parsed successfully");
+        ProxySubcommand::Check { output_format } => {
+            // 默认为表格输出
+            assert!(!output_format.json);
         }
         _ => panic!("Expected Check command"),
     }
 }
 
+#[test]
+fn test_proxy_check_command_json() {
+    let cli = TestProxyCli::try_parse_from(&["test-proxy", "check", "--json"]).unwrap();
+
+    match cli.command {
+        ProxySubcommand::Check { output_format } => assert!(output_format.json),
+        _ => panic!("Expected Check command"),
+    }
+}
+
 #[test]
 fn test_proxy_command_parsing_all_subcommands() {
     // 测试所有子命令都可以正确解析
@@ -79,7 +89,7 @@ fn test_proxy_command_parsing_all_subcommands() {
 
     // Check
     let cli = TestProxyCli::try_parse_from(&["test-proxy", "check"]).unwrap();
-    assert!(matches!(cli.command, ProxySubcommand::Check));
+    assert!(matches!(cli.command, ProxySubcommand::Check { .. }));
 }
 
 #[test]
@@ -143,7 +153,7 @@ fn test_proxy_command_enum_variants() {
     let check_cli = TestProxyCli::try_parse_from(&["test-proxy", "check"]).unwrap();
 
     match (on_cli.command, off_cli.command, check_cli.command) {
-        (ProxySubcommand::On, ProxySubcommand::Off, ProxySubcommand::Check) => {
+        (ProxySubcommand::On, ProxySubcommand::Off, ProxySubcommand::Check { .. }) => {
             assert!(true, "All expected enum variants exist");
         }
         _ => panic!("Unexpected enum variants"),
@@ -166,5 +176,5 @@ fn test_proxy_command_short_names() {
 
     // Check
     let cli = TestProxyCli::try_parse_from(&["test-proxy", "check"]).unwrap();
-    assert!(matches!(cli.command, ProxySubcommand::Check));
+    assert!(matches!(cli.command, ProxySubcommand::Check { .. }));
 }
@@ -3,7 +3,7 @@
 //! 测试 Stash CLI 命令的参数解析、命令执行流程和错误处理。
 
 use clap::Parser;
-use workflow::cli::StashSubcommand;
+use workflow::cli::{StashSubcommand, TableFormat};
 
 // 创建一个测试用的 CLI 结构来测试参数解析
 #[derive(Parser)]
@@ -21,7 +21,7 @@ fn test_stash_list_command_structure() {
     let cli = TestStashCli::try_parse_from(&["test-stash", "list", "--stat"]).unwrap();
 
     match cli.command {
-        StashSubcommand::List { stat } => {
+        StashSubcommand::List { stat, .. } => {
             assert!(stat, "stat should be true");
         }
         _ => panic!("Expected List command"),
@@ -34,13 +34,27 @@ fn test_stash_list_command_minimal() {
     let cli = TestStashCli::try_parse_from(&["test-stash", "list"]).unwrap();
 
     match cli.command {
-        StashSubcommand::List { stat } => {
+        StashSubcommand::List { stat, .. } => {
             assert!(!stat, "stat should be false by default");
         }
         _ => panic!("Expected List command"),
     }
 }
 
+#[test]
+fn test_stash_list_command_format() {
+    // 测试 List 命令的 --format 参数
+    let cli = TestStashCli::try_parse_from(&["test-stash", "list", "--format", "csv"]).unwrap();
+
+    match cli.command {
+        StashSubcommand::List { stat, output } => {
+            assert!(!stat);
+            assert_eq!(output.format, TableFormat::Csv);
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
 // ==================== Apply 命令测试 ====================
 
 #[test]
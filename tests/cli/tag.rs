@@ -0,0 +1,205 @@
+//! Tag CLI 命令测试
+//!
+//! 测试 Tag CLI 命令的参数解析和参数冲突。
+
+use clap::Parser;
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use workflow::cli::{TagBump, TagSubcommand};
+
+// 创建一个测试用的 CLI 结构来测试参数解析
+#[derive(Parser)]
+#[command(name = "test-tag")]
+struct TestTagCli {
+    #[command(subcommand)]
+    command: TagSubcommand,
+}
+
+// ==================== List 命令测试 ====================
+
+#[test]
+fn test_tag_list_command_with_pattern() {
+    let cli =
+        TestTagCli::try_parse_from(&["test-tag", "list", "--pattern", "v1.*", "--local"]).unwrap();
+
+    match cli.command {
+        TagSubcommand::List { pattern, local } => {
+            assert_eq!(pattern, Some("v1.*".to_string()));
+            assert!(local);
+        }
+        _ => panic!("Expected List command"),
+    }
+}
+
+#[test]
+fn test_tag_list_command_alias() {
+    let cli = TestTagCli::try_parse_from(&["test-tag", "ls"]).unwrap();
+
+    assert!(matches!(
+        cli.command,
+        TagSubcommand::List {
+            pattern: None,
+            local: false
+        }
+    ));
+}
+
+// ==================== Create 命令测试 ====================
+
+#[test]
+fn test_tag_create_command_structure() {
+    let cli = TestTagCli::try_parse_from(&[
+        "test-tag",
+        "create",
+        "v1.2.0",
+        "-m",
+        "Release 1.2.0",
+        "--target",
+        "main",
+        "--push",
+    ])
+    .unwrap();
+
+    match cli.command {
+        TagSubcommand::Create {
+            tag_name,
+            message,
+            target,
+            push,
+        } => {
+            assert_eq!(tag_name, "v1.2.0");
+            assert_eq!(message, Some("Release 1.2.0".to_string()));
+            assert_eq!(target, Some("main".to_string()));
+            assert!(push);
+        }
+        _ => panic!("Expected Create command"),
+    }
+}
+
+#[test]
+fn test_tag_create_command_requires_name() {
+    assert!(TestTagCli::try_parse_from(&["test-tag", "create"]).is_err());
+}
+
+// ==================== Next 命令测试 ====================
+
+#[rstest]
+#[case(&["test-tag", "next"], TagBump::Patch, None)]
+#[case(&["test-tag", "next", "--bump", "minor"], TagBump::Minor, None)]
+#[case(&["test-tag", "next", "--bump", "major", "--pre", "rc"], TagBump::Major, Some("rc"))]
+fn test_tag_next_command(
+    #[case] args: &[&str],
+    #[case] expected_bump: TagBump,
+    #[case] expected_pre: Option<&str>,
+) {
+    let cli = TestTagCli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        TagSubcommand::Next {
+            bump,
+            pre,
+            prefix,
+            create,
+            ..
+        } => {
+            assert_eq!(bump, expected_bump);
+            assert_eq!(pre.as_deref(), expected_pre);
+            assert_eq!(prefix, None);
+            assert!(!create);
+        }
+        _ => panic!("Expected Next command"),
+    }
+}
+
+#[test]
+fn test_tag_next_command_create_and_push() {
+    let cli = TestTagCli::try_parse_from(&[
+        "test-tag", "next", "--prefix", "", "--create", "-m", "Release", "--push",
+    ])
+    .unwrap();
+
+    match cli.command {
+        TagSubcommand::Next {
+            prefix,
+            create,
+            message,
+            push,
+            ..
+        } => {
+            assert_eq!(prefix, Some(String::new()));
+            assert!(create);
+            assert_eq!(message, Some("Release".to_string()));
+            assert!(push);
+        }
+        _ => panic!("Expected Next command"),
+    }
+}
+
+#[rstest]
+#[case(&["test-tag", "next", "--push"])]
+#[case(&["test-tag", "next", "-m", "Release"])]
+#[case(&["test-tag", "next", "--bump", "build"])]
+fn test_tag_next_command_invalid_args(#[case] args: &[&str]) {
+    assert!(TestTagCli::try_parse_from(args).is_err());
+}
+
+// ==================== Push 命令测试 ====================
+
+#[rstest]
+#[case(&["test-tag", "push", "v1.2.0"], Some("v1.2.0"), false, None)]
+#[case(&["test-tag", "push", "--all"], None, true, None)]
+#[case(&["test-tag", "push", "--pattern", "v1.*"], None, false, Some("v1.*"))]
+#[case(&["test-tag", "push"], None, false, None)]
+fn test_tag_push_command(
+    #[case] args: &[&str],
+    #[case] expected_tag: Option<&str>,
+    #[case] expected_all: bool,
+    #[case] expected_pattern: Option<&str>,
+) {
+    let cli = TestTagCli::try_parse_from(args).unwrap();
+
+    match cli.command {
+        TagSubcommand::Push {
+            tag_name,
+            all,
+            pattern,
+        } => {
+            assert_eq!(tag_name.as_deref(), expected_tag);
+            assert_eq!(all, expected_all);
+            assert_eq!(pattern.as_deref(), expected_pattern);
+        }
+        _ => panic!("Expected Push command"),
+    }
+}
+
+#[rstest]
+#[case(&["test-tag", "push", "v1.2.0", "--all"])]
+#[case(&["test-tag", "push", "v1.2.0", "--pattern", "v1.*"])]
+#[case(&["test-tag", "push", "--all", "--pattern", "v1.*"])]
+fn test_tag_push_command_conflicts(#[case] args: &[&str]) {
+    assert!(TestTagCli::try_parse_from(args).is_err());
+}
+
+// ==================== Delete 命令测试 ====================
+
+#[test]
+fn test_tag_delete_command_with_pattern() {
+    let cli = TestTagCli::try_parse_from(&["test-tag", "delete", "--pattern", "v1.*", "--local"])
+        .unwrap();
+
+    match cli.command {
+        TagSubcommand::Delete {
+            tag_name,
+            local,
+            remote,
+            pattern,
+            ..
+        } => {
+            assert_eq!(tag_name, None);
+            assert!(local);
+            assert!(!remote);
+            assert_eq!(pattern, Some("v1.*".to_string()));
+        }
+        _ => panic!("Expected Delete command"),
+    }
+}
@@ -0,0 +1,54 @@
+//! Conventional Commits 校验测试
+//!
+//! 测试提交标题的解析，以及完整提交消息的校验（类型、格式、标题与正文之间的空行）。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use workflow::commit::ConventionalCommit;
+
+// ==================== 标题解析测试 ====================
+
+#[test]
+fn test_parse_header_with_scope_and_breaking_change() {
+    let header = ConventionalCommit::parse("feat(auth)!: drop legacy tokens").unwrap();
+
+    assert_eq!(
+        header,
+        ConventionalCommit {
+            commit_type: "feat".to_string(),
+            scope: Some("auth".to_string()),
+            breaking: true,
+            subject: "drop legacy tokens".to_string(),
+        }
+    );
+}
+
+#[rstest]
+#[case("PROJ-123: Fix login")]
+#[case("# Fix login")]
+#[case("feat:missing space")]
+#[case("feat(): empty scope")]
+#[case("Feat: capitalized type")]
+fn test_parse_invalid_header(#[case] header: &str) {
+    assert_eq!(ConventionalCommit::parse(header), None);
+}
+
+// ==================== 消息校验测试 ====================
+
+#[rstest]
+#[case("fix: handle empty response")]
+#[case("docs(readme): describe templates\n\nRefs: PROJ-123")]
+#[case("feat(api)!: remove v1\n\nBREAKING CHANGE: v1 is gone\n\nCo-authored-by: Alice <alice@example.com>")]
+fn test_validate_valid_message(#[case] message: &str) {
+    assert!(ConventionalCommit::validate(message).is_ok());
+}
+
+#[rstest]
+#[case("", "empty")]
+#[case("Fix login", "does not follow Conventional Commits")]
+#[case("feature: add login", "Unknown commit type 'feature'")]
+#[case("fix: add login\nRefs: PROJ-1", "blank line")]
+fn test_validate_invalid_message(#[case] message: &str, #[case] expected_error: &str) {
+    let error = ConventionalCommit::validate(message).unwrap_err().to_string();
+    assert!(error.contains(expected_error), "{}", error);
+}
@@ -3,5 +3,6 @@
 //! 包含 Commit 模块的所有测试文件。
 
 pub mod amend; // 提交修改测试
+pub mod conventional; // Conventional Commits 校验测试
 pub mod reword; // 提交重写测试
 pub mod squash; // 提交压缩测试
@@ -0,0 +1,62 @@
+//! Git 测试辅助工具
+//!
+//! 提供在临时仓库中执行 git 命令、创建提交等常用辅助函数。
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use super::cli_helpers::CliTestEnv;
+
+/// 检查 Git 是否可用（不可用时跳过依赖 Git 的测试）
+pub fn git_available() -> bool {
+    Command::new("git")
+        .arg("--version")
+        .output()
+        .is_ok_and(|output| output.status.success())
+}
+
+/// 在指定目录执行 git 命令并返回输出（失败时 panic）
+pub fn git(dir: &Path, args: &[&str]) -> String {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .expect("Failed to run git");
+    assert!(
+        output.status.success(),
+        "git {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).trim().to_string()
+}
+
+/// 写入文件并提交，返回提交 SHA
+pub fn commit_file(dir: &Path, file: &str, content: &str, message: &str) -> String {
+    fs::write(dir.join(file), content).expect("Failed to write file");
+    git(dir, &["add", file]);
+    git(dir, &["commit", "-m", message]);
+    git(dir, &["rev-parse", "HEAD"])
+}
+
+/// 创建临时 Git 仓库并切换到该目录，返回测试环境和原始目录
+///
+/// `identity` 为 `true` 时通过 `CliTestEnv::init_git_repo()` 同时配置提交者身份，
+/// 否则只执行 `git init`（用于测试身份配置本身）。Git 不可用时返回 `None`。
+pub fn setup_git_repo(identity: bool) -> Option<(CliTestEnv, PathBuf)> {
+    if !git_available() {
+        return None;
+    }
+
+    let original_dir = std::env::current_dir().ok()?;
+    let env = CliTestEnv::new();
+    if identity {
+        env.init_git_repo();
+    } else {
+        git(env.path(), &["init", "-q"]);
+    }
+
+    std::env::set_current_dir(env.path()).ok()?;
+    Some((env, original_dir))
+}
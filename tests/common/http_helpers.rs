@@ -4,6 +4,7 @@
 
 use mockito::Server;
 use std::env;
+use std::net::TcpListener;
 
 /// Mock 服务器包装器
 ///
@@ -50,3 +51,11 @@ impl Drop for MockServer {
         self.cleanup();
     }
 }
+
+/// 获取一个当前没有监听的本地地址（用于测试连接失败）
+pub fn unused_local_url() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").expect("Failed to bind");
+    let addr = listener.local_addr().expect("Failed to get local address");
+    drop(listener);
+    format!("http://{}/", addr)
+}
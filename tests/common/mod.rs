@@ -3,5 +3,6 @@
 //! 包含测试中使用的共享工具和辅助函数。
 
 pub mod cli_helpers;
+pub mod git_helpers;
 pub mod helpers;
 pub mod http_helpers;
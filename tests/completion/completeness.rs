@@ -32,7 +32,9 @@ const TOP_LEVEL_COMMANDS: &[&str] = &[
     "stash",
     "repo",
     "alias",
+    "cache",
     "tag",
+    "mcp",
 ];
 
 /// PR 子命令列表
@@ -47,13 +49,14 @@ const PR_SUBCOMMANDS: &[&str] = &[
     "close",
     "summarize",
     "approve",
+    "review",
     "comment",
     "pick",
     "reword",
 ];
 
 /// Log 子命令列表
-const LOG_SUBCOMMANDS: &[&str] = &["download", "find", "search"];
+const LOG_SUBCOMMANDS: &[&str] = &["download", "find", "search", "tail"];
 
 /// Jira 子命令列表
 const JIRA_SUBCOMMANDS: &[&str] = &[
@@ -62,13 +65,23 @@ const JIRA_SUBCOMMANDS: &[&str] = &[
     "changelog",
     "comment",
     "comments",
+    "sprint",
+    "attach",
     "attachments",
     "clean",
     "log",
 ];
 
 /// GitHub 子命令列表
-const GITHUB_SUBCOMMANDS: &[&str] = &["list", "current", "add", "remove", "switch", "update"];
+const GITHUB_SUBCOMMANDS: &[&str] = &[
+    "list",
+    "current",
+    "add",
+    "remove",
+    "switch",
+    "update",
+    "rate-limit",
+];
 
 /// LLM 子命令列表
 const LLM_SUBCOMMANDS: &[&str] = &["show", "setup"];
@@ -89,7 +102,7 @@ const PROXY_SUBCOMMANDS: &[&str] = &["on", "off", "check"];
 const LOG_LEVEL_SUBCOMMANDS: &[&str] = &["set", "check", "trace-console"];
 
 /// Completion 子命令列表
-const COMPLETION_SUBCOMMANDS: &[&str] = &["generate", "check", "remove"];
+const COMPLETION_SUBCOMMANDS: &[&str] = &["generate", "install", "check", "remove"];
 
 /// Stash 子命令列表
 const STASH_SUBCOMMANDS: &[&str] = &["list", "apply", "drop", "pop", "push"];
@@ -98,10 +111,13 @@ const STASH_SUBCOMMANDS: &[&str] = &["list", "apply", "drop", "pop", "push"];
 const REPO_SUBCOMMANDS: &[&str] = &["setup", "show", "clean"];
 
 /// Alias 子命令列表
-const ALIAS_SUBCOMMANDS: &[&str] = &["list", "add", "remove"];
+const ALIAS_SUBCOMMANDS: &[&str] = &["list", "add", "install", "remove"];
+
+/// Cache 子命令列表
+const CACHE_SUBCOMMANDS: &[&str] = &["list", "clean"];
 
 /// Tag 子命令列表
-const TAG_SUBCOMMANDS: &[&str] = &["delete"];
+const TAG_SUBCOMMANDS: &[&str] = &["list", "create", "next", "push", "delete"];
 
 /// 所有支持的 shell 类型
 const SHELL_TYPES: &[&str] = &["zsh", "bash", "fish", "powershell", "elvish"];
@@ -650,6 +666,7 @@ fn test_all_commands_with_subcommands() {
         ("stash", STASH_SUBCOMMANDS),
         ("repo", REPO_SUBCOMMANDS),
         ("alias", ALIAS_SUBCOMMANDS),
+        ("cache", CACHE_SUBCOMMANDS),
         ("tag", TAG_SUBCOMMANDS),
     ];
 
@@ -3,9 +3,9 @@
 //! 测试 Shell Completion 配置的创建、删除和检查功能。
 
 use crate::common::helpers::create_temp_test_dir;
-use clap_complete::Shell;
 use pretty_assertions::assert_eq;
 use rstest::rstest;
+use workflow::base::shell::Shell;
 use workflow::completion::Completion;
 
 // ==================== Completion 配置检查测试 ====================
@@ -152,12 +152,14 @@ fn test_completion_config_result_structure() {
         already_exists: false,
         added: true,
         config_file: None,
+        backup_path: None,
     };
 
     assert_eq!(result.shell, Shell::Zsh);
     assert_eq!(result.already_exists, false);
     assert_eq!(result.added, true);
     assert_eq!(result.config_file, None);
+    assert_eq!(result.backup_path, None);
 }
 
 #[test]
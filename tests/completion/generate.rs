@@ -5,7 +5,8 @@
 use crate::common::helpers::{cleanup_temp_test_dir, create_temp_test_dir};
 use pretty_assertions::assert_eq;
 use rstest::rstest;
-use workflow::completion::generate::CompletionGenerator;
+use std::fs;
+use workflow::completion::generate::{CompletionGenerator, CompletionScriptStatus};
 
 // ==================== CompletionGenerator 创建测试 ====================
 
@@ -126,6 +127,54 @@ fn test_completion_generator_generate_all(#[case] shell: &str) {
     cleanup_temp_test_dir(&test_dir);
 }
 
+// ==================== 脚本状态检查测试 ====================
+
+#[rstest]
+#[case("zsh", "_workflow")]
+#[case("bash", "workflow.bash")]
+#[case("fish", "workflow.fish")]
+fn test_completion_generator_script_status(#[case] shell: &str, #[case] filename: &str) {
+    // 测试生成后脚本为最新，修改后为过期，删除后为缺失
+    let test_dir = create_temp_test_dir("completion_status");
+    let generator = CompletionGenerator::new(
+        Some(shell.to_string()),
+        Some(test_dir.to_string_lossy().to_string()),
+    )
+    .expect("Failed to create generator");
+
+    assert_eq!(
+        generator.script_status().expect("Failed to check status"),
+        CompletionScriptStatus::Missing
+    );
+
+    generator.generate_all().expect("Failed to generate completion");
+    assert_eq!(
+        generator.script_status().expect("Failed to check status"),
+        CompletionScriptStatus::UpToDate
+    );
+
+    let script = test_dir.join(filename);
+    let mut content = fs::read_to_string(&script).expect("Failed to read completion file");
+    content.push_str("\n# removed-subcommand\n");
+    fs::write(&script, content).expect("Failed to modify completion file");
+    assert_eq!(
+        generator.script_status().expect("Failed to check status"),
+        CompletionScriptStatus::Stale
+    );
+
+    cleanup_temp_test_dir(&test_dir);
+}
+
+#[test]
+fn test_completion_script_status_display() {
+    assert_eq!(CompletionScriptStatus::UpToDate.to_string(), "up to date");
+    assert_eq!(CompletionScriptStatus::Stale.to_string(), "stale");
+    assert_eq!(CompletionScriptStatus::Missing.to_string(), "missing");
+    assert!(!CompletionScriptStatus::UpToDate.needs_regeneration());
+    assert!(CompletionScriptStatus::Stale.needs_regeneration());
+    assert!(CompletionScriptStatus::Missing.needs_regeneration());
+}
+
 // ==================== GenerateResult 结构体测试 ====================
 
 #[test]
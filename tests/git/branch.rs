@@ -10,6 +10,8 @@ use std::fs;
 use tempfile::TempDir;
 use workflow::git::{GitBranch, MergeStrategy};
 
+use crate::common::git_helpers::{commit_file, git, git_available};
+
 // ==================== Fixtures ====================
 
 /// 创建带有初始提交的 Git 仓库
@@ -326,6 +328,69 @@ fn test_delete_existing_branch() {
     }
 }
 
+// ==================== 未推送提交测试 ====================
+
+/// 创建带远程仓库的克隆：`feature` 分支已推送一个提交，当前分支为 `feature`
+fn setup_repo_with_remote() -> Option<TempDir> {
+    if !git_available() {
+        return None;
+    }
+
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let remote = temp_dir.path().join("remote.git");
+    let local = temp_dir.path().join("local");
+    git(temp_dir.path(), &["init", "--bare", "remote.git"]);
+    git(
+        temp_dir.path(),
+        &["clone", remote.to_str().unwrap(), "local"],
+    );
+    git(&local, &["config", "user.name", "Test User"]);
+    git(&local, &["config", "user.email", "test@example.com"]);
+    git(&local, &["checkout", "-b", "feature"]);
+    commit_file(&local, "pushed.txt", "pushed\n", "Pushed commit");
+    git(&local, &["push", "-u", "origin", "feature"]);
+
+    Some(temp_dir)
+}
+
+#[test]
+#[serial]
+fn test_get_unpushed_commits() {
+    let Some(temp_dir) = setup_repo_with_remote() else {
+        return;
+    };
+    let local = temp_dir.path().join("local");
+    let original_dir = std::env::current_dir().unwrap();
+    std::env::set_current_dir(&local).unwrap();
+
+    // 所有提交都已推送
+    let pushed = GitBranch::get_unpushed_commits("feature");
+
+    commit_file(&local, "local.txt", "local\n", "Local commit");
+    let unpushed = GitBranch::get_unpushed_commits("feature");
+
+    std::env::set_current_dir(original_dir).unwrap();
+
+    assert!(pushed.expect("Failed to get unpushed commits").is_empty());
+    assert_eq!(unpushed.expect("Failed to get unpushed commits").len(), 1);
+}
+
+#[test]
+#[serial]
+fn test_get_unpushed_commits_missing_branch() {
+    let Some(temp_dir) = setup_repo_with_remote() else {
+        return;
+    };
+    let original_dir = std::env::current_dir().unwrap();
+    std::env::set_current_dir(temp_dir.path().join("local")).unwrap();
+
+    let result = GitBranch::get_unpushed_commits("missing-branch");
+
+    std::env::set_current_dir(original_dir).unwrap();
+
+    assert!(result.is_err());
+}
+
 // ==================== 分支列表测试 ====================
 
 #[test]
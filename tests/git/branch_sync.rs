@@ -0,0 +1,491 @@
+//! 分支同步测试
+//!
+//! 测试冲突风险评估、dry-run 同步计划的计算（不修改工作区），
+//! squash 同步的提交消息（自定义消息和共同作者 trailer），
+//! 以及分支栈（`--all`）的检测和按顺序 rebase。
+
+use pretty_assertions::assert_eq;
+use serial_test::serial;
+use tempfile::TempDir;
+use workflow::branch::sync::{
+    assess_conflict_risk, build_squash_message, collect_co_authors, detect_stack,
+    squash_message_template, BranchSync, BranchSyncOptions, ConflictRisk, StackBranchStatus,
+};
+use workflow::branch::SyncStrategy;
+
+use crate::common::git_helpers::{commit_file, git, git_available};
+
+// ==================== 辅助函数 ====================
+
+/// 创建分叉的仓库：`base` 分支和 `feature` 分支各有新提交，当前分支为 `feature`
+///
+/// - `base`：修改 `shared.txt`，新增 `base.txt`
+/// - `feature`：修改 `shared.txt`（如果 `touch_shared`），新增 `feature.txt`
+fn setup_diverged_repo(touch_shared: bool) -> Option<TempDir> {
+    if !git_available() {
+        return None;
+    }
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let dir = temp_dir.path();
+    git(dir, &["init", "-b", "base"]);
+    git(dir, &["config", "user.name", "Test User"]);
+    git(dir, &["config", "user.email", "test@example.com"]);
+    commit_file(dir, "shared.txt", "initial\n", "Initial commit");
+
+    git(dir, &["checkout", "-b", "feature"]);
+    commit_file(dir, "feature.txt", "feature\n", "Add feature file");
+    if touch_shared {
+        commit_file(
+            dir,
+            "shared.txt",
+            "feature change\n",
+            "Change shared file on feature",
+        );
+    }
+
+    git(dir, &["checkout", "base"]);
+    commit_file(
+        dir,
+        "shared.txt",
+        "base change\n",
+        "Change shared file on base",
+    );
+    commit_file(dir, "base.txt", "base\n", "Add base file");
+
+    git(dir, &["checkout", "feature"]);
+    Some(temp_dir)
+}
+
+fn options(source_branch: &str, rebase: bool) -> BranchSyncOptions {
+    BranchSyncOptions {
+        source_branch: source_branch.to_string(),
+        rebase,
+        ff_only: false,
+        squash: false,
+        dry_run: true,
+        message: None,
+        no_push: false,
+        all: false,
+    }
+}
+
+// ==================== 冲突风险评估测试 ====================
+
+#[test]
+fn test_assess_conflict_risk_without_changes_on_one_side() {
+    let files = vec!["src/main.rs".to_string()];
+    assert_eq!(assess_conflict_risk(&[], &files), ConflictRisk::None);
+    assert_eq!(assess_conflict_risk(&files, &[]), ConflictRisk::None);
+}
+
+#[test]
+fn test_assess_conflict_risk_without_overlap() {
+    let local = vec!["src/a.rs".to_string()];
+    let incoming = vec!["src/b.rs".to_string()];
+    assert_eq!(assess_conflict_risk(&local, &incoming), ConflictRisk::Low);
+}
+
+#[test]
+fn test_assess_conflict_risk_with_overlap_is_sorted() {
+    let local = vec!["b.rs".to_string(), "a.rs".to_string(), "c.rs".to_string()];
+    let incoming = vec!["a.rs".to_string(), "b.rs".to_string()];
+    assert_eq!(
+        assess_conflict_risk(&local, &incoming),
+        ConflictRisk::Likely(vec!["a.rs".to_string(), "b.rs".to_string()])
+    );
+}
+
+// ==================== 同步计划测试 ====================
+
+#[test]
+#[serial]
+fn test_sync_plan_merge_reports_incoming_commits_and_conflicts() {
+    let Some(temp_dir) = setup_diverged_repo(true) else {
+        return;
+    };
+    let original_dir = std::env::current_dir().unwrap();
+    std::env::set_current_dir(temp_dir.path()).unwrap();
+    let head_before = git(temp_dir.path(), &["rev-parse", "HEAD"]);
+
+    let plan = BranchSync::plan(&options("base", false));
+
+    std::env::set_current_dir(original_dir).unwrap();
+    let plan = plan.expect("Failed to compute sync plan");
+
+    assert!(matches!(plan.strategy, SyncStrategy::Merge(_)));
+    assert_eq!(plan.current_branch, "feature");
+    assert!(!plan.source_branch_info.is_remote);
+    assert!(!plan.has_uncommitted);
+    let applied: Vec<&str> = plan.applied_commits().iter().map(|c| c.message.as_str()).collect();
+    assert_eq!(applied, vec!["Change shared file on base", "Add base file"]);
+    assert_eq!(plan.local_commits.len(), 2);
+    assert!(!plan.can_fast_forward());
+    assert_eq!(
+        plan.conflict_risk,
+        ConflictRisk::Likely(vec!["shared.txt".to_string()])
+    );
+
+    // dry-run 不修改仓库
+    assert_eq!(git(temp_dir.path(), &["rev-parse", "HEAD"]), head_before);
+    assert_eq!(git(temp_dir.path(), &["status", "--porcelain"]), "");
+}
+
+#[test]
+#[serial]
+fn test_sync_plan_rebase_reports_replayed_commits() {
+    let Some(temp_dir) = setup_diverged_repo(false) else {
+        return;
+    };
+    let original_dir = std::env::current_dir().unwrap();
+    std::env::set_current_dir(temp_dir.path()).unwrap();
+
+    let plan = BranchSync::plan(&options("base", true));
+
+    std::env::set_current_dir(original_dir).unwrap();
+    let plan = plan.expect("Failed to compute sync plan");
+
+    assert!(matches!(plan.strategy, SyncStrategy::Rebase));
+    let applied: Vec<&str> = plan.applied_commits().iter().map(|c| c.message.as_str()).collect();
+    assert_eq!(applied, vec!["Add feature file"]);
+    assert_eq!(plan.incoming_commits.len(), 2);
+    assert_eq!(plan.conflict_risk, ConflictRisk::Low);
+}
+
+#[test]
+#[serial]
+fn test_sync_plan_missing_source_branch_returns_error() {
+    let Some(temp_dir) = setup_diverged_repo(false) else {
+        return;
+    };
+    let original_dir = std::env::current_dir().unwrap();
+    std::env::set_current_dir(temp_dir.path()).unwrap();
+
+    let plan = BranchSync::plan(&options("does-not-exist", false));
+
+    std::env::set_current_dir(original_dir).unwrap();
+    assert!(plan.is_err());
+}
+
+// ==================== Squash 提交消息测试 ====================
+
+#[test]
+fn test_collect_co_authors_dedupes_case_insensitively() {
+    let messages = vec![
+        "Fix login\n\nCo-authored-by: Alice <alice@example.com>".to_string(),
+        "Add test\n\nco-authored-by:  Alice  <ALICE@example.com>\nCo-authored-by: Bob <bob@example.com>"
+            .to_string(),
+        "No trailer".to_string(),
+    ];
+
+    assert_eq!(
+        collect_co_authors(&messages),
+        vec![
+            "Co-authored-by: Alice <alice@example.com>".to_string(),
+            "Co-authored-by: Bob <bob@example.com>".to_string(),
+        ]
+    );
+}
+
+#[test]
+fn test_build_squash_message_appends_deduped_co_authors() {
+    let co_authors = vec![
+        "Co-authored-by: Alice <alice@example.com>".to_string(),
+        "Co-authored-by: Bob <bob@example.com>".to_string(),
+    ];
+
+    let message = build_squash_message(
+        "Add feature\n\nCo-authored-by: alice <alice@example.com>\n",
+        &co_authors,
+    );
+
+    assert_eq!(
+        message,
+        "Add feature\n\nCo-authored-by: alice <alice@example.com>\nCo-authored-by: Bob <bob@example.com>"
+    );
+}
+
+#[test]
+fn test_build_squash_message_without_co_authors() {
+    assert_eq!(build_squash_message("  Add feature\n", &[]), "Add feature");
+}
+
+#[test]
+fn test_squash_message_template_lists_commits_and_co_authors() {
+    let messages = vec![
+        "First change\n\nCo-authored-by: Alice <alice@example.com>".to_string(),
+        "Second change".to_string(),
+    ];
+
+    assert_eq!(
+        squash_message_template("feature", &messages),
+        "Squash merge branch 'feature'\n\n* First change\n\n* Second change\n\nCo-authored-by: Alice <alice@example.com>"
+    );
+}
+
+#[test]
+#[serial]
+fn test_squash_sync_commits_with_message_and_co_authors() {
+    let Some(temp_dir) = setup_diverged_repo(false) else {
+        return;
+    };
+    let dir = temp_dir.path();
+    commit_file(
+        dir,
+        "feature2.txt",
+        "feature\n",
+        "Add second feature file\n\nCo-authored-by: Alice <alice@example.com>\nCo-authored-by: Bob <bob@example.com>",
+    );
+    commit_file(
+        dir,
+        "feature3.txt",
+        "feature\n",
+        "Add third feature file\n\nCo-authored-by: alice <ALICE@example.com>",
+    );
+    git(dir, &["checkout", "base"]);
+
+    let original_dir = std::env::current_dir().unwrap();
+    std::env::set_current_dir(dir).unwrap();
+
+    let result = BranchSync::sync(
+        BranchSyncOptions {
+            source_branch: "feature".to_string(),
+            rebase: false,
+            ff_only: false,
+            squash: true,
+            dry_run: false,
+            message: Some("Squash feature work".to_string()),
+            no_push: true,
+            all: false,
+        },
+        None,
+    );
+
+    std::env::set_current_dir(original_dir).unwrap();
+    let result = result.expect("Failed to squash sync");
+    assert!(result.success);
+
+    let message = git(dir, &["log", "-1", "--format=%B"]);
+    assert_eq!(
+        message,
+        "Squash feature work\n\nCo-authored-by: Alice <alice@example.com>\nCo-authored-by: Bob <bob@example.com>"
+    );
+    // squash 合并只产生一个普通提交（只有一个父提交）
+    assert_eq!(
+        git(dir, &["rev-list", "--parents", "-n", "1", "HEAD"]).split(' ').count(),
+        2
+    );
+    assert!(dir.join("feature3.txt").exists());
+}
+
+// ==================== 分支栈测试 ====================
+
+/// 创建分支栈：`main` <- `part-1` <- `part-2`，之后 `main` 有新提交，当前分支为 `part-2`
+///
+/// - `part-1`：新增 `part1.txt`
+/// - `part-2`：新增 `part2.txt`
+/// - `main`：新增 `main.txt`，`conflict` 时也新增内容不同的 `part1.txt`
+fn setup_stack_repo(conflict: bool) -> Option<TempDir> {
+    if !git_available() {
+        return None;
+    }
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let dir = temp_dir.path();
+    git(dir, &["init", "-b", "main"]);
+    git(dir, &["config", "user.name", "Test User"]);
+    git(dir, &["config", "user.email", "test@example.com"]);
+    commit_file(dir, "README.md", "initial\n", "Initial commit");
+
+    git(dir, &["checkout", "-b", "part-1"]);
+    commit_file(dir, "part1.txt", "part 1\n", "Add part 1");
+    git(dir, &["checkout", "-b", "part-2"]);
+    commit_file(dir, "part2.txt", "part 2\n", "Add part 2");
+
+    git(dir, &["checkout", "main"]);
+    commit_file(dir, "main.txt", "main\n", "Update main");
+    if conflict {
+        commit_file(dir, "part1.txt", "main version\n", "Add part 1 on main");
+    }
+
+    git(dir, &["checkout", "part-2"]);
+    Some(temp_dir)
+}
+
+fn stack_options(dry_run: bool) -> BranchSyncOptions {
+    BranchSyncOptions {
+        source_branch: "main".to_string(),
+        rebase: true,
+        ff_only: false,
+        squash: false,
+        dry_run,
+        message: None,
+        no_push: true,
+        all: true,
+    }
+}
+
+/// 线性祖先关系：`order` 中靠前的分支是靠后分支的祖先
+fn linear_ancestry<'a>(order: &'a [&'a str]) -> impl Fn(&str, &str) -> bool + 'a {
+    move |ancestor, descendant| {
+        let rank = |name: &str| order.iter().position(|branch| *branch == name);
+        matches!((rank(ancestor), rank(descendant)), (Some(a), Some(d)) if a < d)
+    }
+}
+
+fn branches(names: &[&str]) -> Vec<String> {
+    names.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn test_detect_stack_orders_ancestors_and_descendants() {
+    let is_ancestor = linear_ancestry(&["main", "part-1", "part-2", "part-3"]);
+
+    let stack = detect_stack(
+        "main",
+        "part-2",
+        &branches(&["part-3", "main", "part-2", "part-1", "unrelated"]),
+        is_ancestor,
+    )
+    .unwrap();
+
+    assert_eq!(stack, vec!["part-1", "part-2", "part-3"]);
+}
+
+#[test]
+fn test_detect_stack_excludes_branches_merged_into_base() {
+    // merged 已经包含在 main 中
+    let is_ancestor = linear_ancestry(&["merged", "main", "feature"]);
+
+    let stack = detect_stack(
+        "main",
+        "feature",
+        &branches(&["main", "merged", "feature"]),
+        is_ancestor,
+    )
+    .unwrap();
+
+    assert_eq!(stack, vec!["feature"]);
+}
+
+#[test]
+fn test_detect_stack_rejects_forked_branches() {
+    // part-2a 和 part-2b 都基于 part-1
+    let is_ancestor =
+        |ancestor: &str, descendant: &str| ancestor == "part-1" && descendant.starts_with("part-2");
+
+    let result = detect_stack(
+        "main",
+        "part-1",
+        &branches(&["main", "part-1", "part-2a", "part-2b"]),
+        is_ancestor,
+    );
+
+    let error = result.unwrap_err().to_string();
+    assert!(error.contains("do not form a single chain"), "{}", error);
+}
+
+#[test]
+fn test_detect_stack_rejects_current_branch_merged_into_base() {
+    let is_ancestor = linear_ancestry(&["feature", "main"]);
+
+    let result = detect_stack(
+        "main",
+        "feature",
+        &branches(&["main", "feature"]),
+        is_ancestor,
+    );
+
+    assert!(result.is_err());
+}
+
+#[test]
+#[serial]
+fn test_sync_stack_rebases_branches_in_order() {
+    let Some(temp_dir) = setup_stack_repo(false) else {
+        return;
+    };
+    let dir = temp_dir.path();
+    let original_dir = std::env::current_dir().unwrap();
+    std::env::set_current_dir(dir).unwrap();
+
+    let result = BranchSync::sync(stack_options(false), None);
+
+    std::env::set_current_dir(original_dir).unwrap();
+    let result = result.expect("Failed to sync stack");
+
+    assert!(result.success);
+    let outcomes: Vec<(&str, &str, StackBranchStatus)> = result
+        .stack
+        .iter()
+        .map(|entry| (entry.branch.as_str(), entry.onto.as_str(), entry.status))
+        .collect();
+    assert_eq!(
+        outcomes,
+        vec![
+            ("part-1", "main", StackBranchStatus::Rebased),
+            ("part-2", "part-1", StackBranchStatus::Rebased),
+        ]
+    );
+
+    // 每个分支都基于前一个分支，且只保留自己的提交
+    assert_eq!(
+        git(dir, &["rev-parse", "part-1~1"]),
+        git(dir, &["rev-parse", "main"])
+    );
+    assert_eq!(
+        git(dir, &["rev-parse", "part-2~1"]),
+        git(dir, &["rev-parse", "part-1"])
+    );
+    assert_eq!(git(dir, &["branch", "--show-current"]), "part-2");
+}
+
+#[test]
+#[serial]
+fn test_sync_stack_stops_on_first_conflict() {
+    let Some(temp_dir) = setup_stack_repo(true) else {
+        return;
+    };
+    let dir = temp_dir.path();
+    let part2_before = git(dir, &["rev-parse", "part-2"]);
+    let original_dir = std::env::current_dir().unwrap();
+    std::env::set_current_dir(dir).unwrap();
+
+    let result = BranchSync::sync(stack_options(false), None);
+
+    std::env::set_current_dir(original_dir).unwrap();
+    let result = result.expect("Conflicts should be reported in the result");
+
+    assert!(!result.success);
+    let statuses: Vec<StackBranchStatus> = result.stack.iter().map(|entry| entry.status).collect();
+    assert_eq!(
+        statuses,
+        vec![StackBranchStatus::Conflict, StackBranchStatus::Pending]
+    );
+    // 后面的分支没有被修改
+    assert_eq!(git(dir, &["rev-parse", "part-2"]), part2_before);
+    assert_eq!(result.stack[1].original_sha, part2_before);
+}
+
+#[test]
+#[serial]
+fn test_sync_stack_dry_run_does_not_modify_branches() {
+    let Some(temp_dir) = setup_stack_repo(false) else {
+        return;
+    };
+    let dir = temp_dir.path();
+    let part1_before = git(dir, &["rev-parse", "part-1"]);
+    let original_dir = std::env::current_dir().unwrap();
+    std::env::set_current_dir(dir).unwrap();
+
+    let result = BranchSync::sync(stack_options(true), None);
+
+    std::env::set_current_dir(original_dir).unwrap();
+    let result = result.expect("Failed to plan stack sync");
+
+    assert!(result.dry_run);
+    assert!(!result.success);
+    assert_eq!(result.stack.len(), 2);
+    assert!(result.stack.iter().all(|entry| entry.status == StackBranchStatus::Pending));
+    assert_eq!(git(dir, &["rev-parse", "part-1"]), part1_before);
+}
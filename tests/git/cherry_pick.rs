@@ -0,0 +1,82 @@
+//! Cherry-pick 测试
+//!
+//! 测试 `git cherry` 输出解析，以及检测已经 cherry-pick 到目标分支的提交。
+
+use pretty_assertions::assert_eq;
+use serial_test::serial;
+use tempfile::TempDir;
+use workflow::git::{parse_cherry_output, CherryCommit, GitCherryPick};
+
+use crate::common::git_helpers::{commit_file, git, git_available};
+
+// ==================== 输出解析测试 ====================
+
+#[test]
+fn test_parse_cherry_output_marks_applied_commits() {
+    let commits = parse_cherry_output("+ aaa111\n- bbb222\n");
+    assert_eq!(
+        commits,
+        vec![
+            CherryCommit {
+                sha: "aaa111".to_string(),
+                applied: false,
+            },
+            CherryCommit {
+                sha: "bbb222".to_string(),
+                applied: true,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_cherry_output_ignores_unknown_lines() {
+    assert_eq!(parse_cherry_output(""), Vec::new());
+    assert_eq!(parse_cherry_output("warning: something\n\n"), Vec::new());
+}
+
+// ==================== 已应用提交检测测试 ====================
+
+#[test]
+#[serial]
+fn test_cherry_detects_commit_already_picked_to_target() {
+    if !git_available() {
+        return;
+    }
+
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let dir = temp_dir.path();
+    git(dir, &["init", "-b", "release"]);
+    git(dir, &["config", "user.name", "Test User"]);
+    git(dir, &["config", "user.email", "test@example.com"]);
+    commit_file(dir, "README.md", "initial\n", "Initial commit");
+
+    git(dir, &["checkout", "-b", "feature"]);
+    let picked = commit_file(dir, "fix.txt", "fix\n", "Fix bug");
+    let pending = commit_file(dir, "feature.txt", "feature\n", "Add feature");
+
+    // 目标分支先提交一次再 cherry-pick（父提交不同，SHA 必然不同，patch-id 相同）
+    git(dir, &["checkout", "release"]);
+    commit_file(dir, "CHANGELOG.md", "release\n", "Prepare release");
+    git(dir, &["cherry-pick", &picked]);
+
+    let original_dir = std::env::current_dir().unwrap();
+    std::env::set_current_dir(dir).unwrap();
+    let result = GitCherryPick::cherry("release", "feature");
+    std::env::set_current_dir(original_dir).unwrap();
+
+    let commits = result.expect("git cherry should succeed");
+    assert_eq!(
+        commits,
+        vec![
+            CherryCommit {
+                sha: picked,
+                applied: true,
+            },
+            CherryCommit {
+                sha: pending,
+                applied: false,
+            },
+        ]
+    );
+}
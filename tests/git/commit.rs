@@ -10,6 +10,8 @@ use std::fs;
 use tempfile::TempDir;
 use workflow::git::GitCommit;
 
+use crate::common::git_helpers::{commit_file, git};
+
 // 辅助函数：创建带有初始提交的临时 Git 仓库
 fn setup_git_repo() -> (TempDir, std::path::PathBuf) {
     // 保存原始目录在创建临时目录之前
@@ -400,6 +402,40 @@ fn test_get_latest_commit_info() {
     }
 }
 
+// ==================== 提交范围测试 ====================
+
+#[test]
+#[serial]
+fn test_get_commit_messages_and_diff_in_range() {
+    let (temp_dir, original_dir) = setup_git_repo();
+    std::env::set_current_dir(temp_dir.path()).unwrap();
+
+    git(temp_dir.path(), &["tag", "base"]);
+    commit_file(
+        temp_dir.path(),
+        "feature.txt",
+        "feature\n",
+        "feat: add feature file\n\nDetails.",
+    );
+
+    let messages = GitCommit::get_commit_messages_in_range("base", "HEAD").unwrap();
+    assert_eq!(
+        messages,
+        vec!["feat: add feature file\n\nDetails.".to_string()]
+    );
+
+    let diff = GitCommit::get_diff_in_range("base", "HEAD").unwrap();
+    assert!(diff.contains("feature.txt"));
+    assert!(diff.contains("+feature"));
+
+    // 没有修改时返回 None
+    assert_eq!(GitCommit::get_diff_in_range("HEAD", "HEAD"), None);
+
+    if original_dir.exists() {
+        let _ = std::env::set_current_dir(original_dir);
+    }
+}
+
 // ==================== WorktreeStatus 结构体测试 ====================
 
 // 注意：WorktreeStatus 没有 Default trait，跳过此测试
@@ -0,0 +1,237 @@
+//! Git 配置管理测试
+//!
+//! 测试 `GitConfig` 对仓库级 Git 用户身份的设置、切换和恢复，提交签名配置的读取，
+//! 以及受支持配置项的校验和读写。
+//! 只修改临时仓库的 local 配置，不会影响全局配置。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use serial_test::serial;
+use std::process::Command;
+use workflow::git::{
+    GitConfig, GitConfigScope, GitSigningConfig, GitSigningFormat, GitUserIdentity,
+    MANAGED_GIT_CONFIG_KEYS,
+};
+
+use crate::common::git_helpers::setup_git_repo;
+
+// ==================== Helper Functions ====================
+
+/// 创建身份
+fn identity(name: &str, email: &str) -> GitUserIdentity {
+    GitUserIdentity {
+        email: Some(email.to_string()),
+        name: Some(name.to_string()),
+    }
+}
+
+// ==================== 身份切换测试 ====================
+
+#[test]
+#[serial]
+fn test_switch_user_updates_local_identity() {
+    let Some((_env, original_dir)) = setup_git_repo(false) else {
+        return;
+    };
+
+    GitConfig::set_user(GitConfigScope::Local, "jane@example.com", "Jane Doe").unwrap();
+
+    let result =
+        GitConfig::switch_user(GitConfigScope::Local, "jane@work.example.com", "work").unwrap();
+
+    assert_eq!(result.before, identity("Jane Doe", "jane@example.com"));
+    assert_eq!(result.after, identity("work", "jane@work.example.com"));
+    assert!(!result.restored);
+    assert_eq!(
+        GitConfig::get_user(GitConfigScope::Local),
+        identity("work", "jane@work.example.com")
+    );
+
+    let _ = std::env::set_current_dir(original_dir);
+}
+
+#[test]
+#[serial]
+fn test_switch_user_back_restores_previous_identity() {
+    let Some((_env, original_dir)) = setup_git_repo(false) else {
+        return;
+    };
+
+    GitConfig::set_user(GitConfigScope::Local, "jane@example.com", "Jane Doe").unwrap();
+    GitConfig::switch_user(GitConfigScope::Local, "jane@work.example.com", "work").unwrap();
+    GitConfig::switch_user(GitConfigScope::Local, "jane@oss.example.com", "oss").unwrap();
+
+    // 切换回原来的邮箱时恢复原来的 user.name，而不是账号名称
+    let result =
+        GitConfig::switch_user(GitConfigScope::Local, "jane@example.com", "personal").unwrap();
+
+    assert!(result.restored);
+    assert_eq!(result.before, identity("oss", "jane@oss.example.com"));
+    assert_eq!(result.after, identity("Jane Doe", "jane@example.com"));
+
+    // 恢复后不再保留记录，再次切换会重新记录
+    let result =
+        GitConfig::switch_user(GitConfigScope::Local, "jane@work.example.com", "work").unwrap();
+    assert!(!result.restored);
+
+    let _ = std::env::set_current_dir(original_dir);
+}
+
+#[test]
+#[serial]
+fn test_switch_user_without_previous_identity() {
+    let Some((_env, original_dir)) = setup_git_repo(false) else {
+        return;
+    };
+
+    let result =
+        GitConfig::switch_user(GitConfigScope::Local, "jane@work.example.com", "work").unwrap();
+
+    assert!(result.before.is_empty());
+    assert_eq!(result.after, identity("work", "jane@work.example.com"));
+
+    let _ = std::env::set_current_dir(original_dir);
+}
+
+// ==================== 显示格式测试 ====================
+
+#[test]
+fn test_git_user_identity_display() {
+    assert_eq!(
+        identity("Jane Doe", "jane@example.com").to_string(),
+        "Jane Doe <jane@example.com>"
+    );
+    assert_eq!(GitUserIdentity::default().to_string(), "(not set)");
+}
+
+// ==================== 提交签名配置测试 ====================
+
+#[test]
+fn test_signing_format_parse() {
+    assert_eq!(GitSigningFormat::parse(None), GitSigningFormat::Gpg);
+    assert_eq!(
+        GitSigningFormat::parse(Some("openpgp")),
+        GitSigningFormat::Gpg
+    );
+    assert_eq!(
+        GitSigningFormat::parse(Some(" ssh ")),
+        GitSigningFormat::Ssh
+    );
+    assert_eq!(
+        GitSigningFormat::parse(Some("x509")),
+        GitSigningFormat::X509
+    );
+    assert_eq!(
+        GitSigningFormat::parse(Some("unknown")),
+        GitSigningFormat::Gpg
+    );
+}
+
+#[test]
+fn test_signing_config_issue() {
+    let ssh_without_key = GitSigningConfig {
+        format: GitSigningFormat::Ssh,
+        key: None,
+    };
+    assert!(ssh_without_key.issue(true).unwrap().contains("user.signingkey"));
+
+    let gpg_without_key = GitSigningConfig {
+        format: GitSigningFormat::Gpg,
+        key: None,
+    };
+    assert_eq!(gpg_without_key.issue(true), None);
+    assert_eq!(
+        gpg_without_key.issue(false).as_deref(),
+        Some("user.signingkey is not set and no gpg secret key was found")
+    );
+
+    let ssh_with_key = GitSigningConfig {
+        format: GitSigningFormat::Ssh,
+        key: Some("~/.ssh/id_ed25519.pub".to_string()),
+    };
+    assert_eq!(ssh_with_key.issue(false), None);
+}
+
+#[test]
+#[serial]
+fn test_get_signing_config_reads_local_config() {
+    let Some((_env, original_dir)) = setup_git_repo(false) else {
+        return;
+    };
+
+    for (key, value) in [
+        ("gpg.format", "ssh"),
+        ("user.signingkey", "~/.ssh/id_ed25519.pub"),
+    ] {
+        Command::new("git").args(["config", "--local", key, value]).status().unwrap();
+    }
+
+    assert_eq!(
+        GitConfig::get_signing_config(),
+        GitSigningConfig {
+            format: GitSigningFormat::Ssh,
+            key: Some("~/.ssh/id_ed25519.pub".to_string()),
+        }
+    );
+
+    let _ = std::env::set_current_dir(original_dir);
+}
+
+// ==================== 配置项读写测试 ====================
+
+#[rstest]
+#[case("user.name", "  Dev User ", "Dev User")]
+#[case("user.email", "dev@example.com", "dev@example.com")]
+#[case("user.signingkey", "~/.ssh/id_ed25519.pub", "~/.ssh/id_ed25519.pub")]
+#[case("gpg.format", "SSH", "ssh")]
+#[case("gpg.format", "gpg", "openpgp")]
+#[case("commit.gpgsign", "yes", "true")]
+#[case("commit.gpgsign", "0", "false")]
+fn test_validate_value_accepts_and_normalizes(
+    #[case] key: &str,
+    #[case] value: &str,
+    #[case] expected: &str,
+) {
+    assert_eq!(GitConfig::validate_value(key, value).unwrap(), expected);
+}
+
+#[rstest]
+#[case("user.name", "   ")]
+#[case("user.email", "not-an-email")]
+#[case("user.email", "dev @example.com")]
+#[case("user.signingkey", "")]
+#[case("gpg.format", "pgp")]
+#[case("commit.gpgsign", "maybe")]
+#[case("core.editor", "vim")]
+fn test_validate_value_rejects_invalid(#[case] key: &str, #[case] value: &str) {
+    assert!(GitConfig::validate_value(key, value).is_err());
+}
+
+#[test]
+fn test_validate_value_lists_supported_keys() {
+    let error = GitConfig::validate_value("core.editor", "vim").unwrap_err().to_string();
+    for key in MANAGED_GIT_CONFIG_KEYS {
+        assert!(error.contains(key), "error should mention {}", key);
+    }
+}
+
+#[test]
+#[serial]
+fn test_set_writes_normalized_local_value() {
+    let Some((_env, original_dir)) = setup_git_repo(false) else {
+        return;
+    };
+
+    assert_eq!(
+        GitConfig::set(GitConfigScope::Local, "commit.gpgsign", "on").unwrap(),
+        "true"
+    );
+    assert_eq!(
+        GitConfig::get(GitConfigScope::Local, "commit.gpgsign").as_deref(),
+        Some("true")
+    );
+    assert!(GitConfig::set(GitConfigScope::Local, "user.email", "invalid").is_err());
+    assert_eq!(GitConfig::get(GitConfigScope::Local, "user.email"), None);
+
+    let _ = std::env::set_current_dir(original_dir);
+}
@@ -3,6 +3,11 @@
 //! 包含 Git 模块的所有测试文件。
 
 pub mod branch; // Git 分支管理测试 - 已启用！
+pub mod branch_sync; // 分支同步计划（dry-run）测试
+pub mod cherry_pick; // Cherry-pick 已应用提交检测测试
 pub mod commit; // Git 提交管理测试 - 已启用！
+pub mod config; // Git 配置管理测试
 pub mod repo; // Git 仓库检测和类型识别测试 - 已启用！
+pub mod semver; // 语义化版本 tag 测试
+pub mod tag; // Git Tag 管理测试
 pub mod types; // Git 数据类型测试 - 已启用！
@@ -2,14 +2,14 @@
 //!
 //! 测试 GitRepo 模块的核心功能，包括：
 //! - URL 解析和仓库名提取
-//! - 仓库类型检测
+//! - 仓库类型检测（包括 GitHub Enterprise Server 主机）
 //! - 错误处理和边界情况
 //! - 与现有 mock 实现的一致性验证
 
 use pretty_assertions::assert_eq;
 use rstest::rstest;
 
-use workflow::git::{GitRepo, RepoType};
+use workflow::git::{github, GitRepo, RepoType};
 
 // ==================== URL 解析和仓库名提取测试 ====================
 
@@ -200,6 +200,29 @@ fn test_parse_repo_type_consistency_with_mock(#[case] url: &str, #[case] expecte
     // 在实际项目中可能需要将其改为 pub(crate) 或添加测试辅助方法
 }
 
+#[rstest]
+#[case(
+    "https://org@dev.azure.com/org/project/_git/repo",
+    RepoType::AzureDevOps
+)]
+#[case("git@ssh.dev.azure.com:v3/org/project/repo", RepoType::AzureDevOps)]
+#[case(
+    "https://org.visualstudio.com/project/_git/repo",
+    RepoType::AzureDevOps
+)]
+#[case(
+    "org@vs-ssh.visualstudio.com:v3/org/project/repo",
+    RepoType::AzureDevOps
+)]
+#[case("git@github.com:owner/repo.git", RepoType::GitHub)]
+#[case("https://gitlab.com/owner/repo", RepoType::Unknown)]
+fn test_parse_repo_type_from_url_detects_azure_devops(
+    #[case] url: &str,
+    #[case] expected: RepoType,
+) {
+    assert_eq!(GitRepo::parse_repo_type_from_url(url), expected);
+}
+
 #[test]
 fn test_github_ssh_alias_detection() {
     // 测试 GitHub SSH 别名的检测
@@ -258,6 +281,56 @@ fn test_case_sensitive_detection() {
     }
 }
 
+// ==================== GitHub Enterprise Server 测试 ====================
+
+#[rstest]
+#[case("git@github.mycorp.com:owner/repo.git", Some("owner/repo"))]
+#[case("https://github.mycorp.com/owner/repo.git", Some("owner/repo"))]
+#[case("https://github.mycorp.com:8443/owner/repo/", Some("owner/repo"))]
+#[case("ssh://git@github.mycorp.com:2222/owner/repo.git", Some("owner/repo"))]
+#[case("https://GitHub.MyCorp.com/owner/repo", Some("owner/repo"))]
+#[case("git@github.com:owner/repo.git", None)]
+#[case("https://github.mycorp.com.evil.io/owner/repo", None)]
+fn test_extract_enterprise_repo(#[case] url: &str, #[case] expected: Option<&str>) {
+    assert_eq!(
+        github::extract_enterprise_repo(url, "github.mycorp.com").as_deref(),
+        expected
+    );
+}
+
+#[rstest]
+#[case(
+    "git@github.mycorp.com:owner/repo.git",
+    Some("github.mycorp.com"),
+    RepoType::GitHub
+)]
+#[case(
+    "https://git.mycorp.com/owner/repo.git",
+    Some("git.mycorp.com"),
+    RepoType::GitHub
+)]
+#[case("https://git.mycorp.com/owner/repo.git", None, RepoType::Unknown)]
+#[case(
+    "git@github.com:owner/repo.git",
+    Some("git.mycorp.com"),
+    RepoType::GitHub
+)]
+#[case(
+    "https://dev.azure.com/org/project/_git/repo",
+    Some("git.mycorp.com"),
+    RepoType::AzureDevOps
+)]
+fn test_parse_repo_type_from_url_with_github_host(
+    #[case] url: &str,
+    #[case] enterprise_host: Option<&str>,
+    #[case] expected: RepoType,
+) {
+    assert_eq!(
+        GitRepo::parse_repo_type_from_url_with_github_host(url, enterprise_host),
+        expected
+    );
+}
+
 // ==================== 边界情况和错误处理测试 ====================
 
 #[test]
@@ -474,3 +547,52 @@ fn test_regression_known_issues() {
         assert_eq!(result.unwrap(), expected);
     }
 }
+
+// ==================== PR 模板查找测试 ====================
+
+#[test]
+fn test_find_pull_request_templates_in_empty_repo() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(GitRepo::find_pull_request_templates_in(dir.path()).is_empty());
+}
+
+#[test]
+fn test_find_pull_request_templates_in_priority_order() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    std::fs::create_dir_all(root.join(".github")).unwrap();
+    std::fs::create_dir_all(root.join("docs")).unwrap();
+    std::fs::write(root.join("docs/pull_request_template.md"), "docs").unwrap();
+    std::fs::write(root.join("PULL_REQUEST_TEMPLATE.md"), "root").unwrap();
+    std::fs::write(root.join(".github/PULL_REQUEST_TEMPLATE.md"), "github").unwrap();
+
+    let templates = GitRepo::find_pull_request_templates_in(root);
+    assert_eq!(
+        templates,
+        vec![
+            root.join(".github/PULL_REQUEST_TEMPLATE.md"),
+            root.join("PULL_REQUEST_TEMPLATE.md"),
+            root.join("docs/pull_request_template.md"),
+        ]
+    );
+}
+
+#[test]
+fn test_find_pull_request_templates_in_template_directory() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = dir.path();
+    let template_dir = root.join(".github/PULL_REQUEST_TEMPLATE");
+    std::fs::create_dir_all(&template_dir).unwrap();
+    std::fs::write(template_dir.join("feature.md"), "feature").unwrap();
+    std::fs::write(template_dir.join("bugfix.md"), "bugfix").unwrap();
+    std::fs::write(template_dir.join("README.txt"), "not a template").unwrap();
+
+    let templates = GitRepo::find_pull_request_templates_in(root);
+    assert_eq!(
+        templates,
+        vec![
+            template_dir.join("bugfix.md"),
+            template_dir.join("feature.md")
+        ]
+    );
+}
@@ -0,0 +1,150 @@
+//! 语义化版本 tag 测试
+//!
+//! 测试 `SemVer` 的 tag 解析、排序和下一个版本的计算。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use workflow::git::{PreRelease, SemVer, VersionBump};
+
+// ==================== Helper Functions ====================
+
+/// 将 tag 列表解析为版本（忽略不符合格式的 tag）
+fn parse_versions(tags: &[&str], prefix: &str) -> Vec<SemVer> {
+    tags.iter().filter_map(|tag| SemVer::parse_tag(tag, prefix)).collect()
+}
+
+// ==================== 解析测试 ====================
+
+#[rstest]
+#[case("v1.2.3", "v", Some((1, 2, 3, None)))]
+#[case("v0.10.0", "v", Some((0, 10, 0, None)))]
+#[case("v1.3.0-rc.2", "v", Some((1, 3, 0, Some(("rc", 2)))))]
+#[case("v2.0.0-beta", "v", Some((2, 0, 0, Some(("beta", 0)))))]
+#[case("release-1.0.0", "release-", Some((1, 0, 0, None)))]
+#[case("1.0.0", "", Some((1, 0, 0, None)))]
+#[case("1.2.3", "v", None)]
+#[case("v1.2", "v", None)]
+#[case("v1.2.3.4", "v", None)]
+#[case("v01.2.3", "v", None)]
+#[case("v1.2.3-", "v", None)]
+#[case("v1.2.3-rc.x", "v", None)]
+#[case("vnext", "v", None)]
+fn test_parse_tag(
+    #[case] tag: &str,
+    #[case] prefix: &str,
+    #[case] expected: Option<(u64, u64, u64, Option<(&str, u64)>)>,
+) {
+    let expected = expected.map(|(major, minor, patch, pre)| SemVer {
+        major,
+        minor,
+        patch,
+        pre: pre.map(|(channel, number)| PreRelease {
+            channel: channel.to_string(),
+            number,
+        }),
+    });
+
+    assert_eq!(SemVer::parse_tag(tag, prefix), expected);
+}
+
+#[test]
+fn test_to_tag() {
+    let version = SemVer::parse_tag("v1.3.0-rc.2", "v").unwrap();
+
+    assert_eq!(version.to_tag("v"), "v1.3.0-rc.2");
+    assert_eq!(SemVer::new(1, 0, 0).to_tag(""), "1.0.0");
+}
+
+// ==================== 排序测试 ====================
+
+#[test]
+fn test_ordering_pre_release_before_release() {
+    let mut versions = parse_versions(
+        &[
+            "v1.10.0",
+            "v1.3.0",
+            "v1.3.0-rc.10",
+            "v1.3.0-rc.2",
+            "v1.3.0-beta.5",
+            "v1.2.9",
+        ],
+        "v",
+    );
+    versions.sort();
+
+    let tags: Vec<String> = versions.iter().map(|version| version.to_tag("v")).collect();
+    assert_eq!(
+        tags,
+        vec![
+            "v1.2.9",
+            "v1.3.0-beta.5",
+            "v1.3.0-rc.2",
+            "v1.3.0-rc.10",
+            "v1.3.0",
+            "v1.10.0"
+        ]
+    );
+}
+
+#[test]
+fn test_latest_release_ignores_pre_releases() {
+    let versions = parse_versions(&["v1.2.3", "v2.0.0-rc.1", "v1.10.0"], "v");
+
+    assert_eq!(
+        SemVer::latest_release(&versions),
+        Some(&SemVer::new(1, 10, 0))
+    );
+    assert_eq!(
+        SemVer::latest(&versions).map(|version| version.to_tag("v")),
+        Some("v2.0.0-rc.1".to_string())
+    );
+}
+
+// ==================== 下一个版本测试 ====================
+
+#[rstest]
+#[case(&["v1.2.3"], VersionBump::Patch, None, "1.2.4")]
+#[case(&["v1.2.3"], VersionBump::Minor, None, "1.3.0")]
+#[case(&["v1.2.3"], VersionBump::Major, None, "2.0.0")]
+#[case(&[], VersionBump::Minor, None, "0.1.0")]
+#[case(&["v1.2.3", "other-tag", "v1.3"], VersionBump::Patch, None, "1.2.4")]
+#[case(&["v1.2.3", "v1.3.0-rc.2"], VersionBump::Minor, None, "1.3.0")]
+#[case(&["v1.2.3"], VersionBump::Minor, Some("rc"), "1.3.0-rc.1")]
+#[case(&["v1.2.3", "v1.3.0-rc.1", "v1.3.0-rc.2"], VersionBump::Minor, Some("rc"), "1.3.0-rc.3")]
+#[case(&["v1.2.3", "v1.3.0-rc.2"], VersionBump::Minor, Some("beta"), "1.3.0-beta.1")]
+#[case(&["v1.2.3", "v1.3.0-rc.2"], VersionBump::Major, Some("rc"), "2.0.0-rc.1")]
+fn test_next_version(
+    #[case] tags: &[&str],
+    #[case] bump: VersionBump,
+    #[case] channel: Option<&str>,
+    #[case] expected: &str,
+) {
+    let versions = parse_versions(tags, "v");
+
+    assert_eq!(SemVer::next(&versions, bump, channel).to_string(), expected);
+}
+
+#[test]
+fn test_next_version_respects_prefix() {
+    // 其他前缀的 tag 不参与计算
+    let versions = parse_versions(&["v9.0.0", "release-1.4.0"], "release-");
+
+    assert_eq!(
+        SemVer::next(&versions, VersionBump::Patch, None).to_tag("release-"),
+        "release-1.4.1"
+    );
+}
+
+// ==================== 预发布通道测试 ====================
+
+#[rstest]
+#[case("rc", true)]
+#[case("beta-2", true)]
+#[case("", false)]
+#[case("1", false)]
+#[case("rc.1", false)]
+#[case("rc/1", false)]
+fn test_is_valid_channel(#[case] channel: &str, #[case] expected: bool) {
+    assert_eq!(SemVer::is_valid_channel(channel), expected);
+    assert_eq!(SemVer::validate_channel(channel).is_ok(), expected);
+}
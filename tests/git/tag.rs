@@ -0,0 +1,162 @@
+//! Git Tag 管理测试
+//!
+//! 测试 `GitTag` 的通配符筛选、tag 名称校验，以及在临时仓库中创建和读取附注 tag。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use serial_test::serial;
+use std::path::PathBuf;
+use std::process::Command;
+use workflow::git::{GitTag, TagInfo, TagRow};
+
+use crate::common::cli_helpers::CliTestEnv;
+use crate::common::git_helpers::{git, setup_git_repo};
+
+// ==================== Helper Functions ====================
+
+/// 创建包含一个提交的临时 Git 仓库并切换到该目录，返回测试环境和原始目录
+///
+/// 附注 tag 需要提交者身份，身份只写入临时仓库的 local 配置。
+fn setup_tag_repo() -> Option<(CliTestEnv, PathBuf)> {
+    let (env, original_dir) = setup_git_repo(true)?;
+    git(env.path(), &["commit", "-q", "--allow-empty", "-m", "init"]);
+    Some((env, original_dir))
+}
+
+fn tag(name: &str) -> TagInfo {
+    TagInfo {
+        name: name.to_string(),
+        commit_hash: "0123456789abcdef".to_string(),
+        exists_local: true,
+        exists_remote: false,
+        annotated: false,
+        message: None,
+    }
+}
+
+fn names(tags: Vec<TagInfo>) -> Vec<String> {
+    tags.into_iter().map(|tag| tag.name).collect()
+}
+
+// ==================== 模式筛选测试 ====================
+
+#[rstest]
+#[case("v1.*", vec!["v1.0.0", "v1.10.0"])]
+#[case("v?.0.0", vec!["v1.0.0", "v2.0.0"])]
+#[case("*-rc*", vec!["v2.0.0-rc1"])]
+#[case("v1.0.0", vec!["v1.0.0"])]
+#[case("release/*", vec![])]
+fn test_filter_by_pattern(#[case] pattern: &str, #[case] expected: Vec<&str>) {
+    let tags = vec![
+        tag("v1.0.0"),
+        tag("v1.10.0"),
+        tag("v1x0"),
+        tag("v2.0.0"),
+        tag("v2.0.0-rc1"),
+    ];
+
+    let matched = GitTag::filter_by_pattern(&tags, pattern).unwrap();
+
+    assert_eq!(names(matched), expected);
+}
+
+#[test]
+fn test_filter_by_pattern_escapes_regex_characters() {
+    let tags = vec![tag("v1+build"), tag("v11build")];
+
+    let matched = GitTag::filter_by_pattern(&tags, "v1+build").unwrap();
+
+    assert_eq!(names(matched), vec!["v1+build"]);
+}
+
+// ==================== 表格行测试 ====================
+
+#[test]
+fn test_tag_row_from_tag_info() {
+    let info = TagInfo {
+        annotated: true,
+        exists_remote: true,
+        message: Some("Release 1.0.0".to_string()),
+        ..tag("v1.0.0")
+    };
+
+    let row = TagRow::from(&info);
+
+    assert_eq!(row.name, "v1.0.0");
+    assert_eq!(row.commit, "01234567");
+    assert_eq!(row.kind, "annotated");
+    assert_eq!(row.local, "✓");
+    assert_eq!(row.remote, "✓");
+    assert_eq!(row.message, "Release 1.0.0");
+}
+
+// ==================== 创建和读取测试 ====================
+
+#[test]
+#[serial]
+fn test_is_valid_name() {
+    let Some((_env, original_dir)) = setup_tag_repo() else {
+        return;
+    };
+
+    assert!(GitTag::is_valid_name("v1.0.0"));
+    assert!(GitTag::is_valid_name("release/2024-01"));
+    assert!(!GitTag::is_valid_name("bad..name"));
+    assert!(!GitTag::is_valid_name("has space"));
+    assert!(!GitTag::is_valid_name("-v1"));
+    assert!(!GitTag::is_valid_name(""));
+
+    std::env::set_current_dir(original_dir).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_create_annotated_tag() {
+    let Some((_env, original_dir)) = setup_tag_repo() else {
+        return;
+    };
+    let head = Command::new("git").args(["rev-parse", "HEAD"]).output().unwrap();
+    let head = String::from_utf8_lossy(&head.stdout).trim().to_string();
+
+    GitTag::create("v1.0.0", "Release 1.0.0", None).unwrap();
+    Command::new("git").args(["tag", "light"]).status().unwrap();
+
+    let tags = GitTag::list_tags(false).unwrap();
+    assert_eq!(names(tags.clone()), vec!["light", "v1.0.0"]);
+
+    let annotated = &tags[1];
+    // 附注 tag 解析到指向的 commit，而不是 tag 对象
+    assert_eq!(annotated.commit_hash, head);
+    assert!(annotated.annotated);
+    assert!(annotated.exists_local);
+    assert!(!annotated.exists_remote);
+    assert_eq!(annotated.message.as_deref(), Some("Release 1.0.0"));
+
+    let light = &tags[0];
+    assert_eq!(light.commit_hash, head);
+    assert!(!light.annotated);
+    assert_eq!(light.message, None);
+
+    let info = GitTag::get_tag_info("v1.0.0").unwrap();
+    assert_eq!(info.commit_hash, head);
+    assert!(info.annotated);
+
+    std::env::set_current_dir(original_dir).unwrap();
+}
+
+#[test]
+#[serial]
+fn test_create_rejects_existing_or_invalid_tag() {
+    let Some((_env, original_dir)) = setup_tag_repo() else {
+        return;
+    };
+
+    GitTag::create("v1.0.0", "Release 1.0.0", None).unwrap();
+    let existing = GitTag::create("v1.0.0", "Again", None).unwrap_err();
+    assert!(existing.to_string().contains("already exists"));
+
+    let invalid = GitTag::create("bad..name", "Invalid", None).unwrap_err();
+    assert!(invalid.to_string().contains("Invalid tag name"));
+
+    std::env::set_current_dir(original_dir).unwrap();
+}
@@ -3,7 +3,11 @@
 //! 使用 mockito 测试 GitHub API 的实际 HTTP 调用。
 
 use crate::common::http_helpers::MockServer;
-use mockito::Matcher;
+use mockito::{Matcher, Mock};
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use workflow::base::http::{HttpClient, RequestConfig};
+use workflow::pr::github::pagination::for_each_page;
 
 /// 设置测试环境
 ///
@@ -107,3 +111,159 @@ fn test_get_pull_request_info() {
 
     // _mock.assert();
 }
+
+// ==================== 分页测试 ====================
+
+const PULLS_PATH: &str = "/repos/owner/repo/pulls";
+
+/// 创建一页 PR 列表的 Mock（`next_page` 为下一页的页码）
+fn mock_pulls_page(
+    mock_server: &mut MockServer,
+    query: &str,
+    numbers: &[u64],
+    next_page: Option<u32>,
+) -> Mock {
+    let body: Vec<Value> = numbers
+        .iter()
+        .map(|number| serde_json::json!({ "number": number, "state": "open" }))
+        .collect();
+    let mut mock = mock_server
+        .server
+        .as_mut()
+        .mock("GET", PULLS_PATH)
+        .match_query(Matcher::Exact(query.to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(serde_json::to_string(&body).unwrap());
+    if let Some(page) = next_page {
+        mock = mock.with_header(
+            "link",
+            &format!(
+                r#"<{base}{path}?state=open&per_page=2&page={page}>; rel="next", <{base}{path}?state=open&per_page=2&page=1>; rel="first""#,
+                base = mock_server.base_url,
+                path = PULLS_PATH,
+            ),
+        );
+    }
+    mock
+}
+
+/// 沿着 Link 头翻页，返回请求的页数和每页的 PR 编号
+fn fetch_pull_numbers(
+    base_url: &str,
+    max_pages: usize,
+    mut keep_going: impl FnMut(usize) -> bool,
+) -> (color_eyre::Result<usize>, Vec<Vec<u64>>) {
+    let client = HttpClient::global().unwrap();
+    let url = format!("{}{}", base_url, PULLS_PATH);
+    let query = [("state", "open"), ("per_page", "2")];
+    let mut pages: Vec<Vec<u64>> = Vec::new();
+
+    let result = for_each_page(
+        max_pages,
+        |next_url| match next_url {
+            None => client.get(&url, RequestConfig::<Value, _>::new().query(&query)),
+            Some(next_url) => client.get(next_url, RequestConfig::<Value, Value>::new()),
+        },
+        |items: Vec<Value>| {
+            pages.push(items.iter().filter_map(|item| item["number"].as_u64()).collect());
+            Ok(keep_going(pages.len()))
+        },
+    );
+    (result, pages)
+}
+
+#[test]
+fn test_pagination_follows_next_link() {
+    let mut mock_server = MockServer::new();
+    let page1 = mock_pulls_page(&mut mock_server, "state=open&per_page=2", &[1, 2], Some(2))
+        .expect(1)
+        .create();
+    // 下一页地址保留第一页的状态筛选条件
+    let page2 = mock_pulls_page(
+        &mut mock_server,
+        "state=open&per_page=2&page=2",
+        &[3, 4],
+        Some(3),
+    )
+    .expect(1)
+    .create();
+    let page3 = mock_pulls_page(&mut mock_server, "state=open&per_page=2&page=3", &[5], None)
+        .expect(1)
+        .create();
+
+    let (result, pages) = fetch_pull_numbers(&mock_server.base_url, 10, |_| true);
+
+    assert_eq!(result.unwrap(), 3);
+    assert_eq!(pages, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    page1.assert();
+    page2.assert();
+    page3.assert();
+}
+
+#[test]
+fn test_pagination_stops_when_limit_reached() {
+    let mut mock_server = MockServer::new();
+    let page1 = mock_pulls_page(&mut mock_server, "state=open&per_page=2", &[1, 2], Some(2))
+        .expect(1)
+        .create();
+    let page2 = mock_pulls_page(
+        &mut mock_server,
+        "state=open&per_page=2&page=2",
+        &[3, 4],
+        None,
+    )
+    .expect(0)
+    .create();
+
+    // 第一页处理后返回 false（已达到数量限制），不再请求下一页
+    let (result, pages) = fetch_pull_numbers(&mock_server.base_url, 10, |_| false);
+
+    assert_eq!(result.unwrap(), 1);
+    assert_eq!(pages, vec![vec![1, 2]]);
+    page1.assert();
+    page2.assert();
+}
+
+#[test]
+fn test_pagination_respects_max_pages() {
+    let mut mock_server = MockServer::new();
+    let _page1 =
+        mock_pulls_page(&mut mock_server, "state=open&per_page=2", &[1, 2], Some(2)).create();
+    let _page2 = mock_pulls_page(
+        &mut mock_server,
+        "state=open&per_page=2&page=2",
+        &[3, 4],
+        Some(3),
+    )
+    .create();
+    let page3 = mock_pulls_page(&mut mock_server, "state=open&per_page=2&page=3", &[5], None)
+        .expect(0)
+        .create();
+
+    let (result, pages) = fetch_pull_numbers(&mock_server.base_url, 2, |_| true);
+
+    assert_eq!(result.unwrap(), 2);
+    assert_eq!(pages, vec![vec![1, 2], vec![3, 4]]);
+    page3.assert();
+}
+
+#[test]
+fn test_pagination_error_keeps_previous_pages() {
+    let mut mock_server = MockServer::new();
+    let _page1 =
+        mock_pulls_page(&mut mock_server, "state=open&per_page=2", &[1, 2], Some(2)).create();
+    let _page2 = mock_server
+        .server
+        .as_mut()
+        .mock("GET", PULLS_PATH)
+        .match_query(Matcher::Exact("state=open&per_page=2&page=2".to_string()))
+        .with_status(500)
+        .with_body(r#"{"message":"Server Error"}"#)
+        .create();
+
+    let (result, pages) = fetch_pull_numbers(&mock_server.base_url, 10, |_| true);
+
+    assert!(result.is_err());
+    assert_eq!(pages, vec![vec![1, 2]]);
+}
@@ -6,6 +6,7 @@
 
 // 引入各个模块的测试
 mod base;
+mod cache;
 mod cli;
 mod commit;
 mod completion;
@@ -13,6 +14,7 @@ mod git;
 mod http;
 mod integration;
 mod jira;
+mod migrate;
 mod pr;
 mod proxy;
 mod rollback;
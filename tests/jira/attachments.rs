@@ -0,0 +1,103 @@
+//! Jira 附件下载模块测试
+//!
+//! 测试下载进度消息的生成与解析、进度显示的文件计数和合计字节数，以及续传临时文件路径。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use std::path::{Path, PathBuf};
+
+use workflow::jira::attachments::{
+    AttachmentDownloader, DownloadProgress, DownloadProgressDisplay, DOWNLOADED_MESSAGE_PREFIX,
+    FAILED_MESSAGE_PREFIX,
+};
+
+// ==================== DownloadProgress 测试 ====================
+
+#[rstest]
+#[case("log.zip", 0, Some(1024), "Progress: log.zip (0/1024)")]
+#[case("log.zip", 512, None, "Progress: log.zip (512/?)")]
+#[case("crash (1).log", 10, Some(20), "Progress: crash (1).log (10/20)")]
+fn test_download_progress_message_round_trip(
+    #[case] file: &str,
+    #[case] downloaded: u64,
+    #[case] total: Option<u64>,
+    #[case] expected: &str,
+) {
+    let progress = DownloadProgress {
+        file: file.to_string(),
+        downloaded,
+        total,
+    };
+
+    assert_eq!(progress.message(), expected);
+    assert_eq!(DownloadProgress::parse(expected), Some(progress));
+}
+
+#[rstest]
+#[case("Downloaded: log.zip")]
+#[case("Failed to download: log.zip")]
+#[case("Progress: log.zip")]
+#[case("Progress: log.zip (abc/100)")]
+#[case("Progress: log.zip (10/abc)")]
+fn test_download_progress_parse_invalid(#[case] message: &str) {
+    assert_eq!(DownloadProgress::parse(message), None);
+}
+
+// ==================== DownloadProgressDisplay 测试 ====================
+
+#[test]
+fn test_download_progress_display_counts_finished_files() {
+    let display = DownloadProgressDisplay::new(3, "Downloading...");
+    let callback = display.callback();
+
+    callback(&format!("{}a.log", DOWNLOADED_MESSAGE_PREFIX));
+    callback(&format!("{}b.log - timeout", FAILED_MESSAGE_PREFIX));
+    callback("Some other message");
+
+    assert_eq!(display.finished_files(), 2);
+    display.finish();
+}
+
+#[test]
+fn test_download_progress_display_ignores_progress_for_count() {
+    let display = DownloadProgressDisplay::new(1, "Downloading...");
+    let callback = display.callback();
+
+    callback("Progress: a.log (0/100)");
+    callback("Progress: a.log (100/100)");
+    assert_eq!(display.finished_files(), 0);
+
+    callback(&format!("{}a.log", DOWNLOADED_MESSAGE_PREFIX));
+    assert_eq!(display.finished_files(), 1);
+    display.finish();
+}
+
+#[test]
+fn test_download_progress_display_aggregates_concurrent_downloads() {
+    let display = DownloadProgressDisplay::new(3, "Downloading...");
+    let callback = display.callback();
+
+    callback("Progress: a.log (100/400)");
+    callback("Progress: b.log (50/?)");
+    callback("Progress: c.log (0/200)");
+    assert_eq!(display.downloaded_bytes(), 150);
+
+    callback("Progress: a.log (400/400)");
+    callback(&format!("{}a.log", DOWNLOADED_MESSAGE_PREFIX));
+    callback(&format!("{}b.log - timeout", FAILED_MESSAGE_PREFIX));
+    callback("Progress: c.log (200/200)");
+
+    assert_eq!(display.downloaded_bytes(), 650);
+    assert_eq!(display.finished_files(), 2);
+    display.finish();
+}
+
+// ==================== 续传临时文件测试 ====================
+
+#[test]
+fn test_part_path_appends_suffix() {
+    assert_eq!(
+        AttachmentDownloader::part_path(Path::new("/tmp/downloads/log.zip")),
+        PathBuf::from("/tmp/downloads/log.zip.part")
+    );
+}
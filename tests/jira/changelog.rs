@@ -0,0 +1,203 @@
+//! Jira 变更时间线测试
+//!
+//! 测试从 changelog 数据生成时间线（字段过滤、时间排序、操作人）、Markdown 输出和变更历史分页解析。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use serde_json::json;
+use workflow::commands::jira::ChangelogCommand;
+use workflow::jira::{ChangelogEvent, ChangelogPage, JiraChangelog};
+
+// ==================== Helper Functions ====================
+
+/// 创建测试用的 changelog（故意打乱时间顺序）
+fn sample_changelog() -> JiraChangelog {
+    serde_json::from_value(serde_json::json!({
+        "id": "10001",
+        "histories": [
+            {
+                "id": "2",
+                "created": "2024-01-16T09:00:00.000+0000",
+                "author": {
+                    "accountId": "acc-2",
+                    "displayName": "Bob",
+                    "emailAddress": "bob@example.com"
+                },
+                "items": [
+                    {
+                        "field": "status",
+                        "fieldId": "status",
+                        "from": "3",
+                        "fromString": "In Progress",
+                        "to": "10001",
+                        "toString": "Done"
+                    }
+                ]
+            },
+            {
+                "id": "1",
+                "created": "2024-01-15T10:30:00.000+0000",
+                "author": {
+                    "accountId": "acc-1",
+                    "displayName": "Alice"
+                },
+                "items": [
+                    {
+                        "field": "status",
+                        "fieldId": "status",
+                        "fromString": "To Do",
+                        "toString": "In Progress"
+                    },
+                    {
+                        "field": "assignee",
+                        "fieldId": "assignee",
+                        "toString": "Alice"
+                    }
+                ]
+            },
+            {
+                "id": "3",
+                "created": "2024-01-17T12:00:00.000+0000",
+                "items": [
+                    {
+                        "field": "labels",
+                        "fromString": "",
+                        "toString": "release"
+                    }
+                ]
+            }
+        ]
+    }))
+    .unwrap()
+}
+
+// ==================== 时间线测试 ====================
+
+#[test]
+fn test_changelog_timeline_status_transitions_sorted() {
+    let timeline = sample_changelog().timeline(&["status".to_string()]);
+
+    assert_eq!(
+        timeline,
+        vec![
+            ChangelogEvent {
+                created: "2024-01-15T10:30:00.000+0000".to_string(),
+                actor: Some("Alice".to_string()),
+                field: "status".to_string(),
+                from: Some("To Do".to_string()),
+                to: Some("In Progress".to_string()),
+            },
+            ChangelogEvent {
+                created: "2024-01-16T09:00:00.000+0000".to_string(),
+                actor: Some("Bob".to_string()),
+                field: "status".to_string(),
+                from: Some("In Progress".to_string()),
+                to: Some("Done".to_string()),
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_changelog_timeline_multiple_fields_case_insensitive() {
+    let timeline = sample_changelog().timeline(&["Status".to_string(), "ASSIGNEE".to_string()]);
+
+    let fields: Vec<&str> = timeline.iter().map(|e| e.field.as_str()).collect();
+    assert_eq!(fields, vec!["status", "assignee", "status"]);
+    // 没有 fromString 时变更前的值为空
+    assert_eq!(timeline[1].from, None);
+}
+
+#[test]
+fn test_changelog_timeline_all_fields_and_unknown_actor() {
+    let timeline = sample_changelog().timeline(&[]);
+
+    assert_eq!(timeline.len(), 4);
+    let last = timeline.last().unwrap();
+    assert_eq!(last.field, "labels");
+    assert_eq!(last.actor, None);
+}
+
+// ==================== Markdown 输出测试 ====================
+
+#[test]
+fn test_changelog_markdown_escapes_pipes_and_newlines() {
+    let timeline = vec![ChangelogEvent {
+        created: "not a date".to_string(),
+        actor: Some("Alice".to_string()),
+        field: "summary".to_string(),
+        from: Some("Fix login | logout".to_string()),
+        to: Some("Fix login\nand logout".to_string()),
+    }];
+
+    let markdown = ChangelogCommand::render_markdown(&timeline, &["summary".to_string()]).unwrap();
+
+    assert_eq!(
+        markdown,
+        "# Changelog\n\n\
+         | Time | Actor | Field | Change |\n\
+         | --- | --- | --- | --- |\n\
+         | not a date | Alice | summary | Fix login \\| logout → Fix login<br>and logout |"
+    );
+}
+
+#[test]
+fn test_changelog_markdown_without_changes() {
+    let markdown = ChangelogCommand::render_markdown(&[], &["status".to_string()]).unwrap();
+    assert_eq!(markdown, "# Changelog\n\nNo changes to status found.\n");
+}
+
+// ==================== 分页测试 ====================
+
+/// 创建只包含 ID 和时间的变更历史记录
+fn history(id: usize) -> serde_json::Value {
+    json!({
+        "id": id.to_string(),
+        "created": "2024-01-15T10:30:00.000+0000",
+        "items": [{ "field": "status", "fromString": "To Do", "toString": "Done" }]
+    })
+}
+
+#[test]
+fn test_changelog_page_from_expand_changelog() {
+    // `expand=changelog` 返回的 changelog 对象使用 histories
+    let page = ChangelogPage::from_value(&json!({
+        "startAt": 0,
+        "maxResults": 2,
+        "total": 5,
+        "histories": [history(1), history(2)]
+    }));
+
+    assert_eq!(page.histories.len(), 2);
+    assert_eq!(
+        page.histories[0].items[0].to_string.as_deref(),
+        Some("Done")
+    );
+    assert_eq!(page.next_start(), Some(2));
+}
+
+#[test]
+fn test_changelog_page_skips_invalid_histories() {
+    // 缺少时间的记录被忽略
+    let page = ChangelogPage::from_value(&json!({
+        "values": [history(1), { "id": "2", "items": [] }],
+        "isLast": true
+    }));
+
+    assert_eq!(page.histories.len(), 1);
+    assert_eq!(page.next_start(), None);
+}
+
+#[rstest]
+#[case(json!({ "startAt": 100, "total": 250, "values": [history(1)] }), Some(101))]
+#[case(json!({ "startAt": 100, "total": 101, "values": [history(1)] }), None)]
+#[case(json!({ "startAt": 0, "isLast": false, "values": [history(1)] }), Some(1))]
+#[case(json!({ "startAt": 0, "isLast": true, "total": 250, "values": [history(1)] }), None)]
+#[case(json!({ "startAt": 0, "total": 250, "values": [] }), None)]
+#[case(json!({ "values": [history(1)] }), None)]
+fn test_changelog_page_next_start(
+    #[case] value: serde_json::Value,
+    #[case] expected: Option<usize>,
+) {
+    assert_eq!(ChangelogPage::from_value(&value).next_start(), expected);
+}
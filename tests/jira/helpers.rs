@@ -0,0 +1,58 @@
+//! Jira 辅助函数测试
+//!
+//! 测试从 Jira URL 和分支名提取 ticket ID。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use workflow::jira::helpers::{extract_jira_ticket_from_branch, extract_jira_ticket_from_url};
+
+// ==================== extract_jira_ticket_from_url 测试 ====================
+
+#[rstest]
+#[case("https://jira.example.com/browse/PROJ-123", Some("PROJ-123"))]
+#[case("  https://jira.example.com/browse/PROJ-123\n", Some("PROJ-123"))]
+#[case(
+    "https://jira.example.com/browse/PROJ-123?focusedCommentId=1",
+    Some("PROJ-123")
+)]
+#[case("http://jira.internal/browse/AB_2-7#comment", Some("AB_2-7"))]
+#[case(
+    "https://example.atlassian.net/jira/software/projects/PROJ/boards/1?selectedIssue=PROJ-45",
+    Some("PROJ-45")
+)]
+#[case(
+    "https://example.atlassian.net/jira/software/c/projects/PROJ/boards/1?quickFilter=2&selectedIssue=PROJ-9",
+    Some("PROJ-9")
+)]
+#[case("PROJ-123", None)]
+#[case("https://github.com/owner/repo/pull/123", None)]
+#[case("https://jira.example.com/browse/proj-123", None)]
+#[case("see https://jira.example.com/browse/PROJ-123", None)]
+fn test_extract_jira_ticket_from_url(#[case] url: &str, #[case] expected: Option<&str>) {
+    assert_eq!(
+        extract_jira_ticket_from_url(url),
+        expected.map(str::to_string)
+    );
+}
+
+// ==================== extract_jira_ticket_from_branch 测试 ====================
+
+#[rstest]
+#[case("PROJ-123", Some("PROJ-123"))]
+#[case("PROJ-123-fix-login", Some("PROJ-123"))]
+#[case("feature/PROJ-123-fix-login", Some("PROJ-123"))]
+#[case("bugfix/PROJ-45", Some("PROJ-45"))]
+#[case("zw/PROJ-123_fix", Some("PROJ-123"))]
+#[case("feature/zw/ABC-9-add-cache", Some("ABC-9"))]
+#[case("  hotfix/PROJ-7-crash\n", Some("PROJ-7"))]
+#[case("main", None)]
+#[case("feature/update-deps", None)]
+#[case("feature/proj-123-lowercase", None)]
+#[case("feature/fix-PROJ-123", None)]
+#[case("release/2024-01", None)]
+fn test_extract_jira_ticket_from_branch(#[case] branch: &str, #[case] expected: Option<&str>) {
+    assert_eq!(
+        extract_jira_ticket_from_branch(branch),
+        expected.map(str::to_string)
+    );
+}
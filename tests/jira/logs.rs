@@ -1,13 +1,15 @@
 //! Jira 日志模块测试
 //!
-//! 测试 Jira 日志下载、搜索、清理和路径处理功能。
+//! 测试 Jira 日志下载、搜索、过滤、跟踪、清理和路径处理功能。
 
 use crate::common::helpers::{cleanup_temp_test_dir, create_temp_test_dir, create_test_file};
 use pretty_assertions::assert_eq;
 use rstest::{fixture, rstest};
 use std::fs;
-use workflow::jira::attachments::AttachmentCleaner;
-use workflow::jira::logs::{JiraLogs, LogEntry};
+use std::path::PathBuf;
+use workflow::base::logger::LogLevel;
+use workflow::jira::attachments::{AttachmentCleaner, CleanResult, DirInfo};
+use workflow::jira::logs::{colorize_log_line, JiraLogs, LogEntry, LogFilter, LogTail};
 
 // ==================== Fixtures ====================
 
@@ -200,12 +202,183 @@ fn test_log_entry(#[case] id: Option<&str>, #[case] url: Option<&str>) {
     let entry = LogEntry {
         id: id.map(|s| s.to_string()),
         url: url.map(|s| s.to_string()),
+        timestamp: None,
+        level: None,
+        component: None,
+        raw: String::new(),
     };
 
     assert_eq!(entry.id, id.map(|s| s.to_string()));
     assert_eq!(entry.url, url.map(|s| s.to_string()));
 }
 
+#[test]
+fn test_log_entry_to_json_line() {
+    // 测试 LogEntry 序列化为单行 JSON（ISO-8601 时间戳、小写级别、保留原始行）
+    let raw = "💡 2024-01-15 10:30:45.123 [HttpClient] #123 GET https://example.com/api";
+    let entry = LogEntry {
+        id: Some("123".to_string()),
+        url: Some("https://example.com/api".to_string()),
+        timestamp: chrono::NaiveDate::from_ymd_opt(2024, 1, 15)
+            .and_then(|d| d.and_hms_milli_opt(10, 30, 45, 123)),
+        level: Some(LogLevel::Info),
+        component: Some("HttpClient".to_string()),
+        raw: raw.to_string(),
+    };
+
+    let line = entry.to_json_line().unwrap();
+    assert!(
+        !line.contains('\n'),
+        "JSON line should not contain newlines"
+    );
+
+    let value: serde_json::Value = serde_json::from_str(&line).unwrap();
+    assert_eq!(value["id"], "123");
+    assert_eq!(value["timestamp"], "2024-01-15T10:30:45.123");
+    assert_eq!(value["level"], "info");
+    assert_eq!(value["component"], "HttpClient");
+    assert_eq!(value["raw"], raw);
+}
+
+#[test]
+fn test_log_entry_to_json_line_without_optional_fields() {
+    // 测试缺失的可选字段序列化为 null
+    let entry = log_entry("7", None, None);
+    let value: serde_json::Value = serde_json::from_str(&entry.to_json_line().unwrap()).unwrap();
+
+    assert_eq!(value["timestamp"], serde_json::Value::Null);
+    assert_eq!(value["level"], serde_json::Value::Null);
+    assert_eq!(value["raw"], "");
+}
+
+// ==================== 日志过滤测试 ====================
+
+fn log_entry(id: &str, level: Option<LogLevel>, component: Option<&str>) -> LogEntry {
+    LogEntry {
+        id: Some(id.to_string()),
+        url: None,
+        timestamp: None,
+        level,
+        component: component.map(|c| c.to_string()),
+        raw: String::new(),
+    }
+}
+
+#[test]
+fn test_log_filter_levels_and_min_level() {
+    // 测试按级别列表和最低级别过滤
+    let entries = vec![
+        log_entry("1", Some(LogLevel::Error), None),
+        log_entry("2", Some(LogLevel::Warn), None),
+        log_entry("3", Some(LogLevel::Info), None),
+        log_entry("4", Some(LogLevel::Debug), None),
+        log_entry("5", None, None),
+    ];
+
+    let by_levels =
+        LogFilter::new(vec![LogLevel::Error, LogLevel::Info], None, None).apply(entries.clone());
+    let ids: Vec<_> = by_levels.entries.iter().filter_map(|e| e.id.clone()).collect();
+    assert_eq!(ids, vec!["1", "3"]);
+    assert_eq!(by_levels.skipped_unparseable, 1);
+
+    let by_min_level = LogFilter::new(Vec::new(), Some(LogLevel::Warn), None).apply(entries);
+    let ids: Vec<_> = by_min_level.entries.iter().filter_map(|e| e.id.clone()).collect();
+    assert_eq!(ids, vec!["1", "2"]);
+    assert_eq!(by_min_level.skipped_unparseable, 1);
+}
+
+#[test]
+fn test_log_filter_component_without_level_filter() {
+    // 测试组件过滤（未启用级别过滤时不统计无法解析级别的条目）
+    let entries = vec![
+        log_entry("1", None, Some("HttpClient")),
+        log_entry("2", Some(LogLevel::Error), Some("Auth")),
+        log_entry("3", None, None),
+    ];
+
+    let filter = LogFilter::new(Vec::new(), None, Some("httpclient".to_string()));
+    assert!(!filter.has_level_filter());
+    let result = filter.apply(entries);
+    assert_eq!(result.entries.len(), 1);
+    assert_eq!(result.entries[0].id, Some("1".to_string()));
+    assert_eq!(result.skipped_unparseable, 0);
+}
+
+#[test]
+fn test_log_filter_empty_matches_everything() {
+    // 测试空过滤器保留所有条目
+    let filter = LogFilter::default();
+    assert!(filter.is_empty());
+    assert!(filter.matches(&log_entry("1", None, None)));
+    assert_eq!(
+        filter.apply(vec![log_entry("1", None, None)]).entries.len(),
+        1
+    );
+}
+
+// ==================== 日志跟踪测试 ====================
+
+#[test]
+fn test_log_tail_read_last_lines_and_follow() {
+    // 测试读取末尾行以及追加内容的跟踪
+    let test_dir = create_temp_test_dir("jira_logs_tail");
+    let log_file = test_dir.join("flutter-api.log");
+    fs::write(&log_file, "line1\nline2\nline3\n").expect("Should write log file");
+
+    let mut tail = LogTail::open(&log_file).expect("Should open log file");
+    assert_eq!(tail.read_last_lines(2).unwrap(), vec!["line2", "line3"]);
+    assert!(tail.read_new_lines().unwrap().is_empty());
+
+    // 追加一行完整内容和一行未写完的内容
+    let mut content = fs::read_to_string(&log_file).unwrap();
+    content.push_str("line4\npartial");
+    fs::write(&log_file, &content).expect("Should append log file");
+    assert_eq!(tail.read_new_lines().unwrap(), vec!["line4"]);
+
+    // 补全未写完的行
+    content.push_str(" line5\n");
+    fs::write(&log_file, &content).expect("Should append log file");
+    assert_eq!(tail.read_new_lines().unwrap(), vec!["partial line5"]);
+
+    cleanup_temp_test_dir(&test_dir);
+}
+
+#[test]
+fn test_log_tail_reopens_after_truncation() {
+    // 测试日志文件被截断（轮转）后重新从头读取
+    let test_dir = create_temp_test_dir("jira_logs_tail_rotate");
+    let log_file = test_dir.join("flutter-api.log");
+    fs::write(&log_file, "old line 1\nold line 2\n").expect("Should write log file");
+
+    let mut tail = LogTail::open(&log_file).expect("Should open log file");
+    assert_eq!(tail.read_new_lines().unwrap().len(), 2);
+
+    fs::write(&log_file, "new\n").expect("Should truncate log file");
+    assert_eq!(tail.read_new_lines().unwrap(), vec!["new"]);
+
+    // 文件被删除后重新创建（替换）
+    fs::remove_file(&log_file).expect("Should remove log file");
+    assert!(tail.read_new_lines().unwrap().is_empty());
+    fs::write(&log_file, "replaced line one\nreplaced line two\n").expect("Should recreate");
+    assert_eq!(
+        tail.read_new_lines().unwrap(),
+        vec!["replaced line one", "replaced line two"]
+    );
+
+    cleanup_temp_test_dir(&test_dir);
+}
+
+#[rstest]
+#[case("plain line", None)]
+#[case("error line", Some(LogLevel::Error))]
+#[case("warn line", Some(LogLevel::Warn))]
+#[case("info line", Some(LogLevel::Info))]
+fn test_colorize_log_line_keeps_content(#[case] line: &str, #[case] level: Option<LogLevel>) {
+    // 测试着色后仍包含原始内容
+    let colored = colorize_log_line(line, level);
+    assert!(colored.contains(line));
+}
+
 // ==================== 清理功能测试 ====================
 
 #[rstest]
@@ -237,6 +410,118 @@ fn test_jira_logs_clean_dir(#[case] jira_id: &str, #[case] dry_run: bool, #[case
     }
 }
 
+#[rstest]
+fn test_jira_logs_clean_plan_nonexistent(nonexistent_jira_id: &str) {
+    // 测试计算不存在目录的清理内容
+    let cleaner = AttachmentCleaner::new();
+
+    let plan = cleaner.plan(nonexistent_jira_id).expect("Should plan clean");
+
+    assert!(plan.dry_run, "Plan should be a dry run");
+    assert!(!plan.dir_exists, "Directory should not exist");
+    assert!(!plan.deleted, "Plan should not delete anything");
+    assert_eq!(plan.reclaimable_bytes(), 0);
+    assert_eq!(plan.file_count(), 0);
+
+    // 删除不存在的目录不做任何操作
+    let result = cleaner.remove(plan).expect("Should handle missing directory");
+    assert!(!result.deleted, "Should not delete non-existent directory");
+    assert!(!result.dry_run, "Remove should not be a dry run");
+}
+
+#[test]
+fn test_jira_logs_clean_result_totals() {
+    // 测试清理结果的文件数和可释放空间
+    let ticket = DirInfo {
+        dir_name: "the directory for PROJ-1".to_string(),
+        dir: PathBuf::from("/tmp/jira/PROJ-1"),
+        size: 2048,
+        file_count: 3,
+        is_base_dir: false,
+        jira_id: Some("PROJ-1".to_string()),
+        contents: Vec::new(),
+        tickets: Vec::new(),
+    };
+    let result = CleanResult {
+        deleted: false,
+        dir_exists: true,
+        dir_info: Some(DirInfo {
+            dir_name: "the entire base directory".to_string(),
+            dir: PathBuf::from("/tmp/jira"),
+            size: 2048,
+            file_count: 3,
+            is_base_dir: true,
+            jira_id: None,
+            contents: Vec::new(),
+            tickets: vec![ticket],
+        }),
+        cancelled: false,
+        dry_run: true,
+        list_only: false,
+    };
+
+    assert_eq!(result.reclaimable_bytes(), 2048);
+    assert_eq!(result.file_count(), 3);
+    // 预览时没有实际释放空间
+    assert_eq!(result.reclaimed_bytes(), 0);
+
+    let deleted = CleanResult {
+        deleted: true,
+        dry_run: false,
+        ..result
+    };
+    assert_eq!(deleted.reclaimed_bytes(), 2048);
+}
+
+/// 创建 ticket 目录信息
+fn ticket_dir_info(jira_id: &str, size: u64) -> DirInfo {
+    DirInfo {
+        dir_name: format!("the directory for {}", jira_id),
+        dir: PathBuf::from("/tmp/jira").join(jira_id),
+        size,
+        file_count: 1,
+        is_base_dir: false,
+        jira_id: Some(jira_id.to_string()),
+        contents: Vec::new(),
+        tickets: Vec::new(),
+    }
+}
+
+#[test]
+fn test_jira_logs_clean_tickets_sorted_by_size() {
+    // 基础目录的 ticket 按大小从大到小排序，大小相同时按 JIRA ID 排序
+    let base = DirInfo {
+        dir_name: "the entire base directory".to_string(),
+        dir: PathBuf::from("/tmp/jira"),
+        size: 7168,
+        file_count: 4,
+        is_base_dir: true,
+        jira_id: None,
+        contents: Vec::new(),
+        tickets: vec![
+            ticket_dir_info("PROJ-1", 1024),
+            ticket_dir_info("PROJ-2", 4096),
+            ticket_dir_info("PROJ-4", 1024),
+            ticket_dir_info("PROJ-3", 1024),
+        ],
+    };
+
+    let order: Vec<&str> = base
+        .tickets_by_size()
+        .iter()
+        .filter_map(|ticket| ticket.jira_id.as_deref())
+        .collect();
+    assert_eq!(order, vec!["PROJ-2", "PROJ-1", "PROJ-3", "PROJ-4"]);
+
+    // 单个 ticket 目录返回其自身
+    let single = ticket_dir_info("PROJ-5", 512);
+    assert_eq!(single.tickets_by_size().len(), 1);
+    assert_eq!(
+        single.tickets_by_size()[0].jira_id.as_deref(),
+        Some("PROJ-5")
+    );
+}
+
 #[test]
 #[ignore] // 需要交互式确认，在 CI 环境中会卡住
 fn test_jira_logs_clean_dir_empty_jira_id() {
@@ -0,0 +1,146 @@
+//! Jira 文本格式渲染测试
+//!
+//! 测试将 Jira 文本格式（标题、列表、代码块、引用、行内格式）渲染为终端文本。
+//! 测试中禁用颜色输出，只比较渲染后的文本。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use serial_test::serial;
+use workflow::base::logger::console::set_colors_enabled;
+use workflow::jira::{is_markdown, render_comment_body, render_jira_markup};
+
+/// 禁用颜色后渲染
+fn render(text: &str) -> String {
+    set_colors_enabled(false);
+    render_jira_markup(text)
+}
+
+// ==================== 块级格式测试 ====================
+
+#[rstest]
+#[case("h1. Summary", "Summary")]
+#[case("h3.   Steps to reproduce", "Steps to reproduce")]
+#[case("bq. Quoted text", "│ Quoted text")]
+#[case("plain text", "plain text")]
+#[case("hello. world", "hello. world")]
+#[serial]
+fn test_render_line_formats(#[case] input: &str, #[case] expected: &str) {
+    assert_eq!(render(input), expected);
+}
+
+#[test]
+#[serial]
+fn test_render_horizontal_rule() {
+    let output = render("above\n----\nbelow");
+    let lines: Vec<&str> = output.lines().collect();
+
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].chars().all(|c| c == '-'));
+    assert!(lines[1].len() > 4);
+}
+
+#[test]
+#[serial]
+fn test_render_nested_lists() {
+    let input = "* first\n** nested\n* second\n# one\n# two\n## two-a\n# three";
+
+    assert_eq!(
+        render(input),
+        "• first\n  • nested\n• second\n1. one\n2. two\n  1. two-a\n3. three"
+    );
+}
+
+#[test]
+#[serial]
+fn test_render_list_requires_space_after_marker() {
+    // `*粗体*` 开头的段落不是列表
+    assert_eq!(render("*Important* note"), "Important note");
+    assert_eq!(render("-- not a list"), "-- not a list");
+}
+
+#[test]
+#[serial]
+fn test_render_code_block_keeps_markup() {
+    let input = "Run:\n{code:bash}\necho *not bold* [x|y]\n{code}\nDone";
+
+    assert_eq!(render(input), "Run:\n    echo *not bold* [x|y]\nDone");
+}
+
+#[test]
+#[serial]
+fn test_render_noformat_and_quote_blocks() {
+    let input = "{noformat}\nraw _text_\n{noformat}\n{quote}\nfirst\n*second*\n{quote}";
+
+    assert_eq!(render(input), "    raw _text_\n│ first\n│ second");
+}
+
+// ==================== 行内格式测试 ====================
+
+#[rstest]
+#[case("*bold* and _italic_", "bold and italic")]
+#[case("*a* *b*", "a b")]
+#[case("use {{cargo test}} here", "use cargo test here")]
+#[case(
+    "see [docs|https://example.com/docs]",
+    "see docs (https://example.com/docs)"
+)]
+#[case("see [https://example.com]", "see https://example.com")]
+#[case("thanks [~jdoe]", "thanks @jdoe")]
+#[case(
+    "thanks [~accountid:5b10a2844c20165700ede21g]",
+    "thanks @5b10a2844c20165700ede21g"
+)]
+#[case("!screenshot.png|thumbnail!", "[image: screenshot.png]")]
+#[case("{color:red}Failed{color}", "Failed")]
+#[serial]
+fn test_render_inline_formats(#[case] input: &str, #[case] expected: &str) {
+    assert_eq!(render(input), expected);
+}
+
+#[rstest]
+#[case("snake_case_name stays")]
+#[case("2*3*4 = 24")]
+#[case("Wow! Great!")]
+#[case("a * b * c")]
+#[serial]
+fn test_render_keeps_non_markup_text(#[case] input: &str) {
+    assert_eq!(render(input), input);
+}
+
+#[test]
+#[serial]
+fn test_render_with_colors_keeps_content() {
+    set_colors_enabled(true);
+    let output = render_jira_markup("h2. Title\n*bold* text");
+    set_colors_enabled(false);
+
+    assert!(output.contains('\u{1b}'));
+    assert!(output.contains("Title"));
+    assert!(output.contains("bold"));
+    assert!(!output.contains("h2."));
+}
+
+// ==================== 评论内容测试 ====================
+
+#[rstest]
+#[case("```\ncode\n```", true)]
+#[case("See [docs](https://example.com)", true)]
+#[case("**Summary**: done", true)]
+#[case("* first\n** nested", false)]
+#[case("# one\n## one-a", false)]
+#[case("*bold* and [docs|https://example.com]", false)]
+fn test_is_markdown(#[case] input: &str, #[case] expected: bool) {
+    assert_eq!(is_markdown(input), expected);
+}
+
+#[test]
+#[serial]
+fn test_render_comment_body_uses_matching_renderer() {
+    set_colors_enabled(false);
+
+    assert_eq!(
+        render_comment_body("**Fixed** in [#12](https://example.com/pull/12)"),
+        "Fixed in #12 (https://example.com/pull/12)"
+    );
+    assert_eq!(render_comment_body("h2. Title\n# step"), "Title\n1. step");
+}
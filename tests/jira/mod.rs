@@ -2,6 +2,13 @@
 //!
 //! 包含 Jira 模块的所有测试文件。
 
+pub mod attachments;
+pub mod changelog;
+pub mod helpers;
 pub mod history;
 pub mod logs;
+pub mod markup;
+pub mod output;
+pub mod related;
+pub mod sprint;
 pub mod status;
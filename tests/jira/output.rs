@@ -0,0 +1,131 @@
+//! Jira 命令机器可读输出测试
+//!
+//! 测试 `jira info --json` 输出的 JSON 文档（字段名、顺序和空值）。
+
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use workflow::base::settings::settings::JiraSettings;
+use workflow::jira::{JiraIssue, JiraIssueOutput};
+
+// ==================== Helper Functions ====================
+
+/// 创建包含常用字段的 Issue
+fn sample_issue() -> JiraIssue {
+    serde_json::from_value(json!({
+        "key": "PROJ-123",
+        "id": "10001",
+        "self": "https://jira.example.com/rest/api/2/issue/10001",
+        "fields": {
+            "summary": "Login page",
+            "description": "Build the login page",
+            "status": { "id": "3", "name": "In Progress" },
+            "priority": { "id": "2", "name": "High" },
+            "created": "2024-03-01T09:00:00.000+0000",
+            "updated": "2024-03-02T10:30:00.000+0000",
+            "reporter": {
+                "accountId": "u1",
+                "displayName": "Alice",
+                "emailAddress": "alice@example.com"
+            },
+            "assignee": { "accountId": "u2", "displayName": "Bob" },
+            "labels": ["frontend"],
+            "components": [{ "id": "1", "name": "Web" }],
+            "fix_versions": [{ "id": "5", "name": "1.2.0" }],
+            "attachment": [{
+                "filename": "mockup.png",
+                "content": "https://jira.example.com/secure/attachment/1/mockup.png"
+            }],
+            "comment": { "comments": [] },
+            JiraSettings::DEFAULT_SPRINT_FIELD: [{ "id": 42, "name": "Sprint 12", "state": "active" }]
+        }
+    }))
+    .unwrap()
+}
+
+// ==================== jira info 测试 ====================
+
+#[test]
+fn test_jira_info_output_snapshot() {
+    let output = JiraIssueOutput::new(
+        &sample_issue(),
+        JiraSettings::DEFAULT_SPRINT_FIELD,
+        Some("https://jira.example.com"),
+    );
+
+    assert_eq!(
+        serde_json::to_string_pretty(&output).unwrap(),
+        r#"{
+  "key": "PROJ-123",
+  "id": "10001",
+  "summary": "Login page",
+  "description": "Build the login page",
+  "status": "In Progress",
+  "priority": "High",
+  "reporter": {
+    "name": "Alice",
+    "email": "alice@example.com"
+  },
+  "assignee": {
+    "name": "Bob",
+    "email": null
+  },
+  "labels": [
+    "frontend"
+  ],
+  "components": [
+    "Web"
+  ],
+  "fix_versions": [
+    "1.2.0"
+  ],
+  "sprint": {
+    "name": "Sprint 12",
+    "state": "active"
+  },
+  "created": "2024-03-01T09:00:00.000+0000",
+  "updated": "2024-03-02T10:30:00.000+0000",
+  "attachments": 1,
+  "comments": 0,
+  "url": "https://jira.example.com/browse/PROJ-123"
+}"#
+    );
+}
+
+#[test]
+fn test_jira_info_output_minimal_issue() {
+    // 只有必需字段时，可选字段为 null，列表字段为空数组
+    let issue: JiraIssue = serde_json::from_value(json!({
+        "key": "PROJ-1",
+        "id": "1",
+        "self": "https://jira.example.com/rest/api/2/issue/1",
+        "fields": {
+            "summary": "Minimal",
+            "status": { "id": "1", "name": "To Do" }
+        }
+    }))
+    .unwrap();
+    let output = JiraIssueOutput::new(&issue, JiraSettings::DEFAULT_SPRINT_FIELD, None);
+
+    assert_eq!(
+        serde_json::to_value(&output).unwrap(),
+        json!({
+            "key": "PROJ-1",
+            "id": "1",
+            "summary": "Minimal",
+            "description": null,
+            "status": "To Do",
+            "priority": null,
+            "reporter": null,
+            "assignee": null,
+            "labels": [],
+            "components": [],
+            "fix_versions": [],
+            "sprint": null,
+            "created": null,
+            "updated": null,
+            "attachments": 0,
+            "comments": 0,
+            "url": null
+        })
+    );
+}
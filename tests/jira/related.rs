@@ -0,0 +1,203 @@
+//! Jira 关联 ticket 测试
+//!
+//! 测试从 Issue 数据整理关联 ticket（Epic/父任务、子任务、Issue 链接）、链接关系解析以及树形渲染。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use workflow::jira::{
+    IssueRelation, JiraIssue, JiraIssueLink, LinkedIssue, RelatedIssue, RelatedIssues,
+};
+
+// ==================== Helper Functions ====================
+
+/// 创建测试用的 Issue
+fn sample_issue(parent_type: &str) -> JiraIssue {
+    serde_json::from_value(serde_json::json!({
+        "key": "PROJ-123",
+        "id": "10123",
+        "self": "https://example.atlassian.net/rest/api/2/issue/10123",
+        "fields": {
+            "summary": "Fix login crash",
+            "status": { "id": "3", "name": "In Progress" },
+            "parent": {
+                "key": "PROJ-100",
+                "id": "10100",
+                "fields": {
+                    "summary": "Auth revamp",
+                    "status": { "id": "1", "name": "Open" },
+                    "issuetype": { "id": "10000", "name": parent_type }
+                }
+            },
+            "subtasks": [
+                {
+                    "key": "PROJ-124",
+                    "id": "10124",
+                    "fields": {
+                        "summary": "Add regression test",
+                        "status": { "id": "10001", "name": "Done" }
+                    }
+                }
+            ],
+            "issuelinks": [
+                {
+                    "id": "1",
+                    "type": { "id": "1", "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+                    "outwardIssue": {
+                        "key": "PROJ-200",
+                        "id": "10200",
+                        "fields": { "summary": "Release 2.0", "status": { "id": "1", "name": "Open" } }
+                    }
+                },
+                {
+                    "id": "2",
+                    "type": { "id": "1", "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+                    "inwardIssue": { "key": "PROJ-90", "id": "10090" }
+                },
+                {
+                    "id": "3",
+                    "type": { "id": "1", "name": "Blocks", "inward": "is blocked by", "outward": "blocks" },
+                    "outwardIssue": { "key": "PROJ-124", "id": "10124" }
+                }
+            ]
+        }
+    }))
+    .unwrap()
+}
+
+// ==================== 关联 ticket 测试 ====================
+
+#[test]
+fn test_related_issues_from_issue() {
+    let related = RelatedIssues::from_issue(&sample_issue("Epic"));
+
+    assert_eq!(related.issue.key, "PROJ-123");
+    assert_eq!(
+        related.epic.as_ref().map(|e| e.key.as_str()),
+        Some("PROJ-100")
+    );
+    assert_eq!(related.parent, None);
+    assert_eq!(related.subtasks.len(), 1);
+    assert_eq!(related.links.len(), 3);
+    assert_eq!(related.links[0].relation, IssueRelation::Blocks);
+    assert_eq!(related.links[1].relation, IssueRelation::IsBlockedBy);
+    assert_eq!(related.links[1].issue.status, None);
+}
+
+#[test]
+fn test_related_issues_non_epic_parent() {
+    let related = RelatedIssues::from_issue(&sample_issue("Story"));
+
+    assert_eq!(related.epic, None);
+    assert_eq!(
+        related.parent.as_ref().map(|p| p.key.as_str()),
+        Some("PROJ-100")
+    );
+}
+
+#[test]
+fn test_related_issues_groups_and_dedup() {
+    let related = RelatedIssues::from_issue(&sample_issue("Epic"));
+
+    let names: Vec<String> = related.groups().into_iter().map(|g| g.name).collect();
+    assert_eq!(names, vec!["Epic", "Subtasks", "blocks", "is blocked by"]);
+
+    // PROJ-124 同时是子任务和链接目标，只出现一次
+    let keys: Vec<String> = related.related().into_iter().map(|i| i.key).collect();
+    assert_eq!(keys, vec!["PROJ-100", "PROJ-124", "PROJ-200", "PROJ-90"]);
+}
+
+#[test]
+fn test_related_issues_tree() {
+    let related = RelatedIssues::from_issue(&sample_issue("Epic"));
+
+    let expected = "\
+PROJ-123 [In Progress] Fix login crash
+├── Epic
+│   └── PROJ-100 [Open] Auth revamp
+├── Subtasks
+│   └── PROJ-124 [Done] Add regression test
+├── blocks
+│   ├── PROJ-200 [Open] Release 2.0
+│   └── PROJ-124 [Unknown]
+└── is blocked by
+    └── PROJ-90 [Unknown]";
+    assert_eq!(related.tree(), expected);
+}
+
+#[test]
+fn test_related_issues_empty() {
+    let issue: JiraIssue = serde_json::from_value(serde_json::json!({
+        "key": "PROJ-1",
+        "id": "1",
+        "self": "https://example.atlassian.net/rest/api/2/issue/1",
+        "fields": {
+            "summary": "Standalone",
+            "status": { "id": "1", "name": "Open" }
+        }
+    }))
+    .unwrap();
+
+    let related = RelatedIssues::from_issue(&issue);
+    assert!(related.is_empty());
+    assert_eq!(related.tree(), "PROJ-1 [Open] Standalone");
+}
+
+#[test]
+fn test_related_issue_label() {
+    let issue = RelatedIssue {
+        key: "PROJ-1".to_string(),
+        status: Some("Done".to_string()),
+        summary: None,
+    };
+    assert_eq!(issue.label(), "PROJ-1 [Done]");
+}
+
+// ==================== 链接关系测试 ====================
+
+#[rstest]
+#[case("blocks", IssueRelation::Blocks)]
+#[case("is blocked by", IssueRelation::IsBlockedBy)]
+#[case("Relates to", IssueRelation::RelatesTo)]
+#[case("duplicates", IssueRelation::Duplicates)]
+#[case("is duplicated by", IssueRelation::IsDuplicatedBy)]
+#[case("clones", IssueRelation::Other("clones".to_string()))]
+fn test_issue_relation_from_description(
+    #[case] description: &str,
+    #[case] expected: IssueRelation,
+) {
+    assert_eq!(IssueRelation::from_description(description), expected);
+}
+
+#[test]
+fn test_linked_issue_from_link() {
+    let link: JiraIssueLink = serde_json::from_value(serde_json::json!({
+        "id": "4",
+        "type": { "id": "2", "name": "Duplicate", "inward": "is duplicated by", "outward": "duplicates" },
+        "inwardIssue": {
+            "key": "PROJ-7",
+            "id": "10007",
+            "fields": { "summary": "Old report", "status": { "id": "6", "name": "Closed" } }
+        }
+    }))
+    .unwrap();
+
+    let linked = LinkedIssue::from_link(&link).unwrap();
+    assert_eq!(linked.relation, IssueRelation::IsDuplicatedBy);
+    assert_eq!(linked.issue.label(), "PROJ-7 [Closed] Old report");
+
+    // 关系序列化为描述文本
+    let value = serde_json::to_value(&linked).unwrap();
+    assert_eq!(value["relation"], "is duplicated by");
+    assert_eq!(value["key"], "PROJ-7");
+}
+
+#[test]
+fn test_linked_issue_without_target() {
+    let link: JiraIssueLink = serde_json::from_value(serde_json::json!({
+        "id": "5",
+        "type": { "id": "3", "name": "Relates", "inward": "relates to", "outward": "relates to" }
+    }))
+    .unwrap();
+
+    assert_eq!(LinkedIssue::from_link(&link), None);
+}
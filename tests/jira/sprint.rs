@@ -0,0 +1,182 @@
+//! Jira Sprint 字段解析测试
+//!
+//! 测试 Sprint 自定义字段的解析（对象格式和旧版 Jira Server 的字符串格式），
+//! 从 Issue 中读取当前 Sprint，以及 Markdown 输出。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use serde_json::{json, Value};
+use workflow::base::settings::settings::JiraSettings;
+use workflow::commands::jira::SprintCommand;
+use workflow::jira::{parse_sprint_field, parse_sprint_string, JiraIssue, JiraSprint};
+
+/// 旧版 Jira Server 返回的 Sprint 字符串
+const SERIALIZED_SPRINT: &str = "com.atlassian.greenhopper.service.sprint.Sprint@5c8f2e1a[id=42,rapidViewId=7,state=ACTIVE,name=Sprint 12,goal=Ship the login page,startDate=2024-03-04T09:00:00.000+08:00,endDate=2024-03-18T09:00:00.000+08:00,completeDate=<null>,sequence=42]";
+
+/// 创建带 Sprint 字段的 Issue
+fn issue_with_sprint(field: &str, sprint: Value) -> JiraIssue {
+    let mut fields = json!({
+        "summary": "Login page",
+        "status": { "id": "3", "name": "In Progress" },
+    });
+    fields[field] = sprint;
+
+    serde_json::from_value(json!({
+        "key": "PROJ-1",
+        "id": "10001",
+        "self": "https://jira.example.com/rest/api/2/issue/10001",
+        "fields": fields,
+    }))
+    .unwrap()
+}
+
+// ==================== 字符串格式解析测试 ====================
+
+#[test]
+fn test_parse_sprint_string_reads_all_attributes() {
+    let sprint = parse_sprint_string(SERIALIZED_SPRINT).unwrap();
+
+    assert_eq!(
+        sprint,
+        JiraSprint {
+            id: 42,
+            name: "Sprint 12".to_string(),
+            state: Some("active".to_string()),
+            start_date: Some("2024-03-04T09:00:00.000+08:00".to_string()),
+            end_date: Some("2024-03-18T09:00:00.000+08:00".to_string()),
+            complete_date: None,
+            goal: Some("Ship the login page".to_string()),
+        }
+    );
+}
+
+#[test]
+fn test_parse_sprint_string_keeps_commas_in_values() {
+    let sprint = parse_sprint_string(
+        "Sprint@1[id=3,state=CLOSED,name=Sprint 3, part 2,goal=Fix bugs, then release,sequence=3]",
+    )
+    .unwrap();
+
+    assert_eq!(sprint.name, "Sprint 3, part 2");
+    assert_eq!(sprint.goal.as_deref(), Some("Fix bugs, then release"));
+    assert_eq!(sprint.state.as_deref(), Some("closed"));
+}
+
+#[rstest]
+#[case("Sprint@1[id=1,name=Sprint 1,goal=,startDate=<null>]")]
+#[case("Sprint@1[id=1,name=Sprint 1]")]
+fn test_parse_sprint_string_empty_values_are_none(#[case] text: &str) {
+    let sprint = parse_sprint_string(text).unwrap();
+
+    assert_eq!(sprint.id, 1);
+    assert_eq!(sprint.goal, None);
+    assert_eq!(sprint.start_date, None);
+    assert_eq!(sprint.state, None);
+}
+
+#[rstest]
+#[case("")]
+#[case("Sprint 12")]
+#[case("Sprint@1[name=Sprint 1]")]
+#[case("Sprint@1[id=abc,name=Sprint 1]")]
+#[case("Sprint@1[id=1,name=<null>]")]
+fn test_parse_sprint_string_invalid(#[case] text: &str) {
+    assert_eq!(parse_sprint_string(text), None);
+}
+
+// ==================== 字段值解析测试 ====================
+
+#[test]
+fn test_parse_sprint_field_objects_and_strings() {
+    let value = json!([
+        { "id": 41, "name": "Sprint 11", "state": "closed", "boardId": 7 },
+        SERIALIZED_SPRINT,
+        42,
+    ]);
+
+    let sprints = parse_sprint_field(&value);
+
+    assert_eq!(sprints.len(), 2);
+    assert_eq!(sprints[0].name, "Sprint 11");
+    assert_eq!(sprints[1].id, 42);
+    assert_eq!(sprints[1].state.as_deref(), Some("active"));
+}
+
+#[rstest]
+#[case(json!(null), 0)]
+#[case(json!([]), 0)]
+#[case(json!(SERIALIZED_SPRINT), 1)]
+#[case(json!({ "id": 1, "name": "Sprint 1", "state": "FUTURE" }), 1)]
+fn test_parse_sprint_field_shapes(#[case] value: Value, #[case] expected: usize) {
+    assert_eq!(parse_sprint_field(&value).len(), expected);
+}
+
+// ==================== Issue Sprint 测试 ====================
+
+#[test]
+fn test_issue_current_sprint_prefers_active() {
+    let field = JiraSettings::DEFAULT_SPRINT_FIELD;
+    let issue = issue_with_sprint(
+        field,
+        json!([SERIALIZED_SPRINT, { "id": 43, "name": "Sprint 13", "state": "future" }]),
+    );
+
+    assert_eq!(issue.sprints(field).len(), 2);
+    assert_eq!(issue.current_sprint(field).unwrap().name, "Sprint 12");
+}
+
+#[test]
+fn test_issue_current_sprint_uses_configured_field() {
+    let issue = issue_with_sprint("customfield_10104", json!([SERIALIZED_SPRINT]));
+
+    assert_eq!(
+        issue.current_sprint(JiraSettings::DEFAULT_SPRINT_FIELD),
+        None
+    );
+    assert_eq!(issue.current_sprint("customfield_10104").unwrap().id, 42);
+}
+
+#[test]
+fn test_jira_settings_sprint_field_default() {
+    let mut jira = JiraSettings::default();
+    assert_eq!(jira.sprint_field(), JiraSettings::DEFAULT_SPRINT_FIELD);
+
+    jira.sprint_field = Some("customfield_10104".to_string());
+    assert_eq!(jira.sprint_field(), "customfield_10104");
+    assert!(!jira.is_empty());
+}
+
+// ==================== Markdown 输出测试 ====================
+
+#[test]
+fn test_sprint_markdown_escapes_pipes_and_newlines_in_summary() {
+    let sprint = JiraSprint {
+        id: 42,
+        name: "Sprint 12".to_string(),
+        state: Some("active".to_string()),
+        start_date: None,
+        end_date: None,
+        complete_date: None,
+        goal: None,
+    };
+    let issue: JiraIssue = serde_json::from_value(json!({
+        "key": "PROJ-1",
+        "id": "10001",
+        "self": "https://jira.example.com/rest/api/2/issue/10001",
+        "fields": {
+            "summary": "Login | logout\nflow",
+            "status": { "id": "3", "name": "In Progress" },
+        },
+    }))
+    .unwrap();
+
+    let markdown = SprintCommand::render_markdown(&sprint, &[issue]).unwrap();
+
+    assert_eq!(
+        markdown,
+        "# Sprint 12\n\n\
+         | Key | Status | Assignee | Summary |\n\
+         | --- | --- | --- | --- |\n\
+         | PROJ-1 | In Progress | - | Login \\| logout<br>flow |"
+    );
+}
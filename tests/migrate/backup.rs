@@ -0,0 +1,75 @@
+//! 迁移备份测试
+//!
+//! 测试 `MigrateCommand::run_with_backup()`：迁移失败时从备份恢复配置，
+//! 并在错误中报告失败的版本。
+
+use pretty_assertions::assert_eq;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+use workflow::commands::migrate::MigrateCommand;
+use workflow::rollback::RollbackManager;
+
+// ==================== Helper Functions ====================
+
+const ORIGINAL_CONFIG: &str = "[branch]\nprefix = \"feature\"\n";
+
+/// 创建测试用的配置文件（`config.toml` 存在，`repository.toml` 不存在）
+fn setup_config_files() -> (TempDir, PathBuf, PathBuf) {
+    let temp_dir = tempfile::tempdir().expect("Failed to create temp dir");
+    let config = temp_dir.path().join("config.toml");
+    let repository = temp_dir.path().join("repository.toml");
+    fs::write(&config, ORIGINAL_CONFIG).expect("Failed to write config");
+    (temp_dir, config, repository)
+}
+
+fn versions(names: &[&str]) -> Vec<String> {
+    names.iter().map(|v| v.to_string()).collect()
+}
+
+// ==================== 备份恢复测试 ====================
+
+#[test]
+fn test_failing_migration_restores_config() {
+    let (_temp_dir, config, repository) = setup_config_files();
+    let files = vec![config.clone(), repository.clone()];
+
+    let result =
+        MigrateCommand::run_with_backup(&versions(&["v1.1.0", "v9.9.9"]), &files, |version| {
+            // 每个版本都会改写配置，注入的版本在改写后失败
+            fs::write(&config, format!("# migrated by {}\n", version))?;
+            fs::write(&repository, "[branch]\n")?;
+            if version == "v9.9.9" {
+                color_eyre::eyre::bail!("injected failure");
+            }
+            Ok(())
+        });
+
+    let error = format!("{:?}", result.unwrap_err());
+    assert!(error.contains("Migration v9.9.9 failed"), "{}", error);
+    assert!(error.contains("injected failure"), "{}", error);
+
+    // 配置恢复原样，迁移中新建的文件被删除
+    assert_eq!(fs::read_to_string(&config).unwrap(), ORIGINAL_CONFIG);
+    assert!(!repository.exists());
+}
+
+#[test]
+fn test_successful_migrations_keep_changes_and_backup() {
+    let (_temp_dir, config, repository) = setup_config_files();
+    let files = vec![config.clone(), repository.clone()];
+
+    let backup = MigrateCommand::run_with_backup(&versions(&["v1.1.0"]), &files, |_| {
+        fs::write(&config, "# migrated\n")?;
+        Ok(())
+    })
+    .unwrap();
+
+    assert_eq!(fs::read_to_string(&config).unwrap(), "# migrated\n");
+    assert!(backup.backup_dir.exists());
+    assert_eq!(backup.entries.len(), 2);
+    assert!(backup.entries[1].backup.is_none());
+
+    RollbackManager::cleanup_file_backup(&backup).unwrap();
+    assert!(!backup.backup_dir.exists());
+}
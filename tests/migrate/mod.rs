@@ -0,0 +1,4 @@
+//! 配置迁移测试模块
+
+pub mod backup;
+pub mod v1_1_0;
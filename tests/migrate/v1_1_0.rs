@@ -0,0 +1,110 @@
+//! v1.1.0 迁移测试
+//!
+//! 测试旧格式项目级配置中 `[branch]` 节的检测、迁移计划和清理。
+
+use pretty_assertions::assert_eq;
+use workflow::commands::migrate::v1_1_0::{
+    read_legacy_branch_config, remove_legacy_branch_section, BranchMigrationPlan,
+};
+use workflow::repo::BranchConfig;
+
+use crate::common::helpers::load_fixture;
+
+// ==================== Helper Functions ====================
+
+/// 加载旧格式的项目级配置
+fn legacy_project_config() -> toml::Value {
+    toml::from_str(&load_fixture("legacy_project_config.toml")).unwrap()
+}
+
+/// 创建分支配置
+fn branch_config(prefix: Option<&str>, ignore: &[&str]) -> BranchConfig {
+    BranchConfig {
+        prefix: prefix.map(str::to_string),
+        ignore: ignore.iter().map(|s| s.to_string()).collect(),
+    }
+}
+
+// ==================== 检测测试 ====================
+
+#[test]
+fn test_read_legacy_branch_config_from_fixture() {
+    let legacy = read_legacy_branch_config(&legacy_project_config()).unwrap();
+
+    assert_eq!(legacy.prefix.as_deref(), Some("feature"));
+    assert_eq!(legacy.ignore, vec!["main", "develop", "release"]);
+}
+
+#[test]
+fn test_read_legacy_branch_config_without_branch_section() {
+    let value: toml::Value = toml::from_str("[template.commit]\nuse_scope = true\n").unwrap();
+
+    assert!(read_legacy_branch_config(&value).is_none());
+}
+
+#[test]
+fn test_read_legacy_branch_config_with_empty_branch_section() {
+    let value: toml::Value = toml::from_str("[branch]\nignore = []\n").unwrap();
+
+    assert!(read_legacy_branch_config(&value).is_none());
+}
+
+// ==================== 迁移计划测试 ====================
+
+#[test]
+fn test_plan_moves_all_fields_without_private_config() {
+    let legacy = read_legacy_branch_config(&legacy_project_config()).unwrap();
+
+    let plan = BranchMigrationPlan::new(&legacy, None);
+
+    assert!(!plan.is_empty());
+    assert_eq!(plan.prefix.as_deref(), Some("feature"));
+    assert_eq!(plan.ignore, vec!["main", "develop", "release"]);
+    assert_eq!(
+        plan.describe(),
+        vec![
+            "branch.prefix = \"feature\"",
+            "branch.ignore += \"main\"",
+            "branch.ignore += \"develop\"",
+            "branch.ignore += \"release\"",
+        ]
+    );
+}
+
+#[test]
+fn test_plan_keeps_existing_private_values() {
+    let legacy = read_legacy_branch_config(&legacy_project_config()).unwrap();
+    let current = branch_config(Some("hotfix"), &["main"]);
+
+    let plan = BranchMigrationPlan::new(&legacy, Some(&current));
+    let migrated = plan.apply(Some(current));
+
+    assert_eq!(plan.prefix, None);
+    assert_eq!(plan.ignore, vec!["develop", "release"]);
+    assert_eq!(migrated.prefix.as_deref(), Some("hotfix"));
+    assert_eq!(migrated.ignore, vec!["main", "develop", "release"]);
+}
+
+#[test]
+fn test_plan_is_empty_after_migration() {
+    let legacy = read_legacy_branch_config(&legacy_project_config()).unwrap();
+    let migrated = BranchMigrationPlan::new(&legacy, None).apply(None);
+
+    // 迁移后再次检测不应重复迁移
+    let plan = BranchMigrationPlan::new(&legacy, Some(&migrated));
+
+    assert!(plan.is_empty());
+    assert!(plan.describe().is_empty());
+}
+
+// ==================== 清理测试 ====================
+
+#[test]
+fn test_remove_legacy_branch_section_keeps_other_sections() {
+    let mut value = legacy_project_config();
+
+    assert!(remove_legacy_branch_section(&mut value));
+    assert!(value.get("branch").is_none());
+    assert!(value.get("template").is_some());
+    assert!(!remove_legacy_branch_section(&mut value));
+}
@@ -0,0 +1,171 @@
+//! Azure DevOps PR 模块测试
+//!
+//! 测试 Azure DevOps remote URL 解析、API URL 构建、状态映射、变更类型标签和分支删除请求。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use workflow::pr::azure_devops::{
+    map_pull_request_status,
+    requests::{CreatePullRequestRequest, GitRefUpdate},
+    responses::PullRequestInfo,
+    with_api_version, AzureDevOpsRepo, API_VERSION,
+};
+use workflow::pr::map_change_types_to_labels;
+
+// ==================== Remote URL 解析测试 ====================
+
+#[rstest]
+#[case("https://dev.azure.com/org/project/_git/repo")]
+#[case("https://org@dev.azure.com/org/project/_git/repo")]
+#[case("https://dev.azure.com/org/project/_git/repo.git")]
+#[case("git@ssh.dev.azure.com:v3/org/project/repo")]
+#[case("ssh://git@ssh.dev.azure.com:22/v3/org/project/repo")]
+#[case("https://org.visualstudio.com/project/_git/repo")]
+#[case("https://org.visualstudio.com/DefaultCollection/project/_git/repo")]
+#[case("org@vs-ssh.visualstudio.com:v3/org/project/repo")]
+fn test_from_remote_url_parses_supported_formats(#[case] url: &str) {
+    let repo = AzureDevOpsRepo::from_remote_url(url).unwrap();
+
+    assert_eq!(repo.organization, "org");
+    assert_eq!(repo.project, "project");
+    assert_eq!(repo.repository, "repo");
+}
+
+#[test]
+fn test_from_remote_url_defaults_project_to_repository() {
+    let repo = AzureDevOpsRepo::from_remote_url("https://dev.azure.com/org/_git/repo").unwrap();
+
+    assert_eq!(repo.project, "repo");
+}
+
+#[rstest]
+#[case("git@github.com:owner/repo.git")]
+#[case("https://dev.azure.com/org/project")]
+#[case("")]
+fn test_from_remote_url_rejects_other_urls(#[case] url: &str) {
+    assert!(AzureDevOpsRepo::from_remote_url(url).is_err());
+}
+
+// ==================== URL 构建测试 ====================
+
+#[test]
+fn test_repo_urls() {
+    let repo =
+        AzureDevOpsRepo::from_remote_url("git@ssh.dev.azure.com:v3/org/My%20Project/repo").unwrap();
+
+    assert_eq!(
+        repo.api_url("/pullrequests/42"),
+        "https://dev.azure.com/org/My%20Project/_apis/git/repositories/repo/pullrequests/42"
+    );
+    assert_eq!(
+        repo.organization_api_url("/connectionData"),
+        "https://dev.azure.com/org/_apis/connectionData"
+    );
+    assert_eq!(
+        repo.project_api_url("/git/policy/configurations"),
+        "https://dev.azure.com/org/My%20Project/_apis/git/policy/configurations"
+    );
+    assert_eq!(
+        repo.pull_request_web_url(42),
+        "https://dev.azure.com/org/My%20Project/_git/repo/pullrequest/42"
+    );
+}
+
+#[test]
+fn test_with_api_version_is_always_first_and_unique() {
+    let query = with_api_version(&[("api-version", "5.0"), ("searchCriteria.status", "all")]);
+
+    assert_eq!(
+        query,
+        vec![
+            ("api-version", API_VERSION),
+            ("searchCriteria.status", "all")
+        ]
+    );
+}
+
+// ==================== 状态映射测试 ====================
+
+#[rstest]
+#[case("active", "open", false)]
+#[case("abandoned", "closed", false)]
+#[case("completed", "merged", true)]
+#[case("notSet", "open", false)]
+fn test_map_pull_request_status(
+    #[case] status: &str,
+    #[case] expected_state: &str,
+    #[case] expected_merged: bool,
+) {
+    let result = map_pull_request_status(status, Some("2024-01-01T00:00:00Z"));
+
+    assert_eq!(result.state, expected_state);
+    assert_eq!(result.merged, expected_merged);
+    assert_eq!(result.merged_at.is_some(), expected_merged);
+}
+
+// ==================== 变更类型标签测试 ====================
+
+#[test]
+fn test_map_change_types_to_labels() {
+    let labels = map_change_types_to_labels(&[true, false, false, true, false]);
+
+    assert_eq!(labels, vec!["bug-fix", "hotfix"]);
+}
+
+#[test]
+fn test_create_request_serializes_labels() {
+    let request = CreatePullRequestRequest {
+        source_ref_name: "refs/heads/feature/test".to_string(),
+        target_ref_name: "refs/heads/main".to_string(),
+        title: "Test PR".to_string(),
+        description: "Test body".to_string(),
+        labels: vec![],
+    };
+
+    let json = serde_json::to_value(&request).unwrap();
+
+    assert_eq!(json["sourceRefName"], "refs/heads/feature/test");
+    assert_eq!(json["targetRefName"], "refs/heads/main");
+    assert!(json.get("labels").is_none());
+}
+
+// ==================== 分支删除测试 ====================
+
+#[test]
+fn test_ref_update_serializes_camel_case() {
+    let update = GitRefUpdate {
+        name: "refs/heads/feature/test".to_string(),
+        old_object_id: "abc123".to_string(),
+        new_object_id: "0".repeat(40),
+    };
+
+    let json = serde_json::to_value(&update).unwrap();
+
+    assert_eq!(json["name"], "refs/heads/feature/test");
+    assert_eq!(json["oldObjectId"], "abc123");
+    assert_eq!(json["newObjectId"], "0".repeat(40));
+}
+
+#[test]
+fn test_pull_request_info_parses_fork_and_repository() {
+    let pr: PullRequestInfo = serde_json::from_value(serde_json::json!({
+        "pullRequestId": 42,
+        "title": "Test PR",
+        "status": "completed",
+        "sourceRefName": "refs/heads/feature/test",
+        "targetRefName": "refs/heads/main",
+        "repository": {"id": "repo-id", "defaultBranch": "refs/heads/main"},
+        "forkSource": {"name": "refs/heads/feature/test"}
+    }))
+    .unwrap();
+
+    let repository = pr.repository.unwrap();
+    assert_eq!(repository.id, "repo-id");
+    assert_eq!(
+        repository.default_branch.as_deref(),
+        Some("refs/heads/main")
+    );
+    assert_eq!(pr.fork_source.unwrap().name, "refs/heads/feature/test");
+    assert!(pr.labels.is_empty());
+}
@@ -0,0 +1,58 @@
+//! 根据 commit 创建 PR 测试
+//!
+//! 测试 `pr create --from-commits` 在 LLM 不可用时使用的 `CreateGenerator::from_commit_messages`。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use workflow::pr::llm::CreateGenerator;
+
+// ==================== 标题和 scope 测试 ====================
+
+#[rstest]
+#[case("feat(api): add retry support", "add retry support", Some("api"))]
+#[case("fix: handle empty response", "handle empty response", None)]
+#[case("Add retry support", "Add retry support", None)]
+#[case("PROJ-123: Add retry support", "PROJ-123: Add retry support", None)]
+fn test_from_commit_messages_title(
+    #[case] message: &str,
+    #[case] expected_title: &str,
+    #[case] expected_scope: Option<&str>,
+) {
+    let content = CreateGenerator::from_commit_messages(&[message.to_string()]).unwrap();
+
+    assert_eq!(content.pr_title, expected_title);
+    assert_eq!(content.scope.as_deref(), expected_scope);
+    // 单个 commit 不生成描述
+    assert_eq!(content.description, None);
+}
+
+#[test]
+fn test_from_commit_messages_uses_first_commit() {
+    let messages = vec![
+        "feat(cli): add from-commits flag\n\nCreate PRs without Jira.".to_string(),
+        "docs: document from-commits".to_string(),
+        "test: cover from-commits".to_string(),
+    ];
+
+    let content = CreateGenerator::from_commit_messages(&messages).unwrap();
+
+    assert_eq!(content.pr_title, "add from-commits flag");
+    assert_eq!(content.scope.as_deref(), Some("cli"));
+    assert_eq!(
+        content.description.as_deref(),
+        Some("- feat(cli): add from-commits flag\n- docs: document from-commits\n- test: cover from-commits")
+    );
+    assert!(!content.branch_name.is_empty());
+    assert!(content.chunking.is_none());
+}
+
+// ==================== 边界情况测试 ====================
+
+#[rstest]
+#[case(&[])]
+#[case(&["", "   "])]
+fn test_from_commit_messages_without_messages(#[case] messages: &[&str]) {
+    let messages: Vec<String> = messages.iter().map(|message| message.to_string()).collect();
+
+    assert!(CreateGenerator::from_commit_messages(&messages).is_none());
+}
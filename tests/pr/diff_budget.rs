@@ -0,0 +1,132 @@
+//! LLM diff 预算测试
+//!
+//! 测试 diff 按文件拆分、按字符数截断和预算检查的功能。
+
+use pretty_assertions::assert_eq;
+use workflow::pr::llm::{
+    exceeds_budget, format_file_summaries, prepare_diff_with_budget, split_diff_by_file,
+    truncate_diff, DiffChunking,
+};
+
+const TWO_FILE_DIFF: &str = "diff --git a/src/main.rs b/src/main.rs
+index 1111111..2222222 100644
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -1,3 +1,4 @@
+ fn main() {
++    println!(\"Hello\");
+ }
+diff --git a/README.md b/docs/README.md
+similarity index 90%
+rename from README.md
+rename to docs/README.md";
+
+// ==================== 拆分测试 ====================
+
+#[test]
+fn test_split_diff_by_file() {
+    let files = split_diff_by_file(TWO_FILE_DIFF);
+
+    assert_eq!(files.len(), 2);
+    assert_eq!(files[0].0, "src/main.rs");
+    assert!(files[0].1.starts_with("diff --git a/src/main.rs b/src/main.rs"));
+    assert!(files[0].1.ends_with(" }"));
+    assert_eq!(files[1].0, "docs/README.md");
+    assert!(files[1].1.ends_with("rename to docs/README.md"));
+}
+
+#[test]
+fn test_split_diff_ignores_leading_content() {
+    let diff = format!("From 1234 Mon Sep 17 00:00:00 2001\n\n{}", TWO_FILE_DIFF);
+    let files = split_diff_by_file(&diff);
+
+    assert_eq!(files.len(), 2);
+    assert!(files[0].1.starts_with("diff --git"));
+}
+
+#[test]
+fn test_split_diff_without_headers() {
+    assert!(split_diff_by_file("").is_empty());
+    assert!(split_diff_by_file("@@ -1 +1 @@\n-a\n+b").is_empty());
+}
+
+// ==================== 截断测试 ====================
+
+#[test]
+fn test_truncate_diff_within_limit() {
+    assert_eq!(truncate_diff("line1\nline2", 100, "diff"), "line1\nline2");
+}
+
+#[test]
+fn test_truncate_diff_at_newline() {
+    let truncated = truncate_diff("line1\nline2\nline3", 14, "git diff");
+    assert_eq!(
+        truncated,
+        "line1\nline2\n... (git diff truncated, 17 characters total)"
+    );
+}
+
+#[test]
+fn test_truncate_diff_multibyte() {
+    let truncated = truncate_diff("修改了文件", 2, "diff");
+    assert_eq!(truncated, "修改\n... (diff truncated, 5 characters total)");
+}
+
+// ==================== 预算测试 ====================
+
+#[test]
+fn test_exceeds_budget() {
+    assert!(!exceeds_budget("abc", 3));
+    assert!(exceeds_budget("abcd", 3));
+    // 按字符而不是字节计算
+    assert!(!exceeds_budget("修改", 2));
+}
+
+#[test]
+fn test_prepare_diff_within_budget() {
+    let prepared = prepare_diff_with_budget(TWO_FILE_DIFF, 100_000).unwrap();
+
+    assert_eq!(prepared.content, TWO_FILE_DIFF);
+    assert_eq!(prepared.chunking, None);
+}
+
+#[test]
+fn test_prepare_diff_without_file_headers_is_truncated() {
+    let diff = "@@ -1,2 +1,2 @@\n-old line\n+new line";
+    let prepared = prepare_diff_with_budget(diff, 20).unwrap();
+
+    assert_eq!(prepared.chunking, None);
+    assert_eq!(
+        prepared.content,
+        "@@ -1,2 +1,2 @@\n... (diff truncated, 35 characters total)"
+    );
+}
+
+#[test]
+fn test_format_file_summaries() {
+    let summaries = vec![
+        ("src/main.rs".to_string(), "Print a greeting.\n".to_string()),
+        (
+            "docs/README.md".to_string(),
+            "Move README into docs.".to_string(),
+        ),
+    ];
+
+    assert_eq!(
+        format_file_summaries(&summaries),
+        "### src/main.rs\nPrint a greeting.\n\n### docs/README.md\nMove README into docs."
+    );
+}
+
+#[test]
+fn test_diff_chunking_notice() {
+    let chunking = DiffChunking {
+        diff_chars: 50000,
+        budget: 30000,
+        files: 12,
+    };
+
+    let notice = chunking.notice();
+    assert!(notice.contains("50000 > 30000"));
+    assert!(notice.contains("12 file(s)"));
+}
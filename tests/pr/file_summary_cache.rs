@@ -0,0 +1,98 @@
+//! LLM 文件修改总结缓存测试
+//!
+//! 测试缓存键的计算、缓存的读写和清空。
+
+use pretty_assertions::assert_eq;
+use tempfile::tempdir;
+use workflow::pr::llm::FileSummaryCache;
+
+const FILE_DIFF: &str = "diff --git a/src/main.rs b/src/main.rs
+--- a/src/main.rs
++++ b/src/main.rs
+@@ -1,3 +1,4 @@
+ fn main() {
++    println!(\"Hello\");
+ }
+";
+
+// ==================== 缓存键测试 ====================
+
+#[test]
+fn test_cache_key_is_stable() {
+    let key = FileSummaryCache::key("src/main.rs", FILE_DIFF, "gpt-4.0", "prompt");
+
+    assert_eq!(
+        key,
+        FileSummaryCache::key("src/main.rs", FILE_DIFF, "gpt-4.0", "prompt")
+    );
+    assert_eq!(key.len(), 64);
+    assert!(key.chars().all(|c| c.is_ascii_hexdigit()));
+}
+
+#[test]
+fn test_cache_key_changes_with_each_input() {
+    let key = FileSummaryCache::key("src/main.rs", FILE_DIFF, "gpt-4.0", "prompt");
+
+    assert_ne!(
+        key,
+        FileSummaryCache::key("src/lib.rs", FILE_DIFF, "gpt-4.0", "prompt")
+    );
+    assert_ne!(
+        key,
+        FileSummaryCache::key("src/main.rs", "+changed", "gpt-4.0", "prompt")
+    );
+    assert_ne!(
+        key,
+        FileSummaryCache::key("src/main.rs", FILE_DIFF, "deepseek-chat", "prompt")
+    );
+    // prompt 模板变化时缓存键也变化
+    assert_ne!(
+        key,
+        FileSummaryCache::key("src/main.rs", FILE_DIFF, "gpt-4.0", "prompt v2")
+    );
+}
+
+#[test]
+fn test_cache_key_separates_fields() {
+    // 字段拼接结果相同但字段边界不同时，缓存键不同
+    assert_ne!(
+        FileSummaryCache::key("ab", "c", "m", "p"),
+        FileSummaryCache::key("a", "bc", "m", "p")
+    );
+}
+
+// ==================== 缓存读写测试 ====================
+
+#[test]
+fn test_cache_miss_then_hit() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let cache = FileSummaryCache::new(temp_dir.path().join("file-summaries"));
+    let key = FileSummaryCache::key("src/main.rs", FILE_DIFF, "gpt-4.0", "prompt");
+
+    assert_eq!(cache.get(&key), None);
+
+    cache.put(&key, "- Print a greeting").unwrap();
+
+    assert_eq!(cache.get(&key), Some("- Print a greeting".to_string()));
+}
+
+#[test]
+fn test_cache_clear() {
+    let temp_dir = tempdir().expect("Failed to create temp dir");
+    let cache = FileSummaryCache::new(temp_dir.path().join("file-summaries"));
+
+    // 缓存目录不存在时不报错
+    assert_eq!(cache.clear().unwrap(), 0);
+
+    let first = FileSummaryCache::key("a.rs", "+a", "gpt-4.0", "prompt");
+    let second = FileSummaryCache::key("b.rs", "+b", "gpt-4.0", "prompt");
+    cache.put(&first, "summary a").unwrap();
+    cache.put(&second, "summary b").unwrap();
+    // 非缓存文件不会被删除
+    std::fs::write(cache.dir().join("README"), "keep").unwrap();
+
+    assert_eq!(cache.clear().unwrap(), 2);
+    assert_eq!(cache.get(&first), None);
+    assert_eq!(cache.get(&second), None);
+    assert!(cache.dir().join("README").exists());
+}
@@ -6,8 +6,14 @@ use pretty_assertions::assert_eq;
 use rstest::{fixture, rstest};
 
 use workflow::pr::github::{
-    requests::{CreatePullRequestRequest, MergePullRequestRequest, UpdatePullRequestRequest},
-    responses::{CreatePullRequestResponse, GitHubUser, PullRequestBranch, PullRequestInfo},
+    requests::{
+        CreatePullRequestRequest, CreateReviewCommentRequest, MergePullRequestRequest,
+        ReplyReviewCommentRequest, UpdatePullRequestRequest,
+    },
+    responses::{
+        BranchInfo, CreatePullRequestResponse, GitHubUser, PullRequestBranch, PullRequestHead,
+        PullRequestHeadCommit, PullRequestInfo,
+    },
 };
 
 // ==================== Fixtures ====================
@@ -183,6 +189,41 @@ fn test_update_pr_request_serialization(
     }
 }
 
+#[test]
+fn test_create_review_comment_request_serialization() {
+    let request = CreateReviewCommentRequest {
+        body: "Needs a test".to_string(),
+        commit_id: "abc123".to_string(),
+        path: "src/main.rs".to_string(),
+        line: 42,
+        side: "RIGHT".to_string(),
+    };
+
+    let json = serde_json::to_value(&request).unwrap();
+
+    assert_eq!(
+        json,
+        serde_json::json!({
+            "body": "Needs a test",
+            "commit_id": "abc123",
+            "path": "src/main.rs",
+            "line": 42,
+            "side": "RIGHT"
+        })
+    );
+}
+
+#[test]
+fn test_reply_review_comment_request_serialization() {
+    let request = ReplyReviewCommentRequest {
+        body: "Done".to_string(),
+    };
+
+    let json = serde_json::to_value(&request).unwrap();
+
+    assert_eq!(json, serde_json::json!({ "body": "Done" }));
+}
+
 // ==================== 响应结构体测试 ====================
 
 #[test]
@@ -225,6 +266,7 @@ fn test_pull_request_info_structure() {
             ref_name: "main".to_string(),
         },
         user: None,
+        labels: Vec::new(),
     };
 
     assert_eq!(pr_info.number, 123);
@@ -350,6 +392,68 @@ fn test_github_user_deserialization() {
     assert_eq!(user.name, Some("Test User".to_string()));
 }
 
+#[test]
+fn test_pull_request_info_deserialization_with_labels() {
+    let json = r#"{
+        "number": 7,
+        "title": "Fix login",
+        "state": "open",
+        "html_url": "https://github.com/owner/repo/pull/7",
+        "head": {"ref": "fix/login"},
+        "base": {"ref": "main"},
+        "labels": [{"name": "bug-fix"}, {"name": "urgent"}]
+    }"#;
+
+    let pr_info: PullRequestInfo = serde_json::from_str(json).unwrap();
+
+    let labels: Vec<&str> = pr_info.labels.iter().map(|l| l.name.as_str()).collect();
+    assert_eq!(labels, vec!["bug-fix", "urgent"]);
+}
+
+#[test]
+fn test_pull_request_head_deserialization() {
+    let json = r#"{
+        "head": {
+            "ref": "feature/test",
+            "repo": {"full_name": "fork-owner/repo", "default_branch": "main"}
+        }
+    }"#;
+
+    let pr: PullRequestHead = serde_json::from_str(json).unwrap();
+
+    assert_eq!(pr.head.ref_name, "feature/test");
+    let repo = pr.head.repo.unwrap();
+    assert_eq!(repo.full_name, "fork-owner/repo");
+    assert_eq!(repo.default_branch.as_deref(), Some("main"));
+}
+
+#[test]
+fn test_pull_request_head_commit_deserialization() {
+    let json = r#"{"number": 1, "head": {"sha": "abc123", "ref": "feature/x"}}"#;
+
+    let pr: PullRequestHeadCommit = serde_json::from_str(json).unwrap();
+
+    assert_eq!(pr.head.sha, "abc123");
+}
+
+#[test]
+fn test_pull_request_head_with_deleted_repo() {
+    let pr: PullRequestHead =
+        serde_json::from_str(r#"{"head": {"ref": "feature/test", "repo": null}}"#).unwrap();
+
+    assert!(pr.head.repo.is_none());
+}
+
+#[rstest]
+#[case(r#"{"name": "main", "protected": true}"#, true)]
+#[case(r#"{"name": "feature/test", "protected": false}"#, false)]
+#[case(r#"{"name": "feature/test"}"#, false)]
+fn test_branch_info_deserialization(#[case] json: &str, #[case] protected: bool) {
+    let branch: BranchInfo = serde_json::from_str(json).unwrap();
+
+    assert_eq!(branch.protected, protected);
+}
+
 // ==================== 序列化/反序列化边界测试 ====================
 
 #[rstest]
@@ -457,6 +561,7 @@ fn test_response_type_safety() {
             ref_name: "base".to_string(),
         },
         user: None,
+        labels: Vec::new(),
     };
 
     // 验证类型正确（通过编译验证）
@@ -0,0 +1,183 @@
+//! GitHub 自动合并模块测试
+//!
+//! 测试 GraphQL 响应解析、合并方式映射，以及开启自动合并前的检查。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use serde_json::{json, Value};
+use workflow::pr::github::auto_merge::{
+    graphql_merge_method, parse_graphql_merge_method, AutoMergeContextData, AutoMergeRepository,
+    EnableAutoMergeData, GraphQLResponse,
+};
+use workflow::pr::MergeMethod;
+
+/// 构造查询结果（默认可以开启自动合并）
+fn repository(overrides: Value) -> AutoMergeRepository {
+    let mut repository = json!({
+        "autoMergeAllowed": true,
+        "squashMergeAllowed": true,
+        "rebaseMergeAllowed": true,
+        "mergeCommitAllowed": true,
+        "pullRequest": {
+            "id": "PR_kwDOABCD",
+            "number": 42,
+            "state": "OPEN",
+            "isDraft": false,
+            "baseRefName": "main",
+            "baseRef": {
+                "branchProtectionRule": {
+                    "requiresStatusChecks": true,
+                    "requiredStatusCheckContexts": ["build", "test"]
+                }
+            }
+        }
+    });
+    merge_json(&mut repository, overrides);
+    serde_json::from_value(repository).expect("Should parse repository")
+}
+
+fn merge_json(target: &mut Value, overrides: Value) {
+    match (target, overrides) {
+        (Value::Object(target), Value::Object(overrides)) => {
+            for (key, value) in overrides {
+                merge_json(target.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (target, value) => *target = value,
+    }
+}
+
+// ==================== 合并方式映射测试 ====================
+
+#[rstest]
+#[case(MergeMethod::Squash, "SQUASH")]
+#[case(MergeMethod::Rebase, "REBASE")]
+#[case(MergeMethod::Merge, "MERGE")]
+fn test_graphql_merge_method_round_trip(#[case] method: MergeMethod, #[case] name: &str) {
+    assert_eq!(graphql_merge_method(method), name);
+    assert_eq!(parse_graphql_merge_method(name), Some(method));
+}
+
+#[test]
+fn test_parse_graphql_merge_method_unknown() {
+    assert_eq!(parse_graphql_merge_method("FAST_FORWARD"), None);
+}
+
+// ==================== GraphQL 响应测试 ====================
+
+#[test]
+fn test_graphql_response_into_data() {
+    let response: GraphQLResponse<AutoMergeContextData> =
+        serde_json::from_value(json!({ "data": { "repository": null } }))
+            .expect("Should parse response");
+
+    let data = response.into_data().expect("Should return data");
+    assert!(data.repository.is_none());
+}
+
+#[test]
+fn test_graphql_response_errors() {
+    let response: GraphQLResponse<EnableAutoMergeData> = serde_json::from_value(json!({
+        "data": { "enablePullRequestAutoMerge": null },
+        "errors": [
+            { "message": "Pull request Auto merge is not allowed for this repository" },
+            { "message": "Pull request is in clean status" }
+        ]
+    }))
+    .expect("Should parse response");
+
+    let error = response.into_data().expect_err("Should return error").to_string();
+    assert_eq!(
+        error,
+        "GitHub GraphQL error: Pull request Auto merge is not allowed for this repository; \
+         Pull request is in clean status"
+    );
+}
+
+#[test]
+fn test_enable_auto_merge_response() {
+    let response: GraphQLResponse<EnableAutoMergeData> = serde_json::from_value(json!({
+        "data": {
+            "enablePullRequestAutoMerge": {
+                "pullRequest": { "autoMergeRequest": { "mergeMethod": "REBASE" } }
+            }
+        }
+    }))
+    .expect("Should parse response");
+
+    let method = response
+        .into_data()
+        .expect("Should return data")
+        .enable_pull_request_auto_merge
+        .and_then(|payload| payload.pull_request)
+        .and_then(|pr| pr.auto_merge_request)
+        .map(|request| request.merge_method);
+    assert_eq!(method.as_deref(), Some("REBASE"));
+}
+
+// ==================== 开启前检查测试 ====================
+
+#[test]
+fn test_validate_auto_merge_defaults_to_squash() {
+    let repository = repository(json!({}));
+
+    let (node_id, method) = repository.validate_auto_merge(None).expect("Should validate");
+    assert_eq!(node_id, "PR_kwDOABCD");
+    assert_eq!(method, MergeMethod::Squash);
+}
+
+#[test]
+fn test_validate_auto_merge_picks_first_allowed_method() {
+    let repository = repository(json!({ "squashMergeAllowed": false }));
+
+    let (_, method) = repository.validate_auto_merge(None).expect("Should validate");
+    assert_eq!(method, MergeMethod::Rebase);
+}
+
+#[test]
+fn test_validate_auto_merge_uses_requested_method() {
+    let repository = repository(json!({}));
+
+    let (_, method) = repository
+        .validate_auto_merge(Some(MergeMethod::Merge))
+        .expect("Should validate");
+    assert_eq!(method, MergeMethod::Merge);
+}
+
+#[rstest]
+#[case(json!({ "autoMergeAllowed": false }), "Auto-merge is not allowed in this repository")]
+#[case(
+    json!({ "pullRequest": { "baseRef": { "branchProtectionRule": null } } }),
+    "Branch 'main' has no required status checks configured"
+)]
+#[case(
+    json!({ "pullRequest": { "baseRef": { "branchProtectionRule": { "requiresStatusChecks": false } } } }),
+    "Branch 'main' has no required status checks configured"
+)]
+#[case(
+    json!({ "pullRequest": { "baseRef": { "branchProtectionRule": { "requiredStatusCheckContexts": [] } } } }),
+    "Branch 'main' has no required status checks configured"
+)]
+#[case(json!({ "pullRequest": { "state": "MERGED" } }), "PR #42 is merged")]
+#[case(json!({ "pullRequest": { "isDraft": true } }), "PR #42 is a draft")]
+#[case(json!({ "pullRequest": null }), "Pull request not found")]
+fn test_validate_auto_merge_errors(#[case] overrides: Value, #[case] expected: &str) {
+    let repository = repository(overrides);
+
+    let error = repository.validate_auto_merge(None).expect_err("Should fail").to_string();
+    assert!(error.starts_with(expected), "unexpected error: {}", error);
+}
+
+#[test]
+fn test_validate_auto_merge_requested_method_not_allowed() {
+    let repository = repository(json!({ "mergeCommitAllowed": false }));
+
+    let error = repository
+        .validate_auto_merge(Some(MergeMethod::Merge))
+        .expect_err("Should fail")
+        .to_string();
+    assert_eq!(
+        error,
+        "Merge method 'merge commit' is not allowed in this repository"
+    );
+}
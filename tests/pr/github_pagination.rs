@@ -0,0 +1,49 @@
+//! GitHub API 分页测试
+//!
+//! 测试 `Link` 响应头中下一页地址的解析。
+
+use pretty_assertions::assert_eq;
+use reqwest::header::{HeaderMap, HeaderValue, LINK};
+use rstest::rstest;
+
+use workflow::pr::github::pagination::{next_page_url, parse_next_link};
+
+// ==================== Link 头解析测试 ====================
+
+#[rstest]
+#[case(
+    r#"<https://api.github.com/repos/o/r/pulls?page=2>; rel="next", <https://api.github.com/repos/o/r/pulls?page=5>; rel="last""#,
+    Some("https://api.github.com/repos/o/r/pulls?page=2")
+)]
+#[case(
+    r#"<https://api.github.com/repos/o/r/pulls?page=1>; rel="prev", <https://api.github.com/repos/o/r/pulls?page=3>; rel="next""#,
+    Some("https://api.github.com/repos/o/r/pulls?page=3")
+)]
+#[case(
+    r#"<https://api.github.com/repos/o/r/issues?labels=bug,ui&page=2>; rel="next""#,
+    Some("https://api.github.com/repos/o/r/issues?labels=bug,ui&page=2")
+)]
+#[case(
+    r#"<https://api.github.com/repos/o/r/pulls?page=2>;rel=next"#,
+    Some("https://api.github.com/repos/o/r/pulls?page=2")
+)]
+#[case(r#"<https://api.github.com/repos/o/r/pulls?page=1>; rel="prev""#, None)]
+#[case("", None)]
+fn test_parse_next_link(#[case] link: &str, #[case] expected: Option<&str>) {
+    assert_eq!(parse_next_link(link).as_deref(), expected);
+}
+
+#[test]
+fn test_next_page_url_from_headers() {
+    let mut headers = HeaderMap::new();
+    assert_eq!(next_page_url(&headers), None);
+
+    headers.insert(
+        LINK,
+        HeaderValue::from_static(r#"<https://api.github.com/repos/o/r/pulls?page=2>; rel="next""#),
+    );
+    assert_eq!(
+        next_page_url(&headers).as_deref(),
+        Some("https://api.github.com/repos/o/r/pulls?page=2")
+    );
+}
@@ -0,0 +1,197 @@
+//! GitHub API 速率限制测试
+//!
+//! 测试速率限制响应头的解析、主要/二级限制的判断和等待时间的格式化。
+
+use std::time::Duration;
+
+use pretty_assertions::assert_eq;
+use reqwest::header::HeaderMap;
+use rstest::rstest;
+
+use workflow::pr::github::rate_limit::{
+    detect_rate_limit, format_duration, parse_retry_after, RateLimitInfo, RateLimitKind,
+    SECONDARY_LIMIT_DEFAULT_WAIT,
+};
+use workflow::pr::github::responses::RateLimitResponse;
+
+const NOW: i64 = 1_700_000_000;
+
+fn headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    for (name, value) in pairs {
+        headers.insert(*name, value.parse().unwrap());
+    }
+    headers
+}
+
+fn exhausted_headers(reset: i64) -> HeaderMap {
+    headers(&[
+        ("x-ratelimit-limit", "5000"),
+        ("x-ratelimit-remaining", "0"),
+        ("x-ratelimit-used", "5000"),
+        ("x-ratelimit-reset", &reset.to_string()),
+        ("x-ratelimit-resource", "core"),
+    ])
+}
+
+// ==================== 响应头解析测试 ====================
+
+#[test]
+fn test_rate_limit_info_from_headers() {
+    let info = RateLimitInfo::from_headers(&exhausted_headers(NOW + 120)).unwrap();
+
+    assert_eq!(
+        info,
+        RateLimitInfo {
+            limit: Some(5000),
+            remaining: Some(0),
+            used: Some(5000),
+            reset: Some(NOW + 120),
+            resource: Some("core".to_string()),
+        }
+    );
+    assert!(info.is_exhausted());
+    assert_eq!(info.reset_in(NOW), Some(Duration::from_secs(120)));
+}
+
+#[test]
+fn test_rate_limit_info_from_headers_without_rate_limit_headers() {
+    assert_eq!(RateLimitInfo::from_headers(&HeaderMap::new()), None);
+    assert_eq!(
+        RateLimitInfo::from_headers(&headers(&[("content-type", "application/json")])),
+        None
+    );
+}
+
+#[test]
+fn test_rate_limit_info_remaining_quota() {
+    let info = RateLimitInfo::from_headers(&headers(&[("x-ratelimit-remaining", "42")])).unwrap();
+
+    assert!(!info.is_exhausted());
+    assert_eq!(info.reset_in(NOW), None);
+}
+
+#[test]
+fn test_rate_limit_info_reset_in_past() {
+    let info = RateLimitInfo {
+        reset: Some(NOW - 10),
+        ..Default::default()
+    };
+
+    assert_eq!(info.reset_in(NOW), None);
+}
+
+// ==================== 限制类型判断测试 ====================
+
+#[test]
+fn test_detect_primary_rate_limit() {
+    let hit = detect_rate_limit(
+        403,
+        &exhausted_headers(NOW + 90),
+        r#"{"message": "API rate limit exceeded for user ID 1."}"#,
+        NOW,
+    )
+    .unwrap();
+
+    assert_eq!(hit.kind, RateLimitKind::Primary);
+    assert_eq!(hit.wait, Duration::from_secs(90));
+    assert!(hit.message().contains("5000/5000 requests used for 'core'"));
+    assert!(hit.message().contains("in 1m 30s"));
+}
+
+#[test]
+fn test_detect_secondary_rate_limit_by_message() {
+    let hit = detect_rate_limit(
+        403,
+        &headers(&[("x-ratelimit-remaining", "4000")]),
+        r#"{"message": "You have exceeded a secondary rate limit."}"#,
+        NOW,
+    )
+    .unwrap();
+
+    assert_eq!(hit.kind, RateLimitKind::Secondary);
+    assert_eq!(hit.wait, SECONDARY_LIMIT_DEFAULT_WAIT);
+    assert!(hit.message().contains("secondary rate limit"));
+}
+
+#[test]
+fn test_detect_secondary_rate_limit_by_retry_after() {
+    let hit = detect_rate_limit(
+        403,
+        &headers(&[("x-ratelimit-remaining", "4000"), ("retry-after", "30")]),
+        "",
+        NOW,
+    )
+    .unwrap();
+
+    assert_eq!(hit.kind, RateLimitKind::Secondary);
+    assert_eq!(hit.wait, Duration::from_secs(30));
+}
+
+#[test]
+fn test_detect_rate_limit_429_without_headers() {
+    let hit = detect_rate_limit(429, &HeaderMap::new(), "", NOW).unwrap();
+
+    assert_eq!(hit.kind, RateLimitKind::Secondary);
+    assert_eq!(hit.wait, SECONDARY_LIMIT_DEFAULT_WAIT);
+}
+
+#[rstest]
+#[case(403, "Resource not accessible by integration")]
+#[case(404, "Not Found")]
+#[case(200, "")]
+fn test_detect_rate_limit_not_rate_limited(#[case] status: u16, #[case] body: &str) {
+    let headers = headers(&[("x-ratelimit-remaining", "4999")]);
+
+    assert_eq!(detect_rate_limit(status, &headers, body, NOW), None);
+}
+
+#[test]
+fn test_detect_rate_limit_ignores_exhausted_quota_on_success() {
+    assert_eq!(
+        detect_rate_limit(200, &exhausted_headers(NOW + 60), "", NOW),
+        None
+    );
+}
+
+// ==================== 等待时间测试 ====================
+
+#[rstest]
+#[case("30", Some(Duration::from_secs(30)))]
+#[case(" 5 ", Some(Duration::from_secs(5)))]
+#[case("Wed, 21 Oct 2015 07:28:00 GMT", None)]
+#[case("", None)]
+fn test_parse_retry_after(#[case] value: &str, #[case] expected: Option<Duration>) {
+    assert_eq!(parse_retry_after(value), expected);
+}
+
+#[rstest]
+#[case(0, "0s")]
+#[case(45, "45s")]
+#[case(180, "3m")]
+#[case(200, "3m 20s")]
+fn test_format_duration(#[case] secs: u64, #[case] expected: &str) {
+    assert_eq!(format_duration(Duration::from_secs(secs)), expected);
+}
+
+// ==================== 响应解析测试 ====================
+
+#[test]
+fn test_rate_limit_response_deserialization() {
+    let json = r#"{
+        "resources": {
+            "core": {"limit": 5000, "used": 12, "remaining": 4988, "reset": 1700003600},
+            "search": {"limit": 30, "used": 0, "remaining": 30, "reset": 1700000060}
+        },
+        "rate": {"limit": 5000, "used": 12, "remaining": 4988, "reset": 1700003600}
+    }"#;
+
+    let response: RateLimitResponse = serde_json::from_str(json).unwrap();
+
+    assert_eq!(response.resources.len(), 2);
+    let core = &response.resources["core"];
+    assert_eq!(core.limit, 5000);
+    assert_eq!(core.used, 12);
+    assert_eq!(core.remaining, 4988);
+    assert_eq!(core.reset, 1_700_003_600);
+}
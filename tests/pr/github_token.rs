@@ -0,0 +1,82 @@
+//! GitHub API Token 校验测试
+//!
+//! 测试 `X-OAuth-Scopes` 响应头的解析和必需权限范围检查。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+
+use workflow::pr::github::responses::GitHubUser;
+use workflow::pr::github::token::{
+    missing_scopes, parse_oauth_scopes, GitHubTokenInfo, REQUIRED_TOKEN_SCOPES,
+};
+
+fn scopes(list: &[&str]) -> Vec<String> {
+    list.iter().map(|s| s.to_string()).collect()
+}
+
+fn token_info(scopes: Option<Vec<String>>) -> GitHubTokenInfo {
+    GitHubTokenInfo {
+        user: GitHubUser {
+            login: "octocat".to_string(),
+            name: None,
+            email: None,
+        },
+        scopes,
+    }
+}
+
+// ==================== 响应头解析测试 ====================
+
+#[rstest]
+#[case("repo, read:org", &["repo", "read:org"])]
+#[case("repo,read:org,gist", &["repo", "read:org", "gist"])]
+#[case("  repo  ", &["repo"])]
+#[case("", &[])]
+#[case(" , ", &[])]
+fn test_parse_oauth_scopes(#[case] header: &str, #[case] expected: &[&str]) {
+    assert_eq!(parse_oauth_scopes(header), scopes(expected));
+}
+
+// ==================== 权限范围检查测试 ====================
+
+#[test]
+fn test_missing_scopes_all_granted() {
+    assert!(missing_scopes(&scopes(&["repo", "read:org"])).is_empty());
+}
+
+#[test]
+fn test_missing_scopes_none_granted() {
+    assert_eq!(missing_scopes(&[]), REQUIRED_TOKEN_SCOPES.to_vec());
+}
+
+#[test]
+fn test_missing_scopes_reports_only_missing() {
+    assert_eq!(missing_scopes(&scopes(&["repo", "gist"])), vec!["read:org"]);
+    assert_eq!(missing_scopes(&scopes(&["read:org"])), vec!["repo"]);
+}
+
+#[rstest]
+#[case("write:org")]
+#[case("admin:org")]
+fn test_missing_scopes_higher_org_scope_satisfies_read(#[case] org_scope: &str) {
+    assert!(missing_scopes(&scopes(&["repo", org_scope])).is_empty());
+}
+
+#[test]
+fn test_missing_scopes_sub_scope_does_not_satisfy_repo() {
+    // public_repo 只授予公共仓库权限，不满足 repo
+    assert_eq!(
+        missing_scopes(&scopes(&["public_repo", "read:org"])),
+        vec!["repo"]
+    );
+}
+
+#[test]
+fn test_token_info_without_scopes_header_reports_nothing_missing() {
+    // fine-grained token 不返回 X-OAuth-Scopes，无法判断权限
+    assert!(token_info(None).missing_scopes().is_empty());
+    assert_eq!(
+        token_info(Some(scopes(&["repo"]))).missing_scopes(),
+        vec!["read:org"]
+    );
+}
@@ -0,0 +1,128 @@
+//! PR 列表筛选条件测试
+//!
+//! 测试作者、标签的本地筛选和默认数量限制。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use workflow::pr::{PrListFilter, PrListSort};
+
+fn filter(author: Option<&str>, label: Option<&str>) -> PrListFilter {
+    PrListFilter {
+        author: author.map(str::to_string),
+        label: label.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+// ==================== 默认值测试 ====================
+
+#[test]
+fn test_default_filter() {
+    let filter = PrListFilter::default();
+
+    assert_eq!(filter.sort, PrListSort::Created);
+    assert_eq!(filter.limit(), PrListFilter::DEFAULT_LIMIT);
+    assert!(!filter.needs_local_filter());
+    assert!(filter.matches(&[], &[]));
+}
+
+#[test]
+fn test_limit() {
+    let filter = PrListFilter {
+        limit: Some(5),
+        ..Default::default()
+    };
+
+    assert_eq!(filter.limit(), 5);
+}
+
+#[rstest]
+#[case(None, None, false)]
+#[case(Some("alice"), None, true)]
+#[case(None, Some("bug-fix"), true)]
+fn test_needs_local_filter(
+    #[case] author: Option<&str>,
+    #[case] label: Option<&str>,
+    #[case] expected: bool,
+) {
+    assert_eq!(filter(author, label).needs_local_filter(), expected);
+}
+
+#[test]
+fn test_mine_does_not_need_local_filter() {
+    let filter = PrListFilter {
+        mine: true,
+        ..Default::default()
+    };
+
+    assert!(!filter.needs_local_filter());
+}
+
+// ==================== 筛选测试 ====================
+
+#[rstest]
+#[case(&["alice"], true)]
+#[case(&["ALICE"], true)]
+#[case(&["alice@example.com", "Alice"], true)]
+#[case(&["bob"], false)]
+#[case(&[], false)]
+fn test_matches_author(#[case] authors: &[&str], #[case] expected: bool) {
+    assert_eq!(
+        filter(Some("alice"), None).matches(authors, &["feature"]),
+        expected
+    );
+}
+
+#[rstest]
+#[case(&["bug-fix"], true)]
+#[case(&["feature", "Bug-Fix"], true)]
+#[case(&["feature"], false)]
+#[case(&[], false)]
+fn test_matches_label(#[case] labels: &[&str], #[case] expected: bool) {
+    assert_eq!(
+        filter(None, Some("bug-fix")).matches(&["alice"], labels),
+        expected
+    );
+}
+
+#[test]
+fn test_matches_author_and_label() {
+    let filter = filter(Some("alice"), Some("bug-fix"));
+
+    assert!(filter.matches(&["alice"], &["bug-fix"]));
+    assert!(!filter.matches(&["alice"], &["feature"]));
+    assert!(!filter.matches(&["bob"], &["bug-fix"]));
+}
+
+// ==================== 状态筛选测试 ====================
+
+#[rstest]
+#[case(None, "open", false, true)]
+#[case(Some("all"), "closed", true, true)]
+#[case(Some("open"), "open", false, true)]
+#[case(Some("open"), "closed", false, false)]
+#[case(Some("closed"), "closed", false, true)]
+#[case(Some("closed"), "closed", true, true)]
+#[case(Some("closed"), "open", false, false)]
+#[case(Some("merged"), "closed", true, true)]
+#[case(Some("merged"), "closed", false, false)]
+#[case(Some("merged"), "open", false, false)]
+fn test_matches_state(
+    #[case] state_filter: Option<&str>,
+    #[case] state: &str,
+    #[case] merged: bool,
+    #[case] expected: bool,
+) {
+    let filter = PrListFilter {
+        state: state_filter.map(str::to_string),
+        ..Default::default()
+    };
+
+    assert_eq!(filter.matches_state(state, merged), expected);
+}
+
+#[test]
+fn test_sort_display() {
+    assert_eq!(PrListSort::Created.to_string(), "created");
+    assert_eq!(PrListSort::Updated.to_string(), "updated");
+}
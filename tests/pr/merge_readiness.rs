@@ -0,0 +1,261 @@
+//! PR 合并前检查模块测试
+//!
+//! 测试 CI 检查状态映射、检查汇总、评审统计以及阻止合并原因的判断。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use workflow::pr::readiness::{
+    collect_checks, CheckState, MergeCheck, MergeMethod, MergeReadiness, ReviewStatus,
+};
+
+fn ready() -> MergeReadiness {
+    MergeReadiness {
+        state: "open".to_string(),
+        merged: false,
+        mergeable: Some(true),
+        mergeable_state: Some("clean".to_string()),
+        checks: vec![MergeCheck {
+            name: "build".to_string(),
+            state: CheckState::Passed,
+            required: true,
+        }],
+        reviews: ReviewStatus {
+            approvals: 1,
+            required_approvals: Some(1),
+            ..Default::default()
+        },
+        merge_methods: MergeMethod::ALL.iter().map(|method| (*method, true)).collect(),
+    }
+}
+
+// ==================== CheckState 映射测试 ====================
+
+#[rstest]
+#[case("completed", Some("success"), CheckState::Passed)]
+#[case("completed", Some("failure"), CheckState::Failed)]
+#[case("completed", Some("cancelled"), CheckState::Failed)]
+#[case("completed", Some("action_required"), CheckState::Failed)]
+#[case("completed", Some("neutral"), CheckState::Skipped)]
+#[case("completed", Some("skipped"), CheckState::Skipped)]
+#[case("queued", None, CheckState::Pending)]
+#[case("in_progress", None, CheckState::Pending)]
+fn test_check_state_from_check_run(
+    #[case] status: &str,
+    #[case] conclusion: Option<&str>,
+    #[case] expected: CheckState,
+) {
+    assert_eq!(CheckState::from_check_run(status, conclusion), expected);
+}
+
+#[rstest]
+#[case("success", CheckState::Passed)]
+#[case("failure", CheckState::Failed)]
+#[case("error", CheckState::Failed)]
+#[case("pending", CheckState::Pending)]
+fn test_check_state_from_commit_status(#[case] state: &str, #[case] expected: CheckState) {
+    assert_eq!(CheckState::from_commit_status(state), expected);
+}
+
+// ==================== collect_checks 测试 ====================
+
+#[test]
+fn test_collect_checks_keeps_first_result_per_name() {
+    let checks = collect_checks(
+        [
+            ("build".to_string(), CheckState::Failed),
+            ("build".to_string(), CheckState::Passed),
+            ("lint".to_string(), CheckState::Passed),
+        ],
+        &[],
+    );
+
+    assert_eq!(checks.len(), 2);
+    assert_eq!(checks[0].state, CheckState::Failed);
+    assert!(!checks[0].required);
+}
+
+#[test]
+fn test_collect_checks_adds_missing_required_checks() {
+    let checks = collect_checks(
+        [("build".to_string(), CheckState::Passed)],
+        &["build".to_string(), "e2e".to_string()],
+    );
+
+    assert_eq!(
+        checks,
+        vec![
+            MergeCheck {
+                name: "build".to_string(),
+                state: CheckState::Passed,
+                required: true,
+            },
+            MergeCheck {
+                name: "e2e".to_string(),
+                state: CheckState::Expected,
+                required: true,
+            },
+        ]
+    );
+}
+
+// ==================== ReviewStatus 测试 ====================
+
+#[test]
+fn test_review_status_uses_latest_review_per_user() {
+    let status = ReviewStatus::from_reviews(
+        [
+            ("alice", "APPROVED"),
+            ("bob", "APPROVED"),
+            ("bob", "CHANGES_REQUESTED"),
+            ("alice", "COMMENTED"),
+        ],
+        Some(2),
+    );
+
+    assert_eq!(status.approvals, 1);
+    assert!(status.changes_requested);
+    assert!(!status.is_satisfied());
+}
+
+#[test]
+fn test_review_status_dismissed_review_clears_changes_requested() {
+    let status =
+        ReviewStatus::from_reviews([("bob", "CHANGES_REQUESTED"), ("bob", "DISMISSED")], None);
+
+    assert_eq!(status.approvals, 0);
+    assert!(!status.changes_requested);
+    assert!(status.is_satisfied());
+}
+
+#[test]
+fn test_review_status_pending_reviewers_not_satisfied() {
+    let status = ReviewStatus {
+        approvals: 2,
+        pending_reviewers: vec!["Team Lead".to_string()],
+        ..Default::default()
+    };
+
+    assert!(!status.is_satisfied());
+}
+
+// ==================== MergeReadiness 测试 ====================
+
+#[test]
+fn test_merge_readiness_ready_to_merge() {
+    let readiness = ready();
+
+    assert!(readiness.can_merge());
+    assert_eq!(readiness.allowed_merge_methods(), MergeMethod::ALL.to_vec());
+}
+
+#[test]
+fn test_merge_readiness_already_merged() {
+    let readiness = MergeReadiness {
+        merged: true,
+        state: "closed".to_string(),
+        ..ready()
+    };
+
+    assert_eq!(readiness.blockers(), vec!["PR has already been merged"]);
+}
+
+#[test]
+fn test_merge_readiness_reports_all_blockers() {
+    let readiness = MergeReadiness {
+        mergeable: Some(false),
+        mergeable_state: Some("dirty".to_string()),
+        checks: collect_checks(
+            [
+                ("build".to_string(), CheckState::Failed),
+                ("lint".to_string(), CheckState::Failed),
+                ("test".to_string(), CheckState::Pending),
+            ],
+            &["build".to_string(), "test".to_string(), "e2e".to_string()],
+        ),
+        reviews: ReviewStatus {
+            approvals: 0,
+            required_approvals: Some(2),
+            changes_requested: true,
+            ..Default::default()
+        },
+        ..ready()
+    };
+
+    assert_eq!(readiness.failing_checks().len(), 2);
+    assert_eq!(readiness.pending_required_checks().len(), 2);
+    assert_eq!(
+        readiness.blockers(),
+        vec![
+            "PR has merge conflicts",
+            "Required check 'build' failed",
+            "Required check 'test' is still running",
+            "Required check 'e2e' has not reported yet",
+            "A reviewer requested changes",
+            "0 of 2 required approvals",
+        ]
+    );
+}
+
+#[test]
+fn test_merge_readiness_optional_check_failure_does_not_block() {
+    let mut readiness = ready();
+    readiness.checks.push(MergeCheck {
+        name: "coverage".to_string(),
+        state: CheckState::Failed,
+        required: false,
+    });
+
+    assert_eq!(readiness.failing_checks().len(), 1);
+    assert!(readiness.can_merge());
+}
+
+#[test]
+fn test_merge_readiness_unknown_mergeability_and_behind() {
+    let readiness = MergeReadiness {
+        mergeable: None,
+        mergeable_state: Some("behind".to_string()),
+        ..ready()
+    };
+
+    assert_eq!(
+        readiness.blockers(),
+        vec![
+            "Mergeability is still being computed, try again",
+            "Branch is behind the base branch and must be updated",
+        ]
+    );
+}
+
+#[test]
+fn test_merge_readiness_no_allowed_merge_method() {
+    let readiness = MergeReadiness {
+        merge_methods: MergeMethod::ALL.iter().map(|method| (*method, false)).collect(),
+        ..ready()
+    };
+
+    assert_eq!(
+        readiness.blockers(),
+        vec!["Repository does not allow any merge method"]
+    );
+}
+
+#[test]
+fn test_merge_readiness_blocked_without_known_reason() {
+    let readiness = MergeReadiness {
+        mergeable_state: Some("blocked".to_string()),
+        reviews: ReviewStatus::default(),
+        ..ready()
+    };
+
+    assert_eq!(
+        readiness.blockers(),
+        vec!["Blocked by branch protection rules"]
+    );
+}
+
+#[test]
+fn test_merge_method_display() {
+    assert_eq!(MergeMethod::Squash.to_string(), "squash");
+    assert_eq!(MergeMethod::Rebase.to_string(), "rebase");
+    assert_eq!(MergeMethod::Merge.to_string(), "merge commit");
+}
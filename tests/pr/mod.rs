@@ -2,6 +2,22 @@
 //!
 //! 包含 PR 模块的所有测试文件。
 
+pub mod azure_devops;
 pub mod body_parser;
+pub mod create_from_commits;
+pub mod diff_budget;
+pub mod file_summary_cache;
 pub mod github;
+pub mod github_auto_merge;
+pub mod github_pagination;
+pub mod github_rate_limit;
+pub mod github_token;
+pub mod list_filter;
+pub mod merge_readiness;
+pub mod output;
+pub mod resolution;
+pub mod review;
+pub mod review_comment;
 pub mod table;
+pub mod template;
+pub mod url;
@@ -0,0 +1,130 @@
+//! PR 命令机器可读输出测试
+//!
+//! 测试 `pr status --json` 和 `pr list --json` 输出的 JSON 文档（字段名、顺序和空值）。
+
+use pretty_assertions::assert_eq;
+use workflow::pr::github::responses::PullRequestInfo;
+use workflow::pr::{
+    PullRequestDetails, PullRequestListOutput, PullRequestRow, PullRequestStatusOutput,
+};
+
+// ==================== Helper Functions ====================
+
+/// 创建 PR 表格行
+fn row(number: &str, title: &str, author: &str) -> PullRequestRow {
+    PullRequestRow {
+        number: number.to_string(),
+        state: "open".to_string(),
+        branch: format!("feature/pr-{}", number),
+        title: title.to_string(),
+        author: author.to_string(),
+        url: format!("https://github.com/owner/repo/pull/{}", number),
+    }
+}
+
+// ==================== pr status 测试 ====================
+
+#[test]
+fn test_pr_status_output_snapshot() {
+    let pr: PullRequestInfo = serde_json::from_str(
+        r#"{
+            "number": 42,
+            "title": "feat(auth): add login page",
+            "body": "Adds the login page.",
+            "state": "closed",
+            "merged": true,
+            "merged_at": "2024-03-05T10:00:00Z",
+            "html_url": "https://github.com/owner/repo/pull/42",
+            "head": {"ref": "feature/login"},
+            "base": {"ref": "main"},
+            "user": {"login": "alice"}
+        }"#,
+    )
+    .unwrap();
+    let output = PullRequestStatusOutput::from(PullRequestDetails::from(pr));
+
+    assert_eq!(
+        serde_json::to_string_pretty(&output).unwrap(),
+        r#"{
+  "id": "42",
+  "title": "feat(auth): add login page",
+  "description": "Adds the login page.",
+  "state": "closed",
+  "merged": true,
+  "merged_at": "2024-03-05T10:00:00Z",
+  "source_branch": "feature/login",
+  "target_branch": "main",
+  "author": "alice",
+  "url": "https://github.com/owner/repo/pull/42"
+}"#
+    );
+}
+
+#[test]
+fn test_pr_status_output_empty_fields_are_null() {
+    // 空描述和缺少的创建者输出为 null（字段始终存在）
+    let pr: PullRequestInfo = serde_json::from_str(
+        r#"{
+            "number": 7,
+            "title": "Fix typo",
+            "body": "  ",
+            "state": "open",
+            "html_url": "https://github.com/owner/repo/pull/7",
+            "head": {"ref": "fix/typo"},
+            "base": {"ref": "develop"}
+        }"#,
+    )
+    .unwrap();
+    let output = PullRequestStatusOutput::from(PullRequestDetails::from(pr));
+    let value = serde_json::to_value(&output).unwrap();
+
+    assert_eq!(value["description"], serde_json::Value::Null);
+    assert_eq!(value["author"], serde_json::Value::Null);
+    assert_eq!(value["merged"], serde_json::Value::Bool(false));
+    assert_eq!(value["merged_at"], serde_json::Value::Null);
+}
+
+// ==================== pr list 测试 ====================
+
+#[test]
+fn test_pr_list_output_snapshot() {
+    let output = PullRequestListOutput::new(vec![
+        row("12", "Add login page", "alice"),
+        row("13", "Update docs", "N/A"),
+    ]);
+
+    assert_eq!(
+        serde_json::to_string_pretty(&output).unwrap(),
+        r#"{
+  "count": 2,
+  "pull_requests": [
+    {
+      "id": "12",
+      "state": "open",
+      "branch": "feature/pr-12",
+      "title": "Add login page",
+      "author": "alice",
+      "url": "https://github.com/owner/repo/pull/12"
+    },
+    {
+      "id": "13",
+      "state": "open",
+      "branch": "feature/pr-13",
+      "title": "Update docs",
+      "author": null,
+      "url": "https://github.com/owner/repo/pull/13"
+    }
+  ]
+}"#
+    );
+}
+
+#[test]
+fn test_pr_list_output_empty() {
+    let output = PullRequestListOutput::new(Vec::new());
+
+    assert_eq!(
+        serde_json::to_string(&output).unwrap(),
+        r#"{"count":0,"pull_requests":[]}"#
+    );
+}
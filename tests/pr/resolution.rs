@@ -0,0 +1,71 @@
+//! PR ID 解析测试
+//!
+//! 测试 PR ID 的解析优先级：命令行参数 > 当前分支的 PR > 交互式选择。
+
+use color_eyre::eyre::eyre;
+use pretty_assertions::assert_eq;
+use workflow::pr::resolve_pull_request_id_with;
+
+// ==================== 优先级测试 ====================
+
+#[test]
+fn test_explicit_id_takes_precedence() {
+    let id = resolve_pull_request_id_with(
+        Some("7".to_string()),
+        || panic!("current branch PR should not be looked up"),
+        || panic!("picker should not be shown"),
+    )
+    .unwrap();
+
+    assert_eq!(id, "7");
+}
+
+#[test]
+fn test_current_branch_pr_used_before_picker() {
+    let id = resolve_pull_request_id_with(
+        None,
+        || Ok(Some("42".to_string())),
+        || panic!("picker should not be shown"),
+    )
+    .unwrap();
+
+    assert_eq!(id, "42");
+}
+
+#[test]
+fn test_picker_used_when_branch_has_no_pr() {
+    let id = resolve_pull_request_id_with(None, || Ok(None), || Ok("15".to_string())).unwrap();
+
+    assert_eq!(id, "15");
+}
+
+// ==================== 错误处理测试 ====================
+
+#[test]
+fn test_current_branch_error_skips_picker() {
+    let result = resolve_pull_request_id_with(
+        None,
+        || Err(eyre!("API error")),
+        || panic!("picker should not be shown"),
+    );
+
+    assert_eq!(result.unwrap_err().to_string(), "API error");
+}
+
+#[test]
+fn test_picker_error_is_returned() {
+    let result = resolve_pull_request_id_with(
+        None,
+        || Ok(None),
+        || {
+            Err(eyre!(
+                "No PR found for current branch. Please specify PR ID."
+            ))
+        },
+    );
+
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "No PR found for current branch. Please specify PR ID."
+    );
+}
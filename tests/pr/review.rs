@@ -0,0 +1,54 @@
+//! PR 评审测试
+//!
+//! 测试评审类型对应的 GitHub event 和评审内容的校验。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use workflow::pr::ReviewEvent;
+
+// ==================== 评审类型测试 ====================
+
+#[rstest]
+#[case(ReviewEvent::Approve, "APPROVE", "approve")]
+#[case(ReviewEvent::RequestChanges, "REQUEST_CHANGES", "request-changes")]
+#[case(ReviewEvent::Comment, "COMMENT", "comment")]
+fn test_review_event_names(
+    #[case] event: ReviewEvent,
+    #[case] github_event: &str,
+    #[case] display: &str,
+) {
+    assert_eq!(event.github_event(), github_event);
+    assert_eq!(event.to_string(), display);
+}
+
+// ==================== 评审内容校验测试 ====================
+
+#[rstest]
+#[case(ReviewEvent::Approve, None, None)]
+#[case(ReviewEvent::Approve, Some("  "), None)]
+#[case(ReviewEvent::Approve, Some("LGTM"), Some("LGTM"))]
+#[case(
+    ReviewEvent::RequestChanges,
+    Some(" Needs tests\n"),
+    Some("Needs tests")
+)]
+#[case(ReviewEvent::Comment, Some("A few nits"), Some("A few nits"))]
+fn test_validate_body(
+    #[case] event: ReviewEvent,
+    #[case] body: Option<&str>,
+    #[case] expected: Option<&str>,
+) {
+    assert_eq!(event.validate_body(body).unwrap(), expected);
+}
+
+#[rstest]
+#[case(ReviewEvent::RequestChanges, None)]
+#[case(ReviewEvent::RequestChanges, Some(""))]
+#[case(ReviewEvent::Comment, None)]
+#[case(ReviewEvent::Comment, Some("   "))]
+fn test_validate_body_required(#[case] event: ReviewEvent, #[case] body: Option<&str>) {
+    let err = event.validate_body(body).unwrap_err();
+
+    assert!(err.to_string().contains("A message is required"));
+    assert!(err.to_string().contains(&event.to_string()));
+}
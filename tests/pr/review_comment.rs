@@ -0,0 +1,132 @@
+//! PR 评审评论测试
+//!
+//! 测试评审评论位置的参数解析、路径规范化和 diff 行号校验。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use workflow::pr::{
+    ensure_line_in_diff, normalize_path, patch_contains_line, ChangedFile, ReviewCommentTarget,
+};
+
+const PATCH: &str = "@@ -10,3 +10,4 @@ fn main() {
+ context
++added
+ context
+ context
+@@ -50 +51 @@
+-old
++new
+@@ -60,2 +62,0 @@
+-removed
+-removed";
+
+fn changed_files() -> Vec<ChangedFile> {
+    vec![
+        ChangedFile {
+            path: "src/main.rs".to_string(),
+            patch: Some(PATCH.to_string()),
+        },
+        ChangedFile {
+            path: "assets/logo.png".to_string(),
+            patch: None,
+        },
+    ]
+}
+
+// ==================== 参数解析测试 ====================
+
+#[test]
+fn test_from_args_without_target() {
+    assert_eq!(
+        ReviewCommentTarget::from_args(None, None, None).unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_from_args_line() {
+    let target =
+        ReviewCommentTarget::from_args(Some("./src/main.rs".to_string()), Some(42), None).unwrap();
+
+    assert_eq!(
+        target,
+        Some(ReviewCommentTarget::Line {
+            path: "src/main.rs".to_string(),
+            line: 42,
+        })
+    );
+}
+
+#[test]
+fn test_from_args_reply() {
+    let target = ReviewCommentTarget::from_args(None, None, Some(987)).unwrap();
+
+    assert_eq!(target, Some(ReviewCommentTarget::Reply { comment_id: 987 }));
+}
+
+#[rstest]
+#[case(Some("src/main.rs"), None, None)]
+#[case(None, Some(42), None)]
+#[case(Some("src/main.rs"), Some(0), None)]
+#[case(Some("src/main.rs"), Some(42), Some(987))]
+#[case(None, Some(42), Some(987))]
+fn test_from_args_invalid(
+    #[case] file: Option<&str>,
+    #[case] line: Option<u32>,
+    #[case] reply_to: Option<u64>,
+) {
+    let result = ReviewCommentTarget::from_args(file.map(str::to_string), line, reply_to);
+
+    assert!(result.is_err());
+}
+
+// ==================== 路径规范化测试 ====================
+
+#[rstest]
+#[case("src/main.rs", "src/main.rs")]
+#[case("./src/main.rs", "src/main.rs")]
+#[case(".\\src\\main.rs", "src/main.rs")]
+#[case("/src/main.rs", "src/main.rs")]
+#[case("  src/main.rs  ", "src/main.rs")]
+fn test_normalize_path(#[case] input: &str, #[case] expected: &str) {
+    assert_eq!(normalize_path(input), expected);
+}
+
+// ==================== diff 行号测试 ====================
+
+#[rstest]
+#[case(9, false)]
+#[case(10, true)]
+#[case(13, true)]
+#[case(14, false)]
+#[case(51, true)]
+#[case(52, false)]
+#[case(62, false)]
+fn test_patch_contains_line(#[case] line: u32, #[case] expected: bool) {
+    assert_eq!(patch_contains_line(PATCH, line), expected);
+}
+
+#[test]
+fn test_ensure_line_in_diff() {
+    let files = changed_files();
+
+    assert!(ensure_line_in_diff(&files, "src/main.rs", 11).is_ok());
+    // 没有 diff 片段的文件只检查文件是否变更
+    assert!(ensure_line_in_diff(&files, "assets/logo.png", 1).is_ok());
+}
+
+#[test]
+fn test_ensure_line_in_diff_file_not_changed() {
+    let error = ensure_line_in_diff(&changed_files(), "src/lib.rs", 1).unwrap_err();
+
+    assert!(error.to_string().contains("'src/lib.rs' is not changed"));
+}
+
+#[test]
+fn test_ensure_line_in_diff_line_outside_hunks() {
+    let error = ensure_line_in_diff(&changed_files(), "src/main.rs", 30).unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("Line 30 of 'src/main.rs' is not part of the PR diff"));
+}
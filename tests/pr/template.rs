@@ -0,0 +1,91 @@
+//! 仓库 PR 模板填充测试
+//!
+//! 测试将生成的描述、变更类型和 Jira 链接填入仓库 PR 模板的功能。
+
+use pretty_assertions::assert_eq;
+use workflow::pr::{fill_pull_request_template, TYPES_OF_CHANGES};
+
+/// 只选中 `Bug fix` 的变更类型数组
+fn bug_fix_selected() -> Vec<bool> {
+    let mut selected = vec![false; TYPES_OF_CHANGES.len()];
+    selected[0] = true;
+    selected
+}
+
+#[test]
+fn test_fill_template_sections() {
+    let template = r#"## Description
+
+<!-- Describe your changes -->
+
+## Type of change
+
+- [ ] Bug fix
+- [ ] New feature
+
+## Related issue
+"#;
+
+    let body = fill_pull_request_template(
+        template,
+        &bug_fix_selected(),
+        Some("Fix login crash"),
+        Some("https://jira.example.com/browse/PROJ-123"),
+    );
+
+    assert_eq!(
+        body,
+        r#"## Description
+
+Fix login crash
+
+<!-- Describe your changes -->
+
+## Type of change
+
+- [x] Bug fix
+- [ ] New feature
+
+## Related issue
+
+https://jira.example.com/browse/PROJ-123
+"#
+    );
+}
+
+#[test]
+fn test_fill_template_inserts_change_type_list_without_checkboxes() {
+    let template = "## Summary\n\n## Type of change\n";
+
+    let body = fill_pull_request_template(template, &bug_fix_selected(), Some("Summary"), None);
+
+    assert!(body.contains(&format!("- [x] {}", TYPES_OF_CHANGES[0])));
+    assert!(body.contains(&format!("- [ ] {}", TYPES_OF_CHANGES[1])));
+    assert!(!body.contains("Jira"));
+}
+
+#[test]
+fn test_fill_template_without_matching_sections() {
+    let template = "## Checklist\n\n- [ ] Tests added\n";
+
+    let body = fill_pull_request_template(
+        template,
+        &bug_fix_selected(),
+        Some("Fix login crash"),
+        Some("PROJ-123"),
+    );
+
+    assert_eq!(
+        body,
+        "Fix login crash\n\nJira: PROJ-123\n\n## Checklist\n\n- [ ] Tests added\n"
+    );
+}
+
+#[test]
+fn test_fill_template_ignores_headings_in_code_blocks() {
+    let template = "```\n# Description\n```\n";
+
+    let body = fill_pull_request_template(template, &[], Some("Fix login crash"), None);
+
+    assert_eq!(body, "Fix login crash\n\n```\n# Description\n```\n");
+}
@@ -0,0 +1,65 @@
+//! PR URL 辅助函数测试
+//!
+//! 测试从 PR URL 提取 PR ID，以及从 Git remote URL 提取 GitHub 仓库（包括 GitHub Enterprise Server 主机）。
+
+use pretty_assertions::assert_eq;
+use rstest::rstest;
+use workflow::pr::helpers::{
+    extract_github_repo_from_url_with_host, extract_pull_request_id_from_url,
+};
+
+const ENTERPRISE_HOST: &str = "github.mycorp.com";
+
+// ==================== PR ID 提取测试 ====================
+
+#[rstest]
+#[case("https://github.com/owner/repo/pull/123", "123")]
+#[case("https://github.com/owner/repo/pull/123/files", "123")]
+#[case("https://github.mycorp.com/owner/repo/pull/45", "45")]
+#[case("https://github.mycorp.com:8443/owner/repo/pull/45#discussion", "45")]
+#[case("https://github.mycorp.com/2024/repo/pull/7", "7")]
+#[case("https://dev.azure.com/org/project/_git/repo/pullrequest/88", "88")]
+fn test_extract_pull_request_id_from_url(#[case] url: &str, #[case] expected: &str) {
+    assert_eq!(extract_pull_request_id_from_url(url).unwrap(), expected);
+}
+
+#[test]
+fn test_extract_pull_request_id_from_url_without_id() {
+    assert!(extract_pull_request_id_from_url("https://github.mycorp.com/owner/repo").is_err());
+}
+
+// ==================== GitHub 仓库提取测试 ====================
+
+#[rstest]
+#[case("git@github.mycorp.com:owner/repo.git", "owner/repo")]
+#[case("https://github.mycorp.com/owner/repo.git", "owner/repo")]
+#[case("https://github.mycorp.com:8443/owner/repo", "owner/repo")]
+#[case("ssh://git@github.mycorp.com/owner/repo.git", "owner/repo")]
+#[case("git@github.com:owner/repo.git", "owner/repo")]
+#[case("https://github.com/owner/repo.git", "owner/repo")]
+fn test_extract_github_repo_from_url_with_enterprise_host(
+    #[case] url: &str,
+    #[case] expected: &str,
+) {
+    assert_eq!(
+        extract_github_repo_from_url_with_host(url, Some(ENTERPRISE_HOST)).unwrap(),
+        expected
+    );
+}
+
+#[rstest]
+#[case("https://git.mycorp.com/owner/repo.git")]
+#[case("https://github.mycorp.com/owner/repo.git")]
+fn test_extract_github_repo_from_url_without_enterprise_host(#[case] url: &str) {
+    assert!(extract_github_repo_from_url_with_host(url, None).is_err());
+}
+
+#[test]
+fn test_extract_github_repo_from_url_other_enterprise_host() {
+    let result = extract_github_repo_from_url_with_host(
+        "https://git.othercorp.com/owner/repo.git",
+        Some(ENTERPRISE_HOST),
+    );
+
+    assert!(result.is_err());
+}
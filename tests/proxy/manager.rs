@@ -184,6 +184,7 @@ fn test_proxy_enable_result_creation() {
         already_configured: false,
         proxy_command: Some("export http_proxy=http://proxy.example.com:8080".to_string()),
         shell_config_path: Some(PathBuf::from("/home/user/.bashrc")),
+        shell_config_backup: None,
     };
 
     assert_eq!(enable_result.already_configured, false);
@@ -207,6 +208,7 @@ fn test_proxy_enable_result_already_configured() {
         already_configured: true,
         proxy_command: None,
         shell_config_path: None,
+        shell_config_backup: None,
     };
 
     // 验证已配置的情况
@@ -222,6 +224,7 @@ fn test_proxy_enable_result_temporary_mode() {
         already_configured: false,
         proxy_command: Some("export http_proxy=http://temp.proxy.com:8080".to_string()),
         shell_config_path: None, // 临时模式不写入配置文件
+        shell_config_backup: None,
     };
 
     // 验证临时模式的情况
@@ -251,6 +254,7 @@ fn test_proxy_disable_result_creation() {
     let disable_result = ProxyDisableResult {
         found_proxy: true,
         shell_config_path: Some(PathBuf::from("/home/user/.zshrc")),
+        shell_config_backup: None,
         unset_command: Some("unset http_proxy && unset https_proxy".to_string()),
         current_env_proxy: current_env_proxy.clone(),
     };
@@ -275,6 +279,7 @@ fn test_proxy_disable_result_no_proxy_found() {
     let disable_result = ProxyDisableResult {
         found_proxy: false,
         shell_config_path: None,
+        shell_config_backup: None,
         unset_command: None,
         current_env_proxy: HashMap::new(),
     };
@@ -298,6 +303,7 @@ fn test_proxy_disable_result_env_only() {
     let disable_result = ProxyDisableResult {
         found_proxy: true,
         shell_config_path: None, // 配置文件中没有代理设置
+        shell_config_backup: None,
         unset_command: Some("unset http_proxy".to_string()),
         current_env_proxy,
     };
@@ -319,6 +325,7 @@ fn test_results_clone() {
         already_configured: false,
         proxy_command: Some("export http_proxy=test".to_string()),
         shell_config_path: Some(PathBuf::from("test_file")),
+        shell_config_backup: None,
     };
 
     let cloned_enable = original_enable.clone();
@@ -339,6 +346,7 @@ fn test_results_clone() {
     let original_disable = ProxyDisableResult {
         found_proxy: true,
         shell_config_path: Some(PathBuf::from("test_file")),
+        shell_config_backup: None,
         unset_command: Some("unset TEST_VAR".to_string()),
         current_env_proxy: env_proxy,
     };
@@ -363,6 +371,7 @@ fn test_results_debug() {
         already_configured: true,
         proxy_command: None,
         shell_config_path: None,
+        shell_config_backup: None,
     };
 
     let enable_debug = format!("{:?}", enable_result);
@@ -372,6 +381,7 @@ fn test_results_debug() {
     let disable_result = ProxyDisableResult {
         found_proxy: false,
         shell_config_path: None,
+        shell_config_backup: None,
         unset_command: None,
         current_env_proxy: HashMap::new(),
     };
@@ -563,6 +573,7 @@ fn test_edge_cases_and_error_handling() {
         already_configured: false,
         proxy_command: None,
         shell_config_path: None,
+        shell_config_backup: None,
     };
 
     assert_eq!(empty_enable_result.already_configured, false);
@@ -572,6 +583,7 @@ fn test_edge_cases_and_error_handling() {
     let empty_disable_result = ProxyDisableResult {
         found_proxy: false,
         shell_config_path: None,
+        shell_config_backup: None,
         unset_command: None,
         current_env_proxy: HashMap::new(),
     };
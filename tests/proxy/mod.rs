@@ -3,4 +3,5 @@
 //! 包含 Proxy 模块的所有测试文件。
 
 pub mod manager; // 代理管理器测试
+pub mod output; // proxy check 机器可读输出测试
 pub mod system_reader; // 系统代理读取器和配置生成器测试
@@ -0,0 +1,97 @@
+//! 代理命令机器可读输出测试
+//!
+//! 测试 `proxy check --json` 输出的 JSON 文档（字段名、顺序和环境变量筛选）。
+
+use pretty_assertions::assert_eq;
+use std::collections::HashMap;
+use workflow::proxy::{ProxyCheckOutput, ProxyConfig, ProxyInfo, ProxyType};
+
+// ==================== Helper Functions ====================
+
+/// 创建环境变量表
+fn vars(entries: &[(&str, &str)]) -> HashMap<String, String> {
+    entries
+        .iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect()
+}
+
+// ==================== proxy check 测试 ====================
+
+#[test]
+fn test_proxy_check_output_snapshot() {
+    let mut proxy_info = ProxyInfo::new();
+    proxy_info.set_config(
+        ProxyType::Https,
+        ProxyConfig {
+            enable: true,
+            address: Some("127.0.0.1".to_string()),
+            port: Some(7890),
+        },
+    );
+    proxy_info.set_config(
+        ProxyType::Http,
+        ProxyConfig {
+            enable: true,
+            address: Some("127.0.0.1".to_string()),
+            port: Some(7890),
+        },
+    );
+    // 未启用的代理不输出
+    proxy_info.set_config(
+        ProxyType::Socks,
+        ProxyConfig {
+            enable: false,
+            address: Some("127.0.0.1".to_string()),
+            port: Some(7891),
+        },
+    );
+
+    let env_proxy = vars(&[
+        ("https_proxy", "http://127.0.0.1:7890"),
+        ("http_proxy", "http://127.0.0.1:7890"),
+    ]);
+    let shell_config_env = vars(&[
+        ("http_proxy", "http://127.0.0.1:7890"),
+        ("all_proxy", "socks5://127.0.0.1:7891"),
+        ("EDITOR", "vim"),
+    ]);
+
+    let output = ProxyCheckOutput::new(&proxy_info, &env_proxy, &shell_config_env, true);
+
+    assert_eq!(
+        serde_json::to_string_pretty(&output).unwrap(),
+        r#"{
+  "system": [
+    {
+      "type": "http",
+      "address": "127.0.0.1",
+      "port": 7890
+    },
+    {
+      "type": "https",
+      "address": "127.0.0.1",
+      "port": 7890
+    }
+  ],
+  "environment": {
+    "http_proxy": "http://127.0.0.1:7890",
+    "https_proxy": "http://127.0.0.1:7890"
+  },
+  "shell_config": {
+    "all_proxy": "socks5://127.0.0.1:7891"
+  },
+  "configured": true
+}"#
+    );
+}
+
+#[test]
+fn test_proxy_check_output_without_proxy() {
+    let output = ProxyCheckOutput::new(&ProxyInfo::new(), &HashMap::new(), &HashMap::new(), false);
+
+    assert_eq!(
+        serde_json::to_string(&output).unwrap(),
+        r#"{"system":[],"environment":{},"shell_config":{},"configured":false}"#
+    );
+}
@@ -5,6 +5,7 @@
 use pretty_assertions::assert_eq;
 use rstest::{fixture, rstest};
 use serde_json;
+use std::collections::BTreeMap;
 use std::fs;
 use tempfile::TempDir;
 use workflow::repo::{BranchConfig, PullRequestsConfig, RepoConfig};
@@ -31,6 +32,9 @@ fn sample_branch_config() -> BranchConfig {
 fn sample_pr_config() -> PullRequestsConfig {
     PullRequestsConfig {
         auto_accept_change_type: Some(true),
+        delete_branch_on_merge: None,
+        merge_strategy: None,
+        merge_strategy_by_branch: BTreeMap::new(),
     }
 }
 
@@ -106,12 +110,12 @@ fn test_branch_config_empty_deserialization() {
 #[case(Some("feature".to_string()), vec![])]
 #[case(Some("hotfix".to_string()), vec!["main".to_string()])]
 #[case(None, vec!["main".to_string(), "develop".to_string()])]
-fn test_branch_config_parametrized(
-    #[case] prefix: Option<String>,
-    #[case] ignore: Vec<String>
-) {
+fn test_branch_config_parametrized(#[case] prefix: Option<String>, #[case] ignore: Vec<String>) {
     /// 参数化测试分支配置的各种组合
-    let config = BranchConfig { prefix, ignore: ignore.clone() };
+    let config = BranchConfig {
+        prefix,
+        ignore: ignore.clone(),
+    };
 
     // 测试序列化和反序列化的一致性
     let json = serde_json::to_string(&config).expect("Failed to serialize");
@@ -129,6 +133,7 @@ fn test_pr_config_default() {
     let config = PullRequestsConfig::default();
 
     assert_eq!(config.auto_accept_change_type, None);
+    assert_eq!(config.delete_branch_on_merge, None);
 }
 
 #[test]
@@ -136,6 +141,9 @@ fn test_pr_config_with_values() {
     /// 测试带值的 PR 配置创建
     let config = PullRequestsConfig {
         auto_accept_change_type: Some(true),
+        delete_branch_on_merge: None,
+        merge_strategy: None,
+        merge_strategy_by_branch: BTreeMap::new(),
     };
 
     assert_eq!(config.auto_accept_change_type, Some(true));
@@ -146,6 +154,9 @@ fn test_pr_config_serialization() {
     /// 测试 PR 配置的序列化
     let config = PullRequestsConfig {
         auto_accept_change_type: Some(false),
+        delete_branch_on_merge: None,
+        merge_strategy: None,
+        merge_strategy_by_branch: BTreeMap::new(),
     };
 
     let json = serde_json::to_string(&config).expect("Failed to serialize");
@@ -163,6 +174,33 @@ fn test_pr_config_deserialization() {
     assert_eq!(config.auto_accept_change_type, Some(true));
 }
 
+#[test]
+fn test_pr_config_delete_branch_on_merge_deserialization() {
+    /// 测试合并后删除分支配置的反序列化
+    let json = r#"{"delete_branch_on_merge":true}"#;
+    let config: PullRequestsConfig = serde_json::from_str(json).expect("Failed to deserialize");
+
+    assert_eq!(config.delete_branch_on_merge, Some(true));
+    assert_eq!(config.auto_accept_change_type, None);
+}
+
+#[rstest]
+#[case(None, true)]
+#[case(Some(true), true)]
+#[case(Some(false), false)]
+fn test_pr_config_should_delete_branch_on_merge(
+    #[case] delete_branch_on_merge: Option<bool>,
+    #[case] expected: bool,
+) {
+    /// 测试合并后删除分支的默认值：未配置时删除（与原来的 `pr merge` 行为一致）
+    let config = PullRequestsConfig {
+        delete_branch_on_merge,
+        ..Default::default()
+    };
+
+    assert_eq!(config.should_delete_branch_on_merge(), expected);
+}
+
 #[test]
 fn test_pr_config_empty_deserialization() {
     /// 测试空 PR 配置的反序列化
@@ -180,15 +218,119 @@ fn test_pr_config_parametrized(#[case] auto_accept: Option<bool>) {
     /// 参数化测试 PR 配置的各种值
     let config = PullRequestsConfig {
         auto_accept_change_type: auto_accept,
+        delete_branch_on_merge: None,
+        merge_strategy: None,
+        merge_strategy_by_branch: BTreeMap::new(),
     };
 
     // 测试序列化和反序列化的一致性
     let json = serde_json::to_string(&config).expect("Failed to serialize");
-    let deserialized: PullRequestsConfig = serde_json::from_str(&json).expect("Failed to deserialize");
+    let deserialized: PullRequestsConfig =
+        serde_json::from_str(&json).expect("Failed to deserialize");
 
     assert_eq!(deserialized.auto_accept_change_type, auto_accept);
 }
 
+#[test]
+fn test_pr_config_merge_strategy_deserialization() {
+    /// 测试合并方式配置的反序列化
+    let json = r#"{"merge_strategy":"squash","merge_strategy_by_branch":{"release/*":"merge"}}"#;
+    let config: PullRequestsConfig = serde_json::from_str(json).expect("Failed to deserialize");
+
+    assert_eq!(config.merge_strategy, Some("squash".to_string()));
+    assert_eq!(
+        config.merge_strategy_by_branch.get("release/*"),
+        Some(&"merge".to_string())
+    );
+}
+
+// ==================== 合并方式分支匹配测试 ====================
+
+/// 创建带分支规则的合并方式配置
+fn merge_strategy_config(default: Option<&str>, rules: &[(&str, &str)]) -> PullRequestsConfig {
+    PullRequestsConfig {
+        merge_strategy: default.map(str::to_string),
+        merge_strategy_by_branch: rules
+            .iter()
+            .map(|(pattern, strategy)| (pattern.to_string(), strategy.to_string()))
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[rstest]
+#[case("main", Some("squash"))]
+#[case("release/1.0", Some("merge"))]
+#[case("release/1.2", Some("rebase"))]
+#[case("release/2.0", Some("merge"))]
+#[case("feature/login", Some("squash"))]
+fn test_merge_strategy_for_precedence(#[case] target_branch: &str, #[case] expected: Option<&str>) {
+    /// 测试合并方式的优先级：精确匹配 > 更具体的通配符 > 默认值
+    let config = merge_strategy_config(
+        Some("squash"),
+        &[
+            ("release/*", "merge"),
+            ("release/1.*", "rebase"),
+            ("release/1.0", "merge"),
+        ],
+    );
+
+    assert_eq!(config.merge_strategy_for(target_branch), expected);
+}
+
+#[test]
+fn test_merge_strategy_for_exact_match_beats_glob() {
+    /// 测试不含通配符的规则优先于同样匹配的通配符规则
+    let config = merge_strategy_config(None, &[("main", "merge"), ("ma*", "rebase")]);
+
+    assert_eq!(config.merge_strategy_for("main"), Some("merge"));
+    assert_eq!(config.merge_strategy_for("master"), Some("rebase"));
+}
+
+/// 测试具体程度相同的通配符规则按规则名称的字母顺序取第一个（与配置中的书写顺序无关）
+#[test]
+fn test_merge_strategy_for_tie_uses_alphabetical_order() {
+    let config = merge_strategy_config(None, &[("release-*", "merge"), ("*-1.0", "rebase")]);
+
+    // 非通配字符更多的规则优先，与规则排序无关
+    assert_eq!(config.merge_strategy_for("release-1.0"), Some("merge"));
+
+    // "*a" 按字母顺序排在 "a*" 之前
+    let config = merge_strategy_config(None, &[("a*", "merge"), ("*a", "rebase")]);
+    assert_eq!(config.merge_strategy_for("aa"), Some("rebase"));
+
+    // "a*c" 按字母顺序排在 "ab*" 之前
+    let config = merge_strategy_config(None, &[("ab*", "rebase"), ("a*c", "merge")]);
+    assert_eq!(config.merge_strategy_for("abc"), Some("merge"));
+}
+
+#[rstest]
+#[case("hotfix-*", "hotfix-123", true)]
+#[case("release/*", "release/1.0/rc", true)]
+#[case("release/?.0", "release/1.0", true)]
+#[case("release/?.0", "release/10.0", false)]
+#[case("release/*", "release", false)]
+#[case("*", "anything/at/all", true)]
+fn test_merge_strategy_for_glob_match(
+    #[case] pattern: &str,
+    #[case] target_branch: &str,
+    #[case] matches: bool,
+) {
+    /// 测试通配符匹配：`*` 匹配任意字符（包括 `/`），`?` 匹配单个字符
+    let config = merge_strategy_config(None, &[(pattern, "merge")]);
+    let expected = if matches { Some("merge") } else { None };
+
+    assert_eq!(config.merge_strategy_for(target_branch), expected);
+}
+
+#[test]
+fn test_merge_strategy_for_not_configured() {
+    /// 测试未配置合并方式时返回 None
+    let config = PullRequestsConfig::default();
+
+    assert_eq!(config.merge_strategy_for("main"), None);
+}
+
 // ==================== 边界条件和错误处理测试 ====================
 
 #[test]
@@ -258,6 +400,9 @@ fn test_config_clone() {
 
     let original_pr = PullRequestsConfig {
         auto_accept_change_type: Some(true),
+        delete_branch_on_merge: None,
+        merge_strategy: None,
+        merge_strategy_by_branch: BTreeMap::new(),
     };
 
     let cloned_branch = original_branch.clone();
@@ -265,7 +410,10 @@ fn test_config_clone() {
 
     assert_eq!(cloned_branch.prefix, original_branch.prefix);
     assert_eq!(cloned_branch.ignore, original_branch.ignore);
-    assert_eq!(cloned_pr.auto_accept_change_type, original_pr.auto_accept_change_type);
+    assert_eq!(
+        cloned_pr.auto_accept_change_type,
+        original_pr.auto_accept_change_type
+    );
 }
 
 #[test]
@@ -278,6 +426,9 @@ fn test_config_debug() {
 
     let pr_config = PullRequestsConfig {
         auto_accept_change_type: Some(true),
+        delete_branch_on_merge: None,
+        merge_strategy: None,
+        merge_strategy_by_branch: BTreeMap::new(),
     };
 
     let branch_debug = format!("{:?}", branch_config);
@@ -5,6 +5,7 @@
 //! - 文件恢复操作
 //! - 错误处理和边界情况
 //! - 备份清理功能
+//! - 备份压缩与解压
 //! - 结构体创建和字段访问
 
 use pretty_assertions::assert_eq;
@@ -12,6 +13,7 @@ use std::fs;
 use std::path::PathBuf;
 use tempfile::TempDir;
 use workflow::rollback::{BackupInfo, BackupResult, RollbackManager, RollbackResult};
+use workflow::Unzip;
 
 // ==================== Helper Functions ====================
 
@@ -69,6 +71,7 @@ fn create_test_backup_info(temp_dir: &TempDir) -> BackupInfo {
             ),
             ("workflow.zsh".to_string(), backup_dir.join("workflow.zsh")),
         ],
+        compressed: false,
     }
 }
 
@@ -90,6 +93,7 @@ fn test_backup_info_creation() {
             "workflow.bash".to_string(),
             backup_dir.join("workflow.bash"),
         )],
+        compressed: false,
     };
 
     // 验证字段访问
@@ -336,6 +340,7 @@ fn test_backup_info_internal_methods() {
         backup_dir: backup_dir.clone(),
         binary_backups: vec![],
         completion_backups: vec![],
+        compressed: false,
     };
 
     // 验证初始状态
@@ -367,6 +372,7 @@ fn test_edge_cases_and_error_handling() {
         backup_dir: PathBuf::from("/nonexistent/path"),
         binary_backups: vec![],
         completion_backups: vec![],
+        compressed: false,
     };
 
     // 验证空的备份信息不会导致 panic
@@ -430,6 +436,7 @@ fn test_complex_backup_rollback_scenario() {
                 backup_dir.join("missing_completion"),
             ),
         ],
+        compressed: false,
     };
 
     // 只创建部分备份文件，模拟部分备份成功的情况
@@ -497,3 +504,61 @@ fn test_complex_backup_rollback_scenario() {
     assert!((binary_success_rate - 0.33).abs() < 0.01); // 约 33%
     assert!((completion_success_rate - 0.5).abs() < 0.01); // 50%
 }
+
+/// 测试压缩备份的归档路径
+#[test]
+fn test_backup_info_archive_path() {
+    let temp_dir = setup_test_environment();
+    let backup_info = create_test_backup_info(&temp_dir);
+
+    assert!(!backup_info.compressed);
+    assert_eq!(
+        backup_info.archive_path(),
+        backup_info.backup_dir.join("backup.tar.gz")
+    );
+}
+
+/// 测试压缩备份的打包与解压
+#[test]
+fn test_compressed_backup_archive_round_trip() {
+    let temp_dir = setup_test_environment();
+    let backup_info = create_test_backup_info(&temp_dir);
+    let backup_dir = &backup_info.backup_dir;
+
+    Unzip::create_tar_gz(
+        &backup_info.archive_path(),
+        backup_dir,
+        &["workflow", "workflow.bash"],
+    )
+    .expect("Failed to create backup archive");
+    fs::remove_file(backup_dir.join("workflow")).expect("Failed to remove backup binary");
+    fs::remove_file(backup_dir.join("workflow.bash")).expect("Failed to remove backup completion");
+
+    Unzip::extract_tar_gz(&backup_info.archive_path(), backup_dir)
+        .expect("Failed to extract backup archive");
+
+    assert_eq!(
+        fs::read_to_string(backup_dir.join("workflow")).expect("Failed to read binary"),
+        "#!/bin/bash\necho 'workflow backup'"
+    );
+    assert_eq!(
+        fs::read_to_string(backup_dir.join("workflow.bash")).expect("Failed to read completion"),
+        "# bash completion backup"
+    );
+}
+
+/// 测试压缩备份归档缺失时回滚将所有文件标记为失败
+#[test]
+fn test_rollback_with_missing_compressed_archive() {
+    let temp_dir = setup_test_environment();
+    let mut backup_info = create_test_backup_info(&temp_dir);
+    backup_info.compressed = true;
+
+    let result = RollbackManager::rollback(&backup_info).expect("Rollback should not error");
+
+    assert!(result.restored_binaries.is_empty());
+    assert!(result.restored_completions.is_empty());
+    assert_eq!(result.failed_binaries.len(), 2);
+    assert_eq!(result.failed_completions.len(), 2);
+    assert_eq!(result.shell_reload_success, None);
+}
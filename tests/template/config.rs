@@ -6,11 +6,14 @@
 //! - 配置合并逻辑
 //! - 模板选择逻辑
 //! - 序列化和反序列化
+//! - 提交正文模板（Refs 和 Co-authored-by trailer）
 
 use pretty_assertions::assert_eq;
 use serde_json::json;
 use workflow::template::config::BranchTemplates;
-use workflow::template::{CommitTemplates, PullRequestsTemplates, TemplateConfig};
+use workflow::template::{
+    CommitTemplateVars, CommitTemplates, PullRequestsTemplates, TemplateConfig, TemplateEngine,
+};
 
 // ==================== 测试用例 ====================
 
@@ -58,6 +61,8 @@ fn test_config_struct_creation() {
     let commit_templates = CommitTemplates {
         default: "{{commit_type}}: {{subject}}".to_string(),
         use_scope: true,
+        body: CommitTemplates::default_body_template(),
+        validate_conventional: false,
     };
 
     let pr_templates = PullRequestsTemplates {
@@ -117,6 +122,11 @@ fn test_commit_templates_default() {
     assert!(commit_templates.default.contains("{{subject}}"));
     assert!(commit_templates.default.contains("{{commit_type}}"));
     assert_eq!(commit_templates.use_scope, false);
+    assert_eq!(
+        commit_templates.body,
+        CommitTemplates::default_body_template()
+    );
+    assert!(!commit_templates.validate_conventional);
 
     // 测试默认模板方法
     let default_template = CommitTemplates::default_commit_template();
@@ -211,6 +221,54 @@ default = "TOML PR template"
     assert_eq!(toml_config.engine, "toml_engine");
     assert_eq!(toml_config.branch.default, "toml-{{jira_key}}");
     assert_eq!(toml_config.commit.use_scope, false);
+    // 未配置的正文模板和校验选项使用默认值
+    assert_eq!(
+        toml_config.commit.body,
+        CommitTemplates::default_body_template()
+    );
+    assert!(!toml_config.commit.validate_conventional);
+}
+
+/// 渲染默认的提交正文模板
+fn render_default_body(body: Option<&str>, refs: Option<&str>, co_authors: &[&str]) -> String {
+    let vars = CommitTemplateVars {
+        commit_type: "feat".to_string(),
+        scope: None,
+        subject: "add login".to_string(),
+        body: body.map(str::to_string),
+        jira_key: None,
+        use_scope: true,
+        refs: refs.map(str::to_string),
+        co_authors: co_authors.iter().map(|c| c.to_string()).collect(),
+    };
+    TemplateEngine::new()
+        .render_string(&CommitTemplates::default_body_template(), &vars)
+        .unwrap()
+}
+
+/// 测试默认正文模板渲染 body、Refs 和 Co-authored-by trailer
+#[test]
+fn test_default_body_template_renders_refs_and_co_authors() {
+    let rendered = render_default_body(
+        Some("Support SSO login."),
+        Some("PROJ-123"),
+        &["Alice <alice@example.com>", "Bob <bob@example.com>"],
+    );
+
+    assert_eq!(
+        rendered.trim(),
+        "Support SSO login.\n\nRefs: PROJ-123\nCo-authored-by: Alice <alice@example.com>\nCo-authored-by: Bob <bob@example.com>"
+    );
+}
+
+/// 测试默认正文模板在没有任何内容时为空
+#[test]
+fn test_default_body_template_without_content_is_empty() {
+    assert_eq!(render_default_body(None, None, &[]).trim(), "");
+    assert_eq!(
+        render_default_body(None, Some("PROJ-1"), &[]).trim(),
+        "Refs: PROJ-1"
+    );
 }
 
 /// 测试分支模板按 JIRA 类型加载
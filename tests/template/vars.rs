@@ -64,6 +64,8 @@ fn test_commit_template_vars_creation() {
         ),
         jira_key: Some("AUTH-789".to_string()),
         use_scope: true,
+        refs: None,
+        co_authors: Vec::new(),
     };
 
     assert_eq!(vars.commit_type, "feat");
@@ -89,6 +91,8 @@ fn test_commit_template_vars_minimal() {
         body: None,
         jira_key: None,
         use_scope: false,
+        refs: None,
+        co_authors: Vec::new(),
     };
 
     assert_eq!(vars.commit_type, "fix");
@@ -213,6 +217,8 @@ fn test_vars_serialization() {
         body: Some("Testing serialization functionality".to_string()),
         jira_key: Some("SER-456".to_string()),
         use_scope: true,
+        refs: None,
+        co_authors: Vec::new(),
     };
 
     let commit_json = serde_json::to_string(&commit_vars);
@@ -282,6 +288,8 @@ fn test_vars_completeness() {
         body: Some("This commit adds complete functionality with all features.".to_string()),
         jira_key: Some("COMPLETE-002".to_string()),
         use_scope: true,
+        refs: None,
+        co_authors: Vec::new(),
     };
 
     let complete_pr_vars = PullRequestTemplateVars {
@@ -358,6 +366,8 @@ fn test_vars_clone() {
         body: None,
         jira_key: Some("CLONE-456".to_string()),
         use_scope: false,
+        refs: None,
+        co_authors: Vec::new(),
     };
 
     let cloned_commit_vars = original_commit_vars.clone();
@@ -393,6 +403,8 @@ fn test_vars_debug() {
         body: None,
         jira_key: None,
         use_scope: true,
+        refs: None,
+        co_authors: Vec::new(),
     };
 
     let commit_debug_str = format!("{:?}", commit_vars);